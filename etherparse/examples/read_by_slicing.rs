@@ -58,6 +58,11 @@ fn main() {
                         payload.protocol_type
                     )
                 }
+                Some(Ieee80211(value)) => println!(
+                    "  Ieee80211 {:?} => {:?}",
+                    value.source_addr(),
+                    value.destination_addr()
+                ),
                 None => {}
             }
 
@@ -0,0 +1,67 @@
+/// Iterator over the labels of a [`crate::DnsName`] (created via
+/// [`crate::DnsName::labels`]).
+///
+/// Compression pointers are followed transparently & the terminating root
+/// label is not returned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DnsLabelIter<'a> {
+    pub(crate) message: &'a [u8],
+    /// Position of the next label length byte (`None` once the end was
+    /// reached).
+    pub(crate) pos: Option<usize>,
+}
+
+impl<'a> Iterator for DnsLabelIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        // the name was validated by DnsName::from_message, so the
+        // labels & pointers can be followed without further limits
+        let mut pos = self.pos?;
+        loop {
+            let len_byte = *self.message.get(pos)?;
+            if len_byte & 0b1100_0000 == 0b1100_0000 {
+                let second = *self.message.get(pos + 1)?;
+                pos = (usize::from(len_byte & 0b0011_1111) << 8) | usize::from(second);
+            } else if len_byte == 0 {
+                self.pos = None;
+                return None;
+            } else {
+                let end = pos + 1 + usize::from(len_byte);
+                let label = self.message.get(pos + 1..end)?;
+                self.pos = Some(end);
+                return Some(label);
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for DnsLabelIter<'_> {}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn next() {
+        let message = [1, b'a', 0, 2, b'b', b'c', 0xC0, 0];
+        let name = DnsName::from_message(&message, 3).unwrap();
+        let mut iter = name.labels();
+        assert_eq!(Some(&b"bc"[..]), iter.next());
+        assert_eq!(Some(&b"a"[..]), iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let name = DnsName::from_message(&[0], 0).unwrap();
+        let iter = name.labels();
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "DnsLabelIter { message: [0], pos: Some(0) }",
+            format!("{:?}", iter)
+        );
+    }
+}
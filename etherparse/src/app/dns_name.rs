@@ -0,0 +1,348 @@
+use crate::*;
+
+/// Name (sequence of labels) contained in a DNS message, decoded without
+/// allocations.
+///
+/// The name is validated when it is created (including following
+/// compression pointers, limited to [`DnsName::MAX_POINTERS`]), after
+/// which the labels can be iterated via [`DnsName::labels`] or joined
+/// via the [`core::fmt::Display`] implementation:
+///
+/// ```
+/// use etherparse::DnsName;
+///
+/// let message = [
+///     // "example.com" at offset 0
+///     7, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 3, b'c', b'o', b'm', 0,
+///     // "www" + pointer to "example.com" at offset 13
+///     3, b'w', b'w', b'w', 0xC0, 0,
+/// ];
+/// let name = DnsName::from_message(&message, 13).unwrap();
+///
+/// // bytes used by the name at the given offset (next field starts after it)
+/// assert_eq!(6, name.encoded_len());
+///
+/// let mut labels = name.labels();
+/// assert_eq!(Some(&b"www"[..]), labels.next());
+/// assert_eq!(Some(&b"example"[..]), labels.next());
+/// assert_eq!(Some(&b"com"[..]), labels.next());
+/// assert_eq!(None, labels.next());
+///
+/// assert_eq!("www.example.com", format!("{}", name));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DnsName<'a> {
+    message: &'a [u8],
+    offset: usize,
+    encoded_len: usize,
+}
+
+impl<'a> DnsName<'a> {
+    /// Maximum length of a name in uncompressed wire format (including
+    /// the length bytes & the terminating root label).
+    pub const MAX_LEN: usize = 255;
+
+    /// Maximum number of compression pointers followed when decoding a
+    /// name (protects against pointer loops).
+    pub const MAX_POINTERS: usize = 16;
+
+    /// Decodes the name starting at `offset` in a DNS `message`.
+    ///
+    /// The complete message is required as compression pointers refer to
+    /// offsets relative to the start of the message. Length errors are
+    /// reported relative to the start of the message.
+    pub fn from_message(
+        message: &'a [u8],
+        offset: usize,
+    ) -> Result<DnsName<'a>, err::dns::NameSliceError> {
+        use err::dns::{NameError::*, NameSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: message.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::DnsName,
+                layer_start_offset: 0,
+            })
+        };
+
+        let mut pos = offset;
+        let mut encoded_len = None;
+        let mut pointers = 0;
+        // terminating root label
+        let mut name_len = 1;
+        loop {
+            let len_byte = *message.get(pos).ok_or_else(|| len_error(pos + 1))?;
+            match len_byte >> 6 {
+                0b00 => {
+                    if len_byte == 0 {
+                        // pos can be before offset if a pointer was followed
+                        let encoded_len = match encoded_len {
+                            Some(value) => value,
+                            None => pos + 1 - offset,
+                        };
+                        return Ok(DnsName {
+                            message,
+                            offset,
+                            encoded_len,
+                        });
+                    }
+                    let label_len = usize::from(len_byte);
+                    name_len += 1 + label_len;
+                    if name_len > DnsName::MAX_LEN {
+                        return Err(Content(NameTooLong { len: name_len }));
+                    }
+                    if message.len() < pos + 1 + label_len {
+                        return Err(len_error(pos + 1 + label_len));
+                    }
+                    pos += 1 + label_len;
+                }
+                0b11 => {
+                    let second = *message.get(pos + 1).ok_or_else(|| len_error(pos + 2))?;
+                    if encoded_len.is_none() {
+                        encoded_len = Some(pos + 2 - offset);
+                    }
+                    pointers += 1;
+                    if pointers > DnsName::MAX_POINTERS {
+                        return Err(Content(PointerLimitExceeded {
+                            limit: DnsName::MAX_POINTERS,
+                        }));
+                    }
+                    pos = (usize::from(len_byte & 0b0011_1111) << 8) | usize::from(second);
+                }
+                label_type => return Err(Content(UnsupportedLabelType { label_type })),
+            }
+        }
+    }
+
+    /// DNS message containing the name.
+    #[inline]
+    pub fn message(&self) -> &'a [u8] {
+        self.message
+    }
+
+    /// Offset of the name in the DNS message.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of bytes the name occupies at its offset (up to & including
+    /// the first compression pointer or the terminating root label).
+    #[inline]
+    pub fn encoded_len(&self) -> usize {
+        self.encoded_len
+    }
+
+    /// Returns true if the name is the root name (contains no labels).
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.labels().next().is_none()
+    }
+
+    /// Returns an iterator over the labels of the name (compression
+    /// pointers are resolved by the iterator).
+    #[inline]
+    pub fn labels(&self) -> DnsLabelIter<'a> {
+        DnsLabelIter {
+            message: self.message,
+            pos: Some(self.offset),
+        }
+    }
+}
+
+impl core::fmt::Display for DnsName<'_> {
+    /// Writes the labels separated by `.` (the root name is written as
+    /// `.`). Dots & backslashes in labels are escaped with a backslash,
+    /// non printable bytes are written as `\DDD` (decimal).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut first = true;
+        for label in self.labels() {
+            if !first {
+                f.write_str(".")?;
+            }
+            first = false;
+            for &b in label {
+                match b {
+                    b'.' | b'\\' => write!(f, "\\{}", char::from(b))?,
+                    0x21..=0x7E => write!(f, "{}", char::from(b))?,
+                    _ => write!(f, "\\{:03}", b)?,
+                }
+            }
+        }
+        if first {
+            f.write_str(".")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::dns::{NameError::*, NameSliceError::*};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn from_message() {
+        // root
+        {
+            let name = DnsName::from_message(&[0], 0).unwrap();
+            assert_eq!(&[0], name.message());
+            assert_eq!(0, name.offset());
+            assert_eq!(1, name.encoded_len());
+            assert!(name.is_root());
+        }
+        // name without pointer
+        {
+            let message = [9, 1, b'a', 0, 9];
+            let name = DnsName::from_message(&message, 1).unwrap();
+            assert_eq!(1, name.offset());
+            assert_eq!(3, name.encoded_len());
+            assert!(!name.is_root());
+        }
+        // pointer chain
+        {
+            let message = [1, b'a', 0, 1, b'b', 0xC0, 0, 0xC0, 3];
+            let name = DnsName::from_message(&message, 7).unwrap();
+            assert_eq!(2, name.encoded_len());
+            let labels: Vec<_> = name.labels().collect();
+            assert_eq!(labels, [&b"b"[..], &b"a"[..]]);
+        }
+        // length errors
+        {
+            let message = [3, b'a', b'b', b'c', 0xC0, 0];
+            for len in 0..4 {
+                assert_eq!(
+                    Len(err::LenError {
+                        required_len: if len == 0 { 1 } else { 4 },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::DnsName,
+                        layer_start_offset: 0,
+                    }),
+                    DnsName::from_message(&message[..len], 0).unwrap_err()
+                );
+            }
+            assert_eq!(
+                Len(err::LenError {
+                    required_len: 5,
+                    len: 4,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::DnsName,
+                    layer_start_offset: 0,
+                }),
+                DnsName::from_message(&message[..4], 0).unwrap_err()
+            );
+            assert_eq!(
+                Len(err::LenError {
+                    required_len: 6,
+                    len: 5,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::DnsName,
+                    layer_start_offset: 0,
+                }),
+                DnsName::from_message(&message[..5], 0).unwrap_err()
+            );
+            // pointer to outside of the message
+            assert_eq!(
+                Len(err::LenError {
+                    required_len: 0x3F + 1,
+                    len: 2,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::DnsName,
+                    layer_start_offset: 0,
+                }),
+                DnsName::from_message(&[0xC0, 0x3F], 0).unwrap_err()
+            );
+        }
+        // unsupported label types
+        for label_type in [0b01u8, 0b10] {
+            assert_eq!(
+                Content(UnsupportedLabelType { label_type }),
+                DnsName::from_message(&[label_type << 6], 0).unwrap_err()
+            );
+        }
+        // pointer loop
+        assert_eq!(
+            Content(PointerLimitExceeded {
+                limit: DnsName::MAX_POINTERS
+            }),
+            DnsName::from_message(&[0xC0, 0], 0).unwrap_err()
+        );
+        // name too long (4 * 64 + 1 = 257 bytes)
+        {
+            let mut message = Vec::new();
+            for _ in 0..4 {
+                message.push(63);
+                message.extend_from_slice(&[b'a'; 63]);
+            }
+            message.push(0);
+            assert_eq!(
+                Content(NameTooLong { len: 257 }),
+                DnsName::from_message(&message, 0).unwrap_err()
+            );
+            // maximum length (255 bytes)
+            message[3 * 64] = 61;
+            message.truncate(3 * 64 + 62);
+            message.push(0);
+            assert_eq!(255, message.len());
+            assert!(DnsName::from_message(&message, 0).is_ok());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn labels_roundtrip(
+            labels in proptest::collection::vec(
+                proptest::collection::vec(any::<u8>(), 1..20),
+                0..8
+            )
+        ) {
+            let mut message = Vec::new();
+            for label in &labels {
+                message.push(label.len() as u8);
+                message.extend_from_slice(label);
+            }
+            message.push(0);
+
+            let name = DnsName::from_message(&message, 0).unwrap();
+            assert_eq!(message.len(), name.encoded_len());
+            assert_eq!(labels.is_empty(), name.is_root());
+            let actual: Vec<&[u8]> = name.labels().collect();
+            let expected: Vec<&[u8]> = labels.iter().map(|l| &l[..]).collect();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let tests: [(&[u8], &str); 4] = [
+            (&[0], "."),
+            (&[3, b'c', b'o', b'm', 0], "com"),
+            (&[2, b'a', b'b', 1, b'c', 0], "ab.c"),
+            (
+                &[4, b'a', b'.', b'\\', 0x20, 1, 0xFF, 0],
+                "a\\.\\\\\\032.\\255",
+            ),
+        ];
+        for (message, expected) in tests {
+            assert_eq!(
+                expected,
+                format!("{}", DnsName::from_message(message, 0).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let name = DnsName::from_message(&[0], 0).unwrap();
+        assert_eq!(name, name.clone());
+        assert_eq!(
+            "DnsName { message: [0], offset: 0, encoded_len: 1 }",
+            format!("{:?}", name)
+        );
+    }
+}
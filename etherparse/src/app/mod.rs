@@ -0,0 +1,16 @@
+pub mod dns_label_iter;
+pub mod dns_name;
+pub mod ntp_header;
+pub mod ntp_leap_indicator;
+pub mod ntp_mode;
+pub mod ntp_packet_slice;
+pub mod ntp_timestamp;
+pub mod ntp_version;
+pub mod ptp_header;
+pub mod ptp_header_slice;
+pub mod ptp_message_type;
+pub mod someip_header_slice;
+pub mod someip_message_type;
+pub mod someip_return_code;
+pub mod wireguard_message_type;
+pub mod wireguard_slice;
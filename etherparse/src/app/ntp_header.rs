@@ -0,0 +1,253 @@
+use crate::*;
+
+/// Fixed 48 byte header of an NTP packet (RFC 5905).
+///
+/// Optional extension fields & message authentication codes following
+/// the header are not part of the header (see
+/// [`crate::NtpPacketSlice::extension`]).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct NtpHeader {
+    /// Leap indicator (LI).
+    pub leap_indicator: NtpLeapIndicator,
+    /// Version number (VN).
+    pub version: NtpVersion,
+    /// Mode of the packet (e.g. client or server).
+    pub mode: NtpMode,
+    /// Stratum of the server (0 = unspecified, 1 = primary server,
+    /// 2-15 = secondary server, 16 = unsynchronized).
+    pub stratum: u8,
+    /// Maximum interval between successive messages in log2 seconds.
+    pub poll: i8,
+    /// Precision of the system clock in log2 seconds.
+    pub precision: i8,
+    /// Total round-trip delay to the reference clock in NTP short format
+    /// (16 bit seconds & 16 bit fraction).
+    pub root_delay: u32,
+    /// Total dispersion to the reference clock in NTP short format
+    /// (16 bit seconds & 16 bit fraction).
+    pub root_dispersion: u32,
+    /// Reference ID identifying the server or reference clock (e.g. an
+    /// IPv4 address or an ASCII "kiss code").
+    pub reference_id: [u8; 4],
+    /// Time the system clock was last set or corrected.
+    pub reference_timestamp: NtpTimestamp,
+    /// Time at the client when the request departed for the server.
+    pub origin_timestamp: NtpTimestamp,
+    /// Time at the server when the request arrived from the client.
+    pub receive_timestamp: NtpTimestamp,
+    /// Time at the server when the response left for the client.
+    pub transmit_timestamp: NtpTimestamp,
+}
+
+impl NtpHeader {
+    /// Serialized size of an NTP header in bytes/octets.
+    pub const LEN: usize = 48;
+
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`NtpHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = NtpHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`NtpHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = NtpHeader::LEN;
+
+    /// Read an NTP header from a slice and return the header & the
+    /// rest of the slice (extension fields & MAC if present).
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(NtpHeader, &[u8]), err::LenError> {
+        let s = NtpPacketSlice::from_slice(slice)?;
+        Ok((s.to_header(), s.extension()))
+    }
+
+    /// Read an NTP header from a static sized byte array.
+    pub fn from_bytes(bytes: [u8; 48]) -> NtpHeader {
+        let ts = |offset: usize| {
+            NtpTimestamp::from_be_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+                bytes[offset + 4],
+                bytes[offset + 5],
+                bytes[offset + 6],
+                bytes[offset + 7],
+            ])
+        };
+        NtpHeader {
+            leap_indicator: NtpLeapIndicator::from_bits(bytes[0] >> 6),
+            version: unchecked! {
+                // SAFETY: Safe as the bitmask limits the value to 3 bits.
+                NtpVersion::new_unchecked_internal((bytes[0] >> 3) & 0b111)
+            },
+            mode: NtpMode::from_bits(bytes[0]),
+            stratum: bytes[1],
+            poll: bytes[2] as i8,
+            precision: bytes[3] as i8,
+            root_delay: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            root_dispersion: u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+            reference_id: [bytes[12], bytes[13], bytes[14], bytes[15]],
+            reference_timestamp: ts(16),
+            origin_timestamp: ts(24),
+            receive_timestamp: ts(32),
+            transmit_timestamp: ts(40),
+        }
+    }
+
+    /// Read an NTP header.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<NtpHeader, std::io::Error> {
+        let mut buffer = [0u8; NtpHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(NtpHeader::from_bytes(buffer))
+    }
+
+    /// Write the NTP header.
+    #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        NtpHeader::LEN
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> [u8; 48] {
+        let mut result = [0u8; 48];
+        result[0] =
+            (self.leap_indicator.bits() << 6) | (self.version.value() << 3) | self.mode.bits();
+        result[1] = self.stratum;
+        result[2] = self.poll as u8;
+        result[3] = self.precision as u8;
+        result[4..8].copy_from_slice(&self.root_delay.to_be_bytes());
+        result[8..12].copy_from_slice(&self.root_dispersion.to_be_bytes());
+        result[12..16].copy_from_slice(&self.reference_id);
+        result[16..24].copy_from_slice(&self.reference_timestamp.to_be_bytes());
+        result[24..32].copy_from_slice(&self.origin_timestamp.to_be_bytes());
+        result[32..40].copy_from_slice(&self.receive_timestamp.to_be_bytes());
+        result[40..48].copy_from_slice(&self.transmit_timestamp.to_be_bytes());
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn constants() {
+        assert_eq!(48, NtpHeader::LEN);
+        assert_eq!(48, NtpHeader::default().header_len());
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in ntp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(NtpHeader::LEN + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // normal
+            {
+                let (result, rest) = NtpHeader::from_slice(&buffer).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &buffer[NtpHeader::LEN..]);
+            }
+
+            // slice length to small
+            for len in 0..NtpHeader::LEN {
+                assert_eq!(
+                    NtpHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: NtpHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::NtpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes_to_bytes(input in ntp_any()) {
+            assert_eq!(input, NtpHeader::from_bytes(input.to_bytes()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in ntp_any()) {
+            // ok
+            {
+                let mut buffer = Vec::new();
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, NtpHeader::read(&mut cursor).unwrap());
+            }
+            // unexpected eof
+            {
+                let buffer = input.to_bytes();
+                let mut cursor = Cursor::new(&buffer[..NtpHeader::LEN - 1]);
+                assert_eq!(
+                    ErrorKind::UnexpectedEof,
+                    NtpHeader::read(&mut cursor).unwrap_err().kind()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        let header = NtpHeader {
+            leap_indicator: NtpLeapIndicator::Unsynchronized,
+            version: NtpVersion::V4,
+            mode: NtpMode::Client,
+            stratum: 2,
+            poll: 6,
+            precision: -20,
+            root_delay: 0x0102_0304,
+            root_dispersion: 0x0506_0708,
+            reference_id: *b"GPS\0",
+            reference_timestamp: NtpTimestamp {
+                seconds: 1,
+                fraction: 2,
+            },
+            origin_timestamp: NtpTimestamp {
+                seconds: 3,
+                fraction: 4,
+            },
+            receive_timestamp: NtpTimestamp {
+                seconds: 5,
+                fraction: 6,
+            },
+            transmit_timestamp: NtpTimestamp {
+                seconds: 7,
+                fraction: 8,
+            },
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(0b11_100_011, bytes[0]);
+        assert_eq!(2, bytes[1]);
+        assert_eq!(6, bytes[2]);
+        assert_eq!((-20i8) as u8, bytes[3]);
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], bytes[4..12]);
+        assert_eq!(*b"GPS\0", bytes[12..16]);
+        assert_eq!([0, 0, 0, 1, 0, 0, 0, 2], bytes[16..24]);
+        assert_eq!([0, 0, 0, 7, 0, 0, 0, 8], bytes[40..48]);
+    }
+}
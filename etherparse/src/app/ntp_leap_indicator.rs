@@ -0,0 +1,65 @@
+/// 2 bit "leap indicator" (LI) of an NTP packet warning of an impending
+/// leap second to be inserted or deleted in the last minute of the
+/// current month.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NtpLeapIndicator {
+    /// No warning (`0`).
+    #[default]
+    NoWarning = 0,
+    /// Last minute of the day has 61 seconds (`1`).
+    LastMinute61 = 1,
+    /// Last minute of the day has 59 seconds (`2`).
+    LastMinute59 = 2,
+    /// Unknown (clock unsynchronized, `3`).
+    Unsynchronized = 3,
+}
+
+impl NtpLeapIndicator {
+    /// Converts the lower 2 bits of the given value to a leap indicator
+    /// (the upper bits are ignored).
+    #[inline]
+    pub const fn from_bits(value: u8) -> NtpLeapIndicator {
+        use NtpLeapIndicator::*;
+        match value & 0b11 {
+            0 => NoWarning,
+            1 => LastMinute61,
+            2 => LastMinute59,
+            _ => Unsynchronized,
+        }
+    }
+
+    /// Returns the 2 bit value of the leap indicator.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_bits_bits() {
+        use NtpLeapIndicator::*;
+        for (bits, expected) in [
+            (0, NoWarning),
+            (1, LastMinute61),
+            (2, LastMinute59),
+            (3, Unsynchronized),
+        ] {
+            assert_eq!(expected, NtpLeapIndicator::from_bits(bits));
+            assert_eq!(expected, NtpLeapIndicator::from_bits(bits | 0b1111_1100));
+            assert_eq!(bits, expected.bits());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: NtpLeapIndicator = Default::default();
+        assert_eq!(NtpLeapIndicator::NoWarning, value);
+        assert_eq!(value, value.clone());
+        assert_eq!("NoWarning", format!("{:?}", value));
+    }
+}
@@ -0,0 +1,79 @@
+/// 3 bit "mode" of an NTP packet.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NtpMode {
+    /// Reserved (`0`).
+    #[default]
+    Reserved = 0,
+    /// Symmetric active (`1`).
+    SymmetricActive = 1,
+    /// Symmetric passive (`2`).
+    SymmetricPassive = 2,
+    /// Client (`3`).
+    Client = 3,
+    /// Server (`4`).
+    Server = 4,
+    /// Broadcast (`5`).
+    Broadcast = 5,
+    /// NTP control message (`6`).
+    Control = 6,
+    /// Reserved for private use (`7`).
+    Private = 7,
+}
+
+impl NtpMode {
+    /// Converts the lower 3 bits of the given value to a mode (the
+    /// upper bits are ignored).
+    #[inline]
+    pub const fn from_bits(value: u8) -> NtpMode {
+        use NtpMode::*;
+        match value & 0b111 {
+            0 => Reserved,
+            1 => SymmetricActive,
+            2 => SymmetricPassive,
+            3 => Client,
+            4 => Server,
+            5 => Broadcast,
+            6 => Control,
+            _ => Private,
+        }
+    }
+
+    /// Returns the 3 bit value of the mode.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_bits_bits() {
+        use NtpMode::*;
+        for (bits, expected) in [
+            (0, Reserved),
+            (1, SymmetricActive),
+            (2, SymmetricPassive),
+            (3, Client),
+            (4, Server),
+            (5, Broadcast),
+            (6, Control),
+            (7, Private),
+        ] {
+            assert_eq!(expected, NtpMode::from_bits(bits));
+            assert_eq!(expected, NtpMode::from_bits(bits | 0b1111_1000));
+            assert_eq!(bits, expected.bits());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: NtpMode = Default::default();
+        assert_eq!(NtpMode::Reserved, value);
+        assert_eq!(value, value.clone());
+        assert_eq!("Client", format!("{:?}", NtpMode::Client));
+    }
+}
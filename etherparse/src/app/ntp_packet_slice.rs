@@ -0,0 +1,241 @@
+use crate::*;
+
+/// Slice containing an NTP packet (fixed header & optional extension
+/// fields / message authentication code).
+///
+/// NTP is transported via UDP (port [`NtpPacketSlice::UDP_PORT`]):
+///
+/// ```
+/// use etherparse::{NtpMode, NtpPacketSlice, SlicedPacket, TransportSlice};
+///
+/// # let mut packet = Vec::new();
+/// # let ntp = etherparse::NtpHeader {
+/// #     mode: NtpMode::Server,
+/// #     stratum: 1,
+/// #     ..Default::default()
+/// # };
+/// # etherparse::PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #     .udp(123, 123)
+/// #     .write(&mut packet, &ntp.to_bytes())
+/// #     .unwrap();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     if udp.source_port() == NtpPacketSlice::UDP_PORT {
+///         let ntp = NtpPacketSlice::from_slice(udp.payload()).unwrap();
+///         assert_eq!(NtpMode::Server, ntp.mode());
+///         assert_eq!(1, ntp.stratum());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NtpPacketSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> NtpPacketSlice<'a> {
+    /// UDP port used by NTP.
+    pub const UDP_PORT: u16 = 123;
+
+    /// Try creating an [`NtpPacketSlice`] from a slice containing an
+    /// NTP packet.
+    pub fn from_slice(slice: &'a [u8]) -> Result<NtpPacketSlice<'a>, err::LenError> {
+        if slice.len() < NtpHeader::LEN {
+            return Err(err::LenError {
+                required_len: NtpHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::NtpHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(NtpPacketSlice { slice })
+    }
+
+    /// Returns the slice containing the complete NTP packet.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing the fixed 48 byte NTP header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        // SAFETY: Safe as the constructor checks that the slice has
+        // at least the length of NtpHeader::LEN (48).
+        unchecked! { get_unchecked_slice(self.slice, 0, NtpHeader::LEN) }
+    }
+
+    /// Returns the data after the fixed header (extension fields and/or
+    /// message authentication code, empty if not present).
+    #[inline]
+    pub fn extension(&self) -> &'a [u8] {
+        // SAFETY: Safe as the constructor checks that the slice has
+        // at least the length of NtpHeader::LEN (48).
+        unchecked! {
+            get_unchecked_slice(self.slice, NtpHeader::LEN, self.slice.len() - NtpHeader::LEN)
+        }
+    }
+
+    /// Read the "leap indicator" (LI).
+    #[inline]
+    pub fn leap_indicator(&self) -> NtpLeapIndicator {
+        NtpLeapIndicator::from_bits(self.slice[0] >> 6)
+    }
+
+    /// Read the "version number" (VN).
+    #[inline]
+    pub fn version(&self) -> NtpVersion {
+        // SAFETY: Safe as the bitmask limits the value to 3 bits.
+        unchecked! { NtpVersion::new_unchecked_internal((self.slice[0] >> 3) & 0b111) }
+    }
+
+    /// Read the "mode".
+    #[inline]
+    pub fn mode(&self) -> NtpMode {
+        NtpMode::from_bits(self.slice[0])
+    }
+
+    /// Read the "stratum".
+    #[inline]
+    pub fn stratum(&self) -> u8 {
+        self.slice[1]
+    }
+
+    /// Read the "poll" interval (log2 seconds).
+    #[inline]
+    pub fn poll(&self) -> i8 {
+        self.slice[2] as i8
+    }
+
+    /// Read the "precision" of the system clock (log2 seconds).
+    #[inline]
+    pub fn precision(&self) -> i8 {
+        self.slice[3] as i8
+    }
+
+    /// Read the "root delay" (NTP short format).
+    #[inline]
+    pub fn root_delay(&self) -> u32 {
+        u32::from_be_bytes([self.slice[4], self.slice[5], self.slice[6], self.slice[7]])
+    }
+
+    /// Read the "root dispersion" (NTP short format).
+    #[inline]
+    pub fn root_dispersion(&self) -> u32 {
+        u32::from_be_bytes([self.slice[8], self.slice[9], self.slice[10], self.slice[11]])
+    }
+
+    /// Read the "reference ID".
+    #[inline]
+    pub fn reference_id(&self) -> [u8; 4] {
+        [
+            self.slice[12],
+            self.slice[13],
+            self.slice[14],
+            self.slice[15],
+        ]
+    }
+
+    /// Read the timestamp starting at the given offset.
+    #[inline]
+    fn timestamp(&self, offset: usize) -> NtpTimestamp {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.slice[offset..offset + 8]);
+        NtpTimestamp::from_be_bytes(bytes)
+    }
+
+    /// Read the "reference timestamp".
+    #[inline]
+    pub fn reference_timestamp(&self) -> NtpTimestamp {
+        self.timestamp(16)
+    }
+
+    /// Read the "origin timestamp".
+    #[inline]
+    pub fn origin_timestamp(&self) -> NtpTimestamp {
+        self.timestamp(24)
+    }
+
+    /// Read the "receive timestamp".
+    #[inline]
+    pub fn receive_timestamp(&self) -> NtpTimestamp {
+        self.timestamp(32)
+    }
+
+    /// Read the "transmit timestamp".
+    #[inline]
+    pub fn transmit_timestamp(&self) -> NtpTimestamp {
+        self.timestamp(40)
+    }
+
+    /// Decode all the fields and copy the results to an [`NtpHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> NtpHeader {
+        NtpHeader {
+            leap_indicator: self.leap_indicator(),
+            version: self.version(),
+            mode: self.mode(),
+            stratum: self.stratum(),
+            poll: self.poll(),
+            precision: self.precision(),
+            root_delay: self.root_delay(),
+            root_dispersion: self.root_dispersion(),
+            reference_id: self.reference_id(),
+            reference_timestamp: self.reference_timestamp(),
+            origin_timestamp: self.origin_timestamp(),
+            receive_timestamp: self.receive_timestamp(),
+            transmit_timestamp: self.transmit_timestamp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            header in ntp_any(),
+            extension in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(NtpHeader::LEN + extension.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&extension);
+
+            let slice = NtpPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(&buffer[..], slice.slice());
+            assert_eq!(&buffer[..NtpHeader::LEN], slice.header_slice());
+            assert_eq!(&extension[..], slice.extension());
+            assert_eq!(header.leap_indicator, slice.leap_indicator());
+            assert_eq!(header.version, slice.version());
+            assert_eq!(header.mode, slice.mode());
+            assert_eq!(header.stratum, slice.stratum());
+            assert_eq!(header.poll, slice.poll());
+            assert_eq!(header.precision, slice.precision());
+            assert_eq!(header.root_delay, slice.root_delay());
+            assert_eq!(header.root_dispersion, slice.root_dispersion());
+            assert_eq!(header.reference_id, slice.reference_id());
+            assert_eq!(header.reference_timestamp, slice.reference_timestamp());
+            assert_eq!(header.origin_timestamp, slice.origin_timestamp());
+            assert_eq!(header.receive_timestamp, slice.receive_timestamp());
+            assert_eq!(header.transmit_timestamp, slice.transmit_timestamp());
+            assert_eq!(header, slice.to_header());
+
+            for len in 0..NtpHeader::LEN {
+                assert_eq!(
+                    NtpPacketSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: NtpHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::NtpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+}
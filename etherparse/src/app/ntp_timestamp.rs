@@ -0,0 +1,123 @@
+/// 64 bit NTP timestamp (seconds since 1900-01-01 00:00 UTC & fraction
+/// of a second in units of 2^-32 seconds).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NtpTimestamp {
+    /// Seconds since the NTP epoch (1900-01-01 00:00 UTC) modulo 2^32.
+    pub seconds: u32,
+    /// Fraction of a second in units of 2^-32 seconds.
+    pub fraction: u32,
+}
+
+impl NtpTimestamp {
+    /// Seconds between the NTP epoch (1900-01-01) & the unix epoch (1970-01-01).
+    pub const UNIX_EPOCH_OFFSET: u64 = 2_208_988_800;
+
+    /// Timestamp with all bits set to zero (used to indicate an
+    /// unknown or invalid time).
+    pub const ZERO: NtpTimestamp = NtpTimestamp {
+        seconds: 0,
+        fraction: 0,
+    };
+
+    /// Creates a timestamp from its big endian byte representation.
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> NtpTimestamp {
+        NtpTimestamp {
+            seconds: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            fraction: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        }
+    }
+
+    /// Returns the big endian byte representation of the timestamp.
+    #[inline]
+    pub const fn to_be_bytes(&self) -> [u8; 8] {
+        let s = self.seconds.to_be_bytes();
+        let f = self.fraction.to_be_bytes();
+        [s[0], s[1], s[2], s[3], f[0], f[1], f[2], f[3]]
+    }
+
+    /// Returns true if all bits of the timestamp are zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        0 == self.seconds && 0 == self.fraction
+    }
+
+    /// Converts the fraction to nanoseconds (rounded down).
+    #[inline]
+    pub const fn fraction_nanos(&self) -> u32 {
+        ((self.fraction as u64 * 1_000_000_000) >> 32) as u32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn be_bytes(seconds in any::<u32>(), fraction in any::<u32>()) {
+            let ts = NtpTimestamp { seconds, fraction };
+            let bytes = ts.to_be_bytes();
+            assert_eq!(&seconds.to_be_bytes(), &bytes[..4]);
+            assert_eq!(&fraction.to_be_bytes(), &bytes[4..]);
+            assert_eq!(ts, NtpTimestamp::from_be_bytes(bytes));
+        }
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(NtpTimestamp::ZERO.is_zero());
+        assert!(
+            false
+                == NtpTimestamp {
+                    seconds: 1,
+                    fraction: 0
+                }
+                .is_zero()
+        );
+        assert!(
+            false
+                == NtpTimestamp {
+                    seconds: 0,
+                    fraction: 1
+                }
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn fraction_nanos() {
+        assert_eq!(0, NtpTimestamp::ZERO.fraction_nanos());
+        assert_eq!(
+            500_000_000,
+            NtpTimestamp {
+                seconds: 0,
+                fraction: 0x8000_0000
+            }
+            .fraction_nanos()
+        );
+        assert_eq!(
+            999_999_999,
+            NtpTimestamp {
+                seconds: 0,
+                fraction: u32::MAX
+            }
+            .fraction_nanos()
+        );
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let ts = NtpTimestamp {
+            seconds: 1,
+            fraction: 2,
+        };
+        assert_eq!(ts, ts.clone());
+        assert_eq!(
+            "NtpTimestamp { seconds: 1, fraction: 2 }",
+            format!("{:?}", ts)
+        );
+    }
+}
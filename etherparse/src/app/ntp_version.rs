@@ -0,0 +1,269 @@
+use crate::err::ValueTooBigError;
+
+/// 3 bit unsigned integer containing the "version number" (VN) of an
+/// NTP packet (present in the [`crate::NtpHeader`]).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NtpVersion(u8);
+
+impl NtpVersion {
+    /// NtpVersion with value 0.
+    pub const ZERO: NtpVersion = NtpVersion(0);
+
+    /// NTP version 3 (RFC 1305).
+    pub const V3: NtpVersion = NtpVersion(3);
+
+    /// NTP version 4 (RFC 5905).
+    pub const V4: NtpVersion = NtpVersion(4);
+
+    /// Maximum value of an NTP version number.
+    pub const MAX_U8: u8 = 0b0000_0111;
+
+    /// Tries to create an [`NtpVersion`] and checks that the passed value
+    /// is smaller or equal than [`NtpVersion::MAX_U8`] (3 bit unsigned integer).
+    ///
+    /// In case the passed value is bigger then what can be represented in a 3 bit
+    /// integer an error is returned. Otherwise an `Ok` containing the [`NtpVersion`].
+    ///
+    /// ```
+    /// use etherparse::NtpVersion;
+    ///
+    /// let version = NtpVersion::try_new(4).unwrap();
+    /// assert_eq!(version.value(), 4);
+    ///
+    /// // if a number that can not be represented in a 3 bit integer
+    /// // gets passed in an error is returned
+    /// use etherparse::err::{ValueTooBigError, ValueType};
+    /// assert_eq!(
+    ///     NtpVersion::try_new(NtpVersion::MAX_U8 + 1),
+    ///     Err(ValueTooBigError{
+    ///         actual: NtpVersion::MAX_U8 + 1,
+    ///         max_allowed: NtpVersion::MAX_U8,
+    ///         value_type: ValueType::NtpVersion,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_new(value: u8) -> Result<NtpVersion, ValueTooBigError<u8>> {
+        use crate::err::ValueType;
+        if value <= NtpVersion::MAX_U8 {
+            Ok(NtpVersion(value))
+        } else {
+            Err(ValueTooBigError {
+                actual: value,
+                max_allowed: NtpVersion::MAX_U8,
+                value_type: ValueType::NtpVersion,
+            })
+        }
+    }
+
+    /// Creates an [`NtpVersion`] without checking that the value
+    /// is smaller or equal than [`NtpVersion::MAX_U8`] (3 bit unsigned integer).
+    /// The caller must guarantee that `value <= NtpVersion::MAX_U8`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be smaller or equal than [`NtpVersion::MAX_U8`]
+    /// otherwise the behavior of functions or data structures relying
+    /// on this pre-requirement is undefined.
+    #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+    pub const unsafe fn new_unchecked(value: u8) -> NtpVersion {
+        unchecked! { NtpVersion::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`NtpVersion::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u8) -> NtpVersion {
+            debug_assert!(value <= NtpVersion::MAX_U8);
+            NtpVersion(value)
+        }
+    }
+
+    /// Returns the underlying unsigned 3 bit value as an `u8` value.
+    #[inline]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for NtpVersion {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<NtpVersion> for u8 {
+    #[inline]
+    fn from(value: NtpVersion) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u8> for NtpVersion {
+    type Error = ValueTooBigError<u8>;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use crate::err::ValueType;
+        if value <= NtpVersion::MAX_U8 {
+            Ok(NtpVersion(value))
+        } else {
+            Err(Self::Error {
+                actual: value,
+                max_allowed: NtpVersion::MAX_U8,
+                value_type: ValueType::NtpVersion,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+mod test {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn derived_traits() {
+        // copy & clone
+        {
+            let a = NtpVersion(2);
+            let b = a;
+            assert_eq!(a, b);
+            assert_eq!(a.clone(), a);
+        }
+
+        // default
+        {
+            let actual: NtpVersion = Default::default();
+            assert_eq!(actual.value(), 0);
+        }
+
+        // debug
+        {
+            let a = NtpVersion(2);
+            assert_eq!(format!("{:?}", a), format!("NtpVersion(2)"));
+        }
+
+        // ord & partial ord
+        {
+            use core::cmp::Ordering;
+            let a = NtpVersion(2);
+            let b = a;
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        }
+
+        // hash
+        {
+            use std::collections::hash_map::DefaultHasher;
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                NtpVersion(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                NtpVersion(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_new(
+            valid_value in 0..=0b0000_0111u8,
+            invalid_value in 0b0000_1000u8..=u8::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            assert_eq!(
+                valid_value,
+                NtpVersion::try_new(valid_value).unwrap().value()
+            );
+            assert_eq!(
+                NtpVersion::try_new(invalid_value).unwrap_err(),
+                ValueTooBigError{
+                    actual: invalid_value,
+                    max_allowed: 0b0000_0111,
+                    value_type:  ValueType::NtpVersion
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_from(
+            valid_value in 0..=0b0000_0111u8,
+            invalid_value in 0b0000_1000u8..=u8::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            // try_into
+            {
+                let actual: NtpVersion = valid_value.try_into().unwrap();
+                assert_eq!(actual.value(), valid_value);
+
+                let err: Result<NtpVersion, ValueTooBigError<u8>> = invalid_value.try_into();
+                assert_eq!(
+                    err.unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b0000_0111,
+                        value_type:  ValueType::NtpVersion
+                    }
+                );
+            }
+            // try_from
+            {
+                assert_eq!(
+                    NtpVersion::try_from(valid_value).unwrap().value(),
+                    valid_value
+                );
+
+                assert_eq!(
+                    NtpVersion::try_from(invalid_value).unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b0000_0111,
+                        value_type:  ValueType::NtpVersion
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_unchecked(valid_value in 0..=0b0000_0111u8) {
+            assert_eq!(
+                valid_value,
+                unsafe {
+                    NtpVersion::new_unchecked(valid_value).value()
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt(valid_value in 0..=0b0000_0111u8) {
+            assert_eq!(format!("{}", NtpVersion(valid_value)), format!("{}", valid_value));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from(valid_value in 0..=0b0000_0111u8,) {
+            let version = NtpVersion::try_new(valid_value).unwrap();
+            let actual: u8 = version.into();
+            assert_eq!(actual, valid_value);
+        }
+    }
+}
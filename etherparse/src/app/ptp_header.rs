@@ -0,0 +1,232 @@
+use crate::*;
+
+/// Common 34 byte header of a PTP (Precision Time Protocol, IEEE 1588)
+/// message.
+///
+/// The message type specific body following the header is not part of
+/// the header (see [`PtpHeader::message_length`]).
+///
+/// Fields that are only 4 bits wide (`transport_specific`,
+/// `minor_version` & `version`) only have their lower 4 bits serialized.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct PtpHeader {
+    /// Transport specific bits (4 bit, "majorSdoId" in IEEE 1588-2019).
+    pub transport_specific: u8,
+    /// Type of the message (e.g. sync or announce).
+    pub message_type: PtpMessageType,
+    /// Minor version of the PTP protocol (4 bit, IEEE 1588-2019).
+    pub minor_version: u8,
+    /// Version of the PTP protocol (4 bit, 2 for IEEE 1588-2008 & 2019).
+    pub version: u8,
+    /// Length of the complete PTP message (header & body) in bytes.
+    pub message_length: u16,
+    /// Domain the message belongs to.
+    pub domain_number: u8,
+    /// Minor standard organization id ("minorSdoId", reserved in IEEE
+    /// 1588-2008).
+    pub minor_sdo_id: u8,
+    /// Flags (e.g. two step flag or unicast flag).
+    pub flags: [u8; 2],
+    /// Correction of the time in nanoseconds multiplied by 2^16.
+    pub correction_field: i64,
+    /// Message type specific field (reserved for most message types).
+    pub message_type_specific: [u8; 4],
+    /// Clock identity of the source port identity.
+    pub clock_identity: [u8; 8],
+    /// Port number of the source port identity.
+    pub source_port_number: u16,
+    /// Sequence id of the message.
+    pub sequence_id: u16,
+    /// Control field (deprecated since IEEE 1588-2008, kept for IEEE
+    /// 1588-2002 compatibility).
+    pub control_field: u8,
+    /// Log2 of the mean message interval in seconds.
+    pub log_message_interval: i8,
+}
+
+impl PtpHeader {
+    /// Serialized size of a PTP header in bytes/octets.
+    pub const LEN: usize = 34;
+
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`PtpHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = PtpHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`PtpHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = PtpHeader::LEN;
+
+    /// Read a PTP header from a slice and return the header & the
+    /// rest of the slice (message body).
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(PtpHeader, &[u8]), err::LenError> {
+        let s = PtpHeaderSlice::from_slice(slice)?;
+        Ok((s.to_header(), &slice[PtpHeader::LEN..]))
+    }
+
+    /// Read a PTP header from a static sized byte array.
+    pub fn from_bytes(bytes: [u8; 34]) -> PtpHeader {
+        PtpHeader {
+            transport_specific: bytes[0] >> 4,
+            message_type: PtpMessageType::from_bits(bytes[0]),
+            minor_version: bytes[1] >> 4,
+            version: bytes[1] & 0b1111,
+            message_length: u16::from_be_bytes([bytes[2], bytes[3]]),
+            domain_number: bytes[4],
+            minor_sdo_id: bytes[5],
+            flags: [bytes[6], bytes[7]],
+            correction_field: i64::from_be_bytes([
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ]),
+            message_type_specific: [bytes[16], bytes[17], bytes[18], bytes[19]],
+            clock_identity: [
+                bytes[20], bytes[21], bytes[22], bytes[23], bytes[24], bytes[25], bytes[26],
+                bytes[27],
+            ],
+            source_port_number: u16::from_be_bytes([bytes[28], bytes[29]]),
+            sequence_id: u16::from_be_bytes([bytes[30], bytes[31]]),
+            control_field: bytes[32],
+            log_message_interval: bytes[33] as i8,
+        }
+    }
+
+    /// Read a PTP header.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<PtpHeader, std::io::Error> {
+        let mut buffer = [0u8; PtpHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(PtpHeader::from_bytes(buffer))
+    }
+
+    /// Write the PTP header.
+    #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        PtpHeader::LEN
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> [u8; 34] {
+        let mut result = [0u8; 34];
+        result[0] = (self.transport_specific << 4) | self.message_type.bits();
+        result[1] = (self.minor_version << 4) | (self.version & 0b1111);
+        result[2..4].copy_from_slice(&self.message_length.to_be_bytes());
+        result[4] = self.domain_number;
+        result[5] = self.minor_sdo_id;
+        result[6..8].copy_from_slice(&self.flags);
+        result[8..16].copy_from_slice(&self.correction_field.to_be_bytes());
+        result[16..20].copy_from_slice(&self.message_type_specific);
+        result[20..28].copy_from_slice(&self.clock_identity);
+        result[28..30].copy_from_slice(&self.source_port_number.to_be_bytes());
+        result[30..32].copy_from_slice(&self.sequence_id.to_be_bytes());
+        result[32] = self.control_field;
+        result[33] = self.log_message_interval as u8;
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn constants() {
+        assert_eq!(34, PtpHeader::LEN);
+        assert_eq!(34, PtpHeader::default().header_len());
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in ptp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(PtpHeader::LEN + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // normal
+            {
+                let (result, rest) = PtpHeader::from_slice(&buffer).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &buffer[PtpHeader::LEN..]);
+            }
+
+            // slice length to small
+            for len in 0..PtpHeader::LEN {
+                assert_eq!(
+                    PtpHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: PtpHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::PtpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes_to_bytes(input in ptp_any()) {
+            assert_eq!(input, PtpHeader::from_bytes(input.to_bytes()));
+        }
+    }
+
+    #[test]
+    fn to_bytes_nibbles() {
+        let header = PtpHeader {
+            transport_specific: 0x1,
+            message_type: PtpMessageType::ANNOUNCE,
+            minor_version: 0x1,
+            version: 0xf2,
+            ..Default::default()
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(0x1B, bytes[0]);
+        assert_eq!(0x12, bytes[1]);
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in ptp_any()) {
+            // ok
+            {
+                let mut buffer = Vec::new();
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, PtpHeader::read(&mut cursor).unwrap());
+            }
+            // read error
+            {
+                let buffer = input.to_bytes();
+                let mut cursor = Cursor::new(&buffer[..PtpHeader::LEN - 1]);
+                assert_eq!(
+                    ErrorKind::UnexpectedEof,
+                    PtpHeader::read(&mut cursor).unwrap_err().kind()
+                );
+            }
+            // write error
+            {
+                let mut buffer = [0u8; PtpHeader::LEN - 1];
+                let mut cursor = Cursor::new(&mut buffer[..]);
+                assert!(input.write(&mut cursor).is_err());
+            }
+        }
+    }
+}
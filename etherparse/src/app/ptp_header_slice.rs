@@ -0,0 +1,261 @@
+use crate::*;
+
+/// Slice containing the common 34 byte header of a PTP (Precision Time
+/// Protocol, IEEE 1588) message.
+///
+/// PTP messages are transported directly via Ethernet (ether type
+/// [`EtherType::PTP`]) or via UDP (ports
+/// [`PtpHeaderSlice::UDP_EVENT_PORT`] & [`PtpHeaderSlice::UDP_GENERAL_PORT`]):
+///
+/// ```
+/// use etherparse::{PtpHeaderSlice, PtpMessageType, SlicedPacket, TransportSlice};
+///
+/// # let mut packet = Vec::new();
+/// # let ptp = etherparse::PtpHeader {
+/// #     message_type: PtpMessageType::SYNC,
+/// #     version: 2,
+/// #     message_length: 44,
+/// #     sequence_id: 7,
+/// #     ..Default::default()
+/// # };
+/// # let mut message = ptp.to_bytes().to_vec();
+/// # message.extend_from_slice(&[0; 10]);
+/// # etherparse::PacketBuilder::ipv4([192, 168, 1, 1], [224, 0, 1, 129], 20)
+/// #     .udp(319, 319)
+/// #     .write(&mut packet, &message)
+/// #     .unwrap();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     if udp.destination_port() == PtpHeaderSlice::UDP_EVENT_PORT {
+///         let ptp = PtpHeaderSlice::from_slice(udp.payload()).unwrap();
+///         assert_eq!(PtpMessageType::SYNC, ptp.message_type());
+///         assert_eq!(7, ptp.sequence_id());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PtpHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> PtpHeaderSlice<'a> {
+    /// UDP port used by PTP "event" messages (see
+    /// [`PtpMessageType::is_event`]).
+    pub const UDP_EVENT_PORT: u16 = 319;
+
+    /// UDP port used by PTP "general" messages.
+    pub const UDP_GENERAL_PORT: u16 = 320;
+
+    /// Try creating a [`PtpHeaderSlice`] from a slice starting with a
+    /// PTP header (the message body after the header is ignored).
+    pub fn from_slice(slice: &'a [u8]) -> Result<PtpHeaderSlice<'a>, err::LenError> {
+        if slice.len() < PtpHeader::LEN {
+            return Err(err::LenError {
+                required_len: PtpHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::PtpHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(PtpHeaderSlice {
+            // SAFETY: Safe as the slice length was verified above to be
+            // at least PtpHeader::LEN (34).
+            slice: unchecked! { get_unchecked_slice(slice, 0, PtpHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the 34 byte PTP header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "transport specific" bits (4 bit, "majorSdoId" in IEEE
+    /// 1588-2019).
+    #[inline]
+    pub fn transport_specific(&self) -> u8 {
+        self.slice[0] >> 4
+    }
+
+    /// Read the "message type".
+    #[inline]
+    pub fn message_type(&self) -> PtpMessageType {
+        PtpMessageType::from_bits(self.slice[0])
+    }
+
+    /// Read the "minor version" of the PTP protocol (4 bit).
+    #[inline]
+    pub fn minor_version(&self) -> u8 {
+        self.slice[1] >> 4
+    }
+
+    /// Read the "version" of the PTP protocol (4 bit).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.slice[1] & 0b1111
+    }
+
+    /// Read the "message length" (length of the complete PTP message
+    /// including the header in bytes).
+    #[inline]
+    pub fn message_length(&self) -> u16 {
+        u16::from_be_bytes([self.slice[2], self.slice[3]])
+    }
+
+    /// Read the "domain number".
+    #[inline]
+    pub fn domain_number(&self) -> u8 {
+        self.slice[4]
+    }
+
+    /// Read the "minorSdoId" (reserved in IEEE 1588-2008).
+    #[inline]
+    pub fn minor_sdo_id(&self) -> u8 {
+        self.slice[5]
+    }
+
+    /// Read the "flags".
+    #[inline]
+    pub fn flags(&self) -> [u8; 2] {
+        [self.slice[6], self.slice[7]]
+    }
+
+    /// Read the "correction field" (nanoseconds multiplied by 2^16).
+    #[inline]
+    pub fn correction_field(&self) -> i64 {
+        i64::from_be_bytes([
+            self.slice[8],
+            self.slice[9],
+            self.slice[10],
+            self.slice[11],
+            self.slice[12],
+            self.slice[13],
+            self.slice[14],
+            self.slice[15],
+        ])
+    }
+
+    /// Read the "message type specific" field.
+    #[inline]
+    pub fn message_type_specific(&self) -> [u8; 4] {
+        [
+            self.slice[16],
+            self.slice[17],
+            self.slice[18],
+            self.slice[19],
+        ]
+    }
+
+    /// Read the "clock identity" of the source port identity.
+    #[inline]
+    pub fn clock_identity(&self) -> [u8; 8] {
+        [
+            self.slice[20],
+            self.slice[21],
+            self.slice[22],
+            self.slice[23],
+            self.slice[24],
+            self.slice[25],
+            self.slice[26],
+            self.slice[27],
+        ]
+    }
+
+    /// Read the "port number" of the source port identity.
+    #[inline]
+    pub fn source_port_number(&self) -> u16 {
+        u16::from_be_bytes([self.slice[28], self.slice[29]])
+    }
+
+    /// Read the "sequence id".
+    #[inline]
+    pub fn sequence_id(&self) -> u16 {
+        u16::from_be_bytes([self.slice[30], self.slice[31]])
+    }
+
+    /// Read the "control field".
+    #[inline]
+    pub fn control_field(&self) -> u8 {
+        self.slice[32]
+    }
+
+    /// Read the "log message interval" (log2 seconds).
+    #[inline]
+    pub fn log_message_interval(&self) -> i8 {
+        self.slice[33] as i8
+    }
+
+    /// Decode all the fields and copy the results to a [`PtpHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> PtpHeader {
+        PtpHeader {
+            transport_specific: self.transport_specific(),
+            message_type: self.message_type(),
+            minor_version: self.minor_version(),
+            version: self.version(),
+            message_length: self.message_length(),
+            domain_number: self.domain_number(),
+            minor_sdo_id: self.minor_sdo_id(),
+            flags: self.flags(),
+            correction_field: self.correction_field(),
+            message_type_specific: self.message_type_specific(),
+            clock_identity: self.clock_identity(),
+            source_port_number: self.source_port_number(),
+            sequence_id: self.sequence_id(),
+            control_field: self.control_field(),
+            log_message_interval: self.log_message_interval(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            header in ptp_any(),
+            body in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(PtpHeader::LEN + body.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&body);
+
+            let slice = PtpHeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(&buffer[..PtpHeader::LEN], slice.slice());
+            assert_eq!(header.transport_specific, slice.transport_specific());
+            assert_eq!(header.message_type, slice.message_type());
+            assert_eq!(header.minor_version, slice.minor_version());
+            assert_eq!(header.version, slice.version());
+            assert_eq!(header.message_length, slice.message_length());
+            assert_eq!(header.domain_number, slice.domain_number());
+            assert_eq!(header.minor_sdo_id, slice.minor_sdo_id());
+            assert_eq!(header.flags, slice.flags());
+            assert_eq!(header.correction_field, slice.correction_field());
+            assert_eq!(header.message_type_specific, slice.message_type_specific());
+            assert_eq!(header.clock_identity, slice.clock_identity());
+            assert_eq!(header.source_port_number, slice.source_port_number());
+            assert_eq!(header.sequence_id, slice.sequence_id());
+            assert_eq!(header.control_field, slice.control_field());
+            assert_eq!(header.log_message_interval, slice.log_message_interval());
+            assert_eq!(header, slice.to_header());
+
+            for len in 0..PtpHeader::LEN {
+                assert_eq!(
+                    PtpHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: PtpHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::PtpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+}
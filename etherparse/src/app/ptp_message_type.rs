@@ -0,0 +1,112 @@
+/// 4 bit "message type" of a PTP (IEEE 1588) message.
+///
+/// ```
+/// use etherparse::PtpMessageType;
+///
+/// assert_eq!(PtpMessageType::SYNC, PtpMessageType::from_bits(0));
+/// assert!(PtpMessageType::SYNC.is_event());
+/// assert!(!PtpMessageType::FOLLOW_UP.is_event());
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PtpMessageType(u8);
+
+impl PtpMessageType {
+    pub const SYNC: PtpMessageType = PtpMessageType(0x0);
+    pub const DELAY_REQ: PtpMessageType = PtpMessageType(0x1);
+    pub const PDELAY_REQ: PtpMessageType = PtpMessageType(0x2);
+    pub const PDELAY_RESP: PtpMessageType = PtpMessageType(0x3);
+    pub const FOLLOW_UP: PtpMessageType = PtpMessageType(0x8);
+    pub const DELAY_RESP: PtpMessageType = PtpMessageType(0x9);
+    pub const PDELAY_RESP_FOLLOW_UP: PtpMessageType = PtpMessageType(0xA);
+    pub const ANNOUNCE: PtpMessageType = PtpMessageType(0xB);
+    pub const SIGNALING: PtpMessageType = PtpMessageType(0xC);
+    pub const MANAGEMENT: PtpMessageType = PtpMessageType(0xD);
+
+    /// Converts the lower 4 bits of the given value to a message type
+    /// (the upper bits are ignored).
+    #[inline]
+    pub const fn from_bits(value: u8) -> PtpMessageType {
+        PtpMessageType(value & 0b1111)
+    }
+
+    /// Returns the 4 bit value of the message type.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if the message is an "event" message (SYNC,
+    /// DELAY_REQ, PDELAY_REQ or PDELAY_RESP) that gets timestamped on
+    /// transmission & reception (sent to UDP port
+    /// [`crate::PtpHeaderSlice::UDP_EVENT_PORT`]).
+    #[inline]
+    pub const fn is_event(self) -> bool {
+        self.0 <= 0x3
+    }
+}
+
+impl core::fmt::Debug for PtpMessageType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SYNC => write!(f, "{:#X} (Sync)", self.0),
+            Self::DELAY_REQ => write!(f, "{:#X} (Delay_Req)", self.0),
+            Self::PDELAY_REQ => write!(f, "{:#X} (Pdelay_Req)", self.0),
+            Self::PDELAY_RESP => write!(f, "{:#X} (Pdelay_Resp)", self.0),
+            Self::FOLLOW_UP => write!(f, "{:#X} (Follow_Up)", self.0),
+            Self::DELAY_RESP => write!(f, "{:#X} (Delay_Resp)", self.0),
+            Self::PDELAY_RESP_FOLLOW_UP => write!(f, "{:#X} (Pdelay_Resp_Follow_Up)", self.0),
+            Self::ANNOUNCE => write!(f, "{:#X} (Announce)", self.0),
+            Self::SIGNALING => write!(f, "{:#X} (Signaling)", self.0),
+            Self::MANAGEMENT => write!(f, "{:#X} (Management)", self.0),
+            _ => write!(f, "{:#X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_bits_bits() {
+        for bits in 0..=0b1111u8 {
+            let value = PtpMessageType::from_bits(bits);
+            assert_eq!(bits, value.bits());
+            assert_eq!(value, PtpMessageType::from_bits(bits | 0b1111_0000));
+        }
+    }
+
+    #[test]
+    fn is_event() {
+        for bits in 0..=0b1111u8 {
+            assert_eq!(bits <= 3, PtpMessageType::from_bits(bits).is_event());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: PtpMessageType = Default::default();
+        assert_eq!(PtpMessageType::SYNC, value);
+        assert_eq!(value, value.clone());
+        let tests = [
+            (PtpMessageType::SYNC, "0x0 (Sync)"),
+            (PtpMessageType::DELAY_REQ, "0x1 (Delay_Req)"),
+            (PtpMessageType::PDELAY_REQ, "0x2 (Pdelay_Req)"),
+            (PtpMessageType::PDELAY_RESP, "0x3 (Pdelay_Resp)"),
+            (PtpMessageType::FOLLOW_UP, "0x8 (Follow_Up)"),
+            (PtpMessageType::DELAY_RESP, "0x9 (Delay_Resp)"),
+            (
+                PtpMessageType::PDELAY_RESP_FOLLOW_UP,
+                "0xA (Pdelay_Resp_Follow_Up)",
+            ),
+            (PtpMessageType::ANNOUNCE, "0xB (Announce)"),
+            (PtpMessageType::SIGNALING, "0xC (Signaling)"),
+            (PtpMessageType::MANAGEMENT, "0xD (Management)"),
+            (PtpMessageType::from_bits(0x4), "0x4"),
+        ];
+        for (value, expected) in tests {
+            assert_eq!(expected, format!("{:?}", value));
+        }
+    }
+}
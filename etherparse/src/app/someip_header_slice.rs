@@ -0,0 +1,474 @@
+use crate::*;
+
+/// Slice containing the 16 byte header of a SOME/IP (Scalable
+/// service-Oriented MiddlewarE over IP) message & the 4 byte SOME/IP-TP
+/// header if the message is a SOME/IP-TP segment.
+///
+/// SOME/IP messages are transported via UDP or TCP (no fixed port) and
+/// multiple messages can be contained in one datagram or stream. The
+/// next message starts after [`SomeipHeaderSlice::message_len`] bytes:
+///
+/// ```
+/// use etherparse::{SomeipHeaderSlice, SomeipMessageType, SomeipReturnCode};
+///
+/// let datagram = [
+///     // notification of service 0x1234, event 0x8001 without payload
+///     0x12, 0x34, 0x80, 0x01, // message id
+///     0x00, 0x00, 0x00, 0x08, // length
+///     0x00, 0x00, 0x00, 0x01, // request id (client id & session id)
+///     0x01, 0x01, 0x02, 0x00, // protocol version, interface version, message type & return code
+///     // response of service 0x1234, method 0x0002 with 2 bytes payload
+///     0x12, 0x34, 0x00, 0x02, // message id
+///     0x00, 0x00, 0x00, 0x0A, // length
+///     0x00, 0x10, 0x00, 0x05, // request id (client id & session id)
+///     0x01, 0x01, 0x80, 0x00, // protocol version, interface version, message type & return code
+///     0xAB, 0xCD, // payload
+/// ];
+///
+/// let mut rest = &datagram[..];
+/// let mut messages = 0;
+/// while false == rest.is_empty() {
+///     let header = SomeipHeaderSlice::from_slice(rest).unwrap();
+///     assert_eq!(0x1234, header.service_id());
+///     assert_eq!(SomeipReturnCode::OK, header.return_code());
+///     if header.message_type() == SomeipMessageType::RESPONSE {
+///         assert_eq!(0x0010, header.client_id());
+///         assert_eq!(0x0005, header.session_id());
+///         assert_eq!(2, header.payload_len());
+///     }
+///     rest = &rest[header.message_len()..];
+///     messages += 1;
+/// }
+/// assert_eq!(2, messages);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SomeipHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> SomeipHeaderSlice<'a> {
+    /// Length of the SOME/IP header in bytes (without the SOME/IP-TP
+    /// header).
+    pub const LEN: usize = 16;
+
+    /// Length of the SOME/IP-TP header in bytes (follows the SOME/IP
+    /// header if [`SomeipMessageType::is_tp`] is true).
+    pub const TP_HEADER_LEN: usize = 4;
+
+    /// Protocol version defined by the SOME/IP specification.
+    pub const PROTOCOL_VERSION: u8 = 1;
+
+    /// Message id used by SOME/IP service discovery (SOME/IP-SD) messages.
+    pub const SD_MESSAGE_ID: u32 = 0xFFFF_8100;
+
+    /// UDP & TCP port used by SOME/IP service discovery (SOME/IP-SD).
+    pub const SD_PORT: u16 = 30490;
+
+    /// Try creating a [`SomeipHeaderSlice`] from a slice starting with a
+    /// SOME/IP header (the payload after the header is ignored).
+    ///
+    /// Only the header itself is validated: the "length" field has to
+    /// cover the rest of the header & the slice has to contain the
+    /// SOME/IP-TP header if the message type indicates a SOME/IP-TP
+    /// segment. The protocol version is not checked. On 16 bit systems a
+    /// [`err::LenError`] is returned if the message length indicated by
+    /// the "length" field does not fit into an `usize`.
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<SomeipHeaderSlice<'a>, err::someip::HeaderSliceError> {
+        use err::someip::{HeaderError::*, HeaderSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::SomeipHeader,
+                layer_start_offset: 0,
+            })
+        };
+
+        if slice.len() < SomeipHeaderSlice::LEN {
+            return Err(len_error(SomeipHeaderSlice::LEN));
+        }
+
+        // SAFETY: Safe as the slice length was verified above to be
+        // at least SomeipHeaderSlice::LEN (16).
+        let (length, message_type) = unchecked! {
+            (
+                get_unchecked_be_u32(slice, 4),
+                SomeipMessageType(get_unchecked_u8(slice, 14)),
+            )
+        };
+
+        let header_len = if message_type.is_tp() {
+            SomeipHeaderSlice::LEN + SomeipHeaderSlice::TP_HEADER_LEN
+        } else {
+            SomeipHeaderSlice::LEN
+        };
+
+        // the length covers everything after the length field
+        if length < (header_len - 8) as u32 {
+            return Err(Content(LengthTooSmall {
+                message_type,
+                length,
+            }));
+        }
+
+        // the message length has to be representable as an usize
+        // (only relevant on 16 & 32 bit systems)
+        if checked_message_len(length, usize::MAX).is_none() {
+            return Err(len_error(usize::MAX));
+        }
+
+        if slice.len() < header_len {
+            return Err(len_error(header_len));
+        }
+
+        Ok(SomeipHeaderSlice {
+            // SAFETY: Safe as the slice length was verified above to be
+            // at least header_len.
+            slice: unchecked! { get_unchecked_slice(slice, 0, header_len) },
+        })
+    }
+
+    /// Returns the slice containing the SOME/IP header (including the
+    /// SOME/IP-TP header if present).
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Length of the header in bytes (16, 20 if the SOME/IP-TP header is
+    /// present).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Read the "message id" (service id & method/event id).
+    #[inline]
+    pub fn message_id(&self) -> u32 {
+        u32::from_be_bytes([self.slice[0], self.slice[1], self.slice[2], self.slice[3]])
+    }
+
+    /// Read the "service id" (upper 16 bits of the message id).
+    #[inline]
+    pub fn service_id(&self) -> u16 {
+        u16::from_be_bytes([self.slice[0], self.slice[1]])
+    }
+
+    /// Read the "method id" (lower 16 bits of the message id, event ids
+    /// have the highest bit set).
+    #[inline]
+    pub fn method_id(&self) -> u16 {
+        u16::from_be_bytes([self.slice[2], self.slice[3]])
+    }
+
+    /// Returns true if the method id is an event id (highest bit set).
+    #[inline]
+    pub fn is_event(&self) -> bool {
+        0 != self.slice[2] & 0x80
+    }
+
+    /// Read the "length" field (length of the message in bytes starting
+    /// with the request id, i.e. 8 bytes of the header + SOME/IP-TP
+    /// header + payload).
+    #[inline]
+    pub fn length(&self) -> u32 {
+        u32::from_be_bytes([self.slice[4], self.slice[5], self.slice[6], self.slice[7]])
+    }
+
+    /// Read the "request id" (client id & session id).
+    #[inline]
+    pub fn request_id(&self) -> u32 {
+        u32::from_be_bytes([self.slice[8], self.slice[9], self.slice[10], self.slice[11]])
+    }
+
+    /// Read the "client id" (upper 16 bits of the request id).
+    #[inline]
+    pub fn client_id(&self) -> u16 {
+        u16::from_be_bytes([self.slice[8], self.slice[9]])
+    }
+
+    /// Read the "session id" (lower 16 bits of the request id).
+    #[inline]
+    pub fn session_id(&self) -> u16 {
+        u16::from_be_bytes([self.slice[10], self.slice[11]])
+    }
+
+    /// Read the "protocol version" (see
+    /// [`SomeipHeaderSlice::PROTOCOL_VERSION`]).
+    #[inline]
+    pub fn protocol_version(&self) -> u8 {
+        self.slice[12]
+    }
+
+    /// Read the "interface version" (major version of the service
+    /// interface).
+    #[inline]
+    pub fn interface_version(&self) -> u8 {
+        self.slice[13]
+    }
+
+    /// Read the "message type".
+    #[inline]
+    pub fn message_type(&self) -> SomeipMessageType {
+        SomeipMessageType(self.slice[14])
+    }
+
+    /// Read the "return code".
+    #[inline]
+    pub fn return_code(&self) -> SomeipReturnCode {
+        SomeipReturnCode(self.slice[15])
+    }
+
+    /// Returns true if the message is a SOME/IP-TP segment (the
+    /// SOME/IP-TP header is present).
+    #[inline]
+    pub fn is_tp(&self) -> bool {
+        self.message_type().is_tp()
+    }
+
+    /// Read the offset of the SOME/IP-TP segment in bytes (always a
+    /// multiple of 16) or `None` if the message is not a SOME/IP-TP segment.
+    #[inline]
+    pub fn tp_offset(&self) -> Option<u32> {
+        self.tp_header_value().map(|value| value & 0xFFFF_FFF0)
+    }
+
+    /// Read the "more segments" flag of the SOME/IP-TP header or `None`
+    /// if the message is not a SOME/IP-TP segment.
+    #[inline]
+    pub fn tp_more_segments(&self) -> Option<bool> {
+        self.tp_header_value().map(|value| 0 != value & 1)
+    }
+
+    /// Length of the complete message in bytes (header + payload), based
+    /// on the "length" field.
+    #[inline]
+    pub fn message_len(&self) -> usize {
+        // "from_slice" verified that the message length fits into an usize
+        checked_message_len(self.length(), usize::MAX).unwrap_or(usize::MAX)
+    }
+
+    /// Length of the payload in bytes (after the SOME/IP & SOME/IP-TP
+    /// header), based on the "length" field.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        // no underflow as the length was verified to cover the header
+        // in "from_slice"
+        self.message_len() - self.header_len()
+    }
+
+    /// Value of the SOME/IP-TP header (offset, reserved bits & more
+    /// segments flag) if present.
+    #[inline]
+    fn tp_header_value(&self) -> Option<u32> {
+        if self.slice.len() < SomeipHeaderSlice::LEN + SomeipHeaderSlice::TP_HEADER_LEN {
+            None
+        } else {
+            Some(u32::from_be_bytes([
+                self.slice[16],
+                self.slice[17],
+                self.slice[18],
+                self.slice[19],
+            ]))
+        }
+    }
+}
+
+/// Returns the length of the complete message (the "length" field plus
+/// the 8 bytes before it) if it does not exceed `max_len` (`usize::MAX`
+/// except in tests simulating systems with a smaller `usize`).
+#[inline]
+fn checked_message_len(length: u32, max_len: usize) -> Option<usize> {
+    let message_len = u64::from(length) + 8;
+    if message_len > max_len as u64 {
+        None
+    } else {
+        Some(message_len as usize)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::someip::{HeaderError::*, HeaderSliceError::*};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            message_id in any::<u32>(),
+            request_id in any::<u32>(),
+            protocol_version in any::<u8>(),
+            interface_version in any::<u8>(),
+            message_type in any::<u8>().prop_map(|v| v & !SomeipMessageType::TP_FLAG),
+            return_code in any::<u8>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(SomeipHeaderSlice::LEN + payload.len());
+            buffer.extend_from_slice(&message_id.to_be_bytes());
+            buffer.extend_from_slice(&(8 + payload.len() as u32).to_be_bytes());
+            buffer.extend_from_slice(&request_id.to_be_bytes());
+            buffer.extend_from_slice(&[protocol_version, interface_version, message_type, return_code]);
+            buffer.extend_from_slice(&payload);
+
+            let slice = SomeipHeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(&buffer[..SomeipHeaderSlice::LEN], slice.slice());
+            assert_eq!(SomeipHeaderSlice::LEN, slice.header_len());
+            assert_eq!(message_id, slice.message_id());
+            assert_eq!((message_id >> 16) as u16, slice.service_id());
+            assert_eq!(message_id as u16, slice.method_id());
+            assert_eq!(0 != message_id & 0x8000, slice.is_event());
+            assert_eq!(8 + payload.len() as u32, slice.length());
+            assert_eq!(request_id, slice.request_id());
+            assert_eq!((request_id >> 16) as u16, slice.client_id());
+            assert_eq!(request_id as u16, slice.session_id());
+            assert_eq!(protocol_version, slice.protocol_version());
+            assert_eq!(interface_version, slice.interface_version());
+            assert_eq!(SomeipMessageType(message_type), slice.message_type());
+            assert_eq!(SomeipReturnCode(return_code), slice.return_code());
+            assert!(false == slice.is_tp());
+            assert_eq!(None, slice.tp_offset());
+            assert_eq!(None, slice.tp_more_segments());
+            assert_eq!(buffer.len(), slice.message_len());
+            assert_eq!(payload.len(), slice.payload_len());
+
+            // length errors
+            for len in 0..SomeipHeaderSlice::LEN {
+                assert_eq!(
+                    SomeipHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    Len(err::LenError {
+                        required_len: SomeipHeaderSlice::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::SomeipHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_tp(
+            message_type in any::<u8>().prop_map(|v| v | SomeipMessageType::TP_FLAG),
+            tp_header in any::<u32>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(20 + payload.len());
+            buffer.extend_from_slice(&[0x12, 0x34, 0x00, 0x01]);
+            buffer.extend_from_slice(&(12 + payload.len() as u32).to_be_bytes());
+            buffer.extend_from_slice(&[0, 1, 0, 2, 1, 1, message_type, 0]);
+            buffer.extend_from_slice(&tp_header.to_be_bytes());
+            buffer.extend_from_slice(&payload);
+
+            let slice = SomeipHeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(&buffer[..20], slice.slice());
+            assert_eq!(20, slice.header_len());
+            assert!(slice.is_tp());
+            assert_eq!(Some(tp_header & 0xFFFF_FFF0), slice.tp_offset());
+            assert_eq!(Some(0 != tp_header & 1), slice.tp_more_segments());
+            assert_eq!(buffer.len(), slice.message_len());
+            assert_eq!(payload.len(), slice.payload_len());
+
+            // missing tp header
+            for len in SomeipHeaderSlice::LEN..20 {
+                assert_eq!(
+                    SomeipHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    Len(err::LenError {
+                        required_len: 20,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::SomeipHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+
+            // length not covering the tp header
+            for length in 0..12u32 {
+                let mut buffer = buffer.clone();
+                buffer[4..8].copy_from_slice(&length.to_be_bytes());
+                assert_eq!(
+                    SomeipHeaderSlice::from_slice(&buffer).unwrap_err(),
+                    Content(LengthTooSmall {
+                        message_type: SomeipMessageType(message_type),
+                        length,
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_length_too_small() {
+        for length in 0..8u32 {
+            let mut buffer = [0u8; 16];
+            buffer[4..8].copy_from_slice(&length.to_be_bytes());
+            assert_eq!(
+                SomeipHeaderSlice::from_slice(&buffer).unwrap_err(),
+                Content(LengthTooSmall {
+                    message_type: SomeipMessageType::REQUEST,
+                    length,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn from_slice_tp_length_too_small() {
+        // lengths not even covering the SOME/IP-TP header
+        for length in 0..SomeipHeaderSlice::TP_HEADER_LEN as u32 {
+            let mut buffer = [0u8; 20];
+            buffer[4..8].copy_from_slice(&length.to_be_bytes());
+            buffer[14] = SomeipMessageType::TP_NOTIFICATION.0;
+            assert_eq!(
+                SomeipHeaderSlice::from_slice(&buffer).unwrap_err(),
+                Content(LengthTooSmall {
+                    message_type: SomeipMessageType::TP_NOTIFICATION,
+                    length,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn checked_message_len() {
+        use super::checked_message_len;
+
+        // 16 bit usize (a plain cast of the length would truncate it)
+        let max_len = u16::MAX as usize;
+        assert_eq!(Some(8), checked_message_len(0, max_len));
+        assert_eq!(
+            Some(max_len),
+            checked_message_len(max_len as u32 - 8, max_len)
+        );
+        assert_eq!(None, checked_message_len(max_len as u32 - 7, max_len));
+        assert_eq!(None, checked_message_len(0x1_0000, max_len));
+
+        // 32 bit usize (adding the 8 bytes would overflow)
+        let max_len = u32::MAX as usize;
+        assert_eq!(Some(max_len), checked_message_len(u32::MAX - 8, max_len));
+        assert_eq!(None, checked_message_len(u32::MAX - 7, max_len));
+        assert_eq!(None, checked_message_len(u32::MAX, max_len));
+
+        // actual usize
+        assert_eq!(Some(8), checked_message_len(0, usize::MAX));
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let buffer = [0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 1, 1, 0, 0];
+        let slice = SomeipHeaderSlice::from_slice(&buffer).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!("SomeipHeaderSlice {{ slice: {:?} }}", &buffer[..]),
+            format!("{:?}", slice)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+/// "Message type" of a SOME/IP message.
+///
+/// Bit `0x20` (see [`SomeipMessageType::TP_FLAG`]) marks messages that
+/// are segmented via SOME/IP-TP (the remaining bits contain the type of
+/// the unsegmented message):
+///
+/// ```
+/// use etherparse::SomeipMessageType;
+///
+/// assert!(SomeipMessageType::TP_NOTIFICATION.is_tp());
+/// assert_eq!(
+///     SomeipMessageType::NOTIFICATION,
+///     SomeipMessageType::TP_NOTIFICATION.without_tp_flag()
+/// );
+/// assert_eq!("0x80 (Response)", format!("{:?}", SomeipMessageType::RESPONSE));
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SomeipMessageType(pub u8);
+
+impl SomeipMessageType {
+    /// Bit set in the message type if the message is a SOME/IP-TP segment.
+    pub const TP_FLAG: u8 = 0x20;
+
+    pub const REQUEST: SomeipMessageType = SomeipMessageType(0x00);
+    pub const REQUEST_NO_RETURN: SomeipMessageType = SomeipMessageType(0x01);
+    pub const NOTIFICATION: SomeipMessageType = SomeipMessageType(0x02);
+    pub const RESPONSE: SomeipMessageType = SomeipMessageType(0x80);
+    pub const ERROR: SomeipMessageType = SomeipMessageType(0x81);
+    pub const TP_REQUEST: SomeipMessageType = SomeipMessageType(0x20);
+    pub const TP_REQUEST_NO_RETURN: SomeipMessageType = SomeipMessageType(0x21);
+    pub const TP_NOTIFICATION: SomeipMessageType = SomeipMessageType(0x22);
+    pub const TP_RESPONSE: SomeipMessageType = SomeipMessageType(0xA0);
+    pub const TP_ERROR: SomeipMessageType = SomeipMessageType(0xA1);
+
+    /// Returns true if the message is a SOME/IP-TP segment (a SOME/IP-TP
+    /// header follows the SOME/IP header).
+    #[inline]
+    pub const fn is_tp(self) -> bool {
+        0 != self.0 & SomeipMessageType::TP_FLAG
+    }
+
+    /// Returns the message type with the SOME/IP-TP flag set.
+    #[inline]
+    pub const fn with_tp_flag(self) -> SomeipMessageType {
+        SomeipMessageType(self.0 | SomeipMessageType::TP_FLAG)
+    }
+
+    /// Returns the message type with the SOME/IP-TP flag cleared.
+    #[inline]
+    pub const fn without_tp_flag(self) -> SomeipMessageType {
+        SomeipMessageType(self.0 & !SomeipMessageType::TP_FLAG)
+    }
+}
+
+impl From<u8> for SomeipMessageType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        SomeipMessageType(value)
+    }
+}
+
+impl From<SomeipMessageType> for u8 {
+    #[inline]
+    fn from(value: SomeipMessageType) -> Self {
+        value.0
+    }
+}
+
+impl core::fmt::Debug for SomeipMessageType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::REQUEST => write!(f, "{:#04X} (Request)", self.0),
+            Self::REQUEST_NO_RETURN => write!(f, "{:#04X} (Request No Return)", self.0),
+            Self::NOTIFICATION => write!(f, "{:#04X} (Notification)", self.0),
+            Self::RESPONSE => write!(f, "{:#04X} (Response)", self.0),
+            Self::ERROR => write!(f, "{:#04X} (Error)", self.0),
+            Self::TP_REQUEST => write!(f, "{:#04X} (TP Request)", self.0),
+            Self::TP_REQUEST_NO_RETURN => write!(f, "{:#04X} (TP Request No Return)", self.0),
+            Self::TP_NOTIFICATION => write!(f, "{:#04X} (TP Notification)", self.0),
+            Self::TP_RESPONSE => write!(f, "{:#04X} (TP Response)", self.0),
+            Self::TP_ERROR => write!(f, "{:#04X} (TP Error)", self.0),
+            _ => write!(f, "{:#04X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_into() {
+        for value in 0..=u8::MAX {
+            assert_eq!(SomeipMessageType(value), SomeipMessageType::from(value));
+            assert_eq!(value, u8::from(SomeipMessageType(value)));
+        }
+    }
+
+    #[test]
+    fn tp_flag() {
+        let tests = [
+            (SomeipMessageType::REQUEST, SomeipMessageType::TP_REQUEST),
+            (
+                SomeipMessageType::REQUEST_NO_RETURN,
+                SomeipMessageType::TP_REQUEST_NO_RETURN,
+            ),
+            (
+                SomeipMessageType::NOTIFICATION,
+                SomeipMessageType::TP_NOTIFICATION,
+            ),
+            (SomeipMessageType::RESPONSE, SomeipMessageType::TP_RESPONSE),
+            (SomeipMessageType::ERROR, SomeipMessageType::TP_ERROR),
+        ];
+        for (plain, tp) in tests {
+            assert!(false == plain.is_tp());
+            assert!(tp.is_tp());
+            assert_eq!(tp, plain.with_tp_flag());
+            assert_eq!(tp, tp.with_tp_flag());
+            assert_eq!(plain, tp.without_tp_flag());
+            assert_eq!(plain, plain.without_tp_flag());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: SomeipMessageType = Default::default();
+        assert_eq!(SomeipMessageType::REQUEST, value);
+        assert_eq!(value, value.clone());
+        let tests = [
+            (SomeipMessageType::REQUEST, "0x00 (Request)"),
+            (
+                SomeipMessageType::REQUEST_NO_RETURN,
+                "0x01 (Request No Return)",
+            ),
+            (SomeipMessageType::NOTIFICATION, "0x02 (Notification)"),
+            (SomeipMessageType::RESPONSE, "0x80 (Response)"),
+            (SomeipMessageType::ERROR, "0x81 (Error)"),
+            (SomeipMessageType::TP_REQUEST, "0x20 (TP Request)"),
+            (
+                SomeipMessageType::TP_REQUEST_NO_RETURN,
+                "0x21 (TP Request No Return)",
+            ),
+            (SomeipMessageType::TP_NOTIFICATION, "0x22 (TP Notification)"),
+            (SomeipMessageType::TP_RESPONSE, "0xA0 (TP Response)"),
+            (SomeipMessageType::TP_ERROR, "0xA1 (TP Error)"),
+            (SomeipMessageType(0x40), "0x40"),
+        ];
+        for (value, expected) in tests {
+            assert_eq!(expected, format!("{:?}", value));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+/// "Return code" of a SOME/IP message.
+///
+/// Codes `0x10` to `0x3F` are reserved for service & method specific
+/// errors.
+///
+/// ```
+/// use etherparse::SomeipReturnCode;
+///
+/// assert_eq!(SomeipReturnCode::OK, SomeipReturnCode(0));
+/// assert_eq!("0x02 (E_UNKNOWN_SERVICE)", format!("{:?}", SomeipReturnCode::UNKNOWN_SERVICE));
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SomeipReturnCode(pub u8);
+
+impl SomeipReturnCode {
+    pub const OK: SomeipReturnCode = SomeipReturnCode(0x00);
+    pub const NOT_OK: SomeipReturnCode = SomeipReturnCode(0x01);
+    pub const UNKNOWN_SERVICE: SomeipReturnCode = SomeipReturnCode(0x02);
+    pub const UNKNOWN_METHOD: SomeipReturnCode = SomeipReturnCode(0x03);
+    pub const NOT_READY: SomeipReturnCode = SomeipReturnCode(0x04);
+    pub const NOT_REACHABLE: SomeipReturnCode = SomeipReturnCode(0x05);
+    pub const TIMEOUT: SomeipReturnCode = SomeipReturnCode(0x06);
+    pub const WRONG_PROTOCOL_VERSION: SomeipReturnCode = SomeipReturnCode(0x07);
+    pub const WRONG_INTERFACE_VERSION: SomeipReturnCode = SomeipReturnCode(0x08);
+    pub const MALFORMED_MESSAGE: SomeipReturnCode = SomeipReturnCode(0x09);
+    pub const WRONG_MESSAGE_TYPE: SomeipReturnCode = SomeipReturnCode(0x0A);
+    pub const E2E_REPEATED: SomeipReturnCode = SomeipReturnCode(0x0B);
+    pub const E2E_WRONG_SEQUENCE: SomeipReturnCode = SomeipReturnCode(0x0C);
+    pub const E2E: SomeipReturnCode = SomeipReturnCode(0x0D);
+    pub const E2E_NOT_AVAILABLE: SomeipReturnCode = SomeipReturnCode(0x0E);
+    pub const E2E_NO_NEW_DATA: SomeipReturnCode = SomeipReturnCode(0x0F);
+}
+
+impl From<u8> for SomeipReturnCode {
+    #[inline]
+    fn from(value: u8) -> Self {
+        SomeipReturnCode(value)
+    }
+}
+
+impl From<SomeipReturnCode> for u8 {
+    #[inline]
+    fn from(value: SomeipReturnCode) -> Self {
+        value.0
+    }
+}
+
+impl core::fmt::Debug for SomeipReturnCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::OK => write!(f, "{:#04X} (E_OK)", self.0),
+            Self::NOT_OK => write!(f, "{:#04X} (E_NOT_OK)", self.0),
+            Self::UNKNOWN_SERVICE => write!(f, "{:#04X} (E_UNKNOWN_SERVICE)", self.0),
+            Self::UNKNOWN_METHOD => write!(f, "{:#04X} (E_UNKNOWN_METHOD)", self.0),
+            Self::NOT_READY => write!(f, "{:#04X} (E_NOT_READY)", self.0),
+            Self::NOT_REACHABLE => write!(f, "{:#04X} (E_NOT_REACHABLE)", self.0),
+            Self::TIMEOUT => write!(f, "{:#04X} (E_TIMEOUT)", self.0),
+            Self::WRONG_PROTOCOL_VERSION => {
+                write!(f, "{:#04X} (E_WRONG_PROTOCOL_VERSION)", self.0)
+            }
+            Self::WRONG_INTERFACE_VERSION => {
+                write!(f, "{:#04X} (E_WRONG_INTERFACE_VERSION)", self.0)
+            }
+            Self::MALFORMED_MESSAGE => write!(f, "{:#04X} (E_MALFORMED_MESSAGE)", self.0),
+            Self::WRONG_MESSAGE_TYPE => write!(f, "{:#04X} (E_WRONG_MESSAGE_TYPE)", self.0),
+            Self::E2E_REPEATED => write!(f, "{:#04X} (E_E2E_REPEATED)", self.0),
+            Self::E2E_WRONG_SEQUENCE => write!(f, "{:#04X} (E_E2E_WRONG_SEQUENCE)", self.0),
+            Self::E2E => write!(f, "{:#04X} (E_E2E)", self.0),
+            Self::E2E_NOT_AVAILABLE => write!(f, "{:#04X} (E_E2E_NOT_AVAILABLE)", self.0),
+            Self::E2E_NO_NEW_DATA => write!(f, "{:#04X} (E_E2E_NO_NEW_DATA)", self.0),
+            _ => write!(f, "{:#04X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_into() {
+        for value in 0..=u8::MAX {
+            assert_eq!(SomeipReturnCode(value), SomeipReturnCode::from(value));
+            assert_eq!(value, u8::from(SomeipReturnCode(value)));
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: SomeipReturnCode = Default::default();
+        assert_eq!(SomeipReturnCode::OK, value);
+        assert_eq!(value, value.clone());
+        let tests = [
+            (SomeipReturnCode::OK, "0x00 (E_OK)"),
+            (SomeipReturnCode::NOT_OK, "0x01 (E_NOT_OK)"),
+            (
+                SomeipReturnCode::UNKNOWN_SERVICE,
+                "0x02 (E_UNKNOWN_SERVICE)",
+            ),
+            (SomeipReturnCode::UNKNOWN_METHOD, "0x03 (E_UNKNOWN_METHOD)"),
+            (SomeipReturnCode::NOT_READY, "0x04 (E_NOT_READY)"),
+            (SomeipReturnCode::NOT_REACHABLE, "0x05 (E_NOT_REACHABLE)"),
+            (SomeipReturnCode::TIMEOUT, "0x06 (E_TIMEOUT)"),
+            (
+                SomeipReturnCode::WRONG_PROTOCOL_VERSION,
+                "0x07 (E_WRONG_PROTOCOL_VERSION)",
+            ),
+            (
+                SomeipReturnCode::WRONG_INTERFACE_VERSION,
+                "0x08 (E_WRONG_INTERFACE_VERSION)",
+            ),
+            (
+                SomeipReturnCode::MALFORMED_MESSAGE,
+                "0x09 (E_MALFORMED_MESSAGE)",
+            ),
+            (
+                SomeipReturnCode::WRONG_MESSAGE_TYPE,
+                "0x0A (E_WRONG_MESSAGE_TYPE)",
+            ),
+            (SomeipReturnCode::E2E_REPEATED, "0x0B (E_E2E_REPEATED)"),
+            (
+                SomeipReturnCode::E2E_WRONG_SEQUENCE,
+                "0x0C (E_E2E_WRONG_SEQUENCE)",
+            ),
+            (SomeipReturnCode::E2E, "0x0D (E_E2E)"),
+            (
+                SomeipReturnCode::E2E_NOT_AVAILABLE,
+                "0x0E (E_E2E_NOT_AVAILABLE)",
+            ),
+            (
+                SomeipReturnCode::E2E_NO_NEW_DATA,
+                "0x0F (E_E2E_NO_NEW_DATA)",
+            ),
+            (SomeipReturnCode(0x10), "0x10"),
+        ];
+        for (value, expected) in tests {
+            assert_eq!(expected, format!("{:?}", value));
+        }
+    }
+}
@@ -0,0 +1,93 @@
+/// Type of a WireGuard message (first byte of every WireGuard message).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum WireguardMessageType {
+    /// First message of the handshake sent by the initiator (`1`).
+    HandshakeInitiation = 1,
+    /// Second message of the handshake sent by the responder (`2`).
+    HandshakeResponse = 2,
+    /// Cookie reply sent by a peer under load instead of a handshake
+    /// response (`3`).
+    CookieReply = 3,
+    /// Encrypted transport data (`4`).
+    TransportData = 4,
+}
+
+impl WireguardMessageType {
+    /// Length of a handshake initiation message in bytes.
+    pub const HANDSHAKE_INITIATION_LEN: usize = 148;
+
+    /// Length of a handshake response message in bytes.
+    pub const HANDSHAKE_RESPONSE_LEN: usize = 92;
+
+    /// Length of a cookie reply message in bytes.
+    pub const COOKIE_REPLY_LEN: usize = 64;
+
+    /// Minimum length of a transport data message in bytes (header &
+    /// the 16 byte authentication tag of an empty keepalive message).
+    pub const TRANSPORT_DATA_MIN_LEN: usize = 32;
+
+    /// Converts the message type byte to a [`WireguardMessageType`]
+    /// (returns `None` if the value is not a known message type).
+    #[inline]
+    pub const fn from_u8(value: u8) -> Option<WireguardMessageType> {
+        use WireguardMessageType::*;
+        match value {
+            1 => Some(HandshakeInitiation),
+            2 => Some(HandshakeResponse),
+            3 => Some(CookieReply),
+            4 => Some(TransportData),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the message type byte.
+    #[inline]
+    pub const fn value(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the minimum length of a message of this type (for all
+    /// types except [`WireguardMessageType::TransportData`] this is
+    /// also the exact length of the message).
+    #[inline]
+    pub const fn min_len(self) -> usize {
+        use WireguardMessageType::*;
+        match self {
+            HandshakeInitiation => Self::HANDSHAKE_INITIATION_LEN,
+            HandshakeResponse => Self::HANDSHAKE_RESPONSE_LEN,
+            CookieReply => Self::COOKIE_REPLY_LEN,
+            TransportData => Self::TRANSPORT_DATA_MIN_LEN,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_u8_value_min_len() {
+        use WireguardMessageType::*;
+        for (value, expected, min_len) in [
+            (1, HandshakeInitiation, 148),
+            (2, HandshakeResponse, 92),
+            (3, CookieReply, 64),
+            (4, TransportData, 32),
+        ] {
+            assert_eq!(Some(expected), WireguardMessageType::from_u8(value));
+            assert_eq!(value, expected.value());
+            assert_eq!(min_len, expected.min_len());
+        }
+        for value in [0, 5, 0xff] {
+            assert_eq!(None, WireguardMessageType::from_u8(value));
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = WireguardMessageType::CookieReply;
+        assert_eq!(value, value.clone());
+        assert_eq!("CookieReply", format!("{:?}", value));
+    }
+}
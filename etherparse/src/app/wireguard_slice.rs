@@ -0,0 +1,373 @@
+use crate::{err::wireguard::*, *};
+
+/// Slice containing a WireGuard message.
+///
+/// Only the cleartext fields of the messages are decoded (message type,
+/// sender & receiver index and the counter of transport data messages).
+/// These are sufficient to track the WireGuard "sessions" in a flow
+/// without access to the keys:
+///
+/// ```
+/// use etherparse::{services, SlicedPacket, TransportSlice, WireguardMessageType, WireguardSlice};
+///
+/// # let mut message = vec![4, 0, 0, 0];
+/// # message.extend_from_slice(&0x1234_5678u32.to_le_bytes());
+/// # message.extend_from_slice(&7u64.to_le_bytes());
+/// # message.extend_from_slice(&[0; 16]);
+/// # let mut packet = Vec::new();
+/// # etherparse::PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #     .udp(services::WIREGUARD, services::WIREGUARD)
+/// #     .write(&mut packet, &message)
+/// #     .unwrap();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     if udp.is_probably_wireguard() {
+///         let wg = WireguardSlice::from_slice(udp.payload()).unwrap();
+///         assert_eq!(WireguardMessageType::TransportData, wg.message_type());
+///         assert_eq!(Some(0x1234_5678), wg.receiver_index());
+///         assert_eq!(Some(7), wg.counter());
+///     }
+/// }
+/// ```
+///
+/// Note that WireGuard messages have no magic number. A message that
+/// passes [`WireguardSlice::from_slice`] is only probably a WireGuard
+/// message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WireguardSlice<'a> {
+    slice: &'a [u8],
+    message_type: WireguardMessageType,
+}
+
+impl<'a> WireguardSlice<'a> {
+    /// Length of the message type & reserved bytes at the start of
+    /// every WireGuard message.
+    pub const TYPE_LEN: usize = 4;
+
+    /// Try creating a [`WireguardSlice`] from a slice containing a
+    /// WireGuard message (e.g. the payload of an UDP packet).
+    ///
+    /// Besides the length of the message the message type & the
+    /// reserved bytes are verified and fixed size messages are required
+    /// to have exactly the size defined for their type.
+    pub fn from_slice(slice: &'a [u8]) -> Result<WireguardSlice<'a>, HeaderSliceError> {
+        use HeaderSliceError::*;
+
+        if slice.len() < Self::TYPE_LEN {
+            return Err(Len(err::LenError {
+                required_len: Self::TYPE_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::WireguardHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let Some(message_type) = WireguardMessageType::from_u8(slice[0]) else {
+            return Err(Content(HeaderError::UnknownMessageType {
+                message_type: slice[0],
+            }));
+        };
+
+        let reserved = [slice[1], slice[2], slice[3]];
+        if reserved != [0, 0, 0] {
+            return Err(Content(HeaderError::NonZeroReserved { reserved }));
+        }
+
+        if slice.len() < message_type.min_len() {
+            return Err(Len(err::LenError {
+                required_len: message_type.min_len(),
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::WireguardHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        if message_type != WireguardMessageType::TransportData
+            && slice.len() != message_type.min_len()
+        {
+            return Err(Content(HeaderError::UnexpectedLen {
+                message_type,
+                len: slice.len(),
+            }));
+        }
+
+        Ok(WireguardSlice {
+            slice,
+            message_type,
+        })
+    }
+
+    /// Returns the slice containing the complete WireGuard message.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the type of the message.
+    #[inline]
+    pub fn message_type(&self) -> WireguardMessageType {
+        self.message_type
+    }
+
+    /// Returns the index the sender chose for the session (only present
+    /// in handshake initiation & handshake response messages).
+    #[inline]
+    pub fn sender_index(&self) -> Option<u32> {
+        use WireguardMessageType::*;
+        match self.message_type {
+            HandshakeInitiation | HandshakeResponse => Some(self.read_le_u32(4)),
+            CookieReply | TransportData => None,
+        }
+    }
+
+    /// Returns the index the receiver chose for the session (present in
+    /// all messages except handshake initiation messages).
+    #[inline]
+    pub fn receiver_index(&self) -> Option<u32> {
+        use WireguardMessageType::*;
+        match self.message_type {
+            HandshakeInitiation => None,
+            HandshakeResponse => Some(self.read_le_u32(8)),
+            CookieReply | TransportData => Some(self.read_le_u32(4)),
+        }
+    }
+
+    /// Returns the counter (nonce) of a transport data message (`None`
+    /// for all other message types).
+    #[inline]
+    pub fn counter(&self) -> Option<u64> {
+        if WireguardMessageType::TransportData == self.message_type {
+            // SAFETY: Safe as the constructor checks that transport data
+            // messages have at least a length of
+            // WireguardMessageType::TRANSPORT_DATA_MIN_LEN (32).
+            Some(u64::from_le_bytes(unchecked! {
+                [
+                    get_unchecked_u8(self.slice, 8),
+                    get_unchecked_u8(self.slice, 9),
+                    get_unchecked_u8(self.slice, 10),
+                    get_unchecked_u8(self.slice, 11),
+                    get_unchecked_u8(self.slice, 12),
+                    get_unchecked_u8(self.slice, 13),
+                    get_unchecked_u8(self.slice, 14),
+                    get_unchecked_u8(self.slice, 15),
+                ]
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the encrypted packet (including the 16 byte authentication
+    /// tag) of a transport data message (`None` for all other message
+    /// types).
+    #[inline]
+    pub fn encrypted_packet(&self) -> Option<&'a [u8]> {
+        if WireguardMessageType::TransportData == self.message_type {
+            // SAFETY: Safe as the constructor checks that transport data
+            // messages have at least a length of
+            // WireguardMessageType::TRANSPORT_DATA_MIN_LEN (32).
+            Some(unchecked! {
+                get_unchecked_slice(self.slice, 16, self.slice.len() - 16)
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the message is a keepalive message (transport
+    /// data message without an encrypted packet).
+    #[inline]
+    pub fn is_keepalive(&self) -> bool {
+        WireguardMessageType::TransportData == self.message_type
+            && WireguardMessageType::TRANSPORT_DATA_MIN_LEN == self.slice.len()
+    }
+
+    /// Reads a little endian u32 at the given offset.
+    #[inline]
+    fn read_le_u32(&self, offset: usize) -> u32 {
+        // SAFETY: Safe as the constructor checks that the slice has at
+        // least the minimum length of the message type (at least 32 bytes)
+        // and only offsets up to 8 are read.
+        unchecked! {
+            u32::from_le_bytes([
+                get_unchecked_u8(self.slice, offset),
+                get_unchecked_u8(self.slice, offset + 1),
+                get_unchecked_u8(self.slice, offset + 2),
+                get_unchecked_u8(self.slice, offset + 3),
+            ])
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    /// Creates a message with the given type & length where the
+    /// bytes after the reserved bytes contain their offset.
+    fn message(message_type: u8, len: usize) -> Vec<u8> {
+        let mut result: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        result[0] = message_type;
+        result[1] = 0;
+        result[2] = 0;
+        result[3] = 0;
+        result
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            sender_index in any::<u32>(),
+            receiver_index in any::<u32>(),
+            counter in any::<u64>(),
+            packet in proptest::collection::vec(any::<u8>(), 16..64)
+        ) {
+            use WireguardMessageType::*;
+
+            // handshake initiation
+            {
+                let mut data = message(1, 148);
+                data[4..8].copy_from_slice(&sender_index.to_le_bytes());
+                let actual = WireguardSlice::from_slice(&data).unwrap();
+                assert_eq!(&data[..], actual.slice());
+                assert_eq!(HandshakeInitiation, actual.message_type());
+                assert_eq!(Some(sender_index), actual.sender_index());
+                assert_eq!(None, actual.receiver_index());
+                assert_eq!(None, actual.counter());
+                assert_eq!(None, actual.encrypted_packet());
+                assert!(false == actual.is_keepalive());
+            }
+
+            // handshake response
+            {
+                let mut data = message(2, 92);
+                data[4..8].copy_from_slice(&sender_index.to_le_bytes());
+                data[8..12].copy_from_slice(&receiver_index.to_le_bytes());
+                let actual = WireguardSlice::from_slice(&data).unwrap();
+                assert_eq!(HandshakeResponse, actual.message_type());
+                assert_eq!(Some(sender_index), actual.sender_index());
+                assert_eq!(Some(receiver_index), actual.receiver_index());
+                assert_eq!(None, actual.counter());
+                assert_eq!(None, actual.encrypted_packet());
+                assert!(false == actual.is_keepalive());
+            }
+
+            // cookie reply
+            {
+                let mut data = message(3, 64);
+                data[4..8].copy_from_slice(&receiver_index.to_le_bytes());
+                let actual = WireguardSlice::from_slice(&data).unwrap();
+                assert_eq!(CookieReply, actual.message_type());
+                assert_eq!(None, actual.sender_index());
+                assert_eq!(Some(receiver_index), actual.receiver_index());
+                assert_eq!(None, actual.counter());
+                assert_eq!(None, actual.encrypted_packet());
+                assert!(false == actual.is_keepalive());
+            }
+
+            // transport data
+            {
+                let mut data = message(4, 16);
+                data[4..8].copy_from_slice(&receiver_index.to_le_bytes());
+                data[8..16].copy_from_slice(&counter.to_le_bytes());
+                data.extend_from_slice(&packet);
+                let actual = WireguardSlice::from_slice(&data).unwrap();
+                assert_eq!(TransportData, actual.message_type());
+                assert_eq!(None, actual.sender_index());
+                assert_eq!(Some(receiver_index), actual.receiver_index());
+                assert_eq!(Some(counter), actual.counter());
+                assert_eq!(Some(&packet[..]), actual.encrypted_packet());
+                assert_eq!(packet.len() == 16, actual.is_keepalive());
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_errors() {
+        use err::{Layer, LenError};
+        use HeaderSliceError::*;
+
+        // too short for the type
+        for len in 0..4 {
+            assert_eq!(
+                WireguardSlice::from_slice(&message(4, 32)[..len]),
+                Err(Len(LenError {
+                    required_len: 4,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::WireguardHeader,
+                    layer_start_offset: 0,
+                }))
+            );
+        }
+
+        // unknown message type
+        for message_type in [0, 5, 0xff] {
+            assert_eq!(
+                WireguardSlice::from_slice(&message(message_type, 32)),
+                Err(Content(HeaderError::UnknownMessageType { message_type }))
+            );
+        }
+
+        // non zero reserved bytes
+        for i in 1..4 {
+            let mut data = message(4, 32);
+            data[i] = 1;
+            let mut reserved = [0; 3];
+            reserved[i - 1] = 1;
+            assert_eq!(
+                WireguardSlice::from_slice(&data),
+                Err(Content(HeaderError::NonZeroReserved { reserved }))
+            );
+        }
+
+        for (message_type, len) in [(1u8, 148), (2, 92), (3, 64), (4, 32)] {
+            let expected_type = WireguardMessageType::from_u8(message_type).unwrap();
+
+            // too short
+            assert_eq!(
+                WireguardSlice::from_slice(&message(message_type, len - 1)),
+                Err(Len(LenError {
+                    required_len: len,
+                    len: len - 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::WireguardHeader,
+                    layer_start_offset: 0,
+                }))
+            );
+
+            // too long (only allowed for transport data)
+            let data = message(message_type, len + 1);
+            let result = WireguardSlice::from_slice(&data);
+            if expected_type == WireguardMessageType::TransportData {
+                assert!(result.is_ok());
+            } else {
+                assert_eq!(
+                    result,
+                    Err(Content(HeaderError::UnexpectedLen {
+                        message_type: expected_type,
+                        len: len + 1
+                    }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = message(4, 32);
+        let slice = WireguardSlice::from_slice(&data).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!(
+                "WireguardSlice {{ slice: {:?}, message_type: TransportData }}",
+                &data[..]
+            ),
+            format!("{:?}", slice)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::err::{ValueTooBigError, ValueType};
+use arrayvec::ArrayVec;
+
+/// Owned payload with a fixed maximum capacity of `N` bytes that does
+/// not require an allocator.
+///
+/// Can be used to own small variable length data (e.g. the data of
+/// an ICMP echo request) in `no_std` environments without `alloc`,
+/// similar to how [`crate::Ipv4Options`] & [`crate::TcpOptions`] store
+/// their data.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{BoundedPayload, Icmpv4Slice, Icmpv4Type, IcmpEchoHeader};
+///
+/// # let mut packet = Vec::new();
+/// # etherparse::Icmpv4Header::with_checksum(
+/// #     Icmpv4Type::EchoRequest(IcmpEchoHeader{ id: 1, seq: 2 }),
+/// #     &[1, 2, 3, 4],
+/// # ).write(&mut packet).unwrap();
+/// # packet.extend_from_slice(&[1, 2, 3, 4]);
+/// let icmp = Icmpv4Slice::from_slice(&packet).unwrap();
+///
+/// // payloads that fit into the capacity can be copied
+/// let owned = BoundedPayload::<64>::try_from(icmp.payload()).unwrap();
+/// assert_eq!(&owned[..], &[1, 2, 3, 4]);
+///
+/// // bigger payloads cause an error or can be truncated
+/// assert!(BoundedPayload::<2>::try_from(icmp.payload()).is_err());
+/// let truncated = BoundedPayload::<2>::from_slice_truncated(icmp.payload());
+/// assert_eq!(&truncated[..], &[1, 2]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct BoundedPayload<const N: usize>(ArrayVec<u8, N>);
+
+impl<const N: usize> BoundedPayload<N> {
+    /// Maximum length of the payload in bytes.
+    pub const CAPACITY: usize = N;
+
+    /// Creates an empty payload.
+    #[inline]
+    pub const fn new() -> BoundedPayload<N> {
+        BoundedPayload(ArrayVec::new_const())
+    }
+
+    /// Copies the first `N` bytes of the given slice (or the full slice
+    /// if it is smaller than `N`).
+    pub fn from_slice_truncated(slice: &[u8]) -> BoundedPayload<N> {
+        let mut result = ArrayVec::new_const();
+        // never fails as the slice is limited to the capacity
+        let _ = result.try_extend_from_slice(&slice[..slice.len().min(N)]);
+        BoundedPayload(result)
+    }
+
+    /// Returns the payload as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Returns the payload as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.0.as_mut_slice()
+    }
+
+    /// Returns the length of the payload in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the payload is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Appends the given slice to the payload.
+    ///
+    /// Returns an error (and leaves the payload unchanged) if the
+    /// resulting payload would exceed the capacity.
+    pub fn try_extend_from_slice(&mut self, slice: &[u8]) -> Result<(), ValueTooBigError<usize>> {
+        self.0
+            .try_extend_from_slice(slice)
+            .map_err(|_| ValueTooBigError {
+                actual: self.0.len() + slice.len(),
+                max_allowed: N,
+                value_type: ValueType::BoundedPayloadLength,
+            })
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for BoundedPayload<N> {
+    type Error = ValueTooBigError<usize>;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let mut result = BoundedPayload::new();
+        result.try_extend_from_slice(value)?;
+        Ok(result)
+    }
+}
+
+impl<const N: usize> core::ops::Deref for BoundedPayload<N> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> core::ops::DerefMut for BoundedPayload<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for BoundedPayload<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn new_default() {
+        assert!(BoundedPayload::<4>::new().is_empty());
+        assert_eq!(BoundedPayload::<4>::new(), BoundedPayload::<4>::default());
+        assert_eq!(4, BoundedPayload::<4>::CAPACITY);
+    }
+
+    proptest! {
+        #[test]
+        fn try_from_truncated(data in proptest::collection::vec(any::<u8>(), 0..16)) {
+            // try_from
+            {
+                let actual = BoundedPayload::<8>::try_from(&data[..]);
+                if data.len() <= 8 {
+                    let actual = actual.unwrap();
+                    assert_eq!(&data[..], actual.as_slice());
+                    assert_eq!(data.len(), actual.len());
+                } else {
+                    assert_eq!(
+                        actual,
+                        Err(ValueTooBigError {
+                            actual: data.len(),
+                            max_allowed: 8,
+                            value_type: ValueType::BoundedPayloadLength,
+                        })
+                    );
+                }
+            }
+            // from_slice_truncated
+            {
+                let actual = BoundedPayload::<8>::from_slice_truncated(&data);
+                assert_eq!(&data[..data.len().min(8)], &actual[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn try_extend_from_slice() {
+        let mut payload = BoundedPayload::<4>::new();
+        payload.try_extend_from_slice(&[1, 2]).unwrap();
+        payload.try_extend_from_slice(&[3]).unwrap();
+        assert_eq!(
+            payload.try_extend_from_slice(&[4, 5]),
+            Err(ValueTooBigError {
+                actual: 5,
+                max_allowed: 4,
+                value_type: ValueType::BoundedPayloadLength,
+            })
+        );
+        // unchanged on error
+        assert_eq!(&[1, 2, 3], payload.as_ref());
+    }
+
+    #[test]
+    fn deref_mut() {
+        let mut payload = BoundedPayload::<4>::try_from(&[1, 2][..]).unwrap();
+        payload[0] = 3;
+        payload.as_mut_slice()[1] = 4;
+        assert_eq!(&[3, 4], &payload[..]);
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let payload = BoundedPayload::<4>::try_from(&[1, 2][..]).unwrap();
+        assert_eq!(payload, payload.clone());
+        assert_eq!("BoundedPayload([1, 2])", format!("{:?}", payload));
+    }
+}
@@ -0,0 +1,252 @@
+/// Classification of a destination address into unicast, multicast or
+/// broadcast (see [`crate::SlicedPacket::cast_type`]).
+///
+/// ```
+/// use etherparse::CastType;
+///
+/// assert_eq!(CastType::Broadcast, CastType::from_mac_addr(&[0xff; 6]));
+/// assert_eq!(CastType::Multicast, CastType::from_ipv4_addr(&[224, 0, 0, 1]));
+/// assert_eq!(CastType::Unicast, CastType::from_ipv6_addr(&[0x20, 1, 0xd, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CastType {
+    /// Address of a single destination.
+    Unicast,
+    /// Address of a group of destinations.
+    Multicast,
+    /// Address of all destinations in the local network.
+    Broadcast,
+}
+
+impl CastType {
+    /// Classifies a MAC address (e.g. the destination of an Ethernet II
+    /// header).
+    ///
+    /// `ff:ff:ff:ff:ff:ff` is classified as broadcast and all other
+    /// addresses with the individual/group bit set as multicast.
+    pub const fn from_mac_addr(addr: &[u8; 6]) -> CastType {
+        if matches!(addr, [0xff, 0xff, 0xff, 0xff, 0xff, 0xff]) {
+            CastType::Broadcast
+        } else if 0 != addr[0] & 0b0000_0001 {
+            CastType::Multicast
+        } else {
+            CastType::Unicast
+        }
+    }
+
+    /// Classifies an IPv4 address (e.g. the destination of an IPv4 header).
+    ///
+    /// Only the limited broadcast address `255.255.255.255` is classified
+    /// as broadcast (directed broadcast addresses can not be recognized
+    /// without knowing the network prefix). Addresses in `224.0.0.0/4`
+    /// are classified as multicast.
+    pub const fn from_ipv4_addr(addr: &[u8; 4]) -> CastType {
+        if matches!(addr, [0xff, 0xff, 0xff, 0xff]) {
+            CastType::Broadcast
+        } else if 0xe0 == addr[0] & 0xf0 {
+            CastType::Multicast
+        } else {
+            CastType::Unicast
+        }
+    }
+
+    /// Classifies an IPv6 address (e.g. the destination of an IPv6 header).
+    ///
+    /// IPv6 has no broadcast addresses, addresses in `ff00::/8` are
+    /// classified as multicast.
+    pub const fn from_ipv6_addr(addr: &[u8; 16]) -> CastType {
+        if crate::ipv6_addr::is_multicast(addr) {
+            CastType::Multicast
+        } else {
+            CastType::Unicast
+        }
+    }
+
+    /// Returns true if the value is [`CastType::Unicast`].
+    #[inline]
+    pub const fn is_unicast(&self) -> bool {
+        matches!(self, CastType::Unicast)
+    }
+
+    /// Returns true if the value is [`CastType::Multicast`].
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        matches!(self, CastType::Multicast)
+    }
+
+    /// Returns true if the value is [`CastType::Broadcast`].
+    #[inline]
+    pub const fn is_broadcast(&self) -> bool {
+        matches!(self, CastType::Broadcast)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CastType::*;
+    use super::*;
+    use crate::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_ipv4_addr(addr in any::<[u8;4]>()) {
+            let std_addr = std::net::Ipv4Addr::from(addr);
+            let expected = if std_addr.is_broadcast() {
+                Broadcast
+            } else if std_addr.is_multicast() {
+                Multicast
+            } else {
+                Unicast
+            };
+            assert_eq!(expected, CastType::from_ipv4_addr(&addr));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_ipv6_addr(addr in any::<[u8;16]>()) {
+            let expected = if std::net::Ipv6Addr::from(addr).is_multicast() {
+                Multicast
+            } else {
+                Unicast
+            };
+            assert_eq!(expected, CastType::from_ipv6_addr(&addr));
+        }
+    }
+
+    #[test]
+    fn fixed_values() {
+        // mac: broadcast & individual/group bit
+        for (expected, addr) in [
+            (Broadcast, [0xff; 6]),
+            (Multicast, [0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]),
+            (Multicast, [0x01, 0x00, 0x5e, 0, 0, 1]),
+            (Multicast, [0x33, 0x33, 0, 0, 0, 1]),
+            (Unicast, [0x00, 0x1b, 0x21, 0, 0, 1]),
+            (Unicast, [0x02, 0, 0, 0, 0, 1]),
+            (Unicast, [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        ] {
+            assert_eq!(expected, CastType::from_mac_addr(&addr));
+        }
+
+        // ipv4: limited broadcast & 224.0.0.0/4
+        for (expected, addr) in [
+            (Broadcast, [255, 255, 255, 255]),
+            (Unicast, [255, 255, 255, 254]),
+            (Unicast, [223, 255, 255, 255]),
+            (Multicast, [224, 0, 0, 0]),
+            (Multicast, [239, 255, 255, 255]),
+            (Unicast, [240, 0, 0, 0]),
+            (Unicast, [192, 168, 1, 255]),
+        ] {
+            assert_eq!(expected, CastType::from_ipv4_addr(&addr));
+        }
+
+        // ipv6: ff00::/8
+        for (expected, first) in [
+            (Multicast, 0xff),
+            (Unicast, 0xfe),
+            (Unicast, 0x20),
+            (Unicast, 0x00),
+        ] {
+            let mut addr = [0xff; 16];
+            addr[0] = first;
+            assert_eq!(expected, CastType::from_ipv6_addr(&addr));
+        }
+    }
+
+    #[test]
+    fn is_unicast_multicast_broadcast() {
+        for (value, unicast, multicast, broadcast) in [
+            (Unicast, true, false, false),
+            (Multicast, false, true, false),
+            (Broadcast, false, false, true),
+        ] {
+            assert_eq!(unicast, value.is_unicast());
+            assert_eq!(multicast, value.is_multicast());
+            assert_eq!(broadcast, value.is_broadcast());
+        }
+    }
+
+    #[test]
+    fn destination_cast_type() {
+        // ethernet ii
+        {
+            let header = Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [0x01, 0x00, 0x5e, 0, 0, 1],
+                ether_type: EtherType::IPV4,
+            };
+            let bytes = header.to_bytes();
+            assert_eq!(Multicast, header.destination_cast_type());
+            assert_eq!(
+                Multicast,
+                Ethernet2HeaderSlice::from_slice(&bytes)
+                    .unwrap()
+                    .destination_cast_type()
+            );
+            assert_eq!(
+                Multicast,
+                Ethernet2Slice::from_slice_without_fcs(&bytes)
+                    .unwrap()
+                    .destination_cast_type()
+            );
+        }
+
+        // ipv4
+        {
+            let header = Ipv4Header {
+                destination: [255, 255, 255, 255],
+                ..Default::default()
+            };
+            let bytes = header.to_bytes();
+            assert_eq!(Broadcast, header.destination_cast_type());
+            assert_eq!(
+                Broadcast,
+                Ipv4HeaderSlice::from_slice(&bytes)
+                    .unwrap()
+                    .destination_cast_type()
+            );
+        }
+
+        // ipv6
+        {
+            let header = Ipv6Header {
+                destination: [0x20, 1, 0xd, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                ..Default::default()
+            };
+            let bytes = header.to_bytes();
+            assert_eq!(Unicast, header.destination_cast_type());
+            assert_eq!(
+                Unicast,
+                Ipv6HeaderSlice::from_slice(&bytes)
+                    .unwrap()
+                    .destination_cast_type()
+            );
+        }
+    }
+
+    #[test]
+    fn clone_eq_hash_ord_debug() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let value = Multicast;
+        assert_eq!(value, value.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            value.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+        assert!(Unicast < Broadcast);
+        assert_eq!("Multicast", format!("{:?}", value));
+    }
+}
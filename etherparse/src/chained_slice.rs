@@ -0,0 +1,198 @@
+/// Bytes scattered over two slices (e.g. a header & a payload ring buffer
+/// of a capture backend) that are treated as one continuous sequence.
+///
+/// ```
+/// use etherparse::ChainedSlice;
+///
+/// let chained = ChainedSlice::new(&[1, 2, 3], &[4, 5]);
+/// assert_eq!(5, chained.len());
+/// assert_eq!(Some(4), chained.get(3));
+/// assert_eq!(vec![1, 2, 3, 4, 5], chained.iter().collect::<Vec<_>>());
+///
+/// let (a, b) = chained.split_at(2).unwrap();
+/// assert_eq!(Some(&[1, 2][..]), a.as_contiguous());
+/// assert_eq!(vec![3, 4, 5], b.to_vec());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct ChainedSlice<'a> {
+    first: &'a [u8],
+    second: &'a [u8],
+}
+
+impl<'a> ChainedSlice<'a> {
+    /// Creates a chained slice with the bytes of `first` followed by
+    /// the bytes of `second`.
+    #[inline]
+    pub const fn new(first: &'a [u8], second: &'a [u8]) -> ChainedSlice<'a> {
+        ChainedSlice { first, second }
+    }
+
+    /// First part of the bytes.
+    #[inline]
+    pub const fn first(&self) -> &'a [u8] {
+        self.first
+    }
+
+    /// Second part of the bytes (following the bytes in [`ChainedSlice::first`]).
+    #[inline]
+    pub const fn second(&self) -> &'a [u8] {
+        self.second
+    }
+
+    /// Total number of bytes in both slices.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+
+    /// Returns true if both slices are empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.first.is_empty() && self.second.is_empty()
+    }
+
+    /// Returns the byte at the given index (`None` if the index is out
+    /// of bounds).
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<u8> {
+        if index < self.first.len() {
+            Some(self.first[index])
+        } else {
+            self.second.get(index - self.first.len()).copied()
+        }
+    }
+
+    /// Returns the bytes as one slice if they are not scattered (at
+    /// least one of the two slices is empty).
+    #[inline]
+    pub fn as_contiguous(&self) -> Option<&'a [u8]> {
+        if self.second.is_empty() {
+            Some(self.first)
+        } else if self.first.is_empty() {
+            Some(self.second)
+        } else {
+            None
+        }
+    }
+
+    /// Splits the bytes into the bytes before `mid` & the bytes starting
+    /// at `mid` (`None` if `mid` is bigger than [`ChainedSlice::len`]).
+    pub fn split_at(&self, mid: usize) -> Option<(ChainedSlice<'a>, ChainedSlice<'a>)> {
+        if mid <= self.first.len() {
+            let (a, b) = self.first.split_at(mid);
+            Some((ChainedSlice::new(a, &[]), ChainedSlice::new(b, self.second)))
+        } else if mid <= self.len() {
+            let (a, b) = self.second.split_at(mid - self.first.len());
+            Some((ChainedSlice::new(self.first, a), ChainedSlice::new(b, &[])))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all bytes.
+    #[inline]
+    pub fn iter(
+        &self,
+    ) -> core::iter::Chain<
+        core::iter::Copied<core::slice::Iter<'a, u8>>,
+        core::iter::Copied<core::slice::Iter<'a, u8>>,
+    > {
+        self.first
+            .iter()
+            .copied()
+            .chain(self.second.iter().copied())
+    }
+
+    /// Copies all bytes into a newly allocated vector.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_vec(&self) -> std::vec::Vec<u8> {
+        let mut result = std::vec::Vec::with_capacity(self.len());
+        result.extend_from_slice(self.first);
+        result.extend_from_slice(self.second);
+        result
+    }
+}
+
+impl<'a> From<&'a [u8]> for ChainedSlice<'a> {
+    #[inline]
+    fn from(value: &'a [u8]) -> Self {
+        ChainedSlice::new(value, &[])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn new_getters(
+            first in proptest::collection::vec(any::<u8>(), 0..10),
+            second in proptest::collection::vec(any::<u8>(), 0..10)
+        ) {
+            let chained = ChainedSlice::new(&first, &second);
+            assert_eq!(&first[..], chained.first());
+            assert_eq!(&second[..], chained.second());
+            assert_eq!(first.len() + second.len(), chained.len());
+            assert_eq!(first.is_empty() && second.is_empty(), chained.is_empty());
+
+            let expected: Vec<u8> = first.iter().chain(second.iter()).copied().collect();
+            assert_eq!(expected, chained.iter().collect::<Vec<_>>());
+            assert_eq!(expected, chained.to_vec());
+            for i in 0..=expected.len() {
+                assert_eq!(expected.get(i).copied(), chained.get(i));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn split_at(
+            first in proptest::collection::vec(any::<u8>(), 0..10),
+            second in proptest::collection::vec(any::<u8>(), 0..10)
+        ) {
+            let chained = ChainedSlice::new(&first, &second);
+            let expected = chained.to_vec();
+            for mid in 0..=expected.len() {
+                let (a, b) = chained.split_at(mid).unwrap();
+                assert_eq!(&expected[..mid], &a.to_vec()[..]);
+                assert_eq!(&expected[mid..], &b.to_vec()[..]);
+            }
+            assert_eq!(None, chained.split_at(expected.len() + 1));
+        }
+    }
+
+    #[test]
+    fn as_contiguous() {
+        assert_eq!(
+            Some(&[1, 2][..]),
+            ChainedSlice::new(&[1, 2], &[]).as_contiguous()
+        );
+        assert_eq!(
+            Some(&[1, 2][..]),
+            ChainedSlice::new(&[], &[1, 2]).as_contiguous()
+        );
+        assert_eq!(Some(&[][..]), ChainedSlice::new(&[], &[]).as_contiguous());
+        assert_eq!(None, ChainedSlice::new(&[1], &[2]).as_contiguous());
+    }
+
+    #[test]
+    fn from() {
+        let data = [1, 2, 3];
+        assert_eq!(ChainedSlice::new(&data, &[]), ChainedSlice::from(&data[..]));
+    }
+
+    #[test]
+    fn clone_eq_debug_default() {
+        let value = ChainedSlice::new(&[1], &[2]);
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "ChainedSlice { first: [1], second: [2] }",
+            format!("{:?}", value)
+        );
+        assert!(ChainedSlice::default().is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+use crate::*;
+
+/// Packet scattered over two slices whose headers are located in the
+/// first slice & whose payload continues in the second slice (e.g. a
+/// capture backend delivering packets via a header & a payload ring).
+///
+/// The headers are sliced from the first slice without copying via
+/// [`LaxSlicedPacket`] (which allows the payload to be cut off) and the
+/// payload of the innermost successfully parsed layer is returned as a
+/// [`ChainedSlice`] spanning both slices. The length of the payload is
+/// limited by the length fields of the IP & UDP headers (if present).
+///
+/// ```
+/// use etherparse::{ChainedSlice, ChainedSlicedPacket, PacketBuilder, TransportSlice};
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(1234, 53)
+///     .write(&mut packet, &[1, 2, 3, 4, 5, 6, 7, 8])
+///     .unwrap();
+///
+/// // headers & the first 2 payload bytes in the first slice
+/// let (headers, payload) = packet.split_at(14 + 20 + 8 + 2);
+///
+/// let sliced = ChainedSlicedPacket::from_ethernet(ChainedSlice::new(headers, payload)).unwrap();
+/// assert!(matches!(sliced.headers.transport, Some(TransportSlice::Udp(_))));
+/// assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], sliced.payload.to_vec());
+/// ```
+///
+/// Headers that are split between the two slices can not be parsed
+/// without copying. They are reported as a length error in the
+/// `stop_err` field of [`ChainedSlicedPacket::headers`] and have to be
+/// parsed from a contiguous copy of the data instead.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainedSlicedPacket<'a> {
+    /// Headers sliced from the first slice.
+    pub headers: LaxSlicedPacket<'a>,
+
+    /// Payload of the innermost successfully parsed layer (spanning both
+    /// slices).
+    pub payload: ChainedSlice<'a>,
+}
+
+impl<'a> ChainedSlicedPacket<'a> {
+    /// Slices a packet starting with an Ethernet II header (see
+    /// [`LaxSlicedPacket::from_ethernet`]).
+    pub fn from_ethernet(data: ChainedSlice<'a>) -> Result<ChainedSlicedPacket<'a>, err::LenError> {
+        let (head, tail) = Self::split(data);
+        let headers = LaxSlicedPacket::from_ethernet(head)?;
+        Ok(Self::new(headers, head, tail))
+    }
+
+    /// Slices a packet starting with an IPv4 or IPv6 header (see
+    /// [`LaxSlicedPacket::from_ip`]).
+    pub fn from_ip(
+        data: ChainedSlice<'a>,
+    ) -> Result<ChainedSlicedPacket<'a>, err::ip::LaxHeaderSliceError> {
+        let (head, tail) = Self::split(data);
+        let headers = LaxSlicedPacket::from_ip(head)?;
+        Ok(Self::new(headers, head, tail))
+    }
+
+    /// Returns the slice containing the headers & the slice containing
+    /// the rest of the data.
+    fn split(data: ChainedSlice<'a>) -> (&'a [u8], &'a [u8]) {
+        if data.first().is_empty() {
+            (data.second(), &[])
+        } else {
+            (data.first(), data.second())
+        }
+    }
+
+    /// Determines the chained payload based on the headers sliced from `head`.
+    fn new(
+        headers: LaxSlicedPacket<'a>,
+        head: &'a [u8],
+        tail: &'a [u8],
+    ) -> ChainedSlicedPacket<'a> {
+        let offset = |s: &[u8]| (s.as_ptr() as usize) - (head.as_ptr() as usize);
+
+        // start of the payload of the innermost layer
+        let start = if let Some(transport) = &headers.transport {
+            use TransportSlice::*;
+            offset(match transport {
+                Icmpv4(s) => s.payload(),
+                Icmpv6(s) => s.payload(),
+                Udp(s) => s.payload(),
+                Tcp(s) => s.payload(),
+            })
+        } else if let Some(ip_payload) = headers.ip_payload() {
+            offset(ip_payload.payload)
+        } else if let Some(ether_payload) = headers.ether_payload() {
+            offset(ether_payload.payload)
+        } else {
+            head.len()
+        };
+
+        // end of the packet based on the length fields
+        let mut end = head.len() + tail.len();
+        match &headers.net {
+            Some(LaxNetSlice::Ipv4(ipv4)) => {
+                let header = ipv4.header();
+                let total_len = usize::from(header.total_len());
+                if total_len >= header.slice().len() {
+                    end = end.min(offset(header.slice()) + total_len);
+                }
+            }
+            Some(LaxNetSlice::Ipv6(ipv6)) => {
+                let header = ipv6.header();
+                // a payload length of 0 indicates a jumbogram
+                if header.payload_length() != 0 {
+                    end = end.min(
+                        offset(header.slice())
+                            + Ipv6Header::LEN
+                            + usize::from(header.payload_length()),
+                    );
+                }
+            }
+            None => {}
+        }
+        if let Some(TransportSlice::Udp(udp)) = &headers.transport {
+            let length = usize::from(udp.length());
+            // a length of 0 indicates a jumbogram
+            if length >= UdpHeader::LEN {
+                end = end.min(offset(udp.slice()) + length);
+            }
+        }
+        let end = end.max(start);
+
+        let payload = if end <= head.len() {
+            ChainedSlice::new(&head[start..end], &[])
+        } else {
+            ChainedSlice::new(&head[start..], &tail[..end - head.len()])
+        };
+        ChainedSlicedPacket { headers, payload }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_ethernet_udp(
+            payload in proptest::collection::vec(any::<u8>(), 0..40),
+            split in 0usize..100,
+            padding in 0usize..10
+        ) {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .udp(1234, 53)
+                .write(&mut packet, &payload)
+                .unwrap();
+            // ethernet padding
+            packet.resize(packet.len() + padding, 0);
+
+            let headers_len = Ethernet2Header::LEN + Ipv4Header::MIN_LEN + UdpHeader::LEN;
+            let split = split.min(packet.len());
+            let (a, b) = packet.split_at(split);
+            if split == 0 || split >= headers_len {
+                // headers in one slice
+                let actual = ChainedSlicedPacket::from_ethernet(ChainedSlice::new(a, b)).unwrap();
+                assert_eq!(None, actual.headers.stop_err);
+                assert!(matches!(actual.headers.transport, Some(TransportSlice::Udp(_))));
+                assert_eq!(payload, actual.payload.to_vec());
+            } else if split < Ethernet2Header::LEN {
+                // ethernet header split between both slices
+                assert!(ChainedSlicedPacket::from_ethernet(ChainedSlice::new(a, b)).is_err());
+            } else {
+                // ip or udp header split between both slices
+                let actual = ChainedSlicedPacket::from_ethernet(ChainedSlice::new(a, b)).unwrap();
+                assert!(actual.headers.stop_err.is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn from_ethernet_split_header() {
+        let mut packet = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(1234, 53)
+            .write(&mut packet, &[1, 2, 3, 4])
+            .unwrap();
+
+        // ipv4 header split
+        let (a, b) = packet.split_at(Ethernet2Header::LEN + 10);
+        let actual = ChainedSlicedPacket::from_ethernet(ChainedSlice::new(a, b)).unwrap();
+        assert!(actual.headers.link.is_some());
+        assert!(actual.headers.net.is_none());
+        assert!(matches!(
+            actual.headers.stop_err,
+            Some((err::packet::SliceError::Len(_), err::Layer::IpHeader))
+        ));
+        // payload of the ethernet header
+        assert_eq!(
+            &packet[Ethernet2Header::LEN..],
+            &actual.payload.to_vec()[..]
+        );
+
+        // ethernet header split
+        let (a, b) = packet.split_at(10);
+        assert!(ChainedSlicedPacket::from_ethernet(ChainedSlice::new(a, b)).is_err());
+
+        // all data in the second slice
+        let actual = ChainedSlicedPacket::from_ethernet(ChainedSlice::new(&[], &packet)).unwrap();
+        assert_eq!(Some(&[1, 2, 3, 4][..]), actual.payload.as_contiguous());
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn from_ip() {
+        // ipv6 & tcp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .tcp(1, 2, 3, 4)
+                .write(&mut packet, &[1, 2, 3, 4, 5, 6])
+                .unwrap();
+            let (a, b) = packet.split_at(Ipv6Header::LEN + 20 + 1);
+            let mut b = b.to_vec();
+            // data after the ip payload
+            b.extend_from_slice(&[9, 9]);
+            let actual = ChainedSlicedPacket::from_ip(ChainedSlice::new(a, &b)).unwrap();
+            assert!(matches!(
+                actual.headers.transport,
+                Some(TransportSlice::Tcp(_))
+            ));
+            assert_eq!(&[1], actual.payload.first());
+            assert_eq!(&[2, 3, 4, 5, 6], actual.payload.second());
+        }
+        // ipv4 with unknown payload
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1; 4], [2; 4], 20)
+                .write(&mut packet, IpNumber(253), &[1, 2, 3, 4])
+                .unwrap();
+            let (a, b) = packet.split_at(Ipv4Header::MIN_LEN);
+            let actual = ChainedSlicedPacket::from_ip(ChainedSlice::new(a, b)).unwrap();
+            assert!(actual.headers.transport.is_none());
+            assert_eq!(Some(&[1, 2, 3, 4][..]), actual.payload.as_contiguous());
+        }
+        // error
+        assert!(ChainedSlicedPacket::from_ip(ChainedSlice::new(&[0x70], &[0; 40])).is_err());
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let packet = [0u8; 14];
+        let value = ChainedSlicedPacket::from_ethernet(ChainedSlice::new(&packet, &[])).unwrap();
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!(
+                "ChainedSlicedPacket {{ headers: {:?}, payload: {:?} }}",
+                value.headers, value.payload
+            ),
+            format!("{:?}", value)
+        );
+    }
+}
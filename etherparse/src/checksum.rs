@@ -38,6 +38,39 @@ impl Sum16BitWords {
         }
     }
 
+    /// Add the given slices to the checksum as if they were one
+    /// contiguous slice (e.g. a payload scattered over multiple buffers).
+    /// In case the total length is not a multiple of 2 the last byte
+    /// will be padded with 0.
+    pub fn add_slices(self, slices: &[&[u8]]) -> Sum16BitWords {
+        let mut result = self;
+        // byte of a slice with an odd length that still has to be
+        // combined with the first byte of the next slice
+        let mut pending: Option<u8> = None;
+        for slice in slices {
+            let mut slice = *slice;
+            if let Some(first) = pending {
+                match slice.split_first() {
+                    Some((second, rest)) => {
+                        result = result.add_2bytes([first, *second]);
+                        slice = rest;
+                        pending = None;
+                    }
+                    None => continue,
+                }
+            }
+            let even_len = slice.len() & !1;
+            result = result.add_slice(&slice[..even_len]);
+            if even_len < slice.len() {
+                pending = Some(slice[even_len]);
+            }
+        }
+        if let Some(last) = pending {
+            result = result.add_2bytes([last, 0]);
+        }
+        result
+    }
+
     /// Add a 2 byte word.
     #[inline]
     #[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
@@ -143,6 +176,7 @@ impl Sum16BitWords {
 mod sum16_bit_words_tests {
     use super::*;
     use alloc::format;
+    use proptest::prelude::*;
 
     #[test]
     fn new() {
@@ -159,6 +193,44 @@ mod sum16_bit_words_tests {
         );
     }
 
+    proptest! {
+        #[test]
+        fn add_slices(
+            data in proptest::collection::vec(any::<u8>(), 0..100),
+            split_a in any::<proptest::sample::Index>(),
+            split_b in any::<proptest::sample::Index>()
+        ) {
+            let expected = Sum16BitWords::new().add_slice(&data).ones_complement();
+            let a = split_a.index(data.len() + 1);
+            let b = a + split_b.index(data.len() - a + 1);
+            assert_eq!(
+                expected,
+                Sum16BitWords::new().add_slices(&[&data[..a], &data[a..b], &data[b..]])
+                    .ones_complement()
+            );
+            assert_eq!(
+                expected,
+                Sum16BitWords::new().add_slices(&[&data[..a], &[], &data[a..]])
+                    .ones_complement()
+            );
+            assert_eq!(expected, Sum16BitWords::new().add_slices(&[&data]).ones_complement());
+        }
+    }
+
+    #[test]
+    fn add_slices_odd() {
+        // single bytes spread over multiple slices
+        assert_eq!(
+            Sum16BitWords::new()
+                .add_slice(&[1, 2, 3, 4, 5])
+                .ones_complement(),
+            Sum16BitWords::new()
+                .add_slices(&[&[1], &[], &[2, 3], &[4], &[5]])
+                .ones_complement()
+        );
+        assert_eq!(Sum16BitWords::new(), Sum16BitWords::new().add_slices(&[]));
+    }
+
     #[test]
     fn add_2bytes() {
         assert_eq!(
@@ -268,9 +340,224 @@ mod sum16_bit_words_tests {
     }
 }
 
+/// Returns the summed up length of all given slices (saturates at
+/// `usize::MAX`).
+#[inline]
+pub(crate) fn slices_len(slices: &[&[u8]]) -> usize {
+    slices
+        .iter()
+        .fold(0usize, |acc, slice| acc.saturating_add(slice.len()))
+}
+
+/// Incrementally updates a 16 bit one's complement checksum after a
+/// single 16 bit word covered by the checksum has been changed from
+/// `old_word` to `new_word` (see
+/// [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624) equation 3).
+///
+/// All values are expected to be in the byte order they are decoded from
+/// the packet (e.g. via `u16::from_be_bytes`).
+///
+/// ```
+/// use etherparse::checksum::{incremental_update, Sum16BitWords};
+///
+/// let old_data = [0x12, 0x34, 0x56, 0x78];
+/// let new_data = [0x12, 0x34, 0xab, 0xcd];
+/// let old_checksum = Sum16BitWords::new().add_slice(&old_data).ones_complement().to_be();
+///
+/// assert_eq!(
+///     incremental_update(old_checksum, 0x5678, 0xabcd),
+///     Sum16BitWords::new().add_slice(&new_data).ones_complement().to_be()
+/// );
+/// ```
+#[inline]
+pub fn incremental_update(checksum: u16, old_word: u16, new_word: u16) -> u16 {
+    // HC' = ~(~HC + ~m + m')
+    let sum = u32::from(!checksum) + u32::from(!old_word) + u32::from(new_word);
+    let first = (sum >> 16) + (sum & 0xffff);
+    let folded = ((first >> 16) + (first & 0xffff)) as u16;
+    !folded
+}
+
+#[cfg(test)]
+mod incremental_update_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn incremental_update_matches_full_calculation(
+            data in proptest::collection::vec(any::<u8>(), 2..40),
+            word_index in any::<proptest::sample::Index>(),
+            new_word in any::<u16>()
+        ) {
+            let mut data = data;
+            // make the data length even so every word is a full word
+            if data.len() % 2 != 0 {
+                data.push(0);
+            }
+            let i = word_index.index(data.len() / 2) * 2;
+            let old_word = u16::from_be_bytes([data[i], data[i + 1]]);
+            let old_checksum = Sum16BitWords::new().add_slice(&data).ones_complement().to_be();
+
+            let new_bytes = new_word.to_be_bytes();
+            data[i] = new_bytes[0];
+            data[i + 1] = new_bytes[1];
+            let expected = Sum16BitWords::new().add_slice(&data).ones_complement().to_be();
+
+            let actual = incremental_update(old_checksum, old_word, new_word);
+            // 0x0000 & 0xffff both represent zero in one's complement
+            if expected == 0 || expected == 0xffff {
+                prop_assert!(actual == 0 || actual == 0xffff);
+            } else {
+                prop_assert_eq!(expected, actual);
+            }
+        }
+    }
+}
+
+/// Lookup table for the reflected CRC-32 polynomial `0xEDB88320`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 == 1 {
+                (value >> 1) ^ 0xEDB8_8320
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+};
+
+/// Helper for calculating CRC-32 (IEEE 802.3) checksums as used in
+/// the frame check sequence (FCS) of Ethernet II frames.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Crc32 {
+    /// Not yet finalized (inverted) CRC value.
+    state: u32,
+}
+
+impl Crc32 {
+    pub const fn new() -> Crc32 {
+        Crc32 { state: 0xffff_ffff }
+    }
+
+    /// Add the given slice to the CRC.
+    #[inline]
+    pub fn add_slice(self, slice: &[u8]) -> Crc32 {
+        let mut state = self.state;
+        for b in slice {
+            state = CRC32_TABLE[usize::from((state as u8) ^ *b)] ^ (state >> 8);
+        }
+        Crc32 { state }
+    }
+
+    /// Returns the finalized CRC-32 value.
+    #[inline]
+    pub const fn value(&self) -> u32 {
+        !self.state
+    }
+
+    /// Returns the CRC-32 value in the byte order it is transmitted
+    /// as ethernet frame check sequence (FCS).
+    #[inline]
+    pub const fn to_fcs(&self) -> [u8; 4] {
+        self.value().to_le_bytes()
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Crc32::new()
+    }
+}
+
+/// Calculates the ethernet frame check sequence (CRC-32) of the given
+/// frame (starting with the destination MAC address and not containing
+/// the FCS itself).
+///
+/// The result can be appended to the frame & is accepted by
+/// [`crate::Ethernet2Slice::from_slice_with_crc32_fcs`]:
+///
+/// ```
+/// use etherparse::{checksum::ethernet_fcs, Ethernet2Slice};
+///
+/// let mut frame = vec![0xff; 60];
+/// let fcs = ethernet_fcs(&frame);
+/// frame.extend_from_slice(&fcs);
+///
+/// let slice = Ethernet2Slice::from_slice_with_crc32_fcs(&frame).unwrap();
+/// assert_eq!(Some(fcs), slice.fcs());
+/// ```
+#[inline]
+pub fn ethernet_fcs(frame: &[u8]) -> [u8; 4] {
+    Crc32::new().add_slice(frame).to_fcs()
+}
+
+#[cfg(test)]
+mod crc32_tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn check_value() {
+        // check value of CRC-32/ISO-HDLC
+        assert_eq!(0xCBF4_3926, Crc32::new().add_slice(b"123456789").value());
+        assert_eq!(0, Crc32::new().value());
+        assert_eq!(
+            [0x26, 0x39, 0xf4, 0xcb],
+            Crc32::new().add_slice(b"123456789").to_fcs()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn add_slice_split(
+            data in proptest::collection::vec(any::<u8>(), 0..100),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let i = split.index(data.len() + 1);
+            assert_eq!(
+                Crc32::new().add_slice(&data),
+                Crc32::new().add_slice(&data[..i]).add_slice(&data[i..])
+            );
+            assert_eq!(ethernet_fcs(&data), Crc32::new().add_slice(&data).to_fcs());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn residue(data in proptest::collection::vec(any::<u8>(), 0..100)) {
+            // the crc over the data & the appended fcs is a constant
+            let fcs = ethernet_fcs(&data);
+            assert_eq!(
+                0x2144_DF1C,
+                Crc32::new().add_slice(&data).add_slice(&fcs).value()
+            );
+        }
+    }
+
+    #[test]
+    fn default_debug_clone_eq() {
+        let value: Crc32 = Default::default();
+        assert_eq!(Crc32::new(), value);
+        assert_eq!(value.clone(), value);
+        assert_eq!("Crc32 { state: 4294967295 }", format!("{:?}", value));
+    }
+}
+
 /// Helper functions for calculating a 16 bit checksum using
 /// a u32 to sum up all values.
 pub mod u32_16bit_word {
+    use crate::get_unchecked_u8;
 
     /// Add a 4 byte word.
     #[inline]
@@ -302,12 +589,12 @@ pub mod u32_16bit_word {
                 // Guranteed to always have at least 4 bytes to read
                 // from i. As end_32 is gurenateed to be a multiple of
                 // 4 bytes with a size equal or less then slice.len().
-                unsafe {
+                unchecked! {
                     [
-                        *slice.get_unchecked(i),
-                        *slice.get_unchecked(i + 1),
-                        *slice.get_unchecked(i + 2),
-                        *slice.get_unchecked(i + 3),
+                        get_unchecked_u8(slice, i),
+                        get_unchecked_u8(slice, i + 1),
+                        get_unchecked_u8(slice, i + 2),
+                        get_unchecked_u8(slice, i + 3),
                     ]
                 },
             );
@@ -320,10 +607,10 @@ pub mod u32_16bit_word {
                 // SAFETY:
                 // If check guarantees there to be at least
                 // 2 bytes.
-                unsafe {
+                unchecked! {
                     [
-                        *slice.get_unchecked(end_32),
-                        *slice.get_unchecked(end_32 + 1),
+                        get_unchecked_u8(slice, end_32),
+                        get_unchecked_u8(slice, end_32 + 1),
                     ]
                 },
             );
@@ -336,7 +623,7 @@ pub mod u32_16bit_word {
                 // SAFETY:
                 // If check guarantees there to be at least
                 // 2 bytes.
-                unsafe { [*slice.get_unchecked(slice.len() - 1), 0] },
+                unchecked! { [get_unchecked_u8(slice, slice.len() - 1), 0] },
             );
         }
 
@@ -548,6 +835,7 @@ pub mod u32_16bit_word {
 /// Helper functions for calculating a 16 bit checksum using
 /// a u64 to sum up all values.
 pub mod u64_16bit_word {
+    use crate::get_unchecked_u8;
 
     /// Add a 8 byte word.
     #[inline]
@@ -586,16 +874,16 @@ pub mod u64_16bit_word {
                 // Guranteed to always have at least 8 bytes to read
                 // from i. As end_64 is gurenateed to be a multiple of
                 // 8 bytes with a size equal or less then slice.len().
-                unsafe {
+                unchecked! {
                     [
-                        *slice.get_unchecked(i),
-                        *slice.get_unchecked(i + 1),
-                        *slice.get_unchecked(i + 2),
-                        *slice.get_unchecked(i + 3),
-                        *slice.get_unchecked(i + 4),
-                        *slice.get_unchecked(i + 5),
-                        *slice.get_unchecked(i + 6),
-                        *slice.get_unchecked(i + 7),
+                        get_unchecked_u8(slice, i),
+                        get_unchecked_u8(slice, i + 1),
+                        get_unchecked_u8(slice, i + 2),
+                        get_unchecked_u8(slice, i + 3),
+                        get_unchecked_u8(slice, i + 4),
+                        get_unchecked_u8(slice, i + 5),
+                        get_unchecked_u8(slice, i + 6),
+                        get_unchecked_u8(slice, i + 7),
                     ]
                 },
             );
@@ -608,12 +896,12 @@ pub mod u64_16bit_word {
                 // SAFETY:
                 // If check guarantees there to be at least
                 // 2 bytes.
-                unsafe {
+                unchecked! {
                     [
-                        *slice.get_unchecked(end_64),
-                        *slice.get_unchecked(end_64 + 1),
-                        *slice.get_unchecked(end_64 + 2),
-                        *slice.get_unchecked(end_64 + 3),
+                        get_unchecked_u8(slice, end_64),
+                        get_unchecked_u8(slice, end_64 + 1),
+                        get_unchecked_u8(slice, end_64 + 2),
+                        get_unchecked_u8(slice, end_64 + 3),
                     ]
                 },
             );
@@ -631,10 +919,10 @@ pub mod u64_16bit_word {
                 // SAFETY:
                 // If check guarantees there to be at least
                 // 2 bytes.
-                unsafe {
+                unchecked! {
                     [
-                        *slice.get_unchecked(end_32),
-                        *slice.get_unchecked(end_32 + 1),
+                        get_unchecked_u8(slice, end_32),
+                        get_unchecked_u8(slice, end_32 + 1),
                     ]
                 },
             );
@@ -647,7 +935,7 @@ pub mod u64_16bit_word {
                 // SAFETY:
                 // If check guarantees there to be at least
                 // 2 bytes.
-                unsafe { [*slice.get_unchecked(slice.len() - 1), 0] },
+                unchecked! { [get_unchecked_u8(slice, slice.len() - 1), 0] },
             );
         }
 
@@ -300,6 +300,7 @@ impl ComponentTest {
                     LinkSlice::LinuxSll(e) => Some(LinkHeader::LinuxSll(e.to_header())),
                     LinkSlice::EtherPayload(_) => None,
                     LinkSlice::LinuxSllPayload(_) => None,
+                    LinkSlice::Ieee80211(_) => None,
                 },
                 None => None,
             }
@@ -644,6 +645,7 @@ fn test_packet_slicing_panics() {
     let s = SlicedPacket {
         link: None,
         vlan: None,
+        backbone: None,
         net: None,
         transport: None,
     };
@@ -118,9 +118,14 @@ impl IpDefragBuf {
             {
                 return Err(AllocationFailure { len: required_len });
             }
+            // the data is overwritten below (with the `safe-only` feature
+            // the memory is zero initialized instead)
+            #[cfg(not(feature = "safe-only"))]
             unsafe {
                 self.data.set_len(required_len);
             }
+            #[cfg(feature = "safe-only")]
+            self.data.resize(required_len, 0);
         }
 
         // insert new data
@@ -148,10 +153,7 @@ impl IpDefragBuf {
         if false == more_fragments {
             self.end = Some(end);
             // restrict the length based on the length
-            unsafe {
-                // SAFETY: Safe as the length has previously been checked to be at least "end" long
-                self.data.set_len(usize::from(end));
-            }
+            self.data.truncate(usize::from(end));
         }
 
         Ok(())
@@ -28,6 +28,13 @@ pub enum IpDefragError {
 
     /// Error if not enough memory could be allocated to store the TP payload.
     AllocationFailure { len: usize },
+
+    /// Error if all preallocated buffers of a pool created via
+    /// [`crate::defrag::IpDefragPool::with_capacity`] are in use (either
+    /// by packets that are being reconstructed or by reconstructed packets
+    /// whose buffers were not yet given back via
+    /// [`crate::defrag::IpDefragPool::return_buf`]).
+    PoolExhausted { max_packets: usize },
 }
 
 impl core::fmt::Display for IpDefragError {
@@ -38,6 +45,7 @@ impl core::fmt::Display for IpDefragError {
             SegmentTooBig{ offset, payload_len, max } => write!(f, "Overall length of IP fragment (offset {offset}, payload len: {payload_len}) bigger then the maximum allowed size of {max}."),
             ConflictingEnd { previous_end, conflicting_end } => write!(f, "Received a IP fragment (offset + len: {conflicting_end}) which conflicts a package that previously set the end to {previous_end}."),
             AllocationFailure { len } => write!(f, "Failed to allocate {len} bytes of memory to reconstruct the fragmented IP packets."),
+            PoolExhausted { max_packets } => write!(f, "All {max_packets} buffers of the IP defragmentation pool are in use (packets are still being reconstructed or buffers were not returned)."),
         }
     }
 }
@@ -92,6 +100,7 @@ mod tests {
             (SegmentTooBig { offset: IpFragOffset::try_new(0).unwrap(), payload_len: 2, max: 3, }, "Overall length of IP fragment (offset 0, payload len: 2) bigger then the maximum allowed size of 3."),
             (ConflictingEnd { previous_end: 2, conflicting_end: 1 }, "Received a IP fragment (offset + len: 1) which conflicts a package that previously set the end to 2."),
             (AllocationFailure { len: 0 }, "Failed to allocate 0 bytes of memory to reconstruct the fragmented IP packets."),
+            (PoolExhausted { max_packets: 2 }, "All 2 buffers of the IP defragmentation pool are in use (packets are still being reconstructed or buffers were not returned)."),
         ];
         for test in tests {
             assert_eq!(format!("{}", test.0), test.1);
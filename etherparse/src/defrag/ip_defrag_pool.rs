@@ -19,6 +19,14 @@ use std::vec::Vec;
 /// Mitigations will hopefully be offered in future versions but if you have
 /// take care right now you can still use [`IpDefragBuf`] directly and implement the
 /// connection handling and mitigation yourself.
+///
+/// # Preallocated pools
+///
+/// A pool created via [`IpDefragPool::with_capacity`] allocates all buffers
+/// during construction & never allocates afterwards. Instead of growing,
+/// [`IpDefragError::PoolExhausted`] is returned if all buffers are in use and
+/// [`IpDefragError::SegmentTooBig`] if a packet exceeds the maximum packet
+/// size. This also limits the memory an attacker can make the pool use.
 #[derive(Debug, Clone)]
 pub struct IpDefragPool<Timestamp = (), CustomChannelId = ()>
 where
@@ -33,6 +41,13 @@ where
 
     /// Section buffers that have finished receiving data and can be re-used.
     finished_section_bufs: Vec<Vec<IpFragRange>>,
+
+    /// Number of preallocated buffers (`None` if the pool allocates
+    /// buffers on demand).
+    max_packets: Option<usize>,
+
+    /// Maximum length of a reconstructed packet.
+    max_packet_size: u16,
 }
 
 impl<Timestamp, CustomChannelId> IpDefragPool<Timestamp, CustomChannelId>
@@ -45,9 +60,78 @@ where
             active: HashMap::new(),
             finished_data_bufs: Vec::new(),
             finished_section_bufs: Vec::new(),
+            max_packets: None,
+            max_packet_size: MAX_IP_DEFRAG_LEN_U16,
         }
     }
 
+    /// Creates a pool with preallocated buffers to reconstruct up to
+    /// `max_packets` packets with a length of up to `max_packet_size` bytes
+    /// in parallel. The pool does not allocate after construction.
+    ///
+    /// Reconstructed packets returned by [`IpDefragPool::process_sliced_packet`]
+    /// use one of the preallocated buffers. These have to be given back via
+    /// [`IpDefragPool::return_buf`] once they are no longer needed, otherwise
+    /// the pool runs out of buffers ([`IpDefragError::PoolExhausted`]).
+    ///
+    /// ```
+    /// use etherparse::{defrag::{IpDefragError, IpDefragPool}, IpFragOffset, Ipv4Header, IpNumber, SlicedPacket};
+    ///
+    /// let mut pool = IpDefragPool::<(), ()>::with_capacity(1, 1500);
+    ///
+    /// // first fragments of two different packets
+    /// let mut fragments = Vec::new();
+    /// for identification in 0..2 {
+    ///     let mut ip = Ipv4Header::new(8, 20, IpNumber::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+    ///     ip.identification = identification;
+    ///     ip.more_fragments = true;
+    ///     let mut packet = ip.to_bytes().to_vec();
+    ///     packet.extend_from_slice(&[0; 8]);
+    ///     fragments.push(packet);
+    /// }
+    ///
+    /// let slice = SlicedPacket::from_ip(&fragments[0]).unwrap();
+    /// assert_eq!(Ok(None), pool.process_sliced_packet(&slice, (), ()));
+    ///
+    /// // only one packet can be reconstructed at the same time
+    /// let slice = SlicedPacket::from_ip(&fragments[1]).unwrap();
+    /// assert_eq!(
+    ///     Err(IpDefragError::PoolExhausted { max_packets: 1 }),
+    ///     pool.process_sliced_packet(&slice, (), ())
+    /// );
+    /// ```
+    pub fn with_capacity(
+        max_packets: usize,
+        max_packet_size: u16,
+    ) -> IpDefragPool<Timestamp, CustomChannelId> {
+        // worst case number of sections (every other 8 byte block received)
+        let max_sections = usize::from(max_packet_size) / 16 + 1;
+        IpDefragPool {
+            active: HashMap::with_capacity(max_packets),
+            finished_data_bufs: (0..max_packets)
+                .map(|_| Vec::with_capacity(usize::from(max_packet_size)))
+                .collect(),
+            finished_section_bufs: (0..max_packets)
+                .map(|_| Vec::with_capacity(max_sections))
+                .collect(),
+            max_packets: Some(max_packets),
+            max_packet_size,
+        }
+    }
+
+    /// Number of preallocated buffers (`None` if the pool was created
+    /// via [`IpDefragPool::new`] and allocates buffers on demand).
+    #[inline]
+    pub fn max_packets(&self) -> Option<usize> {
+        self.max_packets
+    }
+
+    /// Maximum length of a reconstructed packet.
+    #[inline]
+    pub fn max_packet_size(&self) -> u16 {
+        self.max_packet_size
+    }
+
     /// Add data from a sliced packet.
     pub fn process_sliced_packet(
         &mut self,
@@ -149,6 +233,18 @@ where
             }
         };
 
+        // check the packet fits into the preallocated buffers
+        if self.max_packets.is_some()
+            && usize::from(offset.byte_offset()) + payload.payload.len()
+                > usize::from(self.max_packet_size)
+        {
+            return Err(IpDefragError::SegmentTooBig {
+                offset,
+                payload_len: payload.payload.len(),
+                max: self.max_packet_size,
+            });
+        }
+
         // get the reconstruction buffer
         use std::collections::hash_map::Entry;
         match self.active.entry(frag_id) {
@@ -173,6 +269,11 @@ where
                 }
             }
             Entry::Vacant(entry) => {
+                if let Some(max_packets) = self.max_packets {
+                    if self.finished_data_bufs.is_empty() || self.finished_section_bufs.is_empty() {
+                        return Err(IpDefragError::PoolExhausted { max_packets });
+                    }
+                }
                 let data_buf = if let Some(mut d) = self.finished_data_bufs.pop() {
                     d.clear();
                     d
@@ -208,7 +309,79 @@ where
         }
     }
 
+    /// Add data from a sliced packet & lend the reconstructed payload to `f`
+    /// once the packet is complete.
+    ///
+    /// This is only a convenience wrapper around
+    /// [`IpDefragPool::process_sliced_packet`] followed by
+    /// [`IpDefragPool::return_buf`]. The fragment payloads are still copied
+    /// into a buffer owned by the pool as they arrive (the sliced packets
+    /// are not kept between calls). `f` only borrows the reconstructed
+    /// payload & the buffer is re-used after `f` returns.
+    ///
+    /// Returns the value returned by `f` or `None` if the packet is not yet
+    /// complete (or not fragmented).
+    ///
+    /// ```
+    /// use etherparse::{defrag::IpDefragPool, ip_number, IpFragOffset, Ipv4Header, SlicedPacket};
+    ///
+    /// let mut pool = IpDefragPool::<(), ()>::new();
+    /// let mut target = [0u8; 1500];
+    ///
+    /// // first fragment
+    /// let mut header = Ipv4Header::new(8, 20, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+    /// header.more_fragments = true;
+    /// let mut first = header.to_bytes().to_vec();
+    /// first.extend_from_slice(&[1; 8]);
+    ///
+    /// let slice = SlicedPacket::from_ip(&first).unwrap();
+    /// assert_eq!(Ok(None), pool.process_sliced_packet_borrowed(&slice, (), (), |_| 0));
+    ///
+    /// // last fragment
+    /// header.more_fragments = false;
+    /// header.fragment_offset = IpFragOffset::try_new(1).unwrap();
+    /// header.set_payload_len(4).unwrap();
+    /// let mut last = header.to_bytes().to_vec();
+    /// last.extend_from_slice(&[2; 4]);
+    ///
+    /// // copy the reconstructed payload into the target buffer
+    /// let slice = SlicedPacket::from_ip(&last).unwrap();
+    /// let len = pool
+    ///     .process_sliced_packet_borrowed(&slice, (), (), |payload| {
+    ///         target[..payload.payload.len()].copy_from_slice(payload.payload);
+    ///         payload.payload.len()
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(Some(12), len);
+    /// assert_eq!(&[1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2], &target[..12]);
+    /// ```
+    pub fn process_sliced_packet_borrowed<R, F>(
+        &mut self,
+        slice: &SlicedPacket,
+        timestamp: Timestamp,
+        channel_id: CustomChannelId,
+        f: F,
+    ) -> Result<Option<R>, IpDefragError>
+    where
+        F: FnOnce(IpPayloadSlice<'_>) -> R,
+    {
+        let Some(defragmented) = self.process_sliced_packet(slice, timestamp, channel_id)? else {
+            return Ok(None);
+        };
+        let result = f(IpPayloadSlice {
+            ip_number: defragmented.ip_number,
+            fragmented: false,
+            len_source: defragmented.len_source,
+            payload: &defragmented.payload,
+        });
+        self.return_buf(defragmented);
+        Ok(Some(result))
+    }
+
     /// Returns a buffer to the pool so it can be re-used.
+    ///
+    /// Pools created via [`IpDefragPool::with_capacity`] should only be
+    /// given back buffers previously returned by the pool itself.
     pub fn return_buf(&mut self, buf: IpDefragPayloadVec) {
         self.finished_data_bufs.push(buf.payload);
     }
@@ -218,22 +391,24 @@ where
     where
         F: Fn(&Timestamp) -> bool,
     {
-        if self.active.iter().any(|(_, (_, t))| false == f(t)) {
-            self.active = self
-                .active
-                .drain()
-                .filter_map(|(k, v)| {
-                    if f(&v.1) {
-                        Some((k, v))
-                    } else {
-                        let (data, sections) = v.0.take_bufs();
-                        self.finished_data_bufs.push(data);
-                        self.finished_section_bufs.push(sections);
-                        None
-                    }
-                })
-                .collect();
-        }
+        let finished_data_bufs = &mut self.finished_data_bufs;
+        let finished_section_bufs = &mut self.finished_section_bufs;
+        self.active.retain(|_, v| {
+            if f(&v.1) {
+                true
+            } else {
+                // take the buffers (an empty buffer does not allocate)
+                let ip_number = v.0.ip_number();
+                let (data, sections) = core::mem::replace(
+                    &mut v.0,
+                    IpDefragBuf::new(ip_number, Vec::new(), Vec::new()),
+                )
+                .take_bufs();
+                finished_data_bufs.push(data);
+                finished_section_bufs.push(sections);
+                false
+            }
+        });
     }
 }
 
@@ -416,6 +591,7 @@ mod test {
         buf
     }
 
+    #[cfg(feature = "ipv6")]
     #[test]
     fn process_sliced_packet() {
         // non ip packet
@@ -424,6 +600,7 @@ mod test {
             let pslice = SlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
             };
@@ -818,6 +995,155 @@ mod test {
         }
     }
 
+    #[test]
+    fn with_capacity() {
+        let pool = IpDefragPool::<(), ()>::with_capacity(3, 1000);
+        assert_eq!(Some(3), pool.max_packets());
+        assert_eq!(1000, pool.max_packet_size());
+        assert_eq!(pool.active.len(), 0);
+        assert_eq!(pool.finished_data_bufs.len(), 3);
+        assert_eq!(pool.finished_section_bufs.len(), 3);
+        for buf in &pool.finished_data_bufs {
+            assert!(buf.capacity() >= 1000);
+        }
+        for buf in &pool.finished_section_bufs {
+            assert!(buf.capacity() >= 1000 / 16 + 1);
+        }
+
+        let pool = IpDefragPool::<(), ()>::new();
+        assert_eq!(None, pool.max_packets());
+        assert_eq!(MAX_IP_DEFRAG_LEN_U16, pool.max_packet_size());
+    }
+
+    #[test]
+    fn with_capacity_process() {
+        let frag_id = |identification| IpFragId {
+            outer_vlan_id: None,
+            inner_vlan_id: None,
+            ip: IpFragVersionSpecId::Ipv4 {
+                source: [1, 2, 3, 4],
+                destination: [5, 6, 7, 8],
+                identification,
+            },
+            payload_ip_number: IpNumber::UDP,
+            channel_id: (),
+        };
+        let mut pool = IpDefragPool::<u32, ()>::with_capacity(2, 32);
+        let data_ptrs: Vec<_> = pool.finished_data_bufs.iter().map(|b| b.as_ptr()).collect();
+
+        let mut process = |pool: &mut IpDefragPool<u32, ()>, id, offset, more, payload: &[u8]| {
+            let pdata = build_packet(frag_id(id), offset, more, payload);
+            let pslice = SlicedPacket::from_ethernet(&pdata).unwrap();
+            pool.process_sliced_packet(&pslice, 0, ())
+        };
+
+        // two packets in parallel
+        assert_eq!(Ok(None), process(&mut pool, 0, 0, true, &[1; 8]));
+        assert_eq!(Ok(None), process(&mut pool, 1, 0, true, &[2; 8]));
+
+        // third packet exceeds the capacity
+        assert_eq!(
+            Err(IpDefragError::PoolExhausted { max_packets: 2 }),
+            process(&mut pool, 2, 0, true, &[3; 8])
+        );
+
+        // packet exceeding the maximum size
+        assert_eq!(
+            Err(IpDefragError::SegmentTooBig {
+                offset: IpFragOffset::try_new(3).unwrap(),
+                payload_len: 16,
+                max: 32,
+            }),
+            process(&mut pool, 0, 3, true, &[1; 16])
+        );
+
+        // finish the first packet (uses a preallocated buffer)
+        let finished = process(&mut pool, 0, 1, false, &[1; 24]).unwrap().unwrap();
+        assert_eq!(&[1; 32], &finished.payload[..]);
+        assert!(data_ptrs.contains(&finished.payload.as_ptr()));
+
+        // still exhausted until the buffer is returned
+        assert_eq!(
+            Err(IpDefragError::PoolExhausted { max_packets: 2 }),
+            process(&mut pool, 2, 0, true, &[3; 8])
+        );
+        pool.return_buf(finished);
+        assert_eq!(Ok(None), process(&mut pool, 2, 0, true, &[3; 8]));
+
+        // retain frees buffers
+        pool.retain(|_| false);
+        assert_eq!(pool.active.len(), 0);
+        assert_eq!(pool.finished_data_bufs.len(), 2);
+        assert_eq!(pool.finished_section_bufs.len(), 2);
+        for buf in &pool.finished_data_bufs {
+            assert!(data_ptrs.contains(&buf.as_ptr()));
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn process_sliced_packet_borrowed() {
+        let frag_id = IpFragId {
+            outer_vlan_id: Some(VlanId::try_new(1).unwrap()),
+            inner_vlan_id: None,
+            ip: IpFragVersionSpecId::Ipv6 {
+                source: [1; 16],
+                destination: [2; 16],
+                identification: 3,
+            },
+            payload_ip_number: IpNumber::UDP,
+            channel_id: (),
+        };
+        let mut pool = IpDefragPool::<u32, ()>::with_capacity(1, 32);
+        let data_ptr = pool.finished_data_bufs[0].as_ptr();
+        let mut process = |pool: &mut IpDefragPool<u32, ()>, offset, more, payload: &[u8]| {
+            let pdata = build_packet(frag_id.clone(), offset, more, payload);
+            let pslice = SlicedPacket::from_ethernet(&pdata).unwrap();
+            pool.process_sliced_packet_borrowed(&pslice, 0, (), |p| {
+                assert_eq!(data_ptr, p.payload.as_ptr());
+                (p.ip_number, p.fragmented, p.len_source, p.payload.to_vec())
+            })
+        };
+
+        // non fragmented packet
+        {
+            let mut pdata = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1, 2)
+                .write(&mut pdata, &[1, 2, 3, 4])
+                .unwrap();
+            let pslice = SlicedPacket::from_ip(&pdata).unwrap();
+            assert_eq!(
+                Ok(None),
+                pool.process_sliced_packet_borrowed(&pslice, 0, (), |_| unreachable!())
+            );
+        }
+
+        // reconstruct the same packet twice (buffer is re-used)
+        for _ in 0..2 {
+            assert_eq!(Ok(None), process(&mut pool, 1, false, &[2; 8]));
+            assert_eq!(
+                Ok(Some((
+                    IpNumber::UDP,
+                    false,
+                    LenSource::Ipv6HeaderPayloadLen,
+                    [[1; 8], [2; 8]].concat()
+                ))),
+                process(&mut pool, 0, true, &[1; 8])
+            );
+            assert_eq!(1, pool.finished_data_bufs.len());
+        }
+
+        // error
+        assert_eq!(
+            Err(IpDefragError::UnalignedFragmentPayloadLen {
+                offset: IpFragOffset::try_new(0).unwrap(),
+                payload_len: 3,
+            }),
+            process(&mut pool, 0, true, &[1; 3])
+        );
+    }
+
     #[test]
     fn retain() {
         let frag_id_0 = IpFragId {
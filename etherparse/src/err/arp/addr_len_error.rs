@@ -0,0 +1,246 @@
+use crate::err::ErrorKind;
+use crate::{ArpHardwareId, EtherType};
+
+/// Error when the address lengths of an [`crate::ArpPacket`] are not
+/// valid.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AddrLenError {
+    /// Error when a hardware address is longer than 255 bytes (the maximum
+    /// representable in the "hardware address length" field).
+    HwAddrTooLong {
+        /// Length of the hardware address.
+        len: usize,
+    },
+
+    /// Error when a protocol address is longer than 255 bytes (the maximum
+    /// representable in the "protocol address length" field).
+    ProtocolAddrTooLong {
+        /// Length of the protocol address.
+        len: usize,
+    },
+
+    /// Error when the sender & target hardware addresses have different
+    /// lengths.
+    HwAddrLenMismatch {
+        /// Length of the sender hardware address.
+        sender_len: usize,
+        /// Length of the target hardware address.
+        target_len: usize,
+    },
+
+    /// Error when the sender & target protocol addresses have different
+    /// lengths.
+    ProtocolAddrLenMismatch {
+        /// Length of the sender protocol address.
+        sender_len: usize,
+        /// Length of the target protocol address.
+        target_len: usize,
+    },
+
+    /// Error when the length of the hardware addresses does not match the
+    /// fixed address length of the hardware type (see
+    /// [`crate::ArpHardwareId::hardware_address_len`]).
+    HwAddrLenInvalid {
+        /// Hardware type of the packet.
+        hw_addr_type: ArpHardwareId,
+        /// Address length required by the hardware type.
+        expected_len: u8,
+        /// Length of the hardware addresses.
+        actual_len: usize,
+    },
+
+    /// Error when the length of the protocol addresses does not match the
+    /// fixed address length of the protocol type (see
+    /// [`crate::EtherType::protocol_address_len`]).
+    ProtocolAddrLenInvalid {
+        /// Protocol type of the packet.
+        proto_addr_type: EtherType,
+        /// Address length required by the protocol type.
+        expected_len: u8,
+        /// Length of the protocol addresses.
+        actual_len: usize,
+    },
+}
+
+impl AddrLenError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use AddrLenError::*;
+        match self {
+            HwAddrTooLong { .. } => ErrorKind::ContentInvalid,
+            ProtocolAddrTooLong { .. } => ErrorKind::ContentInvalid,
+            HwAddrLenMismatch { .. } => ErrorKind::ContentInvalid,
+            ProtocolAddrLenMismatch { .. } => ErrorKind::ContentInvalid,
+            HwAddrLenInvalid { .. } => ErrorKind::ContentInvalid,
+            ProtocolAddrLenInvalid { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for AddrLenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use AddrLenError::*;
+        match self {
+            HwAddrTooLong { len } => write!(
+                f,
+                "ARP hardware address length of {} bytes is too big (maximum is 255 bytes).",
+                len
+            ),
+            ProtocolAddrTooLong { len } => write!(
+                f,
+                "ARP protocol address length of {} bytes is too big (maximum is 255 bytes).",
+                len
+            ),
+            HwAddrLenMismatch {
+                sender_len,
+                target_len,
+            } => write!(
+                f,
+                "ARP sender hardware address length ({} bytes) differs from the target hardware address length ({} bytes).",
+                sender_len, target_len
+            ),
+            ProtocolAddrLenMismatch {
+                sender_len,
+                target_len,
+            } => write!(
+                f,
+                "ARP sender protocol address length ({} bytes) differs from the target protocol address length ({} bytes).",
+                sender_len, target_len
+            ),
+            HwAddrLenInvalid {
+                hw_addr_type,
+                expected_len,
+                actual_len,
+            } => write!(
+                f,
+                "ARP hardware address length of {} bytes does not match the length of {} bytes required by the hardware type {:?}.",
+                actual_len, expected_len, hw_addr_type
+            ),
+            ProtocolAddrLenInvalid {
+                proto_addr_type,
+                expected_len,
+                actual_len,
+            } => write!(
+                f,
+                "ARP protocol address length of {} bytes does not match the length of {} bytes required by the protocol type {:?}.",
+                actual_len, expected_len, proto_addr_type
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for AddrLenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddrLenError::*;
+    use crate::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "HwAddrTooLong { len: 256 }",
+            format!("{:?}", HwAddrTooLong { len: 256 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HwAddrLenInvalid {
+            hw_addr_type: ArpHardwareId::INFINIBAND,
+            expected_len: 20,
+            actual_len: 6,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "ARP hardware address length of 256 bytes is too big (maximum is 255 bytes).",
+            format!("{}", HwAddrTooLong { len: 256 })
+        );
+        assert_eq!(
+            "ARP protocol address length of 300 bytes is too big (maximum is 255 bytes).",
+            format!("{}", ProtocolAddrTooLong { len: 300 })
+        );
+        assert_eq!(
+            "ARP sender hardware address length (6 bytes) differs from the target hardware address length (7 bytes).",
+            format!(
+                "{}",
+                HwAddrLenMismatch {
+                    sender_len: 6,
+                    target_len: 7
+                }
+            )
+        );
+        assert_eq!(
+            "ARP sender protocol address length (4 bytes) differs from the target protocol address length (16 bytes).",
+            format!(
+                "{}",
+                ProtocolAddrLenMismatch {
+                    sender_len: 4,
+                    target_len: 16
+                }
+            )
+        );
+        assert_eq!(
+            format!(
+                "ARP hardware address length of 6 bytes does not match the length of 20 bytes required by the hardware type {:?}.",
+                ArpHardwareId::INFINIBAND
+            ),
+            format!(
+                "{}",
+                HwAddrLenInvalid {
+                    hw_addr_type: ArpHardwareId::INFINIBAND,
+                    expected_len: 20,
+                    actual_len: 6
+                }
+            )
+        );
+        assert_eq!(
+            format!(
+                "ARP protocol address length of 4 bytes does not match the length of 16 bytes required by the protocol type {:?}.",
+                EtherType::IPV6
+            ),
+            format!(
+                "{}",
+                ProtocolAddrLenInvalid {
+                    proto_addr_type: EtherType::IPV6,
+                    expected_len: 16,
+                    actual_len: 4
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(HwAddrTooLong { len: 256 }.source().is_none());
+    }
+}
@@ -0,0 +1,7 @@
+mod addr_len_error;
+pub use addr_len_error::*;
+
+#[cfg(feature = "std")]
+mod packet_write_error;
+#[cfg(feature = "std")]
+pub use packet_write_error::*;
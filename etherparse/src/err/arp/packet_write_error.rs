@@ -0,0 +1,138 @@
+#[cfg(feature = "std")]
+use super::AddrLenError;
+#[cfg(feature = "std")]
+use crate::err::ErrorKind;
+
+/// Error when writing an [`crate::ArpPacket`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PacketWriteError {
+    /// IO error encountered while writing.
+    Io(std::io::Error),
+    /// Data was not serializable because of its content.
+    Content(AddrLenError),
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl PacketWriteError {
+    /// Returns a reference to the [`std::io::Error`] if the value is an [`PacketWriteError::Io`].
+    pub fn io(&self) -> Option<&std::io::Error> {
+        match self {
+            PacketWriteError::Io(err) => Some(err),
+            PacketWriteError::Content(_) => None,
+        }
+    }
+
+    /// Returns a reference to the [`crate::err::arp::AddrLenError`] if the value is an [`PacketWriteError::Content`].
+    pub fn content(&self) -> Option<&AddrLenError> {
+        match self {
+            PacketWriteError::Io(_) => None,
+            PacketWriteError::Content(err) => Some(err),
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use PacketWriteError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Content(err) => err.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl core::fmt::Display for PacketWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use PacketWriteError::*;
+        match self {
+            Io(err) => err.fmt(f),
+            Content(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for PacketWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PacketWriteError::*;
+        match self {
+            Io(ref err) => Some(err),
+            Content(ref err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddrLenError::*, PacketWriteError::*};
+    use crate::*;
+    use alloc::format;
+    use std::error::Error;
+
+    #[test]
+    fn io() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .io()
+        .is_some());
+        assert!(Content(HwAddrTooLong { len: 256 }).io().is_none());
+    }
+
+    #[test]
+    fn content() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .content()
+        .is_none());
+        {
+            let err = HwAddrTooLong { len: 256 };
+            assert_eq!(Some(&err), Content(err.clone()).content());
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let err = HwAddrTooLong { len: 256 };
+        assert_eq!(
+            format!("Content({:?})", err.clone()),
+            format!("{:?}", Content(err))
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        {
+            let err = std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            );
+            assert_eq!(format!("{}", err), format!("{}", Io(err)));
+        }
+        {
+            let err = HwAddrTooLong { len: 256 };
+            assert_eq!(format!("{}", Content(err.clone())), format!("{}", err));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .source()
+        .is_some());
+        assert!(Content(HwAddrTooLong { len: 256 }).source().is_some());
+    }
+}
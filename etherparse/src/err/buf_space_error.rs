@@ -0,0 +1,106 @@
+use crate::err::ErrorKind;
+
+/// Error when not enough space is left in a [`crate::PacketBuf`]
+/// to append data to it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BufSpaceError {
+    /// Number of bytes that were required.
+    pub required_len: usize,
+
+    /// Number of bytes that were still available in the buffer.
+    pub available_len: usize,
+}
+
+impl BufSpaceError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Len
+    }
+}
+
+impl core::fmt::Display for BufSpaceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Not enough space left in packet buffer. Needed {} byte(s), but only {} byte(s) were available.",
+            self.required_len, self.available_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for BufSpaceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "BufSpaceError { required_len: 2, available_len: 1 }",
+            format!(
+                "{:?}",
+                BufSpaceError {
+                    required_len: 2,
+                    available_len: 1
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = BufSpaceError {
+            required_len: 2,
+            available_len: 1,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Not enough space left in packet buffer. Needed 2 byte(s), but only 1 byte(s) were available.",
+            format!(
+                "{}",
+                BufSpaceError {
+                    required_len: 2,
+                    available_len: 1
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        let err = BufSpaceError {
+            required_len: 2,
+            available_len: 1,
+        };
+        assert!(err.source().is_none());
+    }
+}
@@ -0,0 +1,5 @@
+mod name_error;
+pub use name_error::*;
+
+mod name_slice_error;
+pub use name_slice_error::*;
@@ -0,0 +1,122 @@
+use crate::err::ErrorKind;
+
+/// Errors in a DNS name encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum NameError {
+    /// Error when a label starts with one of the reserved or
+    /// deprecated label types (upper two bits `0b01` or `0b10`).
+    UnsupportedLabelType {
+        /// Upper two bits of the label length byte.
+        label_type: u8,
+    },
+    /// Error when more compression pointers are encountered then
+    /// allowed (e.g. caused by a pointer loop).
+    PointerLimitExceeded {
+        /// Maximum number of pointers that are followed.
+        limit: usize,
+    },
+    /// Error when the decoded name is longer then the maximum of 255
+    /// bytes (in uncompressed wire format).
+    NameTooLong {
+        /// Length of the name (in uncompressed wire format) at the point
+        /// the error was detected.
+        len: usize,
+    },
+}
+
+impl NameError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::ContentInvalid
+    }
+}
+
+impl core::fmt::Display for NameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use NameError::*;
+        match self {
+            UnsupportedLabelType { label_type } => write!(
+                f,
+                "DNS Name Error: Encountered unsupported label type {:#04b}.",
+                label_type
+            ),
+            PointerLimitExceeded { limit } => write!(
+                f,
+                "DNS Name Error: Encountered more then the allowed {} compression pointers.",
+                limit
+            ),
+            NameTooLong { len } => write!(
+                f,
+                "DNS Name Error: Name length of {} bytes exceeds the maximum of 255 bytes.",
+                len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for NameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NameError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnsupportedLabelType { label_type: 1 }",
+            format!("{:?}", UnsupportedLabelType { label_type: 1 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = PointerLimitExceeded { limit: 16 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "DNS Name Error: Encountered unsupported label type 0b10.",
+            format!("{}", UnsupportedLabelType { label_type: 2 })
+        );
+        assert_eq!(
+            "DNS Name Error: Encountered more then the allowed 16 compression pointers.",
+            format!("{}", PointerLimitExceeded { limit: 16 })
+        );
+        assert_eq!(
+            "DNS Name Error: Name length of 256 bytes exceeds the maximum of 255 bytes.",
+            format!("{}", NameTooLong { len: 256 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(NameTooLong { len: 256 }.source().is_none());
+    }
+}
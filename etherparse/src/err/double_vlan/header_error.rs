@@ -1,7 +1,9 @@
+use crate::err::ErrorKind;
 use crate::EtherType;
 
 /// Errors in an double vlan header encountered while decoding it.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error when two vlan header were expected but the ether_type
     /// value of the first vlan header is not an vlan header type.
@@ -10,6 +12,26 @@ pub enum HeaderError {
         /// header.
         unexpected_ether_type: EtherType,
     },
+
+    /// Error when an IEEE 802.1ad conform double vlan header was expected
+    /// (strict mode) but the service VLAN tag (outer tag) is not followed
+    /// by a customer VLAN tag ([`crate::EtherType::VLAN_TAGGED_FRAME`]).
+    NonCustomerTagEtherType {
+        /// Ether type encountered in the outer vlan header instead of
+        /// [`crate::EtherType::VLAN_TAGGED_FRAME`].
+        unexpected_ether_type: EtherType,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            NonVlanEtherType { .. } => ErrorKind::ContentInvalid,
+            NonCustomerTagEtherType { .. } => ErrorKind::ContentInvalid,
+        }
+    }
 }
 
 impl core::fmt::Display for HeaderError {
@@ -17,6 +39,7 @@ impl core::fmt::Display for HeaderError {
         use HeaderError::*;
         match self {
             NonVlanEtherType { unexpected_ether_type } => write!(f, "Double VLAN Error: Expected two VLAN headers but the outer VLAN header is followed by a non-VLAN header of ether type {:?}.", unexpected_ether_type),
+            NonCustomerTagEtherType { unexpected_ether_type } => write!(f, "Double VLAN Error: Expected an IEEE 802.1ad customer VLAN tag (C-tag) after the service VLAN tag (S-tag) but the outer VLAN header is followed by ether type {:?}.", unexpected_ether_type),
         }
     }
 }
@@ -81,6 +104,10 @@ mod tests {
             "Double VLAN Error: Expected two VLAN headers but the outer VLAN header is followed by a non-VLAN header of ether type 0x0001.",
             format!("{}", NonVlanEtherType{ unexpected_ether_type: 1.into() })
         );
+        assert_eq!(
+            "Double VLAN Error: Expected an IEEE 802.1ad customer VLAN tag (C-tag) after the service VLAN tag (S-tag) but the outer VLAN header is followed by ether type 0x88A8 (IEEE Std 802.1Q - Service VLAN tag identifier (S-Tag)).",
+            format!("{}", NonCustomerTagEtherType{ unexpected_ether_type: EtherType::PROVIDER_BRIDGING })
+        );
     }
 
     #[cfg(feature = "std")]
@@ -1,4 +1,6 @@
 use super::HeaderError;
+#[cfg(feature = "std")]
+use crate::err::ErrorKind;
 
 /// Error when decoding two VLAN headers via a `std::io::Read` source.
 ///
@@ -6,6 +8,7 @@ use super::HeaderError;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HeaderReadError {
     /// IO error was encountered while reading header.
     Io(std::io::Error),
@@ -38,6 +41,15 @@ impl HeaderReadError {
             _ => None,
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderReadError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1,8 +1,9 @@
 use super::HeaderError;
-use crate::err::LenError;
+use crate::err::{ErrorKind, LenError};
 
 /// Error when decoding a double VLAN header from a slice.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderSliceError {
     /// Error when an length error is encountered (e.g. unexpected
     /// end of slice).
@@ -22,6 +23,24 @@ impl HeaderSliceError {
             Content(err) => Content(err),
         }
     }
+
+    /// Returns the [`crate::err::LenError`] if the error is a length error.
+    pub fn len_error(&self) -> Option<&LenError> {
+        use HeaderSliceError::*;
+        match self {
+            Len(err) => Some(err),
+            Content(_) => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderSliceError::*;
+        match self {
+            Len(err) => err.kind(),
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for HeaderSliceError {
@@ -87,6 +106,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn len_error() {
+        let err = LenError {
+            required_len: 1,
+            layer: Layer::VlanHeader,
+            len: 2,
+            len_source: LenSource::Slice,
+            layer_start_offset: 3,
+        };
+        assert_eq!(Some(&err), Len(err.clone()).len_error());
+        assert_eq!(
+            None,
+            Content(HeaderError::NonVlanEtherType {
+                unexpected_ether_type: 1.into()
+            })
+            .len_error()
+        );
+    }
+
     #[test]
     fn debug() {
         let err = HeaderError::NonVlanEtherType {
@@ -0,0 +1,1100 @@
+/// Coarse category of an error (returned by the `kind` method of the
+/// error types in [`crate::err`]).
+///
+/// In contrast to the error types themselves the categories are not
+/// extended with every new supported header. This makes them suited for
+/// mapping errors to metrics or log levels without matching on every
+/// variant of the error types:
+///
+/// ```
+/// use etherparse::{err::ErrorKind, SlicedPacket};
+///
+/// // not enough data for an IPv4 header
+/// let err = SlicedPacket::from_ip(&[0x45, 0, 0]).unwrap_err();
+/// assert_eq!(ErrorKind::Len, err.kind());
+/// assert_eq!("len", err.kind().as_str());
+///
+/// // IP version 7 is not supported
+/// let err = SlicedPacket::from_ip(&[0x70, 0, 0]).unwrap_err();
+/// assert_eq!(ErrorKind::UnsupportedVersion, err.kind());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Error caused by conflicting lengths (e.g. not enough data in a
+    /// slice or not enough space in a buffer).
+    Len,
+    /// Error caused by a version field containing a version that is
+    /// not supported (e.g. an IP version other than 4 or 6).
+    UnsupportedVersion,
+    /// Error caused by invalid content (e.g. a header field containing
+    /// a disallowed value, a header in a disallowed position or an
+    /// exceeded parse limit).
+    ContentInvalid,
+    /// Error caused by an IO error (`std::io::Error`).
+    Io,
+}
+
+impl ErrorKind {
+    /// Returns a short lowercase identifier of the category (e.g. to
+    /// be used as a metric label).
+    pub const fn as_str(&self) -> &'static str {
+        use ErrorKind::*;
+        match self {
+            Len => "len",
+            UnsupportedVersion => "unsupported_version",
+            ContentInvalid => "content_invalid",
+            Io => "io",
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ErrorKind::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("ContentInvalid", format!("{:?}", ContentInvalid));
+    }
+
+    #[test]
+    fn clone_eq_hash_ord() {
+        let kind = UnsupportedVersion;
+        assert_eq!(kind, kind.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            kind.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            kind.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+        assert!(Len < Io);
+    }
+
+    #[test]
+    fn as_str_fmt() {
+        let tests = [
+            (Len, "len"),
+            (UnsupportedVersion, "unsupported_version"),
+            (ContentInvalid, "content_invalid"),
+            (Io, "io"),
+        ];
+        for (kind, expected) in tests {
+            assert_eq!(expected, kind.as_str());
+            assert_eq!(expected, format!("{}", kind));
+        }
+    }
+
+    #[test]
+    fn kinds() {
+        use crate::*;
+
+        let len_error = || err::LenError {
+            required_len: 2,
+            len: 1,
+            len_source: LenSource::Slice,
+            layer: err::Layer::Ipv4Header,
+            layer_start_offset: 0,
+        };
+        #[cfg(feature = "std")]
+        let io_error = || std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "failed");
+
+        // expected kind & the kind returned by the different error types
+        let mut tests = alloc::vec![
+            (
+                Len,
+                err::BufSpaceError {
+                    required_len: 2,
+                    available_len: 1
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::dns::NameError::UnsupportedLabelType { label_type: 1 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::dns::NameError::PointerLimitExceeded { limit: 16 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::dns::NameError::NameTooLong { len: 256 }.kind()
+            ),
+            (Len, err::dns::NameSliceError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::dns::NameSliceError::Content(err::dns::NameError::NameTooLong { len: 256 })
+                    .kind()
+            ),
+            (
+                ContentInvalid,
+                err::double_vlan::HeaderError::NonVlanEtherType {
+                    unexpected_ether_type: EtherType::IPV4
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::double_vlan::HeaderError::NonCustomerTagEtherType {
+                    unexpected_ether_type: EtherType::IPV4
+                }
+                .kind()
+            ),
+            (
+                Len,
+                err::double_vlan::HeaderSliceError::Len(len_error()).kind()
+            ),
+            (
+                ContentInvalid,
+                err::double_vlan::HeaderSliceError::Content(
+                    err::double_vlan::HeaderError::NonVlanEtherType {
+                        unexpected_ether_type: EtherType::IPV4
+                    }
+                )
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::erspan::HeaderError::UnsupportedVersion { version_number: 1 }.kind()
+            ),
+            (Len, err::erspan::HeaderSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::erspan::HeaderSliceError::Content(
+                    err::erspan::HeaderError::UnsupportedVersion { version_number: 1 }
+                )
+                .kind()
+            ),
+            (Len, err::FromSliceError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::FromSliceError::LinuxSll(
+                    err::linux_sll::HeaderError::UnsupportedPacketTypeField { packet_type: 1 }
+                )
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::FromSliceError::DoubleVlan(err::double_vlan::HeaderError::NonVlanEtherType {
+                    unexpected_ether_type: EtherType::IPV4
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::FromSliceError::Ip(err::ip::HeaderError::UnsupportedIpVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::FromSliceError::IpAuth(err::ip_auth::HeaderError::ZeroPayloadLen).kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::FromSliceError::Ipv4(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::FromSliceError::Ipv6(err::ipv6::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::FromSliceError::Ipv6Exts(err::ipv6_exts::HeaderError::HopByHopNotAtStart)
+                    .kind()
+            ),
+            (
+                ContentInvalid,
+                err::FromSliceError::Tcp(err::tcp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::FromSliceError::ParseLimit(err::ParseLimitError::MaxLayers {
+                    max_layers: 1,
+                    layer: err::Layer::Ipv4Header
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::FromSliceError::TagOrder(err::TagOrderError::MacsecNotOutermost).kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::FromSliceError::Erspan(err::erspan::HeaderError::UnsupportedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::FromSliceError::Tzsp(err::tzsp::HeaderError::UnsupportedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::ieee80211::HeaderError::UnsupportedProtocolVersion { version: 1 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ieee80211::HeaderError::NotDataFrame { frame_type: 1 }.kind()
+            ),
+            (
+                Len,
+                err::ieee80211::HeaderSliceError::Len(len_error()).kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::ieee80211::HeaderSliceError::Content(
+                    err::ieee80211::HeaderError::UnsupportedProtocolVersion { version: 1 }
+                )
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::ip::HeaderError::UnsupportedIpVersion { version_number: 1 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip::HeaderError::Ipv4HeaderLengthSmallerThanHeader { ihl: 1 }.kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::ip::HeadersError::Ip(err::ip::HeaderError::UnsupportedIpVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip::HeadersError::Ipv4Ext(err::ip_auth::HeaderError::ZeroPayloadLen).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip::HeadersError::Ipv6Ext(err::ipv6_exts::HeaderError::HopByHopNotAtStart)
+                    .kind()
+            ),
+            (Len, err::ip::HeadersSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ip::HeadersSliceError::Content(err::ip::HeadersError::Ip(
+                    err::ip::HeaderError::UnsupportedIpVersion { version_number: 1 }
+                ))
+                .kind()
+            ),
+            (Len, err::ip::LaxHeaderSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ip::LaxHeaderSliceError::Content(err::ip::HeaderError::UnsupportedIpVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (Len, err::ip::SliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ip::SliceError::IpHeaders(err::ip::HeadersError::Ip(
+                    err::ip::HeaderError::UnsupportedIpVersion { version_number: 1 }
+                ))
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip_auth::HeaderError::ZeroPayloadLen.kind()
+            ),
+            (Len, err::ip_auth::HeaderSliceError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::ip_auth::HeaderSliceError::Content(err::ip_auth::HeaderError::ZeroPayloadLen)
+                    .kind()
+            ),
+            (ContentInvalid, err::ip_auth::IcvLenError::TooBig(1).kind()),
+            (
+                ContentInvalid,
+                err::ip_auth::IcvLenError::Unaligned(1).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip_exts::ExtsWalkError::Ipv4Exts(
+                    err::ipv4_exts::ExtsWalkError::ExtNotReferenced {
+                        missing_ext: IpNumber::UDP
+                    }
+                )
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip_exts::ExtsWalkError::Ipv6Exts(
+                    err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart
+                )
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip_exts::HeaderError::Ipv4Ext(err::ip_auth::HeaderError::ZeroPayloadLen)
+                    .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip_exts::HeaderError::Ipv6Ext(err::ipv6_exts::HeaderError::HopByHopNotAtStart)
+                    .kind()
+            ),
+            (
+                Len,
+                err::ip_exts::HeadersSliceError::Len(len_error()).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ip_exts::HeadersSliceError::Content(err::ip_exts::HeaderError::Ipv4Ext(
+                    err::ip_auth::HeaderError::ZeroPayloadLen
+                ))
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv4::BadOptionsLen { bad_len: 1 }.kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::ipv4::HeaderError::UnexpectedVersion { version_number: 1 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv4::HeaderError::HeaderLengthSmallerThanHeader { ihl: 1 }.kind()
+            ),
+            (Len, err::ipv4::HeaderSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ipv4::HeaderSliceError::Content(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (Len, err::ipv4::SliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ipv4::SliceError::Header(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv4::SliceError::Exts(err::ip_auth::HeaderError::ZeroPayloadLen).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv4_exts::ExtsWalkError::ExtNotReferenced {
+                    missing_ext: IpNumber::UDP
+                }
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::ipv6::HeaderError::UnexpectedVersion { version_number: 1 }.kind()
+            ),
+            (Len, err::ipv6::HeaderSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ipv6::HeaderSliceError::Content(err::ipv6::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (Len, err::ipv6::SliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ipv6::SliceError::Header(err::ipv6::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6::SliceError::Exts(err::ipv6_exts::HeaderError::HopByHopNotAtStart).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtPayloadLenError::TooSmall(1).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtPayloadLenError::TooBig(1).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtPayloadLenError::Unaligned(1).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtsBuildError::ExtOutOfOrder {
+                    ext: IpNumber::UDP,
+                    previous_ext: IpNumber::UDP
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtsBuildError::ExtDuplicate { ext: IpNumber::UDP }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtsBuildError::FinalDestOptionsWithoutRouting.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::ExtsWalkError::ExtNotReferenced {
+                    missing_ext: IpNumber::UDP
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::HeaderError::HopByHopNotAtStart.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::HeaderError::IpAuth(err::ip_auth::HeaderError::ZeroPayloadLen)
+                    .kind()
+            ),
+            (
+                Len,
+                err::ipv6_exts::HeaderSliceError::Len(len_error()).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::HeaderSliceError::Content(
+                    err::ipv6_exts::HeaderError::HopByHopNotAtStart
+                )
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_UPDATE,
+                    header_len: 1,
+                    min_header_len: 1
+                }
+                .kind()
+            ),
+            (
+                Len,
+                err::ipv6_mobility::HeaderSliceError::Len(len_error()).kind()
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_mobility::HeaderSliceError::Content(
+                    err::ipv6_mobility::HeaderError::MessageTooShort {
+                        mh_type: MobilityHeaderType::BINDING_UPDATE,
+                        header_len: 1,
+                        min_header_len: 1
+                    }
+                )
+                .kind()
+            ),
+            (Len, len_error().kind()),
+            (
+                ContentInvalid,
+                err::linux_sll::HeaderError::UnsupportedPacketTypeField { packet_type: 1 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::linux_sll::HeaderError::UnsupportedArpHardwareId {
+                    arp_hardware_type: ArpHardwareId::ETHER
+                }
+                .kind()
+            ),
+            (
+                Len,
+                err::linux_sll::HeaderSliceError::Len(len_error()).kind()
+            ),
+            (
+                ContentInvalid,
+                err::linux_sll::HeaderSliceError::Content(
+                    err::linux_sll::HeaderError::UnsupportedPacketTypeField { packet_type: 1 }
+                )
+                .kind()
+            ),
+            (
+                Len,
+                err::packet::HeadersBufError::BufSpace(err::BufSpaceError {
+                    required_len: 2,
+                    available_len: 1
+                })
+                .kind()
+            ),
+            (
+                Len,
+                err::packet::HeadersBufError::Slice(err::packet::SliceError::Len(len_error()))
+                    .kind()
+            ),
+            (
+                Len,
+                err::packet::LocatedSliceError {
+                    error: err::packet::SliceError::Len(len_error()),
+                    layer_start_offset: 14,
+                    parsed_layers: Default::default(),
+                    parsed_layer_count: 0
+                }
+                .kind()
+            ),
+            (Len, err::packet::SliceError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::packet::SliceError::LinuxSll(
+                    err::linux_sll::HeaderError::UnsupportedPacketTypeField { packet_type: 1 }
+                )
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::packet::SliceError::Ip(err::ip::HeaderError::UnsupportedIpVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::packet::SliceError::Ipv4(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::packet::SliceError::Ipv6(err::ipv6::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::SliceError::Ipv4Exts(err::ip_auth::HeaderError::ZeroPayloadLen).kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::SliceError::Ipv6Exts(err::ipv6_exts::HeaderError::HopByHopNotAtStart)
+                    .kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::SliceError::Tcp(err::tcp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::SliceError::ParseLimit(err::ParseLimitError::MaxLayers {
+                    max_layers: 1,
+                    layer: err::Layer::Ipv4Header
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::SliceError::TagOrder(err::TagOrderError::MacsecNotOutermost).kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::TransportChecksumError::PayloadLen(err::ValueTooBigError {
+                    actual: 2,
+                    max_allowed: 1,
+                    value_type: err::ValueType::Ipv4PayloadLength
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::packet::TransportChecksumError::Icmpv6InIpv4.kind()
+            ),
+            (
+                ContentInvalid,
+                err::ParseLimitError::MaxLayers {
+                    max_layers: 1,
+                    layer: err::Layer::Ipv4Header
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ParseLimitError::MaxHeaderLen {
+                    max_header_len: 1,
+                    header_len: 1,
+                    layer: err::Layer::Ipv4Header
+                }
+                .kind()
+            ),
+            (
+                Len,
+                err::SliceWriteSpaceError {
+                    required_len: 2,
+                    len: 1,
+                    layer: err::Layer::Ipv4Header,
+                    layer_start_offset: 0
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::someip::HeaderError::LengthTooSmall {
+                    message_type: SomeipMessageType::REQUEST,
+                    length: 7
+                }
+                .kind()
+            ),
+            (Len, err::someip::HeaderSliceError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::someip::HeaderSliceError::Content(err::someip::HeaderError::LengthTooSmall {
+                    message_type: SomeipMessageType::REQUEST,
+                    length: 0,
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::TagOrderError::MacsecNotOutermost.kind()
+            ),
+            (
+                ContentInvalid,
+                err::TagOrderError::CTagNotInnermost {
+                    next_ether_type: EtherType::IPV4
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::TagOrderError::STagNotOutermost {
+                    ether_type: EtherType::IPV4
+                }
+                .kind()
+            ),
+            (ContentInvalid, err::TagOrderError::TooManyVlanTags.kind()),
+            (
+                ContentInvalid,
+                err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }.kind()
+            ),
+            (Len, err::tcp::HeaderSliceError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::tcp::HeaderSliceError::Content(err::tcp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 1
+                })
+                .kind()
+            ),
+            (
+                UnsupportedVersion,
+                err::tzsp::HeaderError::UnsupportedVersion { version_number: 1 }.kind()
+            ),
+            (Len, err::tzsp::HeaderSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::tzsp::HeaderSliceError::Content(err::tzsp::HeaderError::UnsupportedVersion {
+                    version_number: 1
+                })
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ValueParseError {
+                    value_type: err::ValueType::EtherType
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::ValueTooBigError {
+                    actual: 3,
+                    max_allowed: 2,
+                    value_type: err::ValueType::IpFragmentOffset
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::wireguard::HeaderError::UnknownMessageType { message_type: 1 }.kind()
+            ),
+            (
+                ContentInvalid,
+                err::wireguard::HeaderError::NonZeroReserved {
+                    reserved: [1, 2, 3]
+                }
+                .kind()
+            ),
+            (
+                ContentInvalid,
+                err::wireguard::HeaderError::UnexpectedLen {
+                    message_type: WireguardMessageType::HandshakeInitiation,
+                    len: 1
+                }
+                .kind()
+            ),
+            (
+                Len,
+                err::wireguard::HeaderSliceError::Len(len_error()).kind()
+            ),
+            (
+                ContentInvalid,
+                err::wireguard::HeaderSliceError::Content(
+                    err::wireguard::HeaderError::UnknownMessageType { message_type: 1 }
+                )
+                .kind()
+            ),
+        ];
+        #[cfg(feature = "std")]
+        tests.extend([
+            (Io, err::double_vlan::HeaderReadError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::double_vlan::HeaderReadError::Content(
+                    err::double_vlan::HeaderError::NonVlanEtherType {
+                        unexpected_ether_type: EtherType::IPV4,
+                    },
+                )
+                .kind(),
+            ),
+            (Io, err::io::LimitedReadError::Io(io_error()).kind()),
+            (Len, err::io::LimitedReadError::Len(len_error()).kind()),
+            (Io, err::ip::HeaderReadError::Io(io_error()).kind()),
+            (Len, err::ip::HeaderReadError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ip::HeaderReadError::Content(err::ip::HeadersError::Ip(
+                    err::ip::HeaderError::UnsupportedIpVersion { version_number: 1 },
+                ))
+                .kind(),
+            ),
+            (Io, err::ip::HeadersWriteError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::ip::HeadersWriteError::Ipv4Exts(
+                    err::ipv4_exts::ExtsWalkError::ExtNotReferenced {
+                        missing_ext: IpNumber::UDP,
+                    },
+                )
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ip::HeadersWriteError::Ipv6Exts(
+                    err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart,
+                )
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ip::NetworkParseError::MissingPrefixLen.kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ip::NetworkParseError::InvalidAddr.kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ip::NetworkParseError::InvalidPrefixLen.kind(),
+            ),
+            (
+                Io,
+                err::ip_auth::HeaderLimitedReadError::Io(io_error()).kind(),
+            ),
+            (
+                Len,
+                err::ip_auth::HeaderLimitedReadError::Len(len_error()).kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ip_auth::HeaderLimitedReadError::Content(
+                    err::ip_auth::HeaderError::ZeroPayloadLen,
+                )
+                .kind(),
+            ),
+            (Io, err::ip_auth::HeaderReadError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::ip_auth::HeaderReadError::Content(err::ip_auth::HeaderError::ZeroPayloadLen)
+                    .kind(),
+            ),
+            (Io, err::ipv4::HeaderReadError::Io(io_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ipv4::HeaderReadError::Content(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 1,
+                })
+                .kind(),
+            ),
+            (Io, err::ipv4_exts::HeaderWriteError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::ipv4_exts::HeaderWriteError::Content(
+                    err::ipv4_exts::ExtsWalkError::ExtNotReferenced {
+                        missing_ext: IpNumber::UDP,
+                    },
+                )
+                .kind(),
+            ),
+            (Io, err::ipv6::HeaderReadError::Io(io_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::ipv6::HeaderReadError::Content(err::ipv6::HeaderError::UnexpectedVersion {
+                    version_number: 1,
+                })
+                .kind(),
+            ),
+            (
+                Io,
+                err::ipv6_exts::HeaderLimitedReadError::Io(io_error()).kind(),
+            ),
+            (
+                Len,
+                err::ipv6_exts::HeaderLimitedReadError::Len(len_error()).kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ipv6_exts::HeaderLimitedReadError::Content(
+                    err::ipv6_exts::HeaderError::HopByHopNotAtStart,
+                )
+                .kind(),
+            ),
+            (Io, err::ipv6_exts::HeaderReadError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::ipv6_exts::HeaderReadError::Content(
+                    err::ipv6_exts::HeaderError::HopByHopNotAtStart,
+                )
+                .kind(),
+            ),
+            (Io, err::ipv6_exts::HeaderWriteError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::ipv6_exts::HeaderWriteError::Content(
+                    err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart,
+                )
+                .kind(),
+            ),
+            (Io, err::linux_sll::HeaderReadError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::linux_sll::HeaderReadError::Content(
+                    err::linux_sll::HeaderError::UnsupportedPacketTypeField { packet_type: 1 },
+                )
+                .kind(),
+            ),
+            (Io, err::lldp::TlvWriteError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::lldp::TlvWriteError::Content(err::ValueTooBigError {
+                    actual: 512,
+                    max_allowed: 511,
+                    value_type: err::ValueType::LldpTlvValueLength,
+                })
+                .kind(),
+            ),
+            (Io, err::packet::BuildWriteError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::packet::BuildWriteError::PayloadLen(err::ValueTooBigError {
+                    actual: 2,
+                    max_allowed: 1,
+                    value_type: err::ValueType::Ipv4PayloadLength,
+                })
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::packet::BuildWriteError::Ipv4Exts(
+                    err::ipv4_exts::ExtsWalkError::ExtNotReferenced {
+                        missing_ext: IpNumber::UDP,
+                    },
+                )
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::packet::BuildWriteError::Ipv6Exts(
+                    err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart,
+                )
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::packet::BuildWriteError::Icmpv6InIpv4.kind(),
+            ),
+            (
+                Len,
+                err::packet::BuildWriteError::BufSpace(err::BufSpaceError {
+                    required_len: 2,
+                    available_len: 1,
+                })
+                .kind(),
+            ),
+            (Io, err::ReadError::Io(io_error()).kind()),
+            (Len, err::ReadError::Len(len_error()).kind()),
+            (
+                ContentInvalid,
+                err::ReadError::DoubleVlan(err::double_vlan::HeaderError::NonVlanEtherType {
+                    unexpected_ether_type: EtherType::IPV4,
+                })
+                .kind(),
+            ),
+            (
+                UnsupportedVersion,
+                err::ReadError::Ip(err::ip::HeaderError::UnsupportedIpVersion {
+                    version_number: 1,
+                })
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ReadError::IpAuth(err::ip_auth::HeaderError::ZeroPayloadLen).kind(),
+            ),
+            (
+                UnsupportedVersion,
+                err::ReadError::Ipv4(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 1,
+                })
+                .kind(),
+            ),
+            (
+                UnsupportedVersion,
+                err::ReadError::Ipv6(err::ipv6::HeaderError::UnexpectedVersion {
+                    version_number: 1,
+                })
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ReadError::Ipv6Exts(err::ipv6_exts::HeaderError::HopByHopNotAtStart).kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ReadError::LinuxSll(err::linux_sll::HeaderError::UnsupportedPacketTypeField {
+                    packet_type: 1,
+                })
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ReadError::Tcp(err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 })
+                    .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ReadError::ParseLimit(err::ParseLimitError::MaxLayers {
+                    max_layers: 1,
+                    layer: err::Layer::Ipv4Header,
+                })
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::ReadError::TagOrder(err::TagOrderError::MacsecNotOutermost).kind(),
+            ),
+            (Io, err::tcp::HeaderReadError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::tcp::HeaderReadError::Content(err::tcp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 1,
+                })
+                .kind(),
+            ),
+        ]);
+        #[cfg(feature = "arp")]
+        tests.extend([
+            (
+                ContentInvalid,
+                err::arp::AddrLenError::HwAddrTooLong { len: 256 }.kind(),
+            ),
+            (
+                ContentInvalid,
+                err::arp::AddrLenError::ProtocolAddrLenMismatch {
+                    sender_len: 4,
+                    target_len: 16,
+                }
+                .kind(),
+            ),
+            (
+                ContentInvalid,
+                err::arp::AddrLenError::ProtocolAddrLenInvalid {
+                    proto_addr_type: EtherType::IPV6,
+                    expected_len: 16,
+                    actual_len: 4,
+                }
+                .kind(),
+            ),
+        ]);
+        #[cfg(all(feature = "arp", feature = "std"))]
+        tests.extend([
+            (Io, err::arp::PacketWriteError::Io(io_error()).kind()),
+            (
+                ContentInvalid,
+                err::arp::PacketWriteError::Content(err::arp::AddrLenError::HwAddrTooLong {
+                    len: 256,
+                })
+                .kind(),
+            ),
+        ]);
+        #[cfg(feature = "macsec")]
+        tests.extend([
+            (
+                UnsupportedVersion,
+                err::macsec::HeaderError::UnexpectedVersion { version: 1 }.kind(),
+            ),
+            (Len, err::macsec::HeaderSliceError::Len(len_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::macsec::HeaderSliceError::Content(
+                    err::macsec::HeaderError::UnexpectedVersion { version: 1 },
+                )
+                .kind(),
+            ),
+        ]);
+        #[cfg(all(feature = "macsec", feature = "std"))]
+        tests.extend([
+            (Io, err::macsec::HeaderReadError::Io(io_error()).kind()),
+            (
+                UnsupportedVersion,
+                err::macsec::HeaderReadError::Content(
+                    err::macsec::HeaderError::UnexpectedVersion { version: 1 },
+                )
+                .kind(),
+            ),
+        ]);
+        for (expected, actual) in tests {
+            assert_eq!(expected, actual);
+        }
+    }
+}
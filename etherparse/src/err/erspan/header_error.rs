@@ -0,0 +1,90 @@
+use crate::err::ErrorKind;
+
+/// Errors in an ERSPAN header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the version field of the ERSPAN header is neither
+    /// `1` (Type II) nor `2` (Type III).
+    UnsupportedVersion {
+        /// The unexpected version number in the ERSPAN header.
+        version_number: u8,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnsupportedVersion { .. } => ErrorKind::UnsupportedVersion,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnsupportedVersion { version_number } => write!(f, "ERSPAN Header Error: Encountered '{}' as version number, but only '1' (Type II) & '2' (Type III) are supported.", version_number),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnsupportedVersion { version_number: 6 }",
+            format!("{:?}", UnsupportedVersion { version_number: 6 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HeaderError::UnsupportedVersion { version_number: 6 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "ERSPAN Header Error: Encountered '6' as version number, but only '1' (Type II) & '2' (Type III) are supported.",
+            format!("{}", UnsupportedVersion{ version_number: 6 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnsupportedVersion { version_number: 6 }.source().is_none());
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::err::ErrorKind;
 
 /// "Catch all" error for all `from_slice` errors (supports automatic conversion from all
 /// other slice errors).
@@ -8,6 +9,7 @@ use super::*;
 /// This type can be used as a "catch all" type for errors caused by `from_slice` functions
 /// as all errors from these functions can be converted into this type.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum FromSliceError {
     /// Error when parsing had to be aborted because of a length error (usually
     /// not enough data being available).
@@ -36,6 +38,22 @@ pub enum FromSliceError {
 
     /// Error while parsing a TCP extension header.
     Tcp(tcp::HeaderError),
+
+    /// Error when one of the configured [`crate::ParseLimits`] was exceeded.
+    ParseLimit(ParseLimitError),
+
+    /// Error when the VLAN tags or MACsec SecTAG are in an invalid order
+    /// (only reported if [`crate::SliceOptions::strict_tag_order`] is enabled).
+    TagOrder(TagOrderError),
+
+    /// Error while parsing an ERSPAN header.
+    Erspan(erspan::HeaderError),
+
+    /// Error while parsing a TZSP header.
+    Tzsp(tzsp::HeaderError),
+
+    /// Error while parsing an IEEE 802.11 MAC header.
+    Ieee80211(ieee80211::HeaderError),
 }
 
 impl FromSliceError {
@@ -93,6 +111,57 @@ impl FromSliceError {
             _ => None,
         }
     }
+    pub fn parse_limit(&self) -> Option<&ParseLimitError> {
+        match self {
+            FromSliceError::ParseLimit(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn tag_order(&self) -> Option<&TagOrderError> {
+        match self {
+            FromSliceError::TagOrder(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn erspan(&self) -> Option<&erspan::HeaderError> {
+        match self {
+            FromSliceError::Erspan(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn tzsp(&self) -> Option<&tzsp::HeaderError> {
+        match self {
+            FromSliceError::Tzsp(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn ieee80211(&self) -> Option<&ieee80211::HeaderError> {
+        match self {
+            FromSliceError::Ieee80211(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use FromSliceError::*;
+        match self {
+            Len(err) => err.kind(),
+            LinuxSll(err) => err.kind(),
+            DoubleVlan(err) => err.kind(),
+            Ip(err) => err.kind(),
+            IpAuth(err) => err.kind(),
+            Ipv4(err) => err.kind(),
+            Ipv6(err) => err.kind(),
+            Ipv6Exts(err) => err.kind(),
+            Tcp(err) => err.kind(),
+            ParseLimit(err) => err.kind(),
+            TagOrder(err) => err.kind(),
+            Erspan(err) => err.kind(),
+            Tzsp(err) => err.kind(),
+            Ieee80211(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for FromSliceError {
@@ -108,6 +177,11 @@ impl core::fmt::Display for FromSliceError {
             Ipv6(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            ParseLimit(err) => err.fmt(f),
+            TagOrder(err) => err.fmt(f),
+            Erspan(err) => err.fmt(f),
+            Tzsp(err) => err.fmt(f),
+            Ieee80211(err) => err.fmt(f),
         }
     }
 }
@@ -126,6 +200,11 @@ impl std::error::Error for FromSliceError {
             FromSliceError::Ipv6(err) => Some(err),
             FromSliceError::Ipv6Exts(err) => Some(err),
             FromSliceError::Tcp(err) => Some(err),
+            FromSliceError::ParseLimit(err) => Some(err),
+            FromSliceError::TagOrder(err) => Some(err),
+            FromSliceError::Erspan(err) => Some(err),
+            FromSliceError::Tzsp(err) => Some(err),
+            FromSliceError::Ieee80211(err) => Some(err),
         }
     }
 }
@@ -320,10 +399,29 @@ impl From<packet::SliceError> for FromSliceError {
             Ipv4Exts(err) => FromSliceError::IpAuth(err),
             Ipv6Exts(err) => FromSliceError::Ipv6Exts(err),
             Tcp(err) => FromSliceError::Tcp(err),
+            ParseLimit(err) => FromSliceError::ParseLimit(err),
+            TagOrder(err) => FromSliceError::TagOrder(err),
+            Ieee80211(err) => FromSliceError::Ieee80211(err),
         }
     }
 }
 
+// parse limit error conversions
+
+impl From<ParseLimitError> for FromSliceError {
+    fn from(value: ParseLimitError) -> Self {
+        FromSliceError::ParseLimit(value)
+    }
+}
+
+// tag order error conversions
+
+impl From<TagOrderError> for FromSliceError {
+    fn from(value: TagOrderError) -> Self {
+        FromSliceError::TagOrder(value)
+    }
+}
+
 // tcp error conversions
 
 impl From<tcp::HeaderError> for FromSliceError {
@@ -342,6 +440,60 @@ impl From<tcp::HeaderSliceError> for FromSliceError {
     }
 }
 
+// erspan error conversions
+
+impl From<erspan::HeaderError> for FromSliceError {
+    fn from(value: erspan::HeaderError) -> Self {
+        FromSliceError::Erspan(value)
+    }
+}
+
+impl From<erspan::HeaderSliceError> for FromSliceError {
+    fn from(value: erspan::HeaderSliceError) -> Self {
+        use erspan::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Erspan(err),
+        }
+    }
+}
+
+// tzsp error conversions
+
+impl From<tzsp::HeaderError> for FromSliceError {
+    fn from(value: tzsp::HeaderError) -> Self {
+        FromSliceError::Tzsp(value)
+    }
+}
+
+impl From<tzsp::HeaderSliceError> for FromSliceError {
+    fn from(value: tzsp::HeaderSliceError) -> Self {
+        use tzsp::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Tzsp(err),
+        }
+    }
+}
+
+// ieee 802.11 error conversions
+
+impl From<ieee80211::HeaderError> for FromSliceError {
+    fn from(value: ieee80211::HeaderError) -> Self {
+        FromSliceError::Ieee80211(value)
+    }
+}
+
+impl From<ieee80211::HeaderSliceError> for FromSliceError {
+    fn from(value: ieee80211::HeaderSliceError) -> Self {
+        use ieee80211::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Ieee80211(err),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{ArpHardwareId, EtherType, LenSource};
@@ -377,7 +529,7 @@ mod tests {
 
     #[test]
     fn debug_source() {
-        let test_values: [(&str, FromSliceError); 8] = [
+        let test_values: [(&str, FromSliceError); 13] = [
             (
                 "Len",
                 Len(LenError {
@@ -417,6 +569,26 @@ mod tests {
                 "Tcp",
                 Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
             ),
+            (
+                "ParseLimit",
+                ParseLimit(ParseLimitError::MaxLayers {
+                    max_layers: 1,
+                    layer: Layer::TcpHeader,
+                }),
+            ),
+            ("TagOrder", TagOrder(TagOrderError::TooManyVlanTags)),
+            (
+                "Erspan",
+                Erspan(erspan::HeaderError::UnsupportedVersion { version_number: 0 }),
+            ),
+            (
+                "Tzsp",
+                Tzsp(tzsp::HeaderError::UnsupportedVersion { version_number: 0 }),
+            ),
+            (
+                "Ieee80211",
+                Ieee80211(ieee80211::HeaderError::NotDataFrame { frame_type: 0 }),
+            ),
         ];
         for (prefix, value) in &test_values {
             // display
@@ -429,7 +601,7 @@ mod tests {
 
     #[test]
     fn display_source() {
-        let test_values: [FromSliceError; 9] = [
+        let test_values: [FromSliceError; 14] = [
             Len(LenError {
                 required_len: 0,
                 len: 0,
@@ -451,6 +623,14 @@ mod tests {
             Ipv6(ipv6::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
             Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
+            ParseLimit(ParseLimitError::MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader,
+            }),
+            TagOrder(TagOrderError::TooManyVlanTags),
+            Erspan(erspan::HeaderError::UnsupportedVersion { version_number: 0 }),
+            Tzsp(tzsp::HeaderError::UnsupportedVersion { version_number: 0 }),
+            Ieee80211(ieee80211::HeaderError::NotDataFrame { frame_type: 0 }),
         ];
         for value in &test_values {
             // display
@@ -480,6 +660,9 @@ mod tests {
         let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
         let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
         let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+        let erspan_error = || erspan::HeaderError::UnsupportedVersion { version_number: 0 };
+        let tzsp_error = || tzsp::HeaderError::UnsupportedVersion { version_number: 0 };
+        let ieee80211_error = || ieee80211::HeaderError::NotDataFrame { frame_type: 0 };
 
         // len
         assert_eq!(Len(len_error()).len(), Some(&len_error()));
@@ -525,6 +708,61 @@ mod tests {
         // tcp
         assert_eq!(Tcp(tcp_error()).tcp(), Some(&tcp_error()));
         assert_eq!(IpAuth(ip_auth_error()).tcp(), None);
+
+        // parse_limit
+        {
+            let parse_limit_error = || ParseLimitError::MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader,
+            };
+            assert_eq!(
+                ParseLimit(parse_limit_error()).parse_limit(),
+                Some(&parse_limit_error())
+            );
+            assert_eq!(IpAuth(ip_auth_error()).parse_limit(), None);
+            assert_eq!(
+                FromSliceError::from(parse_limit_error()).parse_limit(),
+                Some(&parse_limit_error())
+            );
+            assert_eq!(
+                FromSliceError::from(packet::SliceError::ParseLimit(parse_limit_error()))
+                    .parse_limit(),
+                Some(&parse_limit_error())
+            );
+        }
+
+        // tag_order
+        {
+            let tag_order_error = || TagOrderError::TooManyVlanTags;
+            assert_eq!(
+                TagOrder(tag_order_error()).tag_order(),
+                Some(&tag_order_error())
+            );
+            assert_eq!(IpAuth(ip_auth_error()).tag_order(), None);
+            assert_eq!(
+                FromSliceError::from(tag_order_error()).tag_order(),
+                Some(&tag_order_error())
+            );
+            assert_eq!(
+                FromSliceError::from(packet::SliceError::TagOrder(tag_order_error())).tag_order(),
+                Some(&tag_order_error())
+            );
+        }
+
+        // erspan
+        assert_eq!(Erspan(erspan_error()).erspan(), Some(&erspan_error()));
+        assert_eq!(IpAuth(ip_auth_error()).erspan(), None);
+
+        // tzsp
+        assert_eq!(Tzsp(tzsp_error()).tzsp(), Some(&tzsp_error()));
+        assert_eq!(IpAuth(ip_auth_error()).tzsp(), None);
+
+        // ieee80211
+        assert_eq!(
+            Ieee80211(ieee80211_error()).ieee80211(),
+            Some(&ieee80211_error())
+        );
+        assert_eq!(IpAuth(ip_auth_error()).ieee80211(), None);
     }
 
     #[test]
@@ -872,5 +1110,68 @@ mod tests {
                     .unwrap()
             );
         }
+
+        // erspan errors
+        {
+            let header_error = || erspan::HeaderError::UnsupportedVersion { version_number: 0 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).erspan().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(erspan::HeaderSliceError::Content(header_error()))
+                    .erspan()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(erspan::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // tzsp errors
+        {
+            let header_error = || tzsp::HeaderError::UnsupportedVersion { version_number: 0 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).tzsp().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(tzsp::HeaderSliceError::Content(header_error()))
+                    .tzsp()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(tzsp::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // ieee80211 errors
+        {
+            let header_error = || ieee80211::HeaderError::NotDataFrame { frame_type: 0 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).ieee80211().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(ieee80211::HeaderSliceError::Content(header_error()))
+                    .ieee80211()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(ieee80211::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
     }
 } // mod tests
@@ -0,0 +1,95 @@
+use crate::err::ErrorKind;
+
+/// Errors in a HIP header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the "header length" field of the HIP header is smaller
+    /// than the fixed part of the header (the field value has to be at
+    /// least `4`, as the fixed part is 40 bytes long).
+    HeaderLengthTooSmall {
+        /// The "header length" field value of the HIP header.
+        header_length: u8,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            HeaderLengthTooSmall { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            HeaderLengthTooSmall { header_length } => write!(
+                f,
+                "HIP Header Error: Encountered '{}' as header length field value, but at least '4' is required for the fixed part of the header.",
+                header_length
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "HeaderLengthTooSmall { header_length: 3 }",
+            format!("{:?}", HeaderLengthTooSmall { header_length: 3 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HeaderError::HeaderLengthTooSmall { header_length: 3 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "HIP Header Error: Encountered '3' as header length field value, but at least '4' is required for the fixed part of the header.",
+            format!("{}", HeaderLengthTooSmall { header_length: 3 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(HeaderLengthTooSmall { header_length: 3 }.source().is_none());
+    }
+}
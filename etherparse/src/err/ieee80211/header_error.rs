@@ -0,0 +1,107 @@
+use crate::err::ErrorKind;
+
+/// Errors in an IEEE 802.11 (WiFi) MAC header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the protocol version in the frame control field is not `0`.
+    UnsupportedProtocolVersion {
+        /// The unexpected protocol version in the frame control field.
+        version: u8,
+    },
+    /// Error when the frame is not a data frame (e.g. a management or
+    /// control frame).
+    NotDataFrame {
+        /// Type of the frame in the frame control field.
+        frame_type: u8,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnsupportedProtocolVersion { .. } => ErrorKind::UnsupportedVersion,
+            NotDataFrame { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnsupportedProtocolVersion { version } => write!(f, "IEEE 802.11 Header Error: Encountered '{}' as protocol version, but only '0' is supported.", version),
+            NotDataFrame { frame_type } => write!(f, "IEEE 802.11 Header Error: Encountered '{}' as frame type, but only data frames (type '2') are supported.", frame_type),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "NotDataFrame { frame_type: 1 }",
+            format!("{:?}", NotDataFrame { frame_type: 1 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HeaderError::UnsupportedProtocolVersion { version: 1 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IEEE 802.11 Header Error: Encountered '1' as protocol version, but only '0' is supported.",
+            format!("{}", UnsupportedProtocolVersion { version: 1 })
+        );
+        assert_eq!(
+            "IEEE 802.11 Header Error: Encountered '1' as frame type, but only data frames (type '2') are supported.",
+            format!("{}", NotDataFrame { frame_type: 1 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        let values = [
+            UnsupportedProtocolVersion { version: 1 },
+            NotDataFrame { frame_type: 1 },
+        ];
+        for v in values {
+            assert!(v.source().is_none());
+        }
+    }
+}
@@ -1,9 +1,12 @@
+#[cfg(feature = "std")]
+use crate::err::ErrorKind;
 use crate::err::LenError;
 
 /// Error that can occur when reading from a [`crate::io::LimitedReader`]
 #[derive(Debug)]
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[non_exhaustive]
 pub enum LimitedReadError {
     /// IO error was encountered while reading header or
     /// expected packet contents.
@@ -39,6 +42,15 @@ impl LimitedReadError {
             _ => None,
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use LimitedReadError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Len(err) => err.kind(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1,8 +1,10 @@
+use crate::err::ErrorKind;
 #[cfg(feature = "std")]
 use crate::*;
 
 /// Error when decoding the IP header part of a message.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error when the IP header version field is not equal to 4 or 6.
     UnsupportedIpVersion {
@@ -17,6 +19,17 @@ pub enum HeaderError {
     },
 }
 
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnsupportedIpVersion { .. } => ErrorKind::UnsupportedVersion,
+            Ipv4HeaderLengthSmallerThanHeader { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for HeaderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeaderError::*;
@@ -1,7 +1,9 @@
+use crate::err::ErrorKind;
 use crate::*;
 
 /// Error when decoding the IP header part of a message.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeadersError {
     /// Error in the IPv4 or IPv6 header.
     Ip(err::ip::HeaderError),
@@ -13,6 +15,18 @@ pub enum HeadersError {
     Ipv6Ext(err::ipv6_exts::HeaderError),
 }
 
+impl HeadersError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeadersError::*;
+        match self {
+            Ip(err) => err.kind(),
+            Ipv4Ext(err) => err.kind(),
+            Ipv6Ext(err) => err.kind(),
+        }
+    }
+}
+
 impl core::fmt::Display for HeadersError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeadersError::*;
@@ -1,10 +1,13 @@
 use super::HeadersError;
+#[cfg(feature = "std")]
+use crate::err::ErrorKind;
 use crate::err::LenError;
 
 /// Error when decoding an IP header via a `std::io::Read` source.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HeaderReadError {
     /// IO error was encountered while reading header.
     Io(std::io::Error),
@@ -53,6 +56,16 @@ impl HeaderReadError {
             _ => None,
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderReadError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Len(err) => err.kind(),
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1,10 +1,11 @@
 #[cfg(feature = "std")]
-use crate::err::{ipv4_exts, ipv6_exts};
+use crate::err::{ipv4_exts, ipv6_exts, ErrorKind};
 
 /// Error when writing IPv4 extension headers.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HeadersWriteError {
     /// IO error encountered while writing.
     Io(std::io::Error),
@@ -44,6 +45,16 @@ impl HeadersWriteError {
             _ => None,
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeadersWriteError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Ipv4Exts(err) => err.kind(),
+            Ipv6Exts(err) => err.kind(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
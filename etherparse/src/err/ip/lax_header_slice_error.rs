@@ -1,8 +1,9 @@
 use super::HeaderError;
-use crate::err::LenError;
+use crate::err::{ErrorKind, LenError};
 
 /// Error when decoding an IP header from a slice.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum LaxHeaderSliceError {
     /// Error when an length error is encountered (e.g. unexpected
     /// end of slice).
@@ -22,6 +23,24 @@ impl LaxHeaderSliceError {
             Content(err) => Content(err),
         }
     }
+
+    /// Returns the [`crate::err::LenError`] if the error is a length error.
+    pub fn len_error(&self) -> Option<&LenError> {
+        use LaxHeaderSliceError::*;
+        match self {
+            Len(err) => Some(err),
+            Content(_) => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use LaxHeaderSliceError::*;
+        match self {
+            Len(err) => err.kind(),
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for LaxHeaderSliceError {
@@ -83,6 +102,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn len_error() {
+        let err = LenError {
+            required_len: 1,
+            layer: Layer::IpHeader,
+            len: 2,
+            len_source: LenSource::Slice,
+            layer_start_offset: 3,
+        };
+        assert_eq!(Some(&err), Len(err.clone()).len_error());
+        assert_eq!(
+            None,
+            Content(UnsupportedIpVersion { version_number: 1 }).len_error()
+        );
+    }
+
     #[test]
     fn debug() {
         let err = UnsupportedIpVersion { version_number: 6 };
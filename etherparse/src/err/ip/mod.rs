@@ -20,5 +20,10 @@ pub use headers_write_error::*;
 mod lax_header_slice_error;
 pub use lax_header_slice_error::*;
 
+#[cfg(feature = "std")]
+mod network_parse_error;
+#[cfg(feature = "std")]
+pub use network_parse_error::*;
+
 mod slice_error;
 pub use slice_error::*;
@@ -0,0 +1,103 @@
+use crate::err::ErrorKind;
+
+/// Error when parsing an [`crate::Ipv4Network`] or [`crate::Ipv6Network`]
+/// from a string (e.g. "10.0.0.0/8").
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum NetworkParseError {
+    /// Error when the string contains no '/' separating the address
+    /// from the prefix length.
+    MissingPrefixLen,
+    /// Error when the address part of the string could not be parsed.
+    InvalidAddr,
+    /// Error when the prefix length could not be parsed or exceeds the
+    /// number of bits in the address.
+    InvalidPrefixLen,
+}
+
+impl NetworkParseError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use NetworkParseError::*;
+        match self {
+            MissingPrefixLen => ErrorKind::ContentInvalid,
+            InvalidAddr => ErrorKind::ContentInvalid,
+            InvalidPrefixLen => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for NetworkParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use NetworkParseError::*;
+        match self {
+            MissingPrefixLen => write!(f, "IP Network Parse Error: Missing '/' followed by the prefix length (e.g. '10.0.0.0/8')."),
+            InvalidAddr => write!(f, "IP Network Parse Error: Invalid IP address before the '/'."),
+            InvalidPrefixLen => write!(f, "IP Network Parse Error: Invalid prefix length after the '/' (must be a decimal number not exceeding the bit length of the address)."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for NetworkParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NetworkParseError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("InvalidAddr", format!("{:?}", InvalidAddr));
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = InvalidPrefixLen;
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IP Network Parse Error: Missing '/' followed by the prefix length (e.g. '10.0.0.0/8').",
+            format!("{}", MissingPrefixLen)
+        );
+        assert_eq!(
+            "IP Network Parse Error: Invalid IP address before the '/'.",
+            format!("{}", InvalidAddr)
+        );
+        assert_eq!(
+            "IP Network Parse Error: Invalid prefix length after the '/' (must be a decimal number not exceeding the bit length of the address).",
+            format!("{}", InvalidPrefixLen)
+        );
+    }
+
+    #[test]
+    fn source() {
+        for v in [MissingPrefixLen, InvalidAddr, InvalidPrefixLen] {
+            assert!(v.source().is_none());
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use crate::err::{ip, LenError};
+use crate::err::{ip, ErrorKind, LenError};
 
 /// Errors that can occur when slicing the IP part of a packet.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum SliceError {
     /// Length related errors (e.g. not enough data in slice).
     Len(LenError),
@@ -10,6 +11,25 @@ pub enum SliceError {
     IpHeaders(ip::HeadersError),
 }
 
+impl SliceError {
+    /// Returns the [`crate::err::LenError`] if the error is a length error.
+    pub fn len_error(&self) -> Option<&LenError> {
+        match self {
+            SliceError::Len(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use SliceError::*;
+        match self {
+            Len(err) => err.kind(),
+            IpHeaders(err) => err.kind(),
+        }
+    }
+}
+
 impl core::fmt::Display for SliceError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use SliceError::*;
@@ -49,6 +69,22 @@ mod tests {
         hash::{Hash, Hasher},
     };
 
+    #[test]
+    fn len_error() {
+        let err = LenError {
+            required_len: 1,
+            layer: Layer::Ipv4Header,
+            len: 2,
+            len_source: LenSource::Slice,
+            layer_start_offset: 3,
+        };
+        assert_eq!(Some(&err), Len(err.clone()).len_error());
+        assert_eq!(
+            None,
+            IpHeaders(Ip(UnsupportedIpVersion { version_number: 6 })).len_error()
+        );
+    }
+
     #[test]
     fn debug() {
         let err = Ip(UnsupportedIpVersion { version_number: 6 });
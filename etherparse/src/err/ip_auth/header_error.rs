@@ -1,6 +1,9 @@
+use crate::err::ErrorKind;
+
 /// Errors that can be encountered while decoding an IP
 /// authentication header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error when the payload length is zero and therefor
     /// too small to contain the minimum fields of the IP
@@ -8,6 +11,16 @@ pub enum HeaderError {
     ZeroPayloadLen,
 }
 
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            ZeroPayloadLen => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for HeaderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeaderError::*;
@@ -1,7 +1,10 @@
+use crate::err::ErrorKind;
+
 /// Error when creating an [`crate::IpAuthHeader`] and the
 /// length of the raw ICV is non representable in an IP authentication
 /// header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum IcvLenError {
     /// Error when the payload length is bigger then
     /// [`crate::IpAuthHeader::MAX_ICV_LEN`] (1016).
@@ -13,6 +16,17 @@ pub enum IcvLenError {
     Unaligned(usize),
 }
 
+impl IcvLenError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use IcvLenError::*;
+        match self {
+            TooBig(_) => ErrorKind::ContentInvalid,
+            Unaligned(_) => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for IcvLenError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use IcvLenError::*;
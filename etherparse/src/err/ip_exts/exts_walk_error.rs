@@ -1,8 +1,9 @@
-use crate::err::{ipv4_exts, ipv6_exts};
+use crate::err::{ipv4_exts, ipv6_exts, ErrorKind};
 
 /// Errors while serializing or determining the next_header of
 /// an [`crate::IpHeaders`].
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum ExtsWalkError {
     /// Error within the IPv4 extensions headers.
     Ipv4Exts(ipv4_exts::ExtsWalkError),
@@ -11,6 +12,17 @@ pub enum ExtsWalkError {
     Ipv6Exts(ipv6_exts::ExtsWalkError),
 }
 
+impl ExtsWalkError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ExtsWalkError::*;
+        match self {
+            Ipv4Exts(err) => err.kind(),
+            Ipv6Exts(err) => err.kind(),
+        }
+    }
+}
+
 impl core::fmt::Display for ExtsWalkError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use ExtsWalkError::*;
@@ -1,7 +1,9 @@
+use crate::err::ErrorKind;
 use crate::*;
 
 /// Error when decoding the IP extension header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error in the IPv4 extension headers (only authentication header).
     Ipv4Ext(err::ip_auth::HeaderError),
@@ -10,6 +12,17 @@ pub enum HeaderError {
     Ipv6Ext(err::ipv6_exts::HeaderError),
 }
 
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            Ipv4Ext(err) => err.kind(),
+            Ipv6Ext(err) => err.kind(),
+        }
+    }
+}
+
 impl core::fmt::Display for HeaderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeaderError::*;
@@ -1,8 +1,9 @@
 use super::HeaderError;
-use crate::err::LenError;
+use crate::err::{ErrorKind, LenError};
 
 /// Error when decoding IP extension headers from a slice.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeadersSliceError {
     /// Error when an length error is encountered (e.g. unexpected
     /// end of slice).
@@ -30,6 +31,15 @@ impl HeadersSliceError {
             Content(err) => Some(err),
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeadersSliceError::*;
+        match self {
+            Len(err) => err.kind(),
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for HeadersSliceError {
@@ -1,3 +1,5 @@
+use crate::err::ErrorKind;
+
 /// Error if a slice can not be used as options data in
 /// [`crate::Ipv4Options`] as then length is non compatible.
 ///
@@ -8,6 +10,13 @@ pub struct BadOptionsLen {
     pub bad_len: usize,
 }
 
+impl BadOptionsLen {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::ContentInvalid
+    }
+}
+
 impl core::fmt::Display for BadOptionsLen {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Slice of length {} cannot be set as IPv4 header options. The length must be a multiple of 4 and at maximum 40.", self.bad_len)
@@ -1,9 +1,12 @@
 use super::HeaderError;
+#[cfg(feature = "std")]
+use crate::err::ErrorKind;
 
 /// Error when decoding an IPv4 header via a `std::io::Read` source.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HeaderReadError {
     /// IO error was encountered while reading header.
     Io(std::io::Error),
@@ -36,6 +39,15 @@ impl HeaderReadError {
             _ => None,
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderReadError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
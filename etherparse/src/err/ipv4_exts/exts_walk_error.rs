@@ -1,8 +1,10 @@
+use crate::err::ErrorKind;
 use crate::IpNumber;
 
 /// Errors in content of IPv4 header extensions that prevent serialization
 /// or determining the next header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum ExtsWalkError {
     /// Error when a header in [`crate::Ipv4Extensions`] is never referenced even
     /// though it is present in the [`crate::Ipv4Extensions`].
@@ -15,6 +17,16 @@ pub enum ExtsWalkError {
     },
 }
 
+impl ExtsWalkError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ExtsWalkError::*;
+        match self {
+            ExtNotReferenced { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for ExtsWalkError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use ExtsWalkError::*;
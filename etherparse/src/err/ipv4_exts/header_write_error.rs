@@ -1,10 +1,13 @@
 #[cfg(feature = "std")]
 use super::ExtsWalkError;
+#[cfg(feature = "std")]
+use crate::err::ErrorKind;
 
 /// Error when writing IPv4 extension headers.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HeaderWriteError {
     /// IO error encountered while writing.
     Io(std::io::Error),
@@ -30,6 +33,15 @@ impl HeaderWriteError {
             HeaderWriteError::Content(err) => Some(err),
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderWriteError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Content(err) => err.kind(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
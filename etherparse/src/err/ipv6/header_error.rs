@@ -1,5 +1,8 @@
+use crate::err::ErrorKind;
+
 /// Error when decoding the IPv6 header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error when the IPv6 header version field is not equal to 6.
     UnexpectedVersion {
@@ -8,6 +11,16 @@ pub enum HeaderError {
     },
 }
 
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnexpectedVersion { .. } => ErrorKind::UnsupportedVersion,
+        }
+    }
+}
+
 impl core::fmt::Display for HeaderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeaderError::*;
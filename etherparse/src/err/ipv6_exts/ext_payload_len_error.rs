@@ -1,6 +1,9 @@
+use crate::err::ErrorKind;
+
 /// Error when creating an [`crate::Ipv6RawExtHeader`] and the
 /// payload len is non representable.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum ExtPayloadLenError {
     /// Error when the payload length is smaller then
     /// [`crate::Ipv6RawExtHeader::MIN_PAYLOAD_LEN`] (6).
@@ -16,6 +19,18 @@ pub enum ExtPayloadLenError {
     Unaligned(usize),
 }
 
+impl ExtPayloadLenError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ExtPayloadLenError::*;
+        match self {
+            TooSmall(_) => ErrorKind::ContentInvalid,
+            TooBig(_) => ErrorKind::ContentInvalid,
+            Unaligned(_) => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for ExtPayloadLenError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use ExtPayloadLenError::*;
@@ -0,0 +1,141 @@
+use crate::err::ErrorKind;
+use crate::IpNumber;
+
+/// Errors that can occur when building [`crate::Ipv6Extensions`] via
+/// [`crate::Ipv6ExtensionsBuilder`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ExtsBuildError {
+    /// Error when an extension header was added after an extension header
+    /// that has to follow it based on the order defined in RFC 8200.
+    ExtOutOfOrder {
+        /// IpNumber of the header that was added out of order.
+        ext: IpNumber,
+        /// IpNumber of the previously added header that has to follow `ext`.
+        previous_ext: IpNumber,
+    },
+
+    /// Error when an extension header was added more then once.
+    ExtDuplicate {
+        /// IpNumber of the header that was added more then once.
+        ext: IpNumber,
+    },
+
+    /// Error when a destination options header after the routing header
+    /// was added without a routing header being present.
+    FinalDestOptionsWithoutRouting,
+}
+
+impl ExtsBuildError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ExtsBuildError::*;
+        match self {
+            ExtOutOfOrder { .. } => ErrorKind::ContentInvalid,
+            ExtDuplicate { .. } => ErrorKind::ContentInvalid,
+            FinalDestOptionsWithoutRouting => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for ExtsBuildError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use ExtsBuildError::*;
+        match self {
+            ExtOutOfOrder { ext, previous_ext } => write!(
+                f,
+                "IPv6 extensions '{:?}' is not allowed after '{:?}' (order defined in RFC 8200).",
+                ext, previous_ext
+            ),
+            ExtDuplicate { ext } => write!(
+                f,
+                "IPv6 extensions '{:?}' was added more then once.",
+                ext
+            ),
+            FinalDestOptionsWithoutRouting => write!(
+                f,
+                "IPv6 extensions destination options header after the routing header was added without a routing header."
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ExtsBuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtsBuildError::*;
+    use crate::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "FinalDestOptionsWithoutRouting",
+            format!("{:?}", FinalDestOptionsWithoutRouting)
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = ExtDuplicate {
+            ext: IpNumber::AUTHENTICATION_HEADER,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 extensions '0 (HOPOPT - IPv6 Hop-by-Hop Option)' is not allowed after '44 (IPv6-Frag - Fragment Header for IPv6)' (order defined in RFC 8200).",
+            format!(
+                "{}",
+                ExtOutOfOrder {
+                    ext: IpNumber::IPV6_HEADER_HOP_BY_HOP,
+                    previous_ext: IpNumber::IPV6_FRAGMENTATION_HEADER
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 extensions '44 (IPv6-Frag - Fragment Header for IPv6)' was added more then once.",
+            format!(
+                "{}",
+                ExtDuplicate {
+                    ext: IpNumber::IPV6_FRAGMENTATION_HEADER
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 extensions destination options header after the routing header was added without a routing header.",
+            format!("{}", FinalDestOptionsWithoutRouting)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(FinalDestOptionsWithoutRouting.source().is_none());
+    }
+}
@@ -1,7 +1,9 @@
+use crate::err::ErrorKind;
 use crate::IpNumber;
 
 /// Errors in content of IPv6 header extensions that prevent serialization.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum ExtsWalkError {
     /// Error when a hop-by-hop header is not referenced as the
     /// first header after the ipv6 header but as a later extension
@@ -17,6 +19,17 @@ pub enum ExtsWalkError {
     },
 }
 
+impl ExtsWalkError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ExtsWalkError::*;
+        match self {
+            HopByHopNotAtStart => ErrorKind::ContentInvalid,
+            ExtNotReferenced { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for ExtsWalkError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -1,13 +1,30 @@
+use crate::err::ErrorKind;
 use crate::*;
 
 /// Error when decoding IPv6 extension headers.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error if the ipv6 hop by hop header does not occur directly after the ipv6 header (see rfc8200 chapter 4.1.)
     HopByHopNotAtStart,
 
     /// Error in the ip authentication header.
     IpAuth(err::ip_auth::HeaderError),
+
+    /// Error in the IPv6 mobility header.
+    Mobility(err::ipv6_mobility::HeaderError),
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            HopByHopNotAtStart => ErrorKind::ContentInvalid,
+            IpAuth(err) => err.kind(),
+            Mobility(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for HeaderError {
@@ -16,6 +33,7 @@ impl core::fmt::Display for HeaderError {
         match self {
             HopByHopNotAtStart => write!(f, "IPv6 Extension Header Error: Encountered an IPv6 hop-by-hop header not directly after the IPv6 header. This is not allowed according to RFC 8200."),
             IpAuth(err) => err.fmt(f),
+            Mobility(err) => err.fmt(f),
         }
     }
 }
@@ -28,6 +46,7 @@ impl std::error::Error for HeaderError {
         match self {
             HopByHopNotAtStart => None,
             IpAuth(err) => Some(err),
+            Mobility(err) => Some(err),
         }
     }
 }
@@ -75,6 +94,14 @@ mod tests {
             let err = err::ip_auth::HeaderError::ZeroPayloadLen;
             assert_eq!(format!("{}", err), format!("{}", IpAuth(err)));
         }
+        {
+            let err = err::ipv6_mobility::HeaderError::MessageTooShort {
+                mh_type: MobilityHeaderType::BINDING_UPDATE,
+                header_len: 8,
+                min_header_len: 12,
+            };
+            assert_eq!(format!("{}", err), format!("{}", Mobility(err)));
+        }
     }
 
     #[cfg(feature = "std")]
@@ -84,5 +111,12 @@ mod tests {
 
         assert!(HopByHopNotAtStart.source().is_none());
         assert!(IpAuth(ZeroPayloadLen).source().is_some());
+        assert!(Mobility(err::ipv6_mobility::HeaderError::MessageTooShort {
+            mh_type: MobilityHeaderType::BINDING_UPDATE,
+            header_len: 8,
+            min_header_len: 12,
+        })
+        .source()
+        .is_some());
     }
 }
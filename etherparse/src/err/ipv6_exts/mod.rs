@@ -14,6 +14,9 @@ mod header_read_error;
 #[cfg(feature = "std")]
 pub use header_read_error::*;
 
+mod exts_build_error;
+pub use exts_build_error::*;
+
 mod exts_walk_error;
 pub use exts_walk_error::*;
 
@@ -0,0 +1,105 @@
+use crate::err::ErrorKind;
+use crate::MobilityHeaderType;
+
+/// Errors in an IPv6 mobility header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the length of the mobility header (based on the
+    /// "header length" field) is too small to contain the fixed fields
+    /// of the message type.
+    MessageTooShort {
+        /// Message type of the mobility header.
+        mh_type: MobilityHeaderType,
+        /// Length of the mobility header in bytes/octets (based on the
+        /// "header length" field).
+        header_len: usize,
+        /// Minimum header length in bytes/octets required for the message type.
+        min_header_len: usize,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            MessageTooShort { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            MessageTooShort { mh_type, header_len, min_header_len } => write!(f, "IPv6 Mobility Header Error: Header length of {} bytes is too small for the message type {} (at least {} bytes are required).", header_len, mh_type.0, min_header_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    const ERR: HeaderError = MessageTooShort {
+        mh_type: MobilityHeaderType::BINDING_UPDATE,
+        header_len: 8,
+        min_header_len: 12,
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!(
+                "MessageTooShort {{ mh_type: {:?}, header_len: 8, min_header_len: 12 }}",
+                MobilityHeaderType::BINDING_UPDATE
+            ),
+            format!("{:?}", ERR)
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        assert_eq!(ERR, ERR.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            ERR.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            ERR.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Mobility Header Error: Header length of 8 bytes is too small for the message type 5 (at least 12 bytes are required).",
+            format!("{}", ERR)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(ERR.source().is_none());
+    }
+}
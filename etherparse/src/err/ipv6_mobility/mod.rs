@@ -0,0 +1,15 @@
+mod header_error;
+pub use header_error::*;
+
+#[cfg(feature = "std")]
+mod header_read_error;
+#[cfg(feature = "std")]
+pub use header_read_error::*;
+
+#[cfg(feature = "std")]
+mod header_limited_read_error;
+#[cfg(feature = "std")]
+pub use header_limited_read_error::*;
+
+mod header_slice_error;
+pub use header_slice_error::*;
@@ -1,5 +1,6 @@
 /// Layers on which an error can occur.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
 pub enum Layer {
     /// Error occurred in the Linux Cooked Capture v1 (SLL) header.
     LinuxSllHeader,
@@ -31,6 +32,8 @@ pub enum Layer {
     Ipv6RouteHeader,
     /// Error occurred while decoding an IPv6 fragment header.
     Ipv6FragHeader,
+    /// Error occurred while decoding an IPv6 mobility header.
+    Ipv6MobilityHeader,
     /// Error occurred while decoding an UDP header.
     UdpHeader,
     /// Error occurred verifying the length of the UDP payload.
@@ -43,8 +46,46 @@ pub enum Layer {
     Icmpv4Timestamp,
     /// Error occurred while parsing an ICMP timestamp reply packet.
     Icmpv4TimestampReply,
+    /// Error occurred while parsing an ICMP address mask request packet.
+    Icmpv4AddressMask,
+    /// Error occurred while parsing an ICMP address mask reply packet.
+    Icmpv4AddressMaskReply,
     /// Error occurred while parsing an ICMPv6 packet.
     Icmpv6,
+    /// Error occurred while decoding an ERSPAN header.
+    ErspanHeader,
+    /// Error occurred while decoding a TZSP header.
+    TzspHeader,
+    /// Error occurred while decoding an IEEE 802.1ah I-TAG header.
+    Ieee8021ahHeader,
+    /// Error occurred while decoding an IEEE 802.11 (WiFi) MAC header.
+    Ieee80211Header,
+    /// Error occurred while decoding an IEEE 802.1AE MACsec header (SecTAG).
+    MacsecHeader,
+    /// Error occurred while decoding an NTP header.
+    NtpHeader,
+    /// Error occurred while decoding a HIP (Host Identity Protocol) header.
+    HipHeader,
+    /// Error occurred while decoding a Shim6 header.
+    Shim6Header,
+    /// Error occurred while decoding an EtherCAT header.
+    EthercatHeader,
+    /// Error occurred while decoding a PROFINET real-time header.
+    ProfinetHeader,
+    /// Error occurred while decoding a WireGuard message header.
+    WireguardHeader,
+    /// Error occurred while decoding the indicators of a Teredo packet.
+    TeredoHeader,
+    /// Error occurred while decoding a PTP (IEEE 1588) header.
+    PtpHeader,
+    /// Error occurred while decoding an LLDP TLV.
+    LldpTlv,
+    /// Error occurred while decoding a DNS name.
+    DnsName,
+    /// Error occurred while decoding a SOME/IP header.
+    SomeipHeader,
+    /// Error occurred while decoding an ARP packet.
+    ArpPacket,
 }
 
 impl Layer {
@@ -67,13 +108,33 @@ impl Layer {
             Ipv6DestOptionsHeader => "IPv6 Destination Options Header Error",
             Ipv6RouteHeader => "IPv6 Routing Header Error",
             Ipv6FragHeader => "IPv6 Fragment Header Error",
+            Ipv6MobilityHeader => "IPv6 Mobility Header Error",
             UdpHeader => "UDP Header Error",
             UdpPayload => "UDP Payload Error",
             TcpHeader => "TCP Header Error",
             Icmpv4 => "ICMP Packet Error",
             Icmpv4Timestamp => "ICMP Timestamp Error",
             Icmpv4TimestampReply => "ICMP Timestamp Reply Error",
+            Icmpv4AddressMask => "ICMP Address Mask Error",
+            Icmpv4AddressMaskReply => "ICMP Address Mask Reply Error",
             Icmpv6 => "ICMPv6 Packet Error",
+            ErspanHeader => "ERSPAN Header Error",
+            TzspHeader => "TZSP Header Error",
+            Ieee8021ahHeader => "IEEE 802.1ah I-TAG Error",
+            Ieee80211Header => "IEEE 802.11 Header Error",
+            MacsecHeader => "MACsec Header Error",
+            NtpHeader => "NTP Header Error",
+            HipHeader => "HIP Header Error",
+            Shim6Header => "Shim6 Header Error",
+            EthercatHeader => "EtherCAT Header Error",
+            ProfinetHeader => "PROFINET Header Error",
+            WireguardHeader => "WireGuard Header Error",
+            TeredoHeader => "Teredo Header Error",
+            PtpHeader => "PTP Header Error",
+            LldpTlv => "LLDP TLV Error",
+            DnsName => "DNS Name Error",
+            SomeipHeader => "SOME/IP Header Error",
+            ArpPacket => "ARP Packet Error",
         }
     }
 }
@@ -97,13 +158,33 @@ impl core::fmt::Display for Layer {
             Ipv6DestOptionsHeader => write!(f, "IPv6 destination options header"),
             Ipv6RouteHeader => write!(f, "IPv6 routing header"),
             Ipv6FragHeader => write!(f, "IPv6 fragment header"),
+            Ipv6MobilityHeader => write!(f, "IPv6 mobility header"),
             UdpHeader => write!(f, "UDP header"),
             UdpPayload => write!(f, "UDP payload"),
             TcpHeader => write!(f, "TCP header"),
             Icmpv4 => write!(f, "ICMP packet"),
             Icmpv4Timestamp => write!(f, "ICMP timestamp message"),
             Icmpv4TimestampReply => write!(f, "ICMP timestamp reply message"),
+            Icmpv4AddressMask => write!(f, "ICMP address mask request message"),
+            Icmpv4AddressMaskReply => write!(f, "ICMP address mask reply message"),
             Icmpv6 => write!(f, "ICMPv6 packet"),
+            ErspanHeader => write!(f, "ERSPAN header"),
+            TzspHeader => write!(f, "TZSP header"),
+            Ieee8021ahHeader => write!(f, "IEEE 802.1ah I-TAG header"),
+            Ieee80211Header => write!(f, "IEEE 802.11 header"),
+            MacsecHeader => write!(f, "MACsec header"),
+            NtpHeader => write!(f, "NTP header"),
+            HipHeader => write!(f, "HIP header"),
+            Shim6Header => write!(f, "Shim6 header"),
+            EthercatHeader => write!(f, "EtherCAT header"),
+            ProfinetHeader => write!(f, "PROFINET header"),
+            WireguardHeader => write!(f, "WireGuard header"),
+            TeredoHeader => write!(f, "Teredo header"),
+            PtpHeader => write!(f, "PTP header"),
+            LldpTlv => write!(f, "LLDP TLV"),
+            DnsName => write!(f, "DNS name"),
+            SomeipHeader => write!(f, "SOME/IP header"),
+            ArpPacket => write!(f, "ARP packet"),
         }
     }
 }
@@ -163,13 +244,33 @@ mod test {
             ),
             (Ipv6RouteHeader, "IPv6 Routing Header Error"),
             (Ipv6FragHeader, "IPv6 Fragment Header Error"),
+            (Ipv6MobilityHeader, "IPv6 Mobility Header Error"),
             (UdpHeader, "UDP Header Error"),
             (UdpPayload, "UDP Payload Error"),
             (TcpHeader, "TCP Header Error"),
             (Icmpv4, "ICMP Packet Error"),
             (Icmpv4Timestamp, "ICMP Timestamp Error"),
             (Icmpv4TimestampReply, "ICMP Timestamp Reply Error"),
+            (Icmpv4AddressMask, "ICMP Address Mask Error"),
+            (Icmpv4AddressMaskReply, "ICMP Address Mask Reply Error"),
             (Icmpv6, "ICMPv6 Packet Error"),
+            (ErspanHeader, "ERSPAN Header Error"),
+            (TzspHeader, "TZSP Header Error"),
+            (Ieee8021ahHeader, "IEEE 802.1ah I-TAG Error"),
+            (Ieee80211Header, "IEEE 802.11 Header Error"),
+            (MacsecHeader, "MACsec Header Error"),
+            (NtpHeader, "NTP Header Error"),
+            (HipHeader, "HIP Header Error"),
+            (Shim6Header, "Shim6 Header Error"),
+            (EthercatHeader, "EtherCAT Header Error"),
+            (ProfinetHeader, "PROFINET Header Error"),
+            (WireguardHeader, "WireGuard Header Error"),
+            (TeredoHeader, "Teredo Header Error"),
+            (PtpHeader, "PTP Header Error"),
+            (LldpTlv, "LLDP TLV Error"),
+            (DnsName, "DNS Name Error"),
+            (SomeipHeader, "SOME/IP Header Error"),
+            (ArpPacket, "ARP Packet Error"),
         ];
         for test in tests {
             assert_eq!(test.0.error_title(), test.1);
@@ -194,13 +295,33 @@ mod test {
             (Ipv6DestOptionsHeader, "IPv6 destination options header"),
             (Ipv6RouteHeader, "IPv6 routing header"),
             (Ipv6FragHeader, "IPv6 fragment header"),
+            (Ipv6MobilityHeader, "IPv6 mobility header"),
             (UdpHeader, "UDP header"),
             (UdpPayload, "UDP payload"),
             (TcpHeader, "TCP header"),
             (Icmpv4, "ICMP packet"),
             (Icmpv4Timestamp, "ICMP timestamp message"),
             (Icmpv4TimestampReply, "ICMP timestamp reply message"),
+            (Icmpv4AddressMask, "ICMP address mask request message"),
+            (Icmpv4AddressMaskReply, "ICMP address mask reply message"),
             (Icmpv6, "ICMPv6 packet"),
+            (ErspanHeader, "ERSPAN header"),
+            (TzspHeader, "TZSP header"),
+            (Ieee8021ahHeader, "IEEE 802.1ah I-TAG header"),
+            (Ieee80211Header, "IEEE 802.11 header"),
+            (MacsecHeader, "MACsec header"),
+            (NtpHeader, "NTP header"),
+            (HipHeader, "HIP header"),
+            (Shim6Header, "Shim6 header"),
+            (EthercatHeader, "EtherCAT header"),
+            (ProfinetHeader, "PROFINET header"),
+            (WireguardHeader, "WireGuard header"),
+            (TeredoHeader, "Teredo header"),
+            (PtpHeader, "PTP header"),
+            (LldpTlv, "LLDP TLV"),
+            (DnsName, "DNS name"),
+            (SomeipHeader, "SOME/IP header"),
+            (ArpPacket, "ARP packet"),
         ];
         for test in tests {
             assert_eq!(format!("{}", test.0), test.1);
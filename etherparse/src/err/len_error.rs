@@ -1,3 +1,4 @@
+use crate::err::ErrorKind;
 use crate::{err::Layer, LenSource};
 
 /// Error when different lengths are conflicting with each other (e.g. not
@@ -14,6 +15,21 @@ use crate::{err::Layer, LenSource};
 /// the upper layer is too big for the lower layer the inverse
 /// (`required_len < len`) must be true.
 ///
+/// All `from_slice` functions of the slice types (e.g. [`crate::UdpSlice::from_slice`])
+/// report length problems via this error, either directly or wrapped in
+/// the `Len` variant of the returned error type. The wrapped error can be
+/// accessed via the `len_error` method of the error types:
+///
+/// ```
+/// use etherparse::{err::Layer, TcpSlice};
+///
+/// let err = TcpSlice::from_slice(&[0u8; 4]).unwrap_err();
+/// let len_err = err.len_error().unwrap();
+/// assert_eq!(Layer::TcpHeader, len_err.layer);
+/// assert_eq!(20, len_err.required_len);
+/// assert_eq!(4, len_err.len);
+/// ```
+///
 /// # Examples:
 ///
 /// An example for an error that could be returned when there is not enough
@@ -70,6 +86,11 @@ impl LenError {
             layer_start_offset: self.layer_start_offset + offset,
         }
     }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Len
+    }
 }
 
 impl core::fmt::Display for LenError {
@@ -84,6 +105,7 @@ impl core::fmt::Display for LenError {
                 }
                 UdpHeaderLen => "length calculated from the UDP header 'length' field",
                 TcpHeaderLen => "length calculated from the TCP header 'length' field",
+                MacsecShortLen => "length calculated from the MACsec header 'short length' field",
             }
         };
 
@@ -230,6 +252,7 @@ mod test {
                 (Ipv6HeaderPayloadLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv6 header 'payload length' field."),
                 (UdpHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the UDP header 'length' field."),
                 (TcpHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the TCP header 'length' field."),
+                (MacsecShortLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the MACsec header 'short length' field."),
             ];
 
             for test in len_source_tests {
@@ -273,6 +296,7 @@ mod test {
                 (Ipv6HeaderPayloadLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv6 header 'payload length' field was used to determine the length."),
                 (UdpHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the UDP header 'length' field was used to determine the length."),
                 (TcpHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the TCP header 'length' field was used to determine the length."),
+                (MacsecShortLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the MACsec header 'short length' field was used to determine the length."),
             ];
 
             for test in len_source_tests {
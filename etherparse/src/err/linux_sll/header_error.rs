@@ -1,7 +1,9 @@
+use crate::err::ErrorKind;
 use crate::ArpHardwareId;
 
 /// Errors in an Linux Cooked Capture header encountered while decoding it.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error when the "packet byte" field is not one of the known ones
     UnsupportedPacketTypeField {
@@ -12,6 +14,17 @@ pub enum HeaderError {
     UnsupportedArpHardwareId { arp_hardware_type: ArpHardwareId },
 }
 
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnsupportedPacketTypeField { .. } => ErrorKind::ContentInvalid,
+            UnsupportedArpHardwareId { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for HeaderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeaderError::*;
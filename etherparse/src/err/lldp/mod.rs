@@ -0,0 +1,4 @@
+#[cfg(feature = "std")]
+mod tlv_write_error;
+#[cfg(feature = "std")]
+pub use tlv_write_error::*;
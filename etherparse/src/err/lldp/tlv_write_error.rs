@@ -0,0 +1,140 @@
+#[cfg(feature = "std")]
+use crate::err::{ErrorKind, ValueTooBigError};
+
+/// Error when writing LLDP TLVs.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TlvWriteError {
+    /// IO error encountered while writing.
+    Io(std::io::Error),
+    /// Value of a TLV is longer then the maximum length that can be
+    /// represented in the 9 bit length field of a TLV (511 bytes).
+    Content(ValueTooBigError<usize>),
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TlvWriteError {
+    /// Returns a reference to the [`std::io::Error`] if the value is an [`TlvWriteError::Io`].
+    pub fn io(&self) -> Option<&std::io::Error> {
+        match self {
+            TlvWriteError::Io(err) => Some(err),
+            TlvWriteError::Content(_) => None,
+        }
+    }
+
+    /// Returns a reference to the [`crate::err::ValueTooBigError`] if the value is an [`TlvWriteError::Content`].
+    pub fn content(&self) -> Option<&ValueTooBigError<usize>> {
+        match self {
+            TlvWriteError::Io(_) => None,
+            TlvWriteError::Content(err) => Some(err),
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use TlvWriteError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Content(err) => err.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl core::fmt::Display for TlvWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use TlvWriteError::*;
+        match self {
+            Io(err) => err.fmt(f),
+            Content(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for TlvWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TlvWriteError::*;
+        match self {
+            Io(ref err) => Some(err),
+            Content(ref err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TlvWriteError::*;
+    use crate::err::{ValueTooBigError, ValueType};
+    use alloc::format;
+    use std::error::Error;
+
+    fn value_too_big() -> ValueTooBigError<usize> {
+        ValueTooBigError {
+            actual: 512,
+            max_allowed: 511,
+            value_type: ValueType::LldpTlvValueLength,
+        }
+    }
+
+    #[test]
+    fn io() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .io()
+        .is_some());
+        assert!(Content(value_too_big()).io().is_none());
+    }
+
+    #[test]
+    fn content() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .content()
+        .is_none());
+        assert_eq!(Some(&value_too_big()), Content(value_too_big()).content());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("Content({:?})", value_too_big()),
+            format!("{:?}", Content(value_too_big()))
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        {
+            let err = std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            );
+            assert_eq!(format!("{}", err), format!("{}", Io(err)));
+        }
+        assert_eq!(
+            format!("{}", value_too_big()),
+            format!("{}", Content(value_too_big()))
+        );
+    }
+
+    #[test]
+    fn source() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .source()
+        .is_some());
+        assert!(Content(value_too_big()).source().is_some());
+    }
+}
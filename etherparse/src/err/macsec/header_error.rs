@@ -0,0 +1,89 @@
+use crate::err::ErrorKind;
+
+/// Errors in a MACsec header (SecTAG) encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the version bit (V) in the TCI is not `0`.
+    UnexpectedVersion {
+        /// The unexpected version bit.
+        version: u8,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnexpectedVersion { .. } => ErrorKind::UnsupportedVersion,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnexpectedVersion { version } => write!(f, "MACsec Header Error: Encountered '{}' as version bit (V) in the TCI, but only '0' is supported.", version),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnexpectedVersion { version: 1 }",
+            format!("{:?}", UnexpectedVersion { version: 1 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HeaderError::UnexpectedVersion { version: 1 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "MACsec Header Error: Encountered '1' as version bit (V) in the TCI, but only '0' is supported.",
+            format!("{}", UnexpectedVersion { version: 1 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnexpectedVersion { version: 1 }.source().is_none());
+    }
+}
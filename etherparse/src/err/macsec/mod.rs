@@ -0,0 +1,10 @@
+mod header_error;
+pub use header_error::*;
+
+#[cfg(feature = "std")]
+mod header_read_error;
+#[cfg(feature = "std")]
+pub use header_read_error::*;
+
+mod header_slice_error;
+pub use header_slice_error::*;
@@ -1,4 +1,11 @@
+#[cfg(feature = "arp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arp")))]
+pub mod arp;
+pub mod dns;
 pub mod double_vlan;
+pub mod erspan;
+pub mod hip;
+pub mod ieee80211;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod io;
@@ -9,13 +16,27 @@ pub mod ipv4;
 pub mod ipv4_exts;
 pub mod ipv6;
 pub mod ipv6_exts;
+pub mod ipv6_mobility;
 pub mod linux_sll;
+pub mod lldp;
+#[cfg(feature = "macsec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+pub mod macsec;
 pub mod packet;
+pub mod someip;
 pub mod tcp;
+pub mod tzsp;
+pub mod wireguard;
 
 mod value_type;
 pub use value_type::*;
 
+mod error_kind;
+pub use error_kind::*;
+
+mod buf_space_error;
+pub use buf_space_error::*;
+
 mod from_slice_error;
 pub use from_slice_error::*;
 
@@ -25,9 +46,18 @@ pub use layer::*;
 mod len_error;
 pub use len_error::*;
 
+mod parse_limit_error;
+pub use parse_limit_error::*;
+
+mod tag_order_error;
+pub use tag_order_error::*;
+
 mod value_too_big_error;
 pub use value_too_big_error::*;
 
+mod value_parse_error;
+pub use value_parse_error::*;
+
 #[cfg(feature = "std")]
 mod read_error;
 #[cfg(feature = "std")]
@@ -1,12 +1,12 @@
-#[cfg(feature = "std")]
-use crate::err::{ipv4_exts, ipv6_exts, ValueTooBigError};
+use crate::err::{ipv4_exts, ipv6_exts, BufSpaceError, ErrorKind, ValueTooBigError};
 
 /// Error while writing packet
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum BuildWriteError {
     /// IO error while writing packet.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     Io(std::io::Error),
 
     /// Error if the length of the payload is too
@@ -25,13 +25,21 @@ pub enum BuildWriteError {
     /// Error if ICMPv6 is packaged in an IPv4 packet (it is undefined
     /// how to calculate the checksum).
     Icmpv6InIpv4,
+
+    /// Error if IPv4 options were set for a packet with an IPv6 header
+    /// (see [`crate::PacketBuilderStep::ipv4_options`]).
+    Ipv4OptionsInIpv6,
+
+    /// Error if the packet does not fit into the [`crate::PacketBuf`]
+    /// it was built into.
+    BufSpace(BufSpaceError),
 }
 
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl BuildWriteError {
     /// Returns the [`std::io::Error`] value if the `BuildWriteError` is an `Io`.
     /// Otherwise `None` is returned.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn io(&self) -> Option<&std::io::Error> {
         match self {
             BuildWriteError::Io(err) => Some(err),
@@ -70,19 +78,49 @@ impl BuildWriteError {
     pub fn is_icmpv6_in_ipv4(&self) -> bool {
         matches!(self, BuildWriteError::Icmpv6InIpv4)
     }
+
+    /// Returns true if the `BuildWriteError` is a `Ipv4OptionsInIpv6`.
+    pub fn is_ipv4_options_in_ipv6(&self) -> bool {
+        matches!(self, BuildWriteError::Ipv4OptionsInIpv6)
+    }
+
+    /// Returns the [`crate::err::BufSpaceError`] value if the
+    /// `BuildWriteError` is a `BufSpace`. Otherwise `None` is returned.
+    pub fn buf_space(&self) -> Option<&BufSpaceError> {
+        match self {
+            BuildWriteError::BufSpace(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use BuildWriteError::*;
+        match self {
+            #[cfg(feature = "std")]
+            Io(_) => ErrorKind::Io,
+            PayloadLen(err) => err.kind(),
+            Ipv4Exts(err) => err.kind(),
+            Ipv6Exts(err) => err.kind(),
+            Icmpv6InIpv4 => ErrorKind::ContentInvalid,
+            Ipv4OptionsInIpv6 => ErrorKind::ContentInvalid,
+            BufSpace(err) => err.kind(),
+        }
+    }
 }
 
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl core::fmt::Display for BuildWriteError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use BuildWriteError::*;
         match self {
+            #[cfg(feature = "std")]
             Io(err) => err.fmt(f),
             PayloadLen(err) => err.fmt(f),
             Ipv4Exts(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Icmpv6InIpv4 => write!(f, "Error: ICMPv6 can not be combined with an IPv4 headers (checksum can not be calculated)."),
+            Ipv4OptionsInIpv6 => write!(f, "Error: IPv4 options can not be added to an IPv6 header."),
+            BufSpace(err) => err.fmt(f),
         }
     }
 }
@@ -98,11 +136,13 @@ impl std::error::Error for BuildWriteError {
             Ipv4Exts(err) => Some(err),
             Ipv6Exts(err) => Some(err),
             Icmpv6InIpv4 => None,
+            Ipv4OptionsInIpv6 => None,
+            BufSpace(err) => Some(err),
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::{BuildWriteError::*, *};
     use crate::{err::ValueType, *};
@@ -185,6 +225,23 @@ mod tests {
             .is_icmpv6_in_ipv4()
         );
         assert!(Icmpv6InIpv4.is_icmpv6_in_ipv4());
+        assert!(false == Ipv4OptionsInIpv6.is_icmpv6_in_ipv4());
+    }
+
+    #[test]
+    fn is_ipv4_options_in_ipv6() {
+        assert!(false == Icmpv6InIpv4.is_ipv4_options_in_ipv6());
+        assert!(Ipv4OptionsInIpv6.is_ipv4_options_in_ipv6());
+    }
+
+    #[test]
+    fn buf_space() {
+        let err = BufSpaceError {
+            required_len: 2,
+            available_len: 1,
+        };
+        assert_eq!(Some(&err), BufSpace(err.clone()).buf_space());
+        assert!(Icmpv6InIpv4.buf_space().is_none());
     }
 
     #[test]
@@ -231,6 +288,17 @@ mod tests {
             "Error: ICMPv6 can not be combined with an IPv4 headers (checksum can not be calculated).",
             format!("{}", Icmpv6InIpv4)
         );
+        assert_eq!(
+            "Error: IPv4 options can not be added to an IPv6 header.",
+            format!("{}", Ipv4OptionsInIpv6)
+        );
+        {
+            let err = BufSpaceError {
+                required_len: 2,
+                available_len: 1,
+            };
+            assert_eq!(format!("{}", err), format!("{}", BufSpace(err.clone())));
+        }
     }
 
     #[cfg(feature = "std")]
@@ -260,5 +328,12 @@ mod tests {
         .source()
         .is_some());
         assert!(Icmpv6InIpv4.source().is_none());
+        assert!(Ipv4OptionsInIpv6.source().is_none());
+        assert!(BufSpace(BufSpaceError {
+            required_len: 2,
+            available_len: 1,
+        })
+        .source()
+        .is_some());
     }
 }
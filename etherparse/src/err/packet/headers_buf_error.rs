@@ -0,0 +1,127 @@
+use crate::err::{packet::SliceError, BufSpaceError, ErrorKind};
+
+/// Error when creating a [`crate::PacketHeadersBuf`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeadersBufError {
+    /// Error if the packet is bigger than the inline buffer.
+    BufSpace(BufSpaceError),
+    /// Error while slicing the packet.
+    Slice(SliceError),
+}
+
+impl HeadersBufError {
+    /// Returns the [`crate::err::BufSpaceError`] value if the
+    /// `HeadersBufError` is a `BufSpace`. Otherwise `None` is returned.
+    pub fn buf_space(&self) -> Option<&BufSpaceError> {
+        match self {
+            HeadersBufError::BufSpace(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`crate::err::packet::SliceError`] value if the
+    /// `HeadersBufError` is a `Slice`. Otherwise `None` is returned.
+    pub fn slice(&self) -> Option<&SliceError> {
+        match self {
+            HeadersBufError::Slice(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeadersBufError::*;
+        match self {
+            BufSpace(err) => err.kind(),
+            Slice(err) => err.kind(),
+        }
+    }
+}
+
+impl core::fmt::Display for HeadersBufError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeadersBufError::*;
+        match self {
+            BufSpace(err) => err.fmt(f),
+            Slice(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeadersBufError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use HeadersBufError::*;
+        match self {
+            BufSpace(err) => Some(err),
+            Slice(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeadersBufError::*, *};
+    use crate::{err::Layer, LenSource};
+    use alloc::format;
+
+    fn buf_space_error() -> BufSpaceError {
+        BufSpaceError {
+            required_len: 2,
+            available_len: 1,
+        }
+    }
+
+    fn slice_error() -> SliceError {
+        SliceError::Len(crate::err::LenError {
+            required_len: 1,
+            len: 0,
+            len_source: LenSource::Slice,
+            layer: Layer::Ethernet2Header,
+            layer_start_offset: 0,
+        })
+    }
+
+    #[test]
+    fn accessors() {
+        assert_eq!(
+            Some(&buf_space_error()),
+            BufSpace(buf_space_error()).buf_space()
+        );
+        assert_eq!(None, Slice(slice_error()).buf_space());
+        assert_eq!(Some(&slice_error()), Slice(slice_error()).slice());
+        assert_eq!(None, BufSpace(buf_space_error()).slice());
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let err = BufSpace(buf_space_error());
+        assert_eq!(err, err.clone());
+        assert_eq!(
+            format!("BufSpace({:?})", buf_space_error()),
+            format!("{:?}", err)
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!("{}", buf_space_error()),
+            format!("{}", BufSpace(buf_space_error()))
+        );
+        assert_eq!(
+            format!("{}", slice_error()),
+            format!("{}", Slice(slice_error()))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        use std::error::Error;
+        assert!(BufSpace(buf_space_error()).source().is_some());
+        assert!(Slice(slice_error()).source().is_some());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::err::{ErrorKind, Layer};
+use arrayvec::ArrayVec;
+
+/// Error when slicing a packet including the location in the packet
+/// at which the error occurred (returned by e.g.
+/// [`crate::SlicedPacket::from_ethernet_located`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LocatedSliceError {
+    /// Error that caused the slicing to fail.
+    pub error: super::SliceError,
+
+    /// Offset in bytes from the start of the sliced data to the start
+    /// of the layer that caused the error.
+    ///
+    /// For errors in IP extension headers (e.g. [`super::SliceError::Ipv6Exts`])
+    /// this offset points to the start of the IP header.
+    pub layer_start_offset: usize,
+
+    /// Layers (& their length in bytes) that were successfully parsed
+    /// before the error occurred (in the order they appear in the packet).
+    ///
+    /// IP extension headers are recorded as separate layers. As the number
+    /// of IPv6 extension headers is not limited, only the last
+    /// [`LocatedSliceError::MAX_PARSED_LAYERS`] layers are kept (see
+    /// [`LocatedSliceError::parsed_layer_count`] for the total number).
+    pub parsed_layers: ArrayVec<(Layer, usize), { LocatedSliceError::MAX_PARSED_LAYERS }>,
+
+    /// Number of layers that were successfully parsed before the error
+    /// occurred (including the layers no longer present in
+    /// [`LocatedSliceError::parsed_layers`]).
+    pub parsed_layer_count: usize,
+}
+
+impl LocatedSliceError {
+    /// Maximum number of layers kept in [`LocatedSliceError::parsed_layers`].
+    pub const MAX_PARSED_LAYERS: usize = 16;
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        self.error.kind()
+    }
+}
+
+impl core::fmt::Display for LocatedSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} (layer starting at offset {})",
+            self.error, self.layer_start_offset
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for LocatedSliceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        err::{packet::SliceError, LenError},
+        LenSource,
+    };
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    fn len_error() -> SliceError {
+        SliceError::Len(LenError {
+            required_len: 20,
+            len: 4,
+            len_source: LenSource::Slice,
+            layer: Layer::Ipv4Header,
+            layer_start_offset: 14,
+        })
+    }
+
+    fn located() -> LocatedSliceError {
+        let mut parsed_layers = ArrayVec::new();
+        parsed_layers.push((Layer::Ethernet2Header, 14));
+        LocatedSliceError {
+            error: len_error(),
+            layer_start_offset: 14,
+            parsed_layers,
+            parsed_layer_count: 1,
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq_hash() {
+        let err = located();
+        assert_eq!(err, err.clone());
+        assert!(format!("{:?}", err).starts_with("LocatedSliceError {"));
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!("{} (layer starting at offset 14)", len_error()),
+            format!("{}", located())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(located().source().is_some());
+    }
+}
@@ -1,8 +1,12 @@
-#[cfg(feature = "std")]
 mod build_write_error;
-#[cfg(feature = "std")]
 pub use build_write_error::*;
 
+mod headers_buf_error;
+pub use headers_buf_error::*;
+
+mod located_slice_error;
+pub use located_slice_error::*;
+
 mod slice_error;
 pub use slice_error::*;
 
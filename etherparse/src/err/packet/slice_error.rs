@@ -1,13 +1,17 @@
+use crate::err::ErrorKind;
 use crate::*;
 
 /// Error when slicing an packet from downwards (both
 /// starting from ethernet or ip layer downwards).
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum SliceError {
     /// Length related errors (e.g. not enough data in slice).
     Len(err::LenError),
     /// Error when decoding an Linux SLL header.
     LinuxSll(err::linux_sll::HeaderError),
+    /// Error when decoding an IEEE 802.11 MAC header.
+    Ieee80211(err::ieee80211::HeaderError),
     /// Error when decoding starting at an IP header (v4 or v6).
     Ip(err::ip::HeaderError),
     /// Error when decoding an IPv4 header.
@@ -20,6 +24,39 @@ pub enum SliceError {
     Ipv6Exts(err::ipv6_exts::HeaderError),
     /// Error when decoding a TCP header.
     Tcp(err::tcp::HeaderError),
+    /// Error when one of the configured [`crate::ParseLimits`] was exceeded.
+    ParseLimit(err::ParseLimitError),
+    /// Error when the VLAN tags or MACsec SecTAG are in an invalid order
+    /// (only reported if [`crate::SliceOptions::strict_tag_order`] is enabled).
+    TagOrder(err::TagOrderError),
+}
+
+impl SliceError {
+    /// Returns the [`crate::err::LenError`] if the error is a length error.
+    pub fn len_error(&self) -> Option<&err::LenError> {
+        match self {
+            SliceError::Len(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use SliceError::*;
+        match self {
+            Len(err) => err.kind(),
+            LinuxSll(err) => err.kind(),
+            Ieee80211(err) => err.kind(),
+            Ip(err) => err.kind(),
+            Ipv4(err) => err.kind(),
+            Ipv6(err) => err.kind(),
+            Ipv4Exts(err) => err.kind(),
+            Ipv6Exts(err) => err.kind(),
+            Tcp(err) => err.kind(),
+            ParseLimit(err) => err.kind(),
+            TagOrder(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for SliceError {
@@ -29,12 +66,15 @@ impl core::fmt::Display for SliceError {
         match self {
             Len(err) => err.fmt(f),
             LinuxSll(err) => err.fmt(f),
+            Ieee80211(err) => err.fmt(f),
             Ip(err) => err.fmt(f),
             Ipv4(err) => err.fmt(f),
             Ipv6(err) => err.fmt(f),
             Ipv4Exts(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            ParseLimit(err) => err.fmt(f),
+            TagOrder(err) => err.fmt(f),
         }
     }
 }
@@ -47,12 +87,15 @@ impl std::error::Error for SliceError {
         match self {
             Len(err) => Some(err),
             LinuxSll(err) => Some(err),
+            Ieee80211(err) => Some(err),
             Ip(err) => Some(err),
             Ipv4(err) => Some(err),
             Ipv6(err) => Some(err),
             Ipv4Exts(err) => Some(err),
             Ipv6Exts(err) => Some(err),
             Tcp(err) => Some(err),
+            ParseLimit(err) => Some(err),
+            TagOrder(err) => Some(err),
         }
     }
 }
@@ -68,6 +111,22 @@ mod tests {
         hash::{Hash, Hasher},
     };
 
+    #[test]
+    fn len_error() {
+        let err = err::LenError {
+            required_len: 1,
+            layer: Layer::Ipv4Header,
+            len: 2,
+            len_source: LenSource::Slice,
+            layer_start_offset: 3,
+        };
+        assert_eq!(Some(&err), Len(err.clone()).len_error());
+        assert_eq!(
+            None,
+            Ipv4(err::ipv4::HeaderError::UnexpectedVersion { version_number: 1 }).len_error()
+        );
+    }
+
     #[test]
     fn debug() {
         let err = err::ipv4::HeaderError::UnexpectedVersion { version_number: 1 };
@@ -119,6 +178,12 @@ mod tests {
             );
         }
 
+        // IEEE 802.11 Header
+        {
+            let err = err::ieee80211::HeaderError::NotDataFrame { frame_type: 1 };
+            assert_eq!(format!("{}", err), format!("{}", Ieee80211(err)));
+        }
+
         // IpHeader
         {
             let err = err::ip::HeaderError::UnsupportedIpVersion { version_number: 1 };
@@ -157,6 +222,21 @@ mod tests {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
             assert_eq!(format!("{}", err), format!("{}", Tcp(err)));
         }
+
+        // ParseLimit
+        {
+            let err = err::ParseLimitError::MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader,
+            };
+            assert_eq!(format!("{}", err), format!("{}", ParseLimit(err)));
+        }
+
+        // TagOrder
+        {
+            let err = err::TagOrderError::TooManyVlanTags;
+            assert_eq!(format!("{}", err), format!("{}", TagOrder(err)));
+        }
     }
 
     #[cfg(feature = "std")]
@@ -182,6 +262,12 @@ mod tests {
             assert!(LinuxSll(err).source().is_some());
         }
 
+        // IEEE 802.11 Header
+        {
+            let err = err::ieee80211::HeaderError::NotDataFrame { frame_type: 1 };
+            assert!(Ieee80211(err).source().is_some());
+        }
+
         // IpHeaders
         {
             let err = err::ip::HeaderError::UnsupportedIpVersion { version_number: 1 };
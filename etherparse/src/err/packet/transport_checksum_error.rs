@@ -1,7 +1,8 @@
-use crate::err::ValueTooBigError;
+use crate::err::{ErrorKind, ValueTooBigError};
 
 /// Error while calculating the checksum in a transport header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum TransportChecksumError {
     /// Error if the length of the payload is too
     /// big to be representable by the length fields.
@@ -11,6 +12,17 @@ pub enum TransportChecksumError {
     Icmpv6InIpv4,
 }
 
+impl TransportChecksumError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use TransportChecksumError::*;
+        match self {
+            PayloadLen(err) => err.kind(),
+            Icmpv6InIpv4 => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for TransportChecksumError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use TransportChecksumError::*;
@@ -0,0 +1,168 @@
+use crate::err::{ErrorKind, Layer};
+
+/// Error when decoding a packet exceeded one of the limits configured
+/// in a [`crate::ParseLimits`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseLimitError {
+    /// Error when decoding the layer would exceed the maximum number
+    /// of layers ([`crate::ParseLimits::max_layers`]).
+    MaxLayers {
+        /// Configured maximum number of layers.
+        max_layers: usize,
+        /// Layer that would have exceeded the limit.
+        layer: Layer,
+    },
+    /// Error when the headers of the packet would exceed the maximum
+    /// number of header bytes ([`crate::ParseLimits::max_header_len`]).
+    MaxHeaderLen {
+        /// Configured maximum summed up length of all headers.
+        max_header_len: usize,
+        /// Summed up length of all headers including the header that
+        /// exceeded the limit.
+        header_len: usize,
+        /// Layer that exceeded the limit.
+        layer: Layer,
+    },
+}
+
+impl ParseLimitError {
+    /// Returns the layer that exceeded the limit.
+    #[inline]
+    pub fn layer(&self) -> Layer {
+        use ParseLimitError::*;
+        match self {
+            MaxLayers { layer, .. } => *layer,
+            MaxHeaderLen { layer, .. } => *layer,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ParseLimitError::*;
+        match self {
+            MaxLayers { .. } => ErrorKind::ContentInvalid,
+            MaxHeaderLen { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for ParseLimitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use ParseLimitError::*;
+        match self {
+            MaxLayers { max_layers, layer } => write!(f, "{}: Parsing aborted as decoding the {} would exceed the limit of {} layers.", layer.error_title(), layer, max_layers),
+            MaxHeaderLen { max_header_len, header_len, layer } => write!(f, "{}: Parsing aborted as the headers up to & including the {} have a length of {} bytes, which exceeds the limit of {} bytes.", layer.error_title(), layer, header_len, max_header_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseLimitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseLimitError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn layer() {
+        assert_eq!(
+            Layer::TcpHeader,
+            MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader
+            }
+            .layer()
+        );
+        assert_eq!(
+            Layer::Ipv4Header,
+            MaxHeaderLen {
+                max_header_len: 1,
+                header_len: 2,
+                layer: Layer::Ipv4Header
+            }
+            .layer()
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "MaxLayers { max_layers: 1, layer: TcpHeader }",
+            format!(
+                "{:?}",
+                MaxLayers {
+                    max_layers: 1,
+                    layer: Layer::TcpHeader
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = MaxLayers {
+            max_layers: 1,
+            layer: Layer::TcpHeader,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "TCP Header Error: Parsing aborted as decoding the TCP header would exceed the limit of 1 layers.",
+            format!(
+                "{}",
+                MaxLayers {
+                    max_layers: 1,
+                    layer: Layer::TcpHeader
+                }
+            )
+        );
+        assert_eq!(
+            "IPv4 Header Error: Parsing aborted as the headers up to & including the IPv4 header have a length of 34 bytes, which exceeds the limit of 20 bytes.",
+            format!(
+                "{}",
+                MaxHeaderLen {
+                    max_header_len: 20,
+                    header_len: 34,
+                    layer: Layer::Ipv4Header
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(MaxLayers {
+            max_layers: 1,
+            layer: Layer::TcpHeader
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -10,6 +10,7 @@ use crate::err::*;
 /// `read` functions as all errors from these functions can be converted into this type.
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[non_exhaustive]
 pub enum ReadError {
     /// IO error was encountered while reading header or expected packet contents.
     Io(std::io::Error),
@@ -41,6 +42,16 @@ pub enum ReadError {
 
     /// Error while parsing a TCP extension header.
     Tcp(tcp::HeaderError),
+
+    /// Error when one of the configured [`crate::ParseLimits`] was exceeded.
+    ParseLimit(ParseLimitError),
+
+    /// Error when the VLAN tags or MACsec SecTAG are in an invalid order
+    /// (only reported if [`crate::SliceOptions::strict_tag_order`] is enabled).
+    TagOrder(TagOrderError),
+
+    /// Error while parsing an IEEE 802.11 MAC header.
+    Ieee80211(ieee80211::HeaderError),
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -105,6 +116,44 @@ impl ReadError {
             _ => None,
         }
     }
+    pub fn parse_limit(&self) -> Option<&ParseLimitError> {
+        match self {
+            ReadError::ParseLimit(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn tag_order(&self) -> Option<&TagOrderError> {
+        match self {
+            ReadError::TagOrder(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn ieee80211(&self) -> Option<&ieee80211::HeaderError> {
+        match self {
+            ReadError::Ieee80211(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use ReadError::*;
+        match self {
+            Io(_) => ErrorKind::Io,
+            Len(err) => err.kind(),
+            DoubleVlan(err) => err.kind(),
+            Ip(err) => err.kind(),
+            IpAuth(err) => err.kind(),
+            Ipv4(err) => err.kind(),
+            Ipv6(err) => err.kind(),
+            Ipv6Exts(err) => err.kind(),
+            LinuxSll(err) => err.kind(),
+            Tcp(err) => err.kind(),
+            ParseLimit(err) => err.kind(),
+            TagOrder(err) => err.kind(),
+            Ieee80211(err) => err.kind(),
+        }
+    }
 }
 
 impl core::fmt::Display for ReadError {
@@ -121,6 +170,9 @@ impl core::fmt::Display for ReadError {
             Ipv6Exts(err) => err.fmt(f),
             LinuxSll(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            ParseLimit(err) => err.fmt(f),
+            TagOrder(err) => err.fmt(f),
+            Ieee80211(err) => err.fmt(f),
         }
     }
 }
@@ -140,6 +192,9 @@ impl std::error::Error for ReadError {
             ReadError::Ipv6Exts(err) => Some(err),
             ReadError::LinuxSll(err) => Some(err),
             ReadError::Tcp(err) => Some(err),
+            ReadError::ParseLimit(err) => Some(err),
+            ReadError::TagOrder(err) => Some(err),
+            ReadError::Ieee80211(err) => Some(err),
         }
     }
 }
@@ -416,6 +471,25 @@ impl From<linux_sll::HeaderSliceError> for ReadError {
     }
 }
 
+// ieee 802.11 error conversions
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<ieee80211::HeaderError> for ReadError {
+    fn from(value: ieee80211::HeaderError) -> Self {
+        ReadError::Ieee80211(value)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<ieee80211::HeaderSliceError> for ReadError {
+    fn from(value: ieee80211::HeaderSliceError) -> Self {
+        use ieee80211::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Ieee80211(err),
+        }
+    }
+}
+
 // packet error conversions
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl From<packet::SliceError> for ReadError {
@@ -430,10 +504,29 @@ impl From<packet::SliceError> for ReadError {
             Ipv4Exts(err) => ReadError::IpAuth(err),
             Ipv6Exts(err) => ReadError::Ipv6Exts(err),
             Tcp(err) => ReadError::Tcp(err),
+            ParseLimit(err) => ReadError::ParseLimit(err),
+            TagOrder(err) => ReadError::TagOrder(err),
+            Ieee80211(err) => ReadError::Ieee80211(err),
         }
     }
 }
 
+// parse limit error conversions
+
+impl From<ParseLimitError> for ReadError {
+    fn from(value: ParseLimitError) -> Self {
+        ReadError::ParseLimit(value)
+    }
+}
+
+// tag order error conversions
+
+impl From<TagOrderError> for ReadError {
+    fn from(value: TagOrderError) -> Self {
+        ReadError::TagOrder(value)
+    }
+}
+
 // tcp error conversions
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl From<tcp::HeaderError> for ReadError {
@@ -476,7 +569,7 @@ mod tests {
 
     #[test]
     fn debug_source() {
-        let test_values: [(&str, ReadError); 10] = [
+        let test_values: [(&str, ReadError); 13] = [
             (
                 "Len",
                 Len(LenError {
@@ -526,6 +619,18 @@ mod tests {
                 "Tcp",
                 Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
             ),
+            (
+                "ParseLimit",
+                ParseLimit(ParseLimitError::MaxLayers {
+                    max_layers: 1,
+                    layer: Layer::TcpHeader,
+                }),
+            ),
+            ("TagOrder", TagOrder(TagOrderError::TooManyVlanTags)),
+            (
+                "Ieee80211",
+                Ieee80211(ieee80211::HeaderError::NotDataFrame { frame_type: 0 }),
+            ),
         ];
         for (prefix, value) in &test_values {
             // display
@@ -546,7 +651,7 @@ mod tests {
 
     #[test]
     fn display_source() {
-        let test_values: [ReadError; 10] = [
+        let test_values: [ReadError; 13] = [
             Len(LenError {
                 required_len: 0,
                 len: 0,
@@ -569,6 +674,12 @@ mod tests {
             Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
             LinuxSll(linux_sll::HeaderError::UnsupportedPacketTypeField { packet_type: 123 }),
             Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
+            ParseLimit(ParseLimitError::MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader,
+            }),
+            TagOrder(TagOrderError::TooManyVlanTags),
+            Ieee80211(ieee80211::HeaderError::NotDataFrame { frame_type: 0 }),
         ];
         for value in &test_values {
             // display
@@ -660,6 +771,55 @@ mod tests {
         // tcp
         assert_eq!(Tcp(tcp_error()).tcp(), Some(&tcp_error()));
         assert_eq!(IpAuth(ip_auth_error()).tcp(), None);
+
+        // ieee80211
+        {
+            let ieee80211_error = || ieee80211::HeaderError::NotDataFrame { frame_type: 0 };
+            assert_eq!(
+                Ieee80211(ieee80211_error()).ieee80211(),
+                Some(&ieee80211_error())
+            );
+            assert_eq!(IpAuth(ip_auth_error()).ieee80211(), None);
+        }
+
+        // parse_limit
+        {
+            let parse_limit_error = || ParseLimitError::MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader,
+            };
+            assert_eq!(
+                ParseLimit(parse_limit_error()).parse_limit(),
+                Some(&parse_limit_error())
+            );
+            assert_eq!(IpAuth(ip_auth_error()).parse_limit(), None);
+            assert_eq!(
+                ReadError::from(parse_limit_error()).parse_limit(),
+                Some(&parse_limit_error())
+            );
+            assert_eq!(
+                ReadError::from(packet::SliceError::ParseLimit(parse_limit_error())).parse_limit(),
+                Some(&parse_limit_error())
+            );
+        }
+
+        // tag_order
+        {
+            let tag_order_error = || TagOrderError::TooManyVlanTags;
+            assert_eq!(
+                TagOrder(tag_order_error()).tag_order(),
+                Some(&tag_order_error())
+            );
+            assert_eq!(IpAuth(ip_auth_error()).tag_order(), None);
+            assert_eq!(
+                ReadError::from(tag_order_error()).tag_order(),
+                Some(&tag_order_error())
+            );
+            assert_eq!(
+                ReadError::from(packet::SliceError::TagOrder(tag_order_error())).tag_order(),
+                Some(&tag_order_error())
+            );
+        }
     }
 
     #[test]
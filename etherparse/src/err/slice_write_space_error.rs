@@ -1,4 +1,4 @@
-use crate::err::Layer;
+use crate::err::{ErrorKind, Layer};
 
 /// Error when not enough space is available in a slice
 /// to write a packet or header to it.
@@ -19,6 +19,13 @@ pub struct SliceWriteSpaceError {
     pub layer_start_offset: usize,
 }
 
+impl SliceWriteSpaceError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::Len
+    }
+}
+
 impl core::fmt::Display for SliceWriteSpaceError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.layer_start_offset > 0 {
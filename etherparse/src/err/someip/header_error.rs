@@ -0,0 +1,133 @@
+use crate::err::ErrorKind;
+use crate::SomeipMessageType;
+
+/// Errors in a SOME/IP header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the "length" field is smaller then the part of the
+    /// header it covers (8 bytes, 12 bytes if the message type indicates
+    /// a SOME/IP-TP segment).
+    LengthTooSmall {
+        /// Type of the message.
+        message_type: SomeipMessageType,
+        /// Value of the "length" field.
+        length: u32,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            LengthTooSmall { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            LengthTooSmall {
+                message_type,
+                length,
+            } => write!(
+                f,
+                "SOME/IP Header Error: Encountered a length of {} bytes in a {:?} message, but the length is required to be at least {} bytes.",
+                length,
+                message_type,
+                if message_type.is_tp() { 12 } else { 8 }
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "LengthTooSmall { message_type: 0x00 (Request), length: 7 }",
+            format!(
+                "{:?}",
+                LengthTooSmall {
+                    message_type: SomeipMessageType::REQUEST,
+                    length: 7
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = LengthTooSmall {
+            message_type: SomeipMessageType::REQUEST,
+            length: 7,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "SOME/IP Header Error: Encountered a length of 7 bytes in a 0x00 (Request) message, but the length is required to be at least 8 bytes.",
+            format!(
+                "{}",
+                LengthTooSmall {
+                    message_type: SomeipMessageType::REQUEST,
+                    length: 7
+                }
+            )
+        );
+        assert_eq!(
+            "SOME/IP Header Error: Encountered a length of 11 bytes in a 0x20 (TP Request) message, but the length is required to be at least 12 bytes.",
+            format!(
+                "{}",
+                LengthTooSmall {
+                    message_type: SomeipMessageType::TP_REQUEST,
+                    length: 11
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(LengthTooSmall {
+            message_type: SomeipMessageType::REQUEST,
+            length: 7
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::err::ErrorKind;
+use crate::EtherType;
+
+/// Error when the VLAN tags or the MACsec SecTAG of a frame are in an order
+/// that is not allowed by IEEE 802.1Q & IEEE 802.1AE (only reported if
+/// [`crate::SliceOptions::strict_tag_order`] is enabled).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TagOrderError {
+    /// Error when a MACsec SecTAG follows a VLAN tag (the SecTAG has to
+    /// directly follow the MAC addresses).
+    MacsecNotOutermost,
+
+    /// Error when a C-TAG (ether type `0x8100`) is followed by another
+    /// VLAN tag (only S-TAGs are allowed to precede other VLAN tags).
+    CTagNotInnermost {
+        /// Ether type of the tag following the C-TAG.
+        next_ether_type: EtherType,
+    },
+
+    /// Error when an S-TAG (ether type `0x88A8` or `0x9100`) follows
+    /// another VLAN tag.
+    STagNotOutermost {
+        /// Ether type identifying the S-TAG.
+        ether_type: EtherType,
+    },
+
+    /// Error when a frame contains more than two VLAN tags.
+    TooManyVlanTags,
+}
+
+impl TagOrderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use TagOrderError::*;
+        match self {
+            MacsecNotOutermost => ErrorKind::ContentInvalid,
+            CTagNotInnermost { .. } => ErrorKind::ContentInvalid,
+            STagNotOutermost { .. } => ErrorKind::ContentInvalid,
+            TooManyVlanTags => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for TagOrderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use TagOrderError::*;
+        match self {
+            MacsecNotOutermost => write!(f, "Tag Order Error: Encountered a MACsec SecTAG after a VLAN tag (the SecTAG is required to directly follow the MAC addresses)."),
+            CTagNotInnermost { next_ether_type } => write!(f, "Tag Order Error: Encountered a VLAN tag with the ether type {:?} after a C-TAG (only S-TAGs are allowed to precede other VLAN tags).", next_ether_type),
+            STagNotOutermost { ether_type } => write!(f, "Tag Order Error: Encountered an S-TAG with the ether type {:?} after another VLAN tag (S-TAGs are required to be the outermost VLAN tag).", ether_type),
+            TooManyVlanTags => write!(f, "Tag Order Error: Encountered more than two VLAN tags."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for TagOrderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TagOrderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("MacsecNotOutermost", format!("{:?}", MacsecNotOutermost));
+        assert_eq!(
+            format!(
+                "CTagNotInnermost {{ next_ether_type: {:?} }}",
+                EtherType::VLAN_TAGGED_FRAME
+            ),
+            format!(
+                "{:?}",
+                CTagNotInnermost {
+                    next_ether_type: EtherType::VLAN_TAGGED_FRAME
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = TooManyVlanTags;
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Tag Order Error: Encountered a MACsec SecTAG after a VLAN tag (the SecTAG is required to directly follow the MAC addresses).",
+            format!("{}", MacsecNotOutermost)
+        );
+        assert_eq!(
+            format!(
+                "Tag Order Error: Encountered a VLAN tag with the ether type {:?} after a C-TAG (only S-TAGs are allowed to precede other VLAN tags).",
+                EtherType::VLAN_TAGGED_FRAME
+            ),
+            format!(
+                "{}",
+                CTagNotInnermost {
+                    next_ether_type: EtherType::VLAN_TAGGED_FRAME
+                }
+            )
+        );
+        assert_eq!(
+            format!(
+                "Tag Order Error: Encountered an S-TAG with the ether type {:?} after another VLAN tag (S-TAGs are required to be the outermost VLAN tag).",
+                EtherType::PROVIDER_BRIDGING
+            ),
+            format!(
+                "{}",
+                STagNotOutermost {
+                    ether_type: EtherType::PROVIDER_BRIDGING
+                }
+            )
+        );
+        assert_eq!(
+            "Tag Order Error: Encountered more than two VLAN tags.",
+            format!("{}", TooManyVlanTags)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(TooManyVlanTags.source().is_none());
+    }
+}
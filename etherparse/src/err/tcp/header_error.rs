@@ -1,11 +1,24 @@
+use crate::err::ErrorKind;
+
 /// Errors that can be encountered while decoding a TCP header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum HeaderError {
     /// Error when the data_offset is so small that the data would
     /// start within the TCP header itself.
     DataOffsetTooSmall { data_offset: u8 },
 }
 
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            DataOffsetTooSmall { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
 impl core::fmt::Display for HeaderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use HeaderError::*;
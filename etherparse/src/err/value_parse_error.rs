@@ -0,0 +1,99 @@
+use crate::err;
+use crate::err::ErrorKind;
+
+/// Error if a value could not be parsed from a string (e.g. when parsing
+/// an [`crate::EtherType`] via [`core::str::FromStr`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ValueParseError {
+    /// Type of value that was parsed.
+    pub value_type: err::ValueType,
+}
+
+impl ValueParseError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::ContentInvalid
+    }
+}
+
+impl core::fmt::Display for ValueParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Error string could not be parsed as '{}' (expected a decimal number, a hexadecimal number starting with '0x' or a known name)",
+            self.value_type
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ValueParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{collections::hash_map::DefaultHasher, error::Error, format, hash::Hasher};
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!(
+                "{}",
+                ValueParseError {
+                    value_type: err::ValueType::EtherType
+                }
+            ),
+            "Error string could not be parsed as 'EtherType' (expected a decimal number, a hexadecimal number starting with '0x' or a known name)"
+        );
+    }
+
+    #[test]
+    fn dbg() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                ValueParseError {
+                    value_type: err::ValueType::IpNumber
+                }
+            ),
+            format!(
+                "ValueParseError {{ value_type: {:?} }}",
+                err::ValueType::IpNumber
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        use core::hash::Hash;
+        let err = ValueParseError {
+            value_type: err::ValueType::ArpOperation,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn source() {
+        assert!(ValueParseError {
+            value_type: err::ValueType::ArpHardwareId
+        }
+        .source()
+        .is_none());
+    }
+}
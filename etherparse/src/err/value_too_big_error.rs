@@ -1,4 +1,4 @@
-use crate::err;
+use crate::err::{self, ErrorKind};
 use core::{cmp::Eq, cmp::PartialEq, fmt::Debug, fmt::Display, hash::Hash};
 
 /// Error if a value exceeds the maximum allowed value.
@@ -14,6 +14,16 @@ pub struct ValueTooBigError<T: Sized + Clone + Display + Debug + Eq + PartialEq
     pub value_type: err::ValueType,
 }
 
+impl<T> ValueTooBigError<T>
+where
+    T: Sized + Clone + Display + Debug + Eq + PartialEq + Hash,
+{
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::ContentInvalid
+    }
+}
+
 impl<T> core::fmt::Display for ValueTooBigError<T>
 where
     T: Sized + Clone + Display + Debug + Eq + PartialEq + Hash,
@@ -1,5 +1,6 @@
 /// Types of values that have a limited allowed value range
-/// and can cause an [`crate::err::ValueTooBigError`].
+/// and can cause an [`crate::err::ValueTooBigError`] or that can
+/// be parsed from a string and can cause an [`crate::err::ValueParseError`].
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ValueType {
     /// VLAN identifier field present in a [`crate::SingleVlanHeader`].
@@ -39,6 +40,33 @@ pub enum ValueType {
     Icmpv6PayloadLength,
     /// Packet type of a Linux Cooked Capture v1 (SLL)
     LinuxSllType,
+    /// Length of a [`crate::BoundedPayload`].
+    BoundedPayloadLength,
+    /// Backbone service instance identifier (I-SID) present in an
+    /// [`crate::Ieee8021ahHeader`].
+    Ieee8021ahIsid,
+    /// Length of the options in an [`crate::MobilityHeader`].
+    Ipv6MobilityOptionsLength,
+    /// MACsec "association number" (AN) field present in a
+    /// [`crate::MacsecHeader`].
+    MacsecAn,
+    /// MACsec "short length" (SL) field present in a
+    /// [`crate::MacsecHeader`].
+    MacsecShortLen,
+    /// NTP "version number" (VN) field present in a [`crate::NtpHeader`].
+    NtpVersion,
+    /// Length of the value of an [`crate::LldpTlv`] (9 bit length field).
+    LldpTlvValueLength,
+    /// Prefix length of an [`crate::Ipv4Network`] or [`crate::Ipv6Network`].
+    IpNetworkPrefixLen,
+    /// [`crate::EtherType`] parsed from a string.
+    EtherType,
+    /// [`crate::IpNumber`] parsed from a string.
+    IpNumber,
+    /// [`crate::ArpOperation`] parsed from a string.
+    ArpOperation,
+    /// [`crate::ArpHardwareId`] parsed from a string.
+    ArpHardwareId,
 }
 
 impl core::fmt::Display for ValueType {
@@ -59,6 +87,18 @@ impl core::fmt::Display for ValueType {
             TcpPayloadLengthIpv6 => write!(f, "TCP Payload Length (in IPv6 checksum calculation)"),
             Icmpv6PayloadLength => write!(f, "ICMPv6 Payload Length"),
             LinuxSllType => write!(f, "Linux Cooked Capture v1 (SLL)"),
+            BoundedPayloadLength => write!(f, "Bounded Payload Length"),
+            Ieee8021ahIsid => write!(f, "IEEE 802.1ah I-SID"),
+            Ipv6MobilityOptionsLength => write!(f, "IPv6 Mobility Header Options Length"),
+            MacsecAn => write!(f, "MACsec AN (Association Number)"),
+            MacsecShortLen => write!(f, "MACsec SL (Short Length)"),
+            NtpVersion => write!(f, "NTP VN (Version Number)"),
+            LldpTlvValueLength => write!(f, "LLDP TLV Value Length"),
+            IpNetworkPrefixLen => write!(f, "IP Network Prefix Length"),
+            EtherType => write!(f, "EtherType"),
+            IpNumber => write!(f, "IP Number"),
+            ArpOperation => write!(f, "ARP Operation"),
+            ArpHardwareId => write!(f, "ARP Hardware Id"),
         }
     }
 }
@@ -126,5 +166,26 @@ mod test {
             &format!("{}", TcpPayloadLengthIpv6)
         );
         assert_eq!("ICMPv6 Payload Length", &format!("{}", Icmpv6PayloadLength));
+        assert_eq!(
+            "Bounded Payload Length",
+            &format!("{}", BoundedPayloadLength)
+        );
+        assert_eq!("IEEE 802.1ah I-SID", &format!("{}", Ieee8021ahIsid));
+        assert_eq!(
+            "IPv6 Mobility Header Options Length",
+            &format!("{}", Ipv6MobilityOptionsLength)
+        );
+        assert_eq!("MACsec AN (Association Number)", &format!("{}", MacsecAn));
+        assert_eq!("MACsec SL (Short Length)", &format!("{}", MacsecShortLen));
+        assert_eq!("NTP VN (Version Number)", &format!("{}", NtpVersion));
+        assert_eq!("LLDP TLV Value Length", &format!("{}", LldpTlvValueLength));
+        assert_eq!(
+            "IP Network Prefix Length",
+            &format!("{}", IpNetworkPrefixLen)
+        );
+        assert_eq!("EtherType", &format!("{}", EtherType));
+        assert_eq!("IP Number", &format!("{}", IpNumber));
+        assert_eq!("ARP Operation", &format!("{}", ArpOperation));
+        assert_eq!("ARP Hardware Id", &format!("{}", ArpHardwareId));
     }
 }
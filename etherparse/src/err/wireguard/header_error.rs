@@ -0,0 +1,140 @@
+use crate::err::ErrorKind;
+use crate::WireguardMessageType;
+
+/// Errors in a WireGuard message header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// Error when the message type is not one of the four message types
+    /// defined by WireGuard (`1` to `4`).
+    UnknownMessageType {
+        /// The unexpected message type.
+        message_type: u8,
+    },
+
+    /// Error when the three reserved bytes after the message type are
+    /// not zero.
+    NonZeroReserved {
+        /// The value of the reserved bytes.
+        reserved: [u8; 3],
+    },
+
+    /// Error when a fixed size message (handshake initiation, handshake
+    /// response or cookie reply) is longer than the size defined for
+    /// the message type.
+    UnexpectedLen {
+        /// Type of the message.
+        message_type: WireguardMessageType,
+        /// Length of the message.
+        len: usize,
+    },
+}
+
+impl HeaderError {
+    /// Returns the coarse category of the error (see [`crate::err::ErrorKind`]).
+    pub fn kind(&self) -> ErrorKind {
+        use HeaderError::*;
+        match self {
+            UnknownMessageType { .. } => ErrorKind::ContentInvalid,
+            NonZeroReserved { .. } => ErrorKind::ContentInvalid,
+            UnexpectedLen { .. } => ErrorKind::ContentInvalid,
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnknownMessageType { message_type } => write!(
+                f,
+                "WireGuard Header Error: Encountered '{}' as message type, but only '1' to '4' are defined.",
+                message_type
+            ),
+            NonZeroReserved { reserved } => write!(
+                f,
+                "WireGuard Header Error: Encountered {:?} as reserved bytes, but they are required to be zero.",
+                reserved
+            ),
+            UnexpectedLen { message_type, len } => write!(
+                f,
+                "WireGuard Header Error: Encountered a {:?} message with a length of {} bytes, but the message is required to be exactly {} bytes long.",
+                message_type,
+                len,
+                message_type.min_len()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderError::*, *};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnknownMessageType { message_type: 6 }",
+            format!("{:?}", UnknownMessageType { message_type: 6 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HeaderError::UnknownMessageType { message_type: 6 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "WireGuard Header Error: Encountered '6' as message type, but only '1' to '4' are defined.",
+            format!("{}", UnknownMessageType { message_type: 6 })
+        );
+        assert_eq!(
+            "WireGuard Header Error: Encountered [0, 1, 2] as reserved bytes, but they are required to be zero.",
+            format!("{}", NonZeroReserved { reserved: [0, 1, 2] })
+        );
+        assert_eq!(
+            "WireGuard Header Error: Encountered a CookieReply message with a length of 65 bytes, but the message is required to be exactly 64 bytes long.",
+            format!(
+                "{}",
+                UnexpectedLen {
+                    message_type: WireguardMessageType::CookieReply,
+                    len: 65
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnknownMessageType { message_type: 6 }.source().is_none());
+    }
+}
@@ -0,0 +1,330 @@
+use crate::*;
+
+/// Formats the bytes of a [`SlicedPacket`] as a hexdump where each line is
+/// annotated with the layer the bytes belong to.
+///
+/// Every layer starts on a new line (a line contains at most 16 bytes) so
+/// malformed or unexpected bytes can be attributed to the header they
+/// were decoded as:
+///
+/// ```
+/// use etherparse::{fmt::AnnotatedHexdump, PacketBuilder, SlicedPacket};
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+///     .udp(53, 1234)
+///     .write(&mut packet, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// println!("{}", AnnotatedHexdump(&sliced));
+/// ```
+///
+/// Prints:
+///
+/// ```text
+/// 0000  07 08 09 0a 0b 0c 01 02 03 04 05 06 08 00        eth
+/// 000e  45 00 00 20 00 00 40 00 14 11 52 cb 0a 00 00 01  ipv4
+/// 001e  0a 00 00 02                                      ipv4
+/// 0022  00 35 04 d2 00 0c e2 c6                          udp
+/// 002a  01 02 03 04                                      payload
+/// ```
+///
+/// Bytes not referenced by the [`SlicedPacket`] (e.g. the ethernet padding
+/// after the IP payload) are not part of the hexdump.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnnotatedHexdump<'a>(pub &'a SlicedPacket<'a>);
+
+impl AnnotatedHexdump<'_> {
+    /// Number of bytes written per line.
+    pub const BYTES_PER_LINE: usize = 16;
+}
+
+impl core::fmt::Display for AnnotatedHexdump<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let packet = self.0;
+        let mut offset = 0;
+
+        // backbone headers (provider backbone bridging)
+        if let Some(backbone) = &packet.backbone {
+            write_link_layers(f, &mut offset, &backbone.link, &backbone.vlan)?;
+            write_layer(f, &mut offset, "itag", backbone.itag.header_slice())?;
+        }
+
+        // link layer & vlan
+        write_link_layers(f, &mut offset, &packet.link, &packet.vlan)?;
+
+        // ip headers & extensions
+        match &packet.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                write_layer(f, &mut offset, "ipv4", ipv4.header().slice())?;
+                if let Some(auth) = ipv4.extensions().auth {
+                    write_layer(f, &mut offset, "ipv4-ext", auth.slice())?;
+                }
+            }
+            Some(NetSlice::Ipv6(ipv6)) => {
+                write_layer(f, &mut offset, "ipv6", ipv6.header().slice())?;
+                write_layer(f, &mut offset, "ipv6-ext", ipv6.extensions().slice())?;
+            }
+            None => {}
+        }
+
+        // transport header & payload
+        if let Some(transport) = &packet.transport {
+            let (name, slice, payload) = match transport {
+                TransportSlice::Icmpv4(s) => ("icmpv4", s.slice(), s.payload()),
+                TransportSlice::Icmpv6(s) => ("icmpv6", s.slice(), s.payload()),
+                TransportSlice::Udp(s) => ("udp", s.slice(), s.payload()),
+                TransportSlice::Tcp(s) => ("tcp", s.slice(), s.payload()),
+            };
+            write_layer(f, &mut offset, name, &slice[..slice.len() - payload.len()])?;
+            write_layer(f, &mut offset, "payload", payload)
+        } else if let Some(ip_payload) = packet.ip_payload() {
+            write_layer(f, &mut offset, "payload", ip_payload.payload)
+        } else if let Some(ether_payload) = packet.ether_payload() {
+            write_layer(f, &mut offset, "payload", ether_payload.payload)
+        } else if let Some(LinkSlice::LinuxSll(sll)) = &packet.link {
+            write_layer(f, &mut offset, "payload", sll.payload().payload)
+        } else if let Some(LinkSlice::LinuxSllPayload(p)) = &packet.link {
+            write_layer(f, &mut offset, "payload", p.payload)
+        } else if let Some(LinkSlice::Ieee80211(wlan)) = &packet.link {
+            write_layer(f, &mut offset, "payload", wlan.payload())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Writes the link layer & vlan headers as hexdump lines & advances the offset.
+fn write_link_layers(
+    f: &mut core::fmt::Formatter<'_>,
+    offset: &mut usize,
+    link: &Option<LinkSlice<'_>>,
+    vlan: &Option<VlanSlice<'_>>,
+) -> core::fmt::Result {
+    // link layer
+    match link {
+        Some(LinkSlice::Ethernet2(eth)) => {
+            write_layer(f, offset, "eth", eth.header_slice())?;
+        }
+        Some(LinkSlice::LinuxSll(sll)) => {
+            write_layer(f, offset, "sll", sll.header_slice())?;
+        }
+        Some(LinkSlice::Ieee80211(wlan)) => {
+            write_layer(f, offset, "wlan", wlan.header_slice())?;
+            if wlan.ether_payload().is_some() {
+                write_layer(
+                    f,
+                    offset,
+                    "llc",
+                    &wlan.payload()[..Ieee80211DataSlice::LLC_SNAP_LEN],
+                )?;
+            }
+        }
+        Some(LinkSlice::EtherPayload(_)) | Some(LinkSlice::LinuxSllPayload(_)) | None => {}
+    }
+
+    // vlan
+    match vlan {
+        Some(VlanSlice::SingleVlan(vlan)) => {
+            write_layer(f, offset, "vlan", vlan.header_slice())?;
+        }
+        Some(VlanSlice::DoubleVlan(vlan)) => {
+            write_layer(f, offset, "vlan", vlan.outer().header_slice())?;
+            write_layer(f, offset, "vlan", vlan.inner().header_slice())?;
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+/// Writes the bytes of one layer as hexdump lines & advances the offset.
+fn write_layer(
+    f: &mut core::fmt::Formatter<'_>,
+    offset: &mut usize,
+    name: &str,
+    bytes: &[u8],
+) -> core::fmt::Result {
+    for line in bytes.chunks(AnnotatedHexdump::BYTES_PER_LINE) {
+        write!(f, "{:04x} ", offset)?;
+        for b in line {
+            write!(f, " {:02x}", b)?;
+        }
+        for _ in line.len()..AnnotatedHexdump::BYTES_PER_LINE {
+            write!(f, "   ")?;
+        }
+        writeln!(f, "  {}", name)?;
+        *offset += line.len();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    use alloc::vec::Vec;
+    use alloc::{format, string::ToString};
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn fmt() {
+        // ethernet, vlan, ipv4 & udp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(VlanId::try_new(12).unwrap())
+                .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+                .udp(53, 1234)
+                .write(&mut packet, &[1, 2, 3, 4])
+                .unwrap();
+            let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+            let ip = &packet[18..38];
+            let udp = &packet[38..46];
+            assert_eq!(
+                format!(
+                    "0000  07 08 09 0a 0b 0c 01 02 03 04 05 06 81 00        eth\n\
+                     000e  00 0c 08 00                                      vlan\n\
+                     0012  {}  ipv4\n\
+                     0022  {}                                      ipv4\n\
+                     0026  {}                          udp\n\
+                     002e  01 02 03 04                                      payload\n",
+                    hex(&ip[..16]),
+                    hex(&ip[16..]),
+                    hex(udp),
+                ),
+                AnnotatedHexdump(&sliced).to_string()
+            );
+        }
+
+        // ipv6 & tcp without payload
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6([0; 16], [1; 16], 20)
+                .tcp(1, 2, 3, 4)
+                .write(&mut packet, &[])
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            let dump = AnnotatedHexdump(&sliced).to_string();
+            let lines: Vec<_> = dump.lines().collect();
+            assert_eq!(5, lines.len());
+            assert!(lines[0].starts_with("0000  60"));
+            for line in &lines[..3] {
+                assert!(line.ends_with("  ipv6"));
+            }
+            assert!(lines[3].starts_with("0028  00 01 00 02"));
+            assert!(lines[3].ends_with("  tcp"));
+            assert!(lines[4].starts_with("0038 "));
+            assert!(lines[4].ends_with("  tcp"));
+        }
+
+        // unknown ether type
+        {
+            let sliced = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+            assert_eq!(
+                "0000  01 02                                            payload\n",
+                AnnotatedHexdump(&sliced).to_string()
+            );
+        }
+
+        // linux sll with non ether type payload
+        {
+            let packet = [
+                0, 0, // packet type
+                0, 1, // arp hardware type
+                0, 6, // link layer address length
+                1, 2, 3, 4, 5, 6, 0, 0, // link layer address
+                0, 1, // protocol type (novell 802.3)
+                9, 8,
+            ];
+            let sliced = SlicedPacket::from_linux_sll(&packet).unwrap();
+            assert_eq!(
+                format!(
+                    "0000  {}  sll\n\
+                     0010  09 08                                            payload\n",
+                    hex(&packet[..16])
+                ),
+                AnnotatedHexdump(&sliced).to_string()
+            );
+        }
+
+        // ieee 802.11 data frame with LLC/SNAP header
+        {
+            let packet = [
+                0x08, 0x01, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, // 802.11 header
+                13, 14, 15, 16, 17, 18, 0x10, 0, // 802.11 header
+                0xAA, 0xAA, 0x03, 0, 0, 0, 0x88, 0xB5, // LLC/SNAP
+                9, 8,
+            ];
+            let sliced = SlicedPacket::from_ieee80211(&packet).unwrap();
+            assert_eq!(
+                "0000  08 01 00 00 01 02 03 04 05 06 07 08 09 0a 0b 0c  wlan\n\
+                 0010  0d 0e 0f 10 11 12 10 00                          wlan\n\
+                 0018  aa aa 03 00 00 00 88 b5                          llc\n\
+                 0020  09 08                                            payload\n",
+                AnnotatedHexdump(&sliced).to_string()
+            );
+        }
+
+        // provider backbone bridging
+        {
+            let packet = [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x88, 0xE7, // backbone ethernet II
+                0x60, 0x12, 0x34, 0x56, // I-TAG
+                13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0x88,
+                0xB5, // customer ethernet II
+                9, 8,
+            ];
+            let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+            assert_eq!(
+                "0000  01 02 03 04 05 06 07 08 09 0a 0b 0c 88 e7        eth\n\
+                 000e  60 12 34 56                                      itag\n\
+                 0012  0d 0e 0f 10 11 12 13 14 15 16 17 18 88 b5        eth\n\
+                 0020  09 08                                            payload\n",
+                AnnotatedHexdump(&sliced).to_string()
+            );
+        }
+
+        // empty
+        {
+            let sliced = SlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+            };
+            assert_eq!("", AnnotatedHexdump(&sliced).to_string());
+        }
+    }
+
+    #[test]
+    fn sliced_packet_annotated_hexdump() {
+        let sliced = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+        assert_eq!(
+            AnnotatedHexdump(&sliced).to_string(),
+            sliced.annotated_hexdump().to_string()
+        );
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let sliced = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+        let dump = AnnotatedHexdump(&sliced);
+        assert_eq!(dump, dump.clone());
+        assert_eq!(
+            format!("AnnotatedHexdump({:?})", sliced),
+            format!("{:?}", dump)
+        );
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    fn hex(bytes: &[u8]) -> alloc::string::String {
+        bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
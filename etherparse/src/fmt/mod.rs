@@ -0,0 +1,45 @@
+mod annotated_hexdump;
+pub use annotated_hexdump::*;
+
+mod packet_summary;
+pub use packet_summary::*;
+
+mod packet_tree;
+pub use packet_tree::*;
+
+use crate::TcpSlice;
+
+/// Writes a MAC address in the format `01:02:03:04:05:06`.
+fn write_mac(f: &mut core::fmt::Formatter<'_>, mac: [u8; 6]) -> core::fmt::Result {
+    write!(
+        f,
+        "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+        mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
+    )
+}
+
+/// Writes the set TCP flags in the format `[SYN, ACK]`.
+fn write_tcp_flags(f: &mut core::fmt::Formatter<'_>, tcp: &TcpSlice) -> core::fmt::Result {
+    let flags = [
+        (tcp.fin(), "FIN"),
+        (tcp.syn(), "SYN"),
+        (tcp.rst(), "RST"),
+        (tcp.psh(), "PSH"),
+        (tcp.ack(), "ACK"),
+        (tcp.urg(), "URG"),
+        (tcp.ece(), "ECE"),
+        (tcp.cwr(), "CWR"),
+        (tcp.ns(), "NS"),
+    ];
+    write!(f, "[")?;
+    let mut first = true;
+    for (_, name) in flags.iter().filter(|(set, _)| *set) {
+        if first {
+            first = false;
+        } else {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", name)?;
+    }
+    write!(f, "]")
+}
@@ -0,0 +1,353 @@
+use crate::*;
+
+/// Formats a [`SlicedPacket`] as a one-line human-readable summary
+/// (similar to the "Info" column of Wireshark).
+///
+/// The summary describes the highest decoded layers of the packet:
+///
+/// ```
+/// # #[cfg(feature = "tcp")]
+/// # {
+/// use etherparse::{fmt::PacketSummary, PacketBuilder, SlicedPacket};
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+///     .tcp(443, 51442, 1000, 4000)
+///     .syn()
+///     .ack(2000)
+///     .write(&mut packet, &[])
+///     .unwrap();
+///
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// assert_eq!(
+///     "IPv4 10.0.0.1 → 10.0.0.2 TCP 443 → 51442 [SYN, ACK] seq=1000 ack=2000 win=4000 len=0",
+///     PacketSummary(&sliced).to_string()
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PacketSummary<'a>(pub &'a SlicedPacket<'a>);
+
+impl core::fmt::Display for PacketSummary<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let packet = self.0;
+
+        let net = match &packet.net {
+            Some(net) => net,
+            None => return write_link_summary(f, packet),
+        };
+
+        match net {
+            NetSlice::Ipv4(ipv4) => {
+                let header = ipv4.header();
+                write!(
+                    f,
+                    "IPv4 {} → {}",
+                    header.source_addr(),
+                    header.destination_addr()
+                )?;
+            }
+            NetSlice::Ipv6(ipv6) => {
+                let header = ipv6.header();
+                write!(
+                    f,
+                    "IPv6 {} → {}",
+                    header.source_addr(),
+                    header.destination_addr()
+                )?;
+            }
+        }
+
+        match &packet.transport {
+            Some(TransportSlice::Tcp(tcp)) => {
+                write!(
+                    f,
+                    " TCP {} → {} ",
+                    tcp.source_port(),
+                    tcp.destination_port()
+                )?;
+                super::write_tcp_flags(f, tcp)?;
+                write!(f, " seq={}", tcp.sequence_number())?;
+                if tcp.ack() {
+                    write!(f, " ack={}", tcp.acknowledgment_number())?;
+                }
+                write!(f, " win={} len={}", tcp.window_size(), tcp.payload().len())
+            }
+            Some(TransportSlice::Udp(udp)) => write!(
+                f,
+                " UDP {} → {} len={}",
+                udp.source_port(),
+                udp.destination_port(),
+                udp.payload().len()
+            ),
+            Some(TransportSlice::Icmpv4(icmp)) => {
+                write!(f, " ICMPv4 ")?;
+                match icmp.icmp_type() {
+                    Icmpv4Type::EchoRequest(echo) => {
+                        write!(f, "echo request id={} seq={}", echo.id, echo.seq)?
+                    }
+                    Icmpv4Type::EchoReply(echo) => {
+                        write!(f, "echo reply id={} seq={}", echo.id, echo.seq)?
+                    }
+                    _ => write!(f, "type={} code={}", icmp.type_u8(), icmp.code_u8())?,
+                }
+                write!(f, " len={}", icmp.payload().len())
+            }
+            Some(TransportSlice::Icmpv6(icmp)) => {
+                write!(f, " ICMPv6 ")?;
+                match icmp.icmp_type() {
+                    Icmpv6Type::EchoRequest(echo) => {
+                        write!(f, "echo request id={} seq={}", echo.id, echo.seq)?
+                    }
+                    Icmpv6Type::EchoReply(echo) => {
+                        write!(f, "echo reply id={} seq={}", echo.id, echo.seq)?
+                    }
+                    _ => write!(f, "type={} code={}", icmp.type_u8(), icmp.code_u8())?,
+                }
+                write!(f, " len={}", icmp.payload().len())
+            }
+            None => {
+                let payload = match net {
+                    NetSlice::Ipv4(ipv4) => ipv4.payload(),
+                    NetSlice::Ipv6(ipv6) => ipv6.payload(),
+                };
+                if let Some(keyword) = payload.ip_number.keyword_str() {
+                    write!(f, " {}", keyword)?;
+                } else {
+                    write!(f, " proto={}", payload.ip_number.0)?;
+                }
+                if payload.fragmented {
+                    write!(f, " fragment")?;
+                }
+                write!(f, " len={}", payload.payload.len())
+            }
+        }
+    }
+}
+
+/// Writes the summary of a packet without a network layer.
+fn write_link_summary(
+    f: &mut core::fmt::Formatter<'_>,
+    packet: &SlicedPacket<'_>,
+) -> core::fmt::Result {
+    let mut separator = "";
+    match &packet.link {
+        Some(LinkSlice::Ethernet2(eth)) => {
+            write!(f, "Ethernet II ")?;
+            super::write_mac(f, eth.source())?;
+            write!(f, " → ")?;
+            super::write_mac(f, eth.destination())?;
+            separator = " ";
+        }
+        Some(LinkSlice::LinuxSll(sll)) => {
+            write!(f, "Linux SLL {:?}", sll.packet_type())?;
+            separator = " ";
+        }
+        Some(LinkSlice::Ieee80211(wlan)) => {
+            write!(f, "IEEE 802.11 ")?;
+            super::write_mac(f, wlan.source_addr())?;
+            write!(f, " → ")?;
+            super::write_mac(f, wlan.destination_addr())?;
+            separator = " ";
+        }
+        Some(LinkSlice::EtherPayload(_)) | Some(LinkSlice::LinuxSllPayload(_)) | None => {}
+    }
+    match &packet.vlan {
+        Some(VlanSlice::SingleVlan(vlan)) => {
+            write!(f, "{}VLAN {}", separator, vlan.vlan_identifier())?;
+            separator = " ";
+        }
+        Some(VlanSlice::DoubleVlan(vlan)) => {
+            write!(
+                f,
+                "{}VLAN {} VLAN {}",
+                separator,
+                vlan.outer().vlan_identifier(),
+                vlan.inner().vlan_identifier()
+            )?;
+            separator = " ";
+        }
+        None => {}
+    }
+    if let Some(payload) = packet.ether_payload() {
+        write!(
+            f,
+            "{}ether_type={:#06X} len={}",
+            separator,
+            payload.ether_type.0,
+            payload.payload.len()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
+    use alloc::{string::ToString, vec::Vec};
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
+    fn summary(packet: &[u8]) -> std::string::String {
+        PacketSummary(&SlicedPacket::from_ethernet(packet).unwrap()).to_string()
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn fmt() {
+        // tcp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6(
+                    [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                    [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2],
+                    20,
+                )
+                .tcp(1234, 80, 1, 2)
+                .psh()
+                .fin()
+                .write(&mut packet, &[1, 2, 3])
+                .unwrap();
+            assert_eq!(
+                "IPv6 2001:db8::1 → 2001:db8::2 TCP 1234 → 80 [FIN, PSH] seq=1 win=2 len=3",
+                summary(&packet)
+            );
+        }
+        // udp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(53, 1234)
+                .write(&mut packet, &[1, 2])
+                .unwrap();
+            assert_eq!(
+                "IPv4 1.2.3.4 → 5.6.7.8 UDP 53 → 1234 len=2",
+                summary(&packet)
+            );
+        }
+        // icmpv4
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut packet, &[1, 2])
+                .unwrap();
+            assert_eq!(
+                "IPv4 1.2.3.4 → 5.6.7.8 ICMPv4 echo request id=1 seq=2 len=2",
+                summary(&packet)
+            );
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_raw(3, 1, [0; 4])
+                .write(&mut packet, &[])
+                .unwrap();
+            assert_eq!(
+                "IPv4 1.2.3.4 → 5.6.7.8 ICMPv4 type=3 code=1 len=0",
+                summary(&packet)
+            );
+        }
+        // icmpv6
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6([0; 16], [0; 16], 20)
+                .icmpv6_echo_reply(3, 4)
+                .write(&mut packet, &[])
+                .unwrap();
+            assert_eq!(
+                "IPv6 :: → :: ICMPv6 echo reply id=3 seq=4 len=0",
+                summary(&packet)
+            );
+        }
+        // ip without transport
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(&mut packet, ip_number::IGMP, &[1, 2])
+                .unwrap();
+            assert_eq!("IPv4 1.2.3.4 → 5.6.7.8 IGMP len=2", summary(&packet));
+
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(&mut packet, IpNumber(200), &[])
+                .unwrap();
+            assert_eq!("IPv4 1.2.3.4 → 5.6.7.8 proto=200 len=0", summary(&packet));
+        }
+        // fragmented
+        {
+            let mut ip =
+                Ipv4Header::new(0, 20, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+            ip.more_fragments = true;
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ip(IpHeaders::Ipv4(ip, Default::default()))
+                .write(&mut packet, ip_number::UDP, &[1, 2, 3])
+                .unwrap();
+            assert_eq!(
+                "IPv4 1.2.3.4 → 5.6.7.8 UDP fragment len=3",
+                summary(&packet)
+            );
+        }
+        // ethernet & vlan only
+        {
+            let mut packet = Vec::new();
+            packet.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1, 2, 3, 4, 5, 6],
+                    destination: [0xa, 0xb, 0xc, 0xd, 0xe, 0xf],
+                    ether_type: EtherType::VLAN_TAGGED_FRAME,
+                }
+                .to_bytes(),
+            );
+            packet.extend_from_slice(
+                &SingleVlanHeader {
+                    vlan_id: VlanId::try_new(12).unwrap(),
+                    ether_type: EtherType::ARP,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            packet.extend_from_slice(&[1, 2, 3]);
+            assert_eq!(
+                "Ethernet II 01:02:03:04:05:06 → 0a:0b:0c:0d:0e:0f VLAN 12 ether_type=0x0806 len=3",
+                summary(&packet)
+            );
+        }
+        // empty
+        {
+            let packet = SlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+            };
+            assert_eq!("", PacketSummary(&packet).to_string());
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let packet = SlicedPacket {
+            link: None,
+            vlan: None,
+            backbone: None,
+            net: None,
+            transport: None,
+        };
+        let summary = PacketSummary(&packet);
+        assert_eq!(summary, summary.clone());
+        assert_eq!(
+            format!("PacketSummary({:?})", packet),
+            format!("{:?}", summary)
+        );
+    }
+}
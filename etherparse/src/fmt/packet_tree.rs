@@ -0,0 +1,416 @@
+use crate::*;
+
+/// Formats a [`SlicedPacket`] as a verbose multi-line tree with one
+/// section per decoded layer (similar to the "Packet Details" pane of
+/// Wireshark).
+///
+/// ```
+/// use etherparse::{fmt::PacketTree, PacketBuilder, SlicedPacket};
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+///     .udp(53, 1234)
+///     .write(&mut packet, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// println!("{}", PacketTree(&sliced));
+/// ```
+///
+/// Prints:
+///
+/// ```text
+/// Ethernet II, Src: 01:02:03:04:05:06, Dst: 07:08:09:0a:0b:0c
+///     Type: 0x0800 (Internet Protocol version 4 (IPv4))
+/// Internet Protocol Version 4, Src: 10.0.0.1, Dst: 10.0.0.2
+///     DSCP: 0, ECN: 0
+///     Total Length: 32
+///     Identification: 0
+///     Flags: DF
+///     Fragment Offset: 0
+///     Time to Live: 20
+///     Protocol: 17 (UDP - User Datagram)
+///     Header Checksum: 0x52cb
+/// User Datagram Protocol, Src Port: 53, Dst Port: 1234
+///     Length: 12
+///     Checksum: 0xe2c6
+/// Payload: 4 bytes
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PacketTree<'a>(pub &'a SlicedPacket<'a>);
+
+impl core::fmt::Display for PacketTree<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let packet = self.0;
+
+        if let Some(backbone) = &packet.backbone {
+            write_link(f, &backbone.link, &backbone.vlan)?;
+            let itag = backbone.itag.to_header();
+            writeln!(f, "IEEE 802.1ah I-TAG, I-SID: {}", itag.isid.value())?;
+            writeln!(
+                f,
+                "    PCP: {}, DEI: {}, UCA: {}",
+                itag.pcp, itag.drop_eligible_indicator, itag.use_customer_address
+            )?;
+        }
+
+        write_link(f, &packet.link, &packet.vlan)?;
+
+        match &packet.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                let header = ipv4.header();
+                writeln!(
+                    f,
+                    "Internet Protocol Version 4, Src: {}, Dst: {}",
+                    header.source_addr(),
+                    header.destination_addr()
+                )?;
+                writeln!(f, "    DSCP: {}, ECN: {}", header.dcp(), header.ecn())?;
+                writeln!(f, "    Total Length: {}", header.total_len())?;
+                writeln!(f, "    Identification: {}", header.identification())?;
+                write!(f, "    Flags:")?;
+                if header.dont_fragment() {
+                    write!(f, " DF")?;
+                }
+                if header.more_fragments() {
+                    write!(f, " MF")?;
+                }
+                writeln!(f)?;
+                writeln!(f, "    Fragment Offset: {}", header.fragments_offset())?;
+                writeln!(f, "    Time to Live: {}", header.ttl())?;
+                writeln!(f, "    Protocol: {:?}", header.protocol())?;
+                writeln!(f, "    Header Checksum: {:#06x}", header.header_checksum())?;
+                if false == header.options().is_empty() {
+                    writeln!(f, "    Options: {} bytes", header.options().len())?;
+                }
+                if let Some(auth) = ipv4.extensions().auth {
+                    writeln!(f, "    Authentication Header: {} bytes", auth.slice().len())?;
+                }
+            }
+            Some(NetSlice::Ipv6(ipv6)) => {
+                let header = ipv6.header();
+                writeln!(
+                    f,
+                    "Internet Protocol Version 6, Src: {}, Dst: {}",
+                    header.source_addr(),
+                    header.destination_addr()
+                )?;
+                writeln!(f, "    Traffic Class: {:#04x}", header.traffic_class())?;
+                writeln!(f, "    Flow Label: {:#07x}", header.flow_label().value())?;
+                writeln!(f, "    Payload Length: {}", header.payload_length())?;
+                writeln!(f, "    Next Header: {:?}", header.next_header())?;
+                writeln!(f, "    Hop Limit: {}", header.hop_limit())?;
+                if false == ipv6.extensions().is_empty() {
+                    writeln!(
+                        f,
+                        "    Extension Headers: {} bytes",
+                        ipv6.extensions().slice().len()
+                    )?;
+                }
+            }
+            None => {}
+        }
+
+        match &packet.transport {
+            Some(TransportSlice::Tcp(tcp)) => {
+                writeln!(
+                    f,
+                    "Transmission Control Protocol, Src Port: {}, Dst Port: {}",
+                    tcp.source_port(),
+                    tcp.destination_port()
+                )?;
+                writeln!(f, "    Sequence Number: {}", tcp.sequence_number())?;
+                writeln!(
+                    f,
+                    "    Acknowledgment Number: {}",
+                    tcp.acknowledgment_number()
+                )?;
+                writeln!(f, "    Header Length: {}", tcp.header_slice().len())?;
+                write!(f, "    Flags: ")?;
+                super::write_tcp_flags(f, tcp)?;
+                writeln!(f)?;
+                writeln!(f, "    Window: {}", tcp.window_size())?;
+                writeln!(f, "    Checksum: {:#06x}", tcp.checksum())?;
+                writeln!(f, "    Urgent Pointer: {}", tcp.urgent_pointer())?;
+                if false == tcp.options().is_empty() {
+                    writeln!(f, "    Options: {} bytes", tcp.options().len())?;
+                }
+                writeln!(f, "Payload: {} bytes", tcp.payload().len())?;
+            }
+            Some(TransportSlice::Udp(udp)) => {
+                writeln!(
+                    f,
+                    "User Datagram Protocol, Src Port: {}, Dst Port: {}",
+                    udp.source_port(),
+                    udp.destination_port()
+                )?;
+                writeln!(f, "    Length: {}", udp.length())?;
+                writeln!(f, "    Checksum: {:#06x}", udp.checksum())?;
+                writeln!(f, "Payload: {} bytes", udp.payload().len())?;
+            }
+            Some(TransportSlice::Icmpv4(icmp)) => {
+                writeln!(
+                    f,
+                    "Internet Control Message Protocol, Type: {}, Code: {}",
+                    icmp.type_u8(),
+                    icmp.code_u8()
+                )?;
+                writeln!(f, "    Checksum: {:#06x}", icmp.checksum())?;
+                writeln!(f, "Payload: {} bytes", icmp.payload().len())?;
+            }
+            Some(TransportSlice::Icmpv6(icmp)) => {
+                writeln!(
+                    f,
+                    "Internet Control Message Protocol v6, Type: {}, Code: {}",
+                    icmp.type_u8(),
+                    icmp.code_u8()
+                )?;
+                writeln!(f, "    Checksum: {:#06x}", icmp.checksum())?;
+                writeln!(f, "Payload: {} bytes", icmp.payload().len())?;
+            }
+            None => {
+                if let Some(payload) = packet.ip_payload() {
+                    if payload.fragmented {
+                        writeln!(f, "Fragment Payload: {} bytes", payload.payload.len())?;
+                    } else {
+                        writeln!(f, "Payload: {} bytes", payload.payload.len())?;
+                    }
+                } else if let Some(payload) = packet.ether_payload() {
+                    writeln!(f, "Payload: {} bytes", payload.payload.len())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the link layer & vlan headers.
+fn write_link(
+    f: &mut core::fmt::Formatter<'_>,
+    link: &Option<LinkSlice<'_>>,
+    vlan: &Option<VlanSlice<'_>>,
+) -> core::fmt::Result {
+    match link {
+        Some(LinkSlice::Ethernet2(eth)) => {
+            write!(f, "Ethernet II, Src: ")?;
+            super::write_mac(f, eth.source())?;
+            write!(f, ", Dst: ")?;
+            super::write_mac(f, eth.destination())?;
+            writeln!(f)?;
+            writeln!(f, "    Type: {:?}", eth.ether_type())?;
+        }
+        Some(LinkSlice::LinuxSll(sll)) => {
+            writeln!(f, "Linux cooked capture v1")?;
+            writeln!(f, "    Packet Type: {:?}", sll.packet_type())?;
+            writeln!(
+                f,
+                "    Link-layer Address Type: {:?}",
+                sll.arp_hardware_type()
+            )?;
+            writeln!(f, "    Source: {:02x?}", sll.sender_address())?;
+            writeln!(f, "    Protocol: {:?}", sll.protocol_type())?;
+        }
+        Some(LinkSlice::Ieee80211(wlan)) => {
+            write!(f, "IEEE 802.11 Data, Src: ")?;
+            super::write_mac(f, wlan.source_addr())?;
+            write!(f, ", Dst: ")?;
+            super::write_mac(f, wlan.destination_addr())?;
+            writeln!(f)?;
+            writeln!(f, "    Frame Control: {:#06x}", wlan.frame_control())?;
+            if let Some(payload) = wlan.ether_payload() {
+                writeln!(f, "    Type: {:?}", payload.ether_type)?;
+            }
+        }
+        Some(LinkSlice::EtherPayload(_)) | Some(LinkSlice::LinuxSllPayload(_)) | None => {}
+    }
+
+    match vlan {
+        Some(VlanSlice::SingleVlan(vlan)) => write_vlan(f, &vlan.to_header())?,
+        Some(VlanSlice::DoubleVlan(vlan)) => {
+            let header = vlan.to_header();
+            write_vlan(f, &header.outer)?;
+            write_vlan(f, &header.inner)?;
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+/// Writes the section of a single VLAN header.
+fn write_vlan(f: &mut core::fmt::Formatter<'_>, vlan: &SingleVlanHeader) -> core::fmt::Result {
+    writeln!(
+        f,
+        "802.1Q Virtual LAN, PRI: {}, DEI: {}, ID: {}",
+        vlan.pcp, vlan.drop_eligible_indicator, vlan.vlan_id
+    )?;
+    writeln!(f, "    Type: {:?}", vlan.ether_type)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
+    use alloc::vec::Vec;
+    use alloc::{format, string::ToString};
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn fmt() {
+        // ethernet, vlan, ipv4 & udp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(VlanId::try_new(12).unwrap())
+                .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+                .udp(53, 1234)
+                .write(&mut packet, &[1, 2, 3, 4])
+                .unwrap();
+            let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+            let udp = match &sliced.transport {
+                Some(TransportSlice::Udp(udp)) => udp.clone(),
+                _ => panic!("expected udp"),
+            };
+            let ip_checksum = match &sliced.net {
+                Some(NetSlice::Ipv4(ipv4)) => ipv4.header().header_checksum(),
+                _ => panic!("expected ipv4"),
+            };
+            assert_eq!(
+                format!(
+                    "Ethernet II, Src: 01:02:03:04:05:06, Dst: 07:08:09:0a:0b:0c\n\
+                    \x20   Type: 0x8100 (Customer VLAN Tag (C-TAG) as defined in IEEE Std 802.1Q)\n\
+                    802.1Q Virtual LAN, PRI: 0, DEI: false, ID: 12\n\
+                    \x20   Type: 0x0800 (Internet Protocol version 4 (IPv4))\n\
+                    Internet Protocol Version 4, Src: 10.0.0.1, Dst: 10.0.0.2\n\
+                    \x20   DSCP: 0, ECN: 0\n\
+                    \x20   Total Length: 32\n\
+                    \x20   Identification: 0\n\
+                    \x20   Flags: DF\n\
+                    \x20   Fragment Offset: 0\n\
+                    \x20   Time to Live: 20\n\
+                    \x20   Protocol: 17 (UDP - User Datagram)\n\
+                    \x20   Header Checksum: {:#06x}\n\
+                    User Datagram Protocol, Src Port: 53, Dst Port: 1234\n\
+                    \x20   Length: 12\n\
+                    \x20   Checksum: {:#06x}\n\
+                    Payload: 4 bytes\n",
+                    ip_checksum,
+                    udp.checksum()
+                ),
+                PacketTree(&sliced).to_string()
+            );
+        }
+        // provider backbone bridging
+        {
+            let packet = [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x88, 0xE7, // backbone ethernet II
+                0x68, 0x12, 0x34, 0x56, // I-TAG
+                13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0x88,
+                0xB5, // customer ethernet II
+            ];
+            let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+            let tree = PacketTree(&sliced).to_string();
+            assert!(
+                tree.starts_with("Ethernet II, Src: 07:08:09:0a:0b:0c, Dst: 01:02:03:04:05:06\n")
+            );
+            assert!(tree.contains(
+                "IEEE 802.1ah I-TAG, I-SID: 1193046\n\
+                \x20   PCP: 3, DEI: false, UCA: true\n\
+                Ethernet II, Src: 13:14:15:16:17:18, Dst: 0d:0e:0f:10:11:12\n"
+            ));
+        }
+        // ipv6 & tcp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6([0; 16], [0; 16], 20)
+                .tcp(1, 2, 3, 4)
+                .syn()
+                .write(&mut packet, &[])
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            let checksum = match &sliced.transport {
+                Some(TransportSlice::Tcp(tcp)) => tcp.checksum(),
+                _ => panic!("expected tcp"),
+            };
+            assert_eq!(
+                format!(
+                    "Internet Protocol Version 6, Src: ::, Dst: ::\n\
+                    \x20   Traffic Class: 0x00\n\
+                    \x20   Flow Label: 0x00000\n\
+                    \x20   Payload Length: 20\n\
+                    \x20   Next Header: 6 (TCP - Transmission Control)\n\
+                    \x20   Hop Limit: 20\n\
+                    Transmission Control Protocol, Src Port: 1, Dst Port: 2\n\
+                    \x20   Sequence Number: 3\n\
+                    \x20   Acknowledgment Number: 0\n\
+                    \x20   Header Length: 20\n\
+                    \x20   Flags: [SYN]\n\
+                    \x20   Window: 4\n\
+                    \x20   Checksum: {:#06x}\n\
+                    \x20   Urgent Pointer: 0\n\
+                    Payload: 0 bytes\n",
+                    checksum
+                ),
+                PacketTree(&sliced).to_string()
+            );
+        }
+        // icmp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut packet, &[])
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert!(PacketTree(&sliced)
+                .to_string()
+                .contains("Internet Control Message Protocol, Type: 8, Code: 0\n"));
+
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6([0; 16], [0; 16], 20)
+                .icmpv6_echo_request(1, 2)
+                .write(&mut packet, &[])
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert!(PacketTree(&sliced)
+                .to_string()
+                .contains("Internet Control Message Protocol v6, Type: 128, Code: 0\n"));
+        }
+        // ether payload only
+        {
+            let mut packet = Vec::new();
+            packet.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1, 2, 3, 4, 5, 6],
+                    destination: [7, 8, 9, 10, 11, 12],
+                    ether_type: EtherType::ARP,
+                }
+                .to_bytes(),
+            );
+            packet.extend_from_slice(&[1, 2]);
+            let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+            assert_eq!(
+                "Ethernet II, Src: 01:02:03:04:05:06, Dst: 07:08:09:0a:0b:0c\n\
+                \x20   Type: 0x0806 (Address Resolution Protocol (ARP))\n\
+                Payload: 2 bytes\n",
+                PacketTree(&sliced).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let packet = SlicedPacket {
+            link: None,
+            vlan: None,
+            backbone: None,
+            net: None,
+            transport: None,
+        };
+        let tree = PacketTree(&packet);
+        assert_eq!(tree, tree.clone());
+        assert_eq!(format!("PacketTree({:?})", packet), format!("{:?}", tree));
+        assert_eq!("", tree.to_string());
+    }
+}
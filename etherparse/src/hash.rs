@@ -0,0 +1,174 @@
+/// Generates a FNV-1a hasher struct for the given state type, offset
+/// basis & prime (the algorithm is the same for all widths).
+macro_rules! fnv1a_hasher {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $state:ty, $bits:literal, $offset_basis:literal, $prime:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        pub struct $name {
+            state: $state,
+        }
+
+        impl $name {
+            #[doc = concat!("FNV offset basis for ", $bits, " bit hashes.")]
+            pub const OFFSET_BASIS: $state = $offset_basis;
+
+            #[doc = concat!("FNV prime for ", $bits, " bit hashes.")]
+            pub const PRIME: $state = $prime;
+
+            /// Creates a new hasher with the state set to the FNV offset basis.
+            pub const fn new() -> $name {
+                $name {
+                    state: $name::OFFSET_BASIS,
+                }
+            }
+
+            /// Add the given slice to the hash.
+            #[inline]
+            pub fn add_slice(self, slice: &[u8]) -> $name {
+                let mut state = self.state;
+                for b in slice {
+                    state = (state ^ <$state>::from(*b)).wrapping_mul($name::PRIME);
+                }
+                $name { state }
+            }
+
+            /// Returns the hash value.
+            #[inline]
+            pub const fn value(&self) -> $state {
+                self.state
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::new()
+            }
+        }
+    };
+}
+
+fnv1a_hasher!(
+    /// Helper for calculating 32 bit FNV-1a hashes (see
+    /// [draft-eastlake-fnv](https://datatracker.ietf.org/doc/draft-eastlake-fnv/)).
+    ///
+    /// Used by the flow hashing helpers (e.g. [`crate::Ipv6HeaderSlice::flow_hash`]
+    /// & [`crate::quick::FlowKey::hash_symmetric`]). The algorithm is fixed, so the
+    /// resulting hashes are stable across versions & platforms.
+    ///
+    /// ```
+    /// use etherparse::hash::Fnv1a32;
+    ///
+    /// assert_eq!(0xbf9c_f968, Fnv1a32::new().add_slice(b"foobar").value());
+    /// ```
+    Fnv1a32,
+    u32,
+    "32",
+    0x811c_9dc5,
+    0x0100_0193
+);
+
+fnv1a_hasher!(
+    /// Helper for calculating 64 bit FNV-1a hashes (see
+    /// [draft-eastlake-fnv](https://datatracker.ietf.org/doc/draft-eastlake-fnv/)).
+    ///
+    /// Used by [`crate::SlicedPacket::dedup_digest`] &
+    /// [`crate::PacketHeaders::dedup_digest`], where the wider state makes
+    /// collisions between different packets less likely than with
+    /// [`Fnv1a32`]. The algorithm is fixed, so the resulting hashes are
+    /// stable across versions & platforms.
+    ///
+    /// ```
+    /// use etherparse::hash::Fnv1a64;
+    ///
+    /// assert_eq!(0x8594_4171_f739_67e8, Fnv1a64::new().add_slice(b"foobar").value());
+    /// ```
+    Fnv1a64,
+    u64,
+    "64",
+    0xcbf2_9ce4_8422_2325,
+    0x0000_0100_0000_01b3
+);
+
+#[cfg(test)]
+mod fnv1a32_tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(0x811c_9dc5, Fnv1a32::new().value());
+        assert_eq!(0xe40c_292c, Fnv1a32::new().add_slice(b"a").value());
+        assert_eq!(0xbf9c_f968, Fnv1a32::new().add_slice(b"foobar").value());
+    }
+
+    proptest! {
+        #[test]
+        fn add_slice_split(
+            data in proptest::collection::vec(any::<u8>(), 0..100),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let i = split.index(data.len() + 1);
+            assert_eq!(
+                Fnv1a32::new().add_slice(&data),
+                Fnv1a32::new().add_slice(&data[..i]).add_slice(&data[i..])
+            );
+        }
+    }
+
+    #[test]
+    fn default_debug_clone_eq() {
+        let value: Fnv1a32 = Default::default();
+        assert_eq!(Fnv1a32::new(), value);
+        assert_eq!(value.clone(), value);
+        assert_eq!("Fnv1a32 { state: 2166136261 }", format!("{:?}", value));
+    }
+}
+
+#[cfg(test)]
+mod fnv1a64_tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, Fnv1a64::new().value());
+        assert_eq!(
+            0xaf63_dc4c_8601_ec8c,
+            Fnv1a64::new().add_slice(b"a").value()
+        );
+        assert_eq!(
+            0x8594_4171_f739_67e8,
+            Fnv1a64::new().add_slice(b"foobar").value()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn add_slice_split(
+            data in proptest::collection::vec(any::<u8>(), 0..100),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let i = split.index(data.len() + 1);
+            assert_eq!(
+                Fnv1a64::new().add_slice(&data),
+                Fnv1a64::new().add_slice(&data[..i]).add_slice(&data[i..])
+            );
+        }
+    }
+
+    #[test]
+    fn default_debug_clone_eq() {
+        let value: Fnv1a64 = Default::default();
+        assert_eq!(Fnv1a64::new(), value);
+        assert_eq!(value.clone(), value);
+        assert_eq!(
+            "Fnv1a64 { state: 14695981039346656037 }",
+            format!("{:?}", value)
+        );
+    }
+}
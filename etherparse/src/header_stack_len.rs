@@ -0,0 +1,202 @@
+use crate::*;
+
+/// Minimum & maximum summed up length of a composition of headers that
+/// can be computed at compile time (e.g. to size stack allocated buffers).
+///
+/// ```
+/// use etherparse::HeaderStackLen;
+///
+/// const STACK: HeaderStackLen = HeaderStackLen::new()
+///     .ethernet2()
+///     .single_vlan()
+///     .ipv4()
+///     .udp();
+///
+/// // buffer big enough for the headers of any matching packet
+/// let buffer = [0u8; STACK.max_len()];
+/// assert_eq!(14 + 4 + 60 + 8, buffer.len());
+/// assert_eq!(14 + 4 + 20 + 8, STACK.min_len());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct HeaderStackLen {
+    min_len: usize,
+    max_len: usize,
+}
+
+impl HeaderStackLen {
+    /// Creates an empty composition (no headers).
+    #[inline]
+    pub const fn new() -> HeaderStackLen {
+        HeaderStackLen {
+            min_len: 0,
+            max_len: 0,
+        }
+    }
+
+    /// Minimum summed up length of all headers in bytes/octets.
+    #[inline]
+    pub const fn min_len(&self) -> usize {
+        self.min_len
+    }
+
+    /// Maximum summed up length of all headers in bytes/octets.
+    #[inline]
+    pub const fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Adds a header with the given minimum & maximum length (e.g. the
+    /// `MIN_LEN` & `MAX_LEN` constants of a header type).
+    #[inline]
+    pub const fn header(self, min_len: usize, max_len: usize) -> HeaderStackLen {
+        HeaderStackLen {
+            min_len: self.min_len + min_len,
+            max_len: self.max_len + max_len,
+        }
+    }
+
+    /// Adds an Ethernet II header.
+    #[inline]
+    pub const fn ethernet2(self) -> HeaderStackLen {
+        self.header(Ethernet2Header::MIN_LEN, Ethernet2Header::MAX_LEN)
+    }
+
+    /// Adds a Linux Cooked Capture v1 (SLL) header.
+    #[inline]
+    pub const fn linux_sll(self) -> HeaderStackLen {
+        self.header(LinuxSllHeader::MIN_LEN, LinuxSllHeader::MAX_LEN)
+    }
+
+    /// Adds a MACsec header (SecTAG) & the ether type at the start of the
+    /// secure data (2 bytes).
+    #[cfg(feature = "macsec")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+    #[inline]
+    pub const fn macsec(self) -> HeaderStackLen {
+        self.header(MacsecHeader::MIN_LEN + 2, MacsecHeader::MAX_LEN + 2)
+    }
+
+    /// Adds a single IEEE 802.1Q VLAN tagging header.
+    #[inline]
+    pub const fn single_vlan(self) -> HeaderStackLen {
+        self.header(SingleVlanHeader::MIN_LEN, SingleVlanHeader::MAX_LEN)
+    }
+
+    /// Adds a double IEEE 802.1Q VLAN tagging header.
+    #[inline]
+    pub const fn double_vlan(self) -> HeaderStackLen {
+        self.header(DoubleVlanHeader::MIN_LEN, DoubleVlanHeader::MAX_LEN)
+    }
+
+    /// Adds an IPv4 header (including options).
+    #[inline]
+    pub const fn ipv4(self) -> HeaderStackLen {
+        self.header(Ipv4Header::MIN_LEN, Ipv4Header::MAX_LEN)
+    }
+
+    /// Adds IPv4 extension headers.
+    #[inline]
+    pub const fn ipv4_exts(self) -> HeaderStackLen {
+        self.header(Ipv4Extensions::MIN_LEN, Ipv4Extensions::MAX_LEN)
+    }
+
+    /// Adds an IPv6 header.
+    #[inline]
+    pub const fn ipv6(self) -> HeaderStackLen {
+        self.header(Ipv6Header::MIN_LEN, Ipv6Header::MAX_LEN)
+    }
+
+    /// Adds IPv6 extension headers.
+    #[inline]
+    pub const fn ipv6_exts(self) -> HeaderStackLen {
+        self.header(Ipv6Extensions::MIN_LEN, Ipv6Extensions::MAX_LEN)
+    }
+
+    /// Adds an UDP header.
+    #[inline]
+    pub const fn udp(self) -> HeaderStackLen {
+        self.header(UdpHeader::MIN_LEN, UdpHeader::MAX_LEN)
+    }
+
+    /// Adds a TCP header (including options).
+    #[inline]
+    pub const fn tcp(self) -> HeaderStackLen {
+        self.header(TcpHeader::MIN_LEN, TcpHeader::MAX_LEN)
+    }
+
+    /// Adds an ICMPv4 header.
+    #[inline]
+    pub const fn icmpv4(self) -> HeaderStackLen {
+        self.header(Icmpv4Header::MIN_LEN, Icmpv4Header::MAX_LEN)
+    }
+
+    /// Adds an ICMPv6 header.
+    #[inline]
+    pub const fn icmpv6(self) -> HeaderStackLen {
+        self.header(Icmpv6Header::MIN_LEN, Icmpv6Header::MAX_LEN)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn new_default() {
+        assert_eq!(HeaderStackLen::new(), HeaderStackLen::default());
+        assert_eq!(0, HeaderStackLen::new().min_len());
+        assert_eq!(0, HeaderStackLen::new().max_len());
+    }
+
+    #[test]
+    fn header() {
+        let actual = HeaderStackLen::new().header(1, 2).header(3, 5);
+        assert_eq!(4, actual.min_len());
+        assert_eq!(7, actual.max_len());
+    }
+
+    #[test]
+    fn layers() {
+        let tests = [
+            (HeaderStackLen::new().ethernet2(), 14, 14),
+            (HeaderStackLen::new().linux_sll(), 16, 16),
+            (HeaderStackLen::new().single_vlan(), 4, 4),
+            (HeaderStackLen::new().double_vlan(), 8, 8),
+            (HeaderStackLen::new().ipv4(), 20, 60),
+            (HeaderStackLen::new().ipv4_exts(), 0, IpAuthHeader::MAX_LEN),
+            (HeaderStackLen::new().ipv6(), 40, 40),
+            (
+                HeaderStackLen::new().ipv6_exts(),
+                0,
+                Ipv6Extensions::MAX_LEN,
+            ),
+            (HeaderStackLen::new().udp(), 8, 8),
+            (HeaderStackLen::new().tcp(), 20, 60),
+            (HeaderStackLen::new().icmpv4(), 8, 20),
+            (HeaderStackLen::new().icmpv6(), 8, 40),
+        ];
+        for (actual, min_len, max_len) in tests {
+            assert_eq!(min_len, actual.min_len(), "{:?}", actual);
+            assert_eq!(max_len, actual.max_len(), "{:?}", actual);
+        }
+    }
+
+    #[cfg(feature = "macsec")]
+    #[test]
+    fn macsec() {
+        let actual = HeaderStackLen::new().macsec();
+        assert_eq!(6 + 2, actual.min_len());
+        assert_eq!(14 + 2, actual.max_len());
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = HeaderStackLen::new().udp();
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "HeaderStackLen { min_len: 8, max_len: 8 }",
+            format!("{:?}", value)
+        );
+    }
+}
@@ -1,104 +1,396 @@
-/// Helper function for reading big endian u16 values from a ptr unchecked.
+/// Evaluates code using the unchecked helpers of this crate (e.g.
+/// [`get_unchecked_be_u16`]) in an `unsafe` block.
+///
+/// If the `safe-only` feature is enabled the unchecked helpers are replaced
+/// by bounds checked (panicking) equivalents and the code is evaluated
+/// without an `unsafe` block. Callers still have to ensure the accessed
+/// bytes are present (documented via `SAFETY` comments), so both variants
+/// behave identically.
+#[cfg(not(feature = "safe-only"))]
+macro_rules! unchecked {
+    ($($body:tt)*) => {
+        unsafe { $($body)* }
+    };
+}
+
+/// Evaluates code using the unchecked helpers of this crate (replaced by
+/// bounds checked equivalents as the `safe-only` feature is enabled).
+#[cfg(feature = "safe-only")]
+macro_rules! unchecked {
+    ($($body:tt)*) => {
+        { $($body)* }
+    };
+}
+
+/// Defines a function that is `unsafe` by default & a safe function if
+/// the `safe-only` feature is enabled.
+///
+/// Only used for crate internal functions (e.g. the `*_unchecked_internal`
+/// versions of the public `*_unchecked` constructors), as public functions
+/// have to keep the same signature independent of the enabled features.
+/// Violating their requirements results in wrong values or panics instead
+/// of undefined behavior as soon as the crate contains no unchecked accesses.
+macro_rules! unchecked_fn {
+    ($(#[$attr:meta])* $vis:vis const fn $($rest:tt)*) => {
+        $(#[$attr])*
+        #[cfg(not(feature = "safe-only"))]
+        $vis const unsafe fn $($rest)*
+
+        $(#[$attr])*
+        #[cfg(feature = "safe-only")]
+        $vis const fn $($rest)*
+    };
+    ($(#[$attr:meta])* $vis:vis fn $($rest:tt)*) => {
+        $(#[$attr])*
+        #[cfg(not(feature = "safe-only"))]
+        $vis unsafe fn $($rest)*
+
+        $(#[$attr])*
+        #[cfg(feature = "safe-only")]
+        $vis fn $($rest)*
+    };
+}
+
+/// Helper function for reading an u8 value from a slice unchecked.
+///
+/// # Safety
+///
+/// It is in the responsibility of the caller to ensure `index` is smaller
+/// then the length of the slice. If this is not the case undefined behavior
+/// will be triggered (or a panic with the `safe-only` feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn get_unchecked_u8(slice: &[u8], index: usize) -> u8 {
+    *slice.get_unchecked(index)
+}
+
+/// Bounds checked variant of the unchecked u8 helper (`safe-only` feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn get_unchecked_u8(slice: &[u8], index: usize) -> u8 {
+    slice[index]
+}
+
+/// Helper function for reading big endian u16 values from a slice unchecked.
 ///
 /// # Safety
 ///
 /// It is in the responsibility of the caller to ensure there are at least 2
-/// bytes accessable via the ptr. If this is not the case undefined behavior
-/// will be triggered.
+/// bytes accessable starting at `offset`. If this is not the case undefined
+/// behavior will be triggered (or a panic with the `safe-only` feature).
+#[cfg(not(feature = "safe-only"))]
 #[inline]
-pub(crate) unsafe fn get_unchecked_be_u16(ptr: *const u8) -> u16 {
+pub(crate) unsafe fn get_unchecked_be_u16(slice: &[u8], offset: usize) -> u16 {
+    let ptr = slice.as_ptr().add(offset);
     u16::from_be_bytes([*ptr, *ptr.add(1)])
 }
 
-/// Helper function for reading big endian u32 values from a ptr unchecked.
+/// Bounds checked variant of the unchecked u16 helper (`safe-only` feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn get_unchecked_be_u16(slice: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes(get_unchecked_array(slice, offset))
+}
+
+/// Helper function for reading big endian u32 values from a slice unchecked.
 ///
 /// # Safety
 ///
 /// It is in the responsibility of the caller to ensure there are at least 4
-/// bytes accessable via the ptr. If this is not the case undefined behavior
-/// will be triggered.
+/// bytes accessable starting at `offset`. If this is not the case undefined
+/// behavior will be triggered (or a panic with the `safe-only` feature).
+#[cfg(not(feature = "safe-only"))]
 #[inline]
-pub(crate) unsafe fn get_unchecked_be_u32(ptr: *const u8) -> u32 {
+pub(crate) unsafe fn get_unchecked_be_u32(slice: &[u8], offset: usize) -> u32 {
+    let ptr = slice.as_ptr().add(offset);
     u32::from_be_bytes([*ptr, *ptr.add(1), *ptr.add(2), *ptr.add(3)])
 }
 
-/// Helper function for reading a 4 byte fixed-size array.
+/// Bounds checked variant of the unchecked u32 helper (`safe-only` feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn get_unchecked_be_u32(slice: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(get_unchecked_array(slice, offset))
+}
+
+/// Helper function for reading a fixed-size array from a slice unchecked.
 ///
 /// # Safety
 ///
-/// It is in the responsibility of the caller to ensure there are at least 4
-/// bytes accessable via the ptr. If this is not the case undefined behavior
-/// will be triggered.
+/// It is in the responsibility of the caller to ensure there are at least
+/// `N` bytes accessable starting at `offset`. If this is not the case
+/// undefined behavior will be triggered (or a panic with the `safe-only`
+/// feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn get_unchecked_array<const N: usize>(slice: &[u8], offset: usize) -> [u8; N] {
+    let mut result = [0u8; N];
+    core::ptr::copy_nonoverlapping(slice.as_ptr().add(offset), result.as_mut_ptr(), N);
+    result
+}
+
+/// Bounds checked variant of the unchecked array helper (`safe-only`
+/// feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn get_unchecked_array<const N: usize>(slice: &[u8], offset: usize) -> [u8; N] {
+    let mut result = [0u8; N];
+    result.copy_from_slice(&slice[offset..offset + N]);
+    result
+}
+
+/// Helper function for getting a sub-slice of a slice unchecked.
+///
+/// # Safety
+///
+/// It is in the responsibility of the caller to ensure there are at least
+/// `len` bytes accessable starting at `offset`. If this is not the case
+/// undefined behavior will be triggered (or a panic with the `safe-only`
+/// feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn get_unchecked_slice(slice: &[u8], offset: usize, len: usize) -> &[u8] {
+    core::slice::from_raw_parts(slice.as_ptr().add(offset), len)
+}
+
+/// Bounds checked variant of the unchecked sub-slice helper (`safe-only`
+/// feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn get_unchecked_slice(slice: &[u8], offset: usize, len: usize) -> &[u8] {
+    &slice[offset..offset + len]
+}
+
+/// Helper function for getting a mutable sub-slice of a slice unchecked.
+///
+/// # Safety
+///
+/// It is in the responsibility of the caller to ensure there are at least
+/// `len` bytes accessable starting at `offset`. If this is not the case
+/// undefined behavior will be triggered (or a panic with the `safe-only`
+/// feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn get_unchecked_slice_mut(
+    slice: &mut [u8],
+    offset: usize,
+    len: usize,
+) -> &mut [u8] {
+    core::slice::from_raw_parts_mut(slice.as_mut_ptr().add(offset), len)
+}
+
+/// Bounds checked variant of the unchecked mutable sub-slice helper
+/// (`safe-only` feature).
+#[cfg(feature = "safe-only")]
 #[inline]
-pub(crate) unsafe fn get_unchecked_4_byte_array(ptr: *const u8) -> [u8; 4] {
-    [*ptr, *ptr.add(1), *ptr.add(2), *ptr.add(3)]
+pub(crate) fn get_unchecked_slice_mut(slice: &mut [u8], offset: usize, len: usize) -> &mut [u8] {
+    &mut slice[offset..offset + len]
 }
 
-/// Helper function for reading a 6 byte fixed-size array.
+/// Helper function for shortening an `ArrayVec` of bytes unchecked.
 ///
 /// # Safety
 ///
-/// It is in the responsibility of the caller to ensure there are at least 6
-/// bytes accessable via the ptr. If this is not the case undefined behavior
-/// will be triggered.
+/// It is in the responsibility of the caller to ensure `len` is smaller
+/// or equal to the current length of the vector. If this is not the case
+/// undefined behavior will be triggered (or a panic with the `safe-only`
+/// feature).
+#[cfg(not(feature = "safe-only"))]
 #[inline]
-pub(crate) unsafe fn get_unchecked_6_byte_array(ptr: *const u8) -> [u8; 6] {
-    [
-        *ptr,
-        *ptr.add(1),
-        *ptr.add(2),
-        *ptr.add(3),
-        *ptr.add(4),
-        *ptr.add(5),
-    ]
+pub(crate) unsafe fn truncate_unchecked<const CAP: usize>(
+    vec: &mut arrayvec::ArrayVec<u8, CAP>,
+    len: usize,
+) {
+    vec.set_len(len);
 }
 
-/// Helper function for reading a 8 byte fixed-size array.
+/// Bounds checked variant of the unchecked `ArrayVec` truncation helper
+/// (`safe-only` feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn truncate_unchecked<const CAP: usize>(
+    vec: &mut arrayvec::ArrayVec<u8, CAP>,
+    len: usize,
+) {
+    assert!(len <= vec.len());
+    vec.truncate(len);
+}
+
+/// Helper function for unwrapping a result that is known to be `Ok`
+/// without a check.
 ///
 /// # Safety
 ///
-/// It is in the responsibility of the caller to ensure there are at least 6
-/// bytes accessable via the ptr. If this is not the case undefined behavior
-/// will be triggered.
+/// It is in the responsibility of the caller to ensure the result is
+/// `Ok`. If this is not the case undefined behavior will be triggered (or
+/// a panic with the `safe-only` feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn unwrap_unchecked<T, E>(result: Result<T, E>) -> T {
+    result.unwrap_unchecked()
+}
+
+/// Checked variant of the unchecked unwrap helper (`safe-only` feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn unwrap_unchecked<T, E>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Helper function for marking code as unreachable without a check.
+///
+/// # Safety
+///
+/// It is in the responsibility of the caller to ensure the code is never
+/// reached. If this is not the case undefined behavior will be triggered
+/// (or a panic with the `safe-only` feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn unreachable_unchecked() -> ! {
+    core::hint::unreachable_unchecked()
+}
+
+/// Checked variant of the unchecked unreachable helper (`safe-only`
+/// feature).
+#[cfg(feature = "safe-only")]
 #[inline]
-pub(crate) unsafe fn get_unchecked_8_byte_array(ptr: *const u8) -> [u8; 8] {
-    [
-        *ptr,
-        *ptr.add(1),
-        *ptr.add(2),
-        *ptr.add(3),
-        *ptr.add(4),
-        *ptr.add(5),
-        *ptr.add(6),
-        *ptr.add(7),
-    ]
+pub(crate) fn unreachable_unchecked() -> ! {
+    unreachable!()
 }
 
-/// Helper function for reading a 16 byte fixed-size array.
+/// Helper function for calculating the offset of `subslice` in `slice`
+/// (in bytes) unchecked.
 ///
 /// # Safety
 ///
-/// It is in the responsibility of the caller to ensure there are at least 16
-/// bytes accessable via the ptr. If this is not the case undefined behavior
-/// will be triggered.
-#[inline]
-pub(crate) unsafe fn get_unchecked_16_byte_array(ptr: *const u8) -> [u8; 16] {
-    [
-        *ptr,
-        *ptr.add(1),
-        *ptr.add(2),
-        *ptr.add(3),
-        *ptr.add(4),
-        *ptr.add(5),
-        *ptr.add(6),
-        *ptr.add(7),
-        *ptr.add(8),
-        *ptr.add(9),
-        *ptr.add(10),
-        *ptr.add(11),
-        *ptr.add(12),
-        *ptr.add(13),
-        *ptr.add(14),
-        *ptr.add(15),
-    ]
+/// It is in the responsibility of the caller to ensure `subslice` is a
+/// part of `slice`. If this is not the case undefined behavior will be
+/// triggered (or a wrong or panicking calculation with the `safe-only`
+/// feature).
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+pub(crate) unsafe fn get_unchecked_offset(slice: &[u8], subslice: &[u8]) -> usize {
+    subslice.as_ptr().offset_from(slice.as_ptr()) as usize
+}
+
+/// Pointer address based variant of the unchecked offset helper
+/// (`safe-only` feature).
+#[cfg(feature = "safe-only")]
+#[inline]
+pub(crate) fn get_unchecked_offset(slice: &[u8], subslice: &[u8]) -> usize {
+    (subslice.as_ptr() as usize) - (slice.as_ptr() as usize)
+}
+
+/// Returns the offset of a sub-slice of `data` (`sub` has to be a part
+/// of `data`).
+#[inline]
+pub(crate) fn offset_in(data: &[u8], sub: &[u8]) -> usize {
+    (sub.as_ptr() as usize) - (data.as_ptr() as usize)
+}
+
+/// Parses a decimal number or a hexadecimal number starting with `0x`
+/// (used by the `FromStr` implementations of the protocol number types).
+pub(crate) fn parse_number<T: TryFrom<u32>>(s: &str) -> Option<T> {
+    let value = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else {
+        s.parse::<u32>().ok()?
+    };
+    T::try_from(value).ok()
+}
+
+/// Compares a string against a name ignoring the ASCII case & treating
+/// `_` & `-` as equal (used by the `FromStr` implementations of the
+/// protocol number types).
+pub(crate) fn eq_name(s: &str, name: &str) -> bool {
+    s.len() == name.len()
+        && s.bytes().zip(name.bytes()).all(|(a, b)| {
+            let norm = |c: u8| {
+                if c == b'_' {
+                    b'-'
+                } else {
+                    c.to_ascii_lowercase()
+                }
+            };
+            norm(a) == norm(b)
+        })
+}
+
+/// Reads exactly `buf.len()` bytes from an async reader (equivalent to
+/// `tokio::io::AsyncReadExt::read_exact`, which would require tokio's
+/// `io-util` feature).
+#[cfg(feature = "tokio")]
+pub(crate) async fn async_read_exact<T: tokio::io::AsyncRead + Unpin>(
+    reader: &mut T,
+    buf: &mut [u8],
+) -> Result<(), std::io::Error> {
+    let mut read_buf = tokio::io::ReadBuf::new(buf);
+    while read_buf.remaining() > 0 {
+        let filled = read_buf.filled().len();
+        core::future::poll_fn(|cx| core::pin::Pin::new(&mut *reader).poll_read(cx, &mut read_buf))
+            .await?;
+        if filled == read_buf.filled().len() {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unchecked_helpers() {
+        let data = [1u8, 2, 3, 4, 5, 6];
+        unchecked! {
+            assert_eq!(3, get_unchecked_u8(&data, 2));
+            assert_eq!(0x0203, get_unchecked_be_u16(&data, 1));
+            assert_eq!(0x03040506, get_unchecked_be_u32(&data, 2));
+            assert_eq!([5, 6], get_unchecked_array::<2>(&data, 4));
+            assert_eq!(&[2, 3, 4], get_unchecked_slice(&data, 1, 3));
+            assert_eq!(4, get_unchecked_offset(&data, &data[4..]));
+            assert_eq!(1, unwrap_unchecked(Ok::<u8, ()>(1)));
+        }
+
+        let mut buffer = [0u8; 4];
+        unchecked! {
+            get_unchecked_slice_mut(&mut buffer, 1, 2).copy_from_slice(&[7, 8]);
+        }
+        assert_eq!([0, 7, 8, 0], buffer);
+
+        let mut vec = arrayvec::ArrayVec::from([1u8, 2, 3, 4]);
+        unchecked! {
+            truncate_unchecked(&mut vec, 2);
+        }
+        assert_eq!(&[1, 2], &vec[..]);
+    }
+
+    #[test]
+    fn parse_number() {
+        assert_eq!(Some(0u16), super::parse_number("0"));
+        assert_eq!(Some(2048u16), super::parse_number("2048"));
+        assert_eq!(Some(0x86ddu16), super::parse_number("0x86dd"));
+        assert_eq!(Some(0x86ddu16), super::parse_number("0X86DD"));
+        assert_eq!(Some(255u8), super::parse_number("0xff"));
+        assert_eq!(None::<u8>, super::parse_number("256"));
+        assert_eq!(None::<u16>, super::parse_number("0x10000"));
+        assert_eq!(None::<u16>, super::parse_number(""));
+        assert_eq!(None::<u16>, super::parse_number("0x"));
+        assert_eq!(None::<u16>, super::parse_number("-1"));
+        assert_eq!(None::<u16>, super::parse_number("ipv4"));
+    }
+
+    #[test]
+    fn eq_name() {
+        assert!(super::eq_name("ipv4", "ipv4"));
+        assert!(super::eq_name("IPv4", "ipv4"));
+        assert!(super::eq_name("wake_on_lan", "wake-on-lan"));
+        assert!(super::eq_name("WAKE-ON-LAN", "wake-on-lan"));
+        assert!(false == super::eq_name("ipv", "ipv4"));
+        assert!(false == super::eq_name("ipv6", "ipv4"));
+    }
 }
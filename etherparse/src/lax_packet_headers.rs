@@ -253,7 +253,7 @@ impl<'a> LaxPacketHeaders<'a> {
         use ether_type::*;
 
         result.vlan = match ether_type {
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+            _ if ether_type.is_vlan_tag() => {
                 use crate::VlanHeader::*;
                 let (outer, outer_rest) = match SingleVlanHeader::from_slice(rest) {
                     Ok(value) => value,
@@ -275,7 +275,7 @@ impl<'a> LaxPacketHeaders<'a> {
                 // parse second vlan header if present
                 match ether_type {
                     // second vlan tagging header
-                    VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+                    _ if ether_type.is_vlan_tag() => {
                         let (inner, inner_rest) = match SingleVlanHeader::from_slice(rest) {
                             Ok(value) => value,
                             Err(mut err) => {
@@ -305,24 +305,23 @@ impl<'a> LaxPacketHeaders<'a> {
             _ => None,
         };
 
-        // parse ip
+        // parse ip (IPv6 only if the "ipv6" feature is enabled)
         match ether_type {
-            IPV4 | IPV6 => match result.add_ip(offset, rest) {
-                Ok(_) => {}
-                Err(err) => {
-                    use err::ip::LaxHeaderSliceError as I;
-                    result.stop_err = Some(match err {
-                        I::Len(mut l) => {
-                            l.layer_start_offset += offset;
-                            (Len(l), Layer::IpHeader)
-                        }
-                        I::Content(c) => (Ip(c), Layer::IpHeader),
-                    });
-                    return result;
+            IPV4 => {}
+            #[cfg(feature = "ipv6")]
+            IPV6 => {}
+            _ => return result,
+        }
+        if let Err(err) = result.add_ip(offset, rest) {
+            use err::ip::LaxHeaderSliceError as I;
+            result.stop_err = Some(match err {
+                I::Len(mut l) => {
+                    l.layer_start_offset += offset;
+                    (Len(l), Layer::IpHeader)
                 }
-            },
-            _ => {}
-        };
+                I::Content(c) => (Ip(c), Layer::IpHeader),
+            });
+        }
 
         result
     }
@@ -501,8 +500,8 @@ impl<'a> LaxPacketHeaders<'a> {
             };
 
             use crate::ip_number::*;
-            use err::tcp::HeaderSliceError::*;
             match ip_payload.ip_number {
+                #[cfg(feature = "icmp")]
                 ICMP => match Icmpv4Slice::from_slice(ip_payload.payload) {
                     Ok(i) => {
                         self.transport = Some(TransportHeader::Icmpv4(i.header()));
@@ -515,6 +514,7 @@ impl<'a> LaxPacketHeaders<'a> {
                         self.stop_err = Some((add_len_source(e), Layer::Icmpv4));
                     }
                 },
+                #[cfg(all(feature = "icmp", feature = "ipv6"))]
                 IPV6_ICMP => match Icmpv6Slice::from_slice(ip_payload.payload) {
                     Ok(i) => {
                         self.transport = Some(TransportHeader::Icmpv6(i.header()));
@@ -542,6 +542,7 @@ impl<'a> LaxPacketHeaders<'a> {
                         }
                     }
                 }
+                #[cfg(feature = "tcp")]
                 TCP => match TcpHeader::from_slice(ip_payload.payload) {
                     Ok(t) => {
                         self.transport = Some(TransportHeader::Tcp(t.0));
@@ -551,10 +552,10 @@ impl<'a> LaxPacketHeaders<'a> {
                         };
                     }
                     Err(e) => match e {
-                        Len(l) => {
+                        err::tcp::HeaderSliceError::Len(l) => {
                             self.stop_err = Some((add_len_source(l), Layer::TcpHeader));
                         }
-                        Content(c) => {
+                        err::tcp::HeaderSliceError::Content(c) => {
                             self.stop_err = Some((SliceError::Tcp(c), Layer::TcpHeader));
                         }
                     },
@@ -564,9 +565,19 @@ impl<'a> LaxPacketHeaders<'a> {
         }
         Ok(())
     }
+
+    /// Returns the (pcp, dei, vid) values of the VLAN tags in outer to inner
+    /// order (empty if no VLAN header is present).
+    pub fn vlan_tags(&self) -> VlanTagIter {
+        self.vlan.as_ref().map(|v| v.tags()).unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
+#[cfg_attr(
+    not(all(feature = "icmp", feature = "ipv6", feature = "tcp")),
+    allow(dead_code)
+)]
 mod test {
     use super::*;
     use crate::test_packet::TestPacket;
@@ -617,6 +628,7 @@ mod test {
         );
     }
 
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
     #[test]
     fn from_x_slice() {
         // no eth
@@ -1391,6 +1403,11 @@ mod test {
                 Some(VlanHeader::Double(d)) => {
                     if data.len() >= vlan_offset + DoubleVlanHeader::LEN {
                         assert_eq!(test.vlan, actual.vlan);
+                        assert!(actual.vlan_tags().eq(test
+                            .vlan
+                            .as_ref()
+                            .map(|v| v.tags())
+                            .unwrap_or_default()));
                     } else if data.len() >= vlan_offset + SingleVlanHeader::LEN {
                         assert_eq!(Some(VlanHeader::Single(d.outer.clone())), actual.vlan);
                     } else {
@@ -1697,4 +1714,52 @@ mod test {
             }
         }
     }
+
+    #[cfg(not(feature = "ipv6"))]
+    #[test]
+    fn ipv6_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([0; 16], [1; 16], 20)
+            .udp(1, 2)
+            .write(&mut packet, &[3, 4])
+            .unwrap();
+
+        // ipv6 packets are returned as ether payload
+        let headers = LaxPacketHeaders::from_ethernet(&packet).unwrap();
+        assert!(headers.net.is_none());
+        assert!(headers.transport.is_none());
+        assert!(headers.stop_err.is_none());
+        assert_eq!(
+            LaxPayloadSlice::Ether(EtherPayloadSlice {
+                ether_type: EtherType::IPV6,
+                payload: &packet[Ethernet2Header::LEN..],
+            }),
+            headers.payload
+        );
+    }
+
+    #[cfg(not(all(feature = "icmp", feature = "tcp")))]
+    #[test]
+    fn transport_disabled() {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20);
+        let mut packet = alloc::vec::Vec::new();
+        #[cfg(not(feature = "tcp"))]
+        builder.tcp(1, 2, 3, 4).write(&mut packet, &[5, 6]).unwrap();
+        #[cfg(feature = "tcp")]
+        builder
+            .icmpv4_echo_request(1, 2)
+            .write(&mut packet, &[5, 6])
+            .unwrap();
+
+        // disabled transport protocols are returned as ip payload
+        let headers = LaxPacketHeaders::from_ip(&packet).unwrap();
+        assert!(headers.transport.is_none());
+        assert!(headers.stop_err.is_none());
+        let LaxPayloadSlice::Ip(payload) = headers.payload else {
+            panic!("expected ip payload");
+        };
+        assert_eq!(packet[9], payload.ip_number.0);
+        assert_eq!(&packet[Ipv4Header::MIN_LEN..], payload.payload);
+    }
 }
@@ -10,6 +10,11 @@ pub struct LaxSlicedPacket<'a> {
     /// Single or double vlan headers if present.
     pub vlan: Option<VlanSlice<'a>>,
 
+    /// Backbone headers & I-TAG if the packet is a Provider Backbone
+    /// Bridging (IEEE 802.1ah) frame. In this case `link` & `vlan`
+    /// contain the headers of the encapsulated customer frame.
+    pub backbone: Option<ProviderBackboneSlice<'a>>,
+
     /// IPv4 or IPv6 header, IP extension headers & payload if present.
     pub net: Option<LaxNetSlice<'a>>,
 
@@ -221,6 +226,118 @@ impl<'a> LaxSlicedPacket<'a> {
         LaxSlicedPacketCursor::parse_from_ip(slice)
     }
 
+    /// Separates a network packet slice into different slices containing
+    /// the headers from the ip header downwards (same as [`LaxSlicedPacket::from_ip`])
+    /// and additionally compares the length of the IP packet indicated in the
+    /// IP header with the slice length & an externally known expected
+    /// total length (e.g. the original length of a captured packet).
+    ///
+    /// If the lengths don't match a [`LaxIpLenDiscrepancy`] is returned
+    /// next to the packet. It can be used to distinguish if a packet was
+    /// cut during the capture (e.g. by the snaplen) or if the packet was
+    /// already incomplete before it was captured (e.g. because it was
+    /// quoted in an ICMP error message).
+    ///
+    /// ```
+    /// use etherparse::{LaxSlicedPacket, PacketBuilder};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234)
+    ///     .write(&mut packet, &[0; 100])
+    ///     .unwrap();
+    ///
+    /// // the packet was complete on the wire (128 bytes) but the capture
+    /// // only recorded the first 40 bytes
+    /// let (sliced, discrepancy) =
+    ///     LaxSlicedPacket::from_ip_with_len_hint(&packet[..40], Some(packet.len())).unwrap();
+    /// assert!(sliced.net.is_some());
+    ///
+    /// let discrepancy = discrepancy.unwrap();
+    /// assert_eq!(128, discrepancy.header_total_len);
+    /// assert_eq!(40, discrepancy.slice_len);
+    /// assert!(discrepancy.is_cut_by_capture());
+    /// ```
+    pub fn from_ip_with_len_hint(
+        slice: &'a [u8],
+        expected_total_len: Option<usize>,
+    ) -> Result<(LaxSlicedPacket<'a>, Option<LaxIpLenDiscrepancy>), err::ip::LaxHeaderSliceError>
+    {
+        let packet = LaxSlicedPacketCursor::parse_from_ip(slice)?;
+        let header_total_len = match &packet.net {
+            Some(LaxNetSlice::Ipv4(ipv4)) => usize::from(ipv4.header().total_len()),
+            Some(LaxNetSlice::Ipv6(ipv6)) => {
+                Ipv6Header::LEN + usize::from(ipv6.header().payload_length())
+            }
+            None => return Ok((packet, None)),
+        };
+        let discrepancy = if header_total_len != slice.len()
+            || expected_total_len
+                .map(|e| e != header_total_len)
+                .unwrap_or(false)
+        {
+            Some(LaxIpLenDiscrepancy {
+                header_total_len,
+                expected_total_len,
+                slice_len: slice.len(),
+            })
+        } else {
+            None
+        };
+        Ok((packet, discrepancy))
+    }
+
+    /// Separates a slice containing an IP payload into slices containing
+    /// the transport header & payload based on an externally known ip number
+    /// (lax version of [`SlicedPacket::from_ip_number`]).
+    ///
+    /// Errors encountered while decoding the transport header are stored in
+    /// the `stop_err` field. The `link`, `vlan` & `net` fields of the result
+    /// are always empty.
+    ///
+    /// ```
+    /// # #[cfg(feature = "tcp")]
+    /// # {
+    /// use etherparse::{ip_number, LaxSlicedPacket, TransportSlice};
+    ///
+    /// // tcp header cut off after 4 bytes
+    /// let data = [0, 80, 1, 2];
+    ///
+    /// let sliced = LaxSlicedPacket::from_ip_number(ip_number::TCP, &data);
+    /// assert_eq!(None, sliced.transport);
+    /// assert!(sliced.stop_err.is_some());
+    /// # }
+    /// ```
+    pub fn from_ip_number(ip_number: IpNumber, slice: &'a [u8]) -> LaxSlicedPacket<'a> {
+        LaxSlicedPacketCursor::parse_from_ip_number(ip_number, slice)
+    }
+
+    /// Returns the (pcp, dei, vid) values of the VLAN tags in outer to inner
+    /// order (empty if no VLAN header is present).
+    pub fn vlan_tags(&self) -> VlanTagIter {
+        self.vlan.as_ref().map(|v| v.tags()).unwrap_or_default()
+    }
+
+    /// Returns the direction of the packet relative to the capturing host
+    /// if it is known from the link layer (e.g. from the packet type of a
+    /// Linux Cooked Capture header).
+    pub fn direction(&self) -> Option<PacketDirection> {
+        self.link.as_ref().and_then(|l| l.direction())
+    }
+
+    /// Summed up length of all decoded headers (link, vlan, net &
+    /// transport layer) in bytes/octets.
+    ///
+    /// The length of the individual layers can be determined via the
+    /// `header_len` methods of the layer slices (e.g.
+    /// [`LaxNetSlice::header_len`]).
+    pub fn headers_total_len(&self) -> usize {
+        self.link.as_ref().map_or(0, |l| l.header_len())
+            + self.vlan.as_ref().map_or(0, |v| v.header_len())
+            + self.net.as_ref().map_or(0, |n| n.header_len())
+            + self.transport.as_ref().map_or(0, |t| t.header_len())
+    }
+
     /// Returns the last ether payload of the packet (if one is present).
     ///
     /// If VLAN header is present the payload after the most inner VLAN
@@ -250,6 +367,7 @@ impl<'a> LaxSlicedPacket<'a> {
                     }
                     _ => None,
                 },
+                LinkSlice::Ieee80211(e) => e.ether_payload(),
             }
         } else {
             None
@@ -272,6 +390,10 @@ impl<'a> LaxSlicedPacket<'a> {
 }
 
 #[cfg(test)]
+#[cfg_attr(
+    not(all(feature = "icmp", feature = "ipv6", feature = "tcp")),
+    allow(dead_code)
+)]
 mod test {
     use super::*;
     use crate::err::{packet::SliceError, LenError};
@@ -288,6 +410,7 @@ mod test {
         let header = LaxSlicedPacket {
             link: None,
             vlan: None,
+            backbone: None,
             net: None,
             transport: None,
             stop_err: None,
@@ -301,6 +424,7 @@ mod test {
         let header = LaxSlicedPacket {
             link: None,
             vlan: None,
+            backbone: None,
             net: None,
             transport: None,
             stop_err: None,
@@ -308,12 +432,125 @@ mod test {
         assert_eq!(
             format!("{:?}", header),
             format!(
-                "LaxSlicedPacket {{ link: {:?}, vlan: {:?}, net: {:?}, transport: {:?}, stop_err: {:?} }}",
-                header.link, header.vlan, header.net, header.transport, header.stop_err
+                "LaxSlicedPacket {{ link: {:?}, vlan: {:?}, backbone: {:?}, net: {:?}, transport: {:?}, stop_err: {:?} }}",
+                header.link, header.vlan, header.backbone, header.net, header.transport, header.stop_err
             )
         );
     }
 
+    #[test]
+    fn direction() {
+        // no link layer
+        assert_eq!(
+            None,
+            LaxSlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+                stop_err: None
+            }
+            .direction()
+        );
+
+        // linux sll
+        for (packet_type, expected) in [
+            (LinuxSllPacketType::HOST, Some(PacketDirection::Inbound)),
+            (
+                LinuxSllPacketType::OUTGOING,
+                Some(PacketDirection::Outbound),
+            ),
+            (
+                LinuxSllPacketType::LOOPBACK,
+                Some(PacketDirection::Loopback),
+            ),
+            (LinuxSllPacketType::KERNEL, None),
+        ] {
+            let header = LinuxSllHeader {
+                packet_type,
+                arp_hrd_type: ArpHardwareId::ETHER,
+                sender_address_valid_length: 6,
+                sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                protocol_type: LinuxSllProtocolType::EtherType(EtherType::WAKE_ON_LAN),
+            };
+            let bytes = header.to_bytes();
+            let sliced = LaxSlicedPacket {
+                link: Some(LinkSlice::LinuxSll(
+                    LinuxSllSlice::from_slice(&bytes).unwrap(),
+                )),
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+                stop_err: None,
+            };
+            assert_eq!(expected, sliced.direction());
+        }
+
+        // ethernet ii
+        let bytes = Ethernet2Header {
+            ether_type: EtherType::WAKE_ON_LAN,
+            ..Default::default()
+        }
+        .to_bytes();
+        assert_eq!(
+            None,
+            LaxSlicedPacket::from_ethernet(&bytes).unwrap().direction()
+        );
+    }
+
+    #[cfg(feature = "tcp")]
+    #[test]
+    fn headers_total_len() {
+        use alloc::vec::*;
+
+        // no content
+        assert_eq!(
+            0,
+            LaxSlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+                stop_err: None
+            }
+            .headers_total_len()
+        );
+
+        let mut packet = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(0x123.try_into().unwrap())
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .tcp(1234, 443, 1, 1024)
+            .write(&mut packet, &[1, 2, 3, 4])
+            .unwrap();
+        let headers_len =
+            Ethernet2Header::LEN + SingleVlanHeader::LEN + Ipv4Header::MIN_LEN + TcpHeader::MIN_LEN;
+
+        // complete packet
+        assert_eq!(
+            headers_len,
+            LaxSlicedPacket::from_ethernet(&packet)
+                .unwrap()
+                .headers_total_len()
+        );
+
+        // packet cut off in the payload
+        assert_eq!(
+            headers_len,
+            LaxSlicedPacket::from_ethernet(&packet[..headers_len + 1])
+                .unwrap()
+                .headers_total_len()
+        );
+
+        // packet cut off in the tcp header
+        let actual = LaxSlicedPacket::from_ethernet(&packet[..headers_len - 1]).unwrap();
+        assert!(actual.stop_err.is_some());
+        assert_eq!(headers_len - TcpHeader::MIN_LEN, actual.headers_total_len());
+    }
+
     #[test]
     fn ether_payload() {
         use alloc::vec::*;
@@ -323,6 +560,7 @@ mod test {
             LaxSlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
                 stop_err: None
@@ -364,6 +602,7 @@ mod test {
                         payload: &payload
                     })),
                     vlan: None,
+                    backbone: None,
                     net: None,
                     transport: None,
                     stop_err: None,
@@ -444,6 +683,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "ipv6")]
     #[test]
     fn ip_payload() {
         use alloc::vec::*;
@@ -453,6 +693,7 @@ mod test {
             LaxSlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
                 stop_err: None,
@@ -512,6 +753,126 @@ mod test {
         }
     }
 
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn from_ip_with_len_hint() {
+        use alloc::vec::*;
+
+        let mut ipv4 = Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .udp(1, 2)
+            .write(&mut ipv4, &[0; 12])
+            .unwrap();
+        let mut ipv6 = Vec::new();
+        PacketBuilder::ipv6([0; 16], [1; 16], 20)
+            .udp(1, 2)
+            .write(&mut ipv6, &[0; 12])
+            .unwrap();
+
+        for packet in [&ipv4, &ipv6] {
+            let len = packet.len();
+
+            // complete packet without or with matching hint
+            for hint in [None, Some(len)] {
+                let (actual, discrepancy) =
+                    LaxSlicedPacket::from_ip_with_len_hint(packet, hint).unwrap();
+                assert_eq!(LaxSlicedPacket::from_ip(packet).unwrap(), actual);
+                assert_eq!(None, discrepancy);
+            }
+
+            // complete packet with differing hint
+            {
+                let (_, discrepancy) =
+                    LaxSlicedPacket::from_ip_with_len_hint(packet, Some(len + 1)).unwrap();
+                assert_eq!(
+                    Some(LaxIpLenDiscrepancy {
+                        header_total_len: len,
+                        expected_total_len: Some(len + 1),
+                        slice_len: len,
+                    }),
+                    discrepancy
+                );
+            }
+
+            // cut packet
+            for hint in [None, Some(len), Some(len - 4)] {
+                let (actual, discrepancy) =
+                    LaxSlicedPacket::from_ip_with_len_hint(&packet[..len - 4], hint).unwrap();
+                assert_eq!(
+                    LaxSlicedPacket::from_ip(&packet[..len - 4]).unwrap(),
+                    actual
+                );
+                let discrepancy = discrepancy.unwrap();
+                assert_eq!(
+                    LaxIpLenDiscrepancy {
+                        header_total_len: len,
+                        expected_total_len: hint,
+                        slice_len: len - 4,
+                    },
+                    discrepancy
+                );
+                assert_eq!(hint == Some(len), discrepancy.is_cut_by_capture());
+                assert_eq!(hint == Some(len - 4), discrepancy.is_cut_before_capture());
+            }
+        }
+
+        // error in ip header
+        assert!(LaxSlicedPacket::from_ip_with_len_hint(&[], Some(20)).is_err());
+    }
+
+    #[test]
+    fn from_ip_number() {
+        let udp = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: (UdpHeader::LEN + 4) as u16,
+            checksum: 0,
+        };
+        let mut data = [0u8; UdpHeader::LEN + 4];
+        data[..UdpHeader::LEN].copy_from_slice(&udp.to_bytes());
+        data[UdpHeader::LEN..].copy_from_slice(&[1, 2, 3, 4]);
+
+        // ok
+        {
+            let actual = LaxSlicedPacket::from_ip_number(ip_number::UDP, &data);
+            assert_eq!(None, actual.link);
+            assert_eq!(None, actual.vlan);
+            assert_eq!(None, actual.net);
+            assert_eq!(
+                Some(TransportSlice::Udp(UdpSlice::from_slice(&data).unwrap())),
+                actual.transport
+            );
+            assert_eq!(None, actual.stop_err);
+        }
+
+        // unknown ip number
+        {
+            let actual = LaxSlicedPacket::from_ip_number(ip_number::GGP, &data);
+            assert_eq!(None, actual.transport);
+            assert_eq!(None, actual.stop_err);
+        }
+
+        // len error
+        {
+            let actual = LaxSlicedPacket::from_ip_number(ip_number::UDP, &data[..7]);
+            assert_eq!(None, actual.transport);
+            assert_eq!(
+                Some((
+                    SliceError::Len(LenError {
+                        required_len: UdpHeader::LEN,
+                        len: 7,
+                        len_source: LenSource::Slice,
+                        layer: Layer::UdpHeader,
+                        layer_start_offset: 0,
+                    }),
+                    Layer::UdpHeader
+                )),
+                actual.stop_err
+            );
+        }
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
     #[test]
     fn from_x_slice() {
         // no eth
@@ -1308,6 +1669,11 @@ mod test {
                     assert_eq!(None, actual.vlan);
                 }
             }
+            assert!(actual.vlan_tags().eq(actual
+                .vlan
+                .as_ref()
+                .map(|v| v.to_header().tags())
+                .unwrap_or_default()));
         }
 
         fn compare_ip(test: &TestPacket, actual: &LaxSlicedPacket) {
@@ -1608,4 +1974,141 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn from_x_provider_backbone() {
+        use ether_type::*;
+
+        // customer frame
+        let builder = PacketBuilder::ethernet2([13, 14, 15, 16, 17, 18], [19, 20, 21, 22, 23, 24])
+            .single_vlan(VlanId::try_new(2).unwrap())
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let mut customer = alloc::vec::Vec::with_capacity(builder.size(4));
+        builder.write(&mut customer, &[1, 2, 3, 4]).unwrap();
+
+        let itag = Ieee8021ahHeader {
+            pcp: VlanPcp::try_new(3).unwrap(),
+            drop_eligible_indicator: false,
+            use_customer_address: true,
+            isid: Ieee8021ahIsid::try_new(0x123456).unwrap(),
+        };
+
+        let mut data = alloc::vec::Vec::new();
+        data.extend_from_slice(
+            &Ethernet2Header {
+                destination: [1, 2, 3, 4, 5, 6],
+                source: [7, 8, 9, 10, 11, 12],
+                ether_type: PROVIDER_BACKBONE_BRIDGING,
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&itag.to_bytes());
+        data.extend_from_slice(&customer);
+
+        // ok
+        {
+            let expected = LaxSlicedPacket::from_ethernet(&customer).unwrap();
+            for actual in [
+                LaxSlicedPacket::from_ethernet(&data).unwrap(),
+                LaxSlicedPacket::from_ether_type(PROVIDER_BACKBONE_BRIDGING, &data[14..]),
+            ] {
+                assert_eq!(expected.link, actual.link);
+                assert_eq!(expected.vlan, actual.vlan);
+                assert_eq!(expected.net, actual.net);
+                assert_eq!(expected.transport, actual.transport);
+                assert_eq!(None, actual.stop_err);
+
+                let backbone = actual.backbone.unwrap();
+                assert_eq!(itag, backbone.itag.to_header());
+                assert_eq!(&customer[..], backbone.itag.payload());
+                assert!(backbone.vlan.is_none());
+            }
+        }
+
+        // len error in the I-TAG
+        {
+            let actual = LaxSlicedPacket::from_ethernet(&data[..14 + 3]).unwrap();
+            assert!(actual.backbone.is_none());
+            assert_eq!(
+                Some((
+                    SliceError::Len(err::LenError {
+                        required_len: Ieee8021ahHeader::LEN,
+                        len: 3,
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ieee8021ahHeader,
+                        layer_start_offset: 14,
+                    }),
+                    Layer::Ieee8021ahHeader
+                )),
+                actual.stop_err
+            );
+        }
+
+        // len error in the customer ethernet II header
+        {
+            let actual = LaxSlicedPacket::from_ethernet(&data[..14 + 4 + 13]).unwrap();
+            assert!(actual.backbone.is_some());
+            assert!(actual.link.is_none());
+            assert_eq!(
+                Some((
+                    SliceError::Len(err::LenError {
+                        required_len: Ethernet2Header::LEN,
+                        len: 13,
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ethernet2Header,
+                        layer_start_offset: 14 + 4,
+                    }),
+                    Layer::Ethernet2Header
+                )),
+                actual.stop_err
+            );
+        }
+    }
+
+    #[cfg(not(feature = "ipv6"))]
+    #[test]
+    fn ipv6_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([0; 16], [1; 16], 20)
+            .udp(1, 2)
+            .write(&mut packet, &[3, 4])
+            .unwrap();
+
+        // ipv6 packets are returned as ether payload
+        let sliced = LaxSlicedPacket::from_ethernet(&packet).unwrap();
+        assert!(sliced.net.is_none());
+        assert!(sliced.transport.is_none());
+        assert!(sliced.stop_err.is_none());
+        assert_eq!(
+            Some(EtherPayloadSlice {
+                ether_type: EtherType::IPV6,
+                payload: &packet[Ethernet2Header::LEN..],
+            }),
+            sliced.ether_payload()
+        );
+    }
+
+    #[cfg(not(all(feature = "icmp", feature = "tcp")))]
+    #[test]
+    fn transport_disabled() {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20);
+        let mut packet = alloc::vec::Vec::new();
+        #[cfg(not(feature = "tcp"))]
+        builder.tcp(1, 2, 3, 4).write(&mut packet, &[5, 6]).unwrap();
+        #[cfg(feature = "tcp")]
+        builder
+            .icmpv4_echo_request(1, 2)
+            .write(&mut packet, &[5, 6])
+            .unwrap();
+
+        // disabled transport protocols are returned as ip payload
+        let sliced = LaxSlicedPacket::from_ip(&packet).unwrap();
+        assert!(sliced.transport.is_none());
+        assert!(sliced.stop_err.is_none());
+        let payload = sliced.ip_payload().unwrap();
+        assert_eq!(packet[9], payload.ip_number.0);
+        assert_eq!(&packet[Ipv4Header::MIN_LEN..], payload.payload);
+    }
 }
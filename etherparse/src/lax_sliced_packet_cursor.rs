@@ -19,6 +19,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
             result: LaxSlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
                 stop_err: None,
@@ -37,10 +38,10 @@ impl<'a> LaxSlicedPacketCursor<'a> {
         // continue parsing (if required)
         match payload.ether_type {
             IPV4 => Ok(cursor.slice_ip(payload.payload)),
+            #[cfg(feature = "ipv6")]
             IPV6 => Ok(cursor.slice_ip(payload.payload)),
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                Ok(cursor.slice_vlan(payload.payload))
-            }
+            ether_type if ether_type.is_vlan_tag() => Ok(cursor.slice_vlan(payload.payload)),
+            PROVIDER_BACKBONE_BRIDGING => Ok(cursor.slice_ieee8021ah(payload.payload)),
             _ => Ok(cursor.result),
         }
     }
@@ -54,6 +55,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                     payload: slice,
                 })),
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
                 stop_err: None,
@@ -62,10 +64,10 @@ impl<'a> LaxSlicedPacketCursor<'a> {
         use ether_type::*;
         match ether_type {
             IPV4 => cursor.slice_ip(slice),
+            #[cfg(feature = "ipv6")]
             IPV6 => cursor.slice_ip(slice),
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                cursor.slice_vlan(slice)
-            }
+            ether_type if ether_type.is_vlan_tag() => cursor.slice_vlan(slice),
+            PROVIDER_BACKBONE_BRIDGING => cursor.slice_ieee8021ah(slice),
             _ => cursor.result,
         }
     }
@@ -85,6 +87,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
             result: LaxSlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: Some(ip.into()),
                 transport: None,
                 stop_err: stop_err.map(|(stop_err, stop_layer)| {
@@ -103,6 +106,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                                         O::Ipv6Exts(E::IpAuth(auth))
                                     }
                                 }
+                                E::Mobility(m) => O::Ipv6Exts(E::Mobility(m)),
                             },
                         },
                         stop_layer,
@@ -113,6 +117,27 @@ impl<'a> LaxSlicedPacketCursor<'a> {
         .slice_transport(payload))
     }
 
+    pub fn parse_from_ip_number(ip_number: IpNumber, slice: &'a [u8]) -> LaxSlicedPacket<'a> {
+        LaxSlicedPacketCursor {
+            offset: 0,
+            result: LaxSlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+                stop_err: None,
+            },
+        }
+        .slice_transport(LaxIpPayloadSlice {
+            incomplete: false,
+            ip_number,
+            fragmented: false,
+            len_source: LenSource::Slice,
+            payload: slice,
+        })
+    }
+
     pub fn slice_vlan(mut self, slice: &'a [u8]) -> LaxSlicedPacket<'a> {
         use ether_type::*;
         use VlanSlice::*;
@@ -136,7 +161,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
         //check if it is a double vlan header
         match outer.ether_type() {
             //in case of a double vlan header continue with the inner
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+            ether_type if ether_type.is_vlan_tag() => {
                 let inner = match SingleVlanSlice::from_slice(outer.payload_slice()) {
                     Ok(v) => v,
                     Err(err) => {
@@ -156,18 +181,75 @@ impl<'a> LaxSlicedPacketCursor<'a> {
 
                 match inner_ether_type {
                     IPV4 => self.slice_ip(inner.payload_slice()),
+                    #[cfg(feature = "ipv6")]
                     IPV6 => self.slice_ip(inner.payload_slice()),
+                    PROVIDER_BACKBONE_BRIDGING if self.result.backbone.is_none() => {
+                        self.slice_ieee8021ah(inner.payload_slice())
+                    }
                     _ => self.result,
                 }
             }
             value => match value {
                 IPV4 => self.slice_ip(outer.payload_slice()),
+                #[cfg(feature = "ipv6")]
                 IPV6 => self.slice_ip(outer.payload_slice()),
+                PROVIDER_BACKBONE_BRIDGING if self.result.backbone.is_none() => {
+                    self.slice_ieee8021ah(outer.payload_slice())
+                }
                 _ => self.result,
             },
         }
     }
 
+    /// Slices an IEEE 802.1ah I-TAG, moves the already decoded link & vlan
+    /// headers to `backbone` & continues with the customer Ethernet II frame.
+    ///
+    /// Only called if no I-TAG was decoded before (no recursion into
+    /// nested backbone frames).
+    pub fn slice_ieee8021ah(mut self, slice: &'a [u8]) -> LaxSlicedPacket<'a> {
+        use ether_type::*;
+
+        let itag = match Ieee8021ahSlice::from_slice(slice) {
+            Ok(v) => v,
+            Err(err) => {
+                self.result.stop_err = Some((
+                    SliceError::Len(err.add_offset(self.offset)),
+                    Layer::Ieee8021ahHeader,
+                ));
+                return self.result;
+            }
+        };
+        self.offset += Ieee8021ahHeader::LEN;
+        self.result.backbone = Some(ProviderBackboneSlice {
+            link: self.result.link.take(),
+            vlan: self.result.vlan.take(),
+            itag: itag.clone(),
+        });
+
+        // continue with the customer frame
+        let eth = match Ethernet2Slice::from_slice_without_fcs(itag.payload()) {
+            Ok(v) => v,
+            Err(err) => {
+                self.result.stop_err = Some((
+                    SliceError::Len(err.add_offset(self.offset)),
+                    Layer::Ethernet2Header,
+                ));
+                return self.result;
+            }
+        };
+        let payload = eth.payload();
+        self.offset += eth.header_len();
+        self.result.link = Some(LinkSlice::Ethernet2(eth));
+
+        match payload.ether_type {
+            IPV4 => self.slice_ip(payload.payload),
+            #[cfg(feature = "ipv6")]
+            IPV6 => self.slice_ip(payload.payload),
+            ether_type if ether_type.is_vlan_tag() => self.slice_vlan(payload.payload),
+            _ => self.result,
+        }
+    }
+
     pub fn slice_ip(mut self, slice: &'a [u8]) -> LaxSlicedPacket<'a> {
         // ip slice
         let ip = match LaxIpSlice::from_slice(slice) {
@@ -201,6 +283,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                             LaxIpSlice::Ipv4(_) => O::Ipv4Exts(auth),
                             LaxIpSlice::Ipv6(_) => O::Ipv6Exts(E::IpAuth(auth)),
                         },
+                        E::Mobility(m) => O::Ipv6Exts(E::Mobility(m)),
                     },
                 },
                 stop_layer,
@@ -221,6 +304,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
             return self.result;
         }
         match slice.ip_number {
+            #[cfg(feature = "icmp")]
             ip_number::ICMP => match Icmpv4Slice::from_slice(slice.payload) {
                 Ok(icmp) => {
                     self.offset += icmp.slice().len();
@@ -243,6 +327,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                     self.result.stop_err = Some((O::Len(err), Layer::UdpHeader));
                 }
             },
+            #[cfg(feature = "tcp")]
             ip_number::TCP => match TcpSlice::from_slice(slice.payload) {
                 Ok(tcp) => {
                     self.offset += tcp.slice().len();
@@ -263,6 +348,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                     ));
                 }
             },
+            #[cfg(all(feature = "icmp", feature = "ipv6"))]
             ip_number::IPV6_ICMP => match Icmpv6Slice::from_slice(slice.payload) {
                 Ok(icmp) => {
                     self.offset += icmp.slice().len();
@@ -12,6 +12,8 @@ pub enum LenSource {
     UdpHeaderLen,
     /// Error occurred while decoding a TCP header.
     TcpHeaderLen,
+    /// Length was determined by the "short length" field of a MACsec header.
+    MacsecShortLen,
 }
 
 #[cfg(test)]
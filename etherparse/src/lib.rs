@@ -3,11 +3,20 @@
 //! Currently supported are:
 //! * Ethernet II
 //! * IEEE 802.1Q VLAN Tagging Header
+//! * IEEE 802.1ah Provider Backbone Bridging I-TAG (MAC-in-MAC)
+//! * IEEE 802.1AE MACsec header (SecTAG & ICV separation, no decryption)
+//! * IEEE 802.11 (WiFi) data frames with LLC/SNAP (slicing only)
+//! * LLDP TLVs (including organizationally specific TLVs)
 //! * IPv4
 //! * IPv6 (supporting the most common extension headers, but not all)
 //! * UDP
 //! * TCP
 //! * ICMP & ICMPv6 (not all message types are supported)
+//! * ERSPAN Type II & Type III (slicing only)
+//! * TZSP (slicing only)
+//! * DNS names (zero allocation label decoding incl. compression)
+//! * NTP (fixed header)
+//! * PTP / IEEE 1588 (common header)
 //!
 //! Reconstruction of fragmented IP packets is also supported, but requires allocations.
 //!
@@ -17,7 +26,18 @@
 //!
 //! ```toml
 //! [dependencies]
-//! etherparse = "0.16"
+//! etherparse = "0.17"
+//! ```
+//!
+//! The decoding of IPv6, ICMP & TCP headers in the packet parsers is
+//! controlled by the `ipv6`, `icmp` & `tcp` features (enabled by default).
+//! If you disable the default features (e.g. for `no_std`) they have to be
+//! enabled explicitly, otherwise these packets are returned as unknown
+//! payloads:
+//!
+//! ```toml
+//! [dependencies]
+//! etherparse = { version = "0.17", default-features = false, features = ["ipv6", "icmp", "tcp"] }
 //! ```
 //!
 //! # What is etherparse?
@@ -68,6 +88,7 @@
 //!
 //! * [`SlicedPacket::from_ethernet`] for parsing from an Ethernet II header downwards
 //! * [`SlicedPacket::from_linux_sll`] for parsing from a Linux Cooked Capture v1 (SLL) downwards
+//! * [`SlicedPacket::from_ieee80211`] for parsing from an IEEE 802.11 (WiFi) data frame MAC header downwards
 //! * [`SlicedPacket::from_ether_type`] for parsing a slice starting after an Ethernet II header
 //! * [`SlicedPacket::from_ip`] for parsing from an IPv4 or IPv6 downwards
 //!
@@ -126,6 +147,7 @@
 //!
 //! * [`Ethernet2Slice::from_slice_without_fcs`] & [`Ethernet2Slice::from_slice_with_crc32_fcs`]
 //! * [`LinuxSllSlice::from_slice`]
+//! * [`Ieee80211DataSlice::from_slice`]
 //! * [`SingleVlanSlice::from_slice`] & [`DoubleVlanSlice::from_slice`]
 //! * [`IpSlice::from_slice`] & [`LaxIpSlice::from_slice`]
 //! * [`Ipv4Slice::from_slice`] & [`LaxIpv4Slice::from_slice`]
@@ -134,6 +156,8 @@
 //! * [`TcpSlice::from_slice`]
 //! * [`Icmpv4Slice::from_slice`]
 //! * [`Icmpv6Slice::from_slice`]
+//! * [`ErspanSlice::from_slice`]
+//! * [`TzspSlice::from_slice`]
 //!
 //! The resulting data types allow access to both the header(s) and the payload of the layer
 //! and will automatically limit the length of payload if the layer has a length field limiting the
@@ -156,6 +180,7 @@
 //! * [`Ipv6RawExtHeaderSlice::from_slice`]
 //! * [`IpAuthHeaderSlice::from_slice`]
 //! * [`Ipv6FragmentHeaderSlice::from_slice`]
+//! * [`MobilityHeaderSlice::from_slice`]
 //! * [`UdpHeaderSlice::from_slice`]
 //! * [`TcpHeaderSlice::from_slice`]
 //!
@@ -173,6 +198,7 @@
 //! * [`Ipv6RawExtHeader::read`] & [`Ipv6RawExtHeader::from_slice`]
 //! * [`IpAuthHeader::read`] & [`IpAuthHeader::from_slice`]
 //! * [`Ipv6FragmentHeader::read`] & [`Ipv6FragmentHeader::from_slice`]
+//! * [`MobilityHeader::read`] & [`MobilityHeader::from_slice`]
 //! * [`UdpHeader::read`] & [`UdpHeader::from_slice`]
 //! * [`TcpHeader::read`] & [`TcpHeader::from_slice`]
 //! * [`Icmpv4Header::read`] & [`Icmpv4Header::from_slice`]
@@ -236,6 +262,7 @@
 //! * [`Ipv6RawExtHeader::to_bytes`] & [`Ipv6RawExtHeader::write`]
 //! * [`IpAuthHeader::to_bytes`] & [`IpAuthHeader::write`]
 //! * [`Ipv6FragmentHeader::to_bytes`] & [`Ipv6FragmentHeader::write`]
+//! * [`MobilityHeader::to_bytes`] & [`MobilityHeader::write`]
 //! * [`UdpHeader::to_bytes`] & [`UdpHeader::write`]
 //! * [`TcpHeader::to_bytes`] & [`TcpHeader::write`]
 //! * [`Icmpv4Header::to_bytes`] & [`Icmpv4Header::write`]
@@ -271,6 +298,7 @@
 //! * LINUX_SLL [header definition](https://github.com/the-tcpdump-group/libpcap/blob/a932566fa1f6df16176ac702b1762ea1cd9ed9a3/pcap/sll.h) on libpcap
 //! * [Linux packet types definitions](https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git/plain/include/uapi/linux/if_packet.h?id=e33c4963bf536900f917fb65a687724d5539bc21) on the Linux kernel
 //! * Address Resolution Protocol (ARP) Parameters [Harware Types](https://www.iana.org/assignments/arp-parameters/arp-parameters.xhtml#arp-parameters-2)
+//! * [ERSPAN](https://datatracker.ietf.org/doc/html/draft-foschiano-erspan-03) Cisco Systems' Encapsulated Remote Switch Port Analyzer
 //! * [Arp hardware identifiers definitions](https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git/plain/include/uapi/linux/if_arp.h?id=e33c4963bf536900f917fb65a687724d5539bc21) on the Linux kernel
 
 // # Reason for 'bool_comparison' disable:
@@ -293,14 +321,29 @@
 // enables https://doc.rust-lang.org/beta/unstable-book/language-features/doc-cfg.html
 // for docs.rs
 #![cfg_attr(docsrs, feature(doc_cfg))]
+// With the "safe-only" feature all unchecked accesses are replaced by
+// bounds checked equivalents. The only remaining exceptions are the
+// declarations of the public `*_unchecked` functions & the pointer casts in
+// the `ref_from_prefix` & `mut_from_prefix` functions of the `*HeaderRaw`
+// types, which are kept so the feature does not change the public API.
+// These exceptions (and the tests calling them) are marked with
+// `allow(unsafe_code)`, which is why the lint is set to `deny` (`forbid`
+// rejects the `unsafe fn` declarations & can not be overridden by an
+// `allow`).
+#![cfg_attr(feature = "safe-only", deny(unsafe_code))]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 extern crate alloc;
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 extern crate proptest;
 #[cfg(any(feature = "std", test))]
 extern crate std;
 
+// declared first so the `unchecked!` macro is available in all modules
+#[macro_use]
+mod helpers;
+pub(crate) use helpers::*;
+
 /// Module containing error types that can be triggered.
 pub mod err;
 
@@ -309,16 +352,74 @@ pub mod err;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod defrag;
 
+/// Module containing helpers to format packets as human-readable text
+/// (one-line summaries, verbose multi-line trees & annotated hexdumps).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod fmt;
+
+/// Module containing helpers to track IGMP & MLD multicast group
+/// memberships (contains allocations).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod multicast;
+
+/// Module containing a cache of IP to MAC address mappings learned from
+/// ARP & NDP messages (contains allocations).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod neighbor;
+
+/// Module containing minimal "fast path" extractors that only decode the
+/// fields needed for filtering (e.g. addresses & ports).
+pub mod quick;
+
+/// Module containing well-known UDP & TCP port numbers and their service
+/// names.
+///
+/// Ports are only a hint for which protocol is transported, but are commonly
+/// used to decide which parser should be tried on a payload:
+///
+/// ```
+/// use etherparse::services;
+///
+/// assert_eq!(Some("dns"), services::udp_service_name(services::DNS));
+/// assert_eq!(Some("vxlan"), services::udp_service_name(4789));
+/// assert_eq!(None, services::udp_service_name(1));
+/// ```
+pub mod services;
+
+/// Module containing helpers to analyze TCP segments in the context of a
+/// tracked connection (e.g. recognizing keep-alives & zero window probes).
+pub mod tcp_analysis;
+
+/// Module containing helpers to truncate packets to a byte budget while
+/// keeping the headers intact (contains allocations).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod truncate;
+
+/// Module containing a streaming parser that calls the callbacks of a
+/// [`visit::PacketVisitor`] for every decoded layer instead of building
+/// a [`SlicedPacket`].
+pub mod visit;
+
 mod link;
 pub use crate::link::arp_hardware_id::*;
 pub use crate::link::double_vlan_header::*;
 pub use crate::link::double_vlan_header_slice::*;
 pub use crate::link::double_vlan_slice::*;
+pub use crate::link::ether_payload_parser::*;
 pub use crate::link::ether_payload_slice::*;
 pub use crate::link::ether_type_impl::*;
+pub use crate::link::ethercat_header_slice::*;
 pub use crate::link::ethernet2_header::*;
 pub use crate::link::ethernet2_header_slice::*;
 pub use crate::link::ethernet2_slice::*;
+pub use crate::link::ieee80211_data_slice::*;
+pub use crate::link::ieee8021ah_header::*;
+pub use crate::link::ieee8021ah_isid::*;
+pub use crate::link::ieee8021ah_slice::*;
 pub use crate::link::link_header::*;
 pub use crate::link::link_slice::*;
 pub use crate::link::linux_nonstandard_ether_type::*;
@@ -328,16 +429,47 @@ pub use crate::link::linux_sll_packet_type::*;
 pub use crate::link::linux_sll_payload_slice::*;
 pub use crate::link::linux_sll_protocol_type::*;
 pub use crate::link::linux_sll_slice::*;
+pub use crate::link::lldp_org_tlv::*;
+pub use crate::link::lldp_tlv::*;
+pub use crate::link::lldp_tlv_iter::*;
+pub use crate::link::lldp_tlv_type::*;
+#[cfg(feature = "macsec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+pub use crate::link::macsec_an::*;
+#[cfg(feature = "macsec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+pub use crate::link::macsec_header::*;
+#[cfg(feature = "macsec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+pub use crate::link::macsec_icv_len::*;
+#[cfg(feature = "macsec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+pub use crate::link::macsec_short_len::*;
+#[cfg(feature = "macsec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macsec")))]
+pub use crate::link::macsec_slice::*;
+pub use crate::link::packet_direction::*;
+pub use crate::link::profinet_header_slice::*;
+pub use crate::link::provider_backbone_slice::*;
 pub use crate::link::single_vlan_header::*;
 pub use crate::link::single_vlan_header_slice::*;
 pub use crate::link::single_vlan_slice::*;
+pub use crate::link::vlan_edit_impl::*;
 pub use crate::link::vlan_header::*;
 pub use crate::link::vlan_id::*;
 pub use crate::link::vlan_pcp::*;
 pub use crate::link::vlan_slice::*;
+pub use crate::link::vlan_tag::*;
+pub use crate::link::vlan_tag_iter::*;
 
-#[cfg(test)]
-pub(crate) mod test_gens;
+/// Proptest strategies generating random headers (only available with
+/// the `test-utils` feature).
+///
+/// These are the generators etherparse uses in its own tests and can be
+/// reused by crates building on etherparse.
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod test_gens;
 
 mod net;
 pub use net::*;
@@ -347,7 +479,10 @@ pub use net::*;
 pub mod io;
 
 mod transport;
+pub use crate::transport::checksum_result::*;
 pub use crate::transport::icmp_echo_header::*;
+pub use crate::transport::icmp_echo_match::*;
+pub use crate::transport::icmp_echo_payload::*;
 pub use crate::transport::icmpv4;
 pub use crate::transport::icmpv4_header::*;
 pub use crate::transport::icmpv4_slice::*;
@@ -355,30 +490,81 @@ pub use crate::transport::icmpv4_type::*;
 pub use crate::transport::icmpv6;
 pub use crate::transport::icmpv6_header::*;
 pub use crate::transport::icmpv6_slice::*;
+pub use crate::transport::icmpv6_slice_with_addrs::*;
 pub use crate::transport::icmpv6_type::*;
+pub use crate::transport::probe_id::*;
 pub use crate::transport::tcp_header::*;
 pub use crate::transport::tcp_header_slice::*;
+pub use crate::transport::tcp_header_slice_mut::*;
 pub use crate::transport::tcp_option_element::*;
 pub use crate::transport::tcp_option_impl::*;
 pub use crate::transport::tcp_option_read_error::*;
 pub use crate::transport::tcp_option_write_error::*;
 pub use crate::transport::tcp_options::*;
 pub use crate::transport::tcp_options_iterator::*;
+pub use crate::transport::tcp_segment_kind::*;
+pub use crate::transport::tcp_seq_range::*;
 pub use crate::transport::tcp_slice::*;
 pub use crate::transport::transport_header::*;
 pub use crate::transport::transport_slice::*;
 pub use crate::transport::udp_header::*;
+pub use crate::transport::udp_header_raw::*;
 pub use crate::transport::udp_header_slice::*;
+pub use crate::transport::udp_header_slice_mut::*;
 pub use crate::transport::udp_slice::*;
+pub use crate::transport::udp_zero_checksum_policy::*;
+
+mod tunnel;
+pub use crate::tunnel::erspan_slice::*;
+pub use crate::tunnel::erspan_version::*;
+pub use crate::tunnel::ipv6_transition_tunnel::*;
+pub use crate::tunnel::teredo_slice::*;
+pub use crate::tunnel::tzsp_encapsulation::*;
+pub use crate::tunnel::tzsp_packet_type::*;
+pub use crate::tunnel::tzsp_slice::*;
+pub use crate::tunnel::tzsp_tags_iterator::*;
+
+mod app;
+pub use crate::app::dns_label_iter::*;
+pub use crate::app::dns_name::*;
+pub use crate::app::ntp_header::*;
+pub use crate::app::ntp_leap_indicator::*;
+pub use crate::app::ntp_mode::*;
+pub use crate::app::ntp_packet_slice::*;
+pub use crate::app::ntp_timestamp::*;
+pub use crate::app::ntp_version::*;
+pub use crate::app::ptp_header::*;
+pub use crate::app::ptp_header_slice::*;
+pub use crate::app::ptp_message_type::*;
+pub use crate::app::someip_header_slice::*;
+pub use crate::app::someip_message_type::*;
+pub use crate::app::someip_return_code::*;
+pub use crate::app::wireguard_message_type::*;
+pub use crate::app::wireguard_slice::*;
 
 /// Helpers for calculating checksums.
 pub mod checksum;
 
-#[cfg(test)]
+/// Non-cryptographic hash functions (e.g. used for flow hashing).
+pub mod hash;
+
+mod bounded_payload;
+pub use crate::bounded_payload::*;
+
+mod cast_type;
+pub use crate::cast_type::*;
+
+mod chained_slice;
+pub use crate::chained_slice::*;
+
+mod chained_sliced_packet;
+pub use crate::chained_sliced_packet::*;
+
+#[cfg(all(test, feature = "icmp", feature = "ipv6", feature = "tcp"))]
 mod compositions_tests;
 
-mod helpers;
-pub(crate) use helpers::*;
+mod header_stack_len;
+pub use crate::header_stack_len::*;
 
 mod lax_packet_headers;
 pub use lax_packet_headers::*;
@@ -395,25 +581,71 @@ pub(crate) use lax_sliced_packet_cursor::*;
 mod len_source;
 pub use len_source::*;
 
-#[cfg(feature = "std")]
+mod packet_buf;
+pub use crate::packet_buf::*;
+
+mod packet_headers_buf;
+pub use crate::packet_headers_buf::*;
+
 mod packet_builder;
-#[cfg(feature = "std")]
 pub use crate::packet_builder::*;
 
+#[cfg(feature = "std")]
+mod packet_corruption;
+#[cfg(feature = "std")]
+pub use crate::packet_corruption::*;
+
+#[cfg(feature = "std")]
+mod packet_template;
+#[cfg(feature = "std")]
+pub use crate::packet_template::*;
+
+#[cfg(feature = "std")]
+mod packet_template_overrides;
+#[cfg(feature = "std")]
+pub use crate::packet_template_overrides::*;
+
+mod nested_sliced_packet;
+pub use crate::nested_sliced_packet::*;
+
 mod packet_headers;
 pub use crate::packet_headers::*;
 
+mod packet_with_meta;
+pub use crate::packet_with_meta::*;
+
+mod parse_stop;
+pub use crate::parse_stop::*;
+
+mod payload_protocol;
+pub use crate::payload_protocol::*;
+
 mod payload_slice;
 pub use crate::payload_slice::*;
 
+mod parse_limits;
+pub use crate::parse_limits::*;
+
+mod slice_buf;
+pub use crate::slice_buf::*;
+
+mod slice_options;
+pub use crate::slice_options::*;
+
 mod sliced_packet;
 pub use crate::sliced_packet::*;
 
 mod sliced_packet_cursor;
 pub(crate) use sliced_packet_cursor::*;
 
-#[cfg(test)]
-pub(crate) mod test_packet;
+/// Description of a packet composition that can be serialized for tests
+/// (only available with the `test-utils` feature).
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod test_packet;
+
+mod u16_be;
+pub use crate::u16_be::*;
 
 /// Deprecated use [err::ReadError] instead or use the specific error type returned by operation you are using.
 #[cfg(feature = "std")]
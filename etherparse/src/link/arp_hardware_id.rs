@@ -93,6 +93,223 @@ impl ArpHardwareId {
     pub const NONE: ArpHardwareId = Self(0xFFFE);
 }
 
+impl ArpHardwareId {
+    /// Known values that can be parsed by name via [`core::str::FromStr`].
+    const NAMED: [ArpHardwareId; 66] = [
+        Self::NETROM,
+        Self::ETHER,
+        Self::EETHER,
+        Self::AX25,
+        Self::PRONET,
+        Self::CHAOS,
+        Self::IEEE802,
+        Self::ARCNET,
+        Self::APPLETLK,
+        Self::DLCI,
+        Self::ATM,
+        Self::METRICOM,
+        Self::IEEE1394,
+        Self::EUI64,
+        Self::INFINIBAND,
+        Self::SLIP,
+        Self::CSLIP,
+        Self::SLIP6,
+        Self::CSLIP6,
+        Self::RSRVD,
+        Self::ADAPT,
+        Self::ROSE,
+        Self::X25,
+        Self::HWX25,
+        Self::CAN,
+        Self::PPP,
+        Self::CISCO_HDLC,
+        Self::LAPB,
+        Self::DDCMP,
+        Self::RAWHDLC,
+        Self::RAWIP,
+        Self::TUNNEL,
+        Self::TUNNEL6,
+        Self::FRAD,
+        Self::SKIP,
+        Self::LOOPBACK,
+        Self::LOCALTLK,
+        Self::FDDI,
+        Self::BIF,
+        Self::SIT,
+        Self::IPDDP,
+        Self::IPGRE,
+        Self::PIMREG,
+        Self::HIPPI,
+        Self::ASH,
+        Self::ECONET,
+        Self::IRDA,
+        Self::FCPP,
+        Self::FCAL,
+        Self::FCPL,
+        Self::FCFABRIC,
+        Self::IEEE802_TR,
+        Self::IEEE80211,
+        Self::IEEE80211_PRISM,
+        Self::IEEE80211_RADIOTAP,
+        Self::IEEE802154,
+        Self::IEEE802154_MONITOR,
+        Self::PHONET,
+        Self::PHONET_PIPE,
+        Self::CAIF,
+        Self::IP6GRE,
+        Self::NETLINK,
+        Self::IPV6LOWPAN,
+        Self::VSOCKMON,
+        Self::VOID,
+        Self::NONE,
+    ];
+
+    /// Returns the name of the value if it is a known constant
+    /// (the lowercase name of the constant with `_` replaced by `-`).
+    ///
+    /// The returned name is accepted by the [`core::str::FromStr`]
+    /// implementation:
+    ///
+    /// ```
+    /// use etherparse::ArpHardwareId;
+    ///
+    /// assert_eq!(Some("ether"), ArpHardwareId::ETHER.as_str());
+    /// assert_eq!(Ok(ArpHardwareId::ETHER), "ether".parse());
+    /// ```
+    pub fn as_str(self) -> Option<&'static str> {
+        match self {
+            Self::NETROM => Some("netrom"),
+            Self::ETHER => Some("ether"),
+            Self::EETHER => Some("eether"),
+            Self::AX25 => Some("ax25"),
+            Self::PRONET => Some("pronet"),
+            Self::CHAOS => Some("chaos"),
+            Self::IEEE802 => Some("ieee802"),
+            Self::ARCNET => Some("arcnet"),
+            Self::APPLETLK => Some("appletlk"),
+            Self::DLCI => Some("dlci"),
+            Self::ATM => Some("atm"),
+            Self::METRICOM => Some("metricom"),
+            Self::IEEE1394 => Some("ieee1394"),
+            Self::EUI64 => Some("eui64"),
+            Self::INFINIBAND => Some("infiniband"),
+            Self::SLIP => Some("slip"),
+            Self::CSLIP => Some("cslip"),
+            Self::SLIP6 => Some("slip6"),
+            Self::CSLIP6 => Some("cslip6"),
+            Self::RSRVD => Some("rsrvd"),
+            Self::ADAPT => Some("adapt"),
+            Self::ROSE => Some("rose"),
+            Self::X25 => Some("x25"),
+            Self::HWX25 => Some("hwx25"),
+            Self::CAN => Some("can"),
+            Self::PPP => Some("ppp"),
+            Self::CISCO_HDLC => Some("cisco-hdlc"),
+            Self::LAPB => Some("lapb"),
+            Self::DDCMP => Some("ddcmp"),
+            Self::RAWHDLC => Some("rawhdlc"),
+            Self::RAWIP => Some("rawip"),
+            Self::TUNNEL => Some("tunnel"),
+            Self::TUNNEL6 => Some("tunnel6"),
+            Self::FRAD => Some("frad"),
+            Self::SKIP => Some("skip"),
+            Self::LOOPBACK => Some("loopback"),
+            Self::LOCALTLK => Some("localtlk"),
+            Self::FDDI => Some("fddi"),
+            Self::BIF => Some("bif"),
+            Self::SIT => Some("sit"),
+            Self::IPDDP => Some("ipddp"),
+            Self::IPGRE => Some("ipgre"),
+            Self::PIMREG => Some("pimreg"),
+            Self::HIPPI => Some("hippi"),
+            Self::ASH => Some("ash"),
+            Self::ECONET => Some("econet"),
+            Self::IRDA => Some("irda"),
+            Self::FCPP => Some("fcpp"),
+            Self::FCAL => Some("fcal"),
+            Self::FCPL => Some("fcpl"),
+            Self::FCFABRIC => Some("fcfabric"),
+            Self::IEEE802_TR => Some("ieee802-tr"),
+            Self::IEEE80211 => Some("ieee80211"),
+            Self::IEEE80211_PRISM => Some("ieee80211-prism"),
+            Self::IEEE80211_RADIOTAP => Some("ieee80211-radiotap"),
+            Self::IEEE802154 => Some("ieee802154"),
+            Self::IEEE802154_MONITOR => Some("ieee802154-monitor"),
+            Self::PHONET => Some("phonet"),
+            Self::PHONET_PIPE => Some("phonet-pipe"),
+            Self::CAIF => Some("caif"),
+            Self::IP6GRE => Some("ip6gre"),
+            Self::NETLINK => Some("netlink"),
+            Self::IPV6LOWPAN => Some("ipv6lowpan"),
+            Self::VSOCKMON => Some("vsockmon"),
+            Self::VOID => Some("void"),
+            Self::NONE => Some("none"),
+            _ => None,
+        }
+    }
+
+    /// Returns the fixed length of the hardware addresses of the hardware
+    /// type in bytes (`None` if the length is unknown or variable).
+    ///
+    /// Can be used to validate the "hardware address length" field of an
+    /// ARP packet:
+    ///
+    /// ```
+    /// use etherparse::ArpHardwareId;
+    ///
+    /// assert_eq!(Some(6), ArpHardwareId::ETHER.hardware_address_len());
+    /// assert_eq!(Some(20), ArpHardwareId::INFINIBAND.hardware_address_len());
+    /// assert_eq!(None, ArpHardwareId(0x1234).hardware_address_len());
+    /// ```
+    pub const fn hardware_address_len(self) -> Option<u8> {
+        match self {
+            // Ethernet style 48 bit MAC addresses
+            Self::ETHER | Self::IEEE802 | Self::FDDI | Self::IEEE802_TR | Self::IEEE80211 => {
+                Some(6)
+            }
+            // AX.25 callsign & SSID
+            Self::AX25 => Some(7),
+            // ARCNET (RFC 1201)
+            Self::ARCNET => Some(1),
+            // IEEE 1394 (RFC 2734)
+            Self::IEEE1394 => Some(16),
+            Self::EUI64 => Some(8),
+            // InfiniBand (RFC 4391)
+            Self::INFINIBAND => Some(20),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an [`ArpHardwareId`] from a decimal number, a hexadecimal number
+/// starting with `0x` or the name of a known constant (see
+/// [`ArpHardwareId::as_str`], ignoring the case & treating `_` and `-` as equal).
+///
+/// ```
+/// use etherparse::ArpHardwareId;
+///
+/// assert_eq!(Ok(ArpHardwareId::ETHER), "1".parse());
+/// assert_eq!(Ok(ArpHardwareId::ETHER), "0x1".parse());
+/// assert_eq!(Ok(ArpHardwareId::ETHER), "ETHER".parse());
+/// assert!("unknown".parse::<ArpHardwareId>().is_err());
+/// ```
+impl core::str::FromStr for ArpHardwareId {
+    type Err = crate::err::ValueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = crate::parse_number::<u16>(s) {
+            return Ok(ArpHardwareId(value));
+        }
+        ArpHardwareId::NAMED
+            .iter()
+            .find(|v| v.as_str().is_some_and(|name| crate::eq_name(s, name)))
+            .copied()
+            .ok_or(crate::err::ValueParseError {
+                value_type: crate::err::ValueType::ArpHardwareId,
+            })
+    }
+}
+
 impl From<u16> for ArpHardwareId {
     #[inline]
     fn from(val: u16) -> Self {
@@ -529,4 +746,50 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn hardware_address_len() {
+        let tests = [
+            (ArpHardwareId::ETHER, Some(6)),
+            (ArpHardwareId::IEEE802, Some(6)),
+            (ArpHardwareId::FDDI, Some(6)),
+            (ArpHardwareId::IEEE802_TR, Some(6)),
+            (ArpHardwareId::IEEE80211, Some(6)),
+            (ArpHardwareId::AX25, Some(7)),
+            (ArpHardwareId::ARCNET, Some(1)),
+            (ArpHardwareId::IEEE1394, Some(16)),
+            (ArpHardwareId::EUI64, Some(8)),
+            (ArpHardwareId::INFINIBAND, Some(20)),
+            (ArpHardwareId::NETROM, None),
+            (ArpHardwareId::LOOPBACK, None),
+            (ArpHardwareId(0x1234), None),
+        ];
+        for (id, expected) in tests {
+            assert_eq!(expected, id.hardware_address_len(), "{:?}", id);
+        }
+    }
+
+    #[test]
+    fn as_str_from_str() {
+        use core::str::FromStr;
+        for value in ArpHardwareId::NAMED {
+            let name = value.as_str().unwrap();
+            assert_eq!(Ok(value), ArpHardwareId::from_str(name));
+            assert_eq!(
+                Ok(value),
+                ArpHardwareId::from_str(&name.to_ascii_uppercase().replace('-', "_"))
+            );
+        }
+        assert_eq!(None, ArpHardwareId(0x1234).as_str());
+        assert_eq!(Ok(ArpHardwareId(0x1234)), ArpHardwareId::from_str("4660"));
+        assert_eq!(Ok(ArpHardwareId(0x1234)), ArpHardwareId::from_str("0x1234"));
+        for s in ["", "0x", "65536", "-1", "unknown"] {
+            assert_eq!(
+                Err(crate::err::ValueParseError {
+                    value_type: crate::err::ValueType::ArpHardwareId
+                }),
+                ArpHardwareId::from_str(s)
+            );
+        }
+    }
 }
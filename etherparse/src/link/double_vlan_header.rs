@@ -13,6 +13,14 @@ impl DoubleVlanHeader {
     /// Serialized size of two VLAN headers in bytes/octets.
     pub const LEN: usize = 8;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`DoubleVlanHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = DoubleVlanHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`DoubleVlanHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = DoubleVlanHeader::LEN;
+
     #[deprecated(since = "0.14.0", note = "Use `DoubleVlanHeader::LEN` instead")]
     pub const SERIALIZED_SIZE: usize = DoubleVlanHeader::LEN;
 
@@ -36,6 +44,64 @@ impl DoubleVlanHeader {
         ))
     }
 
+    /// Creates an IEEE 802.1ad ("Q-in-Q") double VLAN header from a
+    /// service VLAN tag (S-tag, outer) & customer VLAN tag (C-tag, inner).
+    ///
+    /// `ether_type` identifies the payload following both tags. The
+    /// ether type preceding the header (e.g. in the [`Ethernet2Header`])
+    /// should be set to [`EtherType::PROVIDER_BRIDGING`].
+    ///
+    /// ```
+    /// use etherparse::{DoubleVlanHeader, EtherType, VlanTag};
+    ///
+    /// let s_tag = VlanTag {
+    ///     vlan_id: 100.try_into().unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// let c_tag = VlanTag {
+    ///     vlan_id: 200.try_into().unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// let header = DoubleVlanHeader::ieee8021ad(s_tag, c_tag, EtherType::IPV4);
+    ///
+    /// assert_eq!(EtherType::VLAN_TAGGED_FRAME, header.outer.ether_type);
+    /// assert_eq!(EtherType::IPV4, header.inner.ether_type);
+    /// assert_eq!(Ok(()), header.check_ieee8021ad());
+    /// ```
+    pub fn ieee8021ad(s_tag: VlanTag, c_tag: VlanTag, ether_type: EtherType) -> DoubleVlanHeader {
+        DoubleVlanHeader {
+            outer: SingleVlanHeader {
+                pcp: s_tag.pcp,
+                drop_eligible_indicator: s_tag.drop_eligible_indicator,
+                vlan_id: s_tag.vlan_id,
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
+            },
+            inner: SingleVlanHeader {
+                pcp: c_tag.pcp,
+                drop_eligible_indicator: c_tag.drop_eligible_indicator,
+                vlan_id: c_tag.vlan_id,
+                ether_type,
+            },
+        }
+    }
+
+    /// Checks that the header is a valid IEEE 802.1ad S-tag & C-tag pair
+    /// (the outer tag is followed by a [`EtherType::VLAN_TAGGED_FRAME`]
+    /// customer tag).
+    ///
+    /// Note that the ether type of the outer tag itself is stored in the
+    /// preceding header and can be checked via
+    /// [`EtherType::is_service_tag`].
+    pub fn check_ieee8021ad(&self) -> Result<(), err::double_vlan::HeaderError> {
+        if self.outer.ether_type.is_customer_tag() {
+            Ok(())
+        } else {
+            Err(err::double_vlan::HeaderError::NonCustomerTagEtherType {
+                unexpected_ether_type: self.outer.ether_type,
+            })
+        }
+    }
+
     /// Read a double tagging header from the given source
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -46,15 +112,12 @@ impl DoubleVlanHeader {
 
         let outer = SingleVlanHeader::read(reader).map_err(Io)?;
 
-        use crate::ether_type::{PROVIDER_BRIDGING, VLAN_DOUBLE_TAGGED_FRAME, VLAN_TAGGED_FRAME};
         //check that outer ethertype is matching
         match outer.ether_type {
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                Ok(DoubleVlanHeader {
-                    outer,
-                    inner: SingleVlanHeader::read(reader).map_err(Io)?,
-                })
-            }
+            value if value.is_vlan_tag() => Ok(DoubleVlanHeader {
+                outer,
+                inner: SingleVlanHeader::read(reader).map_err(Io)?,
+            }),
             value => Err(Content(NonVlanEtherType {
                 unexpected_ether_type: value,
             })),
@@ -267,6 +330,35 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn ieee8021ad_check_ieee8021ad(
+            s_tag in vlan_single_any(),
+            c_tag in vlan_single_any(),
+            ether_type in ether_type_any(),
+            non_c_tag_ether_type in ether_type_any().prop_filter(
+                "ether_type must not be a c-tag ether type",
+                |v| *v != EtherType::VLAN_TAGGED_FRAME
+            )
+        ) {
+            let actual = DoubleVlanHeader::ieee8021ad(s_tag.tag(), c_tag.tag(), ether_type);
+            assert_eq!(s_tag.tag(), actual.outer.tag());
+            assert_eq!(EtherType::VLAN_TAGGED_FRAME, actual.outer.ether_type);
+            assert_eq!(c_tag.tag(), actual.inner.tag());
+            assert_eq!(ether_type, actual.inner.ether_type);
+            assert_eq!(Ok(()), actual.check_ieee8021ad());
+
+            let mut bad = actual.clone();
+            bad.outer.ether_type = non_c_tag_ether_type;
+            assert_eq!(
+                Err(err::double_vlan::HeaderError::NonCustomerTagEtherType {
+                    unexpected_ether_type: non_c_tag_ether_type,
+                }),
+                bad.check_ieee8021ad()
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn header_len(input in vlan_double_any()) {
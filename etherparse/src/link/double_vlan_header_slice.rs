@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// A slice containing an double vlan header of a network package.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,14 +29,12 @@ impl<'a> DoubleVlanHeaderSlice<'a> {
             // SAFETY:
             // Safe as the slice length is checked is before to have
             // at least the length of DoubleVlanHeader::LEN (8)
-            slice: unsafe { from_raw_parts(slice.as_ptr(), DoubleVlanHeader::LEN) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, DoubleVlanHeader::LEN) },
         };
 
-        use ether_type::*;
-
         //check that outer ethertype is matching
         match result.outer().ether_type() {
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+            value if value.is_vlan_tag() => {
                 //all done
                 Ok(result)
             }
@@ -47,6 +44,31 @@ impl<'a> DoubleVlanHeaderSlice<'a> {
         }
     }
 
+    /// Creates a double header slice from a slice & additionally verifies
+    /// that the header is a valid IEEE 802.1ad S-tag & C-tag pair (strict
+    /// mode, see [`DoubleVlanHeader::check_ieee8021ad`]).
+    ///
+    /// Note that only the inner tag protocol identifier (the ether type
+    /// stored in the outer tag) is checked. The S-tag protocol identifier
+    /// (0x88A8 or 0x9100) is stored in the preceding header (e.g. the Ethernet II
+    /// header) and is not part of the slice, so it has to be checked by
+    /// the caller via [`EtherType::is_service_tag`].
+    pub fn from_slice_ieee8021ad(
+        slice: &'a [u8],
+    ) -> Result<DoubleVlanHeaderSlice<'a>, err::double_vlan::HeaderSliceError> {
+        use err::double_vlan::{HeaderError::*, HeaderSliceError::*};
+
+        let result = DoubleVlanHeaderSlice::from_slice(slice)?;
+        let ether_type = result.outer().ether_type();
+        if ether_type.is_customer_tag() {
+            Ok(result)
+        } else {
+            Err(Content(NonCustomerTagEtherType {
+                unexpected_ether_type: ether_type,
+            }))
+        }
+    }
+
     /// Returns the slice containing the double vlan header
     #[inline]
     pub fn slice(&self) -> &'a [u8] {
@@ -60,11 +82,8 @@ impl<'a> DoubleVlanHeaderSlice<'a> {
         // Safe as the constructor checks that the slice has the length
         // of DoubleVlanHeader::LEN (8) and the
         // SingleVlanHeader::LEN has a size of 4.
-        unsafe {
-            SingleVlanHeaderSlice::from_slice_unchecked(from_raw_parts(
-                self.slice.as_ptr(),
-                SingleVlanHeader::LEN,
-            ))
+        unchecked! {
+            SingleVlanHeaderSlice::from_slice_unchecked(get_unchecked_slice(self.slice, 0, SingleVlanHeader::LEN))
         }
     }
 
@@ -75,11 +94,8 @@ impl<'a> DoubleVlanHeaderSlice<'a> {
         // Safe as the constructor checks that the slice has the length
         // of DoubleVlanHeader::LEN (8) and the
         // SingleVlanHeader::LEN has a size of 4.
-        unsafe {
-            SingleVlanHeaderSlice::from_slice_unchecked(from_raw_parts(
-                self.slice.as_ptr().add(SingleVlanHeader::LEN),
-                SingleVlanHeader::LEN,
-            ))
+        unchecked! {
+            SingleVlanHeaderSlice::from_slice_unchecked(get_unchecked_slice(self.slice, SingleVlanHeader::LEN, SingleVlanHeader::LEN))
         }
     }
 
@@ -151,6 +167,53 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_slice_ieee8021ad(
+            input in vlan_double_any(),
+            ether_type_non_c_tag in ether_type_any().prop_filter(
+                "ether_type must not be a c-tag ether type",
+                |v| *v != EtherType::VLAN_TAGGED_FRAME
+            )
+        ) {
+            use err::double_vlan::{HeaderError::*, HeaderSliceError::*};
+
+            // ok
+            {
+                let mut header = input.clone();
+                header.outer.ether_type = EtherType::VLAN_TAGGED_FRAME;
+                let bytes = header.to_bytes();
+                let slice = DoubleVlanHeaderSlice::from_slice_ieee8021ad(&bytes).unwrap();
+                assert_eq!(header, slice.to_header());
+            }
+
+            // length error
+            {
+                let bytes = input.to_bytes();
+                assert_eq!(
+                    DoubleVlanHeaderSlice::from_slice(&bytes[..7]).unwrap_err(),
+                    DoubleVlanHeaderSlice::from_slice_ieee8021ad(&bytes[..7]).unwrap_err()
+                );
+            }
+
+            // outer tag not followed by a c-tag
+            {
+                let mut bad_outer = input.clone();
+                bad_outer.outer.ether_type = ether_type_non_c_tag;
+                let bytes = bad_outer.to_bytes();
+                let expected = if ether_type_non_c_tag.is_vlan_tag() {
+                    Content(NonCustomerTagEtherType{ unexpected_ether_type: ether_type_non_c_tag })
+                } else {
+                    Content(NonVlanEtherType{ unexpected_ether_type: ether_type_non_c_tag })
+                };
+                assert_eq!(
+                    expected,
+                    DoubleVlanHeaderSlice::from_slice_ieee8021ad(&bytes).unwrap_err()
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn getters(input in vlan_double_any()) {
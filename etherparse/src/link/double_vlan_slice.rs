@@ -29,15 +29,40 @@ impl<'a> DoubleVlanSlice<'a> {
         let result = DoubleVlanSlice { slice };
 
         // check that outer ethertype is matching
-        use ether_type::*;
         match result.outer().ether_type() {
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => Ok(result),
+            value if value.is_vlan_tag() => Ok(result),
             value => Err(Content(NonVlanEtherType {
                 unexpected_ether_type: value,
             })),
         }
     }
 
+    /// Try creating a [`DoubleVlanSlice`] from a slice containing the
+    /// VLAN header & payload and additionally verify that the header is a
+    /// valid IEEE 802.1ad S-tag & C-tag pair (strict mode, see
+    /// [`crate::DoubleVlanHeader::check_ieee8021ad`]).
+    ///
+    /// Note that only the inner tag protocol identifier (the ether type
+    /// stored in the outer tag) is checked. The S-tag protocol identifier
+    /// (0x88A8 or 0x9100) is stored in the preceding header (e.g. the Ethernet II
+    /// header) and is not part of the slice, so it has to be checked by
+    /// the caller via [`crate::EtherType::is_service_tag`].
+    pub fn from_slice_ieee8021ad(
+        slice: &'a [u8],
+    ) -> Result<DoubleVlanSlice<'a>, err::double_vlan::HeaderSliceError> {
+        use err::double_vlan::{HeaderError::*, HeaderSliceError::*};
+
+        let result = DoubleVlanSlice::from_slice(slice)?;
+        let ether_type = result.outer().ether_type();
+        if ether_type.is_customer_tag() {
+            Ok(result)
+        } else {
+            Err(Content(NonCustomerTagEtherType {
+                unexpected_ether_type: ether_type,
+            }))
+        }
+    }
+
     /// Returns the slice containing the VLAN header and payload.
     #[inline]
     pub fn slice(&self) -> &'a [u8] {
@@ -54,13 +79,10 @@ impl<'a> DoubleVlanSlice<'a> {
     #[inline]
     pub fn inner(&self) -> SingleVlanSlice {
         SingleVlanSlice {
-            slice: unsafe {
+            slice: unchecked! {
                 // SAFETY: Safe as "from_slice" verified the slice length
                 // to be DoubleVlanHeader::LEN (aka 2*SingleVlanHeader::LEN).
-                core::slice::from_raw_parts(
-                    self.slice.as_ptr().add(SingleVlanHeader::LEN),
-                    self.slice.len() - SingleVlanHeader::LEN,
-                )
+                get_unchecked_slice(self.slice, SingleVlanHeader::LEN, self.slice.len() - SingleVlanHeader::LEN)
             },
         }
     }
@@ -87,14 +109,11 @@ impl<'a> DoubleVlanSlice<'a> {
     /// Returns the slice containing the payload after both
     /// VLAN headers.
     pub fn payload_slice(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY:
             // Safe as the contructor checks that the slice has
             // at least the length of DoubleVlanHeader::LEN (8).
-            core::slice::from_raw_parts(
-                self.slice.as_ptr().add(DoubleVlanHeader::LEN),
-                self.slice.len() - DoubleVlanHeader::LEN,
-            )
+            get_unchecked_slice(self.slice, DoubleVlanHeader::LEN, self.slice.len() - DoubleVlanHeader::LEN)
         }
     }
 
@@ -241,4 +260,57 @@ mod test {
             }
         }
     }
+
+    proptest! {
+        #[test]
+        fn from_slice_ieee8021ad(
+            vlan in vlan_double_any(),
+            ether_type_non_c_tag in ether_type_any().prop_filter(
+                "ether_type must not be a c-tag ether type",
+                |v| *v != EtherType::VLAN_TAGGED_FRAME
+            )
+        ) {
+            use err::double_vlan::{HeaderError::*, HeaderSliceError::*};
+
+            let payload: [u8;10] = [1,2,3,4,5,6,7,8,9,10];
+            let mut data = Vec::with_capacity(
+                vlan.header_len() +
+                payload.len()
+            );
+            data.extend_from_slice(&vlan.to_bytes());
+            data.extend_from_slice(&payload);
+
+            // outer tag followed by a c-tag
+            {
+                let e_be = EtherType::VLAN_TAGGED_FRAME.0.to_be_bytes();
+                data[2] = e_be[0];
+                data[3] = e_be[1];
+                let slice = DoubleVlanSlice::from_slice_ieee8021ad(&data).unwrap();
+                assert_eq!(slice.inner().to_header(), vlan.inner);
+                assert_eq!(slice.payload_slice(), &payload);
+            }
+
+            // length error
+            assert_eq!(
+                DoubleVlanSlice::from_slice(&data[..7]).unwrap_err(),
+                DoubleVlanSlice::from_slice_ieee8021ad(&data[..7]).unwrap_err()
+            );
+
+            // outer tag not followed by a c-tag
+            {
+                let e_be = ether_type_non_c_tag.0.to_be_bytes();
+                data[2] = e_be[0];
+                data[3] = e_be[1];
+                let expected = if ether_type_non_c_tag.is_vlan_tag() {
+                    Content(NonCustomerTagEtherType{ unexpected_ether_type: ether_type_non_c_tag })
+                } else {
+                    Content(NonVlanEtherType{ unexpected_ether_type: ether_type_non_c_tag })
+                };
+                assert_eq!(
+                    expected,
+                    DoubleVlanSlice::from_slice_ieee8021ad(&data).unwrap_err()
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,107 @@
+use crate::EtherPayloadSlice;
+
+/// Parser for ether payloads with ether types that are not decoded
+/// by etherparse itself (e.g. proprietary or not yet supported protocols).
+///
+/// A parser can be passed to [`crate::SlicedPacket::from_ethernet_with_parser`]
+/// or [`crate::SlicedPacket::parse_ether_payload_with`] and is only called if
+/// the slicing stopped at an ether payload (no IP or transport layer was decoded).
+///
+/// The trait is implemented for all closures & functions with the signature
+/// `FnMut(&EtherPayloadSlice<'a>) -> Option<T>`:
+///
+/// ```
+/// use etherparse::{EtherPayloadSlice, EtherType, PacketBuilder, SlicedPacket};
+///
+/// # let mut packet = Vec::new();
+/// # etherparse::Ethernet2Header {
+/// #     source: [1, 2, 3, 4, 5, 6],
+/// #     destination: [7, 8, 9, 10, 11, 12],
+/// #     ether_type: EtherType(0x88b5),
+/// # }.write(&mut packet).unwrap();
+/// # packet.extend_from_slice(&[0x12, 0x34, 1, 2]);
+/// // parser for a protocol starting with a 2 byte big endian id
+/// let mut parser = |payload: &EtherPayloadSlice| {
+///     if payload.ether_type == EtherType(0x88b5) && payload.payload.len() >= 2 {
+///         Some(u16::from_be_bytes([payload.payload[0], payload.payload[1]]))
+///     } else {
+///         None
+///     }
+/// };
+///
+/// let (sliced, id) = SlicedPacket::from_ethernet_with_parser(&packet, &mut parser).unwrap();
+/// assert_eq!(Some(0x1234), id);
+/// ```
+pub trait EtherPayloadParser<'a> {
+    /// Result of the parser (e.g. a slice type of the parsed protocol).
+    type Output;
+
+    /// Parses the given ether payload. Returns `None` if the parser
+    /// does not support the ether type of the payload.
+    fn parse_ether_payload(&mut self, payload: &EtherPayloadSlice<'a>) -> Option<Self::Output>;
+}
+
+impl<'a, T, F> EtherPayloadParser<'a> for F
+where
+    F: FnMut(&EtherPayloadSlice<'a>) -> Option<T>,
+{
+    type Output = T;
+
+    #[inline]
+    fn parse_ether_payload(&mut self, payload: &EtherPayloadSlice<'a>) -> Option<T> {
+        self(payload)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::EtherType;
+
+    struct LenParser {
+        calls: usize,
+    }
+
+    impl<'a> EtherPayloadParser<'a> for LenParser {
+        type Output = usize;
+
+        fn parse_ether_payload(&mut self, payload: &EtherPayloadSlice<'a>) -> Option<usize> {
+            self.calls += 1;
+            Some(payload.payload.len())
+        }
+    }
+
+    #[test]
+    fn parse_ether_payload() {
+        let payload = EtherPayloadSlice {
+            ether_type: EtherType(0x1234),
+            payload: &[1, 2, 3],
+        };
+
+        // struct
+        {
+            let mut parser = LenParser { calls: 0 };
+            assert_eq!(Some(3), parser.parse_ether_payload(&payload));
+            assert_eq!(1, parser.calls);
+        }
+
+        // closure
+        {
+            let mut parser = |p: &EtherPayloadSlice| {
+                if p.ether_type == EtherType(0x1234) {
+                    Some(p.payload[0])
+                } else {
+                    None
+                }
+            };
+            assert_eq!(Some(1), parser.parse_ether_payload(&payload));
+            assert_eq!(
+                None,
+                parser.parse_ether_payload(&EtherPayloadSlice {
+                    ether_type: EtherType::ARP,
+                    payload: &[],
+                })
+            );
+        }
+    }
+}
@@ -10,11 +10,166 @@ pub struct EtherPayloadSlice<'a> {
     pub payload: &'a [u8],
 }
 
+impl<'a> EtherPayloadSlice<'a> {
+    /// Decodes the payload as an EtherCAT frame header if the ether type
+    /// is [`EtherType::ETHERCAT`] (returns `None` for other ether types).
+    pub fn ethercat(&self) -> Option<Result<EthercatHeaderSlice<'a>, err::LenError>> {
+        if self.ether_type == EtherType::ETHERCAT {
+            Some(EthercatHeaderSlice::from_slice(self.payload))
+        } else {
+            None
+        }
+    }
+
+    /// Decodes the payload as a PROFINET real-time header if the ether type
+    /// is [`EtherType::PROFINET`] (returns `None` for other ether types).
+    pub fn profinet(&self) -> Option<Result<ProfinetHeaderSlice<'a>, err::LenError>> {
+        if self.ether_type == EtherType::PROFINET {
+            Some(ProfinetHeaderSlice::from_slice(self.payload))
+        } else {
+            None
+        }
+    }
+
+    /// Decodes the payload as a PTP (IEEE 1588) header if the ether type
+    /// is [`EtherType::PTP`] (returns `None` for other ether types).
+    pub fn ptp(&self) -> Option<Result<PtpHeaderSlice<'a>, err::LenError>> {
+        if self.ether_type == EtherType::PTP {
+            Some(PtpHeaderSlice::from_slice(self.payload))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the TLVs of an LLDP data unit if the ether
+    /// type is [`EtherType::LLDP`] (returns `None` for other ether types).
+    pub fn lldp(&self) -> Option<LldpTlvIter<'a>> {
+        if self.ether_type == EtherType::LLDP {
+            Some(LldpTlvIter::from_slice(self.payload))
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use alloc::format;
 
+    #[test]
+    fn ethercat() {
+        let bytes = [0x02, 0x10, 1, 2];
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::ETHERCAT,
+                payload: &bytes,
+            }
+            .ethercat(),
+            Some(EthercatHeaderSlice::from_slice(&bytes))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::ETHERCAT,
+                payload: &bytes[..1],
+            }
+            .ethercat(),
+            Some(EthercatHeaderSlice::from_slice(&bytes[..1]))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::PROFINET,
+                payload: &bytes,
+            }
+            .ethercat(),
+            None
+        );
+    }
+
+    #[test]
+    fn profinet() {
+        let bytes = [0xfe, 0xfe, 1, 2];
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::PROFINET,
+                payload: &bytes,
+            }
+            .profinet(),
+            Some(ProfinetHeaderSlice::from_slice(&bytes))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::PROFINET,
+                payload: &bytes[..1],
+            }
+            .profinet(),
+            Some(ProfinetHeaderSlice::from_slice(&bytes[..1]))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::ETHERCAT,
+                payload: &bytes,
+            }
+            .profinet(),
+            None
+        );
+    }
+
+    #[test]
+    fn ptp() {
+        let bytes = PtpHeader {
+            message_type: PtpMessageType::ANNOUNCE,
+            version: 2,
+            ..Default::default()
+        }
+        .to_bytes();
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::PTP,
+                payload: &bytes,
+            }
+            .ptp(),
+            Some(PtpHeaderSlice::from_slice(&bytes))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::PTP,
+                payload: &bytes[..1],
+            }
+            .ptp(),
+            Some(PtpHeaderSlice::from_slice(&bytes[..1]))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::ETHERCAT,
+                payload: &bytes,
+            }
+            .ptp(),
+            None
+        );
+    }
+
+    #[test]
+    fn lldp() {
+        let bytes = [0x06, 2, 0, 120, 0, 0];
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::LLDP,
+                payload: &bytes,
+            }
+            .lldp(),
+            Some(LldpTlvIter::from_slice(&bytes))
+        );
+        assert_eq!(
+            EtherPayloadSlice {
+                ether_type: EtherType::PTP,
+                payload: &bytes,
+            }
+            .lldp(),
+            None
+        );
+    }
+
     #[test]
     fn debug() {
         let s = EtherPayloadSlice {
@@ -38,6 +38,162 @@ impl EtherType {
     pub const VLAN_TAGGED_FRAME: EtherType = Self(0x8100);
     pub const PROVIDER_BRIDGING: EtherType = Self(0x88A8);
     pub const VLAN_DOUBLE_TAGGED_FRAME: EtherType = Self(0x9100);
+    pub const ERSPAN_TYPE_2: EtherType = Self(0x88BE);
+    pub const ERSPAN_TYPE_3: EtherType = Self(0x22EB);
+    pub const PROVIDER_BACKBONE_BRIDGING: EtherType = Self(0x88E7);
+    pub const MACSEC: EtherType = Self(0x88E5);
+    pub const PROFINET: EtherType = Self(0x8892);
+    pub const ETHERCAT: EtherType = Self(0x88A4);
+    pub const PTP: EtherType = Self(0x88F7);
+    pub const LLDP: EtherType = Self(0x88CC);
+}
+
+impl EtherType {
+    /// Returns true if the ether type identifies a VLAN tag
+    /// ([`EtherType::VLAN_TAGGED_FRAME`], [`EtherType::PROVIDER_BRIDGING`]
+    /// or [`EtherType::VLAN_DOUBLE_TAGGED_FRAME`]).
+    ///
+    /// These are the ether types etherparse decodes as VLAN headers.
+    #[inline]
+    pub const fn is_vlan_tag(self) -> bool {
+        matches!(
+            self,
+            EtherType::VLAN_TAGGED_FRAME
+                | EtherType::PROVIDER_BRIDGING
+                | EtherType::VLAN_DOUBLE_TAGGED_FRAME
+        )
+    }
+
+    /// Returns true if the ether type identifies an IEEE 802.1ad
+    /// service VLAN tag ("S-tag", [`EtherType::PROVIDER_BRIDGING`] or the
+    /// pre-standard [`EtherType::VLAN_DOUBLE_TAGGED_FRAME`]).
+    ///
+    /// S-tags are the outer tags added by service providers (Q-in-Q).
+    #[inline]
+    pub const fn is_service_tag(self) -> bool {
+        matches!(
+            self,
+            EtherType::PROVIDER_BRIDGING | EtherType::VLAN_DOUBLE_TAGGED_FRAME
+        )
+    }
+
+    /// Returns true if the ether type identifies an IEEE 802.1Q customer
+    /// VLAN tag ("C-tag", [`EtherType::VLAN_TAGGED_FRAME`]).
+    #[inline]
+    pub const fn is_customer_tag(self) -> bool {
+        matches!(self, EtherType::VLAN_TAGGED_FRAME)
+    }
+
+    /// Returns true if the ether type identifies an IPv4 or IPv6 payload.
+    #[inline]
+    pub const fn is_ip(self) -> bool {
+        matches!(self, EtherType::IPV4 | EtherType::IPV6)
+    }
+
+    /// Returns the length of the protocol addresses of the protocol in
+    /// bytes (`None` if the length is unknown).
+    ///
+    /// Can be used together with [`crate::ArpHardwareId::hardware_address_len`]
+    /// to validate the "protocol address length" field of an ARP packet:
+    ///
+    /// ```
+    /// use etherparse::EtherType;
+    ///
+    /// assert_eq!(Some(4), EtherType::IPV4.protocol_address_len());
+    /// assert_eq!(Some(16), EtherType::IPV6.protocol_address_len());
+    /// assert_eq!(None, EtherType::ARP.protocol_address_len());
+    /// ```
+    #[inline]
+    pub const fn protocol_address_len(self) -> Option<u8> {
+        match self {
+            EtherType::IPV4 => Some(4),
+            EtherType::IPV6 => Some(16),
+            _ => None,
+        }
+    }
+}
+
+impl EtherType {
+    /// Known values that can be parsed by name via [`core::str::FromStr`].
+    const NAMED: [EtherType; 15] = [
+        Self::IPV4,
+        Self::IPV6,
+        Self::ARP,
+        Self::WAKE_ON_LAN,
+        Self::VLAN_TAGGED_FRAME,
+        Self::PROVIDER_BRIDGING,
+        Self::VLAN_DOUBLE_TAGGED_FRAME,
+        Self::ERSPAN_TYPE_2,
+        Self::ERSPAN_TYPE_3,
+        Self::PROVIDER_BACKBONE_BRIDGING,
+        Self::MACSEC,
+        Self::PROFINET,
+        Self::ETHERCAT,
+        Self::PTP,
+        Self::LLDP,
+    ];
+
+    /// Returns the name of the value if it is a known constant
+    /// (the lowercase name of the constant with `_` replaced by `-`).
+    ///
+    /// The returned name is accepted by the [`core::str::FromStr`]
+    /// implementation:
+    ///
+    /// ```
+    /// use etherparse::EtherType;
+    ///
+    /// assert_eq!(Some("ipv6"), EtherType::IPV6.as_str());
+    /// assert_eq!(Ok(EtherType::IPV6), "ipv6".parse());
+    /// ```
+    pub fn as_str(self) -> Option<&'static str> {
+        match self {
+            Self::IPV4 => Some("ipv4"),
+            Self::IPV6 => Some("ipv6"),
+            Self::ARP => Some("arp"),
+            Self::WAKE_ON_LAN => Some("wake-on-lan"),
+            Self::VLAN_TAGGED_FRAME => Some("vlan-tagged-frame"),
+            Self::PROVIDER_BRIDGING => Some("provider-bridging"),
+            Self::VLAN_DOUBLE_TAGGED_FRAME => Some("vlan-double-tagged-frame"),
+            Self::ERSPAN_TYPE_2 => Some("erspan-type-2"),
+            Self::ERSPAN_TYPE_3 => Some("erspan-type-3"),
+            Self::PROVIDER_BACKBONE_BRIDGING => Some("provider-backbone-bridging"),
+            Self::MACSEC => Some("macsec"),
+            Self::PROFINET => Some("profinet"),
+            Self::ETHERCAT => Some("ethercat"),
+            Self::PTP => Some("ptp"),
+            Self::LLDP => Some("lldp"),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an [`EtherType`] from a decimal number, a hexadecimal number
+/// starting with `0x` or the name of a known constant (see
+/// [`EtherType::as_str`], ignoring the case & treating `_` and `-` as equal).
+///
+/// ```
+/// use etherparse::EtherType;
+///
+/// assert_eq!(Ok(EtherType::IPV6), "34525".parse());
+/// assert_eq!(Ok(EtherType::IPV6), "0x86dd".parse());
+/// assert_eq!(Ok(EtherType::IPV6), "IPV6".parse());
+/// assert!("unknown".parse::<EtherType>().is_err());
+/// ```
+impl core::str::FromStr for EtherType {
+    type Err = crate::err::ValueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = crate::parse_number::<u16>(s) {
+            return Ok(EtherType(value));
+        }
+        EtherType::NAMED
+            .iter()
+            .find(|v| v.as_str().is_some_and(|name| crate::eq_name(s, name)))
+            .copied()
+            .ok_or(crate::err::ValueParseError {
+                value_type: crate::err::ValueType::EtherType,
+            })
+    }
 }
 
 impl From<u16> for EtherType {
@@ -74,6 +230,26 @@ impl core::fmt::Debug for EtherType {
             Self::VLAN_DOUBLE_TAGGED_FRAME => {
                 write!(f, "{:#06X} (VLAN Double Tagged Frame)", self.0)
             }
+            Self::ERSPAN_TYPE_2 => write!(f, "{:#06X} (ERSPAN Type II)", self.0),
+            Self::ERSPAN_TYPE_3 => write!(f, "{:#06X} (ERSPAN Type III)", self.0),
+            Self::PROVIDER_BACKBONE_BRIDGING => write!(
+                f,
+                "{:#06X} (IEEE Std 802.1ah - Provider Backbone Bridging I-TAG)",
+                self.0
+            ),
+            Self::MACSEC => write!(
+                f,
+                "{:#06X} (IEEE Std 802.1AE - Media Access Control Security (MACsec))",
+                self.0
+            ),
+            Self::PROFINET => write!(f, "{:#06X} (PROFINET Real-Time (PROFINET RT))", self.0),
+            Self::ETHERCAT => write!(
+                f,
+                "{:#06X} (Ethernet for Control Automation Technology (EtherCAT))",
+                self.0
+            ),
+            Self::PTP => write!(f, "{:#06X} (Precision Time Protocol (PTP))", self.0),
+            Self::LLDP => write!(f, "{:#06X} (Link Layer Discovery Protocol (LLDP))", self.0),
             _ => write!(f, "{:#06X}", self.0),
         }
     }
@@ -106,6 +282,14 @@ pub mod ether_type {
     pub const VLAN_TAGGED_FRAME: EtherType = EtherType::VLAN_TAGGED_FRAME;
     pub const PROVIDER_BRIDGING: EtherType = EtherType::PROVIDER_BRIDGING;
     pub const VLAN_DOUBLE_TAGGED_FRAME: EtherType = EtherType::VLAN_DOUBLE_TAGGED_FRAME;
+    pub const ERSPAN_TYPE_2: EtherType = EtherType::ERSPAN_TYPE_2;
+    pub const ERSPAN_TYPE_3: EtherType = EtherType::ERSPAN_TYPE_3;
+    pub const PROVIDER_BACKBONE_BRIDGING: EtherType = EtherType::PROVIDER_BACKBONE_BRIDGING;
+    pub const MACSEC: EtherType = EtherType::MACSEC;
+    pub const PROFINET: EtherType = EtherType::PROFINET;
+    pub const ETHERCAT: EtherType = EtherType::ETHERCAT;
+    pub const PTP: EtherType = EtherType::PTP;
+    pub const LLDP: EtherType = EtherType::LLDP;
 }
 
 #[cfg(test)]
@@ -122,6 +306,14 @@ mod test {
         assert_eq!(0x8100, u16::from(EtherType::VLAN_TAGGED_FRAME));
         assert_eq!(0x88A8, u16::from(EtherType::PROVIDER_BRIDGING));
         assert_eq!(0x9100, u16::from(EtherType::VLAN_DOUBLE_TAGGED_FRAME));
+        assert_eq!(0x88BE, u16::from(EtherType::ERSPAN_TYPE_2));
+        assert_eq!(0x22EB, u16::from(EtherType::ERSPAN_TYPE_3));
+        assert_eq!(0x88E7, u16::from(EtherType::PROVIDER_BACKBONE_BRIDGING));
+        assert_eq!(0x88E5, u16::from(EtherType::MACSEC));
+        assert_eq!(0x8892, u16::from(EtherType::PROFINET));
+        assert_eq!(0x88A4, u16::from(EtherType::ETHERCAT));
+        assert_eq!(0x88F7, u16::from(EtherType::PTP));
+        assert_eq!(0x88CC, u16::from(EtherType::LLDP));
     }
 
     #[test]
@@ -133,6 +325,17 @@ mod test {
         assert_eq!(EtherType::from(0x8100), EtherType::VLAN_TAGGED_FRAME);
         assert_eq!(EtherType::from(0x88A8), EtherType::PROVIDER_BRIDGING);
         assert_eq!(EtherType::from(0x9100), EtherType::VLAN_DOUBLE_TAGGED_FRAME);
+        assert_eq!(EtherType::from(0x88BE), EtherType::ERSPAN_TYPE_2);
+        assert_eq!(EtherType::from(0x22EB), EtherType::ERSPAN_TYPE_3);
+        assert_eq!(
+            EtherType::from(0x88E7),
+            EtherType::PROVIDER_BACKBONE_BRIDGING
+        );
+        assert_eq!(EtherType::from(0x88E5), EtherType::MACSEC);
+        assert_eq!(EtherType::from(0x8892), EtherType::PROFINET);
+        assert_eq!(EtherType::from(0x88A4), EtherType::ETHERCAT);
+        assert_eq!(EtherType::from(0x88F7), EtherType::PTP);
+        assert_eq!(EtherType::from(0x88CC), EtherType::LLDP);
         assert_eq!(EtherType::from(0x1234), EtherType(0x1234));
     }
 
@@ -150,6 +353,17 @@ mod test {
                 EtherType::VLAN_DOUBLE_TAGGED_FRAME,
                 VLAN_DOUBLE_TAGGED_FRAME,
             ),
+            (EtherType::ERSPAN_TYPE_2, ERSPAN_TYPE_2),
+            (EtherType::ERSPAN_TYPE_3, ERSPAN_TYPE_3),
+            (
+                EtherType::PROVIDER_BACKBONE_BRIDGING,
+                PROVIDER_BACKBONE_BRIDGING,
+            ),
+            (EtherType::MACSEC, MACSEC),
+            (EtherType::PROFINET, PROFINET),
+            (EtherType::ETHERCAT, ETHERCAT),
+            (EtherType::PTP, PTP),
+            (EtherType::LLDP, LLDP),
         ];
 
         for (ether_type, constant) in pairs {
@@ -157,6 +371,59 @@ mod test {
         }
     }
 
+    #[test]
+    fn is_vlan_tag() {
+        for value in 0..=u16::MAX {
+            let ether_type = EtherType(value);
+            assert_eq!(
+                ether_type.is_vlan_tag(),
+                ether_type == EtherType::VLAN_TAGGED_FRAME
+                    || ether_type == EtherType::PROVIDER_BRIDGING
+                    || ether_type == EtherType::VLAN_DOUBLE_TAGGED_FRAME
+            );
+        }
+    }
+
+    #[test]
+    fn is_service_tag_is_customer_tag() {
+        for value in 0..=u16::MAX {
+            let ether_type = EtherType(value);
+            assert_eq!(
+                ether_type.is_service_tag(),
+                ether_type == EtherType::PROVIDER_BRIDGING
+                    || ether_type == EtherType::VLAN_DOUBLE_TAGGED_FRAME
+            );
+            assert_eq!(
+                ether_type.is_customer_tag(),
+                ether_type == EtherType::VLAN_TAGGED_FRAME
+            );
+        }
+    }
+
+    #[test]
+    fn is_ip() {
+        for value in 0..=u16::MAX {
+            let ether_type = EtherType(value);
+            assert_eq!(
+                ether_type.is_ip(),
+                ether_type == EtherType::IPV4 || ether_type == EtherType::IPV6
+            );
+        }
+    }
+
+    #[test]
+    fn protocol_address_len() {
+        for value in 0..=u16::MAX {
+            let ether_type = EtherType(value);
+            let expected = match ether_type {
+                EtherType::IPV4 => Some(4),
+                EtherType::IPV6 => Some(16),
+                _ => None,
+            };
+            assert_eq!(expected, ether_type.protocol_address_len());
+        }
+    }
+
     #[test]
     fn dbg() {
         let pairs = &[
@@ -182,6 +449,29 @@ mod test {
                 EtherType::VLAN_DOUBLE_TAGGED_FRAME,
                 "0x9100 (VLAN Double Tagged Frame)",
             ),
+            (EtherType::ERSPAN_TYPE_2, "0x88BE (ERSPAN Type II)"),
+            (EtherType::ERSPAN_TYPE_3, "0x22EB (ERSPAN Type III)"),
+            (
+                EtherType::PROVIDER_BACKBONE_BRIDGING,
+                "0x88E7 (IEEE Std 802.1ah - Provider Backbone Bridging I-TAG)",
+            ),
+            (
+                EtherType::MACSEC,
+                "0x88E5 (IEEE Std 802.1AE - Media Access Control Security (MACsec))",
+            ),
+            (
+                EtherType::PROFINET,
+                "0x8892 (PROFINET Real-Time (PROFINET RT))",
+            ),
+            (
+                EtherType::ETHERCAT,
+                "0x88A4 (Ethernet for Control Automation Technology (EtherCAT))",
+            ),
+            (EtherType::PTP, "0x88F7 (Precision Time Protocol (PTP))"),
+            (
+                EtherType::LLDP,
+                "0x88CC (Link Layer Discovery Protocol (LLDP))",
+            ),
             (EtherType(1), "0x0001"),
         ];
 
@@ -206,6 +496,9 @@ mod test {
             EtherType::VLAN_TAGGED_FRAME,
             EtherType::PROVIDER_BRIDGING,
             EtherType::VLAN_DOUBLE_TAGGED_FRAME,
+            EtherType::ERSPAN_TYPE_2,
+            EtherType::ERSPAN_TYPE_3,
+            EtherType::PROVIDER_BACKBONE_BRIDGING,
         ];
 
         // clone
@@ -248,4 +541,28 @@ mod test {
         );
         assert!(EtherType::IPV4.ge(&EtherType::IPV4.clone()));
     }
+
+    #[test]
+    fn as_str_from_str() {
+        use core::str::FromStr;
+        for value in EtherType::NAMED {
+            let name = value.as_str().unwrap();
+            assert_eq!(Ok(value), EtherType::from_str(name));
+            assert_eq!(
+                Ok(value),
+                EtherType::from_str(&name.to_ascii_uppercase().replace('-', "_"))
+            );
+        }
+        assert_eq!(None, EtherType(0x1234).as_str());
+        assert_eq!(Ok(EtherType(0x1234)), EtherType::from_str("4660"));
+        assert_eq!(Ok(EtherType(0x1234)), EtherType::from_str("0x1234"));
+        for s in ["", "0x", "65536", "-1", "unknown"] {
+            assert_eq!(
+                Err(crate::err::ValueParseError {
+                    value_type: crate::err::ValueType::EtherType
+                }),
+                EtherType::from_str(s)
+            );
+        }
+    }
 }
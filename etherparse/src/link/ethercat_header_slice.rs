@@ -0,0 +1,163 @@
+use crate::*;
+
+/// Slice containing the EtherCAT frame header (the 2 byte little endian
+/// length/type word directly following the ether type
+/// [`EtherType::ETHERCAT`]).
+///
+/// Only the frame header gets decoded. The EtherCAT datagrams following it
+/// are accessible as raw bytes via [`EthercatHeaderSlice::payload`]:
+///
+/// ```
+/// # use etherparse::*;
+/// # let packet = {
+/// #     let mut packet = Ethernet2Header {
+/// #         source: [1, 2, 3, 4, 5, 6],
+/// #         destination: [7, 8, 9, 10, 11, 12],
+/// #         ether_type: EtherType::ETHERCAT,
+/// #     }.to_bytes().to_vec();
+/// #     packet.extend_from_slice(&[0x02, 0x10, 0xaa, 0xbb]);
+/// #     packet
+/// # };
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// let ether_payload = sliced.ether_payload().unwrap();
+/// if let Some(Ok(ethercat)) = ether_payload.ethercat() {
+///     assert_eq!(2, ethercat.length());
+///     assert_eq!(EthercatHeaderSlice::TYPE_COMMANDS, ethercat.frame_type());
+///     assert_eq!(&[0xaa, 0xbb], ethercat.payload());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EthercatHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> EthercatHeaderSlice<'a> {
+    /// Length of the EtherCAT frame header in bytes/octets.
+    pub const LEN: usize = 2;
+
+    /// Frame type value for EtherCAT commands (datagrams).
+    pub const TYPE_COMMANDS: u8 = 1;
+
+    /// Creates an EtherCAT header slice from a slice (the slice can contain
+    /// the EtherCAT datagrams after the header).
+    pub fn from_slice(slice: &'a [u8]) -> Result<EthercatHeaderSlice<'a>, err::LenError> {
+        if slice.len() < EthercatHeaderSlice::LEN {
+            return Err(err::LenError {
+                required_len: EthercatHeaderSlice::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::EthercatHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(EthercatHeaderSlice { slice })
+    }
+
+    /// Returns the slice containing the EtherCAT header & the datagrams.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing only the 2 byte EtherCAT header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length is checked to be at least
+        //         EthercatHeaderSlice::LEN (2) in the constructor.
+        unchecked! { get_unchecked_slice(self.slice, 0, EthercatHeaderSlice::LEN) }
+    }
+
+    /// Raw 16 bit header word (decoded as little endian).
+    #[inline]
+    pub fn raw_word(&self) -> u16 {
+        u16::from_le_bytes([self.slice[0], self.slice[1]])
+    }
+
+    /// 11 bit length of the EtherCAT datagrams following the header.
+    #[inline]
+    pub fn length(&self) -> u16 {
+        self.raw_word() & 0b0000_0111_1111_1111
+    }
+
+    /// Reserved bit in the header word.
+    #[inline]
+    pub fn reserved(&self) -> bool {
+        0 != self.raw_word() & 0b0000_1000_0000_0000
+    }
+
+    /// 4 bit frame type (1 for EtherCAT commands, see
+    /// [`EthercatHeaderSlice::TYPE_COMMANDS`]).
+    #[inline]
+    pub fn frame_type(&self) -> u8 {
+        (self.raw_word() >> 12) as u8
+    }
+
+    /// Raw bytes after the EtherCAT header (e.g. the EtherCAT datagrams).
+    ///
+    /// Note that the returned slice is not cut to the length given in
+    /// the header (ethernet padding might be included).
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length is checked to be at least
+        //         EthercatHeaderSlice::LEN (2) in the constructor.
+        unchecked! {
+            get_unchecked_slice(self.slice, EthercatHeaderSlice::LEN, self.slice.len() - EthercatHeaderSlice::LEN)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            word in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut bytes = Vec::with_capacity(2 + payload.len());
+            bytes.extend_from_slice(&word.to_le_bytes());
+            bytes.extend_from_slice(&payload);
+
+            // ok case
+            {
+                let s = EthercatHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(s.slice(), &bytes[..]);
+                assert_eq!(s.header_slice(), &bytes[..2]);
+                assert_eq!(s.raw_word(), word);
+                assert_eq!(s.length(), word & 0x7ff);
+                assert_eq!(s.reserved(), 0 != word & 0x800);
+                assert_eq!(s.frame_type(), (word >> 12) as u8);
+                assert_eq!(s.payload(), &payload[..]);
+            }
+
+            // length error
+            for len in 0..EthercatHeaderSlice::LEN {
+                assert_eq!(
+                    EthercatHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: EthercatHeaderSlice::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::EthercatHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let bytes = [0x02, 0x10];
+        let s = EthercatHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(s, s.clone());
+        assert_eq!(
+            format!("{:?}", s),
+            format!("EthercatHeaderSlice {{ slice: {:?} }}", &bytes[..])
+        );
+    }
+}
@@ -15,6 +15,14 @@ impl Ethernet2Header {
     /// Serialized size of an Ethernet2 header in bytes/octets.
     pub const LEN: usize = 14;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`Ethernet2Header::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = Ethernet2Header::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`Ethernet2Header::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = Ethernet2Header::LEN;
+
     /// Deprecated use [`Ethernet2Header::LEN`] instead.
     #[deprecated(since = "0.14.0", note = "Use `Ethernet2Header::LEN` instead")]
     pub const SERIALIZED_SIZE: usize = Ethernet2Header::LEN;
@@ -59,10 +67,22 @@ impl Ethernet2Header {
 
         Ok(
             // SAFETY: Safe as the buffer contains exactly the needed Ethernet2Header::LEN bytes.
-            unsafe { Ethernet2HeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
+            unchecked! { Ethernet2HeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
         )
     }
 
+    /// Reads an Ethernet-II header from the current position of the async
+    /// reader (requires crate feature `tokio`).
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<Ethernet2Header, std::io::Error> {
+        let mut buffer = [0; Ethernet2Header::LEN];
+        async_read_exact(reader, &mut buffer).await?;
+        Ok(Ethernet2Header::from_bytes(buffer))
+    }
+
     /// Serialize the header to a given slice. Returns the unused part of the slice.
     pub fn write_to_slice<'a>(
         &self,
@@ -96,6 +116,13 @@ impl Ethernet2Header {
         14
     }
 
+    /// Returns the classification of the destination MAC address into
+    /// unicast, multicast or broadcast (see [`CastType::from_mac_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_mac_addr(&self.destination)
+    }
+
     /// Returns the serialized form of the header as a statically
     /// sized byte array.
     #[inline]
@@ -118,6 +145,71 @@ impl Ethernet2Header {
             ether_type_be[1],
         ]
     }
+
+    /// Converts the header to a Linux Cooked Capture v1 (SLL) header
+    /// (e.g. to feed ethernet captures into pipelines expecting SLL).
+    ///
+    /// The SLL header uses [`ArpHardwareId::ETHER`] as hardware type, the
+    /// source MAC address as sender address & the ether type as protocol
+    /// type. Values below `0x0600` are not ether types but the length of an
+    /// IEEE 802.3 frame & are mapped to [`LinuxNonstandardEtherType::N802_3`]
+    /// (`ETH_P_802_3`, same as Linux does). The destination MAC address is
+    /// not part of an SLL header and lost.
+    ///
+    /// ```
+    /// use etherparse::{ArpHardwareId, EtherType, Ethernet2Header, LinuxSllPacketType, LinuxSllProtocolType};
+    ///
+    /// let eth = Ethernet2Header {
+    ///     source: [1, 2, 3, 4, 5, 6],
+    ///     destination: [7, 8, 9, 10, 11, 12],
+    ///     ether_type: EtherType::IPV4,
+    /// };
+    /// let sll = eth.to_linux_sll(LinuxSllPacketType::OUTGOING);
+    ///
+    /// assert_eq!(LinuxSllPacketType::OUTGOING, sll.packet_type);
+    /// assert_eq!(ArpHardwareId::ETHER, sll.arp_hrd_type);
+    /// assert_eq!(6, sll.sender_address_valid_length);
+    /// assert_eq!([1, 2, 3, 4, 5, 6, 0, 0], sll.sender_address);
+    /// assert_eq!(LinuxSllProtocolType::EtherType(EtherType::IPV4), sll.protocol_type);
+    /// ```
+    pub fn to_linux_sll(&self, packet_type: LinuxSllPacketType) -> LinuxSllHeader {
+        let protocol_type = if self.ether_type.0 < 0x0600 {
+            // length field of an IEEE 802.3 frame
+            LinuxSllProtocolType::LinuxNonstandardEtherType(LinuxNonstandardEtherType::N802_3)
+        } else {
+            LinuxSllProtocolType::EtherType(self.ether_type)
+        };
+        LinuxSllHeader {
+            packet_type,
+            arp_hrd_type: ArpHardwareId::ETHER,
+            sender_address_valid_length: 6,
+            sender_address: [
+                self.source[0],
+                self.source[1],
+                self.source[2],
+                self.source[3],
+                self.source[4],
+                self.source[5],
+                0,
+                0,
+            ],
+            protocol_type,
+        }
+    }
+}
+
+/// Writes the header in the format `source -> destination (ether type)`,
+/// e.g. `00:1b:21:0f:91:9b -> 04:d4:c4:ef:ea:53 (0x0800)`.
+impl core::fmt::Display for Ethernet2Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = self.source;
+        let d = self.destination;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x} -> {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x} ({:#06x})",
+            s[0], s[1], s[2], s[3], s[4], s[5], d[0], d[1], d[2], d[3], d[4], d[5], self.ether_type.0
+        )
+    }
 }
 
 #[cfg(test)]
@@ -136,6 +228,59 @@ mod test {
         assert_eq!(EtherType(0), e.ether_type);
     }
 
+    proptest! {
+        #[test]
+        fn to_linux_sll(
+            input in ethernet_2_any(),
+            packet_type in linux_sll_packet_type_any()
+        ) {
+            let actual = input.to_linux_sll(packet_type);
+            assert_eq!(packet_type, actual.packet_type);
+            assert_eq!(ArpHardwareId::ETHER, actual.arp_hrd_type);
+            assert_eq!(6, actual.sender_address_valid_length);
+            assert_eq!(&input.source, &actual.sender_address[..6]);
+            assert_eq!([0, 0], actual.sender_address[6..]);
+            if input.ether_type.0 < 0x0600 {
+                assert_eq!(
+                    LinuxSllProtocolType::LinuxNonstandardEtherType(LinuxNonstandardEtherType::N802_3),
+                    actual.protocol_type
+                );
+                assert_eq!(None, actual.to_ethernet2(input.destination));
+            } else {
+                assert_eq!(
+                    LinuxSllProtocolType::EtherType(input.ether_type),
+                    actual.protocol_type
+                );
+                // round trip
+                assert_eq!(Some(input.clone()), actual.to_ethernet2(input.destination));
+            }
+        }
+    }
+
+    #[test]
+    fn to_linux_sll_802_3_len() {
+        for ether_type in [0x0000, 0x0001, 0x0004, 0x05dc, 0x05ff] {
+            let eth = Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [7, 8, 9, 10, 11, 12],
+                ether_type: EtherType(ether_type),
+            };
+            assert_eq!(
+                LinuxSllProtocolType::LinuxNonstandardEtherType(LinuxNonstandardEtherType::N802_3),
+                eth.to_linux_sll(LinuxSllPacketType::HOST).protocol_type
+            );
+        }
+        let eth = Ethernet2Header {
+            source: [1, 2, 3, 4, 5, 6],
+            destination: [7, 8, 9, 10, 11, 12],
+            ether_type: EtherType(0x0600),
+        };
+        assert_eq!(
+            LinuxSllProtocolType::EtherType(EtherType(0x0600)),
+            eth.to_linux_sll(LinuxSllPacketType::HOST).protocol_type
+        );
+    }
+
     proptest! {
         #[test]
         fn from_slice(
@@ -186,6 +331,40 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            input in ethernet_2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            let mut buffer = Vec::with_capacity(Ethernet2Header::LEN + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut reader = &buffer[..];
+                let result = rt.block_on(Ethernet2Header::async_read(&mut reader)).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(dummy_data.len(), reader.len());
+            }
+
+            // unexpected eof
+            for len in 0..Ethernet2Header::LEN {
+                let mut reader = &buffer[..len];
+                assert_eq!(
+                    rt.block_on(Ethernet2Header::async_read(&mut reader))
+                        .unwrap_err()
+                        .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -316,4 +495,17 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn display() {
+        let header = Ethernet2Header {
+            source: [0x00, 0x1b, 0x21, 0x0f, 0x91, 0x9b],
+            destination: [0x04, 0xd4, 0xc4, 0xef, 0xea, 0x53],
+            ether_type: EtherType::IPV4,
+        };
+        assert_eq!(
+            "00:1b:21:0f:91:9b -> 04:d4:c4:ef:ea:53 (0x0800)",
+            format!("{}", header)
+        );
+    }
 }
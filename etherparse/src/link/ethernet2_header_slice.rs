@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 ///A slice containing an ethernet 2 header of a network package.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -26,26 +25,28 @@ impl<'a> Ethernet2HeaderSlice<'a> {
             // SAFETY:
             // Safe as slice length is checked to be at least
             // Ethernet2Header::LEN (14) before this.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), Ethernet2Header::LEN) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, Ethernet2Header::LEN) },
         })
     }
 
-    /// Converts the given slice into a ethernet 2 header slice WITHOUT any
-    /// checks to ensure that the data present is an ethernet 2 header or that the
-    /// slice length is matching the header length.
-    ///
-    /// If you are not sure what this means, use [`Ethernet2HeaderSlice::from_slice`]
-    /// instead.
-    ///
-    /// # Safety
-    ///
-    /// The caller must ensured that the given slice has the length of
-    /// [`Ethernet2Header::LEN`]
-    #[inline]
-    #[cfg(feature = "std")]
-    pub(crate) unsafe fn from_slice_unchecked(slice: &[u8]) -> Ethernet2HeaderSlice {
-        debug_assert!(slice.len() == Ethernet2Header::LEN);
-        Ethernet2HeaderSlice { slice }
+    unchecked_fn! {
+        /// Converts the given slice into a ethernet 2 header slice WITHOUT any
+        /// checks to ensure that the data present is an ethernet 2 header or that the
+        /// slice length is matching the header length.
+        ///
+        /// If you are not sure what this means, use [`Ethernet2HeaderSlice::from_slice`]
+        /// instead.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensured that the given slice has the length of
+        /// [`Ethernet2Header::LEN`]
+        #[inline]
+        #[cfg(feature = "std")]
+        pub(crate) fn from_slice_unchecked(slice: &[u8]) -> Ethernet2HeaderSlice {
+            debug_assert!(slice.len() == Ethernet2Header::LEN);
+            Ethernet2HeaderSlice { slice }
+        }
     }
 
     /// Returns the slice containing the ethernet 2 header
@@ -60,7 +61,14 @@ impl<'a> Ethernet2HeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr()) }
+        unchecked! { get_unchecked_array(self.slice, 0) }
+    }
+
+    /// Returns the classification of the destination MAC address into
+    /// unicast, multicast or broadcast (see [`CastType::from_mac_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_mac_addr(&self.destination())
     }
 
     /// Read the source MAC address
@@ -69,7 +77,7 @@ impl<'a> Ethernet2HeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr().add(6)) }
+        unchecked! { get_unchecked_array(self.slice, 6) }
     }
 
     /// Read the ether_type field of the header indicating the protocol
@@ -79,7 +87,7 @@ impl<'a> Ethernet2HeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(12)) })
+        EtherType(unchecked! { get_unchecked_be_u16(self.slice, 12) })
     }
 
     /// Decode all the fields and copy the results to a [`Ethernet2Header`] struct
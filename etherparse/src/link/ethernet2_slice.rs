@@ -62,7 +62,14 @@ impl<'a> Ethernet2Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr()) }
+        unchecked! { get_unchecked_array(self.slice, 0) }
+    }
+
+    /// Returns the classification of the destination MAC address into
+    /// unicast, multicast or broadcast (see [`CastType::from_mac_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_mac_addr(&self.destination())
     }
 
     /// Read the source MAC address
@@ -71,7 +78,7 @@ impl<'a> Ethernet2Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr().add(6)) }
+        unchecked! { get_unchecked_array(self.slice, 6) }
     }
 
     /// Read the ether_type field of the header indicating the protocol
@@ -81,7 +88,7 @@ impl<'a> Ethernet2Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(12)) })
+        EtherType(unchecked! { get_unchecked_be_u16(self.slice, 12) })
     }
 
     /// Returns the frame check sequence if present.
@@ -91,13 +98,8 @@ impl<'a> Ethernet2Slice<'a> {
             // SAFETY: Safe as the slice length was verified
             // to be at least Ethernet2Header::LEN + fcs_len by
             // "from_slice_without_fcs" & "from_slice_with_crc32_fcs".
-            Some(unsafe {
-                [
-                    *self.slice.as_ptr().add(self.slice.len() - 4),
-                    *self.slice.as_ptr().add(self.slice.len() - 3),
-                    *self.slice.as_ptr().add(self.slice.len() - 2),
-                    *self.slice.as_ptr().add(self.slice.len() - 1),
-                ]
+            Some(unchecked! {
+                get_unchecked_array(self.slice, self.slice.len() - 4)
             })
         } else {
             None
@@ -115,11 +117,11 @@ impl<'a> Ethernet2Slice<'a> {
 
     /// Slice containing the Ethernet 2 header.
     pub fn header_slice(&self) -> &[u8] {
-        unsafe {
+        unchecked! {
             // SAFETY:
             // Safe as the contructor checks that the slice has
             // at least the length of Ethernet2Header::LEN (14).
-            core::slice::from_raw_parts(self.slice.as_ptr(), Ethernet2Header::LEN)
+            get_unchecked_slice(self.slice, 0, Ethernet2Header::LEN)
         }
     }
 
@@ -136,14 +138,11 @@ impl<'a> Ethernet2Slice<'a> {
     /// Returns the slice containing the Ethernet II payload.
     #[inline]
     pub fn payload_slice(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as the slice length was verified
             // to be at least Ethernet2Header::LEN + fcs_len by
             // "from_slice_without_fcs" & "from_slice_with_crc32_fcs".
-            core::slice::from_raw_parts(
-                self.slice.as_ptr().add(Ethernet2Header::LEN),
-                self.slice.len() - Ethernet2Header::LEN - self.fcs_len,
-            )
+            get_unchecked_slice(self.slice, Ethernet2Header::LEN, self.slice.len() - Ethernet2Header::LEN - self.fcs_len)
         }
     }
 
@@ -0,0 +1,576 @@
+use crate::{
+    err::{self, Layer},
+    *,
+};
+
+/// Slice containing an IEEE 802.11 (WiFi) data frame (MAC header & frame body).
+///
+/// Only data frames (frame type `2`) are supported. The frame body of
+/// unprotected data frames usually starts with an LLC/SNAP header that
+/// contains the ether type of the payload (see
+/// [`Ieee80211DataSlice::ether_payload`]). Use [`SlicedPacket::from_ieee80211`]
+/// to slice the frame together with the following headers:
+///
+/// ```
+/// use etherparse::{EtherType, Ieee80211DataSlice, LinkSlice, SlicedPacket};
+///
+/// # let packet = [
+/// #     0x08, 0x01, // frame control (data frame, "to DS")
+/// #     0, 0, // duration
+/// #     1, 2, 3, 4, 5, 6, // address 1 (BSSID)
+/// #     7, 8, 9, 10, 11, 12, // address 2 (source)
+/// #     13, 14, 15, 16, 17, 18, // address 3 (destination)
+/// #     0x10, 0, // sequence control
+/// #     0xAA, 0xAA, 0x03, 0, 0, 0, 0x08, 0x00, // LLC/SNAP (IPv4)
+/// #     0x45, 0, 0, 20, 0, 0, 0x40, 0, 64, 253, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, // IPv4
+/// # ];
+/// let wifi = Ieee80211DataSlice::from_slice(&packet).unwrap();
+/// assert_eq!([7, 8, 9, 10, 11, 12], wifi.source_addr());
+/// assert_eq!([13, 14, 15, 16, 17, 18], wifi.destination_addr());
+///
+/// let ether_payload = wifi.ether_payload().unwrap();
+/// assert_eq!(EtherType::IPV4, ether_payload.ether_type);
+///
+/// // slice the frame & the following headers
+/// let sliced = SlicedPacket::from_ieee80211(&packet).unwrap();
+/// assert_eq!(Some(LinkSlice::Ieee80211(wifi)), sliced.link);
+/// assert!(sliced.net.is_some());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ieee80211DataSlice<'a> {
+    slice: &'a [u8],
+    header_len: usize,
+}
+
+impl<'a> Ieee80211DataSlice<'a> {
+    /// Frame type value of data frames.
+    pub const FRAME_TYPE_DATA: u8 = 2;
+
+    /// Minimum length of a data frame MAC header (without address 4,
+    /// QoS control & HT control).
+    pub const MIN_HEADER_LEN: usize = 24;
+
+    /// Maximum length of a data frame MAC header (with address 4,
+    /// QoS control & HT control).
+    pub const MAX_HEADER_LEN: usize = 36;
+
+    /// Length of an LLC/SNAP header (including the ether type).
+    pub const LLC_SNAP_LEN: usize = 8;
+
+    /// Try creating an [`Ieee80211DataSlice`] from a slice containing an
+    /// IEEE 802.11 data frame (without FCS).
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<Ieee80211DataSlice<'a>, err::ieee80211::HeaderSliceError> {
+        use err::ieee80211::{HeaderError::*, HeaderSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ieee80211Header,
+                layer_start_offset: 0,
+            })
+        };
+
+        if slice.len() < 2 {
+            return Err(len_error(Ieee80211DataSlice::MIN_HEADER_LEN));
+        }
+
+        // check frame control
+        let version = slice[0] & 0b11;
+        if 0 != version {
+            return Err(Content(UnsupportedProtocolVersion { version }));
+        }
+        let frame_type = (slice[0] >> 2) & 0b11;
+        if Ieee80211DataSlice::FRAME_TYPE_DATA != frame_type {
+            return Err(Content(NotDataFrame { frame_type }));
+        }
+
+        // determine the header length based on the flags & subtype
+        let mut header_len = Ieee80211DataSlice::MIN_HEADER_LEN;
+        if 0b11 == slice[1] & 0b11 {
+            // address 4
+            header_len += 6;
+        }
+        if 0 != slice[0] & 0b1000_0000 {
+            // QoS control
+            header_len += 2;
+            if 0 != slice[1] & 0b1000_0000 {
+                // HT control
+                header_len += 4;
+            }
+        }
+        if slice.len() < header_len {
+            return Err(len_error(header_len));
+        }
+
+        Ok(Ieee80211DataSlice { slice, header_len })
+    }
+
+    /// Returns the slice containing the MAC header & frame body.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing the MAC header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least header_len.
+        unchecked! { get_unchecked_slice(self.slice, 0, self.header_len) }
+    }
+
+    /// Returns the slice containing the frame body.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least header_len.
+        unchecked! {
+            get_unchecked_slice(self.slice, self.header_len, self.slice.len() - self.header_len)
+        }
+    }
+
+    /// Length of the MAC header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.header_len
+    }
+
+    /// Raw 16 bit "frame control" field (little endian decoded).
+    #[inline]
+    pub fn frame_control(&self) -> u16 {
+        u16::from_le_bytes([self.slice[0], self.slice[1]])
+    }
+
+    /// 4 bit subtype of the data frame (e.g. `8` for QoS data).
+    #[inline]
+    pub fn subtype(&self) -> u8 {
+        self.slice[0] >> 4
+    }
+
+    /// True if the frame is a QoS data frame (QoS control field is present).
+    #[inline]
+    pub fn is_qos(&self) -> bool {
+        0 != self.slice[0] & 0b1000_0000
+    }
+
+    /// True if the subtype signals that the frame contains no
+    /// frame body (e.g. "Null" frames).
+    #[inline]
+    pub fn is_null_data(&self) -> bool {
+        0 != self.slice[0] & 0b0100_0000
+    }
+
+    /// "To DS" flag (frame is sent to the distribution system).
+    #[inline]
+    pub fn to_ds(&self) -> bool {
+        0 != self.slice[1] & 0b0000_0001
+    }
+
+    /// "From DS" flag (frame is received from the distribution system).
+    #[inline]
+    pub fn from_ds(&self) -> bool {
+        0 != self.slice[1] & 0b0000_0010
+    }
+
+    /// "More fragments" flag.
+    #[inline]
+    pub fn more_fragments(&self) -> bool {
+        0 != self.slice[1] & 0b0000_0100
+    }
+
+    /// "Retry" flag.
+    #[inline]
+    pub fn retry(&self) -> bool {
+        0 != self.slice[1] & 0b0000_1000
+    }
+
+    /// "Power management" flag.
+    #[inline]
+    pub fn power_management(&self) -> bool {
+        0 != self.slice[1] & 0b0001_0000
+    }
+
+    /// "More data" flag.
+    #[inline]
+    pub fn more_data(&self) -> bool {
+        0 != self.slice[1] & 0b0010_0000
+    }
+
+    /// "Protected frame" flag (the frame body is encrypted).
+    #[inline]
+    pub fn protected(&self) -> bool {
+        0 != self.slice[1] & 0b0100_0000
+    }
+
+    /// "+HTC/Order" flag.
+    #[inline]
+    pub fn order(&self) -> bool {
+        0 != self.slice[1] & 0b1000_0000
+    }
+
+    /// "Duration/ID" field.
+    #[inline]
+    pub fn duration(&self) -> u16 {
+        u16::from_le_bytes([self.slice[2], self.slice[3]])
+    }
+
+    /// Address 1 (receiver address).
+    #[inline]
+    pub fn address1(&self) -> [u8; 6] {
+        self.mac_at(4)
+    }
+
+    /// Address 2 (transmitter address).
+    #[inline]
+    pub fn address2(&self) -> [u8; 6] {
+        self.mac_at(10)
+    }
+
+    /// Address 3 (meaning depends on the "to DS" & "from DS" flags).
+    #[inline]
+    pub fn address3(&self) -> [u8; 6] {
+        self.mac_at(16)
+    }
+
+    /// Address 4 (only present if "to DS" & "from DS" are set).
+    #[inline]
+    pub fn address4(&self) -> Option<[u8; 6]> {
+        if self.to_ds() && self.from_ds() {
+            Some(self.mac_at(24))
+        } else {
+            None
+        }
+    }
+
+    /// Raw 16 bit "sequence control" field (little endian decoded).
+    #[inline]
+    pub fn sequence_control(&self) -> u16 {
+        u16::from_le_bytes([self.slice[22], self.slice[23]])
+    }
+
+    /// 4 bit fragment number.
+    #[inline]
+    pub fn fragment_number(&self) -> u8 {
+        (self.sequence_control() & 0b1111) as u8
+    }
+
+    /// 12 bit sequence number.
+    #[inline]
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_control() >> 4
+    }
+
+    /// "QoS control" field (only present in QoS data frames).
+    #[inline]
+    pub fn qos_control(&self) -> Option<u16> {
+        if self.is_qos() {
+            let offset = self.qos_control_offset();
+            Some(u16::from_le_bytes([
+                self.slice[offset],
+                self.slice[offset + 1],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// Traffic identifier (TID) from the QoS control field.
+    #[inline]
+    pub fn tid(&self) -> Option<u8> {
+        self.qos_control().map(|qos| (qos & 0b1111) as u8)
+    }
+
+    /// "HT control" field (only present in QoS data frames with the
+    /// "+HTC/Order" flag set).
+    #[inline]
+    pub fn ht_control(&self) -> Option<u32> {
+        if self.is_qos() && self.order() {
+            let offset = self.qos_control_offset() + 2;
+            Some(u32::from_le_bytes([
+                self.slice[offset],
+                self.slice[offset + 1],
+                self.slice[offset + 2],
+                self.slice[offset + 3],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// MAC address of the final destination of the frame (depends on
+    /// the "to DS" & "from DS" flags).
+    #[inline]
+    pub fn destination_addr(&self) -> [u8; 6] {
+        if self.to_ds() {
+            self.address3()
+        } else {
+            self.address1()
+        }
+    }
+
+    /// MAC address of the original source of the frame (depends on
+    /// the "to DS" & "from DS" flags).
+    pub fn source_addr(&self) -> [u8; 6] {
+        match (self.to_ds(), self.from_ds()) {
+            (_, false) => self.address2(),
+            (false, true) => self.address3(),
+            (true, true) => self.mac_at(24),
+        }
+    }
+
+    /// Returns the ether type & payload if the frame body starts with
+    /// an LLC/SNAP header (RFC 1042 or IEEE 802.1H encapsulation).
+    ///
+    /// `None` is returned for protected (encrypted) frames & frame bodies
+    /// that do not start with an LLC/SNAP header.
+    pub fn ether_payload(&self) -> Option<EtherPayloadSlice<'a>> {
+        if self.protected() {
+            return None;
+        }
+        let payload = self.payload();
+        if payload.len() < Ieee80211DataSlice::LLC_SNAP_LEN {
+            return None;
+        }
+        match payload[..6] {
+            // RFC 1042 & IEEE 802.1H (bridge tunnel) encapsulation
+            [0xAA, 0xAA, 0x03, 0x00, 0x00, 0x00] | [0xAA, 0xAA, 0x03, 0x00, 0x00, 0xF8] => {
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType(u16::from_be_bytes([payload[6], payload[7]])),
+                    payload: &payload[Ieee80211DataSlice::LLC_SNAP_LEN..],
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Offset of the QoS control field.
+    #[inline]
+    fn qos_control_offset(&self) -> usize {
+        if self.to_ds() && self.from_ds() {
+            30
+        } else {
+            24
+        }
+    }
+
+    /// Reads a MAC address at the given offset.
+    #[inline]
+    fn mac_at(&self, offset: usize) -> [u8; 6] {
+        [
+            self.slice[offset],
+            self.slice[offset + 1],
+            self.slice[offset + 2],
+            self.slice[offset + 3],
+            self.slice[offset + 4],
+            self.slice[offset + 5],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+    use err::ieee80211::{HeaderError::*, HeaderSliceError::*};
+
+    /// Builds a data frame with the given frame control bytes.
+    fn build(fc0: u8, fc1: u8, body: &[u8]) -> Vec<u8> {
+        let mut result = Vec::new();
+        result.extend_from_slice(&[fc0, fc1, 0x34, 0x12]);
+        result.extend_from_slice(&[1, 1, 1, 1, 1, 1]);
+        result.extend_from_slice(&[2, 2, 2, 2, 2, 2]);
+        result.extend_from_slice(&[3, 3, 3, 3, 3, 3]);
+        result.extend_from_slice(&[0x52, 0x01]);
+        if 0b11 == fc1 & 0b11 {
+            result.extend_from_slice(&[4, 4, 4, 4, 4, 4]);
+        }
+        if 0 != fc0 & 0b1000_0000 {
+            result.extend_from_slice(&[0x05, 0x00]);
+            if 0 != fc1 & 0b1000_0000 {
+                result.extend_from_slice(&[1, 2, 3, 4]);
+            }
+        }
+        result.extend_from_slice(body);
+        result
+    }
+
+    #[test]
+    fn from_slice() {
+        let body = [0xAA, 0xAA, 0x03, 0, 0, 0, 0x86, 0xDD, 1, 2, 3];
+
+        // basic data frame
+        {
+            let data = build(0x08, 0x00, &body);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert_eq!(&data[..], s.slice());
+            assert_eq!(24, s.header_len());
+            assert_eq!(&data[..24], s.header_slice());
+            assert_eq!(&body[..], s.payload());
+            assert_eq!(0x0008, s.frame_control());
+            assert_eq!(0, s.subtype());
+            assert!(false == s.is_qos());
+            assert!(false == s.is_null_data());
+            assert!(false == s.to_ds());
+            assert!(false == s.from_ds());
+            assert_eq!(0x1234, s.duration());
+            assert_eq!([1; 6], s.address1());
+            assert_eq!([2; 6], s.address2());
+            assert_eq!([3; 6], s.address3());
+            assert_eq!(None, s.address4());
+            assert_eq!(0x0152, s.sequence_control());
+            assert_eq!(2, s.fragment_number());
+            assert_eq!(0x15, s.sequence_number());
+            assert_eq!(None, s.qos_control());
+            assert_eq!(None, s.tid());
+            assert_eq!(None, s.ht_control());
+            assert_eq!([1; 6], s.destination_addr());
+            assert_eq!([2; 6], s.source_addr());
+            assert_eq!(
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType::IPV6,
+                    payload: &[1, 2, 3],
+                }),
+                s.ether_payload()
+            );
+            assert_eq!(s.clone(), s);
+        }
+
+        // qos data frame with address 4 & ht control
+        {
+            let data = build(0x88, 0b1000_0011, &body);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert_eq!(Ieee80211DataSlice::MAX_HEADER_LEN, s.header_len());
+            assert_eq!(8, s.subtype());
+            assert!(s.is_qos());
+            assert!(s.to_ds());
+            assert!(s.from_ds());
+            assert!(s.order());
+            assert_eq!(Some([4; 6]), s.address4());
+            assert_eq!(Some(0x0005), s.qos_control());
+            assert_eq!(Some(5), s.tid());
+            assert_eq!(Some(0x0403_0201), s.ht_control());
+            assert_eq!([3; 6], s.destination_addr());
+            assert_eq!([4; 6], s.source_addr());
+            assert_eq!(&body[..], s.payload());
+        }
+
+        // "to DS" & "from DS" address mapping
+        {
+            let data = build(0x08, 0b01, &body);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert_eq!([3; 6], s.destination_addr());
+            assert_eq!([2; 6], s.source_addr());
+
+            let data = build(0x08, 0b10, &body);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert_eq!([1; 6], s.destination_addr());
+            assert_eq!([3; 6], s.source_addr());
+        }
+
+        // flags
+        {
+            let data = build(0x08, 0b0111_1100, &body);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert!(s.more_fragments());
+            assert!(s.retry());
+            assert!(s.power_management());
+            assert!(s.more_data());
+            assert!(s.protected());
+            assert!(false == s.order());
+            // protected frames have no readable ether payload
+            assert_eq!(None, s.ether_payload());
+        }
+
+        // null data frame
+        {
+            let data = build(0x48, 0, &[]);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert!(s.is_null_data());
+            assert_eq!(None, s.ether_payload());
+        }
+
+        // ieee 802.1h bridge tunnel & unknown llc
+        {
+            let data = build(0x08, 0, &[0xAA, 0xAA, 0x03, 0, 0, 0xF8, 0x80, 0xF3]);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert_eq!(
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType(0x80F3),
+                    payload: &[],
+                }),
+                s.ether_payload()
+            );
+
+            let data = build(0x08, 0, &[0x42, 0x42, 0x03, 0, 0, 0, 0x08, 0x00]);
+            let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+            assert_eq!(None, s.ether_payload());
+        }
+
+        // length errors
+        {
+            let data = build(0x88, 0b1000_0011, &[]);
+            for len in 0..data.len() {
+                assert_eq!(
+                    Err(Len(err::LenError {
+                        required_len: if len < 2 {
+                            Ieee80211DataSlice::MIN_HEADER_LEN
+                        } else {
+                            Ieee80211DataSlice::MAX_HEADER_LEN
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ieee80211Header,
+                        layer_start_offset: 0,
+                    })),
+                    Ieee80211DataSlice::from_slice(&data[..len])
+                );
+            }
+        }
+
+        // content errors
+        {
+            let data = build(0x09, 0, &body);
+            assert_eq!(
+                Err(Content(UnsupportedProtocolVersion { version: 1 })),
+                Ieee80211DataSlice::from_slice(&data)
+            );
+            // management frame
+            let data = build(0x00, 0, &body);
+            assert_eq!(
+                Err(Content(NotDataFrame { frame_type: 0 })),
+                Ieee80211DataSlice::from_slice(&data)
+            );
+            // control frame
+            let data = build(0x04, 0, &body);
+            assert_eq!(
+                Err(Content(NotDataFrame { frame_type: 1 })),
+                Ieee80211DataSlice::from_slice(&data)
+            );
+        }
+    }
+
+    #[test]
+    fn ether_payload_sliced_packet() {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20).udp(1234, 53);
+        let mut ip = Vec::new();
+        builder.write(&mut ip, &[1, 2, 3, 4]).unwrap();
+
+        let mut body = Vec::new();
+        body.extend_from_slice(&[0xAA, 0xAA, 0x03, 0, 0, 0, 0x08, 0x00]);
+        body.extend_from_slice(&ip);
+        let data = build(0x88, 0b01, &body);
+
+        let s = Ieee80211DataSlice::from_slice(&data).unwrap();
+        let p = s.ether_payload().unwrap();
+        let sliced = SlicedPacket::from_ether_type(p.ether_type, p.payload).unwrap();
+        assert_eq!(SlicedPacket::from_ip(&ip).unwrap().net, sliced.net);
+        assert_eq!(
+            SlicedPacket::from_ip(&ip).unwrap().transport,
+            sliced.transport
+        );
+    }
+}
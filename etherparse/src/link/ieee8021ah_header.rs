@@ -0,0 +1,207 @@
+use crate::*;
+
+/// IEEE 802.1ah Provider Backbone Bridging "I-TAG" header (backbone
+/// service instance tag, [`EtherType::PROVIDER_BACKBONE_BRIDGING`]).
+///
+/// The I-TAG is directly followed by the complete customer Ethernet II
+/// frame (customer destination & source MAC, ether type and payload).
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct Ieee8021ahHeader {
+    /// "Priority code point" of the backbone service instance (I-PCP).
+    pub pcp: VlanPcp,
+    /// "Drop eligible indicator" of the backbone service instance (I-DEI).
+    pub drop_eligible_indicator: bool,
+    /// "Use customer address" flag (UCA).
+    pub use_customer_address: bool,
+    /// 24 bit "backbone service instance identifier" (I-SID).
+    pub isid: Ieee8021ahIsid,
+}
+
+impl Ieee8021ahHeader {
+    /// Serialized size of an I-TAG header in bytes/octets.
+    pub const LEN: usize = 4;
+
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`Ieee8021ahHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = Ieee8021ahHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`Ieee8021ahHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = Ieee8021ahHeader::LEN;
+
+    /// Read an Ieee8021ahHeader from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(Ieee8021ahHeader, &[u8]), err::LenError> {
+        let s = Ieee8021ahSlice::from_slice(slice)?;
+        Ok((s.to_header(), s.payload()))
+    }
+
+    /// Read an Ieee8021ahHeader from a static sized byte array.
+    ///
+    /// The reserved bits are ignored.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 4]) -> Ieee8021ahHeader {
+        Ieee8021ahHeader {
+            pcp: unchecked! {
+                // SAFETY: Safe as bitmasks guarantee that value does not exceed
+                //         0b0000_0111.
+                VlanPcp::new_unchecked_internal((bytes[0] >> 5) & 0b0000_0111u8)
+            },
+            drop_eligible_indicator: 0 != (bytes[0] & 0b0001_0000u8),
+            use_customer_address: 0 != (bytes[0] & 0b0000_1000u8),
+            isid: unchecked! {
+                // SAFETY: Safe as the value is build from 3 bytes and
+                //         can not exceed 0x00ff_ffff.
+                Ieee8021ahIsid::new_unchecked_internal(u32::from_be_bytes([0, bytes[1], bytes[2], bytes[3]]))
+            },
+        }
+    }
+
+    /// Read an IEEE 802.1ah I-TAG header.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(
+        reader: &mut T,
+    ) -> Result<Ieee8021ahHeader, std::io::Error> {
+        let mut buffer = [0u8; Ieee8021ahHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(Ieee8021ahHeader::from_bytes(buffer))
+    }
+
+    /// Write the IEEE 802.1ah I-TAG header.
+    #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        Ieee8021ahHeader::LEN
+    }
+
+    /// Returns the serialized form of the header (reserved bits are set to 0).
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        let isid_be = self.isid.value().to_be_bytes();
+        [
+            (self.pcp.value() << 5)
+                | if self.drop_eligible_indicator {
+                    0b0001_0000
+                } else {
+                    0
+                }
+                | if self.use_customer_address {
+                    0b0000_1000
+                } else {
+                    0
+                },
+            isid_be[1],
+            isid_be[2],
+            isid_be[3],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn constants() {
+        assert_eq!(4, Ieee8021ahHeader::LEN);
+        assert_eq!(4, Ieee8021ahHeader::default().header_len());
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in ieee8021ah_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // normal
+            {
+                let (result, rest) = Ieee8021ahHeader::from_slice(&buffer).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &buffer[4..]);
+            }
+
+            // slice length to small
+            for len in 0..4 {
+                assert_eq!(
+                    Ieee8021ahHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: 4,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Ieee8021ahHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes_to_bytes(input in ieee8021ah_any(), reserved in 0u8..0b1000) {
+            assert_eq!(input, Ieee8021ahHeader::from_bytes(input.to_bytes()));
+
+            // reserved bits are ignored when reading & zero when writing
+            let mut bytes = input.to_bytes();
+            assert_eq!(0, bytes[0] & 0b0000_0111);
+            bytes[0] |= reserved;
+            assert_eq!(input, Ieee8021ahHeader::from_bytes(bytes));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in ieee8021ah_any()) {
+            let mut buffer = Vec::with_capacity(4);
+            input.write(&mut buffer).unwrap();
+            assert_eq!(&buffer[..], &input.to_bytes()[..]);
+
+            // read
+            {
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, Ieee8021ahHeader::read(&mut cursor).unwrap());
+            }
+
+            // read with not enough data
+            for len in 0..4 {
+                let mut cursor = Cursor::new(&buffer[..len]);
+                assert_eq!(
+                    ErrorKind::UnexpectedEof,
+                    Ieee8021ahHeader::read(&mut cursor).unwrap_err().kind()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(input in ieee8021ah_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "Ieee8021ahHeader {{ pcp: {:?}, drop_eligible_indicator: {}, use_customer_address: {}, isid: {:?} }}",
+                    input.pcp,
+                    input.drop_eligible_indicator,
+                    input.use_customer_address,
+                    input.isid,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::err::ValueTooBigError;
+
+/// 24 bit unsigned integer containing the "backbone service instance
+/// identifier" (I-SID, present in the [`crate::Ieee8021ahHeader`]).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ieee8021ahIsid(u32);
+
+impl Ieee8021ahIsid {
+    /// Ieee8021ahIsid with value 0.
+    pub const ZERO: Ieee8021ahIsid = Ieee8021ahIsid(0);
+
+    /// Maximum value of an I-SID.
+    pub const MAX_U32: u32 = 0x00ff_ffff;
+
+    /// Tries to create an [`Ieee8021ahIsid`] and checks that the passed value
+    /// is smaller or equal than [`Ieee8021ahIsid::MAX_U32`] (24 bit unsigned integer).
+    ///
+    /// In case the passed value is bigger then what can be represented in an 24 bit
+    /// integer an error is returned. Otherwise an `Ok` containing the [`Ieee8021ahIsid`].
+    ///
+    /// ```
+    /// use etherparse::Ieee8021ahIsid;
+    ///
+    /// let isid = Ieee8021ahIsid::try_new(0x12_3456).unwrap();
+    /// assert_eq!(isid.value(), 0x12_3456);
+    ///
+    /// // if a number that can not be represented in an 24 bit integer
+    /// // gets passed in an error is returned
+    /// use etherparse::err::{ValueTooBigError, ValueType};
+    /// assert_eq!(
+    ///     Ieee8021ahIsid::try_new(Ieee8021ahIsid::MAX_U32 + 1),
+    ///     Err(ValueTooBigError{
+    ///         actual: Ieee8021ahIsid::MAX_U32 + 1,
+    ///         max_allowed: Ieee8021ahIsid::MAX_U32,
+    ///         value_type: ValueType::Ieee8021ahIsid,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_new(value: u32) -> Result<Ieee8021ahIsid, ValueTooBigError<u32>> {
+        use crate::err::ValueType;
+        if value <= Ieee8021ahIsid::MAX_U32 {
+            Ok(Ieee8021ahIsid(value))
+        } else {
+            Err(ValueTooBigError {
+                actual: value,
+                max_allowed: Ieee8021ahIsid::MAX_U32,
+                value_type: ValueType::Ieee8021ahIsid,
+            })
+        }
+    }
+
+    /// Creates an [`Ieee8021ahIsid`] without checking that the value
+    /// is smaller or equal than [`Ieee8021ahIsid::MAX_U32`] (24 bit unsigned integer).
+    /// The caller must guarantee that `value <= Ieee8021ahIsid::MAX_U32`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be smaller or equal than [`Ieee8021ahIsid::MAX_U32`]
+    /// otherwise the behavior of functions or data structures relying
+    /// on this pre-requirement is undefined.
+    #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+    pub const unsafe fn new_unchecked(value: u32) -> Ieee8021ahIsid {
+        unchecked! { Ieee8021ahIsid::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`Ieee8021ahIsid::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u32) -> Ieee8021ahIsid {
+            debug_assert!(value <= Ieee8021ahIsid::MAX_U32);
+            Ieee8021ahIsid(value)
+        }
+    }
+
+    /// Returns the underlying unsigned 24 bit value as an `u32` value.
+    #[inline]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for Ieee8021ahIsid {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Ieee8021ahIsid> for u32 {
+    #[inline]
+    fn from(value: Ieee8021ahIsid) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u32> for Ieee8021ahIsid {
+    type Error = ValueTooBigError<u32>;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ieee8021ahIsid::try_new(value)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn derived_traits() {
+        let a = Ieee8021ahIsid(123);
+        let b = a;
+        assert_eq!(a, b);
+        assert_eq!(a.clone(), a);
+        assert_eq!(Ieee8021ahIsid::ZERO, Default::default());
+        assert_eq!(format!("{:?}", a), "Ieee8021ahIsid(123)");
+        assert!(Ieee8021ahIsid(1) < Ieee8021ahIsid(2));
+    }
+
+    proptest! {
+        #[test]
+        fn try_new_try_from(
+            valid_value in 0..=0x00ff_ffffu32,
+            invalid_value in 0x0100_0000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            let expected_err = ValueTooBigError{
+                actual: invalid_value,
+                max_allowed: 0x00ff_ffff,
+                value_type: ValueType::Ieee8021ahIsid,
+            };
+
+            assert_eq!(valid_value, Ieee8021ahIsid::try_new(valid_value).unwrap().value());
+            assert_eq!(expected_err.clone(), Ieee8021ahIsid::try_new(invalid_value).unwrap_err());
+
+            let actual: Ieee8021ahIsid = valid_value.try_into().unwrap();
+            assert_eq!(valid_value, actual.value());
+            assert_eq!(expected_err, Ieee8021ahIsid::try_from(invalid_value).unwrap_err());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_unchecked_fmt_from(valid_value in 0..=0x00ff_ffffu32) {
+            let isid = unsafe { Ieee8021ahIsid::new_unchecked(valid_value) };
+            assert_eq!(valid_value, isid.value());
+            assert_eq!(format!("{}", valid_value), format!("{}", isid));
+            let actual: u32 = isid.into();
+            assert_eq!(valid_value, actual);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::*;
+
+/// Slice containing an IEEE 802.1ah I-TAG header & the encapsulated
+/// customer Ethernet II frame as payload ("MAC-in-MAC").
+///
+/// Provider Backbone Bridging frames are identified by the ether type
+/// [`EtherType::PROVIDER_BACKBONE_BRIDGING`] (`0x88E7`). When slicing a
+/// packet the I-TAG is stored in [`ProviderBackboneSlice::itag`] & the
+/// slicing continues with the customer Ethernet II frame contained in the
+/// payload of the I-TAG:
+///
+/// ```
+/// use etherparse::SlicedPacket;
+///
+/// # let packet = [
+/// #     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x88, 0xE7, // backbone ethernet II
+/// #     0x60, 0x12, 0x34, 0x56, // I-TAG (pcp 3, I-SID 0x123456)
+/// #     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x88, 0xB5, // customer ethernet II
+/// # ];
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// let itag = &sliced.backbone.as_ref().unwrap().itag;
+/// assert_eq!(0x123456, itag.isid().value());
+/// assert_eq!(&packet[18..], itag.payload());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ieee8021ahSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> Ieee8021ahSlice<'a> {
+    /// Try creating an [`Ieee8021ahSlice`] from a slice containing an
+    /// I-TAG header & the customer frame.
+    pub fn from_slice(slice: &'a [u8]) -> Result<Ieee8021ahSlice<'a>, err::LenError> {
+        if slice.len() < Ieee8021ahHeader::LEN {
+            return Err(err::LenError {
+                required_len: Ieee8021ahHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ieee8021ahHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(Ieee8021ahSlice { slice })
+    }
+
+    /// Returns the slice containing the I-TAG header & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing only the I-TAG header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        unchecked! {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of Ieee8021ahHeader::LEN (4).
+            get_unchecked_slice(self.slice, 0, Ieee8021ahHeader::LEN)
+        }
+    }
+
+    /// Returns the slice containing the customer Ethernet II frame.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        unchecked! {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of Ieee8021ahHeader::LEN (4).
+            get_unchecked_slice(self.slice, Ieee8021ahHeader::LEN, self.slice.len() - Ieee8021ahHeader::LEN)
+        }
+    }
+
+    /// Read the "priority code point" (I-PCP) field.
+    #[inline]
+    pub fn pcp(&self) -> VlanPcp {
+        unchecked! {
+            // SAFETY: Safe as slice len checked in constructor to be at least 4 &
+            // the bitmask guarantees values does not exceed 0b0000_0111.
+            VlanPcp::new_unchecked_internal((get_unchecked_u8(self.slice, 0) >> 5) & 0b0000_0111)
+        }
+    }
+
+    /// Read the "drop eligible indicator" (I-DEI) flag.
+    #[inline]
+    pub fn drop_eligible_indicator(&self) -> bool {
+        // SAFETY:
+        // Slice len checked in constructor to be at least 4.
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 0) & 0b0001_0000) }
+    }
+
+    /// Read the "use customer address" (UCA) flag.
+    #[inline]
+    pub fn use_customer_address(&self) -> bool {
+        // SAFETY:
+        // Slice len checked in constructor to be at least 4.
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 0) & 0b0000_1000) }
+    }
+
+    /// Read the 24 bit "backbone service instance identifier" (I-SID).
+    #[inline]
+    pub fn isid(&self) -> Ieee8021ahIsid {
+        unchecked! {
+            // SAFETY:
+            // Slice len checked in constructor to be at least 4 &
+            // the value is build from 3 bytes (can not exceed 0x00ff_ffff).
+            Ieee8021ahIsid::new_unchecked_internal(u32::from_be_bytes([
+                0,
+                get_unchecked_u8(self.slice, 1),
+                get_unchecked_u8(self.slice, 2),
+                get_unchecked_u8(self.slice, 3),
+            ]))
+        }
+    }
+
+    /// Decode all the fields and copy the results to an [`Ieee8021ahHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> Ieee8021ahHeader {
+        Ieee8021ahHeader {
+            pcp: self.pcp(),
+            drop_eligible_indicator: self.drop_eligible_indicator(),
+            use_customer_address: self.use_customer_address(),
+            isid: self.isid(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in ieee8021ah_any(),
+            inner in ethernet_2_unknown(),
+            reserved in 0u8..0b1000,
+        ) {
+            let mut buffer = Vec::with_capacity(header.header_len() + inner.header_len());
+            let mut header_bytes = header.to_bytes();
+            header_bytes[0] |= reserved;
+            buffer.extend_from_slice(&header_bytes);
+            buffer.extend_from_slice(&inner.to_bytes());
+
+            // ok
+            {
+                let s = Ieee8021ahSlice::from_slice(&buffer).unwrap();
+                assert_eq!(&buffer[..], s.slice());
+                assert_eq!(&buffer[..4], s.header_slice());
+                assert_eq!(&buffer[4..], s.payload());
+                assert_eq!(header.pcp, s.pcp());
+                assert_eq!(header.drop_eligible_indicator, s.drop_eligible_indicator());
+                assert_eq!(header.use_customer_address, s.use_customer_address());
+                assert_eq!(header.isid, s.isid());
+                assert_eq!(header, s.to_header());
+                assert_eq!(s.clone(), s);
+
+                // the customer frame can be sliced again
+                let inner_packet = SlicedPacket::from_ethernet(s.payload()).unwrap();
+                assert_eq!(
+                    Some(LinkHeader::Ethernet2(inner.clone())),
+                    inner_packet.link.and_then(|l| l.to_header())
+                );
+            }
+
+            // length error
+            for len in 0..Ieee8021ahHeader::LEN {
+                assert_eq!(
+                    Ieee8021ahSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: Ieee8021ahHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Ieee8021ahHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+}
@@ -8,6 +8,12 @@ pub enum LinkHeader {
 }
 
 impl LinkHeader {
+    /// Minimum serialized size of a link header in bytes/octets.
+    pub const MIN_LEN: usize = Ethernet2Header::LEN;
+
+    /// Maximum serialized size of a link header in bytes/octets.
+    pub const MAX_LEN: usize = LinuxSllHeader::LEN;
+
     /// Returns `Option::Some` containing the `Ethernet2Header` if self has the
     /// value Ethernet2. Otherwise `Option::None` is returned.
     pub fn ethernet2(self) -> Option<Ethernet2Header> {
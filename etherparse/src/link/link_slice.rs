@@ -1,7 +1,7 @@
 use crate::*;
 
-/// A slice containing the link layer header (currently only Ethernet II and
-/// SLL are supported).
+/// A slice containing the link layer header (currently only Ethernet II,
+/// SLL & IEEE 802.11 data frames are supported).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LinkSlice<'a> {
     /// A slice containing an Ethernet II header.
@@ -15,6 +15,9 @@ pub enum LinkSlice<'a> {
 
     /// Sll payload without header.
     LinuxSllPayload(LinuxSllPayloadSlice<'a>),
+
+    /// A slice containing an IEEE 802.11 (WiFi) data frame.
+    Ieee80211(Ieee80211DataSlice<'a>),
 }
 
 impl<'a> LinkSlice<'a> {
@@ -26,6 +29,7 @@ impl<'a> LinkSlice<'a> {
             LinuxSll(slice) => Some(LinkHeader::LinuxSll(slice.to_header())),
             EtherPayload(_) => None,
             LinuxSllPayload(_) => None,
+            Ieee80211(_) => None,
         }
     }
 
@@ -37,10 +41,43 @@ impl<'a> LinkSlice<'a> {
             LinuxSll(s) => Some(EtherPayloadSlice::try_from(s.payload()).ok()?.clone()),
             EtherPayload(p) => Some(p.clone()),
             LinuxSllPayload(p) => Some(EtherPayloadSlice::try_from(p.clone()).ok()?),
+            Ieee80211(s) => s.ether_payload(),
+        }
+    }
+
+    /// Returns the direction of the packet relative to the capturing host
+    /// if it is known (only the case for Linux Cooked Capture headers).
+    pub fn direction(&self) -> Option<PacketDirection> {
+        use LinkSlice::*;
+        match self {
+            LinuxSll(s) => s.packet_type().direction(),
+            Ethernet2(_) | EtherPayload(_) | LinuxSllPayload(_) | Ieee80211(_) => None,
+        }
+    }
+
+    /// Length of the link layer header in bytes/octets (0 if no header
+    /// is present).
+    ///
+    /// For IEEE 802.11 data frames the LLC/SNAP header is included if
+    /// the frame body starts with one (see [`Ieee80211DataSlice::ether_payload`]).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        use LinkSlice::*;
+        match self {
+            Ethernet2(_) => Ethernet2Header::LEN,
+            LinuxSll(_) => LinuxSllHeader::LEN,
+            EtherPayload(_) | LinuxSllPayload(_) => 0,
+            Ieee80211(s) => match s.ether_payload() {
+                Some(_) => s.header_len() + Ieee80211DataSlice::LLC_SNAP_LEN,
+                None => s.header_len(),
+            },
         }
     }
 
     /// Returns the link layer sll payload (slice + link layer protocol type).
+    ///
+    /// IEEE 802.11 frame bodies without an LLC/SNAP header are returned
+    /// with the protocol type [`LinuxNonstandardEtherType::N802_2`].
     pub fn sll_payload(&self) -> LinuxSllPayloadSlice<'a> {
         use LinkSlice::*;
         match self {
@@ -48,6 +85,15 @@ impl<'a> LinkSlice<'a> {
             LinuxSll(s) => s.payload().clone(),
             EtherPayload(p) => LinuxSllPayloadSlice::from(p.clone()),
             LinuxSllPayload(p) => p.clone(),
+            Ieee80211(s) => match s.ether_payload() {
+                Some(p) => LinuxSllPayloadSlice::from(p),
+                None => LinuxSllPayloadSlice {
+                    protocol_type: LinuxSllProtocolType::LinuxNonstandardEtherType(
+                        LinuxNonstandardEtherType::N802_2,
+                    ),
+                    payload: s.payload(),
+                },
+            },
         }
     }
 }
@@ -94,6 +140,7 @@ mod test {
                     slice.to_header(),
                     Some(LinkHeader::Ethernet2(eth.clone()))
                 );
+                assert_eq!(Ethernet2Header::LEN, slice.header_len());
             }
             {
                 let bytes = linux_sll.to_bytes();
@@ -104,6 +151,7 @@ mod test {
                     slice.to_header(),
                     Some(LinkHeader::LinuxSll(linux_sll.clone()))
                 );
+                assert_eq!(LinuxSllHeader::LEN, slice.header_len());
             }
             {
                 let slice = LinkSlice::EtherPayload(EtherPayloadSlice {
@@ -114,6 +162,7 @@ mod test {
                     slice.to_header(),
                     None
                 );
+                assert_eq!(0, slice.header_len());
             }
             {
                 let slice = LinkSlice::LinuxSllPayload(LinuxSllPayloadSlice {
@@ -124,6 +173,7 @@ mod test {
                     slice.to_header(),
                     None
                 );
+                assert_eq!(0, slice.header_len());
             }
         }
     }
@@ -187,4 +237,97 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn ieee80211() {
+        let header = [
+            0x08, 0x01, // frame control (data frame, "to DS")
+            0, 0, // duration
+            1, 2, 3, 4, 5, 6, // address 1 (BSSID)
+            7, 8, 9, 10, 11, 12, // address 2 (source)
+            13, 14, 15, 16, 17, 18, // address 3 (destination)
+            0x10, 0, // sequence control
+        ];
+
+        // with LLC/SNAP
+        {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header);
+            bytes.extend_from_slice(&[0xAA, 0xAA, 0x03, 0, 0, 0, 0x08, 0x00, 1, 2]);
+            let slice = LinkSlice::Ieee80211(Ieee80211DataSlice::from_slice(&bytes).unwrap());
+            assert_eq!(None, slice.to_header());
+            assert_eq!(None, slice.direction());
+            assert_eq!(header.len() + 8, slice.header_len());
+            assert_eq!(
+                Some(EtherPayloadSlice {
+                    ether_type: ether_type::IPV4,
+                    payload: &[1, 2]
+                }),
+                slice.ether_payload()
+            );
+            assert_eq!(
+                LinuxSllPayloadSlice {
+                    protocol_type: LinuxSllProtocolType::EtherType(ether_type::IPV4),
+                    payload: &[1, 2]
+                },
+                slice.sll_payload()
+            );
+        }
+
+        // without LLC/SNAP
+        {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header);
+            bytes.extend_from_slice(&[1, 2]);
+            let slice = LinkSlice::Ieee80211(Ieee80211DataSlice::from_slice(&bytes).unwrap());
+            assert_eq!(header.len(), slice.header_len());
+            assert_eq!(None, slice.ether_payload());
+            assert_eq!(
+                LinuxSllPayloadSlice {
+                    protocol_type: LinuxSllProtocolType::LinuxNonstandardEtherType(
+                        LinuxNonstandardEtherType::N802_2
+                    ),
+                    payload: &[1, 2]
+                },
+                slice.sll_payload()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn direction(
+            ref eth in ethernet_2_unknown(),
+            ref linux_sll in linux_sll_any()
+        ) {
+            {
+                let bytes = eth.to_bytes();
+                let slice = LinkSlice::Ethernet2(
+                    Ethernet2Slice::from_slice_without_fcs(&bytes).unwrap()
+                );
+                assert_eq!(None, slice.direction());
+            }
+            {
+                let bytes = linux_sll.to_bytes();
+                let slice = LinkSlice::LinuxSll(
+                    LinuxSllSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(linux_sll.packet_type.direction(), slice.direction());
+            }
+            {
+                let slice = LinkSlice::EtherPayload(EtherPayloadSlice {
+                    ether_type: ether_type::IPV4,
+                    payload: &[]
+                });
+                assert_eq!(None, slice.direction());
+            }
+            {
+                let slice = LinkSlice::LinuxSllPayload(LinuxSllPayloadSlice {
+                    protocol_type: LinuxSllProtocolType::EtherType(ether_type::IPV4),
+                    payload: &[]
+                });
+                assert_eq!(None, slice.direction());
+            }
+        }
+    }
 }
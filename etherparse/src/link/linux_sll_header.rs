@@ -1,4 +1,7 @@
-use crate::{err, ArpHardwareId, LinuxSllHeaderSlice, LinuxSllPacketType, LinuxSllProtocolType};
+use crate::{
+    err, ArpHardwareId, EtherType, Ethernet2Header, LinuxSllHeaderSlice, LinuxSllPacketType,
+    LinuxSllProtocolType,
+};
 
 /// Linux Cooked Capture v1 (SLL) Header
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -22,6 +25,14 @@ impl LinuxSllHeader {
     /// Serialized size of an SLL header in bytes/octets.
     pub const LEN: usize = 16;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`LinuxSllHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = LinuxSllHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`LinuxSllHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = LinuxSllHeader::LEN;
+
     /// Read an SLL header from a slice and return the header & unused parts of the slice.
     #[inline]
     pub fn from_slice(
@@ -56,6 +67,47 @@ impl LinuxSllHeader {
         })
     }
 
+    /// Creates an SLL header based on the fields of a Linux `sockaddr_ll`
+    /// (e.g. as returned by `recvfrom` on an `AF_PACKET` socket).
+    ///
+    /// The arguments are in the same order as in the `sockaddr_ll`
+    /// struct (`sll_protocol`, `sll_hatype`, `sll_pkttype`, `sll_halen`,
+    /// `sll_addr`). Note that `sll_protocol` is stored in network byte
+    /// order in a `sockaddr_ll` and has to be converted to the native
+    /// byte order (e.g. via `u16::from_be`) before passing it in.
+    ///
+    /// ```
+    /// use etherparse::{ArpHardwareId, EtherType, LinuxSllHeader, LinuxSllPacketType, LinuxSllProtocolType};
+    ///
+    /// let header = LinuxSllHeader::from_sockaddr_ll(
+    ///     0x0800,            // sll_protocol (converted to native byte order)
+    ///     1,                 // sll_hatype (ARPHRD_ETHER)
+    ///     4,                 // sll_pkttype (PACKET_OUTGOING)
+    ///     6,                 // sll_halen
+    ///     [1, 2, 3, 4, 5, 6, 0, 0], // sll_addr
+    /// ).unwrap();
+    ///
+    /// assert!(header.is_outgoing());
+    /// assert_eq!(ArpHardwareId::ETHER, header.arp_hrd_type);
+    /// assert_eq!(LinuxSllProtocolType::EtherType(EtherType::IPV4), header.protocol_type);
+    /// ```
+    pub fn from_sockaddr_ll(
+        sll_protocol: u16,
+        sll_hatype: u16,
+        sll_pkttype: u8,
+        sll_halen: u8,
+        sll_addr: [u8; 8],
+    ) -> Result<LinuxSllHeader, err::linux_sll::HeaderError> {
+        let arp_hrd_type = ArpHardwareId::from(sll_hatype);
+        Ok(LinuxSllHeader {
+            packet_type: LinuxSllPacketType::try_from(u16::from(sll_pkttype))?,
+            arp_hrd_type,
+            sender_address_valid_length: u16::from(sll_halen),
+            sender_address: sll_addr,
+            protocol_type: LinuxSllProtocolType::try_from((arp_hrd_type, sll_protocol))?,
+        })
+    }
+
     /// Reads an SLL header from the current position of the read argument.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -70,7 +122,7 @@ impl LinuxSllHeader {
 
         Ok(
             // SAFETY: Safe as the buffer contains exactly the needed LinuxSllHeader::LEN bytes.
-            unsafe { LinuxSllHeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
+            unchecked! { LinuxSllHeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
         )
     }
 
@@ -107,6 +159,67 @@ impl LinuxSllHeader {
         Self::LEN
     }
 
+    /// Returns true if the packet was sent by the capturing host.
+    #[inline]
+    pub fn is_outgoing(&self) -> bool {
+        self.packet_type.is_outgoing()
+    }
+
+    /// Converts the header to an Ethernet II header with the given
+    /// destination MAC address (e.g. to re-emit packets captured on the
+    /// Linux "any" device on an ethernet interface).
+    ///
+    /// The ether type is taken from the protocol type
+    /// ([`LinuxSllProtocolType::EtherType`] or the protocol type of GRE
+    /// devices, which is an ether type as well). The source MAC address
+    /// is taken from the sender address if the hardware type is
+    /// [`ArpHardwareId::ETHER`] & the address is 6 bytes long, otherwise it
+    /// is set to zero.
+    ///
+    /// `None` is returned if the protocol type can not be represented as an
+    /// ether type (Linux non standard ether types like `ETH_P_802_2`,
+    /// netlink & ignored protocol types).
+    ///
+    /// ```
+    /// use etherparse::{ArpHardwareId, EtherType, LinuxSllHeader, LinuxSllPacketType, LinuxSllProtocolType};
+    ///
+    /// let sll = LinuxSllHeader {
+    ///     packet_type: LinuxSllPacketType::HOST,
+    ///     arp_hrd_type: ArpHardwareId::ETHER,
+    ///     sender_address_valid_length: 6,
+    ///     sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+    ///     protocol_type: LinuxSllProtocolType::EtherType(EtherType::IPV6),
+    /// };
+    /// let eth = sll.to_ethernet2([7, 8, 9, 10, 11, 12]).unwrap();
+    ///
+    /// assert_eq!([1, 2, 3, 4, 5, 6], eth.source);
+    /// assert_eq!([7, 8, 9, 10, 11, 12], eth.destination);
+    /// assert_eq!(EtherType::IPV6, eth.ether_type);
+    /// ```
+    pub fn to_ethernet2(&self, destination: [u8; 6]) -> Option<Ethernet2Header> {
+        let ether_type = match self.protocol_type {
+            LinuxSllProtocolType::EtherType(ether_type) => ether_type,
+            LinuxSllProtocolType::GenericRoutingEncapsulationProtocolType(value) => {
+                EtherType(value)
+            }
+            LinuxSllProtocolType::LinuxNonstandardEtherType(_)
+            | LinuxSllProtocolType::Ignored(_)
+            | LinuxSllProtocolType::NetlinkProtocolType(_) => return None,
+        };
+        let source =
+            if self.arp_hrd_type == ArpHardwareId::ETHER && 6 == self.sender_address_valid_length {
+                let a = &self.sender_address;
+                [a[0], a[1], a[2], a[3], a[4], a[5]]
+            } else {
+                [0; 6]
+            };
+        Some(Ethernet2Header {
+            source,
+            destination,
+            ether_type,
+        })
+    }
+
     /// Returns the serialized form of the header as a statically
     /// sized byte array.
     #[inline]
@@ -146,6 +259,80 @@ mod test {
     use proptest::prelude::*;
     use std::io::{Cursor, ErrorKind};
 
+    proptest! {
+        #[test]
+        fn to_ethernet2(
+            input in linux_sll_any(),
+            destination in any::<[u8;6]>()
+        ) {
+            let expected_ether_type = match input.protocol_type {
+                LinuxSllProtocolType::EtherType(e) => Some(e),
+                LinuxSllProtocolType::GenericRoutingEncapsulationProtocolType(v) => Some(EtherType(v)),
+                _ => None,
+            };
+            let actual = input.to_ethernet2(destination);
+            if let Some(ether_type) = expected_ether_type {
+                let actual = actual.unwrap();
+                assert_eq!(ether_type, actual.ether_type);
+                assert_eq!(destination, actual.destination);
+                if input.arp_hrd_type == ArpHardwareId::ETHER && 6 == input.sender_address_valid_length {
+                    assert_eq!(&input.sender_address[..6], &actual.source);
+                } else {
+                    assert_eq!([0; 6], actual.source);
+                }
+            } else {
+                assert_eq!(None, actual);
+            }
+        }
+    }
+
+    #[test]
+    fn to_ethernet2_source() {
+        let mut sll = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::HOST,
+            arp_hrd_type: ArpHardwareId::ETHER,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 7, 8],
+            protocol_type: LinuxSllProtocolType::EtherType(EtherType::IPV4),
+        };
+        assert_eq!(
+            Some(Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [9; 6],
+                ether_type: EtherType::IPV4,
+            }),
+            sll.to_ethernet2([9; 6])
+        );
+
+        // address length differs from a mac address
+        sll.sender_address_valid_length = 4;
+        assert_eq!([0; 6], sll.to_ethernet2([9; 6]).unwrap().source);
+
+        // gre device (sender address is an ipv4 address)
+        sll.arp_hrd_type = ArpHardwareId::IPGRE;
+        sll.protocol_type =
+            LinuxSllProtocolType::GenericRoutingEncapsulationProtocolType(EtherType::IPV6.0);
+        assert_eq!(
+            Some(Ethernet2Header {
+                source: [0; 6],
+                destination: [9; 6],
+                ether_type: EtherType::IPV6,
+            }),
+            sll.to_ethernet2([9; 6])
+        );
+
+        // not representable
+        sll.arp_hrd_type = ArpHardwareId::ETHER;
+        sll.protocol_type = LinuxSllProtocolType::LinuxNonstandardEtherType(
+            crate::LinuxNonstandardEtherType::N802_2,
+        );
+        assert_eq!(None, sll.to_ethernet2([9; 6]));
+
+        sll.arp_hrd_type = ArpHardwareId::NETLINK;
+        sll.protocol_type = LinuxSllProtocolType::NetlinkProtocolType(0);
+        assert_eq!(None, sll.to_ethernet2([9; 6]));
+    }
+
     proptest! {
         #[test]
         fn from_slice(
@@ -190,6 +377,44 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_sockaddr_ll(input in linux_sll_any()) {
+            let halen = (input.sender_address_valid_length & 0xff) as u8;
+            let actual = LinuxSllHeader::from_sockaddr_ll(
+                u16::from(input.protocol_type),
+                u16::from(input.arp_hrd_type),
+                u16::from(input.packet_type) as u8,
+                halen,
+                input.sender_address,
+            ).unwrap();
+            assert_eq!(
+                LinuxSllHeader {
+                    sender_address_valid_length: u16::from(halen),
+                    ..input.clone()
+                },
+                actual
+            );
+            assert_eq!(input.packet_type.is_outgoing(), actual.is_outgoing());
+        }
+    }
+
+    #[test]
+    fn from_sockaddr_ll_errors() {
+        use err::linux_sll::HeaderError::*;
+        assert_eq!(
+            LinuxSllHeader::from_sockaddr_ll(0x0800, 1, 8, 6, [0; 8]).unwrap_err(),
+            UnsupportedPacketTypeField { packet_type: 8 }
+        );
+        assert_eq!(
+            LinuxSllHeader::from_sockaddr_ll(0x0800, ArpHardwareId::LOOPBACK.into(), 0, 0, [0; 8])
+                .unwrap_err(),
+            UnsupportedArpHardwareId {
+                arp_hardware_type: ArpHardwareId::LOOPBACK
+            }
+        );
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -1,5 +1,5 @@
 use crate::*;
-use core::{cmp::min, slice::from_raw_parts};
+use core::cmp::min;
 
 ///A slice containing an Linux Cooked Capture (SLL) header of a network package.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -28,7 +28,7 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as it is checked at the start of the function that the
         // length of the slice is at least LinuxSllHeader::LEN (16).
-        let packet_type_val = unsafe { get_unchecked_be_u16(slice.as_ptr()) };
+        let packet_type_val = unchecked! { get_unchecked_be_u16(slice, 0) };
         if let Err(err) = LinuxSllPacketType::try_from(packet_type_val) {
             return Err(err::linux_sll::HeaderSliceError::Content(err));
         }
@@ -38,13 +38,13 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as it is checked at the start of the function that the
         // length of the slice is at least LinuxSllHeader::LEN (16).
-        let arp_hardware_id = unsafe { get_unchecked_be_u16(slice.as_ptr().add(2)) };
+        let arp_hardware_id = unchecked! { get_unchecked_be_u16(slice, 2) };
         let arp_hardware_id = ArpHardwareId::from(arp_hardware_id);
 
         // SAFETY:
         // Safe as it is checked at the start of the function that the
         // length of the slice is at least LinuxSllHeader::LEN (16).
-        let protocol_type = unsafe { get_unchecked_be_u16(slice.as_ptr().add(14)) };
+        let protocol_type = unchecked! { get_unchecked_be_u16(slice, 14) };
 
         if let Err(err) = LinuxSllProtocolType::try_from((arp_hardware_id, protocol_type)) {
             return Err(err::linux_sll::HeaderSliceError::Content(err));
@@ -55,26 +55,28 @@ impl<'a> LinuxSllHeaderSlice<'a> {
             // SAFETY:
             // Safe as slice length is checked to be at least
             // LinuxSllHeader::LEN (16) before this.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), LinuxSllHeader::LEN) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, LinuxSllHeader::LEN) },
         })
     }
 
-    /// Converts the given slice into a SLL header slice WITHOUT any checks to
-    /// ensure that the data present is an sll header or that the slice length
-    /// is matching the header length.
-    ///
-    /// If you are not sure what this means, use [`LinuxSllHeaderSlice::from_slice`]
-    /// instead.
-    ///
-    /// # Safety
-    ///
-    /// The caller must ensured that the given slice has the length of
-    /// [`LinuxSllHeader::LEN`] and the fields are valid
-    #[inline]
-    #[cfg(feature = "std")]
-    pub(crate) unsafe fn from_slice_unchecked(slice: &[u8]) -> LinuxSllHeaderSlice {
-        debug_assert!(slice.len() == LinuxSllHeader::LEN);
-        LinuxSllHeaderSlice { slice }
+    unchecked_fn! {
+        /// Converts the given slice into a SLL header slice WITHOUT any checks to
+        /// ensure that the data present is an sll header or that the slice length
+        /// is matching the header length.
+        ///
+        /// If you are not sure what this means, use [`LinuxSllHeaderSlice::from_slice`]
+        /// instead.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensured that the given slice has the length of
+        /// [`LinuxSllHeader::LEN`] and the fields are valid
+        #[inline]
+        #[cfg(feature = "std")]
+        pub(crate) fn from_slice_unchecked(slice: &[u8]) -> LinuxSllHeaderSlice {
+            debug_assert!(slice.len() == LinuxSllHeader::LEN);
+            LinuxSllHeaderSlice { slice }
+        }
     }
 
     /// Returns the slice containing the SLL header
@@ -89,11 +91,11 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of LinuxSllHeader::LEN (16).
-        let packet_type_raw = unsafe { get_unchecked_be_u16(self.slice.as_ptr()) };
+        let packet_type_raw = unchecked! { get_unchecked_be_u16(self.slice, 0) };
 
         // SAFETY:
         // Safe as the constructor checks that the packet type is valid
-        unsafe { LinuxSllPacketType::try_from(packet_type_raw).unwrap_unchecked() }
+        unchecked! { unwrap_unchecked(LinuxSllPacketType::try_from(packet_type_raw)) }
     }
 
     /// Read the arp hardware type field
@@ -102,7 +104,7 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of LinuxSllHeader::LEN (16).
-        let arp_hardware_type_raw = unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) };
+        let arp_hardware_type_raw = unchecked! { get_unchecked_be_u16(self.slice, 2) };
 
         ArpHardwareId::from(arp_hardware_type_raw)
     }
@@ -113,7 +115,7 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of LinuxSllHeader::LEN (16).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 4) }
     }
 
     /// Read the link layer address field. Only the first
@@ -123,7 +125,7 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of LinuxSllHeader::LEN (16).
-        unsafe { get_unchecked_8_byte_array(self.slice.as_ptr().add(6)) }
+        unchecked! { get_unchecked_array(self.slice, 6) }
     }
 
     /// Get the meaningful bytes of the slice of the link layer address
@@ -140,12 +142,15 @@ impl<'a> LinuxSllHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of LinuxSllHeader::LEN (16).
-        let protocol_type_raw = unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(14)) };
+        let protocol_type_raw = unchecked! { get_unchecked_be_u16(self.slice, 14) };
 
         // SAFETY:
         // Safe as the constructor checks that the arphwd + protocol are supported
-        unsafe {
-            LinuxSllProtocolType::try_from((arp_harware_type, protocol_type_raw)).unwrap_unchecked()
+        unchecked! {
+            unwrap_unchecked(LinuxSllProtocolType::try_from((
+                arp_harware_type,
+                protocol_type_raw,
+            )))
         }
     }
 
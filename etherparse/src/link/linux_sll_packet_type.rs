@@ -1,6 +1,9 @@
-use core::hint::unreachable_unchecked;
+use crate::unreachable_unchecked;
 
-use crate::err::{self};
+use crate::{
+    err::{self},
+    PacketDirection,
+};
 
 /// Represents an "Packet type", indicating the direction where it was sent,
 /// used inside a SLL header
@@ -36,6 +39,48 @@ impl LinuxSllPacketType {
 
     pub const MAX_VAL: u16 = 7;
     const FIRST_INVALID: u16 = LinuxSllPacketType::MAX_VAL + 1;
+
+    /// Returns true if the packet was sent by the capturing host
+    /// ([`LinuxSllPacketType::OUTGOING`]).
+    #[inline]
+    pub const fn is_outgoing(&self) -> bool {
+        self.0 == LinuxSllPacketType::OUTGOING.0
+    }
+
+    /// Returns true if the packet was received by the capturing host
+    /// ([`LinuxSllPacketType::HOST`], [`LinuxSllPacketType::BROADCAST`],
+    /// [`LinuxSllPacketType::MULTICAST`] or [`LinuxSllPacketType::OTHERHOST`]).
+    #[inline]
+    pub const fn is_incoming(&self) -> bool {
+        self.0 <= LinuxSllPacketType::OTHERHOST.0
+    }
+
+    /// Returns the direction of the packet relative to the capturing host
+    /// (`None` for [`LinuxSllPacketType::USER`] &
+    /// [`LinuxSllPacketType::KERNEL`] as they have no direction).
+    #[inline]
+    pub const fn direction(&self) -> Option<PacketDirection> {
+        match self.0 {
+            0..=3 => Some(PacketDirection::Inbound),
+            4 => Some(PacketDirection::Outbound),
+            5 => Some(PacketDirection::Loopback),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the packet was sent to all hosts
+    /// ([`LinuxSllPacketType::BROADCAST`]).
+    #[inline]
+    pub const fn is_broadcast(&self) -> bool {
+        self.0 == LinuxSllPacketType::BROADCAST.0
+    }
+
+    /// Returns true if the packet was sent to a group of hosts
+    /// ([`LinuxSllPacketType::MULTICAST`]).
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        self.0 == LinuxSllPacketType::MULTICAST.0
+    }
 }
 
 impl TryFrom<u16> for LinuxSllPacketType {
@@ -81,7 +126,7 @@ impl core::fmt::Debug for LinuxSllPacketType {
             LinuxSllPacketType::FIRST_INVALID..=u16::MAX => {
                 // SAFETY:
                 // Safe because values over MAX_VAL/FIRST_INVALID are never constructed
-                unsafe { unreachable_unchecked() }
+                unchecked! { unreachable_unchecked() }
             }
         }
     }
@@ -148,6 +193,46 @@ mod test {
         );
     }
 
+    #[test]
+    fn is_outgoing_incoming_broadcast_multicast() {
+        use LinuxSllPacketType as P;
+        let tests = [
+            (P::HOST, false, true, false, false),
+            (P::BROADCAST, false, true, true, false),
+            (P::MULTICAST, false, true, false, true),
+            (P::OTHERHOST, false, true, false, false),
+            (P::OUTGOING, true, false, false, false),
+            (P::LOOPBACK, false, false, false, false),
+            (P::USER, false, false, false, false),
+            (P::KERNEL, false, false, false, false),
+        ];
+        for (t, outgoing, incoming, broadcast, multicast) in tests {
+            assert_eq!(outgoing, t.is_outgoing());
+            assert_eq!(incoming, t.is_incoming());
+            assert_eq!(broadcast, t.is_broadcast());
+            assert_eq!(multicast, t.is_multicast());
+        }
+    }
+
+    #[test]
+    fn direction() {
+        use LinuxSllPacketType as P;
+        use PacketDirection::*;
+        let tests = [
+            (P::HOST, Some(Inbound)),
+            (P::BROADCAST, Some(Inbound)),
+            (P::MULTICAST, Some(Inbound)),
+            (P::OTHERHOST, Some(Inbound)),
+            (P::OUTGOING, Some(Outbound)),
+            (P::LOOPBACK, Some(Loopback)),
+            (P::USER, None),
+            (P::KERNEL, None),
+        ];
+        for (t, expected) in tests {
+            assert_eq!(expected, t.direction());
+        }
+    }
+
     #[test]
     fn dbg() {
         let pairs = &[
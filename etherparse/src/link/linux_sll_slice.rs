@@ -1,7 +1,7 @@
 use crate::{
     err::{self, Layer},
-    ArpHardwareId, LenSource, LinuxSllHeader, LinuxSllHeaderSlice, LinuxSllPacketType,
-    LinuxSllPayloadSlice, LinuxSllProtocolType,
+    get_unchecked_slice, ArpHardwareId, LenSource, LinuxSllHeader, LinuxSllHeaderSlice,
+    LinuxSllPacketType, LinuxSllPayloadSlice, LinuxSllProtocolType,
 };
 
 /// Slice containing a Linux Cooked Capture v1 (SLL) header & payload.
@@ -51,6 +51,12 @@ impl<'a> LinuxSllSlice<'a> {
         self.header_slice.packet_type()
     }
 
+    /// Returns true if the packet was sent by the capturing host.
+    #[inline]
+    pub fn is_outgoing(&self) -> bool {
+        self.packet_type().is_outgoing()
+    }
+
     /// Read the arp hardware type field from the header
     #[inline]
     pub fn arp_hardware_type(&self) -> ArpHardwareId {
@@ -115,11 +121,10 @@ impl<'a> LinuxSllSlice<'a> {
     pub fn payload_slice(&self) -> &'a [u8] {
         // SAFETY: Safe as the slice length was verified to be at least
         // LinuxSllHeader::LEN by "from_slice".
-        unsafe {
-            core::slice::from_raw_parts(
-                self.header_and_payload_slice
-                    .as_ptr()
-                    .add(LinuxSllHeader::LEN),
+        unchecked! {
+            get_unchecked_slice(
+                self.header_and_payload_slice,
+                LinuxSllHeader::LEN,
                 self.header_and_payload_slice.len() - LinuxSllHeader::LEN,
             )
         }
@@ -190,6 +195,7 @@ mod test {
 
             let slice = LinuxSllSlice::from_slice(&data).unwrap();
             assert_eq!(linux_sll.packet_type, slice.packet_type());
+            assert_eq!(linux_sll.is_outgoing(), slice.is_outgoing());
             assert_eq!(linux_sll.arp_hrd_type, slice.arp_hardware_type());
             assert_eq!(linux_sll.sender_address_valid_length, slice.sender_address_valid_length());
             assert_eq!(linux_sll.sender_address, slice.sender_address_full());
@@ -0,0 +1,122 @@
+/// Value of an organizationally specific LLDP TLV (TLV type 127)
+/// consisting of an OUI, a subtype & the organization defined payload.
+///
+/// Organizationally specific TLVs are used to transport extensions like
+/// the IEEE 802.1, IEEE 802.3 or LLDP-MED (ANSI/TIA-1057) TLVs as well
+/// as custom vendor specific capabilities.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LldpOrgTlv<'a> {
+    /// Organizationally unique identifier (OUI) of the organization
+    /// defining the TLV.
+    pub oui: [u8; 3],
+    /// Organization defined subtype.
+    pub subtype: u8,
+    /// Organization defined payload following the OUI & subtype.
+    pub payload: &'a [u8],
+}
+
+impl<'a> LldpOrgTlv<'a> {
+    /// OUI of the IEEE 802.1 organizationally specific TLVs.
+    pub const OUI_IEEE_802_1: [u8; 3] = [0x00, 0x80, 0xC2];
+
+    /// OUI of the IEEE 802.3 organizationally specific TLVs.
+    pub const OUI_IEEE_802_3: [u8; 3] = [0x00, 0x12, 0x0F];
+
+    /// OUI of the TIA used by the LLDP-MED (Media Endpoint Discovery,
+    /// ANSI/TIA-1057) TLVs.
+    pub const OUI_TIA: [u8; 3] = [0x00, 0x12, 0xBB];
+
+    /// Length of the OUI & subtype in bytes/octets preceding the payload.
+    pub const HEADER_LEN: usize = 4;
+
+    /// Maximum length of the payload in bytes/octets (maximum TLV value
+    /// length minus [`LldpOrgTlv::HEADER_LEN`]).
+    pub const MAX_PAYLOAD_LEN: usize = crate::LldpTlv::MAX_VALUE_LEN - LldpOrgTlv::HEADER_LEN;
+
+    /// Decodes the value of an organizationally specific TLV (returns
+    /// `None` if the value is too short to contain an OUI & subtype).
+    pub fn from_value(value: &'a [u8]) -> Option<LldpOrgTlv<'a>> {
+        if value.len() < LldpOrgTlv::HEADER_LEN {
+            None
+        } else {
+            Some(LldpOrgTlv {
+                oui: [value[0], value[1], value[2]],
+                subtype: value[3],
+                payload: &value[LldpOrgTlv::HEADER_LEN..],
+            })
+        }
+    }
+
+    /// Length of the TLV value (OUI, subtype & payload) in bytes/octets.
+    #[inline]
+    pub fn value_len(&self) -> usize {
+        LldpOrgTlv::HEADER_LEN + self.payload.len()
+    }
+
+    /// Returns true if the TLV is an LLDP-MED TLV (OUI is [`LldpOrgTlv::OUI_TIA`]).
+    #[inline]
+    pub fn is_lldp_med(&self) -> bool {
+        self.oui == LldpOrgTlv::OUI_TIA
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_value() {
+        // too short
+        for len in 0..LldpOrgTlv::HEADER_LEN {
+            assert_eq!(None, LldpOrgTlv::from_value(&[1, 2, 3, 4][..len]));
+        }
+        // no payload
+        assert_eq!(
+            Some(LldpOrgTlv {
+                oui: [1, 2, 3],
+                subtype: 4,
+                payload: &[],
+            }),
+            LldpOrgTlv::from_value(&[1, 2, 3, 4])
+        );
+        // with payload
+        let tlv = LldpOrgTlv::from_value(&[0x00, 0x12, 0xBB, 1, 5, 6]).unwrap();
+        assert_eq!(LldpOrgTlv::OUI_TIA, tlv.oui);
+        assert_eq!(1, tlv.subtype);
+        assert_eq!(&[5, 6], tlv.payload);
+        assert_eq!(6, tlv.value_len());
+        assert!(tlv.is_lldp_med());
+    }
+
+    #[test]
+    fn is_lldp_med() {
+        let tests = [
+            (LldpOrgTlv::OUI_IEEE_802_1, false),
+            (LldpOrgTlv::OUI_IEEE_802_3, false),
+            (LldpOrgTlv::OUI_TIA, true),
+        ];
+        for (oui, expected) in tests {
+            let tlv = LldpOrgTlv {
+                oui,
+                subtype: 1,
+                payload: &[],
+            };
+            assert_eq!(expected, tlv.is_lldp_med());
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let tlv = LldpOrgTlv {
+            oui: [1, 2, 3],
+            subtype: 4,
+            payload: &[5],
+        };
+        assert_eq!(tlv, tlv.clone());
+        assert_eq!(
+            "LldpOrgTlv { oui: [1, 2, 3], subtype: 4, payload: [5] }",
+            format!("{:?}", tlv)
+        );
+    }
+}
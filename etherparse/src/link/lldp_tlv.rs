@@ -0,0 +1,416 @@
+use crate::{
+    err::{ValueTooBigError, ValueType},
+    *,
+};
+
+/// TLV (type, length & value) of an LLDP (Link Layer Discovery Protocol,
+/// IEEE 802.1AB) data unit.
+///
+/// LLDP data units are transported directly via Ethernet (ether type
+/// [`EtherType::LLDP`]) and consist of a list of TLVs terminated by an
+/// "End Of LLDPDU" TLV. Use [`LldpTlvIter`] to iterate over the TLVs of
+/// a received data unit & [`LldpTlv::write_lldpdu`] to compose one:
+///
+/// ```
+/// use etherparse::{LldpOrgTlv, LldpTlv, LldpTlvIter, LldpTlvType};
+///
+/// let tlvs = [
+///     LldpTlv::Raw {
+///         tlv_type: LldpTlvType::CHASSIS_ID,
+///         value: &[4, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01],
+///     },
+///     LldpTlv::Raw {
+///         tlv_type: LldpTlvType::PORT_ID,
+///         value: &[7, b'e', b't', b'h', b'0'],
+///     },
+///     LldpTlv::Raw {
+///         tlv_type: LldpTlvType::TIME_TO_LIVE,
+///         value: &[0, 120],
+///     },
+///     // custom capability advertised via an organizationally specific TLV
+///     LldpTlv::OrganizationallySpecific(LldpOrgTlv {
+///         oui: [0x12, 0x34, 0x56],
+///         subtype: 1,
+///         payload: &[1, 2, 3],
+///     }),
+/// ];
+///
+/// let mut lldpdu = Vec::new();
+/// LldpTlv::write_lldpdu(&mut lldpdu, &tlvs).unwrap();
+///
+/// let decoded: Vec<LldpTlv> = LldpTlvIter::from_slice(&lldpdu).collect();
+/// assert_eq!(&tlvs[..], &decoded[..]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LldpTlv<'a> {
+    /// TLV with a not further decoded value.
+    Raw {
+        /// Type of the TLV.
+        tlv_type: LldpTlvType,
+        /// Value of the TLV (without the type & length bytes).
+        value: &'a [u8],
+    },
+    /// Organizationally specific TLV (type
+    /// [`LldpTlvType::ORGANIZATIONALLY_SPECIFIC`]).
+    OrganizationallySpecific(LldpOrgTlv<'a>),
+}
+
+impl<'a> LldpTlv<'a> {
+    /// Length of the type & length fields in bytes/octets.
+    pub const HEADER_LEN: usize = 2;
+
+    /// Maximum length of a TLV value in bytes/octets (limited by the 9
+    /// bit length field).
+    pub const MAX_VALUE_LEN: usize = 0b1_1111_1111;
+
+    /// Read a TLV from a slice and return the TLV & the rest of the slice.
+    ///
+    /// TLVs with the type [`LldpTlvType::ORGANIZATIONALLY_SPECIFIC`] and a
+    /// value containing at least an OUI & subtype are decoded as
+    /// [`LldpTlv::OrganizationallySpecific`].
+    pub fn from_slice(slice: &'a [u8]) -> Result<(LldpTlv<'a>, &'a [u8]), err::LenError> {
+        if slice.len() < LldpTlv::HEADER_LEN {
+            return Err(err::LenError {
+                required_len: LldpTlv::HEADER_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::LldpTlv,
+                layer_start_offset: 0,
+            });
+        }
+        let tlv_type = LldpTlvType(slice[0] >> 1);
+        let value_len = (usize::from(slice[0] & 1) << 8) | usize::from(slice[1]);
+        let total_len = LldpTlv::HEADER_LEN + value_len;
+        if slice.len() < total_len {
+            return Err(err::LenError {
+                required_len: total_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::LldpTlv,
+                layer_start_offset: 0,
+            });
+        }
+        let value = &slice[LldpTlv::HEADER_LEN..total_len];
+        let tlv = if tlv_type == LldpTlvType::ORGANIZATIONALLY_SPECIFIC {
+            match LldpOrgTlv::from_value(value) {
+                Some(org) => LldpTlv::OrganizationallySpecific(org),
+                None => LldpTlv::Raw { tlv_type, value },
+            }
+        } else {
+            LldpTlv::Raw { tlv_type, value }
+        };
+        Ok((tlv, &slice[total_len..]))
+    }
+
+    /// Type of the TLV.
+    #[inline]
+    pub fn tlv_type(&self) -> LldpTlvType {
+        match self {
+            LldpTlv::Raw { tlv_type, .. } => *tlv_type,
+            LldpTlv::OrganizationallySpecific(_) => LldpTlvType::ORGANIZATIONALLY_SPECIFIC,
+        }
+    }
+
+    /// Length of the TLV value in bytes/octets.
+    #[inline]
+    pub fn value_len(&self) -> usize {
+        match self {
+            LldpTlv::Raw { value, .. } => value.len(),
+            LldpTlv::OrganizationallySpecific(org) => org.value_len(),
+        }
+    }
+
+    /// Length of the serialized TLV (type, length & value) in bytes/octets.
+    #[inline]
+    pub fn tlv_len(&self) -> usize {
+        LldpTlv::HEADER_LEN + self.value_len()
+    }
+
+    /// Returns the serialized type & length fields of the TLV or an error
+    /// if the value is longer then [`LldpTlv::MAX_VALUE_LEN`].
+    ///
+    /// Only the lower 7 bits of the TLV type are serialized.
+    pub fn header_bytes(&self) -> Result<[u8; 2], ValueTooBigError<usize>> {
+        let value_len = self.value_len();
+        if value_len > LldpTlv::MAX_VALUE_LEN {
+            return Err(ValueTooBigError {
+                actual: value_len,
+                max_allowed: LldpTlv::MAX_VALUE_LEN,
+                value_type: ValueType::LldpTlvValueLength,
+            });
+        }
+        Ok([
+            (self.tlv_type().0 << 1) | ((value_len >> 8) as u8),
+            (value_len & 0xff) as u8,
+        ])
+    }
+
+    /// Writes the TLV to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(
+        &self,
+        writer: &mut T,
+    ) -> Result<(), err::lldp::TlvWriteError> {
+        use err::lldp::TlvWriteError::*;
+        let header = self.header_bytes().map_err(Content)?;
+        writer.write_all(&header).map_err(Io)?;
+        match self {
+            LldpTlv::Raw { value, .. } => writer.write_all(value).map_err(Io),
+            LldpTlv::OrganizationallySpecific(org) => {
+                writer
+                    .write_all(&[org.oui[0], org.oui[1], org.oui[2], org.subtype])
+                    .map_err(Io)?;
+                writer.write_all(org.payload).map_err(Io)
+            }
+        }
+    }
+
+    /// Writes an LLDP data unit consisting of the given TLVs followed by
+    /// an "End Of LLDPDU" TLV (which should therefore not be part of `tlvs`).
+    ///
+    /// All TLVs are validated before anything is written, so nothing is
+    /// written if one of the TLV values is too long.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_lldpdu<T: std::io::Write + Sized>(
+        writer: &mut T,
+        tlvs: &[LldpTlv<'_>],
+    ) -> Result<(), err::lldp::TlvWriteError> {
+        use err::lldp::TlvWriteError::*;
+        for tlv in tlvs {
+            tlv.header_bytes().map_err(Content)?;
+        }
+        for tlv in tlvs {
+            tlv.write(writer)?;
+        }
+        // end of LLDPDU tlv (type 0 & length 0)
+        writer.write_all(&[0, 0]).map_err(Io)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn constants() {
+        assert_eq!(2, LldpTlv::HEADER_LEN);
+        assert_eq!(511, LldpTlv::MAX_VALUE_LEN);
+        assert_eq!(507, LldpOrgTlv::MAX_PAYLOAD_LEN);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_write(
+            tlv_type in 0u8..0x80,
+            value in proptest::collection::vec(any::<u8>(), 0..600),
+            rest in proptest::collection::vec(any::<u8>(), 0..4),
+        ) {
+            let value = &value[..value.len().min(LldpTlv::MAX_VALUE_LEN)];
+            let tlv = LldpTlv::Raw { tlv_type: LldpTlvType(tlv_type), value };
+            let expected = if tlv_type == 127 {
+                match LldpOrgTlv::from_value(value) {
+                    Some(org) => LldpTlv::OrganizationallySpecific(org),
+                    None => tlv.clone(),
+                }
+            } else {
+                tlv.clone()
+            };
+
+            let mut buffer = Vec::with_capacity(tlv.tlv_len() + rest.len());
+            tlv.write(&mut buffer).unwrap();
+            assert_eq!(tlv.tlv_len(), buffer.len());
+            assert_eq!(&tlv.header_bytes().unwrap(), &buffer[..2]);
+            buffer.extend_from_slice(&rest);
+
+            // normal
+            {
+                let (actual, actual_rest) = LldpTlv::from_slice(&buffer).unwrap();
+                assert_eq!(expected, actual);
+                assert_eq!(&rest[..], actual_rest);
+                assert_eq!(tlv.tlv_type(), actual.tlv_type());
+                assert_eq!(tlv.value_len(), actual.value_len());
+
+                // write the decoded tlv again
+                let mut rewritten = Vec::new();
+                actual.write(&mut rewritten).unwrap();
+                assert_eq!(&buffer[..tlv.tlv_len()], &rewritten[..]);
+            }
+
+            // length errors
+            for len in 0..tlv.tlv_len() {
+                let required_len = if len < LldpTlv::HEADER_LEN {
+                    LldpTlv::HEADER_LEN
+                } else {
+                    tlv.tlv_len()
+                };
+                assert_eq!(
+                    LldpTlv::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::LldpTlv,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn org_tlv() {
+        let tlv = LldpTlv::OrganizationallySpecific(LldpOrgTlv {
+            oui: LldpOrgTlv::OUI_TIA,
+            subtype: 1,
+            payload: &[0, 0x33, 0x03],
+        });
+        assert_eq!(LldpTlvType::ORGANIZATIONALLY_SPECIFIC, tlv.tlv_type());
+        assert_eq!(7, tlv.value_len());
+        assert_eq!(9, tlv.tlv_len());
+
+        let mut buffer = Vec::new();
+        tlv.write(&mut buffer).unwrap();
+        assert_eq!(&buffer[..], &[0xFE, 7, 0x00, 0x12, 0xBB, 1, 0, 0x33, 0x03]);
+        assert_eq!(tlv, LldpTlv::from_slice(&buffer).unwrap().0);
+
+        // organizationally specific tlv without oui & subtype
+        let short = [0xFE, 3, 1, 2, 3];
+        assert_eq!(
+            LldpTlv::Raw {
+                tlv_type: LldpTlvType::ORGANIZATIONALLY_SPECIFIC,
+                value: &[1, 2, 3]
+            },
+            LldpTlv::from_slice(&short).unwrap().0
+        );
+    }
+
+    #[test]
+    fn header_bytes() {
+        let value = [0u8; LldpTlv::MAX_VALUE_LEN + 1];
+        // max length
+        assert_eq!(
+            Ok([0x0B, 0xFF]),
+            LldpTlv::Raw {
+                tlv_type: LldpTlvType::SYSTEM_NAME,
+                value: &value[..LldpTlv::MAX_VALUE_LEN],
+            }
+            .header_bytes()
+        );
+        // only the lower 7 bits of the type are serialized
+        assert_eq!(
+            Ok([0xFE, 0x00]),
+            LldpTlv::Raw {
+                tlv_type: LldpTlvType(0xFF),
+                value: &[],
+            }
+            .header_bytes()
+        );
+        // value too big
+        let expected = ValueTooBigError {
+            actual: LldpTlv::MAX_VALUE_LEN + 1,
+            max_allowed: LldpTlv::MAX_VALUE_LEN,
+            value_type: ValueType::LldpTlvValueLength,
+        };
+        assert_eq!(
+            Err(expected.clone()),
+            LldpTlv::Raw {
+                tlv_type: LldpTlvType::SYSTEM_NAME,
+                value: &value,
+            }
+            .header_bytes()
+        );
+        assert_eq!(
+            Err(expected),
+            LldpTlv::OrganizationallySpecific(LldpOrgTlv {
+                oui: [1, 2, 3],
+                subtype: 4,
+                payload: &value[..LldpOrgTlv::MAX_PAYLOAD_LEN + 1],
+            })
+            .header_bytes()
+        );
+    }
+
+    #[test]
+    fn write_errors() {
+        let value = [0u8; LldpTlv::MAX_VALUE_LEN + 1];
+        let too_big = LldpTlv::Raw {
+            tlv_type: LldpTlvType::SYSTEM_NAME,
+            value: &value,
+        };
+        let ok = LldpTlv::Raw {
+            tlv_type: LldpTlvType::SYSTEM_NAME,
+            value: &[1, 2],
+        };
+
+        // content error
+        {
+            let mut buffer = Vec::new();
+            assert!(too_big.write(&mut buffer).unwrap_err().content().is_some());
+            assert!(buffer.is_empty());
+            assert!(LldpTlv::write_lldpdu(&mut buffer, &[ok.clone(), too_big])
+                .unwrap_err()
+                .content()
+                .is_some());
+            assert!(buffer.is_empty());
+        }
+
+        // io errors
+        for len in 0..ok.tlv_len() {
+            let mut buffer = [0u8; 4];
+            let mut cursor = Cursor::new(&mut buffer[..len]);
+            assert!(ok.write(&mut cursor).unwrap_err().io().is_some());
+        }
+        for len in 0..ok.tlv_len() + 2 {
+            let mut buffer = [0u8; 6];
+            let mut cursor = Cursor::new(&mut buffer[..len]);
+            assert!(LldpTlv::write_lldpdu(&mut cursor, &[ok.clone()])
+                .unwrap_err()
+                .io()
+                .is_some());
+        }
+        {
+            let org = LldpTlv::OrganizationallySpecific(LldpOrgTlv {
+                oui: [1, 2, 3],
+                subtype: 4,
+                payload: &[5],
+            });
+            for len in 0..org.tlv_len() {
+                let mut buffer = [0u8; 7];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(org.write(&mut cursor).unwrap_err().io().is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn write_lldpdu() {
+        let mut buffer = Vec::new();
+        LldpTlv::write_lldpdu(
+            &mut buffer,
+            &[LldpTlv::Raw {
+                tlv_type: LldpTlvType::TIME_TO_LIVE,
+                value: &[0, 120],
+            }],
+        )
+        .unwrap();
+        assert_eq!(&buffer[..], &[0x06, 2, 0, 120, 0, 0]);
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let tlv = LldpTlv::Raw {
+            tlv_type: LldpTlvType::PORT_ID,
+            value: &[1],
+        };
+        assert_eq!(tlv, tlv.clone());
+        assert_eq!(
+            format!("Raw {{ tlv_type: {:?}, value: [1] }}", LldpTlvType::PORT_ID),
+            format!("{:?}", tlv)
+        );
+    }
+}
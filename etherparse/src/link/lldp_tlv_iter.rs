@@ -0,0 +1,117 @@
+use crate::*;
+
+/// Iterator over the TLVs of an LLDP data unit (see [`LldpTlv`]).
+///
+/// Iteration stops when the "End Of LLDPDU" TLV (not returned by the
+/// iterator) or an incomplete TLV is encountered. In case of an
+/// incomplete TLV the not decodable data stays available via
+/// [`LldpTlvIter::rest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LldpTlvIter<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> LldpTlvIter<'a> {
+    /// Creates an iterator over the TLVs of the given LLDP data unit.
+    #[inline]
+    pub fn from_slice(lldpdu: &'a [u8]) -> LldpTlvIter<'a> {
+        LldpTlvIter { rest: lldpdu }
+    }
+
+    /// Returns the not yet iterated part of the LLDP data unit (empty
+    /// after the "End Of LLDPDU" TLV was reached).
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for LldpTlvIter<'a> {
+    type Item = LldpTlv<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (tlv, rest) = LldpTlv::from_slice(self.rest).ok()?;
+        if tlv.tlv_type() == LldpTlvType::END_OF_LLDPDU {
+            self.rest = &[];
+            None
+        } else {
+            self.rest = rest;
+            Some(tlv)
+        }
+    }
+}
+
+impl core::iter::FusedIterator for LldpTlvIter<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn next() {
+        // empty
+        {
+            let mut iter = LldpTlvIter::from_slice(&[]);
+            assert_eq!(None, iter.next());
+        }
+        // tlvs, end & padding
+        {
+            let data = [
+                0x06, 2, 0, 120, // time to live
+                0xFE, 5, 0x00, 0x12, 0xBB, 1, 9, // lldp-med
+                0, 0, // end
+                0, 0, 0, // padding
+            ];
+            let mut iter = LldpTlvIter::from_slice(&data);
+            assert_eq!(
+                Some(LldpTlv::Raw {
+                    tlv_type: LldpTlvType::TIME_TO_LIVE,
+                    value: &[0, 120]
+                }),
+                iter.next()
+            );
+            assert_eq!(
+                Some(LldpTlv::OrganizationallySpecific(LldpOrgTlv {
+                    oui: LldpOrgTlv::OUI_TIA,
+                    subtype: 1,
+                    payload: &[9],
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+            assert_eq!(None, iter.next());
+        }
+        // missing end
+        {
+            let tlvs: Vec<_> = LldpTlvIter::from_slice(&[0x0A, 1, b'a']).collect();
+            assert_eq!(
+                tlvs,
+                [LldpTlv::Raw {
+                    tlv_type: LldpTlvType::SYSTEM_NAME,
+                    value: b"a"
+                }]
+            );
+        }
+        // incomplete tlv
+        {
+            let data = [0x0A, 1, b'a', 0x0A, 2, b'b'];
+            let mut iter = LldpTlvIter::from_slice(&data);
+            assert!(iter.next().is_some());
+            assert_eq!(None, iter.next());
+            assert_eq!(&data[3..], iter.rest());
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = LldpTlvIter::from_slice(&[0, 0]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(format!("{:?}", iter), "LldpTlvIter { rest: [0, 0] }");
+    }
+}
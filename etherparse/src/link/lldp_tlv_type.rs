@@ -0,0 +1,102 @@
+/// 7 bit type of an LLDP (Link Layer Discovery Protocol, IEEE 802.1AB) TLV.
+///
+/// Only the lower 7 bits are serialized when writing a [`crate::LldpTlv`].
+///
+/// ```
+/// use etherparse::LldpTlvType;
+///
+/// assert_eq!(LldpTlvType::SYSTEM_NAME, LldpTlvType(5));
+/// assert_eq!("0x05 (System Name)", format!("{:?}", LldpTlvType::SYSTEM_NAME));
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LldpTlvType(pub u8);
+
+impl LldpTlvType {
+    pub const END_OF_LLDPDU: LldpTlvType = LldpTlvType(0);
+    pub const CHASSIS_ID: LldpTlvType = LldpTlvType(1);
+    pub const PORT_ID: LldpTlvType = LldpTlvType(2);
+    pub const TIME_TO_LIVE: LldpTlvType = LldpTlvType(3);
+    pub const PORT_DESCRIPTION: LldpTlvType = LldpTlvType(4);
+    pub const SYSTEM_NAME: LldpTlvType = LldpTlvType(5);
+    pub const SYSTEM_DESCRIPTION: LldpTlvType = LldpTlvType(6);
+    pub const SYSTEM_CAPABILITIES: LldpTlvType = LldpTlvType(7);
+    pub const MANAGEMENT_ADDRESS: LldpTlvType = LldpTlvType(8);
+    pub const ORGANIZATIONALLY_SPECIFIC: LldpTlvType = LldpTlvType(127);
+}
+
+impl From<u8> for LldpTlvType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        LldpTlvType(value)
+    }
+}
+
+impl From<LldpTlvType> for u8 {
+    #[inline]
+    fn from(value: LldpTlvType) -> Self {
+        value.0
+    }
+}
+
+impl core::fmt::Debug for LldpTlvType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::END_OF_LLDPDU => write!(f, "{:#04X} (End Of LLDPDU)", self.0),
+            Self::CHASSIS_ID => write!(f, "{:#04X} (Chassis ID)", self.0),
+            Self::PORT_ID => write!(f, "{:#04X} (Port ID)", self.0),
+            Self::TIME_TO_LIVE => write!(f, "{:#04X} (Time To Live)", self.0),
+            Self::PORT_DESCRIPTION => write!(f, "{:#04X} (Port Description)", self.0),
+            Self::SYSTEM_NAME => write!(f, "{:#04X} (System Name)", self.0),
+            Self::SYSTEM_DESCRIPTION => write!(f, "{:#04X} (System Description)", self.0),
+            Self::SYSTEM_CAPABILITIES => write!(f, "{:#04X} (System Capabilities)", self.0),
+            Self::MANAGEMENT_ADDRESS => write!(f, "{:#04X} (Management Address)", self.0),
+            Self::ORGANIZATIONALLY_SPECIFIC => {
+                write!(f, "{:#04X} (Organizationally Specific)", self.0)
+            }
+            _ => write!(f, "{:#04X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_into() {
+        for value in 0..=u8::MAX {
+            assert_eq!(LldpTlvType(value), LldpTlvType::from(value));
+            assert_eq!(value, u8::from(LldpTlvType(value)));
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: LldpTlvType = Default::default();
+        assert_eq!(LldpTlvType::END_OF_LLDPDU, value);
+        assert_eq!(value, value.clone());
+        let tests = [
+            (LldpTlvType::END_OF_LLDPDU, "0x00 (End Of LLDPDU)"),
+            (LldpTlvType::CHASSIS_ID, "0x01 (Chassis ID)"),
+            (LldpTlvType::PORT_ID, "0x02 (Port ID)"),
+            (LldpTlvType::TIME_TO_LIVE, "0x03 (Time To Live)"),
+            (LldpTlvType::PORT_DESCRIPTION, "0x04 (Port Description)"),
+            (LldpTlvType::SYSTEM_NAME, "0x05 (System Name)"),
+            (LldpTlvType::SYSTEM_DESCRIPTION, "0x06 (System Description)"),
+            (
+                LldpTlvType::SYSTEM_CAPABILITIES,
+                "0x07 (System Capabilities)",
+            ),
+            (LldpTlvType::MANAGEMENT_ADDRESS, "0x08 (Management Address)"),
+            (
+                LldpTlvType::ORGANIZATIONALLY_SPECIFIC,
+                "0x7F (Organizationally Specific)",
+            ),
+            (LldpTlvType(9), "0x09"),
+        ];
+        for (value, expected) in tests {
+            assert_eq!(expected, format!("{:?}", value));
+        }
+    }
+}
@@ -0,0 +1,398 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// IEEE 802.1AE MACsec header ("SecTAG", identified by the ether type
+/// [`EtherType::MACSEC`]).
+///
+/// The SecTAG is followed by the "secure data" & the "integrity check
+/// value" (ICV). If the secure data is neither encrypted nor changed
+/// (see [`MacsecHeader::is_unmodified`]) it starts with the ether type of
+/// the protected payload. Use [`crate::MacsecSlice`] to separate the
+/// secure data from the ICV.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+pub struct MacsecHeader {
+    /// "End station" flag (ES), set if the SCI is derived from the
+    /// source MAC address.
+    pub endstation_id: bool,
+    /// "Single copy broadcast" flag (SCB).
+    pub scb: bool,
+    /// "Encryption" flag (E), set if the secure data is encrypted.
+    pub encrypted: bool,
+    /// "Changed text" flag (C), set if the secure data differs from
+    /// the user data (e.g. because it is encrypted).
+    pub changed: bool,
+    /// Association number (AN) identifying the secure association.
+    pub an: MacsecAn,
+    /// Short length (SL) of the secure data (`0` if the secure data is
+    /// 48 bytes or longer).
+    pub short_len: MacsecShortLen,
+    /// Packet number (PN).
+    pub packet_nr: u32,
+    /// Optional "secure channel identifier" (SCI). If present the "SC"
+    /// flag is set in the serialized header.
+    pub sci: Option<u64>,
+}
+
+impl MacsecHeader {
+    /// Minimum length of a MACsec header (without SCI) in bytes/octets.
+    pub const MIN_LEN: usize = 6;
+
+    /// Maximum length of a MACsec header (with SCI) in bytes/octets.
+    pub const MAX_LEN: usize = 6 + 8;
+
+    /// Version bit (V) in the TCI.
+    const TCI_VERSION: u8 = 0b1000_0000;
+    /// End station bit (ES) in the TCI.
+    const TCI_ES: u8 = 0b0100_0000;
+    /// Secure channel bit (SC) in the TCI.
+    const TCI_SC: u8 = 0b0010_0000;
+    /// Single copy broadcast bit (SCB) in the TCI.
+    const TCI_SCB: u8 = 0b0001_0000;
+    /// Encryption bit (E) in the TCI.
+    const TCI_E: u8 = 0b0000_1000;
+    /// Changed text bit (C) in the TCI.
+    const TCI_C: u8 = 0b0000_0100;
+
+    /// Read a MACsec header (SecTAG) from a slice and return the header
+    /// & the rest of the slice (secure data & ICV).
+    ///
+    /// The reserved bits in front of the short length are ignored.
+    pub fn from_slice(
+        slice: &[u8],
+    ) -> Result<(MacsecHeader, &[u8]), err::macsec::HeaderSliceError> {
+        use err::macsec::{HeaderError::*, HeaderSliceError::*};
+
+        if slice.len() < MacsecHeader::MIN_LEN {
+            return Err(Len(err::LenError {
+                required_len: MacsecHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::MacsecHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let tci = slice[0];
+        if 0 != tci & MacsecHeader::TCI_VERSION {
+            return Err(Content(UnexpectedVersion { version: 1 }));
+        }
+
+        let header_len = if 0 != tci & MacsecHeader::TCI_SC {
+            MacsecHeader::MAX_LEN
+        } else {
+            MacsecHeader::MIN_LEN
+        };
+        if slice.len() < header_len {
+            return Err(Len(err::LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::MacsecHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        Ok((
+            MacsecHeader {
+                endstation_id: 0 != tci & MacsecHeader::TCI_ES,
+                scb: 0 != tci & MacsecHeader::TCI_SCB,
+                encrypted: 0 != tci & MacsecHeader::TCI_E,
+                changed: 0 != tci & MacsecHeader::TCI_C,
+                // SAFETY: Safe as the bitmask limits the value to 2 bits.
+                an: unchecked! { MacsecAn::new_unchecked_internal(tci & 0b0000_0011) },
+                // SAFETY: Safe as the bitmask limits the value to 6 bits.
+                short_len: unchecked! { MacsecShortLen::new_unchecked_internal(slice[1] & 0b0011_1111) },
+                packet_nr: u32::from_be_bytes([slice[2], slice[3], slice[4], slice[5]]),
+                sci: if header_len == MacsecHeader::MAX_LEN {
+                    Some(u64::from_be_bytes([
+                        slice[6], slice[7], slice[8], slice[9], slice[10], slice[11], slice[12],
+                        slice[13],
+                    ]))
+                } else {
+                    None
+                },
+            },
+            &slice[header_len..],
+        ))
+    }
+
+    /// Read a MACsec header (SecTAG).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(
+        reader: &mut T,
+    ) -> Result<MacsecHeader, err::macsec::HeaderReadError> {
+        use err::macsec::HeaderReadError::*;
+
+        let mut buffer = [0u8; MacsecHeader::MAX_LEN];
+        reader
+            .read_exact(&mut buffer[..MacsecHeader::MIN_LEN])
+            .map_err(Io)?;
+        let len = if 0 != buffer[0] & MacsecHeader::TCI_SC {
+            reader
+                .read_exact(&mut buffer[MacsecHeader::MIN_LEN..])
+                .map_err(Io)?;
+            MacsecHeader::MAX_LEN
+        } else {
+            MacsecHeader::MIN_LEN
+        };
+        match MacsecHeader::from_slice(&buffer[..len]) {
+            Ok((header, _)) => Ok(header),
+            Err(err::macsec::HeaderSliceError::Content(err)) => Err(Content(err)),
+            // length errors can not occur as the buffer was filled above
+            Err(err::macsec::HeaderSliceError::Len(_)) => unreachable!(),
+        }
+    }
+
+    /// Write the MACsec header (SecTAG).
+    #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.sci.is_some() {
+            MacsecHeader::MAX_LEN
+        } else {
+            MacsecHeader::MIN_LEN
+        }
+    }
+
+    /// Returns true if the secure data is neither encrypted nor changed
+    /// (in this case the secure data starts with the ether type of the
+    /// protected payload).
+    #[inline]
+    pub fn is_unmodified(&self) -> bool {
+        false == self.encrypted && false == self.changed
+    }
+
+    /// Sets the short length based on the length of the secure data
+    /// (data between the SecTAG & the ICV).
+    ///
+    /// Lengths of [`MacsecShortLen::SECURE_DATA_LEN_LIMIT`] (48) bytes
+    /// or more are indicated with a short length of 0.
+    #[inline]
+    pub fn set_secure_data_len(&mut self, secure_data_len: usize) {
+        self.short_len = if secure_data_len < MacsecShortLen::SECURE_DATA_LEN_LIMIT {
+            // SAFETY: Safe as the value is smaller then 48.
+            unchecked! { MacsecShortLen::new_unchecked_internal(secure_data_len as u8) }
+        } else {
+            MacsecShortLen::ZERO
+        };
+    }
+
+    /// Returns the serialized form of the header (reserved bits are set to 0).
+    pub fn to_bytes(&self) -> ArrayVec<u8, { MacsecHeader::MAX_LEN }> {
+        let flag = |value: bool, bit: u8| if value { bit } else { 0 };
+        let tci = flag(self.endstation_id, MacsecHeader::TCI_ES)
+            | flag(self.sci.is_some(), MacsecHeader::TCI_SC)
+            | flag(self.scb, MacsecHeader::TCI_SCB)
+            | flag(self.encrypted, MacsecHeader::TCI_E)
+            | flag(self.changed, MacsecHeader::TCI_C)
+            | self.an.value();
+        let pn = self.packet_nr.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.extend([tci, self.short_len.value(), pn[0], pn[1], pn[2], pn[3]]);
+        if let Some(sci) = self.sci {
+            result.extend(sci.to_be_bytes());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn constants() {
+        assert_eq!(6, MacsecHeader::MIN_LEN);
+        assert_eq!(14, MacsecHeader::MAX_LEN);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in macsec_any(),
+            reserved in 0u8..=0b11,
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(input.header_len() + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer[1] |= reserved << 6;
+            buffer.extend(&dummy_data[..]);
+
+            // normal
+            {
+                let (result, rest) = MacsecHeader::from_slice(&buffer).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &buffer[input.header_len()..]);
+            }
+
+            // slice length to small
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    MacsecHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::macsec::HeaderSliceError::Len(err::LenError{
+                        required_len: if len < MacsecHeader::MIN_LEN {
+                            MacsecHeader::MIN_LEN
+                        } else {
+                            MacsecHeader::MAX_LEN
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::MacsecHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+
+            // version bit set
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] |= 0b1000_0000;
+                assert_eq!(
+                    MacsecHeader::from_slice(&buffer).unwrap_err(),
+                    err::macsec::HeaderSliceError::Content(
+                        err::macsec::HeaderError::UnexpectedVersion { version: 1 }
+                    )
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in macsec_any()) {
+            // ok
+            {
+                let mut buffer = Vec::new();
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes()[..]);
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, MacsecHeader::read(&mut cursor).unwrap());
+            }
+            // io error
+            {
+                let buffer = input.to_bytes();
+                let mut cursor = Cursor::new(&buffer[..buffer.len() - 1]);
+                assert!(MacsecHeader::read(&mut cursor).unwrap_err().io_error().is_some());
+            }
+            // content error
+            {
+                let mut buffer = input.to_bytes();
+                buffer[0] |= 0b1000_0000;
+                let mut cursor = Cursor::new(&buffer[..]);
+                assert_eq!(
+                    Some(err::macsec::HeaderError::UnexpectedVersion { version: 1 }),
+                    MacsecHeader::read(&mut cursor).unwrap_err().content_error()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        let header = MacsecHeader {
+            endstation_id: true,
+            scb: false,
+            encrypted: true,
+            changed: true,
+            an: MacsecAn::try_new(2).unwrap(),
+            short_len: MacsecShortLen::try_new(20).unwrap(),
+            packet_nr: 0x1234_5678,
+            sci: Some(0x0102_0304_0506_0708),
+        };
+        assert_eq!(
+            &[
+                0b0110_1110,
+                20,
+                0x12,
+                0x34,
+                0x56,
+                0x78,
+                1,
+                2,
+                3,
+                4,
+                5,
+                6,
+                7,
+                8
+            ],
+            &header.to_bytes()[..]
+        );
+        assert_eq!(
+            &[0b0001_0000, 0, 0, 0, 0, 1],
+            &MacsecHeader {
+                scb: true,
+                packet_nr: 1,
+                ..Default::default()
+            }
+            .to_bytes()[..]
+        );
+    }
+
+    #[test]
+    fn header_len() {
+        assert_eq!(6, MacsecHeader::default().header_len());
+        assert_eq!(
+            14,
+            MacsecHeader {
+                sci: Some(1),
+                ..Default::default()
+            }
+            .header_len()
+        );
+    }
+
+    #[test]
+    fn is_unmodified() {
+        for (encrypted, changed) in [(false, false), (false, true), (true, false), (true, true)] {
+            assert_eq!(
+                false == encrypted && false == changed,
+                MacsecHeader {
+                    encrypted,
+                    changed,
+                    ..Default::default()
+                }
+                .is_unmodified()
+            );
+        }
+    }
+
+    #[test]
+    fn set_secure_data_len() {
+        let mut header = MacsecHeader::default();
+        for len in 0..48 {
+            header.set_secure_data_len(len);
+            assert_eq!(len as u8, header.short_len.value());
+        }
+        for len in [48, 49, 1500, usize::MAX] {
+            header.set_secure_data_len(len);
+            assert_eq!(0, header.short_len.value());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let header = MacsecHeader::default();
+        assert_eq!(header, header.clone());
+        assert_eq!(
+            format!(
+                "MacsecHeader {{ endstation_id: false, scb: false, encrypted: false, changed: false, an: {:?}, short_len: {:?}, packet_nr: 0, sci: None }}",
+                MacsecAn::ZERO,
+                MacsecShortLen::ZERO
+            ),
+            format!("{:?}", header)
+        );
+    }
+}
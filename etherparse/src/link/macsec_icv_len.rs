@@ -0,0 +1,45 @@
+/// Length of the "integrity check value" (ICV) trailing the secure data
+/// of a MACsec frame.
+///
+/// The length depends on the used cipher suite & can not be determined
+/// from the frame itself. The default cipher suites (GCM-AES-128,
+/// GCM-AES-256 & their XPN variants) use a 16 byte ICV.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum MacsecIcvLen {
+    /// 8 byte ICV.
+    Len8,
+    /// 16 byte ICV (used by the default cipher suites).
+    #[default]
+    Len16,
+}
+
+impl MacsecIcvLen {
+    /// Returns the length of the ICV in bytes.
+    #[inline]
+    pub const fn byte_len(self) -> usize {
+        match self {
+            MacsecIcvLen::Len8 => 8,
+            MacsecIcvLen::Len16 => 16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn byte_len() {
+        assert_eq!(8, MacsecIcvLen::Len8.byte_len());
+        assert_eq!(16, MacsecIcvLen::Len16.byte_len());
+    }
+
+    #[test]
+    fn default_clone_eq_debug() {
+        let value: MacsecIcvLen = Default::default();
+        assert_eq!(MacsecIcvLen::Len16, value);
+        assert_eq!(value, value.clone());
+        assert_eq!("Len8", format!("{:?}", MacsecIcvLen::Len8));
+    }
+}
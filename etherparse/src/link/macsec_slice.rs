@@ -0,0 +1,263 @@
+use crate::*;
+
+/// Slice containing a MACsec header (SecTAG), the secure data & the
+/// "integrity check value" (ICV).
+///
+/// The length of the ICV depends on the used cipher suite & has to be
+/// passed in via [`MacsecIcvLen`]. The ICV is not part of the
+/// [`MacsecSlice::secure_data`] & [`MacsecSlice::payload`] and can be
+/// accessed via [`MacsecSlice::icv`]:
+///
+/// ```
+/// use etherparse::{EtherType, MacsecIcvLen, MacsecSlice, SlicedPacket};
+///
+/// # let packet = [
+/// #     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x88, 0xE5, // ethernet II
+/// #     0b0000_0000, 6, 0, 0, 0, 1, // SecTAG (unmodified, short length 6, pn 1)
+/// #     0x12, 0x34, 1, 2, 3, 4, // secure data (ether type & payload)
+/// #     9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, // ICV
+/// # ];
+/// let outer = SlicedPacket::from_ethernet(&packet).unwrap();
+/// let ether_payload = outer.link.as_ref().unwrap().ether_payload().unwrap();
+/// assert_eq!(EtherType::MACSEC, ether_payload.ether_type);
+///
+/// let macsec = MacsecSlice::from_slice(ether_payload.payload, MacsecIcvLen::Len16).unwrap();
+/// assert_eq!(1, macsec.header().packet_nr);
+/// assert_eq!(Some(EtherType(0x1234)), macsec.next_ether_type());
+/// assert_eq!(&[1, 2, 3, 4], macsec.payload());
+/// assert_eq!(&[9; 16], macsec.icv());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MacsecSlice<'a> {
+    /// Decoded SecTAG.
+    header: MacsecHeader,
+    /// Slice containing the SecTAG, secure data & ICV (without
+    /// trailing padding).
+    slice: &'a [u8],
+    /// Length of the ICV in bytes.
+    icv_len: usize,
+}
+
+impl<'a> MacsecSlice<'a> {
+    /// Try creating a [`MacsecSlice`] from a slice containing a MACsec
+    /// header (SecTAG), the secure data & the ICV with the given length.
+    ///
+    /// If the "short length" field is set it determines the length of the
+    /// secure data (bytes after the ICV are treated as padding and are
+    /// not part of the slice). Otherwise the ICV is expected at the end
+    /// of the slice.
+    pub fn from_slice(
+        slice: &'a [u8],
+        icv_len: MacsecIcvLen,
+    ) -> Result<MacsecSlice<'a>, err::macsec::HeaderSliceError> {
+        use err::macsec::HeaderSliceError::Len;
+
+        let (header, rest) = MacsecHeader::from_slice(slice)?;
+        let header_len = slice.len() - rest.len();
+        let icv_len = icv_len.byte_len();
+
+        let total_len = if 0 != header.short_len.value() {
+            let required_len = header_len + usize::from(header.short_len.value()) + icv_len;
+            if slice.len() < required_len {
+                return Err(Len(err::LenError {
+                    required_len,
+                    len: slice.len(),
+                    len_source: LenSource::MacsecShortLen,
+                    layer: err::Layer::MacsecHeader,
+                    layer_start_offset: 0,
+                }));
+            }
+            required_len
+        } else {
+            let required_len = header_len + icv_len;
+            if slice.len() < required_len {
+                return Err(Len(err::LenError {
+                    required_len,
+                    len: slice.len(),
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::MacsecHeader,
+                    layer_start_offset: 0,
+                }));
+            }
+            slice.len()
+        };
+
+        Ok(MacsecSlice {
+            header,
+            slice: &slice[..total_len],
+            icv_len,
+        })
+    }
+
+    /// Returns the slice containing the SecTAG, the secure data & the ICV
+    /// (excluding padding after the ICV).
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the decoded MACsec header (SecTAG).
+    #[inline]
+    pub fn header(&self) -> &MacsecHeader {
+        &self.header
+    }
+
+    /// Returns the slice containing only the MACsec header (SecTAG).
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        &self.slice[..self.header.header_len()]
+    }
+
+    /// Returns the "secure data" (all data between the SecTAG & the ICV).
+    ///
+    /// If the secure data is unmodified (see [`MacsecHeader::is_unmodified`])
+    /// it starts with the ether type of the payload.
+    #[inline]
+    pub fn secure_data(&self) -> &'a [u8] {
+        &self.slice[self.header.header_len()..self.slice.len() - self.icv_len]
+    }
+
+    /// Returns the "integrity check value" (ICV) trailing the secure data.
+    #[inline]
+    pub fn icv(&self) -> &'a [u8] {
+        &self.slice[self.slice.len() - self.icv_len..]
+    }
+
+    /// Returns the ether type of the payload if the secure data is
+    /// unmodified (not encrypted & not changed).
+    #[inline]
+    pub fn next_ether_type(&self) -> Option<EtherType> {
+        let secure_data = self.secure_data();
+        if self.header.is_unmodified() && secure_data.len() >= 2 {
+            Some(EtherType(u16::from_be_bytes([
+                secure_data[0],
+                secure_data[1],
+            ])))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the payload of the MACsec frame (excluding the ICV).
+    ///
+    /// If the secure data is unmodified the ether type at the start of
+    /// the secure data is not included (see [`MacsecSlice::next_ether_type`]).
+    /// Otherwise the complete (encrypted or changed) secure data is returned.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        if self.next_ether_type().is_some() {
+            &self.secure_data()[2..]
+        } else {
+            self.secure_data()
+        }
+    }
+
+    /// Returns the payload as an [`EtherPayloadSlice`] if the secure data
+    /// is unmodified (not encrypted & not changed).
+    #[inline]
+    pub fn ether_payload(&self) -> Option<EtherPayloadSlice<'a>> {
+        self.next_ether_type().map(|ether_type| EtherPayloadSlice {
+            ether_type,
+            payload: self.payload(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    fn build(header: &MacsecHeader, secure_data: &[u8], icv: &[u8], padding: &[u8]) -> Vec<u8> {
+        let mut result = Vec::new();
+        result.extend_from_slice(&header.to_bytes());
+        result.extend_from_slice(secure_data);
+        result.extend_from_slice(icv);
+        result.extend_from_slice(padding);
+        result
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            base_header in macsec_any(),
+            secure_data in proptest::collection::vec(any::<u8>(), 2..80),
+            padding in proptest::collection::vec(any::<u8>(), 0..8),
+        ) {
+            for icv_len in [MacsecIcvLen::Len8, MacsecIcvLen::Len16] {
+                let mut header = base_header.clone();
+                header.set_secure_data_len(secure_data.len());
+                let icv: Vec<u8> = (0..icv_len.byte_len() as u8).collect();
+
+                // with short length set padding gets ignored, otherwise
+                // everything up to the end of the slice is secure data
+                let padding: &[u8] = if 0 != header.short_len.value() {
+                    &padding
+                } else {
+                    &[]
+                };
+                let data = build(&header, &secure_data, &icv, padding);
+
+                let slice = MacsecSlice::from_slice(&data, icv_len).unwrap();
+                assert_eq!(&header, slice.header());
+                assert_eq!(&data[..data.len() - padding.len()], slice.slice());
+                assert_eq!(&data[..header.header_len()], slice.header_slice());
+                assert_eq!(&secure_data[..], slice.secure_data());
+                assert_eq!(&icv[..], slice.icv());
+                if header.is_unmodified() {
+                    let ether_type = EtherType(u16::from_be_bytes([secure_data[0], secure_data[1]]));
+                    assert_eq!(Some(ether_type), slice.next_ether_type());
+                    assert_eq!(&secure_data[2..], slice.payload());
+                    assert_eq!(
+                        Some(EtherPayloadSlice {
+                            ether_type,
+                            payload: &secure_data[2..],
+                        }),
+                        slice.ether_payload()
+                    );
+                } else {
+                    assert_eq!(None, slice.next_ether_type());
+                    assert_eq!(&secure_data[..], slice.payload());
+                    assert_eq!(None, slice.ether_payload());
+                }
+
+                // length error (based on the slice len or the short length)
+                for len in header.header_len()..data.len() - padding.len() {
+                    let short_len = usize::from(header.short_len.value());
+                    if 0 == short_len && len >= header.header_len() + icv.len() {
+                        continue;
+                    }
+                    assert_eq!(
+                        err::macsec::HeaderSliceError::Len(err::LenError {
+                            required_len: header.header_len() + short_len + icv.len(),
+                            len,
+                            len_source: if 0 == short_len {
+                                LenSource::Slice
+                            } else {
+                                LenSource::MacsecShortLen
+                            },
+                            layer: err::Layer::MacsecHeader,
+                            layer_start_offset: 0,
+                        }),
+                        MacsecSlice::from_slice(&data[..len], icv_len).unwrap_err()
+                    );
+                }
+
+                // header error
+                assert!(MacsecSlice::from_slice(&data[..header.header_len() - 1], icv_len).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn secure_data_empty() {
+        let header = MacsecHeader::default();
+        let data = build(&header, &[], &[1; 8], &[]);
+        let slice = MacsecSlice::from_slice(&data, MacsecIcvLen::Len8).unwrap();
+        assert!(slice.secure_data().is_empty());
+        assert_eq!(None, slice.next_ether_type());
+        assert!(slice.payload().is_empty());
+        assert_eq!(&[1; 8], slice.icv());
+    }
+}
@@ -2,11 +2,17 @@ pub mod arp_hardware_id;
 pub mod double_vlan_header;
 pub mod double_vlan_header_slice;
 pub mod double_vlan_slice;
+pub mod ether_payload_parser;
 pub mod ether_payload_slice;
 pub mod ether_type_impl;
+pub mod ethercat_header_slice;
 pub mod ethernet2_header;
 pub mod ethernet2_header_slice;
 pub mod ethernet2_slice;
+pub mod ieee80211_data_slice;
+pub mod ieee8021ah_header;
+pub mod ieee8021ah_isid;
+pub mod ieee8021ah_slice;
 pub mod link_header;
 pub mod link_slice;
 pub mod linux_nonstandard_ether_type;
@@ -16,10 +22,30 @@ pub mod linux_sll_packet_type;
 pub mod linux_sll_payload_slice;
 pub mod linux_sll_protocol_type;
 pub mod linux_sll_slice;
+pub mod lldp_org_tlv;
+pub mod lldp_tlv;
+pub mod lldp_tlv_iter;
+pub mod lldp_tlv_type;
+#[cfg(feature = "macsec")]
+pub mod macsec_an;
+#[cfg(feature = "macsec")]
+pub mod macsec_header;
+#[cfg(feature = "macsec")]
+pub mod macsec_icv_len;
+#[cfg(feature = "macsec")]
+pub mod macsec_short_len;
+#[cfg(feature = "macsec")]
+pub mod macsec_slice;
+pub mod packet_direction;
+pub mod profinet_header_slice;
+pub mod provider_backbone_slice;
 pub mod single_vlan_header;
 pub mod single_vlan_header_slice;
 pub mod single_vlan_slice;
+pub mod vlan_edit_impl;
 pub mod vlan_header;
 pub mod vlan_id;
 pub mod vlan_pcp;
 pub mod vlan_slice;
+pub mod vlan_tag;
+pub mod vlan_tag_iter;
@@ -0,0 +1,36 @@
+/// Direction of a captured packet relative to the capturing host (e.g.
+/// derived from the packet type of a Linux Cooked Capture header).
+///
+/// ```
+/// use etherparse::{LinuxSllPacketType, PacketDirection};
+///
+/// assert_eq!(
+///     Some(PacketDirection::Outbound),
+///     LinuxSllPacketType::OUTGOING.direction()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PacketDirection {
+    /// Packet was received by the capturing host.
+    Inbound,
+    /// Packet was sent by the capturing host.
+    Outbound,
+    /// Multicast or broadcast packet that was looped back to the
+    /// capturing host.
+    Loopback,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = PacketDirection::Inbound;
+        assert_eq!(value, value.clone());
+        assert_eq!("Inbound", format!("{:?}", value));
+        assert_eq!("Outbound", format!("{:?}", PacketDirection::Outbound));
+        assert_eq!("Loopback", format!("{:?}", PacketDirection::Loopback));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::*;
+
+/// Slice containing a PROFINET real-time header (the 2 byte big endian
+/// "FrameID" directly following the ether type [`EtherType::PROFINET`]).
+///
+/// Only the "FrameID" gets decoded. The rest of the PROFINET frame
+/// (e.g. the cyclic IO data or DCP PDUs) is accessible as raw bytes via
+/// [`ProfinetHeaderSlice::payload`]:
+///
+/// ```
+/// # use etherparse::*;
+/// # let packet = {
+/// #     let mut packet = Ethernet2Header {
+/// #         source: [1, 2, 3, 4, 5, 6],
+/// #         destination: [7, 8, 9, 10, 11, 12],
+/// #         ether_type: EtherType::PROFINET,
+/// #     }.to_bytes().to_vec();
+/// #     packet.extend_from_slice(&[0xfe, 0xfe, 0x05, 0x00]);
+/// #     packet
+/// # };
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// let ether_payload = sliced.ether_payload().unwrap();
+/// if let Some(Ok(profinet)) = ether_payload.profinet() {
+///     assert_eq!(0xfefe, profinet.frame_id());
+///     assert_eq!(&[0x05, 0x00], profinet.payload());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfinetHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> ProfinetHeaderSlice<'a> {
+    /// Length of the PROFINET real-time header (FrameID) in bytes/octets.
+    pub const LEN: usize = 2;
+
+    /// Creates a PROFINET header slice from a slice (the slice can contain
+    /// the data after the FrameID).
+    pub fn from_slice(slice: &'a [u8]) -> Result<ProfinetHeaderSlice<'a>, err::LenError> {
+        if slice.len() < ProfinetHeaderSlice::LEN {
+            return Err(err::LenError {
+                required_len: ProfinetHeaderSlice::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::ProfinetHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(ProfinetHeaderSlice { slice })
+    }
+
+    /// Returns the slice containing the PROFINET header & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing only the 2 byte FrameID.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length is checked to be at least
+        //         ProfinetHeaderSlice::LEN (2) in the constructor.
+        unchecked! { get_unchecked_slice(self.slice, 0, ProfinetHeaderSlice::LEN) }
+    }
+
+    /// Read the "FrameID" field (identifies the kind of PROFINET frame,
+    /// e.g. cyclic real-time data, alarms or DCP).
+    #[inline]
+    pub fn frame_id(&self) -> u16 {
+        u16::from_be_bytes([self.slice[0], self.slice[1]])
+    }
+
+    /// Raw bytes after the FrameID.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length is checked to be at least
+        //         ProfinetHeaderSlice::LEN (2) in the constructor.
+        unchecked! {
+            get_unchecked_slice(self.slice, ProfinetHeaderSlice::LEN, self.slice.len() - ProfinetHeaderSlice::LEN)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            frame_id in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut bytes = Vec::with_capacity(2 + payload.len());
+            bytes.extend_from_slice(&frame_id.to_be_bytes());
+            bytes.extend_from_slice(&payload);
+
+            // ok case
+            {
+                let s = ProfinetHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(s.slice(), &bytes[..]);
+                assert_eq!(s.header_slice(), &bytes[..2]);
+                assert_eq!(s.frame_id(), frame_id);
+                assert_eq!(s.payload(), &payload[..]);
+            }
+
+            // length error
+            for len in 0..ProfinetHeaderSlice::LEN {
+                assert_eq!(
+                    ProfinetHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: ProfinetHeaderSlice::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::ProfinetHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let bytes = [0xfe, 0xfe];
+        let s = ProfinetHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(s, s.clone());
+        assert_eq!(
+            format!("{:?}", s),
+            format!("ProfinetHeaderSlice {{ slice: {:?} }}", &bytes[..])
+        );
+    }
+}
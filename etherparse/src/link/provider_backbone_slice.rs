@@ -0,0 +1,68 @@
+use crate::*;
+
+/// Backbone headers of a Provider Backbone Bridging frame (IEEE 802.1ah,
+/// "MAC-in-MAC").
+///
+/// When slicing a packet the backbone headers are moved to
+/// [`SlicedPacket::backbone`] (or [`LaxSlicedPacket::backbone`]) as soon as
+/// an I-TAG is encountered & the slicing continues with the encapsulated
+/// customer Ethernet II frame. The `link` & `vlan` fields of the sliced
+/// packet then contain the headers of the customer frame:
+///
+/// ```
+/// use etherparse::{LinkSlice, SlicedPacket};
+///
+/// # let packet = [
+/// #     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x88, 0xE7, // backbone ethernet II
+/// #     0x60, 0x12, 0x34, 0x56, // I-TAG (pcp 3, I-SID 0x123456)
+/// #     13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0x88, 0xB5, // customer ethernet II
+/// # ];
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+///
+/// let backbone = sliced.backbone.as_ref().unwrap();
+/// assert_eq!(0x123456, backbone.itag.isid().value());
+/// if let Some(LinkSlice::Ethernet2(eth)) = &backbone.link {
+///     assert_eq!([1, 2, 3, 4, 5, 6], eth.destination());
+/// }
+///
+/// // link contains the customer ethernet II header
+/// if let Some(LinkSlice::Ethernet2(eth)) = &sliced.link {
+///     assert_eq!([13, 14, 15, 16, 17, 18], eth.destination());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProviderBackboneSlice<'a> {
+    /// Backbone Ethernet II header (or ether payload if the slicing
+    /// started at the I-TAG ether type).
+    pub link: Option<LinkSlice<'a>>,
+
+    /// Backbone VLAN tag(s) (B-TAG) if present.
+    pub vlan: Option<VlanSlice<'a>>,
+
+    /// I-TAG header (the payload contains the customer Ethernet II frame).
+    pub itag: Ieee8021ahSlice<'a>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = [0x60, 0x12, 0x34, 0x56];
+        let value = ProviderBackboneSlice {
+            link: None,
+            vlan: None,
+            itag: Ieee8021ahSlice::from_slice(&data).unwrap(),
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!(
+                "ProviderBackboneSlice {{ link: {:?}, vlan: {:?}, itag: {:?} }}",
+                value.link, value.vlan, value.itag
+            ),
+            format!("{:?}", value)
+        );
+    }
+}
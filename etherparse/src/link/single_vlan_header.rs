@@ -17,6 +17,14 @@ impl SingleVlanHeader {
     /// Serialized size of an VLAN header in bytes/octets.
     pub const LEN: usize = 4;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`SingleVlanHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = SingleVlanHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`SingleVlanHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = SingleVlanHeader::LEN;
+
     #[deprecated(since = "0.14.0", note = "Use `SingleVlanHeader::LEN` instead")]
     pub const SERIALIZED_SIZE: usize = SingleVlanHeader::LEN;
 
@@ -40,16 +48,16 @@ impl SingleVlanHeader {
     #[inline]
     pub fn from_bytes(bytes: [u8; 4]) -> SingleVlanHeader {
         SingleVlanHeader {
-            pcp: unsafe {
+            pcp: unchecked! {
                 // SAFETY: Safe as bitmasks guarantee that value does not exceed
                 //         0b0000_0111.
-                VlanPcp::new_unchecked((bytes[0] >> 5) & 0b0000_0111u8)
+                VlanPcp::new_unchecked_internal((bytes[0] >> 5) & 0b0000_0111u8)
             },
             drop_eligible_indicator: 0 != (bytes[0] & 0b0001_0000u8),
-            vlan_id: unsafe {
+            vlan_id: unchecked! {
                 // SAFETY: Safe as bitmasks guarantee that value does not exceed
                 //         0b0000_1111_1111_1111.
-                VlanId::new_unchecked(u16::from_be_bytes([bytes[0] & 0b0000_1111u8, bytes[1]]))
+                VlanId::new_unchecked_internal(u16::from_be_bytes([bytes[0] & 0b0000_1111u8, bytes[1]]))
             },
             ether_type: EtherType(u16::from_be_bytes([bytes[2], bytes[3]])),
         }
@@ -69,10 +77,22 @@ impl SingleVlanHeader {
 
         Ok(
             // SAFETY: Safe as the buffer has the exact size of an vlan header.
-            unsafe { SingleVlanHeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
+            unchecked! { SingleVlanHeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
         )
     }
 
+    /// Read a IEEE 802.1Q VLAN tagging header from an async reader
+    /// (requires crate feature `tokio`).
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<SingleVlanHeader, std::io::Error> {
+        let mut buffer = [0; SingleVlanHeader::LEN];
+        async_read_exact(reader, &mut buffer).await?;
+        Ok(SingleVlanHeader::from_bytes(buffer))
+    }
+
     /// Write the IEEE 802.1Q VLAN tagging header
     #[inline]
     #[cfg(feature = "std")]
@@ -87,6 +107,16 @@ impl SingleVlanHeader {
         4
     }
 
+    /// Returns the priority, drop eligibility & identifier of the header.
+    #[inline]
+    pub fn tag(&self) -> VlanTag {
+        VlanTag {
+            pcp: self.pcp,
+            drop_eligible_indicator: self.drop_eligible_indicator,
+            vlan_id: self.vlan_id,
+        }
+    }
+
     /// Returns the serialized form of the header or an value error in case
     /// the header values are outside of range.
     #[inline]
@@ -106,6 +136,21 @@ impl SingleVlanHeader {
     }
 }
 
+/// Writes the header in the format `vlan=<id> pcp=<pcp> dei=<0|1> (ether type)`,
+/// e.g. `vlan=10 pcp=3 dei=0 (0x0800)`.
+impl core::fmt::Display for SingleVlanHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "vlan={} pcp={} dei={} ({:#06x})",
+            self.vlan_id,
+            self.pcp,
+            u8::from(self.drop_eligible_indicator),
+            self.ether_type.0
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{test_gens::*, *};
@@ -169,6 +214,40 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            input in vlan_single_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            let mut buffer = Vec::with_capacity(SingleVlanHeader::LEN + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut reader = &buffer[..];
+                let result = rt.block_on(SingleVlanHeader::async_read(&mut reader)).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(dummy_data.len(), reader.len());
+            }
+
+            // unexpected eof
+            for len in 0..SingleVlanHeader::LEN {
+                let mut reader = &buffer[..len];
+                assert_eq!(
+                    rt.block_on(SingleVlanHeader::async_read(&mut reader))
+                        .unwrap_err()
+                        .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -246,6 +325,20 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn tag(input in vlan_single_any()) {
+            assert_eq!(
+                VlanTag {
+                    pcp: input.pcp,
+                    drop_eligible_indicator: input.drop_eligible_indicator,
+                    vlan_id: input.vlan_id,
+                },
+                input.tag()
+            );
+        }
+    }
+
     #[test]
     fn default() {
         let actual: SingleVlanHeader = Default::default();
@@ -277,4 +370,15 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn display() {
+        let header = SingleVlanHeader {
+            pcp: VlanPcp::try_new(3).unwrap(),
+            drop_eligible_indicator: true,
+            vlan_id: VlanId::try_new(10).unwrap(),
+            ether_type: EtherType::IPV6,
+        };
+        assert_eq!("vlan=10 pcp=3 dei=1 (0x86dd)", format!("{}", header));
+    }
 }
@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 ///A slice containing a single vlan header of a network package.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,24 +26,26 @@ impl<'a> SingleVlanHeaderSlice<'a> {
             // SAFETY:
             // Safe as the slice length is checked beforehand to have
             // at least the length of SingleVlanHeader::LEN (4)
-            slice: unsafe { from_raw_parts(slice.as_ptr(), SingleVlanHeader::LEN) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, SingleVlanHeader::LEN) },
         })
     }
 
-    /// Converts the given slice into a vlan header slice WITHOUT any
-    /// checks to ensure that the data present is an vlan header or that the
-    /// slice length is matching the header length.
-    ///
-    /// If you are not sure what this means, use [`SingleVlanHeaderSlice::from_slice`]
-    /// instead.
-    ///
-    /// # Safety
-    ///
-    /// The caller must ensured that the given slice has the length of
-    /// [`SingleVlanHeader::LEN`]
-    #[inline]
-    pub(crate) unsafe fn from_slice_unchecked(slice: &[u8]) -> SingleVlanHeaderSlice {
-        SingleVlanHeaderSlice { slice }
+    unchecked_fn! {
+        /// Converts the given slice into a vlan header slice WITHOUT any
+        /// checks to ensure that the data present is an vlan header or that the
+        /// slice length is matching the header length.
+        ///
+        /// If you are not sure what this means, use [`SingleVlanHeaderSlice::from_slice`]
+        /// instead.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensured that the given slice has the length of
+        /// [`SingleVlanHeader::LEN`]
+        #[inline]
+        pub(crate) fn from_slice_unchecked(slice: &[u8]) -> SingleVlanHeaderSlice {
+            SingleVlanHeaderSlice { slice }
+        }
     }
 
     /// Returns the slice containing the single vlan header
@@ -56,10 +57,10 @@ impl<'a> SingleVlanHeaderSlice<'a> {
     /// Read the "priority_code_point" field from the slice. This is a 3 bit number which refers to the IEEE 802.1p class of service and maps to the frame priority level.
     #[inline]
     pub fn priority_code_point(&self) -> VlanPcp {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as slice len checked in constructor to be at least 4 &
             // the bitmask guarantees values does not exceed 0b0000_0111.
-            VlanPcp::new_unchecked((*self.slice.get_unchecked(0) >> 5) & 0b0000_0111)
+            VlanPcp::new_unchecked_internal((get_unchecked_u8(self.slice, 0) >> 5) & 0b0000_0111)
         }
     }
 
@@ -68,7 +69,7 @@ impl<'a> SingleVlanHeaderSlice<'a> {
     pub fn drop_eligible_indicator(&self) -> bool {
         // SAFETY:
         // Slice len checked in constructor to be at least 4.
-        unsafe { 0 != (*self.slice.get_unchecked(0) & 0x10) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 0) & 0x10) }
     }
 
     /// Reads the 12 bits "vland identifier" field from the slice.
@@ -79,10 +80,10 @@ impl<'a> SingleVlanHeaderSlice<'a> {
         // value and the value is guaranteed not to exceed
         // 0b0000_1111_1111_1111 as the upper bits have been
         // bitmasked out.
-        unsafe {
-            VlanId::new_unchecked(u16::from_be_bytes([
-                *self.slice.get_unchecked(0) & 0b0000_1111,
-                *self.slice.get_unchecked(1),
+        unchecked! {
+            VlanId::new_unchecked_internal(u16::from_be_bytes([
+                get_unchecked_u8(self.slice, 0) & 0b0000_1111,
+                get_unchecked_u8(self.slice, 1),
             ]))
         }
     }
@@ -92,7 +93,7 @@ impl<'a> SingleVlanHeaderSlice<'a> {
     pub fn ether_type(&self) -> EtherType {
         // SAFETY:
         // Slice len checked in constructor to be at least 4.
-        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+        EtherType(unchecked! { get_unchecked_be_u16(self.slice, 2) })
     }
 
     /// Decode all the fields and copy the results to a SingleVlanHeader struct
@@ -105,6 +106,16 @@ impl<'a> SingleVlanHeaderSlice<'a> {
             ether_type: self.ether_type(),
         }
     }
+
+    /// Decode the priority, drop eligibility & identifier of the header.
+    #[inline]
+    pub fn tag(&self) -> VlanTag {
+        VlanTag {
+            pcp: self.priority_code_point(),
+            drop_eligible_indicator: self.drop_eligible_indicator(),
+            vlan_id: self.vlan_identifier(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +168,7 @@ mod test {
             assert_eq!(input.drop_eligible_indicator, slice.drop_eligible_indicator());
             assert_eq!(input.vlan_id, slice.vlan_identifier());
             assert_eq!(input.ether_type, slice.ether_type());
+            assert_eq!(input.tag(), slice.tag());
         }
     }
 
@@ -36,10 +36,10 @@ impl<'a> SingleVlanSlice<'a> {
     /// of service and maps to the frame priority level.
     #[inline]
     pub fn priority_code_point(&self) -> VlanPcp {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as slice len checked in constructor to be at least 4 &
             // the bitmask guarantees values does not exceed 0b0000_0111.
-            VlanPcp::new_unchecked((*self.slice.get_unchecked(0) >> 5) & 0b0000_0111)
+            VlanPcp::new_unchecked_internal((get_unchecked_u8(self.slice, 0) >> 5) & 0b0000_0111)
         }
     }
 
@@ -51,7 +51,7 @@ impl<'a> SingleVlanSlice<'a> {
     pub fn drop_eligible_indicator(&self) -> bool {
         // SAFETY:
         // Slice len checked in constructor to be at least 4.
-        unsafe { 0 != (*self.slice.get_unchecked(0) & 0x10) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 0) & 0x10) }
     }
 
     /// Reads the 12 bits "vland identifier" field from the VLAN header.
@@ -62,10 +62,10 @@ impl<'a> SingleVlanSlice<'a> {
         // value and the value is guranteed not to exceed
         // 0b0000_1111_1111_1111 as the upper bits have been
         // bitmasked out.
-        unsafe {
-            VlanId::new_unchecked(u16::from_be_bytes([
-                *self.slice.get_unchecked(0) & 0b0000_1111,
-                *self.slice.get_unchecked(1),
+        unchecked! {
+            VlanId::new_unchecked_internal(u16::from_be_bytes([
+                get_unchecked_u8(self.slice, 0) & 0b0000_1111,
+                get_unchecked_u8(self.slice, 1),
             ]))
         }
     }
@@ -77,7 +77,7 @@ impl<'a> SingleVlanSlice<'a> {
     pub fn ether_type(&self) -> EtherType {
         // SAFETY:
         // Slice len checked in constructor to be at least 4.
-        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+        EtherType(unchecked! { get_unchecked_be_u16(self.slice, 2) })
     }
 
     /// Decode all the fields and copy the results to a SingleVlanHeader struct
@@ -91,13 +91,23 @@ impl<'a> SingleVlanSlice<'a> {
         }
     }
 
+    /// Decode the priority, drop eligibility & identifier of the header.
+    #[inline]
+    pub fn tag(&self) -> VlanTag {
+        VlanTag {
+            pcp: self.priority_code_point(),
+            drop_eligible_indicator: self.drop_eligible_indicator(),
+            vlan_id: self.vlan_identifier(),
+        }
+    }
+
     /// Slice containing the Ethernet 2 header.
     pub fn header_slice(&self) -> &[u8] {
-        unsafe {
+        unchecked! {
             // SAFETY:
             // Safe as the contructor checks that the slice has
             // at least the length of SingleVlanHeader::LEN (4).
-            core::slice::from_raw_parts(self.slice.as_ptr(), SingleVlanHeader::LEN)
+            get_unchecked_slice(self.slice, 0, SingleVlanHeader::LEN)
         }
     }
 
@@ -114,14 +124,11 @@ impl<'a> SingleVlanSlice<'a> {
     /// Returns the slice containing the VLAN payload.
     #[inline]
     pub fn payload_slice(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY:
             // Safe as the contructor checks that the slice has
             // at least the length of SingleVlanHeader::LEN (4).
-            core::slice::from_raw_parts(
-                self.slice.as_ptr().add(SingleVlanHeader::LEN),
-                self.slice.len() - SingleVlanHeader::LEN,
-            )
+            get_unchecked_slice(self.slice, SingleVlanHeader::LEN, self.slice.len() - SingleVlanHeader::LEN)
         }
     }
 
@@ -196,6 +203,7 @@ mod test {
             assert_eq!(vlan.vlan_id, slice.vlan_identifier());
             assert_eq!(vlan.ether_type, slice.ether_type());
             assert_eq!(vlan, slice.to_header());
+            assert_eq!(vlan.tag(), slice.tag());
             assert_eq!(&data[..SingleVlanHeader::LEN], slice.header_slice());
 
             assert_eq!(
@@ -0,0 +1,357 @@
+/// Functions for inserting, removing & rewriting 802.1Q VLAN tags
+/// directly in serialized Ethernet II frames.
+///
+/// The functions operate on mutable buffers & update the ether type
+/// fields preceding the tags. Functions that change the length of the
+/// frame return the new length, so callers can adjust their buffers:
+///
+/// ```
+/// use etherparse::{ether_type, vlan_edit, PacketBuilder, SingleVlanHeader, SlicedPacket, VlanId};
+///
+/// let mut frame = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234)
+///     .write(&mut frame, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// // push a tag (the buffer needs room for 4 additional bytes)
+/// let len = frame.len();
+/// frame.resize(len + SingleVlanHeader::LEN, 0);
+/// let vlan = SingleVlanHeader {
+///     vlan_id: VlanId::try_new(42).unwrap(),
+///     ..Default::default()
+/// };
+/// let len = vlan_edit::push(&mut frame, len, ether_type::VLAN_TAGGED_FRAME, &vlan).unwrap();
+///
+/// let sliced = SlicedPacket::from_ethernet(&frame[..len]).unwrap();
+/// assert!(sliced.vlan.is_some());
+/// assert!(sliced.net.is_some());
+///
+/// // pop the tag again
+/// let (popped, len) = vlan_edit::pop(&mut frame[..len]).unwrap().unwrap();
+/// assert_eq!(42, popped.vlan_id.value());
+/// assert_eq!(ether_type::IPV4, popped.ether_type);
+/// frame.truncate(len);
+///
+/// let sliced = SlicedPacket::from_ethernet(&frame).unwrap();
+/// assert!(sliced.vlan.is_none());
+/// ```
+pub mod vlan_edit {
+    use crate::{err, err::Layer, *};
+
+    /// Offset of the ether type field in an Ethernet II header.
+    const ETHER_TYPE_OFFSET: usize = 12;
+
+    /// Checks that the frame contains at least an Ethernet II header.
+    fn check_ethernet2_len(frame_len: usize) -> Result<(), err::LenError> {
+        if frame_len < Ethernet2Header::LEN {
+            Err(err::LenError {
+                required_len: Ethernet2Header::LEN,
+                len: frame_len,
+                len_source: LenSource::Slice,
+                layer: Layer::Ethernet2Header,
+                layer_start_offset: 0,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the start offset of the outermost VLAN tag (if the ether
+    /// type of the Ethernet II header is a VLAN tag ether type).
+    fn outer_tag_start(frame: &[u8]) -> Result<Option<usize>, err::LenError> {
+        check_ethernet2_len(frame.len())?;
+        let ether_type = EtherType(u16::from_be_bytes([
+            frame[ETHER_TYPE_OFFSET],
+            frame[ETHER_TYPE_OFFSET + 1],
+        ]));
+        if false == ether_type.is_vlan_tag() {
+            return Ok(None);
+        }
+        if frame.len() < Ethernet2Header::LEN + SingleVlanHeader::LEN {
+            return Err(err::LenError {
+                required_len: SingleVlanHeader::LEN,
+                len: frame.len() - Ethernet2Header::LEN,
+                len_source: LenSource::Slice,
+                layer: Layer::VlanHeader,
+                layer_start_offset: Ethernet2Header::LEN,
+            });
+        }
+        Ok(Some(Ethernet2Header::LEN))
+    }
+
+    /// Inserts a VLAN tag directly after the Ethernet II header of the
+    /// frame stored in `buffer[..frame_len]` & returns the new length of
+    /// the frame.
+    ///
+    /// The ether type of the Ethernet II header is set to `tpid` (e.g.
+    /// [`ether_type::VLAN_TAGGED_FRAME`] or [`ether_type::PROVIDER_BRIDGING`]
+    /// when pushing an outer tag onto an already tagged frame). The
+    /// `ether_type` field of `vlan` is ignored, instead the ether type
+    /// previously present in the Ethernet II header is written into the tag.
+    ///
+    /// The buffer must have room for at least `frame_len + 4` bytes,
+    /// otherwise an error is returned and the buffer is not modified.
+    pub fn push(
+        buffer: &mut [u8],
+        frame_len: usize,
+        tpid: EtherType,
+        vlan: &SingleVlanHeader,
+    ) -> Result<usize, err::LenError> {
+        check_ethernet2_len(frame_len)?;
+        let new_len = frame_len + SingleVlanHeader::LEN;
+        if buffer.len() < new_len {
+            return Err(err::LenError {
+                required_len: new_len,
+                len: buffer.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::VlanHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        let previous = EtherType(u16::from_be_bytes([
+            buffer[ETHER_TYPE_OFFSET],
+            buffer[ETHER_TYPE_OFFSET + 1],
+        ]));
+
+        // make room for the tag & write it
+        buffer.copy_within(
+            Ethernet2Header::LEN..frame_len,
+            Ethernet2Header::LEN + SingleVlanHeader::LEN,
+        );
+        let tag = SingleVlanHeader {
+            ether_type: previous,
+            ..vlan.clone()
+        }
+        .to_bytes();
+        buffer[Ethernet2Header::LEN..Ethernet2Header::LEN + SingleVlanHeader::LEN]
+            .copy_from_slice(&tag);
+        buffer[ETHER_TYPE_OFFSET..ETHER_TYPE_OFFSET + 2].copy_from_slice(&tpid.0.to_be_bytes());
+
+        Ok(new_len)
+    }
+
+    /// Removes the outermost VLAN tag from the frame & returns the removed
+    /// tag together with the new length of the frame.
+    ///
+    /// The ether type of the removed tag is moved into the Ethernet II
+    /// header. The bytes after the new frame length are left unchanged
+    /// (the caller is expected to truncate the buffer).
+    ///
+    /// `None` is returned if the frame has no VLAN tag.
+    pub fn pop(frame: &mut [u8]) -> Result<Option<(SingleVlanHeader, usize)>, err::LenError> {
+        let start = match outer_tag_start(frame)? {
+            Some(start) => start,
+            None => return Ok(None),
+        };
+        // SAFETY:
+        // Safe as the slice length is checked to contain at least
+        // SingleVlanHeader::LEN bytes after the start in outer_tag_start.
+        let tag =
+            unchecked! { SingleVlanHeaderSlice::from_slice_unchecked(&frame[start..]).to_header() };
+        frame[ETHER_TYPE_OFFSET..ETHER_TYPE_OFFSET + 2]
+            .copy_from_slice(&tag.ether_type.0.to_be_bytes());
+        frame.copy_within(start + SingleVlanHeader::LEN.., start);
+        Ok(Some((tag, frame.len() - SingleVlanHeader::LEN)))
+    }
+
+    /// Sets the VLAN identifier of the outermost VLAN tag in place.
+    ///
+    /// Returns `false` if the frame has no VLAN tag.
+    pub fn set_vlan_id(frame: &mut [u8], vlan_id: VlanId) -> Result<bool, err::LenError> {
+        match outer_tag_start(frame)? {
+            Some(start) => {
+                let [high, low] = vlan_id.value().to_be_bytes();
+                frame[start] = (frame[start] & 0b1111_0000) | high;
+                frame[start + 1] = low;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Sets the priority code point of the outermost VLAN tag in place.
+    ///
+    /// Returns `false` if the frame has no VLAN tag.
+    pub fn set_pcp(frame: &mut [u8], pcp: VlanPcp) -> Result<bool, err::LenError> {
+        match outer_tag_start(frame)? {
+            Some(start) => {
+                frame[start] = (frame[start] & 0b0001_1111) | (pcp.value() << 5);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::vlan_edit::*;
+    use crate::{err::Layer, test_gens::*, *};
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn push_pop(
+            eth in ethernet_2_unknown(),
+            vlan in vlan_single_unknown(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut frame = Vec::new();
+            frame.extend_from_slice(&eth.to_bytes());
+            frame.extend_from_slice(&payload);
+            let frame_len = frame.len();
+
+            // push
+            let mut buffer = frame.clone();
+            buffer.resize(frame_len + SingleVlanHeader::LEN, 0);
+            let new_len = push(&mut buffer, frame_len, ether_type::VLAN_TAGGED_FRAME, &vlan).unwrap();
+            assert_eq!(frame_len + SingleVlanHeader::LEN, new_len);
+            {
+                let (actual_eth, rest) = Ethernet2Header::from_slice(&buffer).unwrap();
+                assert_eq!(eth.source, actual_eth.source);
+                assert_eq!(eth.destination, actual_eth.destination);
+                assert_eq!(ether_type::VLAN_TAGGED_FRAME, actual_eth.ether_type);
+                let (actual_vlan, rest) = SingleVlanHeader::from_slice(rest).unwrap();
+                assert_eq!(
+                    SingleVlanHeader {
+                        ether_type: eth.ether_type,
+                        ..vlan.clone()
+                    },
+                    actual_vlan
+                );
+                assert_eq!(&payload[..], rest);
+            }
+
+            // pop
+            {
+                let (popped, len) = pop(&mut buffer).unwrap().unwrap();
+                assert_eq!(frame_len, len);
+                assert_eq!(eth.ether_type, popped.ether_type);
+                assert_eq!(vlan.vlan_id, popped.vlan_id);
+                assert_eq!(vlan.pcp, popped.pcp);
+                assert_eq!(&frame[..], &buffer[..len]);
+            }
+
+            // pop without tag
+            {
+                // ethernet_2_unknown never generates vlan ether types
+                let mut untagged = frame.clone();
+                assert_eq!(None, pop(&mut untagged).unwrap());
+                assert_eq!(frame, untagged);
+                assert_eq!(Ok(false), set_vlan_id(&mut untagged, VlanId::try_new(1).unwrap()));
+                assert_eq!(Ok(false), set_pcp(&mut untagged, VlanPcp::try_new(1).unwrap()));
+                assert_eq!(frame, untagged);
+            }
+
+            // buffer too small
+            {
+                let mut buffer = frame.clone();
+                assert_eq!(
+                    Err(err::LenError {
+                        required_len: frame_len + SingleVlanHeader::LEN,
+                        len: frame_len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::VlanHeader,
+                        layer_start_offset: 0,
+                    }),
+                    push(&mut buffer, frame_len, ether_type::VLAN_TAGGED_FRAME, &vlan)
+                );
+                assert_eq!(frame, buffer);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn set_vlan_id_pcp(
+            eth in ethernet_2_unknown(),
+            vlan in vlan_single_unknown(),
+            vlan_id in 0..=VlanId::MAX_U16,
+            pcp in 0..=VlanPcp::MAX_U8,
+        ) {
+            let mut frame = Vec::new();
+            frame.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: ether_type::VLAN_TAGGED_FRAME,
+                    ..eth
+                }
+                .to_bytes(),
+            );
+            frame.extend_from_slice(&vlan.to_bytes());
+
+            let vlan_id = VlanId::try_new(vlan_id).unwrap();
+            let pcp = VlanPcp::try_new(pcp).unwrap();
+            assert_eq!(Ok(true), set_vlan_id(&mut frame, vlan_id));
+            assert_eq!(Ok(true), set_pcp(&mut frame, pcp));
+
+            let (actual, _) = SingleVlanHeader::from_slice(&frame[Ethernet2Header::LEN..]).unwrap();
+            assert_eq!(
+                SingleVlanHeader {
+                    pcp,
+                    vlan_id,
+                    ..vlan.clone()
+                },
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn len_errors() {
+        // too short for an ethernet header
+        for len in 0..Ethernet2Header::LEN {
+            let mut buffer = [0u8; Ethernet2Header::LEN + SingleVlanHeader::LEN];
+            let expected = err::LenError {
+                required_len: Ethernet2Header::LEN,
+                len,
+                len_source: LenSource::Slice,
+                layer: Layer::Ethernet2Header,
+                layer_start_offset: 0,
+            };
+            assert_eq!(
+                Err(expected.clone()),
+                push(
+                    &mut buffer,
+                    len,
+                    ether_type::VLAN_TAGGED_FRAME,
+                    &Default::default()
+                )
+            );
+            assert_eq!(Err(expected.clone()), pop(&mut buffer[..len]));
+            assert_eq!(
+                Err(expected.clone()),
+                set_vlan_id(&mut buffer[..len], Default::default())
+            );
+            assert_eq!(
+                Err(expected),
+                set_pcp(&mut buffer[..len], Default::default())
+            );
+        }
+
+        // tag cut off
+        for len in Ethernet2Header::LEN..Ethernet2Header::LEN + SingleVlanHeader::LEN {
+            let mut buffer = [0u8; Ethernet2Header::LEN + SingleVlanHeader::LEN];
+            buffer[12..14].copy_from_slice(&ether_type::VLAN_TAGGED_FRAME.0.to_be_bytes());
+            let expected = err::LenError {
+                required_len: SingleVlanHeader::LEN,
+                len: len - Ethernet2Header::LEN,
+                len_source: LenSource::Slice,
+                layer: Layer::VlanHeader,
+                layer_start_offset: Ethernet2Header::LEN,
+            };
+            assert_eq!(Err(expected.clone()), pop(&mut buffer[..len]));
+            assert_eq!(
+                Err(expected.clone()),
+                set_vlan_id(&mut buffer[..len], Default::default())
+            );
+            assert_eq!(
+                Err(expected),
+                set_pcp(&mut buffer[..len], Default::default())
+            );
+        }
+    }
+}
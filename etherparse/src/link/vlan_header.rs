@@ -10,6 +10,12 @@ pub enum VlanHeader {
 }
 
 impl VlanHeader {
+    /// Minimum serialized size of a VLAN header in bytes/octets (single tagged).
+    pub const MIN_LEN: usize = SingleVlanHeader::LEN;
+
+    /// Maximum serialized size of a VLAN header in bytes/octets (double tagged).
+    pub const MAX_LEN: usize = DoubleVlanHeader::LEN;
+
     /// All ether types that identify a vlan header.
     pub const VLAN_ETHER_TYPES: [EtherType; 3] = [
         ether_type::VLAN_TAGGED_FRAME,
@@ -38,6 +44,15 @@ impl VlanHeader {
         }
     }
 
+    /// Returns an iterator over the VLAN tags in outer to inner order.
+    #[inline]
+    pub fn tags(&self) -> VlanTagIter {
+        match self {
+            VlanHeader::Single(s) => VlanTagIter::single(s.tag()),
+            VlanHeader::Double(d) => VlanTagIter::double(d.outer.tag(), d.inner.tag()),
+        }
+    }
+
     /// Length of the serialized header(s) in bytes.
     #[inline]
     pub fn header_len(&self) -> usize {
@@ -136,6 +151,23 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn tags(
+            single in vlan_single_any(),
+            double in vlan_double_any(),
+        ) {
+            assert_eq!(
+                alloc::vec![single.tag()],
+                VlanHeader::Single(single.clone()).tags().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                alloc::vec![double.outer.tag(), double.inner.tag()],
+                VlanHeader::Double(double.clone()).tags().collect::<Vec<_>>()
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn header_len(
@@ -60,9 +60,19 @@ impl VlanId {
     /// otherwise the behavior of functions or data structures relying
     /// on this pre-requirement is undefined.
     #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub const unsafe fn new_unchecked(value: u16) -> VlanId {
-        debug_assert!(value <= VlanId::MAX_U16);
-        VlanId(value)
+        unchecked! { VlanId::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`VlanId::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u16) -> VlanId {
+            debug_assert!(value <= VlanId::MAX_U16);
+            VlanId(value)
+        }
     }
 
     /// Returns the underlying unsigned 12 bit value as an `u16` value.
@@ -105,6 +115,7 @@ impl TryFrom<u16> for VlanId {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use core::hash::{Hash, Hasher};
@@ -63,9 +63,19 @@ impl VlanPcp {
     /// otherwise the behavior of functions or data structures relying
     /// on this pre-requirement is undefined.
     #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub const unsafe fn new_unchecked(value: u8) -> VlanPcp {
-        debug_assert!(value <= VlanPcp::MAX_U8);
-        VlanPcp(value)
+        unchecked! { VlanPcp::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`VlanPcp::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u8) -> VlanPcp {
+            debug_assert!(value <= VlanPcp::MAX_U8);
+            VlanPcp(value)
+        }
     }
 
     /// Returns the underlying unsigned 3 bit value as an `u8` value.
@@ -108,6 +118,7 @@ impl TryFrom<u8> for VlanPcp {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use core::hash::{Hash, Hasher};
@@ -19,6 +19,24 @@ impl<'a> VlanSlice<'a> {
         }
     }
 
+    /// Returns an iterator over the VLAN tags in outer to inner order.
+    #[inline]
+    pub fn tags(&self) -> VlanTagIter {
+        match self {
+            VlanSlice::SingleVlan(s) => VlanTagIter::single(s.tag()),
+            VlanSlice::DoubleVlan(d) => VlanTagIter::double(d.outer().tag(), d.inner().tag()),
+        }
+    }
+
+    /// Length of the vlan header(s) in bytes/octets.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        match self {
+            VlanSlice::SingleVlan(_) => SingleVlanHeader::LEN,
+            VlanSlice::DoubleVlan(_) => DoubleVlanHeader::LEN,
+        }
+    }
+
     #[inline]
     pub fn payload(&self) -> EtherPayloadSlice<'a> {
         match self {
@@ -48,8 +66,10 @@ mod test {
                 );
                 assert_eq!(
                     slice.to_header(),
-                    VlanHeader::Single(single)
+                    VlanHeader::Single(single.clone())
                 );
+                assert!(slice.tags().eq(VlanHeader::Single(single).tags()));
+                assert_eq!(SingleVlanHeader::LEN, slice.header_len());
             }
 
             // double
@@ -60,8 +80,10 @@ mod test {
                 );
                 assert_eq!(
                     slice.to_header(),
-                    VlanHeader::Double(double)
+                    VlanHeader::Double(double.clone())
                 );
+                assert!(slice.tags().eq(VlanHeader::Double(double).tags()));
+                assert_eq!(DoubleVlanHeader::LEN, slice.header_len());
             }
         }
     }
@@ -0,0 +1,75 @@
+use crate::*;
+
+/// Priority, drop eligibility & identifier of a single VLAN tag
+/// (the "tag control information" of an IEEE 802.1Q header).
+///
+/// Contains the same values independent of whether the tag was part
+/// of a single or double VLAN header (see [`crate::SlicedPacket::vlan_tags`]).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VlanTag {
+    /// 3 bit "priority code point" (IEEE 802.1p class of service).
+    pub pcp: VlanPcp,
+    /// Indicates that the frame may be dropped under the presence of congestion.
+    pub drop_eligible_indicator: bool,
+    /// 12 bit VLAN identifier.
+    pub vlan_id: VlanId,
+}
+
+impl From<&SingleVlanHeader> for VlanTag {
+    #[inline]
+    fn from(value: &SingleVlanHeader) -> Self {
+        value.tag()
+    }
+}
+
+impl From<SingleVlanHeader> for VlanTag {
+    #[inline]
+    fn from(value: SingleVlanHeader) -> Self {
+        value.tag()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn derived_traits() {
+        let tag = VlanTag {
+            pcp: VlanPcp::try_new(1).unwrap(),
+            drop_eligible_indicator: true,
+            vlan_id: VlanId::try_new(2).unwrap(),
+        };
+        assert_eq!(tag, tag.clone());
+        assert_eq!(
+            format!(
+                "VlanTag {{ pcp: {:?}, drop_eligible_indicator: true, vlan_id: {:?} }}",
+                tag.pcp, tag.vlan_id
+            ),
+            format!("{:?}", tag)
+        );
+        assert_eq!(
+            VlanTag {
+                pcp: VlanPcp::ZERO,
+                drop_eligible_indicator: false,
+                vlan_id: VlanId::ZERO,
+            },
+            VlanTag::default()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn from(header in vlan_single_any()) {
+            let expected = VlanTag {
+                pcp: header.pcp,
+                drop_eligible_indicator: header.drop_eligible_indicator,
+                vlan_id: header.vlan_id,
+            };
+            assert_eq!(expected, VlanTag::from(&header));
+            assert_eq!(expected, VlanTag::from(header));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::*;
+
+/// Iterator over the [`VlanTag`]s of a packet in outer to inner order
+/// (see [`crate::SlicedPacket::vlan_tags`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VlanTagIter {
+    tags: [VlanTag; 2],
+    len: u8,
+    next: u8,
+}
+
+impl VlanTagIter {
+    /// Iterator that yields no tags.
+    #[inline]
+    pub fn empty() -> VlanTagIter {
+        VlanTagIter {
+            tags: [VlanTag::default(); 2],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    /// Iterator that yields a single tag.
+    #[inline]
+    pub fn single(tag: VlanTag) -> VlanTagIter {
+        VlanTagIter {
+            tags: [tag, VlanTag::default()],
+            len: 1,
+            next: 0,
+        }
+    }
+
+    /// Iterator that yields the outer and then the inner tag.
+    #[inline]
+    pub fn double(outer: VlanTag, inner: VlanTag) -> VlanTagIter {
+        VlanTagIter {
+            tags: [outer, inner],
+            len: 2,
+            next: 0,
+        }
+    }
+}
+
+impl Default for VlanTagIter {
+    #[inline]
+    fn default() -> Self {
+        VlanTagIter::empty()
+    }
+}
+
+impl Iterator for VlanTagIter {
+    type Item = VlanTag;
+
+    #[inline]
+    fn next(&mut self) -> Option<VlanTag> {
+        if self.next < self.len {
+            let result = self.tags[usize::from(self.next)];
+            self.next += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::from(self.len - self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for VlanTagIter {}
+
+impl core::iter::FusedIterator for VlanTagIter {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    fn tag(vlan_id: u16) -> VlanTag {
+        VlanTag {
+            vlan_id: VlanId::try_new(vlan_id).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut iter = VlanTagIter::empty();
+        assert_eq!(0, iter.len());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(VlanTagIter::empty(), VlanTagIter::default());
+    }
+
+    #[test]
+    fn single() {
+        let mut iter = VlanTagIter::single(tag(1));
+        assert_eq!(1, iter.len());
+        assert_eq!(Some(tag(1)), iter.next());
+        assert_eq!(0, iter.len());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn double() {
+        let iter = VlanTagIter::double(tag(1), tag(2));
+        assert_eq!((2, Some(2)), iter.size_hint());
+        assert_eq!(
+            alloc::vec![tag(1), tag(2)],
+            iter.clone().collect::<Vec<_>>()
+        );
+        assert_eq!(iter, iter.clone());
+        assert!(format!("{:?}", iter).starts_with("VlanTagIter"));
+    }
+}
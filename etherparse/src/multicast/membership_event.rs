@@ -0,0 +1,313 @@
+use crate::*;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// IGMP message type of a membership query.
+const IGMP_MEMBERSHIP_QUERY: u8 = 0x11;
+/// IGMP message type of a version 1 membership report.
+const IGMP_V1_MEMBERSHIP_REPORT: u8 = 0x12;
+/// IGMP message type of a version 2 membership report.
+const IGMP_V2_MEMBERSHIP_REPORT: u8 = 0x16;
+/// IGMP message type of a version 2 leave group message.
+const IGMP_LEAVE_GROUP: u8 = 0x17;
+
+/// Group membership relevant content of an IGMP or MLD message (consumed by
+/// the [`crate::multicast::MulticastMembershipPool`]).
+///
+/// Events can be decoded from IGMPv1, IGMPv2 & MLDv1 messages as well as
+/// IGMPv3 & MLDv2 queries via [`MembershipEvent::from_sliced_packet`]. Source
+/// specific IGMPv3 & MLDv2 reports are not decoded, but can be mapped by the
+/// caller (e.g. a `CHANGE_TO_INCLUDE_MODE` record without sources to
+/// [`MembershipEvent::Leave`] and all other records to
+/// [`MembershipEvent::Report`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MembershipEvent {
+    /// A host reported being a member of the group.
+    Report { group: IpAddr },
+    /// A host left the group.
+    Leave { group: IpAddr },
+    /// General query asking for reports of all groups.
+    GeneralQuery,
+    /// Group specific query asking if there are still members of the group.
+    GroupQuery { group: IpAddr },
+}
+
+impl MembershipEvent {
+    /// Decodes an IGMP message (the payload of an IPv4 packet with the
+    /// ip number [`IpNumber::IGMP`]).
+    ///
+    /// Returns `None` if the message is too short or not a membership
+    /// query, IGMPv1/IGMPv2 report or leave group message.
+    pub fn from_igmp(payload: &[u8]) -> Option<MembershipEvent> {
+        if payload.len() < 8 {
+            return None;
+        }
+        let group = IpAddr::V4(Ipv4Addr::new(
+            payload[4], payload[5], payload[6], payload[7],
+        ));
+        match payload[0] {
+            IGMP_MEMBERSHIP_QUERY => {
+                if group.is_unspecified() {
+                    Some(MembershipEvent::GeneralQuery)
+                } else {
+                    Some(MembershipEvent::GroupQuery { group })
+                }
+            }
+            IGMP_V1_MEMBERSHIP_REPORT | IGMP_V2_MEMBERSHIP_REPORT => {
+                Some(MembershipEvent::Report { group })
+            }
+            IGMP_LEAVE_GROUP => Some(MembershipEvent::Leave { group }),
+            _ => None,
+        }
+    }
+
+    /// Decodes a MLD message.
+    ///
+    /// Returns `None` if the message is too short or not a multicast
+    /// listener query, MLDv1 report or MLDv1 done message.
+    pub fn from_mld(icmpv6: &Icmpv6Slice) -> Option<MembershipEvent> {
+        let payload = icmpv6.payload();
+        if payload.len() < 16 {
+            return None;
+        }
+        let mut addr = [0u8; 16];
+        addr.copy_from_slice(&payload[..16]);
+        let group = IpAddr::V6(Ipv6Addr::from(addr));
+        match icmpv6.type_u8() {
+            icmpv6::TYPE_MULTICAST_LISTENER_QUERY => {
+                if group.is_unspecified() {
+                    Some(MembershipEvent::GeneralQuery)
+                } else {
+                    Some(MembershipEvent::GroupQuery { group })
+                }
+            }
+            icmpv6::TYPE_MULTICAST_LISTENER_REPORT => Some(MembershipEvent::Report { group }),
+            icmpv6::TYPE_MULTICAST_LISTENER_REDUCTION => Some(MembershipEvent::Leave { group }),
+            _ => None,
+        }
+    }
+
+    /// Decodes the IGMP or MLD message contained in a sliced packet
+    /// (`None` if the packet contains no decodable message or if the
+    /// IGMP message is fragmented).
+    ///
+    /// ```
+    /// use core::net::{IpAddr, Ipv4Addr};
+    /// use etherparse::{multicast::MembershipEvent, IpNumber, PacketBuilder, SlicedPacket};
+    ///
+    /// // IGMPv2 membership report for 239.1.2.3
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv4([10, 0, 0, 1], [239, 1, 2, 3], 1)
+    ///     .write(&mut packet, IpNumber::IGMP, &[0x16, 0, 0, 0, 239, 1, 2, 3])
+    ///     .unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// assert_eq!(
+    ///     Some(MembershipEvent::Report {
+    ///         group: IpAddr::V4(Ipv4Addr::new(239, 1, 2, 3))
+    ///     }),
+    ///     MembershipEvent::from_sliced_packet(&sliced)
+    /// );
+    /// ```
+    pub fn from_sliced_packet(slice: &SlicedPacket) -> Option<MembershipEvent> {
+        if let Some(TransportSlice::Icmpv6(icmpv6)) = &slice.transport {
+            return MembershipEvent::from_mld(icmpv6);
+        }
+        match &slice.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                let payload = ipv4.payload();
+                if payload.ip_number == IpNumber::IGMP && false == payload.fragmented {
+                    MembershipEvent::from_igmp(payload.payload)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Group address of the event (`None` for general queries).
+    #[inline]
+    pub fn group(&self) -> Option<IpAddr> {
+        use MembershipEvent::*;
+        match self {
+            Report { group } | Leave { group } | GroupQuery { group } => Some(*group),
+            GeneralQuery => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{format, vec::Vec};
+
+    fn v4(a: u8, b: u8, c: u8, d: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+    }
+
+    #[test]
+    fn from_igmp() {
+        use MembershipEvent::*;
+        let tests = [
+            (0x11, [0, 0, 0, 0], Some(GeneralQuery)),
+            (
+                0x11,
+                [239, 1, 2, 3],
+                Some(GroupQuery {
+                    group: v4(239, 1, 2, 3),
+                }),
+            ),
+            (
+                0x12,
+                [224, 0, 0, 5],
+                Some(Report {
+                    group: v4(224, 0, 0, 5),
+                }),
+            ),
+            (
+                0x16,
+                [239, 1, 2, 3],
+                Some(Report {
+                    group: v4(239, 1, 2, 3),
+                }),
+            ),
+            (
+                0x17,
+                [239, 1, 2, 3],
+                Some(Leave {
+                    group: v4(239, 1, 2, 3),
+                }),
+            ),
+            // igmpv3 report
+            (0x22, [0, 0, 0, 1], None),
+        ];
+        for (igmp_type, group, expected) in tests {
+            let data = [igmp_type, 0, 0, 0, group[0], group[1], group[2], group[3]];
+            assert_eq!(expected, MembershipEvent::from_igmp(&data));
+            // too short
+            assert_eq!(None, MembershipEvent::from_igmp(&data[..7]));
+        }
+    }
+
+    #[test]
+    fn from_mld() {
+        use MembershipEvent::*;
+        let group = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xfb);
+        let tests = [
+            (130, Ipv6Addr::UNSPECIFIED, Some(GeneralQuery)),
+            (
+                130,
+                group,
+                Some(GroupQuery {
+                    group: IpAddr::V6(group),
+                }),
+            ),
+            (
+                131,
+                group,
+                Some(Report {
+                    group: IpAddr::V6(group),
+                }),
+            ),
+            (
+                132,
+                group,
+                Some(Leave {
+                    group: IpAddr::V6(group),
+                }),
+            ),
+            // echo request
+            (128, group, None),
+        ];
+        for (icmp_type, addr, expected) in tests {
+            let mut data = Vec::from([icmp_type, 0, 0, 0, 0, 0, 0, 0]);
+            data.extend_from_slice(&addr.octets());
+            let slice = Icmpv6Slice::from_slice(&data).unwrap();
+            assert_eq!(expected, MembershipEvent::from_mld(&slice));
+            // too short
+            let slice = Icmpv6Slice::from_slice(&data[..data.len() - 1]).unwrap();
+            assert_eq!(None, MembershipEvent::from_mld(&slice));
+        }
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    #[test]
+    fn from_sliced_packet() {
+        // igmp
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([10, 0, 0, 1], [224, 0, 0, 2], 1)
+                .write(&mut packet, IpNumber::IGMP, &[0x17, 0, 0, 0, 239, 1, 2, 3])
+                .unwrap();
+            let slice = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(
+                Some(MembershipEvent::Leave {
+                    group: v4(239, 1, 2, 3)
+                }),
+                MembershipEvent::from_sliced_packet(&slice)
+            );
+        }
+        // fragmented igmp
+        {
+            let mut packet = Vec::new();
+            let mut ipv4 =
+                Ipv4Header::new(8, 1, IpNumber::IGMP, [10, 0, 0, 1], [224, 0, 0, 2]).unwrap();
+            ipv4.more_fragments = true;
+            ipv4.write(&mut packet).unwrap();
+            packet.extend_from_slice(&[0x17, 0, 0, 0, 239, 1, 2, 3]);
+            let slice = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(None, MembershipEvent::from_sliced_packet(&slice));
+        }
+        // mld
+        {
+            let group = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xfb);
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6([1; 16], group.octets(), 1)
+                .icmpv6(Icmpv6Type::Unknown {
+                    type_u8: icmpv6::TYPE_MULTICAST_LISTENER_REPORT,
+                    code_u8: 0,
+                    bytes5to8: [0; 4],
+                })
+                .write(&mut packet, &group.octets())
+                .unwrap();
+            let slice = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(
+                Some(MembershipEvent::Report {
+                    group: IpAddr::V6(group)
+                }),
+                MembershipEvent::from_sliced_packet(&slice)
+            );
+        }
+        // other packets
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([10, 0, 0, 1], [10, 0, 0, 2], 1)
+                .udp(1, 2)
+                .write(&mut packet, &[0x16, 0, 0, 0, 239, 1, 2, 3])
+                .unwrap();
+            let slice = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(None, MembershipEvent::from_sliced_packet(&slice));
+
+            let slice = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+            assert_eq!(None, MembershipEvent::from_sliced_packet(&slice));
+        }
+    }
+
+    #[test]
+    fn group() {
+        let group = v4(239, 1, 2, 3);
+        assert_eq!(Some(group), MembershipEvent::Report { group }.group());
+        assert_eq!(Some(group), MembershipEvent::Leave { group }.group());
+        assert_eq!(Some(group), MembershipEvent::GroupQuery { group }.group());
+        assert_eq!(None, MembershipEvent::GeneralQuery.group());
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = MembershipEvent::Report {
+            group: v4(239, 1, 2, 3),
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!("Report { group: 239.1.2.3 }", format!("{:?}", value));
+    }
+}
@@ -0,0 +1,63 @@
+use core::time::Duration;
+
+/// Timer values used by the [`crate::multicast::MulticastMembershipPool`].
+///
+/// The default values are the defaults of IGMPv3 (RFC 3376 section 8) and
+/// MLDv2 (RFC 3810 section 9), which are derived from a robustness variable
+/// of 2, a query interval of 125 seconds, a query response interval of 10
+/// seconds & a last member query interval of 1 second.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct MembershipTimers {
+    /// Time after which a group membership expires if no further report
+    /// is received ("Group Membership Interval" in IGMP & "Multicast
+    /// Address Listening Interval" in MLD).
+    pub group_membership_interval: Duration,
+
+    /// Time after which a group membership expires after a leave or a
+    /// group specific query was received, if no further report is received
+    /// ("Last Member Query Time" in IGMP & "Last Listener Query Time" in MLD).
+    pub last_member_query_time: Duration,
+}
+
+impl MembershipTimers {
+    /// Default "Group Membership Interval" (260 seconds).
+    pub const DEFAULT_GROUP_MEMBERSHIP_INTERVAL: Duration = Duration::from_secs(260);
+
+    /// Default "Last Member Query Time" (2 seconds).
+    pub const DEFAULT_LAST_MEMBER_QUERY_TIME: Duration = Duration::from_secs(2);
+}
+
+impl Default for MembershipTimers {
+    fn default() -> Self {
+        MembershipTimers {
+            group_membership_interval: MembershipTimers::DEFAULT_GROUP_MEMBERSHIP_INTERVAL,
+            last_member_query_time: MembershipTimers::DEFAULT_LAST_MEMBER_QUERY_TIME,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn default() {
+        let actual = MembershipTimers::default();
+        assert_eq!(Duration::from_secs(260), actual.group_membership_interval);
+        assert_eq!(Duration::from_secs(2), actual.last_member_query_time);
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = MembershipTimers::default();
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!(
+                "MembershipTimers {{ group_membership_interval: {:?}, last_member_query_time: {:?} }}",
+                value.group_membership_interval, value.last_member_query_time
+            ),
+            format!("{:?}", value)
+        );
+    }
+}
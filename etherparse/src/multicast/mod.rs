@@ -0,0 +1,8 @@
+mod membership_event;
+pub use membership_event::*;
+
+mod membership_timers;
+pub use membership_timers::*;
+
+mod multicast_membership_pool;
+pub use multicast_membership_pool::*;
@@ -0,0 +1,264 @@
+use crate::multicast::*;
+use core::{net::IpAddr, time::Duration};
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Tracks the multicast group memberships of multiple interfaces based on
+/// the IGMP & MLD messages observed on them (e.g. for emulating IGMP/MLD
+/// snooping switches or multicast routers in test harnesses).
+///
+/// Memberships are kept per interface (the `InterfaceId` is chosen by the
+/// user) and group. The timers follow the router side behavior of IGMPv3
+/// (RFC 3376 section 6) & MLDv2 (RFC 3810 section 7) without source
+/// filtering:
+///
+/// * A [`MembershipEvent::Report`] (re-)starts the group timer with the
+///   [`MembershipTimers::group_membership_interval`].
+/// * A [`MembershipEvent::Leave`] or [`MembershipEvent::GroupQuery`] lowers
+///   the group timer to the [`MembershipTimers::last_member_query_time`]
+///   (the membership expires unless a report is received in time).
+/// * A [`MembershipEvent::GeneralQuery`] does not change any timers.
+///
+/// Time is passed in as a [`Duration`] since an arbitrary (but fixed) point
+/// in time, which allows driving the pool from packet capture timestamps.
+/// Expired memberships are only removed when [`MulticastMembershipPool::expire`]
+/// is called:
+///
+/// ```
+/// use core::{net::{IpAddr, Ipv4Addr}, time::Duration};
+/// use etherparse::multicast::{MembershipEvent, MembershipTimers, MulticastMembershipPool};
+///
+/// let group = IpAddr::V4(Ipv4Addr::new(239, 1, 2, 3));
+/// let mut pool = MulticastMembershipPool::<&str>::new(MembershipTimers::default());
+///
+/// pool.process("eth0", MembershipEvent::Report { group }, Duration::from_secs(0));
+/// assert!(pool.is_member(&"eth0", group));
+/// assert!(false == pool.is_member(&"eth1", group));
+///
+/// // the last member leaves, the membership expires after the last member query time
+/// pool.process("eth0", MembershipEvent::Leave { group }, Duration::from_secs(10));
+/// assert_eq!(Vec::<(&str, IpAddr)>::new(), pool.expire(Duration::from_secs(11)));
+/// assert_eq!(vec![("eth0", group)], pool.expire(Duration::from_secs(12)));
+/// assert!(false == pool.is_member(&"eth0", group));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MulticastMembershipPool<InterfaceId = ()>
+where
+    InterfaceId: Sized + core::fmt::Debug + Clone + core::hash::Hash + Eq + PartialEq,
+{
+    /// Timers used to calculate the expiry times.
+    timers: MembershipTimers,
+
+    /// Expiry times of the current memberships.
+    memberships: HashMap<(InterfaceId, IpAddr), Duration>,
+}
+
+impl<InterfaceId> MulticastMembershipPool<InterfaceId>
+where
+    InterfaceId: Sized + core::fmt::Debug + Clone + core::hash::Hash + Eq + PartialEq,
+{
+    /// Creates an empty pool using the given timers to compute expiry times.
+    pub fn new(timers: MembershipTimers) -> MulticastMembershipPool<InterfaceId> {
+        MulticastMembershipPool {
+            timers,
+            memberships: HashMap::new(),
+        }
+    }
+
+    /// Timers used to calculate the expiry times.
+    #[inline]
+    pub fn timers(&self) -> &MembershipTimers {
+        &self.timers
+    }
+
+    /// Updates the memberships of the interface based on an event observed
+    /// at the time `now`.
+    pub fn process(&mut self, interface: InterfaceId, event: MembershipEvent, now: Duration) {
+        use MembershipEvent::*;
+        match event {
+            Report { group } => {
+                self.memberships.insert(
+                    (interface, group),
+                    now.saturating_add(self.timers.group_membership_interval),
+                );
+            }
+            Leave { group } | GroupQuery { group } => {
+                if let Some(expiry) = self.memberships.get_mut(&(interface, group)) {
+                    let lowered = now.saturating_add(self.timers.last_member_query_time);
+                    if lowered < *expiry {
+                        *expiry = lowered;
+                    }
+                }
+            }
+            GeneralQuery => {}
+        }
+    }
+
+    /// Removes all memberships that expired at the time `now` & returns
+    /// the removed interface & group pairs.
+    pub fn expire(&mut self, now: Duration) -> Vec<(InterfaceId, IpAddr)> {
+        let expired: Vec<_> = self
+            .memberships
+            .iter()
+            .filter(|(_, expiry)| **expiry <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            self.memberships.remove(key);
+        }
+        expired
+    }
+
+    /// Returns true if the interface is a member of the group (memberships
+    /// that expired but were not yet removed via
+    /// [`MulticastMembershipPool::expire`] are still reported).
+    #[inline]
+    pub fn is_member(&self, interface: &InterfaceId, group: IpAddr) -> bool {
+        self.memberships.contains_key(&(interface.clone(), group))
+    }
+
+    /// Returns the time at which the membership of the interface in the
+    /// group expires (`None` if the interface is not a member).
+    #[inline]
+    pub fn expiry(&self, interface: &InterfaceId, group: IpAddr) -> Option<Duration> {
+        self.memberships.get(&(interface.clone(), group)).copied()
+    }
+
+    /// Returns an iterator over the groups the interface is a member of
+    /// (in no particular order).
+    pub fn groups<'b>(&'b self, interface: &'b InterfaceId) -> impl Iterator<Item = IpAddr> + 'b {
+        self.memberships
+            .keys()
+            .filter(move |(i, _)| i == interface)
+            .map(|(_, group)| *group)
+    }
+
+    /// Number of memberships over all interfaces.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.memberships.len()
+    }
+
+    /// Returns true if there are no memberships.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.memberships.is_empty()
+    }
+}
+
+impl<InterfaceId> Default for MulticastMembershipPool<InterfaceId>
+where
+    InterfaceId: Sized + core::fmt::Debug + Clone + core::hash::Hash + Eq + PartialEq,
+{
+    fn default() -> Self {
+        Self::new(MembershipTimers::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::net::{Ipv4Addr, Ipv6Addr};
+    use std::{format, vec};
+
+    const fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    #[test]
+    fn report_leave_expire() {
+        use MembershipEvent::*;
+        let g1 = IpAddr::V4(Ipv4Addr::new(239, 1, 2, 3));
+        let g2 = IpAddr::V6(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xfb));
+        let mut pool = MulticastMembershipPool::<u8>::default();
+        assert!(pool.is_empty());
+
+        pool.process(1, Report { group: g1 }, secs(0));
+        pool.process(1, Report { group: g2 }, secs(0));
+        pool.process(2, Report { group: g1 }, secs(100));
+        assert_eq!(3, pool.len());
+        assert!(false == pool.is_empty());
+        assert!(pool.is_member(&1, g1));
+        assert!(pool.is_member(&2, g1));
+        assert!(false == pool.is_member(&2, g2));
+        assert_eq!(Some(secs(260)), pool.expiry(&1, g1));
+        assert_eq!(Some(secs(360)), pool.expiry(&2, g1));
+        assert_eq!(None, pool.expiry(&3, g1));
+        {
+            let mut groups: Vec<_> = pool.groups(&1).collect();
+            groups.sort();
+            assert_eq!(vec![g1, g2], groups);
+        }
+
+        // general queries don't change timers
+        pool.process(1, GeneralQuery, secs(50));
+        assert_eq!(Some(secs(260)), pool.expiry(&1, g1));
+
+        // reports refresh the timer
+        pool.process(1, Report { group: g1 }, secs(100));
+        assert_eq!(Some(secs(360)), pool.expiry(&1, g1));
+
+        // leave lowers the timer
+        pool.process(1, Leave { group: g1 }, secs(200));
+        assert_eq!(Some(secs(202)), pool.expiry(&1, g1));
+        // ... but never raises it
+        pool.process(1, GroupQuery { group: g1 }, secs(201));
+        assert_eq!(Some(secs(202)), pool.expiry(&1, g1));
+        // leave & group queries for non members are ignored
+        pool.process(3, Leave { group: g1 }, secs(200));
+        pool.process(3, GroupQuery { group: g1 }, secs(200));
+        assert_eq!(None, pool.expiry(&3, g1));
+
+        // expire
+        assert_eq!(Vec::<(u8, IpAddr)>::new(), pool.expire(secs(201)));
+        assert_eq!(vec![(1, g1)], pool.expire(secs(202)));
+        assert!(false == pool.is_member(&1, g1));
+        {
+            let mut expired = pool.expire(secs(360));
+            expired.sort();
+            assert_eq!(vec![(1, g2), (2, g1)], expired);
+        }
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn group_query_lowers_timer() {
+        let group = IpAddr::V4(Ipv4Addr::new(239, 1, 2, 3));
+        let mut pool = MulticastMembershipPool::<()>::new(MembershipTimers {
+            group_membership_interval: secs(10),
+            last_member_query_time: secs(5),
+        });
+        assert_eq!(secs(10), pool.timers().group_membership_interval);
+        pool.process((), MembershipEvent::Report { group }, secs(0));
+        pool.process((), MembershipEvent::GroupQuery { group }, secs(1));
+        assert_eq!(Some(secs(6)), pool.expiry(&(), group));
+        // a report during the last member query time keeps the membership
+        pool.process((), MembershipEvent::Report { group }, secs(3));
+        assert_eq!(Vec::<((), IpAddr)>::new(), pool.expire(secs(6)));
+        assert_eq!(Some(secs(13)), pool.expiry(&(), group));
+    }
+
+    #[test]
+    fn saturating_timers() {
+        let group = IpAddr::V4(Ipv4Addr::new(239, 1, 2, 3));
+        let mut pool = MulticastMembershipPool::<()>::default();
+        pool.process((), MembershipEvent::Report { group }, Duration::MAX);
+        assert_eq!(Some(Duration::MAX), pool.expiry(&(), group));
+        pool.process((), MembershipEvent::Leave { group }, Duration::MAX);
+        assert_eq!(Some(Duration::MAX), pool.expiry(&(), group));
+    }
+
+    #[test]
+    fn debug_clone() {
+        let pool = MulticastMembershipPool::<()>::default();
+        let clone = pool.clone();
+        assert_eq!(pool.timers(), clone.timers());
+        assert_eq!(
+            format!(
+                "MulticastMembershipPool {{ timers: {:?}, memberships: {{}} }}",
+                MembershipTimers::default()
+            ),
+            format!("{:?}", pool)
+        );
+    }
+}
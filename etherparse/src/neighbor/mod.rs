@@ -0,0 +1,17 @@
+mod neighbor_cache;
+pub use neighbor_cache::*;
+
+mod neighbor_entry;
+pub use neighbor_entry::*;
+
+mod neighbor_eviction_policy;
+pub use neighbor_eviction_policy::*;
+
+mod neighbor_observation;
+pub use neighbor_observation::*;
+
+mod neighbor_state;
+pub use neighbor_state::*;
+
+mod neighbor_update;
+pub use neighbor_update::*;
@@ -0,0 +1,447 @@
+use crate::{neighbor::*, *};
+use core::net::IpAddr;
+use std::collections::HashMap;
+
+/// Table of IP to MAC address mappings learned by passively observing ARP
+/// & NDP messages (e.g. for asset discovery or detecting address changes).
+///
+/// Mappings are decoded via [`NeighborObservation`] & passed to
+/// [`NeighborCache::process`] together with a user defined timestamp (e.g.
+/// the capture time of the packet). [`NeighborCache::process_sliced_packet`]
+/// combines both steps. Entries become [`NeighborState::Stale`] when
+/// [`NeighborCache::mark_stale`] is called & are only removed via
+/// [`NeighborCache::retain`], [`NeighborCache::remove`] or eviction.
+///
+/// # Size limit
+///
+/// A cache created via [`NeighborCache::with_limits`] holds at most
+/// `max_entries` entries. If a new neighbor is seen while the cache is
+/// full, the [`NeighborEvictionPolicy`] decides if the least recently seen
+/// entry is evicted or the new neighbor is ignored. Finding the entry to
+/// evict requires a scan over all entries, so the cache is intended for
+/// small to medium sized networks.
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr};
+/// use etherparse::neighbor::*;
+///
+/// let mut cache = NeighborCache::<u64>::with_limits(1, NeighborEvictionPolicy::EvictOldest);
+/// let a = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+/// let b = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
+///
+/// let observation = NeighborObservation {
+///     ip: a,
+///     mac: [1, 2, 3, 4, 5, 6],
+///     state: NeighborState::Reachable,
+/// };
+/// assert_eq!(NeighborUpdate::Added { evicted: None }, cache.process(observation, 10));
+/// assert_eq!(NeighborUpdate::Refreshed, cache.process(observation, 20));
+/// assert_eq!(Some(20), cache.get(&a).map(|e| e.last_seen));
+///
+/// // the cache is full, the oldest entry gets evicted
+/// let observation = NeighborObservation {
+///     ip: b,
+///     mac: [7, 8, 9, 10, 11, 12],
+///     state: NeighborState::Stale,
+/// };
+/// assert_eq!(NeighborUpdate::Added { evicted: Some(a) }, cache.process(observation, 30));
+/// assert_eq!(None, cache.get(&a));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NeighborCache<Timestamp = ()>
+where
+    Timestamp: Sized + core::fmt::Debug + Clone + Ord,
+{
+    /// Known neighbors.
+    entries: HashMap<IpAddr, NeighborEntry<Timestamp>>,
+
+    /// Maximum number of entries (`None` if unlimited).
+    max_entries: Option<usize>,
+
+    /// Policy applied if a new neighbor is seen while the cache is full.
+    eviction_policy: NeighborEvictionPolicy,
+}
+
+impl<Timestamp> NeighborCache<Timestamp>
+where
+    Timestamp: Sized + core::fmt::Debug + Clone + Ord,
+{
+    /// Creates a cache without a size limit.
+    pub fn new() -> NeighborCache<Timestamp> {
+        NeighborCache {
+            entries: HashMap::new(),
+            max_entries: None,
+            eviction_policy: NeighborEvictionPolicy::default(),
+        }
+    }
+
+    /// Creates a cache holding at most `max_entries` entries (memory for
+    /// the entries is allocated during construction).
+    pub fn with_limits(
+        max_entries: usize,
+        eviction_policy: NeighborEvictionPolicy,
+    ) -> NeighborCache<Timestamp> {
+        NeighborCache {
+            entries: HashMap::with_capacity(max_entries),
+            max_entries: Some(max_entries),
+            eviction_policy,
+        }
+    }
+
+    /// Maximum number of entries (`None` if the cache was created via
+    /// [`NeighborCache::new`] and has no size limit).
+    #[inline]
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// Policy applied if a new neighbor is seen while the cache is full.
+    #[inline]
+    pub fn eviction_policy(&self) -> NeighborEvictionPolicy {
+        self.eviction_policy
+    }
+
+    /// Adds or updates the entry of the observed neighbor.
+    ///
+    /// If an entry with the same MAC address already exists, `last_seen` is
+    /// updated & the state is raised to [`NeighborState::Reachable`] if the
+    /// observation confirms reachability (a stale observation does not
+    /// lower the state). If the MAC address differs, the entry is replaced.
+    pub fn process(
+        &mut self,
+        observation: NeighborObservation,
+        timestamp: Timestamp,
+    ) -> NeighborUpdate {
+        if let Some(entry) = self.entries.get_mut(&observation.ip) {
+            if entry.mac == observation.mac {
+                entry.last_seen = timestamp;
+                if NeighborState::Reachable == observation.state {
+                    entry.state = NeighborState::Reachable;
+                }
+                return NeighborUpdate::Refreshed;
+            }
+            let previous_mac = entry.mac;
+            *entry = NeighborEntry {
+                mac: observation.mac,
+                state: observation.state,
+                first_seen: timestamp.clone(),
+                last_seen: timestamp,
+            };
+            return NeighborUpdate::MacChanged { previous_mac };
+        }
+
+        let mut evicted = None;
+        if let Some(max_entries) = self.max_entries {
+            if self.entries.len() >= max_entries {
+                if NeighborEvictionPolicy::RejectNew == self.eviction_policy {
+                    return NeighborUpdate::Rejected;
+                }
+                // stale entries are evicted before reachable entries
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by(|(_, a), (_, b)| (a.state, &a.last_seen).cmp(&(b.state, &b.last_seen)))
+                    .map(|(ip, _)| *ip);
+                let Some(oldest) = oldest else {
+                    // cache with a size limit of 0
+                    return NeighborUpdate::Rejected;
+                };
+                self.entries.remove(&oldest);
+                evicted = Some(oldest);
+            }
+        }
+        self.entries.insert(
+            observation.ip,
+            NeighborEntry {
+                mac: observation.mac,
+                state: observation.state,
+                first_seen: timestamp.clone(),
+                last_seen: timestamp,
+            },
+        );
+        NeighborUpdate::Added { evicted }
+    }
+
+    /// Decodes the ARP or NDP message contained in the sliced packet &
+    /// processes the contained mapping (`None` if the packet contains no
+    /// mapping, see [`NeighborObservation::from_sliced_packet`]).
+    pub fn process_sliced_packet(
+        &mut self,
+        slice: &SlicedPacket,
+        timestamp: Timestamp,
+    ) -> Option<NeighborUpdate> {
+        let observation = NeighborObservation::from_sliced_packet(slice)?;
+        Some(self.process(observation, timestamp))
+    }
+
+    /// Marks all entries that were last seen before `timestamp` as
+    /// [`NeighborState::Stale`].
+    pub fn mark_stale(&mut self, timestamp: &Timestamp) {
+        for entry in self.entries.values_mut() {
+            if entry.last_seen < *timestamp {
+                entry.state = NeighborState::Stale;
+            }
+        }
+    }
+
+    /// Retains only the entries specified by the predicate.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&IpAddr, &NeighborEntry<Timestamp>) -> bool,
+    {
+        self.entries.retain(|ip, entry| f(ip, entry));
+    }
+
+    /// Removes the entry of the given IP address & returns it.
+    #[inline]
+    pub fn remove(&mut self, ip: &IpAddr) -> Option<NeighborEntry<Timestamp>> {
+        self.entries.remove(ip)
+    }
+
+    /// Returns the entry of the given IP address.
+    #[inline]
+    pub fn get(&self, ip: &IpAddr) -> Option<&NeighborEntry<Timestamp>> {
+        self.entries.get(ip)
+    }
+
+    /// Returns an iterator over all entries (in no particular order).
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&IpAddr, &NeighborEntry<Timestamp>)> {
+        self.entries.iter()
+    }
+
+    /// Number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<Timestamp> Default for NeighborCache<Timestamp>
+where
+    Timestamp: Sized + core::fmt::Debug + Clone + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::net::Ipv4Addr;
+    use std::{format, vec::Vec};
+
+    fn ip(last: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, last))
+    }
+
+    fn observation(last: u8, mac: u8, state: NeighborState) -> NeighborObservation {
+        NeighborObservation {
+            ip: ip(last),
+            mac: [mac; 6],
+            state,
+        }
+    }
+
+    #[test]
+    fn process() {
+        use NeighborState::*;
+        use NeighborUpdate::*;
+        let mut cache = NeighborCache::<u32>::new();
+        assert!(cache.is_empty());
+        assert_eq!(None, cache.max_entries());
+        assert_eq!(NeighborEvictionPolicy::EvictOldest, cache.eviction_policy());
+
+        // added
+        assert_eq!(
+            Added { evicted: None },
+            cache.process(observation(1, 1, Stale), 1)
+        );
+        assert_eq!(1, cache.len());
+        assert!(false == cache.is_empty());
+        assert_eq!(
+            Some(&NeighborEntry {
+                mac: [1; 6],
+                state: Stale,
+                first_seen: 1,
+                last_seen: 1,
+            }),
+            cache.get(&ip(1))
+        );
+
+        // refreshed & raised to reachable
+        assert_eq!(Refreshed, cache.process(observation(1, 1, Reachable), 2));
+        assert_eq!(Reachable, cache.get(&ip(1)).unwrap().state);
+
+        // stale observations don't lower the state
+        assert_eq!(Refreshed, cache.process(observation(1, 1, Stale), 3));
+        assert_eq!(
+            Some(&NeighborEntry {
+                mac: [1; 6],
+                state: Reachable,
+                first_seen: 1,
+                last_seen: 3,
+            }),
+            cache.get(&ip(1))
+        );
+
+        // mac changed
+        assert_eq!(
+            MacChanged {
+                previous_mac: [1; 6]
+            },
+            cache.process(observation(1, 2, Stale), 4)
+        );
+        assert_eq!(
+            Some(&NeighborEntry {
+                mac: [2; 6],
+                state: Stale,
+                first_seen: 4,
+                last_seen: 4,
+            }),
+            cache.get(&ip(1))
+        );
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn with_limits() {
+        use NeighborState::*;
+        use NeighborUpdate::*;
+
+        // evict oldest (stale entries first)
+        {
+            let mut cache =
+                NeighborCache::<u32>::with_limits(3, NeighborEvictionPolicy::EvictOldest);
+            assert_eq!(Some(3), cache.max_entries());
+            cache.process(observation(1, 1, Reachable), 1);
+            cache.process(observation(2, 2, Stale), 3);
+            cache.process(observation(3, 3, Stale), 2);
+            assert_eq!(
+                Added {
+                    evicted: Some(ip(3))
+                },
+                cache.process(observation(4, 4, Reachable), 4)
+            );
+            assert_eq!(
+                Added {
+                    evicted: Some(ip(2))
+                },
+                cache.process(observation(5, 5, Reachable), 5)
+            );
+            assert_eq!(
+                Added {
+                    evicted: Some(ip(1))
+                },
+                cache.process(observation(6, 6, Stale), 6)
+            );
+            assert_eq!(3, cache.len());
+            // updates of existing entries don't evict
+            assert_eq!(Refreshed, cache.process(observation(4, 4, Stale), 7));
+            assert_eq!(3, cache.len());
+        }
+
+        // reject new
+        {
+            let mut cache = NeighborCache::<u32>::with_limits(1, NeighborEvictionPolicy::RejectNew);
+            assert_eq!(NeighborEvictionPolicy::RejectNew, cache.eviction_policy());
+            assert_eq!(
+                Added { evicted: None },
+                cache.process(observation(1, 1, Stale), 1)
+            );
+            assert_eq!(Rejected, cache.process(observation(2, 2, Reachable), 2));
+            assert_eq!(None, cache.get(&ip(2)));
+            assert_eq!(
+                MacChanged {
+                    previous_mac: [1; 6]
+                },
+                cache.process(observation(1, 2, Stale), 3)
+            );
+        }
+
+        // no entries allowed
+        {
+            let mut cache =
+                NeighborCache::<u32>::with_limits(0, NeighborEvictionPolicy::EvictOldest);
+            assert_eq!(Rejected, cache.process(observation(1, 1, Stale), 1));
+            assert!(cache.is_empty());
+        }
+    }
+
+    #[test]
+    fn mark_stale_retain_remove() {
+        use NeighborState::*;
+        let mut cache = NeighborCache::<u32>::default();
+        cache.process(observation(1, 1, Reachable), 1);
+        cache.process(observation(2, 2, Reachable), 2);
+        cache.process(observation(3, 3, Reachable), 3);
+
+        cache.mark_stale(&2);
+        assert_eq!(Stale, cache.get(&ip(1)).unwrap().state);
+        assert_eq!(Reachable, cache.get(&ip(2)).unwrap().state);
+        assert_eq!(Reachable, cache.get(&ip(3)).unwrap().state);
+
+        cache.retain(|_, entry| entry.state == Reachable);
+        {
+            let mut ips: Vec<_> = cache.iter().map(|(ip, _)| *ip).collect();
+            ips.sort();
+            assert_eq!(ips, [ip(2), ip(3)]);
+        }
+
+        assert_eq!(Some([2; 6]), cache.remove(&ip(2)).map(|e| e.mac));
+        assert_eq!(None, cache.remove(&ip(2)));
+        assert_eq!(1, cache.len());
+    }
+
+    #[cfg(feature = "arp")]
+    #[test]
+    fn process_sliced_packet() {
+        let arp = ArpEthIpv4Packet {
+            operation: ArpOperation::REPLY,
+            sender_mac: [1; 6],
+            sender_ipv4: [10, 0, 0, 1],
+            target_mac: [2; 6],
+            target_ipv4: [10, 0, 0, 2],
+        }
+        .to_bytes();
+        let mut cache = NeighborCache::<u32>::new();
+        assert_eq!(
+            Some(NeighborUpdate::Added { evicted: None }),
+            cache.process_sliced_packet(
+                &SlicedPacket::from_ether_type(EtherType::ARP, &arp).unwrap(),
+                1
+            )
+        );
+        assert_eq!(NeighborState::Reachable, cache.get(&ip(1)).unwrap().state);
+        assert_eq!(
+            None,
+            cache.process_sliced_packet(
+                &SlicedPacket::from_ether_type(EtherType::LLDP, &[0, 0]).unwrap(),
+                2
+            )
+        );
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn debug_clone() {
+        let mut cache = NeighborCache::<u32>::with_limits(1, NeighborEvictionPolicy::RejectNew);
+        cache.process(observation(1, 1, NeighborState::Stale), 1);
+        let clone = cache.clone();
+        assert_eq!(cache.get(&ip(1)), clone.get(&ip(1)));
+        assert_eq!(
+            format!(
+                "NeighborCache {{ entries: {{10.0.0.1: {:?}}}, max_entries: Some(1), eviction_policy: RejectNew }}",
+                cache.get(&ip(1)).unwrap()
+            ),
+            format!("{:?}", cache)
+        );
+    }
+}
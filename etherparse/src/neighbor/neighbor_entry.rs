@@ -0,0 +1,37 @@
+use crate::neighbor::NeighborState;
+
+/// Entry of a [`crate::neighbor::NeighborCache`] (MAC address of a neighbor
+/// together with its state & timestamps).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NeighborEntry<Timestamp> {
+    /// MAC address of the neighbor.
+    pub mac: [u8; 6],
+    /// Reachability state of the neighbor.
+    pub state: NeighborState,
+    /// Timestamp of the first packet containing the current mapping (reset
+    /// if the MAC address changes).
+    pub first_seen: Timestamp,
+    /// Timestamp of the last packet containing the current mapping.
+    pub last_seen: Timestamp,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = NeighborEntry {
+            mac: [1, 2, 3, 4, 5, 6],
+            state: NeighborState::Stale,
+            first_seen: 1,
+            last_seen: 2,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "NeighborEntry { mac: [1, 2, 3, 4, 5, 6], state: Stale, first_seen: 1, last_seen: 2 }",
+            format!("{:?}", value)
+        );
+    }
+}
@@ -0,0 +1,29 @@
+/// Defines what a [`crate::neighbor::NeighborCache`] does if a new neighbor
+/// is seen while the cache already contains the maximum number of entries.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum NeighborEvictionPolicy {
+    /// Removes the least recently seen entry to make room for the new
+    /// neighbor (stale entries are evicted before reachable entries).
+    #[default]
+    EvictOldest,
+    /// Keeps the existing entries & ignores the new neighbor.
+    RejectNew,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn default_debug_clone_eq() {
+        let value: NeighborEvictionPolicy = Default::default();
+        assert_eq!(NeighborEvictionPolicy::EvictOldest, value);
+        assert_eq!(value, value.clone());
+        assert_eq!("EvictOldest", format!("{:?}", value));
+        assert_eq!(
+            "RejectNew",
+            format!("{:?}", NeighborEvictionPolicy::RejectNew)
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use crate::{neighbor::NeighborState, *};
+use core::net::{IpAddr, Ipv6Addr};
+
+/// NDP option type of a "Source Link-Layer Address" option.
+const NDP_OPTION_SOURCE_LINK_LAYER_ADDRESS: u8 = 1;
+/// NDP option type of a "Target Link-Layer Address" option.
+const NDP_OPTION_TARGET_LINK_LAYER_ADDRESS: u8 = 2;
+
+/// IP to MAC address mapping contained in an ARP or NDP message (consumed
+/// by the [`crate::neighbor::NeighborCache`]).
+///
+/// The following messages are decoded:
+///
+/// * ARP requests & replies (sender addresses). ARP probes (sender address
+///   `0.0.0.0`) contain no mapping.
+/// * NDP Router Solicitations, Router Advertisements & Neighbor
+///   Solicitations (IPv6 source address & "Source Link-Layer Address"
+///   option).
+/// * NDP Neighbor Advertisements (target address & "Target Link-Layer
+///   Address" option).
+///
+/// Only replies to a request (non gratuitous ARP replies & Neighbor
+/// Advertisements with the "solicited" flag set) result in the state
+/// [`NeighborState::Reachable`], all other messages result in
+/// [`NeighborState::Stale`] (similar to RFC 4861 section 7.3.3).
+///
+/// Checksums & the IPv6 hop limit are not verified, this is left to the
+/// caller (e.g. via [`Icmpv6SliceWithAddrs::is_checksum_valid`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct NeighborObservation {
+    /// IP address of the neighbor.
+    pub ip: IpAddr,
+    /// MAC address of the neighbor.
+    pub mac: [u8; 6],
+    /// State indicated by the message.
+    pub state: NeighborState,
+}
+
+impl NeighborObservation {
+    /// Decodes the sender mapping of an ARP request or reply.
+    ///
+    /// Returns `None` for other operations & ARP probes.
+    #[cfg(feature = "arp")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arp")))]
+    pub fn from_arp(arp: &ArpEthIpv4Packet) -> Option<NeighborObservation> {
+        if arp.is_sender_ipv4_unspecified() {
+            return None;
+        }
+        let state = if arp.operation == ArpOperation::REPLY {
+            if arp.is_gratuitous() {
+                NeighborState::Stale
+            } else {
+                NeighborState::Reachable
+            }
+        } else if arp.operation == ArpOperation::REQUEST {
+            NeighborState::Stale
+        } else {
+            return None;
+        };
+        Some(NeighborObservation {
+            ip: IpAddr::V4(arp.sender_ipv4.into()),
+            mac: arp.sender_mac,
+            state,
+        })
+    }
+
+    /// Decodes the mapping contained in a NDP message.
+    ///
+    /// Returns `None` if the message is not a Router Solicitation, Router
+    /// Advertisement, Neighbor Solicitation or Neighbor Advertisement, is
+    /// too short, the address is unspecified or no link-layer address
+    /// option with an Ethernet MAC address is present.
+    pub fn from_ndp(icmpv6: &Icmpv6SliceWithAddrs) -> Option<NeighborObservation> {
+        let slice = icmpv6.icmpv6();
+        if 0 != slice.code_u8() {
+            return None;
+        }
+        let payload = slice.payload();
+        let (ip, options, option_type, state) = match slice.type_u8() {
+            icmpv6::TYPE_ROUTER_SOLICITATION => (
+                icmpv6.source(),
+                payload,
+                NDP_OPTION_SOURCE_LINK_LAYER_ADDRESS,
+                NeighborState::Stale,
+            ),
+            icmpv6::TYPE_ROUTER_ADVERTISEMENT => (
+                icmpv6.source(),
+                // skip reachable time & retrans timer
+                payload.get(8..)?,
+                NDP_OPTION_SOURCE_LINK_LAYER_ADDRESS,
+                NeighborState::Stale,
+            ),
+            icmpv6::TYPE_NEIGHBOR_SOLICITATION => (
+                icmpv6.source(),
+                // skip target address
+                payload.get(16..)?,
+                NDP_OPTION_SOURCE_LINK_LAYER_ADDRESS,
+                NeighborState::Stale,
+            ),
+            icmpv6::TYPE_NEIGHBOR_ADVERTISEMENT => {
+                let mut target = [0u8; 16];
+                target.copy_from_slice(payload.get(..16)?);
+                let solicited = 0 != slice.bytes5to8()[0] & 0b0100_0000;
+                (
+                    target,
+                    &payload[16..],
+                    NDP_OPTION_TARGET_LINK_LAYER_ADDRESS,
+                    if solicited {
+                        NeighborState::Reachable
+                    } else {
+                        NeighborState::Stale
+                    },
+                )
+            }
+            _ => return None,
+        };
+        let ip = Ipv6Addr::from(ip);
+        if ip.is_unspecified() {
+            return None;
+        }
+        Some(NeighborObservation {
+            ip: IpAddr::V6(ip),
+            mac: ndp_link_layer_address(options, option_type)?,
+            state,
+        })
+    }
+
+    /// Decodes the ARP or NDP message contained in a sliced packet (`None`
+    /// if the packet contains no decodable mapping).
+    ///
+    /// ```
+    /// # #[cfg(feature = "arp")]
+    /// # {
+    /// use core::net::{IpAddr, Ipv4Addr};
+    /// use etherparse::{
+    ///     neighbor::{NeighborObservation, NeighborState},
+    ///     ArpEthIpv4Packet, ArpOperation, Ethernet2Header, EtherType, SlicedPacket,
+    /// };
+    ///
+    /// let mut packet = Vec::new();
+    /// Ethernet2Header {
+    ///     source: [1, 2, 3, 4, 5, 6],
+    ///     destination: [7, 8, 9, 10, 11, 12],
+    ///     ether_type: EtherType::ARP,
+    /// }
+    /// .write(&mut packet)
+    /// .unwrap();
+    /// packet.extend_from_slice(
+    ///     &ArpEthIpv4Packet {
+    ///         operation: ArpOperation::REPLY,
+    ///         sender_mac: [1, 2, 3, 4, 5, 6],
+    ///         sender_ipv4: [192, 168, 1, 1],
+    ///         target_mac: [7, 8, 9, 10, 11, 12],
+    ///         target_ipv4: [192, 168, 1, 2],
+    ///     }
+    ///     .to_bytes(),
+    /// );
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert_eq!(
+    ///     Some(NeighborObservation {
+    ///         ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)),
+    ///         mac: [1, 2, 3, 4, 5, 6],
+    ///         state: NeighborState::Reachable,
+    ///     }),
+    ///     NeighborObservation::from_sliced_packet(&sliced)
+    /// );
+    /// # }
+    /// ```
+    pub fn from_sliced_packet(slice: &SlicedPacket) -> Option<NeighborObservation> {
+        if let Some(icmpv6) = slice.icmpv6_with_addrs() {
+            return NeighborObservation::from_ndp(&icmpv6);
+        }
+        #[cfg(feature = "arp")]
+        if slice.payload_ether_type() == Some(EtherType::ARP) {
+            let payload = slice.ether_payload()?.payload;
+            let mut bytes = [0u8; ArpEthIpv4Packet::LEN];
+            bytes.copy_from_slice(payload.get(..ArpEthIpv4Packet::LEN)?);
+            return NeighborObservation::from_arp(&ArpEthIpv4Packet::from_bytes(bytes)?);
+        }
+        None
+    }
+}
+
+/// Returns the Ethernet MAC address contained in the first link-layer
+/// address NDP option with the given type (RFC 4861 section 4.6.1).
+fn ndp_link_layer_address(mut options: &[u8], option_type: u8) -> Option<[u8; 6]> {
+    while options.len() >= 2 {
+        // length is given in units of 8 octets
+        let len = usize::from(options[1]) * 8;
+        if 0 == len || options.len() < len {
+            return None;
+        }
+        if options[0] == option_type {
+            // only Ethernet addresses (option length of 8 octets) are supported
+            if 8 != len {
+                return None;
+            }
+            let mut mac = [0u8; 6];
+            mac.copy_from_slice(&options[2..8]);
+            return Some(mac);
+        }
+        options = &options[len..];
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::net::Ipv4Addr;
+    use std::format;
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    use std::vec::Vec;
+
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    fn ndp(type_u8: u8, bytes5to8: [u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        PacketBuilder::ipv6(
+            [0xfe, 0x80, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+            [2; 16],
+            255,
+        )
+        .icmpv6(Icmpv6Type::Unknown {
+            type_u8,
+            code_u8: 0,
+            bytes5to8,
+        })
+        .write(&mut data, payload)
+        .unwrap();
+        data
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    fn from_ndp(data: &[u8]) -> Option<NeighborObservation> {
+        NeighborObservation::from_ndp(
+            &SlicedPacket::from_ip(data)
+                .unwrap()
+                .icmpv6_with_addrs()
+                .unwrap(),
+        )
+    }
+
+    #[cfg(feature = "arp")]
+    #[test]
+    fn from_arp() {
+        let arp = |operation: ArpOperation, sender_ipv4: [u8; 4], target_ipv4: [u8; 4]| {
+            NeighborObservation::from_arp(&ArpEthIpv4Packet {
+                operation,
+                sender_mac: MAC,
+                sender_ipv4,
+                target_mac: [0; 6],
+                target_ipv4,
+            })
+        };
+        let expected = |state| {
+            Some(NeighborObservation {
+                ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+                mac: MAC,
+                state,
+            })
+        };
+        use NeighborState::*;
+        assert_eq!(
+            expected(Reachable),
+            arp(ArpOperation::REPLY, [10, 0, 0, 1], [10, 0, 0, 2])
+        );
+        assert_eq!(
+            expected(Stale),
+            arp(ArpOperation::REPLY, [10, 0, 0, 1], [10, 0, 0, 1])
+        );
+        assert_eq!(
+            expected(Stale),
+            arp(ArpOperation::REQUEST, [10, 0, 0, 1], [10, 0, 0, 2])
+        );
+        // announcement
+        assert_eq!(
+            expected(Stale),
+            arp(ArpOperation::REQUEST, [10, 0, 0, 1], [10, 0, 0, 1])
+        );
+        // probe
+        assert_eq!(
+            None,
+            arp(ArpOperation::REQUEST, [0, 0, 0, 0], [10, 0, 0, 1])
+        );
+        // other operations
+        assert_eq!(
+            None,
+            arp(ArpOperation::REVERSE_REPLY, [10, 0, 0, 1], [10, 0, 0, 2])
+        );
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    #[test]
+    fn from_ndp_messages() {
+        let source = IpAddr::V6(Ipv6Addr::from([
+            0xfe, 0x80, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+        ]));
+        let target = [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9];
+        let source_ll = [1, 1, 1, 2, 3, 4, 5, 6];
+        let target_ll = [2, 1, 1, 2, 3, 4, 5, 6];
+        let with = |prefix: &[u8], options: &[&[u8]]| {
+            let mut payload = prefix.to_vec();
+            for o in options {
+                payload.extend_from_slice(o);
+            }
+            payload
+        };
+        use icmpv6::*;
+        use NeighborState::*;
+
+        // router solicitation
+        assert_eq!(
+            Some(NeighborObservation {
+                ip: source,
+                mac: MAC,
+                state: Stale
+            }),
+            from_ndp(&ndp(TYPE_ROUTER_SOLICITATION, [0; 4], &source_ll))
+        );
+        // router advertisement (mtu option before the link-layer address)
+        assert_eq!(
+            Some(NeighborObservation {
+                ip: source,
+                mac: MAC,
+                state: Stale
+            }),
+            from_ndp(&ndp(
+                TYPE_ROUTER_ADVERTISEMENT,
+                [64, 0, 0, 0],
+                &with(&[0; 8], &[&[5, 1, 0, 0, 0, 0, 5, 220], &source_ll])
+            ))
+        );
+        assert_eq!(
+            None,
+            from_ndp(&ndp(TYPE_ROUTER_ADVERTISEMENT, [0; 4], &[0; 7]))
+        );
+        // neighbor solicitation
+        assert_eq!(
+            Some(NeighborObservation {
+                ip: source,
+                mac: MAC,
+                state: Stale
+            }),
+            from_ndp(&ndp(
+                TYPE_NEIGHBOR_SOLICITATION,
+                [0; 4],
+                &with(&target, &[&source_ll])
+            ))
+        );
+        assert_eq!(
+            None,
+            from_ndp(&ndp(TYPE_NEIGHBOR_SOLICITATION, [0; 4], &target))
+        );
+        // neighbor advertisement (solicited & unsolicited)
+        for (flags, state) in [(0b0110_0000, Reachable), (0b0010_0000, Stale)] {
+            assert_eq!(
+                Some(NeighborObservation {
+                    ip: IpAddr::V6(Ipv6Addr::from(target)),
+                    mac: MAC,
+                    state
+                }),
+                from_ndp(&ndp(
+                    TYPE_NEIGHBOR_ADVERTISEMENT,
+                    [flags, 0, 0, 0],
+                    &with(&target, &[&source_ll, &target_ll])
+                ))
+            );
+        }
+        assert_eq!(
+            None,
+            from_ndp(&ndp(TYPE_NEIGHBOR_ADVERTISEMENT, [0; 4], &target[..15]))
+        );
+        // unspecified address
+        assert_eq!(
+            None,
+            from_ndp(&ndp(
+                TYPE_NEIGHBOR_ADVERTISEMENT,
+                [0x40, 0, 0, 0],
+                &with(&[0; 16], &[&target_ll])
+            ))
+        );
+        // other message types
+        assert_eq!(
+            None,
+            from_ndp(&ndp(TYPE_REDIRECT_MESSAGE, [0; 4], &source_ll))
+        );
+        // non zero code
+        {
+            let mut data = ndp(TYPE_ROUTER_SOLICITATION, [0; 4], &source_ll);
+            data[41] = 1;
+            assert_eq!(None, from_ndp(&data));
+        }
+    }
+
+    #[test]
+    fn ndp_link_layer_address_options() {
+        // not present
+        assert_eq!(None, ndp_link_layer_address(&[], 1));
+        assert_eq!(None, ndp_link_layer_address(&[2, 1, 1, 2, 3, 4, 5, 6], 1));
+        // zero length option
+        assert_eq!(
+            None,
+            ndp_link_layer_address(&[3, 0, 1, 1, 1, 2, 3, 4, 5, 6], 1)
+        );
+        // truncated option
+        assert_eq!(None, ndp_link_layer_address(&[1, 1, 1, 2, 3, 4, 5], 1));
+        // non ethernet address
+        assert_eq!(
+            None,
+            ndp_link_layer_address(&[1, 2, 1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0], 1)
+        );
+        // trailing byte is ignored
+        assert_eq!(
+            Some(MAC),
+            ndp_link_layer_address(&[1, 1, 1, 2, 3, 4, 5, 6, 0], 1)
+        );
+    }
+
+    #[cfg(all(feature = "arp", feature = "icmp", feature = "ipv6"))]
+    #[test]
+    fn from_sliced_packet() {
+        // ndp
+        {
+            let data = ndp(
+                icmpv6::TYPE_ROUTER_SOLICITATION,
+                [0; 4],
+                &[1, 1, 1, 2, 3, 4, 5, 6],
+            );
+            assert_eq!(
+                from_ndp(&data),
+                NeighborObservation::from_sliced_packet(&SlicedPacket::from_ip(&data).unwrap())
+            );
+        }
+        // arp
+        let arp = ArpEthIpv4Packet {
+            operation: ArpOperation::REQUEST,
+            sender_mac: MAC,
+            sender_ipv4: [10, 0, 0, 1],
+            target_mac: [0; 6],
+            target_ipv4: [10, 0, 0, 2],
+        };
+        {
+            let mut data = arp.to_bytes().to_vec();
+            // padding
+            data.extend_from_slice(&[0; 18]);
+            assert_eq!(
+                NeighborObservation::from_arp(&arp),
+                NeighborObservation::from_sliced_packet(
+                    &SlicedPacket::from_ether_type(EtherType::ARP, &data).unwrap()
+                )
+            );
+            // too short
+            assert_eq!(
+                None,
+                NeighborObservation::from_sliced_packet(
+                    &SlicedPacket::from_ether_type(EtherType::ARP, &data[..27]).unwrap()
+                )
+            );
+            // non ethernet & ipv4 addresses
+            data[0] = 0xff;
+            assert_eq!(
+                None,
+                NeighborObservation::from_sliced_packet(
+                    &SlicedPacket::from_ether_type(EtherType::ARP, &data).unwrap()
+                )
+            );
+        }
+        // other packets
+        {
+            let mut data = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 64)
+                .udp(1, 2)
+                .write(&mut data, &[])
+                .unwrap();
+            assert_eq!(
+                None,
+                NeighborObservation::from_sliced_packet(&SlicedPacket::from_ip(&data).unwrap())
+            );
+            assert_eq!(
+                None,
+                NeighborObservation::from_sliced_packet(
+                    &SlicedPacket::from_ether_type(EtherType::LLDP, &[0, 0]).unwrap()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = NeighborObservation {
+            ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            mac: MAC,
+            state: NeighborState::Stale,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "NeighborObservation { ip: 10.0.0.1, mac: [1, 2, 3, 4, 5, 6], state: Stale }",
+            format!("{:?}", value)
+        );
+    }
+}
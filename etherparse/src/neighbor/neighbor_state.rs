@@ -0,0 +1,30 @@
+/// Reachability state of an entry in a [`crate::neighbor::NeighborCache`].
+///
+/// Modeled after the neighbor states of RFC 4861 (reduced to the states
+/// that can be determined by passively observing packets).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum NeighborState {
+    /// The address mapping was learned from a message that does not
+    /// confirm the neighbor is reachable (e.g. an ARP request or a
+    /// Neighbor Solicitation) or was marked stale via
+    /// [`crate::neighbor::NeighborCache::mark_stale`].
+    Stale,
+    /// The address mapping was confirmed by a response of the neighbor
+    /// (ARP reply or solicited Neighbor Advertisement).
+    Reachable,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn debug_clone_eq_ord() {
+        let value = NeighborState::Stale;
+        assert_eq!(value, value.clone());
+        assert!(NeighborState::Stale < NeighborState::Reachable);
+        assert_eq!("Stale", format!("{:?}", NeighborState::Stale));
+        assert_eq!("Reachable", format!("{:?}", NeighborState::Reachable));
+    }
+}
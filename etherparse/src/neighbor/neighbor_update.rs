@@ -0,0 +1,46 @@
+use core::net::IpAddr;
+
+/// Change applied to a [`crate::neighbor::NeighborCache`] when processing
+/// a packet containing an IP to MAC address mapping.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NeighborUpdate {
+    /// A new entry was added to the cache.
+    Added {
+        /// Address of the entry that was removed to make room for the new
+        /// entry (only set if the cache was full).
+        evicted: Option<IpAddr>,
+    },
+    /// An existing entry with the same MAC address was refreshed.
+    Refreshed,
+    /// The MAC address of an existing entry changed (e.g. a replaced
+    /// device, a failover or ARP/NDP spoofing).
+    MacChanged {
+        /// MAC address stored before the update.
+        previous_mac: [u8; 6],
+    },
+    /// The new neighbor was not added as the cache is full & the eviction
+    /// policy is [`crate::neighbor::NeighborEvictionPolicy::RejectNew`].
+    Rejected,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = NeighborUpdate::MacChanged {
+            previous_mac: [1, 2, 3, 4, 5, 6],
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "MacChanged { previous_mac: [1, 2, 3, 4, 5, 6] }",
+            format!("{:?}", value)
+        );
+        assert_eq!(
+            "Added { evicted: None }",
+            format!("{:?}", NeighborUpdate::Added { evicted: None })
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Packet consisting of an outer packet & an inner packet that was
+/// parsed from a decrypted or decompressed payload of the outer packet
+/// (e.g. after decrypting an ESP, MACsec or WireGuard payload) or from
+/// the payload of an IPv6 transition tunnel.
+///
+/// Created via [`SlicedPacket::reparse_payload_as_ip`],
+/// [`SlicedPacket::reparse_payload_as_ethernet`],
+/// [`SlicedPacket::reparse_payload_as_ether_type`] or
+/// [`SlicedPacket::decode_ipv6_transition_tunnel`]:
+///
+/// ```
+/// use etherparse::{IpNumber, NetSlice, PacketBuilder, SlicedPacket};
+///
+/// // outer packet with an ESP payload
+/// let mut packet = Vec::new();
+/// PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .write(&mut packet, IpNumber::ENCAPSULATING_SECURITY_PAYLOAD, &[0; 32])
+///     .unwrap();
+/// let outer = SlicedPacket::from_ip(&packet).unwrap();
+///
+/// // inner packet (e.g. the result of decrypting the ESP payload)
+/// let mut decrypted = Vec::new();
+/// PacketBuilder::ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+///     .udp(1234, 53)
+///     .write(&mut decrypted, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let nested = outer.reparse_payload_as_ip(&decrypted).unwrap();
+/// if let Some(NetSlice::Ipv4(ipv4)) = &nested.inner.net {
+///     assert_eq!([10, 0, 0, 1], ipv4.header().source());
+/// }
+/// if let Some(NetSlice::Ipv4(ipv4)) = &nested.outer.net {
+///     assert_eq!([192, 168, 1, 1], ipv4.header().source());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NestedSlicedPacket<'a> {
+    /// Outer packet (containing the encrypted or compressed payload).
+    pub outer: SlicedPacket<'a>,
+    /// Inner packet parsed from the decrypted or decompressed payload
+    /// or the payload of the transition tunnel.
+    pub inner: SlicedPacket<'a>,
+    /// IPv6 transition tunnel the inner packet was tunneled through
+    /// (`None` if the inner packet was not decoded by
+    /// [`SlicedPacket::decode_ipv6_transition_tunnel`]).
+    pub tunnel: Option<Ipv6TransitionTunnel>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq_debug() {
+        let outer = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+        let nested = outer
+            .reparse_payload_as_ether_type(EtherType::ARP, &[3])
+            .unwrap();
+        assert_eq!(nested, nested.clone());
+        assert_eq!(
+            format!(
+                "NestedSlicedPacket {{ outer: {:?}, inner: {:?}, tunnel: None }}",
+                nested.outer, nested.inner
+            ),
+            format!("{:?}", nested)
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use crate::*;
+
+/// ARP packet with Ethernet hardware addresses & IPv4 protocol
+/// addresses (the most common ARP variant).
+///
+/// The fixed fields (hardware type, protocol type & address lengths) are
+/// not stored and are implied by the type.
+///
+/// ```
+/// use etherparse::{ArpEthIpv4Packet, ArpOperation};
+///
+/// // RFC 5227 probe for the address 192.168.1.2
+/// let probe = ArpEthIpv4Packet {
+///     operation: ArpOperation::REQUEST,
+///     sender_mac: [1, 2, 3, 4, 5, 6],
+///     sender_ipv4: [0, 0, 0, 0],
+///     target_mac: [0; 6],
+///     target_ipv4: [192, 168, 1, 2],
+/// };
+/// assert!(probe.is_probe());
+/// assert!(false == probe.is_announcement());
+///
+/// // the probe conflicts with a host already owning the address
+/// assert!(probe.is_ipv4_conflict([192, 168, 1, 2], [7, 8, 9, 10, 11, 12]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArpEthIpv4Packet {
+    /// Operation of the ARP packet (e.g. request or reply).
+    pub operation: ArpOperation,
+    /// MAC address of the sender.
+    pub sender_mac: [u8; 6],
+    /// IPv4 address of the sender.
+    pub sender_ipv4: [u8; 4],
+    /// MAC address of the target.
+    pub target_mac: [u8; 6],
+    /// IPv4 address of the target.
+    pub target_ipv4: [u8; 4],
+}
+
+impl ArpEthIpv4Packet {
+    /// Serialized length of an Ethernet & IPv4 ARP packet in bytes.
+    pub const LEN: usize = 28;
+
+    /// Decodes the packet from the given bytes.
+    ///
+    /// Returns `None` if the hardware type is not Ethernet, the protocol
+    /// type is not IPv4 or the address lengths do not match.
+    pub fn from_bytes(bytes: [u8; 28]) -> Option<ArpEthIpv4Packet> {
+        let hw_type = ArpHardwareId(u16::from_be_bytes([bytes[0], bytes[1]]));
+        let proto_type = EtherType(u16::from_be_bytes([bytes[2], bytes[3]]));
+        if hw_type != ArpHardwareId::ETHER
+            || proto_type != EtherType::IPV4
+            || bytes[4] != 6
+            || bytes[5] != 4
+        {
+            return None;
+        }
+        Some(ArpEthIpv4Packet {
+            operation: ArpOperation(u16::from_be_bytes([bytes[6], bytes[7]])),
+            sender_mac: [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13],
+            ],
+            sender_ipv4: [bytes[14], bytes[15], bytes[16], bytes[17]],
+            target_mac: [
+                bytes[18], bytes[19], bytes[20], bytes[21], bytes[22], bytes[23],
+            ],
+            target_ipv4: [bytes[24], bytes[25], bytes[26], bytes[27]],
+        })
+    }
+
+    /// Returns the serialized packet.
+    pub fn to_bytes(&self) -> [u8; 28] {
+        let hw_type = ArpHardwareId::ETHER.0.to_be_bytes();
+        let proto_type = EtherType::IPV4.0.to_be_bytes();
+        let op = self.operation.0.to_be_bytes();
+        [
+            hw_type[0],
+            hw_type[1],
+            proto_type[0],
+            proto_type[1],
+            6,
+            4,
+            op[0],
+            op[1],
+            self.sender_mac[0],
+            self.sender_mac[1],
+            self.sender_mac[2],
+            self.sender_mac[3],
+            self.sender_mac[4],
+            self.sender_mac[5],
+            self.sender_ipv4[0],
+            self.sender_ipv4[1],
+            self.sender_ipv4[2],
+            self.sender_ipv4[3],
+            self.target_mac[0],
+            self.target_mac[1],
+            self.target_mac[2],
+            self.target_mac[3],
+            self.target_mac[4],
+            self.target_mac[5],
+            self.target_ipv4[0],
+            self.target_ipv4[1],
+            self.target_ipv4[2],
+            self.target_ipv4[3],
+        ]
+    }
+
+    /// Returns the sender IPv4 address as an [`std::net::Ipv4Addr`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn sender_ipv4_addr(&self) -> std::net::Ipv4Addr {
+        std::net::Ipv4Addr::from(self.sender_ipv4)
+    }
+
+    /// Returns the target IPv4 address as an [`std::net::Ipv4Addr`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn target_ipv4_addr(&self) -> std::net::Ipv4Addr {
+        std::net::Ipv4Addr::from(self.target_ipv4)
+    }
+
+    /// Returns true if the sender IPv4 address is `0.0.0.0`.
+    #[inline]
+    pub fn is_sender_ipv4_unspecified(&self) -> bool {
+        self.sender_ipv4 == [0; 4]
+    }
+
+    /// Returns true if the sender & target IPv4 address are identical.
+    #[inline]
+    pub fn is_sender_ipv4_target(&self) -> bool {
+        self.sender_ipv4 == self.target_ipv4
+    }
+
+    /// Returns true if the packet is an "ARP Probe" as defined in
+    /// RFC 5227 (request with an all-zero sender IPv4 address).
+    ///
+    /// The target MAC address is ignored, as required by RFC 5227.
+    pub fn is_probe(&self) -> bool {
+        self.operation == ArpOperation::REQUEST && self.is_sender_ipv4_unspecified()
+    }
+
+    /// Returns true if the packet is an "ARP Announcement" as defined in
+    /// RFC 5227 (request with the sender IPv4 address set as target).
+    pub fn is_announcement(&self) -> bool {
+        self.operation == ArpOperation::REQUEST && self.is_gratuitous()
+    }
+
+    /// Returns true if the packet is a gratuitous ARP request or reply
+    /// (sender & target IPv4 address are identical and not `0.0.0.0`).
+    pub fn is_gratuitous(&self) -> bool {
+        (self.operation == ArpOperation::REQUEST || self.operation == ArpOperation::REPLY)
+            && self.is_sender_ipv4_target()
+            && false == self.is_sender_ipv4_unspecified()
+    }
+
+    /// Returns true if the packet indicates an address conflict for a host
+    /// using (or probing for) `own_ipv4` with the MAC address `own_mac`
+    /// (RFC 5227 section 2.1.1 & 2.4).
+    ///
+    /// A conflict is present if another host uses `own_ipv4` as sender
+    /// address or if another host is probing for `own_ipv4`.
+    pub fn is_ipv4_conflict(&self, own_ipv4: [u8; 4], own_mac: [u8; 6]) -> bool {
+        if self.sender_mac == own_mac {
+            return false;
+        }
+        self.sender_ipv4 == own_ipv4 || (self.is_probe() && self.target_ipv4 == own_ipv4)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    fn packet(op: ArpOperation, sender_ipv4: [u8; 4], target_ipv4: [u8; 4]) -> ArpEthIpv4Packet {
+        ArpEthIpv4Packet {
+            operation: op,
+            sender_mac: [1, 2, 3, 4, 5, 6],
+            sender_ipv4,
+            target_mac: [0; 6],
+            target_ipv4,
+        }
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let p = ArpEthIpv4Packet {
+            operation: ArpOperation::REPLY,
+            sender_mac: [1, 2, 3, 4, 5, 6],
+            sender_ipv4: [7, 8, 9, 10],
+            target_mac: [11, 12, 13, 14, 15, 16],
+            target_ipv4: [17, 18, 19, 20],
+        };
+        let bytes = p.to_bytes();
+        assert_eq!(
+            bytes,
+            [
+                0, 1, 8, 0, 6, 4, 0, 2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20
+            ]
+        );
+        assert_eq!(Some(p), ArpEthIpv4Packet::from_bytes(bytes));
+
+        // bad fixed fields
+        for i in 0..6 {
+            let mut bad = bytes;
+            bad[i] ^= 0xff;
+            assert_eq!(None, ArpEthIpv4Packet::from_bytes(bad));
+        }
+    }
+
+    #[test]
+    fn classification() {
+        use ArpOperation as Op;
+        // (packet, probe, announcement, gratuitous)
+        let tests = [
+            (
+                packet(Op::REQUEST, [0; 4], [1, 2, 3, 4]),
+                true,
+                false,
+                false,
+            ),
+            (packet(Op::REQUEST, [0; 4], [0; 4]), true, false, false),
+            (
+                packet(Op::REQUEST, [1, 2, 3, 4], [1, 2, 3, 4]),
+                false,
+                true,
+                true,
+            ),
+            (
+                packet(Op::REPLY, [1, 2, 3, 4], [1, 2, 3, 4]),
+                false,
+                false,
+                true,
+            ),
+            (
+                packet(Op::REQUEST, [1, 2, 3, 4], [1, 2, 3, 5]),
+                false,
+                false,
+                false,
+            ),
+            (packet(Op::REPLY, [0; 4], [1, 2, 3, 4]), false, false, false),
+            (
+                packet(Op::REVERSE_REQUEST, [1, 2, 3, 4], [1, 2, 3, 4]),
+                false,
+                false,
+                false,
+            ),
+        ];
+        for (p, probe, announcement, gratuitous) in tests {
+            assert_eq!(probe, p.is_probe(), "{:?}", p);
+            assert_eq!(announcement, p.is_announcement(), "{:?}", p);
+            assert_eq!(gratuitous, p.is_gratuitous(), "{:?}", p);
+        }
+    }
+
+    #[test]
+    fn is_ipv4_conflict() {
+        use ArpOperation as Op;
+        let own_ipv4 = [1, 2, 3, 4];
+        let other_mac = [9; 6];
+
+        // other host uses our address
+        assert!(packet(Op::REPLY, own_ipv4, [5, 6, 7, 8]).is_ipv4_conflict(own_ipv4, other_mac));
+        // other host probes for our address
+        assert!(packet(Op::REQUEST, [0; 4], own_ipv4).is_ipv4_conflict(own_ipv4, other_mac));
+        // regular request for our address
+        assert!(
+            false
+                == packet(Op::REQUEST, [5, 6, 7, 8], own_ipv4)
+                    .is_ipv4_conflict(own_ipv4, other_mac)
+        );
+        // our own packets
+        assert!(
+            false
+                == packet(Op::REQUEST, own_ipv4, own_ipv4)
+                    .is_ipv4_conflict(own_ipv4, [1, 2, 3, 4, 5, 6])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ipv4_addr() {
+        let p = packet(ArpOperation::REQUEST, [1, 2, 3, 4], [5, 6, 7, 8]);
+        assert_eq!(std::net::Ipv4Addr::new(1, 2, 3, 4), p.sender_ipv4_addr());
+        assert_eq!(std::net::Ipv4Addr::new(5, 6, 7, 8), p.target_ipv4_addr());
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let p = packet(ArpOperation::REQUEST, [1, 2, 3, 4], [5, 6, 7, 8]);
+        assert_eq!(p, p.clone());
+        assert!(format!("{:?}", p).starts_with("ArpEthIpv4Packet {"));
+    }
+}
@@ -0,0 +1,145 @@
+/// Operation field value in an ARP packet.
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to an `ArpOperation`:
+///
+/// ```
+/// use etherparse::ArpOperation;
+///
+/// assert_eq!(ArpOperation::REQUEST.0, 1);
+///
+/// let op: ArpOperation = 2.into();
+/// assert_eq!(ArpOperation::REPLY, op);
+///
+/// let num: u16 = ArpOperation::REPLY.into();
+/// assert_eq!(2, num);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Default, Hash)]
+pub struct ArpOperation(pub u16);
+
+impl ArpOperation {
+    /// ARP request (RFC 826).
+    pub const REQUEST: ArpOperation = Self(1);
+    /// ARP reply (RFC 826).
+    pub const REPLY: ArpOperation = Self(2);
+    /// Reverse ARP request (RFC 903).
+    pub const REVERSE_REQUEST: ArpOperation = Self(3);
+    /// Reverse ARP reply (RFC 903).
+    pub const REVERSE_REPLY: ArpOperation = Self(4);
+}
+
+impl ArpOperation {
+    /// Known values that can be parsed by name via [`core::str::FromStr`].
+    const NAMED: [ArpOperation; 4] = [
+        Self::REQUEST,
+        Self::REPLY,
+        Self::REVERSE_REQUEST,
+        Self::REVERSE_REPLY,
+    ];
+
+    /// Returns the name of the value if it is a known constant
+    /// (the lowercase name of the constant with `_` replaced by `-`).
+    ///
+    /// The returned name is accepted by the [`core::str::FromStr`]
+    /// implementation:
+    ///
+    /// ```
+    /// use etherparse::ArpOperation;
+    ///
+    /// assert_eq!(Some("reply"), ArpOperation::REPLY.as_str());
+    /// assert_eq!(Ok(ArpOperation::REPLY), "reply".parse());
+    /// ```
+    pub fn as_str(self) -> Option<&'static str> {
+        match self {
+            Self::REQUEST => Some("request"),
+            Self::REPLY => Some("reply"),
+            Self::REVERSE_REQUEST => Some("reverse-request"),
+            Self::REVERSE_REPLY => Some("reverse-reply"),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an [`ArpOperation`] from a decimal number, a hexadecimal number
+/// starting with `0x` or the name of a known constant (see
+/// [`ArpOperation::as_str`], ignoring the case & treating `_` and `-` as equal).
+///
+/// ```
+/// use etherparse::ArpOperation;
+///
+/// assert_eq!(Ok(ArpOperation::REPLY), "2".parse());
+/// assert_eq!(Ok(ArpOperation::REPLY), "0x2".parse());
+/// assert_eq!(Ok(ArpOperation::REPLY), "REPLY".parse());
+/// assert!("unknown".parse::<ArpOperation>().is_err());
+/// ```
+impl core::str::FromStr for ArpOperation {
+    type Err = crate::err::ValueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = crate::parse_number::<u16>(s) {
+            return Ok(ArpOperation(value));
+        }
+        ArpOperation::NAMED
+            .iter()
+            .find(|v| v.as_str().is_some_and(|name| crate::eq_name(s, name)))
+            .copied()
+            .ok_or(crate::err::ValueParseError {
+                value_type: crate::err::ValueType::ArpOperation,
+            })
+    }
+}
+
+impl From<u16> for ArpOperation {
+    #[inline]
+    fn from(val: u16) -> Self {
+        ArpOperation(val)
+    }
+}
+
+impl From<ArpOperation> for u16 {
+    #[inline]
+    fn from(val: ArpOperation) -> Self {
+        val.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_into_u16() {
+        for v in [0, 1, 2, 3, 4, 0xffff] {
+            assert_eq!(v, u16::from(ArpOperation::from(v)));
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(ArpOperation(0), ArpOperation::default());
+    }
+
+    #[test]
+    fn as_str_from_str() {
+        use core::str::FromStr;
+        for value in ArpOperation::NAMED {
+            let name = value.as_str().unwrap();
+            assert_eq!(Ok(value), ArpOperation::from_str(name));
+            assert_eq!(
+                Ok(value),
+                ArpOperation::from_str(&name.to_ascii_uppercase().replace('-', "_"))
+            );
+        }
+        assert_eq!(None, ArpOperation(0x1234).as_str());
+        assert_eq!(Ok(ArpOperation(0x1234)), ArpOperation::from_str("4660"));
+        assert_eq!(Ok(ArpOperation(0x1234)), ArpOperation::from_str("0x1234"));
+        for s in ["", "0x", "65536", "-1", "unknown"] {
+            assert_eq!(
+                Err(crate::err::ValueParseError {
+                    value_type: crate::err::ValueType::ArpOperation
+                }),
+                ArpOperation::from_str(s)
+            );
+        }
+    }
+}
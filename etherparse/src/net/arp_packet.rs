@@ -0,0 +1,763 @@
+use crate::{err::arp::AddrLenError, *};
+use arrayvec::ArrayVec;
+
+/// ARP packet with variable length hardware & protocol addresses
+/// (e.g. for non Ethernet hardware types like InfiniBand).
+///
+/// For the common Ethernet & IPv4 case [`crate::ArpEthIpv4Packet`] can
+/// be used instead.
+///
+/// ```
+/// use etherparse::{ArpHardwareId, ArpOperation, ArpPacket, EtherType};
+///
+/// // InfiniBand uses 20 byte hardware addresses (RFC 4391)
+/// let packet = ArpPacket::try_new(
+///     ArpHardwareId::INFINIBAND,
+///     EtherType::IPV4,
+///     ArpOperation::REQUEST,
+///     &[1; 20],
+///     &[192, 168, 1, 1],
+///     &[0; 20],
+///     &[192, 168, 1, 2],
+/// )
+/// .unwrap();
+/// assert_eq!(20, packet.hw_addr_len());
+///
+/// // 6 byte MAC addresses are rejected for InfiniBand
+/// assert!(ArpPacket::try_new(
+///     ArpHardwareId::INFINIBAND,
+///     EtherType::IPV4,
+///     ArpOperation::REQUEST,
+///     &[1; 6],
+///     &[192, 168, 1, 1],
+///     &[0; 6],
+///     &[192, 168, 1, 2],
+/// )
+/// .is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArpPacket {
+    /// Type of the hardware addresses (e.g. Ethernet).
+    pub hw_addr_type: ArpHardwareId,
+    /// Type of the protocol addresses (e.g. IPv4).
+    pub proto_addr_type: EtherType,
+    /// Operation of the ARP packet (e.g. request or reply).
+    pub operation: ArpOperation,
+    /// Hardware address of the sender.
+    pub sender_hw_addr: ArrayVec<u8, 255>,
+    /// Protocol address of the sender.
+    pub sender_protocol_addr: ArrayVec<u8, 255>,
+    /// Hardware address of the target.
+    pub target_hw_addr: ArrayVec<u8, 255>,
+    /// Protocol address of the target.
+    pub target_protocol_addr: ArrayVec<u8, 255>,
+}
+
+impl ArpPacket {
+    /// Minimum length of an ARP packet in bytes (all addresses empty).
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of an ARP packet in bytes (all addresses 255 bytes long).
+    pub const MAX_LEN: usize = 8 + 4 * 255;
+
+    /// Creates an ARP packet with arbitrary address lengths.
+    ///
+    /// Only checks that the addresses fit into the length fields and
+    /// that the sender & target addresses have the same length. Use
+    /// [`ArpPacket::try_new`] to additionally validate the hardware
+    /// address length against the hardware type or
+    /// [`ArpPacket::new_checked`] to also validate the protocol address
+    /// length against the protocol type.
+    pub fn new(
+        hw_addr_type: ArpHardwareId,
+        proto_addr_type: EtherType,
+        operation: ArpOperation,
+        sender_hw_addr: &[u8],
+        sender_protocol_addr: &[u8],
+        target_hw_addr: &[u8],
+        target_protocol_addr: &[u8],
+    ) -> Result<ArpPacket, AddrLenError> {
+        use AddrLenError::*;
+
+        if sender_hw_addr.len() != target_hw_addr.len() {
+            return Err(HwAddrLenMismatch {
+                sender_len: sender_hw_addr.len(),
+                target_len: target_hw_addr.len(),
+            });
+        }
+        if sender_protocol_addr.len() != target_protocol_addr.len() {
+            return Err(ProtocolAddrLenMismatch {
+                sender_len: sender_protocol_addr.len(),
+                target_len: target_protocol_addr.len(),
+            });
+        }
+        let to_addr = |addr: &[u8]| ArrayVec::<u8, 255>::try_from(addr).ok();
+        Ok(ArpPacket {
+            hw_addr_type,
+            proto_addr_type,
+            operation,
+            sender_hw_addr: to_addr(sender_hw_addr).ok_or(HwAddrTooLong {
+                len: sender_hw_addr.len(),
+            })?,
+            sender_protocol_addr: to_addr(sender_protocol_addr).ok_or(ProtocolAddrTooLong {
+                len: sender_protocol_addr.len(),
+            })?,
+            target_hw_addr: to_addr(target_hw_addr).ok_or(HwAddrTooLong {
+                len: target_hw_addr.len(),
+            })?,
+            target_protocol_addr: to_addr(target_protocol_addr).ok_or(ProtocolAddrTooLong {
+                len: target_protocol_addr.len(),
+            })?,
+        })
+    }
+
+    /// Creates an ARP packet & validates the hardware address length
+    /// against the fixed address length of the hardware type (see
+    /// [`ArpHardwareId::hardware_address_len`]).
+    ///
+    /// Hardware types without a known fixed address length accept any
+    /// hardware address length.
+    pub fn try_new(
+        hw_addr_type: ArpHardwareId,
+        proto_addr_type: EtherType,
+        operation: ArpOperation,
+        sender_hw_addr: &[u8],
+        sender_protocol_addr: &[u8],
+        target_hw_addr: &[u8],
+        target_protocol_addr: &[u8],
+    ) -> Result<ArpPacket, AddrLenError> {
+        let result = ArpPacket::new(
+            hw_addr_type,
+            proto_addr_type,
+            operation,
+            sender_hw_addr,
+            sender_protocol_addr,
+            target_hw_addr,
+            target_protocol_addr,
+        )?;
+        result.check_hw_addr_len()?;
+        Ok(result)
+    }
+
+    /// Creates an ARP packet & validates the hardware & protocol address
+    /// lengths against the fixed address lengths of the hardware & protocol
+    /// types (see [`ArpHardwareId::hardware_address_len`] &
+    /// [`EtherType::protocol_address_len`]).
+    ///
+    /// Types without a known fixed address length accept any address
+    /// length.
+    ///
+    /// ```
+    /// use etherparse::{ArpHardwareId, ArpOperation, ArpPacket, EtherType, err::arp::AddrLenError};
+    ///
+    /// // IPv6 protocol addresses have to be 16 bytes long
+    /// assert_eq!(
+    ///     Err(AddrLenError::ProtocolAddrLenInvalid {
+    ///         proto_addr_type: EtherType::IPV6,
+    ///         expected_len: 16,
+    ///         actual_len: 4,
+    ///     }),
+    ///     ArpPacket::new_checked(
+    ///         ArpHardwareId::ETHER,
+    ///         EtherType::IPV6,
+    ///         ArpOperation::REQUEST,
+    ///         &[1; 6],
+    ///         &[192, 168, 1, 1],
+    ///         &[0; 6],
+    ///         &[192, 168, 1, 2],
+    ///     )
+    /// );
+    /// ```
+    pub fn new_checked(
+        hw_addr_type: ArpHardwareId,
+        proto_addr_type: EtherType,
+        operation: ArpOperation,
+        sender_hw_addr: &[u8],
+        sender_protocol_addr: &[u8],
+        target_hw_addr: &[u8],
+        target_protocol_addr: &[u8],
+    ) -> Result<ArpPacket, AddrLenError> {
+        let result = ArpPacket::try_new(
+            hw_addr_type,
+            proto_addr_type,
+            operation,
+            sender_hw_addr,
+            sender_protocol_addr,
+            target_hw_addr,
+            target_protocol_addr,
+        )?;
+        result.check_protocol_addr_len()?;
+        Ok(result)
+    }
+
+    /// Decodes an ARP packet from the start of the slice & returns the
+    /// packet together with the non decoded rest of the slice.
+    ///
+    /// The address lengths are taken from the length fields & are not
+    /// validated against the hardware & protocol types (see
+    /// [`ArpPacket::is_consistent`]).
+    pub fn from_slice(slice: &[u8]) -> Result<(ArpPacket, &[u8]), err::LenError> {
+        let len_error = |required_len: usize| err::LenError {
+            required_len,
+            len: slice.len(),
+            len_source: LenSource::Slice,
+            layer: err::Layer::ArpPacket,
+            layer_start_offset: 0,
+        };
+        if slice.len() < ArpPacket::MIN_LEN {
+            return Err(len_error(ArpPacket::MIN_LEN));
+        }
+        let hw_addr_len = usize::from(slice[4]);
+        let protocol_addr_len = usize::from(slice[5]);
+        let len = ArpPacket::MIN_LEN + 2 * hw_addr_len + 2 * protocol_addr_len;
+        if slice.len() < len {
+            return Err(len_error(len));
+        }
+
+        // the lengths are limited to 255 by the u8 length fields
+        let addr = |start: usize, addr_len: usize| {
+            let mut result = ArrayVec::<u8, 255>::new();
+            result.extend(slice[start..start + addr_len].iter().copied());
+            result
+        };
+        let sender_protocol_start = ArpPacket::MIN_LEN + hw_addr_len;
+        let target_hw_start = sender_protocol_start + protocol_addr_len;
+        let target_protocol_start = target_hw_start + hw_addr_len;
+        Ok((
+            ArpPacket {
+                hw_addr_type: ArpHardwareId(u16::from_be_bytes([slice[0], slice[1]])),
+                proto_addr_type: EtherType(u16::from_be_bytes([slice[2], slice[3]])),
+                operation: ArpOperation(u16::from_be_bytes([slice[6], slice[7]])),
+                sender_hw_addr: addr(ArpPacket::MIN_LEN, hw_addr_len),
+                sender_protocol_addr: addr(sender_protocol_start, protocol_addr_len),
+                target_hw_addr: addr(target_hw_start, hw_addr_len),
+                target_protocol_addr: addr(target_protocol_start, protocol_addr_len),
+            },
+            &slice[len..],
+        ))
+    }
+
+    /// Length of the hardware addresses in bytes (based on the sender
+    /// hardware address).
+    #[inline]
+    pub fn hw_addr_len(&self) -> u8 {
+        // the length is limited to 255 by the ArrayVec capacity
+        self.sender_hw_addr.len() as u8
+    }
+
+    /// Length of the protocol addresses in bytes (based on the sender
+    /// protocol address).
+    #[inline]
+    pub fn protocol_addr_len(&self) -> u8 {
+        // the length is limited to 255 by the ArrayVec capacity
+        self.sender_protocol_addr.len() as u8
+    }
+
+    /// Serialized length of the packet in bytes.
+    #[inline]
+    pub fn packet_len(&self) -> usize {
+        ArpPacket::MIN_LEN
+            + self.sender_hw_addr.len()
+            + self.sender_protocol_addr.len()
+            + self.target_hw_addr.len()
+            + self.target_protocol_addr.len()
+    }
+
+    /// Checks that the sender & target addresses have the same lengths
+    /// and that the hardware address length matches the hardware type.
+    ///
+    /// This check is also done by [`ArpPacket::to_bytes`] &
+    /// [`ArpPacket::write`] before anything gets serialized.
+    pub fn check_addr_lens(&self) -> Result<(), AddrLenError> {
+        use AddrLenError::*;
+        if self.sender_hw_addr.len() != self.target_hw_addr.len() {
+            return Err(HwAddrLenMismatch {
+                sender_len: self.sender_hw_addr.len(),
+                target_len: self.target_hw_addr.len(),
+            });
+        }
+        if self.sender_protocol_addr.len() != self.target_protocol_addr.len() {
+            return Err(ProtocolAddrLenMismatch {
+                sender_len: self.sender_protocol_addr.len(),
+                target_len: self.target_protocol_addr.len(),
+            });
+        }
+        self.check_hw_addr_len()
+    }
+
+    /// Returns true if the address lengths are consistent with each other
+    /// and with the known address lengths of the hardware & protocol types.
+    ///
+    /// Can be used to detect anomalies in parsed packets (e.g. IPv6 as
+    /// protocol type combined with 4 byte protocol addresses).
+    pub fn is_consistent(&self) -> bool {
+        self.check_addr_lens().is_ok() && self.check_protocol_addr_len().is_ok()
+    }
+
+    /// Checks the protocol address length against the protocol type.
+    fn check_protocol_addr_len(&self) -> Result<(), AddrLenError> {
+        match self.proto_addr_type.protocol_address_len() {
+            Some(expected_len) if usize::from(expected_len) != self.sender_protocol_addr.len() => {
+                Err(AddrLenError::ProtocolAddrLenInvalid {
+                    proto_addr_type: self.proto_addr_type,
+                    expected_len,
+                    actual_len: self.sender_protocol_addr.len(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the hardware address length against the hardware type.
+    fn check_hw_addr_len(&self) -> Result<(), AddrLenError> {
+        match self.hw_addr_type.hardware_address_len() {
+            Some(expected_len) if usize::from(expected_len) != self.sender_hw_addr.len() => {
+                Err(AddrLenError::HwAddrLenInvalid {
+                    hw_addr_type: self.hw_addr_type,
+                    expected_len,
+                    actual_len: self.sender_hw_addr.len(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the serialized packet or an error if the address lengths
+    /// are not valid (see [`ArpPacket::check_addr_lens`]).
+    pub fn to_bytes(&self) -> Result<ArrayVec<u8, { ArpPacket::MAX_LEN }>, AddrLenError> {
+        self.check_addr_lens()?;
+
+        let hw_type = self.hw_addr_type.0.to_be_bytes();
+        let proto_type = self.proto_addr_type.0.to_be_bytes();
+        let op = self.operation.0.to_be_bytes();
+        let mut result = ArrayVec::<u8, { ArpPacket::MAX_LEN }>::new();
+        result.extend([
+            hw_type[0],
+            hw_type[1],
+            proto_type[0],
+            proto_type[1],
+            self.hw_addr_len(),
+            self.protocol_addr_len(),
+            op[0],
+            op[1],
+        ]);
+        result.extend(self.sender_hw_addr.iter().copied());
+        result.extend(self.sender_protocol_addr.iter().copied());
+        result.extend(self.target_hw_addr.iter().copied());
+        result.extend(self.target_protocol_addr.iter().copied());
+        Ok(result)
+    }
+
+    /// Writes the packet to the given writer.
+    ///
+    /// The address lengths are checked before anything gets written
+    /// (see [`ArpPacket::check_addr_lens`]).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(
+        &self,
+        writer: &mut T,
+    ) -> Result<(), err::arp::PacketWriteError> {
+        use err::arp::PacketWriteError::*;
+        let bytes = self.to_bytes().map_err(Content)?;
+        writer.write_all(&bytes).map_err(Io)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::arp::AddrLenError::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    fn infiniband() -> ArpPacket {
+        ArpPacket::try_new(
+            ArpHardwareId::INFINIBAND,
+            EtherType::IPV4,
+            ArpOperation::REPLY,
+            &[1; 20],
+            &[2; 4],
+            &[3; 20],
+            &[4; 4],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        // arbitrary lengths are accepted
+        {
+            let actual = ArpPacket::new(
+                ArpHardwareId::INFINIBAND,
+                EtherType::IPV6,
+                ArpOperation::REQUEST,
+                &[1; 3],
+                &[2; 5],
+                &[3; 3],
+                &[4; 5],
+            )
+            .unwrap();
+            assert_eq!(ArpHardwareId::INFINIBAND, actual.hw_addr_type);
+            assert_eq!(EtherType::IPV6, actual.proto_addr_type);
+            assert_eq!(ArpOperation::REQUEST, actual.operation);
+            assert_eq!(&[1; 3], &actual.sender_hw_addr[..]);
+            assert_eq!(&[2; 5], &actual.sender_protocol_addr[..]);
+            assert_eq!(&[3; 3], &actual.target_hw_addr[..]);
+            assert_eq!(&[4; 5], &actual.target_protocol_addr[..]);
+        }
+        // errors
+        {
+            let new = |sha: &[u8], spa: &[u8], tha: &[u8], tpa: &[u8]| {
+                ArpPacket::new(
+                    ArpHardwareId::ETHER,
+                    EtherType::IPV4,
+                    ArpOperation::REQUEST,
+                    sha,
+                    spa,
+                    tha,
+                    tpa,
+                )
+            };
+            assert_eq!(
+                Err(HwAddrLenMismatch {
+                    sender_len: 6,
+                    target_len: 5
+                }),
+                new(&[0; 6], &[0; 4], &[0; 5], &[0; 4])
+            );
+            assert_eq!(
+                Err(ProtocolAddrLenMismatch {
+                    sender_len: 4,
+                    target_len: 16
+                }),
+                new(&[0; 6], &[0; 4], &[0; 6], &[0; 16])
+            );
+            assert_eq!(
+                Err(HwAddrTooLong { len: 256 }),
+                new(&[0; 256], &[0; 4], &[0; 256], &[0; 4])
+            );
+            assert_eq!(
+                Err(ProtocolAddrTooLong { len: 256 }),
+                new(&[0; 6], &[0; 256], &[0; 6], &[0; 256])
+            );
+        }
+    }
+
+    #[test]
+    fn try_new() {
+        // fixed hardware address lengths
+        for (hw_addr_type, len) in [
+            (ArpHardwareId::ETHER, 6),
+            (ArpHardwareId::IEEE80211, 6),
+            (ArpHardwareId::AX25, 7),
+            (ArpHardwareId::ARCNET, 1),
+            (ArpHardwareId::IEEE1394, 16),
+            (ArpHardwareId::EUI64, 8),
+            (ArpHardwareId::INFINIBAND, 20),
+        ] {
+            let addr = [0xab; 21];
+            let try_new = |hw_len: usize| {
+                ArpPacket::try_new(
+                    hw_addr_type,
+                    EtherType::IPV4,
+                    ArpOperation::REQUEST,
+                    &addr[..hw_len],
+                    &[1; 4],
+                    &addr[..hw_len],
+                    &[2; 4],
+                )
+            };
+            assert_eq!(usize::from(try_new(len).unwrap().hw_addr_len()), len);
+            for bad_len in [0, len - 1, len + 1] {
+                assert_eq!(
+                    Err(HwAddrLenInvalid {
+                        hw_addr_type,
+                        expected_len: len as u8,
+                        actual_len: bad_len
+                    }),
+                    try_new(bad_len)
+                );
+            }
+        }
+        // unknown hardware types accept any length
+        assert!(ArpPacket::try_new(
+            ArpHardwareId(0x1234),
+            EtherType::IPV4,
+            ArpOperation::REQUEST,
+            &[0; 3],
+            &[0; 4],
+            &[0; 3],
+            &[0; 4],
+        )
+        .is_ok());
+        // base checks are still done
+        assert_eq!(
+            Err(HwAddrLenMismatch {
+                sender_len: 20,
+                target_len: 6
+            }),
+            ArpPacket::try_new(
+                ArpHardwareId::INFINIBAND,
+                EtherType::IPV4,
+                ArpOperation::REQUEST,
+                &[0; 20],
+                &[0; 4],
+                &[0; 6],
+                &[0; 4],
+            )
+        );
+    }
+
+    #[test]
+    fn new_checked() {
+        let new_checked = |hw_addr_type, proto_addr_type, hw_len: usize, proto_len: usize| {
+            ArpPacket::new_checked(
+                hw_addr_type,
+                proto_addr_type,
+                ArpOperation::REQUEST,
+                &[1; 21][..hw_len],
+                &[2; 17][..proto_len],
+                &[3; 21][..hw_len],
+                &[4; 17][..proto_len],
+            )
+        };
+
+        // known combinations
+        for (hw_addr_type, hw_len) in [(ArpHardwareId::ETHER, 6), (ArpHardwareId::INFINIBAND, 20)] {
+            for (proto_addr_type, proto_len) in [(EtherType::IPV4, 4), (EtherType::IPV6, 16)] {
+                let actual = new_checked(hw_addr_type, proto_addr_type, hw_len, proto_len).unwrap();
+                assert_eq!(usize::from(actual.hw_addr_len()), hw_len);
+                assert_eq!(usize::from(actual.protocol_addr_len()), proto_len);
+
+                for bad_len in [0, proto_len - 1, proto_len + 1] {
+                    assert_eq!(
+                        Err(ProtocolAddrLenInvalid {
+                            proto_addr_type,
+                            expected_len: proto_len as u8,
+                            actual_len: bad_len,
+                        }),
+                        new_checked(hw_addr_type, proto_addr_type, hw_len, bad_len)
+                    );
+                }
+                assert_eq!(
+                    Err(HwAddrLenInvalid {
+                        hw_addr_type,
+                        expected_len: hw_len as u8,
+                        actual_len: hw_len + 1,
+                    }),
+                    new_checked(hw_addr_type, proto_addr_type, hw_len + 1, proto_len)
+                );
+            }
+        }
+
+        // unknown protocol types accept any length
+        assert!(new_checked(ArpHardwareId::ETHER, EtherType(0x1234), 6, 3).is_ok());
+    }
+
+    #[test]
+    fn is_consistent() {
+        assert!(infiniband().is_consistent());
+
+        // ipv6 with 4 byte protocol addresses
+        {
+            let mut p = infiniband();
+            p.proto_addr_type = EtherType::IPV6;
+            assert!(false == p.is_consistent());
+        }
+        // hardware type & address length mismatch
+        {
+            let mut p = infiniband();
+            p.hw_addr_type = ArpHardwareId::ETHER;
+            assert!(false == p.is_consistent());
+        }
+        // sender & target length mismatch
+        {
+            let mut p = infiniband();
+            p.hw_addr_type = ArpHardwareId(0x1234);
+            p.target_hw_addr.pop();
+            assert!(false == p.is_consistent());
+        }
+        // parsed packet with IPv6 as protocol type & 4 byte addresses
+        {
+            let mut bytes = infiniband().to_bytes().unwrap();
+            bytes[2..4].copy_from_slice(&EtherType::IPV6.0.to_be_bytes());
+            let (p, _) = ArpPacket::from_slice(&bytes).unwrap();
+            assert!(false == p.is_consistent());
+        }
+        // unknown types are always consistent
+        {
+            let p = ArpPacket::new(
+                ArpHardwareId(0x1234),
+                EtherType(0x1234),
+                ArpOperation::REQUEST,
+                &[0; 3],
+                &[0; 5],
+                &[0; 3],
+                &[0; 5],
+            )
+            .unwrap();
+            assert!(p.is_consistent());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes_from_slice(
+            hw_addr_type in any::<u16>(),
+            proto_addr_type in any::<u16>(),
+            operation in any::<u16>(),
+            hw_len in 0..=255usize,
+            proto_len in 0..=255usize,
+            addr_value in any::<u8>(),
+        ) {
+            let hw_addr_type = ArpHardwareId(hw_addr_type);
+            let sha: Vec<u8> = (0..hw_len).map(|i| addr_value.wrapping_add(i as u8)).collect();
+            let spa: Vec<u8> = (0..proto_len).map(|i| addr_value.wrapping_sub(i as u8)).collect();
+            let tha: Vec<u8> = sha.iter().map(|v| !v).collect();
+            let tpa: Vec<u8> = spa.iter().map(|v| !v).collect();
+            let packet = ArpPacket::new(
+                hw_addr_type,
+                EtherType(proto_addr_type),
+                ArpOperation(operation),
+                &sha,
+                &spa,
+                &tha,
+                &tpa,
+            ).unwrap();
+            prop_assert_eq!(ArpPacket::MIN_LEN + 2*hw_len + 2*proto_len, packet.packet_len());
+
+            match hw_addr_type.hardware_address_len() {
+                Some(expected_len) if usize::from(expected_len) != hw_len => {
+                    let expected = HwAddrLenInvalid {
+                        hw_addr_type,
+                        expected_len,
+                        actual_len: hw_len,
+                    };
+                    prop_assert_eq!(Err(expected.clone()), packet.to_bytes());
+                    prop_assert_eq!(Err(expected.clone()), packet.check_addr_lens());
+                }
+                _ => {
+                    let bytes = packet.to_bytes().unwrap();
+                    prop_assert_eq!(packet.packet_len(), bytes.len());
+                    prop_assert_eq!(&hw_addr_type.0.to_be_bytes()[..], &bytes[0..2]);
+                    prop_assert_eq!(&proto_addr_type.to_be_bytes()[..], &bytes[2..4]);
+                    prop_assert_eq!(hw_len as u8, bytes[4]);
+                    prop_assert_eq!(proto_len as u8, bytes[5]);
+                    prop_assert_eq!(&operation.to_be_bytes()[..], &bytes[6..8]);
+
+                    // decode with some trailing data
+                    let mut with_rest = bytes.to_vec();
+                    with_rest.extend_from_slice(&[1, 2]);
+                    let (decoded, rest) = ArpPacket::from_slice(&with_rest).unwrap();
+                    prop_assert_eq!(&packet, &decoded);
+                    prop_assert_eq!(&[1, 2], rest);
+
+                    // length errors
+                    for len in 0..bytes.len() {
+                        let required_len = if len < ArpPacket::MIN_LEN {
+                            ArpPacket::MIN_LEN
+                        } else {
+                            bytes.len()
+                        };
+                        prop_assert_eq!(
+                            Err(err::LenError {
+                                required_len,
+                                len,
+                                len_source: LenSource::Slice,
+                                layer: err::Layer::ArpPacket,
+                                layer_start_offset: 0,
+                            }),
+                            ArpPacket::from_slice(&bytes[..len])
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn check_addr_lens() {
+        assert_eq!(Ok(()), infiniband().check_addr_lens());
+        // modified fields
+        {
+            let mut p = infiniband();
+            p.target_hw_addr.truncate(6);
+            assert_eq!(
+                Err(HwAddrLenMismatch {
+                    sender_len: 20,
+                    target_len: 6
+                }),
+                p.check_addr_lens()
+            );
+        }
+        {
+            let mut p = infiniband();
+            p.target_protocol_addr.push(0);
+            assert_eq!(
+                Err(ProtocolAddrLenMismatch {
+                    sender_len: 4,
+                    target_len: 5
+                }),
+                p.check_addr_lens()
+            );
+        }
+        {
+            let mut p = infiniband();
+            p.hw_addr_type = ArpHardwareId::ETHER;
+            assert_eq!(
+                Err(HwAddrLenInvalid {
+                    hw_addr_type: ArpHardwareId::ETHER,
+                    expected_len: 6,
+                    actual_len: 20
+                }),
+                p.check_addr_lens()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write() {
+        use err::arp::PacketWriteError;
+
+        // ok
+        {
+            let p = infiniband();
+            let mut buffer = Vec::new();
+            p.write(&mut buffer).unwrap();
+            assert_eq!(&p.to_bytes().unwrap()[..], &buffer[..]);
+        }
+        // content error (nothing gets written)
+        {
+            let mut p = infiniband();
+            p.sender_hw_addr.truncate(6);
+            p.target_hw_addr.truncate(6);
+            let mut buffer = Vec::new();
+            let err = p.write(&mut buffer).unwrap_err();
+            assert_eq!(
+                Some(&HwAddrLenInvalid {
+                    hw_addr_type: ArpHardwareId::INFINIBAND,
+                    expected_len: 20,
+                    actual_len: 6
+                }),
+                err.content()
+            );
+            assert!(buffer.is_empty());
+        }
+        // io error
+        {
+            let p = infiniband();
+            let mut buffer = [0u8; 10];
+            let mut cursor = std::io::Cursor::new(&mut buffer[..]);
+            assert!(matches!(p.write(&mut cursor), Err(PacketWriteError::Io(_))));
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let p = infiniband();
+        assert_eq!(p, p.clone());
+        assert!(format!("{:?}", p).starts_with("ArpPacket {"));
+    }
+}
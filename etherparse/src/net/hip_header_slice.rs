@@ -0,0 +1,260 @@
+use crate::*;
+
+/// Slice containing a Host Identity Protocol (HIP) header (RFC 7401).
+///
+/// Only the fixed part of the header is decoded, the HIP parameters
+/// following it are available as raw bytes via [`HipHeaderSlice::parameters`].
+///
+/// HIP headers are not decoded as part of the IPv6 extension headers and
+/// end up in the IP payload when a packet is decoded (e.g. via
+/// [`SlicedPacket`]):
+///
+/// ```
+/// # #[cfg(feature = "ipv6")]
+/// # {
+/// # use etherparse::*;
+/// # let mut hip = [0u8; 40];
+/// # hip[0] = ip_number::IPV6_NO_NEXT_HEADER.0;
+/// # hip[1] = 4;
+/// # hip[2] = HipPacketType::I1.0;
+/// # hip[3] = 0x21;
+/// # let mut packet = Ipv6Header {
+/// #     payload_length: hip.len() as u16,
+/// #     next_header: ip_number::HIP,
+/// #     hop_limit: 64,
+/// #     ..Default::default()
+/// # }.to_bytes().to_vec();
+/// # packet.extend_from_slice(&hip);
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// let ip_payload = sliced.ip_payload().unwrap();
+/// if ip_payload.ip_number == ip_number::HIP {
+///     let hip = HipHeaderSlice::from_slice(ip_payload.payload).unwrap();
+///     assert_eq!(HipPacketType::I1, hip.packet_type());
+///     assert_eq!(2, hip.version());
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HipHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> HipHeaderSlice<'a> {
+    /// Minimum length of a HIP header in bytes/octets (fixed part
+    /// including the sender & receiver host identity tags).
+    pub const MIN_LEN: usize = 40;
+
+    /// Creates a HIP header slice from a slice.
+    ///
+    /// The slice can be longer then the header (the returned slice only
+    /// contains the header itself). A "header length" field smaller than
+    /// the fixed part of the header is reported as an
+    /// [`err::hip::HeaderError::HeaderLengthTooSmall`] error.
+    pub fn from_slice(slice: &'a [u8]) -> Result<HipHeaderSlice<'a>, err::hip::HeaderSliceError> {
+        use err::hip::{HeaderError::*, HeaderSliceError::*};
+
+        if slice.len() < HipHeaderSlice::MIN_LEN {
+            return Err(Len(err::LenError {
+                required_len: HipHeaderSlice::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::HipHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // the header length field is not allowed to be smaller then
+        // the fixed part of the header
+        let header_len = (usize::from(slice[1]) + 1) * 8;
+        if header_len < HipHeaderSlice::MIN_LEN {
+            return Err(Content(HeaderLengthTooSmall {
+                header_length: slice[1],
+            }));
+        }
+        if slice.len() < header_len {
+            return Err(Len(err::LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::HipHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        Ok(HipHeaderSlice {
+            slice: &slice[..header_len],
+        })
+    }
+
+    /// Returns the slice containing the HIP header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "next header" field (normally [`ip_number::IPV6_NO_NEXT_HEADER`]).
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Read the "header length" field (length in 8 octets units,
+    /// excluding the first 8 octets).
+    #[inline]
+    pub fn header_length(&self) -> u8 {
+        self.slice[1]
+    }
+
+    /// Length of the HIP header in bytes/octets.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Read the 7 bit "packet type" field.
+    #[inline]
+    pub fn packet_type(&self) -> HipPacketType {
+        HipPacketType(self.slice[2] & 0b0111_1111)
+    }
+
+    /// Read the 4 bit "version" field.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.slice[3] >> 4
+    }
+
+    /// Read the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[4], self.slice[5]])
+    }
+
+    /// Read the "controls" field.
+    #[inline]
+    pub fn controls(&self) -> u16 {
+        u16::from_be_bytes([self.slice[6], self.slice[7]])
+    }
+
+    /// Read the "sender's host identity tag" (HIT).
+    #[inline]
+    pub fn sender_hit(&self) -> [u8; 16] {
+        let mut result = [0u8; 16];
+        result.copy_from_slice(&self.slice[8..24]);
+        result
+    }
+
+    /// Read the "receiver's host identity tag" (HIT).
+    #[inline]
+    pub fn receiver_hit(&self) -> [u8; 16] {
+        let mut result = [0u8; 16];
+        result.copy_from_slice(&self.slice[24..40]);
+        result
+    }
+
+    /// Returns the slice containing the raw HIP parameters.
+    #[inline]
+    pub fn parameters(&self) -> &'a [u8] {
+        &self.slice[HipHeaderSlice::MIN_LEN..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    fn hip_bytes() -> Vec<u8> {
+        let mut result = Vec::with_capacity(48);
+        result.extend_from_slice(&[
+            ip_number::IPV6_NO_NEXT_HEADER.0,
+            5, // header length ((5 + 1) * 8 = 48)
+            HipPacketType::R1.0,
+            0x21, // version 2 & fixed bit
+            0x12,
+            0x34, // checksum
+            0x56,
+            0x78, // controls
+        ]);
+        result.extend(1u8..=16);
+        result.extend(17u8..=32);
+        result.extend_from_slice(&[9, 8, 7, 6, 5, 4, 3, 2]);
+        result
+    }
+
+    #[test]
+    fn from_slice() {
+        let mut bytes = hip_bytes();
+        bytes.extend_from_slice(&[0xff, 0xff]);
+
+        let slice = HipHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(&bytes[..48], slice.slice());
+        assert_eq!(ip_number::IPV6_NO_NEXT_HEADER, slice.next_header());
+        assert_eq!(5, slice.header_length());
+        assert_eq!(48, slice.header_len());
+        assert_eq!(HipPacketType::R1, slice.packet_type());
+        assert_eq!(2, slice.version());
+        assert_eq!(0x1234, slice.checksum());
+        assert_eq!(0x5678, slice.controls());
+        assert_eq!(
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+            slice.sender_hit()
+        );
+        assert_eq!(
+            [17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32],
+            slice.receiver_hit()
+        );
+        assert_eq!(&[9, 8, 7, 6, 5, 4, 3, 2], slice.parameters());
+    }
+
+    #[test]
+    fn from_slice_header_length_too_small() {
+        let mut bytes = hip_bytes();
+        for header_length in 0..4 {
+            bytes[1] = header_length;
+            assert_eq!(
+                HipHeaderSlice::from_slice(&bytes).unwrap_err(),
+                err::hip::HeaderSliceError::Content(err::hip::HeaderError::HeaderLengthTooSmall {
+                    header_length
+                })
+            );
+        }
+        // smallest valid header length (fixed part only)
+        bytes[1] = 4;
+        let slice = HipHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(HipHeaderSlice::MIN_LEN, slice.header_len());
+        assert!(slice.parameters().is_empty());
+    }
+
+    #[test]
+    fn from_slice_len_error() {
+        let bytes = hip_bytes();
+        for len in 0..bytes.len() {
+            let required_len = if len < HipHeaderSlice::MIN_LEN {
+                HipHeaderSlice::MIN_LEN
+            } else {
+                bytes.len()
+            };
+            assert_eq!(
+                HipHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                err::hip::HeaderSliceError::Len(err::LenError {
+                    required_len,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::HipHeader,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let bytes = hip_bytes();
+        let slice = HipHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!("HipHeaderSlice {{ slice: {:?} }}", slice.slice()),
+            format!("{:?}", slice)
+        );
+    }
+}
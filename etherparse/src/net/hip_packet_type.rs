@@ -0,0 +1,69 @@
+/// Packet type of a Host Identity Protocol (HIP) header
+/// (see [`crate::HipHeaderSlice`]).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `HipPacketType`:
+///
+/// ```
+/// use etherparse::HipPacketType;
+///
+/// assert_eq!(HipPacketType::I1.0, 1);
+///
+/// let value: HipPacketType = 2.into();
+/// assert_eq!(HipPacketType::R1, value);
+///
+/// let num: u8 = HipPacketType::R1.into();
+/// assert_eq!(2, num);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Default, Hash)]
+pub struct HipPacketType(pub u8);
+
+impl HipPacketType {
+    /// HIP initiator packet (RFC 7401).
+    pub const I1: HipPacketType = Self(1);
+    /// HIP responder packet (RFC 7401).
+    pub const R1: HipPacketType = Self(2);
+    /// Second HIP initiator packet (RFC 7401).
+    pub const I2: HipPacketType = Self(3);
+    /// Second HIP responder packet (RFC 7401).
+    pub const R2: HipPacketType = Self(4);
+    /// HIP update packet (RFC 7401).
+    pub const UPDATE: HipPacketType = Self(16);
+    /// HIP notify packet (RFC 7401).
+    pub const NOTIFY: HipPacketType = Self(17);
+    /// HIP close packet (RFC 7401).
+    pub const CLOSE: HipPacketType = Self(18);
+    /// HIP close acknowledgment packet (RFC 7401).
+    pub const CLOSE_ACK: HipPacketType = Self(19);
+}
+
+impl From<u8> for HipPacketType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        HipPacketType(val)
+    }
+}
+
+impl From<HipPacketType> for u8 {
+    #[inline]
+    fn from(val: HipPacketType) -> Self {
+        val.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_into_u8() {
+        for v in [0, 1, 5, 6, 0xff] {
+            assert_eq!(v, u8::from(HipPacketType::from(v)));
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(HipPacketType(0), HipPacketType::default());
+    }
+}
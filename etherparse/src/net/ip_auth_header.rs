@@ -294,8 +294,8 @@ impl<'a> IpAuthHeader {
         result.extend(self.raw_icv_buffer);
         // SAFETY: Safe as the header len can not exceed the maximum length
         // of the header.
-        unsafe {
-            result.set_len(self.header_len());
+        unchecked! {
+            truncate_unchecked(&mut result, self.header_len());
         }
 
         result
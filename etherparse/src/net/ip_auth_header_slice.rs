@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// Deprecated use [IpAuthHeaderSlice] instead.
 #[deprecated(
@@ -34,7 +33,7 @@ impl<'a> IpAuthHeaderSlice<'a> {
 
         // SAFETY:
         // Safe the slice length gets checked to be at least 12 beforehand.
-        let payload_len_enc = unsafe { *slice.get_unchecked(1) };
+        let payload_len_enc = unchecked! { get_unchecked_u8(slice, 1) };
 
         // check header length minimum size
         if payload_len_enc < 1 {
@@ -60,7 +59,7 @@ impl<'a> IpAuthHeaderSlice<'a> {
         Ok(IpAuthHeaderSlice {
             // SAFETY:
             // Safe as slice len is checked to be at last len above.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, len) },
         })
     }
 
@@ -75,9 +74,18 @@ impl<'a> IpAuthHeaderSlice<'a> {
     ///
     /// If these preconditions are not fulfilled the behavior of this function
     /// and the methods of the return IpAuthHeaderSlice will be undefined.
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> IpAuthHeaderSlice<'a> {
-        IpAuthHeaderSlice {
-            slice: from_raw_parts(slice.as_ptr(), ((*slice.get_unchecked(1) as usize) + 2) * 4),
+        unchecked! { IpAuthHeaderSlice::from_slice_unchecked_internal(slice) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`IpAuthHeaderSlice::from_slice_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        pub(crate) fn from_slice_unchecked_internal(slice: &'a [u8]) -> IpAuthHeaderSlice<'a> {
+            IpAuthHeaderSlice {
+                slice: get_unchecked_slice(slice, 0, ((get_unchecked_u8(slice, 1) as usize) + 2) * 4),
+            }
         }
     }
 
@@ -95,7 +103,7 @@ impl<'a> IpAuthHeaderSlice<'a> {
         // SAFETY:
         // Safe as slice length is checked in the constructor
         // to be at least 12.
-        IpNumber(unsafe { *self.slice.get_unchecked(0) })
+        IpNumber(unchecked! { get_unchecked_u8(self.slice, 0) })
     }
 
     /// Read the security parameters index from the slice
@@ -104,7 +112,7 @@ impl<'a> IpAuthHeaderSlice<'a> {
         // SAFETY:
         // Safe as slice length is checked in the constructor
         // to be at least 12.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 4) }
     }
 
     /// This unsigned 32-bit field contains a counter value that
@@ -114,7 +122,7 @@ impl<'a> IpAuthHeaderSlice<'a> {
         // SAFETY:
         // Safe as slice length is checked in the constructor
         // to be at least 12.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(8)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 8) }
     }
 
     /// Return a slice with the raw integrity check value
@@ -137,6 +145,7 @@ impl<'a> IpAuthHeaderSlice<'a> {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use crate::test_gens::*;
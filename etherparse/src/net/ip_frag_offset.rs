@@ -110,9 +110,19 @@ impl IpFragOffset {
     /// otherwise the behavior of functions or data structures relying
     /// on this pre-requirement is undefined.
     #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub const unsafe fn new_unchecked(value: u16) -> IpFragOffset {
-        debug_assert!(value <= IpFragOffset::MAX_U16);
-        IpFragOffset(value)
+        unchecked! { IpFragOffset::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`IpFragOffset::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u16) -> IpFragOffset {
+            debug_assert!(value <= IpFragOffset::MAX_U16);
+            IpFragOffset(value)
+        }
     }
 
     /// Returns the underlying unsigned 13 bit value as an `u16` value.
@@ -161,6 +171,7 @@ impl TryFrom<u16> for IpFragOffset {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use core::hash::{Hash, Hasher};
@@ -14,6 +14,9 @@ pub enum IpHeaders {
 }
 
 impl IpHeaders {
+    /// Minimum summed up length of the IP header & extension headers in bytes/octets.
+    pub const MIN_LEN: usize = Ipv4Header::MIN_LEN;
+
     /// Maximum summed up length of all extension headers in bytes/octets.
     pub const MAX_LEN: usize = Ipv6Header::LEN + Ipv6Extensions::MAX_LEN;
 
@@ -86,7 +89,7 @@ impl IpHeaders {
                     // SAFETY:
                     // Safe as the slice length is checked to be at least
                     // Ipv4Header::MIN_LEN (20) at the start.
-                    let ihl = unsafe { slice.get_unchecked(0) } & 0xf;
+                    let ihl = unchecked! { get_unchecked_u8(slice, 0) } & 0xf;
 
                     //check that the ihl is correct
                     if ihl < 5 {
@@ -105,12 +108,9 @@ impl IpHeaders {
                         }));
                     }
 
-                    let header = unsafe {
+                    let header = unchecked! {
                         // SAFETY: Safe as the IHL & slice len has been validated
-                        Ipv4HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                            slice.as_ptr(),
-                            header_len,
-                        ))
+                        Ipv4HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, header_len))
                         .to_header()
                     };
 
@@ -136,13 +136,10 @@ impl IpHeaders {
                             layer_start_offset: 0,
                         }));
                     } else {
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                // SAFETY: Safe as the slice length was validated to be at least header_length
-                                slice.as_ptr().add(header_len),
-                                // SAFETY: Safe as slice length has been validated to be at least total_length_usize long
-                                total_len - header_len,
-                            )
+                        unchecked! {
+                            get_unchecked_slice(// SAFETY: Safe as the slice length was validated to be at least header_length
+                                slice, header_len, // SAFETY: Safe as slice length has been validated to be at least total_length_usize long
+                                total_len - header_len)
                         }
                     };
 
@@ -170,6 +167,7 @@ impl IpHeaders {
                         },
                     ))
                 }
+                #[cfg(feature = "ipv6")]
                 6 => {
                     if slice.len() < Ipv6Header::LEN {
                         return Err(Len(err::LenError {
@@ -184,55 +182,47 @@ impl IpHeaders {
                         // SAFETY:
                         // This is safe as the slice length is checked to be
                         // at least Ipv6Header::LEN (40) before this code block.
-                        unsafe {
-                            Ipv6HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                                slice.as_ptr(),
-                                Ipv6Header::LEN,
-                            ))
+                        unchecked! {
+                            Ipv6HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, Ipv6Header::LEN))
                             .to_header()
                         }
                     };
 
                     // restrict slice by the length specified in the header
-                    let (header_payload, len_source) =
-                        if 0 == header.payload_length && slice.len() > Ipv6Header::LEN {
-                            // In case the payload_length is 0 assume that the entire
-                            // rest of the slice is part of the packet until the jumbogram
-                            // parameters can be parsed.
-
-                            // TODO: Add payload length parsing from the jumbogram
-                            unsafe {
+                    let (header_payload, len_source) = if 0 == header.payload_length
+                        && slice.len() > Ipv6Header::LEN
+                    {
+                        // In case the payload_length is 0 assume that the entire
+                        // rest of the slice is part of the packet until the jumbogram
+                        // parameters can be parsed.
+
+                        // TODO: Add payload length parsing from the jumbogram
+                        unchecked! {
+                            (
+                                get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN),
+                                LenSource::Slice,
+                            )
+                        }
+                    } else {
+                        let payload_len: usize = header.payload_length.into();
+                        let expected_len = Ipv6Header::LEN + payload_len;
+                        if slice.len() < expected_len {
+                            return Err(Len(LenError {
+                                required_len: expected_len,
+                                len: slice.len(),
+                                len_source: LenSource::Slice,
+                                layer: Layer::Ipv6Packet,
+                                layer_start_offset: 0,
+                            }));
+                        } else {
+                            unchecked! {
                                 (
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
-                                    LenSource::Slice,
+                                    get_unchecked_slice(slice, Ipv6Header::LEN, payload_len),
+                                    LenSource::Ipv6HeaderPayloadLen,
                                 )
                             }
-                        } else {
-                            let payload_len: usize = header.payload_length.into();
-                            let expected_len = Ipv6Header::LEN + payload_len;
-                            if slice.len() < expected_len {
-                                return Err(Len(LenError {
-                                    required_len: expected_len,
-                                    len: slice.len(),
-                                    len_source: LenSource::Slice,
-                                    layer: Layer::Ipv6Packet,
-                                    layer_start_offset: 0,
-                                }));
-                            } else {
-                                unsafe {
-                                    (
-                                        core::slice::from_raw_parts(
-                                            slice.as_ptr().add(Ipv6Header::LEN),
-                                            payload_len,
-                                        ),
-                                        LenSource::Ipv6HeaderPayloadLen,
-                                    )
-                                }
-                            }
-                        };
+                        }
+                    };
 
                     let (exts, next_header, rest) =
                         Ipv6Extensions::from_slice(header.next_header, header_payload).map_err(
@@ -354,7 +344,7 @@ impl IpHeaders {
                     // SAFETY:
                     // Safe as the slice length is checked to be at least
                     // Ipv4Header::MIN_LEN (20) at the start.
-                    let ihl = unsafe { slice.get_unchecked(0) } & 0xf;
+                    let ihl = unchecked! { get_unchecked_u8(slice, 0) } & 0xf;
 
                     //check that the ihl is correct
                     if ihl < 5 {
@@ -373,12 +363,9 @@ impl IpHeaders {
                         }));
                     }
 
-                    let header = unsafe {
+                    let header = unchecked! {
                         // SAFETY: Safe as the IHL & slice len has been validated
-                        Ipv4HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                            slice.as_ptr(),
-                            header_len,
-                        ))
+                        Ipv4HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, header_len))
                         .to_header()
                     };
 
@@ -390,39 +377,30 @@ impl IpHeaders {
                         // fallback to slice len
                         (
                             LenSource::Slice,
-                            unsafe {
-                                core::slice::from_raw_parts(
-                                    // SAFETY: Safe as the slice length was validated to be at least header_length
-                                    slice.as_ptr().add(header_len),
-                                    // SAFETY: Safe as slice length has been validated to be at least header_len long
-                                    slice.len() - header_len,
-                                )
+                            unchecked! {
+                                get_unchecked_slice(// SAFETY: Safe as the slice length was validated to be at least header_length
+                                    slice, header_len, // SAFETY: Safe as slice length has been validated to be at least header_len long
+                                    slice.len() - header_len)
                             },
                             false,
                         )
                     } else if slice.len() < total_len {
                         (
                             LenSource::Slice,
-                            unsafe {
-                                core::slice::from_raw_parts(
-                                    // SAFETY: Safe as the slice length was validated to be at least header_length
-                                    slice.as_ptr().add(header_len),
-                                    // SAFETY: Safe as slice length has been validated to be at least header_len long
-                                    slice.len() - header_len,
-                                )
+                            unchecked! {
+                                get_unchecked_slice(// SAFETY: Safe as the slice length was validated to be at least header_length
+                                    slice, header_len, // SAFETY: Safe as slice length has been validated to be at least header_len long
+                                    slice.len() - header_len)
                             },
                             true,
                         )
                     } else {
                         (
                             LenSource::Ipv4HeaderTotalLen,
-                            unsafe {
-                                core::slice::from_raw_parts(
-                                    // SAFETY: Safe as the slice length was validated to be at least header_length
-                                    slice.as_ptr().add(header_len),
-                                    // SAFETY: Safe as slice length has been validated to be at least total_length_usize long
-                                    total_len - header_len,
-                                )
+                            unchecked! {
+                                get_unchecked_slice(// SAFETY: Safe as the slice length was validated to be at least header_length
+                                    slice, header_len, // SAFETY: Safe as slice length has been validated to be at least total_length_usize long
+                                    total_len - header_len)
                             },
                             false,
                         )
@@ -458,6 +436,7 @@ impl IpHeaders {
                         stop_err.map(|v| (v, Layer::IpAuthHeader)),
                     ))
                 }
+                #[cfg(feature = "ipv6")]
                 6 => {
                     if slice.len() < Ipv6Header::LEN {
                         return Err(Len(err::LenError {
@@ -472,11 +451,8 @@ impl IpHeaders {
                         // SAFETY:
                         // This is safe as the slice length is checked to be
                         // at least Ipv6Header::LEN (40) befpre this code block.
-                        unsafe {
-                            Ipv6HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                                slice.as_ptr(),
-                                Ipv6Header::LEN,
-                            ))
+                        unchecked! {
+                            Ipv6HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, Ipv6Header::LEN))
                             .to_header()
                         }
                     };
@@ -486,40 +462,31 @@ impl IpHeaders {
                     let (header_payload, len_source, incomplete) =
                         if (header.payload_length == 0) && (Ipv6Header::LEN < slice.len()) {
                             // TODO: Add payload length parsing from the jumbogram
-                            unsafe {
+                            unchecked! {
                                 (
-                                    core::slice::from_raw_parts(
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
+                                    get_unchecked_slice(// SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                        slice, Ipv6Header::LEN, // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                        slice.len() - Ipv6Header::LEN),
                                     LenSource::Slice,
                                     false,
                                 )
                             }
                         } else if (slice.len() - Ipv6Header::LEN) < payload_len {
-                            unsafe {
+                            unchecked! {
                                 (
-                                    core::slice::from_raw_parts(
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
+                                    get_unchecked_slice(// SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                        slice, Ipv6Header::LEN, // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                        slice.len() - Ipv6Header::LEN),
                                     LenSource::Slice,
                                     true,
                                 )
                             }
                         } else {
-                            unsafe {
+                            unchecked! {
                                 (
-                                    core::slice::from_raw_parts(
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        // SAFTEY: Safe as we verify that `(slice.len() - Ipv6Header::LEN) >= payload_len` above.
-                                        payload_len,
-                                    ),
+                                    get_unchecked_slice(// SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                        slice, Ipv6Header::LEN, // SAFTEY: Safe as we verify that `(slice.len() - Ipv6Header::LEN) >= payload_len` above.
+                                        payload_len),
                                     LenSource::Ipv6HeaderPayloadLen,
                                     false,
                                 )
@@ -612,9 +579,9 @@ impl IpHeaders {
                 layer_start_offset: 0,
             }));
         } else {
-            unsafe {
+            unchecked! {
                 // Safe as the payload_len <= header_rest.len is verified above
-                core::slice::from_raw_parts(header_rest.as_ptr(), payload_len)
+                get_unchecked_slice(header_rest, 0, payload_len)
             }
         };
 
@@ -729,12 +696,9 @@ impl IpHeaders {
         } else {
             (
                 LenSource::Ipv4HeaderTotalLen,
-                unsafe {
-                    core::slice::from_raw_parts(
-                        header_rest.as_ptr(),
-                        // SAFETY: Safe as slice length has been validated to be at least total_length_usize long
-                        total_len - header_len,
-                    )
+                unchecked! {
+                    get_unchecked_slice(header_rest, 0, // SAFETY: Safe as slice length has been validated to be at least total_length_usize long
+                        total_len - header_len)
                 },
                 false,
             )
@@ -804,9 +768,9 @@ impl IpHeaders {
                         layer_start_offset: 0,
                     }));
                 } else {
-                    unsafe {
+                    unchecked! {
                         (
-                            core::slice::from_raw_parts(header_rest.as_ptr(), payload_len),
+                            get_unchecked_slice(header_rest, 0, payload_len),
                             LenSource::Ipv6HeaderPayloadLen,
                         )
                     }
@@ -901,9 +865,9 @@ impl IpHeaders {
             } else if payload_len > header_rest.len() {
                 (header_rest, LenSource::Slice, true)
             } else {
-                unsafe {
+                unchecked! {
                     (
-                        core::slice::from_raw_parts(header_rest.as_ptr(), payload_len),
+                        get_unchecked_slice(header_rest, 0, payload_len),
                         LenSource::Ipv6HeaderPayloadLen,
                         false,
                     )
@@ -966,7 +930,7 @@ impl IpHeaders {
                 buffer[0] = value;
                 reader.read_exact(&mut buffer[1..header_len]).map_err(Io)?;
 
-                let header = unsafe {
+                let header = unchecked! {
                     // SAFETY: Safe as both the IHL and slice len have been verified
                     Ipv4HeaderSlice::from_slice_unchecked(&buffer[..header_len])
                 }
@@ -1005,6 +969,7 @@ impl IpHeaders {
                         }
                     })
             }
+            #[cfg(feature = "ipv6")]
             6 => {
                 let header = Ipv6Header::read_without_version(reader, value & 0xf).map_err(Io)?;
 
@@ -1032,6 +997,122 @@ impl IpHeaders {
         }
     }
 
+    /// Reads an IP (v4 or v6) header from the current position of the
+    /// async reader (requires crate feature `tokio`).
+    ///
+    /// Only the bytes of the IP header & extension headers are read
+    /// from the reader. The extension headers are limited by the length
+    /// fields of the IP header (same as [`IpHeaders::read`]). Note that
+    /// if the data ends early & a length field is too small at the same
+    /// time, an io error can be returned where [`IpHeaders::read`]
+    /// returns a length error.
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<(IpHeaders, IpNumber), err::ip::HeaderReadError> {
+        use err::ip::{HeaderError::*, HeaderReadError::*, HeadersError::*};
+
+        let mut buffer = [0u8; Ipv4Header::MAX_LEN];
+        async_read_exact(reader, &mut buffer[..1])
+            .await
+            .map_err(Io)?;
+        let value = buffer[0];
+        match value >> 4 {
+            4 => {
+                // get internet header length
+                let ihl = value & 0xf;
+
+                // check that the ihl is correct
+                if ihl < 5 {
+                    return Err(Content(Ip(Ipv4HeaderLengthSmallerThanHeader { ihl })));
+                }
+
+                // read the rest of the header
+                let header_len = usize::from(ihl) * 4;
+                async_read_exact(reader, &mut buffer[1..header_len])
+                    .await
+                    .map_err(Io)?;
+
+                let header = unchecked! {
+                    // SAFETY: Safe as both the IHL and slice len have been verified
+                    Ipv4HeaderSlice::from_slice_unchecked(&buffer[..header_len])
+                }
+                .to_header();
+
+                // check that the total len is long enough to contain the header
+                let total_len = usize::from(header.total_len);
+                if total_len < header_len {
+                    return Err(Len(LenError {
+                        required_len: header_len,
+                        len: total_len,
+                        len_source: LenSource::Ipv4HeaderTotalLen,
+                        layer: Layer::Ipv4Packet,
+                        layer_start_offset: 0,
+                    }));
+                }
+
+                // read the extension headers (if present)
+                let mut exts_buffer = std::vec::Vec::new();
+                loop {
+                    use err::ip_auth::HeaderSliceError as I;
+                    match Ipv4Extensions::from_slice(header.protocol, &exts_buffer) {
+                        Ok((exts, next, _)) => return Ok((IpHeaders::Ipv4(header, exts), next)),
+                        Err(I::Content(err)) => return Err(Content(Ipv4Ext(err))),
+                        Err(I::Len(err)) => {
+                            async_read_more(
+                                reader,
+                                &mut exts_buffer,
+                                err,
+                                total_len - header_len,
+                                LenSource::Ipv4HeaderTotalLen,
+                                header_len,
+                            )
+                            .await?
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "ipv6")]
+            6 => {
+                let mut buffer = [0u8; Ipv6Header::LEN];
+                buffer[0] = value;
+                async_read_exact(reader, &mut buffer[1..])
+                    .await
+                    .map_err(Io)?;
+
+                let header = unchecked! {
+                    // SAFETY: Safe as the buffer has the exact size of an
+                    // IPv6 header & the version was verified
+                    Ipv6HeaderSlice::from_slice_unchecked(&buffer)
+                }
+                .to_header();
+
+                // read the extension headers (if present)
+                let mut exts_buffer = std::vec::Vec::new();
+                loop {
+                    use err::ipv6_exts::HeaderSliceError as I;
+                    match Ipv6Extensions::from_slice(header.next_header, &exts_buffer) {
+                        Ok((exts, next, _)) => return Ok((IpHeaders::Ipv6(header, exts), next)),
+                        Err(I::Content(err)) => return Err(Content(Ipv6Ext(err))),
+                        Err(I::Len(err)) => {
+                            async_read_more(
+                                reader,
+                                &mut exts_buffer,
+                                err,
+                                header.payload_length.into(),
+                                LenSource::Ipv6HeaderPayloadLen,
+                                Ipv6Header::LEN,
+                            )
+                            .await?
+                        }
+                    }
+                }
+            }
+            version_number => Err(Content(Ip(UnsupportedIpVersion { version_number }))),
+        }
+    }
+
     /// Writes an IP (v4 or v6) header to the current position (requires
     /// crate feature `std`).
     #[cfg(feature = "std")]
@@ -1158,19 +1239,51 @@ impl IpHeaders {
     }
 }
 
+/// Reads the data missing in `buffer` (based on the given len error of
+/// slice parsing the buffer) from the async reader. Returns a len error
+/// if the data would exceed `max_len` (the length left in the IP packet).
+#[cfg(feature = "tokio")]
+async fn async_read_more<T: tokio::io::AsyncRead + Unpin>(
+    reader: &mut T,
+    buffer: &mut std::vec::Vec<u8>,
+    slice_err: LenError,
+    max_len: usize,
+    len_source: LenSource,
+    offset: usize,
+) -> Result<(), err::ip::HeaderReadError> {
+    use err::ip::HeaderReadError::*;
+
+    let required_len = slice_err.layer_start_offset + slice_err.required_len;
+    if required_len > max_len {
+        return Err(Len(LenError {
+            required_len: slice_err.required_len,
+            len: max_len - slice_err.layer_start_offset,
+            len_source,
+            layer: slice_err.layer,
+            layer_start_offset: offset + slice_err.layer_start_offset,
+        }));
+    }
+    let start = buffer.len();
+    buffer.resize(required_len, 0);
+    async_read_exact(reader, &mut buffer[start..])
+        .await
+        .map_err(Io)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "ipv6")]
+    use crate::err::ip::{HeadersError, HeadersSliceError};
     use crate::{
-        err::{
-            ip::{HeadersError, HeadersSliceError},
-            Layer, LenError,
-        },
+        err::{Layer, LenError},
         ip_number::*,
         test_gens::*,
         *,
     };
     use alloc::{borrow::ToOwned, format, vec::Vec};
     use proptest::prelude::*;
+    #[cfg(feature = "ipv6")]
     use std::io::Cursor;
 
     const EXTENSION_KNOWN_IP_NUMBERS: [IpNumber; 5] = [
@@ -1314,6 +1427,7 @@ mod test {
     }
 
     proptest! {
+        #[cfg(feature = "ipv6")]
         #[test]
         fn ip_from_slice(
             v4 in ipv4_any(),
@@ -1450,6 +1564,7 @@ mod test {
     }
 
     proptest! {
+        #[cfg(feature = "ipv6")]
         #[test]
         fn from_slice_lax(
             v4 in ipv4_any(),
@@ -2163,6 +2278,7 @@ mod test {
     }
 
     proptest! {
+        #[cfg(feature = "ipv6")]
         #[test]
         fn read(
             v4 in ipv4_any(),
@@ -2357,7 +2473,7 @@ mod test {
                 // extension content error
                 if let Some(auth) = v6_exts.auth.as_ref() {
                     // only do it if auth is the last header
-                    if v6_exts.routing.is_none() {
+                    if v6_exts.routing.is_none() && v6_exts.mobility.is_none() {
                         // inject zero as header len
                         let mut buffer = buffer.clone();
                         let auth_offset = buffer.len() - auth.header_len();
@@ -2378,7 +2494,85 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            v4 in ipv4_any(),
+            v4_exts in ipv4_extensions_any(),
+            v6 in ipv6_any(),
+            v6_exts in ipv6_extensions_any(),
+            len_field in any::<u16>(),
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            // compare the async read with the sync read for prefixes
+            // of the given data (cut in the ip header, at the start of
+            // the extensions & in the extensions)
+            let check = |data: &[u8], ip_len: usize| {
+                let lens = [0, 1, ip_len - 1, ip_len, ip_len + 1, data.len() - 1, data.len()];
+                for len in lens.into_iter().filter(|len| *len <= data.len()) {
+                    let mut cursor = Cursor::new(&data[..len]);
+                    let expected = IpHeaders::read(&mut cursor);
+                    let mut reader = &data[..len];
+                    let actual = rt.block_on(IpHeaders::async_read(&mut reader));
+                    match (expected, actual) {
+                        (Ok(expected), Ok(actual)) => {
+                            assert_eq!(expected, actual);
+                            assert_eq!(cursor.position() as usize, len - reader.len());
+                        }
+                        (Err(expected), Err(actual)) => {
+                            use err::ip::HeaderReadError::*;
+                            match (expected, actual) {
+                                (Io(_), Io(_)) => {}
+                                // if the data is cut & a length field is too small
+                                // the sync reader can hit the length limit first as it
+                                // reads the extension headers in smaller chunks
+                                (Io(_), Len(_)) | (Len(_), Io(_)) if len < data.len() => {}
+                                (Len(expected), Len(actual)) => assert_eq!(expected, actual),
+                                (Content(expected), Content(actual)) => {
+                                    assert_eq!(expected, actual)
+                                }
+                                (expected, actual) => panic!("{:?} != {:?}", expected, actual),
+                            }
+                        }
+                        (expected, actual) => panic!("{:?} != {:?}", expected, actual),
+                    }
+                }
+            };
+
+            // v4
+            {
+                let header = combine_v4(&v4, &v4_exts, &[]);
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                check(&buffer, v4.header_len());
+
+                // modified total length
+                buffer[2..4].copy_from_slice(&len_field.to_be_bytes());
+                check(&buffer, v4.header_len());
+
+                // unsupported version
+                buffer[0] = 0xf << 4;
+                check(&buffer, v4.header_len());
+            }
+
+            // v6
+            {
+                let header = combine_v6(&v6, &v6_exts, &[]);
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                check(&buffer, Ipv6Header::LEN);
+
+                // modified payload length
+                buffer[4..6].copy_from_slice(&len_field.to_be_bytes());
+                check(&buffer, Ipv6Header::LEN);
+            }
+        }
+    }
+
     proptest! {
+        #[cfg(feature = "ipv6")]
         #[test]
         fn write(
             v4 in ipv4_any(),
@@ -357,6 +357,15 @@ impl IpNumber {
         )
     }
 
+    /// Returns true if the given number identifies a transport layer
+    /// protocol that etherparse decodes into a [`crate::TransportSlice`]
+    /// or [`crate::TransportHeader`] (ICMP, TCP, UDP & ICMPv6).
+    #[inline]
+    pub const fn is_transport_like(self) -> bool {
+        use crate::ip_number::*;
+        matches!(self, ICMP | TCP | UDP | IPV6_ICMP)
+    }
+
     /// Returns the "keyword" string if known. Usually this is the abbreviation of the protocol.
     ///
     /// # Example
@@ -729,6 +738,42 @@ impl From<IpNumber> for u8 {
     }
 }
 
+/// Parses an [`IpNumber`] from a decimal number, a hexadecimal number
+/// starting with `0x` or the IANA keyword of the protocol (see
+/// [`IpNumber::keyword_str`], ignoring the case & treating `_` and `-`
+/// as equal).
+///
+/// Additionally `icmpv6` is accepted as alias for the IANA keyword
+/// `IPv6-ICMP`.
+///
+/// ```
+/// use etherparse::IpNumber;
+///
+/// assert_eq!(Ok(IpNumber::TCP), "tcp".parse());
+/// assert_eq!(Ok(IpNumber::TCP), "6".parse());
+/// assert_eq!(Ok(IpNumber::TCP), "0x06".parse());
+/// assert_eq!(Ok(IpNumber::IPV6_ICMP), "icmpv6".parse());
+/// assert!("unknown".parse::<IpNumber>().is_err());
+/// ```
+impl core::str::FromStr for IpNumber {
+    type Err = crate::err::ValueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = crate::parse_number::<u8>(s) {
+            return Ok(IpNumber(value));
+        }
+        if crate::eq_name(s, "icmpv6") {
+            return Ok(IpNumber::IPV6_ICMP);
+        }
+        (0..=u8::MAX)
+            .map(IpNumber)
+            .find(|v| v.keyword_str().is_some_and(|name| crate::eq_name(s, name)))
+            .ok_or(crate::err::ValueParseError {
+                value_type: crate::err::ValueType::IpNumber,
+            })
+    }
+}
+
 impl core::fmt::Debug for IpNumber {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(keyword) = self.keyword_str() {
@@ -1113,6 +1158,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_transport_like() {
+        use crate::ip_number::*;
+        for i in 0..=u8::MAX {
+            assert_eq!(
+                [ICMP, TCP, UDP, IPV6_ICMP].contains(&IpNumber(i)),
+                IpNumber(i).is_transport_like()
+            );
+        }
+        // usable in const contexts
+        const UDP_IS_TRANSPORT_LIKE: bool = IpNumber::UDP.is_transport_like();
+        assert!(UDP_IS_TRANSPORT_LIKE);
+    }
+
     #[test]
     fn keyword_str() {
         // auto generated from CSV
@@ -1891,4 +1950,28 @@ mod tests {
         assert_eq!(value.cmp(&value.clone()), Ordering::Equal);
         assert!(value.ge(&value.clone()));
     }
+
+    #[test]
+    fn from_str() {
+        use core::str::FromStr;
+        for value in (0..=u8::MAX).map(IpNumber) {
+            assert_eq!(Ok(value), IpNumber::from_str(&format!("{}", value.0)));
+            assert_eq!(Ok(value), IpNumber::from_str(&format!("{:#x}", value.0)));
+            if let Some(keyword) = value.keyword_str() {
+                assert_eq!(Ok(value), IpNumber::from_str(keyword));
+                assert_eq!(Ok(value), IpNumber::from_str(&keyword.to_ascii_lowercase()));
+            }
+        }
+        assert_eq!(Ok(IpNumber::UDP), IpNumber::from_str("udp"));
+        assert_eq!(Ok(IpNumber::IPV6_ICMP), IpNumber::from_str("ICMPv6"));
+        assert_eq!(Ok(IpNumber::IPV6_ICMP), IpNumber::from_str("ipv6_icmp"));
+        for s in ["", "0x", "256", "-1", "unknown"] {
+            assert_eq!(
+                Err(crate::err::ValueParseError {
+                    value_type: crate::err::ValueType::IpNumber
+                }),
+                IpNumber::from_str(s)
+            );
+        }
+    }
 }
@@ -104,7 +104,7 @@ impl<'a> IpSlice<'a> {
             }))
         } else {
             // SAFETY: Safe as slice is not empty.
-            let first_byte = unsafe { slice.get_unchecked(0) };
+            let first_byte = unchecked! { get_unchecked_u8(slice, 0) };
             match first_byte >> 4 {
                 4 => {
                     let ihl = first_byte & 0xf;
@@ -129,11 +129,8 @@ impl<'a> IpSlice<'a> {
                     // SAFETY:
                     // Safe as the slice length is checked to be at least
                     // header_len or greater above.
-                    let header = unsafe {
-                        Ipv4HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                            slice.as_ptr(),
-                            header_len,
-                        ))
+                    let header = unchecked! {
+                        Ipv4HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, header_len))
                     };
 
                     // check the total_length at least contains the header
@@ -158,15 +155,12 @@ impl<'a> IpSlice<'a> {
                             layer_start_offset: 0,
                         }));
                     } else {
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                // SAFETY: Safe as slice.len() >= header_len was validated
+                        unchecked! {
+                            get_unchecked_slice(// SAFETY: Safe as slice.len() >= header_len was validated
                                 // in a if statement above.
-                                slice.as_ptr().add(header_len),
-                                // SAFETY: Safe as total_length >= header_len was verified in an
+                                slice, header_len, // SAFETY: Safe as total_length >= header_len was verified in an
                                 // if statement above as well as that slice.len() >= total_length_usize.
-                                total_len - header_len,
-                            )
+                                total_len - header_len)
                         }
                     };
 
@@ -194,11 +188,8 @@ impl<'a> IpSlice<'a> {
                             };
 
                             // remove the extension header from the payload
-                            let payload = unsafe {
-                                core::slice::from_raw_parts(
-                                    header_payload.as_ptr().add(auth.slice().len()),
-                                    header_payload.len() - auth.slice().len(),
-                                )
+                            let payload = unchecked! {
+                                get_unchecked_slice(header_payload, auth.slice().len(), header_payload.len() - auth.slice().len())
                             };
                             Ok(Ipv4(Ipv4Slice {
                                 header,
@@ -223,6 +214,7 @@ impl<'a> IpSlice<'a> {
                         })),
                     }
                 }
+                #[cfg(feature = "ipv6")]
                 6 => {
                     // check length
                     if slice.len() < Ipv6Header::LEN {
@@ -235,53 +227,45 @@ impl<'a> IpSlice<'a> {
                         }));
                     }
 
-                    let header = unsafe {
-                        Ipv6HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                            slice.as_ptr(),
-                            Ipv6Header::LEN,
-                        ))
+                    let header = unchecked! {
+                        Ipv6HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, Ipv6Header::LEN))
                     };
 
                     // restrict slice by the length specified in the header
-                    let (header_payload, len_source) =
-                        if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                            // In case the payload_length is 0 assume that the entire
-                            // rest of the slice is part of the packet until the jumbogram
-                            // parameters can be parsed.
-
-                            // TODO: Add payload length parsing from the jumbogram
+                    let (header_payload, len_source) = if 0 == header.payload_length()
+                        && slice.len() > Ipv6Header::LEN
+                    {
+                        // In case the payload_length is 0 assume that the entire
+                        // rest of the slice is part of the packet until the jumbogram
+                        // parameters can be parsed.
+
+                        // TODO: Add payload length parsing from the jumbogram
+                        (
+                            unchecked! {
+                                get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
+                            },
+                            LenSource::Slice,
+                        )
+                    } else {
+                        let payload_len = usize::from(header.payload_length());
+                        let expected_len = Ipv6Header::LEN + payload_len;
+                        if slice.len() < expected_len {
+                            return Err(Len(LenError {
+                                required_len: expected_len,
+                                len: slice.len(),
+                                len_source: LenSource::Slice,
+                                layer: Layer::Ipv6Packet,
+                                layer_start_offset: 0,
+                            }));
+                        } else {
                             (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    )
+                                unchecked! {
+                                    get_unchecked_slice(slice, Ipv6Header::LEN, payload_len)
                                 },
-                                LenSource::Slice,
+                                LenSource::Ipv6HeaderPayloadLen,
                             )
-                        } else {
-                            let payload_len = usize::from(header.payload_length());
-                            let expected_len = Ipv6Header::LEN + payload_len;
-                            if slice.len() < expected_len {
-                                return Err(Len(LenError {
-                                    required_len: expected_len,
-                                    len: slice.len(),
-                                    len_source: LenSource::Slice,
-                                    layer: Layer::Ipv6Packet,
-                                    layer_start_offset: 0,
-                                }));
-                            } else {
-                                (
-                                    unsafe {
-                                        core::slice::from_raw_parts(
-                                            slice.as_ptr().add(Ipv6Header::LEN),
-                                            payload_len,
-                                        )
-                                    },
-                                    LenSource::Ipv6HeaderPayloadLen,
-                                )
-                            }
-                        };
+                        }
+                    };
 
                     // parse extension headers
                     let (exts, payload_ip_number, payload) =
@@ -579,6 +563,7 @@ mod test {
     }
 
     proptest! {
+        #[cfg(feature = "ipv6")]
         #[test]
         fn from_ip_slice(
             ipv4_header in ipv4_any(),
@@ -1,7 +1,24 @@
-use crate::err::ValueTooBigError;
+use crate::{err::ValueTooBigError, Ipv4DscpNamed};
 
 /// 6 bit unsigned integer containing the "Differentiated Services
-/// Code Point" (present in the [`crate::Ipv4Header`]).
+/// Code Point" (present in the [`crate::Ipv4Header`] & in the upper
+/// 6 bits of the traffic class of the [`crate::Ipv6Header`]).
+///
+/// The standard code points registered by IANA are available as constants
+/// and their names can be determined via [`Ipv4Dscp::name_str`]:
+///
+/// ```
+/// use etherparse::Ipv4Dscp;
+///
+/// assert_eq!(46, Ipv4Dscp::EF.value());
+/// assert_eq!(Some("AF41"), Ipv4Dscp::AF41.name_str());
+/// assert_eq!(None, Ipv4Dscp::try_new(3).unwrap().name_str());
+///
+/// // Display prints the value, use `named()` to display the name instead
+/// assert_eq!("34", Ipv4Dscp::AF41.to_string());
+/// assert_eq!("AF41", Ipv4Dscp::AF41.named().to_string());
+/// assert_eq!("3", Ipv4Dscp::try_new(3).unwrap().named().to_string());
+/// ```
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Ipv4Dscp(u8);
 
@@ -12,6 +29,53 @@ impl Ipv4Dscp {
     /// Maximum value of an IPv4 header DSCP.
     pub const MAX_U8: u8 = 0b0011_1111;
 
+    /// Class Selector 0 / Default Forwarding \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS0: Ipv4Dscp = Ipv4Dscp(0b00_0000);
+    /// Class Selector 1 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS1: Ipv4Dscp = Ipv4Dscp(0b00_1000);
+    /// Class Selector 2 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS2: Ipv4Dscp = Ipv4Dscp(0b01_0000);
+    /// Class Selector 3 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS3: Ipv4Dscp = Ipv4Dscp(0b01_1000);
+    /// Class Selector 4 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS4: Ipv4Dscp = Ipv4Dscp(0b10_0000);
+    /// Class Selector 5 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS5: Ipv4Dscp = Ipv4Dscp(0b10_1000);
+    /// Class Selector 6 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS6: Ipv4Dscp = Ipv4Dscp(0b11_0000);
+    /// Class Selector 7 \[[RFC2474](https://datatracker.ietf.org/doc/html/rfc2474)\]
+    pub const CS7: Ipv4Dscp = Ipv4Dscp(0b11_1000);
+    /// Assured Forwarding class 1, low drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF11: Ipv4Dscp = Ipv4Dscp(0b00_1010);
+    /// Assured Forwarding class 1, medium drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF12: Ipv4Dscp = Ipv4Dscp(0b00_1100);
+    /// Assured Forwarding class 1, high drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF13: Ipv4Dscp = Ipv4Dscp(0b00_1110);
+    /// Assured Forwarding class 2, low drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF21: Ipv4Dscp = Ipv4Dscp(0b01_0010);
+    /// Assured Forwarding class 2, medium drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF22: Ipv4Dscp = Ipv4Dscp(0b01_0100);
+    /// Assured Forwarding class 2, high drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF23: Ipv4Dscp = Ipv4Dscp(0b01_0110);
+    /// Assured Forwarding class 3, low drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF31: Ipv4Dscp = Ipv4Dscp(0b01_1010);
+    /// Assured Forwarding class 3, medium drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF32: Ipv4Dscp = Ipv4Dscp(0b01_1100);
+    /// Assured Forwarding class 3, high drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF33: Ipv4Dscp = Ipv4Dscp(0b01_1110);
+    /// Assured Forwarding class 4, low drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF41: Ipv4Dscp = Ipv4Dscp(0b10_0010);
+    /// Assured Forwarding class 4, medium drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF42: Ipv4Dscp = Ipv4Dscp(0b10_0100);
+    /// Assured Forwarding class 4, high drop precedence \[[RFC2597](https://datatracker.ietf.org/doc/html/rfc2597)\]
+    pub const AF43: Ipv4Dscp = Ipv4Dscp(0b10_0110);
+    /// Voice Admit \[[RFC5865](https://datatracker.ietf.org/doc/html/rfc5865)\]
+    pub const VOICE_ADMIT: Ipv4Dscp = Ipv4Dscp(0b10_1100);
+    /// Expedited Forwarding \[[RFC3246](https://datatracker.ietf.org/doc/html/rfc3246)\]
+    pub const EF: Ipv4Dscp = Ipv4Dscp(0b10_1110);
+    /// Lower-Effort \[[RFC8622](https://datatracker.ietf.org/doc/html/rfc8622)\]
+    pub const LE: Ipv4Dscp = Ipv4Dscp(0b00_0001);
+
     /// Tries to create an [`Ipv4Dscp`] and checks that the passed value
     /// is smaller or equal than [`Ipv4Dscp::MAX_U8`] (6 bit unsigned integer).
     ///
@@ -60,9 +124,19 @@ impl Ipv4Dscp {
     /// otherwise the behavior of functions or data structures relying
     /// on this pre-requirement is undefined.
     #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub const unsafe fn new_unchecked(value: u8) -> Ipv4Dscp {
-        debug_assert!(value <= Ipv4Dscp::MAX_U8);
-        Ipv4Dscp(value)
+        unchecked! { Ipv4Dscp::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`Ipv4Dscp::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u8) -> Ipv4Dscp {
+            debug_assert!(value <= Ipv4Dscp::MAX_U8);
+            Ipv4Dscp(value)
+        }
     }
 
     /// Returns the underlying unsigned 6 bit value as an `u8` value.
@@ -70,6 +144,44 @@ impl Ipv4Dscp {
     pub const fn value(self) -> u8 {
         self.0
     }
+
+    /// Returns the IANA name of the code point (e.g. "EF" or "AF41")
+    /// if the value is a registered standard code point.
+    pub const fn name_str(self) -> Option<&'static str> {
+        match self.0 {
+            0b00_0000 => Some("CS0"),
+            0b00_1000 => Some("CS1"),
+            0b01_0000 => Some("CS2"),
+            0b01_1000 => Some("CS3"),
+            0b10_0000 => Some("CS4"),
+            0b10_1000 => Some("CS5"),
+            0b11_0000 => Some("CS6"),
+            0b11_1000 => Some("CS7"),
+            0b00_1010 => Some("AF11"),
+            0b00_1100 => Some("AF12"),
+            0b00_1110 => Some("AF13"),
+            0b01_0010 => Some("AF21"),
+            0b01_0100 => Some("AF22"),
+            0b01_0110 => Some("AF23"),
+            0b01_1010 => Some("AF31"),
+            0b01_1100 => Some("AF32"),
+            0b01_1110 => Some("AF33"),
+            0b10_0010 => Some("AF41"),
+            0b10_0100 => Some("AF42"),
+            0b10_0110 => Some("AF43"),
+            0b10_1100 => Some("VOICE-ADMIT"),
+            0b10_1110 => Some("EF"),
+            0b00_0001 => Some("LE"),
+            _ => None,
+        }
+    }
+
+    /// Returns a wrapper that displays the IANA name of the code point
+    /// (falls back to the value if the code point has no name).
+    #[inline]
+    pub const fn named(self) -> Ipv4DscpNamed {
+        Ipv4DscpNamed(self)
+    }
 }
 
 impl core::fmt::Display for Ipv4Dscp {
@@ -105,6 +217,7 @@ impl TryFrom<u8> for Ipv4Dscp {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use core::hash::{Hash, Hasher};
@@ -234,6 +347,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn name_str() {
+        let tests = [
+            (Ipv4Dscp::CS0, 0, "CS0"),
+            (Ipv4Dscp::CS1, 8, "CS1"),
+            (Ipv4Dscp::CS2, 16, "CS2"),
+            (Ipv4Dscp::CS3, 24, "CS3"),
+            (Ipv4Dscp::CS4, 32, "CS4"),
+            (Ipv4Dscp::CS5, 40, "CS5"),
+            (Ipv4Dscp::CS6, 48, "CS6"),
+            (Ipv4Dscp::CS7, 56, "CS7"),
+            (Ipv4Dscp::AF11, 10, "AF11"),
+            (Ipv4Dscp::AF12, 12, "AF12"),
+            (Ipv4Dscp::AF13, 14, "AF13"),
+            (Ipv4Dscp::AF21, 18, "AF21"),
+            (Ipv4Dscp::AF22, 20, "AF22"),
+            (Ipv4Dscp::AF23, 22, "AF23"),
+            (Ipv4Dscp::AF31, 26, "AF31"),
+            (Ipv4Dscp::AF32, 28, "AF32"),
+            (Ipv4Dscp::AF33, 30, "AF33"),
+            (Ipv4Dscp::AF41, 34, "AF41"),
+            (Ipv4Dscp::AF42, 36, "AF42"),
+            (Ipv4Dscp::AF43, 38, "AF43"),
+            (Ipv4Dscp::VOICE_ADMIT, 44, "VOICE-ADMIT"),
+            (Ipv4Dscp::EF, 46, "EF"),
+            (Ipv4Dscp::LE, 1, "LE"),
+        ];
+        for (dscp, value, name) in tests {
+            assert_eq!(value, dscp.value());
+            assert_eq!(Some(name), dscp.name_str());
+        }
+        for value in 0..=Ipv4Dscp::MAX_U8 {
+            if tests.iter().all(|(_, v, _)| *v != value) {
+                assert_eq!(None, Ipv4Dscp(value).name_str());
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn fmt(valid_value in 0..=0b0011_1111u8) {
@@ -0,0 +1,47 @@
+use crate::Ipv4Dscp;
+
+/// Wrapper displaying the IANA name of an [`Ipv4Dscp`] (e.g. "EF")
+/// or the numeric value if the code point has no registered name
+/// (created via [`Ipv4Dscp::named`]).
+///
+/// ```
+/// use etherparse::Ipv4Dscp;
+///
+/// assert_eq!("EF", format!("{}", Ipv4Dscp::EF.named()));
+/// assert_eq!("3", format!("{}", Ipv4Dscp::try_new(3).unwrap().named()));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv4DscpNamed(pub Ipv4Dscp);
+
+impl core::fmt::Display for Ipv4DscpNamed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.0.name_str() {
+            f.write_str(name)
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn fmt() {
+        for value in 0..=Ipv4Dscp::MAX_U8 {
+            let dscp = Ipv4Dscp::try_new(value).unwrap();
+            if let Some(name) = dscp.name_str() {
+                assert_eq!(name, format!("{}", Ipv4DscpNamed(dscp)));
+            } else {
+                assert_eq!(format!("{}", value), format!("{}", Ipv4DscpNamed(dscp)));
+            }
+        }
+    }
+
+    #[test]
+    fn named() {
+        assert_eq!(Ipv4DscpNamed(Ipv4Dscp::AF41), Ipv4Dscp::AF41.named());
+    }
+}
@@ -1,7 +1,23 @@
-use crate::err::ValueTooBigError;
+use crate::{err::ValueTooBigError, Ipv4EcnNamed};
 
 /// 2 bit unsigned integer containing the "Explicit Congestion
-/// Notification" (present in the [`crate::Ipv4Header`]).
+/// Notification" (present in the [`crate::Ipv4Header`] & in the lower
+/// 2 bits of the traffic class of the [`crate::Ipv6Header`]).
+///
+/// The code points defined in [RFC3168](https://datatracker.ietf.org/doc/html/rfc3168)
+/// are available as constants and their names can be determined via
+/// [`Ipv4Ecn::name_str`]:
+///
+/// ```
+/// use etherparse::Ipv4Ecn;
+///
+/// assert_eq!(3, Ipv4Ecn::CE.value());
+/// assert_eq!("ECT(0)", Ipv4Ecn::ECT_0.name_str());
+///
+/// // Display prints the value, use `named()` to display the name instead
+/// assert_eq!("3", Ipv4Ecn::CE.to_string());
+/// assert_eq!("CE", Ipv4Ecn::CE.named().to_string());
+/// ```
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Ipv4Ecn(u8);
 
@@ -18,6 +34,18 @@ impl Ipv4Ecn {
     /// Ipv4Ecn with value 0.
     pub const TRHEE: Ipv4Ecn = Ipv4Ecn(3);
 
+    /// Not ECN-Capable Transport (Not-ECT) \[[RFC3168](https://datatracker.ietf.org/doc/html/rfc3168)\]
+    pub const NOT_ECT: Ipv4Ecn = Ipv4Ecn(0b00);
+
+    /// ECN-Capable Transport, ECT(1) \[[RFC3168](https://datatracker.ietf.org/doc/html/rfc3168)\]
+    pub const ECT_1: Ipv4Ecn = Ipv4Ecn(0b01);
+
+    /// ECN-Capable Transport, ECT(0) \[[RFC3168](https://datatracker.ietf.org/doc/html/rfc3168)\]
+    pub const ECT_0: Ipv4Ecn = Ipv4Ecn(0b10);
+
+    /// Congestion Experienced (CE) \[[RFC3168](https://datatracker.ietf.org/doc/html/rfc3168)\]
+    pub const CE: Ipv4Ecn = Ipv4Ecn(0b11);
+
     /// Maximum value of an IPv4 header ECN.
     pub const MAX_U8: u8 = 0b0000_0011;
 
@@ -69,9 +97,19 @@ impl Ipv4Ecn {
     /// otherwise the behavior of functions or data structures relying
     /// on this pre-requirement is undefined.
     #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub const unsafe fn new_unchecked(value: u8) -> Ipv4Ecn {
-        debug_assert!(value <= Ipv4Ecn::MAX_U8);
-        Ipv4Ecn(value)
+        unchecked! { Ipv4Ecn::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`Ipv4Ecn::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u8) -> Ipv4Ecn {
+            debug_assert!(value <= Ipv4Ecn::MAX_U8);
+            Ipv4Ecn(value)
+        }
     }
 
     /// Returns the underlying unsigned 2 bit value as an `u8` value.
@@ -79,6 +117,23 @@ impl Ipv4Ecn {
     pub const fn value(self) -> u8 {
         self.0
     }
+
+    /// Returns the RFC3168 name of the code point ("Not-ECT", "ECT(1)",
+    /// "ECT(0)" or "CE").
+    pub const fn name_str(self) -> &'static str {
+        match self.0 {
+            0b00 => "Not-ECT",
+            0b01 => "ECT(1)",
+            0b10 => "ECT(0)",
+            _ => "CE",
+        }
+    }
+
+    /// Returns a wrapper that displays the RFC3168 name of the code point.
+    #[inline]
+    pub const fn named(self) -> Ipv4EcnNamed {
+        Ipv4EcnNamed(self)
+    }
 }
 
 impl core::fmt::Display for Ipv4Ecn {
@@ -114,6 +169,7 @@ impl TryFrom<u8> for Ipv4Ecn {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use core::hash::{Hash, Hasher};
@@ -243,6 +299,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn name_str() {
+        assert_eq!(0, Ipv4Ecn::NOT_ECT.value());
+        assert_eq!("Not-ECT", Ipv4Ecn::NOT_ECT.name_str());
+        assert_eq!(1, Ipv4Ecn::ECT_1.value());
+        assert_eq!("ECT(1)", Ipv4Ecn::ECT_1.name_str());
+        assert_eq!(2, Ipv4Ecn::ECT_0.value());
+        assert_eq!("ECT(0)", Ipv4Ecn::ECT_0.name_str());
+        assert_eq!(3, Ipv4Ecn::CE.value());
+        assert_eq!("CE", Ipv4Ecn::CE.name_str());
+    }
+
     proptest! {
         #[test]
         fn fmt(valid_value in 0..=0b0000_0011u8) {
@@ -0,0 +1,38 @@
+use crate::Ipv4Ecn;
+
+/// Wrapper displaying the RFC3168 name of an [`Ipv4Ecn`] (e.g. "CE")
+/// (created via [`Ipv4Ecn::named`]).
+///
+/// ```
+/// use etherparse::Ipv4Ecn;
+///
+/// assert_eq!("ECT(0)", format!("{}", Ipv4Ecn::ECT_0.named()));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv4EcnNamed(pub Ipv4Ecn);
+
+impl core::fmt::Display for Ipv4EcnNamed {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.0.name_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn fmt() {
+        for value in 0..=Ipv4Ecn::MAX_U8 {
+            let ecn = Ipv4Ecn::try_new(value).unwrap();
+            assert_eq!(ecn.name_str(), format!("{}", Ipv4EcnNamed(ecn)));
+        }
+    }
+
+    #[test]
+    fn named() {
+        assert_eq!(Ipv4EcnNamed(Ipv4Ecn::CE), Ipv4Ecn::CE.named());
+    }
+}
@@ -167,14 +167,32 @@ impl Ipv4Extensions {
         writer: &mut T,
         start_ip_number: IpNumber,
     ) -> Result<(), err::ipv4_exts::HeaderWriteError> {
-        use err::ipv4_exts::{ExtsWalkError::*, HeaderWriteError::*};
+        use err::ipv4_exts::HeaderWriteError::*;
+        self.write_with(
+            start_ip_number,
+            |bytes| writer.write_all(bytes).map_err(Io),
+            Content,
+        )
+    }
+
+    /// Passes the serialized headers to `write` (shared implementation of
+    /// [`Ipv4Extensions::write`] & the [`crate::PacketBuilder`]).
+    ///
+    /// Consistency errors are converted via `content_err`.
+    pub(crate) fn write_with<E>(
+        &self,
+        start_ip_number: IpNumber,
+        mut write: impl FnMut(&[u8]) -> Result<(), E>,
+        content_err: impl Fn(err::ipv4_exts::ExtsWalkError) -> E,
+    ) -> Result<(), E> {
+        use err::ipv4_exts::ExtsWalkError::*;
         use ip_number::*;
         match self.auth {
             Some(ref header) => {
                 if AUTH == start_ip_number {
-                    header.write(writer).map_err(Io)
+                    write(&header.to_bytes())
                 } else {
-                    Err(Content(ExtNotReferenced {
+                    Err(content_err(ExtNotReferenced {
                         missing_ext: IpNumber::AUTHENTICATION_HEADER,
                     }))
                 }
@@ -236,6 +254,24 @@ impl Ipv4Extensions {
     pub fn is_empty(&self) -> bool {
         self.auth.is_none()
     }
+
+    /// Returns the extensions with the given authentication header set.
+    ///
+    /// Combined with [`Ipv4Extensions::set_next_headers`] this allows
+    /// constructing the extensions without struct literals:
+    ///
+    /// ```
+    /// use etherparse::{ip_number::{AUTH, UDP}, IpAuthHeader, Ipv4Extensions};
+    ///
+    /// let mut exts = Ipv4Extensions::default()
+    ///     .with_auth(IpAuthHeader::new(UDP, 1, 2, &[]).unwrap());
+    /// assert_eq!(AUTH, exts.set_next_headers(UDP));
+    /// ```
+    #[inline]
+    pub fn with_auth(mut self, auth: IpAuthHeader) -> Ipv4Extensions {
+        self.auth = Some(auth);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -535,6 +571,16 @@ mod test {
         );
     }
 
+    proptest! {
+        #[test]
+        fn with_auth(auth in ip_auth_any()) {
+            assert_eq!(
+                Ipv4Extensions { auth: Some(auth.clone()) },
+                Ipv4Extensions::default().with_auth(auth)
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn debug(auth in ip_auth_any()) {
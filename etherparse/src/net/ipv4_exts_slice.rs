@@ -125,13 +125,10 @@ impl<'a> Ipv4ExtensionsSlice<'a> {
         if AUTH == start_ip_number {
             match IpAuthHeaderSlice::from_slice(start_slice) {
                 Ok(header) => {
-                    let rest = unsafe {
+                    let rest = unchecked! {
                         // SAFE as header.slice() has the same start and is a
                         // subslice of start_slice.
-                        core::slice::from_raw_parts(
-                            start_slice.as_ptr().add(header.slice().len()),
-                            start_slice.len() - header.slice().len(),
-                        )
+                        get_unchecked_slice(start_slice, header.slice().len(), start_slice.len() - header.slice().len())
                     };
                     let next_header = header.next_header();
                     (
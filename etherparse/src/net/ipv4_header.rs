@@ -230,6 +230,32 @@ impl Ipv4Header {
         Ipv4Header::MIN_LEN + self.options.len()
     }
 
+    /// Sets the "Differentiated Services Code Point" (same as setting
+    /// the `dscp` field, available for symmetry with
+    /// [`Ipv6Header::set_dscp`]).
+    ///
+    /// ```
+    /// use etherparse::{Ipv4Dscp, Ipv4Ecn, Ipv4Header};
+    ///
+    /// let mut header = Ipv4Header::default();
+    /// header.set_dscp(Ipv4Dscp::EF);
+    /// header.set_ecn(Ipv4Ecn::ECT_0);
+    /// assert_eq!(Ipv4Dscp::EF, header.dscp);
+    /// assert_eq!(Ipv4Ecn::ECT_0, header.ecn);
+    /// ```
+    #[inline]
+    pub fn set_dscp(&mut self, dscp: Ipv4Dscp) {
+        self.dscp = dscp;
+    }
+
+    /// Sets the "Explicit Congestion Notification" (same as setting
+    /// the `ecn` field, available for symmetry with
+    /// [`Ipv6Header::set_ecn`]).
+    #[inline]
+    pub fn set_ecn(&mut self, ecn: Ipv4Ecn) {
+        self.ecn = ecn;
+    }
+
     /// Determine the payload length based on the ihl & total_length
     /// field of the header.
     ///
@@ -423,6 +449,36 @@ impl Ipv4Header {
         Ipv4Header::read_without_version(reader, first_byte[0])
     }
 
+    /// Reads an IPv4 header from the current position of the async reader
+    /// (requires crate feature `tokio`).
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<Ipv4Header, err::ipv4::HeaderReadError> {
+        use err::ipv4::HeaderReadError::*;
+
+        let mut buffer = [0u8; Ipv4Header::MAX_LEN];
+        async_read_exact(reader, &mut buffer[..1])
+            .await
+            .map_err(Io)?;
+
+        let version_number = buffer[0] >> 4;
+        if 4 != version_number {
+            use err::ipv4::HeaderError::UnexpectedVersion;
+            return Err(Content(UnexpectedVersion { version_number }));
+        }
+
+        // read the header & options (if the ihl is valid)
+        let header_len = core::cmp::max(Ipv4Header::MIN_LEN, usize::from(buffer[0] & 0xf) * 4);
+        async_read_exact(reader, &mut buffer[1..header_len])
+            .await
+            .map_err(Io)?;
+
+        // decode & validate via the sync implementation
+        Ipv4Header::read(&mut std::io::Cursor::new(&buffer[..]))
+    }
+
     /// Reads an IPv4 header assuming the version & ihl field have already
     /// been read (requires crate feature `std`).
     #[cfg(feature = "std")]
@@ -460,24 +516,24 @@ impl Ipv4Header {
             u16::from_be_bytes([header_raw[6] & 0b0001_1111, header_raw[7]]),
         );
         Ok(Ipv4Header {
-            dscp: unsafe {
+            dscp: unchecked! {
                 // Safe as only 6 bits were used to decode the
                 // dscp value
-                Ipv4Dscp::new_unchecked(dscp)
+                Ipv4Dscp::new_unchecked_internal(dscp)
             },
-            ecn: unsafe {
+            ecn: unchecked! {
                 // Safe as only 2 bits were used to decode the
                 // ecn value
-                Ipv4Ecn::new_unchecked(ecn)
+                Ipv4Ecn::new_unchecked_internal(ecn)
             },
             total_len,
             identification,
             dont_fragment,
             more_fragments,
-            fragment_offset: unsafe {
+            fragment_offset: unchecked! {
                 // Safe as only 13 bits were used to decode the
                 // fragment offset
-                IpFragOffset::new_unchecked(fragments_offset)
+                IpFragOffset::new_unchecked_internal(fragments_offset)
             },
             time_to_live: header_raw[8],
             protocol: IpNumber(header_raw[9]),
@@ -579,8 +635,8 @@ impl Ipv4Header {
 
         // SAFETY: Safe as header_len() can never exceed the maximum length of an
         // IPv4 header which is the upper limit of the array vec.
-        unsafe {
-            header_raw.set_len(self.header_len());
+        unchecked! {
+            truncate_unchecked(&mut header_raw, self.header_len());
         }
 
         header_raw
@@ -673,6 +729,13 @@ impl Ipv4Header {
             .to_be()
     }
 
+    /// Returns the classification of the destination IPv4 address into
+    /// unicast, multicast or broadcast (see [`CastType::from_ipv4_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_ipv4_addr(&self.destination)
+    }
+
     /// Returns true if the payload is fragmented.
     ///
     /// Either data is missing (more_fragments set) or there is
@@ -703,6 +766,31 @@ impl Default for Ipv4Header {
     }
 }
 
+/// Writes the header in the format `source -> destination proto=<ip number>
+/// ttl=<time to live> len=<total length>`, e.g.
+/// `10.0.0.1 -> 10.0.0.2 proto=6 ttl=64 len=1500`.
+impl core::fmt::Display for Ipv4Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = self.source;
+        let d = self.destination;
+        write!(
+            f,
+            "{}.{}.{}.{} -> {}.{}.{}.{} proto={} ttl={} len={}",
+            s[0],
+            s[1],
+            s[2],
+            s[3],
+            d[0],
+            d[1],
+            d[2],
+            d[3],
+            self.protocol.0,
+            self.time_to_live,
+            self.total_len
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -974,6 +1062,25 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn dscp_ecn(
+            header in ipv4_any(),
+            dscp in 0..=Ipv4Dscp::MAX_U8,
+            ecn in 0..=Ipv4Ecn::MAX_U8
+        ) {
+            let dscp = Ipv4Dscp::try_new(dscp).unwrap();
+            let ecn = Ipv4Ecn::try_new(ecn).unwrap();
+            let mut actual = header.clone();
+            actual.set_dscp(dscp);
+            assert_eq!(actual.dscp, dscp);
+            assert_eq!(actual.ecn, header.ecn);
+            actual.set_ecn(ecn);
+            assert_eq!(actual.dscp, dscp);
+            assert_eq!(actual.ecn, ecn);
+        }
+    }
+
     proptest! {
         #[test]
         fn payload_len(
@@ -1184,6 +1291,52 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            header in ipv4_any(),
+            bad_version in 0..=0b1111u8,
+            bad_ihl in 0u8..5u8,
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            // compare the async read with the sync read for all
+            // prefixes of the given data
+            let check = |data: &[u8]| {
+                for len in 0..=data.len() {
+                    let mut cursor = Cursor::new(&data[..len]);
+                    let expected = Ipv4Header::read(&mut cursor);
+                    let mut reader = &data[..len];
+                    let actual = rt.block_on(Ipv4Header::async_read(&mut reader));
+                    match (expected, actual) {
+                        (Ok(expected), Ok(actual)) => {
+                            assert_eq!(expected, actual);
+                            assert_eq!(cursor.position() as usize, len - reader.len());
+                        }
+                        (Err(err::ipv4::HeaderReadError::Io(_)), Err(err::ipv4::HeaderReadError::Io(_))) => {}
+                        (Err(err::ipv4::HeaderReadError::Content(expected)), Err(err::ipv4::HeaderReadError::Content(actual))) => {
+                            assert_eq!(expected, actual)
+                        }
+                        (expected, actual) => panic!("{:?} != {:?}", expected, actual),
+                    }
+                }
+            };
+
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.push(1);
+            check(&buffer);
+
+            // bad ihl
+            buffer[0] = (buffer[0] & 0xf0) | bad_ihl;
+            check(&buffer);
+
+            // bad version
+            buffer[0] = (bad_version << 4) | (buffer[0] & 0xf);
+            check(&buffer);
+        }
+    }
+
     proptest! {
         #[test]
         fn read_and_read_without_version(header in ipv4_any()) {
@@ -1434,4 +1587,19 @@ mod test {
             assert!(header.is_fragmenting_payload());
         }
     }
+
+    #[test]
+    fn display() {
+        let mut header =
+            Ipv4Header::new(1480, 64, ip_number::TCP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+        assert_eq!(
+            "10.0.0.1 -> 10.0.0.2 proto=6 ttl=64 len=1500",
+            format!("{}", header)
+        );
+        header.protocol = IpNumber(253);
+        assert_eq!(
+            "10.0.0.1 -> 10.0.0.2 proto=253 ttl=64 len=1500",
+            format!("{}", header)
+        );
+    }
 }
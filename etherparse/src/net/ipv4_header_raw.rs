@@ -0,0 +1,467 @@
+use crate::*;
+
+/// `#[repr(C)]` memory layout of the fixed 20 byte part of an IPv4
+/// header with all fields stored in network byte order.
+///
+/// The struct has a size of 20 bytes & an alignment of 1 and can be
+/// mapped directly onto a packet buffer (e.g. a DMA region). IPv4 options
+/// are not part of the layout & follow directly after it in the buffer
+/// if [`Ipv4HeaderRaw::header_len`] is bigger than 20.
+///
+/// The checksum & length logic of [`Ipv4Header`] can be used by converting
+/// between the two types:
+///
+/// ```
+/// use etherparse::{Ipv4Header, Ipv4HeaderRaw, IpNumber};
+///
+/// let header = Ipv4Header::new(8, 64, IpNumber::UDP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+/// let mut buffer = header.to_bytes();
+///
+/// let raw = Ipv4HeaderRaw::mut_from_prefix(&mut buffer).unwrap();
+/// raw.time_to_live -= 1;
+///
+/// // recalculate the checksum via the high level header
+/// let decoded = Ipv4Header::try_from(&*raw).unwrap();
+/// raw.header_checksum.set(decoded.calc_header_checksum());
+///
+/// assert!(Ipv4Header::from_slice(&buffer).is_ok());
+/// ```
+///
+/// The `set_*` methods change only the bits of the given field and update
+/// the header checksum incrementally. All other bits (e.g. the reserved
+/// flag, which is not stored in [`Ipv4Header`]) stay untouched, which makes
+/// it possible to rewrite captured packets bit-exact:
+///
+/// ```
+/// use etherparse::{Ipv4Header, Ipv4HeaderRaw, IpNumber};
+///
+/// use etherparse::checksum::Sum16BitWords;
+///
+/// let mut header = Ipv4Header::new(8, 64, IpNumber::UDP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+/// header.header_checksum = header.calc_header_checksum();
+/// let mut buffer = header.to_bytes();
+///
+/// let raw = Ipv4HeaderRaw::mut_from_prefix(&mut buffer).unwrap();
+/// // set the reserved flag (lost when decoding to an Ipv4Header)
+/// raw.set_reserved_flag(true);
+/// raw.set_time_to_live(63);
+/// raw.set_destination([10, 0, 0, 3]);
+/// assert!(raw.reserved_flag());
+///
+/// // the checksum of the header is still valid
+/// assert_eq!(0, Sum16BitWords::new().add_slice(&buffer).ones_complement());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub struct Ipv4HeaderRaw {
+    /// Version (upper 4 bits) & internet header length (lower 4 bits).
+    pub version_ihl: u8,
+    /// Differentiated Services Code Point (upper 6 bits) & Explicit
+    /// Congestion Notification (lower 2 bits).
+    pub dscp_ecn: u8,
+    /// Total length of the IPv4 header (including options) and the payload after it.
+    pub total_len: U16Be,
+    /// Number used to identify packets that contain an originally fragmented packet.
+    pub identification: U16Be,
+    /// Flags (upper 3 bits) & fragment offset (lower 13 bits).
+    pub flags_fragment_offset: U16Be,
+    /// Number of hops the packet is allowed to take before it should be discarded.
+    pub time_to_live: u8,
+    /// IP protocol number specifying the next header or transport layer protocol.
+    pub protocol: u8,
+    /// Checksum of the header (including options).
+    pub header_checksum: U16Be,
+    /// IPv4 source address
+    pub source: [u8; 4],
+    /// IPv4 destination address
+    pub destination: [u8; 4],
+}
+
+impl Ipv4HeaderRaw {
+    /// Size of the raw header in bytes.
+    pub const LEN: usize = Ipv4Header::MIN_LEN;
+
+    /// Creates a raw header from its serialized bytes.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 20]) -> Ipv4HeaderRaw {
+        Ipv4HeaderRaw {
+            version_ihl: bytes[0],
+            dscp_ecn: bytes[1],
+            total_len: U16Be([bytes[2], bytes[3]]),
+            identification: U16Be([bytes[4], bytes[5]]),
+            flags_fragment_offset: U16Be([bytes[6], bytes[7]]),
+            time_to_live: bytes[8],
+            protocol: bytes[9],
+            header_checksum: U16Be([bytes[10], bytes[11]]),
+            source: [bytes[12], bytes[13], bytes[14], bytes[15]],
+            destination: [bytes[16], bytes[17], bytes[18], bytes[19]],
+        }
+    }
+
+    /// Returns the serialized bytes of the raw header.
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 20] {
+        [
+            self.version_ihl,
+            self.dscp_ecn,
+            self.total_len.0[0],
+            self.total_len.0[1],
+            self.identification.0[0],
+            self.identification.0[1],
+            self.flags_fragment_offset.0[0],
+            self.flags_fragment_offset.0[1],
+            self.time_to_live,
+            self.protocol,
+            self.header_checksum.0[0],
+            self.header_checksum.0[1],
+            self.source[0],
+            self.source[1],
+            self.source[2],
+            self.source[3],
+            self.destination[0],
+            self.destination[1],
+            self.destination[2],
+            self.destination[3],
+        ]
+    }
+
+    /// Length of the header (including options) in bytes based on the
+    /// internet header length field.
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        ((self.version_ihl & 0xf) as usize) * 4
+    }
+
+    /// Returns a reference to the raw header at the start of the
+    /// slice or `None` if the slice is shorter than 20 bytes.
+    ///
+    /// Note that no fields are validated (e.g. the version).
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+    #[inline]
+    pub fn ref_from_prefix(slice: &[u8]) -> Option<&Ipv4HeaderRaw> {
+        if slice.len() < Ipv4HeaderRaw::LEN {
+            None
+        } else {
+            // SAFETY:
+            // Safe as the slice length is checked to be at least
+            // Ipv4HeaderRaw::LEN (20) above and Ipv4HeaderRaw is repr(C)
+            // with an alignment of 1 & only consists of bytes & byte arrays
+            // (all bit patterns are valid).
+            Some(unsafe { &*(slice.as_ptr() as *const Ipv4HeaderRaw) })
+        }
+    }
+
+    /// Returns a mutable reference to the raw header at the start of
+    /// the slice or `None` if the slice is shorter than 20 bytes.
+    ///
+    /// Note that no fields are validated (e.g. the version).
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+    #[inline]
+    pub fn mut_from_prefix(slice: &mut [u8]) -> Option<&mut Ipv4HeaderRaw> {
+        if slice.len() < Ipv4HeaderRaw::LEN {
+            None
+        } else {
+            // SAFETY:
+            // Safe as the slice length is checked to be at least
+            // Ipv4HeaderRaw::LEN (20) above and Ipv4HeaderRaw is repr(C)
+            // with an alignment of 1 & only consists of bytes & byte arrays
+            // (all bit patterns are valid).
+            Some(unsafe { &mut *(slice.as_mut_ptr() as *mut Ipv4HeaderRaw) })
+        }
+    }
+
+    /// Returns the reserved flag bit (also called "evil bit", RFC 3514).
+    ///
+    /// [`Ipv4Header`] does not store this bit, use the raw header to
+    /// preserve or inspect it.
+    #[inline]
+    pub const fn reserved_flag(&self) -> bool {
+        0 != self.flags_fragment_offset.0[0] & 0b1000_0000
+    }
+
+    /// Returns the "don't fragment" flag.
+    #[inline]
+    pub const fn dont_fragment(&self) -> bool {
+        0 != self.flags_fragment_offset.0[0] & 0b0100_0000
+    }
+
+    /// Returns the "more fragments" flag.
+    #[inline]
+    pub const fn more_fragments(&self) -> bool {
+        0 != self.flags_fragment_offset.0[0] & 0b0010_0000
+    }
+
+    /// Returns the fragment offset (in 8 byte units).
+    #[inline]
+    pub const fn fragment_offset(&self) -> IpFragOffset {
+        // SAFETY: Safe as the value is masked to 13 bits.
+        unchecked! {
+            IpFragOffset::new_unchecked_internal(
+                u16::from_be_bytes(self.flags_fragment_offset.0) & IpFragOffset::MAX_U16,
+            )
+        }
+    }
+
+    /// Sets the DSCP field & updates the header checksum incrementally
+    /// (all other bits, including the ECN field, are preserved).
+    pub fn set_dscp(&mut self, dscp: Ipv4Dscp) {
+        let old = [self.version_ihl, self.dscp_ecn];
+        self.dscp_ecn = (dscp.value() << 2) | (self.dscp_ecn & 0b11);
+        self.update_checksum(old, [self.version_ihl, self.dscp_ecn]);
+    }
+
+    /// Sets the ECN field & updates the header checksum incrementally
+    /// (all other bits, including the DSCP field, are preserved).
+    pub fn set_ecn(&mut self, ecn: Ipv4Ecn) {
+        let old = [self.version_ihl, self.dscp_ecn];
+        self.dscp_ecn = (self.dscp_ecn & 0b1111_1100) | ecn.value();
+        self.update_checksum(old, [self.version_ihl, self.dscp_ecn]);
+    }
+
+    /// Sets the identification field & updates the header checksum
+    /// incrementally.
+    pub fn set_identification(&mut self, identification: u16) {
+        let old = self.identification.0;
+        self.identification.set(identification);
+        self.update_checksum(old, self.identification.0);
+    }
+
+    /// Sets the reserved flag bit & updates the header checksum
+    /// incrementally (all other bits are preserved).
+    pub fn set_reserved_flag(&mut self, value: bool) {
+        self.set_flags_fragment_offset_bits(0x8000, if value { 0x8000 } else { 0 });
+    }
+
+    /// Sets the "don't fragment" flag & updates the header checksum
+    /// incrementally (all other bits, including the reserved flag, are
+    /// preserved).
+    pub fn set_dont_fragment(&mut self, value: bool) {
+        self.set_flags_fragment_offset_bits(0x4000, if value { 0x4000 } else { 0 });
+    }
+
+    /// Sets the "more fragments" flag & updates the header checksum
+    /// incrementally (all other bits, including the reserved flag, are
+    /// preserved).
+    pub fn set_more_fragments(&mut self, value: bool) {
+        self.set_flags_fragment_offset_bits(0x2000, if value { 0x2000 } else { 0 });
+    }
+
+    /// Sets the fragment offset & updates the header checksum
+    /// incrementally (the flags are preserved).
+    pub fn set_fragment_offset(&mut self, offset: IpFragOffset) {
+        self.set_flags_fragment_offset_bits(IpFragOffset::MAX_U16, offset.value());
+    }
+
+    /// Sets the time to live & updates the header checksum incrementally.
+    pub fn set_time_to_live(&mut self, time_to_live: u8) {
+        let old = [self.time_to_live, self.protocol];
+        self.time_to_live = time_to_live;
+        self.update_checksum(old, [self.time_to_live, self.protocol]);
+    }
+
+    /// Sets the source address & updates the header checksum incrementally.
+    pub fn set_source(&mut self, source: [u8; 4]) {
+        let old = self.source;
+        self.source = source;
+        self.update_checksum([old[0], old[1]], [source[0], source[1]]);
+        self.update_checksum([old[2], old[3]], [source[2], source[3]]);
+    }
+
+    /// Sets the destination address & updates the header checksum
+    /// incrementally.
+    pub fn set_destination(&mut self, destination: [u8; 4]) {
+        let old = self.destination;
+        self.destination = destination;
+        self.update_checksum([old[0], old[1]], [destination[0], destination[1]]);
+        self.update_checksum([old[2], old[3]], [destination[2], destination[3]]);
+    }
+
+    /// Replaces the bits selected by `mask` in the flags & fragment
+    /// offset field & updates the checksum.
+    fn set_flags_fragment_offset_bits(&mut self, mask: u16, value: u16) {
+        let old = self.flags_fragment_offset.0;
+        let new = (self.flags_fragment_offset.get() & !mask) | (value & mask);
+        self.flags_fragment_offset.set(new);
+        self.update_checksum(old, self.flags_fragment_offset.0);
+    }
+
+    /// Incrementally updates the header checksum after a 16 bit word
+    /// changed from `old` to `new` (see [`checksum::incremental_update`]).
+    fn update_checksum(&mut self, old: [u8; 2], new: [u8; 2]) {
+        self.header_checksum.set(checksum::incremental_update(
+            self.header_checksum.get(),
+            u16::from_be_bytes(old),
+            u16::from_be_bytes(new),
+        ));
+    }
+}
+
+/// Converts the raw header into an [`Ipv4Header`].
+///
+/// As the raw header does not contain the options, the conversion
+/// fails with a length error if the internet header length field
+/// indicates options are present. In this case use
+/// [`Ipv4Header::from_slice`] on the packet buffer instead.
+impl TryFrom<&Ipv4HeaderRaw> for Ipv4Header {
+    type Error = err::ipv4::HeaderSliceError;
+
+    #[inline]
+    fn try_from(value: &Ipv4HeaderRaw) -> Result<Self, Self::Error> {
+        Ipv4Header::from_slice(&value.to_bytes()).map(|(header, _)| header)
+    }
+}
+
+/// Converts the fixed part of an [`Ipv4Header`] into a raw header.
+///
+/// The options of the header are not part of the raw header (the
+/// internet header length field still includes their length).
+impl From<&Ipv4Header> for Ipv4HeaderRaw {
+    #[inline]
+    fn from(value: &Ipv4Header) -> Self {
+        let bytes = value.to_bytes();
+        let mut result = [0u8; 20];
+        result.copy_from_slice(&bytes[..Ipv4HeaderRaw::LEN]);
+        Ipv4HeaderRaw::from_bytes(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err::ipv4::HeaderSliceError, test_gens::*};
+    use proptest::prelude::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(Ipv4HeaderRaw::LEN, core::mem::size_of::<Ipv4HeaderRaw>());
+        assert_eq!(1, core::mem::align_of::<Ipv4HeaderRaw>());
+    }
+
+    proptest! {
+        #[test]
+        fn conversions(header in ipv4_any()) {
+            let bytes = header.to_bytes();
+            let raw = Ipv4HeaderRaw::from(&header);
+            assert_eq!(&bytes[..20], &raw.to_bytes());
+            assert_eq!(raw, Ipv4HeaderRaw::from_bytes(raw.to_bytes()));
+            assert_eq!(header.header_len(), raw.header_len());
+            if header.options.is_empty() {
+                assert_eq!(Ok(header), Ipv4Header::try_from(&raw));
+            } else {
+                assert!(matches!(
+                    Ipv4Header::try_from(&raw),
+                    Err(HeaderSliceError::Len(_))
+                ));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn setters(
+            header in ipv4_any(),
+            reserved in any::<bool>(),
+            dscp in 0u8..=Ipv4Dscp::MAX_U8,
+            ecn in 0u8..=Ipv4Ecn::MAX_U8,
+            identification in any::<u16>(),
+            dont_fragment in any::<bool>(),
+            more_fragments in any::<bool>(),
+            frag_offset in 0u16..=IpFragOffset::MAX_U16,
+            ttl in any::<u8>(),
+            source in any::<[u8;4]>(),
+            destination in any::<[u8;4]>(),
+        ) {
+            let mut header = header;
+            header.header_checksum = header.calc_header_checksum();
+            let mut raw = Ipv4HeaderRaw::from(&header);
+            if reserved {
+                // the reserved flag is not stored in Ipv4Header, set it
+                // via the raw header & check it is preserved
+                raw.set_reserved_flag(true);
+            }
+
+            // checks all non modified bits are preserved & the checksum
+            // matches a fully recalculated checksum
+            let check = |raw: &Ipv4HeaderRaw, expected: &Ipv4Header| {
+                let mut buffer = expected.to_bytes();
+                if reserved {
+                    buffer[6] |= 0b1000_0000;
+                }
+                let checksum = checksum::Sum16BitWords::new()
+                    .add_slice(&buffer[..10])
+                    .add_slice(&buffer[12..])
+                    .ones_complement()
+                    .to_be();
+                buffer[10..12].copy_from_slice(&checksum.to_be_bytes());
+                assert_eq!(&buffer[..20], &raw.to_bytes());
+                assert_eq!(reserved, raw.reserved_flag());
+            };
+
+            let mut expected = header.clone();
+            expected.dscp = Ipv4Dscp::try_new(dscp).unwrap();
+            raw.set_dscp(expected.dscp);
+            check(&raw, &expected);
+
+            expected.ecn = Ipv4Ecn::try_new(ecn).unwrap();
+            raw.set_ecn(expected.ecn);
+            check(&raw, &expected);
+
+            expected.identification = identification;
+            raw.set_identification(identification);
+            check(&raw, &expected);
+
+            expected.dont_fragment = dont_fragment;
+            raw.set_dont_fragment(dont_fragment);
+            assert_eq!(dont_fragment, raw.dont_fragment());
+            check(&raw, &expected);
+
+            expected.more_fragments = more_fragments;
+            raw.set_more_fragments(more_fragments);
+            assert_eq!(more_fragments, raw.more_fragments());
+            check(&raw, &expected);
+
+            expected.fragment_offset = IpFragOffset::try_new(frag_offset).unwrap();
+            raw.set_fragment_offset(expected.fragment_offset);
+            assert_eq!(expected.fragment_offset, raw.fragment_offset());
+            check(&raw, &expected);
+
+            expected.time_to_live = ttl;
+            raw.set_time_to_live(ttl);
+            check(&raw, &expected);
+
+            expected.source = source;
+            raw.set_source(source);
+            check(&raw, &expected);
+
+            expected.destination = destination;
+            raw.set_destination(destination);
+            check(&raw, &expected);
+
+            raw.set_reserved_flag(false);
+            assert!(false == raw.reserved_flag());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_prefix(header in ipv4_any(), len in 0usize..24) {
+            let mut buffer = [0u8; 24];
+            buffer[..20].copy_from_slice(&header.to_bytes()[..20]);
+
+            if len < Ipv4HeaderRaw::LEN {
+                assert!(Ipv4HeaderRaw::ref_from_prefix(&buffer[..len]).is_none());
+                assert!(Ipv4HeaderRaw::mut_from_prefix(&mut buffer[..len]).is_none());
+            } else {
+                assert_eq!(
+                    Ipv4HeaderRaw::from(&header),
+                    *Ipv4HeaderRaw::ref_from_prefix(&buffer[..len]).unwrap()
+                );
+                let raw = Ipv4HeaderRaw::mut_from_prefix(&mut buffer[..len]).unwrap();
+                raw.time_to_live = 7;
+                raw.header_checksum.set(0x1234);
+                assert_eq!(7, buffer[8]);
+                assert_eq!([0x12, 0x34], buffer[10..12]);
+            }
+        }
+    }
+}
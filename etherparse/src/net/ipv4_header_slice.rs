@@ -1,10 +1,14 @@
-use core::slice::from_raw_parts;
 #[cfg(feature = "std")]
 use std::net::Ipv4Addr;
 
 use crate::*;
 
 /// A slice containing an ipv4 header of a network package.
+///
+/// The length of the header (including options) is determined by the
+/// length of the slice. Normally this is equal to `ihl() * 4`. Only if the
+/// options were cut off (see [`crate::LaxIpv4Slice::is_options_truncated`])
+/// the slice contains just the fixed 20 bytes & is shorter than that.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Ipv4HeaderSlice<'a> {
     slice: &'a [u8],
@@ -42,8 +46,8 @@ impl<'a> Ipv4HeaderSlice<'a> {
         }
 
         // read version & ihl
-        let (version_number, ihl) = unsafe {
-            let value = slice.get_unchecked(0);
+        let (version_number, ihl) = unchecked! {
+            let value = get_unchecked_u8(slice, 0);
             (value >> 4, value & 0xf)
         };
 
@@ -74,24 +78,31 @@ impl<'a> Ipv4HeaderSlice<'a> {
             // SAFETY:
             // Safe as the slice length is checked to be at least
             // header_length or greater above.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), header_length) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, header_length) },
         })
     }
 
-    /// Converts the given slice into a ipv4 header slice WITHOUT any
-    /// checks to ensure that the data present is an ipv4 header or that the
-    /// slice length is matching the header length.
-    ///
-    /// If you are not sure what this means, use [`Ipv4HeaderSlice::from_slice`]
-    /// instead.
-    ///
-    /// # Safety
-    ///
-    /// It must ensured that the slice exactly contains the IPv4 header
-    /// and the ihl (intra header length) & total length must be consistent.
-    #[inline]
-    pub(crate) unsafe fn from_slice_unchecked(slice: &[u8]) -> Ipv4HeaderSlice {
-        Ipv4HeaderSlice { slice }
+    unchecked_fn! {
+        /// Converts the given slice into a ipv4 header slice WITHOUT any
+        /// checks to ensure that the data present is an ipv4 header or that the
+        /// slice length is matching the header length.
+        ///
+        /// If you are not sure what this means, use [`Ipv4HeaderSlice::from_slice`]
+        /// instead.
+        ///
+        /// # Safety
+        ///
+        /// It must ensured that the slice exactly contains the IPv4 header
+        /// and the ihl (intra header length) & total length must be consistent.
+        ///
+        /// The only allowed exception is a header with cut off options, in
+        /// which case the slice contains only the fixed part of the header
+        /// ([`Ipv4Header::MIN_LEN`] bytes) while the ihl is bigger than 5
+        /// (all accessors use the slice length as the header length).
+        #[inline]
+        pub(crate) fn from_slice_unchecked(slice: &[u8]) -> Ipv4HeaderSlice {
+            Ipv4HeaderSlice { slice }
+        }
     }
 
     /// Returns the slice containing the ipv4 header
@@ -106,16 +117,20 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { *self.slice.get_unchecked(0) >> 4 }
+        unchecked! { get_unchecked_u8(self.slice, 0) >> 4 }
     }
 
     /// Read the "ip header length" (length of the ipv4 header + options in multiples of 4 bytes).
+    ///
+    /// Note that if the options were cut off (see
+    /// [`crate::LaxIpv4Slice::is_options_truncated`]) the value is bigger than
+    /// the length of [`Ipv4HeaderSlice::slice`] divided by 4.
     #[inline]
     pub fn ihl(&self) -> u8 {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { *self.slice.get_unchecked(0) & 0xf }
+        unchecked! { get_unchecked_u8(self.slice, 0) & 0xf }
     }
 
     /// Read the "differentiated_services_code_point" from the slice.
@@ -126,7 +141,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // Ipv4Header::MIN_LEN (20) in the constructor.
         // new_unchecked: Safe as the bitshift by 2 guarantees that the passed
         // value is not bigger then 6 bits.
-        unsafe { Ipv4Dscp::new_unchecked(*self.slice.get_unchecked(1) >> 2) }
+        unchecked! { Ipv4Dscp::new_unchecked_internal(get_unchecked_u8(self.slice, 1) >> 2) }
     }
 
     /// Read the "explicit_congestion_notification" from the slice.
@@ -136,7 +151,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // get_unchecked: Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
         // new_unchecked: Safe as value has been bitmasked to two bits.
-        unsafe { Ipv4Ecn::new_unchecked(*self.slice.get_unchecked(1) & 0b0000_0011) }
+        unchecked! { Ipv4Ecn::new_unchecked_internal(get_unchecked_u8(self.slice, 1) & 0b0000_0011) }
     }
 
     /// Read the "total length" from the slice (total length of ip header + payload).
@@ -145,7 +160,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 2) }
     }
 
     /// Determine the payload length based on the ihl & total_length
@@ -215,7 +230,21 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 4) }
+    }
+
+    /// Read the 3 bit "flags" field (reserved bit, "dont fragment" &
+    /// "more fragments") from the slice.
+    ///
+    /// The reserved bit is the most significant bit of the returned
+    /// value (`0b100`), "dont fragment" is `0b010` & "more fragments"
+    /// is `0b001`.
+    #[inline]
+    pub fn flags_raw(&self) -> u8 {
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // Ipv4Header::MIN_LEN (20) in the constructor.
+        unchecked! { get_unchecked_u8(self.slice, 6) >> 5 }
     }
 
     /// Read the "dont fragment" flag from the slice.
@@ -224,7 +253,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { 0 != (*self.slice.get_unchecked(6) & 0x40) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 6) & 0x40) }
     }
 
     /// Read the "more fragments" flag from the slice.
@@ -233,21 +262,21 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { 0 != (*self.slice.get_unchecked(6) & 0x20) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 6) & 0x20) }
     }
 
     /// Read the "fragment_offset" field from the slice.
     #[inline]
     pub fn fragments_offset(&self) -> IpFragOffset {
-        unsafe {
+        unchecked! {
             // SAFETY:
             // Safe as the value is limited to be 13 bits long bellow.
-            IpFragOffset::new_unchecked(u16::from_be_bytes([
+            IpFragOffset::new_unchecked_internal(u16::from_be_bytes([
                 // SAFETY:
                 // Safe as the slice length is checked to be at least
                 // Ipv4Header::MIN_LEN (20) in the constructor.
-                *self.slice.get_unchecked(6) & 0x1f,
-                *self.slice.get_unchecked(7),
+                get_unchecked_u8(self.slice, 6) & 0x1f,
+                get_unchecked_u8(self.slice, 7),
             ]))
         }
     }
@@ -258,7 +287,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { *self.slice.get_unchecked(8) }
+        unchecked! { get_unchecked_u8(self.slice, 8) }
     }
 
     /// Read the "protocol" field from the slice.
@@ -267,7 +296,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        IpNumber(unsafe { *self.slice.get_unchecked(9) })
+        IpNumber(unchecked! { get_unchecked_u8(self.slice, 9) })
     }
 
     /// Read the "header checksum" field from the slice.
@@ -276,7 +305,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(10)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 10) }
     }
 
     /// Returns a slice containing the ipv4 source address.
@@ -285,7 +314,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(12)) }
+        unchecked! { get_unchecked_array(self.slice, 12) }
     }
 
     /// Return the ipv4 source address as an std::net::Ipv4Addr
@@ -302,7 +331,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(16)) }
+        unchecked! { get_unchecked_array(self.slice, 16) }
     }
 
     /// Return the ipv4 destination address as an std::net::Ipv4Addr
@@ -314,12 +343,67 @@ impl<'a> Ipv4HeaderSlice<'a> {
     }
 
     /// Returns a slice containing the ipv4 header options (empty when there are no options).
+    ///
+    /// The options are also empty if they were cut off (see
+    /// [`crate::LaxIpv4Slice::is_options_truncated`]).
     #[inline]
     pub fn options(&self) -> &'a [u8] {
         // SAFETY:
         // Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
-        unsafe { from_raw_parts(self.slice.as_ptr().add(20), self.slice.len() - 20) }
+        unchecked! { get_unchecked_slice(self.slice, 20, self.slice.len() - 20) }
+    }
+
+    /// Returns an iterator over the options in the header (without
+    /// copying them).
+    #[inline]
+    pub fn options_iterator(&self) -> Ipv4OptionsIterator<'a> {
+        Ipv4OptionsIterator::from_slice(self.options())
+    }
+
+    /// Returns true if the options contain a "Router Alert" option
+    /// ([RFC 2113](https://datatracker.ietf.org/doc/html/rfc2113)).
+    ///
+    /// The options are only scanned until the router alert option or
+    /// the "end of options list" is found. Returns `false` if a malformed
+    /// option is encountered before the router alert option.
+    pub fn has_router_alert_option(&self) -> bool {
+        for option in self.options_iterator() {
+            match option {
+                Ok(option) if Ipv4RawOption::ROUTER_ALERT == option.option_type => {
+                    return 2 == option.data.len();
+                }
+                Ok(_) => {}
+                Err(_) => return false,
+            }
+        }
+        false
+    }
+
+    /// Returns the classification of the destination IPv4 address into
+    /// unicast, multicast or broadcast (see [`CastType::from_ipv4_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_ipv4_addr(&self.destination())
+    }
+
+    /// Returns true if the destination is a multicast address (`224.0.0.0/4`).
+    #[inline]
+    pub fn is_multicast_destination(&self) -> bool {
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // Ipv4Header::MIN_LEN (20) in the constructor.
+        (unchecked! { get_unchecked_u8(self.slice, 16) } & 0xf0) == 224
+    }
+
+    /// Returns true if the destination is a link-local multicast
+    /// address (`224.0.0.0/24`, e.g. `224.0.0.1` "all systems").
+    ///
+    /// Packets with these destinations are not forwarded by routers.
+    #[inline]
+    pub fn is_link_local_multicast_destination(&self) -> bool {
+        let d = self.destination();
+        d[0] == 224 && d[1] == 0 && d[2] == 0
     }
 
     /// Returns true if the payload is fragmented.
@@ -461,7 +545,7 @@ mod test {
     #[test]
     fn from_slice_unchecked() {
         let buffer = [0u8; 4];
-        let slice = unsafe { Ipv4HeaderSlice::from_slice_unchecked(&buffer) };
+        let slice = unchecked! { Ipv4HeaderSlice::from_slice_unchecked(&buffer) };
         assert_eq!(slice.slice(), &buffer);
     }
 
@@ -479,6 +563,10 @@ mod test {
             assert_eq!(slice.total_len(), header.total_len);
             assert_eq!(slice.payload_len(), header.payload_len());
             assert_eq!(slice.identification(), header.identification);
+            assert_eq!(
+                slice.flags_raw(),
+                (u8::from(header.dont_fragment) << 1) | u8::from(header.more_fragments)
+            );
             assert_eq!(slice.dont_fragment(), header.dont_fragment);
             assert_eq!(slice.more_fragments(), header.more_fragments);
             assert_eq!(slice.fragments_offset(), header.fragment_offset);
@@ -505,6 +593,96 @@ mod test {
         }
     }
 
+    #[test]
+    fn flags_raw() {
+        let mut buffer = Ipv4Header::default().to_bytes();
+        for flags in 0..8u8 {
+            buffer[6] = (flags << 5) | 0x1f;
+            let slice = Ipv4HeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(flags, slice.flags_raw());
+            assert_eq!(0 != flags & 0b010, slice.dont_fragment());
+            assert_eq!(0 != flags & 0b001, slice.more_fragments());
+        }
+    }
+
+    #[test]
+    fn options_iterator() {
+        let header = Ipv4Header {
+            options: [1, 0x94, 4, 0, 0, 0, 0, 0].into(),
+            ..Default::default()
+        };
+        let buffer = header.to_bytes();
+        let slice = Ipv4HeaderSlice::from_slice(&buffer).unwrap();
+        let mut iter = slice.options_iterator();
+        assert_eq!(slice.options(), iter.rest());
+        assert_eq!(
+            Some(Ok(Ipv4RawOption {
+                option_type: Ipv4RawOption::NO_OPERATION,
+                data: &[]
+            })),
+            iter.next()
+        );
+        assert_eq!(
+            Some(Ok(Ipv4RawOption {
+                option_type: Ipv4RawOption::ROUTER_ALERT,
+                data: &[0, 0]
+            })),
+            iter.next()
+        );
+        assert_eq!(
+            Some(Ok(Ipv4RawOption {
+                option_type: Ipv4RawOption::END_OF_OPTIONS,
+                data: &[]
+            })),
+            iter.next()
+        );
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn has_router_alert_option() {
+        let tests: [(&[u8], bool); 11] = [
+            (&[], false),
+            (&[0x94, 4, 0, 0], true),
+            (&[1, 1, 1, 1, 0x94, 4, 0, 0], true),
+            (&[0x44, 4, 0, 0, 0x94, 4, 0, 0], true),
+            (&[0, 0, 0, 0, 0x94, 4, 0, 0], false),
+            (&[0x94, 3, 0, 0], false),
+            (&[0x44, 1, 0, 0, 0x94, 4, 0, 0], false),
+            (&[0x44, 8, 0, 0, 0, 0, 0, 0], false),
+            (&[1, 1, 1, 0x44], false),
+            (&[1, 1, 1, 0x94], false),
+            (&[0x44, 12, 0, 0, 0x94, 4, 0, 0], false),
+        ];
+        for (options, expected) in tests {
+            let header = Ipv4Header {
+                options: options.try_into().unwrap(),
+                ..Default::default()
+            };
+            let buffer = header.to_bytes();
+            let slice = Ipv4HeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(expected, slice.has_router_alert_option(), "{:?}", options);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn multicast_destination(destination in any::<[u8;4]>()) {
+            let header = Ipv4Header {
+                destination,
+                ..Default::default()
+            };
+            let buffer = header.to_bytes();
+            let slice = Ipv4HeaderSlice::from_slice(&buffer).unwrap();
+            let addr = core::net::Ipv4Addr::from(destination);
+            assert_eq!(addr.is_multicast(), slice.is_multicast_destination());
+            assert_eq!(
+                addr.is_multicast() && destination[..3] == [224, 0, 0],
+                slice.is_link_local_multicast_destination()
+            );
+        }
+    }
+
     #[test]
     fn is_fragmenting_payload() {
         // not fragmenting
@@ -0,0 +1,189 @@
+use crate::*;
+
+/// A mutable slice containing an IPv4 header of a network package.
+///
+/// Allows modifying individual fields of an already serialized IPv4
+/// header (e.g. decrementing the time to live when forwarding a packet)
+/// without re-serializing the complete header.
+///
+/// ```
+/// use etherparse::{Ipv4Header, Ipv4HeaderSliceMut, IpNumber};
+///
+/// let mut header = Ipv4Header::new(0, 2, IpNumber::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+/// header.header_checksum = header.calc_header_checksum();
+/// let mut data = header.to_bytes();
+///
+/// let mut slice = Ipv4HeaderSliceMut::from_slice(&mut data).unwrap();
+/// // ttl 2 -> 1, the packet can be forwarded
+/// assert_eq!(false, slice.decrement_ttl());
+/// // ttl 1 -> 0, the packet has to be dropped (& an ICMP time exceeded message sent)
+/// assert_eq!(true, slice.decrement_ttl());
+///
+/// // the header checksum is kept valid
+/// let header = slice.to_header_slice().to_header();
+/// assert_eq!(header.calc_header_checksum(), header.header_checksum);
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct Ipv4HeaderSliceMut<'a> {
+    slice: &'a mut [u8],
+}
+
+impl<'a> Ipv4HeaderSliceMut<'a> {
+    /// Creates a mutable slice containing an IPv4 header (including
+    /// the options).
+    ///
+    /// The same checks as in [`Ipv4HeaderSlice::from_slice`] are performed.
+    pub fn from_slice(
+        slice: &'a mut [u8],
+    ) -> Result<Ipv4HeaderSliceMut<'a>, err::ipv4::HeaderSliceError> {
+        let header_len = Ipv4HeaderSlice::from_slice(slice)?.slice().len();
+        Ok(Ipv4HeaderSliceMut {
+            slice: &mut slice[..header_len],
+        })
+    }
+
+    /// Returns the slice containing the IPv4 header.
+    #[inline]
+    pub fn slice(&self) -> &[u8] {
+        self.slice
+    }
+
+    /// Returns a non mutable [`Ipv4HeaderSlice`] that allows reading all
+    /// fields of the header.
+    #[inline]
+    pub fn to_header_slice(&self) -> Ipv4HeaderSlice<'_> {
+        // SAFETY: Safe as the slice was checked in the constructor.
+        unchecked! { Ipv4HeaderSlice::from_slice_unchecked(self.slice) }
+    }
+
+    /// Read the "time to live" field.
+    #[inline]
+    pub fn ttl(&self) -> u8 {
+        self.slice[8]
+    }
+
+    /// Read the "header checksum" field.
+    #[inline]
+    pub fn header_checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[10], self.slice[11]])
+    }
+
+    /// Sets the "time to live" field & incrementally updates the header
+    /// checksum so it stays valid (if it was valid before).
+    ///
+    /// The update of the checksum is done based on
+    /// [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624).
+    pub fn set_ttl_with_checksum_update(&mut self, ttl: u8) {
+        let old_word = u16::from_be_bytes([self.slice[8], self.slice[9]]);
+        self.slice[8] = ttl;
+        let new_word = u16::from_be_bytes([self.slice[8], self.slice[9]]);
+        let checksum = checksum::incremental_update(self.header_checksum(), old_word, new_word);
+        self.slice[10..12].copy_from_slice(&checksum.to_be_bytes());
+    }
+
+    /// Decrements the "time to live" field by one (with an incremental
+    /// update of the header checksum) & returns true if the packet has
+    /// to be dropped (the time to live reached zero).
+    ///
+    /// A router has to drop a packet for which `true` is returned and
+    /// should send an ICMP "time exceeded" message to the source (see
+    /// [`Icmpv4Type::TimeExceeded`]). If the time to live is already zero
+    /// it is left unchanged & `true` is returned.
+    pub fn decrement_ttl(&mut self) -> bool {
+        match self.ttl() {
+            0 => true,
+            ttl => {
+                self.set_ttl_with_checksum_update(ttl - 1);
+                1 == ttl
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv4_any()) {
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.extend_from_slice(&[1, 2, 3]);
+
+            // ok
+            {
+                let mut data = buffer.clone();
+                let slice = Ipv4HeaderSliceMut::from_slice(&mut data).unwrap();
+                assert_eq!(slice.slice(), &buffer[..header.header_len()]);
+                assert_eq!(slice.to_header_slice().to_header(), header);
+                assert_eq!(slice.ttl(), header.time_to_live);
+                assert_eq!(slice.header_checksum(), header.header_checksum);
+            }
+
+            // errors
+            for len in 0..header.header_len() {
+                let mut data = buffer.clone();
+                assert_eq!(
+                    Ipv4HeaderSliceMut::from_slice(&mut data[..len]).unwrap_err(),
+                    Ipv4HeaderSlice::from_slice(&buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn set_ttl_with_checksum_update(
+            header in ipv4_any(),
+            ttl in any::<u8>()
+        ) {
+            let header = {
+                let mut header = header;
+                header.header_checksum = header.calc_header_checksum();
+                header
+            };
+            let mut data = header.to_bytes();
+            let mut slice = Ipv4HeaderSliceMut::from_slice(&mut data).unwrap();
+            slice.set_ttl_with_checksum_update(ttl);
+
+            let mut expected = header.clone();
+            expected.time_to_live = ttl;
+            expected.header_checksum = expected.calc_header_checksum();
+            assert_eq!(expected, slice.to_header_slice().to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn decrement_ttl(header in ipv4_any()) {
+            let header = {
+                let mut header = header;
+                header.header_checksum = header.calc_header_checksum();
+                header
+            };
+            let mut data = header.to_bytes();
+            let mut slice = Ipv4HeaderSliceMut::from_slice(&mut data).unwrap();
+            let drop = slice.decrement_ttl();
+
+            let mut expected = header.clone();
+            expected.time_to_live = header.time_to_live.saturating_sub(1);
+            expected.header_checksum = expected.calc_header_checksum();
+            assert_eq!(expected, slice.to_header_slice().to_header());
+            assert_eq!(drop, header.time_to_live <= 1);
+        }
+    }
+
+    #[test]
+    fn debug_eq() {
+        let mut data = Ipv4Header::new(0, 1, IpNumber::UDP, [1, 2, 3, 4], [5, 6, 7, 8])
+            .unwrap()
+            .to_bytes();
+        let expected = format!("Ipv4HeaderSliceMut {{ slice: {:?} }}", &data[..]);
+        let mut data2: Vec<u8> = data.to_vec();
+        let slice = Ipv4HeaderSliceMut::from_slice(&mut data).unwrap();
+        assert_eq!(expected, format!("{:?}", slice));
+        assert_eq!(slice, Ipv4HeaderSliceMut::from_slice(&mut data2).unwrap());
+    }
+}
@@ -0,0 +1,223 @@
+use crate::err::{ValueTooBigError, ValueType};
+use core::net::Ipv4Addr;
+
+/// IPv4 network consisting of an address & prefix length (e.g. "10.0.0.0/8").
+///
+/// Can be used to match the addresses of parsed headers against a subnet
+/// (or of extracted flows via [`crate::quick::FlowKey::is_in_ipv4_network`]):
+///
+/// ```
+/// use etherparse::{Ipv4Header, Ipv4Network};
+/// use core::net::Ipv4Addr;
+///
+/// let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+///
+/// let header = Ipv4Header {
+///     source: [10, 1, 2, 3],
+///     destination: [192, 168, 1, 1],
+///     ..Default::default()
+/// };
+/// assert!(network.contains(header.source));
+/// assert!(false == network.contains(header.destination));
+/// ```
+///
+/// With the `std` feature networks can also be parsed from strings:
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use etherparse::Ipv4Network;
+///
+/// let network: Ipv4Network = "192.168.0.0/16".parse().unwrap();
+/// assert!(network.contains([192, 168, 5, 1]));
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv4Network {
+    addr: Ipv4Addr,
+    prefix_len: u8,
+}
+
+impl Ipv4Network {
+    /// Maximum allowed prefix length (number of bits in an IPv4 address).
+    pub const MAX_PREFIX_LEN: u8 = 32;
+
+    /// Creates a network from an address & prefix length.
+    ///
+    /// Host bits set in `addr` (bits after the prefix) are cleared. An
+    /// error is returned if `prefix_len` exceeds [`Ipv4Network::MAX_PREFIX_LEN`].
+    pub const fn new(addr: Ipv4Addr, prefix_len: u8) -> Result<Ipv4Network, ValueTooBigError<u8>> {
+        if prefix_len > Ipv4Network::MAX_PREFIX_LEN {
+            Err(ValueTooBigError {
+                actual: prefix_len,
+                max_allowed: Ipv4Network::MAX_PREFIX_LEN,
+                value_type: ValueType::IpNetworkPrefixLen,
+            })
+        } else {
+            Ok(Ipv4Network {
+                addr: Ipv4Addr::from_bits(addr.to_bits() & Self::mask(prefix_len)),
+                prefix_len,
+            })
+        }
+    }
+
+    /// Returns the network mask as an `u32` for the given prefix length.
+    #[inline]
+    const fn mask(prefix_len: u8) -> u32 {
+        if 0 == prefix_len {
+            0
+        } else {
+            u32::MAX << (32 - prefix_len as u32)
+        }
+    }
+
+    /// Returns the network address (with all host bits cleared).
+    #[inline]
+    pub const fn addr(&self) -> Ipv4Addr {
+        self.addr
+    }
+
+    /// Returns the prefix length (number of leading network bits).
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the network mask (e.g. 255.255.0.0 for a "/16" network).
+    #[inline]
+    pub const fn netmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(Self::mask(self.prefix_len))
+    }
+
+    /// Returns true if the given address is part of the network.
+    ///
+    /// Accepts everything that can be converted to an [`Ipv4Addr`]
+    /// (e.g. the `[u8;4]` address fields of an [`crate::Ipv4Header`]).
+    #[inline]
+    pub fn contains<A: Into<Ipv4Addr>>(&self, addr: A) -> bool {
+        (addr.into().to_bits() & Self::mask(self.prefix_len)) == self.addr.to_bits()
+    }
+}
+
+impl core::fmt::Display for Ipv4Network {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl core::str::FromStr for Ipv4Network {
+    type Err = crate::err::ip::NetworkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::err::ip::NetworkParseError::*;
+        let (addr, prefix_len) = s.split_once('/').ok_or(MissingPrefixLen)?;
+        let addr: Ipv4Addr = addr.parse().map_err(|_| InvalidAddr)?;
+        let prefix_len: u8 = prefix_len.parse().map_err(|_| InvalidPrefixLen)?;
+        Ipv4Network::new(addr, prefix_len).map_err(|_| InvalidPrefixLen)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ip::NetworkParseError;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn new() {
+        // host bits get cleared
+        {
+            let actual = Ipv4Network::new(Ipv4Addr::new(10, 1, 2, 3), 8).unwrap();
+            assert_eq!(Ipv4Addr::new(10, 0, 0, 0), actual.addr());
+            assert_eq!(8, actual.prefix_len());
+        }
+        // edge cases
+        {
+            let actual = Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 4), 0).unwrap();
+            assert_eq!(Ipv4Addr::UNSPECIFIED, actual.addr());
+            let actual = Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
+            assert_eq!(Ipv4Addr::new(1, 2, 3, 4), actual.addr());
+        }
+        // prefix too big
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::UNSPECIFIED, 33).unwrap_err(),
+            ValueTooBigError {
+                actual: 33,
+                max_allowed: 32,
+                value_type: ValueType::IpNetworkPrefixLen,
+            }
+        );
+    }
+
+    #[test]
+    fn netmask() {
+        let tests = [
+            (0, Ipv4Addr::new(0, 0, 0, 0)),
+            (1, Ipv4Addr::new(128, 0, 0, 0)),
+            (16, Ipv4Addr::new(255, 255, 0, 0)),
+            (23, Ipv4Addr::new(255, 255, 254, 0)),
+            (32, Ipv4Addr::new(255, 255, 255, 255)),
+        ];
+        for (prefix_len, expected) in tests {
+            assert_eq!(
+                expected,
+                Ipv4Network::new(Ipv4Addr::UNSPECIFIED, prefix_len)
+                    .unwrap()
+                    .netmask()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn contains(
+            network_addr in any::<[u8;4]>(),
+            prefix_len in 0u8..=32,
+            addr in any::<[u8;4]>()
+        ) {
+            let network = Ipv4Network::new(network_addr.into(), prefix_len).unwrap();
+            // the network address itself is always contained
+            assert!(network.contains(network_addr));
+            // compare against a bitwise check
+            let expected = (0..usize::from(prefix_len)).all(|bit| {
+                let byte = bit / 8;
+                let mask = 0x80u8 >> (bit % 8);
+                (network_addr[byte] & mask) == (addr[byte] & mask)
+            });
+            assert_eq!(expected, network.contains(addr));
+            assert_eq!(expected, network.contains(Ipv4Addr::from(addr)));
+        }
+    }
+
+    #[test]
+    fn fmt() {
+        let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!("10.0.0.0/8", format!("{}", network));
+        assert_eq!(
+            "Ipv4Network { addr: 10.0.0.0, prefix_len: 8 }",
+            format!("{:?}", network)
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        use NetworkParseError::*;
+        assert_eq!(
+            Ok(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()),
+            "10.0.0.0/8".parse::<Ipv4Network>()
+        );
+        assert_eq!(
+            Ok(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap()),
+            "192.168.1.17/24".parse::<Ipv4Network>()
+        );
+        assert_eq!(Err(MissingPrefixLen), "10.0.0.0".parse::<Ipv4Network>());
+        assert_eq!(Err(InvalidAddr), "10.0.0/8".parse::<Ipv4Network>());
+        assert_eq!(Err(InvalidAddr), "::/8".parse::<Ipv4Network>());
+        assert_eq!(Err(InvalidPrefixLen), "10.0.0.0/".parse::<Ipv4Network>());
+        assert_eq!(Err(InvalidPrefixLen), "10.0.0.0/a".parse::<Ipv4Network>());
+        assert_eq!(Err(InvalidPrefixLen), "10.0.0.0/33".parse::<Ipv4Network>());
+    }
+}
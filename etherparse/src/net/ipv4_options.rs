@@ -1,3 +1,4 @@
+use crate::{get_unchecked_slice, get_unchecked_slice_mut};
 use core::borrow::{Borrow, BorrowMut};
 
 /// Options present in an [`crate::Ipv4Header`].
@@ -56,13 +57,13 @@ impl Ipv4Options {
     /// Returns the slice containing the data of the options.
     #[inline]
     pub fn as_slice(&self) -> &[u8] {
-        unsafe { core::slice::from_raw_parts(self.buf.as_ptr(), self.len.into()) }
+        unchecked! { get_unchecked_slice(&self.buf, 0, self.len.into()) }
     }
 
     /// Returns a mutable slice containing the data of the options.
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [u8] {
-        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr(), self.len.into()) }
+        unchecked! { get_unchecked_slice_mut(&mut self.buf, 0, self.len.into()) }
     }
 
     /// Returns the length of the options in bytes.
@@ -93,13 +94,9 @@ impl TryFrom<&[u8]> for Ipv4Options {
                 len: value.len() as u8,
                 buf: [0; 40],
             };
-            unsafe {
+            unchecked! {
                 // SAFETY: Safe as value.len() <= 40 and the result buffer size is 40.
-                core::ptr::copy_nonoverlapping(
-                    value.as_ptr(),
-                    result.buf.as_mut_ptr(),
-                    value.len(),
-                );
+                get_unchecked_slice_mut(&mut result.buf, 0, value.len()).copy_from_slice(value);
             }
             Ok(result)
         } else {
@@ -170,9 +167,9 @@ macro_rules! from_static_array {
                     len: $x,
                     buf: [0; 40],
                 };
-                let r = result.buf.as_mut_ptr() as *mut [u8; $x];
-                unsafe {
-                    *r = values;
+                // SAFETY: Safe as $x <= 40 and the result buffer size is 40.
+                unchecked! {
+                    get_unchecked_slice_mut(&mut result.buf, 0, $x).copy_from_slice(&values);
                 }
                 result
             }
@@ -0,0 +1,260 @@
+use crate::*;
+
+/// Option contained in the options of an IPv4 header (see
+/// [`Ipv4OptionsIterator`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Ipv4RawOption<'a> {
+    /// Type of the option (including the "copied" flag & class bits).
+    pub option_type: u8,
+    /// Data of the option (excluding the type & length fields).
+    pub data: &'a [u8],
+}
+
+impl Ipv4RawOption<'_> {
+    /// End of options list (single byte, no length & data fields).
+    pub const END_OF_OPTIONS: u8 = 0;
+    /// No operation (single byte, no length & data fields).
+    pub const NO_OPERATION: u8 = 1;
+    /// Record Route (RFC 791).
+    pub const RECORD_ROUTE: u8 = 7;
+    /// Timestamp (RFC 791).
+    pub const TIMESTAMP: u8 = 0x44;
+    /// Loose Source and Record Route (RFC 791).
+    pub const LOOSE_SOURCE_ROUTE: u8 = 0x83;
+    /// Security (RFC 1108).
+    pub const SECURITY: u8 = 0x82;
+    /// Stream ID (RFC 791).
+    pub const STREAM_ID: u8 = 0x88;
+    /// Strict Source and Record Route (RFC 791).
+    pub const STRICT_SOURCE_ROUTE: u8 = 0x89;
+    /// Router Alert (RFC 2113).
+    pub const ROUTER_ALERT: u8 = 0x94;
+
+    /// Returns true if the "copied" flag is set (option has to be copied
+    /// into all fragments).
+    #[inline]
+    pub fn is_copied(&self) -> bool {
+        0 != self.option_type & 0x80
+    }
+
+    /// Returns the 2 bit "option class" of the option type.
+    #[inline]
+    pub fn class(&self) -> u8 {
+        (self.option_type >> 5) & 0b11
+    }
+
+    /// Returns the 5 bit "option number" of the option type.
+    #[inline]
+    pub fn number(&self) -> u8 {
+        self.option_type & 0b1_1111
+    }
+}
+
+/// Iterator over the options of an IPv4 header (see
+/// [`crate::Ipv4HeaderSlice::options_iterator`]).
+///
+/// "End of options list" & "no operation" options are also returned by
+/// the iterator. After an "end of options list" option the iteration ends
+/// (the remaining bytes are padding). If an option is truncated or has a
+/// length field smaller than 2 an error is returned & the iteration ends.
+/// The `layer_start_offset` of the errors assumes that the options start
+/// directly after the 20 byte fixed part of the IPv4 header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv4OptionsIterator<'a> {
+    options: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Ipv4OptionsIterator<'a> {
+    /// Creates an iterator over the IPv4 options in the given slice.
+    #[inline]
+    pub fn from_slice(options: &'a [u8]) -> Ipv4OptionsIterator<'a> {
+        Ipv4OptionsIterator { options, offset: 0 }
+    }
+
+    /// Returns the not yet iterated part of the options.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.options
+    }
+}
+
+impl<'a> Iterator for Ipv4OptionsIterator<'a> {
+    type Item = Result<Ipv4RawOption<'a>, err::LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let option_type = *self.options.first()?;
+        match option_type {
+            Ipv4RawOption::END_OF_OPTIONS => {
+                self.offset += self.options.len();
+                self.options = &[];
+                return Some(Ok(Ipv4RawOption {
+                    option_type,
+                    data: &[],
+                }));
+            }
+            Ipv4RawOption::NO_OPERATION => {
+                self.offset += 1;
+                self.options = &self.options[1..];
+                return Some(Ok(Ipv4RawOption {
+                    option_type,
+                    data: &[],
+                }));
+            }
+            _ => {}
+        }
+        // the length field of an option also includes the type & length
+        // field (so values smaller than 2 are invalid)
+        let len = self
+            .options
+            .get(1)
+            .map(|v| usize::from(*v))
+            .unwrap_or(2)
+            .max(2);
+        if self.options.len() < len || self.options[1] < 2 {
+            let err = err::LenError {
+                required_len: len,
+                len: if self.options.len() < len {
+                    self.options.len()
+                } else {
+                    usize::from(self.options[1])
+                },
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv4Header,
+                layer_start_offset: Ipv4Header::MIN_LEN + self.offset,
+            };
+            self.offset += self.options.len();
+            self.options = &[];
+            return Some(Err(err));
+        }
+        let data = &self.options[2..len];
+        self.offset += len;
+        self.options = &self.options[len..];
+        Some(Ok(Ipv4RawOption { option_type, data }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn type_bits() {
+        let option = Ipv4RawOption {
+            option_type: Ipv4RawOption::ROUTER_ALERT,
+            data: &[0, 0],
+        };
+        assert!(option.is_copied());
+        assert_eq!(0, option.class());
+        assert_eq!(20, option.number());
+
+        let option = Ipv4RawOption {
+            option_type: Ipv4RawOption::TIMESTAMP,
+            data: &[],
+        };
+        assert!(false == option.is_copied());
+        assert_eq!(2, option.class());
+        assert_eq!(4, option.number());
+    }
+
+    #[test]
+    fn iterate() {
+        let data = [1, 0x94, 4, 0, 0, 7, 3, 9, 0, 1, 2];
+        let mut iter = Ipv4OptionsIterator::from_slice(&data);
+        assert_eq!(&data[..], iter.rest());
+        let options: Vec<_> = iter.by_ref().collect();
+        assert_eq!(
+            &[
+                Ok(Ipv4RawOption {
+                    option_type: Ipv4RawOption::NO_OPERATION,
+                    data: &[]
+                }),
+                Ok(Ipv4RawOption {
+                    option_type: Ipv4RawOption::ROUTER_ALERT,
+                    data: &[0, 0]
+                }),
+                Ok(Ipv4RawOption {
+                    option_type: Ipv4RawOption::RECORD_ROUTE,
+                    data: &[9]
+                }),
+                Ok(Ipv4RawOption {
+                    option_type: Ipv4RawOption::END_OF_OPTIONS,
+                    data: &[]
+                }),
+            ][..],
+            &options[..]
+        );
+        assert!(iter.rest().is_empty());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn iterate_len_error() {
+        // missing length field
+        {
+            let mut iter = Ipv4OptionsIterator::from_slice(&[1, 7]);
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 2,
+                    len: 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv4Header,
+                    layer_start_offset: 21,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+        // length field bigger then the slice
+        {
+            let mut iter = Ipv4OptionsIterator::from_slice(&[7, 4, 0]);
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 4,
+                    len: 3,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv4Header,
+                    layer_start_offset: 20,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+        // length field smaller then 2
+        {
+            let mut iter = Ipv4OptionsIterator::from_slice(&[7, 1, 0, 0]);
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 2,
+                    len: 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv4Header,
+                    layer_start_offset: 20,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let option = Ipv4RawOption {
+            option_type: 7,
+            data: &[1],
+        };
+        assert_eq!(option, option.clone());
+        assert_eq!(
+            "Ipv4RawOption { option_type: 7, data: [1] }",
+            format!("{:?}", option)
+        );
+        let iter = Ipv4OptionsIterator::from_slice(&[1]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "Ipv4OptionsIterator { options: [1], offset: 0 }",
+            format!("{:?}", iter)
+        );
+    }
+}
@@ -56,11 +56,8 @@ impl<'a> Ipv4Slice<'a> {
                 layer_start_offset: 0,
             }));
         } else {
-            unsafe {
-                core::slice::from_raw_parts(
-                    slice.as_ptr().add(header.slice().len()),
-                    header_total_len - header.slice().len(),
-                )
+            unchecked! {
+                get_unchecked_slice(slice, header.slice().len(), header_total_len - header.slice().len())
             }
         };
 
@@ -85,11 +82,8 @@ impl<'a> Ipv4Slice<'a> {
                 };
 
                 // remove the extension header from the payload
-                let payload = unsafe {
-                    core::slice::from_raw_parts(
-                        header_payload.as_ptr().add(auth.slice().len()),
-                        header_payload.len() - auth.slice().len(),
-                    )
+                let payload = unchecked! {
+                    get_unchecked_slice(header_payload, auth.slice().len(), header_payload.len() - auth.slice().len())
                 };
                 let ip_number = auth.next_header();
                 Ok(Ipv4Slice {
@@ -0,0 +1,186 @@
+/// Helper functions for classifying IPv6 addresses (e.g. the values
+/// returned by [`crate::Ipv6HeaderSlice::source`] or
+/// [`crate::Ipv6HeaderSlice::destination`]).
+///
+/// All functions operate on raw `[u8;16]` addresses and are available
+/// without the `std` feature. An `std::net::Ipv6Addr` can be classified
+/// by passing the result of `octets()`:
+///
+/// ```
+/// use etherparse::{ipv6_addr, Ipv6MulticastScope};
+///
+/// let addr: std::net::Ipv6Addr = "fe80::1".parse().unwrap();
+/// assert!(ipv6_addr::is_link_local(&addr.octets()));
+///
+/// let addr: std::net::Ipv6Addr = "ff05::2".parse().unwrap();
+/// assert!(ipv6_addr::is_multicast(&addr.octets()));
+/// assert_eq!(
+///     Some(Ipv6MulticastScope::SITE_LOCAL),
+///     ipv6_addr::multicast_scope(&addr.octets())
+/// );
+/// ```
+pub mod ipv6_addr {
+    use crate::Ipv6MulticastScope;
+
+    /// Prefix (first 13 bytes) of all solicited-node multicast
+    /// addresses (`ff02::1:ff00:0/104`).
+    pub const SOLICITED_NODE_PREFIX: [u8; 13] = [0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0xff];
+
+    /// Returns true if the address is an unicast link-local address
+    /// (`fe80::/10`).
+    #[inline]
+    pub const fn is_link_local(addr: &[u8; 16]) -> bool {
+        addr[0] == 0xfe && (addr[1] & 0b1100_0000) == 0b1000_0000
+    }
+
+    /// Returns true if the address is an unique local address
+    /// (`fc00::/7`, [RFC 4193](https://datatracker.ietf.org/doc/html/rfc4193)).
+    #[inline]
+    pub const fn is_unique_local(addr: &[u8; 16]) -> bool {
+        (addr[0] & 0b1111_1110) == 0xfc
+    }
+
+    /// Returns true if the address is a multicast address (`ff00::/8`).
+    #[inline]
+    pub const fn is_multicast(addr: &[u8; 16]) -> bool {
+        addr[0] == 0xff
+    }
+
+    /// Returns the scope of a multicast address or `None` if the
+    /// address is not a multicast address.
+    #[inline]
+    pub const fn multicast_scope(addr: &[u8; 16]) -> Option<Ipv6MulticastScope> {
+        if is_multicast(addr) {
+            Some(Ipv6MulticastScope(addr[1] & 0b0000_1111))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the address is an IPv4-mapped IPv6 address
+    /// (`::ffff:0:0/96`).
+    #[inline]
+    pub const fn is_ipv4_mapped(addr: &[u8; 16]) -> bool {
+        matches!(addr, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, _, _, _, _])
+    }
+
+    /// Returns the embedded IPv4 address if the address is an
+    /// IPv4-mapped IPv6 address (`::ffff:0:0/96`).
+    #[inline]
+    pub const fn to_ipv4_mapped(addr: &[u8; 16]) -> Option<[u8; 4]> {
+        if is_ipv4_mapped(addr) {
+            Some([addr[12], addr[13], addr[14], addr[15]])
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the address is a solicited-node multicast address
+    /// (`ff02::1:ff00:0/104`, [RFC 4291](https://datatracker.ietf.org/doc/html/rfc4291#section-2.7.1)).
+    #[inline]
+    pub const fn is_solicited_node_multicast(addr: &[u8; 16]) -> bool {
+        let mut i = 0;
+        while i < SOLICITED_NODE_PREFIX.len() {
+            if addr[i] != SOLICITED_NODE_PREFIX[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns the solicited-node multicast address for the given
+    /// unicast or anycast address (the prefix `ff02::1:ff00:0/104`
+    /// followed by the lower 24 bits of the address).
+    ///
+    /// ```
+    /// use etherparse::ipv6_addr;
+    ///
+    /// let addr: std::net::Ipv6Addr = "2001:db8::1:800:200e:8c6c".parse().unwrap();
+    /// let expected: std::net::Ipv6Addr = "ff02::1:ff0e:8c6c".parse().unwrap();
+    /// assert_eq!(
+    ///     expected.octets(),
+    ///     ipv6_addr::solicited_node_multicast(&addr.octets())
+    /// );
+    /// ```
+    #[inline]
+    pub const fn solicited_node_multicast(addr: &[u8; 16]) -> [u8; 16] {
+        let p = SOLICITED_NODE_PREFIX;
+        [
+            p[0], p[1], p[2], p[3], p[4], p[5], p[6], p[7], p[8], p[9], p[10], p[11], p[12],
+            addr[13], addr[14], addr[15],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ipv6_addr::*;
+    use crate::Ipv6MulticastScope;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn classification(addr in any::<[u8;16]>()) {
+            let std_addr = std::net::Ipv6Addr::from(addr);
+            let segments = std_addr.segments();
+
+            assert_eq!(is_link_local(&addr), (segments[0] & 0xffc0) == 0xfe80);
+            assert_eq!(is_unique_local(&addr), (segments[0] & 0xfe00) == 0xfc00);
+            assert_eq!(is_multicast(&addr), std_addr.is_multicast());
+            assert_eq!(
+                multicast_scope(&addr),
+                if std_addr.is_multicast() {
+                    Some(Ipv6MulticastScope((segments[0] & 0xf) as u8))
+                } else {
+                    None
+                }
+            );
+            assert_eq!(
+                to_ipv4_mapped(&addr),
+                std_addr.to_ipv4_mapped().map(|v| v.octets())
+            );
+            assert_eq!(is_ipv4_mapped(&addr), std_addr.to_ipv4_mapped().is_some());
+            assert_eq!(
+                is_solicited_node_multicast(&addr),
+                segments[0] == 0xff02
+                    && segments[1..5] == [0, 0, 0, 0]
+                    && segments[5] == 1
+                    && (segments[6] & 0xff00) == 0xff00
+            );
+
+            let sn = solicited_node_multicast(&addr);
+            assert!(is_solicited_node_multicast(&sn));
+            assert!(is_multicast(&sn));
+            assert_eq!(Some(Ipv6MulticastScope::LINK_LOCAL), multicast_scope(&sn));
+            assert_eq!(&sn[13..], &addr[13..]);
+        }
+    }
+
+    #[test]
+    fn known_addresses() {
+        let parse = |s: &str| s.parse::<std::net::Ipv6Addr>().unwrap().octets();
+
+        assert!(is_link_local(&parse("fe80::1")));
+        assert!(is_link_local(&parse("febf::1")));
+        assert!(!is_link_local(&parse("fec0::1")));
+
+        assert!(is_unique_local(&parse("fc00::1")));
+        assert!(is_unique_local(&parse("fd12:3456::1")));
+        assert!(!is_unique_local(&parse("fe80::1")));
+
+        assert!(is_ipv4_mapped(&parse("::ffff:192.168.1.1")));
+        assert_eq!(
+            Some([192, 168, 1, 1]),
+            to_ipv4_mapped(&parse("::ffff:192.168.1.1"))
+        );
+        assert!(!is_ipv4_mapped(&parse("::192.168.1.1")));
+
+        assert!(is_solicited_node_multicast(&parse("ff02::1:ff00:1")));
+        assert!(!is_solicited_node_multicast(&parse("ff02::1")));
+        assert_eq!(
+            parse("ff02::1:ff00:1"),
+            solicited_node_multicast(&parse("fe80::1"))
+        );
+    }
+}
@@ -10,9 +10,8 @@ use crate::*;
 /// from <https://www.iana.org/assignments/ipv6-parameters/ipv6-parameters.xhtml>):
 ///
 /// * Encapsulating Security Payload \[[RFC4303](https://datatracker.ietf.org/doc/html/rfc4303)\]
-/// * Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
-/// * Host Identity Protocol \[[RFC7401](https://datatracker.ietf.org/doc/html/rfc7401)\]
-/// * Shim6 Protocol \[[RFC5533](https://datatracker.ietf.org/doc/html/rfc5533)\]
+/// * Host Identity Protocol \[[RFC7401](https://datatracker.ietf.org/doc/html/rfc7401)\] (see [`HipHeaderSlice`])
+/// * Shim6 Protocol \[[RFC5533](https://datatracker.ietf.org/doc/html/rfc5533)\] (see [`Shim6HeaderSlice`])
 /// * 253 Use for experimentation and testing \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
 /// * 254 Use for experimentation and testing \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,6 +26,8 @@ pub enum Ipv6ExtensionSlice<'a> {
     DestinationOptions(Ipv6RawExtHeaderSlice<'a>),
     /// Authentication Header \[[RFC4302](https://datatracker.ietf.org/doc/html/rfc4302)\]
     Authentication(IpAuthHeaderSlice<'a>),
+    /// Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
+    Mobility(MobilityHeaderSlice<'a>),
 }
 
 #[cfg(test)]
@@ -76,6 +77,20 @@ mod test {
                 format!("{:?}", Authentication(slice.clone()))
             );
         }
+        {
+            let header = MobilityHeader::new(
+                IPV6_NO_NEXT_HEADER,
+                MobilityMessage::BindingRefreshRequest,
+                &[],
+            )
+            .unwrap();
+            let buffer = header.to_bytes();
+            let slice = MobilityHeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(
+                format!("Mobility({:?})", slice),
+                format!("{:?}", Mobility(slice.clone()))
+            );
+        }
     }
 
     #[test]
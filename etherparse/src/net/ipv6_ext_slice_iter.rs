@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// Allows iterating over the IPv6 extension headers present in an [Ipv6ExtensionsSlice].
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -32,42 +31,49 @@ impl<'a> Iterator for Ipv6ExtensionSliceIter<'a> {
             // As the slice contents & length were previously checked by
             // Ipv6ExtensionsSlice::from_slice the content does not have to be
             // rechecked.
-            IPV6_HOP_BY_HOP => unsafe {
-                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+            IPV6_HOP_BY_HOP => unchecked! {
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked_internal(self.rest);
                 let len = slice.slice().len();
-                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.rest = get_unchecked_slice(self.rest, len, self.rest.len() - len);
                 self.next_header = slice.next_header();
                 Some(HopByHop(slice))
             },
-            IPV6_ROUTE => unsafe {
-                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+            IPV6_ROUTE => unchecked! {
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked_internal(self.rest);
                 let len = slice.slice().len();
-                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.rest = get_unchecked_slice(self.rest, len, self.rest.len() - len);
                 self.next_header = slice.next_header();
                 Some(Routing(slice))
             },
-            IPV6_DEST_OPTIONS => unsafe {
-                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+            IPV6_DEST_OPTIONS => unchecked! {
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked_internal(self.rest);
                 let len = slice.slice().len();
-                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.rest = get_unchecked_slice(self.rest, len, self.rest.len() - len);
                 self.next_header = slice.next_header();
                 Some(DestinationOptions(slice))
             },
-            IPV6_FRAG => unsafe {
-                let slice = Ipv6FragmentHeaderSlice::from_slice_unchecked(self.rest);
+            IPV6_FRAG => unchecked! {
+                let slice = Ipv6FragmentHeaderSlice::from_slice_unchecked_internal(self.rest);
                 let len = slice.slice().len();
-                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.rest = get_unchecked_slice(self.rest, len, self.rest.len() - len);
                 self.next_header = slice.next_header();
 
                 Some(Fragment(slice))
             },
-            AUTH => unsafe {
-                let slice = IpAuthHeaderSlice::from_slice_unchecked(self.rest);
+            AUTH => unchecked! {
+                let slice = IpAuthHeaderSlice::from_slice_unchecked_internal(self.rest);
                 let len = slice.slice().len();
-                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.rest = get_unchecked_slice(self.rest, len, self.rest.len() - len);
                 self.next_header = slice.next_header();
                 Some(Authentication(slice))
             },
+            MOBILITY => unchecked! {
+                let slice = MobilityHeaderSlice::from_slice_unchecked_internal(self.rest);
+                let len = slice.slice().len();
+                self.rest = get_unchecked_slice(self.rest, len, self.rest.len() - len);
+                self.next_header = slice.payload_proto();
+                Some(Mobility(slice))
+            },
             // done parsing, the next header is not a known/supported header extension
             _ => None,
         }
@@ -146,6 +152,11 @@ mod test {
                                 assert_eq!(next, Authentication(header.clone()));
                                 slice = &slice[header.slice().len()..];
                             },
+                            MOBILITY => {
+                                let header = MobilityHeaderSlice::from_slice(slice).unwrap();
+                                assert_eq!(next, Mobility(header.clone()));
+                                slice = &slice[header.slice().len()..];
+                            },
                             _ => unreachable!()
                         }
                     }
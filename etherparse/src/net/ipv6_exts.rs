@@ -13,13 +13,14 @@ use crate::{
 /// * Routing Header
 /// * Fragment
 /// * Authentication Header
+/// * Mobility Header
 ///
 /// Currently not supported:
 ///
 /// * Encapsulating Security Payload Header (ESP)
-/// * Host Identity Protocol (HIP)
-/// * IP Mobility
-/// * Site Multihoming by IPv6 Intermediation (SHIM6)
+/// * Host Identity Protocol (HIP) (decoded separately via [`HipHeaderSlice`])
+/// * Site Multihoming by IPv6 Intermediation (SHIM6) (decoded separately
+///   via [`Shim6HeaderSlice`])
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ipv6Extensions {
     pub hop_by_hop_options: Option<Ipv6RawExtHeader>,
@@ -27,6 +28,7 @@ pub struct Ipv6Extensions {
     pub routing: Option<Ipv6RoutingExtensions>,
     pub fragment: Option<Ipv6FragmentHeader>,
     pub auth: Option<IpAuthHeader>,
+    pub mobility: Option<MobilityHeader>,
 }
 
 impl Ipv6Extensions {
@@ -38,7 +40,15 @@ impl Ipv6Extensions {
     pub const MAX_LEN: usize = Ipv6RawExtHeader::MAX_LEN * 2
         + Ipv6RoutingExtensions::MAX_LEN
         + Ipv6FragmentHeader::LEN
-        + IpAuthHeader::MAX_LEN;
+        + IpAuthHeader::MAX_LEN
+        + MobilityHeader::MAX_LEN;
+
+    /// Returns a builder that allows adding extension headers in the
+    /// order defined by RFC 8200 (see [`Ipv6ExtensionsBuilder`]).
+    #[inline]
+    pub fn builder() -> Ipv6ExtensionsBuilder {
+        Ipv6ExtensionsBuilder::new()
+    }
 
     /// Reads as many extension headers as possible from the slice.
     ///
@@ -162,6 +172,24 @@ impl Ipv6Extensions {
                         result.auth = Some(slice.to_header());
                     }
                 }
+                MOBILITY => {
+                    if result.mobility.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return Ok((result, next_header, rest));
+                    } else {
+                        let slice = MobilityHeaderSlice::from_slice(rest).map_err(|err| {
+                            use err::ipv6_exts::HeaderError as O;
+                            use err::ipv6_mobility::HeaderSliceError as I;
+                            match err {
+                                I::Len(err) => Len(err.add_offset(slice.len() - rest.len())),
+                                I::Content(err) => Content(O::Mobility(err)),
+                            }
+                        })?;
+                        rest = &rest[slice.slice().len()..];
+                        next_header = slice.payload_proto();
+                        result.mobility = Some(slice.to_header());
+                    }
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return Ok((result, next_header, rest));
@@ -363,6 +391,38 @@ impl Ipv6Extensions {
                         }
                     }
                 }
+                MOBILITY => {
+                    if result.mobility.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return (result, next_header, rest, None);
+                    } else {
+                        match MobilityHeaderSlice::from_slice(rest) {
+                            Ok(slice) => {
+                                rest = &rest[slice.slice().len()..];
+                                next_header = slice.payload_proto();
+                                result.mobility = Some(slice.to_header());
+                            }
+                            Err(err) => {
+                                use err::ipv6_exts::HeaderError as O;
+                                use err::ipv6_mobility::HeaderSliceError as I;
+                                return (
+                                    result,
+                                    next_header,
+                                    rest,
+                                    Some((
+                                        match err {
+                                            I::Len(err) => {
+                                                Len(err.add_offset(slice.len() - rest.len()))
+                                            }
+                                            I::Content(err) => Content(O::Mobility(err)),
+                                        },
+                                        Layer::Ipv6MobilityHeader,
+                                    )),
+                                );
+                            }
+                        }
+                    }
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return (result, next_header, rest, None);
@@ -485,6 +545,22 @@ impl Ipv6Extensions {
                         result.auth = Some(header);
                     }
                 }
+                MOBILITY => {
+                    if result.mobility.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return Ok((result, next_protocol));
+                    } else {
+                        let header = MobilityHeader::read(reader).map_err(|err| {
+                            use err::ipv6_mobility::HeaderReadError as I;
+                            match err {
+                                I::Io(err) => Io(err),
+                                I::Content(err) => Content(Mobility(err)),
+                            }
+                        })?;
+                        next_protocol = header.payload_proto;
+                        result.mobility = Some(header);
+                    }
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return Ok((result, next_protocol));
@@ -623,6 +699,23 @@ impl Ipv6Extensions {
                         result.auth = Some(header);
                     }
                 }
+                MOBILITY => {
+                    if result.mobility.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return Ok((result, next_protocol));
+                    } else {
+                        let header = MobilityHeader::read_limited(reader).map_err(|err| {
+                            use err::ipv6_mobility::HeaderLimitedReadError as I;
+                            match err {
+                                I::Io(err) => Io(err),
+                                I::Len(err) => Len(err),
+                                I::Content(err) => Content(Mobility(err)),
+                            }
+                        })?;
+                        next_protocol = header.payload_proto;
+                        result.mobility = Some(header);
+                    }
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return Ok((result, next_protocol));
@@ -648,8 +741,26 @@ impl Ipv6Extensions {
         writer: &mut T,
         first_header: IpNumber,
     ) -> Result<(), err::ipv6_exts::HeaderWriteError> {
-        use err::ipv6_exts::ExtsWalkError::*;
         use err::ipv6_exts::HeaderWriteError::*;
+        self.write_with(
+            first_header,
+            |bytes| writer.write_all(bytes).map_err(Io),
+            Content,
+        )
+    }
+
+    /// Passes the serialized headers in the order defined by the next
+    /// header fields to `write` (shared implementation of
+    /// [`Ipv6Extensions::write`] & the [`crate::PacketBuilder`]).
+    ///
+    /// Consistency errors are converted via `content_err`.
+    pub(crate) fn write_with<E>(
+        &self,
+        first_header: IpNumber,
+        mut write: impl FnMut(&[u8]) -> Result<(), E>,
+        content_err: impl Fn(err::ipv6_exts::ExtsWalkError) -> E,
+    ) -> Result<(), E> {
+        use err::ipv6_exts::ExtsWalkError::*;
         use ip_number::*;
 
         /// Struct flagging if a header needs to be written.
@@ -659,6 +770,7 @@ impl Ipv6Extensions {
             pub routing: bool,
             pub fragment: bool,
             pub auth: bool,
+            pub mobility: bool,
             pub final_destination_options: bool,
         }
 
@@ -668,6 +780,7 @@ impl Ipv6Extensions {
             routing: self.routing.is_some(),
             fragment: self.fragment.is_some(),
             auth: self.auth.is_some(),
+            mobility: self.mobility.is_some(),
             final_destination_options: if let Some(ref routing) = self.routing {
                 routing.final_destination_options.is_some()
             } else {
@@ -681,7 +794,7 @@ impl Ipv6Extensions {
         // check if hop by hop header should be written first
         if IPV6_HOP_BY_HOP == next_header {
             let header = &self.hop_by_hop_options.as_ref().unwrap();
-            header.write(writer).map_err(Io)?;
+            write(&header.to_bytes())?;
             next_header = header.next_header;
             needs_write.hop_by_hop_options = false;
         }
@@ -698,7 +811,7 @@ impl Ipv6Extensions {
                     // by hop if it is not part of this extensions struct.
                     if needs_write.hop_by_hop_options {
                         // the hop by hop header is only allowed at the start
-                        return Err(Content(HopByHopNotAtStart));
+                        return Err(content_err(HopByHopNotAtStart));
                     } else {
                         break;
                     }
@@ -715,7 +828,7 @@ impl Ipv6Extensions {
                                 .final_destination_options
                                 .as_ref()
                                 .unwrap();
-                            header.write(writer).map_err(Io)?;
+                            write(&header.to_bytes())?;
                             next_header = header.next_header;
                             needs_write.final_destination_options = false;
                         } else {
@@ -723,7 +836,7 @@ impl Ipv6Extensions {
                         }
                     } else if needs_write.destination_options {
                         let header = &self.destination_options.as_ref().unwrap();
-                        header.write(writer).map_err(Io)?;
+                        write(&header.to_bytes())?;
                         next_header = header.next_header;
                         needs_write.destination_options = false;
                     } else {
@@ -733,7 +846,7 @@ impl Ipv6Extensions {
                 IPV6_ROUTE => {
                     if needs_write.routing {
                         let header = &self.routing.as_ref().unwrap().routing;
-                        header.write(writer).map_err(Io)?;
+                        write(&header.to_bytes())?;
                         next_header = header.next_header;
                         needs_write.routing = false;
                         // for destination options
@@ -745,7 +858,7 @@ impl Ipv6Extensions {
                 IPV6_FRAG => {
                     if needs_write.fragment {
                         let header = &self.fragment.as_ref().unwrap();
-                        header.write(writer).map_err(Io)?;
+                        write(&header.to_bytes())?;
                         next_header = header.next_header;
                         needs_write.fragment = false;
                     } else {
@@ -755,13 +868,23 @@ impl Ipv6Extensions {
                 AUTH => {
                     if needs_write.auth {
                         let header = &self.auth.as_ref().unwrap();
-                        header.write(writer).map_err(Io)?;
+                        write(&header.to_bytes())?;
                         next_header = header.next_header;
                         needs_write.auth = false;
                     } else {
                         break;
                     }
                 }
+                MOBILITY => {
+                    if needs_write.mobility {
+                        let header = &self.mobility.as_ref().unwrap();
+                        write(&header.to_bytes())?;
+                        next_header = header.payload_proto;
+                        needs_write.mobility = false;
+                    } else {
+                        break;
+                    }
+                }
                 _ => {
                     // reached an unknown next_header id, proceed to check if everything was written
                     break;
@@ -771,27 +894,31 @@ impl Ipv6Extensions {
 
         // check that all header have been written
         if needs_write.hop_by_hop_options {
-            Err(Content(ExtNotReferenced {
+            Err(content_err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_HEADER_HOP_BY_HOP,
             }))
         } else if needs_write.destination_options {
-            Err(Content(ExtNotReferenced {
+            Err(content_err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_DESTINATION_OPTIONS,
             }))
         } else if needs_write.routing {
-            Err(Content(ExtNotReferenced {
+            Err(content_err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_ROUTE_HEADER,
             }))
         } else if needs_write.fragment {
-            Err(Content(ExtNotReferenced {
+            Err(content_err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_FRAGMENTATION_HEADER,
             }))
         } else if needs_write.auth {
-            Err(Content(ExtNotReferenced {
+            Err(content_err(ExtNotReferenced {
                 missing_ext: IpNumber::AUTHENTICATION_HEADER,
             }))
+        } else if needs_write.mobility {
+            Err(content_err(ExtNotReferenced {
+                missing_ext: IpNumber::MOBILITY_HEADER,
+            }))
         } else if needs_write.final_destination_options {
-            Err(Content(ExtNotReferenced {
+            Err(content_err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_DESTINATION_OPTIONS,
             }))
         } else {
@@ -821,6 +948,9 @@ impl Ipv6Extensions {
         if let Some(ref header) = self.auth {
             result += header.header_len();
         }
+        if let Some(ref header) = self.mobility {
+            result += header.header_len();
+        }
 
         result
     }
@@ -849,6 +979,12 @@ impl Ipv6Extensions {
         // * Destination Options header
         // * Upper-Layer header
         //
+        // The mobility header (RFC 6275) is placed last as it is
+        // normally not followed by an upper-layer header.
+        if let Some(ref mut header) = self.mobility {
+            header.payload_proto = next;
+            next = MOBILITY;
+        }
         if let Some(ref mut routing) = self.routing {
             if let Some(ref mut header) = routing.final_destination_options {
                 header.next_header = next;
@@ -892,6 +1028,7 @@ impl Ipv6Extensions {
             pub routing: bool,
             pub fragment: bool,
             pub auth: bool,
+            pub mobility: bool,
             pub final_destination_options: bool,
         }
 
@@ -901,6 +1038,7 @@ impl Ipv6Extensions {
             routing: self.routing.is_some(),
             fragment: self.fragment.is_some(),
             auth: self.auth.is_some(),
+            mobility: self.mobility.is_some(),
             final_destination_options: if let Some(ref routing) = self.routing {
                 routing.final_destination_options.is_some()
             } else {
@@ -990,6 +1128,15 @@ impl Ipv6Extensions {
                         break;
                     }
                 }
+                MOBILITY => {
+                    if outstanding_refs.mobility {
+                        let header = &self.mobility.as_ref().unwrap();
+                        next = header.payload_proto;
+                        outstanding_refs.mobility = false;
+                    } else {
+                        break;
+                    }
+                }
                 _ => break,
             }
         }
@@ -1020,6 +1167,11 @@ impl Ipv6Extensions {
                 missing_ext: IpNumber::AUTHENTICATION_HEADER,
             });
         }
+        if outstanding_refs.mobility {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::MOBILITY_HEADER,
+            });
+        }
         if outstanding_refs.final_destination_options {
             return Err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_DESTINATION_OPTIONS,
@@ -1054,6 +1206,7 @@ impl Ipv6Extensions {
             && self.routing.is_none()
             && self.fragment.is_none()
             && self.auth.is_none()
+            && self.mobility.is_none()
     }
 }
 
@@ -1064,12 +1217,13 @@ pub mod ipv6_exts_test_helpers {
     use alloc::vec::Vec;
 
     /// IP numbers that are assigned ipv6 header extensions.
-    pub const EXTENSION_KNOWN_IP_NUMBERS: [IpNumber; 5] = [
+    pub const EXTENSION_KNOWN_IP_NUMBERS: [IpNumber; 6] = [
         AUTH,
         IPV6_DEST_OPTIONS,
         IPV6_HOP_BY_HOP,
         IPV6_FRAG,
         IPV6_ROUTE,
+        MOBILITY,
     ];
 
     /// Helper struct that generates test data with dummy
@@ -1143,6 +1297,16 @@ pub mod ipv6_exts_test_helpers {
                     self.data.extend_from_slice(&raw[..len]);
                     self.lengths.push(len);
                 }
+                MOBILITY => {
+                    // binding refresh request (limited so the options
+                    // fit into a MobilityHeader)
+                    let mut raw: [u8; 0xff * 8 + 8] = [0; 0xff * 8 + 8];
+                    raw[0] = next_header.0;
+                    raw[1] = header_ext_len.min(0xfe);
+                    let len = (usize::from(raw[1]) + 1) * 8;
+                    self.data.extend_from_slice(&raw[..len]);
+                    self.lengths.push(len);
+                }
                 _ => unreachable!(),
             }
         }
@@ -1157,6 +1321,7 @@ pub mod ipv6_exts_test_helpers {
                 final_dest_opt: bool,
                 frag: bool,
                 auth: bool,
+                mobility: bool,
             }
 
             // state if a header type has already been read
@@ -1166,6 +1331,7 @@ pub mod ipv6_exts_test_helpers {
                 final_dest_opt: false,
                 frag: false,
                 auth: false,
+                mobility: false,
             };
 
             for i in 0..self.ip_numbers.len() {
@@ -1214,6 +1380,13 @@ pub mod ipv6_exts_test_helpers {
                             read.auth = true;
                         }
                     }
+                    MOBILITY => {
+                        if read.mobility {
+                            return false;
+                        } else {
+                            read.mobility = true;
+                        }
+                    }
                     _ => return false,
                 }
             }
@@ -1233,6 +1406,7 @@ pub mod ipv6_exts_test_helpers {
                 final_dest_opt: bool,
                 frag: bool,
                 auth: bool,
+                mobility: bool,
             }
 
             // state if a header type has already been read
@@ -1243,6 +1417,7 @@ pub mod ipv6_exts_test_helpers {
                 final_dest_opt: false,
                 frag: false,
                 auth: false,
+                mobility: false,
             };
 
             let mut slice = &self.data[..];
@@ -1329,6 +1504,18 @@ pub mod ipv6_exts_test_helpers {
                             last_decoded = Some(AUTH);
                         }
                     }
+                    MOBILITY => {
+                        if read.mobility {
+                            // duplicate header -> stop
+                            stop = true;
+                        } else {
+                            let (header, rest) = MobilityHeader::from_slice(slice).unwrap();
+                            assert_eq!(&header, exts.mobility.as_ref().unwrap());
+                            slice = rest;
+                            read.mobility = true;
+                            last_decoded = Some(MOBILITY);
+                        }
+                    }
                     _ => {
                         // non extension header -> stop
                         stop = true;
@@ -1367,6 +1554,9 @@ pub mod ipv6_exts_test_helpers {
             if false == read.auth {
                 assert!(exts.auth.is_none());
             }
+            if false == read.mobility {
+                assert!(exts.mobility.is_none());
+            }
 
             (self.data.len() - slice.len(), last_decoded, post_header)
         }
@@ -1455,6 +1645,16 @@ pub mod ipv6_exts_test_helpers {
                             slice = rest;
                         }
                     }
+                    MOBILITY => {
+                        if exts.mobility.is_some() {
+                            // duplicate header -> stop
+                            stop = true;
+                        } else {
+                            let (header, rest) = MobilityHeader::from_slice(slice).unwrap();
+                            exts.mobility = Some(header);
+                            slice = rest;
+                        }
+                    }
                     _ => {
                         // non extension header -> stop
                         stop = true;
@@ -1546,6 +1746,9 @@ pub mod ipv6_exts_test_helpers {
                     AUTH => {
                         self.data.auth.as_mut().unwrap().next_header = new_header;
                     }
+                    MOBILITY => {
+                        self.data.mobility.as_mut().unwrap().payload_proto = new_header;
+                    }
                     _ => unreachable!(),
                 }
                 match self.ip_numbers[self.ip_numbers.len() - 2] {
@@ -1554,6 +1757,7 @@ pub mod ipv6_exts_test_helpers {
                     IPV6_ROUTE => IpNumber::IPV6_ROUTE_HEADER,
                     IPV6_FRAG => IpNumber::IPV6_FRAGMENTATION_HEADER,
                     AUTH => IpNumber::AUTHENTICATION_HEADER,
+                    MOBILITY => IpNumber::MOBILITY_HEADER,
                     _ => unreachable!(),
                 }
             } else {
@@ -1566,6 +1770,7 @@ pub mod ipv6_exts_test_helpers {
                     IPV6_ROUTE => IpNumber::IPV6_ROUTE_HEADER,
                     IPV6_FRAG => IpNumber::IPV6_FRAGMENTATION_HEADER,
                     AUTH => IpNumber::AUTHENTICATION_HEADER,
+                    MOBILITY => IpNumber::MOBILITY_HEADER,
                     _ => unreachable!(),
                 }
             }
@@ -1655,6 +1860,25 @@ pub mod ipv6_exts_test_helpers {
                         false
                     }
                 }
+                MOBILITY => {
+                    if self.data.mobility.is_none() {
+                        // binding refresh request (limited so the options
+                        // fit into a MobilityHeader)
+                        let options = [0; 0xfe * 8];
+                        let len = usize::from(header_ext_len.min(0xfe)) * 8;
+                        self.data.mobility = Some(
+                            MobilityHeader::new(
+                                next_header,
+                                MobilityMessage::BindingRefreshRequest,
+                                &options[..len],
+                            )
+                            .unwrap(),
+                        );
+                        true
+                    } else {
+                        false
+                    }
+                }
                 _ => unreachable!(),
             }
         }
@@ -1729,6 +1953,7 @@ mod test {
                                 len_source: LenSource::Slice,
                                 layer: match last_header.unwrap() {
                                     AUTH => err::Layer::IpAuthHeader,
+                                    MOBILITY => err::Layer::Ipv6MobilityHeader,
                                     IPV6_FRAG => err::Layer::Ipv6FragHeader,
                                     _ => err::Layer::Ipv6ExtHeader
                                 },
@@ -1778,6 +2003,32 @@ mod test {
                 use err::ip_auth::HeaderError::ZeroPayloadLen;
                 assert_eq!(actual, Content(IpAuth(ZeroPayloadLen)));
             }
+
+            // test that the mobility content error gets forwarded
+            {
+                let mobility = MobilityHeader::new(
+                    post_header,
+                    MobilityMessage::BindingAcknowledgement {
+                        status: 0,
+                        key_management_compat: false,
+                        sequence_number: 1,
+                        lifetime: 2,
+                    },
+                    &[],
+                )
+                .unwrap();
+                let mut bytes = mobility.to_bytes();
+                // inject a header len too small for the message
+                bytes[1] = 0;
+                let actual = Ipv6Extensions::from_slice(MOBILITY, &bytes).unwrap_err();
+
+                use err::ipv6_exts::HeaderError::Mobility;
+                assert_eq!(actual, Content(Mobility(err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                    header_len: 8,
+                    min_header_len: 12,
+                })));
+            }
         }
     }
 
@@ -1840,6 +2091,7 @@ mod test {
                             len_err.layer,
                             match expected.3.unwrap() {
                                 AUTH => err::Layer::IpAuthHeader,
+                                MOBILITY => err::Layer::Ipv6MobilityHeader,
                                 IPV6_FRAG => err::Layer::Ipv6FragHeader,
                                 _ => err::Layer::Ipv6ExtHeader
                             }
@@ -1891,6 +2143,35 @@ mod test {
                 use err::ip_auth::HeaderError::ZeroPayloadLen;
                 assert_eq!(actual.3.unwrap(), (Content(IpAuth(ZeroPayloadLen)), Layer::IpAuthHeader));
             }
+
+            // test that the mobility content error gets forwarded
+            {
+                let mobility = MobilityHeader::new(
+                    post_header,
+                    MobilityMessage::BindingAcknowledgement {
+                        status: 0,
+                        key_management_compat: false,
+                        sequence_number: 1,
+                        lifetime: 2,
+                    },
+                    &[],
+                )
+                .unwrap();
+                let mut bytes = mobility.to_bytes();
+                // inject a header len too small for the message
+                bytes[1] = 0;
+                let actual = Ipv6Extensions::from_slice_lax(MOBILITY, &bytes);
+                assert_eq!(0, actual.0.header_len());
+                assert_eq!(MOBILITY, actual.1);
+                assert_eq!(&bytes[..], actual.2);
+
+                use err::ipv6_exts::HeaderError::Mobility;
+                assert_eq!(actual.3.unwrap(), (Content(Mobility(err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                    header_len: 8,
+                    min_header_len: 12,
+                })), Layer::Ipv6MobilityHeader));
+            }
         }
     }
 
@@ -1990,6 +2271,33 @@ mod test {
                 use err::ip_auth::HeaderError::ZeroPayloadLen;
                 assert_eq!(actual.content_error().unwrap(), IpAuth(ZeroPayloadLen));
             }
+
+            // test that the mobility content error gets forwarded
+            {
+                let mobility = MobilityHeader::new(
+                    post_header,
+                    MobilityMessage::BindingAcknowledgement {
+                        status: 0,
+                        key_management_compat: false,
+                        sequence_number: 1,
+                        lifetime: 2,
+                    },
+                    &[],
+                )
+                .unwrap();
+                let mut bytes = mobility.to_bytes();
+                // inject a header len too small for the message
+                bytes[1] = 0;
+                let mut cursor = Cursor::new(&bytes[..]);
+                let actual = Ipv6Extensions::read(&mut cursor, MOBILITY).unwrap_err();
+
+                use err::ipv6_exts::HeaderError::Mobility;
+                assert_eq!(actual.content_error().unwrap(), Mobility(err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                    header_len: 8,
+                    min_header_len: 12,
+                }));
+            }
         }
     }
 
@@ -2134,6 +2442,39 @@ mod test {
                 use err::ip_auth::HeaderError::ZeroPayloadLen;
                 assert_eq!(actual.content().unwrap(), IpAuth(ZeroPayloadLen));
             }
+
+            // test that the mobility content error gets forwarded
+            {
+                let mobility = MobilityHeader::new(
+                    post_header,
+                    MobilityMessage::BindingAcknowledgement {
+                        status: 0,
+                        key_management_compat: false,
+                        sequence_number: 1,
+                        lifetime: 2,
+                    },
+                    &[],
+                )
+                .unwrap();
+                let mut bytes = mobility.to_bytes();
+                // inject a header len too small for the message
+                bytes[1] = 0;
+                let mut reader = LimitedReader::new(
+                    Cursor::new(&bytes[..]),
+                    bytes.len(),
+                    LenSource::Slice,
+                    0,
+                    Layer::Ipv6Header
+                );
+                let actual = Ipv6Extensions::read_limited(&mut reader, MOBILITY).unwrap_err();
+
+                use err::ipv6_exts::HeaderError::Mobility;
+                assert_eq!(actual.content().unwrap(), Mobility(err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                    header_len: 8,
+                    min_header_len: 12,
+                }));
+            }
         }
     }
 
@@ -2286,6 +2627,7 @@ mod test {
                     ),
                     fragment: Some(fragment.clone()),
                     auth: Some(auth.clone()),
+                    mobility: None,
                 };
                 assert_eq!(
                     exts.header_len(),
@@ -2313,6 +2655,7 @@ mod test {
                     ),
                     fragment: Some(fragment.clone()),
                     auth: Some(auth.clone()),
+                    mobility: None,
                 };
                 assert_eq!(
                     exts.header_len(),
@@ -2366,6 +2709,7 @@ mod test {
                     ),
                     fragment: Some(fragment.clone()),
                     auth: Some(auth.clone()),
+                    mobility: None,
                 };
                 assert_eq!(IPV6_HOP_BY_HOP, exts.set_next_headers(post_header));
 
@@ -2473,6 +2817,7 @@ mod test {
                 routing: None,
                 fragment: None,
                 auth: None,
+                mobility: None,
             }
             .is_fragmenting_payload()
         );
@@ -2491,6 +2836,7 @@ mod test {
                     0
                 )),
                 auth: None,
+                mobility: None,
             }
             .is_fragmenting_payload()
         );
@@ -2507,6 +2853,7 @@ mod test {
                 0
             )),
             auth: None,
+            mobility: None,
         }
         .is_fragmenting_payload());
     }
@@ -2520,6 +2867,7 @@ mod test {
             routing: None,
             fragment: None,
             auth: None,
+            mobility: None,
         }
         .is_empty());
 
@@ -2534,6 +2882,7 @@ mod test {
                 routing: None,
                 fragment: None,
                 auth: None,
+                mobility: None,
             }
             .is_empty()
         );
@@ -2549,6 +2898,7 @@ mod test {
                 routing: None,
                 fragment: None,
                 auth: None,
+                mobility: None,
             }
             .is_empty()
         );
@@ -2566,6 +2916,7 @@ mod test {
                 }),
                 fragment: None,
                 auth: None,
+                mobility: None,
             }
             .is_empty()
         );
@@ -2584,6 +2935,7 @@ mod test {
                     0
                 )),
                 auth: None,
+                mobility: None,
             }
             .is_empty()
         );
@@ -2597,9 +2949,31 @@ mod test {
                 routing: None,
                 fragment: None,
                 auth: Some(IpAuthHeader::new(ip_number::UDP, 0, 0, &[]).unwrap()),
+                mobility: None,
             }
             .is_empty()
         );
+
+        // mobility
+        assert!(
+            false
+                == Ipv6Extensions {
+                    hop_by_hop_options: None,
+                    destination_options: None,
+                    routing: None,
+                    fragment: None,
+                    auth: None,
+                    mobility: Some(
+                        MobilityHeader::new(
+                            ip_number::IPV6_NO_NEXT_HEADER,
+                            MobilityMessage::BindingRefreshRequest,
+                            &[]
+                        )
+                        .unwrap()
+                    ),
+                }
+                .is_empty()
+        );
     }
 
     #[test]
@@ -2609,12 +2983,13 @@ mod test {
         let a: Ipv6Extensions = Default::default();
         assert_eq!(
             &format!(
-                "Ipv6Extensions {{ hop_by_hop_options: {:?}, destination_options: {:?}, routing: {:?}, fragment: {:?}, auth: {:?} }}",
+                "Ipv6Extensions {{ hop_by_hop_options: {:?}, destination_options: {:?}, routing: {:?}, fragment: {:?}, auth: {:?}, mobility: {:?} }}",
                 a.hop_by_hop_options,
                 a.destination_options,
                 a.routing,
                 a.fragment,
                 a.auth,
+                a.mobility,
             ),
             &format!("{:?}", a)
         );
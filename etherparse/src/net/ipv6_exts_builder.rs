@@ -0,0 +1,263 @@
+use crate::{err::ipv6_exts::ExtsBuildError, *};
+
+/// Builder for [`Ipv6Extensions`] that checks that the extension headers
+/// are added in the order recommended by RFC 8200 & sets the `next_header`
+/// fields of all headers (created via [`Ipv6Extensions::builder`]).
+///
+/// The headers have to be added in the following order:
+///
+/// * Hop-by-Hop Options header
+/// * Destination Options header
+/// * Routing header
+/// * Fragment header
+/// * Authentication header
+/// * Destination Options header (after routing, only allowed if a routing header is present)
+///
+/// ```
+/// use etherparse::{
+///     ip_number::{IPV6_HOP_BY_HOP, UDP},
+///     IpAuthHeader, Ipv6Extensions, Ipv6FragmentHeader, Ipv6RawExtHeader, IpFragOffset,
+/// };
+///
+/// let (exts, first_next_header) = Ipv6Extensions::builder()
+///     .hop_by_hop(Ipv6RawExtHeader::new_raw(UDP, &[1, 2, 3, 4, 5, 6]).unwrap())
+///     .fragment(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, true, 1234))
+///     .auth(IpAuthHeader::new(UDP, 1, 2, &[]).unwrap())
+///     .build(UDP)
+///     .unwrap();
+///
+/// // value that has to be set as "next_header" in the IPv6 header
+/// assert_eq!(IPV6_HOP_BY_HOP, first_next_header);
+/// assert_eq!(UDP, exts.auth.unwrap().next_header);
+///
+/// // headers in the wrong order trigger an error
+/// use etherparse::err::ipv6_exts::ExtsBuildError;
+/// assert_eq!(
+///     Ipv6Extensions::builder()
+///         .auth(IpAuthHeader::new(UDP, 1, 2, &[]).unwrap())
+///         .fragment(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, true, 1234))
+///         .build(UDP),
+///     Err(ExtsBuildError::ExtOutOfOrder{
+///         ext: etherparse::ip_number::IPV6_FRAG,
+///         previous_ext: etherparse::ip_number::AUTH,
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct Ipv6ExtensionsBuilder {
+    exts: Ipv6Extensions,
+    /// Position (in the RFC 8200 order) & ip number of the last added header.
+    last: Option<(u8, IpNumber)>,
+    /// Final destination options header (stored separately till the
+    /// presence of a routing header can be checked in `build`).
+    final_destination_options: Option<Ipv6RawExtHeader>,
+    /// First error that was encountered.
+    error: Option<ExtsBuildError>,
+}
+
+impl Ipv6ExtensionsBuilder {
+    /// Creates a new builder without any extension headers.
+    #[inline]
+    pub fn new() -> Ipv6ExtensionsBuilder {
+        Default::default()
+    }
+
+    /// Adds a hop-by-hop options header.
+    pub fn hop_by_hop(mut self, header: Ipv6RawExtHeader) -> Ipv6ExtensionsBuilder {
+        if self.check_order(0, ip_number::IPV6_HOP_BY_HOP) {
+            self.exts.hop_by_hop_options = Some(header);
+        }
+        self
+    }
+
+    /// Adds a destination options header (placed before the routing header).
+    pub fn destination_options(mut self, header: Ipv6RawExtHeader) -> Ipv6ExtensionsBuilder {
+        if self.check_order(1, ip_number::IPV6_DEST_OPTIONS) {
+            self.exts.destination_options = Some(header);
+        }
+        self
+    }
+
+    /// Adds a routing header.
+    pub fn routing(mut self, header: Ipv6RawExtHeader) -> Ipv6ExtensionsBuilder {
+        if self.check_order(2, ip_number::IPV6_ROUTE) {
+            self.exts.routing = Some(Ipv6RoutingExtensions {
+                routing: header,
+                final_destination_options: None,
+            });
+        }
+        self
+    }
+
+    /// Adds a fragment header.
+    pub fn fragment(mut self, header: Ipv6FragmentHeader) -> Ipv6ExtensionsBuilder {
+        if self.check_order(3, ip_number::IPV6_FRAG) {
+            self.exts.fragment = Some(header);
+        }
+        self
+    }
+
+    /// Adds an authentication header.
+    pub fn auth(mut self, header: IpAuthHeader) -> Ipv6ExtensionsBuilder {
+        if self.check_order(4, ip_number::AUTH) {
+            self.exts.auth = Some(header);
+        }
+        self
+    }
+
+    /// Adds a destination options header that is placed after the routing
+    /// header (requires a routing header to be present).
+    pub fn final_destination_options(mut self, header: Ipv6RawExtHeader) -> Ipv6ExtensionsBuilder {
+        if self.check_order(5, ip_number::IPV6_DEST_OPTIONS) {
+            self.final_destination_options = Some(header);
+        }
+        self
+    }
+
+    /// Checks that the ordering and sets the error if the order is violated.
+    /// Returns true if the header can be added.
+    fn check_order(&mut self, position: u8, ext: IpNumber) -> bool {
+        if self.error.is_some() {
+            return false;
+        }
+        if let Some((last_position, previous_ext)) = self.last {
+            if last_position == position {
+                self.error = Some(ExtsBuildError::ExtDuplicate { ext });
+                return false;
+            }
+            if last_position > position {
+                self.error = Some(ExtsBuildError::ExtOutOfOrder { ext, previous_ext });
+                return false;
+            }
+        }
+        self.last = Some((position, ext));
+        true
+    }
+
+    /// Returns the extension headers with all `next_header` fields set
+    /// & the ip number that has to be set as `next_header` in the IPv6
+    /// header (`last_next_header` if no extension headers were added).
+    ///
+    /// `last_next_header` is the ip number of the payload following the
+    /// extension headers.
+    pub fn build(
+        self,
+        last_next_header: IpNumber,
+    ) -> Result<(Ipv6Extensions, IpNumber), ExtsBuildError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let mut exts = self.exts;
+        if self.final_destination_options.is_some() {
+            match exts.routing.as_mut() {
+                Some(routing) => routing.final_destination_options = self.final_destination_options,
+                None => return Err(ExtsBuildError::FinalDestOptionsWithoutRouting),
+            }
+        }
+        let first_next_header = exts.set_next_headers(last_next_header);
+        Ok((exts, first_next_header))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ip_number::*, test_gens::*};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn build(
+            hop_by_hop in ipv6_raw_ext_any(),
+            destination_options in ipv6_raw_ext_any(),
+            routing in ipv6_raw_ext_any(),
+            fragment in ipv6_fragment_any(),
+            auth in ip_auth_any(),
+            final_destination_options in ipv6_raw_ext_any(),
+        ) {
+            // empty
+            assert_eq!(
+                Ok((Ipv6Extensions::default(), UDP)),
+                Ipv6ExtensionsBuilder::new().build(UDP)
+            );
+
+            // all headers in the correct order
+            {
+                let mut expected = Ipv6Extensions {
+                    hop_by_hop_options: Some(hop_by_hop.clone()),
+                    destination_options: Some(destination_options.clone()),
+                    routing: Some(Ipv6RoutingExtensions {
+                        routing: routing.clone(),
+                        final_destination_options: Some(final_destination_options.clone()),
+                    }),
+                    fragment: Some(fragment.clone()),
+                    auth: Some(auth.clone()),
+                    mobility: None,
+                };
+                let expected_next = expected.set_next_headers(TCP);
+                assert_eq!(
+                    Ok((expected, expected_next)),
+                    Ipv6Extensions::builder()
+                        .hop_by_hop(hop_by_hop.clone())
+                        .destination_options(destination_options.clone())
+                        .routing(routing.clone())
+                        .fragment(fragment.clone())
+                        .auth(auth.clone())
+                        .final_destination_options(final_destination_options.clone())
+                        .build(TCP)
+                );
+            }
+
+            // single headers
+            {
+                let (exts, next) = Ipv6Extensions::builder()
+                    .fragment(fragment.clone())
+                    .build(UDP)
+                    .unwrap();
+                assert_eq!(IPV6_FRAG, next);
+                assert_eq!(
+                    Ipv6Extensions {
+                        fragment: Some(Ipv6FragmentHeader{ next_header: UDP, ..fragment.clone() }),
+                        ..Default::default()
+                    },
+                    exts
+                );
+            }
+
+            // out of order
+            assert_eq!(
+                Err(ExtsBuildError::ExtOutOfOrder { ext: IPV6_HOP_BY_HOP, previous_ext: IPV6_ROUTE }),
+                Ipv6Extensions::builder()
+                    .routing(routing.clone())
+                    .hop_by_hop(hop_by_hop.clone())
+                    .build(UDP)
+            );
+            assert_eq!(
+                Err(ExtsBuildError::ExtOutOfOrder { ext: IPV6_DEST_OPTIONS, previous_ext: AUTH }),
+                Ipv6Extensions::builder()
+                    .auth(auth.clone())
+                    .destination_options(destination_options.clone())
+                    .build(UDP)
+            );
+
+            // first error is kept
+            assert_eq!(
+                Err(ExtsBuildError::ExtDuplicate { ext: IPV6_FRAG }),
+                Ipv6Extensions::builder()
+                    .fragment(fragment.clone())
+                    .fragment(fragment.clone())
+                    .hop_by_hop(hop_by_hop.clone())
+                    .build(UDP)
+            );
+
+            // final destination options without routing
+            assert_eq!(
+                Err(ExtsBuildError::FinalDestOptionsWithoutRouting),
+                Ipv6Extensions::builder()
+                    .auth(auth.clone())
+                    .final_destination_options(final_destination_options.clone())
+                    .build(UDP)
+            );
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// Slice containing the IPv6 extension headers present after the ip header.
 ///
@@ -10,12 +9,13 @@ use core::slice::from_raw_parts;
 /// * Routing Header
 /// * Fragment
 /// * Authentication Header
+/// * Mobility Header
 ///
 /// Currently not supported:
 /// * Encapsulating Security Payload Header (ESP)
-/// * Host Identity Protocol (HIP)
-/// * IP Mobility
-/// * Site Multihoming by IPv6 Intermediation (SHIM6)
+/// * Host Identity Protocol (HIP) (decoded separately via [`HipHeaderSlice`])
+/// * Site Multihoming by IPv6 Intermediation (SHIM6) (decoded separately
+///   via [`Shim6HeaderSlice`])
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ipv6ExtensionsSlice<'a> {
     /// IP protocol number of the first header present in the slice.
@@ -60,9 +60,9 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     // Ipv6RawExtHeaderSlice::from_slice always generates
                     // a subslice from the given slice rest. Therefor it is guaranteed
                     // that len is always greater or equal the len of rest.
-                    rest = unsafe {
+                    rest = unchecked! {
                         let len = slice.slice().len();
-                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                        get_unchecked_slice(rest, len, rest.len() - len)
                     };
                     next_header = slice.next_header();
                 }
@@ -73,9 +73,9 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     // Ipv6FragmentHeaderSlice::from_slice always generates
                     // a subslice from the given slice rest. Therefor it is guaranteed
                     // that len is always greater or equal the len of rest.
-                    rest = unsafe {
+                    rest = unchecked! {
                         let len = slice.slice().len();
-                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                        get_unchecked_slice(rest, len, rest.len() - len)
                     };
                     next_header = slice.next_header();
 
@@ -94,12 +94,30 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     // IpAuthHeaderSlice::from_slice always generates
                     // a subslice from the given slice rest. Therefor it is guaranteed
                     // that len is always greater or equal the len of rest.
-                    rest = unsafe {
+                    rest = unchecked! {
                         let len = slice.slice().len();
-                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                        get_unchecked_slice(rest, len, rest.len() - len)
                     };
                     next_header = slice.next_header();
                 }
+                MOBILITY => {
+                    let slice = MobilityHeaderSlice::from_slice(rest).map_err(|err| {
+                        use err::ipv6_mobility::HeaderSliceError as I;
+                        match err {
+                            I::Len(err) => Len(err.add_offset(start_slice.len() - rest.len())),
+                            I::Content(err) => Content(Mobility(err)),
+                        }
+                    })?;
+                    // SAFETY:
+                    // MobilityHeaderSlice::from_slice always generates
+                    // a subslice from the given slice rest. Therefor it is guaranteed
+                    // that len is always greater or equal the len of rest.
+                    rest = unchecked! {
+                        let len = slice.slice().len();
+                        get_unchecked_slice(rest, len, rest.len() - len)
+                    };
+                    next_header = slice.payload_proto();
+                }
                 // done parsing, the next header is not a known/supported header extension
                 _ => break,
             }
@@ -186,9 +204,9 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     // Ipv6RawExtHeaderSlice::from_slice always generates
                     // a subslice from the given slice rest. Therefor it is guranteed
                     // that len is always greater or equal the len of rest.
-                    rest = unsafe {
+                    rest = unchecked! {
                         let len = slice.slice().len();
-                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                        get_unchecked_slice(rest, len, rest.len() - len)
                     };
                     next_header = slice.next_header();
                 }
@@ -208,9 +226,9 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     // Ipv6FragmentHeaderSlice::from_slice always generates
                     // a subslice from the given slice rest. Therefor it is guranteed
                     // that len is always greater or equal the len of rest.
-                    rest = unsafe {
+                    rest = unchecked! {
                         let len = slice.slice().len();
-                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                        get_unchecked_slice(rest, len, rest.len() - len)
                     };
                     next_header = slice.next_header();
 
@@ -238,12 +256,39 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     // IpAuthHeaderSlice::from_slice always generates
                     // a subslice from the given slice rest. Therefor it is guranteed
                     // that len is always greater or equal the len of rest.
-                    rest = unsafe {
+                    rest = unchecked! {
                         let len = slice.slice().len();
-                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                        get_unchecked_slice(rest, len, rest.len() - len)
                     };
                     next_header = slice.next_header();
                 }
+                MOBILITY => {
+                    use err::ipv6_mobility::HeaderSliceError as I;
+                    let slice = match MobilityHeaderSlice::from_slice(rest) {
+                        Ok(s) => s,
+                        Err(err) => {
+                            error = Some((
+                                match err {
+                                    I::Len(err) => {
+                                        Len(err.add_offset(start_slice.len() - rest.len()))
+                                    }
+                                    I::Content(err) => Content(Mobility(err)),
+                                },
+                                err::Layer::Ipv6MobilityHeader,
+                            ));
+                            break;
+                        }
+                    };
+                    // SAFETY:
+                    // MobilityHeaderSlice::from_slice always generates
+                    // a subslice from the given slice rest. Therefor it is guranteed
+                    // that len is always greater or equal the len of rest.
+                    rest = unchecked! {
+                        let len = slice.slice().len();
+                        get_unchecked_slice(rest, len, rest.len() - len)
+                    };
+                    next_header = slice.payload_proto();
+                }
                 // done parsing, the next header is not a known/supported header extension
                 _ => break,
             }
@@ -380,6 +425,7 @@ mod test {
                                 len_source: LenSource::Slice,
                                 layer: match ip_numbers[ip_numbers.len() - 2] {
                                     AUTH => err::Layer::IpAuthHeader,
+                                    MOBILITY => err::Layer::Ipv6MobilityHeader,
                                     IPV6_FRAG => err::Layer::Ipv6FragHeader,
                                     _ => err::Layer::Ipv6ExtHeader
                                 },
@@ -480,6 +526,7 @@ mod test {
                                 len_source: LenSource::Slice,
                                 layer: match ip_numbers[ip_numbers.len() - 2] {
                                     AUTH => err::Layer::IpAuthHeader,
+                                    MOBILITY => err::Layer::Ipv6MobilityHeader,
                                     IPV6_FRAG => err::Layer::Ipv6FragHeader,
                                     _ => err::Layer::Ipv6ExtHeader
                                 },
@@ -532,6 +579,35 @@ mod test {
                 assert_eq!(actual.2, &bytes[..]);
                 assert_eq!(actual.3.unwrap().0.content().unwrap(), &IpAuth(ZeroPayloadLen));
             }
+
+            // test that the mobility content error gets forwarded
+            {
+                let mobility = MobilityHeader::new(
+                    post_header,
+                    MobilityMessage::BindingAcknowledgement {
+                        status: 0,
+                        key_management_compat: false,
+                        sequence_number: 1,
+                        lifetime: 2,
+                    },
+                    &[],
+                )
+                .unwrap();
+                let mut bytes = mobility.to_bytes();
+                // inject a header len too small for the message
+                bytes[1] = 0;
+                let actual = Ipv6ExtensionsSlice::from_slice_lax(MOBILITY, &bytes);
+
+                use err::ipv6_exts::HeaderError::Mobility;
+                assert_eq!(actual.0.slice(), &[]);
+                assert_eq!(actual.1, MOBILITY);
+                assert_eq!(actual.2, &bytes[..]);
+                assert_eq!(actual.3.unwrap().0.content().unwrap(), &Mobility(err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                    header_len: 8,
+                    min_header_len: 12,
+                }));
+            }
         }
     }
 
@@ -557,6 +633,7 @@ mod test {
                     ),
                     fragment: None,
                     auth: Some(auth),
+                    mobility: None,
                 };
                 let first_ip_number = exts.set_next_headers(UDP);
 
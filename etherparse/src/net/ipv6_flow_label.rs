@@ -106,9 +106,19 @@ impl Ipv6FlowLabel {
     /// otherwise the behavior of functions or data structures relying
     /// on this pre-requirement is undefined.
     #[inline]
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub const unsafe fn new_unchecked(value: u32) -> Ipv6FlowLabel {
-        debug_assert!(value <= Ipv6FlowLabel::MAX_U32);
-        Ipv6FlowLabel(value)
+        unchecked! { Ipv6FlowLabel::new_unchecked_internal(value) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`Ipv6FlowLabel::new_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        #[inline]
+        pub(crate) const fn new_unchecked_internal(value: u32) -> Ipv6FlowLabel {
+            debug_assert!(value <= Ipv6FlowLabel::MAX_U32);
+            Ipv6FlowLabel(value)
+        }
     }
 
     /// Returns the underlying unsigned 20 bit value as an `u32` value.
@@ -151,6 +161,7 @@ impl TryFrom<u32> for Ipv6FlowLabel {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use core::hash::{Hash, Hasher};
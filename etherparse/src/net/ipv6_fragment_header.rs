@@ -20,6 +20,14 @@ impl Ipv6FragmentHeader {
     /// Length of the serialized header.
     pub const LEN: usize = 8;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`Ipv6FragmentHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = Ipv6FragmentHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`Ipv6FragmentHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = Ipv6FragmentHeader::LEN;
+
     /// Create a new fragmentation header with the given parameters.
     ///
     /// Note that the `fragment_offset` can only support values between 0 and 0x1fff (inclusive).
@@ -59,10 +67,10 @@ impl Ipv6FragmentHeader {
 
         Ok(Ipv6FragmentHeader {
             next_header: IpNumber(buffer[0]),
-            fragment_offset: unsafe {
+            fragment_offset: unchecked! {
                 // SAFE as the resulting number is guaranteed to have at most
                 // 13 bits.
-                IpFragOffset::new_unchecked(u16::from_be_bytes([
+                IpFragOffset::new_unchecked_internal(u16::from_be_bytes([
                     (buffer[2] >> 3) & 0b0001_1111u8,
                     ((buffer[2] << 5) & 0b1110_0000u8) | (buffer[3] & 0b0001_1111u8),
                 ]))
@@ -91,10 +99,10 @@ impl Ipv6FragmentHeader {
 
         Ok(Ipv6FragmentHeader {
             next_header: IpNumber(buffer[0]),
-            fragment_offset: unsafe {
+            fragment_offset: unchecked! {
                 // SAFE as the resulting number is guaranteed to have at most
                 // 13 bits.
-                IpFragOffset::new_unchecked(u16::from_be_bytes([
+                IpFragOffset::new_unchecked_internal(u16::from_be_bytes([
                     (buffer[2] >> 3) & 0b0001_1111u8,
                     ((buffer[2] << 5) & 0b1110_0000u8) | (buffer[3] & 0b0001_1111u8),
                 ]))
@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// Slice containing an IPv6 fragment header.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,7 +24,7 @@ impl<'a> Ipv6FragmentHeaderSlice<'a> {
                 // SAFETY:
                 // Safe as slice length is checked to be at least 8 before this
                 // code can be reached.
-                slice: unsafe { from_raw_parts(slice.as_ptr(), 8) },
+                slice: unchecked! { get_unchecked_slice(slice, 0, 8) },
             })
         }
     }
@@ -37,11 +36,20 @@ impl<'a> Ipv6FragmentHeaderSlice<'a> {
     /// This function assumes that the passed slice has at least the length
     /// of 8. If a slice with length less then 8 is passed to this function
     /// the behavior will be undefined.
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> Ipv6FragmentHeaderSlice<'a> {
-        debug_assert!(slice.len() >= Ipv6FragmentHeader::LEN);
-        // the fragmentation header has the exact size of 8 bytes
-        Ipv6FragmentHeaderSlice {
-            slice: from_raw_parts(slice.as_ptr(), Ipv6FragmentHeader::LEN),
+        unchecked! { Ipv6FragmentHeaderSlice::from_slice_unchecked_internal(slice) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`Ipv6FragmentHeaderSlice::from_slice_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        pub(crate) fn from_slice_unchecked_internal(slice: &'a [u8]) -> Ipv6FragmentHeaderSlice<'a> {
+            debug_assert!(slice.len() >= Ipv6FragmentHeader::LEN);
+            // the fragmentation header has the exact size of 8 bytes
+            Ipv6FragmentHeaderSlice {
+                slice: get_unchecked_slice(slice, 0, Ipv6FragmentHeader::LEN),
+            }
         }
     }
 
@@ -58,21 +66,21 @@ impl<'a> Ipv6FragmentHeaderSlice<'a> {
     pub fn next_header(&self) -> IpNumber {
         // SAFETY:
         // Slice size checked to be at least 8 bytes in constructor.
-        IpNumber(unsafe { *self.slice.get_unchecked(0) })
+        IpNumber(unchecked! { get_unchecked_u8(self.slice, 0) })
     }
 
     /// Fragment offset
     #[inline]
     pub fn fragment_offset(&self) -> IpFragOffset {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as the resulting number is guaranteed to be only
             // 13 bit long.
-            IpFragOffset::new_unchecked(u16::from_be_bytes([
+            IpFragOffset::new_unchecked_internal(u16::from_be_bytes([
                 // SAFETY:
                 // Slice size checked to be at least 8 bytes in constructor.
-                (*self.slice.get_unchecked(2) >> 3) & 0b0001_1111u8,
-                ((*self.slice.get_unchecked(2) << 5) & 0b1110_0000u8)
-                    | (*self.slice.get_unchecked(3) & 0b0001_1111u8),
+                (get_unchecked_u8(self.slice, 2) >> 3) & 0b0001_1111u8,
+                ((get_unchecked_u8(self.slice, 2) << 5) & 0b1110_0000u8)
+                    | (get_unchecked_u8(self.slice, 3) & 0b0001_1111u8),
             ]))
         }
     }
@@ -82,14 +90,14 @@ impl<'a> Ipv6FragmentHeaderSlice<'a> {
     pub fn more_fragments(&self) -> bool {
         // SAFETY:
         // Slice size checked to be at least 8 bytes in constructor.
-        unsafe { 0 != *self.slice.get_unchecked(3) & 0b1000_0000u8 }
+        unchecked! { 0 != get_unchecked_u8(self.slice, 3) & 0b1000_0000u8 }
     }
 
     /// Identifcation value generated by the source
     pub fn identification(&self) -> u32 {
         // SAFETY:
         // Slice size checked to be at least 8 bytes in constructor.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 4) }
     }
 
     /// Checks if the fragment header actually fragments the packet.
@@ -144,8 +152,8 @@ impl<'a> Ipv6FragmentHeaderSlice<'a> {
     pub fn is_fragmenting_payload(&self) -> bool {
         // SAFETY:
         // Slice size checked to be at least 8 bytes in constructor.
-        unsafe {
-            0 != *self.slice.get_unchecked(2) || 0 != (*self.slice.get_unchecked(3) & 0b1001_1111u8)
+        unchecked! {
+            0 != get_unchecked_u8(self.slice, 2) || 0 != (get_unchecked_u8(self.slice, 3) & 0b1001_1111u8)
             // exclude the reserved bytes
         }
     }
@@ -163,6 +171,7 @@ impl<'a> Ipv6FragmentHeaderSlice<'a> {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{test_gens::*, *};
     use alloc::{format, vec::Vec};
@@ -24,6 +24,14 @@ impl Ipv6Header {
     /// Serialized size of an IPv6 header in bytes/octets.
     pub const LEN: usize = 40;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`Ipv6Header::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = Ipv6Header::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`Ipv6Header::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = Ipv6Header::LEN;
+
     #[deprecated(since = "0.14.0", note = "Use `Ipv6Header::LEN` instead")]
     pub const SERIALIZED_SIZE: usize = Ipv6Header::LEN;
 
@@ -81,6 +89,33 @@ impl Ipv6Header {
         Ipv6Header::read_without_version(reader, value[0] & 0xf).map_err(Io)
     }
 
+    /// Reads an IPv6 header from the current position of the async reader
+    /// (requires crate feature `tokio`).
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<Ipv6Header, err::ipv6::HeaderReadError> {
+        use err::ipv6::{HeaderError::*, HeaderReadError::*};
+
+        let mut buffer = [0u8; Ipv6Header::LEN];
+        async_read_exact(reader, &mut buffer[..1])
+            .await
+            .map_err(Io)?;
+        let version_number = buffer[0] >> 4;
+        if 6 != version_number {
+            return Err(Content(UnexpectedVersion { version_number }));
+        }
+        async_read_exact(reader, &mut buffer[1..])
+            .await
+            .map_err(Io)?;
+        Ok(
+            // SAFETY: Safe as the buffer has the exact size of an IPv6
+            // header & the version number was verified above.
+            unchecked! { Ipv6HeaderSlice::from_slice_unchecked(&buffer) }.to_header(),
+        )
+    }
+
     ///Reads an IPv6 header assuming the version & flow_label field have already been read.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -93,10 +128,10 @@ impl Ipv6Header {
 
         Ok(Ipv6Header {
             traffic_class: (version_rest << 4) | (buffer[0] >> 4),
-            flow_label: unsafe {
+            flow_label: unchecked! {
                 // SAFETY: Safe as the bitmask & 0 contant guarantee that the value
                 // does not exceed 20 bytes.
-                Ipv6FlowLabel::new_unchecked(u32::from_be_bytes([
+                Ipv6FlowLabel::new_unchecked_internal(u32::from_be_bytes([
                     0,
                     buffer[0] & 0b0000_1111,
                     buffer[1],
@@ -304,6 +339,54 @@ impl Ipv6Header {
         std::net::Ipv6Addr::from(self.destination)
     }
 
+    /// Returns the classification of the destination IPv6 address into
+    /// unicast, multicast or broadcast (see [`CastType::from_ipv6_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_ipv6_addr(&self.destination)
+    }
+
+    /// Returns the "Differentiated Services Code Point" (upper 6 bits
+    /// of the traffic class).
+    #[inline]
+    pub fn dscp(&self) -> Ipv4Dscp {
+        // SAFETY: Safe as the shifted value can never exceed 6 bits.
+        unchecked! { Ipv4Dscp::new_unchecked_internal(self.traffic_class >> 2) }
+    }
+
+    /// Returns the "Explicit Congestion Notification" (lower 2 bits
+    /// of the traffic class).
+    #[inline]
+    pub fn ecn(&self) -> Ipv4Ecn {
+        // SAFETY: Safe as the masked value can never exceed 2 bits.
+        unchecked! { Ipv4Ecn::new_unchecked_internal(self.traffic_class & 0b0000_0011) }
+    }
+
+    /// Sets the "Differentiated Services Code Point" (upper 6 bits
+    /// of the traffic class) and keeps the ECN bits unchanged.
+    ///
+    /// ```
+    /// use etherparse::{Ipv4Dscp, Ipv4Ecn, Ipv6Header};
+    ///
+    /// let mut header = Ipv6Header::default();
+    /// header.set_dscp(Ipv4Dscp::EF);
+    /// header.set_ecn(Ipv4Ecn::ECT_0);
+    /// assert_eq!(Ipv4Dscp::EF, header.dscp());
+    /// assert_eq!(Ipv4Ecn::ECT_0, header.ecn());
+    /// assert_eq!((46 << 2) | 2, header.traffic_class);
+    /// ```
+    #[inline]
+    pub fn set_dscp(&mut self, dscp: Ipv4Dscp) {
+        self.traffic_class = (dscp.value() << 2) | (self.traffic_class & 0b0000_0011);
+    }
+
+    /// Sets the "Explicit Congestion Notification" (lower 2 bits
+    /// of the traffic class) and keeps the DSCP bits unchanged.
+    #[inline]
+    pub fn set_ecn(&mut self, ecn: Ipv4Ecn) {
+        self.traffic_class = (self.traffic_class & 0b1111_1100) | ecn.value();
+    }
+
     /// Length of the serialized header in bytes.
     ///
     /// The function always returns the constant Ipv6Header::LEN
@@ -359,6 +442,23 @@ impl Ipv6Header {
     }
 }
 
+/// Writes the header in the format `source -> destination next=<ip number>
+/// hop_limit=<hop limit> len=<payload length>`, e.g.
+/// `2001:db8::1 -> 2001:db8::2 next=17 hop_limit=64 len=8`.
+impl core::fmt::Display for Ipv6Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} -> {} next={} hop_limit={} len={}",
+            core::net::Ipv6Addr::from(self.source),
+            core::net::Ipv6Addr::from(self.destination),
+            self.next_header.0,
+            self.hop_limit,
+            self.payload_length
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -499,6 +599,47 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            header in ipv6_any(),
+            bad_version in 0..=0b1111u8,
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            // compare the async read with the sync read for all
+            // prefixes of the given data
+            let check = |data: &[u8]| {
+                for len in 0..=data.len() {
+                    let mut cursor = Cursor::new(&data[..len]);
+                    let expected = Ipv6Header::read(&mut cursor);
+                    let mut reader = &data[..len];
+                    let actual = rt.block_on(Ipv6Header::async_read(&mut reader));
+                    match (expected, actual) {
+                        (Ok(expected), Ok(actual)) => {
+                            assert_eq!(expected, actual);
+                            assert_eq!(cursor.position() as usize, len - reader.len());
+                        }
+                        (Err(err::ipv6::HeaderReadError::Io(_)), Err(err::ipv6::HeaderReadError::Io(_))) => {}
+                        (Err(err::ipv6::HeaderReadError::Content(expected)), Err(err::ipv6::HeaderReadError::Content(actual))) => {
+                            assert_eq!(expected, actual)
+                        }
+                        (expected, actual) => panic!("{:?} != {:?}", expected, actual),
+                    }
+                }
+            };
+
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.push(1);
+            check(&buffer);
+
+            // bad version
+            buffer[0] = (bad_version << 4) | (buffer[0] & 0xf);
+            check(&buffer);
+        }
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -1013,6 +1154,29 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn dscp_ecn(
+            header in ipv6_any(),
+            dscp in 0..=Ipv4Dscp::MAX_U8,
+            ecn in 0..=Ipv4Ecn::MAX_U8
+        ) {
+            assert_eq!(header.dscp().value(), header.traffic_class >> 2);
+            assert_eq!(header.ecn().value(), header.traffic_class & 0b11);
+
+            let dscp = Ipv4Dscp::try_new(dscp).unwrap();
+            let ecn = Ipv4Ecn::try_new(ecn).unwrap();
+            let mut actual = header.clone();
+            actual.set_dscp(dscp);
+            assert_eq!(actual.dscp(), dscp);
+            assert_eq!(actual.ecn(), header.ecn());
+            actual.set_ecn(ecn);
+            assert_eq!(actual.dscp(), dscp);
+            assert_eq!(actual.ecn(), ecn);
+            assert_eq!(actual.traffic_class, (dscp.value() << 2) | ecn.value());
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes(header in ipv6_any()) {
@@ -1023,4 +1187,20 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn display() {
+        let header = Ipv6Header {
+            payload_length: 8,
+            next_header: ip_number::UDP,
+            hop_limit: 64,
+            source: [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            destination: [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2],
+            ..Default::default()
+        };
+        assert_eq!(
+            "2001:db8::1 -> 2001:db8::2 next=17 hop_limit=64 len=8",
+            format!("{}", header)
+        );
+    }
 }
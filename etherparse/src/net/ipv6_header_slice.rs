@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// A slice containing an ipv6 header of a network package.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -28,7 +27,7 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // SAFETY:
         // This is safe as the slice len is checked to be
         // at least 40 bytes at the start of the function.
-        let version_number = unsafe { slice.get_unchecked(0) >> 4 };
+        let version_number = unchecked! { get_unchecked_u8(slice, 0) >> 4 };
 
         // check version
         if 6 != version_number {
@@ -41,23 +40,25 @@ impl<'a> Ipv6HeaderSlice<'a> {
             // This is safe as the slice length is checked to be
             // at least Ipv6Header::LEN (40)
             // at the start of the function.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), Ipv6Header::LEN) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, Ipv6Header::LEN) },
         })
     }
 
-    /// Converts the given slice into a ipv6 header slice WITHOUT any
-    /// checks to ensure that the data present is an ipv4 header or that the
-    /// slice length is matching the header length.
-    ///
-    /// If you are not sure what this means, use [`Ipv6HeaderSlice::from_slice`]
-    /// instead.
-    ///
-    /// # Safety
-    ///
-    /// It must ensured that the slice length is at least [`Ipv6Header::LEN`].
-    #[inline]
-    pub(crate) unsafe fn from_slice_unchecked(slice: &[u8]) -> Ipv6HeaderSlice {
-        Ipv6HeaderSlice { slice }
+    unchecked_fn! {
+        /// Converts the given slice into a ipv6 header slice WITHOUT any
+        /// checks to ensure that the data present is an ipv4 header or that the
+        /// slice length is matching the header length.
+        ///
+        /// If you are not sure what this means, use [`Ipv6HeaderSlice::from_slice`]
+        /// instead.
+        ///
+        /// # Safety
+        ///
+        /// It must ensured that the slice length is at least [`Ipv6Header::LEN`].
+        #[inline]
+        pub(crate) fn from_slice_unchecked(slice: &[u8]) -> Ipv6HeaderSlice {
+            Ipv6HeaderSlice { slice }
+        }
     }
 
     /// Returns the slice containing the ipv6 header
@@ -73,7 +74,7 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        unsafe { *self.slice.get_unchecked(0) >> 4 }
+        unchecked! { get_unchecked_u8(self.slice, 0) >> 4 }
     }
 
     /// Read the "traffic class" field from the slice.
@@ -83,27 +84,66 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        unsafe { (self.slice.get_unchecked(0) << 4) | (self.slice.get_unchecked(1) >> 4) }
+        unchecked! { (get_unchecked_u8(self.slice, 0) << 4) | (get_unchecked_u8(self.slice, 1) >> 4) }
     }
 
     /// Read the "flow label" field from the slice.
     #[inline]
     pub fn flow_label(&self) -> Ipv6FlowLabel {
-        unsafe {
+        unchecked! {
             // SAFETY:
             // Slice access safe as the slice length is set to Ipv6Header::LEN (40)
             // during construction of the struct.
             // Conversion to flow label safe as the bitmask & 0 constant guarantee
             // that the value does not exceed 20 bits.
-            Ipv6FlowLabel::new_unchecked(u32::from_be_bytes([
+            Ipv6FlowLabel::new_unchecked_internal(u32::from_be_bytes([
                 0,
-                *self.slice.get_unchecked(1) & 0xf,
-                *self.slice.get_unchecked(2),
-                *self.slice.get_unchecked(3),
+                get_unchecked_u8(self.slice, 1) & 0xf,
+                get_unchecked_u8(self.slice, 2),
+                get_unchecked_u8(self.slice, 3),
             ]))
         }
     }
 
+    /// Returns a hash over the source address, destination address &
+    /// flow label (the "3-tuple" of RFC 6437) that can be used for
+    /// ECMP & load balancing decisions.
+    ///
+    /// The hash is the 32 bit FNV-1a hash (see [`hash::Fnv1a32`]) over
+    /// the 16 bytes of the source address, the 16 bytes of the destination
+    /// address & the flow label as 4 byte big endian integer. The algorithm
+    /// is part of the API and will not change between versions.
+    ///
+    /// ```
+    /// use etherparse::{hash::Fnv1a32, Ipv6Header, Ipv6HeaderSlice, Ipv6FlowLabel};
+    ///
+    /// let header = Ipv6Header {
+    ///     flow_label: Ipv6FlowLabel::try_new(0x12345).unwrap(),
+    ///     source: [1; 16],
+    ///     destination: [2; 16],
+    ///     ..Default::default()
+    /// };
+    /// let bytes = header.to_bytes();
+    /// let slice = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Fnv1a32::new()
+    ///         .add_slice(&[1; 16])
+    ///         .add_slice(&[2; 16])
+    ///         .add_slice(&0x12345u32.to_be_bytes())
+    ///         .value(),
+    ///     slice.flow_hash()
+    /// );
+    /// ```
+    #[inline]
+    pub fn flow_hash(&self) -> u32 {
+        hash::Fnv1a32::new()
+            .add_slice(&self.source())
+            .add_slice(&self.destination())
+            .add_slice(&self.flow_label().value().to_be_bytes())
+            .value()
+    }
+
     /// Read the "payload length" field from  the slice. The length should contain the length of all extension headers and payload.
     #[inline]
     pub fn payload_length(&self) -> u16 {
@@ -111,7 +151,7 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 4) }
     }
 
     /// Read the "next header" field from the slice.
@@ -124,7 +164,7 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        IpNumber(unsafe { *self.slice.get_unchecked(6) })
+        IpNumber(unchecked! { get_unchecked_u8(self.slice, 6) })
     }
 
     /// Read the "hop limit" field from the slice. The hop limit specifies the number of hops the packet can take before it is discarded.
@@ -134,7 +174,7 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        unsafe { *self.slice.get_unchecked(7) }
+        unchecked! { get_unchecked_u8(self.slice, 7) }
     }
 
     /// Returns a slice containing the IPv6 source address.
@@ -144,7 +184,7 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        unsafe { get_unchecked_16_byte_array(self.slice.as_ptr().add(8)) }
+        unchecked! { get_unchecked_array(self.slice, 8) }
     }
 
     /// Return the ipv6 source address as an std::net::Ipv6Addr
@@ -162,7 +202,14 @@ impl<'a> Ipv6HeaderSlice<'a> {
         // Safe as the slice length is set to
         // Ipv6Header::LEN (40) during construction
         // of the struct.
-        unsafe { get_unchecked_16_byte_array(self.slice.as_ptr().add(24)) }
+        unchecked! { get_unchecked_array(self.slice, 24) }
+    }
+
+    /// Returns the classification of the destination IPv6 address into
+    /// unicast, multicast or broadcast (see [`CastType::from_ipv6_addr`]).
+    #[inline]
+    pub fn destination_cast_type(&self) -> CastType {
+        CastType::from_ipv6_addr(&self.destination())
     }
 
     /// Return the ipv6 destination address as an std::net::Ipv6Addr
@@ -267,7 +314,7 @@ mod test {
         #[test]
         fn from_slice_unchecked(header in ipv6_any()) {
             let bytes = header.to_bytes();
-            let actual = unsafe {
+            let actual = unchecked! {
                 Ipv6HeaderSlice::from_slice_unchecked(&bytes)
             };
             assert_eq!(actual.slice(), &bytes[..]);
@@ -291,6 +338,36 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn flow_hash(header in ipv6_any()) {
+            let bytes = header.to_bytes();
+            let actual = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
+            let mut data = [0u8; 36];
+            data[..16].copy_from_slice(&header.source);
+            data[16..32].copy_from_slice(&header.destination);
+            data[32..].copy_from_slice(&header.flow_label.value().to_be_bytes());
+            assert_eq!(
+                actual.flow_hash(),
+                hash::Fnv1a32::new().add_slice(&data).value()
+            );
+        }
+    }
+
+    #[test]
+    fn flow_hash_stable() {
+        // the hash values are part of the API & must not change between versions
+        let header = Ipv6Header {
+            flow_label: Ipv6FlowLabel::try_new(0x12345).unwrap(),
+            source: [1; 16],
+            destination: [2; 16],
+            ..Default::default()
+        };
+        let bytes = header.to_bytes();
+        let slice = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(0x9aed_7d4a, slice.flow_hash());
+    }
+
     #[cfg(feature = "std")]
     proptest! {
         #[test]
@@ -0,0 +1,166 @@
+use crate::*;
+
+/// A mutable slice containing an IPv6 header of a network package.
+///
+/// Allows modifying individual fields of an already serialized IPv6
+/// header (e.g. decrementing the hop limit when forwarding a packet)
+/// without re-serializing the complete header.
+///
+/// ```
+/// use etherparse::{Ipv6Header, Ipv6HeaderSliceMut};
+///
+/// let header = Ipv6Header {
+///     hop_limit: 2,
+///     ..Default::default()
+/// };
+/// let mut data = header.to_bytes();
+///
+/// let mut slice = Ipv6HeaderSliceMut::from_slice(&mut data).unwrap();
+/// // hop limit 2 -> 1, the packet can be forwarded
+/// assert_eq!(false, slice.decrement_hop_limit());
+/// // hop limit 1 -> 0, the packet has to be dropped (& an ICMPv6 time exceeded message sent)
+/// assert_eq!(true, slice.decrement_hop_limit());
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct Ipv6HeaderSliceMut<'a> {
+    slice: &'a mut [u8],
+}
+
+impl<'a> Ipv6HeaderSliceMut<'a> {
+    /// Creates a mutable slice containing an IPv6 header (without header
+    /// extensions).
+    ///
+    /// The same checks as in [`Ipv6HeaderSlice::from_slice`] are performed.
+    pub fn from_slice(
+        slice: &'a mut [u8],
+    ) -> Result<Ipv6HeaderSliceMut<'a>, err::ipv6::HeaderSliceError> {
+        Ipv6HeaderSlice::from_slice(slice)?;
+        Ok(Ipv6HeaderSliceMut {
+            slice: &mut slice[..Ipv6Header::LEN],
+        })
+    }
+
+    /// Returns the slice containing the IPv6 header.
+    #[inline]
+    pub fn slice(&self) -> &[u8] {
+        self.slice
+    }
+
+    /// Returns a non mutable [`Ipv6HeaderSlice`] that allows reading all
+    /// fields of the header.
+    #[inline]
+    pub fn to_header_slice(&self) -> Ipv6HeaderSlice<'_> {
+        // SAFETY: Safe as the slice was checked in the constructor.
+        unchecked! { Ipv6HeaderSlice::from_slice_unchecked(self.slice) }
+    }
+
+    /// Read the "hop limit" field.
+    #[inline]
+    pub fn hop_limit(&self) -> u8 {
+        self.slice[7]
+    }
+
+    /// Sets the "hop limit" field (IPv6 headers have no checksum so no
+    /// checksum update is needed).
+    #[inline]
+    pub fn set_hop_limit(&mut self, hop_limit: u8) {
+        self.slice[7] = hop_limit;
+    }
+
+    /// Decrements the "hop limit" field by one & returns true if the packet
+    /// has to be dropped (the hop limit reached zero).
+    ///
+    /// A router has to drop a packet for which `true` is returned and
+    /// should send an ICMPv6 "time exceeded" message to the source (see
+    /// [`Icmpv6Type::TimeExceeded`]). If the hop limit is already zero
+    /// it is left unchanged & `true` is returned.
+    pub fn decrement_hop_limit(&mut self) -> bool {
+        match self.hop_limit() {
+            0 => true,
+            hop_limit => {
+                self.set_hop_limit(hop_limit - 1);
+                1 == hop_limit
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv6_any()) {
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.extend_from_slice(&[1, 2, 3]);
+
+            // ok
+            {
+                let mut data = buffer.clone();
+                let slice = Ipv6HeaderSliceMut::from_slice(&mut data).unwrap();
+                assert_eq!(slice.slice(), &buffer[..Ipv6Header::LEN]);
+                assert_eq!(slice.to_header_slice().to_header(), header);
+                assert_eq!(slice.hop_limit(), header.hop_limit);
+            }
+
+            // errors
+            for len in 0..Ipv6Header::LEN {
+                let mut data = buffer.clone();
+                assert_eq!(
+                    Ipv6HeaderSliceMut::from_slice(&mut data[..len]).unwrap_err(),
+                    Ipv6HeaderSlice::from_slice(&buffer[..len]).unwrap_err()
+                );
+            }
+            {
+                let mut data = buffer.clone();
+                data[0] = 0x40;
+                assert_eq!(
+                    Ipv6HeaderSliceMut::from_slice(&mut data.clone()).unwrap_err(),
+                    Ipv6HeaderSlice::from_slice(&data).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn set_decrement_hop_limit(
+            header in ipv6_any(),
+            hop_limit in any::<u8>()
+        ) {
+            // set
+            {
+                let mut data = header.to_bytes();
+                let mut slice = Ipv6HeaderSliceMut::from_slice(&mut data).unwrap();
+                slice.set_hop_limit(hop_limit);
+                let mut expected = header.clone();
+                expected.hop_limit = hop_limit;
+                assert_eq!(expected, slice.to_header_slice().to_header());
+            }
+
+            // decrement
+            {
+                let mut data = header.to_bytes();
+                let mut slice = Ipv6HeaderSliceMut::from_slice(&mut data).unwrap();
+                let drop = slice.decrement_hop_limit();
+                let mut expected = header.clone();
+                expected.hop_limit = header.hop_limit.saturating_sub(1);
+                assert_eq!(expected, slice.to_header_slice().to_header());
+                assert_eq!(drop, header.hop_limit <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn debug_eq() {
+        let mut data = Ipv6Header::default().to_bytes();
+        let expected = format!("Ipv6HeaderSliceMut {{ slice: {:?} }}", &data[..]);
+        let mut data2: Vec<u8> = data.to_vec();
+        let slice = Ipv6HeaderSliceMut::from_slice(&mut data).unwrap();
+        assert_eq!(expected, format!("{:?}", slice));
+        assert_eq!(slice, Ipv6HeaderSliceMut::from_slice(&mut data2).unwrap());
+    }
+}
@@ -0,0 +1,94 @@
+/// 4 bit "scope" of an IPv6 multicast address (lower 4 bits of the
+/// second address byte, see [RFC 4291](https://datatracker.ietf.org/doc/html/rfc4291#section-2.7)
+/// & [RFC 7346](https://datatracker.ietf.org/doc/html/rfc7346)).
+///
+/// The scope of an address can be determined via
+/// [`crate::ipv6_addr::multicast_scope`]:
+///
+/// ```
+/// use etherparse::{ipv6_addr, Ipv6MulticastScope};
+///
+/// // ff02::1 (all nodes on the link)
+/// let addr = [0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+/// assert_eq!(
+///     Some(Ipv6MulticastScope::LINK_LOCAL),
+///     ipv6_addr::multicast_scope(&addr)
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv6MulticastScope(pub u8);
+
+impl Ipv6MulticastScope {
+    pub const INTERFACE_LOCAL: Ipv6MulticastScope = Self(0x1);
+    pub const LINK_LOCAL: Ipv6MulticastScope = Self(0x2);
+    pub const REALM_LOCAL: Ipv6MulticastScope = Self(0x3);
+    pub const ADMIN_LOCAL: Ipv6MulticastScope = Self(0x4);
+    pub const SITE_LOCAL: Ipv6MulticastScope = Self(0x5);
+    pub const ORGANIZATION_LOCAL: Ipv6MulticastScope = Self(0x8);
+    pub const GLOBAL: Ipv6MulticastScope = Self(0xe);
+
+    /// Returns the name of the scope if the value is a known scope.
+    pub const fn name_str(&self) -> Option<&'static str> {
+        match self.0 {
+            0x1 => Some("Interface-Local"),
+            0x2 => Some("Link-Local"),
+            0x3 => Some("Realm-Local"),
+            0x4 => Some("Admin-Local"),
+            0x5 => Some("Site-Local"),
+            0x8 => Some("Organization-Local"),
+            0xe => Some("Global"),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Debug for Ipv6MulticastScope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.name_str() {
+            write!(f, "{:#x} ({})", self.0, name)
+        } else {
+            write!(f, "{:#x}", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn name_str() {
+        let tests = [
+            (Ipv6MulticastScope::INTERFACE_LOCAL, "Interface-Local"),
+            (Ipv6MulticastScope::LINK_LOCAL, "Link-Local"),
+            (Ipv6MulticastScope::REALM_LOCAL, "Realm-Local"),
+            (Ipv6MulticastScope::ADMIN_LOCAL, "Admin-Local"),
+            (Ipv6MulticastScope::SITE_LOCAL, "Site-Local"),
+            (Ipv6MulticastScope::ORGANIZATION_LOCAL, "Organization-Local"),
+            (Ipv6MulticastScope::GLOBAL, "Global"),
+        ];
+        for (scope, name) in tests {
+            assert_eq!(Some(name), scope.name_str());
+        }
+        for value in [0x0, 0x6, 0x7, 0x9, 0xf] {
+            assert_eq!(None, Ipv6MulticastScope(value).name_str());
+        }
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "0x2 (Link-Local)",
+            format!("{:?}", Ipv6MulticastScope::LINK_LOCAL)
+        );
+        assert_eq!("0x6", format!("{:?}", Ipv6MulticastScope(6)));
+    }
+
+    #[test]
+    fn clone_eq() {
+        let scope = Ipv6MulticastScope::GLOBAL;
+        assert_eq!(scope, scope.clone());
+        assert_ne!(scope, Ipv6MulticastScope::SITE_LOCAL);
+    }
+}
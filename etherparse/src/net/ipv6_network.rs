@@ -0,0 +1,231 @@
+use crate::err::{ValueTooBigError, ValueType};
+use core::net::Ipv6Addr;
+
+/// IPv6 network consisting of an address & prefix length (e.g. "fd00::/8").
+///
+/// Can be used to match the addresses of parsed headers against a subnet
+/// (or of extracted flows via [`crate::quick::FlowKey::is_in_ipv6_network`]):
+///
+/// ```
+/// use etherparse::{Ipv6Header, Ipv6Network};
+/// use core::net::Ipv6Addr;
+///
+/// let network = Ipv6Network::new(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 0), 8).unwrap();
+///
+/// let header = Ipv6Header {
+///     source: [0xfd, 0x12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+///     destination: [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+///     ..Default::default()
+/// };
+/// assert!(network.contains(header.source));
+/// assert!(false == network.contains(header.destination));
+/// ```
+///
+/// With the `std` feature networks can also be parsed from strings:
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use etherparse::Ipv6Network;
+///
+/// let network: Ipv6Network = "2001:db8::/32".parse().unwrap();
+/// assert!(network.contains("2001:db8::1".parse::<core::net::Ipv6Addr>().unwrap()));
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv6Network {
+    addr: Ipv6Addr,
+    prefix_len: u8,
+}
+
+impl Ipv6Network {
+    /// Maximum allowed prefix length (number of bits in an IPv6 address).
+    pub const MAX_PREFIX_LEN: u8 = 128;
+
+    /// Creates a network from an address & prefix length.
+    ///
+    /// Host bits set in `addr` (bits after the prefix) are cleared. An
+    /// error is returned if `prefix_len` exceeds [`Ipv6Network::MAX_PREFIX_LEN`].
+    pub const fn new(addr: Ipv6Addr, prefix_len: u8) -> Result<Ipv6Network, ValueTooBigError<u8>> {
+        if prefix_len > Ipv6Network::MAX_PREFIX_LEN {
+            Err(ValueTooBigError {
+                actual: prefix_len,
+                max_allowed: Ipv6Network::MAX_PREFIX_LEN,
+                value_type: ValueType::IpNetworkPrefixLen,
+            })
+        } else {
+            Ok(Ipv6Network {
+                addr: Ipv6Addr::from_bits(addr.to_bits() & Self::mask(prefix_len)),
+                prefix_len,
+            })
+        }
+    }
+
+    /// Returns the network mask as an `u128` for the given prefix length.
+    #[inline]
+    const fn mask(prefix_len: u8) -> u128 {
+        if 0 == prefix_len {
+            0
+        } else {
+            u128::MAX << (128 - prefix_len as u32)
+        }
+    }
+
+    /// Returns the network address (with all host bits cleared).
+    #[inline]
+    pub const fn addr(&self) -> Ipv6Addr {
+        self.addr
+    }
+
+    /// Returns the prefix length (number of leading network bits).
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the network mask (e.g. ffff:ffff:: for a "/32" network).
+    #[inline]
+    pub const fn netmask(&self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(Self::mask(self.prefix_len))
+    }
+
+    /// Returns true if the given address is part of the network.
+    ///
+    /// Accepts everything that can be converted to an [`Ipv6Addr`]
+    /// (e.g. the `[u8;16]` address fields of an [`crate::Ipv6Header`]).
+    #[inline]
+    pub fn contains<A: Into<Ipv6Addr>>(&self, addr: A) -> bool {
+        (addr.into().to_bits() & Self::mask(self.prefix_len)) == self.addr.to_bits()
+    }
+}
+
+impl core::fmt::Display for Ipv6Network {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl core::str::FromStr for Ipv6Network {
+    type Err = crate::err::ip::NetworkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::err::ip::NetworkParseError::*;
+        let (addr, prefix_len) = s.split_once('/').ok_or(MissingPrefixLen)?;
+        let addr: Ipv6Addr = addr.parse().map_err(|_| InvalidAddr)?;
+        let prefix_len: u8 = prefix_len.parse().map_err(|_| InvalidPrefixLen)?;
+        Ipv6Network::new(addr, prefix_len).map_err(|_| InvalidPrefixLen)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ip::NetworkParseError;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn new() {
+        // host bits get cleared
+        {
+            let actual =
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 1, 2, 3, 4, 5, 6), 32).unwrap();
+            assert_eq!(
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0),
+                actual.addr()
+            );
+            assert_eq!(32, actual.prefix_len());
+        }
+        // edge cases
+        {
+            let actual = Ipv6Network::new(Ipv6Addr::LOCALHOST, 0).unwrap();
+            assert_eq!(Ipv6Addr::UNSPECIFIED, actual.addr());
+            let actual = Ipv6Network::new(Ipv6Addr::LOCALHOST, 128).unwrap();
+            assert_eq!(Ipv6Addr::LOCALHOST, actual.addr());
+        }
+        // prefix too big
+        assert_eq!(
+            Ipv6Network::new(Ipv6Addr::UNSPECIFIED, 129).unwrap_err(),
+            ValueTooBigError {
+                actual: 129,
+                max_allowed: 128,
+                value_type: ValueType::IpNetworkPrefixLen,
+            }
+        );
+    }
+
+    #[test]
+    fn netmask() {
+        let tests = [
+            (0, Ipv6Addr::UNSPECIFIED),
+            (1, Ipv6Addr::new(0x8000, 0, 0, 0, 0, 0, 0, 0)),
+            (32, Ipv6Addr::new(0xffff, 0xffff, 0, 0, 0, 0, 0, 0)),
+            (
+                127,
+                Ipv6Addr::new(
+                    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xfffe,
+                ),
+            ),
+            (128, Ipv6Addr::from_bits(u128::MAX)),
+        ];
+        for (prefix_len, expected) in tests {
+            assert_eq!(
+                expected,
+                Ipv6Network::new(Ipv6Addr::UNSPECIFIED, prefix_len)
+                    .unwrap()
+                    .netmask()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn contains(
+            network_addr in any::<[u8;16]>(),
+            prefix_len in 0u8..=128,
+            addr in any::<[u8;16]>()
+        ) {
+            let network = Ipv6Network::new(network_addr.into(), prefix_len).unwrap();
+            // the network address itself is always contained
+            assert!(network.contains(network_addr));
+            // compare against a bitwise check
+            let expected = (0..usize::from(prefix_len)).all(|bit| {
+                let byte = bit / 8;
+                let mask = 0x80u8 >> (bit % 8);
+                (network_addr[byte] & mask) == (addr[byte] & mask)
+            });
+            assert_eq!(expected, network.contains(addr));
+            assert_eq!(expected, network.contains(Ipv6Addr::from(addr)));
+        }
+    }
+
+    #[test]
+    fn fmt() {
+        let network = Ipv6Network::new(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 0), 8).unwrap();
+        assert_eq!("fd00::/8", format!("{}", network));
+        assert_eq!(
+            "Ipv6Network { addr: fd00::, prefix_len: 8 }",
+            format!("{:?}", network)
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        use NetworkParseError::*;
+        assert_eq!(
+            Ok(Ipv6Network::new(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 0), 8).unwrap()),
+            "fd00::/8".parse::<Ipv6Network>()
+        );
+        assert_eq!(
+            Ok(Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap()),
+            "2001:db8::1/32".parse::<Ipv6Network>()
+        );
+        assert_eq!(Err(MissingPrefixLen), "fd00::".parse::<Ipv6Network>());
+        assert_eq!(Err(InvalidAddr), "10.0.0.0/8".parse::<Ipv6Network>());
+        assert_eq!(Err(InvalidPrefixLen), "fd00::/".parse::<Ipv6Network>());
+        assert_eq!(Err(InvalidPrefixLen), "fd00::/a".parse::<Ipv6Network>());
+        assert_eq!(Err(InvalidPrefixLen), "fd00::/129".parse::<Ipv6Network>());
+    }
+}
@@ -81,6 +81,14 @@ impl Ipv6RawExtHeader {
     /// Maximum length of a [Ipv6RawExtHeader] the payload
     pub const MAX_PAYLOAD_LEN: usize = 0xff * 8 + 6;
 
+    /// Option type of a single byte padding option in Hop by Hop &
+    /// Destination Options headers (no length & data fields are present).
+    pub const OPTION_PAD1: u8 = 0;
+
+    /// Option type of a padding option of two or more bytes in Hop by Hop
+    /// & Destination Options headers.
+    pub const OPTION_PADN: u8 = 1;
+
     /// Returns true if the given header type ip number can be represented in an `Ipv6ExtensionHeader`.
     pub fn header_type_supported(next_header: IpNumber) -> bool {
         use crate::ip_number::*;
@@ -161,6 +169,141 @@ impl Ipv6RawExtHeader {
         }
     }
 
+    /// Length of the options in the payload without the trailing padding
+    /// (`Pad1` & `PadN` options at the end of the payload).
+    ///
+    /// Only meaningful for headers containing options (Hop by Hop &
+    /// Destination Options). If the options can not be decoded (option
+    /// length exceeding the payload) the length of the complete payload
+    /// is returned.
+    pub fn options_len(&self) -> usize {
+        let payload = self.payload();
+        let mut offset = 0;
+        let mut end = 0;
+        while offset < payload.len() {
+            let option_len = match payload[offset] {
+                Self::OPTION_PAD1 => 1,
+                _ => match payload.get(offset + 1) {
+                    Some(len) => 2 + usize::from(*len),
+                    None => return payload.len(),
+                },
+            };
+            if offset + option_len > payload.len() {
+                return payload.len();
+            }
+            if payload[offset] != Self::OPTION_PAD1 && payload[offset] != Self::OPTION_PADN {
+                end = offset + option_len;
+            }
+            offset += option_len;
+        }
+        end
+    }
+
+    /// Appends an option (type, length & data bytes) after the last
+    /// non padding option & recomputes the header length and padding.
+    ///
+    /// The header length field is set to the smallest number of 8 octet
+    /// units that fits the options and the remaining bytes are filled with
+    /// a `Pad1` or `PadN` option. Alignment requirements of the option
+    /// itself are not taken into account.
+    ///
+    /// If the resulting payload would be bigger then
+    /// [`Ipv6RawExtHeader::MAX_PAYLOAD_LEN`] a
+    /// [`crate::err::ipv6_exts::ExtPayloadLenError::TooBig`] is returned and the
+    /// header is not changed.
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RawExtHeader};
+    ///
+    /// let mut header = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[1, 4, 0, 0, 0, 0]).unwrap();
+    ///
+    /// // router alert option (type 5, length 2)
+    /// header.append_option(&[5, 2, 0, 0]).unwrap();
+    /// assert_eq!(header.payload(), &[5, 2, 0, 0, 1, 0]);
+    ///
+    /// header.append_option(&[0x3e, 3, 1, 2, 3]).unwrap();
+    /// assert_eq!(
+    ///     header.payload(),
+    ///     &[5, 2, 0, 0, 0x3e, 3, 1, 2, 3, 1, 3, 0, 0, 0]
+    /// );
+    /// assert_eq!(16, header.header_len());
+    /// ```
+    pub fn append_option(&mut self, option: &[u8]) -> Result<(), ExtPayloadLenError> {
+        let start = self.options_len();
+        let end = start + option.len();
+        let padded_len = Self::padded_options_len(end);
+        if padded_len > Self::MAX_PAYLOAD_LEN {
+            return Err(ExtPayloadLenError::TooBig(padded_len));
+        }
+        self.payload_buffer[start..end].copy_from_slice(option);
+        self.set_options_padding(end, padded_len);
+        Ok(())
+    }
+
+    /// Removes the first option with the given type & recomputes the
+    /// header length and padding. Returns `true` if an option was removed.
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RawExtHeader};
+    ///
+    /// let mut header = Ipv6RawExtHeader::new_raw(
+    ///     ip_number::UDP,
+    ///     &[5, 2, 0, 0, 0x3e, 3, 1, 2, 3, 1, 3, 0, 0, 0]
+    /// ).unwrap();
+    ///
+    /// assert!(header.remove_option(0x3e));
+    /// assert_eq!(header.payload(), &[5, 2, 0, 0, 1, 0]);
+    /// assert!(false == header.remove_option(0x3e));
+    /// ```
+    pub fn remove_option(&mut self, option_type: u8) -> bool {
+        let end = self.options_len();
+        let mut offset = 0;
+        while offset < end {
+            let option_len = if self.payload_buffer[offset] == Self::OPTION_PAD1 {
+                1
+            } else if offset + 1 >= end {
+                // malformed option at the end
+                end - offset
+            } else {
+                (2 + usize::from(self.payload_buffer[offset + 1])).min(end - offset)
+            };
+            if self.payload_buffer[offset] == option_type {
+                self.payload_buffer
+                    .copy_within(offset + option_len..end, offset);
+                let new_end = end - option_len;
+                self.set_options_padding(new_end, Self::padded_options_len(new_end));
+                return true;
+            }
+            offset += option_len;
+        }
+        false
+    }
+
+    /// Smallest valid payload length that can contain `options_len` bytes.
+    fn padded_options_len(options_len: usize) -> usize {
+        if options_len <= Self::MIN_PAYLOAD_LEN {
+            Self::MIN_PAYLOAD_LEN
+        } else {
+            Self::MIN_PAYLOAD_LEN + (options_len - Self::MIN_PAYLOAD_LEN).div_ceil(8) * 8
+        }
+    }
+
+    /// Fills the payload from `options_end` to `payload_len` with padding
+    /// & sets the header length to match `payload_len`.
+    fn set_options_padding(&mut self, options_end: usize, payload_len: usize) {
+        let padding = &mut self.payload_buffer[options_end..payload_len];
+        padding.fill(0);
+        match padding.len() {
+            0 => {}
+            1 => padding[0] = Self::OPTION_PAD1,
+            len => {
+                padding[0] = Self::OPTION_PADN;
+                padding[1] = (len - 2) as u8;
+            }
+        }
+        self.header_length = ((payload_len - Self::MIN_PAYLOAD_LEN) / 8) as u8;
+    }
+
     /// Read an fragment header from the current reader position.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -497,4 +640,100 @@ mod test {
             assert_eq!(header.header_len(), header.to_bytes().len());
         }
     }
+
+    #[test]
+    fn options_len() {
+        let cases: [(&[u8], usize); 7] = [
+            (&[1, 4, 0, 0, 0, 0], 0),
+            (&[0, 0, 0, 0, 0, 0], 0),
+            (&[5, 2, 0, 0, 1, 0], 4),
+            (&[5, 2, 0, 0, 0, 0], 4),
+            (&[5, 0, 1, 0, 5, 0], 6),
+            // padding between options
+            (&[0, 5, 0, 1, 0, 0, 7, 0, 1, 4, 0, 0, 0, 0], 8),
+            // malformed option length
+            (&[5, 10, 0, 0, 0, 0], 6),
+        ];
+        for (payload, expected) in cases {
+            let header = Ipv6RawExtHeader::new_raw(ip_number::UDP, payload).unwrap();
+            assert_eq!(expected, header.options_len(), "{:?}", payload);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn append_remove_option(
+            next_header in ip_number_any(),
+            options in proptest::collection::vec(
+                (2u8..=255, proptest::collection::vec(any::<u8>(), 0..20)),
+                0..8
+            )
+        ) {
+            let mut header = Ipv6RawExtHeader::new_raw(next_header, &[1, 4, 0, 0, 0, 0]).unwrap();
+            let mut expected = Vec::new();
+            for (option_type, data) in options.iter() {
+                let mut option = Vec::with_capacity(2 + data.len());
+                option.push(*option_type);
+                option.push(data.len() as u8);
+                option.extend_from_slice(data);
+
+                header.append_option(&option).unwrap();
+                expected.extend_from_slice(&option);
+
+                assert_eq!(next_header, header.next_header);
+                assert_eq!(expected.len(), header.options_len());
+                assert_eq!(&expected[..], &header.payload()[..expected.len()]);
+                // smallest possible length
+                assert_eq!(0, (header.payload().len() + 2) % 8);
+                assert!(header.payload().len() < (expected.len() + 8).max(Ipv6RawExtHeader::MIN_PAYLOAD_LEN + 1));
+                // re-parsable
+                let bytes = header.to_bytes();
+                assert_eq!(header, Ipv6RawExtHeader::from_slice(&bytes).unwrap().0);
+            }
+
+            // remove options in order
+            for (option_type, data) in options.iter() {
+                // first occurrence removed
+                let pos = {
+                    let mut offset = 0;
+                    while expected[offset] != *option_type {
+                        offset += 2 + usize::from(expected[offset + 1]);
+                    }
+                    offset
+                };
+                expected.drain(pos..pos + 2 + data.len());
+                assert!(header.remove_option(*option_type));
+                assert_eq!(expected.len(), header.options_len());
+                assert_eq!(&expected[..], &header.payload()[..expected.len()]);
+                assert_eq!(0, (header.payload().len() + 2) % 8);
+            }
+            assert_eq!(header.payload(), &[1, 4, 0, 0, 0, 0]);
+            assert!(false == header.remove_option(2));
+        }
+    }
+
+    #[test]
+    fn append_option_padding() {
+        let mut header = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap();
+
+        // pad1
+        header.append_option(&[5, 3, 1, 2, 3]).unwrap();
+        assert_eq!(header.payload(), &[5, 3, 1, 2, 3, 0]);
+
+        // no padding
+        header.remove_option(5);
+        header.append_option(&[5, 4, 1, 2, 3, 4]).unwrap();
+        assert_eq!(header.payload(), &[5, 4, 1, 2, 3, 4]);
+
+        // too big
+        let before = header.clone();
+        let option = [0x3e; Ipv6RawExtHeader::MAX_PAYLOAD_LEN];
+        assert_eq!(
+            Err(ExtPayloadLenError::TooBig(
+                Ipv6RawExtHeader::MAX_PAYLOAD_LEN + 8
+            )),
+            header.append_option(&option)
+        );
+        assert_eq!(before, header);
+    }
 }
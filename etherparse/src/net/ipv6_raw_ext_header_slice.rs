@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 /// Deprecated. Use [Ipv6RawExtHeaderSlice] instead.
 #[deprecated(
@@ -66,7 +65,7 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
             // SAFETY:
             // Safe as the slice has been checked in the previous if
             // to have at least the the length of the variable len.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, len) },
         })
     }
 
@@ -82,9 +81,18 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
     ///
     /// If these preconditions are not fulfilled the behavior of this function
     /// and the methods of the return [`IpAuthHeaderSlice`] will be undefined.
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
     pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> Ipv6RawExtHeaderSlice<'a> {
-        Ipv6RawExtHeaderSlice {
-            slice: from_raw_parts(slice.as_ptr(), ((*slice.get_unchecked(1) as usize) + 1) * 8),
+        unchecked! { Ipv6RawExtHeaderSlice::from_slice_unchecked_internal(slice) }
+    }
+
+    unchecked_fn! {
+        /// Crate internal version of [`Ipv6RawExtHeaderSlice::from_slice_unchecked`] (a safe
+        /// function if the `safe-only` feature is enabled).
+        pub(crate) fn from_slice_unchecked_internal(slice: &'a [u8]) -> Ipv6RawExtHeaderSlice<'a> {
+            Ipv6RawExtHeaderSlice {
+                slice: get_unchecked_slice(slice, 0, ((get_unchecked_u8(slice, 1) as usize) + 1) * 8),
+            }
         }
     }
 
@@ -99,7 +107,7 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
     /// See [IpNumber] or [ip_number] for a definition of the known values.
     #[inline]
     pub fn next_header(&self) -> IpNumber {
-        IpNumber(unsafe { *self.slice.get_unchecked(0) })
+        IpNumber(unchecked! { get_unchecked_u8(self.slice, 0) })
     }
 
     /// Returns a slice containing the payload data of the header.
@@ -109,7 +117,7 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
     /// hdr ext length field).
     #[inline]
     pub fn payload(&self) -> &'a [u8] {
-        unsafe { from_raw_parts(self.slice.as_ptr().add(2), self.slice.len() - 2) }
+        unchecked! { get_unchecked_slice(self.slice, 2, self.slice.len() - 2) }
     }
 
     /// Convert the slice to an [Ipv6RawExtHeader].
@@ -123,6 +131,7 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{test_gens::*, *};
     use alloc::{format, vec::Vec};
@@ -39,11 +39,8 @@ impl<'a> Ipv6Slice<'a> {
 
                 // TODO: Add payload length parsing from the jumbogram
                 (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
+                    unchecked! {
+                        get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
                     },
                     LenSource::Slice,
                 )
@@ -60,11 +57,8 @@ impl<'a> Ipv6Slice<'a> {
                     }));
                 } else {
                     (
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                slice.as_ptr().add(Ipv6Header::LEN),
-                                payload_len,
-                            )
+                        unchecked! {
+                            get_unchecked_slice(slice, Ipv6Header::LEN, payload_len)
                         },
                         LenSource::Ipv6HeaderPayloadLen,
                     )
@@ -147,47 +141,39 @@ impl<'a> Ipv6Slice<'a> {
         })?;
 
         // restrict slice by the length specified in the header
-        let (header_payload, len_source) =
-            if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
+        let (header_payload, len_source) = if 0 == header.payload_length()
+            && slice.len() > Ipv6Header::LEN
+        {
+            // In case the payload_length is 0 assume that the entire
+            // rest of the slice is part of the packet until the jumbogram
+            // parameters can be parsed.
+
+            // TODO: Add payload length parsing from the jumbogram
+            (
+                unchecked! {
+                    get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
+                },
+                LenSource::Slice,
+            )
+        } else {
+            let payload_len = usize::from(header.payload_length());
+            let expected_len = Ipv6Header::LEN + payload_len;
+            if slice.len() < expected_len {
                 (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
+                    unchecked! {
+                        get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
                     },
                     LenSource::Slice,
                 )
             } else {
-                let payload_len = usize::from(header.payload_length());
-                let expected_len = Ipv6Header::LEN + payload_len;
-                if slice.len() < expected_len {
-                    (
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                slice.as_ptr().add(Ipv6Header::LEN),
-                                slice.len() - Ipv6Header::LEN,
-                            )
-                        },
-                        LenSource::Slice,
-                    )
-                } else {
-                    (
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                slice.as_ptr().add(Ipv6Header::LEN),
-                                payload_len,
-                            )
-                        },
-                        LenSource::Ipv6HeaderPayloadLen,
-                    )
-                }
-            };
+                (
+                    unchecked! {
+                        get_unchecked_slice(slice, Ipv6Header::LEN, payload_len)
+                    },
+                    LenSource::Ipv6HeaderPayloadLen,
+                )
+            }
+        };
 
         // parse extension headers
         let (exts, payload_ip_number, payload) =
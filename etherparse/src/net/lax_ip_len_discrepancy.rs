@@ -0,0 +1,95 @@
+/// Mismatch between the length of an IP packet indicated by its header,
+/// an externally known expected length and the length of the slice that
+/// was parsed (see [`crate::LaxSlicedPacket::from_ip_with_len_hint`]).
+///
+/// Can be used to distinguish why a laxly parsed packet is incomplete:
+///
+/// * [`LaxIpLenDiscrepancy::is_cut_by_capture`]: The original packet was
+///   complete, but the data was cut when it was recorded (e.g. by the
+///   snaplen of a capture).
+/// * [`LaxIpLenDiscrepancy::is_cut_before_capture`]: The packet was
+///   already incomplete before it was recorded (e.g. because it was
+///   quoted in an ICMP error message).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LaxIpLenDiscrepancy {
+    /// Total length of the IP packet based on the length field in
+    /// the IP header (`total_len` for IPv4 and `payload_length + 40`
+    /// for IPv6).
+    pub header_total_len: usize,
+
+    /// Externally known total length of the IP packet (e.g. the
+    /// original length of a captured packet) if one was passed.
+    pub expected_total_len: Option<usize>,
+
+    /// Length of the slice that was parsed.
+    pub slice_len: usize,
+}
+
+impl LaxIpLenDiscrepancy {
+    /// Returns true if the slice is shorter than the length indicated in
+    /// the IP header, but the expected length covers the full packet
+    /// (e.g. the packet was cut by the snaplen of a capture).
+    #[inline]
+    pub fn is_cut_by_capture(&self) -> bool {
+        match self.expected_total_len {
+            Some(expected) => {
+                self.slice_len < self.header_total_len && expected >= self.header_total_len
+            }
+            None => false,
+        }
+    }
+
+    /// Returns true if the expected length is smaller than the length
+    /// indicated in the IP header (e.g. the packet was quoted in an ICMP
+    /// error message and only the start of it was included).
+    #[inline]
+    pub fn is_cut_before_capture(&self) -> bool {
+        match self.expected_total_len {
+            Some(expected) => expected < self.header_total_len,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn is_cut_by_capture_before_capture() {
+        let d = |header_total_len, expected_total_len, slice_len| LaxIpLenDiscrepancy {
+            header_total_len,
+            expected_total_len,
+            slice_len,
+        };
+        // (discrepancy, cut by capture, cut before capture)
+        let tests = [
+            (d(100, None, 50), false, false),
+            (d(100, Some(100), 50), true, false),
+            (d(100, Some(120), 50), true, false),
+            (d(100, Some(60), 50), false, true),
+            (d(100, Some(50), 50), false, true),
+            (d(100, Some(100), 100), false, false),
+            (d(100, Some(100), 120), false, false),
+        ];
+        for (value, by_capture, before_capture) in tests {
+            assert_eq!(by_capture, value.is_cut_by_capture(), "{:?}", value);
+            assert_eq!(before_capture, value.is_cut_before_capture(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = LaxIpLenDiscrepancy {
+            header_total_len: 1,
+            expected_total_len: Some(2),
+            slice_len: 3,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "LaxIpLenDiscrepancy { header_total_len: 1, expected_total_len: Some(2), slice_len: 3 }",
+            format!("{:?}", value)
+        );
+    }
+}
@@ -169,7 +169,7 @@ impl<'a> LaxIpSlice<'a> {
             }))
         } else {
             // SAFETY: Safe as slice is not empty.
-            let first_byte = unsafe { slice.get_unchecked(0) };
+            let first_byte = unchecked! { get_unchecked_u8(slice, 0) };
             match first_byte >> 4 {
                 4 => {
                     let ihl = first_byte & 0xf;
@@ -182,6 +182,13 @@ impl<'a> LaxIpSlice<'a> {
                     // check there is enough data for the header
                     let header_len = (usize::from(ihl)) * 4;
                     if slice.len() < header_len {
+                        if slice.len() >= Ipv4Header::MIN_LEN {
+                            // only the options are cut off
+                            return Ok((
+                                Ipv4(LaxIpv4Slice::from_slice_options_truncated(slice)),
+                                None,
+                            ));
+                        }
                         return Err(E::Len(LenError {
                             required_len: header_len,
                             len: slice.len(),
@@ -194,11 +201,8 @@ impl<'a> LaxIpSlice<'a> {
                     // SAFETY:
                     // Safe as the slice length is checked to be at least
                     // header_len or greater above.
-                    let header = unsafe {
-                        Ipv4HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                            slice.as_ptr(),
-                            header_len,
-                        ))
+                    let header = unchecked! {
+                        Ipv4HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, header_len))
                     };
 
                     // check the total_lenat least contains the header
@@ -207,15 +211,12 @@ impl<'a> LaxIpSlice<'a> {
                     let (header_payload, len_source, incomplete) = if total_len < header_len {
                         // fallback to slice len
                         (
-                            unsafe {
-                                core::slice::from_raw_parts(
-                                    // SAFETY: Safe as slice.len() >= header_len was validated
+                            unchecked! {
+                                get_unchecked_slice(// SAFETY: Safe as slice.len() >= header_len was validated
                                     // in a if statement above.
-                                    slice.as_ptr().add(header_len),
-                                    // SAFETY: Safe as slice.len() >= header_len was validated
+                                    slice, header_len, // SAFETY: Safe as slice.len() >= header_len was validated
                                     // in a if statement above.
-                                    slice.len() - header_len,
-                                )
+                                    slice.len() - header_len)
                             },
                             LenSource::Slice,
                             false,
@@ -223,30 +224,24 @@ impl<'a> LaxIpSlice<'a> {
                     } else if slice.len() < total_len {
                         // fallback to slice len
                         (
-                            unsafe {
-                                core::slice::from_raw_parts(
-                                    // SAFETY: Safe as slice.len() >= header_len was validated
+                            unchecked! {
+                                get_unchecked_slice(// SAFETY: Safe as slice.len() >= header_len was validated
                                     // in a if statement above.
-                                    slice.as_ptr().add(header_len),
-                                    // SAFETY: Safe as slice.len() >= header_len was validated
+                                    slice, header_len, // SAFETY: Safe as slice.len() >= header_len was validated
                                     // in a if statement above.
-                                    slice.len() - header_len,
-                                )
+                                    slice.len() - header_len)
                             },
                             LenSource::Slice,
                             true, // flag payload as incomplete
                         )
                     } else {
                         (
-                            unsafe {
-                                core::slice::from_raw_parts(
-                                    // SAFETY: Safe as slice.len() >= header_len was validated
+                            unchecked! {
+                                get_unchecked_slice(// SAFETY: Safe as slice.len() >= header_len was validated
                                     // in a if statement above.
-                                    slice.as_ptr().add(header_len),
-                                    // SAFETY: Safe as total_length >= header_len was verfied in an
+                                    slice, header_len, // SAFETY: Safe as total_length >= header_len was verfied in an
                                     // if statement above as well as that slice.len() >= total_length_usize.
-                                    total_len - header_len,
-                                )
+                                    total_len - header_len)
                             },
                             LenSource::Ipv4HeaderTotalLen,
                             false,
@@ -264,11 +259,8 @@ impl<'a> LaxIpSlice<'a> {
                             match IpAuthHeaderSlice::from_slice(header_payload) {
                                 Ok(auth) => {
                                     // remove the extension header from the payload
-                                    let payload = unsafe {
-                                        core::slice::from_raw_parts(
-                                            header_payload.as_ptr().add(auth.slice().len()),
-                                            header_payload.len() - auth.slice().len(),
-                                        )
+                                    let payload = unchecked! {
+                                        get_unchecked_slice(header_payload, auth.slice().len(), header_payload.len() - auth.slice().len())
                                     };
                                     Ok((
                                         Ipv4(LaxIpv4Slice {
@@ -281,6 +273,7 @@ impl<'a> LaxIpSlice<'a> {
                                                 len_source,
                                                 payload,
                                             },
+                                            options_truncated: false,
                                         }),
                                         None,
                                     ))
@@ -298,6 +291,7 @@ impl<'a> LaxIpSlice<'a> {
                                                 len_source,
                                                 payload: header_payload,
                                             },
+                                            options_truncated: false,
                                         }),
                                         match err {
                                             A::Len(mut l) => Some((
@@ -327,11 +321,13 @@ impl<'a> LaxIpSlice<'a> {
                                     len_source,
                                     payload: header_payload,
                                 },
+                                options_truncated: false,
                             }),
                             None,
                         )),
                     }
                 }
+                #[cfg(feature = "ipv6")]
                 6 => {
                     // check length
                     if slice.len() < Ipv6Header::LEN {
@@ -344,55 +340,44 @@ impl<'a> LaxIpSlice<'a> {
                         }));
                     }
 
-                    let header = unsafe {
-                        Ipv6HeaderSlice::from_slice_unchecked(core::slice::from_raw_parts(
-                            slice.as_ptr(),
-                            Ipv6Header::LEN,
-                        ))
+                    let header = unchecked! {
+                        Ipv6HeaderSlice::from_slice_unchecked(get_unchecked_slice(slice, 0, Ipv6Header::LEN))
                     };
 
                     // restrict slice by the length specified in the header (if possible)
                     let payload_len = usize::from(header.payload_length());
-                    let (header_payload, len_source, incomplete) =
-                        if 0 == payload_len && slice.len() > Ipv6Header::LEN {
-                            // zero set as payload len, assume jumbograms or unitialized
-                            // length and use the slice length as a fallback value
-                            // TODO: Add payload length parsing from the jumbogram for the zero case
-                            (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    )
-                                },
-                                LenSource::Slice,
-                                false,
-                            )
-                        } else if slice.len() - Ipv6Header::LEN < payload_len {
-                            // slice is smaller then the assumed payload length
-                            (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    )
-                                },
-                                LenSource::Slice,
-                                true, // incomplete
-                            )
-                        } else {
-                            // all good, all data should be here
-                            (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        payload_len,
-                                    )
-                                },
-                                LenSource::Ipv6HeaderPayloadLen,
-                                false,
-                            )
-                        };
+                    let (header_payload, len_source, incomplete) = if 0 == payload_len
+                        && slice.len() > Ipv6Header::LEN
+                    {
+                        // zero set as payload len, assume jumbograms or unitialized
+                        // length and use the slice length as a fallback value
+                        // TODO: Add payload length parsing from the jumbogram for the zero case
+                        (
+                            unchecked! {
+                                get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
+                            },
+                            LenSource::Slice,
+                            false,
+                        )
+                    } else if slice.len() - Ipv6Header::LEN < payload_len {
+                        // slice is smaller then the assumed payload length
+                        (
+                            unchecked! {
+                                get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
+                            },
+                            LenSource::Slice,
+                            true, // incomplete
+                        )
+                    } else {
+                        // all good, all data should be here
+                        (
+                            unchecked! {
+                                get_unchecked_slice(slice, Ipv6Header::LEN, payload_len)
+                            },
+                            LenSource::Ipv6HeaderPayloadLen,
+                            false,
+                        )
+                    };
 
                     // parse extension headers
                     let (exts, payload_ip_number, payload, mut ext_stop_err) =
@@ -694,6 +679,7 @@ mod test {
     }
 
     proptest! {
+        #[cfg(feature = "ipv6")]
         #[test]
         fn from_ip_slice(
             ipv4_header in ipv4_any(),
@@ -793,7 +779,7 @@ mod test {
                 }
 
                 // slice smaller then header error
-                for bad_len in 1..ipv4_header.header_len() {
+                for bad_len in 1..Ipv4Header::MIN_LEN {
                     assert_eq!(
                         LaxIpSlice::from_slice(&buffer[..bad_len]),
                         Err(E::Len(LenError{
@@ -806,6 +792,26 @@ mod test {
                     );
                 }
 
+                // options cut off
+                for bad_len in Ipv4Header::MIN_LEN..ipv4_header.header_len() {
+                    let (actual, actual_stop_err) = LaxIpSlice::from_slice(&buffer[..bad_len]).unwrap();
+                    assert_eq!(None, actual_stop_err);
+                    let actual = actual.ipv4().unwrap();
+                    assert!(actual.is_options_truncated());
+                    assert_eq!(&buffer[..Ipv4Header::MIN_LEN], actual.header().slice());
+                    assert_eq!(None, actual.extensions().auth);
+                    assert_eq!(
+                        actual.payload(),
+                        &LaxIpPayloadSlice{
+                            incomplete: true,
+                            ip_number: ipv4_header.protocol,
+                            fragmented: ipv4_header.is_fragmenting_payload(),
+                            len_source: LenSource::Slice,
+                            payload: &[],
+                        }
+                    );
+                }
+
                 // total len smaller then header
                 for bad_len in 1..ipv4_header.header_len() {
                     let mut buffer = buffer.clone();
@@ -19,6 +19,7 @@ pub struct LaxIpv4Slice<'a> {
     pub(crate) header: Ipv4HeaderSlice<'a>,
     pub(crate) exts: Ipv4ExtensionsSlice<'a>,
     pub(crate) payload: LaxIpPayloadSlice<'a>,
+    pub(crate) options_truncated: bool,
 }
 
 impl<'a> LaxIpv4Slice<'a> {
@@ -68,7 +69,14 @@ impl<'a> LaxIpv4Slice<'a> {
     /// if the `len_source` value in the returned [`LaxIpPayloadSlice`] is set to
     /// [`LenSource::Slice`]. If a substitution was not needed `len_source`
     /// is set to [`LenSource::Ipv4HeaderTotalLen`].
-
+    ///
+    /// ## What happens if the options are cut off?
+    ///
+    /// If the slice contains the fixed 20 bytes of the IPv4 header but not
+    /// all the option bytes indicated by the `ihl` field, only the fixed
+    /// part of the header is returned (without options) together with an
+    /// empty & `incomplete` payload. [`LaxIpv4Slice::is_options_truncated`]
+    /// is set to `true` in this case.
     pub fn from_slice(
         slice: &[u8],
     ) -> Result<(LaxIpv4Slice, Option<err::ip_auth::HeaderSliceError>), err::ipv4::HeaderSliceError>
@@ -76,7 +84,14 @@ impl<'a> LaxIpv4Slice<'a> {
         use crate::ip_number::AUTH;
 
         // decode the header
-        let header = Ipv4HeaderSlice::from_slice(slice)?;
+        let header = match Ipv4HeaderSlice::from_slice(slice) {
+            Ok(header) => header,
+            Err(err::ipv4::HeaderSliceError::Len(_)) if slice.len() >= Ipv4Header::MIN_LEN => {
+                // options cut off (version & ihl are validated before the length)
+                return Ok((LaxIpv4Slice::from_slice_options_truncated(slice), None));
+            }
+            Err(err) => return Err(err),
+        };
 
         // validate total_len at least contains the header
         let header_total_len: usize = header.total_len().into();
@@ -84,11 +99,8 @@ impl<'a> LaxIpv4Slice<'a> {
             // total_length is smaller then the header itself
             // fall back to the slice for the length
             (
-                unsafe {
-                    core::slice::from_raw_parts(
-                        slice.as_ptr().add(header.slice().len()),
-                        slice.len() - header.slice().len(),
-                    )
+                unchecked! {
+                    get_unchecked_slice(slice, header.slice().len(), slice.len() - header.slice().len())
                 },
                 LenSource::Slice,
                 // note that we have no indication that the packet is incomplete
@@ -97,11 +109,8 @@ impl<'a> LaxIpv4Slice<'a> {
         } else if header_total_len > slice.len() {
             // more data was expected, fallback to slice and report payload as "incomplete"
             (
-                unsafe {
-                    core::slice::from_raw_parts(
-                        slice.as_ptr().add(header.slice().len()),
-                        slice.len() - header.slice().len(),
-                    )
+                unchecked! {
+                    get_unchecked_slice(slice, header.slice().len(), slice.len() - header.slice().len())
                 },
                 LenSource::Slice,
                 true, // incomplete
@@ -109,11 +118,8 @@ impl<'a> LaxIpv4Slice<'a> {
         } else {
             // all good the packet seems to be complete
             (
-                unsafe {
-                    core::slice::from_raw_parts(
-                        slice.as_ptr().add(header.slice().len()),
-                        header_total_len - header.slice().len(),
-                    )
+                unchecked! {
+                    get_unchecked_slice(slice, header.slice().len(), header_total_len - header.slice().len())
                 },
                 LenSource::Ipv4HeaderTotalLen,
                 false,
@@ -130,11 +136,8 @@ impl<'a> LaxIpv4Slice<'a> {
                 match IpAuthHeaderSlice::from_slice(header_payload) {
                     Ok(auth) => {
                         // remove the extension header from the payload
-                        let payload = unsafe {
-                            core::slice::from_raw_parts(
-                                header_payload.as_ptr().add(auth.slice().len()),
-                                header_payload.len() - auth.slice().len(),
-                            )
+                        let payload = unchecked! {
+                            get_unchecked_slice(header_payload, auth.slice().len(), header_payload.len() - auth.slice().len())
                         };
                         let ip_number = auth.next_header();
                         Ok((
@@ -148,6 +151,7 @@ impl<'a> LaxIpv4Slice<'a> {
                                     len_source,
                                     payload,
                                 },
+                                options_truncated: false,
                             },
                             None,
                         ))
@@ -173,6 +177,7 @@ impl<'a> LaxIpv4Slice<'a> {
                                     len_source,
                                     payload: header_payload,
                                 },
+                                options_truncated: false,
                             },
                             Some(err),
                         ))
@@ -190,13 +195,45 @@ impl<'a> LaxIpv4Slice<'a> {
                         len_source,
                         payload: header_payload,
                     },
+                    options_truncated: false,
                 },
                 None,
             )),
         }
     }
 
+    /// Creates a slice containing only the fixed part of the IPv4 header of
+    /// a packet whose options are cut off & an empty incomplete payload.
+    ///
+    /// The caller has to ensure that `slice` contains at least
+    /// [`Ipv4Header::MIN_LEN`] bytes, that the version is 4 and that the
+    /// `ihl` is at least 5.
+    pub(crate) fn from_slice_options_truncated(slice: &'a [u8]) -> LaxIpv4Slice<'a> {
+        debug_assert!(slice.len() >= Ipv4Header::MIN_LEN);
+        // SAFETY: Cut off options are the documented exception of the
+        // from_slice_unchecked contract (the header length is determined by
+        // the slice length in all accessors of Ipv4HeaderSlice).
+        let header =
+            unchecked! { Ipv4HeaderSlice::from_slice_unchecked(&slice[..Ipv4Header::MIN_LEN]) };
+        LaxIpv4Slice {
+            header,
+            exts: Ipv4ExtensionsSlice { auth: None },
+            payload: LaxIpPayloadSlice {
+                incomplete: true,
+                ip_number: header.protocol(),
+                fragmented: header.is_fragmenting_payload(),
+                len_source: LenSource::Slice,
+                payload: &slice[slice.len()..],
+            },
+            options_truncated: true,
+        }
+    }
+
     /// Returns a slice containing the IPv4 header.
+    ///
+    /// If the options were cut off (see [`LaxIpv4Slice::is_options_truncated`])
+    /// the slice only contains the fixed 20 bytes of the header (without
+    /// options).
     #[inline]
     pub fn header(&self) -> Ipv4HeaderSlice {
         self.header
@@ -229,6 +266,14 @@ impl<'a> LaxIpv4Slice<'a> {
     pub fn is_payload_fragmented(&self) -> bool {
         self.header.is_fragmenting_payload()
     }
+
+    /// Returns true if the slice ended before all the options indicated
+    /// by the `ihl` field of the header (only the fixed part of the header
+    /// is present & the payload is empty).
+    #[inline]
+    pub fn is_options_truncated(&self) -> bool {
+        self.options_truncated
+    }
 }
 
 #[cfg(test)]
@@ -264,10 +309,11 @@ mod test {
             prop_assert_eq!(
                 format!("{:?}", slice),
                 format!(
-                    "LaxIpv4Slice {{ header: {:?}, exts: {:?}, payload: {:?} }}",
+                    "LaxIpv4Slice {{ header: {:?}, exts: {:?}, payload: {:?}, options_truncated: {:?} }}",
                     slice.header(),
                     slice.extensions(),
-                    slice.payload()
+                    slice.payload(),
+                    slice.is_options_truncated()
                 )
             );
             prop_assert_eq!(slice.clone(), slice);
@@ -367,17 +413,32 @@ mod test {
                 }
             }
 
-            // ihl len error
-            for short_ihl in 5..usize::from(v4.ihl()) {
+            // options cut off (fixed header part returned)
+            for len in Ipv4Header::MIN_LEN..usize::from(v4.ihl())*4 {
+                let (actual, actual_stop_err) = LaxIpv4Slice::from_slice(&buffer[..len]).unwrap();
+                assert_eq!(None, actual_stop_err);
+                assert!(actual.is_options_truncated());
+                assert_eq!(&buffer[..Ipv4Header::MIN_LEN], actual.header().slice());
+                assert_eq!(0, actual.header().options().len());
+                assert_eq!(v4.ihl(), actual.header().ihl());
                 assert_eq!(
-                    LaxIpv4Slice::from_slice(&buffer[..4*short_ihl]),
-                    Err(E::Len(err::LenError {
-                        required_len: usize::from(v4.ihl())*4,
-                        len: 4*short_ihl,
+                    actual.header().to_header(),
+                    {
+                        let mut expected = header.ipv4().unwrap().0.clone();
+                        expected.options = Ipv4Options::new();
+                        expected
+                    }
+                );
+                assert_eq!(None, actual.extensions().auth);
+                assert_eq!(
+                    actual.payload(),
+                    &LaxIpPayloadSlice{
+                        incomplete: true,
+                        ip_number: header.ipv4().unwrap().0.protocol,
+                        fragmented: v4.is_fragmenting_payload(),
                         len_source: LenSource::Slice,
-                        layer: err::Layer::Ipv4Header,
-                        layer_start_offset: 0,
-                    }))
+                        payload: &[],
+                    }
                 );
             }
 
@@ -79,50 +79,42 @@ impl<'a> LaxIpv6Slice<'a> {
         let header = Ipv6HeaderSlice::from_slice(slice)?;
 
         // restrict slice by the length specified in the header
-        let (header_payload, len_source, incomplete) =
-            if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
+        let (header_payload, len_source, incomplete) = if 0 == header.payload_length()
+            && slice.len() > Ipv6Header::LEN
+        {
+            // In case the payload_length is 0 assume that the entire
+            // rest of the slice is part of the packet until the jumbogram
+            // parameters can be parsed.
+
+            // TODO: Add payload length parsing from the jumbogram
+            (
+                unchecked! {
+                    get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
+                },
+                LenSource::Slice,
+                false,
+            )
+        } else {
+            let payload_len = usize::from(header.payload_length());
+            let expected_len = Ipv6Header::LEN + payload_len;
+            if slice.len() < expected_len {
                 (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
+                    unchecked! {
+                        get_unchecked_slice(slice, Ipv6Header::LEN, slice.len() - Ipv6Header::LEN)
                     },
                     LenSource::Slice,
-                    false,
+                    true,
                 )
             } else {
-                let payload_len = usize::from(header.payload_length());
-                let expected_len = Ipv6Header::LEN + payload_len;
-                if slice.len() < expected_len {
-                    (
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                slice.as_ptr().add(Ipv6Header::LEN),
-                                slice.len() - Ipv6Header::LEN,
-                            )
-                        },
-                        LenSource::Slice,
-                        true,
-                    )
-                } else {
-                    (
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                slice.as_ptr().add(Ipv6Header::LEN),
-                                payload_len,
-                            )
-                        },
-                        LenSource::Ipv6HeaderPayloadLen,
-                        false,
-                    )
-                }
-            };
+                (
+                    unchecked! {
+                        get_unchecked_slice(slice, Ipv6Header::LEN, payload_len)
+                    },
+                    LenSource::Ipv6HeaderPayloadLen,
+                    false,
+                )
+            }
+        };
 
         // parse extension headers
         let (exts, payload_ip_number, payload, mut ext_stop_err) =
@@ -0,0 +1,568 @@
+use crate::{err::ValueTooBigError, *};
+use arrayvec::ArrayVec;
+use core::fmt::{Debug, Formatter};
+
+/// IPv6 mobility header (RFC 6275) containing a decoded message & the
+/// raw mobility options.
+///
+/// When serializing the header the options are padded with Pad1 or PadN
+/// options so that the length of the header is a multiple of 8 octets.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, MobilityHeader, MobilityHeaderSlice, MobilityMessage};
+///
+/// let mut header = MobilityHeader::new(
+///     ip_number::IPV6_NO_NEXT_HEADER,
+///     MobilityMessage::BindingUpdate {
+///         sequence_number: 1,
+///         acknowledge: true,
+///         home_registration: true,
+///         link_local_compat: false,
+///         key_management_compat: false,
+///         lifetime: 100,
+///     },
+///     &[],
+/// )
+/// .unwrap();
+/// header.update_checksum_ipv6([1; 16], [2; 16]);
+///
+/// let bytes = header.to_bytes();
+/// assert_eq!(16, bytes.len());
+///
+/// let slice = MobilityHeaderSlice::from_slice(&bytes).unwrap();
+/// assert_eq!(header.message, slice.message());
+/// assert_eq!(header.checksum, slice.calc_checksum_ipv6([1; 16], [2; 16]));
+/// ```
+#[derive(Clone)]
+pub struct MobilityHeader {
+    /// IP number of the header following the mobility header (normally
+    /// [`ip_number::IPV6_NO_NEXT_HEADER`]).
+    pub payload_proto: IpNumber,
+    /// Message type & fixed message fields.
+    pub message: MobilityMessage,
+    /// Checksum of the mobility header (see [`MobilityHeader::calc_checksum_ipv6`]).
+    pub checksum: u16,
+    /// Length of the options.
+    pub(crate) options_len: usize,
+    /// Buffer containing the options.
+    pub(crate) options_buffer: [u8; MobilityHeader::MAX_OPTIONS_LEN],
+}
+
+impl Debug for MobilityHeader {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
+        let mut s = f.debug_struct("MobilityHeader");
+        s.field("payload_proto", &self.payload_proto);
+        s.field("message", &self.message);
+        s.field("checksum", &self.checksum);
+        s.field("options", &self.options());
+        s.finish()
+    }
+}
+
+impl PartialEq for MobilityHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.payload_proto == other.payload_proto
+            && self.message == other.message
+            && self.checksum == other.checksum
+            && self.options() == other.options()
+    }
+}
+
+impl Eq for MobilityHeader {}
+
+impl MobilityHeader {
+    /// Minimum length of a mobility header in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of a mobility header in bytes/octets.
+    pub const MAX_LEN: usize = 8 + 8 * 0xff;
+
+    /// Maximum length of the options in bytes/octets (maximum header length
+    /// minus the fixed header & message fields).
+    pub const MAX_OPTIONS_LEN: usize = MobilityHeader::MAX_LEN - 6 - MobilityMessage::MAX_FIXED_LEN;
+
+    /// Creates a mobility header with the given options & a checksum of 0.
+    ///
+    /// The options are padded when serializing the header, so they don't
+    /// need to be aligned. An error is returned if the options are longer
+    /// then [`MobilityHeader::MAX_OPTIONS_LEN`].
+    pub fn new(
+        payload_proto: IpNumber,
+        message: MobilityMessage,
+        options: &[u8],
+    ) -> Result<MobilityHeader, ValueTooBigError<usize>> {
+        let mut result = MobilityHeader {
+            payload_proto,
+            message,
+            checksum: 0,
+            options_len: 0,
+            options_buffer: [0; MobilityHeader::MAX_OPTIONS_LEN],
+        };
+        result.set_options(options)?;
+        Ok(result)
+    }
+
+    /// Read a mobility header from a slice and return the header & unused
+    /// parts of the slice.
+    pub fn from_slice(
+        slice: &[u8],
+    ) -> Result<(MobilityHeader, &[u8]), err::ipv6_mobility::HeaderSliceError> {
+        let s = MobilityHeaderSlice::from_slice(slice)?;
+        let rest = &slice[s.slice().len()..];
+        Ok((s.to_header(), rest))
+    }
+
+    /// Read a mobility header from the current reader position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(
+        reader: &mut T,
+    ) -> Result<MobilityHeader, err::ipv6_mobility::HeaderReadError> {
+        use err::ipv6_mobility::HeaderReadError::*;
+
+        let mut buffer = [0; MobilityHeader::MAX_LEN];
+        reader
+            .read_exact(&mut buffer[..MobilityHeader::MIN_LEN])
+            .map_err(Io)?;
+        let header_len = (usize::from(buffer[1]) + 1) * 8;
+        reader
+            .read_exact(&mut buffer[MobilityHeader::MIN_LEN..header_len])
+            .map_err(Io)?;
+
+        MobilityHeader::decode_buffer(&buffer[..header_len]).map_err(Content)
+    }
+
+    /// Read a mobility header from the current reader position
+    /// with a limited reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_limited<T: std::io::Read + Sized>(
+        reader: &mut crate::io::LimitedReader<T>,
+    ) -> Result<MobilityHeader, err::ipv6_mobility::HeaderLimitedReadError> {
+        use err::{
+            ipv6_mobility::HeaderLimitedReadError::{self, *},
+            Layer,
+        };
+
+        fn map_err(err: err::io::LimitedReadError) -> HeaderLimitedReadError {
+            use err::io::LimitedReadError as I;
+            match err {
+                I::Io(err) => Io(err),
+                I::Len(err) => Len(err),
+            }
+        }
+
+        // notify reader of layer start
+        reader.start_layer(Layer::Ipv6MobilityHeader);
+
+        let mut buffer = [0; MobilityHeader::MAX_LEN];
+        reader
+            .read_exact(&mut buffer[..MobilityHeader::MIN_LEN])
+            .map_err(map_err)?;
+        let header_len = (usize::from(buffer[1]) + 1) * 8;
+        reader
+            .read_exact(&mut buffer[MobilityHeader::MIN_LEN..header_len])
+            .map_err(map_err)?;
+
+        MobilityHeader::decode_buffer(&buffer[..header_len]).map_err(Content)
+    }
+
+    /// Decodes a complete mobility header (only content errors can occur
+    /// as the buffer length matches the header length).
+    #[cfg(feature = "std")]
+    fn decode_buffer(buffer: &[u8]) -> Result<MobilityHeader, err::ipv6_mobility::HeaderError> {
+        use err::ipv6_mobility::HeaderSliceError as I;
+        match MobilityHeaderSlice::from_slice(buffer) {
+            Ok(slice) => Ok(slice.to_header()),
+            Err(I::Content(err)) => Err(err),
+            // the buffer always contains the complete header
+            Err(I::Len(_)) => unreachable!(),
+        }
+    }
+
+    /// Returns the options (excluding the padding added when serializing).
+    #[inline]
+    pub fn options(&self) -> &[u8] {
+        &self.options_buffer[..self.options_len]
+    }
+
+    /// Sets the options of the header.
+    ///
+    /// An error is returned if the options are longer then
+    /// [`MobilityHeader::MAX_OPTIONS_LEN`] (the options are not changed
+    /// in this case).
+    pub fn set_options(&mut self, options: &[u8]) -> Result<(), ValueTooBigError<usize>> {
+        if options.len() > MobilityHeader::MAX_OPTIONS_LEN {
+            return Err(ValueTooBigError {
+                actual: options.len(),
+                max_allowed: MobilityHeader::MAX_OPTIONS_LEN,
+                value_type: err::ValueType::Ipv6MobilityOptionsLength,
+            });
+        }
+        self.options_buffer[..options.len()].copy_from_slice(options);
+        self.options_len = options.len();
+        Ok(())
+    }
+
+    /// Length of the serialized header in bytes/octets (including padding).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        let unpadded = 6 + self.message.fixed_len() + self.options_len;
+        // round up to the next multiple of 8
+        (unpadded + 7) & !7
+    }
+
+    /// Calculates the checksum of the mobility header based on the
+    /// IPv6 source & destination address.
+    pub fn calc_checksum_ipv6(&self, source: [u8; 16], destination: [u8; 16]) -> u16 {
+        MobilityHeader::calc_checksum_raw(source, destination, &self.to_bytes())
+    }
+
+    /// Calculates & sets the checksum based on the IPv6 source & destination
+    /// address.
+    #[inline]
+    pub fn update_checksum_ipv6(&mut self, source: [u8; 16], destination: [u8; 16]) {
+        self.checksum = self.calc_checksum_ipv6(source, destination);
+    }
+
+    /// Calculates the checksum of a serialized mobility header (the
+    /// checksum field in `header` is ignored).
+    pub(crate) fn calc_checksum_raw(source: [u8; 16], destination: [u8; 16], header: &[u8]) -> u16 {
+        // same pseudo header as ICMPv6 (RFC 6275, section 6.1.1)
+        checksum::Sum16BitWords::new()
+            .add_16bytes(source)
+            .add_16bytes(destination)
+            .add_4bytes((header.len() as u32).to_be_bytes())
+            .add_2bytes([0, ip_number::MOBILITY_HEADER.0])
+            .add_slice(&header[..4])
+            .add_slice(&header[6..])
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns the serialized header (including the padding of the options).
+    pub fn to_bytes(&self) -> ArrayVec<u8, { MobilityHeader::MAX_LEN }> {
+        let header_len = self.header_len();
+        let checksum = self.checksum.to_be_bytes();
+        let mut result = ArrayVec::new();
+        result.extend([
+            self.payload_proto.0,
+            ((header_len / 8) - 1) as u8,
+            self.message.mh_type().0,
+            0,
+            checksum[0],
+            checksum[1],
+        ]);
+        // Unwrap Panic Safety:
+        // The following unwraps should never panic, as the options are
+        // limited to MAX_OPTIONS_LEN, which leaves enough space for the
+        // fixed fields & the padding.
+        result
+            .try_extend_from_slice(&self.message.to_bytes())
+            .unwrap();
+        result.try_extend_from_slice(self.options()).unwrap();
+        match header_len - result.len() {
+            0 => {}
+            1 => result.push(MobilityOption::PAD1),
+            padding => {
+                result.push(MobilityOption::PADN);
+                result.push((padding - 2) as u8);
+                for _ in 2..padding {
+                    result.push(0);
+                }
+            }
+        }
+        result
+    }
+
+    /// Writes the header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    fn binding_ack(options: &[u8]) -> MobilityHeader {
+        MobilityHeader::new(
+            ip_number::IPV6_NO_NEXT_HEADER,
+            MobilityMessage::BindingAcknowledgement {
+                status: 0,
+                key_management_compat: true,
+                sequence_number: 2,
+                lifetime: 3,
+            },
+            options,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let header = binding_ack(&[3, 1, 2]);
+        assert_eq!(ip_number::IPV6_NO_NEXT_HEADER, header.payload_proto);
+        assert_eq!(0, header.checksum);
+        assert_eq!(&[3, 1, 2], header.options());
+
+        let too_big = [0; MobilityHeader::MAX_OPTIONS_LEN + 1];
+        assert_eq!(
+            ValueTooBigError {
+                actual: too_big.len(),
+                max_allowed: MobilityHeader::MAX_OPTIONS_LEN,
+                value_type: err::ValueType::Ipv6MobilityOptionsLength,
+            },
+            MobilityHeader::new(
+                ip_number::IPV6_NO_NEXT_HEADER,
+                MobilityMessage::BindingRefreshRequest,
+                &too_big
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn set_options() {
+        let mut header = binding_ack(&[]);
+        header.set_options(&[1, 0]).unwrap();
+        assert_eq!(&[1, 0], header.options());
+
+        let too_big = [0; MobilityHeader::MAX_OPTIONS_LEN + 1];
+        assert!(header.set_options(&too_big).is_err());
+        assert_eq!(&[1, 0], header.options());
+    }
+
+    #[test]
+    fn header_len_to_bytes_padding() {
+        // binding ack fixed part is 12 bytes
+        let tests: [(&[u8], usize, &[u8]); 4] = [
+            (&[], 16, &[1, 2, 0, 0]),
+            (&[2, 0], 16, &[2, 0, 1, 0]),
+            (&[2, 1, 9], 16, &[2, 1, 9, 0]),
+            (&[2, 2, 1, 2], 16, &[2, 2, 1, 2]),
+        ];
+        for (options, header_len, expected_rest) in tests {
+            let header = binding_ack(options);
+            assert_eq!(header_len, header.header_len());
+            let bytes = header.to_bytes();
+            assert_eq!(header_len, bytes.len());
+            assert_eq!(&[59, 1, 6, 0, 0, 0, 0, 0x80, 0, 2, 0, 3], &bytes[..12]);
+            assert_eq!(expected_rest, &bytes[12..]);
+        }
+
+        // binding refresh request with maximum options
+        let header = MobilityHeader::new(
+            ip_number::IPV6_NO_NEXT_HEADER,
+            MobilityMessage::BindingRefreshRequest,
+            &[0; MobilityHeader::MAX_OPTIONS_LEN],
+        )
+        .unwrap();
+        assert_eq!(MobilityHeader::MAX_LEN, header.header_len());
+        let bytes = header.to_bytes();
+        assert_eq!(MobilityHeader::MAX_LEN, bytes.len());
+        assert_eq!(0xff, bytes[1]);
+        assert_eq!(&[1, 2, 0, 0], &bytes[bytes.len() - 4..]);
+
+        // raw message with a single byte of padding
+        let header = MobilityHeader::new(
+            ip_number::IPV6_NO_NEXT_HEADER,
+            MobilityMessage::Raw(MobilityHeaderType::HOME_TEST_INIT),
+            &[1],
+        )
+        .unwrap();
+        assert_eq!(&[59, 0, 1, 0, 0, 0, 1, 0], &header.to_bytes()[..]);
+    }
+
+    #[test]
+    fn from_slice() {
+        let header = binding_ack(&[3, 1, 2]);
+        let mut bytes = header.to_bytes().to_vec();
+        bytes.push(1);
+        let (actual, rest) = MobilityHeader::from_slice(&bytes).unwrap();
+        // the padding is now part of the options
+        assert_eq!(header.message, actual.message);
+        assert_eq!(&[3, 1, 2, 0], actual.options());
+        assert_eq!(&[1], rest);
+
+        assert!(MobilityHeader::from_slice(&bytes[..4]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read() {
+        use std::io::Cursor;
+
+        let header = binding_ack(&[1, 2, 0, 0]);
+        let bytes = header.to_bytes();
+
+        // ok
+        {
+            let mut cursor = Cursor::new(&bytes);
+            assert_eq!(header, MobilityHeader::read(&mut cursor).unwrap());
+            assert_eq!(bytes.len(), cursor.position() as usize);
+        }
+
+        // io error
+        for len in 0..bytes.len() {
+            let mut cursor = Cursor::new(&bytes[..len]);
+            assert!(MobilityHeader::read(&mut cursor)
+                .unwrap_err()
+                .io()
+                .is_some());
+        }
+
+        // content error (header length too small for a binding ack)
+        {
+            let mut bytes = bytes.clone();
+            bytes[1] = 0;
+            let mut cursor = Cursor::new(&bytes);
+            assert_eq!(
+                Some(err::ipv6_mobility::HeaderError::MessageTooShort {
+                    mh_type: MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                    header_len: 8,
+                    min_header_len: 12,
+                }),
+                MobilityHeader::read(&mut cursor).unwrap_err().content()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_limited() {
+        use crate::io::LimitedReader;
+        use std::io::Cursor;
+
+        let header = binding_ack(&[1, 2, 0, 0]);
+        let bytes = header.to_bytes();
+
+        // ok
+        {
+            let mut cursor = Cursor::new(&bytes);
+            let mut reader = LimitedReader::new(
+                &mut cursor,
+                bytes.len(),
+                LenSource::Slice,
+                0,
+                err::Layer::Ipv6Header,
+            );
+            assert_eq!(header, MobilityHeader::read_limited(&mut reader).unwrap());
+        }
+
+        // io error
+        {
+            let mut cursor = Cursor::new(&bytes[..bytes.len() - 1]);
+            let mut reader = LimitedReader::new(
+                &mut cursor,
+                bytes.len(),
+                LenSource::Slice,
+                0,
+                err::Layer::Ipv6Header,
+            );
+            assert!(MobilityHeader::read_limited(&mut reader)
+                .unwrap_err()
+                .io()
+                .is_some());
+        }
+
+        // len error
+        {
+            let mut cursor = Cursor::new(&bytes);
+            let mut reader = LimitedReader::new(
+                &mut cursor,
+                bytes.len() - 1,
+                LenSource::Slice,
+                0,
+                err::Layer::Ipv6Header,
+            );
+            assert_eq!(
+                err::LenError {
+                    required_len: bytes.len(),
+                    len: bytes.len() - 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv6MobilityHeader,
+                    layer_start_offset: 0,
+                },
+                MobilityHeader::read_limited(&mut reader)
+                    .unwrap_err()
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // content error
+        {
+            let mut bytes = bytes.clone();
+            bytes[1] = 0;
+            let mut cursor = Cursor::new(&bytes);
+            let mut reader = LimitedReader::new(
+                &mut cursor,
+                bytes.len(),
+                LenSource::Slice,
+                0,
+                err::Layer::Ipv6Header,
+            );
+            assert!(MobilityHeader::read_limited(&mut reader)
+                .unwrap_err()
+                .content()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn checksum() {
+        let mut header = binding_ack(&[3, 1, 2]);
+        header.checksum = 0x1234;
+        let source = [1; 16];
+        let destination = [2; 16];
+        let expected = {
+            let bytes = header.to_bytes();
+            let mut sum: u32 = 0;
+            for chunk in source.chunks(2).chain(destination.chunks(2)) {
+                sum += u32::from(u16::from_be_bytes([chunk[0], chunk[1]]));
+            }
+            sum += bytes.len() as u32;
+            sum += u32::from(ip_number::MOBILITY_HEADER.0);
+            for (i, chunk) in bytes.chunks(2).enumerate() {
+                // skip checksum field
+                if i != 2 {
+                    sum += u32::from(u16::from_be_bytes([chunk[0], chunk[1]]));
+                }
+            }
+            while sum > 0xffff {
+                sum = (sum & 0xffff) + (sum >> 16);
+            }
+            !(sum as u16)
+        };
+        assert_eq!(expected, header.calc_checksum_ipv6(source, destination));
+        header.update_checksum_ipv6(source, destination);
+        assert_eq!(expected, header.checksum);
+    }
+
+    #[test]
+    fn write() {
+        let header = binding_ack(&[3, 1, 2]);
+        let mut buffer = Vec::new();
+        header.write(&mut buffer).unwrap();
+        assert_eq!(&header.to_bytes()[..], &buffer[..]);
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let header = binding_ack(&[3, 1, 2]);
+        assert_eq!(header, header.clone());
+        assert_eq!(
+            format!(
+                "MobilityHeader {{ payload_proto: {:?}, message: {:?}, checksum: 0, options: [3, 1, 2] }}",
+                header.payload_proto, header.message
+            ),
+            format!("{:?}", header)
+        );
+    }
+}
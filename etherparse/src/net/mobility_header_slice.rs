@@ -0,0 +1,296 @@
+use crate::{err::ipv6_mobility::*, *};
+
+/// Slice containing an IPv6 mobility header (RFC 6275).
+///
+/// The mobility header is identified by the IP number
+/// [`ip_number::MOBILITY_HEADER`] and is normally the last header in the
+/// IPv6 header chain ("payload proto" is set to [`ip_number::IPV6_NO_NEXT_HEADER`]).
+/// When an IPv6 packet is decoded (e.g. via [`SlicedPacket`]) it is part of
+/// the IPv6 extension headers (see [`Ipv6ExtensionSlice::Mobility`]):
+///
+/// ```
+/// # #[cfg(feature = "ipv6")]
+/// # {
+/// # use etherparse::*;
+/// # let header = MobilityHeader::new(
+/// #     ip_number::IPV6_NO_NEXT_HEADER,
+/// #     MobilityMessage::BindingRefreshRequest,
+/// #     &[],
+/// # ).unwrap();
+/// # let mut packet = Ipv6Header {
+/// #     payload_length: header.header_len() as u16,
+/// #     next_header: ip_number::MOBILITY_HEADER,
+/// #     hop_limit: 64,
+/// #     ..Default::default()
+/// # }.to_bytes().to_vec();
+/// # packet.extend_from_slice(&header.to_bytes());
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(NetSlice::Ipv6(ipv6)) = &sliced.net {
+///     for ext in ipv6.extensions().clone() {
+///         if let Ipv6ExtensionSlice::Mobility(mh) = ext {
+///             assert_eq!(MobilityMessage::BindingRefreshRequest, mh.message());
+///             for option in mh.options_iterator() {
+///                 println!("{:?}", option);
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MobilityHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> MobilityHeaderSlice<'a> {
+    /// Creates a mobility header slice from a slice.
+    ///
+    /// The slice can be longer then the header (the returned slice only
+    /// contains the header itself).
+    pub fn from_slice(slice: &'a [u8]) -> Result<MobilityHeaderSlice<'a>, HeaderSliceError> {
+        use HeaderSliceError::*;
+
+        if slice.len() < MobilityHeader::MIN_LEN {
+            return Err(Len(err::LenError {
+                required_len: MobilityHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv6MobilityHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let header_len = (usize::from(slice[1]) + 1) * 8;
+        if slice.len() < header_len {
+            return Err(Len(err::LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv6MobilityHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let mh_type = MobilityHeaderType(slice[2]);
+        let min_header_len = 6 + MobilityMessage::fixed_len_for(mh_type);
+        if header_len < min_header_len {
+            return Err(Content(HeaderError::MessageTooShort {
+                mh_type,
+                header_len,
+                min_header_len,
+            }));
+        }
+
+        Ok(MobilityHeaderSlice {
+            slice: &slice[..header_len],
+        })
+    }
+
+    unchecked_fn! {
+        /// Creates a mobility header slice from a slice that was already
+        /// checked via [`MobilityHeaderSlice::from_slice`] (a safe function
+        /// if the `safe-only` feature is enabled).
+        pub(crate) fn from_slice_unchecked_internal(slice: &'a [u8]) -> MobilityHeaderSlice<'a> {
+            MobilityHeaderSlice {
+                slice: get_unchecked_slice(slice, 0, (usize::from(get_unchecked_u8(slice, 1)) + 1) * 8),
+            }
+        }
+    }
+
+    /// Returns the slice containing the mobility header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "payload proto" field (IP number of the header following
+    /// the mobility header, normally [`ip_number::IPV6_NO_NEXT_HEADER`]).
+    #[inline]
+    pub fn payload_proto(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Read the "header length" field (length in 8 octets units,
+    /// excluding the first 8 octets).
+    #[inline]
+    pub fn header_length(&self) -> u8 {
+        self.slice[1]
+    }
+
+    /// Length of the mobility header in bytes/octets.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Read the message type ("MH Type") field.
+    #[inline]
+    pub fn mh_type(&self) -> MobilityHeaderType {
+        MobilityHeaderType(self.slice[2])
+    }
+
+    /// Read the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[4], self.slice[5]])
+    }
+
+    /// Decodes the fixed fields of the message.
+    #[inline]
+    pub fn message(&self) -> MobilityMessage {
+        MobilityMessage::from_data(self.mh_type(), &self.slice[6..])
+    }
+
+    /// Returns the slice containing the mobility options (for
+    /// [`MobilityMessage::Raw`] messages this is the complete message data).
+    #[inline]
+    pub fn options(&self) -> &'a [u8] {
+        &self.slice[6 + MobilityMessage::fixed_len_for(self.mh_type())..]
+    }
+
+    /// Returns an iterator over the mobility options.
+    #[inline]
+    pub fn options_iterator(&self) -> MobilityOptionsIterator<'a> {
+        MobilityOptionsIterator::from_slice(self.options())
+    }
+
+    /// Calculates the checksum of the mobility header based on the
+    /// IPv6 source & destination address.
+    #[inline]
+    pub fn calc_checksum_ipv6(&self, source: [u8; 16], destination: [u8; 16]) -> u16 {
+        MobilityHeader::calc_checksum_raw(source, destination, self.slice)
+    }
+
+    /// Decode all the fields and copy the results to a [`MobilityHeader`] struct.
+    pub fn to_header(&self) -> MobilityHeader {
+        let options = self.options();
+        let mut options_buffer = [0; MobilityHeader::MAX_OPTIONS_LEN];
+        // the options are always shorter then the buffer as the buffer is
+        // sized for the maximum header length minus the fixed fields
+        let options_len = options.len().min(MobilityHeader::MAX_OPTIONS_LEN);
+        options_buffer[..options_len].copy_from_slice(&options[..options_len]);
+        MobilityHeader {
+            payload_proto: self.payload_proto(),
+            message: self.message(),
+            checksum: self.checksum(),
+            options_len,
+            options_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    fn binding_update_bytes() -> Vec<u8> {
+        let mut header = MobilityHeader::new(
+            ip_number::IPV6_NO_NEXT_HEADER,
+            MobilityMessage::BindingUpdate {
+                sequence_number: 0x1234,
+                acknowledge: true,
+                home_registration: true,
+                link_local_compat: false,
+                key_management_compat: false,
+                lifetime: 100,
+            },
+            &[3, 2, 1, 2],
+        )
+        .unwrap();
+        header.update_checksum_ipv6([1; 16], [2; 16]);
+        header.to_bytes().to_vec()
+    }
+
+    #[test]
+    fn from_slice() {
+        let mut bytes = binding_update_bytes();
+        bytes.extend_from_slice(&[9, 9]);
+
+        let slice = MobilityHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(&bytes[..16], slice.slice());
+        assert_eq!(ip_number::IPV6_NO_NEXT_HEADER, slice.payload_proto());
+        assert_eq!(1, slice.header_length());
+        assert_eq!(16, slice.header_len());
+        assert_eq!(MobilityHeaderType::BINDING_UPDATE, slice.mh_type());
+        assert_eq!(u16::from_be_bytes([bytes[4], bytes[5]]), slice.checksum());
+        assert_eq!(
+            MobilityMessage::BindingUpdate {
+                sequence_number: 0x1234,
+                acknowledge: true,
+                home_registration: true,
+                link_local_compat: false,
+                key_management_compat: false,
+                lifetime: 100,
+            },
+            slice.message()
+        );
+        assert_eq!(&[3, 2, 1, 2], slice.options());
+        assert_eq!(
+            Some(Ok(MobilityOption {
+                option_type: MobilityOption::ALTERNATE_CARE_OF_ADDRESS,
+                data: &[1, 2],
+            })),
+            slice.options_iterator().next()
+        );
+        assert_eq!(slice.checksum(), slice.calc_checksum_ipv6([1; 16], [2; 16]));
+        assert_eq!(slice.to_header().to_bytes().as_slice(), slice.slice());
+    }
+
+    #[test]
+    fn from_slice_len_error() {
+        let bytes = binding_update_bytes();
+        for len in 0..bytes.len() {
+            assert_eq!(
+                HeaderSliceError::Len(err::LenError {
+                    required_len: if len < 8 { 8 } else { 16 },
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv6MobilityHeader,
+                    layer_start_offset: 0,
+                }),
+                MobilityHeaderSlice::from_slice(&bytes[..len]).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_slice_message_too_short() {
+        for mh_type in [
+            MobilityHeaderType::BINDING_UPDATE,
+            MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+        ] {
+            let bytes = [59, 0, mh_type.0, 0, 0, 0, 0, 0];
+            assert_eq!(
+                HeaderSliceError::Content(HeaderError::MessageTooShort {
+                    mh_type,
+                    header_len: 8,
+                    min_header_len: 12,
+                }),
+                MobilityHeaderSlice::from_slice(&bytes).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn raw_message() {
+        let bytes = [59, 0, MobilityHeaderType::HOME_TEST_INIT.0, 0, 0, 0, 1, 2];
+        let slice = MobilityHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(
+            MobilityMessage::Raw(MobilityHeaderType::HOME_TEST_INIT),
+            slice.message()
+        );
+        assert_eq!(&[1, 2], slice.options());
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let bytes = binding_update_bytes();
+        let slice = MobilityHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!("MobilityHeaderSlice {{ slice: {:?} }}", slice.slice()),
+            format!("{:?}", slice)
+        );
+    }
+}
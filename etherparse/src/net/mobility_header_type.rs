@@ -0,0 +1,69 @@
+/// Message type ("MH Type") of an IPv6 mobility header
+/// (see [`crate::MobilityHeader`]).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `MobilityHeaderType`:
+///
+/// ```
+/// use etherparse::MobilityHeaderType;
+///
+/// assert_eq!(MobilityHeaderType::BINDING_UPDATE.0, 5);
+///
+/// let mh_type: MobilityHeaderType = 6.into();
+/// assert_eq!(MobilityHeaderType::BINDING_ACKNOWLEDGEMENT, mh_type);
+///
+/// let num: u8 = MobilityHeaderType::BINDING_ACKNOWLEDGEMENT.into();
+/// assert_eq!(6, num);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Default, Hash)]
+pub struct MobilityHeaderType(pub u8);
+
+impl MobilityHeaderType {
+    /// Binding Refresh Request (RFC 6275).
+    pub const BINDING_REFRESH_REQUEST: MobilityHeaderType = Self(0);
+    /// Home Test Init (RFC 6275).
+    pub const HOME_TEST_INIT: MobilityHeaderType = Self(1);
+    /// Care-of Test Init (RFC 6275).
+    pub const CARE_OF_TEST_INIT: MobilityHeaderType = Self(2);
+    /// Home Test (RFC 6275).
+    pub const HOME_TEST: MobilityHeaderType = Self(3);
+    /// Care-of Test (RFC 6275).
+    pub const CARE_OF_TEST: MobilityHeaderType = Self(4);
+    /// Binding Update (RFC 6275).
+    pub const BINDING_UPDATE: MobilityHeaderType = Self(5);
+    /// Binding Acknowledgement (RFC 6275).
+    pub const BINDING_ACKNOWLEDGEMENT: MobilityHeaderType = Self(6);
+    /// Binding Error (RFC 6275).
+    pub const BINDING_ERROR: MobilityHeaderType = Self(7);
+}
+
+impl From<u8> for MobilityHeaderType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        MobilityHeaderType(val)
+    }
+}
+
+impl From<MobilityHeaderType> for u8 {
+    #[inline]
+    fn from(val: MobilityHeaderType) -> Self {
+        val.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_into_u8() {
+        for v in [0, 1, 5, 6, 0xff] {
+            assert_eq!(v, u8::from(MobilityHeaderType::from(v)));
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(MobilityHeaderType(0), MobilityHeaderType::default());
+    }
+}
@@ -0,0 +1,270 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// Message contained in an IPv6 mobility header (fixed fields after
+/// the checksum, excluding the mobility options).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MobilityMessage {
+    /// Binding Refresh Request (RFC 6275, section 6.1.2).
+    BindingRefreshRequest,
+    /// Binding Update (RFC 6275, section 6.1.7).
+    BindingUpdate {
+        /// Sequence number used to match a binding acknowledgement.
+        sequence_number: u16,
+        /// "Acknowledge" (A) flag requesting a binding acknowledgement.
+        acknowledge: bool,
+        /// "Home Registration" (H) flag.
+        home_registration: bool,
+        /// "Link-Local Address Compatibility" (L) flag.
+        link_local_compat: bool,
+        /// "Key Management Mobility Capability" (K) flag.
+        key_management_compat: bool,
+        /// Number of time units (4 seconds each) remaining before the
+        /// binding must be considered expired.
+        lifetime: u16,
+    },
+    /// Binding Acknowledgement (RFC 6275, section 6.1.8).
+    BindingAcknowledgement {
+        /// Disposition of the binding update (values below 128
+        /// indicate that the binding update was accepted).
+        status: u8,
+        /// "Key Management Mobility Capability" (K) flag.
+        key_management_compat: bool,
+        /// Sequence number copied from the binding update.
+        sequence_number: u16,
+        /// Granted lifetime in time units of 4 seconds.
+        lifetime: u16,
+    },
+    /// Message type that is not decoded. The complete message data (everything
+    /// after the checksum) is treated as "options" by [`MobilityHeader`] &
+    /// [`MobilityHeaderSlice`].
+    Raw(MobilityHeaderType),
+}
+
+impl MobilityMessage {
+    /// Maximum length of the fixed message fields in bytes/octets.
+    pub const MAX_FIXED_LEN: usize = 6;
+
+    /// Returns the message type ("MH Type") of the message.
+    pub fn mh_type(&self) -> MobilityHeaderType {
+        use MobilityMessage::*;
+        match self {
+            BindingRefreshRequest => MobilityHeaderType::BINDING_REFRESH_REQUEST,
+            BindingUpdate { .. } => MobilityHeaderType::BINDING_UPDATE,
+            BindingAcknowledgement { .. } => MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+            Raw(mh_type) => *mh_type,
+        }
+    }
+
+    /// Returns the length of the fixed message fields in bytes/octets
+    /// (after the checksum & before the mobility options).
+    pub fn fixed_len(&self) -> usize {
+        MobilityMessage::fixed_len_for(self.mh_type())
+    }
+
+    /// Returns the length of the fixed message fields decoded for the
+    /// given message type.
+    pub(crate) fn fixed_len_for(mh_type: MobilityHeaderType) -> usize {
+        match mh_type {
+            MobilityHeaderType::BINDING_REFRESH_REQUEST => 2,
+            MobilityHeaderType::BINDING_UPDATE | MobilityHeaderType::BINDING_ACKNOWLEDGEMENT => 6,
+            _ => 0,
+        }
+    }
+
+    /// Decodes the message from the data after the checksum.
+    ///
+    /// The caller must ensure that `data` contains at least
+    /// `fixed_len_for(mh_type)` bytes.
+    pub(crate) fn from_data(mh_type: MobilityHeaderType, data: &[u8]) -> MobilityMessage {
+        use MobilityMessage::*;
+        match mh_type {
+            MobilityHeaderType::BINDING_REFRESH_REQUEST => BindingRefreshRequest,
+            MobilityHeaderType::BINDING_UPDATE => BindingUpdate {
+                sequence_number: u16::from_be_bytes([data[0], data[1]]),
+                acknowledge: 0 != data[2] & 0x80,
+                home_registration: 0 != data[2] & 0x40,
+                link_local_compat: 0 != data[2] & 0x20,
+                key_management_compat: 0 != data[2] & 0x10,
+                lifetime: u16::from_be_bytes([data[4], data[5]]),
+            },
+            MobilityHeaderType::BINDING_ACKNOWLEDGEMENT => BindingAcknowledgement {
+                status: data[0],
+                key_management_compat: 0 != data[1] & 0x80,
+                sequence_number: u16::from_be_bytes([data[2], data[3]]),
+                lifetime: u16::from_be_bytes([data[4], data[5]]),
+            },
+            mh_type => Raw(mh_type),
+        }
+    }
+
+    /// Returns the serialized fixed message fields.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { MobilityMessage::MAX_FIXED_LEN }> {
+        use MobilityMessage::*;
+        let mut result = ArrayVec::new();
+        match self {
+            BindingRefreshRequest => {
+                result.extend([0, 0]);
+            }
+            BindingUpdate {
+                sequence_number,
+                acknowledge,
+                home_registration,
+                link_local_compat,
+                key_management_compat,
+                lifetime,
+            } => {
+                let seq = sequence_number.to_be_bytes();
+                let lt = lifetime.to_be_bytes();
+                let flags = (if *acknowledge { 0x80 } else { 0 })
+                    | (if *home_registration { 0x40 } else { 0 })
+                    | (if *link_local_compat { 0x20 } else { 0 })
+                    | (if *key_management_compat { 0x10 } else { 0 });
+                result.extend([seq[0], seq[1], flags, 0, lt[0], lt[1]]);
+            }
+            BindingAcknowledgement {
+                status,
+                key_management_compat,
+                sequence_number,
+                lifetime,
+            } => {
+                let seq = sequence_number.to_be_bytes();
+                let lt = lifetime.to_be_bytes();
+                result.extend([
+                    *status,
+                    if *key_management_compat { 0x80 } else { 0 },
+                    seq[0],
+                    seq[1],
+                    lt[0],
+                    lt[1],
+                ]);
+            }
+            Raw(_) => {}
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn mh_type_fixed_len() {
+        use MobilityMessage::*;
+        let tests = [
+            (
+                BindingRefreshRequest,
+                MobilityHeaderType::BINDING_REFRESH_REQUEST,
+                2,
+            ),
+            (
+                BindingUpdate {
+                    sequence_number: 0,
+                    acknowledge: false,
+                    home_registration: false,
+                    link_local_compat: false,
+                    key_management_compat: false,
+                    lifetime: 0,
+                },
+                MobilityHeaderType::BINDING_UPDATE,
+                6,
+            ),
+            (
+                BindingAcknowledgement {
+                    status: 0,
+                    key_management_compat: false,
+                    sequence_number: 0,
+                    lifetime: 0,
+                },
+                MobilityHeaderType::BINDING_ACKNOWLEDGEMENT,
+                6,
+            ),
+            (
+                Raw(MobilityHeaderType::HOME_TEST),
+                MobilityHeaderType::HOME_TEST,
+                0,
+            ),
+        ];
+        for (message, mh_type, fixed_len) in tests {
+            assert_eq!(mh_type, message.mh_type());
+            assert_eq!(fixed_len, message.fixed_len());
+            assert_eq!(fixed_len, message.to_bytes().len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn binding_update_to_bytes_from_data(
+            sequence_number in any::<u16>(),
+            acknowledge in any::<bool>(),
+            home_registration in any::<bool>(),
+            link_local_compat in any::<bool>(),
+            key_management_compat in any::<bool>(),
+            lifetime in any::<u16>(),
+        ) {
+            let message = MobilityMessage::BindingUpdate {
+                sequence_number,
+                acknowledge,
+                home_registration,
+                link_local_compat,
+                key_management_compat,
+                lifetime,
+            };
+            let bytes = message.to_bytes();
+            assert_eq!(&bytes[..2], &sequence_number.to_be_bytes());
+            assert_eq!(0, bytes[2] & 0x0f);
+            assert_eq!(0, bytes[3]);
+            assert_eq!(&bytes[4..], &lifetime.to_be_bytes());
+            assert_eq!(
+                message,
+                MobilityMessage::from_data(MobilityHeaderType::BINDING_UPDATE, &bytes)
+            );
+        }
+
+        #[test]
+        fn binding_ack_to_bytes_from_data(
+            status in any::<u8>(),
+            key_management_compat in any::<bool>(),
+            sequence_number in any::<u16>(),
+            lifetime in any::<u16>(),
+        ) {
+            let message = MobilityMessage::BindingAcknowledgement {
+                status,
+                key_management_compat,
+                sequence_number,
+                lifetime,
+            };
+            let bytes = message.to_bytes();
+            assert_eq!(status, bytes[0]);
+            assert_eq!(0, bytes[1] & 0x7f);
+            assert_eq!(&bytes[2..4], &sequence_number.to_be_bytes());
+            assert_eq!(&bytes[4..], &lifetime.to_be_bytes());
+            assert_eq!(
+                message,
+                MobilityMessage::from_data(MobilityHeaderType::BINDING_ACKNOWLEDGEMENT, &bytes)
+            );
+        }
+    }
+
+    #[test]
+    fn from_data_other() {
+        assert_eq!(
+            MobilityMessage::BindingRefreshRequest,
+            MobilityMessage::from_data(MobilityHeaderType::BINDING_REFRESH_REQUEST, &[0, 0])
+        );
+        assert_eq!(
+            MobilityMessage::Raw(MobilityHeaderType::BINDING_ERROR),
+            MobilityMessage::from_data(MobilityHeaderType::BINDING_ERROR, &[])
+        );
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = MobilityMessage::Raw(MobilityHeaderType(100));
+        assert_eq!(value, value.clone());
+        assert_eq!("Raw(MobilityHeaderType(100))", format!("{:?}", value));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::*;
+
+/// Mobility option contained in an IPv6 mobility header
+/// (see [`MobilityOptionsIterator`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct MobilityOption<'a> {
+    /// Type of the option.
+    pub option_type: u8,
+    /// Data of the option (excluding the type & length fields).
+    pub data: &'a [u8],
+}
+
+impl MobilityOption<'_> {
+    /// Single byte padding (no length & data fields are present).
+    pub const PAD1: u8 = 0;
+    /// Padding of two or more bytes.
+    pub const PADN: u8 = 1;
+    /// Binding Refresh Advice (RFC 6275).
+    pub const BINDING_REFRESH_ADVICE: u8 = 2;
+    /// Alternate Care-of Address (RFC 6275).
+    pub const ALTERNATE_CARE_OF_ADDRESS: u8 = 3;
+    /// Nonce Indices (RFC 6275).
+    pub const NONCE_INDICES: u8 = 4;
+    /// Binding Authorization Data (RFC 6275).
+    pub const BINDING_AUTHORIZATION_DATA: u8 = 5;
+
+    /// Returns true if the option is a padding option (Pad1 or PadN).
+    #[inline]
+    pub fn is_padding(&self) -> bool {
+        self.option_type == Self::PAD1 || self.option_type == Self::PADN
+    }
+}
+
+/// Iterator over the mobility options of an IPv6 mobility header.
+///
+/// Padding options (Pad1 & PadN) are also returned by the iterator
+/// (use [`MobilityOption::is_padding`] to filter them). If an option
+/// is truncated an error is returned & the iteration ends.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MobilityOptionsIterator<'a> {
+    options: &'a [u8],
+}
+
+impl<'a> MobilityOptionsIterator<'a> {
+    /// Creates an iterator over the mobility options in the given slice.
+    #[inline]
+    pub fn from_slice(options: &'a [u8]) -> MobilityOptionsIterator<'a> {
+        MobilityOptionsIterator { options }
+    }
+
+    /// Returns the not yet iterated part of the options.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.options
+    }
+}
+
+impl<'a> Iterator for MobilityOptionsIterator<'a> {
+    type Item = Result<MobilityOption<'a>, err::LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let option_type = *self.options.first()?;
+        if option_type == MobilityOption::PAD1 {
+            self.options = &self.options[1..];
+            return Some(Ok(MobilityOption {
+                option_type,
+                data: &[],
+            }));
+        }
+        let len_error = |required_len| err::LenError {
+            required_len,
+            len: self.options.len(),
+            len_source: LenSource::Slice,
+            layer: err::Layer::Ipv6MobilityHeader,
+            layer_start_offset: 0,
+        };
+        if self.options.len() < 2 {
+            let err = len_error(2);
+            self.options = &[];
+            return Some(Err(err));
+        }
+        let end = 2 + usize::from(self.options[1]);
+        if self.options.len() < end {
+            let err = len_error(end);
+            self.options = &[];
+            return Some(Err(err));
+        }
+        let data = &self.options[2..end];
+        self.options = &self.options[end..];
+        Some(Ok(MobilityOption { option_type, data }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn is_padding() {
+        for option_type in 0..=u8::MAX {
+            assert_eq!(
+                option_type <= 1,
+                MobilityOption {
+                    option_type,
+                    data: &[]
+                }
+                .is_padding()
+            );
+        }
+    }
+
+    #[test]
+    fn iterate() {
+        let data = [0, 1, 2, 0, 0, 3, 1, 9, 2, 0];
+        let mut iter = MobilityOptionsIterator::from_slice(&data);
+        assert_eq!(&data[..], iter.rest());
+        let options: Vec<_> = iter.by_ref().collect();
+        assert_eq!(
+            &[
+                Ok(MobilityOption {
+                    option_type: MobilityOption::PAD1,
+                    data: &[]
+                }),
+                Ok(MobilityOption {
+                    option_type: MobilityOption::PADN,
+                    data: &[0, 0]
+                }),
+                Ok(MobilityOption {
+                    option_type: MobilityOption::ALTERNATE_CARE_OF_ADDRESS,
+                    data: &[9]
+                }),
+                Ok(MobilityOption {
+                    option_type: MobilityOption::BINDING_REFRESH_ADVICE,
+                    data: &[]
+                }),
+            ][..],
+            &options[..]
+        );
+        assert!(iter.rest().is_empty());
+    }
+
+    #[test]
+    fn iterate_len_error() {
+        // missing length field
+        {
+            let mut iter = MobilityOptionsIterator::from_slice(&[3]);
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 2,
+                    len: 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv6MobilityHeader,
+                    layer_start_offset: 0,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+        // data too short
+        {
+            let mut iter = MobilityOptionsIterator::from_slice(&[3, 2, 1]);
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 4,
+                    len: 3,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv6MobilityHeader,
+                    layer_start_offset: 0,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let option = MobilityOption {
+            option_type: 3,
+            data: &[1],
+        };
+        assert_eq!(option, option.clone());
+        assert_eq!(
+            "MobilityOption { option_type: 3, data: [1] }",
+            format!("{:?}", option)
+        );
+        let iter = MobilityOptionsIterator::from_slice(&[0]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "MobilityOptionsIterator { options: [0] }",
+            format!("{:?}", iter)
+        );
+    }
+}
@@ -1,3 +1,27 @@
+#[cfg(feature = "arp")]
+mod arp_eth_ipv4_packet;
+#[cfg(feature = "arp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arp")))]
+pub use arp_eth_ipv4_packet::*;
+
+#[cfg(feature = "arp")]
+mod arp_operation;
+#[cfg(feature = "arp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arp")))]
+pub use arp_operation::*;
+
+#[cfg(feature = "arp")]
+mod arp_packet;
+#[cfg(feature = "arp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arp")))]
+pub use arp_packet::*;
+
+mod hip_header_slice;
+pub use hip_header_slice::*;
+
+mod hip_packet_type;
+pub use hip_packet_type::*;
+
 mod ip_auth_header;
 pub use ip_auth_header::*;
 
@@ -22,9 +46,15 @@ pub use ip_slice::*;
 mod ipv4_dscp;
 pub use ipv4_dscp::*;
 
+mod ipv4_dscp_named;
+pub use ipv4_dscp_named::*;
+
 mod ipv4_ecn;
 pub use ipv4_ecn::*;
 
+mod ipv4_ecn_named;
+pub use ipv4_ecn_named::*;
+
 mod ipv4_exts;
 pub use ipv4_exts::*;
 
@@ -34,15 +64,30 @@ pub use ipv4_exts_slice::*;
 mod ipv4_header;
 pub use ipv4_header::*;
 
+mod ipv4_header_raw;
+pub use ipv4_header_raw::*;
+
 mod ipv4_header_slice;
 pub use ipv4_header_slice::*;
 
+mod ipv4_header_slice_mut;
+pub use ipv4_header_slice_mut::*;
+
+mod ipv4_network;
+pub use ipv4_network::*;
+
 mod ipv4_options;
 pub use ipv4_options::*;
 
+mod ipv4_raw_option;
+pub use ipv4_raw_option::*;
+
 mod ipv4_slice;
 pub use ipv4_slice::*;
 
+mod ipv6_addr_impl;
+pub use ipv6_addr_impl::*;
+
 mod ipv6_ext_slice;
 pub use ipv6_ext_slice::*;
 
@@ -52,6 +97,9 @@ pub use ipv6_ext_slice_iter::*;
 mod ipv6_exts;
 pub use ipv6_exts::*;
 
+mod ipv6_exts_builder;
+pub use ipv6_exts_builder::*;
+
 mod ipv6_exts_slice;
 pub use ipv6_exts_slice::*;
 
@@ -70,6 +118,15 @@ pub use ipv6_header::*;
 mod ipv6_header_slice;
 pub use ipv6_header_slice::*;
 
+mod ipv6_header_slice_mut;
+pub use ipv6_header_slice_mut::*;
+
+mod ipv6_multicast_scope;
+pub use ipv6_multicast_scope::*;
+
+mod ipv6_network;
+pub use ipv6_network::*;
+
 mod ipv6_raw_ext_header;
 pub use ipv6_raw_ext_header::*;
 
@@ -82,6 +139,9 @@ pub use ipv6_routing_exts::*;
 mod ipv6_slice;
 pub use ipv6_slice::*;
 
+mod lax_ip_len_discrepancy;
+pub use lax_ip_len_discrepancy::*;
+
 mod lax_ip_payload_slice;
 pub use lax_ip_payload_slice::*;
 
@@ -97,8 +157,29 @@ pub use lax_ipv6_slice::*;
 mod lax_net_slice;
 pub use lax_net_slice::*;
 
+mod mobility_header;
+pub use mobility_header::*;
+
+mod mobility_header_slice;
+pub use mobility_header_slice::*;
+
+mod mobility_header_type;
+pub use mobility_header_type::*;
+
+mod mobility_message;
+pub use mobility_message::*;
+
+mod mobility_option;
+pub use mobility_option::*;
+
 mod net_headers;
 pub use net_headers::*;
 
 mod net_slice;
 pub use net_slice::*;
+
+mod shim6_header_slice;
+pub use shim6_header_slice::*;
+
+mod shim6_message_type;
+pub use shim6_message_type::*;
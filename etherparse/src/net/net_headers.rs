@@ -15,6 +15,12 @@ pub enum NetHeaders {
 }
 
 impl NetHeaders {
+    /// Minimum summed up length of the network layer headers in bytes/octets.
+    pub const MIN_LEN: usize = IpHeaders::MIN_LEN;
+
+    /// Maximum summed up length of the network layer headers in bytes/octets.
+    pub const MAX_LEN: usize = IpHeaders::MAX_LEN;
+
     /// Returns references to the IPv4 header & extensions if the header contains IPv4 values.
     pub fn ipv4_ref(&self) -> Option<(&Ipv4Header, &Ipv4Extensions)> {
         if let NetHeaders::Ipv4(header, exts) = self {
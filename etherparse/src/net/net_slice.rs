@@ -27,6 +27,16 @@ impl<'a> NetSlice<'a> {
             NetSlice::Ipv6(s) => Some(&s.payload),
         }
     }
+
+    /// Length of the ip header & the decoded extension headers in
+    /// bytes/octets.
+    pub fn header_len(&self) -> usize {
+        let (header, payload) = match self {
+            NetSlice::Ipv4(s) => (s.header().slice().as_ptr(), s.payload.payload.as_ptr()),
+            NetSlice::Ipv6(s) => (s.header().slice().as_ptr(), s.payload.payload.as_ptr()),
+        };
+        (payload as usize) - (header as usize)
+    }
 }
 
 impl<'a> From<IpSlice<'a>> for NetSlice<'a> {
@@ -202,4 +212,37 @@ mod tests {
             assert_eq!(NetSlice::Ipv6(i.clone()), actual);
         }
     }
+
+    #[test]
+    fn header_len() {
+        // ipv4 with options
+        {
+            let mut header = Ipv4Header {
+                protocol: IpNumber::UDP,
+                options: [1, 2, 3, 4].into(),
+                ..Default::default()
+            };
+            header.total_len = header.header_len() as u16 + 4;
+            let mut bytes = header.to_bytes().to_vec();
+            bytes.extend_from_slice(&[1, 2, 3, 4]);
+            let s = NetSlice::Ipv4(Ipv4Slice::from_slice(&bytes).unwrap());
+            assert_eq!(Ipv4Header::MIN_LEN + 4, s.header_len());
+        }
+        // ipv6 with extension header
+        {
+            let mut bytes = Ipv6Header {
+                next_header: IpNumber::IPV6_FRAGMENTATION_HEADER,
+                payload_length: Ipv6FragmentHeader::LEN as u16 + 4,
+                ..Default::default()
+            }
+            .to_bytes()
+            .to_vec();
+            bytes.extend_from_slice(
+                &Ipv6FragmentHeader::new(IpNumber::UDP, 0.try_into().unwrap(), false, 0).to_bytes(),
+            );
+            bytes.extend_from_slice(&[1, 2, 3, 4]);
+            let s = NetSlice::Ipv6(Ipv6Slice::from_slice(&bytes).unwrap());
+            assert_eq!(Ipv6Header::LEN + Ipv6FragmentHeader::LEN, s.header_len());
+        }
+    }
 }
@@ -0,0 +1,248 @@
+use crate::*;
+
+/// Slice containing a Shim6 header (RFC 5533).
+///
+/// A Shim6 header is either a "payload extension header" (the "P" bit is
+/// set) carrying a 47 bit receiver context tag or a control message. For
+/// control messages the type, checksum & raw message data can be accessed.
+///
+/// Shim6 headers are not decoded as part of the IPv6 extension headers and
+/// end up in the IP payload when a packet is decoded (e.g. via
+/// [`SlicedPacket`]):
+///
+/// ```
+/// # #[cfg(feature = "ipv6")]
+/// # {
+/// # use etherparse::*;
+/// # let shim6 = [ip_number::IPV6_NO_NEXT_HEADER.0, 0, Shim6MessageType::KEEPALIVE.0, 0, 0, 0, 0, 0];
+/// # let mut packet = Ipv6Header {
+/// #     payload_length: shim6.len() as u16,
+/// #     next_header: ip_number::SHIM6,
+/// #     hop_limit: 64,
+/// #     ..Default::default()
+/// # }.to_bytes().to_vec();
+/// # packet.extend_from_slice(&shim6);
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// let ip_payload = sliced.ip_payload().unwrap();
+/// if ip_payload.ip_number == ip_number::SHIM6 {
+///     let shim6 = Shim6HeaderSlice::from_slice(ip_payload.payload).unwrap();
+///     assert_eq!(Some(Shim6MessageType::KEEPALIVE), shim6.message_type());
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shim6HeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> Shim6HeaderSlice<'a> {
+    /// Minimum length of a Shim6 header in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum value of the 47 bit receiver context tag.
+    pub const MAX_CONTEXT_TAG: u64 = 0x7fff_ffff_ffff;
+
+    /// Creates a Shim6 header slice from a slice.
+    ///
+    /// The slice can be longer then the header (the returned slice only
+    /// contains the header itself).
+    pub fn from_slice(slice: &'a [u8]) -> Result<Shim6HeaderSlice<'a>, err::LenError> {
+        if slice.len() < Shim6HeaderSlice::MIN_LEN {
+            return Err(err::LenError {
+                required_len: Shim6HeaderSlice::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Shim6Header,
+                layer_start_offset: 0,
+            });
+        }
+
+        let header_len = (usize::from(slice[1]) + 1) * 8;
+        if slice.len() < header_len {
+            return Err(err::LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Shim6Header,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(Shim6HeaderSlice {
+            slice: &slice[..header_len],
+        })
+    }
+
+    /// Returns the slice containing the Shim6 header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "next header" field (for control messages normally
+    /// [`ip_number::IPV6_NO_NEXT_HEADER`]).
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Read the "header length" field (length in 8 octets units,
+    /// excluding the first 8 octets).
+    #[inline]
+    pub fn header_length(&self) -> u8 {
+        self.slice[1]
+    }
+
+    /// Length of the Shim6 header in bytes/octets.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns true if the "P" bit is set & the header is a payload
+    /// extension header (false if it is a control message).
+    #[inline]
+    pub fn is_payload_extension(&self) -> bool {
+        0 != self.slice[2] & 0b1000_0000
+    }
+
+    /// Returns the 47 bit "receiver context tag" if the header is a payload
+    /// extension header.
+    #[inline]
+    pub fn receiver_context_tag(&self) -> Option<u64> {
+        if self.is_payload_extension() {
+            Some(u64::from_be_bytes([
+                0,
+                0,
+                self.slice[2] & 0b0111_1111,
+                self.slice[3],
+                self.slice[4],
+                self.slice[5],
+                self.slice[6],
+                self.slice[7],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the 7 bit message "type" if the header is a control message.
+    #[inline]
+    pub fn message_type(&self) -> Option<Shim6MessageType> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(Shim6MessageType(self.slice[2]))
+        }
+    }
+
+    /// Returns the "checksum" field if the header is a control message.
+    #[inline]
+    pub fn checksum(&self) -> Option<u16> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(u16::from_be_bytes([self.slice[4], self.slice[5]]))
+        }
+    }
+
+    /// Returns the raw type specific message data (everything after the
+    /// checksum) if the header is a control message.
+    #[inline]
+    pub fn message_data(&self) -> Option<&'a [u8]> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(&self.slice[6..])
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn control_message() {
+        let bytes = [
+            ip_number::IPV6_NO_NEXT_HEADER.0,
+            1, // header length ((1 + 1) * 8 = 16)
+            Shim6MessageType::PROBE.0,
+            0,
+            0x12,
+            0x34, // checksum
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            9,
+            10,
+            // not part of the header
+            0xff,
+        ];
+        let slice = Shim6HeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(&bytes[..16], slice.slice());
+        assert_eq!(ip_number::IPV6_NO_NEXT_HEADER, slice.next_header());
+        assert_eq!(1, slice.header_length());
+        assert_eq!(16, slice.header_len());
+        assert!(false == slice.is_payload_extension());
+        assert_eq!(None, slice.receiver_context_tag());
+        assert_eq!(Some(Shim6MessageType::PROBE), slice.message_type());
+        assert_eq!(Some(0x1234), slice.checksum());
+        assert_eq!(Some(&bytes[6..16]), slice.message_data());
+    }
+
+    #[test]
+    fn payload_extension() {
+        let bytes = [ip_number::UDP.0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe];
+        let slice = Shim6HeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(ip_number::UDP, slice.next_header());
+        assert_eq!(8, slice.header_len());
+        assert!(slice.is_payload_extension());
+        assert_eq!(
+            Some(Shim6HeaderSlice::MAX_CONTEXT_TAG - 1),
+            slice.receiver_context_tag()
+        );
+        assert_eq!(None, slice.message_type());
+        assert_eq!(None, slice.checksum());
+        assert_eq!(None, slice.message_data());
+    }
+
+    #[test]
+    fn from_slice_len_error() {
+        let bytes = [0u8, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        for len in 0..bytes.len() {
+            let required_len = if len < Shim6HeaderSlice::MIN_LEN {
+                Shim6HeaderSlice::MIN_LEN
+            } else {
+                bytes.len()
+            };
+            assert_eq!(
+                Shim6HeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                err::LenError {
+                    required_len,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Shim6Header,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let bytes = [0u8; 8];
+        let slice = Shim6HeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!("Shim6HeaderSlice {{ slice: {:?} }}", slice.slice()),
+            format!("{:?}", slice)
+        );
+    }
+}
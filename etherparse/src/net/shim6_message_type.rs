@@ -0,0 +1,73 @@
+/// Message type of a Shim6 control message
+/// (see [`crate::Shim6HeaderSlice`]).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `Shim6MessageType`:
+///
+/// ```
+/// use etherparse::Shim6MessageType;
+///
+/// assert_eq!(Shim6MessageType::I1.0, 1);
+///
+/// let value: Shim6MessageType = 2.into();
+/// assert_eq!(Shim6MessageType::R1, value);
+///
+/// let num: u8 = Shim6MessageType::R1.into();
+/// assert_eq!(2, num);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Default, Hash)]
+pub struct Shim6MessageType(pub u8);
+
+impl Shim6MessageType {
+    /// First message of the context establishment exchange (RFC 5533).
+    pub const I1: Shim6MessageType = Self(1);
+    /// Reply to an I1 message (RFC 5533).
+    pub const R1: Shim6MessageType = Self(2);
+    /// Third message of the context establishment exchange (RFC 5533).
+    pub const I2: Shim6MessageType = Self(3);
+    /// Reply to an I2 message (RFC 5533).
+    pub const R2: Shim6MessageType = Self(4);
+    /// Reply to a payload packet with an unknown context tag (RFC 5533).
+    pub const R1BIS: Shim6MessageType = Self(5);
+    /// Reply to an R1bis message (RFC 5533).
+    pub const I2BIS: Shim6MessageType = Self(6);
+    /// Locator list update request (RFC 5534).
+    pub const UPDATE_REQUEST: Shim6MessageType = Self(64);
+    /// Locator list update acknowledgement (RFC 5534).
+    pub const UPDATE_ACKNOWLEDGEMENT: Shim6MessageType = Self(65);
+    /// Keepalive message (RFC 5534).
+    pub const KEEPALIVE: Shim6MessageType = Self(66);
+    /// Reachability probe message (RFC 5534).
+    pub const PROBE: Shim6MessageType = Self(67);
+}
+
+impl From<u8> for Shim6MessageType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        Shim6MessageType(val)
+    }
+}
+
+impl From<Shim6MessageType> for u8 {
+    #[inline]
+    fn from(val: Shim6MessageType) -> Self {
+        val.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_into_u8() {
+        for v in [0, 1, 5, 6, 0xff] {
+            assert_eq!(v, u8::from(Shim6MessageType::from(v)));
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Shim6MessageType(0), Shim6MessageType::default());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::err::BufSpaceError;
+use arrayvec::ArrayVec;
+
+/// Buffer packets can be serialized into without going through
+/// [`std::io::Write`] (see e.g. [`crate::PacketBuilderStep::build_into`]).
+///
+/// Implementations are provided for `Vec<u8>` (requires the `std`
+/// feature), [`crate::SliceBuf`] (fixed slice with a write position) &
+/// `ArrayVec<u8, N>` (fixed size buffer on the stack).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{PacketBuf, SliceBuf};
+///
+/// let mut memory = [0u8; 4];
+/// let mut buf = SliceBuf::new(&mut memory);
+/// buf.reserve(3).unwrap();
+/// buf.extend_from_slice(&[1, 2, 3]).unwrap();
+/// buf.as_mut_slice()[0] = 4;
+/// assert_eq!(&[4, 2, 3], buf.as_slice());
+///
+/// // not enough space left
+/// assert!(buf.extend_from_slice(&[5, 6]).is_err());
+/// ```
+pub trait PacketBuf {
+    /// Ensures that at least `additional` bytes can be appended without
+    /// reallocation (or returns an error if the buffer can not grow).
+    fn reserve(&mut self, additional: usize) -> Result<(), BufSpaceError>;
+
+    /// Appends the given bytes to the buffer.
+    ///
+    /// If not enough space is available an error is returned and no
+    /// bytes are appended.
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<(), BufSpaceError>;
+
+    /// Returns the bytes written to the buffer.
+    fn as_slice(&self) -> &[u8];
+
+    /// Returns the bytes written to the buffer as mutable slice (e.g.
+    /// to patch fields after serialization).
+    fn as_mut_slice(&mut self) -> &mut [u8];
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl PacketBuf for std::vec::Vec<u8> {
+    #[inline]
+    fn reserve(&mut self, additional: usize) -> Result<(), BufSpaceError> {
+        std::vec::Vec::reserve(self, additional);
+        Ok(())
+    }
+
+    #[inline]
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<(), BufSpaceError> {
+        std::vec::Vec::extend_from_slice(self, data);
+        Ok(())
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u8] {
+        std::vec::Vec::as_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        std::vec::Vec::as_mut_slice(self)
+    }
+}
+
+impl<const CAP: usize> PacketBuf for ArrayVec<u8, CAP> {
+    #[inline]
+    fn reserve(&mut self, additional: usize) -> Result<(), BufSpaceError> {
+        if self.remaining_capacity() < additional {
+            Err(BufSpaceError {
+                required_len: additional,
+                available_len: self.remaining_capacity(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<(), BufSpaceError> {
+        self.try_extend_from_slice(data).map_err(|_| BufSpaceError {
+            required_len: data.len(),
+            available_len: self.remaining_capacity(),
+        })
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u8] {
+        ArrayVec::as_slice(self)
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        ArrayVec::as_mut_slice(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check<T: PacketBuf>(buf: &mut T, capacity: Option<usize>) {
+        buf.reserve(2).unwrap();
+        buf.extend_from_slice(&[1, 2]).unwrap();
+        assert_eq!(&[1, 2], buf.as_slice());
+        buf.as_mut_slice()[1] = 3;
+        assert_eq!(&[1, 3], buf.as_slice());
+
+        if let Some(capacity) = capacity {
+            let available_len = capacity - 2;
+            assert_eq!(
+                Err(BufSpaceError {
+                    required_len: available_len + 1,
+                    available_len,
+                }),
+                buf.reserve(available_len + 1)
+            );
+            assert_eq!(
+                Err(BufSpaceError {
+                    required_len: available_len + 1,
+                    available_len,
+                }),
+                buf.extend_from_slice(&[0; 16][..available_len + 1])
+            );
+            assert_eq!(&[1, 3], buf.as_slice());
+        }
+    }
+
+    #[test]
+    fn vec() {
+        let mut buf = std::vec::Vec::new();
+        check(&mut buf, None);
+    }
+
+    #[test]
+    fn array_vec() {
+        let mut buf = ArrayVec::<u8, 4>::new();
+        check(&mut buf, Some(4));
+    }
+}
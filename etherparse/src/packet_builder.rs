@@ -2,7 +2,9 @@ use crate::err::packet::BuildWriteError;
 
 use super::*;
 
-use std::{io, marker};
+use core::{marker, net::IpAddr};
+#[cfg(feature = "std")]
+use std::{io, vec::Vec};
 
 /// Helper for building packets.
 ///
@@ -40,6 +42,12 @@ use std::{io, marker};
 /// println!("{:?}", result);
 /// ```
 ///
+/// Without the `std` feature packets can be serialized into a fixed size
+/// buffer via `build_into` (e.g. [`PacketBuilderStep<UdpHeader>::build_into`]
+/// with a [`SliceBuf`] or an `ArrayVec<u8, N>`). Writing to an
+/// `std::io::Write`, templates, segmentation, fragmentation, corruptions &
+/// MACsec headers require the `std` feature.
+///
 /// # Options
 ///
 /// * Starting Options:
@@ -48,7 +56,11 @@ use std::{io, marker};
 ///     * [`PacketBuilder::ip`]
 ///     * [`PacketBuilder::ipv4`]
 ///     * [`PacketBuilder::ipv6`]
+///     * [`PacketBuilder::ip_addr`]
+///     * [`PacketBuilder::udp_over`]
+///     * [`PacketBuilder::tcp_over`]
 /// * Options after an Ethernet2 header was added:
+///     * [`PacketBuilderStep<Ethernet2Header>::macsec`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::vlan`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::single_vlan`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::double_vlan`]
@@ -59,11 +71,19 @@ use std::{io, marker};
 ///     * [`PacketBuilderStep<LinuxSllHeader>::ip`]
 ///     * [`PacketBuilderStep<LinuxSllHeader>::ipv4`]
 ///     * [`PacketBuilderStep<LinuxSllHeader>::ipv6`]
+/// * Options after an MACsec header was added:
+///     * [`PacketBuilderStep<MacsecHeader>::vlan`]
+///     * [`PacketBuilderStep<MacsecHeader>::single_vlan`]
+///     * [`PacketBuilderStep<MacsecHeader>::double_vlan`]
+///     * [`PacketBuilderStep<MacsecHeader>::ip`]
+///     * [`PacketBuilderStep<MacsecHeader>::ipv4`]
+///     * [`PacketBuilderStep<MacsecHeader>::ipv6`]
 /// * Options after an Vlan header was added:
 ///     * [`PacketBuilderStep<VlanHeader>::ip`]
 ///     * [`PacketBuilderStep<VlanHeader>::ipv4`]
 ///     * [`PacketBuilderStep<VlanHeader>::ipv6`]
 /// * Options after an IP header was added:
+///     * [`PacketBuilderStep<IpHeaders>::ipv4_options`]
 ///     * [`PacketBuilderStep<IpHeaders>::write`]
 ///     * [`PacketBuilderStep<IpHeaders>::tcp`]
 ///     * [`PacketBuilderStep<IpHeaders>::udp`]
@@ -71,6 +91,10 @@ use std::{io, marker};
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv4_raw`]
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv4_echo_request`]
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv4_echo_reply`]
+///     * [`PacketBuilderStep<IpHeaders>::icmpv4_timestamp_request`]
+///     * [`PacketBuilderStep<IpHeaders>::icmpv4_timestamp_reply`]
+///     * [`PacketBuilderStep<IpHeaders>::icmpv4_address_mask_request`]
+///     * [`PacketBuilderStep<IpHeaders>::icmpv4_address_mask_reply`]
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv6`]
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv6_raw`]
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv6_echo_request`]
@@ -78,6 +102,8 @@ use std::{io, marker};
 /// * Options after an TCP header was added:
 ///     * [`PacketBuilderStep<TcpHeader>::write`]
 ///     * [`PacketBuilderStep<TcpHeader>::size`]
+///     * [`PacketBuilderStep<TcpHeader>::segments`]
+///     * [`PacketBuilderStep<TcpHeader>::template`]
 ///     * [`PacketBuilderStep<TcpHeader>::ns`]
 ///     * [`PacketBuilderStep<TcpHeader>::fin`]
 ///     * [`PacketBuilderStep<TcpHeader>::syn`]
@@ -92,6 +118,7 @@ use std::{io, marker};
 /// * Options after an UDP header was added:
 ///     * [`PacketBuilderStep<UdpHeader>::write`]
 ///     * [`PacketBuilderStep<UdpHeader>::size`]
+///     * [`PacketBuilderStep<UdpHeader>::template`]
 /// * Options after an ICMPv4 header was added:
 ///     * [`PacketBuilderStep<Icmpv4Header>::write`]
 ///     * [`PacketBuilderStep<Icmpv4Header>::size`]
@@ -99,10 +126,50 @@ use std::{io, marker};
 ///     * [`PacketBuilderStep<Icmpv6Header>::write`]
 ///     * [`PacketBuilderStep<Icmpv6Header>::size`]
 ///
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+/// # TUN & TAP devices
+///
+/// TUN devices expect packets starting with the IP header, TAP devices
+/// expect Ethernet II frames:
+///
+/// * Packets for TUN devices are started with [`PacketBuilder::ip`],
+///   [`PacketBuilder::ipv4`], [`PacketBuilder::ipv6`],
+///   [`PacketBuilder::ip_addr`], [`PacketBuilder::udp_over`] or
+///   [`PacketBuilder::tcp_over`].
+/// * Packets for TAP devices are started with [`PacketBuilder::ethernet2`].
+///
+/// [`PacketBuilderStep::has_link_header`] can be used to check which kind
+/// of packet a builder produces.
+///
 pub struct PacketBuilder {}
 
+/// Maximum length of a MACsec header (same as `MacsecHeader::MAX_LEN`,
+/// spelled out so [`PacketBuilder::MAX_HEADER_LEN`] does not depend on the
+/// enabled features).
+const MACSEC_MAX_LEN: usize = 6 + 8;
+
+/// Maximum length of the link layer part written by a packet builder.
+///
+/// MACsec can only follow an Ethernet II header & is followed by the ether
+/// type at the start of the secure data (2 bytes).
+const LINK_MAX_LEN: usize = {
+    let eth_macsec = Ethernet2Header::LEN + MACSEC_MAX_LEN + 2;
+    if eth_macsec > LinkHeader::MAX_LEN {
+        eth_macsec
+    } else {
+        LinkHeader::MAX_LEN
+    }
+};
+
 impl PacketBuilder {
+    /// Maximum summed up length of all headers that can be written by a
+    /// packet builder in bytes/octets (excluding the payload, the MACsec
+    /// ICV & the ethernet FCS).
+    ///
+    /// Use [`HeaderStackLen`] to compute the maximum length for a specific
+    /// composition of headers.
+    pub const MAX_HEADER_LEN: usize =
+        LINK_MAX_LEN + VlanHeader::MAX_LEN + IpHeaders::MAX_LEN + TransportHeader::MAX_LEN;
+
     /// Start an packet with an ethernetII header.
     ///
     /// # Example
@@ -142,6 +209,12 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                #[cfg(all(feature = "std", feature = "macsec"))]
+                macsec: None,
+                ethernet_fcs: false,
+                ipv4_options_in_ipv6: false,
+                #[cfg(feature = "std")]
+                corruptions: Vec::new(),
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -181,18 +254,74 @@ impl PacketBuilder {
         sender_address_valid_length: u16,
         sender_address: [u8; 8],
     ) -> PacketBuilderStep<LinuxSllHeader> {
+        PacketBuilder::linux_sll_header(LinuxSllHeader {
+            packet_type,
+            arp_hrd_type: ArpHardwareId::ETHER,
+            sender_address_valid_length,
+            sender_address,
+            protocol_type: LinuxSllProtocolType::EtherType(EtherType(0)), // Will be overwitten when writing depending on the net layer
+        })
+    }
+
+    /// Start an packet with the given Linux Cooked Catpure (v1) header
+    /// (e.g. a header decoded via [`crate::PacketHeaders::from_linux_sll_slice`]).
+    ///
+    /// The value of the protocol type is overwritten when writing the
+    /// packet, based on the following layers. As all following layers are
+    /// identified via an ether type the `arp_hrd_type` is always set to
+    /// [`ArpHardwareId::ETHER`] (other values would result in an invalid
+    /// frame).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     linux_sll_header(LinuxSllHeader {
+    ///         packet_type: LinuxSllPacketType::OUTGOING,
+    ///         arp_hrd_type: ArpHardwareId::ETHER,
+    ///         sender_address_valid_length: 6,
+    ///         sender_address: [1,2,3,4,5,6,0,0],
+    ///         protocol_type: LinuxSllProtocolType::EtherType(EtherType::IPV4),
+    ///     })
+    ///    .ipv4([192,168,1,1], //source ip
+    ///          [192,168,1,2], //destination ip
+    ///          20)            //time to life
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// // decode again
+    /// let headers = PacketHeaders::from_linux_sll_slice(&result).unwrap();
+    /// assert!(matches!(headers.link, Some(LinkHeader::LinuxSll(_))));
+    /// ```
+    pub fn linux_sll_header(mut header: LinuxSllHeader) -> PacketBuilderStep<LinuxSllHeader> {
+        header.arp_hrd_type = ArpHardwareId::ETHER;
+        header.protocol_type = LinuxSllProtocolType::EtherType(EtherType(0));
         PacketBuilderStep {
             state: PacketImpl {
-                link_header: Some(LinkHeader::LinuxSll(LinuxSllHeader {
-                    packet_type,
-                    arp_hrd_type: ArpHardwareId::ETHER,
-                    sender_address_valid_length,
-                    sender_address,
-                    protocol_type: LinuxSllProtocolType::EtherType(EtherType(0)), // Will be overwitten when writing depending on the net layer
-                })),
+                link_header: Some(LinkHeader::LinuxSll(header)),
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                #[cfg(all(feature = "std", feature = "macsec"))]
+                macsec: None,
+                ethernet_fcs: false,
+                ipv4_options_in_ipv6: false,
+                #[cfg(feature = "std")]
+                corruptions: Vec::new(),
             },
             _marker: marker::PhantomData::<LinuxSllHeader> {},
         }
@@ -235,6 +364,12 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                #[cfg(all(feature = "std", feature = "macsec"))]
+                macsec: None,
+                ethernet_fcs: false,
+                ipv4_options_in_ipv6: false,
+                #[cfg(feature = "std")]
+                corruptions: Vec::new(),
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -282,6 +417,12 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                #[cfg(all(feature = "std", feature = "macsec"))]
+                macsec: None,
+                ethernet_fcs: false,
+                ipv4_options_in_ipv6: false,
+                #[cfg(feature = "std")]
+                corruptions: Vec::new(),
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -359,29 +500,536 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                #[cfg(all(feature = "std", feature = "macsec"))]
+                macsec: None,
+                ethernet_fcs: false,
+                ipv4_options_in_ipv6: false,
+                #[cfg(feature = "std")]
+                corruptions: Vec::new(),
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
         .ip(ip_header)
     }
+
+    /// Starts a packet with an IPv4 or IPv6 header based on the given
+    /// addresses (no link layer header, e.g. for writing to a TUN device).
+    ///
+    /// `None` is returned if the source & destination addresses are not
+    /// of the same IP version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ipv6")]
+    /// # {
+    /// # use etherparse::*;
+    /// use std::net::{IpAddr, Ipv6Addr};
+    ///
+    /// let builder = PacketBuilder::ip_addr(
+    ///     IpAddr::V6(Ipv6Addr::LOCALHOST), //source ip
+    ///     IpAddr::V6(Ipv6Addr::LOCALHOST), //destination ip
+    ///     20)                              //hop limit
+    ///     .unwrap()
+    ///     .udp(21, 1234);
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut result, &[1,2,3,4]).unwrap();
+    /// assert!(SlicedPacket::from_ip(&result).is_ok());
+    /// # }
+    /// ```
+    pub fn ip_addr(
+        source: IpAddr,
+        destination: IpAddr,
+        hop_limit: u8,
+    ) -> Option<PacketBuilderStep<IpHeaders>> {
+        match (source, destination) {
+            (IpAddr::V4(source), IpAddr::V4(destination)) => Some(PacketBuilder::ipv4(
+                source.octets(),
+                destination.octets(),
+                hop_limit,
+            )),
+            (IpAddr::V6(source), IpAddr::V6(destination)) => Some(PacketBuilder::ipv6(
+                source.octets(),
+                destination.octets(),
+                hop_limit,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Starts an UDP packet with an IPv4 or IPv6 header based on the given
+    /// addresses (no link layer header, e.g. for writing to a TUN device).
+    ///
+    /// `None` is returned if the source & destination addresses are not
+    /// of the same IP version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let builder = PacketBuilder::udp_over(
+    ///     IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)), //source ip
+    ///     IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)), //destination ip
+    ///     20,   //time to life
+    ///     21,   //source port
+    ///     1234) //destination port
+    ///     .unwrap();
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut result, &[1,2,3,4]).unwrap();
+    /// assert!(SlicedPacket::from_ip(&result).is_ok());
+    /// ```
+    pub fn udp_over(
+        source: IpAddr,
+        destination: IpAddr,
+        hop_limit: u8,
+        source_port: u16,
+        destination_port: u16,
+    ) -> Option<PacketBuilderStep<UdpHeader>> {
+        Some(
+            PacketBuilder::ip_addr(source, destination, hop_limit)?
+                .udp(source_port, destination_port),
+        )
+    }
+
+    /// Starts a TCP packet with an IPv4 or IPv6 header based on the given
+    /// addresses (no link layer header, e.g. for writing to a TUN device).
+    ///
+    /// `None` is returned if the source & destination addresses are not
+    /// of the same IP version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let builder = PacketBuilder::tcp_over(
+    ///     IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)), //source ip
+    ///     IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)), //destination ip
+    ///     20,    //time to life
+    ///     21,    //source port
+    ///     1234,  //destination port
+    ///     1,     //sequence number
+    ///     26180) //window size
+    ///     .unwrap()
+    ///     .syn();
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(0));
+    /// builder.write(&mut result, &[]).unwrap();
+    /// assert!(SlicedPacket::from_ip(&result).is_ok());
+    /// ```
+    pub fn tcp_over(
+        source: IpAddr,
+        destination: IpAddr,
+        hop_limit: u8,
+        source_port: u16,
+        destination_port: u16,
+        sequence_number: u32,
+        window_size: u16,
+    ) -> Option<PacketBuilderStep<TcpHeader>> {
+        Some(PacketBuilder::ip_addr(source, destination, hop_limit)?.tcp(
+            source_port,
+            destination_port,
+            sequence_number,
+            window_size,
+        ))
+    }
+
+    /// Starts a packet that is a response to the given packet (source &
+    /// destination MAC and IP addresses are swapped).
+    ///
+    /// The following headers are taken over from the received packet:
+    ///
+    /// * Ethernet II header with swapped source & destination (other link
+    ///   layers are not taken over and the response starts with the IP header).
+    /// * VLAN headers (unchanged).
+    /// * IPv4 or IPv6 header with swapped source & destination addresses and
+    ///   the given `hop_limit` as "time to live" or "hop limit". The DSCP, ECN,
+    ///   "don't fragment" flag & the flow label are kept. IPv4 options, the
+    ///   fragmentation fields & all IP extension headers are not taken over.
+    ///
+    /// `None` is returned if the packet contains no IP header.
+    ///
+    /// # Example
+    ///
+    /// Responding to an ICMPv4 echo request:
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// # let mut request = Vec::new();
+    /// # PacketBuilder::ethernet2([1,2,3,4,5,6], [7,8,9,10,11,12])
+    /// #     .ipv4([192,168,1,1], [192,168,1,2], 20)
+    /// #     .icmpv4_echo_request(1, 2)
+    /// #     .write(&mut request, &[1,2,3,4])
+    /// #     .unwrap();
+    /// let received = SlicedPacket::from_ethernet(&request).unwrap();
+    /// if let Some(TransportSlice::Icmpv4(icmp)) = &received.transport {
+    ///     if let Icmpv4Type::EchoRequest(echo) = icmp.icmp_type() {
+    ///         let builder = PacketBuilder::response_to(&received, 64)
+    ///             .unwrap()
+    ///             .icmpv4_echo_reply(echo.id, echo.seq);
+    ///
+    ///         let mut response = Vec::<u8>::with_capacity(
+    ///             builder.size(icmp.payload().len()));
+    ///         builder.write(&mut response, icmp.payload()).unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn response_to(
+        packet: &SlicedPacket,
+        hop_limit: u8,
+    ) -> Option<PacketBuilderStep<IpHeaders>> {
+        let ip_header = match packet.net.as_ref()? {
+            NetSlice::Ipv4(ipv4) => {
+                let received = ipv4.header();
+                IpHeaders::Ipv4(
+                    Ipv4Header {
+                        dscp: received.dcp(),
+                        ecn: received.ecn(),
+                        dont_fragment: received.dont_fragment(),
+                        time_to_live: hop_limit,
+                        source: received.destination(),
+                        destination: received.source(),
+                        ..Default::default()
+                    },
+                    Default::default(),
+                )
+            }
+            NetSlice::Ipv6(ipv6) => {
+                let received = ipv6.header();
+                IpHeaders::Ipv6(
+                    Ipv6Header {
+                        traffic_class: received.traffic_class(),
+                        flow_label: received.flow_label(),
+                        hop_limit,
+                        source: received.destination(),
+                        destination: received.source(),
+                        ..Default::default()
+                    },
+                    Default::default(),
+                )
+            }
+        };
+
+        let link_header = match &packet.link {
+            Some(LinkSlice::Ethernet2(eth)) => Some(LinkHeader::Ethernet2(Ethernet2Header {
+                source: eth.destination(),
+                destination: eth.source(),
+                // set during write
+                ether_type: eth.ether_type(),
+            })),
+            _ => None,
+        };
+
+        Some(PacketBuilderStep {
+            state: PacketImpl {
+                link_header,
+                vlan_header: packet.vlan.as_ref().map(|v| v.to_header()),
+                ip_header: Some(ip_header),
+                transport_header: None,
+                #[cfg(all(feature = "std", feature = "macsec"))]
+                macsec: None,
+                ethernet_fcs: false,
+                ipv4_options_in_ipv6: false,
+                #[cfg(feature = "std")]
+                corruptions: Vec::new(),
+            },
+            _marker: marker::PhantomData::<IpHeaders> {},
+        })
+    }
+
+    /// Starts an UDP packet that is a response to the given UDP packet
+    /// (addresses & ports are swapped).
+    ///
+    /// See [`PacketBuilder::response_to`] for details on which headers are
+    /// taken over. `None` is returned if the packet contains no IP or no
+    /// UDP header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ipv6")]
+    /// # {
+    /// # use etherparse::*;
+    /// # let mut request = Vec::new();
+    /// # PacketBuilder::ethernet2([1,2,3,4,5,6], [7,8,9,10,11,12])
+    /// #     .ipv6([11;16], [12;16], 20)
+    /// #     .udp(1234, 53)
+    /// #     .write(&mut request, &[1,2,3,4])
+    /// #     .unwrap();
+    /// let received = SlicedPacket::from_ethernet(&request).unwrap();
+    /// let builder = PacketBuilder::udp_response_to(&received, 64).unwrap();
+    ///
+    /// let payload = [5,6,7,8];
+    /// let mut response = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    /// builder.write(&mut response, &payload).unwrap();
+    /// # }
+    /// ```
+    pub fn udp_response_to(
+        packet: &SlicedPacket,
+        hop_limit: u8,
+    ) -> Option<PacketBuilderStep<UdpHeader>> {
+        match &packet.transport {
+            Some(TransportSlice::Udp(udp)) => Some(
+                PacketBuilder::response_to(packet, hop_limit)?
+                    .udp(udp.destination_port(), udp.source_port()),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Starts a TCP packet that is a response to the given TCP packet
+    /// (addresses & ports are swapped).
+    ///
+    /// The sequence number of the response is set to the acknowledgment
+    /// number of the received packet and the window size is taken over.
+    /// No flags are set, use the methods of the returned builder step
+    /// (e.g. [`PacketBuilderStep<TcpHeader>::rst`] or
+    /// [`PacketBuilderStep<TcpHeader>::ack`]) to set them.
+    ///
+    /// See [`PacketBuilder::response_to`] for details on which headers are
+    /// taken over. `None` is returned if the packet contains no IP or no
+    /// TCP header.
+    ///
+    /// # Example
+    ///
+    /// Resetting a connection:
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// # let mut request = Vec::new();
+    /// # PacketBuilder::ethernet2([1,2,3,4,5,6], [7,8,9,10,11,12])
+    /// #     .ipv4([192,168,1,1], [192,168,1,2], 20)
+    /// #     .tcp(1234, 80, 1000, 4000)
+    /// #     .syn()
+    /// #     .write(&mut request, &[])
+    /// #     .unwrap();
+    /// let received = SlicedPacket::from_ethernet(&request).unwrap();
+    /// if let Some(TransportSlice::Tcp(tcp)) = &received.transport {
+    ///     let builder = PacketBuilder::tcp_response_to(&received, 64)
+    ///         .unwrap()
+    ///         .rst()
+    ///         .ack(tcp.sequence_number().wrapping_add(1));
+    ///
+    ///     let mut response = Vec::<u8>::with_capacity(builder.size(0));
+    ///     builder.write(&mut response, &[]).unwrap();
+    /// }
+    /// ```
+    pub fn tcp_response_to(
+        packet: &SlicedPacket,
+        hop_limit: u8,
+    ) -> Option<PacketBuilderStep<TcpHeader>> {
+        match &packet.transport {
+            Some(TransportSlice::Tcp(tcp)) => {
+                Some(PacketBuilder::response_to(packet, hop_limit)?.tcp(
+                    tcp.destination_port(),
+                    tcp.source_port(),
+                    tcp.acknowledgment_number(),
+                    tcp.window_size(),
+                ))
+            }
+            _ => None,
+        }
+    }
 }
 
+#[derive(Clone)]
 struct PacketImpl {
     link_header: Option<LinkHeader>,
+    /// MACsec header (SecTAG) & the "integrity check value" (ICV) written
+    /// after the payload.
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    macsec: Option<(MacsecHeader, Vec<u8>)>,
     ip_header: Option<IpHeaders>,
     vlan_header: Option<VlanHeader>,
     transport_header: Option<TransportHeader>,
+    /// If true a CRC-32 frame check sequence is appended after the payload.
+    ethernet_fcs: bool,
+    /// Set if IPv4 options were added to an IPv6 header (reported as
+    /// [`BuildWriteError::Ipv4OptionsInIpv6`] when the packet is written).
+    ipv4_options_in_ipv6: bool,
+    /// Corruptions applied after all lengths & checksums are calculated.
+    #[cfg(feature = "std")]
+    corruptions: Vec<PacketCorruption>,
 }
 
 ///An unfinished packet that is build with the packet builder
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub struct PacketBuilderStep<LastStep> {
     state: PacketImpl,
     _marker: marker::PhantomData<LastStep>,
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<LastStep> PacketBuilderStep<LastStep> {
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Overwrites a header field after all lengths & checksums were
+    /// calculated (e.g. to generate packets with invalid checksums for
+    /// negative testing).
+    ///
+    /// Multiple corruptions can be added & are applied in the order they
+    /// were added. Corruptions of headers that are not present in the
+    /// packet are ignored. If an ethernet FCS is added (see
+    /// [`PacketBuilderStep::with_fcs`]) it is calculated over the corrupted
+    /// data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, PacketCorruption, SlicedPacket, err};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], //source ip
+    ///          [192,168,1,2], //destination ip
+    ///          20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234)  //destination port
+    ///     // udp length bigger then the actual payload
+    ///     .corrupt(PacketCorruption::UdpLength(1000))
+    ///     .corrupt(PacketCorruption::Ipv4HeaderChecksum(0));
+    ///
+    /// let payload = [1,2,3,4,5,6,7,8];
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    /// builder.write(&mut packet, &payload).unwrap();
+    ///
+    /// assert_eq!(&[0, 0], &packet[10..12]);
+    /// assert!(matches!(
+    ///     SlicedPacket::from_ip(&packet),
+    ///     Err(err::packet::SliceError::Len(_))
+    /// ));
+    /// ```
+    pub fn corrupt(mut self, corruption: PacketCorruption) -> PacketBuilderStep<LastStep> {
+        self.state.corruptions.push(corruption);
+        self
+    }
+
+    /// Returns true if the packet starts with a link layer header
+    /// (Ethernet II or Linux SLL).
+    ///
+    /// Packets without a link layer header start with the IP header and
+    /// are the format expected by TUN devices. Packets starting with an
+    /// Ethernet II header are the format expected by TAP devices.
+    ///
+    /// ```
+    /// use etherparse::PacketBuilder;
+    ///
+    /// let tun = PacketBuilder::ipv4([192,168,1,1], [192,168,1,2], 20);
+    /// assert!(!tun.has_link_header());
+    ///
+    /// let tap = PacketBuilder::ethernet2([1,2,3,4,5,6], [7,8,9,10,11,12])
+    ///     .ipv4([192,168,1,1], [192,168,1,2], 20);
+    /// assert!(tap.has_link_header());
+    /// ```
+    #[inline]
+    pub fn has_link_header(&self) -> bool {
+        self.state.link_header.is_some()
+    }
+}
+
 impl PacketBuilderStep<Ethernet2Header> {
+    /// Appends a CRC-32 frame check sequence (FCS) after the payload
+    /// when the packet is written.
+    ///
+    /// Note that no padding is added to frames shorter than the ethernet
+    /// minimum frame length of 64 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, Ethernet2Slice};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destination mac
+    ///    .with_fcs()
+    ///    .ipv4([192,168,1,1], //source ip
+    ///          [192,168,1,2], //destination ip
+    ///          20)            //time to life
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //serialize (the size includes the 4 byte fcs)
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// let eth = Ethernet2Slice::from_slice_with_crc32_fcs(&result).unwrap();
+    /// assert!(eth.fcs().is_some());
+    /// ```
+    pub fn with_fcs(mut self) -> PacketBuilderStep<Ethernet2Header> {
+        self.state.ethernet_fcs = true;
+        self
+    }
+
+    /// Adds a MACsec header (SecTAG) after the Ethernet II header.
+    ///
+    /// The ether type of the Ethernet II header is set to
+    /// [`EtherType::MACSEC`] and the secure data following the SecTAG
+    /// starts with the ether type of the next header (VLAN or IP). The
+    /// short length (SL) of the MACsec header is set automatically based on
+    /// the length of the secure data during the write.
+    ///
+    /// The given `icv` ("integrity check value") is written after the
+    /// payload. The secure data is written unencrypted, if an encrypted
+    /// frame is needed the secure data has to be encrypted & the ICV
+    /// replaced by the caller after the packet was written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destination mac
+    ///     .macsec(
+    ///         MacsecHeader {
+    ///             packet_nr: 1,
+    ///             ..Default::default()
+    ///         },
+    ///         &[0; 16] // icv
+    ///     )
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //destination ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //serialize
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// let eth = Ethernet2Slice::from_slice_without_fcs(&result).unwrap();
+    /// assert_eq!(EtherType::MACSEC, eth.ether_type());
+    /// let macsec = MacsecSlice::from_slice(eth.payload().payload, MacsecIcvLen::Len16).unwrap();
+    /// assert_eq!(Some(EtherType::IPV4), macsec.next_ether_type());
+    /// assert_eq!(&[0; 16], macsec.icv());
+    /// ```
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "macsec"))))]
+    pub fn macsec(
+        mut self,
+        macsec_header: MacsecHeader,
+        icv: &[u8],
+    ) -> PacketBuilderStep<MacsecHeader> {
+        self.state.macsec = Some((macsec_header, icv.to_vec()));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<MacsecHeader> {},
+        }
+    }
+
     /// Add an IPv4 header
     ///
     /// # Example
@@ -689,37 +1337,122 @@ impl PacketBuilderStep<Ethernet2Header> {
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl PacketBuilderStep<LinuxSllHeader> {
-    /// Add an ip header (length, protocol/next_header & checksum fields will be overwritten based on the rest of the packet).
-    ///
-    /// # Example IPv4
-    /// ```
-    /// # use etherparse::*;
-    /// #
-    /// let builder = PacketBuilder::
-    ///     linux_sll(LinuxSllPacketType::OTHERHOST, //packet type
-    ///               6, //sender address valid length
-    ///               [1,2,3,4,5,6,0,0]) //sender address with padding
-    ///    //payload_len, protocol & checksum will be replaced during write
-    ///    .ip(IpHeaders::Ipv4(
-    ///         Ipv4Header::new(
-    ///             0, //payload_len will be replaced during write
-    ///             12, //time_to_live
-    ///             ip_number::UDP, //will be replaced during write
-    ///             [0,1,2,3], //source
-    ///             [4,5,6,7] //destination
-    ///         ).unwrap(),
-    ///         Default::default() // IPv4 extension headers (default is none)
-    ///     ));
-    /// ```
-    ///
-    /// # Example IPv6
-    /// ```
-    /// # use etherparse::*;
-    /// #
-    /// let builder = PacketBuilder::
-    ///     linux_sll(LinuxSllPacketType::OTHERHOST, //packet type
+#[cfg(all(feature = "std", feature = "macsec"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "macsec"))))]
+impl PacketBuilderStep<MacsecHeader> {
+    /// Adds a vlan tagging header (see [`PacketBuilderStep<Ethernet2Header>::vlan`])
+    /// after the MACsec header.
+    pub fn vlan(self, vlan: VlanHeader) -> PacketBuilderStep<VlanHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .vlan(vlan)
+    }
+
+    /// Adds a vlan tagging header with the given vlan identifier (see
+    /// [`PacketBuilderStep<Ethernet2Header>::single_vlan`]) after the
+    /// MACsec header.
+    pub fn single_vlan(self, vlan_identifier: VlanId) -> PacketBuilderStep<VlanHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .single_vlan(vlan_identifier)
+    }
+
+    /// Adds two vlan tagging header with the given vlan identifiers (see
+    /// [`PacketBuilderStep<Ethernet2Header>::double_vlan`]) after the
+    /// MACsec header.
+    pub fn double_vlan(
+        self,
+        outer_vlan_identifier: VlanId,
+        inner_vlan_identifier: VlanId,
+    ) -> PacketBuilderStep<VlanHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .double_vlan(outer_vlan_identifier, inner_vlan_identifier)
+    }
+
+    /// Add an ip header (see [`PacketBuilderStep<Ethernet2Header>::ip`])
+    /// after the MACsec header.
+    pub fn ip(self, ip_header: IpHeaders) -> PacketBuilderStep<IpHeaders> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ip(ip_header)
+    }
+
+    /// Add an IPv6 header (see [`PacketBuilderStep<Ethernet2Header>::ipv6`])
+    /// after the MACsec header.
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeaders> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv6(source, destination, hop_limit)
+    }
+
+    /// Add an IPv4 header (see [`PacketBuilderStep<Ethernet2Header>::ipv4`])
+    /// after the MACsec header.
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeaders> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv4(source, destination, time_to_live)
+    }
+}
+
+impl PacketBuilderStep<LinuxSllHeader> {
+    /// Add an ip header (length, protocol/next_header & checksum fields will be overwritten based on the rest of the packet).
+    ///
+    /// # Example IPv4
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     linux_sll(LinuxSllPacketType::OTHERHOST, //packet type
+    ///               6, //sender address valid length
+    ///               [1,2,3,4,5,6,0,0]) //sender address with padding
+    ///    //payload_len, protocol & checksum will be replaced during write
+    ///    .ip(IpHeaders::Ipv4(
+    ///         Ipv4Header::new(
+    ///             0, //payload_len will be replaced during write
+    ///             12, //time_to_live
+    ///             ip_number::UDP, //will be replaced during write
+    ///             [0,1,2,3], //source
+    ///             [4,5,6,7] //destination
+    ///         ).unwrap(),
+    ///         Default::default() // IPv4 extension headers (default is none)
+    ///     ));
+    /// ```
+    ///
+    /// # Example IPv6
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     linux_sll(LinuxSllPacketType::OTHERHOST, //packet type
     ///               6, //sender address valid length
     ///               [1,2,3,4,5,6,0,0]) //sender address with padding
     ///    .ip(IpHeaders::Ipv6(
@@ -835,7 +1568,6 @@ impl PacketBuilderStep<LinuxSllHeader> {
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<VlanHeader> {
     ///Add an ip header (length, protocol/next_header & checksum fields will be overwritten based on the rest of the packet).
     ///
@@ -981,8 +1713,46 @@ impl PacketBuilderStep<VlanHeader> {
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<IpHeaders> {
+    /// Sets the options of the IPv4 header (e.g. a "Router Alert" option
+    /// required by IGMP).
+    ///
+    /// The "intra header length" (IHL), "total length" & "header checksum"
+    /// fields are adjusted automatically based on the options when the packet
+    /// gets written. If the IP header is an IPv6 header writing the packet
+    /// fails with [`BuildWriteError::Ipv4OptionsInIpv6`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, Ipv4Options, IpNumber};
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,1],  //source ip
+    ///         [224,0,0,22],   //destination ip
+    ///         1)              //time to life
+    ///    // router alert option (RFC 2113)
+    ///    .ipv4_options(Ipv4Options::from([148, 4, 0, 0]));
+    ///
+    /// // IGMPv3 membership report
+    /// let payload = [0x22, 0x00, 0xfa, 0xfd, 0, 0, 0, 0];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, IpNumber::IGMP, &payload).unwrap();
+    /// assert_eq!(result.len(), 24 + payload.len());
+    /// ```
+    pub fn ipv4_options(mut self, options: Ipv4Options) -> PacketBuilderStep<IpHeaders> {
+        match self.state.ip_header.as_mut() {
+            Some(IpHeaders::Ipv4(ip, _)) => ip.options = options,
+            _ => self.state.ipv4_options_in_ipv6 = true,
+        }
+        self
+    }
+
     /// Adds an ICMPv4 header of the given [`Icmpv4Type`] to the packet.
     ///
     /// If an ICMPv4 header gets added the payload used during the builders `write`
@@ -1171,6 +1941,198 @@ impl PacketBuilderStep<IpHeaders> {
         }
     }
 
+    /// Adds an ICMPv4 timestamp request packet (the "receive" & "transmit"
+    /// timestamps are set to 0).
+    ///
+    /// Timestamp messages have no payload, so an empty payload should be
+    /// passed to `write`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,1],  //source ip
+    ///          [192,168,1,2], //destination ip
+    ///          20)            //time to life
+    ///    .icmpv4_timestamp_request(
+    ///         123,       // identifier
+    ///         456,       // sequence number
+    ///         3_600_000, // originate timestamp (ms since midnight UT)
+    ///     );
+    ///
+    /// // get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(0));
+    ///
+    /// // serialize
+    /// builder.write(&mut result, &[]).unwrap();
+    /// ```
+    pub fn icmpv4_timestamp_request(
+        mut self,
+        id: u16,
+        seq: u16,
+        originate_timestamp: u32,
+    ) -> PacketBuilderStep<Icmpv4Header> {
+        let msg = icmpv4::TimestampMessage {
+            id,
+            seq,
+            originate_timestamp,
+            receive_timestamp: 0,
+            transmit_timestamp: 0,
+        };
+        let icmpv4 = Icmpv4Header::new(Icmpv4Type::TimestampRequest(msg));
+        self.state.transport_header = Some(TransportHeader::Icmpv4(icmpv4));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Icmpv4Header> {},
+        }
+    }
+
+    /// Adds an ICMPv4 timestamp reply packet.
+    ///
+    /// Timestamp messages have no payload, so an empty payload should be
+    /// passed to `write`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,2],  //source ip
+    ///          [192,168,1,1], //destination ip
+    ///          20)            //time to life
+    ///    .icmpv4_timestamp_reply(
+    ///         123,       // identifier
+    ///         456,       // sequence number
+    ///         3_600_000, // originate timestamp
+    ///         3_600_010, // receive timestamp
+    ///         3_600_011, // transmit timestamp
+    ///     );
+    ///
+    /// // get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(0));
+    ///
+    /// // serialize
+    /// builder.write(&mut result, &[]).unwrap();
+    /// ```
+    pub fn icmpv4_timestamp_reply(
+        mut self,
+        id: u16,
+        seq: u16,
+        originate_timestamp: u32,
+        receive_timestamp: u32,
+        transmit_timestamp: u32,
+    ) -> PacketBuilderStep<Icmpv4Header> {
+        let msg = icmpv4::TimestampMessage {
+            id,
+            seq,
+            originate_timestamp,
+            receive_timestamp,
+            transmit_timestamp,
+        };
+        let icmpv4 = Icmpv4Header::new(Icmpv4Type::TimestampReply(msg));
+        self.state.transport_header = Some(TransportHeader::Icmpv4(icmpv4));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Icmpv4Header> {},
+        }
+    }
+
+    /// Adds an ICMPv4 address mask request packet (the address mask is
+    /// set to 0).
+    ///
+    /// Address mask messages have no payload, so an empty payload should be
+    /// passed to `write`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,1],  //source ip
+    ///          [192,168,1,2], //destination ip
+    ///          20)            //time to life
+    ///    .icmpv4_address_mask_request(
+    ///         123, // identifier
+    ///         456, // sequence number
+    ///     );
+    ///
+    /// // get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(0));
+    ///
+    /// // serialize
+    /// builder.write(&mut result, &[]).unwrap();
+    /// ```
+    pub fn icmpv4_address_mask_request(
+        mut self,
+        id: u16,
+        seq: u16,
+    ) -> PacketBuilderStep<Icmpv4Header> {
+        let msg = icmpv4::AddressMaskMessage {
+            id,
+            seq,
+            address_mask: [0; 4],
+        };
+        let icmpv4 = Icmpv4Header::new(Icmpv4Type::AddressMaskRequest(msg));
+        self.state.transport_header = Some(TransportHeader::Icmpv4(icmpv4));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Icmpv4Header> {},
+        }
+    }
+
+    /// Adds an ICMPv4 address mask reply packet.
+    ///
+    /// Address mask messages have no payload, so an empty payload should be
+    /// passed to `write`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,2],  //source ip
+    ///          [192,168,1,1], //destination ip
+    ///          20)            //time to life
+    ///    .icmpv4_address_mask_reply(
+    ///         123,             // identifier
+    ///         456,             // sequence number
+    ///         [255,255,255,0], // address mask
+    ///     );
+    ///
+    /// // get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(0));
+    ///
+    /// // serialize
+    /// builder.write(&mut result, &[]).unwrap();
+    /// ```
+    pub fn icmpv4_address_mask_reply(
+        mut self,
+        id: u16,
+        seq: u16,
+        address_mask: [u8; 4],
+    ) -> PacketBuilderStep<Icmpv4Header> {
+        let msg = icmpv4::AddressMaskMessage {
+            id,
+            seq,
+            address_mask,
+        };
+        let icmpv4 = Icmpv4Header::new(Icmpv4Type::AddressMaskReply(msg));
+        self.state.transport_header = Some(TransportHeader::Icmpv4(icmpv4));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Icmpv4Header> {},
+        }
+    }
+
     /// Adds an ICMPv6 header of the given [`Icmpv6Type`] to the packet.
     ///
     /// If an ICMPv6 header gets added the payload used during the builders `write`
@@ -1514,6 +2476,8 @@ impl PacketBuilderStep<IpHeaders> {
         }
     }
 
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     /// Write all the headers and the payload with the given ip number.
     ///
     /// `last_next_header_ip_number` will be set in the last extension header
@@ -1533,14 +2497,32 @@ impl PacketBuilderStep<IpHeaders> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and the payload with the given ip number
+    /// into a [`PacketBuf`] (see [`PacketBuilderStep::<IpHeaders>::write`]
+    /// for the meaning of `last_next_header_ip_number`).
+    pub fn build_into<P: PacketBuf + ?Sized>(
+        mut self,
+        buf: &mut P,
+        last_next_header_ip_number: IpNumber,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        self.state
+            .ip_header
+            .as_mut()
+            .unwrap()
+            .set_next_headers(last_next_header_ip_number);
+        final_build_into(self, buf, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<Icmpv4Header> {
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     /// Write all the headers and the payload.
     pub fn write<T: io::Write + Sized>(
         self,
@@ -1550,14 +2532,28 @@ impl PacketBuilderStep<Icmpv4Header> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and the payload into a [`PacketBuf`]
+    /// (e.g. a [`SliceBuf`] or `ArrayVec<u8, N>` to avoid allocations).
+    ///
+    /// Returns a [`BuildWriteError::BufSpace`] error if the packet does
+    /// not fit into the buffer (in that case nothing is written).
+    pub fn build_into<P: PacketBuf + ?Sized>(
+        self,
+        buf: &mut P,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_build_into(self, buf, payload)
+    }
+
     /// Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<Icmpv6Header> {
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     ///Write all the headers and the payload.
     pub fn write<T: io::Write + Sized>(
         self,
@@ -1567,14 +2563,28 @@ impl PacketBuilderStep<Icmpv6Header> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and the payload into a [`PacketBuf`]
+    /// (e.g. a [`SliceBuf`] or `ArrayVec<u8, N>` to avoid allocations).
+    ///
+    /// Returns a [`BuildWriteError::BufSpace`] error if the packet does
+    /// not fit into the buffer (in that case nothing is written).
+    pub fn build_into<P: PacketBuf + ?Sized>(
+        self,
+        buf: &mut P,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_build_into(self, buf, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<UdpHeader> {
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     ///Write all the headers and the payload.
     pub fn write<T: io::Write + Sized>(
         self,
@@ -1584,13 +2594,185 @@ impl PacketBuilderStep<UdpHeader> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and the payload into a [`PacketBuf`]
+    /// (e.g. a [`SliceBuf`] or `ArrayVec<u8, N>` to avoid allocations).
+    ///
+    /// Returns a [`BuildWriteError::BufSpace`] error if the packet does
+    /// not fit into the buffer (in that case nothing is written).
+    pub fn build_into<P: PacketBuf + ?Sized>(
+        self,
+        buf: &mut P,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_build_into(self, buf, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
-}
 
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Serializes the packet & returns a [`PacketTemplate`] that allows
+    /// changing the ports & IPv4 identification of the packet without
+    /// serializing it again.
+    ///
+    /// ```
+    /// use etherparse::PacketBuilder;
+    ///
+    /// let mut template = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234)
+    ///     .template(&[1, 2, 3, 4])
+    ///     .unwrap();
+    /// for port in 1000..1010 {
+    ///     template.set_source_port(port);
+    ///     // send template.packet()
+    /// }
+    /// ```
+    pub fn template(self, payload: &[u8]) -> Result<PacketTemplate, BuildWriteError> {
+        final_template(self, payload, false)
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Serializes the UDP datagram as IP fragments with a size of at most
+    /// `mtu` bytes each (size of the IP packets, excluding the link layer
+    /// headers).
+    ///
+    /// The UDP checksum is calculated over the complete payload. The first
+    /// fragment contains the UDP header & the start of the payload, all
+    /// following fragments only contain the rest of the payload.
+    ///
+    /// All fragments use the same `identification` value (for IPv4 only
+    /// the lower 16 bits are used, for IPv6 it is written in a fragment
+    /// header). The identification must not be reused for another datagram
+    /// between the same addresses while the fragments can still be in
+    /// transit, as otherwise the receiver can not reassemble them
+    /// correctly.
+    ///
+    /// If the datagram fits into one packet a single non fragmented packet
+    /// is generated (without an IPv6 fragment header). IPv4 & IPv6
+    /// extension headers that are part of the fragmentable part of a packet
+    /// (authentication header & final destination options) are only
+    /// written in the first fragment. Every fragment contains at least 8
+    /// bytes of payload (even if this exceeds the `mtu`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let fragments: Vec<Vec<u8>> = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234)
+    ///     .fragments(1500, 1234, &[0u8; 4000])
+    ///     .unwrap()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(3, fragments.len());
+    ///
+    /// let first = SlicedPacket::from_ip(&fragments[0]).unwrap();
+    /// assert!(first.is_ip_payload_fragmented());
+    /// ```
+    pub fn fragments(
+        self,
+        mtu: u16,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<UdpFragments, BuildWriteError> {
+        use crate::IpHeaders::*;
+        use BuildWriteError::*;
+
+        let mut state = self.state;
+        let (source_port, destination_port) = match state.transport_header.take() {
+            Some(TransportHeader::Udp(udp)) => (udp.source_port, udp.destination_port),
+            _ => unreachable!(),
+        };
+
+        // serialize the fragmentable part (extension headers, udp header & payload)
+        let mut datagram = Vec::new();
+        let (first_ip_number, unfragmentable_len, fragment_header_len) =
+            match state.ip_header.as_mut().unwrap() {
+                Ipv4(ip, exts) => {
+                    ip.identification = identification as u16;
+                    let udp =
+                        UdpHeader::with_ipv4_checksum(source_port, destination_port, ip, payload)
+                            .map_err(PayloadLen)?;
+                    let first = match exts.auth.take() {
+                        Some(mut auth) => {
+                            auth.next_header = IpNumber::UDP;
+                            auth.write(&mut datagram).map_err(Io)?;
+                            IpNumber::AUTHENTICATION_HEADER
+                        }
+                        None => IpNumber::UDP,
+                    };
+                    datagram.extend_from_slice(&udp.to_bytes());
+                    (first, ip.header_len(), 0)
+                }
+                Ipv6(ip, exts) => {
+                    let udp =
+                        UdpHeader::with_ipv6_checksum(source_port, destination_port, ip, payload)
+                            .map_err(PayloadLen)?;
+                    let final_destination_options = exts
+                        .routing
+                        .as_mut()
+                        .and_then(|r| r.final_destination_options.take());
+                    let auth = exts.auth.take();
+                    exts.fragment = None;
+
+                    let mut first = IpNumber::UDP;
+                    let mut fragmentable_exts = Vec::new();
+                    if let Some(mut dest) = final_destination_options {
+                        dest.next_header = first;
+                        first = IpNumber::IPV6_DESTINATION_OPTIONS;
+                        fragmentable_exts.push(dest.to_bytes().to_vec());
+                    }
+                    if let Some(mut auth) = auth {
+                        auth.next_header = first;
+                        first = IpNumber::AUTHENTICATION_HEADER;
+                        fragmentable_exts.push(auth.to_bytes().to_vec());
+                    }
+                    for ext in fragmentable_exts.iter().rev() {
+                        datagram.extend_from_slice(ext);
+                    }
+                    datagram.extend_from_slice(&udp.to_bytes());
+                    (
+                        first,
+                        Ipv6Header::LEN + exts.header_len(),
+                        Ipv6FragmentHeader::LEN,
+                    )
+                }
+            };
+        datagram.extend_from_slice(payload);
+
+        // all fragments except the last one need a payload len that is
+        // a multiple of 8
+        let mtu = usize::from(mtu);
+        let fragment_len =
+            8.max(mtu.saturating_sub(unfragmentable_len + fragment_header_len) / 8 * 8);
+        let fragmented = unfragmentable_len + datagram.len() > mtu && datagram.len() > fragment_len;
+        let fragment_len = if fragmented {
+            fragment_len
+        } else {
+            datagram.len()
+        };
+
+        Ok(UdpFragments {
+            builder: PacketBuilderStep {
+                state,
+                _marker: marker::PhantomData::<IpHeaders> {},
+            },
+            datagram,
+            first_ip_number,
+            identification,
+            fragmented,
+            fragment_len,
+            offset: 0,
+            done: false,
+        })
+    }
+}
+
 impl PacketBuilderStep<TcpHeader> {
     ///Set ns flag (ECN-nonce - concealment protection; experimental: see RFC 3540)
     pub fn ns(mut self) -> PacketBuilderStep<TcpHeader> {
@@ -1736,6 +2918,8 @@ impl PacketBuilderStep<TcpHeader> {
         Ok(self)
     }
 
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     ///Write all the headers and the payload.
     pub fn write<T: io::Write + Sized>(
         self,
@@ -1745,20 +2929,492 @@ impl PacketBuilderStep<TcpHeader> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and the payload into a [`PacketBuf`]
+    /// (e.g. a [`SliceBuf`] or `ArrayVec<u8, N>` to avoid allocations).
+    ///
+    /// Returns a [`BuildWriteError::BufSpace`] error if the packet does
+    /// not fit into the buffer (in that case nothing is written).
+    pub fn build_into<P: PacketBuf + ?Sized>(
+        self,
+        buf: &mut P,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_build_into(self, buf, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Serializes the packet & returns a [`PacketTemplate`] that allows
+    /// changing the ports, sequence & acknowledgment numbers and IPv4
+    /// identification of the packet without serializing it again.
+    ///
+    /// ```
+    /// use etherparse::PacketBuilder;
+    ///
+    /// let mut template = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .tcp(21, 1234, 0, 4000)
+    ///     .syn()
+    ///     .template(&[])
+    ///     .unwrap();
+    /// for sequence_number in 0..10 {
+    ///     template.set_tcp_sequence_number(sequence_number);
+    ///     // send template.packet()
+    /// }
+    /// ```
+    pub fn template(self, payload: &[u8]) -> Result<PacketTemplate, BuildWriteError> {
+        final_template(self, payload, true)
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Splits the payload into TCP segments of at most `mss` bytes & returns
+    /// an iterator over the serialized packets (a software version of the
+    /// "TCP segmentation offload" done by network cards).
+    ///
+    /// For every segment the TCP sequence number is advanced by the payload
+    /// already sent, the FIN & PSH flags are only kept in the last segment
+    /// and all lengths & checksums are calculated. If an IPv4 header is
+    /// present its identification field is incremented by one for every
+    /// segment. An empty payload results in a single segment and an `mss`
+    /// of 0 is treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket, TransportSlice};
+    ///
+    /// let segments: Vec<Vec<u8>> = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .tcp(21, 1234, 1000, 4000)
+    ///     .psh()
+    ///     .segments(1000, &[0u8; 2500])
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(3, segments.len());
+    ///
+    /// let last = SlicedPacket::from_ip(&segments[2]).unwrap();
+    /// if let Some(TransportSlice::Tcp(tcp)) = last.transport {
+    ///     assert_eq!(3000, tcp.sequence_number());
+    ///     assert!(tcp.psh());
+    ///     assert_eq!(500, tcp.payload().len());
+    /// }
+    /// ```
+    pub fn segments(self, mss: u16, payload: &[u8]) -> TcpSegments<'_> {
+        TcpSegments {
+            builder: self,
+            mss: usize::from(mss.max(1)),
+            payload,
+            offset: 0,
+            index: 0,
+            done: false,
+        }
+    }
 }
 
-/// Write all the headers and the payload.
+/// Iterator over the serialized TCP segments of a payload (created via
+/// [`PacketBuilderStep::<TcpHeader>::segments`]).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct TcpSegments<'a> {
+    builder: PacketBuilderStep<TcpHeader>,
+    mss: usize,
+    payload: &'a [u8],
+    offset: usize,
+    index: u16,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl Iterator for TcpSegments<'_> {
+    type Item = Result<Vec<u8>, BuildWriteError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let end = self.payload.len().min(self.offset + self.mss);
+        let is_last = end == self.payload.len();
+        let mut step = PacketBuilderStep::<TcpHeader> {
+            state: self.builder.state.clone(),
+            _marker: marker::PhantomData::<TcpHeader> {},
+        };
+        if let Some(tcp) = step
+            .state
+            .transport_header
+            .as_mut()
+            .and_then(|t| t.mut_tcp())
+        {
+            tcp.sequence_number = tcp.sequence_number.wrapping_add(self.offset as u32);
+            if false == is_last {
+                tcp.fin = false;
+                tcp.psh = false;
+            }
+        }
+        if let Some(IpHeaders::Ipv4(ipv4, _)) = step.state.ip_header.as_mut() {
+            ipv4.identification = ipv4.identification.wrapping_add(self.index);
+        }
+
+        let chunk = &self.payload[self.offset..end];
+        let mut packet = Vec::with_capacity(step.size(chunk.len()));
+        let result = step.write(&mut packet, chunk).map(|_| packet);
+
+        self.offset = end;
+        self.index = self.index.wrapping_add(1);
+        self.done = is_last;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.done {
+            0
+        } else {
+            // at least one segment is generated (even for empty payloads)
+            1.max((self.payload.len() - self.offset).div_ceil(self.mss))
+        };
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "std")]
+impl ExactSizeIterator for TcpSegments<'_> {}
+
+/// Iterator over the serialized IP fragments of an UDP datagram (created
+/// via [`PacketBuilderStep::<UdpHeader>::fragments`]).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct UdpFragments {
+    /// Builder without the transport header & without the extension
+    /// headers that are part of the fragmentable part.
+    builder: PacketBuilderStep<IpHeaders>,
+    /// Fragmentable part of the packet (extension headers, udp header &
+    /// payload).
+    datagram: Vec<u8>,
+    first_ip_number: IpNumber,
+    identification: u32,
+    fragmented: bool,
+    fragment_len: usize,
+    offset: usize,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl Iterator for UdpFragments {
+    type Item = Result<Vec<u8>, BuildWriteError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let end = self.datagram.len().min(self.offset + self.fragment_len);
+        let is_last = end == self.datagram.len();
+        let mut step = PacketBuilderStep::<IpHeaders> {
+            state: self.builder.state.clone(),
+            _marker: marker::PhantomData::<IpHeaders> {},
+        };
+        if self.fragmented {
+            let fragment_offset = match IpFragOffset::try_new((self.offset / 8) as u16) {
+                Ok(value) => value,
+                Err(_) => {
+                    self.done = true;
+                    return Some(Err(BuildWriteError::PayloadLen(err::ValueTooBigError {
+                        actual: self.offset,
+                        max_allowed: usize::from(IpFragOffset::MAX_U16) * 8,
+                        value_type: err::ValueType::IpFragmentOffset,
+                    })));
+                }
+            };
+            match step.state.ip_header.as_mut() {
+                Some(IpHeaders::Ipv4(ip, _)) => {
+                    ip.dont_fragment = false;
+                    ip.more_fragments = false == is_last;
+                    ip.fragment_offset = fragment_offset;
+                }
+                Some(IpHeaders::Ipv6(_, exts)) => {
+                    // next header is set by the write call
+                    exts.fragment = Some(Ipv6FragmentHeader::new(
+                        self.first_ip_number,
+                        fragment_offset,
+                        false == is_last,
+                        self.identification,
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        let chunk = &self.datagram[self.offset..end];
+        let mut packet = Vec::with_capacity(step.size(chunk.len()));
+        let result = step
+            .write(&mut packet, self.first_ip_number, chunk)
+            .map(|_| packet);
+
+        self.offset = end;
+        self.done = is_last;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.done {
+            0
+        } else {
+            1.max((self.datagram.len() - self.offset).div_ceil(self.fragment_len))
+        };
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "std")]
+impl ExactSizeIterator for UdpFragments {}
+
+/// Destination the headers & the payload of a packet are written to.
+trait FrameWriter {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BuildWriteError>;
+}
+
+/// Adapter to write the packet to an [`io::Write`].
+#[cfg(feature = "std")]
+struct IoFrameWriter<'a, T: io::Write + Sized>(&'a mut T);
+
+#[cfg(feature = "std")]
+impl<T: io::Write + Sized> FrameWriter for IoFrameWriter<'_, T> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BuildWriteError> {
+        self.0.write_all(data).map_err(BuildWriteError::Io)
+    }
+}
+
+/// Adapter to write the packet to a [`PacketBuf`].
+struct BufFrameWriter<'a, P: PacketBuf + ?Sized>(&'a mut P);
+
+impl<P: PacketBuf + ?Sized> FrameWriter for BufFrameWriter<'_, P> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BuildWriteError> {
+        self.0
+            .extend_from_slice(data)
+            .map_err(BuildWriteError::BufSpace)
+    }
+}
+
+/// Writer that forwards all data & calculates the CRC-32 of it.
+#[cfg(feature = "std")]
+struct Crc32Writer<'a, W: FrameWriter> {
+    writer: &'a mut W,
+    crc: checksum::Crc32,
+}
+
+#[cfg(feature = "std")]
+impl<W: FrameWriter> FrameWriter for Crc32Writer<'_, W> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BuildWriteError> {
+        self.writer.write_bytes(data)?;
+        self.crc = self.crc.clone().add_slice(data);
+        Ok(())
+    }
+}
+
+/// Corruptions of a builder together with the offsets of the headers
+/// they are applied to.
+#[cfg(feature = "std")]
+struct Corruptions {
+    corruptions: Vec<PacketCorruption>,
+    ip_start: usize,
+    is_ipv4: bool,
+    transport_start: usize,
+    transport: Option<IpNumber>,
+}
+
+#[cfg(feature = "std")]
+impl Corruptions {
+    /// Moves the corruptions out of the builder.
+    fn take<B>(builder: &mut PacketBuilderStep<B>) -> Corruptions {
+        use crate::TransportHeader::*;
+
+        let (ip_start, is_ipv4, transport_start) = header_offsets(builder);
+        Corruptions {
+            corruptions: core::mem::take(&mut builder.state.corruptions),
+            ip_start,
+            is_ipv4,
+            transport_start,
+            transport: builder.state.transport_header.as_ref().map(|t| match t {
+                Icmpv4(_) | Icmpv6(_) => ip_number::ICMP,
+                Udp(_) => ip_number::UDP,
+                Tcp(_) => ip_number::TCP,
+            }),
+        }
+    }
+
+    /// Overwrites the corrupted fields in the serialized packet (without
+    /// the ethernet fcs).
+    fn apply(self, packet: &mut [u8]) {
+        use PacketCorruption::*;
+
+        let Corruptions {
+            corruptions,
+            ip_start,
+            is_ipv4,
+            transport_start,
+            transport,
+        } = self;
+        for corruption in corruptions {
+            let (offset, value) = match corruption {
+                Ipv4HeaderChecksum(v) if is_ipv4 => (ip_start + 10, v),
+                Ipv4TotalLen(v) if is_ipv4 => (ip_start + 2, v),
+                Ipv6PayloadLength(v) if false == is_ipv4 => (ip_start + 4, v),
+                UdpLength(v) if Some(ip_number::UDP) == transport => (transport_start + 4, v),
+                UdpChecksum(v) if Some(ip_number::UDP) == transport => (transport_start + 6, v),
+                TcpChecksum(v) if Some(ip_number::TCP) == transport => (transport_start + 16, v),
+                IcmpChecksum(v) if Some(ip_number::ICMP) == transport => (transport_start + 2, v),
+                TcpDataOffset(v) if Some(ip_number::TCP) == transport => {
+                    let b = &mut packet[transport_start + 12];
+                    *b = (*b & 0b0000_1111) | ((v & 0b0000_1111) << 4);
+                    continue;
+                }
+                _ => continue,
+            };
+            packet[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+        }
+    }
+}
+
+/// Reserve the space for the packet in the buffer and write all the
+/// headers, the payload and the ethernet fcs (if enabled) into it.
+fn final_build_into<P: PacketBuf + ?Sized, B>(
+    builder: PacketBuilderStep<B>,
+    buf: &mut P,
+    payload: &[u8],
+) -> Result<(), BuildWriteError> {
+    buf.reserve(final_size(&builder, payload.len()))
+        .map_err(BuildWriteError::BufSpace)?;
+    let start = buf.as_slice().len();
+    let ethernet_fcs = builder.state.ethernet_fcs;
+
+    #[cfg(feature = "std")]
+    let (builder, corruptions) = {
+        let mut builder = builder;
+        let corruptions = Corruptions::take(&mut builder);
+        (builder, corruptions)
+    };
+
+    final_write_frame(builder, &mut BufFrameWriter(buf), payload)?;
+
+    #[cfg(feature = "std")]
+    corruptions.apply(&mut buf.as_mut_slice()[start..]);
+
+    if ethernet_fcs {
+        let fcs = checksum::Crc32::new()
+            .add_slice(&buf.as_slice()[start..])
+            .to_fcs();
+        buf.extend_from_slice(&fcs)
+            .map_err(BuildWriteError::BufSpace)?;
+    }
+    Ok(())
+}
+
+/// Write all the headers, the payload and the ethernet fcs (if enabled).
+#[cfg(feature = "std")]
 fn final_write<T: io::Write + Sized, B>(
     builder: PacketBuilderStep<B>,
     writer: &mut T,
     payload: &[u8],
+) -> Result<(), BuildWriteError> {
+    if false == builder.state.corruptions.is_empty() {
+        // the corruptions are applied after all lengths & checksums are
+        // calculated, so the packet has to be buffered
+        let mut buffer = Vec::with_capacity(final_size(&builder, payload.len()));
+        final_build_into(builder, &mut buffer, payload)?;
+        return writer.write_all(&buffer).map_err(BuildWriteError::Io);
+    }
+    let mut writer = IoFrameWriter(writer);
+    if builder.state.ethernet_fcs {
+        let mut crc_writer = Crc32Writer {
+            writer: &mut writer,
+            crc: checksum::Crc32::new(),
+        };
+        final_write_frame(builder, &mut crc_writer, payload)?;
+        let fcs = crc_writer.crc.to_fcs();
+        writer.write_bytes(&fcs)
+    } else {
+        final_write_frame(builder, &mut writer, payload)
+    }
+}
+
+/// Returns the start of the ip header, if the ip header is an IPv4 header
+/// and the start of the transport header in the serialized packet.
+#[cfg(feature = "std")]
+fn header_offsets<B>(builder: &PacketBuilderStep<B>) -> (usize, bool, usize) {
+    use crate::IpHeaders::*;
+
+    let ip_start = builder
+        .state
+        .link_header
+        .as_ref()
+        .map(|l| l.header_len())
+        .unwrap_or(0)
+        + builder.state.macsec_header_len()
+        + builder
+            .state
+            .vlan_header
+            .as_ref()
+            .map(|v| v.header_len())
+            .unwrap_or(0);
+    let (is_ipv4, transport_start) = match &builder.state.ip_header {
+        Some(Ipv4(ip, ext)) => (true, ip_start + ip.header_len() + ext.header_len()),
+        Some(Ipv6(_, ext)) => (false, ip_start + Ipv6Header::LEN + ext.header_len()),
+        None => (false, ip_start),
+    };
+    (ip_start, is_ipv4, transport_start)
+}
+
+/// Serializes the packet & creates a [`PacketTemplate`] from it.
+#[cfg(feature = "std")]
+fn final_template<B>(
+    builder: PacketBuilderStep<B>,
+    payload: &[u8],
+    is_tcp: bool,
+) -> Result<PacketTemplate, BuildWriteError> {
+    let (ip_start, is_ipv4, transport_start) = header_offsets(&builder);
+    let ethernet_fcs = builder.state.ethernet_fcs;
+    // start of the macsec header & length of the integrity check value
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    let macsec = builder.state.macsec.as_ref().map(|(_, icv)| {
+        let macsec_start = builder
+            .state
+            .link_header
+            .as_ref()
+            .map(|l| l.header_len())
+            .unwrap_or(0);
+        (macsec_start, icv.len())
+    });
+    #[cfg(not(all(feature = "std", feature = "macsec")))]
+    let macsec = None;
+    let mut data = Vec::with_capacity(final_size(&builder, payload.len()));
+    final_write(builder, &mut data, payload)?;
+    Ok(PacketTemplate::new(
+        data,
+        ip_start,
+        is_ipv4,
+        transport_start,
+        is_tcp,
+        ethernet_fcs,
+        macsec,
+    ))
+}
+
+/// Write all the headers and the payload.
+fn final_write_frame<W: FrameWriter, B>(
+    builder: PacketBuilderStep<B>,
+    writer: &mut W,
+    payload: &[u8],
 ) -> Result<(), BuildWriteError> {
     use BuildWriteError::*;
 
+    if builder.state.ipv4_options_in_ipv6 {
+        return Err(Ipv4OptionsInIpv6);
+    }
+
     let ip_ether_type = {
         use crate::IpHeaders::*;
         match builder.state.ip_header {
@@ -1768,21 +3424,48 @@ fn final_write<T: io::Write + Sized, B>(
         }
     };
 
+    //determine the ether type depending on if there is a vlan tagging header
+    let vlan_or_ip_ether_type = {
+        use crate::VlanHeader::*;
+        match builder.state.vlan_header {
+            Some(Single(_)) => ether_type::VLAN_TAGGED_FRAME,
+            Some(Double(_)) => ether_type::PROVIDER_BRIDGING,
+            //if no vlan header exists, the id is purely defined by the ip type
+            None => ip_ether_type,
+        }
+    };
+
+    //length of the macsec secure data (inner ether type, headers & payload)
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    let macsec_secure_data_len = builder.state.macsec.as_ref().map(|(header, icv)| {
+        final_size(&builder, payload.len())
+            - builder
+                .state
+                .link_header
+                .as_ref()
+                .map(|l| l.header_len())
+                .unwrap_or(0)
+            - header.header_len()
+            - icv.len()
+            - if builder.state.ethernet_fcs { 4 } else { 0 }
+    });
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    let macsec = builder.state.macsec;
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    let is_macsec = macsec.is_some();
+    #[cfg(not(all(feature = "std", feature = "macsec")))]
+    let is_macsec = false;
+
     //link header
     if let Some(link) = builder.state.link_header {
         match link {
             LinkHeader::Ethernet2(mut eth) => {
-                eth.ether_type = {
-                    use crate::VlanHeader::*;
-                    //determine the ether type depending on if there is a vlan tagging header
-                    match builder.state.vlan_header {
-                        Some(Single(_)) => ether_type::VLAN_TAGGED_FRAME,
-                        Some(Double(_)) => ether_type::PROVIDER_BRIDGING,
-                        //if no vlan header exists, the id is purely defined by the ip type
-                        None => ip_ether_type,
-                    }
+                eth.ether_type = if is_macsec {
+                    ether_type::MACSEC
+                } else {
+                    vlan_or_ip_ether_type
                 };
-                eth.write(writer).map_err(Io)?;
+                writer.write_bytes(&eth.to_bytes())?;
             }
             LinkHeader::LinuxSll(mut linux_sll) => {
                 // Assumes that next layers are ether based. If more types of
@@ -1790,11 +3473,20 @@ fn final_write<T: io::Write + Sized, B>(
                 debug_assert_eq!(linux_sll.arp_hrd_type, ArpHardwareId::ETHER);
 
                 linux_sll.protocol_type.change_value(ip_ether_type.into());
-                linux_sll.write(writer).map_err(Io)?;
+                writer.write_bytes(&linux_sll.to_bytes())?;
             }
         }
     }
 
+    //write the macsec header & the ether type at the start of the secure data
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    if let (Some((mut header, _)), Some(secure_data_len)) = (macsec.clone(), macsec_secure_data_len)
+    {
+        header.set_secure_data_len(secure_data_len);
+        writer.write_bytes(&header.to_bytes())?;
+        writer.write_bytes(&vlan_or_ip_ether_type.0.to_be_bytes())?;
+    }
+
     //write the vlan header if it exists
     use crate::VlanHeader::*;
     match builder.state.vlan_header {
@@ -1802,14 +3494,14 @@ fn final_write<T: io::Write + Sized, B>(
             //set ether types
             value.ether_type = ip_ether_type;
             //serialize
-            value.write(writer).map_err(Io)?;
+            writer.write_bytes(&value.to_bytes())?;
         }
         Some(Double(mut value)) => {
             //set ether types
             value.outer.ether_type = ether_type::VLAN_TAGGED_FRAME;
             value.inner.ether_type = ip_ether_type;
             //serialize
-            value.write(writer).map_err(Io)?;
+            writer.write_bytes(&value.to_bytes())?;
         }
         None => {}
     }
@@ -1829,26 +3521,15 @@ fn final_write<T: io::Write + Sized, B>(
                 Ipv4(mut ip, ext) => {
                     ip.set_payload_len(ext.header_len() + payload.len())
                         .map_err(PayloadLen)?;
-                    ip.write(writer).map_err(Io)?;
-                    ext.write(writer, ip.protocol).map_err(|err| {
-                        use err::ipv4_exts::HeaderWriteError as I;
-                        match err {
-                            I::Io(err) => Io(err),
-                            I::Content(err) => Ipv4Exts(err),
-                        }
-                    })?;
+                    ip.header_checksum = ip.calc_header_checksum();
+                    writer.write_bytes(&ip.to_bytes())?;
+                    ext.write_with(ip.protocol, |bytes| writer.write_bytes(bytes), Ipv4Exts)?;
                 }
                 Ipv6(mut ip, ext) => {
                     ip.set_payload_length(ext.header_len() + payload.len())
                         .map_err(PayloadLen)?;
-                    ip.write(writer).map_err(Io)?;
-                    ext.write(writer, ip.next_header).map_err(|err| {
-                        use err::ipv6_exts::HeaderWriteError as I;
-                        match err {
-                            I::Io(err) => Io(err),
-                            I::Content(err) => Ipv6Exts(err),
-                        }
-                    })?;
+                    writer.write_bytes(&ip.to_bytes())?;
+                    ext.write_with(ip.next_header, |bytes| writer.write_bytes(bytes), Ipv6Exts)?;
                 }
             }
         }
@@ -1888,15 +3569,10 @@ fn final_write<T: io::Write + Sized, B>(
                             }
                         })?;
 
-                    //write (will automatically calculate the checksum)
-                    ip.write(writer).map_err(Io)?;
-                    ext.write(writer, ip.protocol).map_err(|err| {
-                        use err::ipv4_exts::HeaderWriteError as I;
-                        match err {
-                            I::Io(err) => Io(err),
-                            I::Content(err) => Ipv4Exts(err),
-                        }
-                    })?;
+                    //calculate the header checksum & write
+                    ip.header_checksum = ip.calc_header_checksum();
+                    writer.write_bytes(&ip.to_bytes())?;
+                    ext.write_with(ip.protocol, |bytes| writer.write_bytes(bytes), Ipv4Exts)?;
                 }
                 Ipv6(mut ip, mut ext) => {
                     //set total length
@@ -1926,23 +3602,27 @@ fn final_write<T: io::Write + Sized, B>(
                         .update_checksum_ipv6(&ip, payload)
                         .map_err(PayloadLen)?;
 
-                    //write (will automatically calculate the checksum)
-                    ip.write(writer).map_err(Io)?;
-                    ext.write(writer, ip.next_header).map_err(|err| {
-                        use err::ipv6_exts::HeaderWriteError as I;
-                        match err {
-                            I::Io(err) => Io(err),
-                            I::Content(err) => Ipv6Exts(err),
-                        }
-                    })?;
+                    writer.write_bytes(&ip.to_bytes())?;
+                    ext.write_with(ip.next_header, |bytes| writer.write_bytes(bytes), Ipv6Exts)?;
                 }
             }
 
             //finally write the udp header & payload
-            transport.write(writer).map_err(Io)?;
+            match &transport {
+                TransportHeader::Icmpv4(header) => writer.write_bytes(&header.to_bytes())?,
+                TransportHeader::Icmpv6(header) => writer.write_bytes(&header.to_bytes())?,
+                TransportHeader::Udp(header) => writer.write_bytes(&header.to_bytes())?,
+                TransportHeader::Tcp(header) => writer.write_bytes(&header.to_bytes())?,
+            }
         }
     }
-    writer.write_all(payload).map_err(Io)?;
+    writer.write_bytes(payload)?;
+
+    //macsec integrity check value
+    #[cfg(all(feature = "std", feature = "macsec"))]
+    if let Some((_, icv)) = macsec {
+        writer.write_bytes(&icv)?;
+    }
     Ok(())
 }
 
@@ -1954,21 +3634,49 @@ fn final_size<B>(builder: &PacketBuilderStep<B>, payload_size: usize) -> usize {
     (match builder.state.link_header {
         Some(ref header) => header.header_len(),
         None => 0,
-    }) + match builder.state.vlan_header {
-        Some(Single(_)) => SingleVlanHeader::LEN,
-        Some(Double(_)) => DoubleVlanHeader::LEN,
-        None => 0,
-    } + match builder.state.ip_header {
-        Some(Ipv4(ref value, ref ext)) => value.header_len() + ext.header_len(),
-        Some(Ipv6(_, ref ext)) => Ipv6Header::LEN + ext.header_len(),
-        None => 0,
-    } + match builder.state.transport_header {
-        Some(Icmpv4(ref value)) => value.header_len(),
-        Some(Icmpv6(ref value)) => value.header_len(),
-        Some(Udp(_)) => UdpHeader::LEN,
-        Some(Tcp(ref value)) => value.header_len(),
-        None => 0,
-    } + payload_size
+    }) + builder.state.macsec_header_len()
+        + builder.state.macsec_icv_len()
+        + match builder.state.vlan_header {
+            Some(Single(_)) => SingleVlanHeader::LEN,
+            Some(Double(_)) => DoubleVlanHeader::LEN,
+            None => 0,
+        }
+        + match builder.state.ip_header {
+            Some(Ipv4(ref value, ref ext)) => value.header_len() + ext.header_len(),
+            Some(Ipv6(_, ref ext)) => Ipv6Header::LEN + ext.header_len(),
+            None => 0,
+        }
+        + match builder.state.transport_header {
+            Some(Icmpv4(ref value)) => value.header_len(),
+            Some(Icmpv6(ref value)) => value.header_len(),
+            Some(Udp(_)) => UdpHeader::LEN,
+            Some(Tcp(ref value)) => value.header_len(),
+            None => 0,
+        }
+        + if builder.state.ethernet_fcs { 4 } else { 0 }
+        + payload_size
+}
+
+impl PacketImpl {
+    /// Length of the MACsec header & the ether type at the start of the
+    /// secure data (zero if no MACsec header is present).
+    fn macsec_header_len(&self) -> usize {
+        #[cfg(all(feature = "std", feature = "macsec"))]
+        if let Some((header, _)) = &self.macsec {
+            return header.header_len() + 2;
+        }
+        0
+    }
+
+    /// Length of the MACsec integrity check value (zero if no MACsec
+    /// header is present).
+    fn macsec_icv_len(&self) -> usize {
+        #[cfg(all(feature = "std", feature = "macsec"))]
+        if let Some((_, icv)) = &self.macsec {
+            return icv.len();
+        }
+        0
+    }
 }
 
 #[cfg(test)]
@@ -1986,7 +3694,12 @@ mod white_box_tests {
                     link_header: None,
                     ip_header: None,
                     vlan_header: None,
-                    transport_header: None
+                    transport_header: None,
+                    #[cfg(feature = "macsec")]
+                    macsec: None,
+                    ethernet_fcs: false,
+                    ipv4_options_in_ipv6: false,
+                    corruptions: Vec::new(),
                 },
                 _marker: marker::PhantomData::<UdpHeader> {}
             }
@@ -2005,6 +3718,11 @@ mod white_box_tests {
                     ip_header: None,
                     vlan_header: None,
                     transport_header: None,
+                    #[cfg(feature = "macsec")]
+                    macsec: None,
+                    ethernet_fcs: false,
+                    ipv4_options_in_ipv6: false,
+                    corruptions: Vec::new(),
                 },
                 _marker: marker::PhantomData::<UdpHeader> {},
             },
@@ -2015,13 +3733,284 @@ mod white_box_tests {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::test_gens::*;
-    use alloc::{vec, vec::Vec};
-    use proptest::prelude::*;
-    use std::io::Read;
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{vec, vec::Vec};
+    use arrayvec::ArrayVec;
+    use proptest::prelude::*;
+    use std::io::Read;
+
+    #[test]
+    fn max_header_len() {
+        assert_eq!(
+            14 + 14 + 2 + 8 + Ipv6Header::LEN + Ipv6Extensions::MAX_LEN + 60,
+            PacketBuilder::MAX_HEADER_LEN
+        );
+
+        // tcp header with maximum options
+        let builder = PacketBuilder::linux_sll(LinuxSllPacketType::OUTGOING, 6, [0; 8])
+            .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .tcp(1, 2, 3, 4)
+            .options_raw(&[0; 40])
+            .unwrap();
+        let stack = HeaderStackLen::new().linux_sll().ipv4().tcp();
+        assert!(builder.size(0) >= stack.min_len());
+        assert!(builder.size(0) <= stack.max_len());
+        assert!(builder.size(0) <= PacketBuilder::MAX_HEADER_LEN);
+    }
+
+    #[cfg(feature = "macsec")]
+    #[test]
+    fn macsec_max_len() {
+        assert_eq!(MacsecHeader::MAX_LEN, MACSEC_MAX_LEN);
+    }
+
+    #[test]
+    fn build_into() {
+        let payload = [1, 2, 3, 4];
+        let builder = || {
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .with_fcs()
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(21, 1234)
+        };
+        let mut expected = Vec::new();
+        builder().write(&mut expected, &payload).unwrap();
+
+        // vec
+        {
+            let mut buf = vec![9];
+            builder().build_into(&mut buf, &payload).unwrap();
+            assert_eq!(&expected[..], &buf[1..]);
+        }
+
+        // slice buffer
+        {
+            let mut memory = [0u8; 1500];
+            let mut buf = SliceBuf::new(&mut memory);
+            builder().build_into(&mut buf, &payload).unwrap();
+            assert_eq!(&expected[..], buf.as_slice());
+        }
+
+        // array vec
+        {
+            let mut buf = ArrayVec::<u8, 128>::new();
+            builder().build_into(&mut buf, &payload).unwrap();
+            assert_eq!(&expected[..], buf.as_slice());
+        }
+
+        // not enough space (nothing gets written)
+        {
+            let mut memory = [0u8; 1500];
+            let mut buf = SliceBuf::new(&mut memory[..expected.len() - 1]);
+            assert_eq!(
+                Some(&err::BufSpaceError {
+                    required_len: expected.len(),
+                    available_len: expected.len() - 1,
+                }),
+                builder()
+                    .build_into(&mut buf, &payload)
+                    .unwrap_err()
+                    .buf_space()
+            );
+            assert!(buf.is_empty());
+        }
+
+        // ip step with explicit ip number
+        {
+            let mut expected = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .write(&mut expected, IpNumber::UDP, &payload)
+                .unwrap();
+            let mut buf = ArrayVec::<u8, 128>::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .build_into(&mut buf, IpNumber::UDP, &payload)
+                .unwrap();
+            assert_eq!(&expected[..], buf.as_slice());
+        }
+
+        // icmp & tcp steps
+        {
+            let mut expected = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut expected, &payload)
+                .unwrap();
+            let mut buf = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2)
+                .build_into(&mut buf, &payload)
+                .unwrap();
+            assert_eq!(expected, buf);
+        }
+        {
+            let mut expected = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .icmpv6_echo_request(1, 2)
+                .write(&mut expected, &payload)
+                .unwrap();
+            let mut buf = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .icmpv6_echo_request(1, 2)
+                .build_into(&mut buf, &payload)
+                .unwrap();
+            assert_eq!(expected, buf);
+        }
+        {
+            let mut expected = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .tcp(1, 2, 3, 4)
+                .write(&mut expected, &payload)
+                .unwrap();
+            let mut buf = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .tcp(1, 2, 3, 4)
+                .build_into(&mut buf, &payload)
+                .unwrap();
+            assert_eq!(expected, buf);
+        }
+
+        // corruptions & fcs are applied to the written packet only
+        {
+            let corrupted = || {
+                builder()
+                    .corrupt(PacketCorruption::UdpChecksum(0x1234))
+                    .corrupt(PacketCorruption::Ipv4TotalLen(1))
+            };
+            let mut expected = Vec::new();
+            corrupted().write(&mut expected, &payload).unwrap();
+
+            // buffer already containing data
+            let mut memory = [0u8; 1500];
+            let mut buf = SliceBuf::new(&mut memory);
+            buf.extend_from_slice(&[9]).unwrap();
+            corrupted().build_into(&mut buf, &payload).unwrap();
+            assert_eq!(9, buf.as_slice()[0]);
+            assert_eq!(&expected[..], &buf.as_slice()[1..]);
+
+            let (frame, fcs) = buf.as_slice()[1..].split_at(expected.len() - 4);
+            assert_eq!(
+                &[0, 1],
+                &frame[Ethernet2Header::LEN + 2..Ethernet2Header::LEN + 4]
+            );
+            assert_eq!(fcs, checksum::Crc32::new().add_slice(frame).to_fcs());
+        }
+    }
+
+    #[test]
+    fn corrupt() {
+        use PacketCorruption::*;
+
+        let payload = [1, 2, 3, 4];
+        let eth_ipv4_udp = || {
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(VlanId::try_new(1).unwrap())
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(21, 1234)
+        };
+
+        // reference packet without corruption
+        let mut ipv4_udp = Vec::new();
+        eth_ipv4_udp().write(&mut ipv4_udp, &payload).unwrap();
+
+        // ip & udp offsets (ethernet II + vlan)
+        let ip = Ethernet2Header::LEN + SingleVlanHeader::LEN;
+        let udp = ip + Ipv4Header::MIN_LEN;
+
+        // ipv4 & udp corruptions
+        {
+            let mut actual = Vec::new();
+            eth_ipv4_udp()
+                .corrupt(Ipv4HeaderChecksum(0x1234))
+                .corrupt(Ipv4TotalLen(0x2345))
+                .corrupt(UdpLength(0x3456))
+                .corrupt(UdpChecksum(0x4567))
+                // ignored as the headers are not present
+                .corrupt(Ipv6PayloadLength(0x5678))
+                .corrupt(TcpChecksum(0x6789))
+                .corrupt(TcpDataOffset(1))
+                .corrupt(IcmpChecksum(0x789a))
+                .write(&mut actual, &payload)
+                .unwrap();
+
+            let mut expected = ipv4_udp.clone();
+            expected[ip + 10..ip + 12].copy_from_slice(&[0x12, 0x34]);
+            expected[ip + 2..ip + 4].copy_from_slice(&[0x23, 0x45]);
+            expected[udp + 4..udp + 6].copy_from_slice(&[0x34, 0x56]);
+            expected[udp + 6..udp + 8].copy_from_slice(&[0x45, 0x67]);
+            assert_eq!(expected, actual);
+        }
+
+        // fcs is calculated over the corrupted data
+        {
+            let mut actual = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .with_fcs()
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(21, 1234)
+                .corrupt(UdpChecksum(0))
+                .write(&mut actual, &payload)
+                .unwrap();
+            let eth = Ethernet2Slice::from_slice_with_crc32_fcs(&actual).unwrap();
+            assert!(eth.fcs().is_some());
+            assert_eq!(
+                Some(
+                    checksum::Crc32::new()
+                        .add_slice(&actual[..actual.len() - 4])
+                        .to_fcs()
+                ),
+                eth.fcs()
+            );
+            let udp = Ethernet2Header::LEN + Ipv4Header::MIN_LEN;
+            assert_eq!(&[0, 0], &actual[udp + 6..udp + 8]);
+        }
+
+        // ipv6 & tcp corruptions
+        {
+            let mut reference = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .tcp(21, 1234, 1, 1024)
+                .write(&mut reference, &payload)
+                .unwrap();
+
+            let mut actual = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .tcp(21, 1234, 1, 1024)
+                .corrupt(Ipv6PayloadLength(0x1234))
+                .corrupt(TcpChecksum(0x2345))
+                .corrupt(TcpDataOffset(0xf3))
+                .corrupt(Ipv4HeaderChecksum(0x3456))
+                .write(&mut actual, &payload)
+                .unwrap();
+
+            let tcp = Ipv6Header::LEN;
+            let mut expected = reference.clone();
+            expected[4..6].copy_from_slice(&[0x12, 0x34]);
+            expected[tcp + 16..tcp + 18].copy_from_slice(&[0x23, 0x45]);
+            expected[tcp + 12] = 0x30 | (expected[tcp + 12] & 0x0f);
+            assert_eq!(expected, actual);
+            assert_eq!(
+                Err(err::tcp::HeaderSliceError::Content(
+                    err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 3 }
+                )),
+                TcpSlice::from_slice(&actual[tcp..]).map(|_| ())
+            );
+        }
+
+        // icmp checksum
+        {
+            let mut actual = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2)
+                .corrupt(IcmpChecksum(0x1234))
+                .write(&mut actual, &payload)
+                .unwrap();
+            let icmp = Ipv4Header::MIN_LEN;
+            assert_eq!(&[0x12, 0x34], &actual[icmp + 2..icmp + 4]);
+        }
+    }
 
     #[test]
     fn eth_ipv4_udp() {
@@ -2081,6 +4070,70 @@ mod test {
         assert_eq!(actual_payload, in_payload);
     }
 
+    #[test]
+    fn eth_with_fcs() {
+        let in_payload = [24, 25, 26, 27];
+
+        let mut without_fcs = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(VlanId::try_new(12).unwrap())
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23)
+            .write(&mut without_fcs, &in_payload)
+            .unwrap();
+
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .with_fcs()
+            .single_vlan(VlanId::try_new(12).unwrap())
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        assert_eq!(without_fcs.len() + 4, builder.size(in_payload.len()));
+        let mut with_fcs = Vec::new();
+        builder.write(&mut with_fcs, &in_payload).unwrap();
+
+        assert_eq!(without_fcs.len() + 4, with_fcs.len());
+        assert_eq!(&without_fcs[..], &with_fcs[..without_fcs.len()]);
+        assert_eq!(
+            checksum::ethernet_fcs(&without_fcs),
+            with_fcs[without_fcs.len()..]
+        );
+
+        let sliced = SlicedPacket::from_ethernet(&with_fcs).unwrap();
+        match sliced.transport {
+            Some(TransportSlice::Udp(udp)) => assert_eq!(&in_payload, udp.payload()),
+            _ => panic!("expected udp"),
+        }
+    }
+
+    #[test]
+    fn eth_with_fcs_write_error() {
+        use crate::err::packet::BuildWriteError;
+
+        // errors while writing the frame are returned
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .with_fcs()
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        let mut buffer = [0u8; 20];
+        let mut writer = std::io::Cursor::new(&mut buffer[..]);
+        assert!(matches!(
+            builder.write(&mut writer, &[]),
+            Err(BuildWriteError::Io(_))
+        ));
+
+        // errors while writing the fcs are returned
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .with_fcs()
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        let mut buffer = [0u8; Ethernet2Header::LEN + Ipv4Header::MIN_LEN + UdpHeader::LEN + 2];
+        let mut writer = std::io::Cursor::new(&mut buffer[..]);
+        assert!(matches!(
+            builder.write(&mut writer, &[]),
+            Err(BuildWriteError::Io(_))
+        ));
+    }
+
     #[test]
     fn linuxsll_ipv4_udp() {
         //generate
@@ -2141,6 +4194,90 @@ mod test {
         assert_eq!(actual_payload, in_payload);
     }
 
+    #[test]
+    fn linuxsll_header_non_ether() {
+        // a non ether arp hardware type gets replaced by ETHER
+        let mut serialized = Vec::new();
+        PacketBuilder::linux_sll_header(LinuxSllHeader {
+            packet_type: LinuxSllPacketType::OUTGOING,
+            arp_hrd_type: ArpHardwareId::NETLINK,
+            sender_address_valid_length: 6,
+            sender_address: [7, 8, 9, 10, 11, 12, 0, 0],
+            protocol_type: LinuxSllProtocolType::NetlinkProtocolType(0),
+        })
+        .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+        .udp(22, 23)
+        .write(&mut serialized, &[24, 25, 26, 27])
+        .unwrap();
+
+        assert_eq!(
+            LinuxSllHeader::from_slice(&serialized).unwrap().0,
+            LinuxSllHeader {
+                packet_type: LinuxSllPacketType::OUTGOING,
+                arp_hrd_type: ArpHardwareId::ETHER,
+                sender_address_valid_length: 6,
+                sender_address: [7, 8, 9, 10, 11, 12, 0, 0],
+                protocol_type: LinuxSllProtocolType::EtherType(EtherType::IPV4)
+            }
+        );
+        let headers = PacketHeaders::from_linux_sll_slice(&serialized).unwrap();
+        assert_eq!(headers.payload.slice(), &[24, 25, 26, 27]);
+    }
+
+    #[test]
+    fn ipv4_options() {
+        let options: Ipv4Options = [148, 4, 0, 0, 1, 1, 1, 0].into();
+
+        // ipv4 with options
+        {
+            let in_payload = [1, 2, 3, 4, 5, 6];
+            let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .ipv4_options(options.clone())
+                .udp(1234, 5678);
+            assert_eq!(
+                builder.size(in_payload.len()),
+                Ipv4Header::MIN_LEN + options.len() + UdpHeader::LEN + in_payload.len()
+            );
+            let mut serialized = Vec::new();
+            builder.write(&mut serialized, &in_payload).unwrap();
+
+            let (ip, rest) = Ipv4Header::from_slice(&serialized).unwrap();
+            let mut ip_expected = Ipv4Header::new(
+                (UdpHeader::LEN + in_payload.len()) as u16,
+                1,
+                ip_number::UDP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            ip_expected.options = options.clone();
+            ip_expected
+                .set_payload_len(UdpHeader::LEN + in_payload.len())
+                .unwrap();
+            ip_expected.header_checksum = ip_expected.calc_header_checksum();
+            assert_eq!(ip, ip_expected);
+            assert_eq!(7, ip.ihl());
+
+            let (udp, payload) = UdpHeader::from_slice(rest).unwrap();
+            assert_eq!(
+                udp.checksum,
+                udp.calc_checksum_ipv4(&ip, &in_payload).unwrap()
+            );
+            assert_eq!(payload, &in_payload);
+        }
+
+        // ipv6 (error when writing)
+        {
+            let builder = PacketBuilder::ipv6([0; 16], [0; 16], 1).ipv4_options(options.clone());
+            let mut serialized = Vec::new();
+            assert!(builder
+                .write(&mut serialized, ip_number::UDP, &[1, 2])
+                .unwrap_err()
+                .is_ipv4_options_in_ipv6());
+            assert!(serialized.is_empty());
+        }
+    }
+
     #[test]
     fn ipv4() {
         let auth_ext = IpAuthHeader::new(0.into(), 1, 2, &[3, 4, 5, 6]).unwrap();
@@ -2245,6 +4382,7 @@ mod test {
                 routing: None,
                 fragment: None,
                 auth: Some(auth_ext.clone()),
+                mobility: None,
             },
         ));
 
@@ -3039,6 +5177,228 @@ mod test {
         assert_eq!(actual_payload, in_payload);
     }
 
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn response_to() {
+        // ethernet, vlan & ipv4
+        {
+            let mut request = Vec::new();
+            let mut ip =
+                Ipv4Header::new(0, 20, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+            ip.dscp = Ipv4Dscp::EF;
+            ip.ecn = Ipv4Ecn::ECT_0;
+            ip.dont_fragment = true;
+            ip.identification = 123;
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(VlanId::try_new(12).unwrap())
+                .ip(IpHeaders::Ipv4(ip, Default::default()))
+                .udp(1234, 53)
+                .write(&mut request, &[1, 2])
+                .unwrap();
+            let received = SlicedPacket::from_ethernet(&request).unwrap();
+
+            let mut response = Vec::new();
+            PacketBuilder::response_to(&received, 64)
+                .unwrap()
+                .udp(53, 1234)
+                .write(&mut response, &[3, 4, 5])
+                .unwrap();
+            let actual = PacketHeaders::from_ethernet_slice(&response).unwrap();
+            assert_eq!(
+                actual.link,
+                Some(LinkHeader::Ethernet2(Ethernet2Header {
+                    source: [7, 8, 9, 10, 11, 12],
+                    destination: [1, 2, 3, 4, 5, 6],
+                    ether_type: ether_type::VLAN_TAGGED_FRAME,
+                }))
+            );
+            assert_eq!(
+                actual.vlan,
+                Some(received.vlan.as_ref().unwrap().to_header())
+            );
+            let mut expected_ip =
+                Ipv4Header::new(8 + 3, 64, ip_number::UDP, [5, 6, 7, 8], [1, 2, 3, 4]).unwrap();
+            expected_ip.dscp = Ipv4Dscp::EF;
+            expected_ip.ecn = Ipv4Ecn::ECT_0;
+            expected_ip.dont_fragment = true;
+            expected_ip.header_checksum = expected_ip.calc_header_checksum();
+            assert_eq!(
+                actual.net,
+                Some(NetHeaders::Ipv4(expected_ip, Default::default()))
+            );
+            assert_eq!(actual.payload.slice(), &[3, 4, 5]);
+        }
+        // ipv6 without link layer
+        {
+            let mut request = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .udp(1234, 53)
+                .write(&mut request, &[])
+                .unwrap();
+            let received = SlicedPacket::from_ip(&request).unwrap();
+
+            let mut response = Vec::new();
+            PacketBuilder::response_to(&received, 64)
+                .unwrap()
+                .udp(53, 1234)
+                .write(&mut response, &[])
+                .unwrap();
+            let actual = PacketHeaders::from_ip_slice(&response).unwrap();
+            assert_eq!(actual.link, None);
+            match actual.net {
+                Some(NetHeaders::Ipv6(ip, _)) => {
+                    assert_eq!(ip.source, [2; 16]);
+                    assert_eq!(ip.destination, [1; 16]);
+                    assert_eq!(ip.hop_limit, 64);
+                }
+                _ => panic!("expected ipv6 header"),
+            }
+        }
+        // no ip
+        {
+            let mut request = Vec::new();
+            Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [7, 8, 9, 10, 11, 12],
+                ether_type: EtherType(0x1234),
+            }
+            .write(&mut request)
+            .unwrap();
+            let received = SlicedPacket::from_ethernet(&request).unwrap();
+            assert!(PacketBuilder::response_to(&received, 64).is_none());
+            assert!(PacketBuilder::udp_response_to(&received, 64).is_none());
+            assert!(PacketBuilder::tcp_response_to(&received, 64).is_none());
+        }
+    }
+
+    #[test]
+    fn ip_addr_udp_over_tcp_over() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        let v4_a = IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4));
+        let v4_b = IpAddr::V4(Ipv4Addr::new(5, 6, 7, 8));
+        let v6_a = IpAddr::V6(Ipv6Addr::from([1; 16]));
+        let v6_b = IpAddr::V6(Ipv6Addr::from([2; 16]));
+
+        // ipv4
+        {
+            let mut actual = Vec::new();
+            PacketBuilder::udp_over(v4_a, v4_b, 20, 21, 1234)
+                .unwrap()
+                .write(&mut actual, &[1, 2, 3, 4])
+                .unwrap();
+            let mut expected = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(21, 1234)
+                .write(&mut expected, &[1, 2, 3, 4])
+                .unwrap();
+            assert_eq!(expected, actual);
+        }
+        // ipv6
+        {
+            let mut actual = Vec::new();
+            PacketBuilder::tcp_over(v6_a, v6_b, 20, 21, 1234, 1, 2)
+                .unwrap()
+                .write(&mut actual, &[1, 2, 3, 4])
+                .unwrap();
+            let mut expected = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .tcp(21, 1234, 1, 2)
+                .write(&mut expected, &[1, 2, 3, 4])
+                .unwrap();
+            assert_eq!(expected, actual);
+            assert!(!PacketBuilder::ip_addr(v6_a, v6_b, 20)
+                .unwrap()
+                .has_link_header());
+        }
+        // mixed ip versions
+        {
+            assert!(PacketBuilder::ip_addr(v4_a, v6_b, 20).is_none());
+            assert!(PacketBuilder::udp_over(v6_a, v4_b, 20, 1, 2).is_none());
+            assert!(PacketBuilder::tcp_over(v4_a, v6_b, 20, 1, 2, 3, 4).is_none());
+        }
+    }
+
+    #[test]
+    fn has_link_header() {
+        assert!(!PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20).has_link_header());
+        assert!(!PacketBuilder::ipv6([1; 16], [2; 16], 20)
+            .udp(1, 2)
+            .has_link_header());
+        assert!(
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(1.try_into().unwrap())
+                .has_link_header()
+        );
+        assert!(
+            PacketBuilder::linux_sll(LinuxSllPacketType::OUTGOING, 6, [0; 8]).has_link_header()
+        );
+    }
+
+    #[test]
+    fn udp_response_to() {
+        let mut request = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .udp(1234, 53)
+            .write(&mut request, &[1, 2])
+            .unwrap();
+        let received = SlicedPacket::from_ethernet(&request).unwrap();
+
+        let mut response = Vec::new();
+        PacketBuilder::udp_response_to(&received, 64)
+            .unwrap()
+            .write(&mut response, &[3, 4, 5])
+            .unwrap();
+        let actual = SlicedPacket::from_ethernet(&response).unwrap();
+        match actual.transport {
+            Some(TransportSlice::Udp(udp)) => {
+                assert_eq!(udp.source_port(), 53);
+                assert_eq!(udp.destination_port(), 1234);
+                assert_eq!(udp.payload(), &[3, 4, 5]);
+            }
+            _ => panic!("expected udp"),
+        }
+
+        // tcp packet
+        assert!(PacketBuilder::tcp_response_to(&received, 64).is_none());
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn tcp_response_to() {
+        let mut request = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([1; 16], [2; 16], 20)
+            .tcp(1234, 80, 1000, 4000)
+            .ack(2000)
+            .write(&mut request, &[1, 2])
+            .unwrap();
+        let received = SlicedPacket::from_ethernet(&request).unwrap();
+
+        let mut response = Vec::new();
+        PacketBuilder::tcp_response_to(&received, 64)
+            .unwrap()
+            .rst()
+            .write(&mut response, &[])
+            .unwrap();
+        let actual = SlicedPacket::from_ethernet(&response).unwrap();
+        match actual.transport {
+            Some(TransportSlice::Tcp(tcp)) => {
+                assert_eq!(tcp.source_port(), 80);
+                assert_eq!(tcp.destination_port(), 1234);
+                assert_eq!(tcp.sequence_number(), 2000);
+                assert_eq!(tcp.window_size(), 4000);
+                assert!(tcp.rst());
+                assert!(!tcp.ack());
+            }
+            _ => panic!("expected tcp"),
+        }
+
+        // udp packet
+        assert!(PacketBuilder::udp_response_to(&received, 64).is_none());
+    }
+
     proptest! {
         #[test]
         fn tcp_ipv4(ref input in tcp_any()) {
@@ -3259,6 +5619,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tcp")]
     #[test]
     fn tcp_options() {
         let mut serialized = Vec::new();
@@ -3285,6 +5646,374 @@ mod test {
         assert_eq!(&[Ok(MaximumSegmentSize(1234)), Ok(Noop)], &dec_options[..]);
     }
 
+    proptest! {
+        #[cfg(all(feature = "ipv6", feature = "tcp"))]
+        #[test]
+        fn tcp_segments(
+            mss in 0u16..100,
+            sequence_number in any::<u32>(),
+            identification in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..400),
+        ) {
+            for ipv6 in [false, true] {
+                let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]);
+                let builder = if ipv6 {
+                    builder.ipv6([1; 16], [2; 16], 20)
+                } else {
+                    let mut ipv4 = Ipv4Header::new(0, 20, IpNumber::TCP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+                    ipv4.identification = identification;
+                    builder.ip(IpHeaders::Ipv4(ipv4, Default::default()))
+                };
+                let segments = builder
+                    .tcp(1, 2, sequence_number, 4)
+                    .fin()
+                    .psh()
+                    .ack(5)
+                    .segments(mss, &payload);
+
+                let effective_mss = usize::from(mss.max(1));
+                let expected_count = 1.max(payload.len().div_ceil(effective_mss));
+                assert_eq!(expected_count, segments.len());
+
+                let segments: Vec<Vec<u8>> = segments.map(|v| v.unwrap()).collect();
+                assert_eq!(expected_count, segments.len());
+
+                let mut reassembled = Vec::with_capacity(payload.len());
+                for (index, segment) in segments.iter().enumerate() {
+                    let is_last = index + 1 == segments.len();
+                    let sliced = SlicedPacket::from_ethernet(segment).unwrap();
+
+                    // ip header
+                    match sliced.net.as_ref().unwrap() {
+                        NetSlice::Ipv4(ipv4) => {
+                            let header = ipv4.header().to_header();
+                            assert_eq!(
+                                identification.wrapping_add(index as u16),
+                                header.identification
+                            );
+                            assert_eq!(header.calc_header_checksum(), header.header_checksum);
+                        }
+                        NetSlice::Ipv6(_) => {}
+                    }
+
+                    // tcp header & payload
+                    let Some(TransportSlice::Tcp(tcp)) = sliced.transport else {
+                        panic!("expected tcp");
+                    };
+                    assert_eq!(
+                        sequence_number.wrapping_add(reassembled.len() as u32),
+                        tcp.sequence_number()
+                    );
+                    assert_eq!(is_last, tcp.fin());
+                    assert_eq!(is_last, tcp.psh());
+                    assert!(tcp.ack());
+                    assert_eq!(5, tcp.acknowledgment_number());
+                    assert!(tcp.payload().len() <= effective_mss);
+                    let expected_checksum = match sliced.net.as_ref().unwrap() {
+                        NetSlice::Ipv4(ipv4) => tcp
+                            .to_header()
+                            .calc_checksum_ipv4(&ipv4.header().to_header(), tcp.payload())
+                            .unwrap(),
+                        NetSlice::Ipv6(ipv6) => tcp
+                            .to_header()
+                            .calc_checksum_ipv6(&ipv6.header().to_header(), tcp.payload())
+                            .unwrap(),
+                    };
+                    assert_eq!(expected_checksum, tcp.checksum());
+                    reassembled.extend_from_slice(tcp.payload());
+                }
+                assert_eq!(reassembled, payload);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn udp_fragments(
+            mtu in 0u16..200,
+            identification in any::<u32>(),
+            auth in ip_auth_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..400),
+        ) {
+            for (ipv6, with_auth) in [(false, false), (false, true), (true, false), (true, true)] {
+                let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]);
+                let builder = if ipv6 {
+                    let header = Ipv6Header {
+                        source: [1; 16],
+                        destination: [2; 16],
+                        hop_limit: 20,
+                        ..Default::default()
+                    };
+                    let exts = Ipv6Extensions {
+                        auth: with_auth.then(|| auth.clone()),
+                        ..Default::default()
+                    };
+                    builder.ip(IpHeaders::Ipv6(header, exts))
+                } else {
+                    let header = Ipv4Header::new(0, 20, IpNumber::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+                    let exts = Ipv4Extensions {
+                        auth: with_auth.then(|| auth.clone()),
+                    };
+                    builder.ip(IpHeaders::Ipv4(header, exts))
+                };
+                let fragments = builder
+                    .udp(1, 2)
+                    .fragments(mtu, identification, &payload)
+                    .unwrap();
+
+                let fragments: Vec<Vec<u8>> = {
+                    let len = fragments.len();
+                    let result: Vec<Vec<u8>> = fragments.map(|v| v.unwrap()).collect();
+                    assert_eq!(len, result.len());
+                    result
+                };
+
+                // reassemble the fragments
+                let is_fragmented = fragments.len() > 1;
+                let expected_next = if with_auth { IpNumber::AUTHENTICATION_HEADER } else { IpNumber::UDP };
+                let mut reassembled = Vec::new();
+                let mut first_ip_header = None;
+                for (index, fragment) in fragments.iter().enumerate() {
+                    let is_last = index + 1 == fragments.len();
+                    let ip = &fragment[Ethernet2Header::LEN..];
+                    // every packet contains at least 8 bytes of payload
+                    let min_len = if ipv6 {
+                        Ipv6Header::LEN + Ipv6FragmentHeader::LEN + 8
+                    } else {
+                        Ipv4Header::MIN_LEN + 8
+                    };
+                    assert!(ip.len() <= usize::from(mtu).max(min_len));
+
+                    let (header, payload) = if ipv6 {
+                        let (ipv6, rest) = Ipv6Header::from_slice(ip).unwrap();
+                        let payload = if is_fragmented {
+                            assert_eq!(IpNumber::IPV6_FRAGMENTATION_HEADER, ipv6.next_header);
+                            let (fragment, payload) = Ipv6FragmentHeader::from_slice(rest).unwrap();
+                            assert_eq!(identification, fragment.identification);
+                            assert_eq!(false == is_last, fragment.more_fragments);
+                            assert_eq!(reassembled.len(), usize::from(fragment.fragment_offset.byte_offset()));
+                            assert_eq!(expected_next, fragment.next_header);
+                            payload
+                        } else {
+                            assert_eq!(expected_next, ipv6.next_header);
+                            rest
+                        };
+                        (IpHeaders::Ipv6(ipv6, Default::default()), payload)
+                    } else {
+                        let (ipv4, rest) = Ipv4Header::from_slice(ip).unwrap();
+                        assert_eq!(identification as u16, ipv4.identification);
+                        assert_eq!(ipv4.calc_header_checksum(), ipv4.header_checksum);
+                        assert_eq!(expected_next, ipv4.protocol);
+                        if is_fragmented {
+                            assert_eq!(false == is_last, ipv4.more_fragments);
+                            assert_eq!(reassembled.len(), usize::from(ipv4.fragment_offset.byte_offset()));
+                        }
+                        (IpHeaders::Ipv4(ipv4, Default::default()), rest)
+                    };
+                    if false == is_last {
+                        assert_eq!(0, payload.len() % 8);
+                    }
+                    if 0 == index {
+                        first_ip_header = Some(header);
+                    }
+                    reassembled.extend_from_slice(payload);
+                }
+
+                // check the reassembled datagram
+                let udp_start = if with_auth { auth.header_len() } else { 0 };
+                if with_auth {
+                    let (reassembled_auth, _) = IpAuthHeader::from_slice(&reassembled).unwrap();
+                    assert_eq!(IpNumber::UDP, reassembled_auth.next_header);
+                }
+                let (udp, udp_payload) = UdpHeader::from_slice(&reassembled[udp_start..]).unwrap();
+                assert_eq!(&payload[..], udp_payload);
+                assert_eq!(1, udp.source_port);
+                assert_eq!(2, udp.destination_port);
+                assert_eq!((UdpHeader::LEN + payload.len()) as u16, udp.length);
+                let expected_checksum = match first_ip_header.unwrap() {
+                    IpHeaders::Ipv4(ipv4, _) => udp.calc_checksum_ipv4(&ipv4, &payload).unwrap(),
+                    IpHeaders::Ipv6(ipv6, _) => udp.calc_checksum_ipv6(&ipv6, &payload).unwrap(),
+                };
+                assert_eq!(expected_checksum, udp.checksum);
+            }
+        }
+    }
+
+    #[test]
+    fn udp_fragments_ipv6_final_destination_options() {
+        let routing = Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[0; 6]).unwrap();
+        let dest = Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[1; 6]).unwrap();
+        let exts = Ipv6Extensions {
+            routing: Some(Ipv6RoutingExtensions {
+                routing: routing.clone(),
+                final_destination_options: Some(dest.clone()),
+            }),
+            ..Default::default()
+        };
+        let header = Ipv6Header {
+            source: [1; 16],
+            destination: [2; 16],
+            hop_limit: 20,
+            ..Default::default()
+        };
+        let payload = [3u8; 64];
+        let fragments: Vec<Vec<u8>> = PacketBuilder::ip(IpHeaders::Ipv6(header.clone(), exts))
+            .udp(1, 2)
+            .fragments(100, 5, &payload)
+            .unwrap()
+            .map(|v| v.unwrap())
+            .collect();
+        assert_eq!(2, fragments.len());
+
+        // routing header in every fragment, destination options only in the first
+        let mut reassembled = Vec::new();
+        for fragment in &fragments {
+            let (ipv6, rest) = Ipv6Header::from_slice(fragment).unwrap();
+            assert_eq!(IpNumber::IPV6_ROUTE_HEADER, ipv6.next_header);
+            let (actual_routing, rest) = Ipv6RawExtHeader::from_slice(rest).unwrap();
+            assert_eq!(
+                IpNumber::IPV6_FRAGMENTATION_HEADER,
+                actual_routing.next_header
+            );
+            assert_eq!(routing.payload(), actual_routing.payload());
+            let (fragment_header, rest) = Ipv6FragmentHeader::from_slice(rest).unwrap();
+            assert_eq!(
+                IpNumber::IPV6_DESTINATION_OPTIONS,
+                fragment_header.next_header
+            );
+            assert_eq!(5, fragment_header.identification);
+            reassembled.extend_from_slice(rest);
+        }
+        let (actual_dest, rest) = Ipv6RawExtHeader::from_slice(&reassembled).unwrap();
+        assert_eq!(dest, actual_dest);
+        let (udp, udp_payload) = UdpHeader::from_slice(rest).unwrap();
+        assert_eq!(&payload[..], udp_payload);
+        assert_eq!(
+            udp.calc_checksum_ipv6(&header, &payload).unwrap(),
+            udp.checksum
+        );
+    }
+
+    #[test]
+    fn udp_fragments_errors() {
+        // payload too big for the udp length field
+        let payload = vec![0u8; usize::from(u16::MAX)];
+        assert!(matches!(
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1, 2)
+                .fragments(1500, 0, &payload),
+            Err(BuildWriteError::PayloadLen(_))
+        ));
+        assert!(matches!(
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .udp(1, 2)
+                .fragments(1500, 0, &payload),
+            Err(BuildWriteError::PayloadLen(_))
+        ));
+    }
+
+    #[test]
+    fn tcp_segments_write_error() {
+        // payload too big for the ipv4 total length field
+        let payload = vec![0u8; usize::from(u16::MAX)];
+        let mut segments = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .tcp(1, 2, 3, 4)
+            .segments(u16::MAX, &payload);
+        assert!(matches!(
+            segments.next(),
+            Some(Err(BuildWriteError::PayloadLen(_)))
+        ));
+        assert!(segments.next().is_none());
+    }
+
+    proptest! {
+        #[cfg(feature = "macsec")]
+        #[test]
+        fn macsec(
+            macsec in macsec_any(),
+            icv in proptest::collection::vec(any::<u8>(), 0..20),
+            vlan_id in 0..=VlanId::MAX_U16,
+            payload in proptest::collection::vec(any::<u8>(), 0..80)
+        ) {
+            for (with_vlan, with_fcs) in [(false, false), (true, false), (false, true), (true, true)] {
+                let builder = {
+                    let eth = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]);
+                    let eth = if with_fcs { eth.with_fcs() } else { eth };
+                    let macsec = eth.macsec(macsec.clone(), &icv);
+                    if with_vlan {
+                        macsec
+                            .single_vlan(VlanId::try_new(vlan_id).unwrap())
+                            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                            .udp(22, 23)
+                    } else {
+                        macsec
+                            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                            .udp(22, 23)
+                    }
+                };
+                let expected_size = builder.size(payload.len());
+                let mut serialized = Vec::new();
+                builder.write(&mut serialized, &payload).unwrap();
+                assert_eq!(expected_size, serialized.len());
+
+                // header lengths
+                {
+                    let stack = HeaderStackLen::new().ethernet2().macsec();
+                    let stack = if with_vlan { stack.single_vlan() } else { stack }.ipv4().udp();
+                    let headers_len = expected_size
+                        - payload.len()
+                        - icv.len()
+                        - if with_fcs { 4 } else { 0 };
+                    assert!(stack.min_len() <= headers_len);
+                    assert!(headers_len <= stack.max_len());
+                    assert!(headers_len <= PacketBuilder::MAX_HEADER_LEN);
+                }
+
+                // ethernet ii
+                let eth = if with_fcs {
+                    Ethernet2Slice::from_slice_with_crc32_fcs(&serialized).unwrap()
+                } else {
+                    Ethernet2Slice::from_slice_without_fcs(&serialized).unwrap()
+                };
+                assert_eq!(EtherType::MACSEC, eth.ether_type());
+
+                // macsec (icv is at the end of the ethernet payload)
+                let eth_payload = eth.payload().payload;
+                let (header, rest) = MacsecHeader::from_slice(eth_payload).unwrap();
+                let secure_data = &rest[..rest.len() - icv.len()];
+                assert_eq!(&rest[secure_data.len()..], &icv[..]);
+                let mut expected_header = macsec.clone();
+                expected_header.set_secure_data_len(secure_data.len());
+                assert_eq!(expected_header, header);
+                assert_eq!(
+                    secure_data.len(),
+                    2 + if with_vlan { SingleVlanHeader::LEN } else { 0 }
+                        + Ipv4Header::MIN_LEN + UdpHeader::LEN + payload.len()
+                );
+
+                // secure data
+                let next_ether_type = EtherType(u16::from_be_bytes([secure_data[0], secure_data[1]]));
+                assert_eq!(
+                    next_ether_type,
+                    if with_vlan { EtherType::VLAN_TAGGED_FRAME } else { EtherType::IPV4 }
+                );
+                let inner = SlicedPacket::from_ether_type(next_ether_type, &secure_data[2..]).unwrap();
+                if with_vlan {
+                    match inner.vlan.unwrap() {
+                        VlanSlice::SingleVlan(s) => assert_eq!(vlan_id, s.vlan_identifier().value()),
+                        _ => panic!("expected single vlan"),
+                    }
+                } else {
+                    assert!(inner.vlan.is_none());
+                }
+                match inner.transport.unwrap() {
+                    TransportSlice::Udp(udp) => assert_eq!(&payload[..], udp.payload()),
+                    _ => panic!("expected udp"),
+                }
+            }
+        }
+    }
+
     #[test]
     fn size() {
         //ipv4 no vlan ethernet
@@ -3381,24 +6110,29 @@ mod test {
     }
 
     proptest! {
+        #[cfg(feature = "icmp")]
         #[test]
         fn ipv4_icmpv4(
             ipv4_source in any::<[u8;4]>(),
             ipv4_dest in any::<[u8;4]>(),
             ipv4_time_to_live in any::<u8>(),
-            icmpv4_type_u8 in 15u8..u8::MAX,
+            icmpv4_type_u8 in 19u8..u8::MAX,
             icmpv4_code_u8 in any::<u8>(),
             icmpv4_bytes5to8 in any::<[u8;4]>(),
             icmpv4 in icmpv4_type_any(),
             echo_id in any::<u16>(),
             echo_seq in any::<u16>(),
+            timestamp in any::<u32>(),
+            address_mask in any::<[u8;4]>(),
             payload in proptest::collection::vec(any::<u8>(), 0..64),
         ) {
             let test_builder = |builder: PacketBuilderStep<Icmpv4Header>, icmpv4_type: Icmpv4Type| {
                 use crate::Icmpv4Type::*;
                 let adapted_payload = match &icmpv4_type {
                     TimestampRequest(_) |
-                    TimestampReply(_) => &[],
+                    TimestampReply(_) |
+                    AddressMaskRequest(_) |
+                    AddressMaskReply(_) => &[],
                     _ => &payload[..],
                 };
                 let icmp_expected = Icmpv4Header::with_checksum(icmpv4_type, &adapted_payload);
@@ -3507,6 +6241,74 @@ mod test {
                     })
                 );
             }
+
+            // icmpv4_timestamp_request
+            {
+                let builder = PacketBuilder::ethernet2([1,2,3,4,5,6],[7,8,9,10,11,12])
+                    .ipv4(ipv4_source, ipv4_dest, ipv4_time_to_live)
+                    .icmpv4_timestamp_request(echo_id, echo_seq, timestamp);
+
+                test_builder(
+                    builder,
+                    Icmpv4Type::TimestampRequest(icmpv4::TimestampMessage{
+                        id: echo_id,
+                        seq: echo_seq,
+                        originate_timestamp: timestamp,
+                        receive_timestamp: 0,
+                        transmit_timestamp: 0,
+                    })
+                );
+            }
+
+            // icmpv4_timestamp_reply
+            {
+                let builder = PacketBuilder::ethernet2([1,2,3,4,5,6],[7,8,9,10,11,12])
+                    .ipv4(ipv4_source, ipv4_dest, ipv4_time_to_live)
+                    .icmpv4_timestamp_reply(echo_id, echo_seq, timestamp, timestamp ^ 1, timestamp ^ 2);
+
+                test_builder(
+                    builder,
+                    Icmpv4Type::TimestampReply(icmpv4::TimestampMessage{
+                        id: echo_id,
+                        seq: echo_seq,
+                        originate_timestamp: timestamp,
+                        receive_timestamp: timestamp ^ 1,
+                        transmit_timestamp: timestamp ^ 2,
+                    })
+                );
+            }
+
+            // icmpv4_address_mask_request
+            {
+                let builder = PacketBuilder::ethernet2([1,2,3,4,5,6],[7,8,9,10,11,12])
+                    .ipv4(ipv4_source, ipv4_dest, ipv4_time_to_live)
+                    .icmpv4_address_mask_request(echo_id, echo_seq);
+
+                test_builder(
+                    builder,
+                    Icmpv4Type::AddressMaskRequest(icmpv4::AddressMaskMessage{
+                        id: echo_id,
+                        seq: echo_seq,
+                        address_mask: [0;4],
+                    })
+                );
+            }
+
+            // icmpv4_address_mask_reply
+            {
+                let builder = PacketBuilder::ethernet2([1,2,3,4,5,6],[7,8,9,10,11,12])
+                    .ipv4(ipv4_source, ipv4_dest, ipv4_time_to_live)
+                    .icmpv4_address_mask_reply(echo_id, echo_seq, address_mask);
+
+                test_builder(
+                    builder,
+                    Icmpv4Type::AddressMaskReply(icmpv4::AddressMaskMessage{
+                        id: echo_id,
+                        seq: echo_seq,
+                        address_mask,
+                    })
+                );
+            }
         }
     }
 
@@ -3601,12 +6403,13 @@ mod test {
     }
 
     proptest! {
+        #[cfg(all(feature = "icmp", feature = "ipv6"))]
         #[test]
         fn ipv6_icmpv4(
             ipv6_source in any::<[u8;16]>(),
             ipv6_dest in any::<[u8;16]>(),
             ipv6_hop_limit in any::<u8>(),
-            icmpv4_type_u8 in 15u8..u8::MAX,
+            icmpv4_type_u8 in 19u8..u8::MAX,
             icmpv4_code_u8 in any::<u8>(),
             icmpv4_bytes5to8 in any::<[u8;4]>(),
             icmpv4 in icmpv4_type_any(),
@@ -3618,7 +6421,7 @@ mod test {
 
                 use Icmpv4Type::*;
                 let adapted_payload = match icmpv4_type {
-                    TimestampRequest(_) | TimestampReply(_) => &[],
+                    TimestampRequest(_) | TimestampReply(_) | AddressMaskRequest(_) | AddressMaskReply(_) => &[],
                     _ => &payload[..],
                 };
 
@@ -3730,6 +6533,7 @@ mod test {
     }
 
     proptest! {
+        #[cfg(all(feature = "icmp", feature = "ipv6"))]
         #[test]
         fn ipv6_icmpv6(
             ipv6_source in any::<[u8;16]>(),
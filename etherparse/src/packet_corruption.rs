@@ -0,0 +1,42 @@
+/// Deliberate corruption of a header field that is applied after the
+/// [`crate::PacketBuilder`] calculated all lengths & checksums (see
+/// [`crate::PacketBuilderStep::corrupt`]).
+///
+/// Allows the generation of invalid packets (e.g. for negative testing
+/// of network stacks). Each variant overwrites the field in the serialized
+/// packet with the given value. Corruptions that target a header that is
+/// not present in the packet (e.g. [`PacketCorruption::UdpLength`] in a TCP
+/// packet) are ignored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PacketCorruption {
+    /// Overwrites the "header checksum" of the IPv4 header.
+    Ipv4HeaderChecksum(u16),
+    /// Overwrites the "total length" field of the IPv4 header.
+    Ipv4TotalLen(u16),
+    /// Overwrites the "payload length" field of the IPv6 header.
+    Ipv6PayloadLength(u16),
+    /// Overwrites the "length" field of the UDP header.
+    UdpLength(u16),
+    /// Overwrites the "checksum" field of the UDP header.
+    UdpChecksum(u16),
+    /// Overwrites the 4 bit "data offset" field of the TCP header (only
+    /// the lower 4 bits of the value are used).
+    TcpDataOffset(u8),
+    /// Overwrites the "checksum" field of the TCP header.
+    TcpChecksum(u16),
+    /// Overwrites the "checksum" field of the ICMPv4 or ICMPv6 header.
+    IcmpChecksum(u16),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = PacketCorruption::UdpLength(1234);
+        assert_eq!(value, value.clone());
+        assert_eq!("UdpLength(1234)", format!("{:?}", value));
+    }
+}
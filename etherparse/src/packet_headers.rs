@@ -7,6 +7,7 @@ use super::*;
 /// You can use
 ///
 /// * [`PacketHeaders::from_ethernet_slice`]
+/// * [`PacketHeaders::from_linux_sll_slice`]
 /// * [`PacketHeaders::from_ether_type`]
 /// * [`PacketHeaders::from_ip_slice`]
 ///
@@ -86,6 +87,85 @@ impl<'a> PacketHeaders<'a> {
         result
     }
 
+    /// Decodes a network packet into different headers from a slice that starts
+    /// with a Linux Cooked Capture v1 (SLL) header.
+    ///
+    /// The result is returned as a [`PacketHeaders`] struct. If the protocol
+    /// type of the SLL header is not an ether type, the payload after the SLL
+    /// header is returned as [`PayloadSlice::LinuxSll`].
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    ///```
+    /// # use etherparse::{PacketBuilder, LinuxSllPacketType};
+    /// # let builder = PacketBuilder::
+    /// #    linux_sll(LinuxSllPacketType::OTHERHOST, //packet type
+    /// #              6, //sender address valid length
+    /// #              [1,2,3,4,5,6,0,0]) //sender address with padding
+    /// #    .ipv4([192,168,1,1], //source ip
+    /// #          [192,168,1,2], //destination ip
+    /// #          20)            //time to life
+    /// #    .udp(21,    //source port
+    /// #         1234); // destination port
+    /// # // payload of the udp packet
+    /// # let payload = [1,2,3,4,5,6,7,8];
+    /// # // get some memory to store the serialized data
+    /// # let mut packet = Vec::<u8>::with_capacity(
+    /// #     builder.size(payload.len())
+    /// # );
+    /// # builder.write(&mut packet, &payload).unwrap();
+    /// #
+    /// use etherparse::PacketHeaders;
+    ///
+    /// match PacketHeaders::from_linux_sll_slice(&packet) {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("vlan: {:?}", value.vlan);
+    ///         println!("net: {:?}", value.net);
+    ///         println!("transport: {:?}", value.transport);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_linux_sll_slice(
+        slice: &'a [u8],
+    ) -> Result<PacketHeaders<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::{Len, LinuxSll};
+
+        let (linux_sll, rest) = LinuxSllHeader::from_slice(slice).map_err(|err| match err {
+            err::linux_sll::HeaderSliceError::Len(err) => Len(err),
+            err::linux_sll::HeaderSliceError::Content(err) => LinuxSll(err),
+        })?;
+
+        let ether_type = match linux_sll.protocol_type {
+            LinuxSllProtocolType::EtherType(ether_type) => ether_type,
+            protocol_type => {
+                return Ok(PacketHeaders {
+                    link: Some(LinkHeader::LinuxSll(linux_sll)),
+                    vlan: None,
+                    net: None,
+                    transport: None,
+                    payload: PayloadSlice::LinuxSll(LinuxSllPayloadSlice {
+                        protocol_type,
+                        payload: rest,
+                    }),
+                })
+            }
+        };
+
+        let mut result = Self::from_ether_type(ether_type, rest);
+        match &mut result {
+            // inject sll header into the result
+            Ok(result) => result.link = Some(LinkHeader::LinuxSll(linux_sll)),
+            // add the sll header to the overall offset in case there is a length error
+            Err(Len(err)) => err.layer_start_offset += LinuxSllHeader::LEN,
+            _ => {}
+        }
+        result
+    }
+
     /// Tries to decode a network packet into different headers using the
     /// given `ether_type` number to identify the first header.
     ///
@@ -148,9 +228,9 @@ impl<'a> PacketHeaders<'a> {
 
         // helper function to add the current offset to length errors
         let add_offset = |mut len_error: LenError, rest: &[u8]| -> LenError {
-            len_error.layer_start_offset += unsafe {
+            len_error.layer_start_offset += unchecked! {
                 // SAFETY: Safe as rest is a subslice of slice.
-                rest.as_ptr().offset_from(slice.as_ptr()) as usize
+                get_unchecked_offset(slice, rest)
             };
             len_error
         };
@@ -170,7 +250,7 @@ impl<'a> PacketHeaders<'a> {
         use ether_type::*;
 
         result.vlan = match ether_type {
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+            _ if ether_type.is_vlan_tag() => {
                 use crate::VlanHeader::*;
                 let (outer, outer_rest) = SingleVlanHeader::from_slice(rest).map_err(Len)?;
 
@@ -185,7 +265,7 @@ impl<'a> PacketHeaders<'a> {
                 //parse second vlan header if present
                 match ether_type {
                     //second vlan tagging header
-                    VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+                    _ if ether_type.is_vlan_tag() => {
                         let (inner, inner_rest) = SingleVlanHeader::from_slice(rest)
                             .map_err(|err| Len(err.add_offset(SingleVlanHeader::LEN)))?;
 
@@ -237,6 +317,7 @@ impl<'a> PacketHeaders<'a> {
                 result.transport = transport;
                 result.payload = payload;
             }
+            #[cfg(feature = "ipv6")]
             IPV6 => {
                 // read ipv6 header & extensions and payload slice
                 let (ip, ip_payload) = IpHeaders::from_ipv6_slice(rest).map_err(|err| {
@@ -342,9 +423,9 @@ impl<'a> PacketHeaders<'a> {
             use err::tcp::HeaderSliceError as I;
             match err {
                 I::Len(mut err) => {
-                    err.layer_start_offset += unsafe {
+                    err.layer_start_offset += unchecked! {
                         // SAFETY: Safe as rest is a subslice of slice.
-                        rest.as_ptr().offset_from(slice.as_ptr()) as usize
+                        get_unchecked_offset(slice, rest)
                     };
                     Len(err)
                 }
@@ -358,9 +439,336 @@ impl<'a> PacketHeaders<'a> {
 
         Ok(result)
     }
+
+    /// Decodes the transport header of a slice containing an IP payload
+    /// based on an externally known ip number (e.g. known from a decrypted
+    /// ESP payload).
+    ///
+    /// The slice is treated as a complete & unfragmented IP payload. The
+    /// `link`, `vlan` & `net` fields of the result are always empty.
+    pub fn from_ip_number(
+        ip_number: IpNumber,
+        slice: &[u8],
+    ) -> Result<PacketHeaders<'_>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        let (transport, payload) = read_transport(IpPayloadSlice {
+            ip_number,
+            fragmented: false,
+            len_source: LenSource::Slice,
+            payload: slice,
+        })
+        .map_err(|err| {
+            use err::tcp::HeaderSliceError as I;
+            match err {
+                I::Len(err) => Len(err),
+                I::Content(err) => Tcp(err),
+            }
+        })?;
+
+        Ok(PacketHeaders {
+            link: None,
+            vlan: None,
+            net: None,
+            transport,
+            payload,
+        })
+    }
+
+    /// Reads a network packet starting with an Ethernet II header from an
+    /// async reader into `buffer` and decodes it (requires crate feature
+    /// `tokio`).
+    ///
+    /// As Ethernet II frames contain no length field, the amount of data
+    /// read is determined by the headers: First the Ethernet II header &
+    /// VLAN headers are read. If they are followed by an IPv4 or IPv6
+    /// header, the rest of the IP packet is read based on the IPv4 total
+    /// length or the IPv6 payload length. For all other ether types no
+    /// payload is read (the returned payload is empty). The read data is
+    /// then decoded via [`PacketHeaders::from_ethernet_slice`].
+    ///
+    /// IPv6 jumbograms (payload length set to zero) are read based on the
+    /// jumbo payload option in the hop by hop header. Like the slice
+    /// decoders the data following the IPv6 header is then treated as its
+    /// payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "tokio")]
+    /// # {
+    /// # use etherparse::PacketBuilder;
+    /// # let builder = PacketBuilder::
+    /// #    ethernet2([1,2,3,4,5,6],     //source mac
+    /// #               [7,8,9,10,11,12]) //destination mac
+    /// #    .ipv4([192,168,1,1], //source ip
+    /// #          [192,168,1,2], //destination ip
+    /// #          20)            //time to life
+    /// #    .udp(21,    //source port
+    /// #         1234); // destination port
+    /// # let mut stream = Vec::<u8>::with_capacity(builder.size(4));
+    /// # builder.write(&mut stream, &[1,2,3,4]).unwrap();
+    /// # let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// # rt.block_on(async {
+    /// use etherparse::PacketHeaders;
+    ///
+    /// // e.g. a tokio::net::TcpStream delivering ethernet frames
+    /// let mut reader = &stream[..];
+    /// let mut buffer = Vec::new();
+    /// match PacketHeaders::async_read(&mut reader, &mut buffer).await {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("vlan: {:?}", value.vlan);
+    ///         println!("net: {:?}", value.net);
+    ///         println!("transport: {:?}", value.transport);
+    ///         assert_eq!(&[1, 2, 3, 4], value.payload.slice());
+    ///     }
+    /// }
+    /// # });
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+        buffer: &'a mut std::vec::Vec<u8>,
+    ) -> Result<PacketHeaders<'a>, err::ReadError> {
+        use ether_type::*;
+
+        // appends the given number of bytes from the reader to the buffer
+        async fn read_more<T: tokio::io::AsyncRead + Unpin>(
+            reader: &mut T,
+            buffer: &mut std::vec::Vec<u8>,
+            len: usize,
+        ) -> Result<(), std::io::Error> {
+            let start = buffer.len();
+            buffer.resize(start + len, 0);
+            async_read_exact(reader, &mut buffer[start..]).await?;
+            Ok(())
+        }
+
+        // read link & vlan headers
+        buffer.clear();
+        let ethernet = Ethernet2Header::async_read(reader).await?;
+        buffer.extend_from_slice(&ethernet.to_bytes());
+
+        let mut ether_type = ethernet.ether_type;
+        for _ in 0..2 {
+            if false == ether_type.is_vlan_tag() {
+                break;
+            }
+            let vlan = SingleVlanHeader::async_read(reader).await?;
+            buffer.extend_from_slice(&vlan.to_bytes());
+            ether_type = vlan.ether_type;
+        }
+
+        // read the fixed part of the ip header & then the rest of the ip
+        // packet based on its length field (in case the version does not
+        // match the ether type the decoding below reports the error)
+        let ip_start = buffer.len();
+        match ether_type {
+            IPV4 => {
+                read_more(reader, buffer, Ipv4Header::MIN_LEN).await?;
+                if 4 == buffer[ip_start] >> 4 {
+                    let total_len =
+                        u16::from_be_bytes([buffer[ip_start + 2], buffer[ip_start + 3]]);
+                    read_more(
+                        reader,
+                        buffer,
+                        usize::from(total_len).saturating_sub(Ipv4Header::MIN_LEN),
+                    )
+                    .await?;
+                }
+            }
+            IPV6 => {
+                read_more(reader, buffer, Ipv6Header::LEN).await?;
+                if 6 == buffer[ip_start] >> 4 {
+                    let payload_len =
+                        u16::from_be_bytes([buffer[ip_start + 4], buffer[ip_start + 5]]);
+                    if 0 == payload_len && ip_number::IPV6_HOP_BY_HOP.0 == buffer[ip_start + 6] {
+                        // jumbogram (RFC 2675), the payload length is stored in
+                        // the jumbo payload option of the hop by hop header
+                        let ext_start = buffer.len();
+                        read_more(reader, buffer, 8).await?;
+                        let ext_len = (usize::from(buffer[ext_start + 1]) + 1) * 8;
+                        read_more(reader, buffer, ext_len - 8).await?;
+                        if let Some(jumbo_len) = ipv6_jumbo_payload_len(&buffer[ext_start..]) {
+                            read_more(reader, buffer, (jumbo_len as usize).saturating_sub(ext_len))
+                                .await?;
+                        }
+                    } else {
+                        read_more(reader, buffer, usize::from(payload_len)).await?;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let buffer: &'a std::vec::Vec<u8> = buffer;
+        Ok(PacketHeaders::from_ethernet_slice(buffer)?)
+    }
+
+    /// Returns the (pcp, dei, vid) values of the VLAN tags in outer to inner
+    /// order (empty if no VLAN header is present).
+    pub fn vlan_tags(&self) -> VlanTagIter {
+        self.vlan.as_ref().map(|v| v.tags()).unwrap_or_default()
+    }
+
+    /// Returns the source & destination port of the transport layer
+    /// (`None` if no transport layer with ports was decoded).
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, PacketHeaders};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(5353, 53)
+    ///     .write(&mut packet, &[])
+    ///     .unwrap();
+    ///
+    /// let headers = PacketHeaders::from_ip_slice(&packet).unwrap();
+    /// assert_eq!(Some((5353, 53)), headers.ports());
+    /// ```
+    pub fn ports(&self) -> Option<(u16, u16)> {
+        self.transport.as_ref()?.ports()
+    }
+
+    /// Returns the IP number identifying the payload after the IP header
+    /// & IP extension headers (`None` if no IP header is present).
+    ///
+    /// The IP number is also returned if the transport layer was decoded
+    /// (e.g. [`IpNumber::UDP`] for an UDP packet).
+    pub fn next_header_ip_number(&self) -> Option<IpNumber> {
+        if let PayloadSlice::Ip(ip_payload) = &self.payload {
+            return Some(ip_payload.ip_number);
+        }
+        match self.net.as_ref()? {
+            NetHeaders::Ipv4(header, exts) => exts.next_header(header.protocol).ok(),
+            NetHeaders::Ipv6(header, exts) => exts.next_header(header.next_header).ok(),
+        }
+    }
+
+    /// Returns the protocol identifier of the payload if the payload was not
+    /// decoded as a transport layer (e.g. an unknown ether type or the IP
+    /// number of a fragmented or unsupported IP payload).
+    ///
+    /// `None` is returned if a transport layer was decoded (the payload
+    /// then belongs to the transport layer) or if the payload is not
+    /// identified by an ether type or IP number.
+    ///
+    /// ```
+    /// use etherparse::{EtherType, PacketHeaders, PayloadProtocol};
+    ///
+    /// let headers = PacketHeaders::from_ether_type(EtherType::ARP, &[]).unwrap();
+    /// assert_eq!(Some(PayloadProtocol::Ether(EtherType::ARP)), headers.payload_protocol());
+    /// ```
+    pub fn payload_protocol(&self) -> Option<PayloadProtocol> {
+        match &self.payload {
+            PayloadSlice::Ether(e) => Some(PayloadProtocol::Ether(e.ether_type)),
+            PayloadSlice::Ip(p) => Some(PayloadProtocol::Ip(p.ip_number)),
+            _ => None,
+        }
+    }
+
+    /// Returns a stable 64 bit digest of the parts of the packet that are
+    /// not modified while the packet is forwarded (e.g. to detect
+    /// duplicates of a packet captured at multiple points of a network).
+    ///
+    /// See [`SlicedPacket::dedup_digest`] for the hashed & the skipped
+    /// fields. Both return the same digest for the same packet.
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    /// # {
+    /// use etherparse::{PacketBuilder, PacketHeaders, SlicedPacket};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv6([1; 16], [2; 16], 64)
+    ///     .tcp(1234, 443, 1, 1024)
+    ///     .write(&mut packet, &[1, 2, 3, 4])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     SlicedPacket::from_ip(&packet).unwrap().dedup_digest(),
+    ///     PacketHeaders::from_ip_slice(&packet).unwrap().dedup_digest()
+    /// );
+    /// # }
+    /// ```
+    pub fn dedup_digest(&self) -> u64 {
+        let mut hash = hash::Fnv1a64::new();
+        match &self.net {
+            Some(NetHeaders::Ipv4(header, _)) => {
+                hash = hash
+                    .add_slice(&[4])
+                    .add_slice(&header.source)
+                    .add_slice(&header.destination)
+                    .add_slice(&header.identification.to_be_bytes());
+            }
+            Some(NetHeaders::Ipv6(header, _)) => {
+                hash = hash
+                    .add_slice(&[6])
+                    .add_slice(&header.source)
+                    .add_slice(&header.destination);
+            }
+            None => {
+                hash = hash.add_slice(&[0]);
+                let ether_type = match &self.payload {
+                    PayloadSlice::Ether(e) => Some(e.ether_type),
+                    PayloadSlice::LinuxSll(e) => match e.protocol_type {
+                        LinuxSllProtocolType::EtherType(EtherType(v))
+                        | LinuxSllProtocolType::LinuxNonstandardEtherType(
+                            LinuxNonstandardEtherType(v),
+                        ) => Some(EtherType(v)),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                if let Some(ether_type) = ether_type {
+                    hash = hash.add_slice(&ether_type.0.to_be_bytes());
+                }
+            }
+        }
+        if self.net.is_some() {
+            if let Some(ip_number) = self.next_header_ip_number() {
+                hash = hash.add_slice(&[ip_number.0]);
+            }
+        }
+        match &self.transport {
+            Some(TransportHeader::Udp(udp)) => {
+                hash = hash
+                    .add_slice(&udp.source_port.to_be_bytes())
+                    .add_slice(&udp.destination_port.to_be_bytes());
+            }
+            Some(TransportHeader::Tcp(tcp)) => {
+                hash = hash
+                    .add_slice(&tcp.source_port.to_be_bytes())
+                    .add_slice(&tcp.destination_port.to_be_bytes())
+                    .add_slice(&tcp.sequence_number.to_be_bytes())
+                    .add_slice(&tcp.acknowledgment_number.to_be_bytes());
+            }
+            Some(TransportHeader::Icmpv4(icmp)) => {
+                let mut header = icmp.to_bytes();
+                header[2] = 0;
+                header[3] = 0;
+                hash = hash.add_slice(&header);
+            }
+            Some(TransportHeader::Icmpv6(icmp)) => {
+                let mut header = icmp.to_bytes();
+                header[2] = 0;
+                header[3] = 0;
+                hash = hash.add_slice(&header);
+            }
+            None => {}
+        }
+        hash.add_slice(self.payload.slice()).value()
+    }
 }
 
 /// helper function to process transport headers
+///
+/// Transport protocols that are disabled via their feature are returned as
+/// an IP payload.
 fn read_transport(
     ip_payload: IpPayloadSlice,
 ) -> Result<(Option<TransportHeader>, PayloadSlice), err::tcp::HeaderSliceError> {
@@ -378,6 +786,7 @@ fn read_transport(
         use crate::ip_number::*;
         use err::tcp::HeaderSliceError::*;
         match ip_payload.ip_number {
+            #[cfg(feature = "icmp")]
             ICMP => Icmpv4Slice::from_slice(ip_payload.payload)
                 .map_err(add_len_source)
                 .map(|value| {
@@ -386,6 +795,7 @@ fn read_transport(
                         PayloadSlice::Icmpv4(value.payload()),
                     )
                 }),
+            #[cfg(all(feature = "icmp", feature = "ipv6"))]
             IPV6_ICMP => Icmpv6Slice::from_slice(ip_payload.payload)
                 .map_err(add_len_source)
                 .map(|value| {
@@ -402,6 +812,7 @@ fn read_transport(
                         PayloadSlice::Udp(value.1),
                     )
                 }),
+            #[cfg(feature = "tcp")]
             TCP => TcpHeader::from_slice(ip_payload.payload)
                 .map_err(|err| match err {
                     Len(err) => add_len_source(err),
@@ -418,7 +829,38 @@ fn read_transport(
     }
 }
 
+/// helper function to read the payload length from the jumbo payload
+/// option (RFC 2675) of an IPv6 hop by hop header
+///
+/// Returns `None` if the header contains no jumbo payload option.
+#[cfg(feature = "tokio")]
+fn ipv6_jumbo_payload_len(hop_by_hop: &[u8]) -> Option<u32> {
+    // type of the jumbo payload option
+    const JUMBO_PAYLOAD: u8 = 0xc2;
+    // type of the single byte padding option
+    const PAD1: u8 = 0;
+
+    let mut rest = hop_by_hop.get(2..)?;
+    while let Some(&option_type) = rest.first() {
+        if PAD1 == option_type {
+            rest = &rest[1..];
+            continue;
+        }
+        let data_len = usize::from(*rest.get(1)?);
+        let data = rest.get(2..2 + data_len)?;
+        if JUMBO_PAYLOAD == option_type && 4 == data_len {
+            return Some(u32::from_be_bytes([data[0], data[1], data[2], data[3]]));
+        }
+        rest = &rest[2 + data_len..];
+    }
+    None
+}
+
 #[cfg(test)]
+#[cfg_attr(
+    not(all(feature = "icmp", feature = "ipv6", feature = "tcp")),
+    allow(dead_code)
+)]
 mod test {
     use super::*;
     use crate::err::packet::SliceError;
@@ -471,6 +913,211 @@ mod test {
         assert_eq!(header.clone(), header);
     }
 
+    #[test]
+    fn from_linux_sll_slice() {
+        let sll = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::OUTGOING,
+            arp_hrd_type: ArpHardwareId::ETHER,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+            protocol_type: LinuxSllProtocolType::EtherType(EtherType::IPV4),
+        };
+
+        // ip & udp after the sll header
+        {
+            let mut data = alloc::vec::Vec::new();
+            PacketBuilder::linux_sll_header(sll.clone())
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(21, 22)
+                .write(&mut data, &[1, 2, 3, 4])
+                .unwrap();
+
+            let actual = PacketHeaders::from_linux_sll_slice(&data).unwrap();
+            assert_eq!(Some(LinkHeader::LinuxSll(sll.clone())), actual.link);
+            assert_eq!(None, actual.vlan);
+            assert!(actual.net.is_some());
+            assert_eq!(
+                Some(TransportHeader::Udp(
+                    UdpHeader::from_slice(&data[LinuxSllHeader::LEN + Ipv4Header::MIN_LEN..])
+                        .unwrap()
+                        .0
+                )),
+                actual.transport
+            );
+            assert_eq!(PayloadSlice::Udp(&[1, 2, 3, 4]), actual.payload);
+
+            // length error in the ip header (offset has to include the sll header)
+            let err =
+                PacketHeaders::from_linux_sll_slice(&data[..LinuxSllHeader::LEN + 1]).unwrap_err();
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN,
+                    len: 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv4Header,
+                    layer_start_offset: LinuxSllHeader::LEN,
+                }),
+                err
+            );
+        }
+
+        // non ether type protocol
+        {
+            let netlink = LinuxSllHeader {
+                arp_hrd_type: ArpHardwareId::NETLINK,
+                protocol_type: LinuxSllProtocolType::NetlinkProtocolType(1),
+                ..sll.clone()
+            };
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(&netlink.to_bytes());
+            data.extend_from_slice(&[1, 2, 3, 4]);
+            let actual = PacketHeaders::from_linux_sll_slice(&data).unwrap();
+            assert_eq!(Some(LinkHeader::LinuxSll(netlink)), actual.link);
+            assert_eq!(None, actual.net);
+            assert_eq!(
+                PayloadSlice::LinuxSll(LinuxSllPayloadSlice {
+                    protocol_type: LinuxSllProtocolType::NetlinkProtocolType(1),
+                    payload: &[1, 2, 3, 4],
+                }),
+                actual.payload
+            );
+        }
+
+        // sll errors
+        {
+            let data = sll.to_bytes();
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: LinuxSllHeader::LEN,
+                    len: 3,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::LinuxSllHeader,
+                    layer_start_offset: 0,
+                }),
+                PacketHeaders::from_linux_sll_slice(&data[..3]).unwrap_err()
+            );
+
+            let mut data = data;
+            data[1] = 8;
+            assert_eq!(
+                SliceError::LinuxSll(err::linux_sll::HeaderError::UnsupportedPacketTypeField {
+                    packet_type: 8
+                }),
+                PacketHeaders::from_linux_sll_slice(&data).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_ip_number() {
+        let udp = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: (UdpHeader::LEN + 4) as u16,
+            checksum: 0,
+        };
+        let mut data = [0u8; UdpHeader::LEN + 4];
+        data[..UdpHeader::LEN].copy_from_slice(&udp.to_bytes());
+        data[UdpHeader::LEN..].copy_from_slice(&[1, 2, 3, 4]);
+
+        // ok
+        {
+            let actual = PacketHeaders::from_ip_number(ip_number::UDP, &data).unwrap();
+            assert_eq!(None, actual.link);
+            assert_eq!(None, actual.vlan);
+            assert_eq!(None, actual.net);
+            assert_eq!(Some(TransportHeader::Udp(udp.clone())), actual.transport);
+            assert_eq!(PayloadSlice::Udp(&[1, 2, 3, 4]), actual.payload);
+        }
+
+        // unknown ip number
+        {
+            let actual = PacketHeaders::from_ip_number(ip_number::GGP, &data).unwrap();
+            assert_eq!(None, actual.transport);
+            assert_eq!(
+                PayloadSlice::Ip(IpPayloadSlice {
+                    ip_number: ip_number::GGP,
+                    fragmented: false,
+                    len_source: LenSource::Slice,
+                    payload: &data,
+                }),
+                actual.payload
+            );
+        }
+
+        // len error
+        assert_eq!(
+            SliceError::Len(err::LenError {
+                required_len: UdpHeader::LEN,
+                len: 7,
+                len_source: LenSource::Slice,
+                layer: err::Layer::UdpHeader,
+                layer_start_offset: 0,
+            }),
+            PacketHeaders::from_ip_number(ip_number::UDP, &data[..7]).unwrap_err()
+        );
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn next_header_ip_number_payload_protocol() {
+        // ether payload
+        {
+            let headers = PacketHeaders::from_ether_type(EtherType::ARP, &[]).unwrap();
+            assert_eq!(None, headers.next_header_ip_number());
+            assert_eq!(
+                Some(PayloadProtocol::Ether(EtherType::ARP)),
+                headers.payload_protocol()
+            );
+        }
+
+        // ip payloads
+        {
+            let build = |ip_number: IpNumber, more_fragments: bool| {
+                let mut ip = Ipv4Header::new(0, 1, ip_number, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+                ip.more_fragments = more_fragments;
+                ip.header_checksum = ip.calc_header_checksum();
+                ip.to_bytes()
+            };
+            for (ip_number, more_fragments) in [(IpNumber(253), false), (IpNumber::UDP, true)] {
+                let bytes = build(ip_number, more_fragments);
+                let headers = PacketHeaders::from_ip_slice(&bytes).unwrap();
+                assert_eq!(Some(ip_number), headers.next_header_ip_number());
+                assert_eq!(
+                    Some(PayloadProtocol::Ip(ip_number)),
+                    headers.payload_protocol()
+                );
+            }
+        }
+
+        // transport payload (with ipv6 extension header)
+        {
+            let mut data = alloc::vec::Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ip(IpHeaders::Ipv6(
+                    Ipv6Header {
+                        source: [0; 16],
+                        destination: [0; 16],
+                        hop_limit: 64,
+                        ..Default::default()
+                    },
+                    Ipv6Extensions {
+                        destination_options: Some(
+                            Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[0; 6]).unwrap(),
+                        ),
+                        ..Default::default()
+                    },
+                ))
+                .udp(1, 2)
+                .write(&mut data, &[1, 2, 3, 4])
+                .unwrap();
+            let headers = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(Some(IpNumber::UDP), headers.next_header_ip_number());
+            assert_eq!(None, headers.payload_protocol());
+        }
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
     #[test]
     fn from_x_slice() {
         // no eth
@@ -1155,6 +1802,11 @@ mod test {
             let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
             assert_eq!(result.link, test.link);
             assert_eq!(result.vlan, test.vlan);
+            assert!(result.vlan_tags().eq(test
+                .vlan
+                .as_ref()
+                .map(|v| v.tags())
+                .unwrap_or_default()));
             assert_eq!(result.net, test.net);
             if is_fragmented {
                 assert_eq!(result.transport, None);
@@ -1163,6 +1815,33 @@ mod test {
                 assert_eq!(result.payload.slice(), &[1, 2, 3, 4]);
             }
         }
+        // async_read (with additional data after the packet)
+        #[cfg(feature = "tokio")]
+        if test.link.is_some() {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap();
+            let mut stream = data.clone();
+            stream.extend_from_slice(&[5, 6, 7]);
+            let mut reader = &stream[..];
+            let mut buffer = alloc::vec::Vec::new();
+            let result = rt
+                .block_on(PacketHeaders::async_read(&mut reader, &mut buffer))
+                .unwrap();
+
+            // only the ip packet is read (the link & vlan headers in case
+            // no ip header is present)
+            let read_len = if test.net.is_some() {
+                data.len()
+            } else {
+                data.len() - payload.len()
+            };
+            assert_eq!(stream.len() - read_len, reader.len());
+            assert_eq!(
+                PacketHeaders::from_ethernet_slice(&data[..read_len]).unwrap(),
+                result
+            );
+        }
         // from_ether_type (vlan at start)
         if test.link.is_none() && test.vlan.is_some() {
             for ether_type in VLAN_ETHER_TYPES {
@@ -1253,4 +1932,198 @@ mod test {
             assert_eq!(err, PacketHeaders::from_ip_slice(&data).unwrap_err());
         }
     }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn async_read() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(VlanId::try_new(123).unwrap())
+            .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .udp(1, 2)
+            .write(&mut packet, &[3, 4])
+            .unwrap();
+
+        // ok
+        {
+            let mut reader = &packet[..];
+            let mut buffer = alloc::vec::Vec::new();
+            let result = rt
+                .block_on(PacketHeaders::async_read(&mut reader, &mut buffer))
+                .unwrap();
+            assert_eq!(PacketHeaders::from_ethernet_slice(&packet).unwrap(), result);
+            assert!(reader.is_empty());
+        }
+
+        // unexpected eof
+        for len in 0..packet.len() {
+            let mut reader = &packet[..len];
+            let mut buffer = alloc::vec::Vec::new();
+            let err = rt
+                .block_on(PacketHeaders::async_read(&mut reader, &mut buffer))
+                .unwrap_err();
+            assert_eq!(std::io::ErrorKind::UnexpectedEof, err.io().unwrap().kind());
+        }
+
+        // ip version not matching the ether type
+        {
+            let mut data = packet.clone();
+            let ip_start = Ethernet2Header::LEN + SingleVlanHeader::LEN;
+            data[ip_start] = (6 << 4) | (data[ip_start] & 0xf);
+            let mut reader = &data[..];
+            let mut buffer = alloc::vec::Vec::new();
+            let err = rt
+                .block_on(PacketHeaders::async_read(&mut reader, &mut buffer))
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                err::ReadError::Ipv4(err::ipv4::HeaderError::UnexpectedVersion {
+                    version_number: 6
+                })
+            ));
+            // only the fixed part of the ip header is read
+            assert_eq!(data.len() - ip_start - Ipv4Header::MIN_LEN, reader.len());
+        }
+
+        // ipv6 jumbogram
+        #[cfg(feature = "ipv6")]
+        {
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1, 2, 3, 4, 5, 6],
+                    destination: [7, 8, 9, 10, 11, 12],
+                    ether_type: EtherType::IPV6,
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(
+                &Ipv6Header {
+                    payload_length: 0,
+                    next_header: IpNumber::IPV6_HEADER_HOP_BY_HOP,
+                    hop_limit: 20,
+                    source: [0; 16],
+                    destination: [1; 16],
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            // hop by hop header with a padding & jumbo payload option
+            let jumbo_len: u32 = 16 + UdpHeader::LEN as u32 + 2;
+            let jumbo_len_be = jumbo_len.to_be_bytes();
+            data.extend_from_slice(&[
+                ip_number::UDP.0,
+                1,
+                0,
+                0xc2,
+                4,
+                jumbo_len_be[0],
+                jumbo_len_be[1],
+                jumbo_len_be[2],
+                jumbo_len_be[3],
+                1,
+                3,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ]);
+            data.extend_from_slice(
+                &UdpHeader {
+                    source_port: 1,
+                    destination_port: 2,
+                    length: UdpHeader::LEN_U16 + 2,
+                    checksum: 0,
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(&[3, 4]);
+            let packet_len = data.len();
+            // data of the next packet
+            data.extend_from_slice(&[5, 6, 7]);
+
+            let mut reader = &data[..];
+            let mut buffer = alloc::vec::Vec::new();
+            let result = rt
+                .block_on(PacketHeaders::async_read(&mut reader, &mut buffer))
+                .unwrap();
+            assert_eq!(
+                PacketHeaders::from_ethernet_slice(&data[..packet_len]).unwrap(),
+                result
+            );
+            assert_eq!(&[3, 4], result.payload.slice());
+            assert_eq!(&[5, 6, 7][..], reader);
+        }
+    }
+
+    #[cfg(not(feature = "ipv6"))]
+    #[test]
+    fn ipv6_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([0; 16], [1; 16], 20)
+            .udp(1, 2)
+            .write(&mut packet, &[3, 4])
+            .unwrap();
+
+        // ipv6 packets are returned as ether payload
+        let headers = PacketHeaders::from_ethernet_slice(&packet).unwrap();
+        assert!(headers.net.is_none());
+        assert!(headers.transport.is_none());
+        assert_eq!(
+            PayloadSlice::Ether(EtherPayloadSlice {
+                ether_type: EtherType::IPV6,
+                payload: &packet[Ethernet2Header::LEN..],
+            }),
+            headers.payload
+        );
+
+        // when starting at the ip layer the version is unsupported
+        assert_eq!(
+            SliceError::Ip(err::ip::HeaderError::UnsupportedIpVersion { version_number: 6 }),
+            PacketHeaders::from_ip_slice(&packet[Ethernet2Header::LEN..]).unwrap_err()
+        );
+    }
+
+    #[cfg(not(feature = "tcp"))]
+    #[test]
+    fn tcp_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .tcp(1, 2, 3, 4)
+            .write(&mut packet, &[5, 6])
+            .unwrap();
+
+        // tcp segments are returned as ip payload
+        let headers = PacketHeaders::from_ip_slice(&packet).unwrap();
+        assert!(headers.transport.is_none());
+        let PayloadSlice::Ip(payload) = headers.payload else {
+            panic!("expected ip payload");
+        };
+        assert_eq!(ip_number::TCP, payload.ip_number);
+        assert_eq!(&packet[Ipv4Header::MIN_LEN..], payload.payload);
+    }
+
+    #[cfg(not(feature = "icmp"))]
+    #[test]
+    fn icmp_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .icmpv4_echo_request(1, 2)
+            .write(&mut packet, &[3, 4])
+            .unwrap();
+
+        // icmp messages are returned as ip payload
+        let headers = PacketHeaders::from_ip_slice(&packet).unwrap();
+        assert!(headers.transport.is_none());
+        let PayloadSlice::Ip(payload) = headers.payload else {
+            panic!("expected ip payload");
+        };
+        assert_eq!(ip_number::ICMP, payload.ip_number);
+        assert_eq!(&packet[Ipv4Header::MIN_LEN..], payload.payload);
+    }
 }
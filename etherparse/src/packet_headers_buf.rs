@@ -0,0 +1,310 @@
+use crate::{err::packet::HeadersBufError, *};
+
+/// Header the packet data in a [`PacketHeadersBuf`] starts with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+enum PacketStart {
+    Ethernet2,
+    LinuxSll,
+    Ip,
+}
+
+/// Owned copy of a packet (up to `N` bytes stored inline) together with
+/// the offsets of the parsed headers.
+///
+/// Allows storing parsed packets without lifetimes & without heap
+/// allocations (e.g. in capture ring buffers on embedded systems). The
+/// packet is validated when the buffer is created & the offsets of the
+/// headers are stored, afterwards a [`SlicedPacket`] referencing the
+/// inline data can be created at any time via [`PacketHeadersBuf::sliced`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{PacketBuilder, PacketHeadersBuf, TransportSlice};
+///
+/// # let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+/// #     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #     .udp(21, 1234);
+/// # let mut packet = Vec::new();
+/// # builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+/// // copy the packet into an inline buffer (no lifetime attached)
+/// let buf = PacketHeadersBuf::<1518>::from_ethernet(&packet).unwrap();
+/// drop(packet);
+///
+/// assert_eq!(Some(14), buf.net_offset());
+/// assert_eq!(Some(14 + 20), buf.transport_offset());
+/// assert_eq!(&[1, 2, 3, 4], buf.payload());
+///
+/// // re-create the sliced packet on demand
+/// let sliced = buf.sliced().unwrap();
+/// assert!(matches!(sliced.transport, Some(TransportSlice::Udp(_))));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct PacketHeadersBuf<const N: usize> {
+    data: [u8; N],
+    len: usize,
+    start: PacketStart,
+    net_offset: Option<usize>,
+    transport_offset: Option<usize>,
+    payload_offset: usize,
+    payload_len: usize,
+}
+
+impl<const N: usize> PacketHeadersBuf<N> {
+    /// Copies & slices a packet starting with an Ethernet II header
+    /// (see [`SlicedPacket::from_ethernet`]).
+    pub fn from_ethernet(data: &[u8]) -> Result<PacketHeadersBuf<N>, HeadersBufError> {
+        Self::new(data, PacketStart::Ethernet2)
+    }
+
+    /// Copies & slices a packet starting with a Linux SLL header
+    /// (see [`SlicedPacket::from_linux_sll`]).
+    pub fn from_linux_sll(data: &[u8]) -> Result<PacketHeadersBuf<N>, HeadersBufError> {
+        Self::new(data, PacketStart::LinuxSll)
+    }
+
+    /// Copies & slices a packet starting with an IPv4 or IPv6 header
+    /// (see [`SlicedPacket::from_ip`]).
+    pub fn from_ip(data: &[u8]) -> Result<PacketHeadersBuf<N>, HeadersBufError> {
+        Self::new(data, PacketStart::Ip)
+    }
+
+    fn new(data: &[u8], start: PacketStart) -> Result<PacketHeadersBuf<N>, HeadersBufError> {
+        if data.len() > N {
+            return Err(HeadersBufError::BufSpace(err::BufSpaceError {
+                required_len: data.len(),
+                available_len: N,
+            }));
+        }
+
+        let sliced = Self::slice(data, start).map_err(HeadersBufError::Slice)?;
+        let offset = |sub: &[u8]| offset_in(data, sub);
+        let net_offset = sliced.net.as_ref().map(|net| match net {
+            NetSlice::Ipv4(s) => offset(s.header().slice()),
+            NetSlice::Ipv6(s) => offset(s.header().slice()),
+        });
+        let transport_offset = sliced.transport.as_ref().map(|transport| match transport {
+            TransportSlice::Icmpv4(s) => offset(s.slice()),
+            TransportSlice::Icmpv6(s) => offset(s.slice()),
+            TransportSlice::Udp(s) => offset(s.slice()),
+            TransportSlice::Tcp(s) => offset(s.slice()),
+        });
+        let payload = if let Some(transport) = &sliced.transport {
+            match transport {
+                TransportSlice::Icmpv4(s) => s.payload(),
+                TransportSlice::Icmpv6(s) => s.payload(),
+                TransportSlice::Udp(s) => s.payload(),
+                TransportSlice::Tcp(s) => s.payload(),
+            }
+        } else if let Some(ip_payload) = sliced.ip_payload() {
+            ip_payload.payload
+        } else if let Some(ether_payload) = sliced.ether_payload() {
+            ether_payload.payload
+        } else {
+            &data[data.len()..]
+        };
+        let payload_offset = offset(payload);
+        let payload_len = payload.len();
+
+        let mut result = PacketHeadersBuf {
+            data: [0u8; N],
+            len: data.len(),
+            start,
+            net_offset,
+            transport_offset,
+            payload_offset,
+            payload_len,
+        };
+        result.data[..data.len()].copy_from_slice(data);
+        Ok(result)
+    }
+
+    fn slice(data: &[u8], start: PacketStart) -> Result<SlicedPacket<'_>, err::packet::SliceError> {
+        match start {
+            PacketStart::Ethernet2 => SlicedPacket::from_ethernet(data),
+            PacketStart::LinuxSll => SlicedPacket::from_linux_sll(data),
+            PacketStart::Ip => SlicedPacket::from_ip(data),
+        }
+    }
+
+    /// Returns the stored packet data.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Slices the stored packet data.
+    ///
+    /// The data is sliced again on every call (a [`SlicedPacket`] can
+    /// not be stored together with the data it references). Use the
+    /// stored offsets (e.g. [`PacketHeadersBuf::payload`]) if only they
+    /// are needed. As the data was already successfully sliced when the
+    /// buffer was created no error is expected.
+    pub fn sliced(&self) -> Result<SlicedPacket<'_>, err::packet::SliceError> {
+        Self::slice(self.as_slice(), self.start)
+    }
+
+    /// Offset of the IPv4 or IPv6 header in the packet data (if present).
+    #[inline]
+    pub fn net_offset(&self) -> Option<usize> {
+        self.net_offset
+    }
+
+    /// Offset of the transport header (TCP, UDP, ICMP or ICMPv6) in the
+    /// packet data (if present).
+    #[inline]
+    pub fn transport_offset(&self) -> Option<usize> {
+        self.transport_offset
+    }
+
+    /// Offset of the payload after the last decoded header.
+    #[inline]
+    pub fn payload_offset(&self) -> usize {
+        self.payload_offset
+    }
+
+    /// Payload after the last decoded header (without padding after the
+    /// payload e.g. introduced by ethernet II frame minimum sizes).
+    #[inline]
+    pub fn payload(&self) -> &[u8] {
+        &self.data[self.payload_offset..self.payload_offset + self.payload_len]
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for PacketHeadersBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PacketHeadersBuf")
+            .field("data", &self.as_slice())
+            .field("start", &self.start)
+            .field("net_offset", &self.net_offset)
+            .field("transport_offset", &self.transport_offset)
+            .field("payload_offset", &self.payload_offset)
+            .field("payload_len", &self.payload_len)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    fn eth_ipv4_udp(payload: &[u8]) -> Vec<u8> {
+        let mut result = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234)
+            .write(&mut result, payload)
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn from_ethernet() {
+        let packet = eth_ipv4_udp(&[1, 2, 3, 4]);
+        let buf = PacketHeadersBuf::<128>::from_ethernet(&packet).unwrap();
+        assert_eq!(&packet[..], buf.as_slice());
+        assert_eq!(Some(14), buf.net_offset());
+        assert_eq!(Some(14 + 20), buf.transport_offset());
+        assert_eq!(14 + 20 + 8, buf.payload_offset());
+        assert_eq!(&[1, 2, 3, 4], buf.payload());
+        assert_eq!(
+            SlicedPacket::from_ethernet(&packet).unwrap(),
+            buf.sliced().unwrap()
+        );
+
+        // ethernet padding is not part of the payload
+        let mut padded = packet.clone();
+        padded.extend_from_slice(&[0; 6]);
+        let buf = PacketHeadersBuf::<128>::from_ethernet(&padded).unwrap();
+        assert_eq!(&padded[..], buf.as_slice());
+        assert_eq!(&[1, 2, 3, 4], buf.payload());
+    }
+
+    #[test]
+    fn from_ip() {
+        let packet = eth_ipv4_udp(&[1, 2]);
+        let buf = PacketHeadersBuf::<128>::from_ip(&packet[14..]).unwrap();
+        assert_eq!(Some(0), buf.net_offset());
+        assert_eq!(Some(20), buf.transport_offset());
+        assert_eq!(&[1, 2], buf.payload());
+        assert_eq!(
+            SlicedPacket::from_ip(&packet[14..]).unwrap(),
+            buf.sliced().unwrap()
+        );
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn from_linux_sll() {
+        let mut packet = Vec::new();
+        PacketBuilder::linux_sll(LinuxSllPacketType::HOST, 6, [1, 2, 3, 4, 5, 6, 0, 0])
+            .ipv6([1; 16], [2; 16], 20)
+            .udp(21, 1234)
+            .write(&mut packet, &[1, 2])
+            .unwrap();
+        let buf = PacketHeadersBuf::<128>::from_linux_sll(&packet).unwrap();
+        assert_eq!(Some(LinuxSllHeader::LEN), buf.net_offset());
+        assert_eq!(&[1, 2], buf.payload());
+        assert_eq!(
+            SlicedPacket::from_linux_sll(&packet).unwrap(),
+            buf.sliced().unwrap()
+        );
+    }
+
+    #[test]
+    fn link_only() {
+        let mut packet = Vec::new();
+        Ethernet2Header {
+            source: [1; 6],
+            destination: [2; 6],
+            ether_type: EtherType(0x1234),
+        }
+        .write(&mut packet)
+        .unwrap();
+        packet.extend_from_slice(&[1, 2, 3]);
+        let buf = PacketHeadersBuf::<128>::from_ethernet(&packet).unwrap();
+        assert_eq!(None, buf.net_offset());
+        assert_eq!(None, buf.transport_offset());
+        assert_eq!(14, buf.payload_offset());
+        assert_eq!(&[1, 2, 3], buf.payload());
+    }
+
+    #[test]
+    fn errors() {
+        let packet = eth_ipv4_udp(&[1, 2, 3, 4]);
+
+        // too big for the buffer
+        assert_eq!(
+            Some(&err::BufSpaceError {
+                required_len: packet.len(),
+                available_len: 16,
+            }),
+            PacketHeadersBuf::<16>::from_ethernet(&packet)
+                .unwrap_err()
+                .buf_space()
+        );
+
+        // slice error
+        assert_eq!(
+            Some(&SlicedPacket::from_ethernet(&packet[..10]).unwrap_err()),
+            PacketHeadersBuf::<128>::from_ethernet(&packet[..10])
+                .unwrap_err()
+                .slice()
+        );
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let packet = eth_ipv4_udp(&[]);
+        let buf = PacketHeadersBuf::<64>::from_ethernet(&packet).unwrap();
+        assert_eq!(buf, buf.clone());
+        assert_eq!(
+            format!(
+                "PacketHeadersBuf {{ data: {:?}, start: Ethernet2, net_offset: Some(14), transport_offset: Some(34), payload_offset: 42, payload_len: 0 }}",
+                &packet[..]
+            ),
+            format!("{:?}", buf)
+        );
+    }
+}
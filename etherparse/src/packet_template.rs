@@ -0,0 +1,737 @@
+use crate::*;
+use std::vec::Vec;
+
+/// Pre-serialized UDP or TCP packet in which individual fields can be
+/// changed efficiently (created via [`PacketBuilderStep::<UdpHeader>::template`]
+/// or [`PacketBuilderStep::<TcpHeader>::template`]).
+///
+/// The packet is only serialized once. Changing a field afterwards only
+/// overwrites the field & incrementally updates the affected checksums
+/// (based on [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624))
+/// instead of recalculating all lengths & checksums. This makes templates
+/// suited for generating large numbers of packets that only differ in
+/// ports, identification or sequence numbers:
+///
+/// ```
+/// use etherparse::{services, PacketBuilder, SlicedPacket, TransportSlice};
+///
+/// let mut template = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(0, services::DNS)
+///     .template(&[1, 2, 3, 4])
+///     .unwrap();
+///
+/// for i in 0..1000u32 {
+///     template.set_source_port(services::ephemeral_port(i));
+///     template.set_ipv4_identification(i as u16);
+///
+///     // send the packet
+///     let packet: &[u8] = template.packet();
+///     # let sliced = SlicedPacket::from_ip(packet).unwrap();
+///     # if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     #     assert_eq!(services::ephemeral_port(i), udp.source_port());
+///     # }
+/// }
+/// ```
+///
+/// If the ethernet frame check sequence is enabled in the builder it is
+/// recalculated after every change (which requires processing the complete
+/// packet).
+///
+/// Packets with a different payload can be generated via
+/// [`PacketTemplate::render`]. The constant header bytes & the checksum
+/// sums over the constant header fields are only computed once when the
+/// template is created, so rendering only has to write the variable
+/// fields & sum up the payload:
+///
+/// ```
+/// use etherparse::{PacketBuilder, PacketTemplateOverrides, SlicedPacket};
+///
+/// let template = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .tcp(1234, 80, 0, 1024)
+///     .template(&[])
+///     .unwrap();
+///
+/// let mut buf = Vec::new();
+/// for seq in 0..1000u32 {
+///     template
+///         .render(
+///             &mut buf,
+///             &seq.to_be_bytes(),
+///             &PacketTemplateOverrides {
+///                 ipv4_identification: Some(seq as u16),
+///                 tcp_sequence_number: Some(seq * 4),
+///                 ..Default::default()
+///             },
+///         )
+///         .unwrap();
+///     // send the packet in buf
+///     # let sliced = SlicedPacket::from_ip(&buf).unwrap();
+///     # assert_eq!(&seq.to_be_bytes(), sliced.ip_payload().unwrap().payload.get(20..).unwrap());
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PacketTemplate {
+    /// Serialized packet (including the ethernet fcs if enabled).
+    data: Vec<u8>,
+    /// Start of the IP header.
+    ip_start: usize,
+    /// Start of the IPv4 header (`None` if the packet is an IPv6 packet).
+    ipv4_start: Option<usize>,
+    /// Start of the UDP or TCP header.
+    transport_start: usize,
+    /// Start of the payload after the UDP or TCP header.
+    payload_start: usize,
+    /// True if the transport header is a TCP header (otherwise UDP).
+    is_tcp: bool,
+    /// True if the last 4 bytes contain the ethernet frame check sequence.
+    ethernet_fcs: bool,
+    /// Start of the MACsec header & length of the integrity check value
+    /// (if a MACsec header is present).
+    #[cfg_attr(not(feature = "macsec"), allow(dead_code))]
+    macsec: Option<(usize, usize)>,
+    /// Sum of the IPv4 header words excluding the total length,
+    /// identification & checksum fields.
+    ipv4_header_sum: checksum::Sum16BitWords,
+    /// Sum of the pseudo header & transport header words excluding the
+    /// length, ports, sequence & acknowledgment numbers & the checksum.
+    transport_sum: checksum::Sum16BitWords,
+    /// False if the UDP checksum was not calculated (set to zero).
+    transport_checksum: bool,
+}
+
+impl PacketTemplate {
+    /// Creates a template from a serialized packet.
+    pub(crate) fn new(
+        data: Vec<u8>,
+        ip_start: usize,
+        is_ipv4: bool,
+        transport_start: usize,
+        is_tcp: bool,
+        ethernet_fcs: bool,
+        macsec: Option<(usize, usize)>,
+    ) -> PacketTemplate {
+        let transport_header_len = if is_tcp {
+            usize::from(data[transport_start + 12] >> 4) * 4
+        } else {
+            UdpHeader::LEN
+        };
+        let payload_start = transport_start + transport_header_len;
+        let ip_number = if is_tcp { IpNumber::TCP } else { IpNumber::UDP };
+
+        let (ipv4_header_sum, pseudo_header_sum) = if is_ipv4 {
+            let header = &data[ip_start..transport_start];
+            let header_len = usize::from(header[0] & 0xf) * 4;
+            (
+                checksum::Sum16BitWords::new()
+                    .add_slice(&header[..2])
+                    .add_slice(&header[6..10])
+                    .add_slice(&header[12..header_len]),
+                checksum::Sum16BitWords::new().add_slice(&header[12..20]),
+            )
+        } else {
+            (
+                checksum::Sum16BitWords::new(),
+                checksum::Sum16BitWords::new().add_slice(&data[ip_start + 8..ip_start + 40]),
+            )
+        };
+        let transport = &data[transport_start..payload_start];
+        let pseudo_header_sum = pseudo_header_sum.add_2bytes([0, ip_number.0]);
+        let transport_sum = if is_tcp {
+            pseudo_header_sum
+                .add_slice(&transport[12..16])
+                .add_slice(&transport[18..])
+        } else {
+            pseudo_header_sum
+        };
+        let transport_checksum = is_tcp || [0, 0] != transport[6..8];
+
+        PacketTemplate {
+            data,
+            ip_start,
+            ipv4_start: if is_ipv4 { Some(ip_start) } else { None },
+            transport_start,
+            payload_start,
+            is_tcp,
+            ethernet_fcs,
+            macsec,
+            ipv4_header_sum,
+            transport_sum,
+            transport_checksum,
+        }
+    }
+
+    /// Returns the serialized packet.
+    #[inline]
+    pub fn packet(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Read the source port of the UDP or TCP header.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        self.read_u16(self.transport_start)
+    }
+
+    /// Read the destination port of the UDP or TCP header.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        self.read_u16(self.transport_start + 2)
+    }
+
+    /// Sets the source port of the UDP or TCP header & updates the
+    /// transport checksum.
+    pub fn set_source_port(&mut self, port: u16) {
+        self.set_transport_word(self.transport_start, port);
+        self.update_fcs();
+    }
+
+    /// Sets the destination port of the UDP or TCP header & updates the
+    /// transport checksum.
+    pub fn set_destination_port(&mut self, port: u16) {
+        self.set_transport_word(self.transport_start + 2, port);
+        self.update_fcs();
+    }
+
+    /// Read the "identification" field of the IPv4 header (`None` if
+    /// the packet is an IPv6 packet).
+    #[inline]
+    pub fn ipv4_identification(&self) -> Option<u16> {
+        self.ipv4_start.map(|start| self.read_u16(start + 4))
+    }
+
+    /// Sets the "identification" field of the IPv4 header & updates the
+    /// IPv4 header checksum (does nothing if the packet is an IPv6 packet).
+    pub fn set_ipv4_identification(&mut self, identification: u16) {
+        if let Some(start) = self.ipv4_start {
+            self.set_word(start + 4, identification, start + 10, false);
+            self.update_fcs();
+        }
+    }
+
+    /// Read the sequence number of the TCP header (`None` if the packet
+    /// is an UDP packet).
+    #[inline]
+    pub fn tcp_sequence_number(&self) -> Option<u32> {
+        self.is_tcp.then(|| self.read_u32(self.transport_start + 4))
+    }
+
+    /// Sets the sequence number of the TCP header & updates the TCP
+    /// checksum (does nothing if the packet is an UDP packet).
+    pub fn set_tcp_sequence_number(&mut self, sequence_number: u32) {
+        if self.is_tcp {
+            self.set_transport_u32(self.transport_start + 4, sequence_number);
+            self.update_fcs();
+        }
+    }
+
+    /// Read the acknowledgment number of the TCP header (`None` if the
+    /// packet is an UDP packet).
+    #[inline]
+    pub fn tcp_acknowledgment_number(&self) -> Option<u32> {
+        self.is_tcp.then(|| self.read_u32(self.transport_start + 8))
+    }
+
+    /// Sets the acknowledgment number of the TCP header & updates the TCP
+    /// checksum (does nothing if the packet is an UDP packet).
+    pub fn set_tcp_acknowledgment_number(&mut self, acknowledgment_number: u32) {
+        if self.is_tcp {
+            self.set_transport_u32(self.transport_start + 8, acknowledgment_number);
+            self.update_fcs();
+        }
+    }
+
+    /// Writes the packet with the given payload & the variable fields
+    /// set to the given overrides into `buf` (previous content of `buf`
+    /// is removed).
+    ///
+    /// The template itself is not modified. All length fields & checksums
+    /// are set based on the given payload. Fields that are `None` in the
+    /// overrides keep the value stored in the template.
+    ///
+    /// An error is returned if the payload is too big to be represented
+    /// in the length fields of the IP header.
+    pub fn render(
+        &self,
+        buf: &mut Vec<u8>,
+        payload: &[u8],
+        overrides: &PacketTemplateOverrides,
+    ) -> Result<(), err::ValueTooBigError<usize>> {
+        use err::{ValueTooBigError, ValueType};
+
+        // check that the lengths can be represented
+        let transport_len = self.payload_start - self.transport_start + payload.len();
+        let (ip_len_start, max_payload_len, value_type) = if self.ipv4_start.is_some() {
+            (
+                self.ip_start,
+                usize::from(u16::MAX) - (self.payload_start - self.ip_start),
+                ValueType::Ipv4PayloadLength,
+            )
+        } else {
+            (
+                self.ip_start + Ipv6Header::LEN,
+                usize::from(u16::MAX) - (self.payload_start - self.ip_start - Ipv6Header::LEN),
+                ValueType::Ipv6PayloadLength,
+            )
+        };
+        if payload.len() > max_payload_len {
+            return Err(ValueTooBigError {
+                actual: payload.len(),
+                max_allowed: max_payload_len,
+                value_type,
+            });
+        }
+        let ip_len = (self.payload_start - ip_len_start + payload.len()) as u16;
+        let transport_len = transport_len as u16;
+
+        // headers, payload & macsec integrity check value
+        let fcs_len = if self.ethernet_fcs { 4 } else { 0 };
+        buf.clear();
+        buf.reserve(self.payload_start + payload.len() + fcs_len);
+        buf.extend_from_slice(&self.data[..self.payload_start]);
+        buf.extend_from_slice(payload);
+        #[cfg(feature = "macsec")]
+        if let Some((macsec_start, icv_len)) = self.macsec {
+            let icv_end = self.data.len() - fcs_len;
+            buf.extend_from_slice(&self.data[icv_end - icv_len..icv_end]);
+
+            // the short length depends on the payload length
+            if let Ok((mut header, _)) = MacsecHeader::from_slice(&buf[macsec_start..]) {
+                let header_len = header.header_len();
+                header.set_secure_data_len(buf.len() - macsec_start - header_len - icv_len);
+                buf[macsec_start..macsec_start + header_len].copy_from_slice(&header.to_bytes());
+            }
+        }
+
+        // ip header
+        if let Some(start) = self.ipv4_start {
+            let identification = overrides
+                .ipv4_identification
+                .unwrap_or_else(|| self.read_u16(start + 4));
+            let checksum = self
+                .ipv4_header_sum
+                .clone()
+                .add_2bytes(ip_len.to_be_bytes())
+                .add_2bytes(identification.to_be_bytes())
+                .ones_complement()
+                .to_be();
+            buf[start + 2..start + 4].copy_from_slice(&ip_len.to_be_bytes());
+            buf[start + 4..start + 6].copy_from_slice(&identification.to_be_bytes());
+            buf[start + 10..start + 12].copy_from_slice(&checksum.to_be_bytes());
+        } else {
+            let start = self.ip_start;
+            buf[start + 4..start + 6].copy_from_slice(&ip_len.to_be_bytes());
+        }
+
+        // transport header
+        let start = self.transport_start;
+        let source_port = overrides.source_port.unwrap_or_else(|| self.source_port());
+        let destination_port = overrides
+            .destination_port
+            .unwrap_or_else(|| self.destination_port());
+        buf[start..start + 2].copy_from_slice(&source_port.to_be_bytes());
+        buf[start + 2..start + 4].copy_from_slice(&destination_port.to_be_bytes());
+        let sum = self
+            .transport_sum
+            .clone()
+            .add_2bytes(transport_len.to_be_bytes())
+            .add_2bytes(source_port.to_be_bytes())
+            .add_2bytes(destination_port.to_be_bytes());
+        let (sum, checksum_offset) = if self.is_tcp {
+            let sequence_number = overrides
+                .tcp_sequence_number
+                .unwrap_or_else(|| self.read_u32(start + 4));
+            let acknowledgment_number = overrides
+                .tcp_acknowledgment_number
+                .unwrap_or_else(|| self.read_u32(start + 8));
+            buf[start + 4..start + 8].copy_from_slice(&sequence_number.to_be_bytes());
+            buf[start + 8..start + 12].copy_from_slice(&acknowledgment_number.to_be_bytes());
+            (
+                sum.add_slice(&sequence_number.to_be_bytes())
+                    .add_slice(&acknowledgment_number.to_be_bytes()),
+                start + 16,
+            )
+        } else {
+            buf[start + 4..start + 6].copy_from_slice(&transport_len.to_be_bytes());
+            (sum.add_2bytes(transport_len.to_be_bytes()), start + 6)
+        };
+        if self.transport_checksum {
+            let sum = sum.add_slice(payload);
+            let checksum = if self.is_tcp {
+                sum.ones_complement()
+            } else {
+                sum.to_ones_complement_with_no_zero()
+            }
+            .to_be();
+            buf[checksum_offset..checksum_offset + 2].copy_from_slice(&checksum.to_be_bytes());
+        }
+
+        // ethernet frame check sequence
+        if self.ethernet_fcs {
+            let fcs = checksum::Crc32::new().add_slice(buf).to_fcs();
+            buf.extend_from_slice(&fcs);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn read_u16(&self, offset: usize) -> u16 {
+        u16::from_be_bytes([self.data[offset], self.data[offset + 1]])
+    }
+
+    #[inline]
+    fn read_u32(&self, offset: usize) -> u32 {
+        u32::from_be_bytes([
+            self.data[offset],
+            self.data[offset + 1],
+            self.data[offset + 2],
+            self.data[offset + 3],
+        ])
+    }
+
+    /// Sets a 32 bit value in the transport header & updates the
+    /// transport checksum.
+    fn set_transport_u32(&mut self, offset: usize, value: u32) {
+        let bytes = value.to_be_bytes();
+        self.set_transport_word(offset, u16::from_be_bytes([bytes[0], bytes[1]]));
+        self.set_transport_word(offset + 2, u16::from_be_bytes([bytes[2], bytes[3]]));
+    }
+
+    /// Sets a 16 bit word in the transport header & updates the
+    /// transport checksum.
+    fn set_transport_word(&mut self, offset: usize, value: u16) {
+        if self.is_tcp {
+            self.set_word(offset, value, self.transport_start + 16, false);
+        } else {
+            self.set_word(offset, value, self.transport_start + 6, true);
+        }
+    }
+
+    /// Sets a 16 bit word & incrementally updates the checksum at
+    /// `checksum_offset`.
+    ///
+    /// If `is_udp_checksum` is set a checksum of zero is left untouched (no
+    /// checksum was calculated) and a calculated checksum of zero is
+    /// written as `0xffff` (as required by RFC 768).
+    fn set_word(
+        &mut self,
+        offset: usize,
+        value: u16,
+        checksum_offset: usize,
+        is_udp_checksum: bool,
+    ) {
+        let old_value = self.read_u16(offset);
+        self.data[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+
+        let old_checksum = self.read_u16(checksum_offset);
+        if is_udp_checksum && 0 == old_checksum {
+            return;
+        }
+        let checksum = match checksum::incremental_update(old_checksum, old_value, value) {
+            0 if is_udp_checksum => 0xffff,
+            checksum => checksum,
+        };
+        self.data[checksum_offset..checksum_offset + 2].copy_from_slice(&checksum.to_be_bytes());
+    }
+
+    /// Recalculates the ethernet frame check sequence (if enabled).
+    fn update_fcs(&mut self) {
+        if self.ethernet_fcs {
+            let end = self.data.len() - 4;
+            let fcs = checksum::Crc32::new().add_slice(&self.data[..end]).to_fcs();
+            self.data[end..].copy_from_slice(&fcs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    /// Builds a packet without a template.
+    fn build(step: PacketBuilderStep<UdpHeader>, payload: &[u8]) -> Vec<u8> {
+        let mut result = Vec::new();
+        step.write(&mut result, payload).unwrap();
+        result
+    }
+
+    proptest! {
+        #[test]
+        fn udp(
+            source_ip in any::<[u8;4]>(),
+            destination_ip in any::<[u8;4]>(),
+            ports in any::<[u16;4]>(),
+            identification in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let builder = |source_port, destination_port, identification| {
+                let mut ipv4 = Ipv4Header::new(0, 20, IpNumber::UDP, source_ip, destination_ip).unwrap();
+                ipv4.identification = identification;
+                PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                    .with_fcs()
+                    .ip(IpHeaders::Ipv4(ipv4, Default::default()))
+                    .udp(source_port, destination_port)
+            };
+
+            let mut template = builder(ports[0], ports[1], 0).template(&payload).unwrap();
+            assert_eq!(template.packet(), &build(builder(ports[0], ports[1], 0), &payload)[..]);
+            assert_eq!(ports[0], template.source_port());
+            assert_eq!(ports[1], template.destination_port());
+            assert_eq!(Some(0), template.ipv4_identification());
+            assert_eq!(None, template.tcp_sequence_number());
+            assert_eq!(None, template.tcp_acknowledgment_number());
+
+            template.set_source_port(ports[2]);
+            template.set_destination_port(ports[3]);
+            template.set_ipv4_identification(identification);
+            // ignored for udp packets
+            template.set_tcp_sequence_number(1);
+            template.set_tcp_acknowledgment_number(1);
+
+            assert_eq!(
+                template.packet(),
+                &build(builder(ports[2], ports[3], identification), &payload)[..]
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn tcp(
+            ip in ipv6_any(),
+            tcp in tcp_any(),
+            ports in any::<[u16;2]>(),
+            sequence_number in any::<u32>(),
+            acknowledgment_number in any::<u32>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let builder = |tcp: TcpHeader| {
+                PacketBuilder::ipv6(ip.source, ip.destination, ip.hop_limit)
+                    .tcp_header(tcp)
+            };
+
+            let mut template = builder(tcp.clone()).template(&payload).unwrap();
+            let mut expected = Vec::new();
+            builder(tcp.clone()).write(&mut expected, &payload).unwrap();
+            assert_eq!(template.packet(), &expected[..]);
+            assert_eq!(None, template.ipv4_identification());
+            assert_eq!(Some(tcp.sequence_number), template.tcp_sequence_number());
+            assert_eq!(Some(tcp.acknowledgment_number), template.tcp_acknowledgment_number());
+
+            template.set_source_port(ports[0]);
+            template.set_destination_port(ports[1]);
+            template.set_tcp_sequence_number(sequence_number);
+            template.set_tcp_acknowledgment_number(acknowledgment_number);
+            // ignored for ipv6 packets
+            template.set_ipv4_identification(1);
+
+            let mut modified = tcp.clone();
+            modified.source_port = ports[0];
+            modified.destination_port = ports[1];
+            modified.sequence_number = sequence_number;
+            modified.acknowledgment_number = acknowledgment_number;
+            let mut expected = Vec::new();
+            builder(modified).write(&mut expected, &payload).unwrap();
+            assert_eq!(template.packet(), &expected[..]);
+        }
+    }
+
+    proptest! {
+        #[cfg(feature = "macsec")]
+        #[test]
+        fn render_udp(
+            source_ip in any::<[u8;4]>(),
+            destination_ip in any::<[u8;4]>(),
+            ports in any::<[u16;2]>(),
+            identification in any::<u16>(),
+            macsec in macsec_any(),
+            icv in proptest::collection::vec(any::<u8>(), 0..20),
+            template_payload in proptest::collection::vec(any::<u8>(), 0..20),
+            payload in proptest::collection::vec(any::<u8>(), 0..80)
+        ) {
+            for (with_macsec, with_fcs) in [(false, false), (true, false), (false, true), (true, true)] {
+                let builder = |source_port, destination_port, identification| {
+                    let mut ipv4 = Ipv4Header::new(0, 20, IpNumber::UDP, source_ip, destination_ip).unwrap();
+                    ipv4.identification = identification;
+                    let eth = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]);
+                    let eth = if with_fcs { eth.with_fcs() } else { eth };
+                    if with_macsec {
+                        eth.macsec(macsec.clone(), &icv)
+                            .ip(IpHeaders::Ipv4(ipv4, Default::default()))
+                            .udp(source_port, destination_port)
+                    } else {
+                        eth.ip(IpHeaders::Ipv4(ipv4, Default::default()))
+                            .udp(source_port, destination_port)
+                    }
+                };
+
+                let template = builder(1, 2, 3).template(&template_payload).unwrap();
+                let mut buf = alloc::vec![0xff; 4];
+
+                // no overrides
+                template.render(&mut buf, &payload, &Default::default()).unwrap();
+                assert_eq!(buf, build(builder(1, 2, 3), &payload));
+
+                // overrides
+                template.render(
+                    &mut buf,
+                    &payload,
+                    &PacketTemplateOverrides {
+                        source_port: Some(ports[0]),
+                        destination_port: Some(ports[1]),
+                        ipv4_identification: Some(identification),
+                        // ignored for udp packets
+                        tcp_sequence_number: Some(1),
+                        tcp_acknowledgment_number: Some(1),
+                    },
+                ).unwrap();
+                assert_eq!(buf, build(builder(ports[0], ports[1], identification), &payload));
+
+                // template is unchanged
+                assert_eq!(template.packet(), &build(builder(1, 2, 3), &template_payload)[..]);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn render_tcp(
+            ip in ipv6_any(),
+            tcp in tcp_any(),
+            ports in any::<[u16;2]>(),
+            sequence_number in any::<u32>(),
+            acknowledgment_number in any::<u32>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..80)
+        ) {
+            let builder = |tcp: TcpHeader| {
+                PacketBuilder::ipv6(ip.source, ip.destination, ip.hop_limit)
+                    .tcp_header(tcp)
+            };
+            let template = builder(tcp.clone()).template(&[1, 2, 3]).unwrap();
+            let mut buf = Vec::new();
+
+            // no overrides
+            template.render(&mut buf, &payload, &Default::default()).unwrap();
+            let mut expected = Vec::new();
+            builder(tcp.clone()).write(&mut expected, &payload).unwrap();
+            assert_eq!(buf, expected);
+
+            // overrides
+            template.render(
+                &mut buf,
+                &payload,
+                &PacketTemplateOverrides {
+                    source_port: Some(ports[0]),
+                    destination_port: Some(ports[1]),
+                    // ignored for ipv6 packets
+                    ipv4_identification: Some(1),
+                    tcp_sequence_number: Some(sequence_number),
+                    tcp_acknowledgment_number: Some(acknowledgment_number),
+                },
+            ).unwrap();
+            let mut modified = tcp.clone();
+            modified.source_port = ports[0];
+            modified.destination_port = ports[1];
+            modified.sequence_number = sequence_number;
+            modified.acknowledgment_number = acknowledgment_number;
+            let mut expected = Vec::new();
+            builder(modified).write(&mut expected, &payload).unwrap();
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    fn render_udp_without_checksum() {
+        let mut template = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .udp(1, 2)
+            .template(&[])
+            .unwrap();
+        // zero the udp checksum (no checksum calculated)
+        template.data[26] = 0;
+        template.data[27] = 0;
+        let template = PacketTemplate::new(template.data, 0, true, 20, false, false, None);
+
+        let mut buf = Vec::new();
+        template
+            .render(&mut buf, &[1, 2, 3], &Default::default())
+            .unwrap();
+        let sliced = SlicedPacket::from_ip(&buf).unwrap();
+        match sliced.transport {
+            Some(TransportSlice::Udp(udp)) => {
+                assert_eq!(0, udp.checksum());
+                assert_eq!(&[1, 2, 3], udp.payload());
+            }
+            _ => panic!("expected udp"),
+        }
+    }
+
+    #[test]
+    fn render_payload_too_big() {
+        use err::{ValueTooBigError, ValueType};
+
+        let payload = alloc::vec![0u8; usize::from(u16::MAX)];
+        let mut buf = Vec::new();
+
+        // ipv4
+        {
+            let template = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1, 2)
+                .template(&[])
+                .unwrap();
+            let max = usize::from(u16::MAX) - Ipv4Header::MIN_LEN - UdpHeader::LEN;
+            assert!(template
+                .render(&mut buf, &payload[..max], &Default::default())
+                .is_ok());
+            assert_eq!(
+                Err(ValueTooBigError {
+                    actual: max + 1,
+                    max_allowed: max,
+                    value_type: ValueType::Ipv4PayloadLength,
+                }),
+                template.render(&mut buf, &payload[..max + 1], &Default::default())
+            );
+        }
+        // ipv6
+        {
+            let template = PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .udp(1, 2)
+                .template(&[])
+                .unwrap();
+            let max = usize::from(u16::MAX) - UdpHeader::LEN;
+            assert!(template
+                .render(&mut buf, &payload[..max], &Default::default())
+                .is_ok());
+            let mut payload = payload.clone();
+            payload.push(0);
+            assert_eq!(
+                Err(ValueTooBigError {
+                    actual: max + 1,
+                    max_allowed: max,
+                    value_type: ValueType::Ipv6PayloadLength,
+                }),
+                template.render(&mut buf, &payload[..max + 1], &Default::default())
+            );
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let template = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .udp(1, 2)
+            .template(&[])
+            .unwrap();
+        assert_eq!(template, template.clone());
+        assert_eq!(
+            format!(
+                "PacketTemplate {{ data: {:?}, ip_start: 0, ipv4_start: Some(0), transport_start: 20, payload_start: 28, is_tcp: false, ethernet_fcs: false, macsec: None, ipv4_header_sum: {:?}, transport_sum: {:?}, transport_checksum: true }}",
+                template.packet(),
+                template.ipv4_header_sum,
+                template.transport_sum
+            ),
+            format!("{:?}", template)
+        );
+    }
+}
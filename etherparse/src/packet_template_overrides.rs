@@ -0,0 +1,76 @@
+/// Values of the variable fields of a packet rendered via
+/// [`crate::PacketTemplate::render`].
+///
+/// Fields set to `None` keep the value currently stored in the template.
+/// Fields that are not present in the packet (e.g. the TCP sequence
+/// number in an UDP packet or the IPv4 identification in an IPv6 packet)
+/// are ignored.
+///
+/// ```
+/// use etherparse::PacketTemplateOverrides;
+///
+/// let overrides = PacketTemplateOverrides {
+///     source_port: Some(1234),
+///     ipv4_identification: Some(1),
+///     ..Default::default()
+/// };
+/// assert_eq!(None, overrides.destination_port);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct PacketTemplateOverrides {
+    /// Source port of the UDP or TCP header.
+    pub source_port: Option<u16>,
+    /// Destination port of the UDP or TCP header.
+    pub destination_port: Option<u16>,
+    /// "Identification" field of the IPv4 header.
+    pub ipv4_identification: Option<u16>,
+    /// Sequence number of the TCP header.
+    pub tcp_sequence_number: Option<u32>,
+    /// Acknowledgment number of the TCP header.
+    pub tcp_acknowledgment_number: Option<u32>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn default() {
+        let value = PacketTemplateOverrides::default();
+        assert_eq!(None, value.source_port);
+        assert_eq!(None, value.destination_port);
+        assert_eq!(None, value.ipv4_identification);
+        assert_eq!(None, value.tcp_sequence_number);
+        assert_eq!(None, value.tcp_acknowledgment_number);
+    }
+
+    #[test]
+    fn clone_eq_hash_debug() {
+        let value = PacketTemplateOverrides {
+            source_port: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(value, value.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            value.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+        assert_eq!(
+            "PacketTemplateOverrides { source_port: Some(1), destination_port: None, ipv4_identification: None, tcp_sequence_number: None, tcp_acknowledgment_number: None }",
+            format!("{:?}", value)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+/// Parse result (e.g. [`crate::SlicedPacket`] or [`crate::PacketHeaders`])
+/// paired with user defined metadata (e.g. a capture timestamp, the id of
+/// the receiving interface or the direction of the packet).
+///
+/// The wrapper dereferences to the packet, so the layers can be accessed
+/// directly:
+///
+/// ```
+/// use etherparse::{PacketBuilder, PacketWithMeta, SlicedPacket};
+///
+/// # let mut packet = Vec::new();
+/// # PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+/// #     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #     .udp(21, 1234)
+/// #     .write(&mut packet, &[1, 2, 3, 4])
+/// #     .unwrap();
+/// // metadata as delivered by the capture library
+/// struct CaptureMeta {
+///     timestamp_ns: u64,
+///     interface_id: u32,
+/// }
+///
+/// let sliced = PacketWithMeta::new(
+///     SlicedPacket::from_ethernet(&packet).unwrap(),
+///     CaptureMeta {
+///         timestamp_ns: 1_700_000_000_000_000_000,
+///         interface_id: 2,
+///     },
+/// );
+///
+/// assert!(sliced.net.is_some());
+/// assert_eq!(2, sliced.meta.interface_id);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PacketWithMeta<P, M> {
+    /// Parsed packet.
+    pub packet: P,
+
+    /// Metadata associated with the packet.
+    pub meta: M,
+}
+
+impl<P, M> PacketWithMeta<P, M> {
+    /// Pairs a packet with metadata.
+    #[inline]
+    pub const fn new(packet: P, meta: M) -> PacketWithMeta<P, M> {
+        PacketWithMeta { packet, meta }
+    }
+
+    /// Splits the wrapper into the packet & the metadata.
+    #[inline]
+    pub fn into_parts(self) -> (P, M) {
+        (self.packet, self.meta)
+    }
+
+    /// Replaces the packet (e.g. with a re-parsed or converted
+    /// version) while keeping the metadata.
+    #[inline]
+    pub fn map_packet<Q, F: FnOnce(P) -> Q>(self, f: F) -> PacketWithMeta<Q, M> {
+        PacketWithMeta {
+            packet: f(self.packet),
+            meta: self.meta,
+        }
+    }
+
+    /// Replaces the metadata while keeping the packet.
+    #[inline]
+    pub fn map_meta<N, F: FnOnce(M) -> N>(self, f: F) -> PacketWithMeta<P, N> {
+        PacketWithMeta {
+            packet: self.packet,
+            meta: f(self.meta),
+        }
+    }
+}
+
+impl<P, M> core::ops::Deref for PacketWithMeta<P, M> {
+    type Target = P;
+
+    #[inline]
+    fn deref(&self) -> &P {
+        &self.packet
+    }
+}
+
+impl<P, M> core::ops::DerefMut for PacketWithMeta<P, M> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut P {
+        &mut self.packet
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn new_into_parts() {
+        let p = PacketWithMeta::new(1u8, "meta");
+        assert_eq!(1, p.packet);
+        assert_eq!("meta", p.meta);
+        assert_eq!((1, "meta"), p.into_parts());
+    }
+
+    #[test]
+    fn map_packet_meta() {
+        let p = PacketWithMeta::new(1u8, 2u16);
+        assert_eq!(
+            PacketWithMeta::new(3u32, 2u16),
+            p.clone().map_packet(|v| u32::from(v) + 2)
+        );
+        assert_eq!(
+            PacketWithMeta::new(1u8, 4u64),
+            p.map_meta(|v| u64::from(v) * 2)
+        );
+    }
+
+    #[test]
+    fn deref() {
+        let mut packet = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .udp(1, 2)
+            .write(&mut packet, &[1, 2])
+            .unwrap();
+
+        let mut p = PacketWithMeta::new(PacketHeaders::from_ethernet_slice(&packet).unwrap(), 7u32);
+        assert!(p.net.is_some());
+        assert_eq!(&[1, 2], p.payload.slice());
+
+        p.net = None;
+        assert!(p.packet.net.is_none());
+        assert_eq!(7, p.meta);
+    }
+
+    #[test]
+    fn debug_clone_eq_hash() {
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        let p = PacketWithMeta::new(1u8, 2u8);
+        assert_eq!("PacketWithMeta { packet: 1, meta: 2 }", format!("{:?}", p));
+        assert_eq!(p, p.clone());
+        let hash = |v: &PacketWithMeta<u8, u8>| {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&p), hash(&p.clone()));
+    }
+}
@@ -0,0 +1,73 @@
+/// Upper bounds for the work done when decoding a packet (see
+/// [`crate::SliceOptions::limits`]).
+///
+/// If one of the limits is exceeded decoding is aborted and an
+/// [`crate::err::packet::SliceError::ParseLimit`] error is returned.
+/// This allows to give a contractual bound for the work done per packet
+/// when decoding untrusted input. The limits are checked before a layer
+/// gets decoded (headers with a variable length are checked again after
+/// decoding). The default limits (`None`) don't restrict the decoding.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{err, ParseLimits, PacketBuilder, SliceOptions, SlicedPacket};
+///
+/// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234);
+/// let mut packet = Vec::with_capacity(builder.size(0));
+/// builder.write(&mut packet, &[]).unwrap();
+///
+/// let options = SliceOptions {
+///     limits: ParseLimits {
+///         // only allow ethernet & ip layer
+///         max_layers: Some(2),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// assert!(matches!(
+///     SlicedPacket::from_ethernet_with_options(&packet, options),
+///     Err(err::packet::SliceError::ParseLimit(
+///         err::ParseLimitError::MaxLayers { max_layers: 2, layer: err::Layer::UdpHeader }
+///     ))
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ParseLimits {
+    /// Maximum number of layers that are decoded (link header, each VLAN
+    /// header, IP header including extensions & transport header each
+    /// count as one layer).
+    pub max_layers: Option<usize>,
+
+    /// Maximum summed up length in bytes/octets of all decoded headers
+    /// (payloads are not counted as they are not examined).
+    pub max_header_len: Option<usize>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn default() {
+        let limits: ParseLimits = Default::default();
+        assert_eq!(None, limits.max_layers);
+        assert_eq!(None, limits.max_header_len);
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let limits = ParseLimits {
+            max_layers: Some(1),
+            max_header_len: Some(2),
+        };
+        assert_eq!(limits, limits.clone());
+        assert_eq!(
+            "ParseLimits { max_layers: Some(1), max_header_len: Some(2) }",
+            format!("{:?}", limits)
+        );
+    }
+}
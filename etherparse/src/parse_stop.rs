@@ -0,0 +1,61 @@
+use crate::*;
+
+/// Reason why the slicing of a packet stopped (see
+/// [`crate::SlicedPacket::stop_reason`]).
+///
+/// Allows to distinguish between payloads that are "actual" payloads
+/// of the last parsed layer (e.g. UDP payload) and payloads that
+/// are left over because the parser does not know how to decode
+/// the next layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseStop {
+    /// All layers were decoded (e.g. the transport layer was reached or
+    /// an IPv6 header signaled "no next header").
+    Completed,
+    /// The payload is identified by an ether type that is not supported
+    /// by the parser.
+    UnknownEtherType(EtherType),
+    /// The payload of a Linux Cooked Capture v1 (SLL) header has a
+    /// protocol type that is not an ether type.
+    UnknownLinuxSllProtocol(LinuxSllProtocolType),
+    /// More link extensions are present then can be stored in the
+    /// sliced packet (e.g. a third VLAN tag).
+    LinkExtLimit(EtherType),
+    /// The IP payload is identified by an IP number that is not
+    /// supported by the parser.
+    UnknownIpNumber(IpNumber),
+    /// The IP payload is fragmented and was not decoded (the IP
+    /// number identifies the protocol of the fragmented payload).
+    IpFragmented(IpNumber),
+}
+
+impl ParseStop {
+    /// Returns true if all layers of the packet were decoded.
+    #[inline]
+    pub fn is_completed(&self) -> bool {
+        matches!(self, ParseStop::Completed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn is_completed() {
+        assert!(ParseStop::Completed.is_completed());
+        assert!(false == ParseStop::UnknownEtherType(EtherType::ARP).is_completed());
+        assert!(false == ParseStop::IpFragmented(IpNumber::UDP).is_completed());
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = ParseStop::UnknownIpNumber(IpNumber(253));
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("UnknownIpNumber({:?})", IpNumber(253)),
+            format!("{:?}", value)
+        );
+    }
+}
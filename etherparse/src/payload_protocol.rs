@@ -0,0 +1,48 @@
+use crate::*;
+
+/// Protocol identifier of a payload that was not decoded by etherparse
+/// (see [`crate::SlicedPacket::payload_protocol`] &
+/// [`crate::PacketHeaders::payload_protocol`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PayloadProtocol {
+    /// Payload identified by an ether type (e.g. after an ethernet II or
+    /// VLAN header).
+    Ether(EtherType),
+    /// Payload identified by an IP number (e.g. after an IP header & the
+    /// IP extension headers).
+    Ip(IpNumber),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = PayloadProtocol::Ip(IpNumber::UDP);
+        assert_eq!(value, value.clone());
+        assert_eq!(format!("Ip({:?})", IpNumber::UDP), format!("{:?}", value));
+    }
+
+    #[test]
+    fn hash_ord() {
+        use core::cmp::Ordering;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let value = PayloadProtocol::Ether(EtherType::ARP);
+        let a_hash = {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let b_hash = {
+            let mut hasher = DefaultHasher::new();
+            value.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(a_hash, b_hash);
+        assert_eq!(value.cmp(&value.clone()), Ordering::Equal);
+    }
+}
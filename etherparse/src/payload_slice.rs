@@ -1,7 +1,11 @@
 use crate::*;
 
 /// Payload together with an identifier the type of content.
+///
+/// New payload types can be added in future versions, so matches need
+/// a wildcard arm.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum PayloadSlice<'a> {
     /// Payload with it's type identified by an ether type number
     /// (e.g. after an ethernet II or vlan header).
@@ -19,6 +23,9 @@ pub enum PayloadSlice<'a> {
     /// Payload part of an ICMP V4 message. Check [`crate::Icmpv6Type`]
     /// for a description what will be part of the payload.
     Icmpv6(&'a [u8]),
+    /// Payload of a Linux Cooked Capture v1 (SLL) header that is not
+    /// identified by an ether type.
+    LinuxSll(LinuxSllPayloadSlice<'a>),
 }
 
 impl<'a> PayloadSlice<'a> {
@@ -30,6 +37,7 @@ impl<'a> PayloadSlice<'a> {
             PayloadSlice::Tcp(s) => s,
             PayloadSlice::Icmpv4(s) => s,
             PayloadSlice::Icmpv6(s) => s,
+            PayloadSlice::LinuxSll(s) => s.payload,
         }
     }
 }
@@ -99,5 +107,13 @@ mod test {
         assert_eq!(Tcp(&payload).slice(), &payload);
         assert_eq!(Icmpv4(&payload).slice(), &payload);
         assert_eq!(Icmpv6(&payload).slice(), &payload);
+        assert_eq!(
+            LinuxSll(LinuxSllPayloadSlice {
+                protocol_type: LinuxSllProtocolType::NetlinkProtocolType(1),
+                payload: &payload
+            })
+            .slice(),
+            &payload
+        );
     }
 }
@@ -0,0 +1,408 @@
+use super::FlowKey;
+use crate::{ether_type, ip_number, EtherType, IpNumber};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Maximum number of IPv6 extension headers that are skipped before giving up.
+const MAX_IPV6_EXTS: usize = 8;
+
+/// Extracts the IP addresses, transport protocol & ports from a packet
+/// starting with an Ethernet II header.
+///
+/// This is a "fast path" for filtering that only performs the bounds checks
+/// needed to read the returned fields. In contrast to
+/// [`crate::SlicedPacket::from_ethernet`] no header is validated:
+///
+/// * Up to two VLAN headers are skipped.
+/// * IPv4 options are skipped (based on the IHL field) without reading them
+///   & the total length & checksum are ignored.
+/// * IPv6 hop-by-hop, destination options, routing, fragment & authentication
+///   extension headers are skipped (at most 8). Any other extension header
+///   results in `None`.
+/// * Only TCP & UDP are supported. The TCP or UDP payload is not touched.
+///
+/// `None` is returned if the packet is not an IPv4 or IPv6 packet containing a
+/// TCP or UDP header, if the packet is too short or if the packet is a non first
+/// fragment (first fragments still contain the ports and are returned).
+///
+/// # Example
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr};
+/// use etherparse::{quick::extract_flow_from_ethernet, IpNumber, PacketBuilder};
+///
+/// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234);
+/// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+/// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+///
+/// let flow = extract_flow_from_ethernet(&packet).unwrap();
+/// assert_eq!(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)), flow.source_addr);
+/// assert_eq!(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)), flow.destination_addr);
+/// assert_eq!(IpNumber::UDP, flow.ip_number);
+/// assert_eq!(21, flow.source_port);
+/// assert_eq!(1234, flow.destination_port);
+/// ```
+pub fn extract_flow_from_ethernet(packet: &[u8]) -> Option<FlowKey> {
+    // ethernet II header
+    let mut ether_type = EtherType(read_u16(packet, 12)?);
+    let mut offset = 14;
+
+    // vlan headers
+    for _ in 0..2 {
+        match ether_type {
+            _ if ether_type.is_vlan_tag() => {
+                ether_type = EtherType(read_u16(packet, offset + 2)?);
+                offset += 4;
+            }
+            _ => break,
+        }
+    }
+
+    match ether_type {
+        ether_type::IPV4 => extract_ipv4(packet.get(offset..)?),
+        ether_type::IPV6 => extract_ipv6(packet.get(offset..)?),
+        _ => None,
+    }
+}
+
+fn extract_ipv4(ip: &[u8]) -> Option<FlowKey> {
+    let fixed: &[u8; 20] = ip.get(..20)?.try_into().ok()?;
+    if 4 != fixed[0] >> 4 {
+        return None;
+    }
+    // non first fragments contain no ports
+    if 0 != u16::from_be_bytes([fixed[6] & 0b0001_1111, fixed[7]]) {
+        return None;
+    }
+    let ihl = usize::from(fixed[0] & 0b0000_1111) * 4;
+    if ihl < 20 {
+        return None;
+    }
+    extract_ports(
+        IpAddr::V4(Ipv4Addr::new(fixed[12], fixed[13], fixed[14], fixed[15])),
+        IpAddr::V4(Ipv4Addr::new(fixed[16], fixed[17], fixed[18], fixed[19])),
+        IpNumber(fixed[9]),
+        ip,
+        ihl,
+    )
+}
+
+fn extract_ipv6(ip: &[u8]) -> Option<FlowKey> {
+    let fixed: &[u8; 40] = ip.get(..40)?.try_into().ok()?;
+    if 6 != fixed[0] >> 4 {
+        return None;
+    }
+    let source: [u8; 16] = fixed[8..24].try_into().ok()?;
+    let destination: [u8; 16] = fixed[24..40].try_into().ok()?;
+
+    // skip extension headers
+    let mut next_header = IpNumber(fixed[6]);
+    let mut offset = 40;
+    for _ in 0..MAX_IPV6_EXTS {
+        match next_header {
+            ip_number::IPV6_HOP_BY_HOP | ip_number::IPV6_DEST_OPTIONS | ip_number::IPV6_ROUTE => {
+                let ext = ip.get(offset..offset + 2)?;
+                next_header = IpNumber(ext[0]);
+                offset += (usize::from(ext[1]) + 1) * 8;
+            }
+            ip_number::IPV6_FRAG => {
+                let ext = ip.get(offset..offset + 4)?;
+                // non first fragments contain no ports
+                if 0 != u16::from_be_bytes([ext[2], ext[3]]) >> 3 {
+                    return None;
+                }
+                next_header = IpNumber(ext[0]);
+                offset += 8;
+            }
+            ip_number::AUTH => {
+                let ext = ip.get(offset..offset + 2)?;
+                next_header = IpNumber(ext[0]);
+                offset += (usize::from(ext[1]) + 2) * 4;
+            }
+            _ => break,
+        }
+    }
+
+    extract_ports(
+        IpAddr::V6(Ipv6Addr::from(source)),
+        IpAddr::V6(Ipv6Addr::from(destination)),
+        next_header,
+        ip,
+        offset,
+    )
+}
+
+/// Reads the ports of a TCP or UDP header starting at `offset`.
+#[inline]
+fn extract_ports(
+    source_addr: IpAddr,
+    destination_addr: IpAddr,
+    ip_number: IpNumber,
+    ip: &[u8],
+    offset: usize,
+) -> Option<FlowKey> {
+    // the header length is checked to avoid reporting ports
+    // of truncated headers
+    let header_len = match ip_number {
+        ip_number::TCP => 20,
+        ip_number::UDP => 8,
+        _ => return None,
+    };
+    let ports = ip.get(offset..offset.checked_add(header_len)?)?;
+    Some(FlowKey {
+        source_addr,
+        destination_addr,
+        ip_number,
+        source_port: u16::from_be_bytes([ports[0], ports[1]]),
+        destination_port: u16::from_be_bytes([ports[2], ports[3]]),
+    })
+}
+
+/// Reads a big endian u16 at the given offset.
+#[inline]
+fn read_u16(slice: &[u8], offset: usize) -> Option<u16> {
+    let bytes = slice.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    use crate::test_gens::*;
+    use crate::*;
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    #[cfg(feature = "ipv6")]
+    /// Flow key based on the result of the full slicing.
+    fn expected_flow(packet: &[u8]) -> Option<FlowKey> {
+        let sliced = SlicedPacket::from_ethernet(packet).ok()?;
+        let (source_addr, destination_addr) = match sliced.net? {
+            NetSlice::Ipv4(ipv4) => (
+                IpAddr::V4(ipv4.header().source_addr()),
+                IpAddr::V4(ipv4.header().destination_addr()),
+            ),
+            NetSlice::Ipv6(ipv6) => (
+                IpAddr::V6(ipv6.header().source_addr()),
+                IpAddr::V6(ipv6.header().destination_addr()),
+            ),
+        };
+        match sliced.transport? {
+            TransportSlice::Udp(udp) => Some(FlowKey {
+                source_addr,
+                destination_addr,
+                ip_number: IpNumber::UDP,
+                source_port: udp.source_port(),
+                destination_port: udp.destination_port(),
+            }),
+            TransportSlice::Tcp(tcp) => Some(FlowKey {
+                source_addr,
+                destination_addr,
+                ip_number: IpNumber::TCP,
+                source_port: tcp.source_port(),
+                destination_port: tcp.destination_port(),
+            }),
+            _ => None,
+        }
+    }
+
+    proptest! {
+        #[cfg(all(feature = "ipv6", feature = "tcp"))]
+        #[test]
+        fn matches_sliced_packet(
+            eth in ethernet_2_unknown(),
+            vlan_outer in vlan_single_unknown(),
+            vlan_inner in vlan_single_unknown(),
+            ipv4 in ipv4_unknown(),
+            ipv6 in ipv6_unknown(),
+            udp in udp_any(),
+            tcp in tcp_any(),
+            vlan_count in 0usize..3,
+            use_ipv6 in any::<bool>(),
+            use_tcp in any::<bool>(),
+        ) {
+            let payload = [1, 2, 3, 4];
+            let transport_len = if use_tcp { tcp.header_len() } else { udp.header_len() } + payload.len();
+            let ip_number = if use_tcp { IpNumber::TCP } else { IpNumber::UDP };
+
+            let mut packet = Vec::new();
+            {
+                let mut eth = eth.clone();
+                let mut vlan_outer = vlan_outer.clone();
+                let mut vlan_inner = vlan_inner.clone();
+                let ip_ether_type = if use_ipv6 { EtherType::IPV6 } else { EtherType::IPV4 };
+                match vlan_count {
+                    0 => eth.ether_type = ip_ether_type,
+                    1 => {
+                        eth.ether_type = EtherType::VLAN_TAGGED_FRAME;
+                        vlan_outer.ether_type = ip_ether_type;
+                    }
+                    _ => {
+                        eth.ether_type = EtherType::PROVIDER_BRIDGING;
+                        vlan_outer.ether_type = EtherType::VLAN_TAGGED_FRAME;
+                        vlan_inner.ether_type = ip_ether_type;
+                    }
+                }
+                packet.extend_from_slice(&eth.to_bytes());
+                if vlan_count > 0 {
+                    packet.extend_from_slice(&vlan_outer.to_bytes());
+                }
+                if vlan_count > 1 {
+                    packet.extend_from_slice(&vlan_inner.to_bytes());
+                }
+            }
+            if use_ipv6 {
+                let mut ipv6 = ipv6.clone();
+                ipv6.next_header = ip_number;
+                ipv6.payload_length = transport_len as u16;
+                ipv6.write(&mut packet).unwrap();
+            } else {
+                let mut ipv4 = ipv4.clone();
+                ipv4.protocol = ip_number;
+                ipv4.more_fragments = false;
+                ipv4.fragment_offset = IpFragOffset::ZERO;
+                ipv4.set_payload_len(transport_len).unwrap();
+                ipv4.write(&mut packet).unwrap();
+            }
+            if use_tcp {
+                tcp.write(&mut packet).unwrap();
+            } else {
+                let mut udp = udp.clone();
+                udp.length = (UdpHeader::LEN + payload.len()) as u16;
+                udp.write(&mut packet).unwrap();
+            }
+            packet.extend_from_slice(&payload);
+
+            let expected = expected_flow(&packet);
+            prop_assert!(expected.is_some());
+            prop_assert_eq!(expected, extract_flow_from_ethernet(&packet));
+
+            // truncated packets (only the minimal tcp header length is checked)
+            let min_len = packet.len() - payload.len() - if use_tcp {
+                tcp.header_len() - TcpHeader::MIN_LEN
+            } else {
+                0
+            };
+            for len in 0..min_len {
+                prop_assert_eq!(None, extract_flow_from_ethernet(&packet[..len]));
+            }
+            prop_assert_eq!(expected, extract_flow_from_ethernet(&packet[..min_len]));
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn ipv6_extensions() {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([1; 16], [2; 16], 20)
+            .udp(1234, 53);
+        let mut packet = Vec::new();
+        builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+
+        // insert hop by hop, routing, fragment & auth headers
+        let (exts, first_next_header) = Ipv6Extensions::builder()
+            .hop_by_hop(Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[0; 6]).unwrap())
+            .routing(Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[0; 14]).unwrap())
+            .fragment(Ipv6FragmentHeader::new(
+                IpNumber::UDP,
+                IpFragOffset::ZERO,
+                false,
+                1,
+            ))
+            .auth(IpAuthHeader::new(IpNumber::UDP, 1, 2, &[0; 4]).unwrap())
+            .build(IpNumber::UDP)
+            .unwrap();
+        let mut exts_bytes = Vec::new();
+        exts.write(&mut exts_bytes, first_next_header).unwrap();
+        let mut with_exts = Vec::new();
+        with_exts.extend_from_slice(&packet[..14 + 40]);
+        with_exts.extend_from_slice(&exts_bytes);
+        with_exts.extend_from_slice(&packet[14 + 40..]);
+        with_exts[14 + 6] = first_next_header.0;
+        let payload_len = (exts_bytes.len() + UdpHeader::LEN + 4) as u16;
+        with_exts[14 + 4..14 + 6].copy_from_slice(&payload_len.to_be_bytes());
+
+        let expected = Some(FlowKey {
+            source_addr: IpAddr::V6(Ipv6Addr::from([1; 16])),
+            destination_addr: IpAddr::V6(Ipv6Addr::from([2; 16])),
+            ip_number: IpNumber::UDP,
+            source_port: 1234,
+            destination_port: 53,
+        });
+        assert_eq!(expected, expected_flow(&with_exts));
+        assert_eq!(expected, extract_flow_from_ethernet(&with_exts));
+
+        // non first fragment
+        {
+            let frag_start = 14 + 40 + 8 + 16;
+            let mut data = with_exts.clone();
+            data[frag_start + 3] = 1 << 3;
+            assert_eq!(None, extract_flow_from_ethernet(&data));
+        }
+
+        // unknown extension header
+        {
+            let mut data = with_exts.clone();
+            data[14 + 6] = IpNumber::IPV6_NO_NEXT_HEADER.0;
+            assert_eq!(None, extract_flow_from_ethernet(&data));
+        }
+    }
+
+    #[test]
+    fn not_supported() {
+        // non first ipv4 fragment
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1234, 53);
+            let mut packet = Vec::new();
+            builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+            assert!(extract_flow_from_ethernet(&packet).is_some());
+
+            packet[14 + 7] = 1;
+            assert_eq!(None, extract_flow_from_ethernet(&packet));
+        }
+
+        // icmp
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2);
+            let mut packet = Vec::new();
+            builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+            assert_eq!(None, extract_flow_from_ethernet(&packet));
+        }
+
+        // bad ip version & ihl
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1234, 53);
+            let mut packet = Vec::new();
+            builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+
+            let mut data = packet.clone();
+            data[14] = 0x65;
+            assert_eq!(None, extract_flow_from_ethernet(&data));
+
+            let mut data = packet.clone();
+            data[14] = 0x44;
+            assert_eq!(None, extract_flow_from_ethernet(&data));
+        }
+
+        // unknown ether type
+        {
+            let eth = Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [7, 8, 9, 10, 11, 12],
+                ether_type: EtherType::ARP,
+            };
+            let mut packet = Vec::new();
+            packet.extend_from_slice(&eth.to_bytes());
+            packet.extend_from_slice(&[0; 40]);
+            assert_eq!(None, extract_flow_from_ethernet(&packet));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{hash::Fnv1a32, IpNumber, Ipv4Network, Ipv6Network};
+use core::net::IpAddr;
+
+/// Addresses, ports & transport protocol of a TCP or UDP packet
+/// (returned by [`crate::quick::extract_flow_from_ethernet`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct FlowKey {
+    /// Source IP address.
+    pub source_addr: IpAddr,
+    /// Destination IP address.
+    pub destination_addr: IpAddr,
+    /// Transport protocol ([`IpNumber::TCP`] or [`IpNumber::UDP`]).
+    pub ip_number: IpNumber,
+    /// Source port of the TCP or UDP header.
+    pub source_port: u16,
+    /// Destination port of the TCP or UDP header.
+    pub destination_port: u16,
+}
+
+impl FlowKey {
+    /// Returns the flow key with source & destination swapped (e.g. to
+    /// match the responses of a flow).
+    #[inline]
+    pub fn reversed(&self) -> FlowKey {
+        FlowKey {
+            source_addr: self.destination_addr,
+            destination_addr: self.source_addr,
+            ip_number: self.ip_number,
+            source_port: self.destination_port,
+            destination_port: self.source_port,
+        }
+    }
+
+    /// Returns a hash of the flow key that is identical for both
+    /// directions of a flow (`key.hash_symmetric() == key.reversed().hash_symmetric()`).
+    ///
+    /// The hash is the 32 bit FNV-1a hash (see [`crate::hash::Fnv1a32`])
+    /// over the following bytes:
+    ///
+    /// 1. The "lower" endpoint address (4 bytes for IPv4, 16 bytes for IPv6).
+    /// 2. The "higher" endpoint address.
+    /// 3. The IP number (1 byte).
+    /// 4. The "lower" endpoint port (2 bytes big endian).
+    /// 5. The "higher" endpoint port (2 bytes big endian).
+    ///
+    /// The endpoints (address & port) are ordered by comparing the address
+    /// bytes first & the ports second. The algorithm is part of the API and
+    /// will not change between versions, so simulations based on the hash
+    /// are reproducible.
+    ///
+    /// ```
+    /// use core::net::{IpAddr, Ipv4Addr};
+    /// use etherparse::{quick::FlowKey, IpNumber};
+    ///
+    /// let key = FlowKey {
+    ///     source_addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+    ///     destination_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+    ///     ip_number: IpNumber::UDP,
+    ///     source_port: 1234,
+    ///     destination_port: 53,
+    /// };
+    /// assert_eq!(key.hash_symmetric(), key.reversed().hash_symmetric());
+    /// ```
+    pub fn hash_symmetric(&self) -> u32 {
+        fn octets(addr: &IpAddr) -> ([u8; 16], usize) {
+            let mut result = [0u8; 16];
+            match addr {
+                IpAddr::V4(a) => {
+                    result[..4].copy_from_slice(&a.octets());
+                    (result, 4)
+                }
+                IpAddr::V6(a) => (a.octets(), 16),
+            }
+        }
+        let (source, source_len) = octets(&self.source_addr);
+        let (destination, destination_len) = octets(&self.destination_addr);
+        let source = (&source[..source_len], self.source_port);
+        let destination = (&destination[..destination_len], self.destination_port);
+        let (lower, higher) = if source <= destination {
+            (source, destination)
+        } else {
+            (destination, source)
+        };
+        Fnv1a32::new()
+            .add_slice(lower.0)
+            .add_slice(higher.0)
+            .add_slice(&[self.ip_number.0])
+            .add_slice(&lower.1.to_be_bytes())
+            .add_slice(&higher.1.to_be_bytes())
+            .value()
+    }
+
+    /// Returns true if the source or the destination address is part of the
+    /// given IPv4 network (same as the `net` primitive of pcap filters).
+    ///
+    /// IPv6 flows never match.
+    ///
+    /// ```
+    /// use core::net::{IpAddr, Ipv4Addr};
+    /// use etherparse::{quick::FlowKey, IpNumber, Ipv4Network};
+    ///
+    /// let key = FlowKey {
+    ///     source_addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+    ///     destination_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+    ///     ip_number: IpNumber::UDP,
+    ///     source_port: 1234,
+    ///     destination_port: 53,
+    /// };
+    /// let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    /// assert!(key.is_in_ipv4_network(&network));
+    /// ```
+    pub fn is_in_ipv4_network(&self, network: &Ipv4Network) -> bool {
+        let contains = |addr: &IpAddr| match addr {
+            IpAddr::V4(a) => network.contains(*a),
+            IpAddr::V6(_) => false,
+        };
+        contains(&self.source_addr) || contains(&self.destination_addr)
+    }
+
+    /// Returns true if the source or the destination address is part of the
+    /// given IPv6 network (same as the `net` primitive of pcap filters).
+    ///
+    /// IPv4 flows never match.
+    pub fn is_in_ipv6_network(&self, network: &Ipv6Network) -> bool {
+        let contains = |addr: &IpAddr| match addr {
+            IpAddr::V4(_) => false,
+            IpAddr::V6(a) => network.contains(*a),
+        };
+        contains(&self.source_addr) || contains(&self.destination_addr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn reversed() {
+        let key = FlowKey {
+            source_addr: IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+            destination_addr: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ip_number: IpNumber::UDP,
+            source_port: 1234,
+            destination_port: 53,
+        };
+        assert_eq!(
+            FlowKey {
+                source_addr: IpAddr::V6(Ipv6Addr::LOCALHOST),
+                destination_addr: IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+                ip_number: IpNumber::UDP,
+                source_port: 53,
+                destination_port: 1234,
+            },
+            key.reversed()
+        );
+        assert_eq!(key, key.reversed().reversed());
+    }
+
+    #[test]
+    fn hash_symmetric() {
+        let v4 = FlowKey {
+            source_addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+            destination_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            ip_number: IpNumber::UDP,
+            source_port: 1234,
+            destination_port: 53,
+        };
+        assert_eq!(v4.hash_symmetric(), v4.reversed().hash_symmetric());
+        assert_eq!(
+            Fnv1a32::new()
+                .add_slice(&[10, 0, 0, 1, 192, 168, 1, 2, 17, 0, 53, 4, 210])
+                .value(),
+            v4.hash_symmetric()
+        );
+        // the hash values are part of the API & must not change between versions
+        assert_eq!(0x7fd0_191f, v4.hash_symmetric());
+
+        // same addresses are ordered by port
+        let same_addr = FlowKey {
+            source_addr: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            destination_addr: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ip_number: IpNumber::TCP,
+            source_port: 80,
+            destination_port: 8080,
+        };
+        assert_eq!(
+            same_addr.hash_symmetric(),
+            same_addr.reversed().hash_symmetric()
+        );
+        assert_eq!(
+            Fnv1a32::new()
+                .add_slice(&Ipv6Addr::LOCALHOST.octets())
+                .add_slice(&Ipv6Addr::LOCALHOST.octets())
+                .add_slice(&[6, 0, 80, 0x1f, 0x90])
+                .value(),
+            same_addr.hash_symmetric()
+        );
+
+        // different ports result in a different hash
+        let mut other = v4;
+        other.source_port = 1235;
+        assert_ne!(v4.hash_symmetric(), other.hash_symmetric());
+    }
+
+    #[test]
+    fn is_in_ipv4_network() {
+        let key = FlowKey {
+            source_addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+            destination_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            ip_number: IpNumber::UDP,
+            source_port: 1234,
+            destination_port: 53,
+        };
+        let source_net = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        let destination_net = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        let other_net = Ipv4Network::new(Ipv4Addr::new(172, 16, 0, 0), 12).unwrap();
+        assert!(key.is_in_ipv4_network(&source_net));
+        assert!(key.is_in_ipv4_network(&destination_net));
+        assert!(false == key.is_in_ipv4_network(&other_net));
+        assert!(
+            false == key.is_in_ipv6_network(&Ipv6Network::new(Ipv6Addr::UNSPECIFIED, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn is_in_ipv6_network() {
+        let key = FlowKey {
+            source_addr: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+            destination_addr: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ip_number: IpNumber::TCP,
+            source_port: 1234,
+            destination_port: 80,
+        };
+        let source_net =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        let destination_net = Ipv6Network::new(Ipv6Addr::LOCALHOST, 128).unwrap();
+        let other_net = Ipv6Network::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10).unwrap();
+        assert!(key.is_in_ipv6_network(&source_net));
+        assert!(key.is_in_ipv6_network(&destination_net));
+        assert!(false == key.is_in_ipv6_network(&other_net));
+        assert!(
+            false == key.is_in_ipv4_network(&Ipv4Network::new(Ipv4Addr::UNSPECIFIED, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let key = FlowKey {
+            source_addr: IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+            destination_addr: IpAddr::V4(Ipv4Addr::new(5, 6, 7, 8)),
+            ip_number: IpNumber::TCP,
+            source_port: 1234,
+            destination_port: 80,
+        };
+        assert_eq!(key, key.clone());
+        assert_eq!(
+            format!(
+                "FlowKey {{ source_addr: 1.2.3.4, destination_addr: 5.6.7.8, ip_number: {:?}, source_port: 1234, destination_port: 80 }}",
+                IpNumber::TCP
+            ),
+            format!("{:?}", key)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod flow_key;
+pub use flow_key::*;
+
+mod extract_flow;
+pub use extract_flow::*;
@@ -0,0 +1,184 @@
+/// Port of the Secure Shell protocol (TCP).
+pub const SSH: u16 = 22;
+/// Port of the Telnet protocol (TCP).
+pub const TELNET: u16 = 23;
+/// Port of the Simple Mail Transfer Protocol (TCP).
+pub const SMTP: u16 = 25;
+/// Port of the Domain Name System (UDP & TCP).
+pub const DNS: u16 = 53;
+/// Port of the DHCP server (UDP).
+pub const DHCP_SERVER: u16 = 67;
+/// Port of the DHCP client (UDP).
+pub const DHCP_CLIENT: u16 = 68;
+/// Port of the Trivial File Transfer Protocol (UDP).
+pub const TFTP: u16 = 69;
+/// Port of the Hypertext Transfer Protocol (TCP).
+pub const HTTP: u16 = 80;
+/// Port of the Network Time Protocol (UDP).
+pub const NTP: u16 = crate::NtpPacketSlice::UDP_PORT;
+/// Port of the Simple Network Management Protocol (UDP).
+pub const SNMP: u16 = 161;
+/// Port of SNMP traps (UDP).
+pub const SNMP_TRAP: u16 = 162;
+/// Port of the Border Gateway Protocol (TCP).
+pub const BGP: u16 = 179;
+/// Port of HTTP over TLS (TCP) & QUIC (UDP).
+pub const HTTPS: u16 = 443;
+/// Port of the Internet Security Association and Key Management Protocol
+/// (IKE, UDP).
+pub const ISAKMP: u16 = 500;
+/// Port of the syslog protocol (UDP).
+pub const SYSLOG: u16 = 514;
+/// Port of the RADIUS authentication protocol (UDP).
+pub const RADIUS: u16 = 1812;
+/// Port of the RADIUS accounting protocol (UDP).
+pub const RADIUS_ACCOUNTING: u16 = 1813;
+/// Port of the GPRS Tunneling Protocol control plane (GTP-C, UDP).
+pub const GTP_C: u16 = 2123;
+/// Port of the GPRS Tunneling Protocol user plane (GTP-U, UDP).
+pub const GTP_U: u16 = 2152;
+/// Port of the Teredo server (IPv6 over UDP, UDP).
+pub const TEREDO: u16 = 3544;
+/// Port of IPsec NAT traversal (UDP).
+pub const IPSEC_NAT_T: u16 = 4500;
+/// Port of the Virtual eXtensible Local Area Network protocol (VXLAN, UDP).
+pub const VXLAN: u16 = 4789;
+/// Port of multicast DNS (UDP).
+pub const MDNS: u16 = 5353;
+/// Port of the Generic Network Virtualization Encapsulation (Geneve, UDP).
+pub const GENEVE: u16 = 6081;
+/// Port of the TaZmen Sniffer Protocol (TZSP, UDP).
+pub const TZSP: u16 = crate::TzspSlice::DEFAULT_UDP_PORT;
+/// Default port of WireGuard (UDP).
+pub const WIREGUARD: u16 = 51820;
+
+/// Range of the dynamic/private ports that are used as ephemeral (client)
+/// ports (49152 to 65535 as recommended by RFC 6335).
+pub const EPHEMERAL_PORTS: core::ops::RangeInclusive<u16> = 49152..=65535;
+
+/// Maps a counter (or random number) to a port in the [`EPHEMERAL_PORTS`]
+/// range (wrapping around after 16384 ports).
+///
+/// ```
+/// use etherparse::services;
+///
+/// assert_eq!(49152, services::ephemeral_port(0));
+/// assert_eq!(49153, services::ephemeral_port(1));
+/// assert_eq!(65535, services::ephemeral_port(16383));
+/// assert_eq!(49152, services::ephemeral_port(16384));
+/// ```
+#[inline]
+pub const fn ephemeral_port(index: u32) -> u16 {
+    *EPHEMERAL_PORTS.start() + (index % (1 << 14)) as u16
+}
+
+/// Returns the name of the service registered for the given UDP port
+/// (only a selection of well-known services is known).
+pub const fn udp_service_name(port: u16) -> Option<&'static str> {
+    match port {
+        DNS => Some("dns"),
+        DHCP_SERVER => Some("dhcp-server"),
+        DHCP_CLIENT => Some("dhcp-client"),
+        TFTP => Some("tftp"),
+        NTP => Some("ntp"),
+        SNMP => Some("snmp"),
+        SNMP_TRAP => Some("snmp-trap"),
+        HTTPS => Some("quic"),
+        ISAKMP => Some("isakmp"),
+        SYSLOG => Some("syslog"),
+        RADIUS => Some("radius"),
+        RADIUS_ACCOUNTING => Some("radius-acct"),
+        GTP_C => Some("gtpc"),
+        GTP_U => Some("gtpu"),
+        TEREDO => Some("teredo"),
+        IPSEC_NAT_T => Some("ipsec-nat-t"),
+        VXLAN => Some("vxlan"),
+        MDNS => Some("mdns"),
+        GENEVE => Some("geneve"),
+        TZSP => Some("tzsp"),
+        WIREGUARD => Some("wireguard"),
+        _ => None,
+    }
+}
+
+/// Returns the name of the service registered for the given TCP port
+/// (only a selection of well-known services is known).
+pub const fn tcp_service_name(port: u16) -> Option<&'static str> {
+    match port {
+        SSH => Some("ssh"),
+        TELNET => Some("telnet"),
+        SMTP => Some("smtp"),
+        DNS => Some("dns"),
+        HTTP => Some("http"),
+        BGP => Some("bgp"),
+        HTTPS => Some("https"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn udp_service_name() {
+        let tests = [
+            (53, Some("dns")),
+            (67, Some("dhcp-server")),
+            (68, Some("dhcp-client")),
+            (69, Some("tftp")),
+            (123, Some("ntp")),
+            (161, Some("snmp")),
+            (162, Some("snmp-trap")),
+            (443, Some("quic")),
+            (500, Some("isakmp")),
+            (514, Some("syslog")),
+            (1812, Some("radius")),
+            (1813, Some("radius-acct")),
+            (2123, Some("gtpc")),
+            (2152, Some("gtpu")),
+            (3544, Some("teredo")),
+            (4500, Some("ipsec-nat-t")),
+            (4789, Some("vxlan")),
+            (5353, Some("mdns")),
+            (6081, Some("geneve")),
+            (37008, Some("tzsp")),
+            (51820, Some("wireguard")),
+            (22, None),
+            (80, None),
+            (0, None),
+        ];
+        for (port, expected) in tests {
+            assert_eq!(expected, super::udp_service_name(port));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ephemeral_port(index in any::<u32>()) {
+            let port = super::ephemeral_port(index);
+            assert!(EPHEMERAL_PORTS.contains(&port));
+            assert_eq!(u32::from(port - 49152), index % 16384);
+        }
+    }
+
+    #[test]
+    fn tcp_service_name() {
+        let tests = [
+            (22, Some("ssh")),
+            (23, Some("telnet")),
+            (25, Some("smtp")),
+            (53, Some("dns")),
+            (80, Some("http")),
+            (179, Some("bgp")),
+            (443, Some("https")),
+            (123, None),
+            (4789, None),
+            (0, None),
+        ];
+        for (port, expected) in tests {
+            assert_eq!(expected, super::tcp_service_name(port));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{err::BufSpaceError, PacketBuf};
+
+/// [`PacketBuf`] writing into a fixed mutable slice (keeps track of the
+/// write position).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{PacketBuilder, SliceBuf};
+///
+/// let mut memory = [0u8; 1500];
+/// let mut buf = SliceBuf::new(&mut memory);
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234)
+///     .build_into(&mut buf, &[1, 2, 3, 4])
+///     .unwrap();
+/// assert_eq!(14 + 20 + 8 + 4, buf.len());
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct SliceBuf<'a> {
+    slice: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceBuf<'a> {
+    /// Creates an empty buffer writing to the given slice.
+    #[inline]
+    pub fn new(slice: &'a mut [u8]) -> SliceBuf<'a> {
+        SliceBuf { slice, len: 0 }
+    }
+
+    /// Number of bytes written to the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no bytes have been written to the buffer.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        0 == self.len
+    }
+
+    /// Number of bytes that can still be written to the buffer.
+    #[inline]
+    pub fn remaining_len(&self) -> usize {
+        self.slice.len() - self.len
+    }
+
+    /// Clears the written data (resets the write position to the start).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Consumes the buffer & returns the part of the slice that
+    /// was written to.
+    #[inline]
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.slice[..self.len]
+    }
+}
+
+impl PacketBuf for SliceBuf<'_> {
+    #[inline]
+    fn reserve(&mut self, additional: usize) -> Result<(), BufSpaceError> {
+        if self.remaining_len() < additional {
+            Err(BufSpaceError {
+                required_len: additional,
+                available_len: self.remaining_len(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<(), BufSpaceError> {
+        self.reserve(data.len())?;
+        self.slice[self.len..self.len + data.len()].copy_from_slice(data);
+        self.len += data.len();
+        Ok(())
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u8] {
+        &self.slice[..self.len]
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.slice[..self.len]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn new_len() {
+        let mut memory = [0u8; 4];
+        let mut buf = SliceBuf::new(&mut memory);
+        assert_eq!(0, buf.len());
+        assert!(buf.is_empty());
+        assert_eq!(4, buf.remaining_len());
+
+        buf.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(3, buf.len());
+        assert!(false == buf.is_empty());
+        assert_eq!(1, buf.remaining_len());
+        assert_eq!(&[1, 2, 3], buf.as_slice());
+
+        buf.clear();
+        assert!(buf.is_empty());
+        assert_eq!(4, buf.remaining_len());
+    }
+
+    #[test]
+    fn reserve_extend() {
+        let mut memory = [0u8; 4];
+        let mut buf = SliceBuf::new(&mut memory);
+        buf.reserve(4).unwrap();
+        buf.extend_from_slice(&[1, 2]).unwrap();
+        assert_eq!(
+            Err(BufSpaceError {
+                required_len: 3,
+                available_len: 2
+            }),
+            buf.reserve(3)
+        );
+        assert_eq!(
+            Err(BufSpaceError {
+                required_len: 3,
+                available_len: 2
+            }),
+            buf.extend_from_slice(&[3, 4, 5])
+        );
+        buf.as_mut_slice()[0] = 9;
+        assert_eq!(&[9, 2], buf.as_slice());
+        assert_eq!(&[9, 2], buf.into_written());
+        assert_eq!([9, 2, 0, 0], memory);
+    }
+
+    #[test]
+    fn debug_eq() {
+        let mut memory = [0u8; 1];
+        let buf = SliceBuf::new(&mut memory);
+        assert_eq!("SliceBuf { slice: [0], len: 0 }", format!("{:?}", buf));
+        let mut memory2 = [0u8; 1];
+        assert_eq!(buf, SliceBuf::new(&mut memory2));
+    }
+}
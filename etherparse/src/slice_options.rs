@@ -0,0 +1,90 @@
+use crate::ParseLimits;
+
+/// Options that change the behavior of the composite slicing functions
+/// (e.g. [`crate::SlicedPacket::from_ethernet_with_options`]).
+///
+/// The default options result in the same behavior as the functions
+/// without options (e.g. [`crate::SlicedPacket::from_ethernet`]).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::SliceOptions;
+///
+/// let options = SliceOptions {
+///     first_fragment_transport: true,
+///     ..Default::default()
+/// };
+/// assert!(options.first_fragment_transport);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SliceOptions {
+    /// If set to true the transport header (UDP, TCP, ICMPv4 or ICMPv6)
+    /// is also decoded for the first fragment of a fragmented IP packet
+    /// (fragment offset of 0).
+    ///
+    /// The transport header is decoded on a "best effort" basis. If the
+    /// transport header is not completely contained in the first fragment
+    /// (or can not be decoded) no error is returned and the `transport`
+    /// field is left empty. As the payload of a fragment only contains a
+    /// part of the transport payload, lengths & checksums contained in
+    /// the transport header can not be verified. Transport headers
+    /// decoded from a first fragment can be identified via
+    /// [`crate::SlicedPacket::is_transport_from_first_fragment`].
+    ///
+    /// Non first fragments (fragment offset not 0) never contain a
+    /// transport header and are never decoded.
+    pub first_fragment_transport: bool,
+
+    /// If set to true the order of the VLAN tags & the MACsec SecTAG is
+    /// validated against the rules of IEEE 802.1Q & IEEE 802.1AE and
+    /// violations are reported as [`crate::err::packet::SliceError::TagOrder`]
+    /// errors.
+    ///
+    /// The following orders are reported as errors:
+    ///
+    /// * A MACsec SecTAG after a VLAN tag (the SecTAG has to directly
+    ///   follow the MAC addresses).
+    /// * A C-TAG (`0x8100`) followed by another VLAN tag.
+    /// * An S-TAG (`0x88A8` or `0x9100`) after another VLAN tag.
+    /// * More than two VLAN tags.
+    ///
+    /// By default any order that can be decoded is accepted. This is
+    /// useful for compliance testing, but should not be enabled when
+    /// decoding traffic from non compliant (but common) setups.
+    pub strict_tag_order: bool,
+
+    /// Limits for the number of decoded layers & header bytes.
+    pub limits: ParseLimits,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn default() {
+        let options: SliceOptions = Default::default();
+        assert!(false == options.first_fragment_transport);
+        assert!(false == options.strict_tag_order);
+        assert_eq!(ParseLimits::default(), options.limits);
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let options = SliceOptions {
+            first_fragment_transport: true,
+            strict_tag_order: false,
+            limits: Default::default(),
+        };
+        assert_eq!(options, options.clone());
+        assert_eq!(
+            format!(
+                "SliceOptions {{ first_fragment_transport: true, strict_tag_order: false, limits: {:?} }}",
+                options.limits
+            ),
+            format!("{:?}", options)
+        );
+    }
+}
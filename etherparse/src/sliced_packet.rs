@@ -48,6 +48,10 @@ pub struct SlicedPacket<'a> {
     pub link: Option<LinkSlice<'a>>,
     /// Single or double vlan headers if present.
     pub vlan: Option<VlanSlice<'a>>,
+    /// Backbone headers & I-TAG if the packet is a Provider Backbone
+    /// Bridging (IEEE 802.1ah) frame. In this case `link` & `vlan`
+    /// contain the headers of the encapsulated customer frame.
+    pub backbone: Option<ProviderBackboneSlice<'a>>,
     /// IPv4 or IPv6 header, IP extension headers & payload if present.
     pub net: Option<NetSlice<'a>>,
     /// TCP or UDP header & payload if present.
@@ -60,6 +64,9 @@ impl<'a> SlicedPacket<'a> {
     /// The result is returned as a [`SlicedPacket`] struct. This function assumes the given data starts
     /// with an ethernet II header.
     ///
+    /// Use [`SlicedPacket::from_ethernet_located`] if the offset of the layer that
+    /// caused an error & the previously parsed layers are needed.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -91,7 +98,48 @@ impl<'a> SlicedPacket<'a> {
     /// }
     /// ```
     pub fn from_ethernet(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
-        SlicedPacketCursor::new(data).slice_ethernet2()
+        let mut cursor = SlicedPacketCursor::new(data);
+        let result = cursor.slice_ethernet2();
+        cursor.finish(result)
+    }
+
+    /// Separates a network packet slice starting with an Ethernet II header
+    /// (like [`SlicedPacket::from_ethernet`]) and passes the ether payload to
+    /// the given parser if the ether type is not decoded by etherparse.
+    ///
+    /// The result of the parser is returned next to the sliced packet (see
+    /// [`SlicedPacket::parse_ether_payload_with`] for details when the parser
+    /// gets called). A tuple is used instead of storing the result in the
+    /// [`SlicedPacket`], as this would require an additional generic
+    /// parameter & field in [`SlicedPacket`] (breaking code constructing
+    /// or matching on it) for a result only present if a parser was used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{EtherPayloadSlice, EtherType, SlicedPacket};
+    ///
+    /// # let mut packet = Vec::new();
+    /// # etherparse::Ethernet2Header {
+    /// #     source: [1, 2, 3, 4, 5, 6],
+    /// #     destination: [7, 8, 9, 10, 11, 12],
+    /// #     ether_type: EtherType(0x88b5),
+    /// # }.write(&mut packet).unwrap();
+    /// # packet.extend_from_slice(&[1, 2, 3, 4]);
+    /// let (sliced, len) = SlicedPacket::from_ethernet_with_parser(
+    ///     &packet,
+    ///     &mut |payload: &EtherPayloadSlice| Some(payload.payload.len()),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(Some(4), len);
+    /// ```
+    pub fn from_ethernet_with_parser<P: EtherPayloadParser<'a>>(
+        data: &'a [u8],
+        parser: &mut P,
+    ) -> Result<(SlicedPacket<'a>, Option<P::Output>), err::packet::SliceError> {
+        let sliced = SlicedPacket::from_ethernet(data)?;
+        let output = sliced.parse_ether_payload_with(parser);
+        Ok((sliced, output))
     }
 
     /// Separates a network packet slice into different slices containing the
@@ -101,6 +149,9 @@ impl<'a> SlicedPacket<'a> {
     /// assumes the given data starts with a Linux Cooked Capture v1 (SLL)
     /// header.
     ///
+    /// Use [`SlicedPacket::from_linux_sll_located`] if the offset of the layer that
+    /// caused an error & the previously parsed layers are needed.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -133,7 +184,48 @@ impl<'a> SlicedPacket<'a> {
     /// }
     /// ```
     pub fn from_linux_sll(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
-        SlicedPacketCursor::new(data).slice_linux_sll()
+        let mut cursor = SlicedPacketCursor::new(data);
+        let result = cursor.slice_linux_sll();
+        cursor.finish(result)
+    }
+
+    /// Separates a network packet slice into different slices containing the
+    /// headers from the IEEE 802.11 (WiFi) MAC header downwards.
+    ///
+    /// The result is returned as a [`SlicedPacket`] struct. This function
+    /// assumes the given data starts with the MAC header of an IEEE 802.11
+    /// data frame (e.g. `LINKTYPE_IEEE802_11` captures without radiotap
+    /// header & without FCS). If the frame body starts with an LLC/SNAP
+    /// header the slicing continues based on its ether type.
+    ///
+    /// Use [`SlicedPacket::from_ieee80211_located`] if the offset of the layer that
+    /// caused an error & the previously parsed layers are needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{LinkSlice, NetSlice, SlicedPacket};
+    ///
+    /// # let packet = [
+    /// #     0x08, 0x01, // frame control (data frame, "to DS")
+    /// #     0, 0, // duration
+    /// #     1, 2, 3, 4, 5, 6, // address 1 (BSSID)
+    /// #     7, 8, 9, 10, 11, 12, // address 2 (source)
+    /// #     13, 14, 15, 16, 17, 18, // address 3 (destination)
+    /// #     0x10, 0, // sequence control
+    /// #     0xAA, 0xAA, 0x03, 0, 0, 0, 0x08, 0x00, // LLC/SNAP (IPv4)
+    /// #     0x45, 0, 0, 20, 0, 0, 0x40, 0, 64, 253, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, // IPv4
+    /// # ];
+    /// let sliced = SlicedPacket::from_ieee80211(&packet).unwrap();
+    /// if let Some(LinkSlice::Ieee80211(wifi)) = &sliced.link {
+    ///     assert_eq!([7, 8, 9, 10, 11, 12], wifi.source_addr());
+    /// }
+    /// assert!(matches!(sliced.net, Some(NetSlice::Ipv4(_))));
+    /// ```
+    pub fn from_ieee80211(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::new(data);
+        let result = cursor.slice_ieee80211();
+        cursor.finish(result)
     }
 
     /// Separates a network packet slice into different slices containing the headers using
@@ -151,6 +243,9 @@ impl<'a> SlicedPacket<'a> {
     /// If an unsupported ether type is given the given slice will be set as payload
     /// and all other fields will be set to `None`.
     ///
+    /// Use [`SlicedPacket::from_ether_type_located`] if the offset of the layer that
+    /// caused an error & the previously parsed layers are needed.
+    ///
     /// # Example
     ///
     /// Basic usage:
@@ -192,18 +287,9 @@ impl<'a> SlicedPacket<'a> {
         ether_type: EtherType,
         data: &'a [u8],
     ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
-        use ether_type::*;
         let mut cursor = SlicedPacketCursor::new(data);
-        cursor.result.link = Some(LinkSlice::EtherPayload(EtherPayloadSlice {
-            ether_type,
-            payload: data,
-        }));
-        match ether_type {
-            IPV4 => cursor.slice_ipv4(),
-            IPV6 => cursor.slice_ipv6(),
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => cursor.slice_vlan(),
-            _ => Ok(cursor.result),
-        }
+        let result = cursor.slice_ether_type(ether_type);
+        cursor.finish(result)
     }
 
     /// Separates a network packet slice into different slices containing the headers from the ip header downwards.
@@ -211,6 +297,9 @@ impl<'a> SlicedPacket<'a> {
     /// The result is returned as a [`SlicedPacket`] struct. This function assumes the given data starts
     /// with an IPv4 or IPv6 header.
     ///
+    /// Use [`SlicedPacket::from_ip_located`] if the offset of the layer that
+    /// caused an error & the previously parsed layers are needed.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -243,7 +332,248 @@ impl<'a> SlicedPacket<'a> {
     /// }
     /// ```
     pub fn from_ip(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
-        SlicedPacketCursor::new(data).slice_ip()
+        let mut cursor = SlicedPacketCursor::new(data);
+        let result = cursor.slice_ip();
+        cursor.finish(result)
+    }
+
+    /// Separates a slice containing an IP payload into slices containing the
+    /// transport header & payload based on an externally known ip number.
+    ///
+    /// This allows the decoding of the transport layer if the ip number is
+    /// known from an out-of-band source (e.g. a decrypted ESP payload). The
+    /// slice is treated as a complete & unfragmented IP payload. The `link`,
+    /// `vlan` & `net` fields of the result are always empty and if the ip
+    /// number does not identify a supported transport protocol the
+    /// `transport` field is empty as well.
+    ///
+    /// Use [`SlicedPacket::from_ip_number_located`] if the offset of the layer that
+    /// caused an error & the previously parsed layers are needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, SlicedPacket, TransportSlice};
+    ///
+    /// // udp header & payload
+    /// let data = [0, 53, 4, 0, 0, 12, 0, 0, 1, 2, 3, 4];
+    ///
+    /// let sliced = SlicedPacket::from_ip_number(ip_number::UDP, &data).unwrap();
+    /// assert_eq!(None, sliced.net);
+    /// match sliced.transport {
+    ///     Some(TransportSlice::Udp(udp)) => {
+    ///         assert_eq!(53, udp.source_port());
+    ///         assert_eq!(&[1, 2, 3, 4], udp.payload());
+    ///     }
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// ```
+    pub fn from_ip_number(
+        ip_number: IpNumber,
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::new(data);
+        let result = cursor.slice_ip_number(ip_number);
+        cursor.finish(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ethernet`] but on error additionally
+    /// returns where in the packet the error occurred & which layers
+    /// were successfully parsed before it.
+    ///
+    /// ```
+    /// use etherparse::{err::Layer, SlicedPacket};
+    ///
+    /// // ethernet II header announcing an IPv4 payload, followed by
+    /// // an incomplete IPv4 header
+    /// let packet = [
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x08, 0x00,
+    ///     0x45, 0, 0, 20,
+    /// ];
+    /// let err = SlicedPacket::from_ethernet_located(&packet).unwrap_err();
+    /// assert_eq!(14, err.layer_start_offset);
+    /// assert_eq!(&[(Layer::Ethernet2Header, 14)], &err.parsed_layers[..]);
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn from_ethernet_located(
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        let mut cursor = SlicedPacketCursor::located(data);
+        let result = cursor.slice_ethernet2();
+        cursor.finish_located(result)
+    }
+
+    /// Same as [`SlicedPacket::from_linux_sll`] but on error additionally
+    /// returns where in the packet the error occurred & which layers
+    /// were successfully parsed before it.
+    #[allow(clippy::result_large_err)]
+    pub fn from_linux_sll_located(
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        let mut cursor = SlicedPacketCursor::located(data);
+        let result = cursor.slice_linux_sll();
+        cursor.finish_located(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ieee80211`] but on error additionally
+    /// returns where in the packet the error occurred & which layers
+    /// were successfully parsed before it.
+    #[allow(clippy::result_large_err)]
+    pub fn from_ieee80211_located(
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        let mut cursor = SlicedPacketCursor::located(data);
+        let result = cursor.slice_ieee80211();
+        cursor.finish_located(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ether_type`] but on error additionally
+    /// returns where in the packet the error occurred & which layers
+    /// were successfully parsed before it.
+    #[allow(clippy::result_large_err)]
+    pub fn from_ether_type_located(
+        ether_type: EtherType,
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        let mut cursor = SlicedPacketCursor::located(data);
+        let result = cursor.slice_ether_type(ether_type);
+        cursor.finish_located(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ip`] but on error additionally
+    /// returns where in the packet the error occurred & which layers
+    /// were successfully parsed before it.
+    #[allow(clippy::result_large_err)]
+    pub fn from_ip_located(
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        let mut cursor = SlicedPacketCursor::located(data);
+        let result = cursor.slice_ip();
+        cursor.finish_located(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ip_number`] but on error additionally
+    /// returns where in the packet the error occurred & which layers
+    /// were successfully parsed before it.
+    #[allow(clippy::result_large_err)]
+    pub fn from_ip_number_located(
+        ip_number: IpNumber,
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        let mut cursor = SlicedPacketCursor::located(data);
+        let result = cursor.slice_ip_number(ip_number);
+        cursor.finish_located(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ethernet`] but with additional
+    /// [`SliceOptions`] that change how the packet is sliced.
+    ///
+    /// # Example
+    ///
+    /// Decoding the UDP header of the first fragment of a fragmented
+    /// IPv4 packet:
+    ///
+    /// ```
+    /// use etherparse::{SliceOptions, SlicedPacket, TransportSlice};
+    ///
+    /// let packet = [
+    ///     // ethernet II
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x08, 0x00,
+    ///     // ipv4 (more fragments flag set & fragment offset 0)
+    ///     0x45, 0, 0, 32, 0, 0, 0x20, 0, 64, 17, 0, 0,
+    ///     192, 168, 1, 1, 192, 168, 1, 2,
+    ///     // udp (length contains the length of the reassembled packet)
+    ///     0, 53, 4, 0, 0, 100, 0, 0,
+    ///     // start of the udp payload
+    ///     1, 2, 3, 4,
+    /// ];
+    ///
+    /// // by default fragmented payloads are not decoded
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert!(sliced.transport.is_none());
+    ///
+    /// // with the option set the transport header of the first fragment is decoded
+    /// let sliced = SlicedPacket::from_ethernet_with_options(
+    ///     &packet,
+    ///     SliceOptions {
+    ///         first_fragment_transport: true,
+    ///         ..Default::default()
+    ///     },
+    /// )
+    /// .unwrap();
+    /// assert!(sliced.is_transport_from_first_fragment());
+    /// match sliced.transport {
+    ///     Some(TransportSlice::Udp(udp)) => {
+    ///         assert_eq!(53, udp.source_port());
+    ///         assert_eq!(&[1, 2, 3, 4], udp.payload());
+    ///     }
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// ```
+    pub fn from_ethernet_with_options(
+        data: &'a [u8],
+        options: SliceOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::with_options(data, options);
+        let result = cursor.slice_ethernet2();
+        cursor.finish(result)
+    }
+
+    /// Same as [`SlicedPacket::from_linux_sll`] but with additional
+    /// [`SliceOptions`] that change how the packet is sliced.
+    pub fn from_linux_sll_with_options(
+        data: &'a [u8],
+        options: SliceOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::with_options(data, options);
+        let result = cursor.slice_linux_sll();
+        cursor.finish(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ieee80211`] but with additional
+    /// [`SliceOptions`] that change how the packet is sliced.
+    pub fn from_ieee80211_with_options(
+        data: &'a [u8],
+        options: SliceOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::with_options(data, options);
+        let result = cursor.slice_ieee80211();
+        cursor.finish(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ether_type`] but with additional
+    /// [`SliceOptions`] that change how the packet is sliced.
+    pub fn from_ether_type_with_options(
+        ether_type: EtherType,
+        data: &'a [u8],
+        options: SliceOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::with_options(data, options);
+        let result = cursor.slice_ether_type(ether_type);
+        cursor.finish(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ip`] but with additional
+    /// [`SliceOptions`] that change how the packet is sliced.
+    pub fn from_ip_with_options(
+        data: &'a [u8],
+        options: SliceOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::with_options(data, options);
+        let result = cursor.slice_ip();
+        cursor.finish(result)
+    }
+
+    /// Same as [`SlicedPacket::from_ip_number`] but with additional
+    /// [`SliceOptions`] that change how the packet is sliced.
+    pub fn from_ip_number_with_options(
+        ip_number: IpNumber,
+        data: &'a [u8],
+        options: SliceOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        let mut cursor = SlicedPacketCursor::with_options(data, options);
+        let result = cursor.slice_ip_number(ip_number);
+        cursor.finish(result)
     }
 
     /// If the slice in the `payload` field contains an ethernet payload
@@ -283,12 +613,188 @@ impl<'a> SlicedPacket<'a> {
                     )) => Some(EtherType(v)),
                     _ => None,
                 },
+                Ieee80211(s) => s.ether_payload().map(|p| p.ether_type),
             }
         } else {
             None
         }
     }
 
+    /// Returns the reason why the slicing of the packet stopped.
+    ///
+    /// Can be used to distinguish payloads that belong to the last
+    /// decoded layer from payloads that were not decoded because the
+    /// protocol is not supported (or the IP payload is fragmented):
+    ///
+    /// ```
+    /// use etherparse::{EtherType, ParseStop, SlicedPacket};
+    ///
+    /// let packet = SlicedPacket::from_ether_type(EtherType::ARP, &[]).unwrap();
+    /// assert_eq!(ParseStop::UnknownEtherType(EtherType::ARP), packet.stop_reason());
+    /// ```
+    pub fn stop_reason(&self) -> ParseStop {
+        if self.transport.is_some() {
+            ParseStop::Completed
+        } else if let Some(net) = &self.net {
+            match net.ip_payload_ref() {
+                Some(p) if p.fragmented => ParseStop::IpFragmented(p.ip_number),
+                Some(p) if p.ip_number != IpNumber::IPV6_NO_NEXT_HEADER => {
+                    ParseStop::UnknownIpNumber(p.ip_number)
+                }
+                _ => ParseStop::Completed,
+            }
+        } else if let Some(ether_type) = self.payload_ether_type() {
+            if ether_type.is_vlan_tag() {
+                ParseStop::LinkExtLimit(ether_type)
+            } else {
+                ParseStop::UnknownEtherType(ether_type)
+            }
+        } else {
+            match &self.link {
+                Some(LinkSlice::LinuxSll(s)) => {
+                    ParseStop::UnknownLinuxSllProtocol(s.protocol_type())
+                }
+                Some(LinkSlice::LinuxSllPayload(s)) => {
+                    ParseStop::UnknownLinuxSllProtocol(s.protocol_type)
+                }
+                _ => ParseStop::Completed,
+            }
+        }
+    }
+
+    /// Returns the (pcp, dei, vid) values of the VLAN tags in outer to inner
+    /// order (empty if no VLAN header is present).
+    ///
+    /// ```
+    /// use etherparse::{ether_type, PacketBuilder, SlicedPacket, VlanId};
+    ///
+    /// # let mut packet = Vec::new();
+    /// # PacketBuilder::ethernet2([0;6], [0;6])
+    /// #     .double_vlan(1.try_into().unwrap(), 2.try_into().unwrap())
+    /// #     .ipv4([0;4], [0;4], 20)
+    /// #     .udp(1, 2)
+    /// #     .write(&mut packet, &[])
+    /// #     .unwrap();
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// let ids: Vec<VlanId> = sliced.vlan_tags().map(|t| t.vlan_id).collect();
+    /// assert_eq!(ids, [VlanId::try_new(1).unwrap(), VlanId::try_new(2).unwrap()]);
+    /// ```
+    pub fn vlan_tags(&self) -> VlanTagIter {
+        self.vlan.as_ref().map(|v| v.tags()).unwrap_or_default()
+    }
+
+    /// Returns the direction of the packet relative to the capturing host
+    /// if it is known from the link layer (e.g. from the packet type of a
+    /// Linux Cooked Capture header).
+    ///
+    /// ```
+    /// use etherparse::{PacketDirection, SlicedPacket};
+    ///
+    /// let packet = [
+    ///     0, 4, // packet type (outgoing)
+    ///     0, 1, // arp hardware type (ethernet)
+    ///     0, 6, // link layer address length
+    ///     1, 2, 3, 4, 5, 6, 0, 0, // link layer address
+    ///     0x88, 0xb5, // protocol type (experimental ether type)
+    /// ];
+    /// let sliced = SlicedPacket::from_linux_sll(&packet).unwrap();
+    /// assert_eq!(Some(PacketDirection::Outbound), sliced.direction());
+    /// ```
+    pub fn direction(&self) -> Option<PacketDirection> {
+        self.link.as_ref().and_then(|l| l.direction())
+    }
+
+    /// Returns the classification of the destination of the innermost
+    /// layer that contains a destination (`None` if no such layer was
+    /// decoded or the destination is unknown).
+    ///
+    /// The destination of the IP header is classified if present, otherwise
+    /// the destination of the Ethernet II header or the packet type of
+    /// the Linux Cooked Capture header (see [`CastType`] for the details
+    /// of the classification).
+    ///
+    /// ```
+    /// use etherparse::{CastType, PacketBuilder, SlicedPacket};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [0xff; 6])
+    ///     .ipv4([192, 168, 1, 1], [255, 255, 255, 255], 20)
+    ///     .udp(68, 67)
+    ///     .write(&mut packet, &[])
+    ///     .unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert_eq!(Some(CastType::Broadcast), sliced.cast_type());
+    /// ```
+    pub fn cast_type(&self) -> Option<CastType> {
+        match &self.net {
+            Some(NetSlice::Ipv4(ipv4)) => return Some(ipv4.header().destination_cast_type()),
+            Some(NetSlice::Ipv6(ipv6)) => return Some(ipv6.header().destination_cast_type()),
+            None => {}
+        }
+        match self.link.as_ref()? {
+            LinkSlice::Ethernet2(eth) => Some(eth.destination_cast_type()),
+            LinkSlice::LinuxSll(sll) => match sll.packet_type() {
+                LinuxSllPacketType::HOST | LinuxSllPacketType::OTHERHOST => Some(CastType::Unicast),
+                LinuxSllPacketType::BROADCAST => Some(CastType::Broadcast),
+                LinuxSllPacketType::MULTICAST => Some(CastType::Multicast),
+                _ => None,
+            },
+            LinkSlice::Ieee80211(s) => Some(CastType::from_mac_addr(&s.destination_addr())),
+            LinkSlice::EtherPayload(_) | LinkSlice::LinuxSllPayload(_) => None,
+        }
+    }
+
+    /// Returns the source & destination port of the transport layer
+    /// (`None` if no transport layer with ports was decoded).
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    /// # {
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv6([1; 16], [2; 16], 20)
+    ///     .tcp(1234, 443, 1, 1024)
+    ///     .write(&mut packet, &[])
+    ///     .unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// assert_eq!(Some((1234, 443)), sliced.ports());
+    /// # }
+    /// ```
+    pub fn ports(&self) -> Option<(u16, u16)> {
+        self.transport.as_ref()?.ports()
+    }
+
+    /// Summed up length of all decoded headers (link, vlan, net &
+    /// transport layer) in bytes/octets.
+    ///
+    /// The length of the individual layers can be determined via the
+    /// `header_len` methods of the layer slices (e.g.
+    /// [`NetSlice::header_len`]).
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(1234, 53)
+    ///     .write(&mut packet, &[1, 2, 3, 4])
+    ///     .unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert_eq!(14 + 20 + 8, sliced.headers_total_len());
+    /// assert_eq!(Some(20), sliced.net.as_ref().map(|n| n.header_len()));
+    /// ```
+    pub fn headers_total_len(&self) -> usize {
+        self.link.as_ref().map_or(0, |l| l.header_len())
+            + self.vlan.as_ref().map_or(0, |v| v.header_len())
+            + self.net.as_ref().map_or(0, |n| n.header_len())
+            + self.transport.as_ref().map_or(0, |t| t.header_len())
+    }
+
     /// Returns the last ether payload of the packet (if one is present).
     ///
     /// If VLAN header is present the payload after the most inner VLAN
@@ -318,14 +824,37 @@ impl<'a> SlicedPacket<'a> {
                     }
                     _ => None,
                 },
+                LinkSlice::Ieee80211(e) => e.ether_payload(),
             }
         } else {
             None
         }
     }
 
+    /// Passes the ether payload to the given parser if the slicing stopped at
+    /// an ether payload (neither `net` nor `transport` is set).
+    ///
+    /// This allows decoding ether types not supported by etherparse via
+    /// user provided code (see [`EtherPayloadParser`]). `None` is returned
+    /// if no ether payload is present or the parser returned `None`.
+    pub fn parse_ether_payload_with<P: EtherPayloadParser<'a>>(
+        &self,
+        parser: &mut P,
+    ) -> Option<P::Output> {
+        if self.net.is_some() || self.transport.is_some() {
+            None
+        } else {
+            parser.parse_ether_payload(&self.ether_payload()?)
+        }
+    }
+
     /// Return the IP payload after the the IP header and the IP extension
     /// headers (if one is present).
+    ///
+    /// The payload is limited by the length fields of the IP header (e.g.
+    /// the total length of an IPv4 header), use
+    /// [`SlicedPacket::captured_payload`] to get the payload up to the end
+    /// of the captured data.
     pub fn ip_payload(&self) -> Option<&IpPayloadSlice<'a>> {
         if let Some(net) = self.net.as_ref() {
             use NetSlice::*;
@@ -338,76 +867,574 @@ impl<'a> SlicedPacket<'a> {
         }
     }
 
-    /// Returns true if `net` contains an fragmented IPv4 or IPv6 payload.
-    pub fn is_ip_payload_fragmented(&self) -> bool {
-        use NetSlice::*;
-        match &self.net {
-            Some(Ipv4(v)) => v.is_payload_fragmented(),
-            Some(Ipv6(v)) => v.is_payload_fragmented(),
-            None => false,
-        }
+    /// Returns the bytes after the IP header & IP extension headers up to
+    /// the end of the captured data (`None` if no IP header is present).
+    ///
+    /// In contrast to [`SlicedPacket::ip_payload`] (which is limited by
+    /// the length fields of the IP header) the returned slice also
+    /// contains the [`SlicedPacket::padding`] after the IP packet (e.g.
+    /// the padding of an Ethernet II frame to the minimum frame size).
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .write(&mut packet, 253.into(), &[1, 2])
+    ///     .unwrap();
+    /// // ethernet padding
+    /// packet.extend_from_slice(&[0, 0, 0]);
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// // payload limited by the total length field of the IPv4 header
+    /// assert_eq!(&[1, 2], sliced.ip_payload().unwrap().payload);
+    /// // payload limited by the captured data
+    /// assert_eq!(Some(&[1, 2, 0, 0, 0][..]), sliced.captured_payload());
+    /// // data after the end of the IP packet
+    /// assert_eq!(Some(&[0, 0, 0][..]), sliced.padding());
+    /// ```
+    pub fn captured_payload(&self) -> Option<&'a [u8]> {
+        let ip_payload = self.ip_payload()?.payload;
+        Some(match self.captured_link_payload() {
+            Some(captured) => {
+                let start = (ip_payload.as_ptr() as usize) - (captured.as_ptr() as usize);
+                &captured[start..]
+            }
+            None => ip_payload,
+        })
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::err::{packet::SliceError, Layer, LenError};
-    use crate::test_gens::*;
-    use crate::test_packet::TestPacket;
-    use proptest::prelude::*;
-
-    const VLAN_ETHER_TYPES: [EtherType; 3] = [
-        ether_type::VLAN_TAGGED_FRAME,
-        ether_type::PROVIDER_BRIDGING,
-        ether_type::VLAN_DOUBLE_TAGGED_FRAME,
-    ];
 
-    #[test]
-    fn clone_eq() {
-        let header = SlicedPacket {
-            link: None,
-            vlan: None,
-            net: None,
-            transport: None,
-        };
-        assert_eq!(header.clone(), header);
+    /// Returns the bytes after the end of the IP packet (as indicated by the
+    /// length fields of the IP header) up to the end of the captured data
+    /// (`None` if no IP header is present).
+    ///
+    /// Padding is added by the link layer (e.g. Ethernet II frames are padded
+    /// to a minimum size of 64 bytes). It can only be detected if the packet
+    /// was sliced starting with a link layer, otherwise an empty slice is
+    /// returned. See [`SlicedPacket::captured_payload`] for an example.
+    pub fn padding(&self) -> Option<&'a [u8]> {
+        let ip_payload = self.ip_payload()?.payload;
+        Some(match self.captured_link_payload() {
+            Some(captured) => {
+                let end = (ip_payload.as_ptr() as usize) + ip_payload.len()
+                    - (captured.as_ptr() as usize);
+                &captured[end..]
+            }
+            None => &ip_payload[ip_payload.len()..],
+        })
     }
 
-    #[test]
-    fn debug() {
-        use alloc::format;
-        let header = SlicedPacket {
-            link: None,
-            vlan: None,
-            net: None,
-            transport: None,
-        };
-        assert_eq!(
-            format!("{:?}", header),
-            format!(
-                "SlicedPacket {{ link: {:?}, vlan: {:?}, net: {:?}, transport: {:?} }}",
-                header.link, header.vlan, header.net, header.transport,
-            )
-        );
+    /// Returns the payload of the innermost link or VLAN layer up to
+    /// the end of the captured data.
+    fn captured_link_payload(&self) -> Option<&'a [u8]> {
+        if let Some(vlan) = self.vlan.as_ref() {
+            match vlan {
+                VlanSlice::SingleVlan(s) => Some(s.payload().payload),
+                VlanSlice::DoubleVlan(s) => Some(s.payload_slice()),
+            }
+        } else if let Some(link) = self.link.as_ref() {
+            match link {
+                LinkSlice::Ethernet2(e) => Some(e.payload_slice()),
+                LinkSlice::LinuxSll(e) => Some(e.payload_slice()),
+                LinkSlice::EtherPayload(e) => Some(e.payload),
+                LinkSlice::LinuxSllPayload(e) => Some(e.payload),
+                LinkSlice::Ieee80211(e) => {
+                    Some(e.ether_payload().map_or(e.payload(), |p| p.payload))
+                }
+            }
+        } else {
+            None
+        }
     }
 
-    #[test]
-    fn ether_payload() {
-        use alloc::vec::*;
+    /// Returns the IP number identifying the payload after the IP header
+    /// & IP extension headers (`None` if no IP header is present).
+    ///
+    /// The IP number is also returned if the transport layer was decoded
+    /// (e.g. [`IpNumber::UDP`] for an UDP packet).
+    pub fn next_header_ip_number(&self) -> Option<IpNumber> {
+        self.ip_payload().map(|p| p.ip_number)
+    }
 
-        // no content
-        assert_eq!(
-            SlicedPacket {
-                link: None,
-                vlan: None,
-                net: None,
-                transport: None,
-            }
-            .ether_payload(),
+    /// Returns the protocol identifier of the payload if the payload was not
+    /// decoded as a transport layer (e.g. an unknown ether type or the IP
+    /// number of a fragmented or unsupported IP payload).
+    ///
+    /// `None` is returned if a transport layer was decoded (the payload
+    /// then belongs to the transport layer) or if the payload is not
+    /// identified by an ether type or IP number.
+    ///
+    /// ```
+    /// use etherparse::{EtherType, PayloadProtocol, SlicedPacket};
+    ///
+    /// let packet = SlicedPacket::from_ether_type(EtherType::ARP, &[]).unwrap();
+    /// assert_eq!(Some(PayloadProtocol::Ether(EtherType::ARP)), packet.payload_protocol());
+    /// ```
+    pub fn payload_protocol(&self) -> Option<PayloadProtocol> {
+        if self.transport.is_some() {
             None
-        );
-
+        } else if let Some(ip_payload) = self.ip_payload() {
+            Some(PayloadProtocol::Ip(ip_payload.ip_number))
+        } else {
+            self.payload_ether_type().map(PayloadProtocol::Ether)
+        }
+    }
+
+    /// Returns a stable 64 bit digest of the parts of the packet that are
+    /// not modified while the packet is forwarded (e.g. to detect
+    /// duplicates of a packet captured at multiple points of a network).
+    ///
+    /// The digest is a [`hash::Fnv1a64`] hash over the following
+    /// values (in this order, multi byte values in network byte order):
+    ///
+    /// 1. Network layer:
+    ///     * IPv4: the byte `4`, source & destination address,
+    ///       identification & the IP number of the payload (after the
+    ///       IP extension headers).
+    ///     * IPv6: the byte `6`, source & destination address & the IP
+    ///       number of the payload (after the IP extension headers).
+    ///     * No IP header: the byte `0` followed by the ether type of the
+    ///       payload (if present).
+    /// 2. Transport layer (if decoded):
+    ///     * UDP: source & destination port.
+    ///     * TCP: source & destination port, sequence & acknowledgment
+    ///       number.
+    ///     * ICMPv4 & ICMPv6: the ICMP header with the checksum set to zero.
+    /// 3. The payload of the innermost decoded layer.
+    ///
+    /// Skipped are the fields that can change in transit:
+    ///
+    /// * link & VLAN layers (rewritten on every hop),
+    /// * IPv4 time to live & IPv6 hop limit,
+    /// * IPv4 DSCP & ECN, IPv6 traffic class (DSCP & ECN) & flow label,
+    /// * all checksums (IPv4 header, UDP, TCP & ICMP),
+    /// * link layer padding after the end of the IP packet.
+    ///
+    /// Additionally all other header fields (e.g. lengths, IPv4 flags,
+    /// IPv4 options, IPv6 extension headers, TCP flags, window size & TCP
+    /// options) are not part of the digest. [`PacketHeaders::dedup_digest`]
+    /// returns the same digest for the same packet.
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let build = |ttl: u8| {
+    ///     let mut packet = Vec::new();
+    ///     PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], ttl)
+    ///         .udp(1234, 53)
+    ///         .write(&mut packet, &[1, 2, 3, 4])
+    ///         .unwrap();
+    ///     packet
+    /// };
+    /// let a = build(64);
+    /// let b = build(63);
+    ///
+    /// // the TTL (& the header checksum) differ, the digests not
+    /// assert_eq!(
+    ///     SlicedPacket::from_ip(&a).unwrap().dedup_digest(),
+    ///     SlicedPacket::from_ip(&b).unwrap().dedup_digest()
+    /// );
+    /// ```
+    pub fn dedup_digest(&self) -> u64 {
+        let mut hash = hash::Fnv1a64::new();
+        match &self.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                let header = ipv4.header();
+                hash = hash
+                    .add_slice(&[4])
+                    .add_slice(&header.source())
+                    .add_slice(&header.destination())
+                    .add_slice(&header.identification().to_be_bytes())
+                    .add_slice(&[ipv4.payload().ip_number.0]);
+            }
+            Some(NetSlice::Ipv6(ipv6)) => {
+                let header = ipv6.header();
+                hash = hash
+                    .add_slice(&[6])
+                    .add_slice(&header.source())
+                    .add_slice(&header.destination())
+                    .add_slice(&[ipv6.payload().ip_number.0]);
+            }
+            None => {
+                hash = hash.add_slice(&[0]);
+                if let Some(ether_type) = self.payload_ether_type() {
+                    hash = hash.add_slice(&ether_type.0.to_be_bytes());
+                }
+            }
+        }
+        let payload = match &self.transport {
+            Some(TransportSlice::Udp(udp)) => {
+                hash = hash
+                    .add_slice(&udp.source_port().to_be_bytes())
+                    .add_slice(&udp.destination_port().to_be_bytes());
+                udp.payload()
+            }
+            Some(TransportSlice::Tcp(tcp)) => {
+                hash = hash
+                    .add_slice(&tcp.source_port().to_be_bytes())
+                    .add_slice(&tcp.destination_port().to_be_bytes())
+                    .add_slice(&tcp.sequence_number().to_be_bytes())
+                    .add_slice(&tcp.acknowledgment_number().to_be_bytes());
+                tcp.payload()
+            }
+            Some(TransportSlice::Icmpv4(icmp)) => {
+                let header = &icmp.slice()[..icmp.header_len()];
+                hash = hash
+                    .add_slice(&header[..2])
+                    .add_slice(&[0, 0])
+                    .add_slice(&header[4..]);
+                icmp.payload()
+            }
+            Some(TransportSlice::Icmpv6(icmp)) => {
+                let header = &icmp.slice()[..icmp.header_len()];
+                hash = hash
+                    .add_slice(&header[..2])
+                    .add_slice(&[0, 0])
+                    .add_slice(&header[4..]);
+                icmp.payload()
+            }
+            None => {
+                if let Some(ip_payload) = self.ip_payload() {
+                    ip_payload.payload
+                } else if let Some(ether_payload) = self.ether_payload() {
+                    ether_payload.payload
+                } else {
+                    self.captured_link_payload().unwrap_or(&[])
+                }
+            }
+        };
+        hash.add_slice(payload).value()
+    }
+
+    /// Returns a hexdump of the packet where each line is annotated with
+    /// the layer the bytes belong to (see [`crate::fmt::AnnotatedHexdump`]).
+    ///
+    /// ```
+    /// use etherparse::{EtherType, SlicedPacket};
+    ///
+    /// let packet = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+    /// assert_eq!(
+    ///     "0000  01 02                                            payload\n",
+    ///     packet.annotated_hexdump().to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn annotated_hexdump(&self) -> crate::fmt::AnnotatedHexdump<'_> {
+        crate::fmt::AnnotatedHexdump(self)
+    }
+
+    /// Returns the ICMPv6 slice together with the addresses of the IPv6
+    /// header (allows validating the ICMPv6 checksum, see
+    /// [`Icmpv6SliceWithAddrs::is_checksum_valid`]).
+    ///
+    /// `None` is returned if the transport layer is not ICMPv6 or if the
+    /// ICMPv6 packet is not enclosed in an IPv6 header.
+    pub fn icmpv6_with_addrs(&self) -> Option<Icmpv6SliceWithAddrs<'a>> {
+        match (&self.net, &self.transport) {
+            (Some(NetSlice::Ipv6(ipv6)), Some(TransportSlice::Icmpv6(icmpv6))) => {
+                let header = ipv6.header();
+                Some(Icmpv6SliceWithAddrs::new(
+                    icmpv6.clone(),
+                    header.source(),
+                    header.destination(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses the given (e.g. decrypted) data as an IP packet & returns
+    /// it together with this packet as outer packet.
+    ///
+    /// See [`SlicedPacket::from_ip`] for details on the parsing.
+    pub fn reparse_payload_as_ip(
+        &self,
+        data: &'a [u8],
+    ) -> Result<NestedSlicedPacket<'a>, err::packet::SliceError> {
+        Ok(NestedSlicedPacket {
+            outer: self.clone(),
+            inner: SlicedPacket::from_ip(data)?,
+            tunnel: None,
+        })
+    }
+
+    /// Parses the given (e.g. decrypted) data as an Ethernet II frame &
+    /// returns it together with this packet as outer packet.
+    ///
+    /// See [`SlicedPacket::from_ethernet`] for details on the parsing.
+    pub fn reparse_payload_as_ethernet(
+        &self,
+        data: &'a [u8],
+    ) -> Result<NestedSlicedPacket<'a>, err::packet::SliceError> {
+        Ok(NestedSlicedPacket {
+            outer: self.clone(),
+            inner: SlicedPacket::from_ethernet(data)?,
+            tunnel: None,
+        })
+    }
+
+    /// Parses the given (e.g. decrypted) data as a payload with the given
+    /// ether type (e.g. the secure data of a MACsec frame) & returns it
+    /// together with this packet as outer packet.
+    ///
+    /// See [`SlicedPacket::from_ether_type`] for details on the parsing.
+    pub fn reparse_payload_as_ether_type(
+        &self,
+        ether_type: EtherType,
+        data: &'a [u8],
+    ) -> Result<NestedSlicedPacket<'a>, err::packet::SliceError> {
+        Ok(NestedSlicedPacket {
+            outer: self.clone(),
+            inner: SlicedPacket::from_ether_type(ether_type, data)?,
+            tunnel: None,
+        })
+    }
+
+    /// Decodes the IPv6 packet tunneled in this packet via an IPv6
+    /// transition mechanism & returns it together with this packet as
+    /// outer packet.
+    ///
+    /// The following transition tunnels are detected:
+    ///
+    /// * Teredo: UDP packets from or to the port [`services::TEREDO`]
+    ///   (after the optional Teredo indicators, see [`TeredoSlice`]).
+    /// * 6to4 & 6in4: Non fragmented IPv4 payloads with the IP number
+    ///   [`IpNumber::IPV6`] (41). If the source or destination address of
+    ///   the inner IPv6 header is a 6to4 address (`2002::/16`) the tunnel
+    ///   is marked as [`Ipv6TransitionTunnel::SixToFour`], otherwise as
+    ///   [`Ipv6TransitionTunnel::SixInFour`].
+    ///
+    /// In both cases the tunneled data has to start with the IP version
+    /// 6, otherwise no tunnel is detected.
+    ///
+    /// `None` is returned if no transition tunnel was detected. Errors
+    /// encountered while decoding the inner packet (or the Teredo
+    /// indicators) are returned as `Some(Err(..))`. Offsets in the
+    /// errors are relative to the start of the inner packet.
+    ///
+    /// ```
+    /// # #[cfg(feature = "ipv6")]
+    /// # {
+    /// use etherparse::{IpNumber, Ipv6TransitionTunnel, NetSlice, PacketBuilder, SlicedPacket};
+    ///
+    /// let mut inner = Vec::new();
+    /// PacketBuilder::ipv6([0x20, 0x02, 192, 168, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], [2; 16], 20)
+    ///     .udp(1234, 53)
+    ///     .write(&mut inner, &[1, 2, 3, 4])
+    ///     .unwrap();
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv4([192, 168, 1, 1], [192, 88, 99, 1], 20)
+    ///     .write(&mut packet, IpNumber::IPV6, &inner)
+    ///     .unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// let nested = sliced.decode_ipv6_transition_tunnel().unwrap().unwrap();
+    /// assert_eq!(Some(Ipv6TransitionTunnel::SixToFour), nested.tunnel);
+    /// assert!(matches!(nested.inner.net, Some(NetSlice::Ipv6(_))));
+    /// # }
+    /// ```
+    pub fn decode_ipv6_transition_tunnel(
+        &self,
+    ) -> Option<Result<NestedSlicedPacket<'a>, err::packet::SliceError>> {
+        let (data, tunnel) = match (&self.net, &self.transport) {
+            (Some(_), Some(TransportSlice::Udp(udp))) if udp.is_probably_teredo() => {
+                let teredo = match TeredoSlice::from_slice(udp.payload()) {
+                    Ok(teredo) => teredo,
+                    Err(err) => return Some(Err(err::packet::SliceError::Len(err))),
+                };
+                (teredo.ipv6_packet(), Some(Ipv6TransitionTunnel::Teredo))
+            }
+            (Some(NetSlice::Ipv4(ipv4)), None) => {
+                let payload = ipv4.payload();
+                if IpNumber::IPV6 != payload.ip_number || payload.fragmented {
+                    return None;
+                }
+                // 6to4 or 6in4 is determined based on the inner addresses
+                (payload.payload, None)
+            }
+            _ => return None,
+        };
+
+        // only treat the payload as tunneled packet if it is an IPv6 packet
+        if Some(6) != data.first().map(|b| b >> 4) {
+            return None;
+        }
+        let inner = match SlicedPacket::from_ip(data) {
+            Ok(inner) => inner,
+            Err(err) => return Some(Err(err)),
+        };
+        let tunnel = tunnel.or_else(|| match &inner.net {
+            Some(NetSlice::Ipv6(ipv6))
+                if Ipv6TransitionTunnel::is_six_to_four_addr(&ipv6.header().source())
+                    || Ipv6TransitionTunnel::is_six_to_four_addr(&ipv6.header().destination()) =>
+            {
+                Some(Ipv6TransitionTunnel::SixToFour)
+            }
+            _ => Some(Ipv6TransitionTunnel::SixInFour),
+        });
+        Some(Ok(NestedSlicedPacket {
+            outer: self.clone(),
+            inner,
+            tunnel,
+        }))
+    }
+
+    /// Returns true if `net` contains an fragmented IPv4 or IPv6 payload.
+    pub fn is_ip_payload_fragmented(&self) -> bool {
+        use NetSlice::*;
+        match &self.net {
+            Some(Ipv4(v)) => v.is_payload_fragmented(),
+            Some(Ipv6(v)) => v.is_payload_fragmented(),
+            None => false,
+        }
+    }
+
+    /// Returns true if `transport` was decoded from the first fragment of
+    /// a fragmented IP packet (only possible if
+    /// [`SliceOptions::first_fragment_transport`] is enabled).
+    ///
+    /// In this case only the part of the transport payload contained in
+    /// the first fragment is available & length or checksum fields of the
+    /// transport header refer to the reassembled packet.
+    pub fn is_transport_from_first_fragment(&self) -> bool {
+        self.transport.is_some() && self.is_ip_payload_fragmented()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(
+    not(all(feature = "icmp", feature = "ipv6", feature = "tcp")),
+    allow(dead_code)
+)]
+mod test {
+    use super::*;
+    use crate::err::{packet::SliceError, Layer, LenError};
+    use crate::test_gens::*;
+    use crate::test_packet::TestPacket;
+    use proptest::prelude::*;
+
+    const VLAN_ETHER_TYPES: [EtherType; 3] = [
+        ether_type::VLAN_TAGGED_FRAME,
+        ether_type::PROVIDER_BRIDGING,
+        ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+    ];
+
+    #[test]
+    fn clone_eq() {
+        let header = SlicedPacket {
+            link: None,
+            vlan: None,
+            backbone: None,
+            net: None,
+            transport: None,
+        };
+        assert_eq!(header.clone(), header);
+    }
+
+    #[test]
+    fn debug() {
+        use alloc::format;
+        let header = SlicedPacket {
+            link: None,
+            vlan: None,
+            backbone: None,
+            net: None,
+            transport: None,
+        };
+        assert_eq!(
+            format!("{:?}", header),
+            format!(
+                "SlicedPacket {{ link: {:?}, vlan: {:?}, backbone: {:?}, net: {:?}, transport: {:?} }}",
+                header.link, header.vlan, header.backbone, header.net, header.transport,
+            )
+        );
+    }
+
+    #[test]
+    fn parse_ether_payload_with() {
+        use alloc::vec::*;
+
+        let mut parser = |p: &EtherPayloadSlice| Some((p.ether_type, p.payload.len()));
+
+        // no content
+        assert_eq!(
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+            }
+            .parse_ether_payload_with(&mut parser),
+            None
+        );
+
+        // unknown ether type after vlan
+        {
+            let mut buf = Vec::new();
+            buf.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::VLAN_TAGGED_FRAME,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(
+                &SingleVlanHeader {
+                    ether_type: EtherType(0x88b5),
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(&[1, 2, 3]);
+            let (sliced, output) =
+                SlicedPacket::from_ethernet_with_parser(&buf, &mut parser).unwrap();
+            assert!(sliced.vlan.is_some());
+            assert_eq!(output, Some((EtherType(0x88b5), 3)));
+        }
+
+        // parser not called if ip is present
+        {
+            let mut buf = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(&mut buf, ip_number::IGMP, &[1, 2])
+                .unwrap();
+            let mut called = false;
+            let (sliced, output) =
+                SlicedPacket::from_ethernet_with_parser(&buf, &mut |_: &EtherPayloadSlice| {
+                    called = true;
+                    Some(())
+                })
+                .unwrap();
+            assert!(sliced.net.is_some());
+            assert_eq!(output, None);
+            assert!(!called);
+        }
+
+        // error
+        assert!(SlicedPacket::from_ethernet_with_parser(&[], &mut parser).is_err());
+    }
+
+    #[test]
+    fn ether_payload() {
+        use alloc::vec::*;
+
+        // no content
+        assert_eq!(
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+            }
+            .ether_payload(),
+            None
+        );
+
         // only ethernet header II
         {
             let payload = [1, 2, 3, 4];
@@ -439,6 +1466,7 @@ mod test {
                         payload: &payload
                     })),
                     vlan: None,
+                    backbone: None,
                     net: None,
                     transport: None,
                 }
@@ -484,6 +1512,7 @@ mod test {
                         payload: &payload
                     })),
                     vlan: None,
+                    backbone: None,
                     net: None,
                     transport: None,
                 }
@@ -559,6 +1588,160 @@ mod test {
         }
     }
 
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn cast_type() {
+        use alloc::vec::*;
+
+        // no link & net layer
+        assert_eq!(
+            None,
+            SlicedPacket::from_ip_number(IpNumber::UDP, &[0; 8])
+                .unwrap()
+                .cast_type()
+        );
+        // ether payload without a destination
+        assert_eq!(
+            None,
+            SlicedPacket::from_ether_type(EtherType::ARP, &[])
+                .unwrap()
+                .cast_type()
+        );
+
+        // ethernet only
+        {
+            let eth = Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [0x01, 0x00, 0x5e, 0, 0, 1],
+                ether_type: EtherType::ARP,
+            };
+            let bytes = eth.to_bytes();
+            assert_eq!(
+                Some(CastType::Multicast),
+                SlicedPacket::from_ethernet(&bytes).unwrap().cast_type()
+            );
+        }
+
+        // ip destination has precedence over the ethernet destination
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [0xff; 6])
+                .ipv6([1; 16], [2; 16], 20)
+                .udp(1, 2)
+                .write(&mut packet, &[])
+                .unwrap();
+            assert_eq!(
+                Some(CastType::Unicast),
+                SlicedPacket::from_ethernet(&packet).unwrap().cast_type()
+            );
+        }
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1; 4], [239, 1, 1, 1], 20)
+                .udp(1, 2)
+                .write(&mut packet, &[])
+                .unwrap();
+            assert_eq!(
+                Some(CastType::Multicast),
+                SlicedPacket::from_ip(&packet).unwrap().cast_type()
+            );
+        }
+
+        // linux sll packet types
+        for (packet_type, expected) in [
+            (LinuxSllPacketType::HOST, Some(CastType::Unicast)),
+            (LinuxSllPacketType::OTHERHOST, Some(CastType::Unicast)),
+            (LinuxSllPacketType::BROADCAST, Some(CastType::Broadcast)),
+            (LinuxSllPacketType::MULTICAST, Some(CastType::Multicast)),
+            (LinuxSllPacketType::OUTGOING, None),
+        ] {
+            let header = LinuxSllHeader {
+                packet_type,
+                arp_hrd_type: ArpHardwareId::ETHER,
+                sender_address_valid_length: 6,
+                sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                protocol_type: LinuxSllProtocolType::EtherType(EtherType::ARP),
+            };
+            let bytes = header.to_bytes();
+            assert_eq!(
+                expected,
+                SlicedPacket::from_linux_sll(&bytes).unwrap().cast_type()
+            );
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn captured_payload_padding() {
+        use alloc::vec::*;
+
+        // no ip header
+        {
+            let sliced = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+            assert_eq!(None, sliced.captured_payload());
+            assert_eq!(None, sliced.padding());
+        }
+
+        // ethernet & vlan with padding
+        for vlan in [false, true] {
+            let payload = [1, 2, 3];
+            let mut packet = Vec::new();
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]);
+            if vlan {
+                builder
+                    .single_vlan(VlanId::try_new(1).unwrap())
+                    .ipv6([1; 16], [2; 16], 20)
+                    .udp(1, 2)
+                    .write(&mut packet, &payload)
+                    .unwrap();
+            } else {
+                builder
+                    .ipv6([1; 16], [2; 16], 20)
+                    .udp(1, 2)
+                    .write(&mut packet, &payload)
+                    .unwrap();
+            }
+            let ip_end = packet.len();
+            packet.extend_from_slice(&[0, 0]);
+
+            let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+            let ip_payload = sliced.ip_payload().unwrap().payload;
+            let ip_payload_start = ip_end - ip_payload.len();
+            assert_eq!(Some(&packet[ip_payload_start..]), sliced.captured_payload());
+            assert_eq!(Some(&[0, 0][..]), sliced.padding());
+
+            // padding removed
+            let sliced = SlicedPacket::from_ethernet(&packet[..ip_end]).unwrap();
+            assert_eq!(Some(ip_payload), sliced.captured_payload());
+            assert_eq!(Some(&[][..]), sliced.padding());
+        }
+
+        // ip without link layer (padding can not be detected)
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1; 4], [2; 4], 20)
+                .write(&mut packet, IpNumber(253), &[1, 2])
+                .unwrap();
+            packet.extend_from_slice(&[0, 0]);
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(Some(&[1, 2][..]), sliced.captured_payload());
+            assert_eq!(Some(&[][..]), sliced.padding());
+        }
+
+        // ether type with padding
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1; 4], [2; 4], 20)
+                .write(&mut packet, IpNumber(253), &[1, 2])
+                .unwrap();
+            packet.extend_from_slice(&[0, 0]);
+            let sliced = SlicedPacket::from_ether_type(EtherType::IPV4, &packet).unwrap();
+            assert_eq!(Some(&[1, 2, 0, 0][..]), sliced.captured_payload());
+            assert_eq!(Some(&[0, 0][..]), sliced.padding());
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
     #[test]
     fn ip_payload() {
         use alloc::vec::*;
@@ -568,6 +1751,7 @@ mod test {
             SlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
             }
@@ -624,6 +1808,57 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_ip_number() {
+        let udp = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: (UdpHeader::LEN + 4) as u16,
+            checksum: 0,
+        };
+        let mut data = [0u8; UdpHeader::LEN + 4];
+        data[..UdpHeader::LEN].copy_from_slice(&udp.to_bytes());
+        data[UdpHeader::LEN..].copy_from_slice(&[1, 2, 3, 4]);
+
+        // ok
+        for f in [
+            |n, d| SlicedPacket::from_ip_number(n, d),
+            |n, d| SlicedPacket::from_ip_number_located(n, d).map_err(|e| e.error),
+            |n, d| SlicedPacket::from_ip_number_with_options(n, d, Default::default()),
+        ] {
+            let actual = f(ip_number::UDP, &data).unwrap();
+            assert_eq!(None, actual.link);
+            assert_eq!(None, actual.vlan);
+            assert_eq!(None, actual.net);
+            assert_eq!(
+                Some(TransportSlice::Udp(UdpSlice::from_slice(&data).unwrap())),
+                actual.transport
+            );
+
+            // unknown ip number
+            let actual = f(ip_number::GGP, &data).unwrap();
+            assert_eq!(None, actual.transport);
+        }
+
+        // len error
+        {
+            let err = SlicedPacket::from_ip_number_located(ip_number::UDP, &data[..7]).unwrap_err();
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: UdpHeader::LEN,
+                    len: 7,
+                    len_source: LenSource::Slice,
+                    layer: Layer::UdpHeader,
+                    layer_start_offset: 0,
+                }),
+                err.error
+            );
+            assert_eq!(0, err.layer_start_offset);
+            assert!(err.parsed_layers.is_empty());
+        }
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
     #[test]
     fn from_x_slice() {
         // no eth
@@ -646,6 +1881,7 @@ mod test {
                         payload: &data
                     })),
                     vlan: None,
+                    backbone: None,
                     net: None,
                     transport: None
                 }
@@ -1381,11 +2617,17 @@ mod test {
                         LinkSlice::LinuxSll(e) => Some(LinkHeader::LinuxSll(e.to_header())),
                         LinkSlice::EtherPayload(_) => None,
                         LinkSlice::LinuxSllPayload(_) => None,
+                        LinkSlice::Ieee80211(_) => None,
                     },
                     None => None,
                 }
             );
             assert_eq!(test.vlan, result.vlan.as_ref().map(|e| e.to_header()));
+            assert!(result.vlan_tags().eq(test
+                .vlan
+                .as_ref()
+                .map(|v| v.tags())
+                .unwrap_or_default()));
             assert_eq!(
                 test.net,
                 result.net.as_ref().map(|s: &NetSlice| -> NetHeaders {
@@ -1410,7 +2652,22 @@ mod test {
             // check transport header & payload
             if is_fragmented {
                 assert_eq!(result.transport, None);
+                assert_eq!(
+                    ParseStop::IpFragmented(
+                        result
+                            .net
+                            .as_ref()
+                            .unwrap()
+                            .ip_payload_ref()
+                            .unwrap()
+                            .ip_number
+                    ),
+                    result.stop_reason()
+                );
             } else {
+                if result.transport.is_some() {
+                    assert_eq!(ParseStop::Completed, result.stop_reason());
+                }
                 use TransportHeader as H;
                 use TransportSlice as S;
                 match &result.transport {
@@ -1433,10 +2690,24 @@ mod test {
                     }
                 }
             }
-        }
-
-        // setup payload
-        let payload = [1, 2, 3, 4];
+
+            // check summed up header length
+            let transport_len = if is_fragmented {
+                0
+            } else {
+                test.transport.as_ref().map_or(0, |t| t.header_len())
+            };
+            assert_eq!(
+                test.link.as_ref().map_or(0, |l| l.header_len())
+                    + test.vlan.as_ref().map_or(0, |v| v.header_len())
+                    + test.net.as_ref().map_or(0, |n| n.header_len())
+                    + transport_len,
+                result.headers_total_len()
+            );
+        }
+
+        // setup payload
+        let payload = [1, 2, 3, 4];
 
         // set length fields in ip headers
         let test = {
@@ -1445,94 +2716,1534 @@ mod test {
             test
         };
 
-        // write data
-        let data = test.to_vec(&payload);
+        // write data
+        let data = test.to_vec(&payload);
+
+        // from_ethernet
+        if test.link.is_some() {
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_test_result(&test, &payload, &result);
+        }
+        // from_ether_type (vlan at start)
+        if test.link.is_none() && test.vlan.is_some() {
+            for ether_type in VLAN_ETHER_TYPES {
+                let result = SlicedPacket::from_ether_type(ether_type, &data).unwrap();
+                assert_eq!(
+                    result.link,
+                    Some(LinkSlice::EtherPayload(EtherPayloadSlice {
+                        ether_type,
+                        payload: &data
+                    }))
+                );
+                assert_test_result(&test, &payload, &result);
+            }
+        }
+        // from_ether_type (ip at start)
+        if test.link.is_none() && test.vlan.is_none() {
+            if let Some(ip) = &test.net {
+                let ether_type = match ip {
+                    NetHeaders::Ipv4(_, _) => ether_type::IPV4,
+                    NetHeaders::Ipv6(_, _) => ether_type::IPV6,
+                };
+                let result = SlicedPacket::from_ether_type(ether_type, &data).unwrap();
+                assert_eq!(
+                    result.link,
+                    Some(LinkSlice::EtherPayload(EtherPayloadSlice {
+                        ether_type,
+                        payload: &data
+                    }))
+                );
+                assert_test_result(&test, &payload, &result);
+            }
+        }
+        // from_ip_slice
+        if test.link.is_none() && test.vlan.is_none() && test.net.is_some() {
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            assert_test_result(&test, &payload, &result);
+        }
+    }
+
+    /// Check that the given errors get triggered if presented with the given
+    /// data.
+    fn from_slice_assert_err(test: &TestPacket, data: &[u8], err: SliceError) {
+        // checks that the located error wraps the expected error & that
+        // the error is located after the successfully parsed layers
+        let assert_located = |located: err::packet::LocatedSliceError| {
+            assert_eq!(err, located.error);
+            let parsed_len: usize = located.parsed_layers.iter().map(|(_, len)| len).sum();
+            assert!(parsed_len <= located.layer_start_offset);
+        };
+
+        // from_ethernet_slice
+        if let Some(ref header) = test.link {
+            match header {
+                LinkHeader::Ethernet2(_) => {
+                    assert_eq!(err.clone(), SlicedPacket::from_ethernet(&data).unwrap_err());
+                    assert_located(SlicedPacket::from_ethernet_located(data).unwrap_err());
+                }
+                LinkHeader::LinuxSll(_) => {
+                    assert_eq!(
+                        err.clone(),
+                        SlicedPacket::from_linux_sll(&data).unwrap_err()
+                    );
+                    assert_located(SlicedPacket::from_linux_sll_located(data).unwrap_err());
+                }
+            }
+        }
+        // from_ether_type (vlan at start)
+        if test.link.is_none() && test.vlan.is_some() {
+            for ether_type in VLAN_ETHER_TYPES {
+                assert_eq!(
+                    err.clone(),
+                    SlicedPacket::from_ether_type(ether_type, &data).unwrap_err()
+                );
+            }
+        }
+        // from_ether_type (ip at start)
+        if test.link.is_none() && test.vlan.is_none() {
+            if let Some(ip) = &test.net {
+                let err = SlicedPacket::from_ether_type(
+                    match ip {
+                        NetHeaders::Ipv4(_, _) => ether_type::IPV4,
+                        NetHeaders::Ipv6(_, _) => ether_type::IPV6,
+                    },
+                    &data,
+                )
+                .unwrap_err();
+                assert_eq!(err, err.clone());
+            }
+        }
+        // from_ip_slice
+        if test.link.is_none() && test.vlan.is_none() && test.net.is_some() {
+            assert_eq!(err, SlicedPacket::from_ip(&data).unwrap_err());
+            assert_located(SlicedPacket::from_ip_located(data).unwrap_err());
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn stop_reason() {
+        use ParseStop::*;
+
+        // unknown ether type after ethernet 2 & vlan
+        {
+            let eth = Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [7, 8, 9, 10, 11, 12],
+                ether_type: EtherType::ARP,
+            };
+            let data = eth.to_bytes();
+            assert_eq!(
+                UnknownEtherType(EtherType::ARP),
+                SlicedPacket::from_ethernet(&data).unwrap().stop_reason()
+            );
+            assert_eq!(
+                UnknownEtherType(EtherType::ARP),
+                SlicedPacket::from_ether_type(EtherType::ARP, &[])
+                    .unwrap()
+                    .stop_reason()
+            );
+        }
+
+        // vlan limit (third vlan tag)
+        {
+            let vlan = SingleVlanHeader {
+                pcp: VlanPcp::ZERO,
+                drop_eligible_indicator: false,
+                vlan_id: VlanId::try_new(1).unwrap(),
+                ether_type: EtherType::VLAN_TAGGED_FRAME,
+            };
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(&vlan.to_bytes());
+            data.extend_from_slice(&vlan.to_bytes());
+            assert_eq!(
+                LinkExtLimit(EtherType::VLAN_TAGGED_FRAME),
+                SlicedPacket::from_ether_type(EtherType::VLAN_TAGGED_FRAME, &data)
+                    .unwrap()
+                    .stop_reason()
+            );
+        }
+
+        // linux sll with non ether type protocol
+        {
+            let sll = LinuxSllHeader {
+                packet_type: LinuxSllPacketType::HOST,
+                arp_hrd_type: ArpHardwareId::NETLINK,
+                sender_address_valid_length: 0,
+                sender_address: [0; 8],
+                protocol_type: LinuxSllProtocolType::NetlinkProtocolType(1),
+            };
+            assert_eq!(
+                UnknownLinuxSllProtocol(LinuxSllProtocolType::NetlinkProtocolType(1)),
+                SlicedPacket::from_linux_sll(&sll.to_bytes())
+                    .unwrap()
+                    .stop_reason()
+            );
+        }
+
+        // ip payloads
+        {
+            let build = |ip_number: IpNumber, more_fragments: bool| {
+                let mut ip = Ipv4Header::new(0, 1, ip_number, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+                ip.more_fragments = more_fragments;
+                ip.header_checksum = ip.calc_header_checksum();
+                ip.to_bytes()
+            };
+            assert_eq!(
+                UnknownIpNumber(IpNumber(253)),
+                SlicedPacket::from_ip(&build(IpNumber(253), false))
+                    .unwrap()
+                    .stop_reason()
+            );
+            assert_eq!(
+                IpFragmented(IpNumber::UDP),
+                SlicedPacket::from_ip(&build(IpNumber::UDP, true))
+                    .unwrap()
+                    .stop_reason()
+            );
+
+            let ipv6 = Ipv6Header {
+                next_header: IpNumber::IPV6_NO_NEXT_HEADER,
+                source: [0; 16],
+                destination: [0; 16],
+                ..Default::default()
+            };
+            assert_eq!(
+                Completed,
+                SlicedPacket::from_ip(&ipv6.to_bytes())
+                    .unwrap()
+                    .stop_reason()
+            );
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn reparse_payload() {
+        let mut outer_data = alloc::vec::Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .write(
+                &mut outer_data,
+                IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+                &[0; 16],
+            )
+            .unwrap();
+        let outer = SlicedPacket::from_ip(&outer_data).unwrap();
+
+        let mut inner_data = alloc::vec::Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([1; 16], [2; 16], 20)
+            .udp(1, 2)
+            .write(&mut inner_data, &[1, 2])
+            .unwrap();
+        let inner_ip = &inner_data[Ethernet2Header::LEN..];
+
+        // ip
+        assert_eq!(
+            outer.reparse_payload_as_ip(inner_ip).unwrap(),
+            NestedSlicedPacket {
+                outer: outer.clone(),
+                inner: SlicedPacket::from_ip(inner_ip).unwrap(),
+                tunnel: None,
+            }
+        );
+        assert_eq!(
+            outer.reparse_payload_as_ip(&[]).unwrap_err(),
+            SlicedPacket::from_ip(&[]).unwrap_err()
+        );
+
+        // ethernet
+        assert_eq!(
+            outer.reparse_payload_as_ethernet(&inner_data).unwrap(),
+            NestedSlicedPacket {
+                outer: outer.clone(),
+                inner: SlicedPacket::from_ethernet(&inner_data).unwrap(),
+                tunnel: None,
+            }
+        );
+        assert_eq!(
+            outer.reparse_payload_as_ethernet(&[]).unwrap_err(),
+            SlicedPacket::from_ethernet(&[]).unwrap_err()
+        );
+
+        // ether type
+        assert_eq!(
+            outer
+                .reparse_payload_as_ether_type(EtherType::IPV6, inner_ip)
+                .unwrap(),
+            NestedSlicedPacket {
+                outer: outer.clone(),
+                inner: SlicedPacket::from_ether_type(EtherType::IPV6, inner_ip).unwrap(),
+                tunnel: None,
+            }
+        );
+        assert_eq!(
+            outer
+                .reparse_payload_as_ether_type(EtherType::IPV6, &[])
+                .unwrap_err(),
+            SlicedPacket::from_ether_type(EtherType::IPV6, &[]).unwrap_err()
+        );
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn decode_ipv6_transition_tunnel() {
+        use alloc::vec::Vec;
+
+        let inner = |source: [u8; 16]| {
+            let mut result = Vec::new();
+            PacketBuilder::ipv6(source, [2; 16], 20)
+                .udp(1, 2)
+                .write(&mut result, &[1, 2])
+                .unwrap();
+            result
+        };
+        let six_to_four_addr = [0x20, 0x02, 1, 2, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+        // teredo (with & without origin indication)
+        for prefix in [&[][..], &[0, 0, 0xff, 0xfe, 1, 2, 3, 4][..]] {
+            let mut payload = prefix.to_vec();
+            payload.extend_from_slice(&inner([1; 16]));
+            for (source_port, destination_port) in [(3544, 1234), (1234, 3544)] {
+                let mut packet = Vec::new();
+                PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                    .udp(source_port, destination_port)
+                    .write(&mut packet, &payload)
+                    .unwrap();
+                let outer = SlicedPacket::from_ip(&packet).unwrap();
+                assert_eq!(
+                    outer.decode_ipv6_transition_tunnel(),
+                    Some(Ok(NestedSlicedPacket {
+                        outer: outer.clone(),
+                        inner: SlicedPacket::from_ip(&payload[prefix.len()..]).unwrap(),
+                        tunnel: Some(Ipv6TransitionTunnel::Teredo),
+                    }))
+                );
+            }
+        }
+
+        // teredo with truncated indicator
+        {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(3544, 1234)
+                .write(&mut packet, &[0, 0, 1])
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(
+                outer.decode_ipv6_transition_tunnel(),
+                Some(Err(err::packet::SliceError::Len(
+                    TeredoSlice::from_slice(&[0, 0, 1]).unwrap_err()
+                )))
+            );
+        }
+
+        // teredo port without ipv6 packet & udp without teredo port
+        for (port, payload) in [
+            (3544, Vec::new()),
+            (3544, alloc::vec![0x45, 0]),
+            (1234, inner([1; 16])),
+        ] {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(port, 1234)
+                .write(&mut packet, &payload)
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(outer.decode_ipv6_transition_tunnel(), None);
+        }
+
+        // 6to4 & 6in4
+        for (source, expected) in [
+            ([1; 16], Ipv6TransitionTunnel::SixInFour),
+            (six_to_four_addr, Ipv6TransitionTunnel::SixToFour),
+        ] {
+            let payload = inner(source);
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(&mut packet, IpNumber::IPV6, &payload)
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(
+                outer.decode_ipv6_transition_tunnel(),
+                Some(Ok(NestedSlicedPacket {
+                    outer: outer.clone(),
+                    inner: SlicedPacket::from_ip(&payload).unwrap(),
+                    tunnel: Some(expected),
+                }))
+            );
+        }
+
+        // 6to4 destination address
+        {
+            let mut payload = Vec::new();
+            PacketBuilder::ipv6([1; 16], six_to_four_addr, 20)
+                .write(&mut payload, IpNumber::IPV6_NO_NEXT_HEADER, &[])
+                .unwrap();
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(&mut packet, IpNumber::IPV6, &payload)
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(
+                Some(Ipv6TransitionTunnel::SixToFour),
+                outer
+                    .decode_ipv6_transition_tunnel()
+                    .unwrap()
+                    .unwrap()
+                    .tunnel
+            );
+        }
+
+        // 6in4 with an invalid inner packet
+        {
+            let mut payload = inner([1; 16]);
+            payload.truncate(Ipv6Header::LEN - 1);
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(&mut packet, IpNumber::IPV6, &payload)
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(
+                outer.decode_ipv6_transition_tunnel(),
+                Some(Err(SlicedPacket::from_ip(&payload).unwrap_err()))
+            );
+        }
+
+        // ip number 41 in ipv6, other ip numbers & non ip packets
+        {
+            let payload = inner([1; 16]);
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .write(&mut packet, IpNumber::IPV6, &payload)
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(outer.decode_ipv6_transition_tunnel(), None);
+
+            let mut packet = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .write(
+                    &mut packet,
+                    IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+                    &payload,
+                )
+                .unwrap();
+            let outer = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(outer.decode_ipv6_transition_tunnel(), None);
+
+            let outer = SlicedPacket::from_ether_type(EtherType::ARP, &[1, 2]).unwrap();
+            assert_eq!(outer.decode_ipv6_transition_tunnel(), None);
+        }
+    }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    #[test]
+    fn icmpv6_with_addrs() {
+        // icmpv6 in ipv6
+        {
+            let mut packet = alloc::vec::Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .icmpv6_echo_request(1, 2)
+                .write(&mut packet, &[3, 4])
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            let actual = sliced.icmpv6_with_addrs().unwrap();
+            assert_eq!([1; 16], actual.source());
+            assert_eq!([2; 16], actual.destination());
+            assert_eq!(&packet[Ipv6Header::LEN..], actual.icmpv6().slice());
+            assert!(actual.is_checksum_valid());
+        }
+
+        // other transport layer
+        {
+            let mut packet = alloc::vec::Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                .udp(1, 2)
+                .write(&mut packet, &[])
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert_eq!(None, sliced.icmpv6_with_addrs());
+        }
+
+        // icmpv6 in ipv4
+        {
+            let mut packet = alloc::vec::Vec::new();
+            PacketBuilder::ipv4([1; 4], [2; 4], 20)
+                .write(
+                    &mut packet,
+                    IpNumber::IPV6_ICMP,
+                    &[128, 0, 0, 0, 0, 0, 0, 0],
+                )
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert!(matches!(sliced.transport, Some(TransportSlice::Icmpv6(_))));
+            assert_eq!(None, sliced.icmpv6_with_addrs());
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn next_header_ip_number_payload_protocol() {
+        // ether payload
+        {
+            let packet = SlicedPacket::from_ether_type(EtherType::ARP, &[]).unwrap();
+            assert_eq!(None, packet.next_header_ip_number());
+            assert_eq!(
+                Some(PayloadProtocol::Ether(EtherType::ARP)),
+                packet.payload_protocol()
+            );
+        }
+
+        // linux sll with non ether type protocol
+        {
+            let sll = LinuxSllHeader {
+                packet_type: LinuxSllPacketType::HOST,
+                arp_hrd_type: ArpHardwareId::NETLINK,
+                sender_address_valid_length: 0,
+                sender_address: [0; 8],
+                protocol_type: LinuxSllProtocolType::NetlinkProtocolType(1),
+            };
+            let bytes = sll.to_bytes();
+            let packet = SlicedPacket::from_linux_sll(&bytes).unwrap();
+            assert_eq!(None, packet.next_header_ip_number());
+            assert_eq!(None, packet.payload_protocol());
+        }
+
+        // ip payloads
+        {
+            let build = |ip_number: IpNumber, more_fragments: bool| {
+                let mut ip = Ipv4Header::new(0, 1, ip_number, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+                ip.more_fragments = more_fragments;
+                ip.header_checksum = ip.calc_header_checksum();
+                ip.to_bytes()
+            };
+            for (ip_number, more_fragments) in [(IpNumber(253), false), (IpNumber::UDP, true)] {
+                let bytes = build(ip_number, more_fragments);
+                let packet = SlicedPacket::from_ip(&bytes).unwrap();
+                assert_eq!(Some(ip_number), packet.next_header_ip_number());
+                assert_eq!(
+                    Some(PayloadProtocol::Ip(ip_number)),
+                    packet.payload_protocol()
+                );
+            }
+        }
+
+        // transport payload
+        {
+            let mut data = alloc::vec::Vec::new();
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6([0; 16], [0; 16], 64)
+                .udp(1, 2)
+                .write(&mut data, &[1, 2, 3, 4])
+                .unwrap();
+            let packet = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(IpNumber::UDP), packet.next_header_ip_number());
+            assert_eq!(None, packet.payload_protocol());
+        }
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn from_x_with_options() {
+        let first_fragment_transport = SliceOptions {
+            first_fragment_transport: true,
+            ..Default::default()
+        };
+
+        // checks all "with_options" functions for the given ip packet
+        fn check(ip: &[u8], options: SliceOptions) -> Option<TransportSlice<'_>> {
+            let from_ip = SlicedPacket::from_ip_with_options(ip, options).unwrap();
+
+            let ether_type = match from_ip.net {
+                Some(NetSlice::Ipv4(_)) => EtherType::IPV4,
+                _ => EtherType::IPV6,
+            };
+            let from_ether_type =
+                SlicedPacket::from_ether_type_with_options(ether_type, ip, options).unwrap();
+            assert_eq!(from_ip.net, from_ether_type.net);
+            assert_eq!(from_ip.transport, from_ether_type.transport);
+
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1, 2, 3, 4, 5, 6],
+                    destination: [7, 8, 9, 10, 11, 12],
+                    ether_type,
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(ip);
+            let from_ethernet = SlicedPacket::from_ethernet_with_options(&data, options).unwrap();
+            assert_eq!(from_ip.net, from_ethernet.net);
+            assert_eq!(from_ip.transport, from_ethernet.transport);
+
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(
+                &LinuxSllHeader {
+                    packet_type: LinuxSllPacketType::HOST,
+                    arp_hrd_type: ArpHardwareId::ETHER,
+                    sender_address_valid_length: 6,
+                    sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                    protocol_type: LinuxSllProtocolType::EtherType(ether_type),
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(ip);
+            let from_linux_sll = SlicedPacket::from_linux_sll_with_options(&data, options).unwrap();
+            assert_eq!(from_ip.net, from_linux_sll.net);
+            assert_eq!(from_ip.transport, from_linux_sll.transport);
+
+            // default options behave like the functions without options
+            if options == SliceOptions::default() {
+                assert_eq!(from_ip, SlicedPacket::from_ip(ip).unwrap());
+            }
+
+            from_ip.transport
+        }
+
+        let udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 53,
+            // length of the reassembled packet
+            length: 100,
+            checksum: 0,
+        };
+        let tcp = TcpHeader::new(1234, 80, 1, 1024);
+
+        // ipv4
+        {
+            let build = |ip_number: IpNumber, offset: u16, payload: &[u8]| {
+                let mut ip = Ipv4Header::new(
+                    payload.len() as u16,
+                    1,
+                    ip_number,
+                    [1, 2, 3, 4],
+                    [5, 6, 7, 8],
+                )
+                .unwrap();
+                ip.more_fragments = true;
+                ip.fragment_offset = IpFragOffset::try_new(offset).unwrap();
+                ip.header_checksum = ip.calc_header_checksum();
+                let mut data = alloc::vec::Vec::new();
+                data.extend_from_slice(&ip.to_bytes());
+                data.extend_from_slice(payload);
+                data
+            };
+
+            let mut udp_payload = alloc::vec::Vec::new();
+            udp_payload.extend_from_slice(&udp.to_bytes());
+            udp_payload.extend_from_slice(&[1, 2, 3, 4]);
+
+            // first fragment
+            {
+                let data = build(ip_number::UDP, 0, &udp_payload);
+                assert_eq!(None, check(&data, SliceOptions::default()));
+                match check(&data, first_fragment_transport) {
+                    Some(TransportSlice::Udp(s)) => {
+                        assert_eq!(udp, s.to_header());
+                        assert_eq!(&[1, 2, 3, 4], s.payload());
+                    }
+                    value => panic!("unexpected transport {:?}", value),
+                }
+                assert_eq!(
+                    ParseStop::Completed,
+                    SlicedPacket::from_ip_with_options(&data, first_fragment_transport)
+                        .unwrap()
+                        .stop_reason()
+                );
+            }
+
+            // first fragment tcp
+            {
+                let data = build(ip_number::TCP, 0, &tcp.to_bytes());
+                assert_eq!(None, check(&data, SliceOptions::default()));
+                match check(&data, first_fragment_transport) {
+                    Some(TransportSlice::Tcp(s)) => assert_eq!(tcp, s.to_header()),
+                    value => panic!("unexpected transport {:?}", value),
+                }
+            }
+
+            // first fragment with cut off transport header (no error)
+            {
+                let data = build(ip_number::TCP, 0, &tcp.to_bytes()[..4]);
+                assert_eq!(None, check(&data, first_fragment_transport));
+                let data = build(ip_number::UDP, 0, &udp_payload[..4]);
+                assert_eq!(None, check(&data, first_fragment_transport));
+            }
+
+            // non first fragment
+            {
+                let data = build(ip_number::UDP, 1, &udp_payload);
+                assert_eq!(None, check(&data, SliceOptions::default()));
+                assert_eq!(None, check(&data, first_fragment_transport));
+            }
+        }
+
+        // ipv6
+        {
+            let build = |offset: u16| {
+                let frag = Ipv6FragmentHeader::new(
+                    ip_number::UDP,
+                    IpFragOffset::try_new(offset).unwrap(),
+                    true,
+                    1234,
+                );
+                let ip = Ipv6Header {
+                    payload_length: (frag.header_len() + UdpHeader::LEN + 4) as u16,
+                    next_header: ip_number::IPV6_FRAG,
+                    hop_limit: 1,
+                    source: [1; 16],
+                    destination: [2; 16],
+                    ..Default::default()
+                };
+                let mut data = alloc::vec::Vec::new();
+                data.extend_from_slice(&ip.to_bytes());
+                data.extend_from_slice(&frag.to_bytes());
+                data.extend_from_slice(&udp.to_bytes());
+                data.extend_from_slice(&[1, 2, 3, 4]);
+                data
+            };
+
+            // first fragment
+            {
+                let data = build(0);
+                assert_eq!(None, check(&data, SliceOptions::default()));
+                match check(&data, first_fragment_transport) {
+                    Some(TransportSlice::Udp(s)) => {
+                        assert_eq!(udp, s.to_header());
+                        assert_eq!(&[1, 2, 3, 4], s.payload());
+                    }
+                    value => panic!("unexpected transport {:?}", value),
+                }
+            }
+
+            // non first fragment
+            {
+                let data = build(1);
+                assert_eq!(None, check(&data, first_fragment_transport));
+            }
+        }
+    }
+
+    #[test]
+    fn is_transport_from_first_fragment() {
+        let first_fragment_transport = SliceOptions {
+            first_fragment_transport: true,
+            ..Default::default()
+        };
+        let build = |more_fragments: bool, fragment_offset: u16| {
+            let udp = UdpHeader {
+                source_port: 1234,
+                destination_port: 53,
+                // fragments contain the length of the reassembled packet
+                length: if more_fragments || fragment_offset != 0 {
+                    100
+                } else {
+                    12
+                },
+                checksum: 0,
+            };
+            let mut ip = Ipv4Header::new(
+                udp.header_len_u16() + 4,
+                64,
+                ip_number::UDP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            ip.more_fragments = more_fragments;
+            ip.fragment_offset = IpFragOffset::try_new(fragment_offset).unwrap();
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(&ip.to_bytes());
+            data.extend_from_slice(&udp.to_bytes());
+            data.extend_from_slice(&[1, 2, 3, 4]);
+            data
+        };
+
+        // not fragmented
+        {
+            let data = build(false, 0);
+            for options in [SliceOptions::default(), first_fragment_transport] {
+                let sliced = SlicedPacket::from_ip_with_options(&data, options).unwrap();
+                assert!(sliced.transport.is_some());
+                assert!(false == sliced.is_transport_from_first_fragment());
+            }
+        }
+
+        // first fragment
+        {
+            let data = build(true, 0);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert!(sliced.transport.is_none());
+            assert!(false == sliced.is_transport_from_first_fragment());
+
+            let sliced =
+                SlicedPacket::from_ip_with_options(&data, first_fragment_transport).unwrap();
+            assert!(sliced.transport.is_some());
+            assert!(sliced.is_transport_from_first_fragment());
+        }
+
+        // later fragment
+        {
+            let data = build(false, 1);
+            let sliced =
+                SlicedPacket::from_ip_with_options(&data, first_fragment_transport).unwrap();
+            assert!(sliced.transport.is_none());
+            assert!(false == sliced.is_transport_from_first_fragment());
+        }
+    }
+
+    #[cfg(feature = "tcp")]
+    #[test]
+    fn from_x_with_limits() {
+        use err::{packet::SliceError::ParseLimit, Layer, ParseLimitError::*};
+
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(VlanId::try_new(1).unwrap())
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .tcp(21, 1234, 1, 1024);
+        let mut data = alloc::vec::Vec::with_capacity(builder.size(4));
+        builder.write(&mut data, &[1, 2, 3, 4]).unwrap();
+
+        let with_limits = |max_layers: Option<usize>, max_header_len: Option<usize>| {
+            SlicedPacket::from_ethernet_with_options(
+                &data,
+                SliceOptions {
+                    limits: ParseLimits {
+                        max_layers,
+                        max_header_len,
+                    },
+                    ..Default::default()
+                },
+            )
+        };
+
+        // no limits & limits that are not exceeded
+        {
+            let expected = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(expected, with_limits(None, None).unwrap());
+            assert_eq!(expected, with_limits(Some(4), Some(58)).unwrap());
+        }
+
+        // max layers
+        for (max_layers, layer) in [
+            (0, Layer::Ethernet2Header),
+            (1, Layer::VlanHeader),
+            (2, Layer::Ipv4Header),
+            (3, Layer::TcpHeader),
+        ] {
+            assert_eq!(
+                ParseLimit(MaxLayers { max_layers, layer }),
+                with_limits(Some(max_layers), None).unwrap_err()
+            );
+        }
+
+        // max header len
+        for (max_header_len, header_len, layer) in [
+            (13, 14, Layer::Ethernet2Header),
+            (17, 18, Layer::VlanHeader),
+            (37, 38, Layer::Ipv4Header),
+            (57, 58, Layer::TcpHeader),
+        ] {
+            assert_eq!(
+                ParseLimit(MaxHeaderLen {
+                    max_header_len,
+                    header_len,
+                    layer
+                }),
+                with_limits(None, Some(max_header_len)).unwrap_err()
+            );
+        }
+
+        // error location points to the start of the layer
+        {
+            let mut cursor = SlicedPacketCursor::with_options(
+                &data,
+                SliceOptions {
+                    limits: ParseLimits {
+                        max_layers: Some(3),
+                        max_header_len: None,
+                    },
+                    ..Default::default()
+                },
+            );
+            let result = cursor.slice_ethernet2();
+            let err = cursor.finish_located(result).unwrap_err();
+            assert_eq!(38, err.layer_start_offset);
+        }
+
+        // limits are checked before a layer is decoded (no length error
+        // for the cut off next layer)
+        {
+            let slice_cut =
+                |len: usize, max_layers: Option<usize>, max_header_len: Option<usize>| {
+                    SlicedPacket::from_ethernet_with_options(
+                        &data[..len],
+                        SliceOptions {
+                            limits: ParseLimits {
+                                max_layers,
+                                max_header_len,
+                            },
+                            ..Default::default()
+                        },
+                    )
+                    .unwrap_err()
+                };
+            for (len, max_layers, header_len, layer) in [
+                (14, 1, 18, Layer::VlanHeader),
+                (18, 2, 38, Layer::Ipv4Header),
+            ] {
+                assert!(matches!(
+                    slice_cut(len, None, None),
+                    err::packet::SliceError::Len(_)
+                ));
+                assert_eq!(
+                    ParseLimit(MaxLayers { max_layers, layer }),
+                    slice_cut(len, Some(max_layers), None)
+                );
+                assert_eq!(
+                    ParseLimit(MaxHeaderLen {
+                        max_header_len: len,
+                        header_len,
+                        layer
+                    }),
+                    slice_cut(len, None, Some(len))
+                );
+            }
+
+            // ipv4 packet with a cut off tcp header
+            let mut ip = Ipv4Header::new(4, 64, ip_number::TCP, [1, 2, 3, 4], [5, 6, 7, 8])
+                .unwrap()
+                .to_bytes()
+                .to_vec();
+            ip.extend_from_slice(&[0; 4]);
+            let slice_ip = |max_layers: Option<usize>, max_header_len: Option<usize>| {
+                SlicedPacket::from_ip_with_options(
+                    &ip,
+                    SliceOptions {
+                        limits: ParseLimits {
+                            max_layers,
+                            max_header_len,
+                        },
+                        ..Default::default()
+                    },
+                )
+                .unwrap_err()
+            };
+            assert!(matches!(
+                slice_ip(None, None),
+                err::packet::SliceError::Len(_)
+            ));
+            assert_eq!(
+                ParseLimit(MaxLayers {
+                    max_layers: 1,
+                    layer: Layer::TcpHeader
+                }),
+                slice_ip(Some(1), None)
+            );
+            assert_eq!(
+                ParseLimit(MaxHeaderLen {
+                    max_header_len: 20,
+                    header_len: 40,
+                    layer: Layer::TcpHeader
+                }),
+                slice_ip(None, Some(20))
+            );
+        }
+
+        // ip start
+        assert_eq!(
+            ParseLimit(MaxLayers {
+                max_layers: 1,
+                layer: Layer::TcpHeader
+            }),
+            SlicedPacket::from_ip_with_options(
+                &data[18..],
+                SliceOptions {
+                    limits: ParseLimits {
+                        max_layers: Some(1),
+                        max_header_len: None,
+                    },
+                    ..Default::default()
+                },
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_x_with_strict_tag_order() {
+        use err::{packet::SliceError::TagOrder, TagOrderError::*};
+        use ether_type::*;
+
+        // builds an ethernet frame with the given tag ether types
+        // (each tag is followed by a 2 byte tci)
+        let frame = |tags: &[EtherType], ether_type: EtherType| {
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+            for tag in tags {
+                data.extend_from_slice(&tag.0.to_be_bytes());
+                data.extend_from_slice(&[0, 1]);
+            }
+            data.extend_from_slice(&ether_type.0.to_be_bytes());
+            data.extend_from_slice(&[0; 8]);
+            data
+        };
+        let strict = SliceOptions {
+            strict_tag_order: true,
+            ..Default::default()
+        };
+
+        // valid orders
+        for tags in [
+            &[VLAN_TAGGED_FRAME][..],
+            &[PROVIDER_BRIDGING][..],
+            &[VLAN_DOUBLE_TAGGED_FRAME][..],
+            &[PROVIDER_BRIDGING, VLAN_TAGGED_FRAME][..],
+            &[VLAN_DOUBLE_TAGGED_FRAME, VLAN_TAGGED_FRAME][..],
+            &[MACSEC][..],
+        ] {
+            let data = frame(tags, ARP);
+            assert!(SlicedPacket::from_ethernet_with_options(&data, strict.clone()).is_ok());
+        }
+
+        // violations
+        for (tags, ether_type, expected) in [
+            (&[VLAN_TAGGED_FRAME][..], MACSEC, MacsecNotOutermost),
+            (
+                &[PROVIDER_BRIDGING, VLAN_TAGGED_FRAME][..],
+                MACSEC,
+                MacsecNotOutermost,
+            ),
+            (
+                &[VLAN_TAGGED_FRAME, VLAN_TAGGED_FRAME][..],
+                ARP,
+                CTagNotInnermost {
+                    next_ether_type: VLAN_TAGGED_FRAME,
+                },
+            ),
+            (
+                &[VLAN_TAGGED_FRAME, PROVIDER_BRIDGING][..],
+                ARP,
+                STagNotOutermost {
+                    ether_type: PROVIDER_BRIDGING,
+                },
+            ),
+            (
+                &[PROVIDER_BRIDGING, VLAN_DOUBLE_TAGGED_FRAME][..],
+                ARP,
+                STagNotOutermost {
+                    ether_type: VLAN_DOUBLE_TAGGED_FRAME,
+                },
+            ),
+            (
+                &[PROVIDER_BRIDGING, VLAN_TAGGED_FRAME, VLAN_TAGGED_FRAME][..],
+                ARP,
+                TooManyVlanTags,
+            ),
+        ] {
+            let data = frame(tags, ether_type);
+
+            // accepted by default
+            assert!(SlicedPacket::from_ethernet(&data).is_ok());
+
+            // reported in strict mode
+            assert_eq!(
+                TagOrder(expected.clone()),
+                SlicedPacket::from_ethernet_with_options(&data, strict.clone()).unwrap_err()
+            );
+            assert_eq!(
+                TagOrder(expected),
+                SlicedPacket::from_ether_type_with_options(tags[0], &data[14..], strict.clone())
+                    .unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_x_provider_backbone() {
+        use err::{packet::SliceError::*, Layer, ParseLimitError::*};
+        use ether_type::*;
+
+        // customer frame
+        let builder = PacketBuilder::ethernet2([13, 14, 15, 16, 17, 18], [19, 20, 21, 22, 23, 24])
+            .single_vlan(VlanId::try_new(2).unwrap())
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let mut customer = alloc::vec::Vec::with_capacity(builder.size(4));
+        builder.write(&mut customer, &[1, 2, 3, 4]).unwrap();
+
+        let itag = Ieee8021ahHeader {
+            pcp: VlanPcp::try_new(3).unwrap(),
+            drop_eligible_indicator: false,
+            use_customer_address: true,
+            isid: Ieee8021ahIsid::try_new(0x123456).unwrap(),
+        };
+        let b_tag = SingleVlanHeader {
+            pcp: VlanPcp::ZERO,
+            drop_eligible_indicator: false,
+            vlan_id: VlanId::try_new(1).unwrap(),
+            ether_type: PROVIDER_BACKBONE_BRIDGING,
+        };
+
+        for with_b_tag in [false, true] {
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(
+                &Ethernet2Header {
+                    destination: [1, 2, 3, 4, 5, 6],
+                    source: [7, 8, 9, 10, 11, 12],
+                    ether_type: if with_b_tag {
+                        PROVIDER_BRIDGING
+                    } else {
+                        PROVIDER_BACKBONE_BRIDGING
+                    },
+                }
+                .to_bytes(),
+            );
+            if with_b_tag {
+                data.extend_from_slice(&b_tag.to_bytes());
+            }
+            let itag_offset = data.len();
+            data.extend_from_slice(&itag.to_bytes());
+            data.extend_from_slice(&customer);
 
-        // from_ethernet
-        if test.link.is_some() {
-            let result = SlicedPacket::from_ethernet(&data).unwrap();
-            assert_test_result(&test, &payload, &result);
-        }
-        // from_ether_type (vlan at start)
-        if test.link.is_none() && test.vlan.is_some() {
-            for ether_type in VLAN_ETHER_TYPES {
-                let result = SlicedPacket::from_ether_type(ether_type, &data).unwrap();
+            // ok
+            {
+                let actual = SlicedPacket::from_ethernet(&data).unwrap();
+                let expected = SlicedPacket::from_ethernet(&customer).unwrap();
+                assert_eq!(expected.link, actual.link);
+                assert_eq!(expected.vlan, actual.vlan);
+                assert_eq!(expected.net, actual.net);
+                assert_eq!(expected.transport, actual.transport);
+
+                let backbone = actual.backbone.unwrap();
                 assert_eq!(
-                    result.link,
-                    Some(LinkSlice::EtherPayload(EtherPayloadSlice {
-                        ether_type,
-                        payload: &data
-                    }))
+                    Some(LinkSlice::Ethernet2(
+                        Ethernet2Slice::from_slice_without_fcs(&data).unwrap()
+                    )),
+                    backbone.link
                 );
-                assert_test_result(&test, &payload, &result);
+                assert_eq!(
+                    if with_b_tag {
+                        Some(VlanHeader::Single(b_tag.clone()))
+                    } else {
+                        None
+                    },
+                    backbone.vlan.map(|v| v.to_header())
+                );
+                assert_eq!(itag, backbone.itag.to_header());
+                assert_eq!(&customer[..], backbone.itag.payload());
             }
-        }
-        // from_ether_type (ip at start)
-        if test.link.is_none() && test.vlan.is_none() {
-            if let Some(ip) = &test.net {
-                let ether_type = match ip {
-                    NetHeaders::Ipv4(_, _) => ether_type::IPV4,
-                    NetHeaders::Ipv6(_, _) => ether_type::IPV6,
-                };
-                let result = SlicedPacket::from_ether_type(ether_type, &data).unwrap();
+
+            // from ether type (backbone link is the ether payload)
+            if false == with_b_tag {
+                let actual =
+                    SlicedPacket::from_ether_type(PROVIDER_BACKBONE_BRIDGING, &data[14..]).unwrap();
                 assert_eq!(
-                    result.link,
                     Some(LinkSlice::EtherPayload(EtherPayloadSlice {
-                        ether_type,
-                        payload: &data
-                    }))
+                        ether_type: PROVIDER_BACKBONE_BRIDGING,
+                        payload: &data[14..],
+                    })),
+                    actual.backbone.unwrap().link
+                );
+                assert_eq!(
+                    SlicedPacket::from_ethernet(&customer).unwrap().net,
+                    actual.net
                 );
-                assert_test_result(&test, &payload, &result);
             }
+
+            // len error in the I-TAG
+            assert_eq!(
+                Len(err::LenError {
+                    required_len: Ieee8021ahHeader::LEN,
+                    len: 3,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ieee8021ahHeader,
+                    layer_start_offset: itag_offset,
+                }),
+                SlicedPacket::from_ethernet(&data[..itag_offset + 3]).unwrap_err()
+            );
+
+            // len error in the customer ethernet II header
+            assert_eq!(
+                Len(err::LenError {
+                    required_len: Ethernet2Header::LEN,
+                    len: 13,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ethernet2Header,
+                    layer_start_offset: itag_offset + Ieee8021ahHeader::LEN,
+                }),
+                SlicedPacket::from_ethernet(&data[..itag_offset + Ieee8021ahHeader::LEN + 13])
+                    .unwrap_err()
+            );
+
+            // I-TAG counts as layer
+            assert_eq!(
+                ParseLimit(MaxLayers {
+                    max_layers: if with_b_tag { 2 } else { 1 },
+                    layer: Layer::Ieee8021ahHeader,
+                }),
+                SlicedPacket::from_ethernet_with_options(
+                    &data,
+                    SliceOptions {
+                        limits: ParseLimits {
+                            max_layers: Some(if with_b_tag { 2 } else { 1 }),
+                            max_header_len: None,
+                        },
+                        ..Default::default()
+                    },
+                )
+                .unwrap_err()
+            );
         }
-        // from_ip_slice
-        if test.link.is_none() && test.vlan.is_none() && test.net.is_some() {
-            let result = SlicedPacket::from_ip(&data).unwrap();
-            assert_test_result(&test, &payload, &result);
-        }
-    }
 
-    /// Check that the given errors get triggered if presented with the given
-    /// data.
-    fn from_slice_assert_err(test: &TestPacket, data: &[u8], err: SliceError) {
-        // from_ethernet_slice
-        if let Some(ref header) = test.link {
-            match header {
-                LinkHeader::Ethernet2(_) => {
-                    assert_eq!(err.clone(), SlicedPacket::from_ethernet(&data).unwrap_err())
-                }
-                LinkHeader::LinuxSll(_) => assert_eq!(
-                    err.clone(),
-                    SlicedPacket::from_linux_sll(&data).unwrap_err()
-                ),
+        // nested backbone frames are not unwrapped a second time
+        {
+            let mut data = alloc::vec::Vec::new();
+            for _ in 0..2 {
+                data.extend_from_slice(
+                    &Ethernet2Header {
+                        destination: [1, 2, 3, 4, 5, 6],
+                        source: [7, 8, 9, 10, 11, 12],
+                        ether_type: PROVIDER_BACKBONE_BRIDGING,
+                    }
+                    .to_bytes(),
+                );
+                data.extend_from_slice(&itag.to_bytes());
             }
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(actual.backbone.is_some());
+            assert_eq!(
+                Some(EtherPayloadSlice {
+                    ether_type: PROVIDER_BACKBONE_BRIDGING,
+                    payload: &data[32..],
+                }),
+                actual.ether_payload()
+            );
         }
-        // from_ether_type (vlan at start)
-        if test.link.is_none() && test.vlan.is_some() {
-            for ether_type in VLAN_ETHER_TYPES {
+    }
+
+    #[test]
+    fn from_ieee80211() {
+        use err::{packet::SliceError::*, Layer, ParseLimitError::*};
+
+        let header = [
+            0x08, 0x01, // frame control (data frame, "to DS")
+            0, 0, // duration
+            1, 2, 3, 4, 5, 6, // address 1 (BSSID)
+            7, 8, 9, 10, 11, 12, // address 2 (source)
+            13, 14, 15, 16, 17, 18, // address 3 (destination)
+            0x10, 0, // sequence control
+        ];
+        let llc_snap = [0xAA, 0xAA, 0x03, 0, 0, 0, 0x08, 0x00];
+
+        let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20).udp(21, 1234);
+        let mut ip = alloc::vec::Vec::with_capacity(builder.size(4));
+        builder.write(&mut ip, &[1, 2, 3, 4]).unwrap();
+
+        // ok with LLC/SNAP
+        {
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(&header);
+            data.extend_from_slice(&llc_snap);
+            data.extend_from_slice(&ip);
+
+            let expected = SlicedPacket::from_ip(&ip).unwrap();
+            for actual in [
+                SlicedPacket::from_ieee80211(&data).unwrap(),
+                SlicedPacket::from_ieee80211_with_options(&data, Default::default()).unwrap(),
+                SlicedPacket::from_ieee80211_located(&data).unwrap(),
+            ] {
                 assert_eq!(
-                    err.clone(),
-                    SlicedPacket::from_ether_type(ether_type, &data).unwrap_err()
+                    Some(LinkSlice::Ieee80211(
+                        Ieee80211DataSlice::from_slice(&data).unwrap()
+                    )),
+                    actual.link
+                );
+                assert_eq!(
+                    header.len() + llc_snap.len(),
+                    actual.link.as_ref().unwrap().header_len()
                 );
+                assert_eq!(expected.net, actual.net);
+                assert_eq!(expected.transport, actual.transport);
             }
         }
-        // from_ether_type (ip at start)
-        if test.link.is_none() && test.vlan.is_none() {
-            if let Some(ip) = &test.net {
-                let err = SlicedPacket::from_ether_type(
-                    match ip {
-                        NetHeaders::Ipv4(_, _) => ether_type::IPV4,
-                        NetHeaders::Ipv6(_, _) => ether_type::IPV6,
+
+        // ok without LLC/SNAP (frame body is not decoded)
+        {
+            let mut data = alloc::vec::Vec::new();
+            data.extend_from_slice(&header);
+            data.extend_from_slice(&[1, 2, 3, 4]);
+
+            let actual = SlicedPacket::from_ieee80211(&data).unwrap();
+            assert!(matches!(actual.link, Some(LinkSlice::Ieee80211(_))));
+            assert_eq!(None, actual.ether_payload());
+            assert_eq!(None, actual.net);
+            assert_eq!(None, actual.transport);
+        }
+
+        // len error
+        assert_eq!(
+            Len(err::LenError {
+                required_len: Ieee80211DataSlice::MIN_HEADER_LEN,
+                len: header.len() - 1,
+                len_source: LenSource::Slice,
+                layer: Layer::Ieee80211Header,
+                layer_start_offset: 0,
+            }),
+            SlicedPacket::from_ieee80211(&header[..header.len() - 1]).unwrap_err()
+        );
+
+        // content error (management frame)
+        {
+            let mut data = header;
+            data[0] = 0;
+            assert_eq!(
+                Ieee80211(err::ieee80211::HeaderError::NotDataFrame { frame_type: 0 }),
+                SlicedPacket::from_ieee80211(&data).unwrap_err()
+            );
+            assert_eq!(
+                Ieee80211(err::ieee80211::HeaderError::NotDataFrame { frame_type: 0 }),
+                SlicedPacket::from_ieee80211_located(&data)
+                    .unwrap_err()
+                    .error
+            );
+        }
+
+        // limits
+        assert_eq!(
+            ParseLimit(MaxLayers {
+                max_layers: 0,
+                layer: Layer::Ieee80211Header,
+            }),
+            SlicedPacket::from_ieee80211_with_options(
+                &header,
+                SliceOptions {
+                    limits: ParseLimits {
+                        max_layers: Some(0),
+                        max_header_len: None,
                     },
-                    &data,
-                )
-                .unwrap_err();
-                assert_eq!(err, err.clone());
+                    ..Default::default()
+                },
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_x_located() {
+        let eth = Ethernet2Header {
+            source: [1, 2, 3, 4, 5, 6],
+            destination: [7, 8, 9, 10, 11, 12],
+            ether_type: ether_type::VLAN_TAGGED_FRAME,
+        };
+        let vlan = SingleVlanHeader {
+            pcp: VlanPcp::ZERO,
+            drop_eligible_indicator: false,
+            vlan_id: VlanId::try_new(1).unwrap(),
+            ether_type: ether_type::IPV4,
+        };
+        let ipv4 = Ipv4Header::new(4, 1, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+
+        let mut data = alloc::vec::Vec::new();
+        eth.write(&mut data).unwrap();
+        vlan.write(&mut data).unwrap();
+        ipv4.write(&mut data).unwrap();
+        // incomplete udp header
+        data.extend_from_slice(&[0, 1, 2, 3]);
+
+        // ok
+        {
+            let mut full = data.clone();
+            // increase the ipv4 total length to cover the full udp header
+            full[14 + 4 + 3] += 4;
+            full.extend_from_slice(&[0, 8, 0, 0]);
+            let expected = SlicedPacket::from_ethernet(&full).unwrap();
+            assert_eq!(
+                expected,
+                SlicedPacket::from_ethernet_located(&full).unwrap()
+            );
+        }
+
+        // error in the transport layer
+        {
+            let actual = SlicedPacket::from_ethernet_located(&data).unwrap_err();
+            assert_eq!(
+                SlicedPacket::from_ethernet(&data).unwrap_err(),
+                actual.error
+            );
+            assert_eq!(14 + 4 + 20, actual.layer_start_offset);
+            assert_eq!(
+                &[
+                    (Layer::Ethernet2Header, 14),
+                    (Layer::VlanHeader, 4),
+                    (Layer::Ipv4Header, 20)
+                ],
+                &actual.parsed_layers[..]
+            );
+            assert_eq!(3, actual.parsed_layer_count);
+
+            // layers are only recorded for the located errors
+            let mut cursor = SlicedPacketCursor::new(&data);
+            let result = cursor.slice_ethernet2();
+            assert!(cursor.parsed_layers.is_empty());
+            assert_eq!(actual.error, cursor.finish(result).unwrap_err());
+        }
+
+        // error starting from the vlan header
+        {
+            let actual =
+                SlicedPacket::from_ether_type_located(ether_type::VLAN_TAGGED_FRAME, &data[14..])
+                    .unwrap_err();
+            assert_eq!(4 + 20, actual.layer_start_offset);
+            assert_eq!(
+                &[(Layer::VlanHeader, 4), (Layer::Ipv4Header, 20)],
+                &actual.parsed_layers[..]
+            );
+        }
+
+        // error in the ip header
+        {
+            let mut bad = data.clone();
+            // unexpected ip version
+            bad[14 + 4] = 0x35;
+            let actual = SlicedPacket::from_ethernet_located(&bad).unwrap_err();
+            assert_eq!(14 + 4, actual.layer_start_offset);
+            assert_eq!(
+                &[(Layer::Ethernet2Header, 14), (Layer::VlanHeader, 4)],
+                &actual.parsed_layers[..]
+            );
+            let actual = SlicedPacket::from_ip_located(&bad[14 + 4..]).unwrap_err();
+            assert_eq!(0, actual.layer_start_offset);
+            assert!(actual.parsed_layers.is_empty());
+        }
+
+        // error in the linux sll header
+        {
+            let actual = SlicedPacket::from_linux_sll_located(&[0, 1]).unwrap_err();
+            assert_eq!(0, actual.layer_start_offset);
+            assert!(actual.parsed_layers.is_empty());
+        }
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[test]
+    fn from_x_located_ipv6_exts() {
+        use ip_number::*;
+
+        // builds an ethernet frame with an ipv6 header followed by the
+        // given extension headers & a cut off udp header
+        let build = |exts: &[(IpNumber, alloc::vec::Vec<u8>)]| {
+            let mut payload = alloc::vec::Vec::new();
+            for (_, ext) in exts {
+                payload.extend_from_slice(ext);
+            }
+            payload.extend_from_slice(&[0, 1, 2, 3]);
+
+            let mut data = alloc::vec::Vec::new();
+            Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [7, 8, 9, 10, 11, 12],
+                ether_type: ether_type::IPV6,
+            }
+            .write(&mut data)
+            .unwrap();
+            Ipv6Header {
+                traffic_class: 0,
+                flow_label: Ipv6FlowLabel::ZERO,
+                payload_length: payload.len() as u16,
+                next_header: exts.first().map(|(n, _)| *n).unwrap_or(UDP),
+                hop_limit: 1,
+                source: [0; 16],
+                destination: [0; 16],
             }
+            .write(&mut data)
+            .unwrap();
+            data.extend_from_slice(&payload);
+            data
+        };
+        let raw = |next: IpNumber| {
+            Ipv6RawExtHeader::new_raw(next, &[0; 6])
+                .unwrap()
+                .to_bytes()
+                .to_vec()
+        };
+
+        // every extension header is recorded as a separate layer
+        {
+            let data = build(&[
+                (IPV6_HOP_BY_HOP, raw(IPV6_ROUTE)),
+                (IPV6_ROUTE, raw(IPV6_FRAG)),
+                (
+                    IPV6_FRAG,
+                    Ipv6FragmentHeader::new(AUTH, IpFragOffset::ZERO, false, 0)
+                        .to_bytes()
+                        .to_vec(),
+                ),
+                (
+                    AUTH,
+                    IpAuthHeader::new(IPV6_DEST_OPTIONS, 0, 0, &[])
+                        .unwrap()
+                        .to_bytes()
+                        .to_vec(),
+                ),
+                (IPV6_DEST_OPTIONS, raw(UDP)),
+            ]);
+            let actual = SlicedPacket::from_ethernet_located(&data).unwrap_err();
+            assert_eq!(14 + 40 + 8 + 8 + 8 + 12 + 8, actual.layer_start_offset);
+            assert_eq!(
+                &[
+                    (Layer::Ethernet2Header, 14),
+                    (Layer::Ipv6Header, 40),
+                    (Layer::Ipv6HopByHopHeader, 8),
+                    (Layer::Ipv6RouteHeader, 8),
+                    (Layer::Ipv6FragHeader, 8),
+                    (Layer::IpAuthHeader, 12),
+                    (Layer::Ipv6DestOptionsHeader, 8),
+                ],
+                &actual.parsed_layers[..]
+            );
+            assert_eq!(7, actual.parsed_layer_count);
         }
-        // from_ip_slice
-        if test.link.is_none() && test.vlan.is_none() && test.net.is_some() {
-            assert_eq!(err, SlicedPacket::from_ip(&data).unwrap_err());
+
+        // mobility header
+        {
+            let data = build(&[(
+                MOBILITY,
+                MobilityHeader::new(UDP, MobilityMessage::BindingRefreshRequest, &[])
+                    .unwrap()
+                    .to_bytes()
+                    .to_vec(),
+            )]);
+            let actual = SlicedPacket::from_ethernet_located(&data).unwrap_err();
+            assert_eq!(14 + 40 + 8, actual.layer_start_offset);
+            assert_eq!(
+                &[
+                    (Layer::Ethernet2Header, 14),
+                    (Layer::Ipv6Header, 40),
+                    (Layer::Ipv6MobilityHeader, 8),
+                ],
+                &actual.parsed_layers[..]
+            );
+        }
+
+        // only the last layers are kept for long extension header chains
+        {
+            let mut exts = alloc::vec::Vec::new();
+            for _ in 0..19 {
+                exts.push((IPV6_DEST_OPTIONS, raw(IPV6_DEST_OPTIONS)));
+            }
+            exts.push((IPV6_DEST_OPTIONS, raw(UDP)));
+            let data = build(&exts);
+
+            let actual = SlicedPacket::from_ethernet_located(&data).unwrap_err();
+            assert_eq!(14 + 40 + 20 * 8, actual.layer_start_offset);
+            assert_eq!(22, actual.parsed_layer_count);
+            assert_eq!(
+                &[(Layer::Ipv6DestOptionsHeader, 8);
+                    err::packet::LocatedSliceError::MAX_PARSED_LAYERS],
+                &actual.parsed_layers[..]
+            );
         }
     }
 
@@ -1554,6 +4265,7 @@ mod test {
                 let s = SlicedPacket{
                     link: None,
                     vlan: None,
+                    backbone: None,
                     net: None,
                     transport: None,
                 };
@@ -1664,4 +4376,219 @@ mod test {
             }
         }
     }
+
+    #[cfg(all(feature = "icmp", feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn dedup_digest() {
+        use alloc::vec::Vec;
+
+        // checks that the modified packet has the same (or a different)
+        // digest & that PacketHeaders returns the same digests
+        fn check(from: fn(&[u8]) -> (u64, u64), base: &[u8], modify: &[(usize, u8)], equal: bool) {
+            let mut modified = base.to_vec();
+            for (index, value) in modify {
+                modified[*index] ^= *value;
+            }
+            let (a, a_headers) = from(base);
+            let (b, b_headers) = from(&modified);
+            assert_eq!(a, a_headers);
+            assert_eq!(b, b_headers);
+            assert_eq!(equal, a == b, "{:?}", modify);
+        }
+        let from_ip = |data: &[u8]| {
+            (
+                SlicedPacket::from_ip(data).unwrap().dedup_digest(),
+                PacketHeaders::from_ip_slice(data).unwrap().dedup_digest(),
+            )
+        };
+        let from_ethernet = |data: &[u8]| {
+            (
+                SlicedPacket::from_ethernet(data).unwrap().dedup_digest(),
+                PacketHeaders::from_ethernet_slice(data)
+                    .unwrap()
+                    .dedup_digest(),
+            )
+        };
+
+        // ipv4 & udp
+        {
+            let mut data = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 64)
+                .udp(1234, 53)
+                .write(&mut data, &[1, 2, 3, 4])
+                .unwrap();
+            // dscp & ecn, ttl, header checksum, udp checksum
+            for modify in [&[(1, 0xff)][..], &[(8, 1)], &[(10, 1), (11, 1)], &[(26, 1)]] {
+                check(from_ip, &data, modify, true);
+            }
+            // identification, protocol, addresses, ports & payload
+            for modify in [4, 9, 12, 19, 20, 23, 28, 31] {
+                check(from_ip, &data, &[(modify, 1)], false);
+            }
+        }
+
+        // ipv6 & tcp
+        {
+            let mut data = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 64)
+                .tcp(1234, 443, 1, 1024)
+                .ack(2)
+                .write(&mut data, &[1, 2, 3, 4])
+                .unwrap();
+            // traffic class, flow label, hop limit, tcp flags, window & checksum
+            for modify in [
+                &[(0, 0x0f), (1, 0xf0)][..],
+                &[(3, 1)],
+                &[(7, 1)],
+                &[(53, 0x08)],
+                &[(55, 1)],
+                &[(56, 1)],
+            ] {
+                check(from_ip, &data, modify, true);
+            }
+            // addresses, ports, sequence & acknowledgment number & payload
+            for modify in [8, 39, 40, 43, 47, 51, 60, 63] {
+                check(from_ip, &data, &[(modify, 1)], false);
+            }
+        }
+
+        // icmpv4
+        {
+            let mut data = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 64)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut data, &[1, 2])
+                .unwrap();
+            check(from_ip, &data, &[(22, 1), (23, 1)], true);
+            for modify in [21, 24, 27, 28] {
+                check(from_ip, &data, &[(modify, 1)], false);
+            }
+        }
+
+        // icmpv6
+        {
+            let mut data = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 64)
+                .icmpv6_echo_request(1, 2)
+                .write(&mut data, &[1, 2])
+                .unwrap();
+            check(from_ip, &data, &[(42, 1), (43, 1)], true);
+            for modify in [41, 44, 47, 48] {
+                check(from_ip, &data, &[(modify, 1)], false);
+            }
+        }
+
+        // ethernet with a non ip payload
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                source: [1, 2, 3, 4, 5, 6],
+                destination: [7, 8, 9, 10, 11, 12],
+                ether_type: ether_type::ARP,
+            }
+            .write(&mut data)
+            .unwrap();
+            data.extend_from_slice(&[1, 2, 3, 4]);
+            // mac addresses
+            check(from_ethernet, &data, &[(0, 1), (6, 1)], true);
+            // ether type & payload
+            for modify in [13, 14, 17] {
+                check(from_ethernet, &data, &[(modify, 1)], false);
+            }
+        }
+
+        // stable digest of an empty packet
+        assert_eq!(
+            hash::Fnv1a64::new().add_slice(&[0]).value(),
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                backbone: None,
+                net: None,
+                transport: None,
+            }
+            .dedup_digest()
+        );
+    }
+
+    #[cfg(not(feature = "ipv6"))]
+    #[test]
+    fn ipv6_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([0; 16], [1; 16], 20)
+            .udp(1, 2)
+            .write(&mut packet, &[3, 4])
+            .unwrap();
+
+        // ipv6 packets are returned as ether payload
+        let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+        assert!(sliced.net.is_none());
+        assert!(sliced.transport.is_none());
+        assert_eq!(
+            Some(EtherPayloadSlice {
+                ether_type: EtherType::IPV6,
+                payload: &packet[Ethernet2Header::LEN..],
+            }),
+            sliced.ether_payload()
+        );
+
+        // when starting at the ip layer the version is unsupported
+        assert_eq!(
+            SliceError::Ip(err::ip::HeaderError::UnsupportedIpVersion { version_number: 6 }),
+            SlicedPacket::from_ip(&packet[Ethernet2Header::LEN..]).unwrap_err()
+        );
+    }
+
+    #[cfg(not(feature = "tcp"))]
+    #[test]
+    fn tcp_disabled() {
+        let mut packet = alloc::vec::Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .tcp(1, 2, 3, 4)
+            .write(&mut packet, &[5, 6])
+            .unwrap();
+
+        // tcp segments are returned as ip payload
+        let sliced = SlicedPacket::from_ip(&packet).unwrap();
+        assert!(sliced.transport.is_none());
+        let payload = sliced.ip_payload().unwrap();
+        assert_eq!(ip_number::TCP, payload.ip_number);
+        assert_eq!(&packet[Ipv4Header::MIN_LEN..], payload.payload);
+    }
+
+    #[cfg(not(feature = "icmp"))]
+    #[test]
+    fn icmp_disabled() {
+        // icmpv4
+        {
+            let mut packet = alloc::vec::Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut packet, &[3, 4])
+                .unwrap();
+
+            // icmp messages are returned as ip payload
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert!(sliced.transport.is_none());
+            let payload = sliced.ip_payload().unwrap();
+            assert_eq!(ip_number::ICMP, payload.ip_number);
+            assert_eq!(&packet[Ipv4Header::MIN_LEN..], payload.payload);
+        }
+        // icmpv6
+        #[cfg(feature = "ipv6")]
+        {
+            let mut packet = alloc::vec::Vec::new();
+            PacketBuilder::ipv6([0; 16], [1; 16], 20)
+                .icmpv6_echo_request(1, 2)
+                .write(&mut packet, &[3, 4])
+                .unwrap();
+
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            assert!(sliced.transport.is_none());
+            let payload = sliced.ip_payload().unwrap();
+            assert_eq!(ip_number::IPV6_ICMP, payload.ip_number);
+            assert_eq!(&packet[Ipv6Header::LEN..], payload.payload);
+        }
+    }
 }
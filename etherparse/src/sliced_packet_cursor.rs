@@ -1,4 +1,5 @@
-use crate::*;
+use crate::{err::Layer, *};
+use arrayvec::ArrayVec;
 
 /// Helper class for slicing packets
 pub(crate) struct SlicedPacketCursor<'a> {
@@ -6,6 +7,19 @@ pub(crate) struct SlicedPacketCursor<'a> {
     pub offset: usize,
     pub len_source: LenSource,
     pub result: SlicedPacket<'a>,
+    /// Last successfully parsed layers.
+    pub parsed_layers:
+        ArrayVec<(Layer, usize), { err::packet::LocatedSliceError::MAX_PARSED_LAYERS }>,
+    /// Number of successfully parsed layers (including the layers no
+    /// longer present in `parsed_layers`).
+    pub parsed_layer_count: usize,
+    /// If true the successfully parsed layers are recorded in
+    /// `parsed_layers` (only needed for the located errors).
+    pub track_layers: bool,
+    /// Options influencing how the packet gets sliced.
+    pub options: SliceOptions,
+    /// Number of layers decoded so far (checked against the limits).
+    pub layer_count: usize,
 }
 
 impl<'a> SlicedPacketCursor<'a> {
@@ -17,25 +31,164 @@ impl<'a> SlicedPacketCursor<'a> {
             result: SlicedPacket {
                 link: None,
                 vlan: None,
+                backbone: None,
                 net: None,
                 transport: None,
             },
+            parsed_layers: ArrayVec::new_const(),
+            parsed_layer_count: 0,
+            track_layers: false,
+            options: SliceOptions::default(),
+            layer_count: 0,
+        }
+    }
+
+    pub fn with_options(slice: &'a [u8], options: SliceOptions) -> SlicedPacketCursor<'a> {
+        let mut result = SlicedPacketCursor::new(slice);
+        result.options = options;
+        result
+    }
+
+    /// Creates a cursor that records the successfully parsed layers
+    /// (see [`SlicedPacketCursor::finish_located`]).
+    pub fn located(slice: &'a [u8]) -> SlicedPacketCursor<'a> {
+        let mut result = SlicedPacketCursor::new(slice);
+        result.track_layers = true;
+        result
+    }
+
+    /// Converts the result of one of the "slice_*" functions into the
+    /// sliced packet or error.
+    pub fn finish(
+        self,
+        result: Result<(), err::packet::SliceError>,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        result.map(|()| self.result)
+    }
+
+    /// Converts the result of one of the "slice_*" functions into the
+    /// sliced packet or an error containing the location of the error
+    /// (the parsed layers are only present if the cursor was created via
+    /// [`SlicedPacketCursor::located`]).
+    #[allow(clippy::result_large_err)]
+    pub fn finish_located(
+        self,
+        result: Result<(), err::packet::SliceError>,
+    ) -> Result<SlicedPacket<'a>, err::packet::LocatedSliceError> {
+        match result {
+            Ok(()) => Ok(self.result),
+            Err(error) => Err(err::packet::LocatedSliceError {
+                layer_start_offset: match &error {
+                    err::packet::SliceError::Len(len) => len.layer_start_offset,
+                    _ => self.offset,
+                },
+                error,
+                parsed_layers: self.parsed_layers,
+                parsed_layer_count: self.parsed_layer_count,
+            }),
+        }
+    }
+
+    /// Checks before a layer gets decoded that decoding it (with a header
+    /// of at least `min_header_len` bytes at the current offset) does not
+    /// exceed the configured limits.
+    fn check_limits(
+        &mut self,
+        layer: Layer,
+        min_header_len: usize,
+    ) -> Result<(), err::packet::SliceError> {
+        use err::{packet::SliceError::ParseLimit, ParseLimitError::*};
+
+        if let Some(max_layers) = self.options.limits.max_layers {
+            if self.layer_count >= max_layers {
+                return Err(ParseLimit(MaxLayers { max_layers, layer }));
+            }
+        }
+        self.check_header_len(layer, self.offset + min_header_len)?;
+        self.layer_count += 1;
+        Ok(())
+    }
+
+    /// Checks that a header ending at `header_end` (offset relative to
+    /// the start of the packet) does not exceed the configured maximum
+    /// header length (used after decoding headers with a variable length).
+    fn check_header_len(
+        &self,
+        layer: Layer,
+        header_end: usize,
+    ) -> Result<(), err::packet::SliceError> {
+        use err::{packet::SliceError::ParseLimit, ParseLimitError::*};
+
+        if let Some(max_header_len) = self.options.limits.max_header_len {
+            if header_end > max_header_len {
+                return Err(ParseLimit(MaxHeaderLen {
+                    max_header_len,
+                    header_len: header_end,
+                    layer,
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    /// Records a successfully parsed layer (dropping the oldest recorded
+    /// layer if the maximum number of recorded layers is reached).
+    fn push_layer(&mut self, layer: Layer, len: usize) {
+        if false == self.track_layers {
+            return;
+        }
+        if self.parsed_layers.is_full() {
+            self.parsed_layers.remove(0);
+        }
+        self.parsed_layers.push((layer, len));
+        self.parsed_layer_count += 1;
+    }
+
+    /// Records the IPv4 header & its extension headers as parsed layers.
+    fn push_ipv4_layers(&mut self, ipv4: &Ipv4Slice) {
+        if false == self.track_layers {
+            return;
+        }
+        self.push_layer(Layer::Ipv4Header, ipv4.header().slice().len());
+        if let Some(auth) = ipv4.extensions().auth {
+            self.push_layer(Layer::IpAuthHeader, auth.slice().len());
+        }
+    }
+
+    /// Records the IPv6 header & each of its extension headers as parsed layers.
+    fn push_ipv6_layers(&mut self, ipv6: &Ipv6Slice) {
+        if false == self.track_layers {
+            return;
+        }
+        use Ipv6ExtensionSlice::*;
+
+        self.push_layer(Layer::Ipv6Header, Ipv6Header::LEN);
+        for ext in ipv6.extensions().clone() {
+            let (layer, len) = match ext {
+                HopByHop(s) => (Layer::Ipv6HopByHopHeader, s.slice().len()),
+                Routing(s) => (Layer::Ipv6RouteHeader, s.slice().len()),
+                Fragment(s) => (Layer::Ipv6FragHeader, s.slice().len()),
+                DestinationOptions(s) => (Layer::Ipv6DestOptionsHeader, s.slice().len()),
+                Authentication(s) => (Layer::IpAuthHeader, s.slice().len()),
+                Mobility(s) => (Layer::Ipv6MobilityHeader, s.slice().len()),
+            };
+            self.push_layer(layer, len);
         }
     }
 
     fn move_by(&mut self, len: usize) {
-        unsafe {
-            use core::slice::from_raw_parts;
-            self.slice = from_raw_parts(self.slice.as_ptr().add(len), self.slice.len() - len);
+        unchecked! {
+            self.slice = get_unchecked_slice(self.slice, len, self.slice.len() - len);
         }
         self.offset += len;
     }
 
-    pub fn slice_ethernet2(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+    pub fn slice_ethernet2(&mut self) -> Result<(), err::packet::SliceError> {
         use err::packet::SliceError::*;
         use ether_type::*;
         use LinkSlice::*;
 
+        self.check_limits(Layer::Ethernet2Header, Ethernet2Header::LEN)?;
         let result = Ethernet2Slice::from_slice_without_fcs(self.slice)
             .map_err(|err| Len(err.add_offset(self.offset)))?;
 
@@ -44,20 +197,44 @@ impl<'a> SlicedPacketCursor<'a> {
 
         //set the new data
         self.move_by(result.header_len());
+        self.push_layer(Layer::Ethernet2Header, result.header_len());
         self.result.link = Some(Ethernet2(result));
 
         //continue parsing (if required)
         match ether_type {
             IPV4 => self.slice_ipv4(),
+            #[cfg(feature = "ipv6")]
             IPV6 => self.slice_ipv6(),
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => self.slice_vlan(),
-            _ => Ok(self.result),
+            ether_type if ether_type.is_vlan_tag() => self.slice_vlan(ether_type),
+            PROVIDER_BACKBONE_BRIDGING if self.result.backbone.is_none() => self.slice_ieee8021ah(),
+            _ => Ok(()),
         }
     }
 
-    pub fn slice_linux_sll(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+    pub fn slice_ether_type(
+        &mut self,
+        ether_type: EtherType,
+    ) -> Result<(), err::packet::SliceError> {
+        use ether_type::*;
+
+        self.result.link = Some(LinkSlice::EtherPayload(EtherPayloadSlice {
+            ether_type,
+            payload: self.slice,
+        }));
+        match ether_type {
+            IPV4 => self.slice_ipv4(),
+            #[cfg(feature = "ipv6")]
+            IPV6 => self.slice_ipv6(),
+            ether_type if ether_type.is_vlan_tag() => self.slice_vlan(ether_type),
+            PROVIDER_BACKBONE_BRIDGING if self.result.backbone.is_none() => self.slice_ieee8021ah(),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn slice_linux_sll(&mut self) -> Result<(), err::packet::SliceError> {
         use err::packet::SliceError::*;
 
+        self.check_limits(Layer::LinuxSllHeader, LinuxSllHeader::LEN)?;
         let result = LinuxSllSlice::from_slice(self.slice).map_err(|err| match err {
             err::linux_sll::HeaderSliceError::Len(len) => Len(len.add_offset(self.offset)),
             err::linux_sll::HeaderSliceError::Content(content) => {
@@ -70,17 +247,90 @@ impl<'a> SlicedPacketCursor<'a> {
 
         //set the new data
         self.move_by(result.header_len());
+        self.push_layer(Layer::LinuxSllHeader, result.header_len());
         self.result.link = Some(LinkSlice::LinuxSll(result));
 
         //continue parsing (if required)
         match protocol_type {
             LinuxSllProtocolType::EtherType(EtherType::IPV4) => self.slice_ipv4(),
+            #[cfg(feature = "ipv6")]
             LinuxSllProtocolType::EtherType(EtherType::IPV6) => self.slice_ipv6(),
-            _ => Ok(self.result),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn slice_ieee80211(&mut self) -> Result<(), err::packet::SliceError> {
+        use err::packet::SliceError::*;
+        use ether_type::*;
+
+        self.check_limits(Layer::Ieee80211Header, Ieee80211DataSlice::MIN_HEADER_LEN)?;
+        let result = Ieee80211DataSlice::from_slice(self.slice).map_err(|err| match err {
+            err::ieee80211::HeaderSliceError::Len(len) => Len(len.add_offset(self.offset)),
+            err::ieee80211::HeaderSliceError::Content(content) => Ieee80211(content),
+        })?;
+
+        // the LLC/SNAP header (if present) is treated as part of the
+        // link layer header
+        let ether_payload = result.ether_payload();
+        let header_len = match ether_payload {
+            Some(_) => result.header_len() + Ieee80211DataSlice::LLC_SNAP_LEN,
+            None => result.header_len(),
+        };
+        self.check_header_len(Layer::Ieee80211Header, self.offset + header_len)?;
+
+        //set the new data
+        self.move_by(header_len);
+        self.push_layer(Layer::Ieee80211Header, header_len);
+        self.result.link = Some(LinkSlice::Ieee80211(result));
+
+        //continue parsing (if required)
+        match ether_payload.map(|p| p.ether_type) {
+            Some(IPV4) => self.slice_ipv4(),
+            #[cfg(feature = "ipv6")]
+            Some(IPV6) => self.slice_ipv6(),
+            Some(ether_type) if ether_type.is_vlan_tag() => self.slice_vlan(ether_type),
+            _ => Ok(()),
         }
     }
 
-    pub fn slice_vlan(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+    /// Checks the order of the VLAN tags & MACsec SecTAG if
+    /// [`SliceOptions::strict_tag_order`] is enabled.
+    ///
+    /// `tag_ether_type` is the ether type identifying the VLAN tag that
+    /// was decoded last, `tag_count` the number of decoded VLAN tags
+    /// and `next_ether_type` the ether type following the tag.
+    fn check_tag_order(
+        &self,
+        tag_ether_type: EtherType,
+        tag_count: usize,
+        next_ether_type: EtherType,
+    ) -> Result<(), err::packet::SliceError> {
+        use err::{packet::SliceError::TagOrder, TagOrderError::*};
+        use ether_type::*;
+
+        if false == self.options.strict_tag_order {
+            return Ok(());
+        }
+        match next_ether_type {
+            MACSEC => Err(TagOrder(MacsecNotOutermost)),
+            next if next.is_vlan_tag() => {
+                if tag_count >= 2 {
+                    Err(TagOrder(TooManyVlanTags))
+                } else if next != VLAN_TAGGED_FRAME {
+                    Err(TagOrder(STagNotOutermost { ether_type: next }))
+                } else if tag_ether_type == VLAN_TAGGED_FRAME {
+                    Err(TagOrder(CTagNotInnermost {
+                        next_ether_type: next,
+                    }))
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn slice_vlan(&mut self, tag_ether_type: EtherType) -> Result<(), err::packet::SliceError> {
         use err::packet::SliceError::*;
         use ether_type::*;
         use VlanSlice::*;
@@ -88,39 +338,83 @@ impl<'a> SlicedPacketCursor<'a> {
         // cache the starting slice so the later combining
         // of outer & inner vlan is defined behavior (for miri)
         let outer_start_slice = self.slice;
+        self.check_limits(Layer::VlanHeader, SingleVlanHeader::LEN)?;
         let outer = SingleVlanSlice::from_slice(self.slice)
             .map_err(|err| Len(err.add_offset(self.offset)))?;
         self.result.vlan = Some(SingleVlan(outer.clone()));
         self.move_by(outer.header_len());
+        self.push_layer(Layer::VlanHeader, outer.header_len());
+        self.check_tag_order(tag_ether_type, 1, outer.ether_type())?;
 
         //check if it is a double vlan header
         match outer.ether_type() {
             //in case of a double vlan header continue with the inner
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+            ether_type if ether_type.is_vlan_tag() => {
+                self.check_limits(Layer::VlanHeader, SingleVlanHeader::LEN)?;
                 let inner = SingleVlanSlice::from_slice(self.slice)
                     .map_err(|err| Len(err.add_offset(self.offset)))?;
                 self.move_by(inner.header_len());
+                self.push_layer(Layer::VlanHeader, inner.header_len());
 
                 let inner_ether_type = inner.ether_type();
+                self.check_tag_order(outer.ether_type(), 2, inner_ether_type)?;
                 self.result.vlan = Some(DoubleVlan(DoubleVlanSlice {
                     slice: outer_start_slice,
                 }));
 
                 match inner_ether_type {
                     IPV4 => self.slice_ipv4(),
+                    #[cfg(feature = "ipv6")]
                     IPV6 => self.slice_ipv6(),
-                    _ => Ok(self.result),
+                    PROVIDER_BACKBONE_BRIDGING if self.result.backbone.is_none() => {
+                        self.slice_ieee8021ah()
+                    }
+                    _ => Ok(()),
                 }
             }
             IPV4 => self.slice_ipv4(),
+            #[cfg(feature = "ipv6")]
             IPV6 => self.slice_ipv6(),
-            _ => Ok(self.result),
+            PROVIDER_BACKBONE_BRIDGING if self.result.backbone.is_none() => self.slice_ieee8021ah(),
+            _ => Ok(()),
         }
     }
 
-    pub fn slice_ip(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+    /// Slices an IEEE 802.1ah I-TAG, moves the already decoded link & vlan
+    /// headers to `backbone` & continues with the customer Ethernet II frame.
+    ///
+    /// Only called if no I-TAG was decoded before (no recursion into
+    /// nested backbone frames).
+    pub fn slice_ieee8021ah(&mut self) -> Result<(), err::packet::SliceError> {
         use err::packet::SliceError::*;
 
+        self.check_limits(Layer::Ieee8021ahHeader, Ieee8021ahHeader::LEN)?;
+        let itag = Ieee8021ahSlice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+        self.move_by(Ieee8021ahHeader::LEN);
+        self.push_layer(Layer::Ieee8021ahHeader, Ieee8021ahHeader::LEN);
+        self.result.backbone = Some(ProviderBackboneSlice {
+            link: self.result.link.take(),
+            vlan: self.result.vlan.take(),
+            itag,
+        });
+
+        // continue with the customer frame
+        self.slice_ethernet2()
+    }
+
+    pub fn slice_ip(&mut self) -> Result<(), err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        // check the limits based on the version (decoding fails anyways
+        // if the version is unknown)
+        match self.slice.first().map(|v| v >> 4) {
+            Some(4) => self.check_limits(Layer::Ipv4Header, Ipv4Header::MIN_LEN)?,
+            #[cfg(feature = "ipv6")]
+            Some(6) => self.check_limits(Layer::Ipv6Header, Ipv6Header::LEN)?,
+            _ => self.check_limits(Layer::IpHeader, 0)?,
+        }
+
         // slice header, extension headers and identify payload range
         let ip = IpSlice::from_slice(self.slice).map_err(|err| {
             use err::ip::SliceError as I;
@@ -141,40 +435,39 @@ impl<'a> SlicedPacketCursor<'a> {
         let payload = ip.payload().clone();
 
         // set the new data
-        self.offset += unsafe {
+        let ip_len = unchecked! {
             // SAFETY: The payload is a subslice of self.slice.
             // therefor calculating the offset from it is safe and
             // the result should always be a positive number.
-            payload.payload.as_ptr().offset_from(self.slice.as_ptr()) as usize
+            get_unchecked_offset(self.slice, payload.payload)
+        };
+        let ip_layer = match &ip {
+            IpSlice::Ipv4(_) => Layer::Ipv4Header,
+            IpSlice::Ipv6(_) => Layer::Ipv6Header,
         };
+        self.check_header_len(ip_layer, self.offset + ip_len)?;
+        self.offset += ip_len;
         self.len_source = payload.len_source;
         self.slice = payload.payload;
+        match &ip {
+            IpSlice::Ipv4(ipv4) => self.push_ipv4_layers(ipv4),
+            IpSlice::Ipv6(ipv6) => self.push_ipv6_layers(ipv6),
+        }
+        let first_fragment = match &ip {
+            IpSlice::Ipv4(ipv4) => ipv4_first_fragment(ipv4),
+            IpSlice::Ipv6(ipv6) => ipv6_first_fragment(ipv6),
+        };
         self.result.net = Some(ip.into());
 
         // continue to the lower layers
-        if payload.fragmented {
-            Ok(self.result)
-        } else {
-            match payload.ip_number {
-                ip_number::ICMP => self.slice_icmp4().map_err(Len),
-                ip_number::UDP => self.slice_udp().map_err(Len),
-                ip_number::TCP => self.slice_tcp().map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(err),
-                        I::Content(err) => Tcp(err),
-                    }
-                }),
-                ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
-                _ => Ok(self.result),
-            }
-        }
+        self.slice_ip_payload(payload.ip_number, payload.fragmented, first_fragment)
     }
 
-    pub fn slice_ipv4(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+    pub fn slice_ipv4(&mut self) -> Result<(), err::packet::SliceError> {
         use err::packet::SliceError::*;
 
         // slice ipv4 header & extension headers
+        self.check_limits(Layer::Ipv4Header, Ipv4Header::MIN_LEN)?;
         let ipv4 = Ipv4Slice::from_slice(self.slice).map_err(|err| {
             use err::ipv4::SliceError as I;
             match err {
@@ -191,38 +484,28 @@ impl<'a> SlicedPacketCursor<'a> {
         let payload = ipv4.payload().clone();
 
         // set the new data
-        self.offset += unsafe {
+        let ip_len = unchecked! {
             // SAFETY: The payload is a subslice of self.slice.
             // therefor calculating the offset from it is safe and
             // the result should always be a positive number.
-            payload.payload.as_ptr().offset_from(self.slice.as_ptr()) as usize
+            get_unchecked_offset(self.slice, payload.payload)
         };
+        self.check_header_len(Layer::Ipv4Header, self.offset + ip_len)?;
+        self.offset += ip_len;
         self.len_source = payload.len_source;
         self.slice = payload.payload;
+        self.push_ipv4_layers(&ipv4);
+        let first_fragment = ipv4_first_fragment(&ipv4);
         self.result.net = Some(NetSlice::Ipv4(ipv4));
 
-        if payload.fragmented {
-            Ok(self.result)
-        } else {
-            match payload.ip_number {
-                ip_number::UDP => self.slice_udp().map_err(Len),
-                ip_number::TCP => self.slice_tcp().map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(err),
-                        I::Content(err) => Tcp(err),
-                    }
-                }),
-                ip_number::ICMP => self.slice_icmp4().map_err(Len),
-                ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
-                _ => Ok(self.result),
-            }
-        }
+        self.slice_ip_payload(payload.ip_number, payload.fragmented, first_fragment)
     }
 
-    pub fn slice_ipv6(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+    #[cfg(feature = "ipv6")]
+    pub fn slice_ipv6(&mut self) -> Result<(), err::packet::SliceError> {
         use err::packet::SliceError::*;
 
+        self.check_limits(Layer::Ipv6Header, Ipv6Header::LEN)?;
         let ipv6 = Ipv6Slice::from_slice(self.slice).map_err(|err| {
             use err::ipv6::SliceError as I;
             match err {
@@ -239,28 +522,122 @@ impl<'a> SlicedPacketCursor<'a> {
         let payload = ipv6.payload().clone();
 
         // set the new data
-        self.offset += unsafe {
+        let ip_len = unchecked! {
             // SAFETY: The payload is a subslice of self.slice.
             // therefor calculating the offset from it is safe and
             // the result should always be a positive number.
-            ipv6.payload()
-                .payload
-                .as_ptr()
-                .offset_from(self.slice.as_ptr()) as usize
+            get_unchecked_offset(self.slice, ipv6.payload().payload)
         };
+        self.check_header_len(Layer::Ipv6Header, self.offset + ip_len)?;
+        self.offset += ip_len;
+        self.push_ipv6_layers(&ipv6);
         self.len_source = ipv6.payload().len_source;
         self.slice = ipv6.payload().payload;
+        let first_fragment = ipv6_first_fragment(&ipv6);
         self.result.net = Some(NetSlice::Ipv6(ipv6));
 
-        // only try to decode the transport layer if the payload
-        // is not fragmented
-        if payload.fragmented {
-            Ok(self.result)
+        self.slice_ip_payload(payload.ip_number, payload.fragmented, first_fragment)
+    }
+
+    /// Slices the transport layer based on an externally known ip number
+    /// (the slice is treated as a complete & unfragmented ip payload).
+    pub fn slice_ip_number(&mut self, ip_number: IpNumber) -> Result<(), err::packet::SliceError> {
+        self.slice_ip_payload(ip_number, false, false)
+    }
+
+    /// Slices the transport layer based on the given ip number.
+    ///
+    /// Fragmented payloads are only decoded if the payload is the first
+    /// fragment and [`SliceOptions::first_fragment_transport`] is set. In
+    /// this case errors are ignored (the transport header might be cut off
+    /// by the fragmentation) and the transport field is left empty.
+    fn slice_ip_payload(
+        &mut self,
+        ip_number: IpNumber,
+        fragmented: bool,
+        first_fragment: bool,
+    ) -> Result<(), err::packet::SliceError> {
+        // check the limits before decoding the transport header
+        let decode =
+            false == fragmented || (self.options.first_fragment_transport && first_fragment);
+        if decode {
+            let transport = match ip_number {
+                #[cfg(feature = "icmp")]
+                ip_number::ICMP => Some((Layer::Icmpv4, Icmpv4Header::MIN_LEN)),
+                ip_number::UDP => Some((Layer::UdpHeader, UdpHeader::LEN)),
+                #[cfg(feature = "tcp")]
+                ip_number::TCP => Some((Layer::TcpHeader, TcpHeader::MIN_LEN)),
+                #[cfg(all(feature = "icmp", feature = "ipv6"))]
+                ip_number::IPV6_ICMP => Some((Layer::Icmpv6, Icmpv6Header::MIN_LEN)),
+                _ => None,
+            };
+            if let Some((layer, min_header_len)) = transport {
+                self.check_limits(layer, min_header_len)?;
+            }
+        }
+
+        let start_offset = self.offset;
+        self.slice_transport(ip_number, fragmented, first_fragment)?;
+
+        // check the actual length of transport headers with a variable length
+        let transport = match &self.result.transport {
+            Some(TransportSlice::Icmpv4(s)) => Some((Layer::Icmpv4, s.header_len())),
+            Some(TransportSlice::Icmpv6(s)) => Some((Layer::Icmpv6, s.header_len())),
+            Some(TransportSlice::Tcp(s)) => Some((Layer::TcpHeader, s.header_len())),
+            Some(TransportSlice::Udp(_)) | None => None,
+        };
+        if let Some((layer, header_len)) = transport {
+            if let Err(err) = self.check_header_len(layer, start_offset + header_len) {
+                // point the error location to the start of the transport header
+                self.offset = start_offset;
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Slices the transport header (see [`SlicedPacketCursor::slice_ip_payload`]).
+    fn slice_transport(
+        &mut self,
+        ip_number: IpNumber,
+        fragmented: bool,
+        first_fragment: bool,
+    ) -> Result<(), err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        if fragmented {
+            if self.options.first_fragment_transport && first_fragment {
+                match ip_number {
+                    #[cfg(feature = "icmp")]
+                    ip_number::ICMP => {
+                        let _ = self.slice_icmp4();
+                    }
+                    ip_number::UDP => {
+                        // the length field of the UDP header contains the length
+                        // of the reassembled payload, so only a lax check is possible
+                        if let Ok(udp) = UdpSlice::from_slice_lax(self.slice) {
+                            self.move_by(udp.slice().len());
+                            self.result.transport = Some(TransportSlice::Udp(udp));
+                        }
+                    }
+                    #[cfg(feature = "tcp")]
+                    ip_number::TCP => {
+                        let _ = self.slice_tcp();
+                    }
+                    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+                    ip_number::IPV6_ICMP => {
+                        let _ = self.slice_icmp6();
+                    }
+                    _ => {}
+                }
+            }
+            Ok(())
         } else {
-            //parse the data bellow
-            match payload.ip_number {
+            match ip_number {
+                #[cfg(feature = "icmp")]
                 ip_number::ICMP => self.slice_icmp4().map_err(Len),
                 ip_number::UDP => self.slice_udp().map_err(Len),
+                #[cfg(feature = "tcp")]
                 ip_number::TCP => self.slice_tcp().map_err(|err| {
                     use err::tcp::HeaderSliceError as I;
                     match err {
@@ -268,13 +645,15 @@ impl<'a> SlicedPacketCursor<'a> {
                         I::Content(err) => Tcp(err),
                     }
                 }),
+                #[cfg(all(feature = "icmp", feature = "ipv6"))]
                 ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
-                _ => Ok(self.result),
+                _ => Ok(()),
             }
         }
     }
 
-    pub fn slice_icmp4(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+    #[cfg(feature = "icmp")]
+    pub fn slice_icmp4(&mut self) -> Result<(), err::LenError> {
         use crate::TransportSlice::*;
 
         let result = Icmpv4Slice::from_slice(self.slice).map_err(|mut err| {
@@ -289,10 +668,11 @@ impl<'a> SlicedPacketCursor<'a> {
         self.move_by(result.slice().len());
         self.result.transport = Some(Icmpv4(result.clone()));
 
-        Ok(self.result)
+        Ok(())
     }
 
-    pub fn slice_icmp6(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
+    pub fn slice_icmp6(&mut self) -> Result<(), err::LenError> {
         use crate::TransportSlice::*;
 
         let result = Icmpv6Slice::from_slice(self.slice).map_err(|mut err| {
@@ -308,10 +688,10 @@ impl<'a> SlicedPacketCursor<'a> {
         self.result.transport = Some(Icmpv6(result.clone()));
 
         //done
-        Ok(self.result)
+        Ok(())
     }
 
-    pub fn slice_udp(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+    pub fn slice_udp(&mut self) -> Result<(), err::LenError> {
         use crate::TransportSlice::*;
 
         let result = UdpSlice::from_slice(self.slice).map_err(|mut err| {
@@ -327,10 +707,11 @@ impl<'a> SlicedPacketCursor<'a> {
         self.result.transport = Some(Udp(result));
 
         // done
-        Ok(self.result)
+        Ok(())
     }
 
-    pub fn slice_tcp(mut self) -> Result<SlicedPacket<'a>, err::tcp::HeaderSliceError> {
+    #[cfg(feature = "tcp")]
+    pub fn slice_tcp(&mut self) -> Result<(), err::tcp::HeaderSliceError> {
         use crate::TransportSlice::*;
 
         let result = TcpSlice::from_slice(self.slice).map_err(|mut err| {
@@ -349,6 +730,20 @@ impl<'a> SlicedPacketCursor<'a> {
         self.result.transport = Some(Tcp(result));
 
         // done
-        Ok(self.result)
+        Ok(())
     }
 }
+
+/// Returns true if the IPv4 payload is not fragmented or the first fragment.
+fn ipv4_first_fragment(ipv4: &Ipv4Slice) -> bool {
+    0 == ipv4.header().fragments_offset().value()
+}
+
+/// Returns true if the IPv6 payload is not fragmented or the first fragment
+/// (all fragment headers have an offset of 0).
+fn ipv6_first_fragment(ipv6: &Ipv6Slice) -> bool {
+    ipv6.extensions().clone().into_iter().all(|ext| match ext {
+        Ipv6ExtensionSlice::Fragment(f) => 0 == f.fragment_offset().value(),
+        _ => true,
+    })
+}
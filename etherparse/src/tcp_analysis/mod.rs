@@ -0,0 +1,5 @@
+mod tcp_probe_kind;
+pub use tcp_probe_kind::*;
+
+mod tcp_sender_state;
+pub use tcp_sender_state::*;
@@ -0,0 +1,40 @@
+/// Kind of "probe" segment that carries no new data (returned by
+/// [`crate::tcp_analysis::TcpSenderState::classify`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum TcpProbeKind {
+    /// Keep-alive segment (RFC 9293 section 3.8.4) that re-sends the
+    /// last already acknowledged sequence number (`next_seq - 1`) with
+    /// no or one garbage byte of data to provoke an acknowledgment.
+    KeepAlive,
+    /// Zero window probe (RFC 9293 section 3.8.6.1) containing one byte
+    /// of new data that is sent while the peer advertises a window of zero.
+    ZeroWindowProbe,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn clone_eq_debug_hash_ord() {
+        let value = TcpProbeKind::KeepAlive;
+        assert_eq!(value, value.clone());
+        assert_eq!("KeepAlive", format!("{:?}", value));
+        assert_eq!(
+            "ZeroWindowProbe",
+            format!("{:?}", TcpProbeKind::ZeroWindowProbe)
+        );
+        assert!(TcpProbeKind::KeepAlive < TcpProbeKind::ZeroWindowProbe);
+        let hash = |v: TcpProbeKind| {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(value), hash(value.clone()));
+    }
+}
@@ -0,0 +1,200 @@
+use super::TcpProbeKind;
+use crate::TcpSlice;
+
+/// State of one direction of a TCP connection as tracked by a connection
+/// monitor, used to classify the segments sent in this direction.
+///
+/// `next_seq` is the sequence number the sender is expected to use for the
+/// next new data ("SND.NXT", usually the end of the highest sequence number
+/// range seen so far, see [`crate::TcpSlice::seq_range`]) and `peer_window`
+/// the (scaled) receive window last advertised by the peer.
+///
+/// ```
+/// use etherparse::{tcp_analysis::{TcpProbeKind, TcpSenderState}, PacketBuilder, SlicedPacket, TransportSlice};
+///
+/// let state = TcpSenderState::new(1000, 0);
+///
+/// // generate a keep-alive
+/// let mut packet = Vec::new();
+/// PacketBuilder::ipv4([10, 0, 0, 1], [10, 0, 0, 2], 20)
+///     .tcp(1234, 80, state.keep_alive_seq(), 4096)
+///     .ack(500)
+///     .write(&mut packet, &[])
+///     .unwrap();
+///
+/// // classify it
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Tcp(tcp)) = &sliced.transport {
+///     assert_eq!(Some(TcpProbeKind::KeepAlive), state.classify(tcp));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TcpSenderState {
+    /// Sequence number expected for the next new data sent by the
+    /// sender ("SND.NXT").
+    pub next_seq: u32,
+    /// Receive window (already scaled) last advertised by the peer.
+    pub peer_window: u32,
+}
+
+impl TcpSenderState {
+    /// Creates a new sender state.
+    #[inline]
+    pub const fn new(next_seq: u32, peer_window: u32) -> TcpSenderState {
+        TcpSenderState {
+            next_seq,
+            peer_window,
+        }
+    }
+
+    /// Sequence number to use when generating a keep-alive (`next_seq - 1`).
+    #[inline]
+    pub const fn keep_alive_seq(&self) -> u32 {
+        self.next_seq.wrapping_sub(1)
+    }
+
+    /// Sequence number to use when generating a zero window probe
+    /// (`next_seq`, the probe contains one byte of new data).
+    #[inline]
+    pub const fn zero_window_probe_seq(&self) -> u32 {
+        self.next_seq
+    }
+
+    /// Returns true if the segment is a keep-alive.
+    ///
+    /// A segment is considered to be a keep-alive if:
+    ///
+    /// * none of the SYN, FIN or RST flags are set,
+    /// * it contains zero or one byte of data &
+    /// * its sequence number is `next_seq - 1`.
+    pub fn is_keep_alive(&self, tcp: &TcpSlice) -> bool {
+        !(tcp.syn() || tcp.fin() || tcp.rst())
+            && tcp.payload().len() <= 1
+            && tcp.sequence_number() == self.keep_alive_seq()
+    }
+
+    /// Returns true if the segment is a zero window probe.
+    ///
+    /// A segment is considered to be a zero window probe if:
+    ///
+    /// * the peer advertised a window of zero (`peer_window == 0`),
+    /// * none of the SYN, FIN or RST flags are set,
+    /// * it contains exactly one byte of data &
+    /// * its sequence number is `next_seq`.
+    pub fn is_zero_window_probe(&self, tcp: &TcpSlice) -> bool {
+        0 == self.peer_window
+            && !(tcp.syn() || tcp.fin() || tcp.rst())
+            && 1 == tcp.payload().len()
+            && tcp.sequence_number() == self.zero_window_probe_seq()
+    }
+
+    /// Classifies the segment as keep-alive or zero window probe (`None`
+    /// if the segment is neither).
+    pub fn classify(&self, tcp: &TcpSlice) -> Option<TcpProbeKind> {
+        if self.is_keep_alive(tcp) {
+            Some(TcpProbeKind::KeepAlive)
+        } else if self.is_zero_window_probe(tcp) {
+            Some(TcpProbeKind::ZeroWindowProbe)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_gens::*, TcpHeader};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    fn segment(tcp: &TcpHeader, payload: &[u8]) -> Vec<u8> {
+        let mut data = tcp.to_bytes().to_vec();
+        data.extend_from_slice(payload);
+        data
+    }
+
+    proptest! {
+        #[test]
+        fn classify(
+            tcp in tcp_any(),
+            next_seq in any::<u32>(),
+            peer_window in any::<u32>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..3)
+        ) {
+            let mut tcp = tcp;
+            tcp.syn = false;
+            tcp.fin = false;
+            tcp.rst = false;
+
+            let zero = TcpSenderState::new(next_seq, 0);
+            let open = TcpSenderState::new(next_seq, peer_window.max(1));
+
+            // keep-alive (with & without garbage byte)
+            tcp.sequence_number = next_seq.wrapping_sub(1);
+            for len in 0..=1 {
+                let data = segment(&tcp, &payload[..len.min(payload.len())]);
+                let slice = TcpSlice::from_slice(&data).unwrap();
+                let expected = (slice.payload().len() <= 1).then_some(TcpProbeKind::KeepAlive);
+                assert_eq!(expected, zero.classify(&slice));
+                assert_eq!(expected, open.classify(&slice));
+            }
+            if 2 == payload.len() {
+                let data = segment(&tcp, &payload);
+                let slice = TcpSlice::from_slice(&data).unwrap();
+                assert!(false == open.is_keep_alive(&slice));
+            }
+
+            // zero window probe
+            tcp.sequence_number = next_seq;
+            {
+                let data = segment(&tcp, &[0]);
+                let slice = TcpSlice::from_slice(&data).unwrap();
+                assert_eq!(Some(TcpProbeKind::ZeroWindowProbe), zero.classify(&slice));
+                assert_eq!(None, open.classify(&slice));
+            }
+            for len in [0, 2] {
+                let data = segment(&tcp, &[0; 2][..len]);
+                let slice = TcpSlice::from_slice(&data).unwrap();
+                assert_eq!(None, zero.classify(&slice));
+            }
+
+            // syn, fin & rst are never probes
+            for flag in 0..3 {
+                let mut tcp = tcp.clone();
+                match flag {
+                    0 => tcp.syn = true,
+                    1 => tcp.fin = true,
+                    _ => tcp.rst = true,
+                }
+                for seq in [next_seq, next_seq.wrapping_sub(1)] {
+                    tcp.sequence_number = seq;
+                    let data = segment(&tcp, &[0]);
+                    let slice = TcpSlice::from_slice(&data).unwrap();
+                    assert_eq!(None, zero.classify(&slice));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seq_generation() {
+        let state = TcpSenderState::new(0, 0);
+        assert_eq!(u32::MAX, state.keep_alive_seq());
+        assert_eq!(0, state.zero_window_probe_seq());
+        let state = TcpSenderState::new(100, 0);
+        assert_eq!(99, state.keep_alive_seq());
+        assert_eq!(100, state.zero_window_probe_seq());
+    }
+
+    #[test]
+    fn clone_eq_debug_default() {
+        let state = TcpSenderState::new(1, 2);
+        assert_eq!(state, state.clone());
+        assert_eq!(TcpSenderState::new(0, 0), TcpSenderState::default());
+        assert_eq!(
+            "TcpSenderState { next_seq: 1, peer_window: 2 }",
+            format!("{:?}", state)
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::*;
 use proptest::prelude::*;
 use proptest::*;
 
+/// Generates one of the ether types indicating a VLAN header.
 pub fn vlan_ethertype_any() -> impl Strategy<Value = EtherType> {
     prop_oneof![
         Just(ether_type::VLAN_TAGGED_FRAME),
@@ -11,6 +12,7 @@ pub fn vlan_ethertype_any() -> impl Strategy<Value = EtherType> {
 }
 
 prop_compose! {
+    /// Generates an arbitrary ether type.
     pub fn ether_type_any()
         (value in any::<u16>())
         -> EtherType
@@ -20,6 +22,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary valid VLAN identifier.
     pub fn vlan_id_any()
         (value in 0..=0b0000_1111_1111_1111u16)
         -> VlanId
@@ -29,6 +32,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary valid VLAN priority code point.
     pub fn vlan_pcp_any()
         (value in 0..=0b0000_0111u8)
         -> VlanPcp
@@ -38,6 +42,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates a single VLAN header with an ether type not known to etherparse.
     pub fn vlan_single_unknown()(
         pcp in vlan_pcp_any(),
         drop_eligible_indicator in any::<bool>(),
@@ -56,6 +61,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary valid IPv6 flow label.
     pub fn ipv6_flow_label_any()
         (value in 0u32..=0b1111_11111111_11111111u32)
         -> Ipv6FlowLabel
@@ -65,6 +71,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary IP number.
     pub fn ip_number_any()
         (value in any::<u8>())
         -> IpNumber
@@ -74,6 +81,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an Ethernet II header with the given ether type.
     pub fn ethernet_2_with(ether_type: EtherType)(
         source in prop::array::uniform6(any::<u8>()),
         dest in prop::array::uniform6(any::<u8>()),
@@ -81,14 +89,15 @@ prop_compose! {
         -> Ethernet2Header
     {
         Ethernet2Header {
-            source: source,
+            source,
             destination: dest,
-            ether_type: ether_type
+            ether_type
         }
     }
 }
 
 prop_compose! {
+    /// Generates an arbitrary Ethernet II header.
     pub fn ethernet_2_any()
         (ether_type in ether_type_any())
         (result in ethernet_2_with(ether_type))
@@ -99,6 +108,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary valid Linux SLL packet type.
     pub fn linux_sll_packet_type_any()
         (value in 0..=LinuxSllPacketType::MAX_VAL)
         -> LinuxSllPacketType
@@ -108,6 +118,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates one of the ARP hardware ids supported in Linux SLL headers.
     pub fn linux_sll_arphrd()
         (index in 0..=(LinuxSllProtocolType::SUPPORTED_ARPHWD.len()-1))
         -> ArpHardwareId
@@ -117,6 +128,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates a Linux SLL sender address & its valid length.
     pub fn linux_sll_sender_adress_any()
         (mut sender_address in prop::collection::vec(any::<u8>(), 0..8))
         -> (u16, [u8; 8])
@@ -131,6 +143,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary Linux SLL header.
     pub fn linux_sll_any()
         (packet_type in linux_sll_packet_type_any(),
         arp_hrd_type in linux_sll_arphrd(),
@@ -149,7 +162,8 @@ prop_compose! {
     }
 }
 
-pub static ETHERNET_KNOWN_ETHER_TYPES: &'static [EtherType] = &[
+/// Ether types for which etherparse decodes the payload.
+pub static ETHERNET_KNOWN_ETHER_TYPES: &[EtherType] = &[
     ether_type::IPV4,
     ether_type::IPV6,
     ether_type::VLAN_TAGGED_FRAME,
@@ -158,6 +172,7 @@ pub static ETHERNET_KNOWN_ETHER_TYPES: &'static [EtherType] = &[
 ];
 
 prop_compose! {
+    /// Generates an Ethernet II header with an ether type not known to etherparse.
     pub fn ethernet_2_unknown()(
         source in prop::array::uniform6(any::<u8>()),
         dest in prop::array::uniform6(any::<u8>()),
@@ -166,14 +181,15 @@ prop_compose! {
         -> Ethernet2Header
     {
         Ethernet2Header {
-            source: source,
+            source,
             destination: dest,
-            ether_type: ether_type
+            ether_type
         }
     }
 }
 
 prop_compose! {
+    /// Generates a single VLAN header with the given ether type.
     pub fn vlan_single_with(ether_type: EtherType)(
         pcp in vlan_pcp_any(),
         drop_eligible_indicator in any::<bool>(),
@@ -191,6 +207,125 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary IEEE 802.1ah header.
+    pub fn ieee8021ah_any()(
+        pcp in vlan_pcp_any(),
+        drop_eligible_indicator in any::<bool>(),
+        use_customer_address in any::<bool>(),
+        isid in 0..=Ieee8021ahIsid::MAX_U32)
+        -> Ieee8021ahHeader
+    {
+        Ieee8021ahHeader {
+            pcp,
+            drop_eligible_indicator,
+            use_customer_address,
+            isid: Ieee8021ahIsid::try_new(isid).unwrap(),
+        }
+    }
+}
+
+prop_compose! {
+    /// Generates an arbitrary NTP header.
+    pub fn ntp_any()(
+        leap_indicator in 0u8..=0b11,
+        version in 0..=NtpVersion::MAX_U8,
+        mode in 0u8..=0b111,
+        stratum in any::<u8>(),
+        poll in any::<i8>(),
+        precision in any::<i8>(),
+        root_delay in any::<u32>(),
+        root_dispersion in any::<u32>(),
+        reference_id in any::<[u8; 4]>(),
+        timestamps in any::<[u64; 4]>())
+        -> NtpHeader
+    {
+        NtpHeader {
+            leap_indicator: NtpLeapIndicator::from_bits(leap_indicator),
+            version: NtpVersion::try_new(version).unwrap(),
+            mode: NtpMode::from_bits(mode),
+            stratum,
+            poll,
+            precision,
+            root_delay,
+            root_dispersion,
+            reference_id,
+            reference_timestamp: NtpTimestamp::from_be_bytes(timestamps[0].to_be_bytes()),
+            origin_timestamp: NtpTimestamp::from_be_bytes(timestamps[1].to_be_bytes()),
+            receive_timestamp: NtpTimestamp::from_be_bytes(timestamps[2].to_be_bytes()),
+            transmit_timestamp: NtpTimestamp::from_be_bytes(timestamps[3].to_be_bytes()),
+        }
+    }
+}
+
+prop_compose! {
+    /// Generates an arbitrary PTP header.
+    pub fn ptp_any()(
+        transport_specific in 0u8..=0b1111,
+        message_type in 0u8..=0b1111,
+        minor_version in 0u8..=0b1111,
+        version in 0u8..=0b1111,
+        message_length in any::<u16>(),
+        domain_number in any::<u8>(),
+        minor_sdo_id in any::<u8>(),
+        flags in any::<[u8; 2]>(),
+        correction_field in any::<i64>(),
+        message_type_specific in any::<[u8; 4]>(),
+        clock_identity in any::<[u8; 8]>(),
+        source_port_number in any::<u16>(),
+        sequence_id in any::<u16>(),
+        control_field in any::<u8>(),
+        log_message_interval in any::<i8>())
+        -> PtpHeader
+    {
+        PtpHeader {
+            transport_specific,
+            message_type: PtpMessageType::from_bits(message_type),
+            minor_version,
+            version,
+            message_length,
+            domain_number,
+            minor_sdo_id,
+            flags,
+            correction_field,
+            message_type_specific,
+            clock_identity,
+            source_port_number,
+            sequence_id,
+            control_field,
+            log_message_interval,
+        }
+    }
+}
+
+prop_compose! {
+    /// Generates an arbitrary MACsec header.
+    #[cfg(feature = "macsec")]
+    pub fn macsec_any()(
+        endstation_id in any::<bool>(),
+        scb in any::<bool>(),
+        encrypted in any::<bool>(),
+        changed in any::<bool>(),
+        an in 0..=MacsecAn::MAX_U8,
+        short_len in 0..=MacsecShortLen::MAX_U8,
+        packet_nr in any::<u32>(),
+        sci in proptest::option::of(any::<u64>()))
+        -> MacsecHeader
+    {
+        MacsecHeader {
+            endstation_id,
+            scb,
+            encrypted,
+            changed,
+            an: MacsecAn::try_new(an).unwrap(),
+            short_len: MacsecShortLen::try_new(short_len).unwrap(),
+            packet_nr,
+            sci,
+        }
+    }
+}
+
+prop_compose! {
+    /// Generates an arbitrary single VLAN header.
     pub fn vlan_single_any()
         (ether_type in ether_type_any())
         (result in vlan_single_with(ether_type))
@@ -201,6 +336,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary double VLAN header.
     pub fn vlan_double_any()
         (ether_type in ether_type_any())
         (result in vlan_double_with(ether_type))
@@ -211,6 +347,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates a double VLAN header with the given inner ether type.
     pub fn vlan_double_with(ether_type: EtherType)(
         outer_ethertype in vlan_ethertype_any(),
         inner_ethertype in proptest::strategy::Just(ether_type)
@@ -226,6 +363,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates arbitrary IPv4 options (0 to 40 bytes).
     pub fn ipv4_options_any()
     (
         len_div_4 in 0u8..10,
@@ -252,6 +390,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an IPv4 header with the given protocol.
     pub fn ipv4_with(protocol: IpNumber)
     (
         protocol in proptest::strategy::Just(protocol),
@@ -267,7 +406,7 @@ prop_compose! {
         more_fragments in any::<bool>(),
         fragment_offset in prop::bits::u16::between(0, 13),
         header_checksum in any::<u16>(),
-        total_len in ((Ipv4Header::MIN_LEN + usize::from(options.len())) as u16)..core::u16::MAX,
+        total_len in ((Ipv4Header::MIN_LEN + options.len()) as u16)..u16::MAX,
         protocol in proptest::strategy::Just(protocol),
         options in proptest::strategy::Just(options)
     ) -> Ipv4Header
@@ -290,6 +429,7 @@ prop_compose! {
     }
 }
 prop_compose! {
+    /// Generates an arbitrary IPv4 header.
     pub fn ipv4_any()
                (protocol in ip_number_any())
                (result in ipv4_with(protocol))
@@ -308,6 +448,7 @@ static IPV4_KNOWN_PROTOCOLS: &[IpNumber] = &[
 ];
 
 prop_compose! {
+    /// Generates an IPv4 header with a protocol not known to etherparse.
     pub fn ipv4_unknown()
         (protocol in ip_number_any().prop_filter("protocol must be unknown",
             |v| !IPV4_KNOWN_PROTOCOLS.iter().any(|&x| v == &x))
@@ -320,6 +461,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates IPv4 extensions with the given next header.
     pub fn ipv4_extensions_with(next_header: IpNumber)
     (
         has_auth in any::<bool>(),
@@ -339,6 +481,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates arbitrary IPv4 extensions.
     pub fn ipv4_extensions_any()
                (protocol in ip_number_any())
                (result in ipv4_extensions_with(protocol))
@@ -349,6 +492,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates IPv4 extensions with a next header not known to etherparse.
     pub fn ipv4_extensions_unknown()
         (
             next_header in ip_number_any().prop_filter(
@@ -364,6 +508,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an IPv6 header with the given next header.
     pub fn ipv6_with(next_header: IpNumber)
     (
         source in prop::array::uniform16(any::<u8>()),
@@ -376,18 +521,19 @@ prop_compose! {
     ) -> Ipv6Header
     {
         Ipv6Header {
-            traffic_class: traffic_class,
-            flow_label: flow_label,
-            payload_length: payload_length,
-            next_header: next_header,
-            hop_limit: hop_limit,
-            source: source,
+            traffic_class,
+            flow_label,
+            payload_length,
+            next_header,
+            hop_limit,
+            source,
             destination: dest
         }
     }
 }
 
 prop_compose! {
+    /// Generates an arbitrary IPv6 header.
     pub fn ipv6_any()
         (next_header in ip_number_any())
         (result in ipv6_with(next_header)
@@ -417,6 +563,7 @@ static IPV6_KNOWN_NEXT_HEADERS: &[IpNumber] = &[
 ];
 
 prop_compose! {
+    /// Generates an IPv6 header with a next header not known to etherparse.
     pub fn ipv6_unknown()(
         source in prop::array::uniform16(any::<u8>()),
         destination in prop::array::uniform16(any::<u8>()),
@@ -441,6 +588,8 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates a raw IPv6 extension header with the given next header &
+    /// length (in 8 byte units, excluding the first 8 bytes).
     pub fn ipv6_raw_ext_with(
         next_header: IpNumber,
         len: u8
@@ -457,6 +606,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary raw IPv6 extension header.
     pub fn ipv6_raw_ext_any()
         (
             next_header in ip_number_any(),
@@ -470,6 +620,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates IPv6 extensions with the given next header.
     pub fn ipv6_extensions_with(next_header: IpNumber)
     (
         has_hop_by_hop_options in any::<bool>(),
@@ -483,7 +634,9 @@ prop_compose! {
         has_auth in any::<bool>(),
         auth in ip_auth_with(next_header),
         has_final_destination_options in any::<bool>(),
-        final_destination_options in ipv6_raw_ext_any()
+        final_destination_options in ipv6_raw_ext_any(),
+        has_mobility in any::<bool>(),
+        mobility in ipv6_mobility_with(next_header)
     ) -> Ipv6Extensions
     {
         let mut result = Ipv6Extensions {
@@ -521,6 +674,11 @@ prop_compose! {
             } else {
                 None
             },
+            mobility: if has_mobility {
+                Some(mobility)
+            } else {
+                None
+            },
         };
         result.set_next_headers(next_header);
         result
@@ -528,6 +686,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates arbitrary IPv6 extensions.
     pub fn ipv6_extensions_any()
         (
             next_header in ip_number_any()
@@ -540,6 +699,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates IPv6 extensions with a next header not known to etherparse.
     pub fn ipv6_extensions_unknown()
         (
             next_header in ip_number_any().prop_filter(
@@ -555,6 +715,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an IPv6 fragment header with the given next header.
     pub fn ipv6_fragment_with(
         next_header: IpNumber
     ) (
@@ -574,6 +735,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary IPv6 fragment header.
     pub fn ipv6_fragment_any()
         (next_header in ip_number_any())
         (result in ipv6_fragment_with(next_header)
@@ -584,6 +746,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an IP authentication header with the given next header.
     pub fn ip_auth_with(
         next_header: IpNumber
     ) (
@@ -605,6 +768,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary IP authentication header.
     pub fn ip_auth_any() (
         next_header in ip_number_any()
     ) (
@@ -615,6 +779,57 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an IPv6 mobility header with the given payload proto
+    /// (the options are sized so no padding has to be added).
+    pub fn ipv6_mobility_with(payload_proto: IpNumber)
+    (
+        payload_proto in proptest::strategy::Just(payload_proto),
+        message in prop_oneof![
+            Just(MobilityMessage::BindingRefreshRequest),
+            (any::<u16>(), any::<bool>(), any::<bool>(), any::<u16>()).prop_map(
+                |(sequence_number, acknowledge, home_registration, lifetime)| {
+                    MobilityMessage::BindingUpdate {
+                        sequence_number,
+                        acknowledge,
+                        home_registration,
+                        link_local_compat: false,
+                        key_management_compat: true,
+                        lifetime,
+                    }
+                }
+            ),
+            (any::<u8>(), any::<u16>(), any::<u16>()).prop_map(
+                |(status, sequence_number, lifetime)| {
+                    MobilityMessage::BindingAcknowledgement {
+                        status,
+                        key_management_compat: false,
+                        sequence_number,
+                        lifetime,
+                    }
+                }
+            ),
+            any::<u8>().prop_filter("mh_type must not be decoded", |v| *v > 7)
+                .prop_map(|v| MobilityMessage::Raw(MobilityHeaderType(v))),
+        ],
+        checksum in any::<u16>(),
+        blocks in 0usize..4,
+        options in proptest::collection::vec(any::<u8>(), 5*8),
+    ) -> MobilityHeader
+    {
+        // size the options so that the header is a multiple of 8 octets
+        let options_len = blocks*8 + (8 - (6 + message.fixed_len()) % 8) % 8;
+        let mut result = MobilityHeader::new(
+            payload_proto,
+            message,
+            &options[..options_len]
+        ).unwrap();
+        result.checksum = checksum;
+        result
+    }
+}
+
+prop_compose! {
+    /// Generates an arbitrary UDP header.
     pub fn udp_any()(
             source_port in any::<u16>(),
             destination_port in any::<u16>(),
@@ -623,15 +838,16 @@ prop_compose! {
         -> UdpHeader
     {
         UdpHeader {
-            source_port: source_port,
-            destination_port: destination_port,
-            length: length,
-            checksum: checksum
+            source_port,
+            destination_port,
+            length,
+            checksum
         }
     }
 }
 
 prop_compose! {
+    /// Generates an arbitrary TCP header (including options).
     pub fn tcp_any()
         (data_offset in TcpHeader::MIN_DATA_OFFSET..(TcpHeader::MAX_DATA_OFFSET + 1))
         (
@@ -673,6 +889,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates arbitrary TCP options.
     pub fn tcp_options_any()
     (data_offset in TcpHeader::MIN_DATA_OFFSET..(TcpHeader::MAX_DATA_OFFSET + 1))
     (
@@ -684,26 +901,40 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary ICMPv4 type.
     pub fn icmpv4_type_any()
         (
             bytes in any::<[u8;20]>(),
         ) -> Icmpv4Type
     {
-        Icmpv4Header::from_slice(&bytes).unwrap().0.icmp_type
+        icmpv4_header_from_any_bytes(&bytes).icmp_type
     }
 }
 
 prop_compose! {
+    /// Generates an arbitrary ICMPv4 header.
     pub fn icmpv4_header_any()
         (
             bytes in any::<[u8;20]>(),
         ) -> Icmpv4Header
     {
-        Icmpv4Header::from_slice(&bytes).unwrap().0
+        icmpv4_header_from_any_bytes(&bytes)
     }
 }
 
+/// Decodes an ICMPv4 header from random bytes (cutting off the bytes
+/// that would otherwise trigger a length error for message types
+/// with a fixed length).
+fn icmpv4_header_from_any_bytes(bytes: &[u8; 20]) -> Icmpv4Header {
+    let len = match (bytes[0], bytes[1]) {
+        (icmpv4::TYPE_ADDRESS | icmpv4::TYPE_ADDRESSREPLY, 0) => icmpv4::AddressMaskMessage::LEN,
+        _ => bytes.len(),
+    };
+    Icmpv4Header::from_slice(&bytes[..len]).unwrap().0
+}
+
 prop_compose! {
+    /// Generates an arbitrary ICMPv6 type.
     pub fn icmpv6_type_any()
         (
             bytes in any::<[u8;8]>(),
@@ -714,6 +945,7 @@ prop_compose! {
 }
 
 prop_compose! {
+    /// Generates an arbitrary ICMPv6 header.
     pub fn icmpv6_header_any()
         (
             bytes in any::<[u8;8]>(),
@@ -1,8 +1,29 @@
 use crate::*;
 use alloc::vec::Vec;
 
+/// Headers of a packet that can be serialized together with a payload
+/// (e.g. to build a matrix of header combinations in tests).
+///
+/// ```
+/// use etherparse::{test_packet::TestPacket, *};
+///
+/// let mut test = TestPacket {
+///     link: None,
+///     vlan: None,
+///     net: Some(NetHeaders::Ipv4(
+///         Ipv4Header::new(0, 64, IpNumber::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap(),
+///         Default::default(),
+///     )),
+///     transport: Some(TransportHeader::Udp(UdpHeader::default())),
+/// };
+/// test.set_payload_len(4);
+///
+/// let bytes = test.to_vec(&[1, 2, 3, 4]);
+/// let sliced = SlicedPacket::from_ip(&bytes).unwrap();
+/// assert_eq!(Some(IpNumber::UDP), sliced.next_header_ip_number());
+/// ```
 #[derive(Clone)]
-pub(crate) struct TestPacket {
+pub struct TestPacket {
     pub link: Option<LinkHeader>,
     pub vlan: Option<VlanHeader>,
     pub net: Option<NetHeaders>,
@@ -10,6 +31,7 @@ pub(crate) struct TestPacket {
 }
 
 impl TestPacket {
+    /// Length of the serialized headers together with the given payload.
     pub fn len(&self, payload: &[u8]) -> usize {
         self.link.as_ref().map_or(0, |x| x.header_len())
             + self.vlan.as_ref().map_or(0, |x| x.header_len())
@@ -18,6 +40,8 @@ impl TestPacket {
             + payload.len()
     }
 
+    /// Serializes the headers & the given payload (length fields are not
+    /// updated, use [`TestPacket::set_payload_len`] for this).
     pub fn to_vec(&self, payload: &[u8]) -> Vec<u8> {
         let mut result = Vec::with_capacity(self.len(payload));
         if let Some(link) = &self.link {
@@ -45,6 +69,8 @@ impl TestPacket {
         result
     }
 
+    /// Sets the ether type in the most inner VLAN header or, if no VLAN
+    /// header is present, in the link header.
     pub fn set_ether_type(&mut self, ether_type: EtherType) {
         if let Some(vlan) = &mut self.vlan {
             use VlanHeader::*;
@@ -66,6 +92,8 @@ impl TestPacket {
         }
     }
 
+    /// Sets the length fields in the IP & UDP headers for the given
+    /// payload length.
     pub fn set_payload_len(&mut self, payload_len: usize) {
         use NetHeaders::*;
         match &mut self.net {
@@ -121,8 +149,10 @@ impl TestPacket {
         }
     }
 
+    /// Returns true if the IP header or IP extension headers indicate a
+    /// fragmented payload.
     pub fn is_ip_payload_fragmented(&self) -> bool {
-        self.net.as_ref().map_or(false, |net| match net {
+        self.net.as_ref().is_some_and(|net| match net {
             NetHeaders::Ipv4(h, _) => h.is_fragmenting_payload(),
             NetHeaders::Ipv6(_, e) => e.is_fragmenting_payload(),
         })
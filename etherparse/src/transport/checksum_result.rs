@@ -0,0 +1,58 @@
+/// Result of validating a checksum (e.g. returned by
+/// [`crate::UdpSlice::checksum_policy_result`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ChecksumResult {
+    /// The checksum matches the content.
+    Valid,
+    /// The checksum does not match the content or is not allowed
+    /// to be omitted.
+    Invalid,
+    /// The checksum was omitted & not validated (e.g. an UDP checksum of
+    /// zero in an IPv4 packet).
+    Skipped,
+}
+
+impl ChecksumResult {
+    /// Returns true if the result is not [`ChecksumResult::Invalid`] (the
+    /// packet can be accepted).
+    #[inline]
+    pub const fn is_acceptable(&self) -> bool {
+        !matches!(self, ChecksumResult::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChecksumResult::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn is_acceptable() {
+        assert!(Valid.is_acceptable());
+        assert!(false == Invalid.is_acceptable());
+        assert!(Skipped.is_acceptable());
+    }
+
+    #[test]
+    fn clone_eq_hash_ord_debug() {
+        let value = Skipped;
+        assert_eq!(value, value.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            value.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+        assert!(Valid < Invalid);
+        assert_eq!("Skipped", format!("{:?}", value));
+    }
+}
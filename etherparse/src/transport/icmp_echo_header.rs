@@ -19,6 +19,14 @@ impl IcmpEchoHeader {
     /// Serialized size of an IcmpEchoHeader header in bytes/octets.
     pub const LEN: usize = 4;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`IcmpEchoHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = IcmpEchoHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`IcmpEchoHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = IcmpEchoHeader::LEN;
+
     /// Return the seq + id encoded to the on the wire format.
     #[inline]
     pub fn to_bytes(&self) -> [u8; 4] {
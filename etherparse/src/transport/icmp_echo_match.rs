@@ -0,0 +1,192 @@
+use crate::*;
+
+/// Key to match ICMP echo replies to outstanding echo requests, consisting
+/// of the echo "identifier", "sequence number" & the token of the
+/// [`IcmpEchoPayload`] in the echo payload.
+///
+/// When sending an echo request the key can be stored (e.g. in a
+/// `HashMap` together with the send time). Received replies can then
+/// be converted via [`IcmpEchoMatch::from_icmpv4_reply`] or
+/// [`IcmpEchoMatch::from_icmpv6_reply`] and looked up.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{IcmpEchoMatch, IcmpEchoPayload, Icmpv4Slice, PacketBuilder};
+///
+/// // outstanding request
+/// let request = IcmpEchoMatch { id: 1, seq: 2, token: 0xabcd };
+///
+/// // reply received from the network
+/// let payload = IcmpEchoPayload { token: 0xabcd, timestamp: 1000 }.to_bytes();
+/// let builder = PacketBuilder::ipv4([192, 168, 1, 2], [192, 168, 1, 1], 20)
+///     .icmpv4_echo_reply(1, 2);
+/// let mut packet = Vec::with_capacity(builder.size(payload.len()));
+/// builder.write(&mut packet, &payload).unwrap();
+///
+/// let icmp = Icmpv4Slice::from_slice(&packet[20..]).unwrap();
+/// assert!(request.matches_icmpv4_reply(&icmp));
+/// assert_eq!(Some(request), IcmpEchoMatch::from_icmpv4_reply(&icmp));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IcmpEchoMatch {
+    /// Identifier of the echo request & reply.
+    pub id: u16,
+    /// Sequence number of the echo request & reply.
+    pub seq: u16,
+    /// Token contained in the [`IcmpEchoPayload`] of the echo payload.
+    pub token: u64,
+}
+
+impl IcmpEchoMatch {
+    /// Returns the match key of an ICMPv4 echo reply.
+    ///
+    /// `None` is returned if the packet is not an echo reply or
+    /// if the payload does not start with an [`IcmpEchoPayload`].
+    pub fn from_icmpv4_reply(icmp: &Icmpv4Slice) -> Option<IcmpEchoMatch> {
+        if let Icmpv4Type::EchoReply(echo) = icmp.icmp_type() {
+            IcmpEchoMatch::from_echo(echo, icmp.payload())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the match key of an ICMPv6 echo reply.
+    ///
+    /// `None` is returned if the packet is not an echo reply or
+    /// if the payload does not start with an [`IcmpEchoPayload`].
+    pub fn from_icmpv6_reply(icmp: &Icmpv6Slice) -> Option<IcmpEchoMatch> {
+        if let Icmpv6Type::EchoReply(echo) = icmp.icmp_type() {
+            IcmpEchoMatch::from_echo(echo, icmp.payload())
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the given ICMPv4 packet is an echo reply to the
+    /// request identified by `self`.
+    #[inline]
+    pub fn matches_icmpv4_reply(&self, icmp: &Icmpv4Slice) -> bool {
+        Some(*self) == IcmpEchoMatch::from_icmpv4_reply(icmp)
+    }
+
+    /// Returns true if the given ICMPv6 packet is an echo reply to the
+    /// request identified by `self`.
+    #[inline]
+    pub fn matches_icmpv6_reply(&self, icmp: &Icmpv6Slice) -> bool {
+        Some(*self) == IcmpEchoMatch::from_icmpv6_reply(icmp)
+    }
+
+    fn from_echo(echo: IcmpEchoHeader, payload: &[u8]) -> Option<IcmpEchoMatch> {
+        IcmpEchoPayload::from_slice(payload).map(|p| IcmpEchoMatch {
+            id: echo.id,
+            seq: echo.seq,
+            token: p.token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    const PAYLOAD: IcmpEchoPayload = IcmpEchoPayload {
+        token: 0x0102_0304_0506_0708,
+        timestamp: 1234,
+    };
+
+    const KEY: IcmpEchoMatch = IcmpEchoMatch {
+        id: 1,
+        seq: 2,
+        token: 0x0102_0304_0506_0708,
+    };
+
+    fn icmpv4(reply: bool, payload: &[u8]) -> Vec<u8> {
+        let icmp_type = if reply {
+            Icmpv4Type::EchoReply(IcmpEchoHeader { id: 1, seq: 2 })
+        } else {
+            Icmpv4Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 })
+        };
+        let mut result = Icmpv4Header::with_checksum(icmp_type, payload)
+            .to_bytes()
+            .to_vec();
+        result.extend_from_slice(payload);
+        result
+    }
+
+    fn icmpv6(reply: bool, payload: &[u8]) -> Vec<u8> {
+        let icmp_type = if reply {
+            Icmpv6Type::EchoReply(IcmpEchoHeader { id: 1, seq: 2 })
+        } else {
+            Icmpv6Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 })
+        };
+        let mut result = Icmpv6Header::new(icmp_type).to_bytes().to_vec();
+        result.extend_from_slice(payload);
+        result
+    }
+
+    #[test]
+    fn from_icmpv4_reply() {
+        let payload = PAYLOAD.to_bytes();
+
+        // reply
+        {
+            let bytes = icmpv4(true, &payload);
+            let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+            assert_eq!(Some(KEY), IcmpEchoMatch::from_icmpv4_reply(&slice));
+            assert!(KEY.matches_icmpv4_reply(&slice));
+            assert!(false == IcmpEchoMatch { seq: 3, ..KEY }.matches_icmpv4_reply(&slice));
+            assert!(false == IcmpEchoMatch { token: 0, ..KEY }.matches_icmpv4_reply(&slice));
+        }
+        // request
+        {
+            let bytes = icmpv4(false, &payload);
+            let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+            assert_eq!(None, IcmpEchoMatch::from_icmpv4_reply(&slice));
+            assert!(false == KEY.matches_icmpv4_reply(&slice));
+        }
+        // payload too short
+        {
+            let bytes = icmpv4(true, &payload[..IcmpEchoPayload::LEN - 1]);
+            let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+            assert_eq!(None, IcmpEchoMatch::from_icmpv4_reply(&slice));
+        }
+    }
+
+    #[test]
+    fn from_icmpv6_reply() {
+        let payload = PAYLOAD.to_bytes();
+
+        // reply
+        {
+            let bytes = icmpv6(true, &payload);
+            let slice = Icmpv6Slice::from_slice(&bytes).unwrap();
+            assert_eq!(Some(KEY), IcmpEchoMatch::from_icmpv6_reply(&slice));
+            assert!(KEY.matches_icmpv6_reply(&slice));
+            assert!(false == IcmpEchoMatch { id: 3, ..KEY }.matches_icmpv6_reply(&slice));
+        }
+        // request
+        {
+            let bytes = icmpv6(false, &payload);
+            let slice = Icmpv6Slice::from_slice(&bytes).unwrap();
+            assert_eq!(None, IcmpEchoMatch::from_icmpv6_reply(&slice));
+            assert!(false == KEY.matches_icmpv6_reply(&slice));
+        }
+        // payload too short
+        {
+            let bytes = icmpv6(true, &payload[..IcmpEchoPayload::LEN - 1]);
+            let slice = Icmpv6Slice::from_slice(&bytes).unwrap();
+            assert_eq!(None, IcmpEchoMatch::from_icmpv6_reply(&slice));
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        assert_eq!(KEY, KEY.clone());
+        assert_eq!(
+            "IcmpEchoMatch { id: 1, seq: 2, token: 72623859790382856 }",
+            format!("{:?}", KEY)
+        );
+    }
+}
@@ -0,0 +1,111 @@
+/// Payload pattern for ICMP echo requests consisting of a user defined token
+/// & a timestamp (both encoded in big endian byte order).
+///
+/// Ping implementations usually embed a pattern in the payload of the echo
+/// requests to be able to identify the replies & to calculate the round trip
+/// time (the payload is returned unchanged by the receiver). The
+/// `timestamp` has no predefined unit, it is up to the user to choose one
+/// (e.g. nanoseconds since the unix epoch).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::IcmpEchoPayload;
+///
+/// let payload = IcmpEchoPayload {
+///     token: 0x1234_5678_9abc_def0,
+///     timestamp: 42,
+/// };
+/// let bytes = payload.to_bytes();
+///
+/// // the payload can be decoded again from the echo reply payload
+/// assert_eq!(Some(payload), IcmpEchoPayload::from_slice(&bytes));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IcmpEchoPayload {
+    /// User defined token identifying the echo requests of a sender.
+    pub token: u64,
+    /// Timestamp of the moment the echo request was sent.
+    pub timestamp: u64,
+}
+
+impl IcmpEchoPayload {
+    /// Serialized size of the payload pattern in bytes/octets.
+    pub const LEN: usize = 16;
+
+    /// Returns the serialized payload pattern.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; IcmpEchoPayload::LEN] {
+        let t = self.token.to_be_bytes();
+        let s = self.timestamp.to_be_bytes();
+        [
+            t[0], t[1], t[2], t[3], t[4], t[5], t[6], t[7], s[0], s[1], s[2], s[3], s[4], s[5],
+            s[6], s[7],
+        ]
+    }
+
+    /// Decodes the payload pattern from the start of an echo payload.
+    ///
+    /// Additional bytes after the pattern (e.g. padding) are ignored.
+    /// `None` is returned if the slice is shorter then
+    /// [`IcmpEchoPayload::LEN`].
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Option<IcmpEchoPayload> {
+        if slice.len() < IcmpEchoPayload::LEN {
+            return None;
+        }
+        Some(IcmpEchoPayload {
+            token: u64::from_be_bytes([
+                slice[0], slice[1], slice[2], slice[3], slice[4], slice[5], slice[6], slice[7],
+            ]),
+            timestamp: u64::from_be_bytes([
+                slice[8], slice[9], slice[10], slice[11], slice[12], slice[13], slice[14],
+                slice[15],
+            ]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn to_bytes_from_slice(
+            token in any::<u64>(),
+            timestamp in any::<u64>(),
+            padding in proptest::collection::vec(any::<u8>(), 0..10)
+        ) {
+            let value = IcmpEchoPayload{ token, timestamp };
+            let bytes = value.to_bytes();
+            assert_eq!(&bytes[..8], &token.to_be_bytes());
+            assert_eq!(&bytes[8..], &timestamp.to_be_bytes());
+
+            // with padding
+            let mut data = bytes.to_vec();
+            data.extend_from_slice(&padding);
+            assert_eq!(Some(value), IcmpEchoPayload::from_slice(&data));
+
+            // too short
+            for len in 0..IcmpEchoPayload::LEN {
+                assert_eq!(None, IcmpEchoPayload::from_slice(&bytes[..len]));
+            }
+        }
+
+        #[test]
+        fn clone_eq_debug(
+            token in any::<u64>(),
+            timestamp in any::<u64>()
+        ) {
+            let value = IcmpEchoPayload{ token, timestamp };
+            assert_eq!(value.clone(), value);
+            assert_eq!(
+                format!("IcmpEchoPayload {{ token: {}, timestamp: {} }}", token, timestamp),
+                format!("{:?}", value)
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/// A ICMPv4 address mask request or address mask reply message
+/// (defined in [RFC 950](https://tools.ietf.org/html/rfc950)).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressMaskMessage {
+    pub id: u16,
+    pub seq: u16,
+    /// Subnet address mask (set to 0 in requests).
+    pub address_mask: [u8; 4],
+}
+
+impl AddressMaskMessage {
+    /// The size in bytes/octets of an address mask request or address
+    /// mask reply message.
+    pub const LEN: usize = 12;
+
+    /// Decodes the address mask message part of an ICMPv4 message.
+    pub fn from_bytes(bytes: [u8; 8]) -> AddressMaskMessage {
+        AddressMaskMessage {
+            id: u16::from_be_bytes([bytes[0], bytes[1]]),
+            seq: u16::from_be_bytes([bytes[2], bytes[3]]),
+            address_mask: [bytes[4], bytes[5], bytes[6], bytes[7]],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::icmpv4::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(12, AddressMaskMessage::LEN);
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(bytes in any::<[u8;8]>()) {
+            assert_eq!(
+                AddressMaskMessage::from_bytes(bytes),
+                AddressMaskMessage{
+                    id: u16::from_be_bytes([bytes[0], bytes[1]]),
+                    seq: u16::from_be_bytes([bytes[2], bytes[3]]),
+                    address_mask: [bytes[4], bytes[5], bytes[6], bytes[7]],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn clone_eq() {
+        let v = AddressMaskMessage {
+            id: 0,
+            seq: 0,
+            address_mask: [0; 4],
+        };
+        assert_eq!(v.clone(), v);
+    }
+
+    #[test]
+    fn debug() {
+        let v = AddressMaskMessage {
+            id: 1,
+            seq: 2,
+            address_mask: [255, 255, 255, 0],
+        };
+        assert_eq!(
+            format!("{:?}", v),
+            "AddressMaskMessage { id: 1, seq: 2, address_mask: [255, 255, 255, 0] }"
+        );
+    }
+}
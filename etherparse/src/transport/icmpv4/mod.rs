@@ -1,3 +1,6 @@
+mod address_mask_message;
+pub use address_mask_message::*;
+
 mod dest_unreachable_header;
 pub use dest_unreachable_header::*;
 
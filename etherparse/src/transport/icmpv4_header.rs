@@ -35,6 +35,11 @@ impl Icmpv4Header {
     #[deprecated(since = "0.14.0", note = "Please use Icmpv4Header::MAX_LEN instead")]
     pub const MAX_SERIALIZED_SIZE: usize = 20;
 
+    /// Number of bytes of the offending packets payload that get included
+    /// in ICMPv4 error messages (the "first 64 bits of the original
+    /// datagram's data" according to RFC 792).
+    pub const ERROR_ORIGINAL_DATA_LEN: usize = 8;
+
     /// Constructs an [`Icmpv4Header`] using the given type
     /// and the checksum set to 0.
     pub fn new(icmp_type: Icmpv4Type) -> Icmpv4Header {
@@ -54,6 +59,104 @@ impl Icmpv4Header {
         }
     }
 
+    /// Creates an ICMPv4 error message (e.g. "Destination Unreachable" or
+    /// "Time Exceeded") for an offending IPv4 packet.
+    ///
+    /// Returns the header (with the checksum already calculated) and the
+    /// payload of the error message. As defined in
+    /// [RFC 792](https://datatracker.ietf.org/doc/html/rfc792) the payload
+    /// consists of the IPv4 header of the offending packet followed by the
+    /// first [`Icmpv4Header::ERROR_ORIGINAL_DATA_LEN`] bytes of its payload
+    /// (`invoking_payload` is truncated if it is longer).
+    ///
+    /// Note that the `header_checksum` & `total_len` of `invoking_header`
+    /// are written unchanged, so pass the header as it was received.
+    pub fn error_for(
+        icmp_type: Icmpv4Type,
+        invoking_header: &Ipv4Header,
+        invoking_payload: &[u8],
+    ) -> (
+        Icmpv4Header,
+        ArrayVec<u8, { Ipv4Header::MAX_LEN + Icmpv4Header::ERROR_ORIGINAL_DATA_LEN }>,
+    ) {
+        let mut payload = ArrayVec::new();
+        // cannot fail as the capacity is big enough for the largest ipv4 header
+        // and the truncated payload
+        payload
+            .try_extend_from_slice(&invoking_header.to_bytes())
+            .unwrap();
+        payload
+            .try_extend_from_slice(
+                &invoking_payload[..invoking_payload
+                    .len()
+                    .min(Icmpv4Header::ERROR_ORIGINAL_DATA_LEN)],
+            )
+            .unwrap();
+        (Icmpv4Header::with_checksum(icmp_type, &payload), payload)
+    }
+
+    /// Creates an ICMPv4 "Destination Unreachable" message for an offending
+    /// IPv4 packet (see [`Icmpv4Header::error_for`] for details on the payload).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{icmpv4::DestUnreachableHeader, Icmpv4Header, PacketBuilder, SlicedPacket};
+    ///
+    /// # let mut offending = Vec::new();
+    /// # PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    /// #     .udp(1234, 53)
+    /// #     .write(&mut offending, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+    /// #     .unwrap();
+    /// let (ip, ip_payload) = etherparse::Ipv4Header::from_slice(&offending).unwrap();
+    /// let (icmp, icmp_payload) = Icmpv4Header::destination_unreachable_for(
+    ///     DestUnreachableHeader::Port,
+    ///     &ip,
+    ///     ip_payload,
+    /// );
+    ///
+    /// // ip header + 8 bytes of the original data
+    /// assert_eq!(icmp_payload.len(), ip.header_len() + 8);
+    ///
+    /// let builder = PacketBuilder::ipv4([192, 168, 1, 2], [192, 168, 1, 1], 64)
+    ///     .icmpv4(icmp.icmp_type);
+    /// let mut response = Vec::with_capacity(builder.size(icmp_payload.len()));
+    /// builder.write(&mut response, &icmp_payload).unwrap();
+    /// ```
+    #[inline]
+    pub fn destination_unreachable_for(
+        header: icmpv4::DestUnreachableHeader,
+        invoking_header: &Ipv4Header,
+        invoking_payload: &[u8],
+    ) -> (
+        Icmpv4Header,
+        ArrayVec<u8, { Ipv4Header::MAX_LEN + Icmpv4Header::ERROR_ORIGINAL_DATA_LEN }>,
+    ) {
+        Icmpv4Header::error_for(
+            Icmpv4Type::DestinationUnreachable(header),
+            invoking_header,
+            invoking_payload,
+        )
+    }
+
+    /// Creates an ICMPv4 "Time Exceeded" message for an offending IPv4
+    /// packet (see [`Icmpv4Header::error_for`] for details on the payload).
+    #[inline]
+    pub fn time_exceeded_for(
+        code: icmpv4::TimeExceededCode,
+        invoking_header: &Ipv4Header,
+        invoking_payload: &[u8],
+    ) -> (
+        Icmpv4Header,
+        ArrayVec<u8, { Ipv4Header::MAX_LEN + Icmpv4Header::ERROR_ORIGINAL_DATA_LEN }>,
+    ) {
+        Icmpv4Header::error_for(
+            Icmpv4Type::TimeExceeded(code),
+            invoking_header,
+            invoking_payload,
+        )
+    }
+
     /// Reads an icmp4 header from a slice directly and returns a tuple containing the resulting header & unused part of the slice.
     #[inline]
     pub fn from_slice(slice: &[u8]) -> Result<(Icmpv4Header, &[u8]), err::LenError> {
@@ -86,6 +189,19 @@ impl Icmpv4Header {
                     Ok(Icmpv4Slice { slice: &bytes[..8] }.header())
                 }
             }
+            icmpv4::TYPE_ADDRESS | icmpv4::TYPE_ADDRESSREPLY => {
+                if 0 == bytes[1] {
+                    // Address mask messages need the mask read additionally
+                    reader.read_exact(&mut bytes[8..icmpv4::AddressMaskMessage::LEN])?;
+                    Ok(Icmpv4Slice {
+                        slice: &bytes[..icmpv4::AddressMaskMessage::LEN],
+                    }
+                    .header())
+                } else {
+                    // fallback to unknown
+                    Ok(Icmpv4Slice { slice: &bytes[..8] }.header())
+                }
+            }
             _ => Ok(Icmpv4Slice { slice: &bytes[..8] }.header()),
         }
     }
@@ -118,6 +234,31 @@ impl Icmpv4Header {
         self.checksum = self.icmp_type.calc_checksum(payload);
     }
 
+    /// Calculates & updates the checksum in the header based on a payload
+    /// scattered over multiple parts (the parts are treated as if they were
+    /// one contiguous payload).
+    ///
+    /// Useful for error messages, where the payload consists of the
+    /// headers of the embedded packet followed by its payload bytes:
+    ///
+    /// ```
+    /// use etherparse::{icmpv4::DestUnreachableHeader, Icmpv4Header, Icmpv4Type, Ipv4Header, ip_number};
+    ///
+    /// let embedded_header = Ipv4Header::new(8, 20, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+    /// let embedded_payload = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// let mut header = Icmpv4Header::new(Icmpv4Type::DestinationUnreachable(
+    ///     DestUnreachableHeader::Port,
+    /// ));
+    /// header.update_checksum_from_parts(&[&embedded_header.to_bytes(), &embedded_payload]);
+    /// ```
+    ///
+    /// Note this method assumes that all unused bytes/octets
+    /// are filled with zeroes.
+    pub fn update_checksum_from_parts(&mut self, payload_parts: &[&[u8]]) {
+        self.checksum = self.icmp_type.calc_checksum_slices(payload_parts);
+    }
+
     /// Converts the header to the on the wire bytes.
     #[rustfmt::skip]
     pub fn to_bytes(&self) -> ArrayVec<u8, { Icmpv4Header::MAX_LEN }> {
@@ -134,8 +275,8 @@ impl Icmpv4Header {
                     0, 0, 0, 0,
                 ]);
                 // SAFETY: Safe as u8 has no destruction behavior and as 8 is smaller then 20.
-                unsafe {
-                    re.set_len(8);
+                unchecked! {
+                    truncate_unchecked(&mut re, 8);
                 }
                 re
             };
@@ -157,8 +298,8 @@ impl Icmpv4Header {
                 0, 0, 0, 0,
             ]);
             // SAFETY: Safe as u8 has no destruction behavior and as 8 is smaller then 20.
-            unsafe {
-                re.set_len(8);
+            unchecked! {
+                truncate_unchecked(&mut re, 8);
             }
             re
         };
@@ -177,8 +318,8 @@ impl Icmpv4Header {
                 0, 0, 0, 0,
             ]);
             // SAFETY: Safe as u8 has no destruction behavior and as 8 is smaller then 20.
-            unsafe {
-                re.set_len(8);
+            unchecked! {
+                truncate_unchecked(&mut re, 8);
             }
             re
         };
@@ -201,6 +342,28 @@ impl Icmpv4Header {
             ])
         };
 
+        let re_address_mask_msg = |type_u8: u8,
+                                   msg: &icmpv4::AddressMaskMessage|
+         -> ArrayVec<u8, { Icmpv4Header::MAX_LEN }> {
+            let id = msg.id.to_be_bytes();
+            let seq = msg.seq.to_be_bytes();
+            let m = msg.address_mask;
+
+            #[rustfmt::skip]
+            let mut re = ArrayVec::from([
+                type_u8, 0, checksum_be[0], checksum_be[1],
+                id[0], id[1], seq[0], seq[1],
+                m[0], m[1], m[2], m[3],
+                0, 0, 0, 0,
+                0, 0, 0, 0,
+            ]);
+            // SAFETY: Safe as u8 has no destruction behavior and as 12 is smaller then 20.
+            unchecked! {
+                truncate_unchecked(&mut re, icmpv4::AddressMaskMessage::LEN);
+            }
+            re
+        };
+
         use Icmpv4Type::*;
         use icmpv4::*;
         match self.icmp_type {
@@ -265,11 +428,14 @@ impl Icmpv4Header {
             }
             TimestampRequest(ref msg) => re_timestamp_msg(TYPE_TIMESTAMP, msg),
             TimestampReply(ref msg) => re_timestamp_msg(TYPE_TIMESTAMP_REPLY, msg),
+            AddressMaskRequest(ref msg) => re_address_mask_msg(TYPE_ADDRESS, msg),
+            AddressMaskReply(ref msg) => re_address_mask_msg(TYPE_ADDRESSREPLY, msg),
         }
     }
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{
         err::{Layer, LenError},
@@ -287,6 +453,7 @@ mod test {
         assert_eq!(20, Icmpv4Header::MAX_LEN);
         assert_eq!(8, Icmpv4Header::MIN_SERIALIZED_SIZE);
         assert_eq!(20, Icmpv4Header::MAX_SERIALIZED_SIZE);
+        assert_eq!(8, Icmpv4Header::ERROR_ORIGINAL_DATA_LEN);
     }
 
     proptest! {
@@ -318,6 +485,55 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn error_for(
+            ip_header in ipv4_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let expected_payload = {
+                let mut v = Vec::new();
+                v.extend_from_slice(&ip_header.to_bytes());
+                v.extend_from_slice(&payload[..payload.len().min(8)]);
+                v
+            };
+
+            // destination unreachable
+            {
+                let (header, actual) = Icmpv4Header::destination_unreachable_for(
+                    DestUnreachableHeader::Port,
+                    &ip_header,
+                    &payload
+                );
+                assert_eq!(&actual[..], &expected_payload[..]);
+                assert_eq!(
+                    header,
+                    Icmpv4Header::with_checksum(
+                        Icmpv4Type::DestinationUnreachable(DestUnreachableHeader::Port),
+                        &expected_payload
+                    )
+                );
+            }
+
+            // time exceeded
+            {
+                let (header, actual) = Icmpv4Header::time_exceeded_for(
+                    TimeExceededCode::TtlExceededInTransit,
+                    &ip_header,
+                    &payload
+                );
+                assert_eq!(&actual[..], &expected_payload[..]);
+                assert_eq!(
+                    header,
+                    Icmpv4Header::with_checksum(
+                        Icmpv4Type::TimeExceeded(TimeExceededCode::TtlExceededInTransit),
+                        &expected_payload
+                    )
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice(
@@ -337,8 +553,8 @@ mod test {
                 buffer.extend_from_slice(&header.to_bytes());
 
                 match icmpv4_type {
-                    // skip the payoad for the timestamp request (those don't have a payload)
-                    TimestampRequest(_) | TimestampReply(_) => {},
+                    // skip the payoad for the timestamp & address mask messages (those don't have a payload)
+                    TimestampRequest(_) | TimestampReply(_) | AddressMaskRequest(_) | AddressMaskReply(_) => {},
                     _ => {
                         buffer.extend_from_slice(&[0u8;36]);
                     }
@@ -370,6 +586,8 @@ mod test {
                             match icmpv4_type {
                                 TimestampRequest(_) => Layer::Icmpv4Timestamp,
                                 TimestampReply(_) => Layer::Icmpv4TimestampReply,
+                                AddressMaskRequest(_) => Layer::Icmpv4AddressMask,
+                                AddressMaskReply(_) => Layer::Icmpv4AddressMaskReply,
                                 _ => Layer::Icmpv4,
                             }
                         },
@@ -384,8 +602,13 @@ mod test {
         #[test]
         fn read(
             non_timestamp_type in any::<u8>().prop_filter(
-                "type must be a non timestamp type",
-                |v| (*v != icmpv4::TYPE_TIMESTAMP_REPLY && *v != icmpv4::TYPE_TIMESTAMP)
+                "type must be a non timestamp or address mask type",
+                |v| (
+                    *v != icmpv4::TYPE_TIMESTAMP_REPLY &&
+                    *v != icmpv4::TYPE_TIMESTAMP &&
+                    *v != icmpv4::TYPE_ADDRESS &&
+                    *v != icmpv4::TYPE_ADDRESSREPLY
+                )
             ),
             non_zero_code in 1u8..=u8::MAX,
             bytes in any::<[u8;icmpv4::TimestampMessage::LEN]>()
@@ -399,6 +622,12 @@ mod test {
                 // timestamp with non-zero code
                 (TYPE_TIMESTAMP_REPLY, non_zero_code),
                 (TYPE_TIMESTAMP, non_zero_code),
+                // address mask with zero code
+                (TYPE_ADDRESSREPLY, 0u8),
+                (TYPE_ADDRESS, 0u8),
+                // address mask with non-zero code
+                (TYPE_ADDRESSREPLY, non_zero_code),
+                (TYPE_ADDRESS, non_zero_code),
             ] {
                 let b = {
                     let mut b = bytes.clone();
@@ -406,7 +635,11 @@ mod test {
                     b[1] = code_u8;
                     b
                 };
-                let expected = Icmpv4Header::from_slice(&b).unwrap().0;
+                let expected = if (TYPE_ADDRESS == type_u8 || TYPE_ADDRESSREPLY == type_u8) && 0 == code_u8 {
+                    Icmpv4Header::from_slice(&b[..AddressMaskMessage::LEN]).unwrap().0
+                } else {
+                    Icmpv4Header::from_slice(&b).unwrap().0
+                };
 
                 // ok case
                 {
@@ -499,6 +732,31 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn update_checksum_from_parts(
+            icmpv4_type in icmpv4_type_any(),
+            checksum in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..1024),
+            split_a in any::<usize>(),
+            split_b in any::<usize>(),
+        ) {
+            let split_a = split_a % (payload.len() + 1);
+            let split_b = split_a + split_b % (payload.len() - split_a + 1);
+            let mut header = Icmpv4Header {
+                icmp_type: icmpv4_type.clone(),
+                checksum,
+            };
+            header.update_checksum_from_parts(&[
+                &payload[..split_a],
+                &payload[split_a..split_b],
+                &[],
+                &payload[split_b..],
+            ]);
+            assert_eq!(header.checksum, icmpv4_type.calc_checksum(&payload));
+        }
+    }
+
     proptest! {
         #[test]
         #[rustfmt::skip]
@@ -517,10 +775,28 @@ mod test {
             unknown_type_u8 in any::<u8>(),
             unknown_code_u8 in any::<u8>(),
             bytes5to8 in any::<[u8;4]>(),
+            address_mask in any::<[u8;4]>(),
         ) {
             use Icmpv4Type::*;
             use arrayvec::ArrayVec;
 
+            let mask = AddressMaskMessage{
+                id,
+                seq,
+                address_mask,
+            };
+            let mask_bytes = {
+                let id_be = id.to_be_bytes();
+                let seq_be = seq.to_be_bytes();
+                [
+                    0, 0, 0, 0,
+                    id_be[0], id_be[1], seq_be[0], seq_be[1],
+                    address_mask[0], address_mask[1], address_mask[2], address_mask[3],
+                    0, 0, 0, 0,
+                    0, 0, 0, 0,
+                ]
+            };
+
             let ts = TimestampMessage{
                 id,
                 seq,
@@ -642,6 +918,24 @@ mod test {
                         b
                     }
                 ),
+                (
+                    AddressMaskRequest(mask.clone()),
+                    12,
+                    {
+                        let mut b = mask_bytes;
+                        b[0] = TYPE_ADDRESS;
+                        b
+                    }
+                ),
+                (
+                    AddressMaskReply(mask),
+                    12,
+                    {
+                        let mut b = mask_bytes;
+                        b[0] = TYPE_ADDRESSREPLY;
+                        b
+                    }
+                ),
             ];
 
             for t in random_values {
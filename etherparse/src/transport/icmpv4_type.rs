@@ -1,7 +1,11 @@
 use crate::*;
 
 /// Starting contents of an ICMPv4 packet without the checksum.
+///
+/// Support for more message types can be added in future versions, so
+/// matches need a wildcard arm.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Icmpv4Type {
     /// In case of an unknown ICMP type and code combination is received the
     /// header elements are stored raw in this enum value. The `Unknown` value can
@@ -194,6 +198,23 @@ pub enum Icmpv4Type {
     /// For the `Icmpv4Type::TimestampReply` type the entire ICMP packet is
     /// contained within the header. The payload data is empty.
     TimestampReply(icmpv4::TimestampMessage),
+
+    /// Request for the subnet address mask (deprecated by RFC 6918, but still
+    /// encountered in legacy networks).
+    ///
+    /// # What is part of the header for `Icmpv4Type::AddressMaskRequest`?
+    ///
+    /// For the `Icmpv4Type::AddressMaskRequest` type the entire ICMP packet is
+    /// contained within the header. The payload data is empty.
+    AddressMaskRequest(icmpv4::AddressMaskMessage),
+
+    /// Anwser to a `AddressMaskRequest` message.
+    ///
+    /// # What is part of the header for `Icmpv4Type::AddressMaskReply`?
+    ///
+    /// For the `Icmpv4Type::AddressMaskReply` type the entire ICMP packet is
+    /// contained within the header. The payload data is empty.
+    AddressMaskReply(icmpv4::AddressMaskMessage),
 }
 
 impl Icmpv4Type {
@@ -215,6 +236,7 @@ impl Icmpv4Type {
             | TimeExceeded(_)
             | ParameterProblem(_) => 8,
             TimestampRequest(_) | TimestampReply(_) => icmpv4::TimestampMessage::LEN,
+            AddressMaskRequest(_) | AddressMaskReply(_) => icmpv4::AddressMaskMessage::LEN,
         }
     }
 
@@ -235,12 +257,22 @@ impl Icmpv4Type {
             | EchoRequest(_)
             | TimeExceeded(_)
             | ParameterProblem(_) => None,
-            TimestampRequest(_) | TimestampReply(_) => Some(0),
+            TimestampRequest(_)
+            | TimestampReply(_)
+            | AddressMaskRequest(_)
+            | AddressMaskReply(_) => Some(0),
         }
     }
 
     /// Calculate the ICMP checksum value.
     pub fn calc_checksum(&self, payload: &[u8]) -> u16 {
+        self.calc_checksum_slices(&[payload])
+    }
+
+    /// Calculate the ICMP checksum value for a payload that is scattered
+    /// over multiple slices (the slices are treated as if they were one
+    /// contiguous payload).
+    pub fn calc_checksum_slices(&self, payload_slices: &[&[u8]]) -> u16 {
         use crate::{icmpv4::*, Icmpv4Type::*};
         match self {
             Unknown {
@@ -335,8 +367,18 @@ impl Icmpv4Type {
                 .add_4bytes(msg.originate_timestamp.to_be_bytes())
                 .add_4bytes(msg.receive_timestamp.to_be_bytes())
                 .add_4bytes(msg.transmit_timestamp.to_be_bytes()),
+            AddressMaskRequest(msg) => checksum::Sum16BitWords::new()
+                .add_2bytes([TYPE_ADDRESS, 0])
+                .add_2bytes(msg.id.to_be_bytes())
+                .add_2bytes(msg.seq.to_be_bytes())
+                .add_4bytes(msg.address_mask),
+            AddressMaskReply(msg) => checksum::Sum16BitWords::new()
+                .add_2bytes([TYPE_ADDRESSREPLY, 0])
+                .add_2bytes(msg.id.to_be_bytes())
+                .add_2bytes(msg.seq.to_be_bytes())
+                .add_4bytes(msg.address_mask),
         }
-        .add_slice(payload)
+        .add_slices(payload_slices)
         .ones_complement()
         .to_be()
     }
@@ -358,6 +400,11 @@ mod test {
             transmit_timestamp: 0,
         };
         let dummy_echo = IcmpEchoHeader { id: 0, seq: 0 };
+        let dummy_mask = AddressMaskMessage {
+            id: 0,
+            seq: 0,
+            address_mask: [0; 4],
+        };
         let dummy_redirect = RedirectHeader {
             code: RedirectCode::RedirectForNetwork,
             gateway_internet_address: [0; 4],
@@ -379,6 +426,8 @@ mod test {
             (8, ParameterProblem(ParameterProblemHeader::BadLength)),
             (20, TimestampRequest(dummy_ts.clone())),
             (20, TimestampReply(dummy_ts)),
+            (12, AddressMaskRequest(dummy_mask.clone())),
+            (12, AddressMaskReply(dummy_mask)),
         ];
         for t in tests {
             assert_eq!(t.0, t.1.header_len());
@@ -397,6 +446,11 @@ mod test {
             transmit_timestamp: 0,
         };
         let dummy_echo = IcmpEchoHeader { id: 0, seq: 0 };
+        let dummy_mask = AddressMaskMessage {
+            id: 0,
+            seq: 0,
+            address_mask: [0; 4],
+        };
         let dummy_redirect = RedirectHeader {
             code: RedirectCode::RedirectForNetwork,
             gateway_internet_address: [0; 4],
@@ -418,12 +472,29 @@ mod test {
             (None, ParameterProblem(ParameterProblemHeader::BadLength)),
             (Some(0), TimestampRequest(dummy_ts.clone())),
             (Some(0), TimestampReply(dummy_ts)),
+            (Some(0), AddressMaskRequest(dummy_mask.clone())),
+            (Some(0), AddressMaskReply(dummy_mask)),
         ];
         for t in tests {
             assert_eq!(t.0, t.1.fixed_payload_size());
         }
     }
 
+    proptest! {
+        #[test]
+        fn calc_checksum_slices(
+            icmpv4_type in crate::test_gens::icmpv4_type_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..64),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let split = split.index(payload.len() + 1);
+            assert_eq!(
+                icmpv4_type.calc_checksum(&payload),
+                icmpv4_type.calc_checksum_slices(&[&payload[..split], &[], &payload[split..]])
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn calc_checksum(
@@ -442,8 +513,14 @@ mod test {
             unknown_type_u8 in any::<u8>(),
             unknown_code_u8 in any::<u8>(),
             bytes5to8 in any::<[u8;4]>(),
+            address_mask in any::<[u8;4]>(),
             payload in proptest::collection::vec(any::<u8>(), 0..1024)
         ) {
+            let mask = AddressMaskMessage{
+                id,
+                seq,
+                address_mask,
+            };
             let ts = TimestampMessage{
                 id,
                 seq,
@@ -475,6 +552,8 @@ mod test {
                 ParameterProblem(param_prob),
                 TimestampRequest(ts.clone()),
                 TimestampReply(ts),
+                AddressMaskRequest(mask.clone()),
+                AddressMaskReply(mask),
             ];
 
             for t in values {
@@ -33,6 +33,15 @@ impl Icmpv6Header {
     #[deprecated(since = "0.14.0", note = "Please use Icmpv6Header::MAX_LEN instead")]
     pub const MAX_SERIALIZED_SIZE: usize = Icmpv6Header::MAX_LEN;
 
+    /// Maximum number of bytes of the offending packet that get included
+    /// in ICMPv6 error messages.
+    ///
+    /// According to [RFC 4443](https://datatracker.ietf.org/doc/html/rfc4443#section-2.4)
+    /// an ICMPv6 error message should not exceed the minimum IPv6 MTU
+    /// (1280 bytes) including the IPv6 header (40 bytes) & the ICMPv6
+    /// header (8 bytes).
+    pub const MAX_ERROR_INVOKING_PACKET_LEN: usize = 1280 - Ipv6Header::LEN - 8;
+
     /// Setups a new header with the checksum being set to 0.
     #[inline]
     pub fn new(icmp_type: Icmpv6Type) -> Icmpv6Header {
@@ -57,6 +66,148 @@ impl Icmpv6Header {
         })
     }
 
+    /// Creates an ICMPv6 error message (e.g. "Destination Unreachable",
+    /// "Packet Too Big" or "Time Exceeded") for an offending packet.
+    ///
+    /// `source_ip` & `destination_ip` are the addresses of the IPv6 header
+    /// the error message is sent with (used for the checksum calculation) and
+    /// `invoking_packet` is the offending packet starting with its IPv6 header.
+    ///
+    /// Returns the header (with the checksum already calculated) and the
+    /// payload of the error message. The payload is the `invoking_packet`
+    /// truncated to [`Icmpv6Header::MAX_ERROR_INVOKING_PACKET_LEN`] bytes so
+    /// that the resulting IPv6 packet does not exceed the minimum IPv6 MTU.
+    pub fn error_for(
+        icmp_type: Icmpv6Type,
+        source_ip: [u8; 16],
+        destination_ip: [u8; 16],
+        invoking_packet: &[u8],
+    ) -> (Icmpv6Header, &[u8]) {
+        let payload = &invoking_packet[..invoking_packet
+            .len()
+            .min(Icmpv6Header::MAX_ERROR_INVOKING_PACKET_LEN)];
+        let checksum = match icmp_type.calc_checksum(source_ip, destination_ip, payload) {
+            Ok(checksum) => checksum,
+            // the payload length is limited to MAX_ERROR_INVOKING_PACKET_LEN
+            // and can therefor never be too big for the checksum calculation
+            Err(_) => unreachable!(),
+        };
+        (
+            Icmpv6Header {
+                icmp_type,
+                checksum,
+            },
+            payload,
+        )
+    }
+
+    /// Creates an ICMPv6 "Destination Unreachable" message for an offending
+    /// packet (see [`Icmpv6Header::error_for`] for details on the payload).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{icmpv6::DestUnreachableCode, Icmpv6Header, PacketBuilder};
+    ///
+    /// # let mut offending = Vec::new();
+    /// # PacketBuilder::ipv6([1; 16], [2; 16], 20)
+    /// #     .udp(1234, 53)
+    /// #     .write(&mut offending, &[0; 2000])
+    /// #     .unwrap();
+    /// let (icmp, icmp_payload) = Icmpv6Header::destination_unreachable_for(
+    ///     DestUnreachableCode::Port,
+    ///     [2; 16], // source of the error message
+    ///     [1; 16], // destination of the error message
+    ///     &offending,
+    /// );
+    ///
+    /// // the error message is limited to the minimum IPv6 MTU
+    /// assert_eq!(icmp_payload.len(), Icmpv6Header::MAX_ERROR_INVOKING_PACKET_LEN);
+    ///
+    /// let builder = PacketBuilder::ipv6([2; 16], [1; 16], 64)
+    ///     .icmpv6(icmp.icmp_type);
+    /// let mut response = Vec::with_capacity(builder.size(icmp_payload.len()));
+    /// builder.write(&mut response, icmp_payload).unwrap();
+    /// assert_eq!(1280, response.len());
+    /// ```
+    #[inline]
+    pub fn destination_unreachable_for(
+        code: icmpv6::DestUnreachableCode,
+        source_ip: [u8; 16],
+        destination_ip: [u8; 16],
+        invoking_packet: &[u8],
+    ) -> (Icmpv6Header, &[u8]) {
+        Icmpv6Header::error_for(
+            Icmpv6Type::DestinationUnreachable(code),
+            source_ip,
+            destination_ip,
+            invoking_packet,
+        )
+    }
+
+    /// Creates an ICMPv6 "Packet Too Big" message for an offending packet
+    /// (e.g. for path MTU discovery, see [`Icmpv6Header::error_for`] for
+    /// details on the payload).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Icmpv6Header, Ipv6HeaderSlice, PacketBuilder};
+    ///
+    /// # let mut offending = Vec::new();
+    /// # PacketBuilder::ipv6([1; 16], [2; 16], 20)
+    /// #     .udp(1234, 53)
+    /// #     .write(&mut offending, &[0; 1452])
+    /// #     .unwrap();
+    /// // the response is sent back to the source of the offending packet
+    /// let offending_ip = Ipv6HeaderSlice::from_slice(&offending).unwrap();
+    /// let router_ip = [3; 16];
+    ///
+    /// let (icmp, icmp_payload) = Icmpv6Header::packet_too_big_for(
+    ///     1280,
+    ///     router_ip,
+    ///     offending_ip.source(),
+    ///     &offending,
+    /// );
+    ///
+    /// let builder = PacketBuilder::ipv6(router_ip, offending_ip.source(), 64)
+    ///     .icmpv6(icmp.icmp_type);
+    /// let mut response = Vec::with_capacity(builder.size(icmp_payload.len()));
+    /// builder.write(&mut response, icmp_payload).unwrap();
+    /// assert_eq!(1280, response.len());
+    /// ```
+    #[inline]
+    pub fn packet_too_big_for(
+        mtu: u32,
+        source_ip: [u8; 16],
+        destination_ip: [u8; 16],
+        invoking_packet: &[u8],
+    ) -> (Icmpv6Header, &[u8]) {
+        Icmpv6Header::error_for(
+            Icmpv6Type::PacketTooBig { mtu },
+            source_ip,
+            destination_ip,
+            invoking_packet,
+        )
+    }
+
+    /// Creates an ICMPv6 "Time Exceeded" message for an offending packet
+    /// (see [`Icmpv6Header::error_for`] for details on the payload).
+    #[inline]
+    pub fn time_exceeded_for(
+        code: icmpv6::TimeExceededCode,
+        source_ip: [u8; 16],
+        destination_ip: [u8; 16],
+        invoking_packet: &[u8],
+    ) -> (Icmpv6Header, &[u8]) {
+        Icmpv6Header::error_for(
+            Icmpv6Type::TimeExceeded(code),
+            source_ip,
+            destination_ip,
+            invoking_packet,
+        )
+    }
+
     /// Reads an icmp6 header from a slice directly and returns a tuple
     /// containing the resulting header & unused part of the slice.
     #[inline]
@@ -112,6 +263,47 @@ impl Icmpv6Header {
         Ok(())
     }
 
+    /// Updates the checksum of the header based on a payload scattered over
+    /// multiple parts (the parts are treated as if they were one contiguous
+    /// payload).
+    ///
+    /// Useful for error messages, where the payload consists of the
+    /// headers of the embedded packet followed by its payload bytes:
+    ///
+    /// ```
+    /// use etherparse::{icmpv6::DestUnreachableCode, Icmpv6Header, Icmpv6Type, Ipv6Header};
+    ///
+    /// let embedded_header = Ipv6Header {
+    ///     payload_length: 4,
+    ///     source: [1; 16],
+    ///     destination: [2; 16],
+    ///     ..Default::default()
+    /// };
+    /// let embedded_payload = [1, 2, 3, 4];
+    ///
+    /// let mut header = Icmpv6Header::new(Icmpv6Type::DestinationUnreachable(
+    ///     DestUnreachableCode::Port,
+    /// ));
+    /// header
+    ///     .update_checksum_from_parts(
+    ///         [2; 16],
+    ///         [1; 16],
+    ///         &[&embedded_header.to_bytes(), &embedded_payload],
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn update_checksum_from_parts(
+        &mut self,
+        source_ip: [u8; 16],
+        destination_ip: [u8; 16],
+        payload_parts: &[&[u8]],
+    ) -> Result<(), ValueTooBigError<usize>> {
+        self.checksum =
+            self.icmp_type
+                .calc_checksum_slices(source_ip, destination_ip, payload_parts)?;
+        Ok(())
+    }
+
     /// Returns the header on the wire bytes.
     #[inline]
     pub fn to_bytes(&self) -> ArrayVec<u8, { Icmpv6Header::MAX_LEN }> {
@@ -135,8 +327,8 @@ impl Icmpv6Header {
                 0, 0, 0, 0,
             ]);
                 // SAFETY: Safe as u8 has no destruction behavior and as 8 is smaller then 20.
-                unsafe {
-                    re.set_len(8);
+                unchecked! {
+                    truncate_unchecked(&mut re, 8);
                 }
                 re
             };
@@ -161,8 +353,8 @@ impl Icmpv6Header {
                 0, 0, 0, 0,
             ]);
             // SAFETY: Safe as u8 has no destruction behavior and as 8 is smaller then 20.
-            unsafe {
-                re.set_len(8);
+            unchecked! {
+                truncate_unchecked(&mut re, 8);
             }
             re
         };
@@ -189,6 +381,7 @@ impl Icmpv6Header {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{
         err::{ValueTooBigError, ValueType},
@@ -259,6 +452,77 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn error_for(
+            ip_header in ipv6_any(),
+            packet in proptest::collection::vec(any::<u8>(), 0..1300),
+            mtu in any::<u32>(),
+        ) {
+            let expected_payload = &packet[..packet.len().min(1232)];
+
+            // destination unreachable
+            {
+                let (header, actual) = Icmpv6Header::destination_unreachable_for(
+                    DestUnreachableCode::Port,
+                    ip_header.source,
+                    ip_header.destination,
+                    &packet
+                );
+                assert_eq!(actual, expected_payload);
+                assert_eq!(
+                    header,
+                    Icmpv6Header::with_checksum(
+                        Icmpv6Type::DestinationUnreachable(DestUnreachableCode::Port),
+                        ip_header.source,
+                        ip_header.destination,
+                        expected_payload
+                    ).unwrap()
+                );
+            }
+
+            // packet too big
+            {
+                let (header, actual) = Icmpv6Header::packet_too_big_for(
+                    mtu,
+                    ip_header.source,
+                    ip_header.destination,
+                    &packet
+                );
+                assert_eq!(actual, expected_payload);
+                assert_eq!(
+                    header,
+                    Icmpv6Header::with_checksum(
+                        Icmpv6Type::PacketTooBig { mtu },
+                        ip_header.source,
+                        ip_header.destination,
+                        expected_payload
+                    ).unwrap()
+                );
+            }
+
+            // time exceeded
+            {
+                let (header, actual) = Icmpv6Header::time_exceeded_for(
+                    TimeExceededCode::HopLimitExceeded,
+                    ip_header.source,
+                    ip_header.destination,
+                    &packet
+                );
+                assert_eq!(actual, expected_payload);
+                assert_eq!(
+                    header,
+                    Icmpv6Header::with_checksum(
+                        Icmpv6Type::TimeExceeded(TimeExceededCode::HopLimitExceeded),
+                        ip_header.source,
+                        ip_header.destination,
+                        expected_payload
+                    ).unwrap()
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice(
@@ -446,6 +710,32 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn update_checksum_from_parts(
+            ip_header in ipv6_any(),
+            icmp_type in icmpv6_type_any(),
+            start_checksum in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..1024),
+            split in any::<usize>(),
+        ) {
+            let split = split % (payload.len() + 1);
+            let mut header = Icmpv6Header{
+                icmp_type,
+                checksum: start_checksum,
+            };
+            header.update_checksum_from_parts(
+                ip_header.source,
+                ip_header.destination,
+                &[&payload[..split], &[], &payload[split..]]
+            ).unwrap();
+            assert_eq!(
+                header.checksum,
+                icmp_type.calc_checksum(ip_header.source, ip_header.destination, &payload).unwrap()
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes(
@@ -114,7 +114,7 @@ impl<'a> Icmpv6Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Icmpv6Header::MIN_LEN (8).
-        unsafe { *self.slice.get_unchecked(0) }
+        unchecked! { get_unchecked_u8(self.slice, 0) }
     }
 
     /// Returns "code" value in the ICMPv6 header.
@@ -123,7 +123,7 @@ impl<'a> Icmpv6Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Icmpv6Header::MIN_LEN (8).
-        unsafe { *self.slice.get_unchecked(1) }
+        unchecked! { get_unchecked_u8(self.slice, 1) }
     }
 
     /// Returns "checksum" value in the ICMPv6 header.
@@ -132,7 +132,7 @@ impl<'a> Icmpv6Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Icmpv6Header::MIN_LEN  (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 2) }
     }
 
     /// Returns if the checksum in the slice is correct.
@@ -164,12 +164,12 @@ impl<'a> Icmpv6Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Icmpv6Header::MIN_LEN  (8).
-        unsafe {
+        unchecked! {
             [
-                *self.slice.get_unchecked(4),
-                *self.slice.get_unchecked(5),
-                *self.slice.get_unchecked(6),
-                *self.slice.get_unchecked(7),
+                get_unchecked_u8(self.slice, 4),
+                get_unchecked_u8(self.slice, 5),
+                get_unchecked_u8(self.slice, 6),
+                get_unchecked_u8(self.slice, 7),
             ]
         }
     }
@@ -186,11 +186,12 @@ impl<'a> Icmpv6Slice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Icmpv6Header::MIN_LEN(8).
-        unsafe { core::slice::from_raw_parts(self.slice.as_ptr().add(8), self.slice.len() - 8) }
+        unchecked! { get_unchecked_slice(self.slice, 8, self.slice.len() - 8) }
     }
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{icmpv6::*, test_gens::*, Icmpv6Type::*, *};
     use alloc::{format, vec::Vec};
@@ -0,0 +1,125 @@
+use crate::*;
+
+/// [`Icmpv6Slice`] together with the source & destination addresses of
+/// the enclosing IPv6 header.
+///
+/// The ICMPv6 checksum is calculated over a pseudo header containing the
+/// IPv6 addresses (RFC 4443 section 2.3). Keeping the addresses next to the
+/// slice allows validating the checksum without access to the IPv6 header:
+///
+/// ```
+/// # #[cfg(all(feature = "ipv6", feature = "icmp"))]
+/// # {
+/// use etherparse::{PacketBuilder, SlicedPacket};
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ipv6([1; 16], [2; 16], 20)
+///     .icmpv6_echo_request(1, 2)
+///     .write(&mut packet, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// let icmpv6 = sliced.icmpv6_with_addrs().unwrap();
+/// assert!(icmpv6.is_checksum_valid());
+/// assert_eq!([1; 16], icmpv6.source());
+/// assert_eq!(&[1, 2, 3, 4], icmpv6.icmpv6().payload());
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Icmpv6SliceWithAddrs<'a> {
+    icmpv6: Icmpv6Slice<'a>,
+    source: [u8; 16],
+    destination: [u8; 16],
+}
+
+impl<'a> Icmpv6SliceWithAddrs<'a> {
+    /// Creates a ICMPv6 slice with the source & destination addresses of
+    /// the enclosing IPv6 header.
+    #[inline]
+    pub fn new(
+        icmpv6: Icmpv6Slice<'a>,
+        source: [u8; 16],
+        destination: [u8; 16],
+    ) -> Icmpv6SliceWithAddrs<'a> {
+        Icmpv6SliceWithAddrs {
+            icmpv6,
+            source,
+            destination,
+        }
+    }
+
+    /// Returns the ICMPv6 slice.
+    #[inline]
+    pub fn icmpv6(&self) -> &Icmpv6Slice<'a> {
+        &self.icmpv6
+    }
+
+    /// Source address of the enclosing IPv6 header.
+    #[inline]
+    pub fn source(&self) -> [u8; 16] {
+        self.source
+    }
+
+    /// Destination address of the enclosing IPv6 header.
+    #[inline]
+    pub fn destination(&self) -> [u8; 16] {
+        self.destination
+    }
+
+    /// Returns if the checksum in the ICMPv6 slice is correct.
+    #[inline]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.icmpv6.is_checksum_valid(self.source, self.destination)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn new_getters_checksum(
+            source in any::<[u8;16]>(),
+            destination in any::<[u8;16]>(),
+            id in any::<u16>(),
+            seq in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut packet = Vec::new();
+            PacketBuilder::ipv6(source, destination, 20)
+                .icmpv6_echo_request(id, seq)
+                .write(&mut packet, &payload)
+                .unwrap();
+            let icmpv6 = Icmpv6Slice::from_slice(&packet[Ipv6Header::LEN..]).unwrap();
+
+            let actual = Icmpv6SliceWithAddrs::new(icmpv6.clone(), source, destination);
+            assert_eq!(&icmpv6, actual.icmpv6());
+            assert_eq!(source, actual.source());
+            assert_eq!(destination, actual.destination());
+            assert!(actual.is_checksum_valid());
+
+            // wrong addresses
+            let mut other = destination;
+            other[0] = other[0].wrapping_add(1);
+            assert!(false == Icmpv6SliceWithAddrs::new(icmpv6, source, other).is_checksum_valid());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let bytes = [128, 0, 0, 0, 0, 0, 0, 0];
+        let icmpv6 = Icmpv6Slice::from_slice(&bytes).unwrap();
+        let value = Icmpv6SliceWithAddrs::new(icmpv6.clone(), [1; 16], [2; 16]);
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!(
+                "Icmpv6SliceWithAddrs {{ icmpv6: {:?}, source: {:?}, destination: {:?} }}",
+                icmpv6, [1u8; 16], [2u8; 16]
+            ),
+            format!("{:?}", value)
+        );
+    }
+}
@@ -386,6 +386,20 @@ impl Icmpv6Type {
         source_ip: [u8; 16],
         destination_ip: [u8; 16],
         payload: &[u8],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_slices(source_ip, destination_ip, &[payload])
+    }
+
+    /// Calculates the checksum of the ICMPv6 header for a payload that is
+    /// scattered over multiple slices (the slices are treated as if they
+    /// were one contiguous payload).
+    ///
+    /// The same restrictions as for [`Icmpv6Type::calc_checksum`] apply.
+    pub fn calc_checksum_slices(
+        &self,
+        source_ip: [u8; 16],
+        destination_ip: [u8; 16],
+        payload_slices: &[&[u8]],
     ) -> Result<u16, ValueTooBigError<usize>> {
         // check that the total length fits into the field
         //
@@ -395,15 +409,16 @@ impl Icmpv6Type {
         // from the IPv6 header, minus the length of any extension headers present
         // between the IPv6 header and the upper-layer header."
         let max_payload_len: usize = (u32::MAX as usize) - self.header_len();
-        if max_payload_len < payload.len() {
+        let payload_len = checksum::slices_len(payload_slices);
+        if max_payload_len < payload_len {
             return Err(ValueTooBigError {
-                actual: payload.len(),
+                actual: payload_len,
                 max_allowed: max_payload_len,
                 value_type: ValueType::Icmpv6PayloadLength,
             });
         }
 
-        let msg_len = payload.len() + self.header_len();
+        let msg_len = payload_len + self.header_len();
 
         // calculate the checksum
         // NOTE: rfc4443 section 2.3 - Icmp6 *does* use a pseudoheader,
@@ -440,7 +455,7 @@ impl Icmpv6Type {
                 .add_2bytes([TYPE_ECHO_REPLY, 0])
                 .add_4bytes(echo.to_bytes()),
         }
-        .add_slice(payload)
+        .add_slices(payload_slices)
         .ones_complement()
         .to_be())
     }
@@ -501,6 +516,7 @@ impl Icmpv6Type {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{
         err::{ValueTooBigError, ValueType},
@@ -600,6 +616,26 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn calc_checksum_slices(
+            ip_header in ipv6_any(),
+            icmpv6_type in icmpv6_type_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..64),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let split = split.index(payload.len() + 1);
+            assert_eq!(
+                icmpv6_type.calc_checksum(ip_header.source, ip_header.destination, &payload),
+                icmpv6_type.calc_checksum_slices(
+                    ip_header.source,
+                    ip_header.destination,
+                    &[&payload[..split], &[], &payload[split..]]
+                )
+            );
+        }
+    }
+
     proptest! {
         #[test]
         #[cfg(not(target_pointer_width = "32"))]
@@ -1,4 +1,7 @@
+pub mod checksum_result;
 pub mod icmp_echo_header;
+pub mod icmp_echo_match;
+pub mod icmp_echo_payload;
 /// Module containing ICMPv4 related types and constants.
 pub mod icmpv4;
 pub mod icmpv4_header;
@@ -8,18 +11,26 @@ pub mod icmpv4_type;
 pub mod icmpv6;
 pub mod icmpv6_header;
 pub mod icmpv6_slice;
+pub mod icmpv6_slice_with_addrs;
 pub mod icmpv6_type;
+pub mod probe_id;
 pub mod tcp_header;
 pub mod tcp_header_slice;
+pub mod tcp_header_slice_mut;
 pub mod tcp_option_element;
 pub mod tcp_option_impl;
 pub mod tcp_option_read_error;
 pub mod tcp_option_write_error;
 pub mod tcp_options;
 pub mod tcp_options_iterator;
+pub mod tcp_segment_kind;
+pub mod tcp_seq_range;
 pub mod tcp_slice;
 pub mod transport_header;
 pub mod transport_slice;
 pub mod udp_header;
+pub mod udp_header_raw;
 pub mod udp_header_slice;
+pub mod udp_header_slice_mut;
 pub mod udp_slice;
+pub mod udp_zero_checksum_policy;
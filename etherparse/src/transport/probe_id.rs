@@ -0,0 +1,401 @@
+use crate::*;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Key to match ICMP error messages (e.g. "time exceeded" messages sent by
+/// routers) to the UDP or TCP probe packets that triggered them (e.g. for a
+/// traceroute implementation).
+///
+/// ICMP error messages contain the start of the packet that caused the
+/// error. [`ProbeId::from_icmpv4_error`] & [`ProbeId::from_icmpv6_error`]
+/// parse this embedded packet via the lax parser (it is usually cut off)
+/// and extract the same key as [`ProbeId::from_sliced_packet`] extracts
+/// from the sent probe. Only the first 4 bytes of the embedded UDP or TCP
+/// header are required (ICMPv4 errors are only guaranteed to contain the
+/// first 8 bytes of the original IP payload).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{Icmpv4Slice, PacketBuilder, ProbeId, SlicedPacket, icmpv4};
+///
+/// // probes with incrementing ttl
+/// let mut probes = Vec::new();
+/// for ttl in 1..=3u8 {
+///     let mut probe = Vec::new();
+///     PacketBuilder::ipv4([192, 168, 1, 1], [10, 0, 0, 1], ttl)
+///         .udp(50000, 33434 + u16::from(ttl))
+///         .write(&mut probe, &[0; 8])
+///         .unwrap();
+///     let id = ProbeId::from_sliced_packet(&SlicedPacket::from_ip(&probe).unwrap()).unwrap();
+///     probes.push((ttl, id, probe));
+/// }
+///
+/// // "time exceeded" message of the router two hops away (containing the
+/// // ip header & the first 8 bytes of the payload of the second probe)
+/// let mut reply = Vec::new();
+/// PacketBuilder::ipv4([172, 16, 0, 1], [192, 168, 1, 1], 64)
+///     .icmpv4(etherparse::Icmpv4Type::TimeExceeded(
+///         icmpv4::TimeExceededCode::TtlExceededInTransit,
+///     ))
+///     .write(&mut reply, &probes[1].2[..20 + 8])
+///     .unwrap();
+///
+/// let icmp = Icmpv4Slice::from_slice(&reply[20..]).unwrap();
+/// let id = ProbeId::from_icmpv4_error(&icmp).unwrap();
+/// let (ttl, _, _) = probes.iter().find(|(_, probe_id, _)| *probe_id == id).unwrap();
+/// assert_eq!(2, *ttl);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct ProbeId {
+    /// Source IP address of the probe.
+    pub source_addr: IpAddr,
+    /// Destination IP address of the probe.
+    pub destination_addr: IpAddr,
+    /// Transport protocol of the probe ([`IpNumber::UDP`] or [`IpNumber::TCP`]).
+    pub ip_number: IpNumber,
+    /// Source port of the probe.
+    pub source_port: u16,
+    /// Destination port of the probe.
+    pub destination_port: u16,
+    /// "identification" field of the IPv4 header of the probe (`None`
+    /// for IPv6 probes).
+    pub ipv4_identification: Option<u16>,
+}
+
+impl ProbeId {
+    /// Returns the key of a sent UDP or TCP probe packet.
+    ///
+    /// `None` is returned if the packet contains no IP header or no UDP
+    /// or TCP header.
+    pub fn from_sliced_packet(packet: &SlicedPacket) -> Option<ProbeId> {
+        let (source_port, destination_port) = match &packet.transport {
+            Some(TransportSlice::Udp(udp)) => (udp.source_port(), udp.destination_port()),
+            Some(TransportSlice::Tcp(tcp)) => (tcp.source_port(), tcp.destination_port()),
+            _ => return None,
+        };
+        let (source_addr, destination_addr, ip_number, ipv4_identification) = match &packet.net {
+            Some(NetSlice::Ipv4(ipv4)) => (
+                IpAddr::V4(Ipv4Addr::from(ipv4.header().source())),
+                IpAddr::V4(Ipv4Addr::from(ipv4.header().destination())),
+                ipv4.payload().ip_number,
+                Some(ipv4.header().identification()),
+            ),
+            Some(NetSlice::Ipv6(ipv6)) => (
+                IpAddr::V6(Ipv6Addr::from(ipv6.header().source())),
+                IpAddr::V6(Ipv6Addr::from(ipv6.header().destination())),
+                ipv6.payload().ip_number,
+                None,
+            ),
+            None => return None,
+        };
+        Some(ProbeId {
+            source_addr,
+            destination_addr,
+            ip_number,
+            source_port,
+            destination_port,
+            ipv4_identification,
+        })
+    }
+
+    /// Returns the key of the probe embedded in an ICMPv4 "destination
+    /// unreachable", "time exceeded" or "parameter problem" message.
+    ///
+    /// `None` is returned if the ICMP message is of a different type or
+    /// if the embedded packet is not an IPv4 packet containing at least
+    /// the ports of an UDP or TCP header (non first fragments are ignored).
+    pub fn from_icmpv4_error(icmp: &Icmpv4Slice) -> Option<ProbeId> {
+        use Icmpv4Type::*;
+        match icmp.icmp_type() {
+            DestinationUnreachable(_) | TimeExceeded(_) | ParameterProblem(_) => {
+                ProbeId::from_embedded(icmp.payload())
+                    .filter(|id| matches!(id.source_addr, IpAddr::V4(_)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the key of the probe embedded in an ICMPv6 "destination
+    /// unreachable", "packet too big", "time exceeded" or "parameter
+    /// problem" message.
+    ///
+    /// `None` is returned if the ICMP message is of a different type or
+    /// if the embedded packet is not an IPv6 packet containing at least
+    /// the ports of an UDP or TCP header (non first fragments are ignored).
+    pub fn from_icmpv6_error(icmp: &Icmpv6Slice) -> Option<ProbeId> {
+        use Icmpv6Type::*;
+        match icmp.icmp_type() {
+            DestinationUnreachable(_)
+            | PacketTooBig { .. }
+            | TimeExceeded(_)
+            | ParameterProblem(_) => ProbeId::from_embedded(icmp.payload())
+                .filter(|id| matches!(id.source_addr, IpAddr::V6(_))),
+            _ => None,
+        }
+    }
+
+    /// Extracts the key from the (usually cut off) packet embedded in an
+    /// ICMP error message.
+    fn from_embedded(ip: &[u8]) -> Option<ProbeId> {
+        let packet = LaxSlicedPacket::from_ip(ip).ok()?;
+        let (source_addr, destination_addr, ipv4_identification, first_fragment) =
+            match packet.net.as_ref()? {
+                LaxNetSlice::Ipv4(ipv4) => {
+                    let header = ipv4.header();
+                    (
+                        IpAddr::V4(Ipv4Addr::from(header.source())),
+                        IpAddr::V4(Ipv4Addr::from(header.destination())),
+                        Some(header.identification()),
+                        header.fragments_offset().value() == 0,
+                    )
+                }
+                LaxNetSlice::Ipv6(ipv6) => (
+                    IpAddr::V6(Ipv6Addr::from(ipv6.header().source())),
+                    IpAddr::V6(Ipv6Addr::from(ipv6.header().destination())),
+                    None,
+                    ipv6.extensions().clone().into_iter().all(|ext| match ext {
+                        Ipv6ExtensionSlice::Fragment(frag) => frag.fragment_offset().value() == 0,
+                        _ => true,
+                    }),
+                ),
+            };
+        if false == first_fragment {
+            return None;
+        }
+
+        // only the ports are read as the embedded transport header
+        // is often cut off
+        let payload = packet.ip_payload()?;
+        match payload.ip_number {
+            ip_number::UDP | ip_number::TCP => {}
+            _ => return None,
+        }
+        let ports = payload.payload.get(..4)?;
+        Some(ProbeId {
+            source_addr,
+            destination_addr,
+            ip_number: payload.ip_number,
+            source_port: u16::from_be_bytes([ports[0], ports[1]]),
+            destination_port: u16::from_be_bytes([ports[2], ports[3]]),
+            ipv4_identification,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    use core::net::Ipv6Addr;
+    use core::net::{IpAddr, Ipv4Addr};
+    use proptest::prelude::*;
+
+    fn icmpv4_error(icmp_type: Icmpv4Type, embedded: &[u8]) -> Vec<u8> {
+        let mut result = Icmpv4Header::with_checksum(icmp_type, embedded)
+            .to_bytes()
+            .to_vec();
+        result.extend_from_slice(embedded);
+        result
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    fn icmpv6_error(icmp_type: Icmpv6Type, embedded: &[u8]) -> Vec<u8> {
+        let mut result = Icmpv6Header::new(icmp_type).to_bytes().to_vec();
+        result.extend_from_slice(embedded);
+        result
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_udp(
+            source in any::<[u8;4]>(),
+            destination in any::<[u8;4]>(),
+            identification in any::<u16>(),
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+            embedded_payload_len in 4usize..16
+        ) {
+            let mut probe = Vec::new();
+            let mut ip = Ipv4Header::new(0, 1, IpNumber::UDP, source, destination).unwrap();
+            ip.identification = identification;
+            PacketBuilder::ip(IpHeaders::Ipv4(ip, Default::default()))
+                .udp(source_port, destination_port)
+                .write(&mut probe, &[0; 8])
+                .unwrap();
+
+            let expected = ProbeId {
+                source_addr: IpAddr::V4(Ipv4Addr::from(source)),
+                destination_addr: IpAddr::V4(Ipv4Addr::from(destination)),
+                ip_number: IpNumber::UDP,
+                source_port,
+                destination_port,
+                ipv4_identification: Some(identification),
+            };
+            assert_eq!(
+                Some(expected),
+                ProbeId::from_sliced_packet(&SlicedPacket::from_ip(&probe).unwrap())
+            );
+
+            let embedded = &probe[..Ipv4Header::MIN_LEN + embedded_payload_len];
+            let error_types = [
+                Icmpv4Type::TimeExceeded(icmpv4::TimeExceededCode::TtlExceededInTransit),
+                Icmpv4Type::DestinationUnreachable(icmpv4::DestUnreachableHeader::Port),
+                Icmpv4Type::ParameterProblem(icmpv4::ParameterProblemHeader::PointerIndicatesError(0)),
+            ];
+            for icmp_type in error_types {
+                let icmp = icmpv4_error(icmp_type, embedded);
+                assert_eq!(
+                    Some(expected),
+                    ProbeId::from_icmpv4_error(&Icmpv4Slice::from_slice(&icmp).unwrap())
+                );
+            }
+
+            // other icmp type
+            let icmp = icmpv4_error(Icmpv4Type::EchoReply(IcmpEchoHeader { id: 1, seq: 2 }), embedded);
+            assert_eq!(None, ProbeId::from_icmpv4_error(&Icmpv4Slice::from_slice(&icmp).unwrap()));
+
+            // ports cut off
+            let icmp = icmpv4_error(
+                Icmpv4Type::TimeExceeded(icmpv4::TimeExceededCode::TtlExceededInTransit),
+                &probe[..Ipv4Header::MIN_LEN + 3],
+            );
+            assert_eq!(None, ProbeId::from_icmpv4_error(&Icmpv4Slice::from_slice(&icmp).unwrap()));
+        }
+    }
+
+    proptest! {
+        #[cfg(all(feature = "ipv6", feature = "tcp"))]
+        #[test]
+        fn ipv6_tcp(
+            source in any::<[u8;16]>(),
+            destination in any::<[u8;16]>(),
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+        ) {
+            let mut probe = Vec::new();
+            PacketBuilder::ipv6(source, destination, 1)
+                .tcp(source_port, destination_port, 1, 1024)
+                .syn()
+                .write(&mut probe, &[])
+                .unwrap();
+
+            let expected = ProbeId {
+                source_addr: IpAddr::V6(Ipv6Addr::from(source)),
+                destination_addr: IpAddr::V6(Ipv6Addr::from(destination)),
+                ip_number: IpNumber::TCP,
+                source_port,
+                destination_port,
+                ipv4_identification: None,
+            };
+            assert_eq!(
+                Some(expected),
+                ProbeId::from_sliced_packet(&SlicedPacket::from_ip(&probe).unwrap())
+            );
+
+            let embedded = &probe[..Ipv6Header::LEN + 8];
+            let error_types = [
+                Icmpv6Type::TimeExceeded(icmpv6::TimeExceededCode::HopLimitExceeded),
+                Icmpv6Type::DestinationUnreachable(icmpv6::DestUnreachableCode::Port),
+                Icmpv6Type::PacketTooBig { mtu: 1280 },
+                Icmpv6Type::ParameterProblem(icmpv6::ParameterProblemHeader {
+                    code: icmpv6::ParameterProblemCode::ErroneousHeaderField,
+                    pointer: 0,
+                }),
+            ];
+            for icmp_type in error_types {
+                let icmp = icmpv6_error(icmp_type, embedded);
+                assert_eq!(
+                    Some(expected),
+                    ProbeId::from_icmpv6_error(&Icmpv6Slice::from_slice(&icmp).unwrap())
+                );
+            }
+
+            // other icmp type
+            let icmp = icmpv6_error(Icmpv6Type::EchoReply(IcmpEchoHeader { id: 1, seq: 2 }), embedded);
+            assert_eq!(None, ProbeId::from_icmpv6_error(&Icmpv6Slice::from_slice(&icmp).unwrap()));
+        }
+    }
+
+    #[test]
+    fn from_embedded_none() {
+        let time_exceeded = |embedded: &[u8]| {
+            let icmp = icmpv4_error(
+                Icmpv4Type::TimeExceeded(icmpv4::TimeExceededCode::TtlExceededInTransit),
+                embedded,
+            );
+            ProbeId::from_icmpv4_error(&Icmpv4Slice::from_slice(&icmp).unwrap())
+        };
+
+        // non udp or tcp packet
+        {
+            let mut probe = Vec::new();
+            PacketBuilder::ipv4([1; 4], [2; 4], 1)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut probe, &[])
+                .unwrap();
+            assert_eq!(None, time_exceeded(&probe));
+            assert_eq!(
+                None,
+                ProbeId::from_sliced_packet(&SlicedPacket::from_ip(&probe).unwrap())
+            );
+        }
+
+        // non first fragment
+        {
+            let mut ip = Ipv4Header::new(8, 1, IpNumber::UDP, [1; 4], [2; 4]).unwrap();
+            ip.more_fragments = true;
+            ip.fragment_offset = IpFragOffset::try_new(1).unwrap();
+            let mut probe = ip.to_bytes().to_vec();
+            probe.extend_from_slice(&[0; 8]);
+            assert_eq!(None, time_exceeded(&probe));
+
+            // first fragment
+            ip.fragment_offset = IpFragOffset::ZERO;
+            let mut probe = ip.to_bytes().to_vec();
+            probe.extend_from_slice(&[0, 1, 0, 2, 0, 0, 0, 0]);
+            assert_eq!(Some(1), time_exceeded(&probe).map(|id| id.source_port));
+        }
+
+        // invalid ip header
+        assert_eq!(None, time_exceeded(&[0x70, 0, 0, 0]));
+
+        // ipv6 packet embedded in icmpv4 error
+        {
+            let mut probe = Vec::new();
+            PacketBuilder::ipv6([1; 16], [2; 16], 1)
+                .udp(1, 2)
+                .write(&mut probe, &[])
+                .unwrap();
+            assert_eq!(None, time_exceeded(&probe));
+        }
+
+        // no ip header
+        assert_eq!(
+            None,
+            ProbeId::from_sliced_packet(
+                &SlicedPacket::from_ether_type(EtherType::ARP, &[]).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let id = ProbeId {
+            source_addr: IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+            destination_addr: IpAddr::V4(Ipv4Addr::new(5, 6, 7, 8)),
+            ip_number: IpNumber::UDP,
+            source_port: 1,
+            destination_port: 2,
+            ipv4_identification: None,
+        };
+        assert_eq!(id, id.clone());
+        assert_eq!(
+            format!(
+                "ProbeId {{ source_addr: 1.2.3.4, destination_addr: 5.6.7.8, ip_number: {:?}, source_port: 1, destination_port: 2, ipv4_identification: None }}",
+                IpNumber::UDP
+            ),
+            format!("{:?}", id)
+        );
+    }
+}
@@ -206,6 +206,41 @@ impl TcpHeader {
         self.options.elements_iter()
     }
 
+    /// Classifies the segment based on its flags & the given payload
+    /// length (see [`TcpSegmentKind`]).
+    #[inline]
+    pub fn segment_kind(&self, payload_len: usize) -> TcpSegmentKind {
+        TcpSegmentKind::from_flags(self.syn, self.ack, self.fin, self.rst, payload_len)
+    }
+
+    /// Returns the range of sequence numbers occupied by a segment with
+    /// this header & the given payload length ("SEG.SEQ" to
+    /// "SEG.SEQ + SEG.LEN" as defined in RFC 793, the SYN & FIN flags
+    /// each occupy one sequence number).
+    ///
+    /// Payload lengths bigger than `u32::MAX` are truncated.
+    #[inline]
+    pub fn seq_range(&self, payload_len: usize) -> TcpSeqRange {
+        TcpSeqRange::new(
+            self.sequence_number,
+            (payload_len as u32)
+                .wrapping_add(u32::from(self.syn))
+                .wrapping_add(u32::from(self.fin)),
+        )
+    }
+
+    /// Returns the range of sequence numbers of the payload bytes
+    /// (starts after the sequence number occupied by a SYN flag).
+    ///
+    /// Payload lengths bigger than `u32::MAX` are truncated.
+    #[inline]
+    pub fn payload_seq_range(&self, payload_len: usize) -> TcpSeqRange {
+        TcpSeqRange::new(
+            self.sequence_number.wrapping_add(u32::from(self.syn)),
+            payload_len as u32,
+        )
+    }
+
     /// Renamed to `TcpHeader::from_slice`
     #[deprecated(since = "0.10.1", note = "Use TcpHeader::from_slice instead.")]
     #[inline]
@@ -280,6 +315,32 @@ impl TcpHeader {
         })
     }
 
+    /// Read a tcp header from the current position of the async reader
+    /// (requires crate feature `tokio`).
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<TcpHeader, err::tcp::HeaderReadError> {
+        use err::tcp::HeaderReadError::*;
+
+        let mut buffer = [0u8; TcpHeader::MAX_LEN];
+        async_read_exact(reader, &mut buffer[..TcpHeader::MIN_LEN])
+            .await
+            .map_err(Io)?;
+
+        // read the options (if the data offset is valid)
+        let header_len = usize::from(buffer[12] >> 4) * 4;
+        if header_len > TcpHeader::MIN_LEN {
+            async_read_exact(reader, &mut buffer[TcpHeader::MIN_LEN..header_len])
+                .await
+                .map_err(Io)?;
+        }
+
+        // decode & validate via the sync implementation
+        TcpHeader::read(&mut std::io::Cursor::new(&buffer[..]))
+    }
+
     /// Write the tcp header to a stream (does NOT calculate the checksum).
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -436,8 +497,8 @@ impl TcpHeader {
         result.extend(self.options.buf);
         // SAFETY: Safe as the header len can not exceed the maximum length
         // of the header.
-        unsafe {
-            result.set_len(self.header_len());
+        unchecked! {
+            truncate_unchecked(&mut result, self.header_len());
         }
 
         result
@@ -458,26 +519,51 @@ impl TcpHeader {
         source_ip: [u8; 4],
         destination_ip: [u8; 4],
         payload: &[u8],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_ipv4_raw_slices(source_ip, destination_ip, &[payload])
+    }
+
+    /// Calculates the checksum for the current header in ipv4 mode with a
+    /// payload that is scattered over multiple slices (the slices are
+    /// treated as if they were one contiguous payload). This does NOT set
+    /// the checksum.
+    pub fn calc_checksum_ipv4_slices(
+        &self,
+        ip_header: &Ipv4Header,
+        payload_slices: &[&[u8]],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_ipv4_raw_slices(ip_header.source, ip_header.destination, payload_slices)
+    }
+
+    /// Calculates the checksum for the current header in ipv4 mode based on
+    /// the given addresses and a payload that is scattered over multiple
+    /// slices. This does NOT set the checksum.
+    pub fn calc_checksum_ipv4_raw_slices(
+        &self,
+        source_ip: [u8; 4],
+        destination_ip: [u8; 4],
+        payload_slices: &[&[u8]],
     ) -> Result<u16, ValueTooBigError<usize>> {
         // check that the total length fits into the tcp length field
         let max_payload = usize::from(u16::MAX) - self.header_len();
-        if max_payload < payload.len() {
+        let payload_len = checksum::slices_len(payload_slices);
+        if max_payload < payload_len {
             return Err(ValueTooBigError {
-                actual: payload.len(),
+                actual: payload_len,
                 max_allowed: max_payload,
                 value_type: ValueType::TcpPayloadLengthIpv4,
             });
         }
 
         // calculate the checksum
-        let tcp_len = self.header_len_u16() + (payload.len() as u16);
+        let tcp_len = self.header_len_u16() + (payload_len as u16);
         Ok(self.calc_checksum_post_ip(
             checksum::Sum16BitWords::new()
                 .add_4bytes(source_ip)
                 .add_4bytes(destination_ip)
                 .add_2bytes([0, ip_number::TCP.0])
                 .add_2bytes(tcp_len.to_be_bytes()),
-            payload,
+            payload_slices,
         ))
     }
 
@@ -496,25 +582,50 @@ impl TcpHeader {
         source: [u8; 16],
         destination: [u8; 16],
         payload: &[u8],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_ipv6_raw_slices(source, destination, &[payload])
+    }
+
+    /// Calculates the checksum for the current header in ipv6 mode with a
+    /// payload that is scattered over multiple slices (the slices are
+    /// treated as if they were one contiguous payload). This does NOT set
+    /// the checksum.
+    pub fn calc_checksum_ipv6_slices(
+        &self,
+        ip_header: &Ipv6Header,
+        payload_slices: &[&[u8]],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_ipv6_raw_slices(ip_header.source, ip_header.destination, payload_slices)
+    }
+
+    /// Calculates the checksum for the current header in ipv6 mode based on
+    /// the given addresses and a payload that is scattered over multiple
+    /// slices. This does NOT set the checksum.
+    pub fn calc_checksum_ipv6_raw_slices(
+        &self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        payload_slices: &[&[u8]],
     ) -> Result<u16, ValueTooBigError<usize>> {
         // check that the total length fits into the tcp length field
         let max_payload = (u32::MAX as usize) - self.header_len();
-        if max_payload < payload.len() {
+        let payload_len = checksum::slices_len(payload_slices);
+        if max_payload < payload_len {
             return Err(ValueTooBigError {
-                actual: payload.len(),
+                actual: payload_len,
                 max_allowed: max_payload,
                 value_type: ValueType::TcpPayloadLengthIpv6,
             });
         }
 
-        let tcp_len = u32::from(self.header_len_u16()) + (payload.len() as u32);
+        let tcp_len = u32::from(self.header_len_u16()) + (payload_len as u32);
         Ok(self.calc_checksum_post_ip(
             checksum::Sum16BitWords::new()
                 .add_16bytes(source)
                 .add_16bytes(destination)
                 .add_4bytes(tcp_len.to_be_bytes())
                 .add_2bytes([0, ip_number::TCP.0]),
-            payload,
+            payload_slices,
         ))
     }
 
@@ -522,7 +633,7 @@ impl TcpHeader {
     fn calc_checksum_post_ip(
         &self,
         ip_pseudo_header_sum: checksum::Sum16BitWords,
-        payload: &[u8],
+        payload_slices: &[&[u8]],
     ) -> u16 {
         ip_pseudo_header_sum
             .add_2bytes(self.source_port.to_be_bytes())
@@ -570,7 +681,7 @@ impl TcpHeader {
             .add_2bytes(self.window_size.to_be_bytes())
             .add_2bytes(self.urgent_pointer.to_be_bytes())
             .add_slice(self.options.as_slice())
-            .add_slice(payload)
+            .add_slices(payload_slices)
             .ones_complement()
             .to_be()
     }
@@ -603,7 +714,43 @@ impl Default for TcpHeader {
     }
 }
 
+/// Writes the header in the format `source port -> destination port [flags]
+/// seq=<sequence number> ack=<acknowledgment number> win=<window size>`, e.g.
+/// `1234 -> 80 [SYN, ACK] seq=1 ack=2 win=1024` (`ack=` is only written if the
+/// ACK flag is set).
+impl core::fmt::Display for TcpHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} -> {} [", self.source_port, self.destination_port)?;
+        let flags = [
+            (self.fin, "FIN"),
+            (self.syn, "SYN"),
+            (self.rst, "RST"),
+            (self.psh, "PSH"),
+            (self.ack, "ACK"),
+            (self.urg, "URG"),
+            (self.ece, "ECE"),
+            (self.cwr, "CWR"),
+            (self.ns, "NS"),
+        ];
+        let mut first = true;
+        for (_, name) in flags.iter().filter(|(set, _)| *set) {
+            if first {
+                first = false;
+            } else {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", name)?;
+        }
+        write!(f, "] seq={}", self.sequence_number)?;
+        if self.ack {
+            write!(f, " ack={}", self.acknowledgment_number)?;
+        }
+        write!(f, " win={}", self.window_size)
+    }
+}
+
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{
         err::{
@@ -1460,6 +1607,47 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            header in tcp_any(),
+            bad_data_offset in 0u8..TcpHeader::MIN_DATA_OFFSET,
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            // compare the async read with the sync read for all
+            // prefixes of the given data
+            let check = |data: &[u8]| {
+                for len in 0..=data.len() {
+                    let mut cursor = Cursor::new(&data[..len]);
+                    let expected = TcpHeader::read(&mut cursor);
+                    let mut reader = &data[..len];
+                    let actual = rt.block_on(TcpHeader::async_read(&mut reader));
+                    match (expected, actual) {
+                        (Ok(expected), Ok(actual)) => {
+                            assert_eq!(expected, actual);
+                            assert_eq!(cursor.position() as usize, len - reader.len());
+                        }
+                        (Err(err::tcp::HeaderReadError::Io(_)), Err(err::tcp::HeaderReadError::Io(_))) => {}
+                        (Err(err::tcp::HeaderReadError::Content(expected)), Err(err::tcp::HeaderReadError::Content(actual))) => {
+                            assert_eq!(expected, actual)
+                        }
+                        (expected, actual) => panic!("{:?} != {:?}", expected, actual),
+                    }
+                }
+            };
+
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.push(1);
+            check(&buffer);
+
+            // bad data offset
+            buffer[12] = (bad_data_offset << 4) | (buffer[12] & 0xf);
+            check(&buffer);
+        }
+    }
+
     proptest! {
         #[test]
         fn read(header in tcp_any()) {
@@ -1741,6 +1929,49 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn calc_checksum_slices(
+            tcp in tcp_any(),
+            ipv4 in ipv4_any(),
+            ipv6 in ipv6_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..64),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let split = split.index(payload.len() + 1);
+            let slices: [&[u8]; 3] = [&payload[..split], &[], &payload[split..]];
+            assert_eq!(
+                tcp.calc_checksum_ipv4(&ipv4, &payload),
+                tcp.calc_checksum_ipv4_slices(&ipv4, &slices)
+            );
+            assert_eq!(
+                tcp.calc_checksum_ipv4_raw(ipv4.source, ipv4.destination, &payload),
+                tcp.calc_checksum_ipv4_raw_slices(ipv4.source, ipv4.destination, &slices)
+            );
+            assert_eq!(
+                tcp.calc_checksum_ipv6(&ipv6, &payload),
+                tcp.calc_checksum_ipv6_slices(&ipv6, &slices)
+            );
+            assert_eq!(
+                tcp.calc_checksum_ipv6_raw(ipv6.source, ipv6.destination, &payload),
+                tcp.calc_checksum_ipv6_raw_slices(ipv6.source, ipv6.destination, &slices)
+            );
+
+            // length error (total length of all slices is checked)
+            let len = usize::from(u16::MAX) - tcp.header_len() + 1;
+            let half = [0u8; (u16::MAX as usize) / 2 + 1];
+            let rest = alloc::vec![0u8; len - half.len()];
+            assert_eq!(
+                Err(ValueTooBigError {
+                    actual: len,
+                    max_allowed: usize::from(u16::MAX) - tcp.header_len(),
+                    value_type: ValueType::TcpPayloadLengthIpv4,
+                }),
+                tcp.calc_checksum_ipv4_slices(&ipv4, &[&half, &rest])
+            );
+        }
+    }
+
     #[test]
     fn calc_checksum_ipv6() {
         // ok case
@@ -1885,4 +2116,46 @@ mod test {
             );
         }
     }
+
+    proptest! {
+        #[test]
+        fn segment_kind(header in tcp_any(), payload_len in 0usize..2) {
+            assert_eq!(
+                TcpSegmentKind::from_flags(header.syn, header.ack, header.fin, header.rst, payload_len),
+                header.segment_kind(payload_len)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn seq_range(header in tcp_any(), payload_len in 0usize..100) {
+            let flags = u32::from(header.syn) + u32::from(header.fin);
+            assert_eq!(
+                TcpSeqRange::new(header.sequence_number, payload_len as u32 + flags),
+                header.seq_range(payload_len)
+            );
+            assert_eq!(
+                TcpSeqRange::new(
+                    header.sequence_number.wrapping_add(u32::from(header.syn)),
+                    payload_len as u32
+                ),
+                header.payload_seq_range(payload_len)
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        let mut header = TcpHeader::new(1234, 80, 1, 1024);
+        assert_eq!("1234 -> 80 [] seq=1 win=1024", format!("{}", header));
+
+        header.syn = true;
+        header.ack = true;
+        header.acknowledgment_number = 2;
+        assert_eq!(
+            "1234 -> 80 [SYN, ACK] seq=1 ack=2 win=1024",
+            format!("{}", header)
+        );
+    }
 }
@@ -28,7 +28,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Safe as it is checked at the start of the function that the
         // length of the slice is at least TcpHeader::MIN_LEN (20).
-        let header_len = unsafe {
+        let header_len = unchecked! {
             // The length of the TCP header can be determined via
             // the data offset field of the TCP header. "data offset"
             // stores the offset in 4 byte steps from the start of the
@@ -58,7 +58,7 @@ impl<'a> TcpHeaderSlice<'a> {
             // ```
             // let len = (*slice.get_unchecked(12) & 0xf0) >> 2;
             // ```
-            usize::from((*slice.get_unchecked(12) & 0xf0) >> 2)
+            usize::from((get_unchecked_u8(slice, 12) & 0xf0) >> 2)
         };
 
         if header_len < TcpHeader::MIN_LEN {
@@ -79,7 +79,7 @@ impl<'a> TcpHeaderSlice<'a> {
                 // SAFETY:
                 // Safe as there is a check above that the slice length
                 // is at least len.
-                slice: unsafe { core::slice::from_raw_parts(slice.as_ptr(), header_len) },
+                slice: unchecked! { get_unchecked_slice(slice, 0, header_len) },
             })
         }
     }
@@ -96,7 +96,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr()) }
+        unchecked! { get_unchecked_be_u16(self.slice, 0) }
     }
 
     /// Read the destination port number.
@@ -105,7 +105,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 2) }
     }
 
     /// Read the sequence number of the first data octet in this segment (except when SYN is present).
@@ -118,7 +118,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 4) }
     }
 
     /// Reads the acknowledgment number.
@@ -133,7 +133,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(8)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 8) }
     }
 
     /// Read the number of 32 bit words in the TCP Header.
@@ -145,7 +145,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { (*self.slice.get_unchecked(12) & 0b1111_0000) >> 4 }
+        unchecked! { (get_unchecked_u8(self.slice, 12) & 0b1111_0000) >> 4 }
     }
 
     /// ECN-nonce - concealment protection (experimental: see RFC 3540)
@@ -154,7 +154,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(12) & 0b0000_0001) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 12) & 0b0000_0001) }
     }
 
     /// Read the fin flag (no more data from sender).
@@ -163,7 +163,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_0001) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_0001) }
     }
 
     /// Reads the syn flag (synchronize sequence numbers).
@@ -172,7 +172,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_0010) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_0010) }
     }
 
     /// Reads the rst flag (reset the connection).
@@ -181,7 +181,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_0100) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_0100) }
     }
 
     /// Reads the psh flag (push function).
@@ -190,7 +190,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_1000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_1000) }
     }
 
     /// Reads the ack flag (acknowledgment field significant).
@@ -199,7 +199,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0001_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0001_0000) }
     }
 
     /// Reads the urg flag (Urgent Pointer field significant).
@@ -208,7 +208,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0010_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0010_0000) }
     }
 
     /// Read the ECN-Echo flag (RFC 3168).
@@ -217,7 +217,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0100_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0100_0000) }
     }
 
     /// Reads the cwr flag (Congestion Window Reduced).
@@ -230,7 +230,7 @@ impl<'a> TcpHeaderSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b1000_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b1000_0000) }
     }
 
     /// The number of data octets beginning with the one indicated in the
@@ -242,7 +242,7 @@ impl<'a> TcpHeaderSlice<'a> {
             // SAFETY:
             // Constructor checks that the slice has at least the length
             // of 20.
-            unsafe { [*self.slice.get_unchecked(14), *self.slice.get_unchecked(15)] },
+            unchecked! { [get_unchecked_u8(self.slice, 14), get_unchecked_u8(self.slice, 15)] },
         )
     }
 
@@ -253,7 +253,7 @@ impl<'a> TcpHeaderSlice<'a> {
             // SAFETY:
             // Constructor checks that the slice has at least the length
             // of 20.
-            unsafe { [*self.slice.get_unchecked(16), *self.slice.get_unchecked(17)] },
+            unchecked! { [get_unchecked_u8(self.slice, 16), get_unchecked_u8(self.slice, 17)] },
         )
     }
 
@@ -269,7 +269,7 @@ impl<'a> TcpHeaderSlice<'a> {
             // SAFETY:
             // Constructor checks that the slice has at least the length
             // of 20.
-            unsafe { [*self.slice.get_unchecked(18), *self.slice.get_unchecked(19)] },
+            unchecked! { [get_unchecked_u8(self.slice, 18), get_unchecked_u8(self.slice, 19)] },
         )
     }
 
@@ -285,6 +285,13 @@ impl<'a> TcpHeaderSlice<'a> {
         TcpOptionsIterator::from_slice(self.options())
     }
 
+    /// Classifies the segment based on its flags & the given payload
+    /// length (see [`TcpSegmentKind`]).
+    #[inline]
+    pub fn segment_kind(&self, payload_len: usize) -> TcpSegmentKind {
+        TcpSegmentKind::from_flags(self.syn(), self.ack(), self.fin(), self.rst(), payload_len)
+    }
+
     /// Decode all the fields and copy the results to a TcpHeader struct
     pub fn to_header(&self) -> TcpHeader {
         TcpHeader {
@@ -410,6 +417,7 @@ impl<'a> TcpHeaderSlice<'a> {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{
         err::{
@@ -973,4 +981,13 @@ mod test {
             );
         }
     }
+
+    proptest! {
+        #[test]
+        fn segment_kind(header in tcp_any(), payload_len in 0usize..2) {
+            let bytes = header.to_bytes();
+            let slice = TcpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header.segment_kind(payload_len), slice.segment_kind(payload_len));
+        }
+    }
 }
@@ -0,0 +1,187 @@
+use crate::*;
+
+/// A mutable slice containing a TCP header of a network package.
+///
+/// Allows modifying individual fields of an already serialized TCP
+/// header (e.g. for rewriting ports) without re-serializing the
+/// complete header.
+#[derive(Debug, Eq, PartialEq)]
+pub struct TcpHeaderSliceMut<'a> {
+    slice: &'a mut [u8],
+}
+
+impl<'a> TcpHeaderSliceMut<'a> {
+    /// Creates a mutable slice containing a TCP header.
+    ///
+    /// The same checks as in [`TcpHeaderSlice::from_slice`] are applied.
+    pub fn from_slice(
+        slice: &'a mut [u8],
+    ) -> Result<TcpHeaderSliceMut<'a>, err::tcp::HeaderSliceError> {
+        let header_len = TcpHeaderSlice::from_slice(slice)?.slice().len();
+        Ok(TcpHeaderSliceMut {
+            slice: &mut slice[..header_len],
+        })
+    }
+
+    /// Returns the slice containing the TCP header.
+    #[inline]
+    pub fn slice(&self) -> &[u8] {
+        self.slice
+    }
+
+    /// Returns a non mutable [`TcpHeaderSlice`] that allows reading all
+    /// fields of the header.
+    #[inline]
+    pub fn to_header_slice(&self) -> TcpHeaderSlice<'_> {
+        TcpHeaderSlice { slice: self.slice }
+    }
+
+    /// Read the source port number.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        u16::from_be_bytes([self.slice[0], self.slice[1]])
+    }
+
+    /// Read the destination port number.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        u16::from_be_bytes([self.slice[2], self.slice[3]])
+    }
+
+    /// Read the checksum.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[16], self.slice[17]])
+    }
+
+    /// Sets the source port & incrementally updates the checksum so it
+    /// stays valid (if it was valid before).
+    ///
+    /// The update of the checksum is done based on
+    /// [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624) and does
+    /// not require access to the payload or the IP header.
+    pub fn set_source_port_with_checksum_update(&mut self, source_port: u16) {
+        let old = self.source_port();
+        self.slice[..2].copy_from_slice(&source_port.to_be_bytes());
+        self.update_checksum(old, source_port);
+    }
+
+    /// Sets the destination port & incrementally updates the checksum so
+    /// it stays valid (if it was valid before).
+    ///
+    /// The update of the checksum is done based on
+    /// [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624) and does
+    /// not require access to the payload or the IP header.
+    pub fn set_destination_port_with_checksum_update(&mut self, destination_port: u16) {
+        let old = self.destination_port();
+        self.slice[2..4].copy_from_slice(&destination_port.to_be_bytes());
+        self.update_checksum(old, destination_port);
+    }
+
+    fn update_checksum(&mut self, old_word: u16, new_word: u16) {
+        let checksum = checksum::incremental_update(self.checksum(), old_word, new_word);
+        self.slice[16..18].copy_from_slice(&checksum.to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    /// Checks that two checksums are equal (0x0000 & 0xffff both
+    /// represent zero in one's complement).
+    fn assert_checksum_eq(expected: u16, actual: u16) {
+        if expected == 0 || expected == 0xffff {
+            assert!(actual == 0 || actual == 0xffff);
+        } else {
+            assert_eq!(expected, actual);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in tcp_any()) {
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.extend_from_slice(&[1, 2, 3]);
+            let header_len = header.header_len();
+
+            // ok
+            {
+                let mut data = buffer.clone();
+                let slice = TcpHeaderSliceMut::from_slice(&mut data).unwrap();
+                assert_eq!(slice.slice(), &buffer[..header_len]);
+                assert_eq!(slice.to_header_slice().to_header(), header);
+                assert_eq!(slice.source_port(), header.source_port);
+                assert_eq!(slice.destination_port(), header.destination_port);
+                assert_eq!(slice.checksum(), header.checksum);
+            }
+
+            // length error
+            for len in 0..header_len {
+                let mut data = buffer.clone();
+                assert_eq!(
+                    TcpHeaderSliceMut::from_slice(&mut data[..len]).unwrap_err(),
+                    TcpHeaderSlice::from_slice(&buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn set_ports_with_checksum_update(
+            ipv4 in ipv4_unknown(),
+            tcp in tcp_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+            new_source_port in any::<u16>(),
+            new_destination_port in any::<u16>(),
+        ) {
+            let mut tcp = tcp;
+            tcp.checksum = tcp.calc_checksum_ipv4(&ipv4, &payload).unwrap();
+            let mut data = tcp.to_bytes().to_vec();
+
+            // source port
+            {
+                let mut slice = TcpHeaderSliceMut::from_slice(&mut data).unwrap();
+                slice.set_source_port_with_checksum_update(new_source_port);
+                assert_eq!(slice.source_port(), new_source_port);
+                let mut expected = tcp.clone();
+                expected.source_port = new_source_port;
+                assert_checksum_eq(
+                    expected.calc_checksum_ipv4(&ipv4, &payload).unwrap(),
+                    slice.checksum()
+                );
+            }
+
+            // destination port
+            {
+                let mut slice = TcpHeaderSliceMut::from_slice(&mut data).unwrap();
+                slice.set_destination_port_with_checksum_update(new_destination_port);
+                assert_eq!(slice.destination_port(), new_destination_port);
+                let mut expected = tcp.clone();
+                expected.source_port = new_source_port;
+                expected.destination_port = new_destination_port;
+                assert_checksum_eq(
+                    expected.calc_checksum_ipv4(&ipv4, &payload).unwrap(),
+                    slice.checksum()
+                );
+                // other fields are unchanged
+                let mut actual = slice.to_header_slice().to_header();
+                actual.checksum = expected.checksum;
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    #[test]
+    fn debug_eq() {
+        let mut data = TcpHeader::new(1, 2, 3, 4).to_bytes().to_vec();
+        let expected = format!("TcpHeaderSliceMut {{ slice: {:?} }}", &data[..]);
+        let mut data2: Vec<u8> = data.clone();
+        let slice = TcpHeaderSliceMut::from_slice(&mut data).unwrap();
+        assert_eq!(expected, format!("{:?}", slice));
+        assert_eq!(slice, TcpHeaderSliceMut::from_slice(&mut data2).unwrap());
+    }
+}
@@ -1,5 +1,13 @@
+use arrayvec::ArrayVec;
+
 /// Different kinds of options that can be present in the options part of a tcp header.
+///
+/// New options can be added in future versions, so matches need a
+/// wildcard arm. Note that the data of the `TcpAuthentication`, `Mptcp` &
+/// `Experimental` options is stored inline, which makes every element 48
+/// bytes big (on 64 bit targets).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum TcpOptionElement {
     /// "No-Operation" option.
     ///
@@ -26,12 +34,43 @@ pub enum TcpOptionElement {
     SelectiveAcknowledgement((u32, u32), [Option<(u32, u32)>; 3]),
     ///Timestamp & echo (first number is the sender timestamp, the second the echo timestamp)
     Timestamp(u32, u32),
+    /// "TCP Authentication Option" (TCP-AO, RFC 5925).
+    TcpAuthentication {
+        /// Identifier of the key used to generate the MAC ("KeyID").
+        key_id: u8,
+        /// Identifier of the key the sender is ready to use next
+        /// ("RNextKeyID").
+        rnext_key_id: u8,
+        /// Message authentication code.
+        mac: ArrayVec<u8, 36>,
+    },
+    /// "Multipath TCP" option (MPTCP, RFC 8684).
+    Mptcp {
+        /// Subtype of the option (4 bit, e.g. 0 for MP_CAPABLE or 2 for DSS).
+        subtype: u8,
+        /// Subtype specific flags or version bits following the subtype
+        /// (4 bit).
+        flags: u8,
+        /// Subtype specific data following the subtype & flags.
+        data: ArrayVec<u8, 37>,
+    },
+    /// "Experimental" option (kind 253 or 254, RFC 6994).
+    Experimental {
+        /// Kind of the option ([`crate::tcp_option::KIND_EXPERIMENTAL_1`]
+        /// or [`crate::tcp_option::KIND_EXPERIMENTAL_2`]).
+        kind: u8,
+        /// 16 bit experiment identifier ("ExID").
+        experiment_id: u16,
+        /// Experiment specific data following the experiment identifier.
+        data: ArrayVec<u8, 36>,
+    },
 }
 
 #[cfg(test)]
 mod test {
     use crate::*;
     use alloc::format;
+    use arrayvec::ArrayVec;
 
     #[test]
     fn clone_eq() {
@@ -43,6 +82,21 @@ mod test {
             SelectiveAcknowledgementPermitted,
             SelectiveAcknowledgement((1, 2), [Some((3, 4)), Some((5, 6)), None]),
             Timestamp(123, 456),
+            TcpAuthentication {
+                key_id: 1,
+                rnext_key_id: 2,
+                mac: [3; 36].into(),
+            },
+            Mptcp {
+                subtype: 1,
+                flags: 2,
+                data: [3; 37].into(),
+            },
+            Experimental {
+                kind: 253,
+                experiment_id: 1,
+                data: [2; 36].into(),
+            },
         ];
         for value in values {
             assert_eq!(value.clone(), value);
@@ -70,5 +124,16 @@ mod test {
             )
         );
         assert_eq!("Timestamp(123, 456)", format!("{:?}", Timestamp(123, 456)));
+        assert_eq!(
+            "Mptcp { subtype: 1, flags: 2, data: [3] }",
+            format!(
+                "{:?}",
+                Mptcp {
+                    subtype: 1,
+                    flags: 2,
+                    data: ArrayVec::try_from(&[3][..]).unwrap(),
+                }
+            )
+        );
     }
 }
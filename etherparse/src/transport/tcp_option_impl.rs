@@ -57,6 +57,14 @@ pub mod tcp_option {
     pub const KIND_SELECTIVE_ACK: u8 = 5;
     /// `u8` identifying a "timestamp and echo of previous timestamp" tcp option.
     pub const KIND_TIMESTAMP: u8 = 8;
+    /// `u8` identifying a "TCP authentication option" (TCP-AO, RFC 5925).
+    pub const KIND_TCP_AO: u8 = 29;
+    /// `u8` identifying a "multipath TCP" option (MPTCP, RFC 8684).
+    pub const KIND_MPTCP: u8 = 30;
+    /// `u8` identifying the first "experimental" tcp option (RFC 6994).
+    pub const KIND_EXPERIMENTAL_1: u8 = 253;
+    /// `u8` identifying the second "experimental" tcp option (RFC 6994).
+    pub const KIND_EXPERIMENTAL_2: u8 = 254;
     /// Length in octets/bytes of the "end" tcp option (includes kind value).
     pub const LEN_END: u8 = 1;
     /// Length in octets/bytes of the "no operation" tcp option (includes kind value).
@@ -69,4 +77,10 @@ pub mod tcp_option {
     pub const LEN_SELECTIVE_ACK_PERMITTED: u8 = 2;
     /// Length in octets/bytes of the "timestamp and echo of previous timestamp" tcp option (includes kind value).
     pub const LEN_TIMESTAMP: u8 = 10;
+    /// Minimum length in octets/bytes of the "TCP authentication option" (includes kind value).
+    pub const LEN_TCP_AO_MIN: u8 = 4;
+    /// Minimum length in octets/bytes of the "multipath TCP" option (includes kind value).
+    pub const LEN_MPTCP_MIN: u8 = 3;
+    /// Minimum length in octets/bytes of an "experimental" tcp option (includes kind value).
+    pub const LEN_EXPERIMENTAL_MIN: u8 = 4;
 }
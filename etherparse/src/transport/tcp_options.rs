@@ -1,4 +1,7 @@
-use crate::{tcp_option, TcpHeader, TcpOptionElement, TcpOptionWriteError, TcpOptionsIterator};
+use crate::{
+    get_unchecked_slice, get_unchecked_slice_mut, tcp_option, TcpHeader, TcpOptionElement,
+    TcpOptionWriteError, TcpOptionsIterator,
+};
 
 /// Options present in a TCP header.
 ///
@@ -263,6 +266,9 @@ impl TcpOptions {
                     Some(_) => acc2 + 8,
                 }),
                 Timestamp(_, _) => 10,
+                TcpAuthentication { mac, .. } => 4 + mac.len(),
+                Mptcp { data, .. } => 3 + data.len(),
+                Experimental { data, .. } => 4 + data.len(),
             }
         });
 
@@ -358,6 +364,53 @@ impl TcpOptions {
 
                         len += 10;
                     }
+                    TcpAuthentication {
+                        key_id,
+                        rnext_key_id,
+                        mac,
+                    } => {
+                        let option_len = 4 + mac.len();
+                        let t = &mut buf[len..len + option_len];
+
+                        t[0] = KIND_TCP_AO;
+                        t[1] = option_len as u8;
+                        t[2] = *key_id;
+                        t[3] = *rnext_key_id;
+                        t[4..].copy_from_slice(mac);
+
+                        len += option_len;
+                    }
+                    Mptcp {
+                        subtype,
+                        flags,
+                        data,
+                    } => {
+                        let option_len = 3 + data.len();
+                        let t = &mut buf[len..len + option_len];
+
+                        t[0] = KIND_MPTCP;
+                        t[1] = option_len as u8;
+                        // only the lower 4 bits of subtype & flags are written
+                        t[2] = (subtype << 4) | (flags & 0b1111);
+                        t[3..].copy_from_slice(data);
+
+                        len += option_len;
+                    }
+                    Experimental {
+                        kind,
+                        experiment_id,
+                        data,
+                    } => {
+                        let option_len = 4 + data.len();
+                        let t = &mut buf[len..len + option_len];
+
+                        t[0] = *kind;
+                        t[1] = option_len as u8;
+                        t[2..4].copy_from_slice(&experiment_id.to_be_bytes());
+                        t[4..].copy_from_slice(data);
+
+                        len += option_len;
+                    }
                 }
             }
             // set the new data offset
@@ -425,7 +478,7 @@ impl TcpOptions {
     pub fn as_slice(&self) -> &[u8] {
         debug_assert!(self.len <= 40);
         // SAFETY: Safe as all constructing methods verify len to be less then 40.
-        unsafe { core::slice::from_raw_parts(self.buf.as_ptr(), self.len()) }
+        unchecked! { get_unchecked_slice(&self.buf, 0, self.len()) }
     }
 
     /// Mutable slice containing the options.
@@ -433,7 +486,7 @@ impl TcpOptions {
     pub fn as_mut_slice(&mut self) -> &mut [u8] {
         debug_assert!(self.len <= 40);
         // SAFETY: Safe as all constructing methods verify len to be less then 40.
-        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr(), self.len()) }
+        unchecked! { get_unchecked_slice_mut(&mut self.buf, 0, self.len.into()) }
     }
 
     /// Returns an iterator that allows to iterate through the
@@ -568,9 +621,9 @@ macro_rules! from_static_array {
                     len: $x,
                     buf: [0; 40],
                 };
-                let r = result.buf.as_mut_ptr() as *mut [u8; $x];
-                unsafe {
-                    *r = values;
+                // SAFETY: Safe as $x <= 40 and the result buffer size is 40.
+                unchecked! {
+                    get_unchecked_slice_mut(&mut result.buf, 0, $x).copy_from_slice(&values);
                 }
                 result
             }
@@ -635,6 +688,73 @@ mod test {
         );
     }
 
+    #[test]
+    fn try_from_elements_mptcp_tcp_ao_experimental() {
+        use crate::tcp_option::*;
+        use crate::TcpOptionElement::*;
+        use arrayvec::ArrayVec;
+
+        let elements = [
+            TcpAuthentication {
+                key_id: 1,
+                rnext_key_id: 2,
+                mac: ArrayVec::try_from(&[3, 4, 5, 6][..]).unwrap(),
+            },
+            Mptcp {
+                subtype: 2,
+                flags: 0b0101,
+                data: ArrayVec::try_from(&[7, 8][..]).unwrap(),
+            },
+            Experimental {
+                kind: KIND_EXPERIMENTAL_2,
+                experiment_id: 0x1234,
+                data: ArrayVec::try_from(&[9][..]).unwrap(),
+            },
+        ];
+        let actual = TcpOptions::try_from_elements(&elements[..]).unwrap();
+        assert_eq!(
+            actual.as_slice(),
+            &[
+                KIND_TCP_AO,
+                8,
+                1,
+                2,
+                3,
+                4,
+                5,
+                6, //
+                KIND_MPTCP,
+                5,
+                0x25,
+                7,
+                8, //
+                KIND_EXPERIMENTAL_2,
+                5,
+                0x12,
+                0x34,
+                9, //
+                KIND_END,
+                KIND_END
+            ]
+        );
+        let read: std::vec::Vec<_> = actual.elements_iter().map(|e| e.unwrap()).collect();
+        assert_eq!(&elements[..], &read[..]);
+
+        // not enough space
+        let too_big = [
+            Mptcp {
+                subtype: 0,
+                flags: 0,
+                data: ArrayVec::try_from(&[0; 37][..]).unwrap(),
+            },
+            Noop,
+        ];
+        assert_eq!(
+            Err(TcpOptionWriteError::NotEnoughSpace(41)),
+            TcpOptions::try_from_elements(&too_big[..])
+        );
+    }
+
     proptest! {
         #[test]
         fn data_offset(
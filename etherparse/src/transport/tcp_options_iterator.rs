@@ -1,4 +1,5 @@
 use crate::*;
+use arrayvec::ArrayVec;
 
 /// Allows iterating over the options after a TCP header.
 #[derive(Clone, Eq, PartialEq)]
@@ -44,6 +45,30 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                 }
             };
 
+        let expect_min_size = |min_size: u8, slice: &[u8]| -> Result<usize, TcpOptionReadError> {
+            let id = slice[0];
+            if slice.len() < 2 {
+                Err(UnexpectedEndOfSlice {
+                    option_id: id,
+                    expected_len: 2,
+                    actual_len: slice.len(),
+                })
+            } else if slice[1] < min_size {
+                Err(UnexpectedSize {
+                    option_id: id,
+                    size: slice[1],
+                })
+            } else if slice.len() < usize::from(slice[1]) {
+                Err(UnexpectedEndOfSlice {
+                    option_id: id,
+                    expected_len: slice[1],
+                    actual_len: slice.len(),
+                })
+            } else {
+                Ok(usize::from(slice[1]))
+            }
+        };
+
         if self.options.is_empty() {
             None
         } else {
@@ -63,8 +88,7 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                             // SAFETY:
                             // Safe as the slice size is checked beforehand to be at
                             // least of size LEN_MAXIMUM_SEGMENT_SIZE (4).
-                            let value =
-                                unsafe { get_unchecked_be_u16(self.options.as_ptr().add(2)) };
+                            let value = unchecked! { get_unchecked_be_u16(self.options, 2) };
                             self.options = &self.options[4..];
                             Some(Ok(MaximumSegmentSize(value)))
                         }
@@ -115,10 +139,10 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                             // This is safe as above the len is checked
                             // to be at least 10 and the slice len is
                             // checked to be at least len bytes.
-                            let first = unsafe {
+                            let first = unchecked! {
                                 (
-                                    get_unchecked_be_u32(self.options.as_ptr().add(2)),
-                                    get_unchecked_be_u32(self.options.as_ptr().add(6)),
+                                    get_unchecked_be_u32(self.options, 2),
+                                    get_unchecked_be_u32(self.options, 6),
                                 )
                             };
                             for (i, item) in acks.iter_mut().enumerate().take(3) {
@@ -127,13 +151,11 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                                 // len can only be 10, 18, 26 or 34
                                 // therefore if the offset is smaller then the
                                 // len, then at least 8 bytes can be read.
-                                unsafe {
+                                unchecked! {
                                     if offset < (len as usize) {
                                         *item = Some((
-                                            get_unchecked_be_u32(self.options.as_ptr().add(offset)),
-                                            get_unchecked_be_u32(
-                                                self.options.as_ptr().add(offset + 4),
-                                            ),
+                                            get_unchecked_be_u32(self.options, offset),
+                                            get_unchecked_be_u32(self.options, offset + 4),
                                         ));
                                     }
                                 }
@@ -148,13 +170,13 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                     match expect_specific_size(LEN_TIMESTAMP, self.options) {
                         Err(value) => Some(Err(value)),
 
-                        _ => unsafe {
+                        _ => unchecked! {
                             let t = Timestamp(
                                 // SAFETY:
                                 // Safe as the len first gets checked to be equal
                                 // LEN_TIMESTAMP (10).
-                                get_unchecked_be_u32(self.options.as_ptr().add(2)),
-                                get_unchecked_be_u32(self.options.as_ptr().add(6)),
+                                get_unchecked_be_u32(self.options, 2),
+                                get_unchecked_be_u32(self.options, 6),
                             );
                             self.options = &self.options[10..];
                             Some(Ok(t))
@@ -162,6 +184,71 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                     }
                 }
 
+                KIND_TCP_AO => match expect_min_size(LEN_TCP_AO_MIN, self.options) {
+                    Err(value) => Some(Err(value)),
+                    Ok(len) => {
+                        let option = &self.options[..len];
+                        let mut mac = ArrayVec::new();
+                        // the length field can at most point to the end of
+                        // the options (max 40 bytes) in valid tcp headers
+                        if mac.try_extend_from_slice(&option[4..]).is_err() {
+                            Some(Err(UnexpectedSize {
+                                option_id: option[0],
+                                size: option[1],
+                            }))
+                        } else {
+                            self.options = &self.options[len..];
+                            Some(Ok(TcpAuthentication {
+                                key_id: option[2],
+                                rnext_key_id: option[3],
+                                mac,
+                            }))
+                        }
+                    }
+                },
+                KIND_MPTCP => match expect_min_size(LEN_MPTCP_MIN, self.options) {
+                    Err(value) => Some(Err(value)),
+                    Ok(len) => {
+                        let option = &self.options[..len];
+                        let mut data = ArrayVec::new();
+                        if data.try_extend_from_slice(&option[3..]).is_err() {
+                            Some(Err(UnexpectedSize {
+                                option_id: option[0],
+                                size: option[1],
+                            }))
+                        } else {
+                            self.options = &self.options[len..];
+                            Some(Ok(Mptcp {
+                                subtype: option[2] >> 4,
+                                flags: option[2] & 0b1111,
+                                data,
+                            }))
+                        }
+                    }
+                },
+                KIND_EXPERIMENTAL_1 | KIND_EXPERIMENTAL_2 => {
+                    match expect_min_size(LEN_EXPERIMENTAL_MIN, self.options) {
+                        Err(value) => Some(Err(value)),
+                        Ok(len) => {
+                            let option = &self.options[..len];
+                            let mut data = ArrayVec::new();
+                            if data.try_extend_from_slice(&option[4..]).is_err() {
+                                Some(Err(UnexpectedSize {
+                                    option_id: option[0],
+                                    size: option[1],
+                                }))
+                            } else {
+                                self.options = &self.options[len..];
+                                Some(Ok(Experimental {
+                                    kind: option[0],
+                                    experiment_id: u16::from_be_bytes([option[2], option[3]]),
+                                    data,
+                                }))
+                            }
+                        }
+                    }
+                }
+
                 //unknown id
                 _ => Some(Err(UnknownId(self.options[0]))),
             };
@@ -205,6 +292,7 @@ impl<'a> core::fmt::Debug for TcpOptionsIterator<'a> {
 mod test {
     use crate::{tcp_option::*, *};
     use alloc::format;
+    use arrayvec::ArrayVec;
 
     #[test]
     fn debug() {
@@ -248,6 +336,122 @@ mod test {
         assert_eq!(it.rest(), &buffer[..]);
     }
 
+    #[test]
+    fn next_mptcp_tcp_ao_experimental() {
+        use crate::TcpOptionElement::*;
+
+        // ok
+        {
+            let data = [
+                KIND_TCP_AO,
+                6,
+                1,
+                2,
+                3,
+                4,
+                KIND_MPTCP,
+                3,
+                0x21,
+                KIND_EXPERIMENTAL_1,
+                4,
+                0x12,
+                0x34,
+                KIND_END,
+            ];
+            let mut it = TcpOptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Ok(TcpAuthentication {
+                    key_id: 1,
+                    rnext_key_id: 2,
+                    mac: ArrayVec::try_from(&[3, 4][..]).unwrap(),
+                })),
+                it.next()
+            );
+            assert_eq!(
+                Some(Ok(Mptcp {
+                    subtype: 2,
+                    flags: 1,
+                    data: ArrayVec::new(),
+                })),
+                it.next()
+            );
+            assert_eq!(
+                Some(Ok(Experimental {
+                    kind: KIND_EXPERIMENTAL_1,
+                    experiment_id: 0x1234,
+                    data: ArrayVec::new(),
+                })),
+                it.next()
+            );
+            assert_eq!(None, it.next());
+            assert_eq!(0, it.rest().len());
+        }
+
+        for (kind, min_len) in [
+            (KIND_TCP_AO, LEN_TCP_AO_MIN),
+            (KIND_MPTCP, LEN_MPTCP_MIN),
+            (KIND_EXPERIMENTAL_1, LEN_EXPERIMENTAL_MIN),
+            (KIND_EXPERIMENTAL_2, LEN_EXPERIMENTAL_MIN),
+        ] {
+            // length field missing
+            {
+                let data = [kind];
+                let mut it = TcpOptionsIterator::from_slice(&data);
+                assert_eq!(
+                    Some(Err(TcpOptionReadError::UnexpectedEndOfSlice {
+                        option_id: kind,
+                        expected_len: 2,
+                        actual_len: 1,
+                    })),
+                    it.next()
+                );
+                assert_eq!(0, it.rest().len());
+            }
+            // length smaller then the minimum
+            {
+                let data = [kind, min_len - 1, 0, 0, 0];
+                let mut it = TcpOptionsIterator::from_slice(&data);
+                assert_eq!(
+                    Some(Err(TcpOptionReadError::UnexpectedSize {
+                        option_id: kind,
+                        size: min_len - 1,
+                    })),
+                    it.next()
+                );
+                assert_eq!(0, it.rest().len());
+            }
+            // length bigger then the slice
+            {
+                let data = [kind, 8, 0, 0, 0, 0, 0];
+                let mut it = TcpOptionsIterator::from_slice(&data);
+                assert_eq!(
+                    Some(Err(TcpOptionReadError::UnexpectedEndOfSlice {
+                        option_id: kind,
+                        expected_len: 8,
+                        actual_len: 7,
+                    })),
+                    it.next()
+                );
+                assert_eq!(0, it.rest().len());
+            }
+            // length bigger then the maximum tcp options length
+            {
+                let data = [kind; 41];
+                let mut data = data;
+                data[1] = 41;
+                let mut it = TcpOptionsIterator::from_slice(&data);
+                assert_eq!(
+                    Some(Err(TcpOptionReadError::UnexpectedSize {
+                        option_id: kind,
+                        size: 41,
+                    })),
+                    it.next()
+                );
+                assert_eq!(0, it.rest().len());
+            }
+        }
+    }
+
     #[test]
     #[rustfmt::skip]
     fn next() {
@@ -0,0 +1,148 @@
+/// Classification of a TCP segment based on its flags & payload
+/// (e.g. returned by [`crate::TcpHeader::segment_kind`] or
+/// [`crate::TcpSlice::segment_kind`]).
+///
+/// The classification only looks at a single segment. Identifying the
+/// final ACK of a three way handshake or the ACK of a FIN requires the
+/// state of the connection and is therefore left to connection trackers.
+///
+/// ```
+/// use etherparse::{TcpHeader, TcpSegmentKind};
+///
+/// let mut header = TcpHeader::new(1234, 80, 0, 1024);
+/// header.syn = true;
+/// assert_eq!(TcpSegmentKind::Syn, header.segment_kind(0));
+/// assert!(header.segment_kind(0).is_handshake());
+///
+/// header.syn = false;
+/// header.ack = true;
+/// assert_eq!(TcpSegmentKind::Ack, header.segment_kind(0));
+/// assert_eq!(TcpSegmentKind::Data, header.segment_kind(100));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum TcpSegmentKind {
+    /// Connection request (SYN set, ACK not set).
+    Syn,
+    /// Connection request acknowledgment (SYN & ACK set).
+    SynAck,
+    /// Connection close (FIN set, SYN & RST not set). Can also carry data.
+    Fin,
+    /// Connection reset (RST set).
+    Rst,
+    /// Acknowledgment without data (only ACK set, optionally with PSH,
+    /// URG, ECE, CWR or NS).
+    Ack,
+    /// Segment carrying data (SYN, FIN & RST not set).
+    Data,
+    /// Segment without SYN, FIN, RST & ACK and without data (e.g. a
+    /// "null" scan).
+    Other,
+}
+
+impl TcpSegmentKind {
+    /// Classifies a segment based on its flags & the length of its payload.
+    ///
+    /// RST takes precedence over all other flags, followed by SYN & FIN.
+    pub const fn from_flags(
+        syn: bool,
+        ack: bool,
+        fin: bool,
+        rst: bool,
+        payload_len: usize,
+    ) -> TcpSegmentKind {
+        use TcpSegmentKind::*;
+        if rst {
+            Rst
+        } else if syn {
+            if ack {
+                SynAck
+            } else {
+                Syn
+            }
+        } else if fin {
+            Fin
+        } else if payload_len > 0 {
+            Data
+        } else if ack {
+            Ack
+        } else {
+            Other
+        }
+    }
+
+    /// Returns true if the segment is part of the connection establishment
+    /// (SYN or SYN-ACK).
+    #[inline]
+    pub const fn is_handshake(&self) -> bool {
+        matches!(self, TcpSegmentKind::Syn | TcpSegmentKind::SynAck)
+    }
+
+    /// Returns true if the segment closes or aborts the connection
+    /// (FIN or RST).
+    #[inline]
+    pub const fn is_teardown(&self) -> bool {
+        matches!(self, TcpSegmentKind::Fin | TcpSegmentKind::Rst)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TcpSegmentKind::*;
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn from_flags() {
+        for payload_len in [0, 1] {
+            for flags in 0u8..16 {
+                let syn = 0 != flags & 1;
+                let ack = 0 != flags & 2;
+                let fin = 0 != flags & 4;
+                let rst = 0 != flags & 8;
+                let expected = if rst {
+                    Rst
+                } else if syn && ack {
+                    SynAck
+                } else if syn {
+                    Syn
+                } else if fin {
+                    Fin
+                } else if payload_len > 0 {
+                    Data
+                } else if ack {
+                    Ack
+                } else {
+                    Other
+                };
+                assert_eq!(
+                    expected,
+                    TcpSegmentKind::from_flags(syn, ack, fin, rst, payload_len)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_handshake_teardown() {
+        let tests = [
+            (Syn, true, false),
+            (SynAck, true, false),
+            (Fin, false, true),
+            (Rst, false, true),
+            (Ack, false, false),
+            (Data, false, false),
+            (Other, false, false),
+        ];
+        for (kind, handshake, teardown) in tests {
+            assert_eq!(handshake, kind.is_handshake());
+            assert_eq!(teardown, kind.is_teardown());
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        assert_eq!("SynAck", format!("{:?}", SynAck));
+        assert_eq!(Syn, Syn.clone());
+        assert!(Syn < SynAck);
+    }
+}
@@ -0,0 +1,138 @@
+/// Range of TCP sequence numbers (e.g. the sequence numbers occupied
+/// by a TCP segment, see [`crate::TcpSlice::seq_range`]).
+///
+/// Sequence numbers are compared modulo 2^32 as described in RFC 793
+/// (section 3.3), so ranges can wrap around at `u32::MAX`:
+///
+/// ```
+/// use etherparse::TcpSeqRange;
+///
+/// let range = TcpSeqRange::new(u32::MAX - 1, 4);
+/// assert_eq!(2, range.end());
+/// assert!(range.contains(u32::MAX));
+/// assert!(range.contains(1));
+/// assert!(false == range.contains(2));
+///
+/// assert!(range.overlaps(&TcpSeqRange::new(1, 10)));
+/// assert!(false == range.overlaps(&TcpSeqRange::new(2, 10)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TcpSeqRange {
+    start: u32,
+    len: u32,
+}
+
+impl TcpSeqRange {
+    /// Creates a range starting at `start` containing `len` sequence numbers.
+    #[inline]
+    pub const fn new(start: u32, len: u32) -> TcpSeqRange {
+        TcpSeqRange { start, len }
+    }
+
+    /// First sequence number in the range.
+    #[inline]
+    pub const fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Number of sequence numbers in the range.
+    #[inline]
+    pub const fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns true if the range contains no sequence numbers.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        0 == self.len
+    }
+
+    /// Sequence number after the last sequence number of the range
+    /// (exclusive end, wraps around at `u32::MAX`).
+    #[inline]
+    pub const fn end(&self) -> u32 {
+        self.start.wrapping_add(self.len)
+    }
+
+    /// Returns true if the given sequence number is part of the range.
+    #[inline]
+    pub const fn contains(&self, seq: u32) -> bool {
+        seq.wrapping_sub(self.start) < self.len
+    }
+
+    /// Returns true if the two ranges have at least one sequence
+    /// number in common (empty ranges never overlap).
+    #[inline]
+    pub const fn overlaps(&self, other: &TcpSeqRange) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && (self.contains(other.start) || other.contains(self.start))
+    }
+
+    /// Returns true if sequence number `a` is "less than" `b` with
+    /// modulo 2^32 arithmetic (RFC 793 & RFC 1982 serial number
+    /// comparison).
+    #[inline]
+    pub const fn seq_lt(a: u32, b: u32) -> bool {
+        (a.wrapping_sub(b) as i32) < 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn new_accessors() {
+        let range = TcpSeqRange::new(10, 5);
+        assert_eq!(10, range.start());
+        assert_eq!(5, range.len());
+        assert_eq!(15, range.end());
+        assert!(false == range.is_empty());
+        assert!(TcpSeqRange::new(10, 0).is_empty());
+        assert_eq!(TcpSeqRange::new(0, 0), TcpSeqRange::default());
+    }
+
+    proptest! {
+        #[test]
+        fn contains(start in any::<u32>(), len in 0..1000u32, offset in 0..2000u32) {
+            let range = TcpSeqRange::new(start, len);
+            assert_eq!(offset < len, range.contains(start.wrapping_add(offset)));
+            assert!(false == range.contains(start.wrapping_sub(1)));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn overlaps(
+            start in any::<u32>(),
+            len_a in 0..1000u32,
+            len_b in 0..1000u32,
+            offset in 0..2000u32
+        ) {
+            let a = TcpSeqRange::new(start, len_a);
+            let b = TcpSeqRange::new(start.wrapping_add(offset), len_b);
+            let expected = len_b > 0 && offset < len_a;
+            assert_eq!(expected, a.overlaps(&b));
+            assert_eq!(expected, b.overlaps(&a));
+        }
+    }
+
+    #[test]
+    fn seq_lt() {
+        assert!(TcpSeqRange::seq_lt(1, 2));
+        assert!(false == TcpSeqRange::seq_lt(2, 1));
+        assert!(false == TcpSeqRange::seq_lt(1, 1));
+        assert!(TcpSeqRange::seq_lt(u32::MAX, 0));
+        assert!(false == TcpSeqRange::seq_lt(0, u32::MAX));
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let range = TcpSeqRange::new(1, 2);
+        assert_eq!(range, range.clone());
+        assert_eq!("TcpSeqRange { start: 1, len: 2 }", format!("{:?}", range));
+    }
+}
@@ -30,7 +30,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Safe as it is checked at the start of the function that the
         // length of the slice is at least TcpHeader::MIN_LEN (20).
-        let header_len = unsafe {
+        let header_len = unchecked! {
             // The length of the TCP header can be determined via
             // the data offset field of the TCP header. "data offset"
             // stores the offset in 4 byte steps from the start of the
@@ -60,7 +60,7 @@ impl<'a> TcpSlice<'a> {
             // ```
             // let len = (*slice.get_unchecked(12) & 0xf0) >> 2;
             // ```
-            usize::from((*slice.get_unchecked(12) & 0xf0) >> 2)
+            usize::from((get_unchecked_u8(slice, 12) & 0xf0) >> 2)
         };
 
         if header_len < TcpHeader::MIN_LEN {
@@ -91,39 +91,69 @@ impl<'a> TcpSlice<'a> {
     /// (including options).
     #[inline]
     pub fn header_slice(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as the slice was verified
             // to be at least header_len long.
-            core::slice::from_raw_parts(self.slice.as_ptr(), self.header_len)
+            get_unchecked_slice(self.slice, 0, self.header_len)
         }
     }
 
     /// Returns the slice containing the TCP payload.
     #[inline]
     pub fn payload(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as the slice was verified
             // to be at least header_len long.
-            core::slice::from_raw_parts(
-                self.slice.as_ptr().add(self.header_len),
-                self.slice.len() - self.header_len,
-            )
+            get_unchecked_slice(self.slice, self.header_len, self.slice.len() - self.header_len)
         }
     }
 
+    /// Length of the TCP payload in bytes.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        self.slice.len() - self.header_len
+    }
+
     /// Length of the TCP header (including TCP options).
     #[inline]
     pub const fn header_len(&self) -> usize {
         self.header_len
     }
 
+    /// Returns the range of sequence numbers occupied by the segment
+    /// ("SEG.SEQ" to "SEG.SEQ + SEG.LEN" as defined in RFC 793, the
+    /// SYN & FIN flags each occupy one sequence number).
+    ///
+    /// Payload lengths bigger than `u32::MAX` are truncated.
+    #[inline]
+    pub fn seq_range(&self) -> TcpSeqRange {
+        TcpSeqRange::new(
+            self.sequence_number(),
+            (self.payload_len() as u32)
+                .wrapping_add(u32::from(self.syn()))
+                .wrapping_add(u32::from(self.fin())),
+        )
+    }
+
+    /// Returns the range of sequence numbers of the payload bytes
+    /// (starts after the sequence number occupied by a SYN flag).
+    ///
+    /// Payload lengths bigger than `u32::MAX` are truncated.
+    #[inline]
+    pub fn payload_seq_range(&self) -> TcpSeqRange {
+        TcpSeqRange::new(
+            self.sequence_number().wrapping_add(u32::from(self.syn())),
+            self.payload_len() as u32,
+        )
+    }
+
     /// Read the destination port number in the TCP header.
     #[inline]
     pub fn source_port(&self) -> u16 {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr()) }
+        unchecked! { get_unchecked_be_u16(self.slice, 0) }
     }
 
     /// Read the destination port number in the TCP header.
@@ -132,7 +162,16 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 2) }
+    }
+
+    /// Returns true if the source or destination port is the DNS port
+    /// ([`services::DNS`] 53).
+    ///
+    /// Note that ports are only a hint and the payload is not checked.
+    #[inline]
+    pub fn is_probably_dns(&self) -> bool {
+        services::DNS == self.source_port() || services::DNS == self.destination_port()
     }
 
     /// Read the sequence number in the TCP header.
@@ -145,7 +184,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 4) }
     }
 
     /// Reads the acknowledgment number in the TCP header.
@@ -160,7 +199,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(8)) }
+        unchecked! { get_unchecked_be_u32(self.slice, 8) }
     }
 
     /// Read the number of 32 bit words in the TCP Header.
@@ -173,7 +212,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { (*self.slice.get_unchecked(12) & 0b1111_0000) >> 4 }
+        unchecked! { (get_unchecked_u8(self.slice, 12) & 0b1111_0000) >> 4 }
     }
 
     /// ECN-nonce - concealment protection (experimental: see RFC 3540)
@@ -182,7 +221,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(12) & 0b0000_0001) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 12) & 0b0000_0001) }
     }
 
     /// Read the fin flag (no more data from sender).
@@ -191,7 +230,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_0001) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_0001) }
     }
 
     /// Reads the syn flag (synchronize sequence numbers).
@@ -200,7 +239,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_0010) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_0010) }
     }
 
     /// Reads the rst flag (reset the connection).
@@ -209,7 +248,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_0100) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_0100) }
     }
 
     /// Reads the psh flag (push function).
@@ -218,7 +257,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0000_1000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0000_1000) }
     }
 
     /// Reads the ack flag (acknowledgment field significant).
@@ -227,7 +266,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0001_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0001_0000) }
     }
 
     /// Reads the urg flag (Urgent Pointer field significant).
@@ -236,7 +275,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0010_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0010_0000) }
     }
 
     /// Read the ECN-Echo flag (RFC 3168).
@@ -245,7 +284,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b0100_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b0100_0000) }
     }
 
     /// Reads the cwr flag (Congestion Window Reduced).
@@ -258,7 +297,7 @@ impl<'a> TcpSlice<'a> {
         // SAFETY:
         // Constructor checks that the slice has at least the length
         // of 20.
-        unsafe { 0 != (*self.slice.get_unchecked(13) & 0b1000_0000) }
+        unchecked! { 0 != (get_unchecked_u8(self.slice, 13) & 0b1000_0000) }
     }
 
     /// The number of data octets beginning with the one indicated in the
@@ -270,7 +309,7 @@ impl<'a> TcpSlice<'a> {
             // SAFETY:
             // Constructor checks that the slice has at least the length
             // of 20.
-            unsafe { [*self.slice.get_unchecked(14), *self.slice.get_unchecked(15)] },
+            unchecked! { [get_unchecked_u8(self.slice, 14), get_unchecked_u8(self.slice, 15)] },
         )
     }
 
@@ -281,7 +320,7 @@ impl<'a> TcpSlice<'a> {
             // SAFETY:
             // Constructor checks that the slice has at least the length
             // of 20.
-            unsafe { [*self.slice.get_unchecked(16), *self.slice.get_unchecked(17)] },
+            unchecked! { [get_unchecked_u8(self.slice, 16), get_unchecked_u8(self.slice, 17)] },
         )
     }
 
@@ -297,7 +336,7 @@ impl<'a> TcpSlice<'a> {
             // SAFETY:
             // Constructor checks that the slice has at least the length
             // of 20.
-            unsafe { [*self.slice.get_unchecked(18), *self.slice.get_unchecked(19)] },
+            unchecked! { [get_unchecked_u8(self.slice, 18), get_unchecked_u8(self.slice, 19)] },
         )
     }
 
@@ -313,6 +352,19 @@ impl<'a> TcpSlice<'a> {
         TcpOptionsIterator::from_slice(self.options())
     }
 
+    /// Classifies the segment based on its flags & payload length
+    /// (see [`TcpSegmentKind`]).
+    #[inline]
+    pub fn segment_kind(&self) -> TcpSegmentKind {
+        TcpSegmentKind::from_flags(
+            self.syn(),
+            self.ack(),
+            self.fin(),
+            self.rst(),
+            self.payload().len(),
+        )
+    }
+
     /// Decode all the fields and copy the results to a
     /// [`crate::TcpHeader`]` struct.
     pub fn to_header(&self) -> TcpHeader {
@@ -418,6 +470,7 @@ impl<'a> core::fmt::Debug for TcpSlice<'a> {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use super::*;
     use crate::test_gens::*;
@@ -554,6 +607,17 @@ mod test {
         }
     }
 
+    #[test]
+    fn is_probably_dns() {
+        for (source_port, destination_port, expected) in
+            [(53, 1234, true), (1234, 53, true), (1234, 1235, false)]
+        {
+            let data = TcpHeader::new(source_port, destination_port, 0, 0).to_bytes();
+            let slice = TcpSlice::from_slice(&data).unwrap();
+            assert_eq!(expected, slice.is_probably_dns());
+        }
+    }
+
     #[test]
     fn calc_checksum_ipv4() {
         use TcpOptionElement::*;
@@ -718,4 +782,28 @@ mod test {
             );
         }
     }
+
+    proptest! {
+        #[test]
+        fn segment_kind(tcp in tcp_any(), payload_len in 0usize..2) {
+            let mut data = Vec::with_capacity(tcp.header_len() + payload_len);
+            data.extend_from_slice(&tcp.to_bytes());
+            data.resize(tcp.header_len() + payload_len, 0);
+            let slice = TcpSlice::from_slice(&data).unwrap();
+            assert_eq!(tcp.segment_kind(payload_len), slice.segment_kind());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payload_len_seq_range(tcp in tcp_any(), payload_len in 0usize..100) {
+            let mut data = Vec::with_capacity(tcp.header_len() + payload_len);
+            data.extend_from_slice(&tcp.to_bytes());
+            data.resize(tcp.header_len() + payload_len, 0);
+            let slice = TcpSlice::from_slice(&data).unwrap();
+            assert_eq!(payload_len, slice.payload_len());
+            assert_eq!(tcp.seq_range(payload_len), slice.seq_range());
+            assert_eq!(tcp.payload_seq_range(payload_len), slice.payload_seq_range());
+        }
+    }
 }
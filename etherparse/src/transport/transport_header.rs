@@ -13,6 +13,12 @@ pub enum TransportHeader {
 }
 
 impl TransportHeader {
+    /// Minimum serialized size of a transport header in bytes/octets.
+    pub const MIN_LEN: usize = UdpHeader::LEN;
+
+    /// Maximum serialized size of a transport header in bytes/octets.
+    pub const MAX_LEN: usize = TcpHeader::MAX_LEN;
+
     /// Returns Result::Some containing the udp header if self has the value Udp.
     /// Otherwise None is returned.
     pub fn udp(self) -> Option<UdpHeader> {
@@ -101,6 +107,17 @@ impl TransportHeader {
         }
     }
 
+    /// Returns the source & destination port (`None` for transport
+    /// protocols without ports like ICMP).
+    pub fn ports(&self) -> Option<(u16, u16)> {
+        use crate::TransportHeader::*;
+        match self {
+            Udp(value) => Some((value.source_port, value.destination_port)),
+            Tcp(value) => Some((value.source_port, value.destination_port)),
+            Icmpv4(_) | Icmpv6(_) => None,
+        }
+    }
+
     /// Returns the size of the transport header (in case of UDP fixed,
     /// in case of TCP cotanining the options).
     pub fn header_len(&self) -> usize {
@@ -178,6 +195,7 @@ impl TransportHeader {
 }
 
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{test_gens::*, *};
     use alloc::{format, vec::Vec};
@@ -234,6 +252,22 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn ports(
+            tcp in tcp_any(),
+            udp in udp_any(),
+            icmpv4 in icmpv4_header_any(),
+            icmpv6 in icmpv6_header_any(),
+        ) {
+            use TransportHeader::*;
+            assert_eq!(Some((udp.source_port, udp.destination_port)), Udp(udp).ports());
+            assert_eq!(Some((tcp.source_port, tcp.destination_port)), Tcp(tcp).ports());
+            assert_eq!(None, Icmpv4(icmpv4).ports());
+            assert_eq!(None, Icmpv6(icmpv6).ports());
+        }
+    }
+
     #[test]
     fn udp() {
         let udp: UdpHeader = Default::default();
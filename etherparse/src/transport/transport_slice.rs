@@ -16,6 +16,30 @@ pub enum TransportSlice<'a> {
     Tcp(TcpSlice<'a>),
 }
 
+impl TransportSlice<'_> {
+    /// Returns the source & destination port (`None` for transport
+    /// protocols without ports like ICMP).
+    pub fn ports(&self) -> Option<(u16, u16)> {
+        use TransportSlice::*;
+        match self {
+            Udp(s) => Some((s.source_port(), s.destination_port())),
+            Tcp(s) => Some((s.source_port(), s.destination_port())),
+            Icmpv4(_) | Icmpv6(_) => None,
+        }
+    }
+
+    /// Length of the transport header in bytes/octets.
+    pub fn header_len(&self) -> usize {
+        use TransportSlice::*;
+        match self {
+            Icmpv4(s) => s.header_len(),
+            Icmpv6(s) => s.header_len(),
+            Udp(_) => UdpHeader::LEN,
+            Tcp(s) => s.header_slice().len(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -54,4 +78,79 @@ mod test {
             assert_eq!(format!("{:?}", slice), format!("Tcp({:?})", t));
         }
     }
+
+    #[test]
+    fn ports() {
+        // udp
+        {
+            let raw = UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                ..Default::default()
+            }
+            .to_bytes();
+            let slice = TransportSlice::Udp(UdpSlice::from_slice(&raw).unwrap());
+            assert_eq!(Some((1, 2)), slice.ports());
+        }
+        // tcp
+        {
+            let raw = TcpHeader::new(3, 4, 5, 6).to_bytes();
+            let slice = TransportSlice::Tcp(TcpSlice::from_slice(&raw).unwrap());
+            assert_eq!(Some((3, 4)), slice.ports());
+        }
+        // icmpv4
+        {
+            let raw = Icmpv4Header::new(Icmpv4Type::Unknown {
+                type_u8: 0xff,
+                code_u8: 0,
+                bytes5to8: [0; 4],
+            })
+            .to_bytes();
+            let slice = TransportSlice::Icmpv4(Icmpv4Slice::from_slice(&raw).unwrap());
+            assert_eq!(None, slice.ports());
+        }
+        // icmpv6
+        {
+            let raw = Icmpv6Header::new(Icmpv6Type::Unknown {
+                type_u8: 0,
+                code_u8: 0,
+                bytes5to8: [0; 4],
+            })
+            .to_bytes();
+            let slice = TransportSlice::Icmpv6(Icmpv6Slice::from_slice(&raw).unwrap());
+            assert_eq!(None, slice.ports());
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        // udp
+        {
+            let raw = UdpHeader::default().to_bytes();
+            let slice = TransportSlice::Udp(UdpSlice::from_slice(&raw).unwrap());
+            assert_eq!(UdpHeader::LEN, slice.header_len());
+        }
+        // tcp with options
+        {
+            let mut header = TcpHeader::new(3, 4, 5, 6);
+            header.set_options_raw(&[1, 1, 1, 1]).unwrap();
+            let raw = header.to_bytes();
+            let slice = TransportSlice::Tcp(TcpSlice::from_slice(&raw).unwrap());
+            assert_eq!(TcpHeader::MIN_LEN + 4, slice.header_len());
+        }
+        // icmpv4
+        {
+            let raw = Icmpv4Header::new(Icmpv4Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                .to_bytes();
+            let slice = TransportSlice::Icmpv4(Icmpv4Slice::from_slice(&raw).unwrap());
+            assert_eq!(Icmpv4Header::MIN_LEN, slice.header_len());
+        }
+        // icmpv6
+        {
+            let raw = Icmpv6Header::new(Icmpv6Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                .to_bytes();
+            let slice = TransportSlice::Icmpv6(Icmpv6Slice::from_slice(&raw).unwrap());
+            assert_eq!(Icmpv6Header::MIN_LEN, slice.header_len());
+        }
+    }
 }
@@ -17,6 +17,14 @@ impl UdpHeader {
     /// Serialized size of an UDP header in bytes/octets.
     pub const LEN: usize = 8;
 
+    /// Minimum serialized size of the header in bytes/octets (same as
+    /// [`UdpHeader::LEN`] as the header has a fixed size).
+    pub const MIN_LEN: usize = UdpHeader::LEN;
+
+    /// Maximum serialized size of the header in bytes/octets (same as
+    /// [`UdpHeader::LEN`] as the header has a fixed size).
+    pub const MAX_LEN: usize = UdpHeader::LEN;
+
     /// Serialized size of an UDP header in bytes/octets in an [`u16`].
     pub const LEN_U16: u16 = 8;
 
@@ -71,7 +79,7 @@ impl UdpHeader {
             checksum: 0,
         };
         result.checksum =
-            result.calc_checksum_ipv4_internal(ip_header.source, ip_header.destination, payload);
+            result.calc_checksum_ipv4_internal(ip_header.source, ip_header.destination, &[payload]);
         Ok(result)
     }
 
@@ -90,18 +98,41 @@ impl UdpHeader {
         source: [u8; 4],
         destination: [u8; 4],
         payload: &[u8],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_ipv4_raw_slices(source, destination, &[payload])
+    }
+
+    /// Calculates the upd header checksum based on a ipv4 header and
+    /// a payload that is scattered over multiple slices (the slices
+    /// are treated as if they were one contiguous payload).
+    pub fn calc_checksum_ipv4_slices(
+        &self,
+        ip_header: &Ipv4Header,
+        payload_slices: &[&[u8]],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.calc_checksum_ipv4_raw_slices(ip_header.source, ip_header.destination, payload_slices)
+    }
+
+    /// Calculates the upd header checksum based on ipv4 addresses and
+    /// a payload that is scattered over multiple slices.
+    pub fn calc_checksum_ipv4_raw_slices(
+        &self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        payload_slices: &[&[u8]],
     ) -> Result<u16, ValueTooBigError<usize>> {
         // check that the total length fits into the field
         const MAX_PAYLOAD_LENGTH: usize = (u16::MAX as usize) - UdpHeader::LEN;
-        if MAX_PAYLOAD_LENGTH < payload.len() {
+        let payload_len = checksum::slices_len(payload_slices);
+        if MAX_PAYLOAD_LENGTH < payload_len {
             return Err(ValueTooBigError {
-                actual: payload.len(),
+                actual: payload_len,
                 max_allowed: MAX_PAYLOAD_LENGTH,
                 value_type: err::ValueType::UdpPayloadLengthIpv4,
             });
         }
 
-        Ok(self.calc_checksum_ipv4_internal(source, destination, payload))
+        Ok(self.calc_checksum_ipv4_internal(source, destination, payload_slices))
     }
 
     /// Calculates the upd header checksum based on a ipv4 header.
@@ -109,7 +140,7 @@ impl UdpHeader {
         &self,
         source: [u8; 4],
         destination: [u8; 4],
-        payload: &[u8],
+        payload_slices: &[&[u8]],
     ) -> u16 {
         self.calc_checksum_post_ip(
             //pseudo header
@@ -118,7 +149,7 @@ impl UdpHeader {
                 .add_4bytes(destination)
                 .add_2bytes([0, ip_number::UDP.0])
                 .add_2bytes(self.length.to_be_bytes()),
-            payload,
+            payload_slices,
         )
     }
 
@@ -146,7 +177,7 @@ impl UdpHeader {
             checksum: 0,
         };
         result.checksum =
-            result.calc_checksum_ipv6_internal(ip_header.source, ip_header.destination, payload);
+            result.calc_checksum_ipv6_internal(ip_header.source, ip_header.destination, &[payload]);
         Ok(result)
     }
 
@@ -165,25 +196,49 @@ impl UdpHeader {
         source: [u8; 16],
         destination: [u8; 16],
         payload: &[u8],
+    ) -> Result<u16, err::ValueTooBigError<usize>> {
+        self.calc_checksum_ipv6_raw_slices(source, destination, &[payload])
+    }
+
+    /// Calculates the checksum of the current udp header given an ipv6
+    /// header and a payload that is scattered over multiple slices (the
+    /// slices are treated as if they were one contiguous payload).
+    pub fn calc_checksum_ipv6_slices(
+        &self,
+        ip_header: &Ipv6Header,
+        payload_slices: &[&[u8]],
+    ) -> Result<u16, err::ValueTooBigError<usize>> {
+        self.calc_checksum_ipv6_raw_slices(ip_header.source, ip_header.destination, payload_slices)
+    }
+
+    /// Calculates the checksum of the current udp header given an ipv6
+    /// source & destination address plus a payload that is scattered
+    /// over multiple slices.
+    pub fn calc_checksum_ipv6_raw_slices(
+        &self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        payload_slices: &[&[u8]],
     ) -> Result<u16, err::ValueTooBigError<usize>> {
         //check that the total length fits into the field
         const MAX_PAYLOAD_LENGTH: usize = (u32::MAX as usize) - UdpHeader::LEN;
-        if MAX_PAYLOAD_LENGTH < payload.len() {
+        let payload_len = checksum::slices_len(payload_slices);
+        if MAX_PAYLOAD_LENGTH < payload_len {
             return Err(err::ValueTooBigError {
-                actual: payload.len(),
+                actual: payload_len,
                 max_allowed: MAX_PAYLOAD_LENGTH,
                 value_type: err::ValueType::UdpPayloadLengthIpv6,
             });
         }
 
-        Ok(self.calc_checksum_ipv6_internal(source, destination, payload))
+        Ok(self.calc_checksum_ipv6_internal(source, destination, payload_slices))
     }
 
     fn calc_checksum_ipv6_internal(
         &self,
         source: [u8; 16],
         destination: [u8; 16],
-        payload: &[u8],
+        payload_slices: &[&[u8]],
     ) -> u16 {
         self.calc_checksum_post_ip(
             //pseudo header
@@ -192,7 +247,7 @@ impl UdpHeader {
                 .add_16bytes(destination)
                 .add_2bytes([0, ip_number::UDP.0])
                 .add_2bytes(self.length.to_be_bytes()),
-            payload,
+            payload_slices,
         )
     }
 
@@ -200,13 +255,13 @@ impl UdpHeader {
     fn calc_checksum_post_ip(
         &self,
         ip_pseudo_header_sum: checksum::Sum16BitWords,
-        payload: &[u8],
+        payload_slices: &[&[u8]],
     ) -> u16 {
         ip_pseudo_header_sum
             .add_2bytes(self.source_port.to_be_bytes())
             .add_2bytes(self.destination_port.to_be_bytes())
             .add_2bytes(self.length.to_be_bytes())
-            .add_slice(payload)
+            .add_slices(payload_slices)
             .to_ones_complement_with_no_zero()
             .to_be()
     }
@@ -252,6 +307,18 @@ impl UdpHeader {
         Ok(UdpHeader::from_bytes(bytes))
     }
 
+    /// Tries to read an udp header from the current position of the async
+    /// reader (requires crate feature `tokio`).
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub async fn async_read<T: tokio::io::AsyncRead + Unpin>(
+        reader: &mut T,
+    ) -> Result<UdpHeader, std::io::Error> {
+        let mut bytes = [0; UdpHeader::LEN];
+        async_read_exact(reader, &mut bytes).await?;
+        Ok(UdpHeader::from_bytes(bytes))
+    }
+
     /// Write the udp header without recalculating the checksum or length.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -299,7 +366,20 @@ impl UdpHeader {
     }
 }
 
+/// Writes the header in the format `source port -> destination port len=<length>`,
+/// e.g. `1234 -> 53 len=40`.
+impl core::fmt::Display for UdpHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} -> {} len={}",
+            self.source_port, self.destination_port, self.length
+        )
+    }
+}
+
 #[cfg(test)]
+#[cfg_attr(feature = "safe-only", allow(unsafe_code))]
 mod test {
     use crate::{
         err::{ValueTooBigError, ValueType},
@@ -590,6 +670,48 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn calc_checksum_slices(
+            udp in udp_any(),
+            ipv4 in ipv4_any(),
+            ipv6 in ipv6_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..64),
+            split in any::<proptest::sample::Index>()
+        ) {
+            let split = split.index(payload.len() + 1);
+            let slices: [&[u8]; 3] = [&payload[..split], &[], &payload[split..]];
+            assert_eq!(
+                udp.calc_checksum_ipv4(&ipv4, &payload),
+                udp.calc_checksum_ipv4_slices(&ipv4, &slices)
+            );
+            assert_eq!(
+                udp.calc_checksum_ipv4_raw(ipv4.source, ipv4.destination, &payload),
+                udp.calc_checksum_ipv4_raw_slices(ipv4.source, ipv4.destination, &slices)
+            );
+            assert_eq!(
+                udp.calc_checksum_ipv6(&ipv6, &payload),
+                udp.calc_checksum_ipv6_slices(&ipv6, &slices)
+            );
+            assert_eq!(
+                udp.calc_checksum_ipv6_raw(ipv6.source, ipv6.destination, &payload),
+                udp.calc_checksum_ipv6_raw_slices(ipv6.source, ipv6.destination, &slices)
+            );
+
+            // length error (total length of all slices is checked)
+            let first = [0u8; UdpHeader::LEN];
+            let rest = alloc::vec![0u8; (u16::MAX as usize) - 2*UdpHeader::LEN + 1];
+            assert_eq!(
+                Err(ValueTooBigError {
+                    actual: (u16::MAX as usize) - UdpHeader::LEN + 1,
+                    max_allowed: (u16::MAX as usize) - UdpHeader::LEN,
+                    value_type: ValueType::UdpPayloadLengthIpv4,
+                }),
+                udp.calc_checksum_ipv4_slices(&ipv4, &[&first, &rest])
+            );
+        }
+    }
+
     /// Calculat the expected UDP header checksum for the tests.
     fn expected_udp_ipv6_checksum(
         source: [u8; 16],
@@ -981,6 +1103,40 @@ mod test {
         }
     }
 
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        fn async_read(
+            input in udp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+            let mut buffer = Vec::with_capacity(UdpHeader::LEN + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut reader = &buffer[..];
+                let result = rt.block_on(UdpHeader::async_read(&mut reader)).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(dummy_data.len(), reader.len());
+            }
+
+            // unexpected eof
+            for len in 0..UdpHeader::LEN {
+                let mut reader = &buffer[..len];
+                assert_eq!(
+                    rt.block_on(UdpHeader::async_read(&mut reader))
+                        .unwrap_err()
+                        .kind(),
+                    std::io::ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -1096,4 +1252,15 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn display() {
+        let header = UdpHeader {
+            source_port: 1234,
+            destination_port: 53,
+            length: 40,
+            checksum: 0,
+        };
+        assert_eq!("1234 -> 53 len=40", format!("{}", header));
+    }
 }
@@ -0,0 +1,169 @@
+use crate::*;
+
+/// `#[repr(C)]` memory layout of an UDP header with all fields
+/// stored in network byte order.
+///
+/// The struct has a size of 8 bytes & an alignment of 1 and can be
+/// mapped directly onto a packet buffer (e.g. a DMA region). The
+/// checksum & length logic of [`UdpHeader`] can be used by converting
+/// between the two types:
+///
+/// ```
+/// use etherparse::{UdpHeader, UdpHeaderRaw};
+///
+/// let mut buffer = [0x12, 0x34, 0x00, 0x35, 0x00, 0x08, 0x00, 0x00];
+///
+/// let raw = UdpHeaderRaw::mut_from_prefix(&mut buffer).unwrap();
+/// assert_eq!(0x1234, raw.source_port.get());
+/// raw.destination_port.set(5353);
+///
+/// let header = UdpHeader::from(*raw);
+/// assert_eq!(5353, header.destination_port);
+/// assert_eq!([0x12, 0x34, 0x14, 0xe9], buffer[..4]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub struct UdpHeaderRaw {
+    /// Source port of the packet (optional).
+    pub source_port: U16Be,
+    /// Destination port of the packet.
+    pub destination_port: U16Be,
+    /// Length of the packet (includes the udp header length of 8 bytes).
+    pub length: U16Be,
+    /// Checksum of the packet (0 if not used with IPv4).
+    pub checksum: U16Be,
+}
+
+impl UdpHeaderRaw {
+    /// Size of the raw header in bytes.
+    pub const LEN: usize = UdpHeader::LEN;
+
+    /// Creates a raw header from its serialized bytes.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 8]) -> UdpHeaderRaw {
+        UdpHeaderRaw {
+            source_port: U16Be([bytes[0], bytes[1]]),
+            destination_port: U16Be([bytes[2], bytes[3]]),
+            length: U16Be([bytes[4], bytes[5]]),
+            checksum: U16Be([bytes[6], bytes[7]]),
+        }
+    }
+
+    /// Returns the serialized bytes of the raw header.
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        [
+            self.source_port.0[0],
+            self.source_port.0[1],
+            self.destination_port.0[0],
+            self.destination_port.0[1],
+            self.length.0[0],
+            self.length.0[1],
+            self.checksum.0[0],
+            self.checksum.0[1],
+        ]
+    }
+
+    /// Returns a reference to the raw header at the start of the
+    /// slice or `None` if the slice is shorter than 8 bytes.
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+    #[inline]
+    pub fn ref_from_prefix(slice: &[u8]) -> Option<&UdpHeaderRaw> {
+        if slice.len() < UdpHeaderRaw::LEN {
+            None
+        } else {
+            // SAFETY:
+            // Safe as the slice length is checked to be at least
+            // UdpHeaderRaw::LEN (8) above and UdpHeaderRaw is repr(C)
+            // with an alignment of 1 & only consists of byte arrays
+            // (all bit patterns are valid).
+            Some(unsafe { &*(slice.as_ptr() as *const UdpHeaderRaw) })
+        }
+    }
+
+    /// Returns a mutable reference to the raw header at the start of
+    /// the slice or `None` if the slice is shorter than 8 bytes.
+    #[cfg_attr(feature = "safe-only", allow(unsafe_code))]
+    #[inline]
+    pub fn mut_from_prefix(slice: &mut [u8]) -> Option<&mut UdpHeaderRaw> {
+        if slice.len() < UdpHeaderRaw::LEN {
+            None
+        } else {
+            // SAFETY:
+            // Safe as the slice length is checked to be at least
+            // UdpHeaderRaw::LEN (8) above and UdpHeaderRaw is repr(C)
+            // with an alignment of 1 & only consists of byte arrays
+            // (all bit patterns are valid).
+            Some(unsafe { &mut *(slice.as_mut_ptr() as *mut UdpHeaderRaw) })
+        }
+    }
+}
+
+impl From<UdpHeaderRaw> for UdpHeader {
+    #[inline]
+    fn from(value: UdpHeaderRaw) -> Self {
+        UdpHeader {
+            source_port: value.source_port.get(),
+            destination_port: value.destination_port.get(),
+            length: value.length.get(),
+            checksum: value.checksum.get(),
+        }
+    }
+}
+
+impl From<&UdpHeader> for UdpHeaderRaw {
+    #[inline]
+    fn from(value: &UdpHeader) -> Self {
+        UdpHeaderRaw {
+            source_port: U16Be::new(value.source_port),
+            destination_port: U16Be::new(value.destination_port),
+            length: U16Be::new(value.length),
+            checksum: U16Be::new(value.checksum),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(UdpHeaderRaw::LEN, core::mem::size_of::<UdpHeaderRaw>());
+        assert_eq!(1, core::mem::align_of::<UdpHeaderRaw>());
+    }
+
+    proptest! {
+        #[test]
+        fn conversions(header in udp_any()) {
+            let bytes = header.to_bytes();
+            let raw = UdpHeaderRaw::from(&header);
+            assert_eq!(bytes, raw.to_bytes());
+            assert_eq!(raw, UdpHeaderRaw::from_bytes(bytes));
+            assert_eq!(header, UdpHeader::from(raw));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_prefix(header in udp_any(), len in 0usize..12) {
+            let mut buffer = [0u8; 12];
+            buffer[..8].copy_from_slice(&header.to_bytes());
+
+            if len < UdpHeaderRaw::LEN {
+                assert!(UdpHeaderRaw::ref_from_prefix(&buffer[..len]).is_none());
+                assert!(UdpHeaderRaw::mut_from_prefix(&mut buffer[..len]).is_none());
+            } else {
+                assert_eq!(
+                    UdpHeaderRaw::from(&header),
+                    *UdpHeaderRaw::ref_from_prefix(&buffer[..len]).unwrap()
+                );
+                let raw = UdpHeaderRaw::mut_from_prefix(&mut buffer[..len]).unwrap();
+                raw.checksum.set(0xabcd);
+                assert_eq!([0xab, 0xcd], buffer[6..8]);
+            }
+        }
+    }
+}
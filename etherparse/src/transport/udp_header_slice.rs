@@ -1,5 +1,4 @@
 use crate::*;
-use core::slice::from_raw_parts;
 
 ///A slice containing an udp header of a network package. Struct allows the selective read of fields in the header.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,7 +26,7 @@ impl<'a> UdpHeaderSlice<'a> {
             // SAFETY:
             // Safe as slice length is checked to be at least
             // UdpHeader::LEN (8) before this.
-            slice: unsafe { from_raw_parts(slice.as_ptr(), UdpHeader::LEN) },
+            slice: unchecked! { get_unchecked_slice(slice, 0, UdpHeader::LEN) },
         })
     }
 
@@ -43,7 +42,7 @@ impl<'a> UdpHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr()) }
+        unchecked! { get_unchecked_be_u16(self.slice, 0) }
     }
 
     /// Reads the "udp destination port" from the slice.
@@ -52,7 +51,7 @@ impl<'a> UdpHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 2) }
     }
 
     /// Reads the "length" from the slice.
@@ -61,7 +60,7 @@ impl<'a> UdpHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 4) }
     }
 
     /// Reads the "checksum" from the slice.
@@ -70,7 +69,7 @@ impl<'a> UdpHeaderSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 6) }
     }
 
     /// Decode all the fields and copy the results to a UdpHeader struct
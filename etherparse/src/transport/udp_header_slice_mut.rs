@@ -0,0 +1,215 @@
+use crate::*;
+
+/// A mutable slice containing an UDP header of a network package.
+///
+/// Allows modifying individual fields of an already serialized UDP
+/// header (e.g. for rewriting ports) without re-serializing the
+/// complete header.
+#[derive(Debug, Eq, PartialEq)]
+pub struct UdpHeaderSliceMut<'a> {
+    slice: &'a mut [u8],
+}
+
+impl<'a> UdpHeaderSliceMut<'a> {
+    /// Creates a mutable slice containing an UDP header.
+    pub fn from_slice(slice: &'a mut [u8]) -> Result<UdpHeaderSliceMut<'a>, err::LenError> {
+        if slice.len() < UdpHeader::LEN {
+            return Err(err::LenError {
+                required_len: UdpHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::UdpHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(UdpHeaderSliceMut {
+            slice: &mut slice[..UdpHeader::LEN],
+        })
+    }
+
+    /// Returns the slice containing the UDP header.
+    #[inline]
+    pub fn slice(&self) -> &[u8] {
+        self.slice
+    }
+
+    /// Returns a non mutable [`UdpHeaderSlice`] that allows reading all
+    /// fields of the header.
+    #[inline]
+    pub fn to_header_slice(&self) -> UdpHeaderSlice<'_> {
+        UdpHeaderSlice { slice: self.slice }
+    }
+
+    /// Read the source port number.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        u16::from_be_bytes([self.slice[0], self.slice[1]])
+    }
+
+    /// Read the destination port number.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        u16::from_be_bytes([self.slice[2], self.slice[3]])
+    }
+
+    /// Read the checksum.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[6], self.slice[7]])
+    }
+
+    /// Sets the source port & incrementally updates the checksum so it
+    /// stays valid (if it was valid before).
+    ///
+    /// The update of the checksum is done based on
+    /// [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624) and does
+    /// not require access to the payload or the IP header. A checksum
+    /// of zero (checksum not used) stays zero.
+    pub fn set_source_port_with_checksum_update(&mut self, source_port: u16) {
+        let old = self.source_port();
+        self.slice[..2].copy_from_slice(&source_port.to_be_bytes());
+        self.update_checksum(old, source_port);
+    }
+
+    /// Sets the destination port & incrementally updates the checksum so
+    /// it stays valid (if it was valid before).
+    ///
+    /// The update of the checksum is done based on
+    /// [RFC 1624](https://datatracker.ietf.org/doc/html/rfc1624) and does
+    /// not require access to the payload or the IP header. A checksum
+    /// of zero (checksum not used) stays zero.
+    pub fn set_destination_port_with_checksum_update(&mut self, destination_port: u16) {
+        let old = self.destination_port();
+        self.slice[2..4].copy_from_slice(&destination_port.to_be_bytes());
+        self.update_checksum(old, destination_port);
+    }
+
+    fn update_checksum(&mut self, old_word: u16, new_word: u16) {
+        let old_checksum = self.checksum();
+        // a checksum of zero indicates that no checksum is used
+        if 0 != old_checksum {
+            let checksum = match checksum::incremental_update(old_checksum, old_word, new_word) {
+                // zero is reserved for "no checksum"
+                0 => 0xffff,
+                value => value,
+            };
+            self.slice[6..8].copy_from_slice(&checksum.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(header in udp_any()) {
+            let mut buffer = header.to_bytes().to_vec();
+            buffer.extend_from_slice(&[1, 2, 3]);
+
+            // ok
+            {
+                let mut data = buffer.clone();
+                let slice = UdpHeaderSliceMut::from_slice(&mut data).unwrap();
+                assert_eq!(slice.slice(), &buffer[..UdpHeader::LEN]);
+                assert_eq!(slice.to_header_slice().to_header(), header);
+                assert_eq!(slice.source_port(), header.source_port);
+                assert_eq!(slice.destination_port(), header.destination_port);
+                assert_eq!(slice.checksum(), header.checksum);
+            }
+
+            // length error
+            for len in 0..UdpHeader::LEN {
+                let mut data = buffer.clone();
+                assert_eq!(
+                    UdpHeaderSliceMut::from_slice(&mut data[..len]).unwrap_err(),
+                    UdpHeaderSlice::from_slice(&buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn set_ports_with_checksum_update(
+            ipv4 in ipv4_unknown(),
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+            new_source_port in any::<u16>(),
+            new_destination_port in any::<u16>(),
+        ) {
+            let udp = UdpHeader::with_ipv4_checksum(
+                source_port,
+                destination_port,
+                &ipv4,
+                &payload
+            ).unwrap();
+            let mut data = udp.to_bytes().to_vec();
+
+            // source port
+            {
+                let mut slice = UdpHeaderSliceMut::from_slice(&mut data).unwrap();
+                slice.set_source_port_with_checksum_update(new_source_port);
+                assert_eq!(slice.source_port(), new_source_port);
+                let expected = UdpHeader::with_ipv4_checksum(
+                    new_source_port,
+                    destination_port,
+                    &ipv4,
+                    &payload
+                ).unwrap();
+                assert_eq!(expected.checksum, slice.checksum());
+            }
+
+            // destination port
+            {
+                let mut slice = UdpHeaderSliceMut::from_slice(&mut data).unwrap();
+                slice.set_destination_port_with_checksum_update(new_destination_port);
+                assert_eq!(slice.destination_port(), new_destination_port);
+                let expected = UdpHeader::with_ipv4_checksum(
+                    new_source_port,
+                    new_destination_port,
+                    &ipv4,
+                    &payload
+                ).unwrap();
+                assert_eq!(expected, slice.to_header_slice().to_header());
+            }
+        }
+    }
+
+    #[test]
+    fn set_ports_zero_checksum() {
+        let mut data = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: 8,
+            checksum: 0,
+        }
+        .to_bytes();
+        let mut slice = UdpHeaderSliceMut::from_slice(&mut data).unwrap();
+        slice.set_source_port_with_checksum_update(1234);
+        slice.set_destination_port_with_checksum_update(4321);
+        assert_eq!(slice.source_port(), 1234);
+        assert_eq!(slice.destination_port(), 4321);
+        assert_eq!(slice.checksum(), 0);
+    }
+
+    #[test]
+    fn debug_eq() {
+        let mut data = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: 8,
+            checksum: 0,
+        }
+        .to_bytes();
+        let expected = format!("UdpHeaderSliceMut {{ slice: {:?} }}", &data[..]);
+        let mut data2: Vec<u8> = data.to_vec();
+        let slice = UdpHeaderSliceMut::from_slice(&mut data).unwrap();
+        assert_eq!(expected, format!("{:?}", slice));
+        assert_eq!(slice, UdpHeaderSliceMut::from_slice(&mut data2).unwrap());
+    }
+}
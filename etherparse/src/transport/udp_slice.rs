@@ -46,7 +46,7 @@ impl<'a> UdpSlice<'a> {
                 Ok(UdpSlice {
                     // SAFETY: Safe as slice.len() was validated before to
                     // be at least as big as "len".
-                    slice: unsafe { core::slice::from_raw_parts(slice.as_ptr(), len) },
+                    slice: unchecked! { get_unchecked_slice(slice, 0, len) },
                 })
             }
         }
@@ -72,7 +72,7 @@ impl<'a> UdpSlice<'a> {
             Ok(UdpSlice {
                 // SAFETY: Safe as slice.len() was validated before to
                 // be at least as big as "len".
-                slice: unsafe { core::slice::from_raw_parts(slice.as_ptr(), len) },
+                slice: unchecked! { get_unchecked_slice(slice, 0, len) },
             })
         }
     }
@@ -86,23 +86,20 @@ impl<'a> UdpSlice<'a> {
     /// Return the slice containing the UDP header.
     #[inline]
     pub fn header_slice(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as the slice length was verified
             // to be at least UdpHeader::LEN by "from_slice".
-            core::slice::from_raw_parts(self.slice.as_ptr(), UdpHeader::LEN)
+            get_unchecked_slice(self.slice, 0, UdpHeader::LEN)
         }
     }
 
     /// Returns the slice containing the UDP payload.
     #[inline]
     pub fn payload(&self) -> &'a [u8] {
-        unsafe {
+        unchecked! {
             // SAFETY: Safe as the slice length was verified
             // to be at least UdpHeader::LEN by "from_slice".
-            core::slice::from_raw_parts(
-                self.slice.as_ptr().add(UdpHeader::LEN),
-                self.slice.len() - UdpHeader::LEN,
-            )
+            get_unchecked_slice(self.slice, UdpHeader::LEN, self.slice.len() - UdpHeader::LEN)
         }
     }
 
@@ -122,7 +119,7 @@ impl<'a> UdpSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr()) }
+        unchecked! { get_unchecked_be_u16(self.slice, 0) }
     }
 
     /// Reads the "udp destination port" in the UDP header.
@@ -131,7 +128,76 @@ impl<'a> UdpSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 2) }
+    }
+
+    /// Returns true if the source or destination port is the DNS port
+    /// ([`services::DNS`] 53).
+    ///
+    /// Note that ports are only a hint and the payload is not checked.
+    #[inline]
+    pub fn is_probably_dns(&self) -> bool {
+        services::DNS == self.source_port() || services::DNS == self.destination_port()
+    }
+
+    /// Returns true if the source or destination port is the NTP port
+    /// ([`services::NTP`] 123).
+    ///
+    /// Note that ports are only a hint and the payload is not checked.
+    #[inline]
+    pub fn is_probably_ntp(&self) -> bool {
+        services::NTP == self.source_port() || services::NTP == self.destination_port()
+    }
+
+    /// Returns true if the source or destination port is the default
+    /// WireGuard port ([`services::WIREGUARD`] 51820).
+    ///
+    /// Note that WireGuard ports are freely configurable, so the port
+    /// is only a hint (use [`crate::WireguardSlice::from_slice`] to
+    /// check the payload).
+    #[inline]
+    pub fn is_probably_wireguard(&self) -> bool {
+        services::WIREGUARD == self.source_port() || services::WIREGUARD == self.destination_port()
+    }
+
+    /// Returns true if the source or destination port is the Teredo
+    /// server port ([`services::TEREDO`] 3544).
+    ///
+    /// Note that the port is only a hint and that Teredo packets
+    /// exchanged directly between clients & relays use other ports.
+    #[inline]
+    pub fn is_probably_teredo(&self) -> bool {
+        services::TEREDO == self.source_port() || services::TEREDO == self.destination_port()
+    }
+
+    /// Returns true if the destination port is the VXLAN port
+    /// ([`services::VXLAN`] 4789).
+    ///
+    /// Only the destination port is checked as the source port of VXLAN
+    /// packets is usually set based on a hash of the inner packet.
+    #[inline]
+    pub fn is_probably_vxlan(&self) -> bool {
+        services::VXLAN == self.destination_port()
+    }
+
+    /// Returns true if the destination port is the Geneve port
+    /// ([`services::GENEVE`] 6081).
+    ///
+    /// Only the destination port is checked as the source port of Geneve
+    /// packets is usually set based on a hash of the inner packet.
+    #[inline]
+    pub fn is_probably_geneve(&self) -> bool {
+        services::GENEVE == self.destination_port()
+    }
+
+    /// Returns true if the destination port is the GTP-U port
+    /// ([`services::GTP_U`] 2152).
+    ///
+    /// Only the destination port is checked as the source port of GTP-U
+    /// packets can be chosen freely by the sender.
+    #[inline]
+    pub fn is_probably_gtpu(&self) -> bool {
+        services::GTP_U == self.destination_port()
     }
 
     /// Reads the "length" field in the UDP header.
@@ -140,7 +206,7 @@ impl<'a> UdpSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 4) }
     }
 
     /// Reads the "checksum" from the slice.
@@ -149,7 +215,7 @@ impl<'a> UdpSlice<'a> {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of UdpHeader::LEN (8).
-        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) }
+        unchecked! { get_unchecked_be_u16(self.slice, 6) }
     }
 
     /// Length of the UDP header (equal to [`crate::UdpHeader::LEN`]).
@@ -164,6 +230,112 @@ impl<'a> UdpSlice<'a> {
         UdpHeader::LEN_U16
     }
 
+    /// Validates the checksum based on the addresses of the given IP header
+    /// & classifies a checksum of zero based on the given policy.
+    ///
+    /// ```
+    /// # #[cfg(feature = "ipv6")]
+    /// # {
+    /// use etherparse::{ChecksumResult, PacketBuilder, SlicedPacket, TransportSlice, UdpZeroChecksumPolicy};
+    ///
+    /// let mut packet = Vec::new();
+    /// PacketBuilder::ipv6([1; 16], [2; 16], 20)
+    ///     .udp(4789, 4789)
+    ///     .write(&mut packet, &[1, 2, 3, 4])
+    ///     .unwrap();
+    /// // clear the checksum (as done by some tunnel endpoints)
+    /// packet[40 + 6] = 0;
+    /// packet[40 + 7] = 0;
+    ///
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// let (Some(net), Some(TransportSlice::Udp(udp))) = (&sliced.net, &sliced.transport) else {
+    ///     panic!("expected an UDP packet");
+    /// };
+    /// assert_eq!(
+    ///     ChecksumResult::Invalid,
+    ///     udp.checksum_policy_result(net, UdpZeroChecksumPolicy::RFC)
+    /// );
+    /// assert_eq!(
+    ///     ChecksumResult::Skipped,
+    ///     udp.checksum_policy_result(net, UdpZeroChecksumPolicy::TUNNEL)
+    /// );
+    /// # }
+    /// ```
+    pub fn checksum_policy_result(
+        &self,
+        net: &NetSlice,
+        policy: UdpZeroChecksumPolicy,
+    ) -> ChecksumResult {
+        match net {
+            NetSlice::Ipv4(ipv4) => self.checksum_policy_result_ipv4(
+                ipv4.header().source(),
+                ipv4.header().destination(),
+                policy,
+            ),
+            NetSlice::Ipv6(ipv6) => self.checksum_policy_result_ipv6(
+                ipv6.header().source(),
+                ipv6.header().destination(),
+                policy,
+            ),
+        }
+    }
+
+    /// Validates the checksum based on the given IPv4 addresses &
+    /// classifies a checksum of zero based on the given policy.
+    pub fn checksum_policy_result_ipv4(
+        &self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        policy: UdpZeroChecksumPolicy,
+    ) -> ChecksumResult {
+        if 0 == self.checksum() {
+            return policy.zero_checksum_result(false);
+        }
+        let sum = checksum::Sum16BitWords::new()
+            .add_slice(&source)
+            .add_slice(&destination)
+            .add_2bytes([0, ip_number::UDP.0])
+            .add_2bytes(self.length().to_be_bytes());
+        Self::verify_sum(sum, self.slice)
+    }
+
+    /// Validates the checksum based on the given IPv6 addresses &
+    /// classifies a checksum of zero based on the given policy.
+    ///
+    /// For jumbograms (length field set to zero) the length of the
+    /// slice is used in the pseudo header.
+    pub fn checksum_policy_result_ipv6(
+        &self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        policy: UdpZeroChecksumPolicy,
+    ) -> ChecksumResult {
+        if 0 == self.checksum() {
+            return policy.zero_checksum_result(true);
+        }
+        let len = if 0 == self.length() {
+            self.slice.len() as u32
+        } else {
+            u32::from(self.length())
+        };
+        let sum = checksum::Sum16BitWords::new()
+            .add_slice(&source)
+            .add_slice(&destination)
+            .add_slice(&len.to_be_bytes())
+            .add_2bytes([0, ip_number::UDP.0]);
+        Self::verify_sum(sum, self.slice)
+    }
+
+    /// Adds the UDP header (including the checksum) & payload to the
+    /// pseudo header sum & checks that the result matches.
+    fn verify_sum(pseudo_header_sum: checksum::Sum16BitWords, slice: &[u8]) -> ChecksumResult {
+        if 0 == pseudo_header_sum.add_slice(slice).ones_complement() {
+            ChecksumResult::Valid
+        } else {
+            ChecksumResult::Invalid
+        }
+    }
+
     /// Decode all the fields of the UDP header and copy the results
     /// to a UdpHeader struct.
     #[inline]
@@ -247,6 +419,56 @@ mod test {
         }
     }
 
+    #[test]
+    fn is_probably() {
+        let build = |source_port: u16, destination_port: u16| {
+            UdpHeader {
+                source_port,
+                destination_port,
+                length: UdpHeader::LEN as u16,
+                checksum: 0,
+            }
+            .to_bytes()
+        };
+
+        // dns, ntp, wireguard & teredo (either port)
+        for (port, dns, ntp, wireguard, teredo) in [
+            (53, true, false, false, false),
+            (123, false, true, false, false),
+            (51820, false, false, true, false),
+            (3544, false, false, false, true),
+            (1234, false, false, false, false),
+        ] {
+            for data in [build(port, 1234), build(1234, port)] {
+                let slice = UdpSlice::from_slice(&data).unwrap();
+                assert_eq!(dns, slice.is_probably_dns());
+                assert_eq!(ntp, slice.is_probably_ntp());
+                assert_eq!(wireguard, slice.is_probably_wireguard());
+                assert_eq!(teredo, slice.is_probably_teredo());
+            }
+        }
+
+        // tunnels (destination port only)
+        for (port, vxlan, geneve, gtpu) in [
+            (4789, true, false, false),
+            (6081, false, true, false),
+            (2152, false, false, true),
+            (1234, false, false, false),
+        ] {
+            let data = build(1234, port);
+            let slice = UdpSlice::from_slice(&data).unwrap();
+            assert_eq!(vxlan, slice.is_probably_vxlan());
+            assert_eq!(geneve, slice.is_probably_geneve());
+            assert_eq!(gtpu, slice.is_probably_gtpu());
+
+            let data = build(port, 1234);
+            let slice = UdpSlice::from_slice(&data).unwrap();
+            assert!(!slice.is_probably_vxlan());
+            assert!(!slice.is_probably_geneve());
+            assert!(!slice.is_probably_gtpu());
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice(
@@ -456,4 +678,127 @@ mod test {
             }
         }
     }
+
+    proptest! {
+        #[cfg(feature = "ipv6")]
+        #[test]
+        fn checksum_policy_result(
+            ipv4 in ipv4_any(),
+            ipv6 in ipv6_any(),
+            ports in any::<[u16;2]>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            use ChecksumResult::*;
+
+            // ipv4
+            {
+                let mut data = Vec::new();
+                PacketBuilder::ipv4(ipv4.source, ipv4.destination, 20)
+                    .udp(ports[0], ports[1])
+                    .write(&mut data, &payload)
+                    .unwrap();
+                let sliced = SlicedPacket::from_ip(&data).unwrap();
+                let net = sliced.net.as_ref().unwrap();
+                let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+                    panic!("expected udp");
+                };
+                assert_eq!(Valid, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::REQUIRED));
+                assert_eq!(
+                    Valid,
+                    udp.checksum_policy_result_ipv4(ipv4.source, ipv4.destination, Default::default())
+                );
+
+                // bad checksum
+                let mut bad = data.clone();
+                bad[Ipv4Header::MIN_LEN + 6] ^= 0x01;
+                if 0 != bad[Ipv4Header::MIN_LEN + 6] || 0 != bad[Ipv4Header::MIN_LEN + 7] {
+                    let sliced = SlicedPacket::from_ip(&bad).unwrap();
+                    let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+                        panic!("expected udp");
+                    };
+                    assert_eq!(Invalid, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::TUNNEL));
+                }
+
+                // zero checksum
+                let mut zero = data.clone();
+                zero[Ipv4Header::MIN_LEN + 6] = 0;
+                zero[Ipv4Header::MIN_LEN + 7] = 0;
+                let sliced = SlicedPacket::from_ip(&zero).unwrap();
+                let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+                    panic!("expected udp");
+                };
+                assert_eq!(Skipped, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::RFC));
+                assert_eq!(Invalid, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::REQUIRED));
+            }
+
+            // ipv6
+            {
+                let mut data = Vec::new();
+                PacketBuilder::ipv6(ipv6.source, ipv6.destination, 20)
+                    .udp(ports[0], ports[1])
+                    .write(&mut data, &payload)
+                    .unwrap();
+                let sliced = SlicedPacket::from_ip(&data).unwrap();
+                let net = sliced.net.as_ref().unwrap();
+                let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+                    panic!("expected udp");
+                };
+                assert_eq!(Valid, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::REQUIRED));
+
+                // other addresses
+                assert_eq!(
+                    Invalid,
+                    udp.checksum_policy_result_ipv6(
+                        ipv6.destination,
+                        {
+                            let mut d = ipv6.source;
+                            d[15] ^= 1;
+                            d
+                        },
+                        Default::default()
+                    )
+                );
+
+                // zero checksum
+                let mut zero = data.clone();
+                zero[Ipv6Header::LEN + 6] = 0;
+                zero[Ipv6Header::LEN + 7] = 0;
+                let sliced = SlicedPacket::from_ip(&zero).unwrap();
+                let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+                    panic!("expected udp");
+                };
+                assert_eq!(Invalid, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::RFC));
+                assert_eq!(Skipped, udp.checksum_policy_result(net, UdpZeroChecksumPolicy::TUNNEL));
+            }
+        }
+    }
+
+    #[test]
+    fn checksum_policy_result_jumbogram() {
+        let payload = [1, 2, 3, 4];
+        // length field set to zero & the length of the slice used in
+        // the pseudo header
+        let mut header = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: 0,
+            checksum: 0,
+        };
+        header.checksum = checksum::Sum16BitWords::new()
+            .add_slice(&[0; 32])
+            .add_slice(&((UdpHeader::LEN + payload.len()) as u32).to_be_bytes())
+            .add_2bytes([0, ip_number::UDP.0])
+            .add_slice(&header.to_bytes())
+            .add_slice(&payload)
+            .to_ones_complement_with_no_zero()
+            .to_be();
+        let mut data = Vec::new();
+        data.extend_from_slice(&header.to_bytes());
+        data.extend_from_slice(&payload);
+        let udp = UdpSlice::from_slice_lax(&data).unwrap();
+        assert_eq!(
+            ChecksumResult::Valid,
+            udp.checksum_policy_result_ipv6([0; 16], [0; 16], Default::default())
+        );
+    }
 }
@@ -0,0 +1,110 @@
+use crate::ChecksumResult;
+
+/// Policy defining how an UDP checksum of zero (checksum omitted by the
+/// sender) is classified (see [`crate::UdpSlice::checksum_policy_result`]).
+///
+/// [RFC 768](https://datatracker.ietf.org/doc/html/rfc768) allows omitting
+/// the checksum for UDP over IPv4, while
+/// [RFC 8200](https://datatracker.ietf.org/doc/html/rfc8200#section-8.1)
+/// requires a checksum for UDP over IPv6. [RFC 6935](https://datatracker.ietf.org/doc/html/rfc6935)
+/// & [RFC 6936](https://datatracker.ietf.org/doc/html/rfc6936) relax this
+/// requirement for tunnel protocols over IPv6 (e.g. VXLAN or GRE-in-UDP).
+///
+/// ```
+/// use etherparse::{ChecksumResult, UdpZeroChecksumPolicy};
+///
+/// let policy = UdpZeroChecksumPolicy::default();
+/// assert_eq!(UdpZeroChecksumPolicy::RFC, policy);
+/// assert_eq!(ChecksumResult::Skipped, policy.zero_checksum_result(false));
+/// assert_eq!(ChecksumResult::Invalid, policy.zero_checksum_result(true));
+///
+/// // custom policy
+/// let policy = UdpZeroChecksumPolicy {
+///     ipv4: ChecksumResult::Invalid,
+///     ipv6: ChecksumResult::Skipped,
+/// };
+/// assert_eq!(ChecksumResult::Invalid, policy.zero_checksum_result(false));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct UdpZeroChecksumPolicy {
+    /// Result for an UDP checksum of zero in an IPv4 packet.
+    pub ipv4: ChecksumResult,
+    /// Result for an UDP checksum of zero in an IPv6 packet.
+    pub ipv6: ChecksumResult,
+}
+
+impl UdpZeroChecksumPolicy {
+    /// Policy following RFC 768 & RFC 8200 (a zero checksum is skipped
+    /// over IPv4 & invalid over IPv6).
+    pub const RFC: UdpZeroChecksumPolicy = UdpZeroChecksumPolicy {
+        ipv4: ChecksumResult::Skipped,
+        ipv6: ChecksumResult::Invalid,
+    };
+
+    /// Policy for tunnel protocols following RFC 6935 & RFC 6936 (a zero
+    /// checksum is skipped over IPv4 & IPv6).
+    pub const TUNNEL: UdpZeroChecksumPolicy = UdpZeroChecksumPolicy {
+        ipv4: ChecksumResult::Skipped,
+        ipv6: ChecksumResult::Skipped,
+    };
+
+    /// Policy requiring a checksum (a zero checksum is invalid over IPv4
+    /// & IPv6).
+    pub const REQUIRED: UdpZeroChecksumPolicy = UdpZeroChecksumPolicy {
+        ipv4: ChecksumResult::Invalid,
+        ipv6: ChecksumResult::Invalid,
+    };
+
+    /// Returns the result for an UDP checksum of zero in an IPv4
+    /// (`is_ipv6 == false`) or IPv6 packet.
+    #[inline]
+    pub const fn zero_checksum_result(&self, is_ipv6: bool) -> ChecksumResult {
+        if is_ipv6 {
+            self.ipv6
+        } else {
+            self.ipv4
+        }
+    }
+}
+
+impl Default for UdpZeroChecksumPolicy {
+    #[inline]
+    fn default() -> Self {
+        UdpZeroChecksumPolicy::RFC
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn default() {
+        assert_eq!(UdpZeroChecksumPolicy::RFC, UdpZeroChecksumPolicy::default());
+    }
+
+    #[test]
+    fn zero_checksum_result() {
+        use ChecksumResult::*;
+        let tests = [
+            (UdpZeroChecksumPolicy::RFC, Skipped, Invalid),
+            (UdpZeroChecksumPolicy::TUNNEL, Skipped, Skipped),
+            (UdpZeroChecksumPolicy::REQUIRED, Invalid, Invalid),
+        ];
+        for (policy, ipv4, ipv6) in tests {
+            assert_eq!(ipv4, policy.zero_checksum_result(false));
+            assert_eq!(ipv6, policy.zero_checksum_result(true));
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = UdpZeroChecksumPolicy::TUNNEL;
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "UdpZeroChecksumPolicy { ipv4: Skipped, ipv6: Skipped }",
+            format!("{:?}", value)
+        );
+    }
+}
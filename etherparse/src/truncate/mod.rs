@@ -0,0 +1,2 @@
+mod truncate_packet;
+pub use truncate_packet::*;
@@ -0,0 +1,351 @@
+use crate::*;
+use std::vec::Vec;
+
+/// Returns a copy of an ethernet II packet cut down to `max_len` bytes
+/// while keeping all decodable headers intact (similar to the "snaplen"
+/// of a packet capture).
+///
+/// The packet is decoded via [`SlicedPacket::from_ethernet`] and the cut
+/// is placed after the last decoded header if `max_len` would cut into
+/// the headers. If the packet can not be decoded the data is simply cut
+/// at `max_len`.
+///
+/// If `fix_len_fields` is `false` the length fields are left unchanged
+/// (like in a real packet capture). If it is `true` the IPv4 "total length"
+/// (including the header checksum), the IPv6 "payload length" and the
+/// UDP "length" fields are reduced to match the truncated data. The
+/// length fields are patched in place (all other header fields are kept
+/// as they are) and the UDP checksum is recalculated for the remaining
+/// payload (an unused zero checksum in IPv4 stays zero).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{truncate::truncate_ethernet, LaxSlicedPacket, PacketBuilder};
+///
+/// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234);
+/// let payload = [0u8; 100];
+/// let mut packet = Vec::with_capacity(builder.size(payload.len()));
+/// builder.write(&mut packet, &payload).unwrap();
+///
+/// // a budget smaller than the headers keeps the headers intact
+/// let truncated = truncate_ethernet(&packet, 10, false);
+/// assert_eq!(14 + 20 + 8, truncated.len());
+///
+/// // the result can be used to test the lax parsers
+/// let sliced = LaxSlicedPacket::from_ethernet(&truncated).unwrap();
+/// assert!(sliced.net.is_some());
+/// ```
+pub fn truncate_ethernet(data: &[u8], max_len: usize, fix_len_fields: bool) -> Vec<u8> {
+    truncate_sliced(
+        data,
+        SlicedPacket::from_ethernet(data).ok(),
+        max_len,
+        fix_len_fields,
+    )
+}
+
+/// Returns a copy of an IPv4 or IPv6 packet cut down to `max_len` bytes
+/// while keeping all decodable headers intact.
+///
+/// Behaves the same as [`truncate_ethernet`] but expects the data to start
+/// with an IP header (decoded via [`SlicedPacket::from_ip`]).
+pub fn truncate_ip(data: &[u8], max_len: usize, fix_len_fields: bool) -> Vec<u8> {
+    truncate_sliced(
+        data,
+        SlicedPacket::from_ip(data).ok(),
+        max_len,
+        fix_len_fields,
+    )
+}
+
+fn truncate_sliced(
+    data: &[u8],
+    sliced: Option<SlicedPacket>,
+    max_len: usize,
+    fix_len_fields: bool,
+) -> Vec<u8> {
+    let Some(sliced) = sliced else {
+        return data[..max_len.min(data.len())].to_vec();
+    };
+
+    // determine the end of the headers
+    let headers_end = if let Some(transport) = &sliced.transport {
+        offset_in(
+            data,
+            match transport {
+                TransportSlice::Icmpv4(s) => s.payload(),
+                TransportSlice::Icmpv6(s) => s.payload(),
+                TransportSlice::Udp(s) => s.payload(),
+                TransportSlice::Tcp(s) => s.payload(),
+            },
+        )
+    } else if let Some(ip_payload) = sliced.ip_payload() {
+        offset_in(data, ip_payload.payload)
+    } else if let Some(ether_payload) = sliced.ether_payload() {
+        offset_in(data, ether_payload.payload)
+    } else {
+        0
+    };
+
+    let cut = max_len.max(headers_end).min(data.len());
+    let mut result = data[..cut].to_vec();
+
+    if fix_len_fields {
+        match &sliced.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                let start = offset_in(data, ipv4.header().slice());
+                let total_len = ipv4.header().total_len();
+                let new_len = total_len.min((cut - start).min(usize::from(u16::MAX)) as u16);
+                if new_len != total_len {
+                    result[start + 2..start + 4].copy_from_slice(&new_len.to_be_bytes());
+                    let header_checksum = checksum::incremental_update(
+                        ipv4.header().header_checksum(),
+                        total_len,
+                        new_len,
+                    );
+                    result[start + 10..start + 12].copy_from_slice(&header_checksum.to_be_bytes());
+                }
+            }
+            Some(NetSlice::Ipv6(ipv6)) => {
+                let start = offset_in(data, ipv6.header().slice());
+                let payload_len = ipv6.header().payload_length();
+                // a payload length of zero indicates a jumbogram
+                if payload_len != 0 {
+                    let new_len = payload_len
+                        .min((cut - start - Ipv6Header::LEN).min(usize::from(u16::MAX)) as u16);
+                    result[start + 4..start + 6].copy_from_slice(&new_len.to_be_bytes());
+                }
+            }
+            None => {}
+        }
+        if let Some(TransportSlice::Udp(udp)) = &sliced.transport {
+            let start = offset_in(data, udp.header_slice());
+            let new_len = udp
+                .length()
+                .min((cut - start).min(usize::from(u16::MAX)) as u16);
+            if new_len != udp.length() {
+                result[start + 4..start + 6].copy_from_slice(&new_len.to_be_bytes());
+
+                // the checksum covers the length & the removed payload, so it
+                // gets recalculated (unless it is unused in IPv4)
+                let header = UdpHeader {
+                    length: new_len,
+                    checksum: 0,
+                    ..udp.to_header()
+                };
+                let payload = &result[start + UdpHeader::LEN..start + usize::from(new_len)];
+                let new_checksum = match &sliced.net {
+                    Some(NetSlice::Ipv4(_)) if 0 == udp.checksum() => Ok(0),
+                    Some(NetSlice::Ipv4(ipv4)) => header.calc_checksum_ipv4_raw(
+                        ipv4.header().source(),
+                        ipv4.header().destination(),
+                        payload,
+                    ),
+                    Some(NetSlice::Ipv6(ipv6)) => header.calc_checksum_ipv6_raw(
+                        ipv6.header().source(),
+                        ipv6.header().destination(),
+                        payload,
+                    ),
+                    None => Ok(udp.checksum()),
+                };
+                // the length can not be too big as it was reduced
+                if let Ok(new_checksum) = new_checksum {
+                    result[start + 6..start + 8].copy_from_slice(&new_checksum.to_be_bytes());
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ipv4_udp(payload_len: usize) -> Vec<u8> {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let payload: Vec<u8> = (0..payload_len).map(|v| v as u8).collect();
+        let mut result = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut result, &payload).unwrap();
+        result
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    fn ipv6_tcp(payload_len: usize) -> Vec<u8> {
+        let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).tcp(21, 1234, 1, 2);
+        let payload: Vec<u8> = (0..payload_len).map(|v| v as u8).collect();
+        let mut result = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut result, &payload).unwrap();
+        result
+    }
+
+    #[test]
+    fn truncate_ethernet_keep_len_fields() {
+        let packet = ipv4_udp(100);
+        let headers_len = 14 + 20 + 8;
+
+        // cut in the headers
+        for max_len in 0..=headers_len {
+            assert_eq!(
+                &packet[..headers_len],
+                &truncate_ethernet(&packet, max_len, false)[..]
+            );
+        }
+        // cut in the payload
+        assert_eq!(
+            &packet[..headers_len + 10],
+            &truncate_ethernet(&packet, headers_len + 10, false)[..]
+        );
+        // budget bigger then the packet
+        assert_eq!(packet, truncate_ethernet(&packet, packet.len() + 10, false));
+    }
+
+    #[test]
+    fn truncate_ethernet_fix_len_fields() {
+        let packet = ipv4_udp(100);
+        let truncated = truncate_ethernet(&packet, 14 + 20 + 8 + 10, true);
+        assert_eq!(14 + 20 + 8 + 10, truncated.len());
+
+        // the result is decodable by the non lax parser
+        let sliced = SlicedPacket::from_ethernet(&truncated).unwrap();
+        let Some(NetSlice::Ipv4(ipv4)) = &sliced.net else {
+            panic!()
+        };
+        assert_eq!(20 + 8 + 10, ipv4.header().total_len());
+        assert_eq!(
+            ipv4.header().header_checksum(),
+            ipv4.header().to_header().calc_header_checksum()
+        );
+        let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+            panic!()
+        };
+        assert_eq!(8 + 10, udp.length());
+        assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], udp.payload());
+        assert_eq!(
+            udp.checksum(),
+            udp.to_header()
+                .calc_checksum_ipv4(&ipv4.header().to_header(), udp.payload())
+                .unwrap()
+        );
+
+        // no change if nothing is cut
+        assert_eq!(packet, truncate_ethernet(&packet, packet.len(), true));
+
+        // all other header fields are kept (e.g. the reserved flag)
+        {
+            let mut packet = packet.clone();
+            let mut header = Ipv4HeaderSlice::from_slice(&packet[14..])
+                .unwrap()
+                .to_header();
+            header.header_checksum = 0;
+            let mut header_bytes = header.to_bytes();
+            header_bytes[6] |= 0b1000_0000;
+            let header_checksum = checksum::Sum16BitWords::new()
+                .add_slice(&header_bytes)
+                .ones_complement()
+                .to_be();
+            header_bytes[10..12].copy_from_slice(&header_checksum.to_be_bytes());
+            packet[14..14 + 20].copy_from_slice(&header_bytes);
+
+            let truncated = truncate_ethernet(&packet, 14 + 20 + 8 + 10, true);
+            let mut expected = header_bytes;
+            expected[2..4].copy_from_slice(&(20u16 + 8 + 10).to_be_bytes());
+            expected[10..12].copy_from_slice(&[0, 0]);
+            let expected_checksum = checksum::Sum16BitWords::new()
+                .add_slice(&expected)
+                .ones_complement()
+                .to_be();
+            expected[10..12].copy_from_slice(&expected_checksum.to_be_bytes());
+            assert_eq!(&expected[..], &truncated[14..14 + 20]);
+        }
+
+        // an unused udp checksum stays unused
+        {
+            let mut packet = packet.clone();
+            packet[14 + 20 + 6] = 0;
+            packet[14 + 20 + 7] = 0;
+            let truncated = truncate_ethernet(&packet, 14 + 20 + 8 + 10, true);
+            assert_eq!(&[0, 0], &truncated[14 + 20 + 6..14 + 20 + 8]);
+        }
+    }
+
+    #[test]
+    fn truncate_ethernet_ethernet_padding() {
+        // padding after the ip packet is not added to the lengths
+        let mut packet = ipv4_udp(2);
+        packet.extend_from_slice(&[0; 10]);
+        let truncated = truncate_ethernet(&packet, packet.len() - 5, true);
+        assert_eq!(&packet[..packet.len() - 5], &truncated[..]);
+    }
+
+    #[test]
+    fn truncate_ethernet_undecodable() {
+        let packet = ipv4_udp(10);
+        // cut in the ip header
+        let broken = &packet[..14 + 10];
+        assert_eq!(&broken[..5], &truncate_ethernet(broken, 5, true)[..]);
+        assert_eq!(broken, &truncate_ethernet(broken, 100, true)[..]);
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn truncate_ip_keep_len_fields() {
+        let packet = ipv6_tcp(100);
+        let headers_len = 40 + 20;
+        assert_eq!(&packet[..headers_len], &truncate_ip(&packet, 0, false)[..]);
+        assert_eq!(
+            &packet[..headers_len + 1],
+            &truncate_ip(&packet, headers_len + 1, false)[..]
+        );
+    }
+
+    #[cfg(all(feature = "ipv6", feature = "tcp"))]
+    #[test]
+    fn truncate_ip_fix_len_fields() {
+        let packet = ipv6_tcp(100);
+        let truncated = truncate_ip(&packet, 40 + 20 + 10, true);
+        let sliced = SlicedPacket::from_ip(&truncated).unwrap();
+        let Some(NetSlice::Ipv6(ipv6)) = &sliced.net else {
+            panic!()
+        };
+        assert_eq!(20 + 10, ipv6.header().payload_length());
+        let Some(TransportSlice::Tcp(tcp)) = &sliced.transport else {
+            panic!()
+        };
+        assert_eq!(10, tcp.payload().len());
+
+        // udp checksum is recalculated
+        {
+            let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).udp(21, 1234);
+            let mut packet = Vec::with_capacity(builder.size(100));
+            builder.write(&mut packet, &[1; 100]).unwrap();
+            let truncated = truncate_ip(&packet, 40 + 8 + 10, true);
+            let sliced = SlicedPacket::from_ip(&truncated).unwrap();
+            let Some(NetSlice::Ipv6(ipv6)) = &sliced.net else {
+                panic!()
+            };
+            let Some(TransportSlice::Udp(udp)) = &sliced.transport else {
+                panic!()
+            };
+            assert_eq!(8 + 10, udp.length());
+            assert_eq!(
+                udp.checksum(),
+                udp.to_header()
+                    .calc_checksum_ipv6(&ipv6.header().to_header(), udp.payload())
+                    .unwrap()
+            );
+        }
+
+        // jumbogram payload length is kept
+        let mut jumbo = packet.clone();
+        jumbo[4] = 0;
+        jumbo[5] = 0;
+        let truncated = truncate_ip(&jumbo, 40, true);
+        assert_eq!(&[0, 0], &truncated[4..6]);
+    }
+}
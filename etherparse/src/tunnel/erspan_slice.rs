@@ -0,0 +1,498 @@
+use crate::{
+    err::{self, Layer},
+    *,
+};
+
+/// Slice containing an ERSPAN Type II or Type III header & the mirrored
+/// frame as payload.
+///
+/// ERSPAN headers are usually transported in GRE packets with the protocol
+/// type [`EtherType::ERSPAN_TYPE_2`] (`0x88BE`) or [`EtherType::ERSPAN_TYPE_3`]
+/// (`0x22EB`). The payload of an ERSPAN packet is the mirrored frame (usually
+/// an Ethernet II frame) that can be passed back into the normal slicing
+/// pipeline:
+///
+/// ```
+/// use etherparse::{ErspanSlice, ErspanVersion, SlicedPacket};
+///
+/// # let packet = [
+/// #     0x10, 0x0a, 0x24, 0x07, // version 1, vlan 10, cos 1, en 0, session 7
+/// #     0x00, 0x00, 0x00, 0x00, // reserved & index
+/// #     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x08, 0x00, // ethernet II
+/// # ];
+/// let erspan = ErspanSlice::from_slice(&packet).unwrap();
+/// assert_eq!(ErspanVersion::Type2, erspan.version());
+/// assert_eq!(7, erspan.session_id());
+///
+/// // the mirrored frame can be sliced like any other ethernet frame
+/// let inner = SlicedPacket::from_ethernet(erspan.payload());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErspanSlice<'a> {
+    slice: &'a [u8],
+    header_len: usize,
+}
+
+impl<'a> ErspanSlice<'a> {
+    /// Length of an ERSPAN Type II header.
+    pub const TYPE2_HEADER_LEN: usize = 8;
+
+    /// Length of an ERSPAN Type III header (without the optional
+    /// platform specific sub-header).
+    pub const TYPE3_HEADER_LEN: usize = 12;
+
+    /// Length of the optional platform specific sub-header of an
+    /// ERSPAN Type III header.
+    pub const TYPE3_PLATFORM_SUB_HEADER_LEN: usize = 8;
+
+    /// Minimum length of an ERSPAN header.
+    pub const MIN_LEN: usize = ErspanSlice::TYPE2_HEADER_LEN;
+
+    /// Maximum length of an ERSPAN header.
+    pub const MAX_LEN: usize =
+        ErspanSlice::TYPE3_HEADER_LEN + ErspanSlice::TYPE3_PLATFORM_SUB_HEADER_LEN;
+
+    /// Try creating an [`ErspanSlice`] from a slice containing an ERSPAN
+    /// header & the mirrored frame.
+    pub fn from_slice(slice: &'a [u8]) -> Result<ErspanSlice<'a>, err::erspan::HeaderSliceError> {
+        use err::erspan::{HeaderError::*, HeaderSliceError::*};
+
+        // the version is encoded in the first 4 bits
+        if slice.is_empty() {
+            return Err(Len(err::LenError {
+                required_len: ErspanSlice::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::ErspanHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let version_number = slice[0] >> 4;
+        let header_len = match version_number {
+            ErspanVersion::TYPE2_VERSION_NUMBER => ErspanSlice::TYPE2_HEADER_LEN,
+            ErspanVersion::TYPE3_VERSION_NUMBER => {
+                if slice.len() < ErspanSlice::TYPE3_HEADER_LEN {
+                    return Err(Len(err::LenError {
+                        required_len: ErspanSlice::TYPE3_HEADER_LEN,
+                        len: slice.len(),
+                        len_source: LenSource::Slice,
+                        layer: Layer::ErspanHeader,
+                        layer_start_offset: 0,
+                    }));
+                }
+                // check the "O" flag indicating the presence of the
+                // platform specific sub-header
+                if 0 != slice[11] & 0b1 {
+                    ErspanSlice::TYPE3_HEADER_LEN + ErspanSlice::TYPE3_PLATFORM_SUB_HEADER_LEN
+                } else {
+                    ErspanSlice::TYPE3_HEADER_LEN
+                }
+            }
+            version_number => return Err(Content(UnsupportedVersion { version_number })),
+        };
+
+        if slice.len() < header_len {
+            return Err(Len(err::LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::ErspanHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        Ok(ErspanSlice { slice, header_len })
+    }
+
+    /// Returns the slice containing the ERSPAN header & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing the ERSPAN header (including the
+    /// platform specific sub-header if present).
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least header_len.
+        unchecked! { get_unchecked_slice(self.slice, 0, self.header_len) }
+    }
+
+    /// Returns the slice containing the mirrored frame.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least header_len.
+        unchecked! {
+            get_unchecked_slice(self.slice, self.header_len, self.slice.len() - self.header_len)
+        }
+    }
+
+    /// Length of the ERSPAN header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.header_len
+    }
+
+    /// ERSPAN version of the header.
+    #[inline]
+    pub fn version(&self) -> ErspanVersion {
+        // the version was validated in "from_slice"
+        if ErspanVersion::TYPE2_VERSION_NUMBER == self.slice[0] >> 4 {
+            ErspanVersion::Type2
+        } else {
+            ErspanVersion::Type3
+        }
+    }
+
+    /// Original VLAN id of the mirrored frame.
+    #[inline]
+    pub fn vlan_id(&self) -> VlanId {
+        // SAFETY: Safe as the slice has at least the length of
+        // ErspanSlice::MIN_LEN (8) and the value is masked to 12 bits.
+        unchecked! { VlanId::new_unchecked_internal(get_unchecked_be_u16(self.slice, 0) & 0x0fff) }
+    }
+
+    /// Class of service (priority) of the mirrored frame.
+    #[inline]
+    pub fn cos(&self) -> VlanPcp {
+        // SAFETY: Safe as the value is masked to 3 bits.
+        unchecked! { VlanPcp::new_unchecked_internal(self.slice[2] >> 5) }
+    }
+
+    /// Raw 2 bit value that contains the "encapsulation type" for
+    /// ERSPAN Type II headers or the "bad/short/oversized" (BSO) value
+    /// for ERSPAN Type III headers.
+    #[inline]
+    pub fn encap_type_or_bso(&self) -> u8 {
+        (self.slice[2] >> 3) & 0b11
+    }
+
+    /// True if the mirrored frame was truncated.
+    #[inline]
+    pub fn truncated(&self) -> bool {
+        0 != self.slice[2] & 0b100
+    }
+
+    /// 10 bit session id identifying the mirror session.
+    #[inline]
+    pub fn session_id(&self) -> u16 {
+        // SAFETY: Safe as the slice has at least the length of
+        // ErspanSlice::MIN_LEN (8).
+        unchecked! { get_unchecked_be_u16(self.slice, 2) & 0x03ff }
+    }
+
+    /// 20 bit "index" (port index of the mirrored frame) present in
+    /// ERSPAN Type II headers. `None` for ERSPAN Type III headers.
+    #[inline]
+    pub fn index(&self) -> Option<u32> {
+        match self.version() {
+            // SAFETY: Safe as the slice has at least the length of
+            // ErspanSlice::MIN_LEN (8).
+            ErspanVersion::Type2 => {
+                Some(unchecked! { get_unchecked_be_u32(self.slice, 4) } & 0x000f_ffff)
+            }
+            ErspanVersion::Type3 => None,
+        }
+    }
+
+    /// 32 bit timestamp present in ERSPAN Type III headers (granularity
+    /// is defined by [`ErspanSlice::timestamp_granularity`]). `None` for
+    /// ERSPAN Type II headers.
+    #[inline]
+    pub fn timestamp(&self) -> Option<u32> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            // SAFETY: Safe as the slice has at least the length of
+            // ErspanSlice::TYPE3_HEADER_LEN (12) for Type III headers.
+            ErspanVersion::Type3 => Some(unchecked! { get_unchecked_be_u32(self.slice, 4) }),
+        }
+    }
+
+    /// 16 bit "security group tag" present in ERSPAN Type III headers.
+    #[inline]
+    pub fn security_group_tag(&self) -> Option<u16> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            // SAFETY: Safe as the slice has at least the length of
+            // ErspanSlice::TYPE3_HEADER_LEN (12) for Type III headers.
+            ErspanVersion::Type3 => Some(unchecked! { get_unchecked_be_u16(self.slice, 8) }),
+        }
+    }
+
+    /// "P" flag present in ERSPAN Type III headers (set if the payload
+    /// is an Ethernet frame with a preserved FCS).
+    #[inline]
+    pub fn p_flag(&self) -> Option<bool> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            ErspanVersion::Type3 => Some(0 != self.slice[10] & 0b1000_0000),
+        }
+    }
+
+    /// 5 bit "frame type" present in ERSPAN Type III headers
+    /// (`0` for Ethernet frames & `2` for IP packets).
+    #[inline]
+    pub fn frame_type(&self) -> Option<u8> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            ErspanVersion::Type3 => Some((self.slice[10] >> 2) & 0b1_1111),
+        }
+    }
+
+    /// 6 bit "hardware id" present in ERSPAN Type III headers.
+    #[inline]
+    pub fn hardware_id(&self) -> Option<u8> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            ErspanVersion::Type3 => Some(((self.slice[10] & 0b11) << 4) | (self.slice[11] >> 4)),
+        }
+    }
+
+    /// Direction flag present in ERSPAN Type III headers (`false` if
+    /// the frame was mirrored on ingress & `true` if it was mirrored on
+    /// egress).
+    #[inline]
+    pub fn direction(&self) -> Option<bool> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            ErspanVersion::Type3 => Some(0 != self.slice[11] & 0b1000),
+        }
+    }
+
+    /// 2 bit timestamp granularity present in ERSPAN Type III headers.
+    #[inline]
+    pub fn timestamp_granularity(&self) -> Option<u8> {
+        match self.version() {
+            ErspanVersion::Type2 => None,
+            ErspanVersion::Type3 => Some((self.slice[11] >> 1) & 0b11),
+        }
+    }
+
+    /// Platform specific sub-header present in ERSPAN Type III headers
+    /// when the "O" flag is set.
+    #[inline]
+    pub fn platform_sub_header(&self) -> Option<&'a [u8]> {
+        if self.header_len == ErspanSlice::MAX_LEN {
+            Some(&self.slice[ErspanSlice::TYPE3_HEADER_LEN..ErspanSlice::MAX_LEN])
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::erspan::{HeaderError, HeaderSliceError};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    fn type2_header(vlan: u16, cos: u8, en: u8, t: bool, session: u16, index: u32) -> [u8; 8] {
+        let vlan = vlan.to_be_bytes();
+        let session = session.to_be_bytes();
+        let index = index.to_be_bytes();
+        [
+            0x10 | vlan[0],
+            vlan[1],
+            (cos << 5) | (en << 3) | (if t { 0b100 } else { 0 }) | session[0],
+            session[1],
+            index[0],
+            index[1],
+            index[2],
+            index[3],
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn type2(
+            vlan in 0..=VlanId::MAX_U16,
+            cos in 0..=VlanPcp::MAX_U8,
+            en in 0u8..=0b11,
+            t in any::<bool>(),
+            session in 0u16..=0x3ff,
+            index in 0u32..=0xf_ffff,
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let mut data = Vec::new();
+            data.extend_from_slice(&type2_header(vlan, cos, en, t, session, index));
+            data.extend_from_slice(&payload);
+
+            let slice = ErspanSlice::from_slice(&data).unwrap();
+            assert_eq!(slice.slice(), &data[..]);
+            assert_eq!(slice.header_slice(), &data[..8]);
+            assert_eq!(slice.payload(), &payload[..]);
+            assert_eq!(slice.header_len(), 8);
+            assert_eq!(slice.version(), ErspanVersion::Type2);
+            assert_eq!(slice.vlan_id().value(), vlan);
+            assert_eq!(slice.cos().value(), cos);
+            assert_eq!(slice.encap_type_or_bso(), en);
+            assert_eq!(slice.truncated(), t);
+            assert_eq!(slice.session_id(), session);
+            assert_eq!(slice.index(), Some(index));
+            assert_eq!(slice.timestamp(), None);
+            assert_eq!(slice.security_group_tag(), None);
+            assert_eq!(slice.p_flag(), None);
+            assert_eq!(slice.frame_type(), None);
+            assert_eq!(slice.hardware_id(), None);
+            assert_eq!(slice.direction(), None);
+            assert_eq!(slice.timestamp_granularity(), None);
+            assert_eq!(slice.platform_sub_header(), None);
+
+            // length error
+            for len in 0..8 {
+                assert_eq!(
+                    ErspanSlice::from_slice(&data[..len]).unwrap_err(),
+                    HeaderSliceError::Len(err::LenError {
+                        required_len: 8,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::ErspanHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn type3(
+            vlan in 0..=VlanId::MAX_U16,
+            cos in 0..=VlanPcp::MAX_U8,
+            bso in 0u8..=0b11,
+            t in any::<bool>(),
+            session in 0u16..=0x3ff,
+            timestamp in any::<u32>(),
+            sgt in any::<u16>(),
+            p in any::<bool>(),
+            ft in 0u8..=0b1_1111,
+            hw_id in 0u8..=0b11_1111,
+            d in any::<bool>(),
+            gra in 0u8..=0b11,
+            sub_header in proptest::option::of(any::<[u8;8]>()),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let mut data = Vec::new();
+            {
+                let vlan = vlan.to_be_bytes();
+                let session = session.to_be_bytes();
+                data.extend_from_slice(&[
+                    0x20 | vlan[0],
+                    vlan[1],
+                    (cos << 5) | (bso << 3) | (if t { 0b100 } else { 0 }) | session[0],
+                    session[1],
+                ]);
+                data.extend_from_slice(&timestamp.to_be_bytes());
+                data.extend_from_slice(&sgt.to_be_bytes());
+                data.push((if p { 0b1000_0000 } else { 0 }) | (ft << 2) | (hw_id >> 4));
+                data.push(
+                    ((hw_id & 0b1111) << 4)
+                    | (if d { 0b1000 } else { 0 })
+                    | (gra << 1)
+                    | (if sub_header.is_some() { 1 } else { 0 })
+                );
+                if let Some(sub_header) = sub_header.as_ref() {
+                    data.extend_from_slice(sub_header);
+                }
+            }
+            let header_len = data.len();
+            data.extend_from_slice(&payload);
+
+            let slice = ErspanSlice::from_slice(&data).unwrap();
+            assert_eq!(slice.slice(), &data[..]);
+            assert_eq!(slice.header_slice(), &data[..header_len]);
+            assert_eq!(slice.payload(), &payload[..]);
+            assert_eq!(slice.header_len(), header_len);
+            assert_eq!(slice.version(), ErspanVersion::Type3);
+            assert_eq!(slice.vlan_id().value(), vlan);
+            assert_eq!(slice.cos().value(), cos);
+            assert_eq!(slice.encap_type_or_bso(), bso);
+            assert_eq!(slice.truncated(), t);
+            assert_eq!(slice.session_id(), session);
+            assert_eq!(slice.index(), None);
+            assert_eq!(slice.timestamp(), Some(timestamp));
+            assert_eq!(slice.security_group_tag(), Some(sgt));
+            assert_eq!(slice.p_flag(), Some(p));
+            assert_eq!(slice.frame_type(), Some(ft));
+            assert_eq!(slice.hardware_id(), Some(hw_id));
+            assert_eq!(slice.direction(), Some(d));
+            assert_eq!(slice.timestamp_granularity(), Some(gra));
+            assert_eq!(slice.platform_sub_header(), sub_header.as_ref().map(|v| &v[..]));
+
+            // length error
+            for len in 1..header_len {
+                assert_eq!(
+                    ErspanSlice::from_slice(&data[..len]).unwrap_err(),
+                    HeaderSliceError::Len(err::LenError {
+                        required_len: if len < 12 { 12 } else { header_len },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::ErspanHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_errors() {
+        // empty slice
+        assert_eq!(
+            ErspanSlice::from_slice(&[]).unwrap_err(),
+            HeaderSliceError::Len(err::LenError {
+                required_len: ErspanSlice::MIN_LEN,
+                len: 0,
+                len_source: LenSource::Slice,
+                layer: Layer::ErspanHeader,
+                layer_start_offset: 0,
+            })
+        );
+
+        // unsupported versions
+        for version_number in 0..16u8 {
+            if version_number == 1 || version_number == 2 {
+                continue;
+            }
+            let mut data = type2_header(0, 0, 0, false, 0, 0);
+            data[0] = version_number << 4;
+            assert_eq!(
+                ErspanSlice::from_slice(&data).unwrap_err(),
+                HeaderSliceError::Content(HeaderError::UnsupportedVersion { version_number })
+            );
+        }
+    }
+
+    #[test]
+    fn inner_ethernet() {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let payload = [1, 2, 3, 4];
+        let mut data = Vec::new();
+        data.extend_from_slice(&type2_header(10, 0, 0, false, 1, 0));
+        builder.write(&mut data, &payload).unwrap();
+
+        let erspan = ErspanSlice::from_slice(&data).unwrap();
+        let inner = SlicedPacket::from_ethernet(erspan.payload()).unwrap();
+        match inner.transport {
+            Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &payload),
+            _ => panic!("expected udp"),
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = type2_header(1, 2, 0, false, 3, 4);
+        let slice = ErspanSlice::from_slice(&data).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!("{:?}", slice),
+            format!("ErspanSlice {{ slice: {:?}, header_len: 8 }}", &data[..])
+        );
+    }
+}
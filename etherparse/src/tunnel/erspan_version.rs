@@ -0,0 +1,72 @@
+/// Version of an ERSPAN header (determines the layout of the header).
+///
+/// The version is encoded in the first 4 bits of an ERSPAN header.
+/// Note that the version number in the header is one smaller then
+/// the "Type" name (`1` for ERSPAN Type II and `2` for ERSPAN Type III).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ErspanVersion {
+    /// ERSPAN Type II (version number `1`, GRE protocol type `0x88BE`).
+    Type2,
+    /// ERSPAN Type III (version number `2`, GRE protocol type `0x22EB`).
+    Type3,
+}
+
+impl ErspanVersion {
+    /// Version number of an ERSPAN Type II header.
+    pub const TYPE2_VERSION_NUMBER: u8 = 1;
+
+    /// Version number of an ERSPAN Type III header.
+    pub const TYPE3_VERSION_NUMBER: u8 = 2;
+
+    /// Returns the version number as it is encoded in the header.
+    #[inline]
+    pub const fn version_number(self) -> u8 {
+        match self {
+            ErspanVersion::Type2 => ErspanVersion::TYPE2_VERSION_NUMBER,
+            ErspanVersion::Type3 => ErspanVersion::TYPE3_VERSION_NUMBER,
+        }
+    }
+
+    /// Returns the GRE protocol type (an ether type value) used to
+    /// identify an ERSPAN header of this version.
+    #[inline]
+    pub const fn gre_protocol_type(self) -> crate::EtherType {
+        match self {
+            ErspanVersion::Type2 => crate::EtherType::ERSPAN_TYPE_2,
+            ErspanVersion::Type3 => crate::EtherType::ERSPAN_TYPE_3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::EtherType;
+    use alloc::format;
+
+    #[test]
+    fn version_number() {
+        assert_eq!(1, ErspanVersion::Type2.version_number());
+        assert_eq!(2, ErspanVersion::Type3.version_number());
+    }
+
+    #[test]
+    fn gre_protocol_type() {
+        assert_eq!(
+            EtherType::ERSPAN_TYPE_2,
+            ErspanVersion::Type2.gre_protocol_type()
+        );
+        assert_eq!(
+            EtherType::ERSPAN_TYPE_3,
+            ErspanVersion::Type3.gre_protocol_type()
+        );
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let v = ErspanVersion::Type2;
+        assert_eq!(v, v.clone());
+        assert_eq!("Type2", format!("{:?}", v));
+        assert_eq!("Type3", format!("{:?}", ErspanVersion::Type3));
+    }
+}
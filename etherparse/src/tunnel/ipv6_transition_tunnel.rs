@@ -0,0 +1,50 @@
+/// IPv6 transition mechanism that was used to tunnel an IPv6 packet
+/// through an IPv4 network (see [`crate::SlicedPacket::decode_ipv6_transition_tunnel`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Ipv6TransitionTunnel {
+    /// IPv6 packet encapsulated in an UDP packet ("Teredo", RFC 4380).
+    Teredo,
+    /// IPv6 packet with a 6to4 source or destination address
+    /// (`2002::/16`) directly encapsulated in an IPv4 packet with the
+    /// protocol number 41 ("6to4", RFC 3056).
+    SixToFour,
+    /// IPv6 packet directly encapsulated in an IPv4 packet with the
+    /// protocol number 41 ("6in4", RFC 4213) without 6to4 addresses.
+    SixInFour,
+}
+
+impl Ipv6TransitionTunnel {
+    /// Prefix of 6to4 IPv6 addresses (`2002::/16`).
+    pub const SIX_TO_FOUR_PREFIX: [u8; 2] = [0x20, 0x02];
+
+    /// Returns true if the given IPv6 address is a 6to4 address
+    /// (starts with the prefix `2002::/16`).
+    #[inline]
+    pub fn is_six_to_four_addr(addr: &[u8; 16]) -> bool {
+        addr[..2] == Self::SIX_TO_FOUR_PREFIX
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn is_six_to_four_addr() {
+        let mut addr = [0u8; 16];
+        assert!(false == Ipv6TransitionTunnel::is_six_to_four_addr(&addr));
+        addr[0] = 0x20;
+        addr[1] = 0x02;
+        assert!(Ipv6TransitionTunnel::is_six_to_four_addr(&addr));
+        addr[1] = 0x01;
+        assert!(false == Ipv6TransitionTunnel::is_six_to_four_addr(&addr));
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let value = Ipv6TransitionTunnel::Teredo;
+        assert_eq!(value, value.clone());
+        assert_eq!("Teredo", format!("{:?}", value));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod erspan_slice;
+pub mod erspan_version;
+pub mod ipv6_transition_tunnel;
+pub mod teredo_slice;
+pub mod tzsp_encapsulation;
+pub mod tzsp_packet_type;
+pub mod tzsp_slice;
+pub mod tzsp_tags_iterator;
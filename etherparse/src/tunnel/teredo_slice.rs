@@ -0,0 +1,270 @@
+use crate::*;
+
+/// Slice containing a Teredo packet (RFC 4380), an IPv6 packet that
+/// is optionally preceded by an authentication indicator and/or an
+/// origin indication.
+///
+/// Teredo packets are transported via UDP (port [`services::TEREDO`] on
+/// the server side):
+///
+/// ```
+/// # #[cfg(feature = "ipv6")]
+/// # {
+/// use etherparse::{SlicedPacket, TeredoSlice, TransportSlice};
+///
+/// # let mut ipv6 = Vec::new();
+/// # etherparse::PacketBuilder::ipv6([0x20, 0x01, 0, 0, 1, 2, 3, 4, 0, 0, 0, 0, 0, 0, 0, 1], [2; 16], 20)
+/// #     .udp(1234, 53)
+/// #     .write(&mut ipv6, &[1, 2, 3, 4])
+/// #     .unwrap();
+/// # let mut packet = Vec::new();
+/// # etherparse::PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #     .udp(3544, 50000)
+/// #     .write(&mut packet, &ipv6)
+/// #     .unwrap();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     if udp.is_probably_teredo() {
+///         let teredo = TeredoSlice::from_slice(udp.payload()).unwrap();
+///         assert_eq!(None, teredo.origin_port());
+///         let inner = SlicedPacket::from_ip(teredo.ipv6_packet()).unwrap();
+///     }
+/// }
+/// # }
+/// ```
+///
+/// See [`SlicedPacket::decode_ipv6_transition_tunnel`] to directly
+/// decode the IPv6 packet in a Teredo packet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeredoSlice<'a> {
+    slice: &'a [u8],
+    /// Start of the origin indication (equal to the length of the
+    /// authentication indicator).
+    origin_start: usize,
+    /// Start of the IPv6 packet.
+    ipv6_start: usize,
+}
+
+impl<'a> TeredoSlice<'a> {
+    /// Indicator type value of an authentication indicator (first two bytes).
+    pub const AUTH_INDICATOR_TYPE: [u8; 2] = [0x00, 0x01];
+
+    /// Indicator type value of an origin indication (first two bytes).
+    pub const ORIGIN_INDICATION_TYPE: [u8; 2] = [0x00, 0x00];
+
+    /// Minimum length of an authentication indicator (without client
+    /// identifier & authentication value).
+    pub const AUTH_INDICATOR_MIN_LEN: usize = 13;
+
+    /// Length of an origin indication.
+    pub const ORIGIN_INDICATION_LEN: usize = 8;
+
+    /// Try creating a [`TeredoSlice`] from a slice containing a Teredo
+    /// packet (e.g. the payload of an UDP packet).
+    ///
+    /// Only the lengths of the indicators are checked. Data without
+    /// indicators is always accepted (the contained IPv6 packet is
+    /// not verified).
+    pub fn from_slice(slice: &'a [u8]) -> Result<TeredoSlice<'a>, err::LenError> {
+        let len_error = |required_len| err::LenError {
+            required_len,
+            len: slice.len(),
+            len_source: LenSource::Slice,
+            layer: err::Layer::TeredoHeader,
+            layer_start_offset: 0,
+        };
+
+        // authentication indicator
+        let origin_start = if slice.len() >= 2 && slice[..2] == Self::AUTH_INDICATOR_TYPE {
+            if slice.len() < Self::AUTH_INDICATOR_MIN_LEN {
+                return Err(len_error(Self::AUTH_INDICATOR_MIN_LEN));
+            }
+            let len = Self::AUTH_INDICATOR_MIN_LEN + usize::from(slice[2]) + usize::from(slice[3]);
+            if slice.len() < len {
+                return Err(len_error(len));
+            }
+            len
+        } else {
+            0
+        };
+
+        // origin indication
+        let rest = &slice[origin_start..];
+        let ipv6_start = if rest.len() >= 2 && rest[..2] == Self::ORIGIN_INDICATION_TYPE {
+            let end = origin_start + Self::ORIGIN_INDICATION_LEN;
+            if slice.len() < end {
+                return Err(len_error(end));
+            }
+            end
+        } else {
+            origin_start
+        };
+
+        Ok(TeredoSlice {
+            slice,
+            origin_start,
+            ipv6_start,
+        })
+    }
+
+    /// Returns the slice containing the complete Teredo packet.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing the authentication indicator (`None`
+    /// if not present).
+    #[inline]
+    pub fn auth_indicator(&self) -> Option<&'a [u8]> {
+        (self.origin_start > 0).then(|| &self.slice[..self.origin_start])
+    }
+
+    /// Returns the slice containing the origin indication (`None` if not
+    /// present).
+    #[inline]
+    pub fn origin_indication(&self) -> Option<&'a [u8]> {
+        (self.ipv6_start > self.origin_start)
+            .then(|| &self.slice[self.origin_start..self.ipv6_start])
+    }
+
+    /// Returns the (de-obfuscated) origin UDP port of the origin
+    /// indication (`None` if no origin indication is present).
+    #[inline]
+    pub fn origin_port(&self) -> Option<u16> {
+        self.origin_indication()
+            .map(|o| !u16::from_be_bytes([o[2], o[3]]))
+    }
+
+    /// Returns the (de-obfuscated) origin IPv4 address of the origin
+    /// indication (`None` if no origin indication is present).
+    #[inline]
+    pub fn origin_addr(&self) -> Option<[u8; 4]> {
+        self.origin_indication()
+            .map(|o| [!o[4], !o[5], !o[6], !o[7]])
+    }
+
+    /// Returns the slice containing the IPv6 packet after the indicators.
+    #[inline]
+    pub fn ipv6_packet(&self) -> &'a [u8] {
+        &self.slice[self.ipv6_start..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            client_id in proptest::collection::vec(any::<u8>(), 0..4),
+            auth_value in proptest::collection::vec(any::<u8>(), 0..4),
+            origin_port in any::<u16>(),
+            origin_addr in any::<[u8;4]>(),
+            ipv6 in proptest::collection::vec(any::<u8>(), 0..8)
+        ) {
+            let mut auth = Vec::new();
+            auth.extend_from_slice(&[0, 1, client_id.len() as u8, auth_value.len() as u8]);
+            auth.extend_from_slice(&client_id);
+            auth.extend_from_slice(&auth_value);
+            auth.extend_from_slice(&[0; 9]);
+
+            let mut origin = Vec::new();
+            origin.extend_from_slice(&[0, 0]);
+            origin.extend_from_slice(&(!origin_port).to_be_bytes());
+            origin.extend(origin_addr.iter().map(|v| !v));
+
+            // the ipv6 packet is not allowed to start with an indicator type
+            let mut ipv6 = ipv6.clone();
+            ipv6.insert(0, 0x60);
+
+            for (with_auth, with_origin) in [(false, false), (true, false), (false, true), (true, true)] {
+                let mut data = Vec::new();
+                if with_auth {
+                    data.extend_from_slice(&auth);
+                }
+                if with_origin {
+                    data.extend_from_slice(&origin);
+                }
+                data.extend_from_slice(&ipv6);
+
+                let actual = TeredoSlice::from_slice(&data).unwrap();
+                assert_eq!(&data[..], actual.slice());
+                assert_eq!(with_auth.then(|| &auth[..]), actual.auth_indicator());
+                assert_eq!(with_origin.then(|| &origin[..]), actual.origin_indication());
+                assert_eq!(with_origin.then_some(origin_port), actual.origin_port());
+                assert_eq!(with_origin.then_some(origin_addr), actual.origin_addr());
+                assert_eq!(&ipv6[..], actual.ipv6_packet());
+
+                // length errors (once the indicator type is present)
+                let origin_start = if with_auth { auth.len() } else { 0 };
+                let mut lens = Vec::new();
+                if with_auth {
+                    lens.extend(2..auth.len());
+                }
+                if with_origin {
+                    lens.extend(origin_start + 2..origin_start + origin.len());
+                }
+                for len in lens {
+                    let err = TeredoSlice::from_slice(&data[..len]).unwrap_err();
+                    assert_eq!(len, err.len);
+                    assert!(err.required_len > len);
+                    assert_eq!(LenSource::Slice, err.len_source);
+                    assert_eq!(err::Layer::TeredoHeader, err.layer);
+                    assert_eq!(0, err.layer_start_offset);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_len_errors() {
+        let len_error = |required_len, len| err::LenError {
+            required_len,
+            len,
+            len_source: LenSource::Slice,
+            layer: err::Layer::TeredoHeader,
+            layer_start_offset: 0,
+        };
+
+        // authentication indicator shorter then the minimum length
+        assert_eq!(
+            TeredoSlice::from_slice(&[0, 1, 0, 0]),
+            Err(len_error(13, 4))
+        );
+
+        // authentication indicator shorter then the client id & auth value
+        assert_eq!(
+            TeredoSlice::from_slice(&[0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(len_error(16, 14))
+        );
+
+        // origin indication
+        assert_eq!(TeredoSlice::from_slice(&[0, 0, 1, 2]), Err(len_error(8, 4)));
+
+        // data without indicators
+        for data in [&[][..], &[0][..], &[0x60, 0][..]] {
+            let actual = TeredoSlice::from_slice(data).unwrap();
+            assert_eq!(None, actual.auth_indicator());
+            assert_eq!(None, actual.origin_indication());
+            assert_eq!(data, actual.ipv6_packet());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = [0x60, 0];
+        let slice = TeredoSlice::from_slice(&data).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!(
+                "TeredoSlice {{ slice: {:?}, origin_start: 0, ipv6_start: 0 }}",
+                &data[..]
+            ),
+            format!("{:?}", slice)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+/// Identifier of the protocol encapsulated in a TZSP packet.
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to a `TzspEncapsulation`:
+///
+/// ```
+/// use etherparse::TzspEncapsulation;
+///
+/// assert_eq!(TzspEncapsulation::ETHERNET.0, 1);
+/// assert_eq!(TzspEncapsulation::ETHERNET, TzspEncapsulation(1));
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct TzspEncapsulation(pub u16);
+
+impl TzspEncapsulation {
+    pub const ETHERNET: TzspEncapsulation = Self(1);
+    pub const IEEE_802_11: TzspEncapsulation = Self(18);
+    pub const PRISM_HEADER: TzspEncapsulation = Self(119);
+    pub const WLAN_AVS: TzspEncapsulation = Self(127);
+}
+
+impl From<u16> for TzspEncapsulation {
+    #[inline]
+    fn from(val: u16) -> Self {
+        TzspEncapsulation(val)
+    }
+}
+
+impl From<TzspEncapsulation> for u16 {
+    #[inline]
+    fn from(val: TzspEncapsulation) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for TzspEncapsulation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::ETHERNET => write!(f, "{} (Ethernet)", self.0),
+            Self::IEEE_802_11 => write!(f, "{} (IEEE 802.11)", self.0),
+            Self::PRISM_HEADER => write!(f, "{} (Prism Header)", self.0),
+            Self::WLAN_AVS => write!(f, "{} (WLAN AVS)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_from_u16() {
+        assert_eq!(1, u16::from(TzspEncapsulation::ETHERNET));
+        assert_eq!(18, u16::from(TzspEncapsulation::IEEE_802_11));
+        assert_eq!(119, u16::from(TzspEncapsulation::PRISM_HEADER));
+        assert_eq!(127, u16::from(TzspEncapsulation::WLAN_AVS));
+        assert_eq!(TzspEncapsulation::from(1), TzspEncapsulation::ETHERNET);
+        assert_eq!(TzspEncapsulation::from(1234), TzspEncapsulation(1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (TzspEncapsulation::ETHERNET, "1 (Ethernet)"),
+            (TzspEncapsulation::IEEE_802_11, "18 (IEEE 802.11)"),
+            (TzspEncapsulation::PRISM_HEADER, "119 (Prism Header)"),
+            (TzspEncapsulation::WLAN_AVS, "127 (WLAN AVS)"),
+            (TzspEncapsulation(2), "2"),
+        ];
+        for (value, str_value) in pairs {
+            assert_eq!(str_value, &format!("{:?}", value));
+        }
+    }
+
+    #[test]
+    fn default_clone_eq() {
+        let value: TzspEncapsulation = Default::default();
+        assert_eq!(TzspEncapsulation(0), value);
+        assert_eq!(value, value.clone());
+    }
+}
@@ -0,0 +1,94 @@
+/// Packet type field of a TZSP header.
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `TzspPacketType`:
+///
+/// ```
+/// use etherparse::TzspPacketType;
+///
+/// assert_eq!(TzspPacketType::RECEIVED_TAG_LIST.0, 0);
+/// assert_eq!(TzspPacketType::RECEIVED_TAG_LIST, TzspPacketType(0));
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct TzspPacketType(pub u8);
+
+impl TzspPacketType {
+    pub const RECEIVED_TAG_LIST: TzspPacketType = Self(0);
+    pub const PACKET_FOR_TRANSMIT: TzspPacketType = Self(1);
+    pub const RESERVED: TzspPacketType = Self(2);
+    pub const CONFIGURATION: TzspPacketType = Self(3);
+    pub const KEEPALIVE: TzspPacketType = Self(4);
+    pub const PORT_OPENER: TzspPacketType = Self(5);
+}
+
+impl From<u8> for TzspPacketType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        TzspPacketType(val)
+    }
+}
+
+impl From<TzspPacketType> for u8 {
+    #[inline]
+    fn from(val: TzspPacketType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for TzspPacketType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::RECEIVED_TAG_LIST => write!(f, "{} (Received tag list)", self.0),
+            Self::PACKET_FOR_TRANSMIT => write!(f, "{} (Packet for transmit)", self.0),
+            Self::RESERVED => write!(f, "{} (Reserved)", self.0),
+            Self::CONFIGURATION => write!(f, "{} (Configuration)", self.0),
+            Self::KEEPALIVE => write!(f, "{} (Keepalive)", self.0),
+            Self::PORT_OPENER => write!(f, "{} (Port opener)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_from_u8() {
+        assert_eq!(0, u8::from(TzspPacketType::RECEIVED_TAG_LIST));
+        assert_eq!(1, u8::from(TzspPacketType::PACKET_FOR_TRANSMIT));
+        assert_eq!(2, u8::from(TzspPacketType::RESERVED));
+        assert_eq!(3, u8::from(TzspPacketType::CONFIGURATION));
+        assert_eq!(4, u8::from(TzspPacketType::KEEPALIVE));
+        assert_eq!(5, u8::from(TzspPacketType::PORT_OPENER));
+        assert_eq!(TzspPacketType::from(4), TzspPacketType::KEEPALIVE);
+        assert_eq!(TzspPacketType::from(123), TzspPacketType(123));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (TzspPacketType::RECEIVED_TAG_LIST, "0 (Received tag list)"),
+            (
+                TzspPacketType::PACKET_FOR_TRANSMIT,
+                "1 (Packet for transmit)",
+            ),
+            (TzspPacketType::RESERVED, "2 (Reserved)"),
+            (TzspPacketType::CONFIGURATION, "3 (Configuration)"),
+            (TzspPacketType::KEEPALIVE, "4 (Keepalive)"),
+            (TzspPacketType::PORT_OPENER, "5 (Port opener)"),
+            (TzspPacketType(6), "6"),
+        ];
+        for (value, str_value) in pairs {
+            assert_eq!(str_value, &format!("{:?}", value));
+        }
+    }
+
+    #[test]
+    fn default_clone_eq() {
+        let value: TzspPacketType = Default::default();
+        assert_eq!(TzspPacketType(0), value);
+        assert_eq!(value, value.clone());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{
+    err::{self, Layer},
+    *,
+};
+
+/// Slice containing a TZSP (TaZmen Sniffer Protocol) header & the
+/// encapsulated frame as payload.
+///
+/// TZSP is usually transported via UDP (port [`TzspSlice::DEFAULT_UDP_PORT`])
+/// and used by switches, routers & access points to forward mirrored
+/// traffic. If the encapsulated protocol is [`TzspEncapsulation::ETHERNET`]
+/// the payload can be passed back into the normal slicing pipeline:
+///
+/// ```
+/// use etherparse::{SlicedPacket, TzspEncapsulation, TzspSlice};
+///
+/// # let packet = [
+/// #     1, 0, 0, 1, // version 1, received tag list, ethernet
+/// #     1, // end tag
+/// #     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x08, 0x00, // ethernet II
+/// # ];
+/// let tzsp = TzspSlice::from_slice(&packet).unwrap();
+/// if TzspEncapsulation::ETHERNET == tzsp.encapsulation() {
+///     let inner = SlicedPacket::from_ethernet(tzsp.payload());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TzspSlice<'a> {
+    slice: &'a [u8],
+    header_len: usize,
+}
+
+impl<'a> TzspSlice<'a> {
+    /// Default UDP port used to transport TZSP packets.
+    pub const DEFAULT_UDP_PORT: u16 = 37008;
+
+    /// Supported TZSP version number.
+    pub const VERSION: u8 = 1;
+
+    /// Minimum length of a TZSP header (fixed part & the "end" tag).
+    pub const MIN_LEN: usize = 5;
+
+    /// Tag type of the single byte "padding" tag.
+    pub const TAG_PADDING: u8 = 0;
+
+    /// Tag type of the single byte "end" tag (terminates the tag list).
+    pub const TAG_END: u8 = 1;
+
+    /// Try creating a [`TzspSlice`] from a slice containing a TZSP
+    /// header & the encapsulated frame.
+    pub fn from_slice(slice: &'a [u8]) -> Result<TzspSlice<'a>, err::tzsp::HeaderSliceError> {
+        use err::tzsp::{HeaderError::*, HeaderSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::TzspHeader,
+                layer_start_offset: 0,
+            })
+        };
+
+        if slice.len() < TzspSlice::MIN_LEN {
+            return Err(len_error(TzspSlice::MIN_LEN));
+        }
+
+        if TzspSlice::VERSION != slice[0] {
+            return Err(Content(UnsupportedVersion {
+                version_number: slice[0],
+            }));
+        }
+
+        // walk the tagged fields until the end tag is found
+        let mut offset = 4;
+        loop {
+            if slice.len() <= offset {
+                return Err(len_error(offset + 1));
+            }
+            match slice[offset] {
+                TzspSlice::TAG_END => {
+                    offset += 1;
+                    break;
+                }
+                TzspSlice::TAG_PADDING => {
+                    offset += 1;
+                }
+                _ => {
+                    if slice.len() <= offset + 1 {
+                        return Err(len_error(offset + 2));
+                    }
+                    let next = offset + 2 + usize::from(slice[offset + 1]);
+                    // the end tag has to follow after the data
+                    if slice.len() < next + 1 {
+                        return Err(len_error(next + 1));
+                    }
+                    offset = next;
+                }
+            }
+        }
+
+        Ok(TzspSlice {
+            slice,
+            header_len: offset,
+        })
+    }
+
+    /// Returns the slice containing the TZSP header & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the slice containing the TZSP header (including all tagged
+    /// fields & the end tag).
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least header_len.
+        unchecked! { get_unchecked_slice(self.slice, 0, self.header_len) }
+    }
+
+    /// Returns the slice containing the encapsulated frame.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least header_len.
+        unchecked! {
+            get_unchecked_slice(self.slice, self.header_len, self.slice.len() - self.header_len)
+        }
+    }
+
+    /// Length of the TZSP header in bytes (including all tagged fields).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.header_len
+    }
+
+    /// Read the "version" field of the TZSP header (always 1).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.slice[0]
+    }
+
+    /// Read the "type" field of the TZSP header.
+    #[inline]
+    pub fn packet_type(&self) -> TzspPacketType {
+        TzspPacketType(self.slice[1])
+    }
+
+    /// Read the "encapsulated protocol" field of the TZSP header.
+    #[inline]
+    pub fn encapsulation(&self) -> TzspEncapsulation {
+        // SAFETY: Safe as the slice length was verified in "from_slice"
+        // to be at least TzspSlice::MIN_LEN (5).
+        TzspEncapsulation(unchecked! { get_unchecked_be_u16(self.slice, 2) })
+    }
+
+    /// Returns the slice containing the tagged fields (without the end
+    /// tag).
+    #[inline]
+    pub fn tagged_fields_slice(&self) -> &'a [u8] {
+        &self.slice[4..self.header_len - 1]
+    }
+
+    /// Returns an iterator over all tagged fields (padding & the end tag
+    /// are skipped).
+    #[inline]
+    pub fn tags(&self) -> TzspTagsIterator<'a> {
+        TzspTagsIterator::from_slice(self.tagged_fields_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::tzsp::{HeaderError, HeaderSliceError};
+    use alloc::{format, vec, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            packet_type in any::<u8>(),
+            encapsulation in any::<u16>(),
+            tags in proptest::collection::vec(
+                (2u8..=255u8, proptest::collection::vec(any::<u8>(), 0..5)),
+                0..4
+            ),
+            padding in 0usize..3,
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let mut data = vec![1, packet_type];
+            data.extend_from_slice(&encapsulation.to_be_bytes());
+            for _ in 0..padding {
+                data.push(TzspSlice::TAG_PADDING);
+            }
+            for (tag_type, tag_data) in &tags {
+                data.push(*tag_type);
+                data.push(tag_data.len() as u8);
+                data.extend_from_slice(tag_data);
+            }
+            data.push(TzspSlice::TAG_END);
+            let header_len = data.len();
+            data.extend_from_slice(&payload);
+
+            let slice = TzspSlice::from_slice(&data).unwrap();
+            assert_eq!(slice.slice(), &data[..]);
+            assert_eq!(slice.header_slice(), &data[..header_len]);
+            assert_eq!(slice.header_len(), header_len);
+            assert_eq!(slice.payload(), &payload[..]);
+            assert_eq!(slice.version(), 1);
+            assert_eq!(slice.packet_type(), TzspPacketType(packet_type));
+            assert_eq!(slice.encapsulation(), TzspEncapsulation(encapsulation));
+            assert_eq!(slice.tagged_fields_slice(), &data[4..header_len - 1]);
+            assert_eq!(
+                slice.tags().collect::<Vec<_>>(),
+                tags.iter().map(|(t, d)| TzspTag{ tag_type: *t, data: &d[..] }).collect::<Vec<_>>()
+            );
+
+            // length errors
+            for len in 0..header_len {
+                let err = TzspSlice::from_slice(&data[..len]).unwrap_err();
+                match err {
+                    HeaderSliceError::Len(err) => {
+                        assert_eq!(err.len, len);
+                        assert!(err.required_len > len);
+                        assert!(err.required_len <= header_len);
+                        assert_eq!(err.len_source, LenSource::Slice);
+                        assert_eq!(err.layer, Layer::TzspHeader);
+                        assert_eq!(err.layer_start_offset, 0);
+                    },
+                    _ => panic!("unexpected error {:?}", err),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_version_error() {
+        for version_number in [0, 2, 255] {
+            assert_eq!(
+                TzspSlice::from_slice(&[version_number, 0, 0, 1, 1]).unwrap_err(),
+                HeaderSliceError::Content(HeaderError::UnsupportedVersion { version_number })
+            );
+        }
+    }
+
+    #[test]
+    fn inner_ethernet() {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let payload = [1, 2, 3, 4];
+        let mut data = vec![1, 0, 0, 1, 1];
+        builder.write(&mut data, &payload).unwrap();
+
+        let tzsp = TzspSlice::from_slice(&data).unwrap();
+        assert_eq!(tzsp.encapsulation(), TzspEncapsulation::ETHERNET);
+        let inner = SlicedPacket::from_ethernet(tzsp.payload()).unwrap();
+        match inner.transport {
+            Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &payload),
+            _ => panic!("expected udp"),
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = [1, 0, 0, 1, 1];
+        let slice = TzspSlice::from_slice(&data).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            format!("{:?}", slice),
+            format!("TzspSlice {{ slice: {:?}, header_len: 5 }}", &data[..])
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::TzspSlice;
+
+/// A tagged field of a TZSP header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TzspTag<'a> {
+    /// Type of the tag (e.g. `10` for the "RX channel").
+    pub tag_type: u8,
+    /// Data of the tag (without the type & length bytes).
+    pub data: &'a [u8],
+}
+
+/// Iterator over the tagged fields of a TZSP header.
+///
+/// Padding tags & the end tag are skipped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TzspTagsIterator<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> TzspTagsIterator<'a> {
+    /// Creates an iterator over the given tagged fields slice.
+    ///
+    /// Iteration stops when the end tag or an incomplete tag is
+    /// encountered.
+    #[inline]
+    pub fn from_slice(tagged_fields: &'a [u8]) -> TzspTagsIterator<'a> {
+        TzspTagsIterator {
+            rest: tagged_fields,
+        }
+    }
+
+    /// Returns the not yet iterated part of the tagged fields.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for TzspTagsIterator<'a> {
+    type Item = TzspTag<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(tag_type) = self.rest.first() {
+            match *tag_type {
+                TzspSlice::TAG_PADDING => {
+                    self.rest = &self.rest[1..];
+                }
+                TzspSlice::TAG_END => {
+                    self.rest = &[];
+                    return None;
+                }
+                tag_type => {
+                    let len = usize::from(*self.rest.get(1)?);
+                    if self.rest.len() < 2 + len {
+                        self.rest = &[];
+                        return None;
+                    }
+                    let data = &self.rest[2..2 + len];
+                    self.rest = &self.rest[2 + len..];
+                    return Some(TzspTag { tag_type, data });
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn next() {
+        // padding & end
+        {
+            let mut iter = TzspTagsIterator::from_slice(&[0, 0, 1, 10, 1, 2]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+        // tags
+        {
+            let data = [10, 1, 5, 0, 11, 2, 6, 7];
+            let tags: Vec<_> = TzspTagsIterator::from_slice(&data).collect();
+            assert_eq!(
+                tags,
+                [
+                    TzspTag {
+                        tag_type: 10,
+                        data: &[5]
+                    },
+                    TzspTag {
+                        tag_type: 11,
+                        data: &[6, 7]
+                    },
+                ]
+            );
+        }
+        // incomplete tag
+        {
+            let mut iter = TzspTagsIterator::from_slice(&[10]);
+            assert_eq!(None, iter.next());
+            let mut iter = TzspTagsIterator::from_slice(&[10, 2, 1]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = TzspTagsIterator::from_slice(&[10, 0]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(format!("{:?}", iter), "TzspTagsIterator { rest: [10, 0] }");
+        let tag = TzspTag {
+            tag_type: 10,
+            data: &[],
+        };
+        assert_eq!(tag, tag.clone());
+        assert_eq!(format!("{:?}", tag), "TzspTag { tag_type: 10, data: [] }");
+    }
+}
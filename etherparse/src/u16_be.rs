@@ -0,0 +1,93 @@
+/// Big endian (network byte order) encoded `u16` with an alignment of 1.
+///
+/// Used as field type in the `#[repr(C)]` raw header layouts (e.g.
+/// [`crate::UdpHeaderRaw`] & [`crate::Ipv4HeaderRaw`]) so they can be
+/// mapped directly onto packet buffers independent of the byte order &
+/// alignment requirements of the host:
+///
+/// ```
+/// use etherparse::U16Be;
+///
+/// let value = U16Be::new(0x1234);
+/// assert_eq!([0x12, 0x34], value.0);
+/// assert_eq!(0x1234, value.get());
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct U16Be(pub [u8; 2]);
+
+impl U16Be {
+    /// Creates a big endian value from a native `u16`.
+    #[inline]
+    pub const fn new(value: u16) -> U16Be {
+        U16Be(value.to_be_bytes())
+    }
+
+    /// Returns the value as native `u16`.
+    #[inline]
+    pub const fn get(&self) -> u16 {
+        u16::from_be_bytes(self.0)
+    }
+
+    /// Sets the value from a native `u16`.
+    #[inline]
+    pub fn set(&mut self, value: u16) {
+        self.0 = value.to_be_bytes();
+    }
+}
+
+impl From<u16> for U16Be {
+    #[inline]
+    fn from(value: u16) -> Self {
+        U16Be::new(value)
+    }
+}
+
+impl From<U16Be> for u16 {
+    #[inline]
+    fn from(value: U16Be) -> Self {
+        value.get()
+    }
+}
+
+impl core::fmt::Debug for U16Be {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn new_get_set(value in any::<u16>(), other in any::<u16>()) {
+            let mut v = U16Be::new(value);
+            assert_eq!(value.to_be_bytes(), v.0);
+            assert_eq!(value, v.get());
+            assert_eq!(v, U16Be::from(value));
+            assert_eq!(value, u16::from(v));
+
+            v.set(other);
+            assert_eq!(other.to_be_bytes(), v.0);
+            assert_eq!(other, v.get());
+        }
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(2, core::mem::size_of::<U16Be>());
+        assert_eq!(1, core::mem::align_of::<U16Be>());
+    }
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let v = U16Be::new(1234);
+        assert_eq!("1234", format!("{:?}", v));
+        assert_eq!(v, v.clone());
+        assert_eq!(U16Be([0, 0]), U16Be::default());
+    }
+}
@@ -0,0 +1,5 @@
+mod packet_visitor;
+pub use packet_visitor::*;
+
+mod parse;
+pub use parse::*;
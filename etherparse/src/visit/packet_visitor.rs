@@ -0,0 +1,99 @@
+use crate::*;
+use core::ops::ControlFlow;
+
+/// Callbacks that are called by [`crate::visit::parse_ethernet`] &
+/// [`crate::visit::parse_ip`] for every layer in the order the layers
+/// are located in the packet.
+///
+/// All callbacks have a default implementation that continues parsing,
+/// so only the callbacks for the layers of interest have to be
+/// implemented. Returning [`ControlFlow::Break`] from a callback stops
+/// the parsing (no further layers are decoded & no further callbacks
+/// are called).
+///
+/// ```
+/// # #[cfg(feature = "tcp")]
+/// # {
+/// use core::ops::ControlFlow;
+/// use etherparse::{visit::{self, PacketVisitor}, Ipv4Slice, PacketBuilder, TcpSlice};
+///
+/// /// Collects the destination port of TCP packets sent to 10.0.0.1.
+/// struct Filter {
+///     port: Option<u16>,
+/// }
+///
+/// impl<'a> PacketVisitor<'a> for Filter {
+///     fn on_ipv4(&mut self, ipv4: &Ipv4Slice<'a>) -> ControlFlow<()> {
+///         if ipv4.header().destination() == [10, 0, 0, 1] {
+///             ControlFlow::Continue(())
+///         } else {
+///             // not of interest, skip the rest of the packet
+///             ControlFlow::Break(())
+///         }
+///     }
+///
+///     fn on_tcp(&mut self, tcp: &TcpSlice<'a>) -> ControlFlow<()> {
+///         self.port = Some(tcp.destination_port());
+///         ControlFlow::Break(())
+///     }
+/// }
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([10, 0, 0, 2], [10, 0, 0, 1], 20)
+///     .tcp(1234, 443, 1, 1024)
+///     .write(&mut packet, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let mut filter = Filter { port: None };
+/// visit::parse_ethernet(&packet, &mut filter).unwrap();
+/// assert_eq!(Some(443), filter.port);
+/// # }
+/// ```
+pub trait PacketVisitor<'a> {
+    /// Called after an Ethernet II header was decoded.
+    fn on_ethernet(&mut self, _ethernet: &Ethernet2Slice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called for every decoded VLAN tag (at most two, outer tag first).
+    fn on_vlan(&mut self, _vlan: &SingleVlanSlice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called after an IPv4 header & its extension headers were decoded.
+    fn on_ipv4(&mut self, _ipv4: &Ipv4Slice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called after an IPv6 header & its extension headers were decoded.
+    fn on_ipv6(&mut self, _ipv6: &Ipv6Slice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called after an UDP header was decoded.
+    fn on_udp(&mut self, _udp: &UdpSlice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called after a TCP header was decoded.
+    fn on_tcp(&mut self, _tcp: &TcpSlice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called after an ICMPv4 header was decoded.
+    fn on_icmpv4(&mut self, _icmpv4: &Icmpv4Slice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called after an ICMPv6 header was decoded.
+    fn on_icmpv6(&mut self, _icmpv6: &Icmpv6Slice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called with the payload of the innermost decoded layer (called
+    /// last, if no other callback stopped the parsing before).
+    fn on_payload(&mut self, _payload: &PayloadSlice<'a>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,550 @@
+use super::PacketVisitor;
+use crate::*;
+
+/// Parses a packet starting with an Ethernet II header & calls the
+/// callbacks of the visitor for every decoded layer (see [`PacketVisitor`]).
+///
+/// The layers are decoded the same way as by [`SlicedPacket::from_ethernet`]
+/// (including the returned errors), but without building a [`SlicedPacket`].
+/// Parsing stops without an error as soon as a callback returns
+/// [`core::ops::ControlFlow::Break`]. In case of an error the callbacks for all layers
+/// before the erroneous layer have already been called.
+///
+/// ```
+/// # #[cfg(feature = "ipv6")]
+/// # {
+/// use core::ops::ControlFlow;
+/// use etherparse::{visit::{self, PacketVisitor}, PacketBuilder, PayloadSlice};
+///
+/// #[derive(Default)]
+/// struct PayloadLen(usize);
+///
+/// impl<'a> PacketVisitor<'a> for PayloadLen {
+///     fn on_payload(&mut self, payload: &PayloadSlice<'a>) -> ControlFlow<()> {
+///         self.0 = payload.slice().len();
+///         ControlFlow::Continue(())
+///     }
+/// }
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv6([1; 16], [2; 16], 20)
+///     .udp(1234, 53)
+///     .write(&mut packet, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let mut visitor = PayloadLen::default();
+/// visit::parse_ethernet(&packet, &mut visitor).unwrap();
+/// assert_eq!(4, visitor.0);
+/// # }
+/// ```
+pub fn parse_ethernet<'a, V: PacketVisitor<'a>>(
+    data: &'a [u8],
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    use err::packet::SliceError::*;
+    use ether_type::*;
+
+    let ethernet = Ethernet2Slice::from_slice_without_fcs(data).map_err(Len)?;
+    if visitor.on_ethernet(&ethernet).is_break() {
+        return Ok(());
+    }
+
+    let mut offset = ethernet.header_len();
+    let mut payload = ethernet.payload();
+    let mut vlan_count = 0;
+    while payload.ether_type.is_vlan_tag() && vlan_count < 2 {
+        let vlan = SingleVlanSlice::from_slice(payload.payload)
+            .map_err(|err| Len(err.add_offset(offset)))?;
+        if visitor.on_vlan(&vlan).is_break() {
+            return Ok(());
+        }
+        offset += vlan.header_len();
+        payload = vlan.payload();
+        vlan_count += 1;
+    }
+
+    match payload.ether_type {
+        IPV4 => parse_ipv4(payload.payload, offset, visitor),
+        #[cfg(feature = "ipv6")]
+        IPV6 => parse_ipv6(payload.payload, offset, visitor),
+        _ => {
+            let _ = visitor.on_payload(&PayloadSlice::Ether(payload));
+            Ok(())
+        }
+    }
+}
+
+/// Parses a packet starting with an IPv4 or IPv6 header & calls the
+/// callbacks of the visitor for every decoded layer (see [`PacketVisitor`]).
+///
+/// The layers are decoded the same way as by [`SlicedPacket::from_ip`]
+/// (including the returned errors), but without building a [`SlicedPacket`].
+/// Parsing stops without an error as soon as a callback returns
+/// [`core::ops::ControlFlow::Break`].
+///
+/// ```
+/// use core::ops::ControlFlow;
+/// use etherparse::{visit::{self, PacketVisitor}, PacketBuilder, UdpSlice};
+///
+/// struct Ports(Option<(u16, u16)>);
+///
+/// impl<'a> PacketVisitor<'a> for Ports {
+///     fn on_udp(&mut self, udp: &UdpSlice<'a>) -> ControlFlow<()> {
+///         self.0 = Some((udp.source_port(), udp.destination_port()));
+///         ControlFlow::Break(())
+///     }
+/// }
+///
+/// let mut packet = Vec::new();
+/// PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(1234, 53)
+///     .write(&mut packet, &[1, 2, 3, 4])
+///     .unwrap();
+///
+/// let mut visitor = Ports(None);
+/// visit::parse_ip(&packet, &mut visitor).unwrap();
+/// assert_eq!(Some((1234, 53)), visitor.0);
+/// ```
+pub fn parse_ip<'a, V: PacketVisitor<'a>>(
+    data: &'a [u8],
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    use err::packet::SliceError::*;
+
+    let ip = IpSlice::from_slice(data).map_err(|err| {
+        use err::ip::SliceError as I;
+        match err {
+            I::Len(err) => Len(err),
+            I::IpHeaders(err) => match err {
+                err::ip::HeadersError::Ip(err) => Ip(err),
+                err::ip::HeadersError::Ipv4Ext(err) => Ipv4Exts(err),
+                err::ip::HeadersError::Ipv6Ext(err) => Ipv6Exts(err),
+            },
+        }
+    })?;
+    match &ip {
+        IpSlice::Ipv4(ipv4) => visit_ipv4(ipv4, data, 0, visitor),
+        IpSlice::Ipv6(ipv6) => visit_ipv6(ipv6, data, 0, visitor),
+    }
+}
+
+/// Parses an IPv4 header located at `offset` in the packet.
+fn parse_ipv4<'a, V: PacketVisitor<'a>>(
+    data: &'a [u8],
+    offset: usize,
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    use err::packet::SliceError::*;
+
+    let ipv4 = Ipv4Slice::from_slice(data).map_err(|err| {
+        use err::ipv4::SliceError as I;
+        match err {
+            I::Len(mut err) => {
+                err.layer_start_offset += offset;
+                Len(err)
+            }
+            I::Header(err) => Ipv4(err),
+            I::Exts(err) => Ipv4Exts(err),
+        }
+    })?;
+    visit_ipv4(&ipv4, data, offset, visitor)
+}
+
+/// Parses an IPv6 header located at `offset` in the packet.
+#[cfg(feature = "ipv6")]
+fn parse_ipv6<'a, V: PacketVisitor<'a>>(
+    data: &'a [u8],
+    offset: usize,
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    use err::packet::SliceError::*;
+
+    let ipv6 = Ipv6Slice::from_slice(data).map_err(|err| {
+        use err::ipv6::SliceError as I;
+        match err {
+            I::Len(mut err) => {
+                err.layer_start_offset += offset;
+                Len(err)
+            }
+            I::Header(err) => Ipv6(err),
+            I::Exts(err) => Ipv6Exts(err),
+        }
+    })?;
+    visit_ipv6(&ipv6, data, offset, visitor)
+}
+
+/// Calls the IPv4 callback & continues with the IP payload.
+fn visit_ipv4<'a, V: PacketVisitor<'a>>(
+    ipv4: &Ipv4Slice<'a>,
+    data: &'a [u8],
+    offset: usize,
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    if visitor.on_ipv4(ipv4).is_break() {
+        return Ok(());
+    }
+    parse_ip_payload(ipv4.payload(), data, offset, visitor)
+}
+
+/// Calls the IPv6 callback & continues with the IP payload.
+fn visit_ipv6<'a, V: PacketVisitor<'a>>(
+    ipv6: &Ipv6Slice<'a>,
+    data: &'a [u8],
+    offset: usize,
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    if visitor.on_ipv6(ipv6).is_break() {
+        return Ok(());
+    }
+    parse_ip_payload(ipv6.payload(), data, offset, visitor)
+}
+
+/// Parses the transport layer contained in an IP payload (`data` is the
+/// slice starting with the IP header located at `offset` in the packet).
+///
+/// Fragmented payloads are passed to [`PacketVisitor::on_payload`]
+/// without decoding the transport layer.
+fn parse_ip_payload<'a, V: PacketVisitor<'a>>(
+    ip_payload: &IpPayloadSlice<'a>,
+    data: &'a [u8],
+    offset: usize,
+    visitor: &mut V,
+) -> Result<(), err::packet::SliceError> {
+    use err::packet::SliceError::*;
+
+    let slice = ip_payload.payload;
+    let offset = offset + ((slice.as_ptr() as usize) - (data.as_ptr() as usize));
+    let map_len = |mut err: err::LenError| {
+        err.layer_start_offset += offset;
+        if LenSource::Slice == err.len_source {
+            err.len_source = ip_payload.len_source;
+        }
+        err
+    };
+
+    if ip_payload.fragmented {
+        let _ = visitor.on_payload(&PayloadSlice::Ip(ip_payload.clone()));
+        return Ok(());
+    }
+
+    let payload = match ip_payload.ip_number {
+        #[cfg(feature = "icmp")]
+        ip_number::ICMP => {
+            let icmpv4 = Icmpv4Slice::from_slice(slice).map_err(|err| Len(map_len(err)))?;
+            if visitor.on_icmpv4(&icmpv4).is_break() {
+                return Ok(());
+            }
+            PayloadSlice::Icmpv4(icmpv4.payload())
+        }
+        ip_number::UDP => {
+            let udp = UdpSlice::from_slice(slice).map_err(|err| Len(map_len(err)))?;
+            if visitor.on_udp(&udp).is_break() {
+                return Ok(());
+            }
+            PayloadSlice::Udp(udp.payload())
+        }
+        #[cfg(feature = "tcp")]
+        ip_number::TCP => {
+            let tcp = TcpSlice::from_slice(slice).map_err(|err| {
+                use err::tcp::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(map_len(err)),
+                    I::Content(err) => Tcp(err),
+                }
+            })?;
+            if visitor.on_tcp(&tcp).is_break() {
+                return Ok(());
+            }
+            PayloadSlice::Tcp(tcp.payload())
+        }
+        #[cfg(all(feature = "icmp", feature = "ipv6"))]
+        ip_number::IPV6_ICMP => {
+            let icmpv6 = Icmpv6Slice::from_slice(slice).map_err(|err| Len(map_len(err)))?;
+            if visitor.on_icmpv6(&icmpv6).is_break() {
+                return Ok(());
+            }
+            PayloadSlice::Icmpv6(icmpv6.payload())
+        }
+        _ => PayloadSlice::Ip(ip_payload.clone()),
+    };
+    let _ = visitor.on_payload(&payload);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::vec::Vec;
+    use core::ops::ControlFlow;
+    use proptest::prelude::*;
+
+    /// Visitor recording the name & payload of every visited layer.
+    struct Recorder<'a> {
+        events: Vec<(&'static str, &'a [u8])>,
+        /// Number of events after which the parsing gets stopped.
+        break_after: Option<usize>,
+    }
+
+    impl<'a> Recorder<'a> {
+        fn new(break_after: Option<usize>) -> Recorder<'a> {
+            Recorder {
+                events: Vec::new(),
+                break_after,
+            }
+        }
+
+        fn record(&mut self, name: &'static str, slice: &'a [u8]) -> ControlFlow<()> {
+            self.events.push((name, slice));
+            if Some(self.events.len()) == self.break_after {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<'a> PacketVisitor<'a> for Recorder<'a> {
+        fn on_ethernet(&mut self, ethernet: &Ethernet2Slice<'a>) -> ControlFlow<()> {
+            self.record("ethernet", ethernet.payload_slice())
+        }
+        fn on_vlan(&mut self, vlan: &SingleVlanSlice<'a>) -> ControlFlow<()> {
+            self.record("vlan", vlan.payload().payload)
+        }
+        fn on_ipv4(&mut self, ipv4: &Ipv4Slice<'a>) -> ControlFlow<()> {
+            self.record("ipv4", ipv4.payload().payload)
+        }
+        fn on_ipv6(&mut self, ipv6: &Ipv6Slice<'a>) -> ControlFlow<()> {
+            self.record("ipv6", ipv6.payload().payload)
+        }
+        fn on_udp(&mut self, udp: &UdpSlice<'a>) -> ControlFlow<()> {
+            self.record("udp", udp.slice())
+        }
+        fn on_tcp(&mut self, tcp: &TcpSlice<'a>) -> ControlFlow<()> {
+            self.record("tcp", tcp.slice())
+        }
+        fn on_icmpv4(&mut self, icmpv4: &Icmpv4Slice<'a>) -> ControlFlow<()> {
+            self.record("icmpv4", icmpv4.slice())
+        }
+        fn on_icmpv6(&mut self, icmpv6: &Icmpv6Slice<'a>) -> ControlFlow<()> {
+            self.record("icmpv6", icmpv6.slice())
+        }
+        fn on_payload(&mut self, payload: &PayloadSlice<'a>) -> ControlFlow<()> {
+            self.record("payload", payload.slice())
+        }
+    }
+
+    /// Events expected based on the result of [`SlicedPacket`].
+    fn expected_events<'a>(sliced: &'a SlicedPacket<'a>) -> Vec<(&'static str, &'a [u8])> {
+        let mut result = Vec::new();
+        if let Some(LinkSlice::Ethernet2(eth)) = &sliced.link {
+            result.push(("ethernet", eth.payload_slice()));
+        }
+        match &sliced.vlan {
+            Some(VlanSlice::SingleVlan(s)) => result.push(("vlan", s.payload().payload)),
+            Some(VlanSlice::DoubleVlan(d)) => {
+                result.push(("vlan", &d.slice()[SingleVlanHeader::LEN..]));
+                result.push(("vlan", &d.slice()[2 * SingleVlanHeader::LEN..]));
+            }
+            None => {}
+        }
+        match &sliced.net {
+            Some(NetSlice::Ipv4(ipv4)) => result.push(("ipv4", ipv4.payload().payload)),
+            Some(NetSlice::Ipv6(ipv6)) => result.push(("ipv6", ipv6.payload().payload)),
+            _ => {}
+        }
+        let payload = match &sliced.transport {
+            Some(TransportSlice::Udp(s)) => {
+                result.push(("udp", s.slice()));
+                s.payload()
+            }
+            Some(TransportSlice::Tcp(s)) => {
+                result.push(("tcp", s.slice()));
+                s.payload()
+            }
+            Some(TransportSlice::Icmpv4(s)) => {
+                result.push(("icmpv4", s.slice()));
+                s.payload()
+            }
+            Some(TransportSlice::Icmpv6(s)) => {
+                result.push(("icmpv6", s.slice()));
+                s.payload()
+            }
+            None => {
+                if let Some(ip_payload) = sliced.ip_payload() {
+                    ip_payload.payload
+                } else {
+                    sliced.ether_payload().unwrap().payload
+                }
+            }
+        };
+        result.push(("payload", payload));
+        result
+    }
+
+    /// Checks that the visitor based parsing results in the same
+    /// layers & errors as [`SlicedPacket`] for all prefixes of the
+    /// packet & for stopping after every event.
+    fn assert_same_as_sliced(packet: &[u8], from_ip: bool) {
+        for len in 0..=packet.len() {
+            let data = &packet[..len];
+            let (expected, actual) = if from_ip {
+                let mut recorder = Recorder::new(None);
+                (
+                    SlicedPacket::from_ip(data),
+                    parse_ip(data, &mut recorder).map(|_| recorder.events),
+                )
+            } else {
+                let mut recorder = Recorder::new(None);
+                (
+                    SlicedPacket::from_ethernet(data),
+                    parse_ethernet(data, &mut recorder).map(|_| recorder.events),
+                )
+            };
+            match expected {
+                Ok(sliced) => {
+                    let events = expected_events(&sliced);
+                    assert_eq!(Ok(events.clone()), actual);
+
+                    // early termination
+                    for break_after in 1..=events.len() {
+                        let mut recorder = Recorder::new(Some(break_after));
+                        if from_ip {
+                            parse_ip(data, &mut recorder).unwrap();
+                        } else {
+                            parse_ethernet(data, &mut recorder).unwrap();
+                        }
+                        assert_eq!(&events[..break_after], &recorder.events[..]);
+                    }
+                }
+                Err(err) => assert_eq!(Err(err), actual),
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_ethernet_ipv4(
+            eth in ethernet_2_unknown(),
+            ip_number in prop_oneof![
+                Just(ip_number::UDP),
+                Just(ip_number::TCP),
+                Just(ip_number::ICMP),
+                Just(ip_number::IPV6_ICMP),
+                Just(IpNumber(253))
+            ],
+            more_fragments in any::<bool>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let builder = PacketBuilder::ethernet2(eth.source, eth.destination)
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20);
+            let mut packet = Vec::new();
+            match ip_number {
+                ip_number::UDP => builder.udp(1, 2).write(&mut packet, &payload).unwrap(),
+                ip_number::TCP => builder.tcp(1, 2, 3, 4).write(&mut packet, &payload).unwrap(),
+                ip_number::ICMP => builder.icmpv4_echo_request(1, 2).write(&mut packet, &payload).unwrap(),
+                ip_number => builder.write(&mut packet, ip_number, &payload).unwrap(),
+            }
+            if more_fragments {
+                // set the "more fragments" flag
+                packet[Ethernet2Header::LEN + 6] |= 0b0010_0000;
+                let mut header = Ipv4HeaderSlice::from_slice(&packet[Ethernet2Header::LEN..])
+                    .unwrap()
+                    .to_header();
+                header.header_checksum = header.calc_header_checksum();
+                let start = Ethernet2Header::LEN;
+                packet[start..start + header.header_len()]
+                    .copy_from_slice(&header.to_bytes());
+            }
+            assert_same_as_sliced(&packet, false);
+            assert_same_as_sliced(&packet[Ethernet2Header::LEN..], true);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_ethernet_vlan_ipv6(
+            vlan_count in 0usize..4,
+            ip_number in prop_oneof![
+                Just(ip_number::UDP),
+                Just(ip_number::TCP),
+                Just(ip_number::IPV6_ICMP),
+                Just(IpNumber(253))
+            ],
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut ip = Vec::new();
+            let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20);
+            match ip_number {
+                ip_number::UDP => builder.udp(1, 2).write(&mut ip, &payload).unwrap(),
+                ip_number::TCP => builder.tcp(1, 2, 3, 4).write(&mut ip, &payload).unwrap(),
+                ip_number::IPV6_ICMP => builder.icmpv6_echo_request(1, 2).write(&mut ip, &payload).unwrap(),
+                ip_number => builder.write(&mut ip, ip_number, &payload).unwrap(),
+            }
+
+            // ethernet & vlan headers
+            let mut packet = Vec::new();
+            let ether_types = [
+                ether_type::PROVIDER_BRIDGING,
+                ether_type::VLAN_TAGGED_FRAME,
+                ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+            ];
+            let first_ether_type = if vlan_count > 0 {
+                ether_types[0]
+            } else {
+                ether_type::IPV6
+            };
+            packet.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1, 2, 3, 4, 5, 6],
+                    destination: [7, 8, 9, 10, 11, 12],
+                    ether_type: first_ether_type,
+                }
+                .to_bytes(),
+            );
+            for i in 0..vlan_count {
+                packet.extend_from_slice(
+                    &SingleVlanHeader {
+                        pcp: VlanPcp::ZERO,
+                        drop_eligible_indicator: false,
+                        vlan_id: VlanId::try_new(i as u16 + 1).unwrap(),
+                        ether_type: if i + 1 < vlan_count {
+                            ether_types[i + 1]
+                        } else {
+                            ether_type::IPV6
+                        },
+                    }
+                    .to_bytes(),
+                );
+            }
+            packet.extend_from_slice(&ip);
+
+            assert_same_as_sliced(&packet, false);
+        }
+    }
+
+    #[test]
+    fn parse_ethernet_unknown_ether_type() {
+        let packet = Ethernet2Header {
+            source: [1, 2, 3, 4, 5, 6],
+            destination: [7, 8, 9, 10, 11, 12],
+            ether_type: ether_type::ARP,
+        }
+        .to_bytes();
+        let mut recorder = Recorder::new(None);
+        parse_ethernet(&packet, &mut recorder).unwrap();
+        assert_eq!(
+            &[("ethernet", &[][..]), ("payload", &[][..])],
+            &recorder.events[..]
+        );
+    }
+
+    #[test]
+    fn parse_ip_errors() {
+        // unsupported ip version
+        assert_same_as_sliced(&[0x70, 0, 0, 0], true);
+        // ipv4 header with a bad ihl
+        assert_same_as_sliced(&[0x41, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0], true);
+    }
+}
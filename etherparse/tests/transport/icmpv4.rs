@@ -17,6 +17,7 @@ mod icmpv4_regression {
         assert_eq!(rest.len(), 0);
     }
 
+    #[cfg(feature = "icmp")]
     #[test]
     fn ip4_echo_marshall_unmarshall() {
         let builder = PacketBuilder::ipv4(
@@ -66,6 +67,7 @@ mod icmpv4_regression {
 
     // real echo request/reply captured from tcpdump
     // ping 127.0.0.1 to 127.0.0.1
+    #[cfg(feature = "icmp")]
     #[test]
     fn pcap_echo_session() {
         let request = PacketHeaders::from_ethernet_slice(&ICMP4_ECHO_REQUEST_BYTES).unwrap();
@@ -101,6 +103,7 @@ mod icmpv4_regression {
         }
     }
 
+    #[cfg(feature = "icmp")]
     #[test]
     fn echo_request_slice() {
         let echo = SlicedPacket::from_ethernet(&ICMP4_ECHO_REQUEST_BYTES).unwrap();
@@ -112,6 +115,7 @@ mod icmpv4_regression {
         assert!(matches!(icmp4.icmp_type(), Icmpv4Type::EchoRequest(_)));
     }
 
+    #[cfg(feature = "icmp")]
     #[test]
     fn verify_icmp4_checksum() {
         for (pkt, checksum) in [
@@ -143,6 +147,7 @@ mod icmpv4_regression {
         0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53,
         0x54, 0x55, 0x56, 0x57,
     ];
+    #[cfg(feature = "icmp")]
     #[test]
     fn parse_icmp4_ttl_exceeded() {
         let ttl_exceeded = PacketHeaders::from_ethernet_slice(&ICMP4_TTL_EXCEEDED_BYTES).unwrap();
@@ -185,6 +190,7 @@ mod icmpv4_regression {
         0x3c, 0xe3, 0xd2, 0x00, 0x00, 0x01, 0x11, 0x13, 0xe1, 0xc0, 0xa8, 0x01, 0x6e, 0xd8, 0xef,
         0x26, 0x78, 0xb3, 0x4e, 0x82, 0xb2, 0x00, 0x28, 0x13, 0x1a,
     ];
+    #[cfg(feature = "icmp")]
     #[test]
     fn icmp4_dst_unreachable() {
         let offset = 14 + 20 + 1; // ethernet + iphdr + icmp_type
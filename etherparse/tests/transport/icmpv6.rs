@@ -17,6 +17,7 @@ mod regression {
         assert_eq!(rest.len(), 0);
     }
 
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
     #[test]
     fn ip6_echo_marshall_unmarshall() {
         let builder = PacketBuilder::ipv6(
@@ -68,6 +69,7 @@ mod regression {
         0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
     ];
 
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
     #[test]
     fn verify_icmp6_checksum() {
         for (pkt, checksum) in [
@@ -95,6 +97,7 @@ mod regression {
         }
     }
 
+    #[cfg(all(feature = "icmp", feature = "ipv6"))]
     #[test]
     fn echo_request_slice() {
         let echo = SlicedPacket::from_ethernet(&ICMP6_ECHO_REQUEST_BYTES).unwrap();
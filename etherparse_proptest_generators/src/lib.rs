@@ -482,6 +482,7 @@ prop_compose! {
             } else {
                 None
             },
+            mobility: None,
         };
         result.set_next_headers(next_header);
         result